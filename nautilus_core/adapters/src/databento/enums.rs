@@ -119,5 +119,35 @@ impl FromU8 for DatabentoStatisticUpdateAction {
     }
 }
 
+/// The Nautilus data types which can be read from DBN files in chunks.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum DatabentoDataType {
+    /// Order book deltas from the MBO schema.
+    OrderBookDelta = 1,
+    /// Quotes from the MBP-1 and BBO schemas.
+    QuoteTick = 2,
+    /// Trades from the trades schema, or the trades within the MBO and MBP-1 schemas.
+    TradeTick = 3,
+    /// Bars from the OHLCV schemas.
+    Bar = 4,
+}
+
 enum_strum_serde!(DatabentoStatisticType);
 enum_strum_serde!(DatabentoStatisticUpdateAction);
@@ -0,0 +1,225 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! C foreign function interface (FFI) from `cbindgen`.
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+    path::PathBuf,
+};
+
+use indexmap::IndexMap;
+use nautilus_core::ffi::{cvec::CVec, parsing::optional_bytes_to_str_map, string::cstr_to_str};
+use nautilus_model::{
+    data::{bar::Bar, delta::OrderBookDelta, quote::QuoteTick, trade::TradeTick},
+    identifiers::InstrumentId,
+};
+
+use super::{enums::DatabentoDataType, loader::DatabentoDataLoader, reader::DatabentoReaderAny};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`DatabentoReaderAny`].
+///
+/// This struct wraps `DatabentoReaderAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `DatabentoReaderAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `DatabentoReader_API` to be
+/// dereferenced to `DatabentoReaderAny`, providing access to `DatabentoReaderAny`'s methods
+/// without having to manually access the underlying `DatabentoReaderAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct DatabentoReader_API(Box<DatabentoReaderAny>);
+
+impl Deref for DatabentoReader_API {
+    type Target = DatabentoReaderAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for DatabentoReader_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Opens a new DBN reader for `data_type` at the file path `path_ptr`, returning chunks of
+/// `chunk_size` items with prices at `price_precision`.
+///
+/// The venue of each record is given by its publisher in the publishers JSON file at
+/// `publishers_path_ptr`. The `symbology_ptr` is a JSON object mapping Databento instrument
+/// IDs to Nautilus instrument IDs, which take precedence over the symbology in the file.
+///
+/// # Safety
+///
+/// - Assumes `path_ptr` is a valid C string pointer.
+/// - Assumes `publishers_path_ptr` is a valid C string pointer.
+/// - Assumes `symbology_ptr` is either NULL or a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the publishers file cannot be loaded.
+/// - If a Databento instrument ID in the symbology map is not an integer.
+/// - If the reader cannot be created (e.g. the file cannot be opened, or has another schema).
+#[no_mangle]
+pub unsafe extern "C" fn databento_reader_new(
+    data_type: DatabentoDataType,
+    path_ptr: *const c_char,
+    publishers_path_ptr: *const c_char,
+    symbology_ptr: *const c_char,
+    price_precision: u8,
+    chunk_size: usize,
+) -> DatabentoReader_API {
+    let publishers_path = PathBuf::from(cstr_to_str(publishers_path_ptr));
+    let loader = DatabentoDataLoader::new(Some(publishers_path)).unwrap();
+    let symbology: IndexMap<u32, InstrumentId> = optional_bytes_to_str_map(symbology_ptr)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(raw_id, instrument_id)| {
+            let raw_id = raw_id
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid Databento instrument ID, was {raw_id}"));
+            (raw_id, InstrumentId::from(instrument_id.as_str()))
+        })
+        .collect();
+
+    let reader = DatabentoReaderAny::new(
+        data_type,
+        cstr_to_str(path_ptr),
+        loader.get_publisher_venue_map().clone(),
+        symbology,
+        price_precision,
+        chunk_size,
+    )
+    .unwrap();
+    DatabentoReader_API(Box::new(reader))
+}
+
+#[no_mangle]
+pub extern "C" fn databento_reader_drop(reader: DatabentoReader_API) {
+    drop(reader); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn databento_reader_data_type(reader: &DatabentoReader_API) -> DatabentoDataType {
+    reader.data_type()
+}
+
+/// Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+/// once the file is exhausted.
+///
+/// The chunk must be freed with `databento_reader_drop_chunk`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If reading or decoding the file fails.
+#[no_mangle]
+pub extern "C" fn databento_reader_next_chunk(reader: &mut DatabentoReader_API) -> CVec {
+    match &mut **reader {
+        DatabentoReaderAny::OrderBookDelta(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        DatabentoReaderAny::QuoteTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        DatabentoReaderAny::TradeTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        DatabentoReaderAny::Bar(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+    }
+}
+
+/// Drops a chunk previously returned by `databento_reader_next_chunk`.
+///
+/// # Safety
+///
+/// - Assumes `chunk` was returned by `databento_reader_next_chunk` for this `reader`.
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub unsafe extern "C" fn databento_reader_drop_chunk(reader: &DatabentoReader_API, chunk: CVec) {
+    let CVec { ptr, len, cap } = chunk;
+    if ptr.is_null() {
+        return;
+    }
+
+    match reader.data_type() {
+        DatabentoDataType::OrderBookDelta => {
+            drop(Vec::from_raw_parts(ptr.cast::<OrderBookDelta>(), len, cap));
+        }
+        DatabentoDataType::QuoteTick => {
+            drop(Vec::from_raw_parts(ptr.cast::<QuoteTick>(), len, cap))
+        }
+        DatabentoDataType::TradeTick => {
+            drop(Vec::from_raw_parts(ptr.cast::<TradeTick>(), len, cap))
+        }
+        DatabentoDataType::Bar => drop(Vec::from_raw_parts(ptr.cast::<Bar>(), len, cap)),
+    }
+}
+
+/// Returns a chunk of data as a `CVec`, or an empty `CVec` if there is no chunk.
+fn chunk_to_cvec<T>(chunk: Option<Vec<T>>) -> CVec {
+    chunk.map_or_else(CVec::empty, CVec::from)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, path::Path};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn c_path(relative: &str) -> CString {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(relative);
+        CString::new(path.to_str().unwrap()).unwrap()
+    }
+
+    #[rstest]
+    fn test_databento_reader_ffi() {
+        let path = c_path("src/databento/test_data/test_data.trades.dbn.zst");
+        let publishers_path = c_path("src/databento/publishers.json");
+        let symbology = CString::new(r#"{"5482":"ES.XCME"}"#).unwrap();
+        let mut reader = unsafe {
+            databento_reader_new(
+                DatabentoDataType::TradeTick,
+                path.as_ptr(),
+                publishers_path.as_ptr(),
+                symbology.as_ptr(),
+                2,
+                1,
+            )
+        };
+        assert_eq!(
+            databento_reader_data_type(&reader),
+            DatabentoDataType::TradeTick
+        );
+
+        let mut trades = Vec::new();
+        loop {
+            let chunk = databento_reader_next_chunk(&mut reader);
+            if chunk.len == 0 {
+                break;
+            }
+            let items =
+                unsafe { std::slice::from_raw_parts(chunk.ptr.cast::<TradeTick>(), chunk.len) };
+            trades.extend_from_slice(items);
+            unsafe { databento_reader_drop_chunk(&reader, chunk) };
+        }
+
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].instrument_id, InstrumentId::from("ES.XCME"));
+        databento_reader_drop(reader);
+    }
+}
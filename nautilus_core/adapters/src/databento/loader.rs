@@ -144,6 +144,12 @@ impl DatabentoDataLoader {
         &self.publishers_map
     }
 
+    /// Return the venue of each Databento publisher currently held by the loader.
+    #[must_use]
+    pub const fn get_publisher_venue_map(&self) -> &IndexMap<PublisherId, Venue> {
+        &self.publisher_venue_map
+    }
+
     // Return the dataset which matches the given `venue` (if found).
     #[must_use]
     pub fn get_dataset_for_venue(&self, venue: &Venue) -> Option<&Dataset> {
@@ -20,8 +20,12 @@ pub mod decode;
 pub mod enums;
 pub mod live;
 pub mod loader;
+pub mod reader;
 pub mod symbology;
 pub mod types;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 #[cfg(feature = "python")]
 pub mod python;
@@ -0,0 +1,491 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Chunked decoding of DBN files into Nautilus data types.
+
+use std::{fs::File, io::BufReader, path::Path};
+
+use databento::dbn;
+use dbn::{
+    decode::{DbnMetadata, DecodeRecordRef, DynDecoder},
+    Record, Schema, VersionUpgradePolicy,
+};
+use indexmap::IndexMap;
+use nautilus_model::{
+    data::{bar::Bar, delta::OrderBookDelta, quote::QuoteTick, trade::TradeTick, Data},
+    identifiers::{InstrumentId, Venue},
+};
+
+use super::{
+    decode::decode_record, enums::DatabentoDataType,
+    symbology::get_nautilus_instrument_id_for_record, types::PublisherId,
+};
+
+/// A Nautilus data type which can be decoded from DBN records.
+pub trait DatabentoData: Sized {
+    /// The DBN schemas from which the data type can be decoded.
+    const SCHEMAS: &'static [Schema];
+
+    /// If trades should be decoded from records which also carry other data (e.g. MBP-1).
+    const INCLUDE_TRADES: bool = false;
+
+    /// Returns the data type if `data` is of this type.
+    fn from_data(data: Data) -> Option<Self>;
+}
+
+impl DatabentoData for OrderBookDelta {
+    const SCHEMAS: &'static [Schema] = &[Schema::Mbo];
+
+    fn from_data(data: Data) -> Option<Self> {
+        match data {
+            Data::Delta(delta) => Some(delta),
+            _ => None,
+        }
+    }
+}
+
+impl DatabentoData for QuoteTick {
+    const SCHEMAS: &'static [Schema] = &[Schema::Mbp1, Schema::Bbo1S, Schema::Bbo1M];
+
+    fn from_data(data: Data) -> Option<Self> {
+        match data {
+            Data::Quote(quote) => Some(quote),
+            _ => None,
+        }
+    }
+}
+
+impl DatabentoData for TradeTick {
+    const SCHEMAS: &'static [Schema] = &[Schema::Trades, Schema::Mbo, Schema::Mbp1];
+    const INCLUDE_TRADES: bool = true;
+
+    fn from_data(data: Data) -> Option<Self> {
+        match data {
+            Data::Trade(trade) => Some(trade),
+            _ => None,
+        }
+    }
+}
+
+impl DatabentoData for Bar {
+    const SCHEMAS: &'static [Schema] = &[
+        Schema::Ohlcv1S,
+        Schema::Ohlcv1M,
+        Schema::Ohlcv1H,
+        Schema::Ohlcv1D,
+    ];
+
+    fn from_data(data: Data) -> Option<Self> {
+        match data {
+            Data::Bar(bar) => Some(bar),
+            _ => None,
+        }
+    }
+}
+
+/// Reads Nautilus data from a DBN file (optionally zstd compressed) in fixed-size chunks.
+///
+/// The instrument ID of each record is taken from the `symbology` map of Databento instrument
+/// IDs when present, otherwise from the raw symbol in the file metadata with the venue of the
+/// record's publisher.
+pub struct DatabentoReader<T: DatabentoData> {
+    decoder: DynDecoder<'static, BufReader<File>>,
+    metadata: dbn::Metadata,
+    publisher_venue_map: IndexMap<PublisherId, Venue>,
+    symbology: IndexMap<u32, InstrumentId>,
+    price_precision: u8,
+    chunk_size: usize,
+    buffer: Vec<T>,
+}
+
+impl<T: DatabentoData> DatabentoReader<T> {
+    /// Creates a new [`DatabentoReader`] instance for the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `chunk_size` is zero.
+    /// - If the file cannot be opened or is not a valid DBN file.
+    /// - If the schema of the file cannot be decoded as the data type.
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        publisher_venue_map: IndexMap<PublisherId, Venue>,
+        symbology: IndexMap<u32, InstrumentId>,
+        price_precision: u8,
+        chunk_size: usize,
+    ) -> anyhow::Result<Self> {
+        if chunk_size == 0 {
+            anyhow::bail!("Invalid `chunk_size`, was zero");
+        }
+
+        let decoder = DynDecoder::from_file(path, VersionUpgradePolicy::Upgrade)?;
+        let metadata = decoder.metadata().clone();
+        match metadata.schema {
+            Some(schema) if T::SCHEMAS.contains(&schema) => {}
+            schema => anyhow::bail!(
+                "Invalid DBN schema for data type, expected one of {:?}, was {schema:?}",
+                T::SCHEMAS
+            ),
+        }
+
+        Ok(Self {
+            decoder,
+            metadata,
+            publisher_venue_map,
+            symbology,
+            price_precision,
+            chunk_size,
+            buffer: Vec::with_capacity(chunk_size),
+        })
+    }
+
+    /// Returns the metadata of the file.
+    #[must_use]
+    pub const fn metadata(&self) -> &dbn::Metadata {
+        &self.metadata
+    }
+
+    /// Returns the next chunk of up to `chunk_size` items, or `None` once the file is exhausted.
+    ///
+    /// Every chunk other than the last contains exactly `chunk_size` items.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If reading or decoding a record fails.
+    /// - If the instrument ID of a record cannot be determined.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<T>>> {
+        while self.buffer.len() < self.chunk_size {
+            let Some(record) = self.decoder.decode_record_ref()? else {
+                break;
+            };
+
+            let instrument_id = match self.symbology.get(&record.header().instrument_id) {
+                Some(instrument_id) => *instrument_id,
+                None => {
+                    let publisher_id = record.header().publisher_id;
+                    let venue = self.publisher_venue_map.get(&publisher_id).ok_or_else(|| {
+                        anyhow::anyhow!("`Venue` not found for `publisher_id` {publisher_id}")
+                    })?;
+                    get_nautilus_instrument_id_for_record(&record, &self.metadata, *venue)?
+                }
+            };
+
+            let (data1, data2) = decode_record(
+                &record,
+                instrument_id,
+                self.price_precision,
+                None,
+                T::INCLUDE_TRADES,
+            )?;
+            self.buffer.extend(
+                [data1, data2]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(T::from_data),
+            );
+        }
+
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let rest = self
+            .buffer
+            .split_off(self.chunk_size.min(self.buffer.len()));
+        Ok(Some(std::mem::replace(&mut self.buffer, rest)))
+    }
+}
+
+impl<T: DatabentoData> Iterator for DatabentoReader<T> {
+    type Item = anyhow::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+/// A [`DatabentoReader`] for any of the supported data types.
+pub enum DatabentoReaderAny {
+    OrderBookDelta(DatabentoReader<OrderBookDelta>),
+    QuoteTick(DatabentoReader<QuoteTick>),
+    TradeTick(DatabentoReader<TradeTick>),
+    Bar(DatabentoReader<Bar>),
+}
+
+impl DatabentoReaderAny {
+    /// Creates a new [`DatabentoReaderAny`] instance for the given `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the reader cannot be created (see [`DatabentoReader::new`]).
+    pub fn new<P: AsRef<Path>>(
+        data_type: DatabentoDataType,
+        path: P,
+        publisher_venue_map: IndexMap<PublisherId, Venue>,
+        symbology: IndexMap<u32, InstrumentId>,
+        price_precision: u8,
+        chunk_size: usize,
+    ) -> anyhow::Result<Self> {
+        Ok(match data_type {
+            DatabentoDataType::OrderBookDelta => Self::OrderBookDelta(DatabentoReader::new(
+                path,
+                publisher_venue_map,
+                symbology,
+                price_precision,
+                chunk_size,
+            )?),
+            DatabentoDataType::QuoteTick => Self::QuoteTick(DatabentoReader::new(
+                path,
+                publisher_venue_map,
+                symbology,
+                price_precision,
+                chunk_size,
+            )?),
+            DatabentoDataType::TradeTick => Self::TradeTick(DatabentoReader::new(
+                path,
+                publisher_venue_map,
+                symbology,
+                price_precision,
+                chunk_size,
+            )?),
+            DatabentoDataType::Bar => Self::Bar(DatabentoReader::new(
+                path,
+                publisher_venue_map,
+                symbology,
+                price_precision,
+                chunk_size,
+            )?),
+        })
+    }
+
+    /// Returns the data type being read.
+    #[must_use]
+    pub const fn data_type(&self) -> DatabentoDataType {
+        match self {
+            Self::OrderBookDelta(_) => DatabentoDataType::OrderBookDelta,
+            Self::QuoteTick(_) => DatabentoDataType::QuoteTick,
+            Self::TradeTick(_) => DatabentoDataType::TradeTick,
+            Self::Bar(_) => DatabentoDataType::Bar,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::{AggressorSide, BookAction, OrderSide},
+        identifiers::TradeId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::*;
+
+    use super::*;
+    use crate::databento::loader::DatabentoDataLoader;
+
+    fn test_data_path(file_name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("src/databento/test_data")
+            .join(file_name)
+    }
+
+    fn publisher_venue_map() -> IndexMap<PublisherId, Venue> {
+        let publishers_filepath =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("src/databento/publishers.json");
+        DatabentoDataLoader::new(Some(publishers_filepath))
+            .unwrap()
+            .get_publisher_venue_map()
+            .clone()
+    }
+
+    fn read_all<T: DatabentoData>(file_name: &str) -> Vec<T> {
+        DatabentoReader::<T>::new(
+            test_data_path(file_name),
+            publisher_venue_map(),
+            IndexMap::new(),
+            2,
+            1_000,
+        )
+        .unwrap()
+        .next_chunk()
+        .unwrap()
+        .unwrap_or_default()
+    }
+
+    #[rstest]
+    #[case("test_data.mbo.dbn.zst")]
+    #[case("test_data.mbo.dbn")]
+    fn test_read_order_book_deltas(#[case] file_name: &str) {
+        let deltas = read_all::<OrderBookDelta>(file_name);
+
+        assert_eq!(deltas.len(), 2);
+        let delta = deltas[0];
+        assert_eq!(delta.instrument_id, InstrumentId::from("ESH1.GLBX"));
+        assert_eq!(delta.action, BookAction::Delete);
+        assert_eq!(delta.order.side, OrderSide::Sell);
+        assert_eq!(delta.order.price, Price::from("3722.75"));
+        assert_eq!(delta.order.size, Quantity::from(1));
+        assert_eq!(delta.order.order_id, 647_784_973_705);
+        assert_eq!(delta.flags, 128);
+        assert_eq!(delta.sequence, 1_170_352);
+        assert_eq!(delta.ts_event, 1_609_160_400_000_704_060);
+        assert_eq!(delta.ts_init, 1_609_160_400_000_704_060);
+        assert_eq!(deltas[1].order.price, Price::from("3723.00"));
+    }
+
+    #[rstest]
+    fn test_read_quotes() {
+        let quotes = read_all::<QuoteTick>("test_data.mbp-1.dbn.zst");
+
+        assert_eq!(quotes.len(), 2);
+        let quote = quotes[0];
+        assert_eq!(quote.instrument_id, InstrumentId::from("ESH1.GLBX"));
+        assert_eq!(quote.bid_price, Price::from("3720.25"));
+        assert_eq!(quote.ask_price, Price::from("3720.50"));
+        assert_eq!(quote.bid_size, Quantity::from(24));
+        assert_eq!(quote.ask_size, Quantity::from(11));
+        assert_eq!(quote.ts_event, 1_609_160_400_006_136_329);
+        assert_eq!(quotes[1].ask_size, Quantity::from(12));
+    }
+
+    #[rstest]
+    fn test_read_trades() {
+        let trades = read_all::<TradeTick>("test_data.trades.dbn.zst");
+
+        assert_eq!(trades.len(), 2);
+        let trade = trades[0];
+        assert_eq!(trade.instrument_id, InstrumentId::from("ESH1.GLBX"));
+        assert_eq!(trade.price, Price::from("3720.25"));
+        assert_eq!(trade.size, Quantity::from(5));
+        assert_eq!(trade.aggressor_side, AggressorSide::Seller);
+        assert_eq!(trade.trade_id, TradeId::new("1170380"));
+        assert_eq!(trade.ts_event, 1_609_160_400_099_150_057);
+        assert_eq!(trades[1].size, Quantity::from(21));
+    }
+
+    #[rstest]
+    fn test_read_trades_from_mbp1_without_trades() {
+        let trades = read_all::<TradeTick>("test_data.mbp-1.dbn.zst");
+
+        assert!(trades.is_empty());
+    }
+
+    #[rstest]
+    fn test_read_bars() {
+        let bars = read_all::<Bar>("test_data.ohlcv-1s.dbn.zst");
+
+        assert_eq!(bars.len(), 2);
+        let bar = bars[0];
+        assert_eq!(
+            bar.bar_type,
+            BarType::from("ESH1.GLBX-1-SECOND-LAST-EXTERNAL")
+        );
+        assert_eq!(bar.open, Price::from("372025.00"));
+        assert_eq!(bar.high, Price::from("372050.00"));
+        assert_eq!(bar.low, Price::from("372025.00"));
+        assert_eq!(bar.close, Price::from("372050.00"));
+        assert_eq!(bar.volume, Quantity::from(57));
+        assert_eq!(bar.ts_event, 1_609_160_400_000_000_000);
+        assert_eq!(bar.ts_init, 1_609_160_401_000_000_000);
+    }
+
+    #[rstest]
+    fn test_symbology_map_takes_precedence() {
+        let symbology = IndexMap::from([(5482, InstrumentId::from("ES.XCME"))]);
+        let mut reader = DatabentoReader::<TradeTick>::new(
+            test_data_path("test_data.trades.dbn.zst"),
+            IndexMap::new(),
+            symbology,
+            2,
+            1_000,
+        )
+        .unwrap();
+
+        let trades = reader.next_chunk().unwrap().unwrap();
+
+        assert!(trades
+            .iter()
+            .all(|trade| trade.instrument_id == InstrumentId::from("ES.XCME")));
+    }
+
+    #[rstest]
+    fn test_unknown_publisher_errors() {
+        let mut reader = DatabentoReader::<TradeTick>::new(
+            test_data_path("test_data.trades.dbn.zst"),
+            IndexMap::new(),
+            IndexMap::new(),
+            2,
+            1_000,
+        )
+        .unwrap();
+
+        let result = reader.next_chunk();
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "`Venue` not found for `publisher_id` 1"
+        );
+    }
+
+    #[rstest]
+    fn test_read_in_chunks() {
+        let reader = DatabentoReader::<OrderBookDelta>::new(
+            test_data_path("test_data.mbo.dbn.zst"),
+            publisher_venue_map(),
+            IndexMap::new(),
+            2,
+            1,
+        )
+        .unwrap();
+
+        let lens: Vec<usize> = reader.map(|chunk| chunk.unwrap().len()).collect();
+
+        assert_eq!(lens, vec![1, 1]);
+    }
+
+    #[rstest]
+    fn test_invalid_schema_for_data_type() {
+        let result = DatabentoReaderAny::new(
+            DatabentoDataType::Bar,
+            test_data_path("test_data.mbo.dbn.zst"),
+            publisher_venue_map(),
+            IndexMap::new(),
+            2,
+            1_000,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_invalid_chunk_size() {
+        let result = DatabentoReader::<Bar>::new(
+            test_data_path("test_data.ohlcv-1s.dbn.zst"),
+            publisher_venue_map(),
+            IndexMap::new(),
+            2,
+            0,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,142 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_core::{ffi::cvec::CVec, nanos::UnixNanos};
+use nautilus_model::types::money::Money;
+
+use crate::performance::{drawdown_series, PerformanceSummary};
+
+/// The result of a performance analysis over an equity curve.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct PerformanceResult {
+    pub summary: PerformanceSummary,
+    /// The drawdown at each point of the equity curve, as a `CVec` of `double`, which must be
+    /// freed with `vec_drawdowns_drop`.
+    pub drawdowns: CVec,
+}
+
+unsafe fn slice_from_raw<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    if len == 0 {
+        &[]
+    } else {
+        assert!(!ptr.is_null(), "pointer was NULL");
+        std::slice::from_raw_parts(ptr, len)
+    }
+}
+
+/// Analyzes the performance of an equity curve of `len` account balances at the given UNIX
+/// timestamps (nanoseconds), with the win rate calculated from the realized PnL of each trade.
+///
+/// Statistics are annualized with `period` trading days per year.
+///
+/// # Safety
+///
+/// - Assumes `ts_ptr` and `balances_ptr` each point to at least `len` elements.
+/// - Assumes `realized_pnls_ptr` points to at least `realized_pnls_len` elements.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the equity curve is empty, not ordered by timestamp, or has balances which are not
+///   positive or not all in the same currency.
+#[no_mangle]
+pub unsafe extern "C" fn analysis_performance(
+    ts_ptr: *const UnixNanos,
+    balances_ptr: *const Money,
+    len: usize,
+    realized_pnls_ptr: *const f64,
+    realized_pnls_len: usize,
+    period: usize,
+) -> PerformanceResult {
+    let equity_curve: Vec<(UnixNanos, Money)> = slice_from_raw(ts_ptr, len)
+        .iter()
+        .copied()
+        .zip(slice_from_raw(balances_ptr, len).iter().copied())
+        .collect();
+    let realized_pnls = slice_from_raw(realized_pnls_ptr, realized_pnls_len);
+
+    let summary =
+        PerformanceSummary::from_equity_curve(&equity_curve, realized_pnls, Some(period)).unwrap();
+
+    PerformanceResult {
+        summary,
+        drawdowns: drawdown_series(&equity_curve).into(),
+    }
+}
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn vec_drawdowns_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let drawdowns: Vec<f64> = unsafe { Vec::from_raw_parts(ptr.cast::<f64>(), len, cap) };
+    drop(drawdowns); // Memory freed here
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_analysis_performance() {
+        let ts = [1, 2, 3, 4].map(UnixNanos::from);
+        let balances = ["100 USD", "120 USD", "90 USD", "108 USD"].map(Money::from);
+        let realized_pnls = [20.0, -30.0, 18.0];
+
+        let result = unsafe {
+            analysis_performance(
+                ts.as_ptr(),
+                balances.as_ptr(),
+                ts.len(),
+                realized_pnls.as_ptr(),
+                realized_pnls.len(),
+                252,
+            )
+        };
+
+        let drawdowns = unsafe {
+            std::slice::from_raw_parts(result.drawdowns.ptr.cast::<f64>(), result.drawdowns.len)
+        };
+        assert_eq!(drawdowns, [0.0, 0.0, -0.25, -0.09999999999999998]);
+        assert_eq!(result.summary.total_return, 0.08000000000000007);
+        assert_eq!(result.summary.max_drawdown, -0.25);
+        assert_eq!(result.summary.max_drawdown_start, UnixNanos::from(2));
+        assert_eq!(result.summary.max_drawdown_end, UnixNanos::from(3));
+        assert_eq!(result.summary.win_rate, 2.0 / 3.0);
+
+        vec_drawdowns_drop(result.drawdowns);
+    }
+
+    #[rstest]
+    fn test_analysis_performance_without_trades() {
+        let ts = [UnixNanos::from(1)];
+        let balances = [Money::from("100 USD")];
+
+        let result = unsafe {
+            analysis_performance(ts.as_ptr(), balances.as_ptr(), 1, std::ptr::null(), 0, 252)
+        };
+
+        assert_eq!(result.drawdowns.len, 1);
+        assert_eq!(result.summary.total_return, 0.0);
+        assert_eq!(result.summary.win_rate, 0.0);
+
+        vec_drawdowns_drop(result.drawdowns);
+    }
+}
@@ -36,6 +36,9 @@ use std::collections::BTreeMap;
 use nautilus_core::nanos::UnixNanos;
 
 mod analyzer;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod performance;
 #[cfg(feature = "python")]
 pub mod python;
 pub mod statistic;
@@ -0,0 +1,370 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Portfolio performance analytics calculated from an equity curve.
+//!
+//! # Day-count convention
+//!
+//! Returns are simple returns between consecutive points of the equity curve, which are then
+//! summed into UTC calendar day bins (as for the [`PortfolioStatistic`] implementations).
+//! Annualized figures assume `period` trading days per year (default 252), and measure the
+//! elapsed time as the number of daily bins, i.e. days without any equity change (weekends,
+//! holidays) do not count towards the elapsed time.
+
+use nautilus_core::{
+    correctness::{check_equal, check_predicate_true, check_slice_not_empty},
+    nanos::UnixNanos,
+};
+use nautilus_model::types::money::Money;
+
+use crate::{
+    statistic::PortfolioStatistic,
+    statistics::{
+        returns_volatility::ReturnsVolatility, sharpe_ratio::SharpeRatio,
+        sortino_ratio::SortinoRatio, win_rate::WinRate,
+    },
+    Returns,
+};
+
+/// The default number of trading days per year used to annualize statistics.
+pub const TRADING_DAYS_PER_YEAR: usize = 252;
+
+/// A summary of portfolio performance over an equity curve.
+///
+/// Ratios and returns are fractions (e.g. 0.1 is 10%). Statistics which cannot be calculated
+/// from the given equity curve (e.g. volatility of fewer than two daily returns) are NaN.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PerformanceSummary {
+    /// The return from the first to the last point of the equity curve.
+    pub total_return: f64,
+    /// The total return compounded to an annual rate.
+    pub annualized_return: f64,
+    /// The annualized standard deviation of daily returns.
+    pub volatility: f64,
+    /// The annualized Sharpe ratio of daily returns.
+    pub sharpe_ratio: f64,
+    /// The annualized Sortino ratio of daily returns.
+    pub sortino_ratio: f64,
+    /// The largest decline from a peak of the equity curve (zero or negative).
+    pub max_drawdown: f64,
+    /// UNIX timestamp (nanoseconds) of the peak the max drawdown is measured from (zero if none).
+    pub max_drawdown_start: UnixNanos,
+    /// UNIX timestamp (nanoseconds) of the trough of the max drawdown (zero if none).
+    pub max_drawdown_end: UnixNanos,
+    /// The fraction of trades with a positive realized PnL.
+    pub win_rate: f64,
+}
+
+impl PerformanceSummary {
+    /// Calculates the performance summary of the `equity_curve` of account balances (ordered by
+    /// timestamp), with the win rate calculated from the `realized_pnls` of each trade.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `equity_curve` is empty.
+    /// - If the balances of `equity_curve` are not all in the same currency.
+    /// - If `equity_curve` is not ordered by timestamp.
+    /// - If any balance of `equity_curve` is not positive.
+    pub fn from_equity_curve(
+        equity_curve: &[(UnixNanos, Money)],
+        realized_pnls: &[f64],
+        period: Option<usize>,
+    ) -> anyhow::Result<Self> {
+        let curve = equity_values(equity_curve)?;
+        let period = period.unwrap_or(TRADING_DAYS_PER_YEAR);
+
+        let first = curve[0].1;
+        let last = curve[curve.len() - 1].1;
+        let total_return = last / first - 1.0;
+
+        let returns = returns_from_equity_curve(&curve);
+        let sharpe = SharpeRatio::new(Some(period));
+        let days = sharpe.downsample_to_daily_bins(&returns).len();
+        let annualized_return = if days == 0 {
+            f64::NAN
+        } else {
+            (1.0 + total_return).powf(period as f64 / days as f64) - 1.0
+        };
+
+        let (max_drawdown, max_drawdown_start, max_drawdown_end) = max_drawdown(&curve);
+
+        Ok(Self {
+            total_return,
+            annualized_return,
+            volatility: calculate(&ReturnsVolatility::new(Some(period)), &returns),
+            sharpe_ratio: calculate(&sharpe, &returns),
+            sortino_ratio: calculate(&SortinoRatio::new(Some(period)), &returns),
+            max_drawdown,
+            max_drawdown_start,
+            max_drawdown_end,
+            win_rate: WinRate {}
+                .calculate_from_realized_pnls(realized_pnls)
+                .unwrap_or(f64::NAN),
+        })
+    }
+}
+
+/// Returns the equity curve starting at the `starting_balance` at `start`, followed by the
+/// balance after each of the `realized_pnls` (ordered by timestamp).
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If any realized PnL is not in the currency of `starting_balance`.
+pub fn equity_curve_from_realized_pnls(
+    starting_balance: Money,
+    start: UnixNanos,
+    realized_pnls: &[(UnixNanos, Money)],
+) -> anyhow::Result<Vec<(UnixNanos, Money)>> {
+    let mut balance = starting_balance;
+    let mut equity_curve = Vec::with_capacity(realized_pnls.len() + 1);
+    equity_curve.push((start, balance));
+
+    for (ts, pnl) in realized_pnls {
+        check_equal(
+            pnl.currency,
+            starting_balance.currency,
+            "realized PnL currency",
+            "starting balance currency",
+        )?;
+        balance += *pnl;
+        equity_curve.push((*ts, balance));
+    }
+
+    Ok(equity_curve)
+}
+
+/// Returns the drawdown at each point of the `equity_curve`, as the (zero or negative)
+/// fractional decline from the highest preceding balance.
+#[must_use]
+pub fn drawdown_series(equity_curve: &[(UnixNanos, Money)]) -> Vec<f64> {
+    let mut peak = f64::MIN;
+    equity_curve
+        .iter()
+        .map(|(_, balance)| {
+            let balance = balance.as_f64();
+            peak = peak.max(balance);
+            balance / peak - 1.0
+        })
+        .collect()
+}
+
+/// Returns the simple returns between consecutive points of the `equity_curve`, keyed by the
+/// timestamp of the later point.
+#[must_use]
+pub fn returns_from_equity_curve(equity_curve: &[(UnixNanos, f64)]) -> Returns {
+    equity_curve
+        .windows(2)
+        .map(|pair| (pair[1].0, pair[1].1 / pair[0].1 - 1.0))
+        .collect()
+}
+
+fn equity_values(equity_curve: &[(UnixNanos, Money)]) -> anyhow::Result<Vec<(UnixNanos, f64)>> {
+    check_slice_not_empty(equity_curve, "equity_curve")?;
+
+    let currency = equity_curve[0].1.currency;
+    let mut last_ts = equity_curve[0].0;
+    let mut curve = Vec::with_capacity(equity_curve.len());
+
+    for (ts, balance) in equity_curve {
+        check_equal(balance.currency, currency, "balance currency", "currency")?;
+        check_predicate_true(
+            *ts >= last_ts,
+            "`equity_curve` was not ordered by timestamp",
+        )?;
+        check_predicate_true(balance.raw > 0, "`equity_curve` balances must be positive")?;
+        last_ts = *ts;
+        curve.push((*ts, balance.as_f64()));
+    }
+
+    Ok(curve)
+}
+
+fn max_drawdown(curve: &[(UnixNanos, f64)]) -> (f64, UnixNanos, UnixNanos) {
+    let mut peak = curve[0];
+    let mut max = (0.0, UnixNanos::default(), UnixNanos::default());
+
+    for &(ts, balance) in curve {
+        if balance > peak.1 {
+            peak = (ts, balance);
+        }
+
+        let drawdown = balance / peak.1 - 1.0;
+        if drawdown < max.0 {
+            max = (drawdown, peak.0, ts);
+        }
+    }
+
+    max
+}
+
+fn calculate(statistic: &impl PortfolioStatistic<Item = f64>, returns: &Returns) -> f64 {
+    statistic
+        .calculate_from_returns(returns)
+        .unwrap_or(f64::NAN)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::types::currency::Currency;
+    use rstest::rstest;
+
+    use super::*;
+
+    const ONE_DAY_NANOS: u64 = 86_400_000_000_000;
+    const START_NANOS: u64 = 1_600_000_000_000_000_000;
+
+    fn create_equity_curve(balances: &[&str]) -> Vec<(UnixNanos, Money)> {
+        balances
+            .iter()
+            .enumerate()
+            .map(|(i, balance)| {
+                let ts = UnixNanos::from(START_NANOS + i as u64 * ONE_DAY_NANOS);
+                (ts, Money::from(format!("{balance} USD").as_str()))
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_returns_from_equity_curve() {
+        let curve = [
+            (UnixNanos::from(1), 100.0),
+            (UnixNanos::from(2), 110.0),
+            (UnixNanos::from(3), 99.0),
+        ];
+
+        let returns = returns_from_equity_curve(&curve);
+
+        assert_eq!(returns.len(), 2);
+        assert_eq!(returns[&UnixNanos::from(2)], 0.10000000000000009);
+        assert_eq!(returns[&UnixNanos::from(3)], -0.09999999999999998);
+    }
+
+    #[rstest]
+    fn test_drawdown_series() {
+        let equity_curve = create_equity_curve(&["100", "120", "90", "108", "132", "99"]);
+
+        let drawdowns = drawdown_series(&equity_curve);
+
+        assert_eq!(
+            drawdowns,
+            vec![0.0, 0.0, -0.25, -0.09999999999999998, 0.0, -0.25]
+        );
+    }
+
+    #[rstest]
+    fn test_summary_with_hand_computed_series() {
+        // Daily returns of +20%, -25%, +20%, +22.2%, -25%
+        let equity_curve = create_equity_curve(&["100", "120", "90", "108", "132", "99"]);
+        let realized_pnls = [20.0, -30.0, 18.0, 24.0, -33.0];
+
+        let summary =
+            PerformanceSummary::from_equity_curve(&equity_curve, &realized_pnls, None).unwrap();
+
+        assert_eq!(summary.total_return, -0.010000000000000009);
+        assert_eq!(summary.annualized_return, -0.3974212561777164);
+        assert_eq!(summary.volatility, 3.9796845212766416);
+        assert_eq!(summary.sharpe_ratio, 1.5478613862648432);
+        assert_eq!(summary.sortino_ratio, 2.454202744499955);
+        assert_eq!(summary.max_drawdown, -0.25);
+        // First drawdown of -25% (peak 120 to trough 90) is not exceeded by the second
+        assert_eq!(summary.max_drawdown_start, equity_curve[1].0);
+        assert_eq!(summary.max_drawdown_end, equity_curve[2].0);
+        assert_eq!(summary.win_rate, 0.6);
+    }
+
+    #[rstest]
+    fn test_summary_with_single_point() {
+        let equity_curve = create_equity_curve(&["100"]);
+
+        let summary = PerformanceSummary::from_equity_curve(&equity_curve, &[], None).unwrap();
+
+        assert_eq!(summary.total_return, 0.0);
+        assert!(summary.annualized_return.is_nan());
+        assert!(summary.volatility.is_nan());
+        assert!(summary.sharpe_ratio.is_nan());
+        assert!(summary.sortino_ratio.is_nan());
+        assert_eq!(summary.max_drawdown, 0.0);
+        assert_eq!(summary.max_drawdown_start, UnixNanos::default());
+        assert_eq!(summary.max_drawdown_end, UnixNanos::default());
+        assert_eq!(summary.win_rate, 0.0);
+    }
+
+    #[rstest]
+    fn test_summary_with_empty_equity_curve_errors() {
+        assert!(PerformanceSummary::from_equity_curve(&[], &[], None).is_err());
+    }
+
+    #[rstest]
+    fn test_summary_with_mixed_currencies_errors() {
+        let mut equity_curve = create_equity_curve(&["100", "110"]);
+        equity_curve[1].1 = Money::new(110.0, Currency::EUR());
+
+        assert!(PerformanceSummary::from_equity_curve(&equity_curve, &[], None).is_err());
+    }
+
+    #[rstest]
+    fn test_summary_with_non_positive_balance_errors() {
+        let equity_curve = create_equity_curve(&["100", "0"]);
+
+        let err = PerformanceSummary::from_equity_curve(&equity_curve, &[], None).unwrap_err();
+
+        assert!(err
+            .to_string()
+            .contains("`equity_curve` balances must be positive"));
+    }
+
+    #[rstest]
+    fn test_summary_with_unordered_equity_curve_errors() {
+        let mut equity_curve = create_equity_curve(&["100", "110"]);
+        equity_curve.reverse();
+
+        assert!(PerformanceSummary::from_equity_curve(&equity_curve, &[], None).is_err());
+    }
+
+    #[rstest]
+    fn test_equity_curve_from_realized_pnls() {
+        let start = UnixNanos::from(START_NANOS);
+        let pnls = [
+            (
+                UnixNanos::from(START_NANOS + ONE_DAY_NANOS),
+                Money::from("20 USD"),
+            ),
+            (
+                UnixNanos::from(START_NANOS + 2 * ONE_DAY_NANOS),
+                Money::from("-30 USD"),
+            ),
+        ];
+
+        let equity_curve =
+            equity_curve_from_realized_pnls(Money::from("100 USD"), start, &pnls).unwrap();
+
+        assert_eq!(equity_curve, create_equity_curve(&["100", "120", "90"]));
+    }
+
+    #[rstest]
+    fn test_equity_curve_from_realized_pnls_with_mixed_currencies_errors() {
+        let pnls = [(UnixNanos::from(1), Money::from("20 EUR"))];
+
+        let result = equity_curve_from_realized_pnls(Money::from("100 USD"), 0.into(), &pnls);
+
+        assert!(result.is_err());
+    }
+}
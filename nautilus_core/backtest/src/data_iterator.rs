@@ -0,0 +1,348 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A data iterator for backtesting, which replays streams of data in strict `ts_init` order.
+//!
+//! Data is only ever ordered by `ts_init` (the time the data was received by the system), never by
+//! `ts_event` (the time the event occurred at the venue), as replaying by `ts_event` would make
+//! data available to strategies before it could have been received, introducing lookahead bias.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{Data, GetTsInit},
+    identifiers::InstrumentId,
+};
+
+/// The counts of records with invalid timestamps for a single instrument.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimestampViolations {
+    /// The count of records where `ts_init` is before `ts_event`.
+    pub ts_init_before_ts_event: usize,
+    /// The count of records where `ts_event` is zero.
+    pub zero_ts_event: usize,
+    /// The count of records where `ts_init` is zero.
+    pub zero_ts_init: usize,
+}
+
+impl TimestampViolations {
+    /// Returns the total count of violations (a record may be counted more than once).
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.ts_init_before_ts_event + self.zero_ts_event + self.zero_ts_init
+    }
+}
+
+/// Validates the timestamps of the given `data`, returning the violations for each instrument
+/// which has at least one record where `ts_init` is before `ts_event`, or either timestamp is zero.
+#[must_use]
+pub fn validate_timestamps(data: &[Data]) -> BTreeMap<InstrumentId, TimestampViolations> {
+    let mut violations: BTreeMap<InstrumentId, TimestampViolations> = BTreeMap::new();
+
+    for item in data {
+        let ts_event = item.ts_event();
+        let ts_init = item.ts_init();
+        let ts_init_before_ts_event = ts_init < ts_event;
+        let zero_ts_event = ts_event == 0;
+        let zero_ts_init = ts_init == 0;
+
+        if ts_init_before_ts_event || zero_ts_event || zero_ts_init {
+            let entry = violations.entry(item.instrument_id()).or_default();
+            entry.ts_init_before_ts_event += usize::from(ts_init_before_ts_event);
+            entry.zero_ts_event += usize::from(zero_ts_event);
+            entry.zero_ts_init += usize::from(zero_ts_init);
+        }
+    }
+
+    violations
+}
+
+/// Provides a means of replaying multiple streams of backtest data as a single stream in
+/// ascending `ts_init` order.
+///
+/// Each stream is validated with [`validate_timestamps`] when added, with any violations logged
+/// as warnings with their counts per instrument. The `ts_init` of each record is then delayed to
+/// at least `ts_event` plus the `latency_floor_ns`, to model the latency of the data feed.
+///
+/// Records with equal `ts_init` are yielded in the order their streams were added, and then in
+/// the order they appear within their stream.
+#[derive(Debug, Default)]
+pub struct BacktestDataIterator {
+    latency_floor_ns: u64,
+    streams: Vec<Vec<Data>>,
+    positions: Vec<usize>,
+    heap: BinaryHeap<Reverse<(UnixNanos, usize)>>,
+}
+
+impl BacktestDataIterator {
+    /// Creates a new [`BacktestDataIterator`] instance.
+    ///
+    /// The `latency_floor_ns` is the minimum latency (nanoseconds) between the `ts_event` and
+    /// `ts_init` of each record, with zero leaving the `ts_init` of valid records unchanged.
+    #[must_use]
+    pub fn new(latency_floor_ns: u64) -> Self {
+        Self {
+            latency_floor_ns,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the minimum latency (nanoseconds) between the `ts_event` and `ts_init` of each record.
+    #[must_use]
+    pub const fn latency_floor_ns(&self) -> u64 {
+        self.latency_floor_ns
+    }
+
+    /// Adds the `data` stream to the iterator, returning the timestamp violations found for
+    /// each instrument.
+    ///
+    /// The stream need not be ordered, as it is stably sorted by `ts_init` once any latency
+    /// floor has been applied.
+    pub fn add_data(&mut self, mut data: Vec<Data>) -> BTreeMap<InstrumentId, TimestampViolations> {
+        let violations = validate_timestamps(&data);
+        for (instrument_id, v) in &violations {
+            log::warn!(
+                "Invalid timestamps for {instrument_id}: {} with `ts_init` before `ts_event`, {} with zero `ts_event`, {} with zero `ts_init`",
+                v.ts_init_before_ts_event,
+                v.zero_ts_event,
+                v.zero_ts_init,
+            );
+        }
+
+        for item in &mut data {
+            let ts_init = item.ts_event() + self.latency_floor_ns;
+            if item.ts_init() < ts_init {
+                set_ts_init(item, ts_init);
+            }
+        }
+
+        data.sort_by_key(GetTsInit::ts_init);
+
+        let index = self.streams.len();
+        if let Some(first) = data.first() {
+            self.heap.push(Reverse((first.ts_init(), index)));
+        }
+        self.streams.push(data);
+        self.positions.push(0);
+
+        violations
+    }
+
+    /// Returns whether all data has been yielded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+}
+
+impl Iterator for BacktestDataIterator {
+    type Item = Data;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((_, index)) = self.heap.pop()?;
+        let stream = &self.streams[index];
+        let position = self.positions[index];
+        let item = stream[position].clone();
+
+        self.positions[index] += 1;
+        if let Some(next) = stream.get(position + 1) {
+            self.heap.push(Reverse((next.ts_init(), index)));
+        }
+
+        Some(item)
+    }
+}
+
+fn set_ts_init(data: &mut Data, ts_init: UnixNanos) {
+    match data {
+        Data::Delta(delta) => delta.ts_init = ts_init,
+        Data::Deltas(deltas) => {
+            deltas.ts_init = ts_init;
+            for delta in &mut deltas.deltas {
+                delta.ts_init = ts_init;
+            }
+        }
+        Data::Depth10(depth) => depth.ts_init = ts_init,
+        Data::Quote(quote) => quote.ts_init = ts_init,
+        Data::Trade(trade) => trade.ts_init = ts_init,
+        Data::Bar(bar) => bar.ts_init = ts_init,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{quote::QuoteTick, trade::TradeTick},
+        enums::AggressorSide,
+        identifiers::TradeId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument_id: &str, ts_event: u64, ts_init: u64) -> Data {
+        Data::Quote(QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            bid_price: Price::from("1.00000"),
+            ask_price: Price::from("1.00010"),
+            bid_size: Quantity::from(100_000),
+            ask_size: Quantity::from(100_000),
+            ts_event: ts_event.into(),
+            ts_init: ts_init.into(),
+        })
+    }
+
+    fn trade(instrument_id: &str, ts_event: u64, ts_init: u64) -> Data {
+        Data::Trade(TradeTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            price: Price::from("1.00005"),
+            size: Quantity::from(10_000),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::from("1"),
+            ts_event: ts_event.into(),
+            ts_init: ts_init.into(),
+        })
+    }
+
+    fn ts_inits(iter: BacktestDataIterator) -> Vec<u64> {
+        iter.map(|d| d.ts_init().as_u64()).collect()
+    }
+
+    #[rstest]
+    fn test_validate_timestamps_counts_per_instrument() {
+        let data = vec![
+            quote("AUDUSD.SIM", 10, 20),
+            quote("AUDUSD.SIM", 30, 20),
+            quote("AUDUSD.SIM", 0, 40),
+            trade("GBPUSD.SIM", 50, 0),
+            trade("EURUSD.SIM", 50, 60),
+        ];
+
+        let violations = validate_timestamps(&data);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[&InstrumentId::from("AUDUSD.SIM")],
+            TimestampViolations {
+                ts_init_before_ts_event: 1,
+                zero_ts_event: 1,
+                zero_ts_init: 0,
+            }
+        );
+        // A zero `ts_init` is also before a non-zero `ts_event`
+        assert_eq!(
+            violations[&InstrumentId::from("GBPUSD.SIM")],
+            TimestampViolations {
+                ts_init_before_ts_event: 1,
+                zero_ts_event: 0,
+                zero_ts_init: 1,
+            }
+        );
+        assert_eq!(violations[&InstrumentId::from("GBPUSD.SIM")].total(), 2);
+    }
+
+    #[rstest]
+    fn test_orders_by_ts_init_not_ts_event() {
+        // Vendor data where the `ts_event` order differs from the `ts_init` order: the trade
+        // occurred first but was received last, so replaying by `ts_event` would yield it
+        // before the quotes and leak it into the past.
+        let mut iter = BacktestDataIterator::new(0);
+        iter.add_data(vec![
+            quote("AUDUSD.SIM", 20, 30),
+            quote("AUDUSD.SIM", 25, 40),
+        ]);
+        iter.add_data(vec![
+            trade("AUDUSD.SIM", 5, 50),
+            trade("AUDUSD.SIM", 35, 35),
+        ]);
+
+        let data: Vec<Data> = iter.collect();
+
+        let ts_inits: Vec<u64> = data.iter().map(|d| d.ts_init().as_u64()).collect();
+        let ts_events: Vec<u64> = data.iter().map(|d| d.ts_event().as_u64()).collect();
+        assert_eq!(ts_inits, vec![30, 35, 40, 50]);
+        assert_eq!(ts_events, vec![20, 35, 25, 5]);
+    }
+
+    #[rstest]
+    fn test_sorts_unordered_stream_stably() {
+        let mut iter = BacktestDataIterator::new(0);
+        iter.add_data(vec![
+            quote("AUDUSD.SIM", 3, 30),
+            quote("AUDUSD.SIM", 1, 10),
+            quote("AUDUSD.SIM", 2, 10),
+        ]);
+
+        let ts_events: Vec<u64> = iter.map(|d| d.ts_event().as_u64()).collect();
+
+        assert_eq!(ts_events, vec![1, 2, 3]);
+    }
+
+    #[rstest]
+    fn test_equal_ts_init_yielded_in_stream_order() {
+        let mut iter = BacktestDataIterator::new(0);
+        iter.add_data(vec![trade("AUDUSD.SIM", 10, 10)]);
+        iter.add_data(vec![quote("AUDUSD.SIM", 10, 10)]);
+
+        let data: Vec<Data> = iter.collect();
+
+        assert!(matches!(data[0], Data::Trade(_)));
+        assert!(matches!(data[1], Data::Quote(_)));
+    }
+
+    #[rstest]
+    fn test_latency_floor_delays_ts_init() {
+        let mut iter = BacktestDataIterator::new(100);
+        iter.add_data(vec![
+            quote("AUDUSD.SIM", 10, 20),   // Delayed to 110
+            quote("AUDUSD.SIM", 50, 500),  // Already beyond the floor
+            quote("AUDUSD.SIM", 200, 100), // Invalid, delayed to 300
+        ]);
+
+        assert_eq!(iter.latency_floor_ns(), 100);
+        assert_eq!(ts_inits(iter), vec![110, 300, 500]);
+    }
+
+    #[rstest]
+    fn test_add_data_returns_violations() {
+        let mut iter = BacktestDataIterator::new(0);
+
+        let violations = iter.add_data(vec![quote("AUDUSD.SIM", 200, 100)]);
+
+        assert_eq!(
+            violations[&InstrumentId::from("AUDUSD.SIM")].ts_init_before_ts_event,
+            1
+        );
+        // Invalid records are still replayed, at their `ts_event`
+        assert_eq!(ts_inits(iter), vec![200]);
+    }
+
+    #[rstest]
+    fn test_empty() {
+        let mut iter = BacktestDataIterator::new(0);
+        iter.add_data(Vec::new());
+
+        assert!(iter.is_empty());
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,253 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi::{cvec::CVec, parsing::u8_as_bool};
+use nautilus_execution::matching_book::{MatchingOrder, OrderMatch, OrderMatchingBook};
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    ffi::orderbook::book::OrderBook_API,
+    identifiers::{ClientOrderId, InstrumentId},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying `OrderMatchingBook`.
+///
+/// This struct wraps `OrderMatchingBook` in a way that makes it compatible with C function
+/// calls, enabling interaction with `OrderMatchingBook` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `OrderMatchingBook_API` to be
+/// dereferenced to `OrderMatchingBook`, providing access to `OrderMatchingBook`'s methods without
+/// having to manually access the underlying `OrderMatchingBook` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderMatchingBook_API(Box<OrderMatchingBook>);
+
+impl Deref for OrderMatchingBook_API {
+    type Target = OrderMatchingBook;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderMatchingBook_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_new(
+    instrument_id: InstrumentId,
+    fill_on_touch: u8,
+) -> OrderMatchingBook_API {
+    OrderMatchingBook_API(Box::new(OrderMatchingBook::new(
+        instrument_id,
+        u8_as_bool(fill_on_touch),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_drop(book: OrderMatchingBook_API) {
+    drop(book); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_reset(book: &mut OrderMatchingBook_API) {
+    book.reset();
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_order_exists(
+    book: &OrderMatchingBook_API,
+    client_order_id: ClientOrderId,
+) -> u8 {
+    u8::from(book.order_exists(client_order_id))
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_is_triggered(
+    book: &OrderMatchingBook_API,
+    client_order_id: ClientOrderId,
+) -> u8 {
+    u8::from(book.is_triggered(client_order_id))
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_orders_bid(book: &OrderMatchingBook_API) -> CVec {
+    book.orders_bid().into()
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_orders_ask(book: &OrderMatchingBook_API) -> CVec {
+    book.orders_ask().into()
+}
+
+/// Adds the given `order` to the book, writing the resulting `CVec` of `OrderMatch` to `matches`.
+///
+/// Returns 1 if the order was added, or 0 if it is invalid or already exists in the book
+/// (in which case `matches` is written as an empty `CVec`).
+///
+/// # Safety
+///
+/// - Assumes `matches` is a valid pointer to write the matches to.
+#[no_mangle]
+pub unsafe extern "C" fn order_matching_book_add_order(
+    book: &mut OrderMatchingBook_API,
+    order: MatchingOrder,
+    matches: *mut CVec,
+) -> u8 {
+    write_matches(book.add_order(order), matches)
+}
+
+/// Updates the resting order for the given `order`, writing the resulting `CVec` of
+/// `OrderMatch` to `matches`.
+///
+/// Returns 1 if the order was updated, or 0 if it is invalid or no longer in the book (for
+/// instance because it was filled before the update was processed), in which case `matches`
+/// is written as an empty `CVec`.
+///
+/// # Safety
+///
+/// - Assumes `matches` is a valid pointer to write the matches to.
+#[no_mangle]
+pub unsafe extern "C" fn order_matching_book_update_order(
+    book: &mut OrderMatchingBook_API,
+    order: MatchingOrder,
+    matches: *mut CVec,
+) -> u8 {
+    write_matches(book.update_order(order), matches)
+}
+
+unsafe fn write_matches(result: anyhow::Result<Vec<OrderMatch>>, matches: *mut CVec) -> u8 {
+    let (vec, status) = match result {
+        Ok(vec) => (vec, 1),
+        Err(_) => (Vec::new(), 0),
+    };
+    *matches = vec.into();
+    status
+}
+
+/// Cancels the resting order with the given `client_order_id`.
+///
+/// Returns 1 if the order was canceled, or 0 if it was not in the book (for instance
+/// because it was filled before the cancel was processed).
+#[no_mangle]
+pub extern "C" fn order_matching_book_cancel_order(
+    book: &mut OrderMatchingBook_API,
+    client_order_id: ClientOrderId,
+) -> u8 {
+    u8::from(book.cancel_order(client_order_id).is_ok())
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_process_quote(
+    book: &mut OrderMatchingBook_API,
+    quote: &QuoteTick,
+) -> CVec {
+    book.process_quote(quote).into()
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_process_trade(
+    book: &mut OrderMatchingBook_API,
+    trade: &TradeTick,
+) -> CVec {
+    book.process_trade(trade).into()
+}
+
+#[no_mangle]
+pub extern "C" fn order_matching_book_process_book(
+    book: &mut OrderMatchingBook_API,
+    order_book: &OrderBook_API,
+) -> CVec {
+    book.process_book(order_book).into()
+}
+
+#[no_mangle]
+pub extern "C" fn vec_matching_orders_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    if ptr.is_null() {
+        return; // Empty vec, nothing allocated
+    }
+    let data: Vec<MatchingOrder> =
+        unsafe { Vec::from_raw_parts(ptr.cast::<MatchingOrder>(), len, cap) };
+    drop(data); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn vec_order_matches_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    if ptr.is_null() {
+        return; // Empty vec, nothing allocated
+    }
+    let data: Vec<OrderMatch> = unsafe { Vec::from_raw_parts(ptr.cast::<OrderMatch>(), len, cap) };
+    drop(data); // Memory freed here
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::stubs::stub_trade_ethusdt_buyer,
+        enums::OrderSide,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_matching_book_cancel_after_fill(stub_trade_ethusdt_buyer: TradeTick) {
+        let trade = stub_trade_ethusdt_buyer;
+        let mut book = order_matching_book_new(trade.instrument_id, 0);
+        let order = MatchingOrder::limit(
+            ClientOrderId::from("O-1"),
+            OrderSide::Buy,
+            Price::from("20000.00"),
+            Quantity::from("1.00000000"),
+        );
+
+        let mut matches = CVec::empty();
+        let status = unsafe { order_matching_book_add_order(&mut book, order, &mut matches) };
+        assert_eq!(status, 1);
+        assert_eq!(matches.len, 0);
+        vec_order_matches_drop(matches);
+
+        let matches = order_matching_book_process_trade(&mut book, &trade);
+        assert_eq!(matches.len, 1);
+        vec_order_matches_drop(matches);
+
+        assert_eq!(
+            order_matching_book_order_exists(&book, order.client_order_id),
+            0
+        );
+        assert_eq!(
+            order_matching_book_cancel_order(&mut book, order.client_order_id),
+            0
+        );
+
+        // An update racing the fill reports the order is no longer in the book
+        let mut matches = CVec::empty();
+        let status = unsafe { order_matching_book_update_order(&mut book, order, &mut matches) };
+        assert_eq!(status, 0);
+        assert_eq!(matches.len, 0);
+        vec_order_matches_drop(matches);
+        order_matching_book_drop(book);
+    }
+}
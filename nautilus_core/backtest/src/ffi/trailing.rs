@@ -0,0 +1,151 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use nautilus_execution::trailing;
+use nautilus_model::{
+    enums::{OrderSide, TrailingOffsetType, TriggerType},
+    types::price::Price,
+};
+
+/// The output of a trailing stop calculation, where a price is only valid if its flag is set.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct TrailingStopOutput {
+    /// If the trigger price should be updated (1) or not (0).
+    pub has_trigger_price: u8,
+    /// The new trigger price.
+    pub trigger_price: Price,
+    /// If the limit price should be updated (1) or not (0).
+    pub has_price: u8,
+    /// The new limit price.
+    pub price: Price,
+}
+
+/// Calculates the new trigger price (and limit price) for a trailing stop order.
+///
+/// Pass a `limit_offset` of NaN for trailing stop market orders.
+///
+/// # Safety
+///
+/// - Assumes `trigger_price`, `price`, `bid`, `ask` and `last` are each either null (no price)
+///   or a valid `Price` pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If a market price required by the `trigger_type` is null.
+/// - If the `trigger_type`, `trailing_offset_type` or `order_side` is not supported.
+#[no_mangle]
+pub unsafe extern "C" fn trailing_stop_calculate(
+    price_increment: Price,
+    order_side: OrderSide,
+    trigger_type: TriggerType,
+    trailing_offset_type: TrailingOffsetType,
+    trailing_offset: f64,
+    limit_offset: f64,
+    trigger_price: *const Price,
+    price: *const Price,
+    bid: *const Price,
+    ask: *const Price,
+    last: *const Price,
+) -> TrailingStopOutput {
+    let (new_trigger_price, new_price) = trailing::trailing_stop_calculate(
+        price_increment,
+        order_side,
+        trigger_type,
+        trailing_offset_type,
+        trailing_offset,
+        (!limit_offset.is_nan()).then_some(limit_offset),
+        trigger_price.as_ref().copied(),
+        price.as_ref().copied(),
+        bid.as_ref().copied(),
+        ask.as_ref().copied(),
+        last.as_ref().copied(),
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+
+    let zero = Price::zero(price_increment.precision);
+    TrailingStopOutput {
+        has_trigger_price: u8::from(new_trigger_price.is_some()),
+        trigger_price: new_trigger_price.unwrap_or(zero),
+        has_price: u8::from(new_price.is_some()),
+        price: new_price.unwrap_or(zero),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_trailing_stop_calculate_limit_order() {
+        let trigger_price = Price::from("98.00");
+        let price = Price::from("97.50");
+        let last = Price::from("100.00");
+
+        let output = unsafe {
+            trailing_stop_calculate(
+                Price::from("0.01"),
+                OrderSide::Sell,
+                TriggerType::LastTrade,
+                TrailingOffsetType::Ticks,
+                100.0,
+                150.0,
+                &trigger_price,
+                &price,
+                ptr::null(),
+                ptr::null(),
+                &last,
+            )
+        };
+
+        assert_eq!(output.has_trigger_price, 1);
+        assert_eq!(output.trigger_price, Price::from("99.00"));
+        assert_eq!(output.has_price, 1);
+        assert_eq!(output.price, Price::from("98.50"));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_calculate_no_update() {
+        let trigger_price = Price::from("99.00");
+        let last = Price::from("99.50");
+
+        let output = unsafe {
+            trailing_stop_calculate(
+                Price::from("0.01"),
+                OrderSide::Sell,
+                TriggerType::LastTrade,
+                TrailingOffsetType::BasisPoints,
+                100.0,
+                f64::NAN,
+                &trigger_price,
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                &last,
+            )
+        };
+
+        assert_eq!(output.has_trigger_price, 0);
+        assert_eq!(output.has_price, 0);
+    }
+}
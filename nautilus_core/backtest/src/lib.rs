@@ -28,8 +28,11 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod data_client;
+pub mod data_iterator;
 pub mod engine;
 pub mod exchange;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod matching_engine;
 pub mod models;
 pub mod modules;
@@ -218,7 +218,9 @@ impl OrderMatchingEngine {
         log::debug!("Processing {delta}");
 
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_delta(delta);
+            if let Err(e) = self.book.apply_delta(delta) {
+                log::error!("Error applying delta: {e}");
+            }
         }
 
         self.iterate(delta.ts_event);
@@ -228,7 +230,9 @@ impl OrderMatchingEngine {
         log::debug!("Processing {deltas}");
 
         if self.book_type == BookType::L2_MBP || self.book_type == BookType::L3_MBO {
-            self.book.apply_deltas(deltas);
+            if let Err(e) = self.book.apply_deltas(deltas) {
+                log::error!("Error applying deltas: {e}");
+            }
         }
 
         self.iterate(deltas.ts_event);
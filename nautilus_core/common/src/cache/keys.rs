@@ -0,0 +1,198 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Keys for cache objects persisted in a key-value database.
+
+use nautilus_core::uuid::UUID4;
+use nautilus_model::identifiers::{AccountId, ClientOrderId, PositionId, TraderId};
+
+use super::CacheConfig;
+
+/// The delimiter between the parts of a cache key.
+pub const KEY_DELIMITER: char = ':';
+
+// Collection keys
+pub const ACCOUNTS: &str = "accounts";
+pub const ORDERS: &str = "orders";
+pub const POSITIONS: &str = "positions";
+
+/// Maps cache objects to the keys they are persisted under, so any key-value backend can be
+/// used for a cache database.
+///
+/// Keys are of the form `{trader_key}:{collection}:{id}` (e.g. `trader-TRADER-001:orders:O-1`),
+/// matching the keys written by the Python cache database.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheMap {
+    trader_key: String,
+}
+
+impl CacheMap {
+    /// Creates a new [`CacheMap`] instance.
+    ///
+    /// The trader key is prefixed with `trader-` if `use_trader_prefix`, and suffixed with the
+    /// `instance_id` if `use_instance_id`.
+    #[must_use]
+    pub fn new(
+        trader_id: TraderId,
+        instance_id: UUID4,
+        use_trader_prefix: bool,
+        use_instance_id: bool,
+    ) -> Self {
+        let mut trader_key = String::new();
+
+        if use_trader_prefix {
+            trader_key.push_str("trader-");
+        }
+
+        trader_key.push_str(trader_id.as_str());
+
+        if use_instance_id {
+            trader_key.push(KEY_DELIMITER);
+            trader_key.push_str(&instance_id.to_string());
+        }
+
+        Self { trader_key }
+    }
+
+    /// Creates a new [`CacheMap`] instance with the key options from the given `config`.
+    #[must_use]
+    pub fn from_config(trader_id: TraderId, instance_id: UUID4, config: &CacheConfig) -> Self {
+        Self::new(
+            trader_id,
+            instance_id,
+            config.use_trader_prefix,
+            config.use_instance_id,
+        )
+    }
+
+    /// Returns the key prefixing all keys for the trader.
+    #[must_use]
+    pub fn trader_key(&self) -> &str {
+        &self.trader_key
+    }
+
+    /// Returns the key prefixing all keys in the `collection` (e.g. for scanning).
+    #[must_use]
+    pub fn collection_key(&self, collection: &str) -> String {
+        format!("{}{KEY_DELIMITER}{collection}", self.trader_key)
+    }
+
+    /// Returns the key for the object with the given `id` in the `collection`.
+    #[must_use]
+    pub fn key(&self, collection: &str, id: &str) -> String {
+        format!(
+            "{}{KEY_DELIMITER}{collection}{KEY_DELIMITER}{id}",
+            self.trader_key
+        )
+    }
+
+    /// Returns the key for the order with the given `client_order_id`.
+    #[must_use]
+    pub fn order_key(&self, client_order_id: &ClientOrderId) -> String {
+        self.key(ORDERS, client_order_id.as_str())
+    }
+
+    /// Returns the key for the position with the given `position_id`.
+    #[must_use]
+    pub fn position_key(&self, position_id: &PositionId) -> String {
+        self.key(POSITIONS, position_id.as_str())
+    }
+
+    /// Returns the key for the account with the given `account_id`.
+    #[must_use]
+    pub fn account_key(&self, account_id: &AccountId) -> String {
+        self.key(ACCOUNTS, account_id.as_str())
+    }
+
+    /// Splits a `key` of this trader into its collection and ID.
+    ///
+    /// Returns `None` if the key is not for this trader or has no ID.
+    #[must_use]
+    pub fn parse_key<'a>(&self, key: &'a str) -> Option<(&'a str, &'a str)> {
+        key.strip_prefix(self.trader_key.as_str())?
+            .strip_prefix(KEY_DELIMITER)?
+            .split_once(KEY_DELIMITER)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_cache_map_keys() {
+        let cache_map = CacheMap::new(TraderId::from("TRADER-001"), UUID4::new(), true, false);
+
+        assert_eq!(cache_map.trader_key(), "trader-TRADER-001");
+        assert_eq!(
+            cache_map.order_key(&ClientOrderId::from("O-123456789")),
+            "trader-TRADER-001:orders:O-123456789"
+        );
+        assert_eq!(
+            cache_map.position_key(&PositionId::from("P-123456789")),
+            "trader-TRADER-001:positions:P-123456789"
+        );
+        assert_eq!(
+            cache_map.account_key(&AccountId::from("SIM-001")),
+            "trader-TRADER-001:accounts:SIM-001"
+        );
+        assert_eq!(cache_map.collection_key(ORDERS), "trader-TRADER-001:orders");
+    }
+
+    #[rstest]
+    fn test_cache_map_with_instance_id_and_no_prefix() {
+        let instance_id = UUID4::new();
+        let cache_map = CacheMap::new(TraderId::from("TRADER-001"), instance_id, false, true);
+
+        assert_eq!(
+            cache_map.order_key(&ClientOrderId::from("O-1")),
+            format!("TRADER-001:{instance_id}:orders:O-1")
+        );
+    }
+
+    #[rstest]
+    fn test_cache_map_from_config() {
+        let config = CacheConfig::default();
+        let trader_id = TraderId::from("TRADER-001");
+        let instance_id = UUID4::new();
+
+        assert_eq!(
+            CacheMap::from_config(trader_id, instance_id, &config),
+            CacheMap::new(
+                trader_id,
+                instance_id,
+                config.use_trader_prefix,
+                config.use_instance_id
+            )
+        );
+    }
+
+    #[rstest]
+    #[case("trader-TRADER-001:orders:O-1", Some(("orders", "O-1")))]
+    #[case("trader-TRADER-001:positions:P-1:2", Some(("positions", "P-1:2")))]
+    #[case("trader-TRADER-001:orders", None)]
+    #[case("trader-TRADER-002:orders:O-1", None)]
+    #[case("trader-TRADER-0012:orders:O-1", None)]
+    fn test_cache_map_parse_key(#[case] key: &str, #[case] expected: Option<(&str, &str)>) {
+        let cache_map = CacheMap::new(TraderId::from("TRADER-001"), UUID4::new(), true, false);
+
+        assert_eq!(cache_map.parse_key(key), expected);
+    }
+}
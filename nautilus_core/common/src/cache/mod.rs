@@ -19,7 +19,9 @@
 #![allow(dead_code)]
 #![allow(unused_variables)]
 
+pub mod codec;
 pub mod database;
+pub mod keys;
 
 use std::{
     collections::{HashMap, HashSet, VecDeque},
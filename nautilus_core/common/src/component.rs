@@ -13,7 +13,7 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use crate::enums::ComponentState;
+use crate::enums::{ComponentState, ComponentTrigger};
 
 pub struct PreInitialized;
 pub struct Ready;
@@ -103,3 +103,346 @@ impl State for Disposed {
         ComponentState::Disposed
     }
 }
+
+/// An invalid trigger for the current state of a component.
+#[derive(thiserror::Error, Clone, Copy, Debug, PartialEq, Eq)]
+#[error("Invalid state trigger {state} -> {trigger}")]
+pub struct InvalidStateTrigger {
+    /// The state of the component when triggered.
+    pub state: ComponentState,
+    /// The trigger which is invalid for the state.
+    pub trigger: ComponentTrigger,
+}
+
+/// A finite-state machine for the lifecycle of a component.
+///
+/// Enforces the same transitions as the Python `Component` base class, starting from
+/// `PRE_INITIALIZED`, with `DISPOSED` and `FAULTED` as terminal states.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ComponentFSM {
+    state: ComponentState,
+}
+
+impl Default for ComponentFSM {
+    /// Creates a new default [`ComponentFSM`] instance.
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComponentFSM {
+    /// Creates a new [`ComponentFSM`] instance in the `PRE_INITIALIZED` state.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: ComponentState::PreInitialized,
+        }
+    }
+
+    /// Returns the state resulting from `trigger` in `state`, or `None` if the trigger is
+    /// invalid for the state.
+    #[must_use]
+    pub const fn transition(
+        state: ComponentState,
+        trigger: ComponentTrigger,
+    ) -> Option<ComponentState> {
+        use ComponentState as S;
+        use ComponentTrigger as T;
+
+        match (state, trigger) {
+            (S::PreInitialized, T::Initialize) => Some(S::Ready),
+            (S::Ready, T::Reset) => Some(S::Resetting),
+            (S::Ready, T::Start) => Some(S::Starting),
+            (S::Ready, T::Dispose) => Some(S::Disposing),
+            (S::Resetting, T::ResetCompleted) => Some(S::Ready),
+            (S::Starting, T::StartCompleted) => Some(S::Running),
+            (S::Starting, T::Stop) => Some(S::Stopping),
+            (S::Starting, T::Fault) => Some(S::Faulting),
+            (S::Running, T::Stop) => Some(S::Stopping),
+            (S::Running, T::Degrade) => Some(S::Degrading),
+            (S::Running, T::Fault) => Some(S::Faulting),
+            (S::Resuming, T::Stop) => Some(S::Stopping),
+            (S::Resuming, T::ResumeCompleted) => Some(S::Running),
+            (S::Resuming, T::Fault) => Some(S::Faulting),
+            (S::Stopping, T::StopCompleted) => Some(S::Stopped),
+            (S::Stopping, T::Fault) => Some(S::Faulting),
+            (S::Stopped, T::Reset) => Some(S::Resetting),
+            (S::Stopped, T::Resume) => Some(S::Resuming),
+            (S::Stopped, T::Dispose) => Some(S::Disposing),
+            (S::Stopped, T::Fault) => Some(S::Faulting),
+            (S::Degrading, T::DegradeCompleted) => Some(S::Degraded),
+            (S::Degraded, T::Resume) => Some(S::Resuming),
+            (S::Degraded, T::Stop) => Some(S::Stopping),
+            (S::Degraded, T::Fault) => Some(S::Faulting),
+            (S::Disposing, T::DisposeCompleted) => Some(S::Disposed),
+            (S::Faulting, T::FaultCompleted) => Some(S::Faulted),
+            _ => None,
+        }
+    }
+
+    /// Returns the current state.
+    #[must_use]
+    pub const fn state(&self) -> ComponentState {
+        self.state
+    }
+
+    /// Returns whether `trigger` is valid for the current state.
+    #[must_use]
+    pub const fn is_valid_trigger(&self, trigger: ComponentTrigger) -> bool {
+        Self::transition(self.state, trigger).is_some()
+    }
+
+    /// Applies the `trigger`, returning the new state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `trigger` is invalid for the current state, which is then left unchanged.
+    pub fn trigger(
+        &mut self,
+        trigger: ComponentTrigger,
+    ) -> Result<ComponentState, InvalidStateTrigger> {
+        let state = Self::transition(self.state, trigger).ok_or(InvalidStateTrigger {
+            state: self.state,
+            trigger,
+        })?;
+        self.state = state;
+        Ok(state)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    /// The transition table of the Python `Component` base class.
+    const TRANSITIONS: [(ComponentState, ComponentTrigger, ComponentState); 26] = [
+        (
+            ComponentState::PreInitialized,
+            ComponentTrigger::Initialize,
+            ComponentState::Ready,
+        ),
+        (
+            ComponentState::Ready,
+            ComponentTrigger::Reset,
+            ComponentState::Resetting,
+        ),
+        (
+            ComponentState::Ready,
+            ComponentTrigger::Start,
+            ComponentState::Starting,
+        ),
+        (
+            ComponentState::Ready,
+            ComponentTrigger::Dispose,
+            ComponentState::Disposing,
+        ),
+        (
+            ComponentState::Resetting,
+            ComponentTrigger::ResetCompleted,
+            ComponentState::Ready,
+        ),
+        (
+            ComponentState::Starting,
+            ComponentTrigger::StartCompleted,
+            ComponentState::Running,
+        ),
+        (
+            ComponentState::Starting,
+            ComponentTrigger::Stop,
+            ComponentState::Stopping,
+        ),
+        (
+            ComponentState::Starting,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Running,
+            ComponentTrigger::Stop,
+            ComponentState::Stopping,
+        ),
+        (
+            ComponentState::Running,
+            ComponentTrigger::Degrade,
+            ComponentState::Degrading,
+        ),
+        (
+            ComponentState::Running,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Resuming,
+            ComponentTrigger::Stop,
+            ComponentState::Stopping,
+        ),
+        (
+            ComponentState::Resuming,
+            ComponentTrigger::ResumeCompleted,
+            ComponentState::Running,
+        ),
+        (
+            ComponentState::Resuming,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Stopping,
+            ComponentTrigger::StopCompleted,
+            ComponentState::Stopped,
+        ),
+        (
+            ComponentState::Stopping,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Stopped,
+            ComponentTrigger::Reset,
+            ComponentState::Resetting,
+        ),
+        (
+            ComponentState::Stopped,
+            ComponentTrigger::Resume,
+            ComponentState::Resuming,
+        ),
+        (
+            ComponentState::Stopped,
+            ComponentTrigger::Dispose,
+            ComponentState::Disposing,
+        ),
+        (
+            ComponentState::Stopped,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Degrading,
+            ComponentTrigger::DegradeCompleted,
+            ComponentState::Degraded,
+        ),
+        (
+            ComponentState::Degraded,
+            ComponentTrigger::Resume,
+            ComponentState::Resuming,
+        ),
+        (
+            ComponentState::Degraded,
+            ComponentTrigger::Stop,
+            ComponentState::Stopping,
+        ),
+        (
+            ComponentState::Degraded,
+            ComponentTrigger::Fault,
+            ComponentState::Faulting,
+        ),
+        (
+            ComponentState::Disposing,
+            ComponentTrigger::DisposeCompleted,
+            ComponentState::Disposed,
+        ),
+        (
+            ComponentState::Faulting,
+            ComponentTrigger::FaultCompleted,
+            ComponentState::Faulted,
+        ),
+    ];
+
+    fn expected_transition(
+        state: ComponentState,
+        trigger: ComponentTrigger,
+    ) -> Option<ComponentState> {
+        TRANSITIONS
+            .iter()
+            .find(|(from, on, _)| *from == state && *on == trigger)
+            .map(|(_, _, to)| *to)
+    }
+
+    #[rstest]
+    fn test_new_is_pre_initialized() {
+        assert_eq!(ComponentFSM::new().state(), ComponentState::PreInitialized);
+        assert_eq!(ComponentFSM::default(), ComponentFSM::new());
+    }
+
+    #[rstest]
+    fn test_every_trigger_from_every_state() {
+        let mut valid_count = 0;
+        for state in ComponentState::iter() {
+            for trigger in ComponentTrigger::iter() {
+                let mut fsm = ComponentFSM { state };
+                let result = fsm.trigger(trigger);
+
+                match expected_transition(state, trigger) {
+                    Some(expected) => {
+                        valid_count += 1;
+                        assert_eq!(result, Ok(expected), "{state} -> {trigger}");
+                        assert_eq!(fsm.state(), expected);
+                        assert_eq!(trigger.target_state(), expected);
+                    }
+                    None => {
+                        assert_eq!(
+                            result,
+                            Err(InvalidStateTrigger { state, trigger }),
+                            "{state} -> {trigger}"
+                        );
+                        assert_eq!(fsm.state(), state);
+                    }
+                }
+                assert_eq!(
+                    ComponentFSM { state }.is_valid_trigger(trigger),
+                    result.is_ok()
+                );
+            }
+        }
+        assert_eq!(valid_count, TRANSITIONS.len());
+    }
+
+    #[rstest]
+    #[case(ComponentState::Disposed)]
+    #[case(ComponentState::Faulted)]
+    fn test_terminal_states(#[case] state: ComponentState) {
+        let fsm = ComponentFSM { state };
+        assert!(ComponentTrigger::iter().all(|trigger| !fsm.is_valid_trigger(trigger)));
+    }
+
+    #[rstest]
+    fn test_lifecycle() {
+        let mut fsm = ComponentFSM::new();
+        for trigger in [
+            ComponentTrigger::Initialize,
+            ComponentTrigger::Start,
+            ComponentTrigger::StartCompleted,
+            ComponentTrigger::Stop,
+            ComponentTrigger::StopCompleted,
+            ComponentTrigger::Resume,
+            ComponentTrigger::ResumeCompleted,
+            ComponentTrigger::Stop,
+            ComponentTrigger::StopCompleted,
+            ComponentTrigger::Dispose,
+        ] {
+            fsm.trigger(trigger).unwrap();
+        }
+        assert_eq!(
+            fsm.trigger(ComponentTrigger::DisposeCompleted),
+            Ok(ComponentState::Disposed)
+        );
+    }
+
+    #[rstest]
+    fn test_invalid_trigger_display() {
+        let err = ComponentFSM::new()
+            .trigger(ComponentTrigger::Start)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid state trigger PRE_INITIALIZED -> START"
+        );
+    }
+}
@@ -17,8 +17,9 @@
 
 use std::fmt::Debug;
 
+use nautilus_model::enum_as_str;
 use serde::{Deserialize, Serialize};
-use strum::{Display, EnumIter, EnumString, FromRepr};
+use strum::{Display, EnumIter, EnumString, FromRepr, IntoStaticStr};
 
 /// The state of a component within the system.
 #[repr(C)]
@@ -27,6 +28,7 @@ use strum::{Display, EnumIter, EnumString, FromRepr};
     Clone,
     Debug,
     Display,
+    IntoStaticStr,
     Hash,
     PartialEq,
     Eq,
@@ -82,6 +84,7 @@ pub enum ComponentState {
     Clone,
     Debug,
     Display,
+    IntoStaticStr,
     Hash,
     PartialEq,
     Eq,
@@ -132,6 +135,34 @@ pub enum ComponentTrigger {
     FaultCompleted = 15,
 }
 
+impl ComponentTrigger {
+    /// Returns the state the trigger drives a component toward.
+    ///
+    /// Triggers which begin an action drive toward its transitional state (e.g. `Start` to
+    /// `Starting`), and those which complete an action toward its resulting state (e.g.
+    /// `StartCompleted` to `Running`). The target never depends on the current state, as each
+    /// trigger leads to the same state from every state in which it is valid (e.g. `Reset` leads
+    /// to `Resetting` from both `Ready` and `Stopped`), so this is defined for every trigger.
+    #[must_use]
+    pub const fn target_state(self) -> ComponentState {
+        match self {
+            Self::Initialize | Self::ResetCompleted => ComponentState::Ready,
+            Self::Start => ComponentState::Starting,
+            Self::StartCompleted | Self::ResumeCompleted => ComponentState::Running,
+            Self::Stop => ComponentState::Stopping,
+            Self::StopCompleted => ComponentState::Stopped,
+            Self::Resume => ComponentState::Resuming,
+            Self::Reset => ComponentState::Resetting,
+            Self::Dispose => ComponentState::Disposing,
+            Self::DisposeCompleted => ComponentState::Disposed,
+            Self::Degrade => ComponentState::Degrading,
+            Self::DegradeCompleted => ComponentState::Degraded,
+            Self::Fault => ComponentState::Faulting,
+            Self::FaultCompleted => ComponentState::Faulted,
+        }
+    }
+}
+
 /// The log level for log messages.
 #[repr(C)]
 #[derive(
@@ -139,6 +170,7 @@ pub enum ComponentTrigger {
     Clone,
     Debug,
     Display,
+    IntoStaticStr,
     Hash,
     PartialEq,
     Eq,
@@ -190,6 +222,7 @@ pub enum LogLevel {
     Clone,
     Debug,
     Display,
+    IntoStaticStr,
     Hash,
     PartialEq,
     Eq,
@@ -260,10 +293,37 @@ impl From<u8> for LogColor {
     }
 }
 
+/// The status of a logging operation across the FFI boundary, where each error status
+/// corresponds to a [`LogError`](crate::logging::error::LogError) variant.
+#[repr(C)]
+#[derive(
+    Copy, Clone, Debug, Display, IntoStaticStr, Hash, PartialEq, Eq, FromRepr, EnumIter, EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogStatus {
+    /// The operation succeeded.
+    Ok = 0,
+    /// A log level string was invalid.
+    InvalidLevel = 1,
+    /// A logger configuration option was invalid.
+    ConfigParse = 2,
+    /// A logger configuration JSON value could not be parsed.
+    JsonParse = 3,
+    /// A log file directory could not be created.
+    CreateDirectory = 4,
+    /// A log file could not be opened.
+    FileOpen = 5,
+    /// The logger configuration environment variable could not be read.
+    EnvVar = 6,
+    /// A logger has already been initialized for the process.
+    AlreadyInitialized = 7,
+}
+
 /// An ANSI log line format specifier.
 /// This is used for formatting log messages with ANSI escape codes.
 #[repr(C)]
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, FromRepr, EnumString, Display, IntoStaticStr)]
 #[strum(ascii_case_insensitive)]
 #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
 #[cfg_attr(
@@ -290,6 +350,24 @@ pub enum LogFormat {
     Underline,
 }
 
+impl LogFormat {
+    #[must_use]
+    pub const fn as_ansi(&self) -> &str {
+        match *self {
+            Self::Header => "\x1b[95m",
+            Self::Endc => "\x1b[0m",
+            Self::Bold => "\x1b[1m",
+            Self::Underline => "\x1b[4m",
+        }
+    }
+
+    /// Wraps the given `text` in this format code, followed by the [`LogFormat::Endc`] reset code.
+    #[must_use]
+    pub fn wrap(self, text: &str) -> String {
+        format!("{}{text}{}", self.as_ansi(), Self::Endc.as_ansi())
+    }
+}
+
 /// The serialization encoding.
 #[repr(C)]
 #[derive(
@@ -297,6 +375,7 @@ pub enum LogFormat {
     Clone,
     Debug,
     Display,
+    IntoStaticStr,
     Hash,
     PartialEq,
     Eq,
@@ -322,3 +401,103 @@ pub enum SerializationEncoding {
     #[serde(rename = "json")]
     Json = 1,
 }
+
+enum_as_str!(ComponentState);
+enum_as_str!(ComponentTrigger);
+enum_as_str!(LogLevel);
+enum_as_str!(LogColor);
+enum_as_str!(LogStatus);
+enum_as_str!(LogFormat);
+enum_as_str!(SerializationEncoding);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    fn assert_as_str_matches_to_string<T>(as_str: fn(T) -> &'static str)
+    where
+        T: IntoEnumIterator + Display + Copy,
+    {
+        for variant in T::iter() {
+            assert_eq!(as_str(variant), variant.to_string());
+        }
+    }
+
+    #[rstest]
+    #[case(ComponentTrigger::Initialize, ComponentState::Ready)]
+    #[case(ComponentTrigger::Start, ComponentState::Starting)]
+    #[case(ComponentTrigger::StartCompleted, ComponentState::Running)]
+    #[case(ComponentTrigger::Stop, ComponentState::Stopping)]
+    #[case(ComponentTrigger::StopCompleted, ComponentState::Stopped)]
+    #[case(ComponentTrigger::Resume, ComponentState::Resuming)]
+    #[case(ComponentTrigger::ResumeCompleted, ComponentState::Running)]
+    #[case(ComponentTrigger::Reset, ComponentState::Resetting)]
+    #[case(ComponentTrigger::ResetCompleted, ComponentState::Ready)]
+    #[case(ComponentTrigger::Dispose, ComponentState::Disposing)]
+    #[case(ComponentTrigger::DisposeCompleted, ComponentState::Disposed)]
+    #[case(ComponentTrigger::Degrade, ComponentState::Degrading)]
+    #[case(ComponentTrigger::DegradeCompleted, ComponentState::Degraded)]
+    #[case(ComponentTrigger::Fault, ComponentState::Faulting)]
+    #[case(ComponentTrigger::FaultCompleted, ComponentState::Faulted)]
+    fn test_component_trigger_target_state(
+        #[case] trigger: ComponentTrigger,
+        #[case] expected: ComponentState,
+    ) {
+        assert_eq!(trigger.target_state(), expected);
+    }
+
+    #[rstest]
+    fn test_component_trigger_target_state_never_pre_initialized() {
+        for trigger in ComponentTrigger::iter() {
+            assert_ne!(trigger.target_state(), ComponentState::PreInitialized);
+        }
+    }
+
+    #[rstest]
+    #[case(LogFormat::Header, "\x1b[95mtext\x1b[0m")]
+    #[case(LogFormat::Endc, "\x1b[0mtext\x1b[0m")]
+    #[case(LogFormat::Bold, "\x1b[1mtext\x1b[0m")]
+    #[case(LogFormat::Underline, "\x1b[4mtext\x1b[0m")]
+    fn test_log_format_wrap(#[case] format: LogFormat, #[case] expected: &str) {
+        assert_eq!(format.wrap("text"), expected);
+    }
+
+    #[rstest]
+    fn test_log_format_as_ansi_matches_display() {
+        for format in [
+            LogFormat::Header,
+            LogFormat::Endc,
+            LogFormat::Bold,
+            LogFormat::Underline,
+        ] {
+            assert_eq!(format.as_ansi(), format.to_string());
+        }
+    }
+
+    #[rstest]
+    fn test_as_str_matches_to_string() {
+        assert_as_str_matches_to_string(ComponentState::as_str);
+        assert_as_str_matches_to_string(ComponentTrigger::as_str);
+        assert_as_str_matches_to_string(LogLevel::as_str);
+        assert_as_str_matches_to_string(LogColor::as_str);
+        assert_as_str_matches_to_string(LogStatus::as_str);
+        assert_as_str_matches_to_string(SerializationEncoding::as_str);
+
+        for format in [
+            LogFormat::Header,
+            LogFormat::Endc,
+            LogFormat::Bold,
+            LogFormat::Underline,
+        ] {
+            assert_eq!(format.as_str(), format.to_string());
+        }
+    }
+}
@@ -0,0 +1,285 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{ffi::c_char, ops::Deref};
+
+use nautilus_core::{
+    ffi::{cvec::CVec, parsing::u8_as_bool, string::str_to_cstr},
+    uuid::UUID4,
+};
+use nautilus_model::{
+    accounts::any::AccountAny,
+    ffi::{
+        accounts::{cash::CashAccount_API, margin::MarginAccount_API},
+        orders::OrderAny_API,
+        position::Position_API,
+    },
+    identifiers::{AccountId, ClientOrderId, PositionId, TraderId},
+    orders::any::OrderAny,
+    position::Position,
+};
+
+use crate::cache::{
+    codec::{CacheBlob, CacheCodec},
+    keys::CacheMap,
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`CacheMap`].
+///
+/// This struct wraps `CacheMap` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CacheMap` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `CacheMap_API` to be
+/// dereferenced to `CacheMap`, providing access to `CacheMap`'s methods without
+/// having to manually access the underlying `CacheMap` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct CacheMap_API(Box<CacheMap>);
+
+impl Deref for CacheMap_API {
+    type Target = CacheMap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cache_map_new(
+    trader_id: TraderId,
+    instance_id: UUID4,
+    use_trader_prefix: u8,
+    use_instance_id: u8,
+) -> CacheMap_API {
+    CacheMap_API(Box::new(CacheMap::new(
+        trader_id,
+        instance_id,
+        u8_as_bool(use_trader_prefix),
+        u8_as_bool(use_instance_id),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_map_drop(cache_map: CacheMap_API) {
+    drop(cache_map); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn cache_map_order_key(
+    cache_map: &CacheMap_API,
+    client_order_id: ClientOrderId,
+) -> *const c_char {
+    str_to_cstr(&cache_map.order_key(&client_order_id))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_map_position_key(
+    cache_map: &CacheMap_API,
+    position_id: PositionId,
+) -> *const c_char {
+    str_to_cstr(&cache_map.position_key(&position_id))
+}
+
+#[no_mangle]
+pub extern "C" fn cache_map_account_key(
+    cache_map: &CacheMap_API,
+    account_id: AccountId,
+) -> *const c_char {
+    str_to_cstr(&cache_map.account_key(&account_id))
+}
+
+fn encode_blob<T: CacheBlob>(value: &T) -> CVec {
+    CacheCodec::default().encode(value).unwrap().to_vec().into()
+}
+
+unsafe fn decode_blob<T: CacheBlob>(blob_ptr: *const u8, blob_len: usize) -> T {
+    assert!(!blob_ptr.is_null(), "`blob_ptr` was NULL");
+    let blob = std::slice::from_raw_parts(blob_ptr, blob_len);
+    CacheCodec::default().decode(blob).unwrap()
+}
+
+/// Returns a `CVec` of the order encoded as a cache blob, which must be freed with `cvec_drop`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the order cannot be encoded.
+#[no_mangle]
+pub extern "C" fn cache_order_encode(order: &OrderAny_API) -> CVec {
+    encode_blob::<OrderAny>(order)
+}
+
+/// Decodes an order from a cache blob.
+///
+/// # Safety
+///
+/// - Assumes `blob_ptr` points to at least `blob_len` bytes.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the blob is not a valid order blob with a supported schema version.
+#[no_mangle]
+pub unsafe extern "C" fn cache_order_decode(blob_ptr: *const u8, blob_len: usize) -> OrderAny_API {
+    OrderAny_API::new(decode_blob(blob_ptr, blob_len))
+}
+
+/// Returns a `CVec` of the position encoded as a cache blob, which must be freed with `cvec_drop`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the position cannot be encoded.
+#[no_mangle]
+pub extern "C" fn cache_position_encode(position: &Position_API) -> CVec {
+    encode_blob::<Position>(position)
+}
+
+/// Decodes a position from a cache blob.
+///
+/// # Safety
+///
+/// - Assumes `blob_ptr` points to at least `blob_len` bytes.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the blob is not a valid position blob with a supported schema version.
+#[no_mangle]
+pub unsafe extern "C" fn cache_position_decode(
+    blob_ptr: *const u8,
+    blob_len: usize,
+) -> Position_API {
+    Position_API::new(decode_blob(blob_ptr, blob_len))
+}
+
+/// Returns a `CVec` of the cash account encoded as a cache blob, which must be freed with
+/// `cvec_drop`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the account cannot be encoded.
+#[no_mangle]
+pub extern "C" fn cache_cash_account_encode(account: &CashAccount_API) -> CVec {
+    encode_blob(&AccountAny::Cash((**account).clone()))
+}
+
+/// Decodes a cash account from a cache blob.
+///
+/// # Safety
+///
+/// - Assumes `blob_ptr` points to at least `blob_len` bytes.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the blob is not a valid cash account blob with a supported schema version.
+#[no_mangle]
+pub unsafe extern "C" fn cache_cash_account_decode(
+    blob_ptr: *const u8,
+    blob_len: usize,
+) -> CashAccount_API {
+    match decode_blob(blob_ptr, blob_len) {
+        AccountAny::Cash(account) => CashAccount_API::new(account),
+        AccountAny::Margin(account) => panic!("Expected a cash account, was {}", account.id),
+    }
+}
+
+/// Returns a `CVec` of the margin account encoded as a cache blob, which must be freed with
+/// `cvec_drop`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the account cannot be encoded.
+#[no_mangle]
+pub extern "C" fn cache_margin_account_encode(account: &MarginAccount_API) -> CVec {
+    encode_blob(&AccountAny::Margin((**account).clone()))
+}
+
+/// Decodes a margin account from a cache blob.
+///
+/// # Safety
+///
+/// - Assumes `blob_ptr` points to at least `blob_len` bytes.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the blob is not a valid margin account blob with a supported schema version.
+#[no_mangle]
+pub unsafe extern "C" fn cache_margin_account_decode(
+    blob_ptr: *const u8,
+    blob_len: usize,
+) -> MarginAccount_API {
+    match decode_blob(blob_ptr, blob_len) {
+        AccountAny::Margin(account) => MarginAccount_API::new(account),
+        AccountAny::Cash(account) => panic!("Expected a margin account, was {}", account.id),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::ffi::{
+        cvec::cvec_drop,
+        string::{cstr_drop, cstr_to_str},
+    };
+    use nautilus_model::{
+        accounts::{cash::CashAccount, stubs::cash_account},
+        stubs::stub_position_long,
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_cache_map_order_key() {
+        let cache_map = cache_map_new(TraderId::from("TRADER-001"), UUID4::new(), 1, 0);
+
+        let key_ptr = cache_map_order_key(&cache_map, ClientOrderId::from("O-1"));
+        unsafe {
+            assert_eq!(cstr_to_str(key_ptr), "trader-TRADER-001:orders:O-1");
+            cstr_drop(key_ptr);
+        }
+        cache_map_drop(cache_map);
+    }
+
+    #[rstest]
+    fn test_cache_position_encode_decode(stub_position_long: Position) {
+        let position = Position_API::new(stub_position_long.clone());
+
+        let blob = cache_position_encode(&position);
+        let decoded = unsafe { cache_position_decode(blob.ptr.cast::<u8>(), blob.len) };
+        cvec_drop(blob);
+
+        assert_eq!(*decoded, stub_position_long);
+        assert_eq!(decoded.quantity, stub_position_long.quantity);
+    }
+
+    #[rstest]
+    fn test_cache_cash_account_encode_decode(cash_account: CashAccount) {
+        let account = CashAccount_API::new(cash_account.clone());
+
+        let blob = cache_cash_account_encode(&account);
+        let decoded = unsafe { cache_cash_account_decode(blob.ptr.cast::<u8>(), blob.len) };
+        cvec_drop(blob);
+
+        assert_eq!(*decoded, cash_account);
+    }
+}
@@ -0,0 +1,113 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use crate::{
+    component::ComponentFSM,
+    enums::{ComponentState, ComponentTrigger},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFSM`].
+///
+/// This struct wraps `ComponentFSM` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ComponentFSM` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `ComponentFSM_API` to be
+/// dereferenced to `ComponentFSM`, providing access to `ComponentFSM`'s methods without
+/// having to manually access the underlying `ComponentFSM` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ComponentFSM_API(Box<ComponentFSM>);
+
+impl Deref for ComponentFSM_API {
+    type Target = ComponentFSM;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ComponentFSM_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_new() -> ComponentFSM_API {
+    ComponentFSM_API(Box::default())
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_drop(fsm: ComponentFSM_API) {
+    drop(fsm); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_state(fsm: &ComponentFSM_API) -> ComponentState {
+    fsm.state()
+}
+
+#[no_mangle]
+pub extern "C" fn component_fsm_is_valid_trigger(
+    fsm: &ComponentFSM_API,
+    trigger: ComponentTrigger,
+) -> u8 {
+    u8::from(fsm.is_valid_trigger(trigger))
+}
+
+/// Applies the `trigger` to the state machine.
+///
+/// Returns 1 if the state transitioned, or 0 if the trigger is invalid for the current state
+/// (which is then left unchanged), so the caller can raise `InvalidStateTrigger`.
+#[no_mangle]
+pub extern "C" fn component_fsm_trigger(
+    fsm: &mut ComponentFSM_API,
+    trigger: ComponentTrigger,
+) -> u8 {
+    u8::from(fsm.trigger(trigger).is_ok())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_component_fsm_trigger() {
+        let mut fsm = component_fsm_new();
+        assert_eq!(component_fsm_state(&fsm), ComponentState::PreInitialized);
+        assert_eq!(
+            component_fsm_is_valid_trigger(&fsm, ComponentTrigger::Initialize),
+            1
+        );
+
+        assert_eq!(
+            component_fsm_trigger(&mut fsm, ComponentTrigger::Initialize),
+            1
+        );
+        assert_eq!(component_fsm_state(&fsm), ComponentState::Ready);
+
+        assert_eq!(component_fsm_trigger(&mut fsm, ComponentTrigger::Stop), 0);
+        assert_eq!(component_fsm_state(&fsm), ComponentState::Ready);
+
+        component_fsm_drop(fsm);
+    }
+}
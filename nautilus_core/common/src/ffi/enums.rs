@@ -17,11 +17,11 @@ use std::{ffi::c_char, str::FromStr};
 
 use nautilus_core::ffi::string::{cstr_to_str, str_to_cstr};
 
-use crate::enums::{ComponentState, ComponentTrigger, LogColor, LogLevel};
+use crate::enums::{ComponentState, ComponentTrigger, LogColor, LogLevel, LogStatus};
 
 #[no_mangle]
 pub extern "C" fn component_state_to_cstr(value: ComponentState) -> *const c_char {
-    str_to_cstr(&value.to_string())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -38,7 +38,7 @@ pub unsafe extern "C" fn component_state_from_cstr(ptr: *const c_char) -> Compon
 
 #[no_mangle]
 pub extern "C" fn component_trigger_to_cstr(value: ComponentTrigger) -> *const c_char {
-    str_to_cstr(&value.to_string())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -53,9 +53,15 @@ pub unsafe extern "C" fn component_trigger_from_cstr(ptr: *const c_char) -> Comp
         .unwrap_or_else(|_| panic!("invalid `ComponentTrigger` enum string value, was '{value}'"))
 }
 
+/// Returns the state the trigger drives a component toward.
+#[no_mangle]
+pub extern "C" fn component_trigger_target_state(value: ComponentTrigger) -> ComponentState {
+    value.target_state()
+}
+
 #[no_mangle]
 pub extern "C" fn log_level_to_cstr(value: LogLevel) -> *const c_char {
-    str_to_cstr(&value.to_string())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -72,7 +78,7 @@ pub unsafe extern "C" fn log_level_from_cstr(ptr: *const c_char) -> LogLevel {
 
 #[no_mangle]
 pub extern "C" fn log_color_to_cstr(value: LogColor) -> *const c_char {
-    str_to_cstr(&value.to_string())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -86,3 +92,8 @@ pub unsafe extern "C" fn log_color_from_cstr(ptr: *const c_char) -> LogColor {
     LogColor::from_str(value)
         .unwrap_or_else(|_| panic!("invalid `LogColor` enum string value, was '{value}'"))
 }
+
+#[no_mangle]
+pub extern "C" fn log_status_to_cstr(value: LogStatus) -> *const c_char {
+    str_to_cstr(value.as_str())
+}
@@ -20,19 +20,28 @@ use std::{
 
 use nautilus_core::{
     ffi::{
-        parsing::{optional_bytes_to_json, u8_as_bool},
-        string::{cstr_to_str, cstr_to_ustr, optional_cstr_to_str},
+        parsing::u8_as_bool,
+        string::{
+            cstr_to_str, cstr_to_str_lossy, cstr_to_ustr, cstr_to_ustr_lossy, optional_cstr_to_str,
+            str_to_cstr,
+        },
     },
     uuid::UUID4,
 };
 use nautilus_model::identifiers::TraderId;
+use serde::de::DeserializeOwned;
 
 use crate::{
-    enums::{LogColor, LogLevel},
+    enums::{LogColor, LogLevel, LogStatus},
     logging::{
-        self, headers,
+        self,
+        error::LogError,
+        headers,
         logger::{self, LogGuard, LoggerConfig},
-        logging_set_bypass, map_log_level_to_filter, parse_component_levels,
+        logging_set_bypass, map_log_level_to_filter, parse_component_colors,
+        parse_component_levels, parse_file_sinks,
+        ratelimit::parse_rate_limits,
+        strip_ansi,
         writer::FileWriterConfig,
     },
 };
@@ -63,6 +72,25 @@ impl DerefMut for LogGuard_API {
     }
 }
 
+/// Parses the JSON C string at `ptr` (if not NULL), described by `name` in any error.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is either NULL or a valid C string pointer.
+unsafe fn parse_optional_json<T: DeserializeOwned>(
+    ptr: *const c_char,
+    name: &str,
+) -> Result<Option<T>, LogError> {
+    optional_cstr_to_str(ptr)
+        .map(|json| {
+            serde_json::from_str(json).map_err(|e| LogError::JsonParse {
+                name: name.to_string(),
+                reason: e.to_string(),
+            })
+        })
+        .transpose()
+}
+
 /// Initializes logging.
 ///
 /// Logging should be used for Python and sync Rust logic which is most of
@@ -70,6 +98,9 @@ impl DerefMut for LogGuard_API {
 /// Logging can be configured to filter components and write up to a specific level only
 /// by passing a configuration using the `NAUTILUS_LOG` environment variable.
 ///
+/// The outcome is written to `status`, which is `LogStatus::Ok` on success. On failure the
+/// error is printed to stderr, and the returned guard has no logger to flush.
+///
 /// # Safety
 ///
 /// Should only be called once during an applications run, ideally at the
@@ -79,6 +110,10 @@ impl DerefMut for LogGuard_API {
 /// - Assume `file_name_ptr` is either NULL or a valid C string pointer.
 /// - Assume `file_format_ptr` is either NULL or a valid C string pointer.
 /// - Assume `component_level_ptr` is either NULL or a valid C string pointer.
+/// - Assume `rate_limits_ptr` is either NULL or a valid C string pointer.
+/// - Assume `component_colors_ptr` is either NULL or a valid C string pointer.
+/// - Assume `file_sinks_ptr` is either NULL or a valid C string pointer (a JSON array).
+/// - Assume `status` is a valid pointer to write the status to.
 #[no_mangle]
 pub unsafe extern "C" fn logging_init(
     trader_id: TraderId,
@@ -89,44 +124,67 @@ pub unsafe extern "C" fn logging_init(
     file_name_ptr: *const c_char,
     file_format_ptr: *const c_char,
     component_levels_ptr: *const c_char,
+    rate_limits_ptr: *const c_char,
+    component_colors_ptr: *const c_char,
+    file_sinks_ptr: *const c_char,
     is_colored: u8,
     is_bypassed: u8,
     print_config: u8,
+    status: *mut LogStatus,
 ) -> LogGuard_API {
-    let level_stdout = map_log_level_to_filter(level_stdout);
-    let level_file = map_log_level_to_filter(level_file);
-
-    let component_levels_json = optional_bytes_to_json(component_levels_ptr);
-    let component_levels = parse_component_levels(component_levels_json);
-
-    let config = LoggerConfig::new(
-        level_stdout,
-        level_file,
-        component_levels,
-        u8_as_bool(is_colored),
-        u8_as_bool(print_config),
-    );
+    let init = || -> Result<LogGuard, LogError> {
+        let component_levels = parse_optional_json(component_levels_ptr, "component levels")?;
 
-    let directory = optional_cstr_to_str(directory_ptr).map(std::string::ToString::to_string);
-    let file_name = optional_cstr_to_str(file_name_ptr).map(std::string::ToString::to_string);
-    let file_format = optional_cstr_to_str(file_format_ptr).map(std::string::ToString::to_string);
-    let file_config = FileWriterConfig::new(directory, file_name, file_format);
+        let config = LoggerConfig::new(
+            map_log_level_to_filter(level_stdout),
+            map_log_level_to_filter(level_file),
+            parse_component_levels(component_levels)?,
+            u8_as_bool(is_colored),
+            u8_as_bool(print_config),
+        )
+        .with_rate_limits(parse_rate_limits(parse_optional_json(
+            rate_limits_ptr,
+            "rate limits",
+        )?))
+        .with_component_colors(parse_component_colors(parse_optional_json(
+            component_colors_ptr,
+            "component colors",
+        )?))
+        .with_file_sinks(parse_file_sinks(parse_optional_json(
+            file_sinks_ptr,
+            "file sinks",
+        )?));
 
-    if u8_as_bool(is_bypassed) {
-        logging_set_bypass();
-    }
+        let directory = optional_cstr_to_str(directory_ptr).map(ToString::to_string);
+        let file_name = optional_cstr_to_str(file_name_ptr).map(ToString::to_string);
+        let file_format = optional_cstr_to_str(file_format_ptr).map(ToString::to_string);
+        let file_config = FileWriterConfig::new(directory, file_name, file_format);
+
+        if u8_as_bool(is_bypassed) {
+            logging_set_bypass();
+        }
 
-    LogGuard_API(Box::new(logging::init_logging(
-        trader_id,
-        instance_id,
-        config,
-        file_config,
-    )))
-    // logging::init_logging(trader_id, instance_id, config, file_config);
+        logging::init_logging(trader_id, instance_id, config, file_config)
+    };
+
+    match init() {
+        Ok(log_guard) => {
+            *status = LogStatus::Ok;
+            LogGuard_API(Box::new(log_guard))
+        }
+        Err(e) => {
+            eprintln!("Error initializing logging: {e}");
+            *status = e.status();
+            LogGuard_API(Box::new(LogGuard::new(None)))
+        }
+    }
 }
 
 /// Creates a new log event.
 ///
+/// Invalid UTF-8 sequences in the component or message are replaced with
+/// `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+///
 /// # Safety
 ///
 /// - Assumes `component_ptr` is a valid C string pointer.
@@ -138,10 +196,44 @@ pub unsafe extern "C" fn logger_log(
     component_ptr: *const c_char,
     message_ptr: *const c_char,
 ) {
-    let component = cstr_to_ustr(component_ptr);
-    let message = cstr_to_str(message_ptr);
+    let component = cstr_to_ustr_lossy(component_ptr);
+    let message = cstr_to_str_lossy(message_ptr);
+
+    logger::log(level, color, component, &message);
+}
+
+/// Creates a new log event with the source `file` and `line` it originated from.
+///
+/// The location is only included if the logger is configured `with_source_location`.
+/// Invalid UTF-8 sequences in the component, message or file are replaced with
+/// `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+///
+/// # Safety
+///
+/// - Assumes `component_ptr` is a valid C string pointer.
+/// - Assumes `message_ptr` is a valid C string pointer.
+/// - Assumes `file_ptr` is either NULL or a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn logger_log_with_location(
+    level: LogLevel,
+    color: LogColor,
+    component_ptr: *const c_char,
+    message_ptr: *const c_char,
+    file_ptr: *const c_char,
+    line: u32,
+) {
+    let component = cstr_to_ustr_lossy(component_ptr);
+    let message = cstr_to_str_lossy(message_ptr);
+    let file = (!file_ptr.is_null()).then(|| cstr_to_str_lossy(file_ptr));
 
-    logger::log(level, color, component, message);
+    logger::log_with_location(
+        level,
+        color,
+        component,
+        &message,
+        file.as_deref(),
+        Some(line),
+    );
 }
 
 /// Logs the Nautilus system header.
@@ -173,8 +265,92 @@ pub unsafe extern "C" fn logging_log_sysinfo(component_ptr: *const c_char) {
     headers::log_sysinfo(component);
 }
 
+/// Returns whether an event at `level` from the component would be written by the logger.
+///
+/// Allows callers to skip formatting messages which would be filtered out.
+///
+/// # Safety
+///
+/// - Assumes `component_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn logger_would_log(
+    log_guard: &LogGuard_API,
+    level: LogLevel,
+    component_ptr: *const c_char,
+) -> u8 {
+    u8::from(log_guard.would_log(level, &cstr_to_ustr(component_ptr)))
+}
+
+/// Returns the count of log events dropped because the logger queue was full.
+#[no_mangle]
+pub extern "C" fn logger_get_dropped_count(log_guard: &LogGuard_API) -> u64 {
+    log_guard.dropped_count()
+}
+
 /// Flushes global logger buffers of any records.
 #[no_mangle]
 pub extern "C" fn logger_drop(log_guard: LogGuard_API) {
     drop(log_guard);
 }
+
+/// Returns the string with all ANSI SGR escape sequences (`\x1b[...m`) removed.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn ansi_strip(ptr: *const c_char) -> *const c_char {
+    str_to_cstr(&strip_ansi(&cstr_to_str_lossy(ptr)))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr::null};
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Some(r#"{"RiskEngine": "#), None, None)]
+    #[case(None, Some("[1, 2]"), None)]
+    #[case(None, None, Some("not json"))]
+    fn test_logging_init_invalid_json_status(
+        #[case] component_levels: Option<&str>,
+        #[case] rate_limits: Option<&str>,
+        #[case] file_sinks: Option<&str>,
+    ) {
+        let to_cstring = |json: Option<&str>| json.map(|json| CString::new(json).unwrap());
+        let as_ptr = |cstring: &Option<CString>| cstring.as_ref().map_or(null(), |s| s.as_ptr());
+        let component_levels = to_cstring(component_levels);
+        let rate_limits = to_cstring(rate_limits);
+        let file_sinks = to_cstring(file_sinks);
+        let mut status = LogStatus::Ok;
+
+        let log_guard = unsafe {
+            logging_init(
+                TraderId::from("TRADER-001"),
+                UUID4::new(),
+                LogLevel::Info,
+                LogLevel::Off,
+                null(),
+                null(),
+                null(),
+                as_ptr(&component_levels),
+                as_ptr(&rate_limits),
+                null(),
+                as_ptr(&file_sinks),
+                0,
+                0,
+                0,
+                &mut status,
+            )
+        };
+
+        assert_eq!(status, LogStatus::JsonParse);
+        logger_drop(log_guard);
+    }
+}
@@ -0,0 +1,230 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_char;
+
+use indexmap::IndexMap;
+use nautilus_core::{
+    ffi::string::{cstr_to_str, optional_cstr_to_str, str_to_cstr},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
+use nautilus_model::identifiers::{ClientId, Venue};
+
+use crate::messages::engine::{
+    DataDescriptor, DataRequest, DataTypeRegistry, Subscribe, Unsubscribe,
+};
+
+/// Returns the descriptor for the data type `type_name` with the JSON object `metadata_ptr`.
+///
+/// # Safety
+///
+/// - Assumes `type_name_ptr` is a valid C string pointer.
+/// - Assumes `metadata_ptr` is a valid C string pointer or NULL.
+unsafe fn descriptor_from_cstrs(
+    type_name_ptr: *const c_char,
+    metadata_ptr: *const c_char,
+) -> DataDescriptor {
+    let type_name = cstr_to_str(type_name_ptr);
+    let metadata: IndexMap<String, String> = optional_cstr_to_str(metadata_ptr)
+        .map(|json| serde_json::from_str(json).expect("Invalid metadata JSON"))
+        .unwrap_or_default();
+
+    DataTypeRegistry::default()
+        .descriptor_from_parts(type_name, &metadata)
+        .unwrap()
+}
+
+fn to_json_cstr<T: serde::Serialize>(message: &T) -> *const c_char {
+    str_to_cstr(&serde_json::to_string(message).expect("Error serializing message"))
+}
+
+/// Returns a new [`Subscribe`] command serialized as a JSON C string.
+///
+/// # Safety
+///
+/// - Assumes `type_name_ptr` is a valid C string pointer.
+/// - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+/// - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the data type is not registered, or its metadata has no valid instrument ID.
+/// - If both `client_id_ptr` and `venue_ptr` are NULL.
+#[no_mangle]
+pub unsafe extern "C" fn data_subscribe_new(
+    type_name_ptr: *const c_char,
+    metadata_ptr: *const c_char,
+    client_id_ptr: *const c_char,
+    venue_ptr: *const c_char,
+    command_id: UUID4,
+    ts_init: UnixNanos,
+) -> *const c_char {
+    to_json_cstr(&Subscribe::new(
+        command_id,
+        optional_cstr_to_str(client_id_ptr).map(ClientId::from),
+        optional_cstr_to_str(venue_ptr).map(Venue::from),
+        descriptor_from_cstrs(type_name_ptr, metadata_ptr),
+        ts_init,
+    ))
+}
+
+/// Returns a new [`Unsubscribe`] command serialized as a JSON C string.
+///
+/// # Safety
+///
+/// - Assumes `type_name_ptr` is a valid C string pointer.
+/// - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+/// - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the data type is not registered, or its metadata has no valid instrument ID.
+/// - If both `client_id_ptr` and `venue_ptr` are NULL.
+#[no_mangle]
+pub unsafe extern "C" fn data_unsubscribe_new(
+    type_name_ptr: *const c_char,
+    metadata_ptr: *const c_char,
+    client_id_ptr: *const c_char,
+    venue_ptr: *const c_char,
+    command_id: UUID4,
+    ts_init: UnixNanos,
+) -> *const c_char {
+    to_json_cstr(&Unsubscribe::new(
+        command_id,
+        optional_cstr_to_str(client_id_ptr).map(ClientId::from),
+        optional_cstr_to_str(venue_ptr).map(Venue::from),
+        descriptor_from_cstrs(type_name_ptr, metadata_ptr),
+        ts_init,
+    ))
+}
+
+/// Returns a new [`DataRequest`] serialized as a JSON C string.
+///
+/// A `start`, `end` or `limit` of zero is treated as unbounded.
+///
+/// # Safety
+///
+/// - Assumes `type_name_ptr` is a valid C string pointer.
+/// - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+/// - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the data type is not registered, or its metadata has no valid instrument ID.
+/// - If both `client_id_ptr` and `venue_ptr` are NULL.
+/// - If `start` is after `end`.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn data_request_new(
+    type_name_ptr: *const c_char,
+    metadata_ptr: *const c_char,
+    client_id_ptr: *const c_char,
+    venue_ptr: *const c_char,
+    correlation_id: UUID4,
+    start: UnixNanos,
+    end: UnixNanos,
+    limit: usize,
+    ts_init: UnixNanos,
+) -> *const c_char {
+    to_json_cstr(&DataRequest::new(
+        correlation_id,
+        optional_cstr_to_str(client_id_ptr).map(ClientId::from),
+        optional_cstr_to_str(venue_ptr).map(Venue::from),
+        descriptor_from_cstrs(type_name_ptr, metadata_ptr),
+        (start != 0).then_some(start),
+        (end != 0).then_some(end),
+        (limit != 0).then_some(limit),
+        ts_init,
+    ))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::{CStr, CString};
+
+    use nautilus_core::ffi::string::cstr_drop;
+    use nautilus_model::data::bar::BarType;
+    use rstest::rstest;
+
+    use super::*;
+
+    unsafe fn take_json(ptr: *const c_char) -> String {
+        let json = CStr::from_ptr(ptr).to_str().unwrap().to_string();
+        cstr_drop(ptr);
+        json
+    }
+
+    #[rstest]
+    fn test_data_subscribe_new() {
+        let type_name = CString::new("Bar").unwrap();
+        let metadata =
+            CString::new(r#"{"bar_type":"ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL"}"#).unwrap();
+        let venue = CString::new("BINANCE").unwrap();
+        let command_id = UUID4::new();
+
+        let json = unsafe {
+            take_json(data_subscribe_new(
+                type_name.as_ptr(),
+                metadata.as_ptr(),
+                std::ptr::null(),
+                venue.as_ptr(),
+                command_id,
+                UnixNanos::from(1),
+            ))
+        };
+        let command: Subscribe = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(command.command_id, command_id);
+        assert_eq!(command.client_id, None);
+        assert_eq!(command.venue, Some(Venue::from("BINANCE")));
+        assert_eq!(
+            command.data_type,
+            DataDescriptor::bars(BarType::from("ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL"))
+        );
+    }
+
+    #[rstest]
+    fn test_data_request_new_with_unbounded_range() {
+        let type_name = CString::new("QuoteTick").unwrap();
+        let metadata = CString::new(r#"{"instrument_id":"ETHUSDT.BINANCE"}"#).unwrap();
+        let client_id = CString::new("BINANCE").unwrap();
+
+        let json = unsafe {
+            take_json(data_request_new(
+                type_name.as_ptr(),
+                metadata.as_ptr(),
+                client_id.as_ptr(),
+                std::ptr::null(),
+                UUID4::new(),
+                UnixNanos::from(1_000),
+                UnixNanos::default(),
+                0,
+                UnixNanos::from(2_000),
+            ))
+        };
+        let request: DataRequest = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(request.start, Some(UnixNanos::from(1_000)));
+        assert_eq!(request.end, None);
+        assert_eq!(request.limit, None);
+    }
+}
@@ -15,7 +15,12 @@
 
 //! C foreign function interface (FFI) from `cbindgen`.
 
+pub mod cache;
 pub mod clock;
+pub mod component;
 pub mod enums;
 pub mod logging;
+pub mod messages;
+pub mod msgbus;
+pub mod throttler;
 pub mod timer;
@@ -0,0 +1,246 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi::{cvec::CVec, string::cstr_to_ustr};
+
+use crate::msgbus::{is_matching, registry::SubscriptionRegistry};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`SubscriptionRegistry`].
+///
+/// This struct wraps `SubscriptionRegistry` in a way that makes it compatible with C function
+/// calls, enabling interaction with `SubscriptionRegistry` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `SubscriptionRegistry_API` to be
+/// dereferenced to `SubscriptionRegistry`, providing access to `SubscriptionRegistry`'s methods
+/// without having to manually access the underlying `SubscriptionRegistry` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct SubscriptionRegistry_API(Box<SubscriptionRegistry>);
+
+impl Deref for SubscriptionRegistry_API {
+    type Target = SubscriptionRegistry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SubscriptionRegistry_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Returns whether the `topic` matches the `pattern` (which may contain `*` and `?` wildcards).
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` and `pattern_ptr` are valid C string pointers.
+#[no_mangle]
+pub unsafe extern "C" fn msgbus_is_matching(
+    topic_ptr: *const c_char,
+    pattern_ptr: *const c_char,
+) -> u8 {
+    let topic = cstr_to_ustr(topic_ptr);
+    let pattern = cstr_to_ustr(pattern_ptr);
+    u8::from(is_matching(&topic, &pattern))
+}
+
+#[no_mangle]
+pub extern "C" fn subscription_registry_new() -> SubscriptionRegistry_API {
+    SubscriptionRegistry_API(Box::new(SubscriptionRegistry::new()))
+}
+
+#[no_mangle]
+pub extern "C" fn subscription_registry_drop(registry: SubscriptionRegistry_API) {
+    drop(registry); // Memory freed here
+}
+
+/// Subscribes the `handle` to the `pattern`, returning 0 if it was already subscribed.
+///
+/// # Safety
+///
+/// - Assumes `pattern_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_registry_subscribe(
+    registry: &mut SubscriptionRegistry_API,
+    pattern_ptr: *const c_char,
+    handle: u64,
+    priority: u8,
+) -> u8 {
+    let pattern = cstr_to_ustr(pattern_ptr);
+    u8::from(registry.subscribe(pattern, handle, priority))
+}
+
+/// Unsubscribes the `handle` from the `pattern`, returning 0 if it was not subscribed.
+///
+/// # Safety
+///
+/// - Assumes `pattern_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_registry_unsubscribe(
+    registry: &mut SubscriptionRegistry_API,
+    pattern_ptr: *const c_char,
+    handle: u64,
+) -> u8 {
+    let pattern = cstr_to_ustr(pattern_ptr);
+    u8::from(registry.unsubscribe(pattern, handle))
+}
+
+/// Returns a `CVec` of the `uint64_t` handles subscribed to patterns matching the `topic`, in
+/// descending priority order, which must be freed with [`vec_handles_drop`].
+///
+/// # Safety
+///
+/// - Assumes `topic_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_registry_matching_handlers(
+    registry: &mut SubscriptionRegistry_API,
+    topic_ptr: *const c_char,
+) -> CVec {
+    let topic = cstr_to_ustr(topic_ptr);
+    registry.matching_handlers(topic).to_vec().into()
+}
+
+/// Returns a `CVec` with a `CVec` of matching handles (as for
+/// [`subscription_registry_matching_handlers`]) for each of the `topics_len` topics, which must
+/// be freed with [`vec_handles_batch_drop`].
+///
+/// # Safety
+///
+/// - Assumes `topics_ptr` points to `topics_len` valid C string pointers.
+#[no_mangle]
+pub unsafe extern "C" fn subscription_registry_matching_handlers_batch(
+    registry: &mut SubscriptionRegistry_API,
+    topics_ptr: *const *const c_char,
+    topics_len: usize,
+) -> CVec {
+    if topics_len == 0 {
+        return CVec::empty();
+    }
+
+    std::slice::from_raw_parts(topics_ptr, topics_len)
+        .iter()
+        .map(|&topic_ptr| subscription_registry_matching_handlers(registry, topic_ptr))
+        .collect::<Vec<CVec>>()
+        .into()
+}
+
+#[no_mangle]
+pub extern "C" fn vec_handles_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    if ptr.is_null() {
+        return; // Empty
+    }
+    let data: Vec<u64> = unsafe { Vec::from_raw_parts(ptr.cast::<u64>(), len, cap) };
+    drop(data); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn vec_handles_batch_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    if ptr.is_null() {
+        return; // Empty
+    }
+    let data: Vec<CVec> = unsafe { Vec::from_raw_parts(ptr.cast::<CVec>(), len, cap) };
+    for v in data {
+        vec_handles_drop(v); // Memory freed here
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    unsafe fn handles(v: &CVec) -> &[u64] {
+        if v.ptr.is_null() {
+            return &[];
+        }
+        std::slice::from_raw_parts(v.ptr.cast::<u64>(), v.len)
+    }
+
+    #[rstest]
+    fn test_msgbus_is_matching() {
+        let topic = CString::new("data.quotes.BINANCE.ETHUSDT").unwrap();
+        let pattern = CString::new("data.quotes.*").unwrap();
+        let other = CString::new("data.trades.*").unwrap();
+
+        unsafe {
+            assert_eq!(msgbus_is_matching(topic.as_ptr(), pattern.as_ptr()), 1);
+            assert_eq!(msgbus_is_matching(topic.as_ptr(), other.as_ptr()), 0);
+        }
+    }
+
+    #[rstest]
+    fn test_subscription_registry_batch_query() {
+        let quotes = CString::new("data.quotes.*").unwrap();
+        let all = CString::new("data.*").unwrap();
+        let topics = [
+            CString::new("data.quotes.BINANCE.ETHUSDT").unwrap(),
+            CString::new("data.trades.BINANCE.ETHUSDT").unwrap(),
+            CString::new("events.order.S-001").unwrap(),
+        ];
+        let topic_ptrs: Vec<*const c_char> = topics.iter().map(|t| t.as_ptr()).collect();
+
+        let mut registry = subscription_registry_new();
+        unsafe {
+            assert_eq!(
+                subscription_registry_subscribe(&mut registry, quotes.as_ptr(), 1, 0),
+                1
+            );
+            assert_eq!(
+                subscription_registry_subscribe(&mut registry, all.as_ptr(), 2, 1),
+                1
+            );
+            assert_eq!(
+                subscription_registry_subscribe(&mut registry, all.as_ptr(), 2, 1),
+                0
+            );
+
+            let batch = subscription_registry_matching_handlers_batch(
+                &mut registry,
+                topic_ptrs.as_ptr(),
+                topic_ptrs.len(),
+            );
+            let results = std::slice::from_raw_parts(batch.ptr.cast::<CVec>(), batch.len);
+            assert_eq!(handles(&results[0]), [2, 1]);
+            assert_eq!(handles(&results[1]), [2]);
+            assert!(handles(&results[2]).is_empty());
+            vec_handles_batch_drop(batch);
+
+            assert_eq!(
+                subscription_registry_unsubscribe(&mut registry, all.as_ptr(), 2),
+                1
+            );
+            let matching =
+                subscription_registry_matching_handlers(&mut registry, topics[0].as_ptr());
+            assert_eq!(handles(&matching), [1]);
+            vec_handles_drop(matching);
+        }
+        subscription_registry_drop(registry);
+    }
+}
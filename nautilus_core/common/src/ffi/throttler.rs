@@ -0,0 +1,171 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi::cvec::CVec;
+use nautilus_model::data::deltas::{OrderBookDeltas, OrderBookDeltas_API};
+
+use crate::throttler::conflation::ConflatingThrottler;
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`ConflatingThrottler`] of
+/// [`OrderBookDeltas`].
+///
+/// This struct wraps `ConflatingThrottler` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ConflatingThrottler` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `DeltasThrottler_API` to be
+/// dereferenced to `ConflatingThrottler`, providing access to `ConflatingThrottler`'s methods
+/// without having to manually access the underlying `ConflatingThrottler` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct DeltasThrottler_API(Box<ConflatingThrottler<OrderBookDeltas>>);
+
+impl Deref for DeltasThrottler_API {
+    type Target = ConflatingThrottler<OrderBookDeltas>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for DeltasThrottler_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn throttle_new(interval_ns: u64) -> DeltasThrottler_API {
+    DeltasThrottler_API(Box::new(ConflatingThrottler::new(interval_ns)))
+}
+
+#[no_mangle]
+pub extern "C" fn throttle_drop(throttler: DeltasThrottler_API) {
+    drop(throttler); // Memory freed here
+}
+
+/// Pushes a copy of `deltas`, merging it with any pending deltas for the same instrument.
+#[no_mangle]
+pub extern "C" fn throttle_push(throttler: &mut DeltasThrottler_API, deltas: &OrderBookDeltas_API) {
+    throttler.push((**deltas).clone());
+}
+
+/// Returns a `CVec` of `OrderBookDeltas_API` for the instruments whose interval has elapsed at
+/// `ts_now`, which must be freed with [`vec_throttled_deltas_drop`].
+#[no_mangle]
+pub extern "C" fn throttle_poll(throttler: &mut DeltasThrottler_API, ts_now: u64) -> CVec {
+    into_cvec(throttler.poll(ts_now.into()))
+}
+
+/// Returns a `CVec` of `OrderBookDeltas_API` for all pending deltas, regardless of the interval,
+/// which must be freed with [`vec_throttled_deltas_drop`].
+#[no_mangle]
+pub extern "C" fn throttle_flush(throttler: &mut DeltasThrottler_API, ts_now: u64) -> CVec {
+    into_cvec(throttler.flush(ts_now.into()))
+}
+
+#[no_mangle]
+pub extern "C" fn throttle_qsize(throttler: &DeltasThrottler_API) -> usize {
+    throttler.qsize()
+}
+
+#[no_mangle]
+pub extern "C" fn throttle_dropped_count(throttler: &DeltasThrottler_API) -> usize {
+    throttler.dropped_count()
+}
+
+#[no_mangle]
+pub extern "C" fn vec_throttled_deltas_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    if ptr.is_null() {
+        return; // Empty
+    }
+    let data: Vec<OrderBookDeltas_API> =
+        unsafe { Vec::from_raw_parts(ptr.cast::<OrderBookDeltas_API>(), len, cap) };
+    drop(data); // Memory freed here
+}
+
+fn into_cvec(deltas: Vec<OrderBookDeltas>) -> CVec {
+    deltas
+        .into_iter()
+        .map(OrderBookDeltas_API::new)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{delta::OrderBookDelta, order::BookOrder},
+        enums::{BookAction, OrderSide},
+        identifiers::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    fn deltas_api(price: &str, sequence: u64) -> OrderBookDeltas_API {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from("1"),
+            sequence,
+        );
+        let delta = OrderBookDelta::new(
+            instrument_id,
+            BookAction::Update,
+            order,
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        );
+        OrderBookDeltas_API::new(OrderBookDeltas::new(instrument_id, vec![delta]))
+    }
+
+    #[rstest]
+    fn test_throttle_push_poll() {
+        let mut throttler = throttle_new(1_000);
+        for sequence in 1..=5 {
+            throttle_push(&mut throttler, &deltas_api("100.00", sequence));
+        }
+        assert_eq!(throttle_qsize(&throttler), 1);
+        assert_eq!(throttle_dropped_count(&throttler), 4);
+
+        let polled = throttle_poll(&mut throttler, 1_000);
+        assert_eq!(polled.len, 1);
+        let emitted = unsafe { &*polled.ptr.cast::<OrderBookDeltas_API>() };
+        assert_eq!(emitted.deltas.len(), 5);
+        assert_eq!(emitted.sequence, 5);
+        vec_throttled_deltas_drop(polled);
+
+        throttle_push(&mut throttler, &deltas_api("101.00", 6));
+        let polled = throttle_poll(&mut throttler, 1_500);
+        assert_eq!(polled.len, 0);
+        vec_throttled_deltas_drop(polled);
+
+        let flushed = throttle_flush(&mut throttler, 1_500);
+        assert_eq!(flushed.len, 1);
+        vec_throttled_deltas_drop(flushed);
+
+        throttle_drop(throttler);
+    }
+}
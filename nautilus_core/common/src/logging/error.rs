@@ -0,0 +1,128 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Errors associated with configuring and initializing logging.
+
+use std::{env::VarError, io, path::PathBuf};
+
+use crate::enums::LogStatus;
+
+#[derive(thiserror::Error, Debug)]
+pub enum LogError {
+    #[error("Invalid log level, was {0:?}")]
+    InvalidLevel(String),
+    #[error("Error parsing `LoggerConfig` spec: invalid {key} {value:?}, {reason}")]
+    ConfigParse {
+        key: String,
+        value: String,
+        reason: String,
+    },
+    #[error("Error parsing logging {name} JSON: {reason}")]
+    JsonParse { name: String, reason: String },
+    #[error("Error creating log directory {path:?}: {source}")]
+    CreateDirectory { path: PathBuf, source: io::Error },
+    #[error("Error opening log file {path:?}: {source}")]
+    FileOpen { path: PathBuf, source: io::Error },
+    #[error("Error reading `NAUTILUS_LOG` environment variable: {0}")]
+    EnvVar(#[from] VarError),
+    #[error("Cannot set logger: {0}")]
+    AlreadyInitialized(String),
+}
+
+impl LogError {
+    /// Returns the status code of the error, for reporting across the FFI boundary.
+    #[must_use]
+    pub const fn status(&self) -> LogStatus {
+        match self {
+            Self::InvalidLevel(_) => LogStatus::InvalidLevel,
+            Self::ConfigParse { .. } => LogStatus::ConfigParse,
+            Self::JsonParse { .. } => LogStatus::JsonParse,
+            Self::CreateDirectory { .. } => LogStatus::CreateDirectory,
+            Self::FileOpen { .. } => LogStatus::FileOpen,
+            Self::EnvVar(_) => LogStatus::EnvVar,
+            Self::AlreadyInitialized(_) => LogStatus::AlreadyInitialized,
+        }
+    }
+}
+
+impl From<log::SetLoggerError> for LogError {
+    fn from(e: log::SetLoggerError) -> Self {
+        Self::AlreadyInitialized(e.to_string())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(LogError::InvalidLevel("Bogus".to_string()), LogStatus::InvalidLevel)]
+    #[case(
+        LogError::ConfigParse {
+            key: "queue capacity".to_string(),
+            value: "lots".to_string(),
+            reason: "invalid digit found in string".to_string(),
+        },
+        LogStatus::ConfigParse
+    )]
+    #[case(
+        LogError::JsonParse {
+            name: "component_levels".to_string(),
+            reason: "EOF while parsing a value".to_string(),
+        },
+        LogStatus::JsonParse
+    )]
+    #[case(
+        LogError::CreateDirectory {
+            path: PathBuf::from("logs"),
+            source: io::ErrorKind::PermissionDenied.into(),
+        },
+        LogStatus::CreateDirectory
+    )]
+    #[case(
+        LogError::FileOpen {
+            path: PathBuf::from("logs/trader.log"),
+            source: io::ErrorKind::NotFound.into(),
+        },
+        LogStatus::FileOpen
+    )]
+    #[case(LogError::from(VarError::NotPresent), LogStatus::EnvVar)]
+    #[case(
+        LogError::AlreadyInitialized("logger already set".to_string()),
+        LogStatus::AlreadyInitialized
+    )]
+    fn test_status(#[case] error: LogError, #[case] expected: LogStatus) {
+        assert_eq!(error.status(), expected);
+    }
+
+    #[rstest]
+    fn test_display() {
+        let error = LogError::ConfigParse {
+            key: "queue capacity".to_string(),
+            value: "lots".to_string(),
+            reason: "invalid digit found in string".to_string(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Error parsing `LoggerConfig` spec: invalid queue capacity \"lots\", invalid digit found in string"
+        );
+    }
+}
@@ -14,35 +14,301 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::HashMap,
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
     env,
     fmt::Display,
     str::FromStr,
-    sync::{atomic::Ordering, mpsc::SendError},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{SendError, TrySendError},
+        Arc, Mutex,
+    },
+    time::{Duration, UNIX_EPOCH},
 };
 
+use chrono::{DateTime, FixedOffset, Local, Offset, SecondsFormat, Utc};
 use indexmap::IndexMap;
 use log::{
     kv::{ToValue, Value},
     set_boxed_logger, set_max_level, Level, LevelFilter, Log, STATIC_MAX_LEVEL,
 };
 use nautilus_core::{
-    datetime::unix_nanos_to_iso8601,
+    datetime::{
+        unix_nanos_to_iso8601, NANOSECONDS_IN_MICROSECOND, NANOSECONDS_IN_MILLISECOND,
+        NANOSECONDS_IN_SECOND,
+    },
     nanos::UnixNanos,
     time::{get_atomic_clock_realtime, get_atomic_clock_static},
     uuid::UUID4,
 };
 use nautilus_model::identifiers::TraderId;
 use serde::{Deserialize, Serialize, Serializer};
+use sysinfo::System;
 use ustr::Ustr;
 
-use super::{LOGGING_BYPASSED, LOGGING_REALTIME};
+use super::{
+    error::LogError, map_log_level_to_filter, parse_level_filter_str, LOGGING_BYPASSED,
+    LOGGING_REALTIME,
+};
 use crate::{
     enums::{LogColor, LogLevel},
-    logging::writer::{FileWriter, FileWriterConfig, LogWriter, StderrWriter, StdoutWriter},
+    logging::{
+        ratelimit::{ByteLimitPolicy, LogByteLimiter, LogRateLimiter, RateLimitConfig},
+        writer::{
+            FileSinkConfig, FileWriter, FileWriterConfig, LogFileFormat, LogWriter, StderrWriter,
+            StdoutWriter,
+        },
+    },
 };
 
 const LOGGING: &str = "logging";
+const BANNER_COMPONENT: &str = "Logger";
+const BANNER_EVENT: &str = "logger_started";
+
+/// The precision of the timestamp written with each log line.
+///
+/// Timestamps are truncated (never rounded) when reduced to a coarser precision, so a log line
+/// is never stamped later than the event it records.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TimestampPrecision {
+    /// Nanoseconds.
+    #[default]
+    Nanos,
+    /// Microseconds.
+    Micros,
+    /// Milliseconds.
+    Millis,
+    /// Seconds.
+    Seconds,
+}
+
+impl TimestampPrecision {
+    /// Returns the number of nanoseconds in one unit of this precision.
+    #[must_use]
+    pub const fn nanos_per_unit(self) -> u64 {
+        match self {
+            Self::Nanos => 1,
+            Self::Micros => NANOSECONDS_IN_MICROSECOND,
+            Self::Millis => NANOSECONDS_IN_MILLISECOND,
+            Self::Seconds => NANOSECONDS_IN_SECOND,
+        }
+    }
+
+    /// Returns the number of whole units of this precision since the UNIX epoch for `timestamp`.
+    #[must_use]
+    pub const fn units_since_epoch(self, timestamp: UnixNanos) -> u64 {
+        timestamp.as_u64() / self.nanos_per_unit()
+    }
+
+    /// Returns `timestamp` truncated to this precision.
+    #[must_use]
+    pub fn truncate(self, timestamp: UnixNanos) -> UnixNanos {
+        UnixNanos::from(self.units_since_epoch(timestamp) * self.nanos_per_unit())
+    }
+
+    const fn seconds_format(self) -> SecondsFormat {
+        match self {
+            Self::Nanos => SecondsFormat::Nanos,
+            Self::Micros => SecondsFormat::Micros,
+            Self::Millis => SecondsFormat::Millis,
+            Self::Seconds => SecondsFormat::Secs,
+        }
+    }
+}
+
+impl FromStr for TimestampPrecision {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "nanos" => Ok(Self::Nanos),
+            "micros" => Ok(Self::Micros),
+            "millis" => Ok(Self::Millis),
+            "seconds" => Ok(Self::Seconds),
+            _ => anyhow::bail!(
+                "Invalid timestamp precision, must be 'nanos', 'micros', 'millis' or 'seconds', was {s:?}"
+            ),
+        }
+    }
+}
+
+/// The representation of the timestamp written with each log line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TimestampStyle {
+    /// ISO 8601 (RFC 3339), e.g. `2022-04-15T05:20:00.123456789Z` with nanosecond precision.
+    #[default]
+    Iso8601,
+    /// An integer count of units since the UNIX epoch, e.g. `1650000000123` with millisecond
+    /// precision.
+    Unix,
+}
+
+/// The format of the timestamp written with each log line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TimestampFormat {
+    /// The representation of the timestamp.
+    pub style: TimestampStyle,
+    /// The precision of the timestamp.
+    pub precision: TimestampPrecision,
+}
+
+impl TimestampFormat {
+    /// Creates a new ISO 8601 [`TimestampFormat`] with the given `precision`.
+    #[must_use]
+    pub const fn iso8601(precision: TimestampPrecision) -> Self {
+        Self {
+            style: TimestampStyle::Iso8601,
+            precision,
+        }
+    }
+
+    /// Creates a new UNIX epoch [`TimestampFormat`] with the given `precision`.
+    #[must_use]
+    pub const fn unix(precision: TimestampPrecision) -> Self {
+        Self {
+            style: TimestampStyle::Unix,
+            precision,
+        }
+    }
+
+    /// Returns the UTC rendering of `timestamp` in this format.
+    #[must_use]
+    pub fn format(&self, timestamp: UnixNanos) -> String {
+        match self.style {
+            TimestampStyle::Iso8601 => LogTimezone::Utc.format_iso8601(timestamp, self.precision),
+            TimestampStyle::Unix => self.precision.units_since_epoch(timestamp).to_string(),
+        }
+    }
+}
+
+impl FromStr for TimestampFormat {
+    type Err = anyhow::Error;
+
+    /// Parses a format from `iso8601` or `unix` (with nanosecond precision), or `unix_millis`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "iso8601" => Ok(Self::iso8601(TimestampPrecision::Nanos)),
+            "unix" => Ok(Self::unix(TimestampPrecision::Nanos)),
+            "unix_millis" => Ok(Self::unix(TimestampPrecision::Millis)),
+            _ => anyhow::bail!(
+                "Invalid timestamp format, must be 'iso8601', 'unix' or 'unix_millis', was {s:?}"
+            ),
+        }
+    }
+}
+
+/// The timezone in which timestamps are rendered for stdout and stderr.
+///
+/// A local timezone is resolved to a fixed UTC offset when the value is created and never
+/// re-read, so the rendering does not change with daylight saving transitions and backtests
+/// remain deterministic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LogTimezone {
+    /// Coordinated Universal Time (the same rendering as files).
+    #[default]
+    Utc,
+    /// A fixed offset from UTC.
+    Offset(FixedOffset),
+}
+
+impl LogTimezone {
+    /// Returns the current offset of the local timezone from UTC.
+    #[must_use]
+    pub fn local() -> Self {
+        Self::Offset(Local::now().offset().fix())
+    }
+
+    /// Returns the ISO 8601 (RFC 3339) rendering of `timestamp` in this timezone, truncated to
+    /// the given `precision`.
+    #[must_use]
+    pub fn format_iso8601(&self, timestamp: UnixNanos, precision: TimestampPrecision) -> String {
+        let datetime = DateTime::<Utc>::from(
+            UNIX_EPOCH + Duration::from_nanos(precision.truncate(timestamp).as_u64()),
+        );
+        match self {
+            Self::Utc => datetime.to_rfc3339_opts(precision.seconds_format(), true),
+            Self::Offset(offset) => datetime
+                .with_timezone(offset)
+                .to_rfc3339_opts(precision.seconds_format(), true),
+        }
+    }
+}
+
+impl FromStr for LogTimezone {
+    type Err = anyhow::Error;
+
+    /// Parses a timezone from `utc`, `local` or an offset such as `+08:00`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "utc" => Ok(Self::Utc),
+            "local" => Ok(Self::local()),
+            _ => s.parse::<FixedOffset>().map(Self::Offset).map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid timezone, must be 'utc', 'local' or an offset such as '+08:00', was {s:?}"
+                )
+            }),
+        }
+    }
+}
+
+/// The terminator appended to each written log line.
+///
+/// Defaults to the platform-native terminator (`\r\n` on Windows, `\n` elsewhere).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineTerminator {
+    /// Line feed (`\n`).
+    Lf,
+    /// Carriage return and line feed (`\r\n`).
+    CrLf,
+    /// No terminator.
+    Empty,
+}
+
+impl Default for LineTerminator {
+    fn default() -> Self {
+        Self::native()
+    }
+}
+
+impl LineTerminator {
+    /// Returns the platform-native line terminator.
+    #[must_use]
+    pub const fn native() -> Self {
+        if cfg!(windows) {
+            Self::CrLf
+        } else {
+            Self::Lf
+        }
+    }
+
+    /// Returns the terminator as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+            Self::Empty => "",
+        }
+    }
+}
+
+impl FromStr for LineTerminator {
+    type Err = anyhow::Error;
+
+    /// Parses a line terminator, accepting either the literal terminator or its escaped form
+    /// (e.g. `"\r\n"` or `"\\r\\n"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "\n" | "\\n" => Ok(Self::Lf),
+            "\r\n" | "\\r\\n" => Ok(Self::CrLf),
+            "" => Ok(Self::Empty),
+            _ => anyhow::bail!(
+                "Invalid line terminator, must be '\\n', '\\r\\n' or empty, was {s:?}"
+            ),
+        }
+    }
+}
 
 #[cfg_attr(
     feature = "python",
@@ -55,11 +321,51 @@ pub struct LoggerConfig {
     /// Maximum log level to write to file (disabled is `Off`).
     pub fileout_level: LevelFilter,
     /// Per-component log levels, allowing finer-grained control.
+    ///
+    /// Components are hierarchical by dotted name, so a level for `DataEngine` also applies to
+    /// `DataEngine.Binance` unless that component has a level of its own.
     component_level: HashMap<Ustr, LevelFilter>,
+    /// Per-component colors, used for log events with the default color below warning level.
+    pub component_colors: HashMap<Ustr, LogColor>,
     /// If logger is using ANSI color codes.
     pub is_colored: bool,
     /// If the configuration should be printed to stdout at initialization.
     pub print_config: bool,
+    /// If error events should be written to stderr.
+    pub errors_to_stderr: bool,
+    /// If error events should also be written to stdout (always the case if not written to stderr).
+    pub errors_to_stdout: bool,
+    /// Limits on repeated identical messages, per component.
+    pub rate_limits: RateLimitConfig,
+    /// The terminator appended to each line written to stdout, stderr and file.
+    pub line_terminator: LineTerminator,
+    /// The format of the timestamp written with each line.
+    pub timestamp_format: TimestampFormat,
+    /// The timezone of ISO 8601 timestamps written to stdout and stderr (files are always UTC).
+    pub stdout_timezone: LogTimezone,
+    /// If the source file and line of each log event should be included.
+    pub with_source_location: bool,
+    /// If the sequence number of each log event should be included in text output (it is
+    /// always included in JSON output).
+    pub with_sequence_number: bool,
+    /// Additional file sinks, each filtered at its own level.
+    pub file_sinks: Vec<FileSinkConfig>,
+    /// Maximum number of log events queued for the 'logging' thread (unbounded if `None`).
+    ///
+    /// When the queue is full, new log lines are dropped rather than blocking the caller.
+    pub queue_capacity: Option<usize>,
+    /// Maximum bytes written to each log file per second (unlimited if `None`).
+    ///
+    /// The budget is measured by log event timestamps, so throttling is deterministic when
+    /// backtesting.
+    pub max_bytes_per_sec: Option<u64>,
+    /// What happens to lines which would exceed the `max_bytes_per_sec` budget.
+    pub byte_limit_policy: ByteLimitPolicy,
+    /// Size in bytes to pre-allocate each log file to (and to grow it by when full), reducing
+    /// fragmentation from many small appends (no pre-allocation if `None`).
+    ///
+    /// The file is truncated to the bytes actually written when it is closed or rotated.
+    pub preallocate_bytes: Option<u64>,
 }
 
 impl Default for LoggerConfig {
@@ -69,8 +375,22 @@ impl Default for LoggerConfig {
             stdout_level: LevelFilter::Info,
             fileout_level: LevelFilter::Off,
             component_level: HashMap::new(),
+            component_colors: HashMap::new(),
             is_colored: false,
             print_config: false,
+            errors_to_stderr: true,
+            errors_to_stdout: false,
+            rate_limits: RateLimitConfig::default(),
+            line_terminator: LineTerminator::default(),
+            timestamp_format: TimestampFormat::default(),
+            stdout_timezone: LogTimezone::default(),
+            with_source_location: false,
+            with_sequence_number: false,
+            file_sinks: Vec::new(),
+            queue_capacity: None,
+            max_bytes_per_sec: None,
+            byte_limit_policy: ByteLimitPolicy::default(),
+            preallocate_bytes: None,
         }
     }
 }
@@ -78,7 +398,7 @@ impl Default for LoggerConfig {
 impl LoggerConfig {
     /// Creates a new [`LoggerConfig`] instance.
     #[must_use]
-    pub const fn new(
+    pub fn new(
         stdout_level: LevelFilter,
         fileout_level: LevelFilter,
         component_level: HashMap<Ustr, LevelFilter>,
@@ -89,58 +409,283 @@ impl LoggerConfig {
             stdout_level,
             fileout_level,
             component_level,
+            component_colors: HashMap::new(),
             is_colored,
             print_config,
+            errors_to_stderr: true,
+            errors_to_stdout: false,
+            rate_limits: RateLimitConfig::default(),
+            line_terminator: LineTerminator::default(),
+            timestamp_format: TimestampFormat::default(),
+            stdout_timezone: LogTimezone::default(),
+            with_source_location: false,
+            with_sequence_number: false,
+            file_sinks: Vec::new(),
+            queue_capacity: None,
+            max_bytes_per_sec: None,
+            byte_limit_policy: ByteLimitPolicy::default(),
+            preallocate_bytes: None,
         }
     }
 
+    /// Sets the limits on repeated identical messages, per component.
+    #[must_use]
+    pub fn with_rate_limits(mut self, rate_limits: RateLimitConfig) -> Self {
+        self.rate_limits = rate_limits;
+        self
+    }
+
+    /// Sets the per-component colors for log events with the default color.
+    #[must_use]
+    pub fn with_component_colors(mut self, component_colors: HashMap<Ustr, LogColor>) -> Self {
+        self.component_colors = component_colors;
+        self
+    }
+
+    /// Sets the terminator appended to each written log line.
+    #[must_use]
+    pub const fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Sets the format of the timestamp written with each log line.
+    #[must_use]
+    pub const fn with_timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// Sets the timezone of ISO 8601 timestamps written to stdout and stderr.
+    #[must_use]
+    pub const fn with_stdout_timezone(mut self, stdout_timezone: LogTimezone) -> Self {
+        self.stdout_timezone = stdout_timezone;
+        self
+    }
+
+    /// Sets whether the source file and line of each log event should be included.
+    #[must_use]
+    pub const fn with_source_location(mut self, with_source_location: bool) -> Self {
+        self.with_source_location = with_source_location;
+        self
+    }
+
+    /// Sets whether the sequence number of each log event should be included in text output.
+    #[must_use]
+    pub const fn with_sequence_number(mut self, with_sequence_number: bool) -> Self {
+        self.with_sequence_number = with_sequence_number;
+        self
+    }
+
+    /// Sets the additional file sinks, each filtered at its own level.
+    #[must_use]
+    pub fn with_file_sinks(mut self, file_sinks: Vec<FileSinkConfig>) -> Self {
+        self.file_sinks = file_sinks;
+        self
+    }
+
+    /// Sets the maximum number of log events queued for the 'logging' thread.
+    #[must_use]
+    pub const fn with_queue_capacity(mut self, queue_capacity: Option<usize>) -> Self {
+        self.queue_capacity = queue_capacity;
+        self
+    }
+
+    /// Sets the maximum bytes written to each log file per second, and the policy for excess lines.
+    #[must_use]
+    pub const fn with_max_bytes_per_sec(
+        mut self,
+        max_bytes_per_sec: Option<u64>,
+        byte_limit_policy: ByteLimitPolicy,
+    ) -> Self {
+        self.max_bytes_per_sec = max_bytes_per_sec;
+        self.byte_limit_policy = byte_limit_policy;
+        self
+    }
+
+    /// Sets the size in bytes to pre-allocate each log file to.
+    #[must_use]
+    pub const fn with_preallocate_bytes(mut self, preallocate_bytes: Option<u64>) -> Self {
+        self.preallocate_bytes = preallocate_bytes;
+        self
+    }
+
+    /// Returns the most verbose level written to any file (the main file or a sink).
     #[must_use]
-    pub fn from_spec(spec: &str) -> Self {
+    pub fn max_file_level(&self) -> LevelFilter {
+        self.file_sinks
+            .iter()
+            .map(|sink| sink.level)
+            .fold(self.fileout_level, std::cmp::max)
+    }
+
+    /// Returns the most verbose level accepted by any output (stdout, file or sink), so events
+    /// above it can be skipped without further filtering.
+    ///
+    /// Error events are always written, so this is never less verbose than `Error`. Component
+    /// levels only narrow what a component writes, so do not affect the result.
+    #[must_use]
+    pub fn max_level(&self) -> LevelFilter {
+        self.max_file_level()
+            .max(self.stdout_level)
+            .max(LevelFilter::Error)
+    }
+
+    /// Returns whether an event at `level` from `component` would be written to any output,
+    /// applying the per-component level (if any) and then the stdout, stderr and file levels.
+    #[must_use]
+    pub fn would_log(&self, level: Level, component: &Ustr) -> bool {
+        if let Some(component_level) = find_component_level(&self.component_level, component) {
+            if level > component_level {
+                return false;
+            }
+        }
+
+        (level == Level::Error && (self.errors_to_stderr || self.errors_to_stdout))
+            || level <= self.stdout_level
+            || level <= self.max_file_level()
+    }
+
+    /// Parses a logger configuration from a `;` separated spec of options, levels
+    /// (e.g. `stdout=Info`, `fileout=Debug`) and per-component levels (e.g. `RiskEngine=Error`).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a level is not a valid log level name.
+    /// - If an option is unrecognized or has an invalid value.
+    pub fn from_spec(spec: &str) -> Result<Self, LogError> {
         let Self {
             mut stdout_level,
             mut fileout_level,
             mut component_level,
+            component_colors,
             mut is_colored,
             mut print_config,
+            mut errors_to_stderr,
+            mut errors_to_stdout,
+            rate_limits,
+            mut line_terminator,
+            mut timestamp_format,
+            mut stdout_timezone,
+            mut with_source_location,
+            mut with_sequence_number,
+            file_sinks,
+            mut queue_capacity,
+            mut max_bytes_per_sec,
+            mut byte_limit_policy,
+            mut preallocate_bytes,
         } = Self::default();
-        spec.split(';').for_each(|kv| {
-            if kv == "is_colored" {
-                is_colored = true;
-            } else if kv == "print_config" {
-                print_config = true;
-            } else {
-                let mut kv = kv.split('=');
-                if let (Some(k), Some(Ok(lvl))) = (kv.next(), kv.next().map(LevelFilter::from_str))
-                {
-                    if k == "stdout" {
-                        stdout_level = lvl;
-                    } else if k == "fileout" {
-                        fileout_level = lvl;
-                    } else {
-                        component_level.insert(Ustr::from(k), lvl);
+        let mut timestamp_precision = None;
+
+        for kv in spec.split(';') {
+            match kv.split_once('=') {
+                None => match kv {
+                    "" => {}
+                    "is_colored" => is_colored = true,
+                    "print_config" => print_config = true,
+                    "errors_to_stdout" => errors_to_stdout = true,
+                    "no_errors_to_stderr" => errors_to_stderr = false,
+                    "with_source_location" => with_source_location = true,
+                    "with_sequence_number" => with_sequence_number = true,
+                    _ => {
+                        return Err(LogError::ConfigParse {
+                            key: "option".to_string(),
+                            value: kv.to_string(),
+                            reason: "unrecognized option".to_string(),
+                        })
+                    }
+                },
+                Some(("line_terminator", value)) => {
+                    line_terminator = parse_spec_value("line terminator", value)?;
+                }
+                Some(("timestamp_format", value)) => {
+                    timestamp_format = parse_spec_value("timestamp format", value)?;
+                }
+                Some(("timestamp_precision", value)) => {
+                    timestamp_precision = Some(parse_spec_value("timestamp precision", value)?);
+                }
+                Some(("stdout_timezone", value)) => {
+                    stdout_timezone = parse_spec_value("stdout timezone", value)?;
+                }
+                Some(("queue_capacity", value)) => {
+                    queue_capacity = Some(parse_spec_value("queue capacity", value)?);
+                }
+                Some(("max_bytes_per_sec", value)) => {
+                    max_bytes_per_sec = Some(parse_spec_value("max bytes per sec", value)?);
+                }
+                Some(("preallocate_bytes", value)) => {
+                    preallocate_bytes = Some(parse_spec_value("preallocate bytes", value)?);
+                }
+                Some(("byte_limit_policy", value)) => {
+                    byte_limit_policy = parse_spec_value("byte limit policy", value)?;
+                }
+                Some((key, value)) => {
+                    let level = parse_level_filter_str(value)?;
+                    match key {
+                        "stdout" => stdout_level = level,
+                        "fileout" => fileout_level = level,
+                        _ => {
+                            component_level.insert(Ustr::from(key), level);
+                        }
                     }
                 }
             }
-        });
+        }
 
-        Self {
+        // An explicit precision applies regardless of the order of the keys
+        if let Some(precision) = timestamp_precision {
+            timestamp_format.precision = precision;
+        }
+
+        Ok(Self {
             stdout_level,
             fileout_level,
             component_level,
+            component_colors,
             is_colored,
             print_config,
-        }
+            errors_to_stderr,
+            errors_to_stdout,
+            rate_limits,
+            line_terminator,
+            timestamp_format,
+            stdout_timezone,
+            with_source_location,
+            with_sequence_number,
+            file_sinks,
+            queue_capacity,
+            max_bytes_per_sec,
+            byte_limit_policy,
+            preallocate_bytes,
+        })
     }
 
-    #[must_use]
-    pub fn from_env() -> Self {
-        match env::var("NAUTILUS_LOG") {
-            Ok(spec) => Self::from_spec(&spec),
-            Err(e) => panic!("Error parsing `LoggerConfig` spec: {e}"),
-        }
+    /// Parses a logger configuration from the spec in the `NAUTILUS_LOG` environment variable.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `NAUTILUS_LOG` environment variable is not set or not valid unicode.
+    /// - If the spec is invalid, see [`LoggerConfig::from_spec`].
+    pub fn from_env() -> Result<Self, LogError> {
+        Self::from_spec(&env::var("NAUTILUS_LOG")?)
     }
 }
 
+/// Parses the `value` of a `LoggerConfig` spec option, described by `key` in any error.
+fn parse_spec_value<T>(key: &str, value: &str) -> Result<T, LogError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|e: T::Err| LogError::ConfigParse {
+        key: key.to_string(),
+        value: value.to_string(),
+        reason: e.to_string(),
+    })
+}
+
 /// A high-performance logger utilizing a MPSC channel under the hood.
 ///
 /// A logger is initialized with a [`LoggerConfig`] to set up different logging levels for
@@ -151,7 +696,42 @@ pub struct Logger {
     /// Configuration for logging levels and behavior.
     pub config: LoggerConfig,
     /// Transmitter for sending log events to the 'logging' thread.
-    tx: std::sync::mpsc::Sender<LogEvent>,
+    tx: LogSender,
+    /// The count of log events dropped because the queue was full.
+    dropped_count: Arc<AtomicU64>,
+    /// The sequence number to assign to the next log event sent.
+    sequence: AtomicU64,
+    /// The most verbose level accepted by any output, cached from the `config` on creation.
+    max_level: LevelFilter,
+    /// The flag for whether logging is bypassed (the global `LOGGING_BYPASSED` flag).
+    bypassed: &'static AtomicBool,
+}
+
+/// Transmitter for the 'logging' thread channel, which is bounded if a queue capacity is configured.
+#[derive(Debug, Clone)]
+enum LogSender {
+    Unbounded(std::sync::mpsc::Sender<LogEvent>),
+    Bounded(std::sync::mpsc::SyncSender<LogEvent>),
+}
+
+impl LogSender {
+    /// Sends the event, blocking on a bounded channel until there is space in the queue.
+    fn send(&self, event: LogEvent) -> Result<(), SendError<LogEvent>> {
+        match self {
+            Self::Unbounded(tx) => tx.send(event),
+            Self::Bounded(tx) => tx.send(event),
+        }
+    }
+
+    /// Sends the event without blocking, failing with `TrySendError::Full` if the queue is full.
+    fn try_send(&self, event: LogEvent) -> Result<(), TrySendError<LogEvent>> {
+        match self {
+            Self::Unbounded(tx) => tx
+                .send(event)
+                .map_err(|SendError(e)| TrySendError::Disconnected(e)),
+            Self::Bounded(tx) => tx.try_send(event),
+        }
+    }
 }
 
 /// Represents a type of log event.
@@ -160,6 +740,8 @@ pub enum LogEvent {
     Log(LogLine),
     /// A command to flush all logger buffers.
     Flush,
+    /// A command to reopen the log file at its configured path (e.g. after external rotation).
+    Reopen,
 }
 
 /// Represents a log event which includes a message.
@@ -173,11 +755,39 @@ pub struct LogLine {
     pub component: Ustr,
     /// The log message content.
     pub message: String,
+    /// The source file the log event originated from (if source locations are enabled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<Ustr>,
+    /// The source line the log event originated from (if source locations are enabled).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// The sequence number assigned by the logger when the event was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seq: Option<u64>,
+}
+
+impl LogLine {
+    /// Returns the `(file:line)` source location suffix, or an empty string if not set.
+    #[must_use]
+    pub fn location_suffix(&self) -> String {
+        match (self.file, self.line) {
+            (Some(file), Some(line)) => format!(" ({file}:{line})"),
+            (Some(file), None) => format!(" ({file})"),
+            _ => String::new(),
+        }
+    }
 }
 
 impl Display for LogLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "[{}] {}: {}", self.level, self.component, self.message)
+        write!(
+            f,
+            "[{}] {}: {}{}",
+            self.level,
+            self.component,
+            self.message,
+            self.location_suffix(),
+        )
     }
 }
 
@@ -194,10 +804,22 @@ pub struct LogLineWrapper {
     cache: Option<String>,
     /// Cached colored string representation of the log line.
     colored: Option<String>,
+    /// Cached plain string representation of the log line for stdout and stderr.
+    console: Option<String>,
     /// The timestamp of when the log event occurred.
+    ts_event: UnixNanos,
+    /// The formatted timestamp of when the log event occurred.
     timestamp: String,
+    /// The formatted timestamp for stdout and stderr, if in a timezone other than UTC.
+    console_timestamp: Option<String>,
+    /// The format of the timestamp.
+    timestamp_format: TimestampFormat,
     /// The ID of the trader associated with this log event.
     trader_id: Ustr,
+    /// The terminator appended to each formatted representation.
+    line_terminator: LineTerminator,
+    /// If the sequence number should be included in the text representations.
+    with_sequence_number: bool,
 }
 
 impl LogLineWrapper {
@@ -208,8 +830,59 @@ impl LogLineWrapper {
             line,
             cache: None,
             colored: None,
+            console: None,
+            ts_event: timestamp,
             timestamp: unix_nanos_to_iso8601(timestamp),
+            console_timestamp: None,
+            timestamp_format: TimestampFormat::default(),
             trader_id,
+            line_terminator: LineTerminator::default(),
+            with_sequence_number: false,
+        }
+    }
+
+    /// Sets the terminator appended to each formatted representation of the log line.
+    #[must_use]
+    pub fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Sets whether the sequence number (if assigned) is included in the text representations.
+    #[must_use]
+    pub const fn with_sequence_number(mut self, with_sequence_number: bool) -> Self {
+        self.with_sequence_number = with_sequence_number;
+        self
+    }
+
+    /// Sets the format of the timestamp for each representation of the log line.
+    #[must_use]
+    pub fn with_timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp = timestamp_format.format(self.ts_event);
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// Sets the timezone of the timestamp for the stdout and stderr representations.
+    ///
+    /// Only applies to ISO 8601 timestamps, so must be called after setting the timestamp format.
+    #[must_use]
+    pub fn with_console_timezone(mut self, timezone: LogTimezone) -> Self {
+        self.console_timestamp = match (self.timestamp_format.style, timezone) {
+            (TimestampStyle::Iso8601, LogTimezone::Offset(_)) => {
+                Some(timezone.format_iso8601(self.ts_event, self.timestamp_format.precision))
+            }
+            _ => None,
+        };
+        self
+    }
+
+    /// Returns the `#seq ` prefix of the text representations, or an empty string if the
+    /// sequence number is not included.
+    fn sequence_prefix(&self) -> String {
+        match self.line.seq {
+            Some(seq) if self.with_sequence_number => format!("#{seq} "),
+            _ => String::new(),
         }
     }
 
@@ -218,14 +891,41 @@ impl LogLineWrapper {
     /// This method constructs the log line format and caches it for repeated calls. Useful when the
     /// same log message needs to be printed multiple times.
     pub fn get_string(&mut self) -> &str {
+        let prefix = self.sequence_prefix();
         self.cache.get_or_insert_with(|| {
             format!(
-                "{} [{}] {}.{}: {}\n",
+                "{} [{}] {}{}.{}: {}{}{}",
                 self.timestamp,
                 self.line.level,
+                prefix,
                 self.trader_id,
                 &self.line.component,
                 &self.line.message,
+                self.line.location_suffix(),
+                self.line_terminator.as_str(),
+            )
+        })
+    }
+
+    /// Returns the plain log message string for stdout and stderr, caching the result.
+    ///
+    /// This is the same as [`Self::get_string`] unless a console timezone other than UTC is set.
+    pub fn get_console_string(&mut self) -> &str {
+        let Some(timestamp) = &self.console_timestamp else {
+            return self.get_string();
+        };
+        let prefix = self.sequence_prefix();
+        self.console.get_or_insert_with(|| {
+            format!(
+                "{} [{}] {}{}.{}: {}{}{}",
+                timestamp,
+                self.line.level,
+                prefix,
+                self.trader_id,
+                &self.line.component,
+                &self.line.message,
+                self.line.location_suffix(),
+                self.line_terminator.as_str(),
             )
         })
     }
@@ -236,15 +936,19 @@ impl LogLineWrapper {
     /// for repeated calls, providing the message with ANSI color codes if the
     /// logger is configured to use colors.
     pub fn get_colored(&mut self) -> &str {
+        let prefix = self.sequence_prefix();
         self.colored.get_or_insert_with(|| {
             format!(
-                "\x1b[1m{}\x1b[0m {}[{}] {}.{}: {}\x1b[0m\n",
-                self.timestamp,
+                "\x1b[1m{}\x1b[0m {}[{}] {}{}.{}: {}{}\x1b[0m{}",
+                self.console_timestamp.as_ref().unwrap_or(&self.timestamp),
                 &self.line.color.as_ansi(),
                 self.line.level,
+                prefix,
                 self.trader_id,
                 &self.line.component,
                 &self.line.message,
+                self.line.location_suffix(),
+                self.line_terminator.as_str(),
             )
         })
     }
@@ -258,7 +962,32 @@ impl LogLineWrapper {
     pub fn get_json(&self) -> String {
         let json_string =
             serde_json::to_string(&self).expect("Error serializing log event to string");
-        format!("{json_string}\n")
+        format!("{json_string}{}", self.line_terminator.as_str())
+    }
+
+    /// Returns the log message as a CSV row of `timestamp,level,component,message`.
+    ///
+    /// Fields containing a comma, quote or line break are quoted, with any quotes doubled
+    /// (as per RFC 4180).
+    #[must_use]
+    pub fn get_csv(&self) -> String {
+        format!(
+            "{},{},{},{}{}",
+            csv_field(&self.timestamp),
+            self.line.level,
+            csv_field(&self.line.component),
+            csv_field(&self.line.message),
+            self.line_terminator.as_str(),
+        )
+    }
+}
+
+/// Returns the `value` as a CSV field, quoted if it contains a comma, quote or line break.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
     }
 }
 
@@ -267,13 +996,34 @@ impl Serialize for LogLineWrapper {
     where
         S: Serializer,
     {
+        let timestamp = match self.timestamp_format.style {
+            TimestampStyle::Iso8601 => serde_json::Value::from(self.timestamp.clone()),
+            TimestampStyle::Unix => serde_json::Value::from(
+                self.timestamp_format
+                    .precision
+                    .units_since_epoch(self.ts_event),
+            ),
+        };
+
         let mut json_obj = IndexMap::new();
-        json_obj.insert("timestamp".to_string(), self.timestamp.clone());
-        json_obj.insert("trader_id".to_string(), self.trader_id.to_string());
-        json_obj.insert("level".to_string(), self.line.level.to_string());
-        json_obj.insert("color".to_string(), self.line.color.to_string());
-        json_obj.insert("component".to_string(), self.line.component.to_string());
-        json_obj.insert("message".to_string(), self.line.message.to_string());
+        json_obj.insert("timestamp".to_string(), timestamp);
+        if let Some(seq) = self.line.seq {
+            json_obj.insert("seq".to_string(), seq.into());
+        }
+        json_obj.insert("trader_id".to_string(), self.trader_id.to_string().into());
+        json_obj.insert("level".to_string(), self.line.level.to_string().into());
+        json_obj.insert("color".to_string(), self.line.color.to_string().into());
+        json_obj.insert(
+            "component".to_string(),
+            self.line.component.to_string().into(),
+        );
+        json_obj.insert("message".to_string(), self.line.message.to_string().into());
+        if let Some(file) = self.line.file {
+            json_obj.insert("file".to_string(), file.to_string().into());
+        }
+        if let Some(line) = self.line.line {
+            json_obj.insert("line".to_string(), line.to_string().into());
+        }
 
         json_obj.serialize(serializer)
     }
@@ -281,10 +1031,7 @@ impl Serialize for LogLineWrapper {
 
 impl Log for Logger {
     fn enabled(&self, metadata: &log::Metadata) -> bool {
-        !LOGGING_BYPASSED.load(Ordering::Relaxed)
-            && (metadata.level() == Level::Error
-                || metadata.level() <= self.config.stdout_level
-                || metadata.level() <= self.config.fileout_level)
+        metadata.level() <= self.max_level && !self.bypassed.load(Ordering::Relaxed)
     }
 
     fn log(&self, record: &log::Record) {
@@ -299,14 +1046,30 @@ impl Log for Logger {
                 |v| Ustr::from(&v.to_string()),
             );
 
+            let (file, line) = if self.config.with_source_location {
+                (record.file().map(Ustr::from), record.line())
+            } else {
+                (None, None)
+            };
+
             let line = LogLine {
                 level: record.level(),
                 color,
                 component,
                 message: format!("{}", record.args()),
+                file,
+                line,
+                seq: Some(self.sequence.fetch_add(1, Ordering::Relaxed)),
             };
-            if let Err(SendError(LogEvent::Log(line))) = self.tx.send(LogEvent::Log(line)) {
-                eprintln!("Error sending log event: {line}");
+            match self.tx.try_send(LogEvent::Log(line)) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => {
+                    self.dropped_count.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(TrySendError::Disconnected(LogEvent::Log(line))) => {
+                    eprintln!("Error sending log event: {line}");
+                }
+                Err(TrySendError::Disconnected(_)) => {}
             }
         }
     }
@@ -318,40 +1081,123 @@ impl Log for Logger {
     }
 }
 
+/// Transmitter for the installed logger, for commands issued outside of the `log` facade.
+///
+/// Set when the logger is initialized, and cleared when its [`LogGuard`] is dropped.
+static LOGGER_TX: Mutex<Option<LogSender>> = Mutex::new(None);
+
+fn set_logger_tx(tx: Option<LogSender>) {
+    *LOGGER_TX.lock().expect("Error locking logger transmitter") = tx;
+}
+
+/// Posts a command to the 'logging' thread to reopen the log file at its configured path.
+///
+/// Has no effect if the logger has not been initialized.
+pub fn reopen_log_file() {
+    let logger_tx = LOGGER_TX.lock().expect("Error locking logger transmitter");
+    if let Some(tx) = logger_tx.as_ref() {
+        if let Err(e) = tx.send(LogEvent::Reopen) {
+            eprintln!("Error sending reopen log event: {e}");
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 impl Logger {
+    fn new(config: LoggerConfig, tx: LogSender, dropped_count: Arc<AtomicU64>) -> Self {
+        Self {
+            max_level: config.max_level(),
+            config,
+            tx,
+            dropped_count,
+            sequence: AtomicU64::new(0),
+            bypassed: &LOGGING_BYPASSED,
+        }
+    }
+
+    /// Sets the bypass flag checked by the logger, so tests can toggle it without
+    /// affecting the global flag shared with other tests.
+    #[cfg(test)]
+    const fn with_bypassed(mut self, bypassed: &'static AtomicBool) -> Self {
+        self.bypassed = bypassed;
+        self
+    }
+
+    /// Returns the most verbose level accepted by any output of the logger.
     #[must_use]
+    pub const fn max_level(&self) -> LevelFilter {
+        self.max_level
+    }
+
+    /// Initializes the logger with the configuration from the `NAUTILUS_LOG` environment variable.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the configuration cannot be read, see [`LoggerConfig::from_env`].
+    /// - If the logger cannot be initialized, see [`Logger::init_with_config`].
     pub fn init_with_env(
         trader_id: TraderId,
         instance_id: UUID4,
         file_config: FileWriterConfig,
-    ) -> LogGuard {
-        let config = LoggerConfig::from_env();
+    ) -> Result<LogGuard, LogError> {
+        let config = LoggerConfig::from_env()?;
         Self::init_with_config(trader_id, instance_id, config, file_config)
     }
 
     /// Initializes the logger with the given configuration.
     ///
+    /// If the config sets a `queue_capacity` then log lines are dropped (and counted) while the
+    /// queue is full, see [`LogGuard::dropped_count`].
+    ///
+    /// Once installed, the logger emits a startup banner as an `Info` event from the `Logger`
+    /// component, with a JSON message recording the trader, machine and instance IDs along
+    /// with the applied log levels.
+    ///
+    /// The log levels are fixed once the logger is initialized, as `log` then owns the logger
+    /// and its maximum level is set from the config (with `log::set_max_level`) only here.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a log file (or its directory) cannot be created or opened.
+    /// - If a logger has already been initialized.
+    ///
     /// # Examples
     ///
     /// ```rust
-    /// let config = LoggerConfig::from_spec("stdout=Info;fileout=Debug;RiskEngine=Error");
+    /// let config = LoggerConfig::from_spec("stdout=Info;fileout=Debug;RiskEngine=Error")?;
     /// let file_config = FileWriterConfig::default();
-    /// let log_guard = Logger::init_with_config(trader_id, instance_id, config, file_config);
+    /// let log_guard = Logger::init_with_config(trader_id, instance_id, config, file_config)?;
     /// ```
-    #[must_use]
     pub fn init_with_config(
         trader_id: TraderId,
         instance_id: UUID4,
         config: LoggerConfig,
         file_config: FileWriterConfig,
-    ) -> LogGuard {
-        let (tx, rx) = std::sync::mpsc::channel::<LogEvent>();
-
-        let logger = Self {
-            tx,
-            config: config.clone(),
+    ) -> Result<LogGuard, LogError> {
+        // Open log files before installing the logger, so failures are returned to the caller
+        let file_writers = Self::create_file_writers(
+            &trader_id.to_string(),
+            &instance_id.to_string(),
+            &config,
+            file_config.clone(),
+        )?;
+
+        let (tx, rx) = match config.queue_capacity {
+            Some(capacity) => {
+                let (tx, rx) = std::sync::mpsc::sync_channel::<LogEvent>(capacity);
+                (LogSender::Bounded(tx), rx)
+            }
+            None => {
+                let (tx, rx) = std::sync::mpsc::channel::<LogEvent>();
+                (LogSender::Unbounded(tx), rx)
+            }
         };
+        let dropped_count = Arc::new(AtomicU64::new(0));
+
+        let logger = Self::new(config.clone(), tx.clone(), dropped_count.clone());
+        let max_level = logger.max_level();
 
         let print_config = config.print_config;
         if print_config {
@@ -359,65 +1205,142 @@ impl Logger {
             println!("Logger initialized with {config:?} {file_config:?}");
         }
 
-        let mut handle: Option<std::thread::JoinHandle<()>> = None;
-        match set_boxed_logger(Box::new(logger)) {
-            Ok(()) => {
-                handle = Some(
-                    std::thread::Builder::new()
-                        .name(LOGGING.to_string())
-                        .spawn(move || {
-                            Self::handle_messages(
-                                trader_id.to_string(),
-                                instance_id.to_string(),
-                                config,
-                                file_config,
-                                rx,
-                            );
-                        })
-                        .expect("Error spawning thread '{LOGGING}'"),
-                );
+        let banner_config = config.clone();
+        set_boxed_logger(Box::new(logger))?;
 
-                let max_level = log::LevelFilter::Trace;
-                set_max_level(max_level);
-                if print_config {
-                    println!("Logger set as `log` implementation with max level {max_level}");
-                }
-            }
-            Err(e) => {
-                eprintln!("Cannot set logger because of error: {e}");
-            }
+        set_logger_tx(Some(tx));
+        let handle = std::thread::Builder::new()
+            .name(LOGGING.to_string())
+            .spawn(move || {
+                Self::handle_messages(trader_id.to_string(), config, file_writers, rx);
+            })
+            .expect("Error spawning thread '{LOGGING}'");
+
+        // Events above every output level are then skipped by the `log` macros themselves
+        set_max_level(max_level);
+        if print_config {
+            println!("Logger set as `log` implementation with max level {max_level}");
         }
 
-        LogGuard::new(handle)
+        let machine_id = System::host_name().unwrap_or_default();
+        let banner = Self::startup_banner(trader_id, &machine_id, instance_id, &banner_config);
+        log::info!(component = BANNER_COMPONENT; "{banner}");
+
+        Ok(LogGuard::new(Some(handle))
+            .with_dropped_count(dropped_count)
+            .with_config(banner_config))
+    }
+
+    /// Creates a file writer for the main file (if enabled by the `fileout_level`) and each
+    /// file sink of the `config`.
+    fn create_file_writers(
+        trader_id: &str,
+        instance_id: &str,
+        config: &LoggerConfig,
+        file_config: FileWriterConfig,
+    ) -> Result<Vec<FileWriter>, LogError> {
+        std::iter::once((file_config, config.fileout_level))
+            .chain(
+                config
+                    .file_sinks
+                    .iter()
+                    .map(|sink| (sink.file_config.clone(), sink.level)),
+            )
+            .filter(|(_, level)| *level != LevelFilter::Off)
+            .map(|(file_config, level)| {
+                FileWriter::new(
+                    trader_id.to_string(),
+                    instance_id.to_string(),
+                    file_config,
+                    level,
+                )
+                .map(|writer| {
+                    writer
+                        .with_preallocation(config.preallocate_bytes)
+                        .with_line_terminator(config.line_terminator)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the startup banner message as a JSON object, recording the identity of the
+    /// process and the logging configuration which was actually applied.
+    fn startup_banner(
+        trader_id: TraderId,
+        machine_id: &str,
+        instance_id: UUID4,
+        config: &LoggerConfig,
+    ) -> String {
+        let component_levels: BTreeMap<&str, String> = config
+            .component_level
+            .iter()
+            .map(|(component, level)| (component.as_str(), level.to_string()))
+            .collect();
+
+        let file_sink_levels: Vec<String> = config
+            .file_sinks
+            .iter()
+            .map(|sink| sink.level.to_string())
+            .collect();
+
+        serde_json::json!({
+            "event": BANNER_EVENT,
+            "trader_id": trader_id.to_string(),
+            "machine_id": machine_id,
+            "instance_id": instance_id.to_string(),
+            "stdout_level": config.stdout_level.to_string(),
+            "fileout_level": config.fileout_level.to_string(),
+            "component_levels": component_levels,
+            "file_sinks": file_sink_levels,
+            "file_logging": config.max_file_level() != LevelFilter::Off,
+        })
+        .to_string()
     }
 
     fn handle_messages(
         trader_id: String,
-        instance_id: String,
         config: LoggerConfig,
-        file_config: FileWriterConfig,
+        file_writers: Vec<FileWriter>,
         rx: std::sync::mpsc::Receiver<LogEvent>,
     ) {
         let LoggerConfig {
             stdout_level,
-            fileout_level,
+            fileout_level: _,
             ref component_level,
+            ref component_colors,
             is_colored,
             print_config: _,
+            errors_to_stderr,
+            errors_to_stdout,
+            rate_limits,
+            line_terminator,
+            timestamp_format,
+            stdout_timezone,
+            with_source_location: _,
+            with_sequence_number,
+            file_sinks: _,
+            queue_capacity: _,
+            max_bytes_per_sec,
+            byte_limit_policy,
+            preallocate_bytes: _,
         } = config;
 
         let trader_id_cache = Ustr::from(&trader_id);
 
         // Set up std I/O buffers
-        let mut stdout_writer = StdoutWriter::new(stdout_level, is_colored);
+        let mut stdout_writer = StdoutWriter::new(stdout_level, is_colored)
+            .with_errors(errors_to_stdout || !errors_to_stderr);
         let mut stderr_writer = StderrWriter::new(is_colored);
+        let mut rate_limiter = LogRateLimiter::new(rate_limits);
 
-        // Conditionally create file writer based on fileout_level
-        let mut file_writer_opt = if fileout_level != LevelFilter::Off {
-            FileWriter::new(trader_id, instance_id, file_config, fileout_level)
-        } else {
-            None
-        };
+        // Give each file writer its own byte budget
+        let mut file_writers: Vec<(FileWriter, LogByteLimiter)> = file_writers
+            .into_iter()
+            .map(|writer| {
+                let limiter = LogByteLimiter::new(max_bytes_per_sec, byte_limit_policy);
+                (writer, limiter)
+            })
+            .collect();
 
         // Continue to receive and handle log events until channel is hung up
         while let Ok(event) = rx.recv() {
@@ -425,29 +1348,44 @@ impl Logger {
                 LogEvent::Flush => {
                     break;
                 }
+                LogEvent::Reopen => {
+                    for (writer, _) in &mut file_writers {
+                        writer.reopen();
+                    }
+                }
                 LogEvent::Log(line) => {
                     let timestamp = match LOGGING_REALTIME.load(Ordering::Relaxed) {
                         true => get_atomic_clock_realtime().get_time_ns(),
                         false => get_atomic_clock_static().get_time_ns(),
                     };
 
-                    let component_level = component_level.get(&line.component);
-
-                    // Check if the component exists in level_filters,
+                    // Check if the component (or a dotted prefix of it) has a level,
                     // and if its level is greater than event.level.
-                    if let Some(&filter_level) = component_level {
+                    if let Some(filter_level) =
+                        find_component_level(component_level, &line.component)
+                    {
                         if line.level > filter_level {
                             continue;
                         }
                     }
 
-                    let mut wrapper = LogLineWrapper::new(line, trader_id_cache, timestamp);
+                    if !rate_limiter.allow(&line, timestamp) {
+                        continue;
+                    }
+
+                    let line = apply_component_color(component_colors, line);
 
-                    if stderr_writer.enabled(&wrapper.line) {
+                    let mut wrapper = LogLineWrapper::new(line, trader_id_cache, timestamp)
+                        .with_line_terminator(line_terminator)
+                        .with_sequence_number(with_sequence_number)
+                        .with_timestamp_format(timestamp_format)
+                        .with_console_timezone(stdout_timezone);
+
+                    if errors_to_stderr && stderr_writer.enabled(&wrapper.line) {
                         if is_colored {
                             stderr_writer.write(wrapper.get_colored());
                         } else {
-                            stderr_writer.write(wrapper.get_string());
+                            stderr_writer.write(wrapper.get_console_string());
                         }
                     }
 
@@ -455,23 +1393,70 @@ impl Logger {
                         if is_colored {
                             stdout_writer.write(wrapper.get_colored());
                         } else {
-                            stdout_writer.write(wrapper.get_string());
+                            stdout_writer.write(wrapper.get_console_string());
                         }
                     }
 
-                    if let Some(ref mut writer) = file_writer_opt {
+                    for (writer, limiter) in &mut file_writers {
                         if writer.enabled(&wrapper.line) {
-                            if writer.json_format {
-                                writer.write(&wrapper.get_json());
-                            } else {
-                                writer.write(wrapper.get_string());
+                            match writer.file_format {
+                                LogFileFormat::Text => {
+                                    limiter.write(writer, wrapper.get_string(), timestamp);
+                                }
+                                LogFileFormat::Json => {
+                                    limiter.write(writer, &wrapper.get_json(), timestamp);
+                                }
+                                LogFileFormat::Csv => {
+                                    limiter.write(writer, &wrapper.get_csv(), timestamp);
+                                }
                             }
                         }
                     }
                 }
             }
         }
+
+        // Write any lines still buffered for exceeding a file's byte budget
+        for (writer, limiter) in &mut file_writers {
+            limiter.flush(writer);
+        }
+    }
+}
+
+/// Returns the level for `component` from the most specific matching entry, trying the full
+/// name and then each dotted prefix (e.g. `DataEngine.Binance` then `DataEngine`).
+///
+/// Returns `None` if no entry matches, in which case the global levels apply.
+fn find_component_level(
+    component_level: &HashMap<Ustr, LevelFilter>,
+    component: &Ustr,
+) -> Option<LevelFilter> {
+    if let Some(&level) = component_level.get(component) {
+        return Some(level);
+    }
+
+    let mut name = component.as_str();
+    while let Some((parent, _)) = name.rsplit_once('.') {
+        // Configured components are already interned, so a prefix which isn't cannot match
+        if let Some(&level) = Ustr::from_existing(parent).and_then(|key| component_level.get(&key))
+        {
+            return Some(level);
+        }
+        name = parent;
+    }
+    None
+}
+
+/// Applies the configured color for the line's component, if the line has the default color.
+///
+/// Warning and error lines are left unchanged so their severity remains visible.
+fn apply_component_color(component_colors: &HashMap<Ustr, LogColor>, mut line: LogLine) -> LogLine {
+    if line.color == LogColor::Normal && line.level > Level::Warn {
+        if let Some(&color) = component_colors.get(&line.component) {
+            line.color = color;
+        }
     }
+    line
 }
 
 pub fn log(level: LogLevel, color: LogColor, component: Ustr, message: &str) {
@@ -497,6 +1482,42 @@ pub fn log(level: LogLevel, color: LogColor, component: Ustr, message: &str) {
     }
 }
 
+/// Logs the given `message` with the source `file` and `line` it originated from.
+///
+/// The location is only included in the output if the logger is configured
+/// `with_source_location`.
+pub fn log_with_location(
+    level: LogLevel,
+    color: LogColor,
+    component: Ustr,
+    message: &str,
+    file: Option<&str>,
+    line: Option<u32>,
+) {
+    let Some(level) = map_log_level_to_filter(level).to_level() else {
+        return;
+    };
+    if level > STATIC_MAX_LEVEL || level > log::max_level() {
+        return;
+    }
+
+    let key_values = [
+        ("component", component.to_value()),
+        ("color", Value::from(color as u8)),
+    ];
+    log::logger().log(
+        &log::Record::builder()
+            .args(format_args!("{message}"))
+            .level(level)
+            .target(module_path!())
+            .module_path_static(Some(module_path!()))
+            .file(file)
+            .line(line)
+            .key_values(&key_values)
+            .build(),
+    );
+}
+
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")
@@ -504,13 +1525,55 @@ pub fn log(level: LogLevel, color: LogColor, component: Ustr, message: &str) {
 #[derive(Debug)]
 pub struct LogGuard {
     handle: Option<std::thread::JoinHandle<()>>,
+    dropped_count: Arc<AtomicU64>,
+    config: Option<LoggerConfig>,
 }
 
 impl LogGuard {
     /// Creates a new [`LogGuard`] instance.
     #[must_use]
-    pub const fn new(handle: Option<std::thread::JoinHandle<()>>) -> Self {
-        Self { handle }
+    pub fn new(handle: Option<std::thread::JoinHandle<()>>) -> Self {
+        Self {
+            handle,
+            dropped_count: Arc::new(AtomicU64::new(0)),
+            config: None,
+        }
+    }
+
+    /// Sets the configuration of the installed [`Logger`], used by [`LogGuard::would_log`].
+    #[must_use]
+    pub fn with_config(mut self, config: LoggerConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Returns whether an event at `level` from `component` would be written, so callers can
+    /// skip building messages which would be filtered out.
+    ///
+    /// Always `false` if logging is bypassed or the guard has no logger configuration.
+    #[must_use]
+    pub fn would_log(&self, level: LogLevel, component: &Ustr) -> bool {
+        let Some(level) = map_log_level_to_filter(level).to_level() else {
+            return false;
+        };
+        !LOGGING_BYPASSED.load(Ordering::Relaxed)
+            && self
+                .config
+                .as_ref()
+                .is_some_and(|config| config.would_log(level, component))
+    }
+
+    /// Sets the dropped log event counter shared with the installed [`Logger`].
+    #[must_use]
+    pub fn with_dropped_count(mut self, dropped_count: Arc<AtomicU64>) -> Self {
+        self.dropped_count = dropped_count;
+        self
+    }
+
+    /// Returns the count of log events dropped because the logger queue was full.
+    #[must_use]
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped_count.load(Ordering::Relaxed)
     }
 }
 
@@ -525,6 +1588,7 @@ impl Drop for LogGuard {
     fn drop(&mut self) {
         log::logger().flush();
         if let Some(handle) = self.handle.take() {
+            set_logger_tx(None);
             handle.join().expect("Error joining logging handle");
         }
     }
@@ -535,7 +1599,12 @@ impl Drop for LogGuard {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashMap, time::Duration};
+    use std::{
+        collections::HashMap,
+        env::VarError,
+        path::{Path, PathBuf},
+        time::Duration,
+    };
 
     use log::LevelFilter;
     use nautilus_core::uuid::UUID4;
@@ -547,11 +1616,21 @@ mod tests {
 
     use super::*;
     use crate::{
-        enums::LogColor,
-        logging::{logging_clock_set_static_mode, logging_clock_set_static_time},
+        enums::{LogColor, LogStatus},
+        logging::{
+            logging_clock_set_static_mode, logging_clock_set_static_time, logging_reopen,
+            writer::{log_file_path, CSV_HEADER},
+        },
         testing::wait_until,
     };
 
+    fn without_banner(log_contents: &str) -> String {
+        log_contents
+            .split_inclusive('\n')
+            .filter(|line| !line.contains(BANNER_EVENT))
+            .collect()
+    }
+
     #[rstest]
     fn log_message_serialization() {
         let log_message = LogLine {
@@ -559,6 +1638,9 @@ mod tests {
             color: LogColor::Normal,
             component: Ustr::from("Portfolio"),
             message: "This is a log message".to_string(),
+            file: None,
+            line: None,
+            seq: None,
         };
 
         let serialized_json = serde_json::to_string(&log_message).unwrap();
@@ -572,7 +1654,8 @@ mod tests {
     #[rstest]
     fn log_config_parsing() {
         let config =
-            LoggerConfig::from_spec("stdout=Info;is_colored;fileout=Debug;RiskEngine=Error");
+            LoggerConfig::from_spec("stdout=Info;is_colored;fileout=Debug;RiskEngine=Error")
+                .unwrap();
         assert_eq!(
             config,
             LoggerConfig {
@@ -582,197 +1665,1821 @@ mod tests {
                     Ustr::from("RiskEngine"),
                     LevelFilter::Error
                 )]),
+                component_colors: HashMap::new(),
                 is_colored: true,
                 print_config: false,
+                errors_to_stderr: true,
+                errors_to_stdout: false,
+                rate_limits: RateLimitConfig::default(),
+                line_terminator: LineTerminator::native(),
+                timestamp_format: TimestampFormat::default(),
+                stdout_timezone: LogTimezone::Utc,
+                with_source_location: false,
+                with_sequence_number: false,
+                file_sinks: Vec::new(),
+                queue_capacity: None,
+                max_bytes_per_sec: None,
+                byte_limit_policy: ByteLimitPolicy::Drop,
+                preallocate_bytes: None,
             }
         );
     }
 
     #[rstest]
     fn log_config_parsing2() {
-        let config = LoggerConfig::from_spec("stdout=Warn;print_config;fileout=Error;");
+        let config = LoggerConfig::from_spec("stdout=Warn;print_config;fileout=Error;").unwrap();
         assert_eq!(
             config,
             LoggerConfig {
                 stdout_level: LevelFilter::Warn,
                 fileout_level: LevelFilter::Error,
                 component_level: HashMap::new(),
+                component_colors: HashMap::new(),
                 is_colored: false,
                 print_config: true,
+                errors_to_stderr: true,
+                errors_to_stdout: false,
+                rate_limits: RateLimitConfig::default(),
+                line_terminator: LineTerminator::native(),
+                timestamp_format: TimestampFormat::default(),
+                stdout_timezone: LogTimezone::Utc,
+                with_source_location: false,
+                with_sequence_number: false,
+                file_sinks: Vec::new(),
+                queue_capacity: None,
+                max_bytes_per_sec: None,
+                byte_limit_policy: ByteLimitPolicy::Drop,
+                preallocate_bytes: None,
             }
         );
     }
 
     #[rstest]
-    fn test_logging_to_file() {
-        let config = LoggerConfig {
-            fileout_level: LevelFilter::Debug,
-            ..Default::default()
-        };
+    fn log_config_parsing_error_streams() {
+        let config =
+            LoggerConfig::from_spec("stdout=Info;errors_to_stdout;no_errors_to_stderr").unwrap();
 
-        let temp_dir = tempdir().expect("Failed to create temporary directory");
-        let file_config = FileWriterConfig {
-            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
-            ..Default::default()
-        };
+        assert!(!config.errors_to_stderr);
+        assert!(config.errors_to_stdout);
+    }
 
-        let log_guard = Logger::init_with_config(
-            TraderId::from("TRADER-001"),
-            UUID4::new(),
-            config,
-            file_config,
-        );
+    #[rstest]
+    #[case("stdout=Info", LineTerminator::native())]
+    #[case("stdout=Info;line_terminator=\\n", LineTerminator::Lf)]
+    #[case("stdout=Info;line_terminator=\\r\\n", LineTerminator::CrLf)]
+    #[case("stdout=Info;line_terminator=", LineTerminator::Empty)]
+    fn log_config_parsing_line_terminator(#[case] spec: &str, #[case] expected: LineTerminator) {
+        let config = LoggerConfig::from_spec(spec).unwrap();
+
+        assert_eq!(config.line_terminator, expected);
+    }
 
-        logging_clock_set_static_mode();
-        logging_clock_set_static_time(1_650_000_000_000_000);
+    #[rstest]
+    fn log_config_parsing_invalid_line_terminator() {
+        let result = LoggerConfig::from_spec("stdout=Info;line_terminator=\\r");
 
-        log::info!(
-            component = "RiskEngine";
-            "This is a test."
-        );
+        assert!(matches!(result, Err(LogError::ConfigParse { .. })));
+    }
 
-        let mut log_contents = String::new();
+    #[rstest]
+    #[case(
+        "stdout=Info;timestamp_format=unix_millis",
+        TimestampFormat::unix(TimestampPrecision::Millis)
+    )]
+    #[case(
+        "stdout=Info;timestamp_format=ISO8601",
+        TimestampFormat::iso8601(TimestampPrecision::Nanos)
+    )]
+    #[case("stdout=Info", TimestampFormat::iso8601(TimestampPrecision::Nanos))]
+    #[case(
+        "stdout=Info;timestamp_format=unix;timestamp_precision=micros",
+        TimestampFormat::unix(TimestampPrecision::Micros)
+    )]
+    #[case(
+        "stdout=Info;timestamp_precision=seconds;timestamp_format=unix_millis",
+        TimestampFormat::unix(TimestampPrecision::Seconds)
+    )]
+    #[case(
+        "stdout=Info;timestamp_precision=millis",
+        TimestampFormat::iso8601(TimestampPrecision::Millis)
+    )]
+    fn log_config_parsing_timestamp_format(#[case] spec: &str, #[case] expected: TimestampFormat) {
+        let config = LoggerConfig::from_spec(spec).unwrap();
+
+        assert_eq!(config.timestamp_format, expected);
+    }
 
-        wait_until(
-            || {
-                std::fs::read_dir(&temp_dir)
-                    .expect("Failed to read directory")
-                    .filter_map(Result::ok)
-                    .any(|entry| entry.path().is_file())
-            },
-            Duration::from_secs(2),
-        );
+    #[rstest]
+    fn log_config_parsing_invalid_timestamp_format() {
+        let result = LoggerConfig::from_spec("stdout=Info;timestamp_format=unix_nanos");
 
-        drop(log_guard); // Ensure log buffers are flushed
+        assert!(matches!(result, Err(LogError::ConfigParse { .. })));
+    }
 
-        wait_until(
-            || {
-                let log_file_path = std::fs::read_dir(&temp_dir)
-                    .expect("Failed to read directory")
-                    .filter_map(Result::ok)
-                    .find(|entry| entry.path().is_file())
-                    .expect("No files found in directory")
-                    .path();
-                dbg!(&log_file_path);
-                log_contents =
-                    std::fs::read_to_string(log_file_path).expect("Error while reading log file");
-                !log_contents.is_empty()
-            },
-            Duration::from_secs(2),
-        );
+    #[rstest]
+    fn log_config_parsing_invalid_timestamp_precision() {
+        let result = LoggerConfig::from_spec("stdout=Info;timestamp_precision=picos");
+
+        assert!(matches!(
+            result,
+            Err(LogError::ConfigParse { ref key, .. }) if key == "timestamp precision"
+        ));
+    }
 
+    #[rstest]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Nanos),
+        "2022-04-15T05:20:00.123456789Z"
+    )]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Micros),
+        "2022-04-15T05:20:00.123456Z"
+    )]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Millis),
+        "2022-04-15T05:20:00.123Z"
+    )]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Seconds),
+        "2022-04-15T05:20:00Z"
+    )]
+    #[case(
+        TimestampFormat::unix(TimestampPrecision::Nanos),
+        "1650000000123456789"
+    )]
+    #[case(TimestampFormat::unix(TimestampPrecision::Micros), "1650000000123456")]
+    #[case(TimestampFormat::unix(TimestampPrecision::Millis), "1650000000123")]
+    #[case(TimestampFormat::unix(TimestampPrecision::Seconds), "1650000000")]
+    fn test_timestamp_format(#[case] format: TimestampFormat, #[case] expected: &str) {
         assert_eq!(
-            log_contents,
-            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: This is a test.\n"
+            format.format(UnixNanos::from(1_650_000_000_123_456_789)),
+            expected
         );
     }
 
     #[rstest]
-    fn test_log_component_level_filtering() {
-        let config = LoggerConfig::from_spec("stdout=Info;fileout=Debug;RiskEngine=Error");
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Micros),
+        "2022-04-15T05:20:00.999999Z"
+    )]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Millis),
+        "2022-04-15T05:20:00.999Z"
+    )]
+    #[case(
+        TimestampFormat::iso8601(TimestampPrecision::Seconds),
+        "2022-04-15T05:20:00Z"
+    )]
+    #[case(TimestampFormat::unix(TimestampPrecision::Micros), "1650000000999999")]
+    #[case(TimestampFormat::unix(TimestampPrecision::Millis), "1650000000999")]
+    #[case(TimestampFormat::unix(TimestampPrecision::Seconds), "1650000000")]
+    fn test_timestamp_format_truncates(#[case] format: TimestampFormat, #[case] expected: &str) {
+        assert_eq!(
+            format.format(UnixNanos::from(1_650_000_000_999_999_999)),
+            expected
+        );
+    }
 
-        let temp_dir = tempdir().expect("Failed to create temporary directory");
-        let file_config = FileWriterConfig {
-            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
-            ..Default::default()
-        };
+    #[rstest]
+    fn test_log_line_wrapper_console_timezone_with_precision() {
+        let mut wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_timestamp_format(TimestampFormat::iso8601(TimestampPrecision::Micros))
+        .with_line_terminator(LineTerminator::Lf)
+        .with_console_timezone(LogTimezone::Offset(
+            FixedOffset::east_opt(8 * 3600).unwrap(),
+        ));
 
-        let log_guard = Logger::init_with_config(
-            TraderId::from("TRADER-001"),
-            UUID4::new(),
-            config,
-            file_config,
+        assert_eq!(
+            wrapper.get_console_string(),
+            "2022-04-15T13:20:00.123456+08:00 [ERROR] TRADER-001.RiskEngine: This is an error\n"
+        );
+        assert_eq!(
+            wrapper.get_string(),
+            "2022-04-15T05:20:00.123456Z [ERROR] TRADER-001.RiskEngine: This is an error\n"
         );
+    }
 
-        logging_clock_set_static_mode();
-        logging_clock_set_static_time(1_650_000_000_000_000);
+    #[rstest]
+    fn test_log_line_wrapper_unix_micros_json() {
+        let wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_timestamp_format(TimestampFormat::unix(TimestampPrecision::Micros));
+
+        assert!(wrapper
+            .get_json()
+            .starts_with("{\"timestamp\":1650000000123456,"));
+    }
 
-        log::info!(
-            component = "RiskEngine";
-            "This is a test."
+    #[rstest]
+    fn test_log_line_wrapper_unix_millis_timestamp() {
+        let mut wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_timestamp_format(TimestampFormat::unix(TimestampPrecision::Millis))
+        .with_line_terminator(LineTerminator::Lf);
+
+        assert_eq!(
+            wrapper.get_string(),
+            "1650000000123 [ERROR] TRADER-001.RiskEngine: This is an error\n"
         );
+        assert!(wrapper
+            .get_colored()
+            .starts_with("\x1b[1m1650000000123\x1b[0m "));
+        assert_eq!(
+            wrapper.get_json(),
+            "{\"timestamp\":1650000000123,\"trader_id\":\"TRADER-001\",\"level\":\"ERROR\",\"color\":\"RED\",\"component\":\"RiskEngine\",\"message\":\"This is an error\"}\n"
+        );
+    }
 
-        drop(log_guard); // Ensure log buffers are flushed
+    #[rstest]
+    fn test_log_line_wrapper_csv_escapes_comma_and_quote() {
+        let mut line = error_line();
+        line.message = "Order denied, \"price\" exceeds limit".to_string();
+        let wrapper = LogLineWrapper::new(
+            line,
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_line_terminator(LineTerminator::Lf);
 
-        wait_until(
-            || {
-                if let Some(log_file) = std::fs::read_dir(&temp_dir)
-                    .expect("Failed to read directory")
-                    .filter_map(Result::ok)
-                    .find(|entry| entry.path().is_file())
-                {
-                    let log_file_path = log_file.path();
-                    let log_contents = std::fs::read_to_string(log_file_path)
-                        .expect("Error while reading log file");
-                    !log_contents.contains("RiskEngine")
-                } else {
-                    false
-                }
-            },
-            Duration::from_secs(3),
+        assert_eq!(
+            wrapper.get_csv(),
+            "2022-04-15T05:20:00.123456789Z,ERROR,RiskEngine,\"Order denied, \"\"price\"\" exceeds limit\"\n"
         );
+    }
 
-        assert!(
-            std::fs::read_dir(&temp_dir)
-                .expect("Failed to read directory")
-                .filter_map(Result::ok)
-                .any(|entry| entry.path().is_file()),
-            "Log file exists"
-        );
+    #[rstest]
+    #[case("plain message", "plain message")]
+    #[case("a,b", "\"a,b\"")]
+    #[case("say \"hi\"", "\"say \"\"hi\"\"\"")]
+    #[case("line1\nline2", "\"line1\nline2\"")]
+    #[case("line1\r\nline2", "\"line1\r\nline2\"")]
+    #[case("", "")]
+    fn test_csv_field(#[case] value: &str, #[case] expected: &str) {
+        assert_eq!(csv_field(value), expected);
     }
 
     #[rstest]
-    fn test_logging_to_file_in_json_format() {
-        let config =
-            LoggerConfig::from_spec("stdout=Info;is_colored;fileout=Debug;RiskEngine=Info");
+    #[case("stdout=Info", LogTimezone::Utc)]
+    #[case("stdout=Info;stdout_timezone=UTC", LogTimezone::Utc)]
+    #[case(
+        "stdout=Info;stdout_timezone=+08:00",
+        LogTimezone::Offset(FixedOffset::east_opt(8 * 3600).unwrap())
+    )]
+    #[case(
+        "stdout=Info;stdout_timezone=-05:30",
+        LogTimezone::Offset(FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap())
+    )]
+    fn log_config_parsing_stdout_timezone(#[case] spec: &str, #[case] expected: LogTimezone) {
+        let config = LoggerConfig::from_spec(spec).unwrap();
+
+        assert_eq!(config.stdout_timezone, expected);
+    }
 
-        let temp_dir = tempdir().expect("Failed to create temporary directory");
-        let file_config = FileWriterConfig {
-            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
-            file_format: Some("json".to_string()),
-            ..Default::default()
-        };
+    #[rstest]
+    fn log_config_parsing_local_stdout_timezone_is_fixed_offset() {
+        let config = LoggerConfig::from_spec("stdout=Info;stdout_timezone=local").unwrap();
 
-        let log_guard = Logger::init_with_config(
-            TraderId::from("TRADER-001"),
-            UUID4::new(),
-            config,
-            file_config,
-        );
+        assert!(matches!(config.stdout_timezone, LogTimezone::Offset(_)));
+    }
 
-        logging_clock_set_static_mode();
-        logging_clock_set_static_time(1_650_000_000_000_000);
+    #[rstest]
+    fn log_config_parsing_invalid_stdout_timezone() {
+        let result = LoggerConfig::from_spec("stdout=Info;stdout_timezone=Mars/Olympus");
 
-        log::info!(
-            component = "RiskEngine";
-            "This is a test."
-        );
+        assert!(matches!(result, Err(LogError::ConfigParse { .. })));
+    }
 
-        let mut log_contents = String::new();
+    #[rstest]
+    #[case("stdout=Bogus", "Bogus")]
+    #[case("stdout=Info;fileout=Verbose", "Verbose")]
+    #[case("stdout=Info;RiskEngine=Loud", "Loud")]
+    fn log_config_parsing_invalid_level(#[case] spec: &str, #[case] expected: &str) {
+        let result = LoggerConfig::from_spec(spec);
 
-        drop(log_guard); // Ensure log buffers are flushed
+        assert!(matches!(result, Err(LogError::InvalidLevel(ref level)) if level == expected));
+    }
 
-        wait_until(
-            || {
-                if let Some(log_file) = std::fs::read_dir(&temp_dir)
-                    .expect("Failed to read directory")
-                    .filter_map(Result::ok)
-                    .find(|entry| entry.path().is_file())
-                {
-                    let log_file_path = log_file.path();
-                    log_contents = std::fs::read_to_string(log_file_path)
-                        .expect("Error while reading log file");
-                    !log_contents.is_empty()
-                } else {
-                    false
-                }
-            },
-            Duration::from_secs(2),
+    #[rstest]
+    fn log_config_parsing_unrecognized_option() {
+        let result = LoggerConfig::from_spec("stdout=Info;is_colourful");
+
+        assert!(matches!(
+            result,
+            Err(LogError::ConfigParse { ref key, ref value, .. })
+                if key == "option" && value == "is_colourful"
+        ));
+    }
+
+    #[rstest]
+    #[case("stdout=Info;queue_capacity=lots", "queue capacity")]
+    #[case("stdout=Info;max_bytes_per_sec=-1", "max bytes per sec")]
+    #[case("stdout=Info;preallocate_bytes=1MB", "preallocate bytes")]
+    fn log_config_parsing_invalid_value(#[case] spec: &str, #[case] expected_key: &str) {
+        let result = LoggerConfig::from_spec(spec);
+
+        assert!(matches!(
+            result,
+            Err(LogError::ConfigParse { ref key, .. }) if key == expected_key
+        ));
+    }
+
+    #[rstest]
+    fn test_log_line_wrapper_console_timezone_offset() {
+        let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+        let mut wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_line_terminator(LineTerminator::Lf)
+        .with_console_timezone(LogTimezone::Offset(offset));
+
+        assert_eq!(
+            wrapper.get_console_string(),
+            "2022-04-15T13:20:00.123456789+08:00 [ERROR] TRADER-001.RiskEngine: This is an error\n"
+        );
+        assert!(wrapper
+            .get_colored()
+            .starts_with("\x1b[1m2022-04-15T13:20:00.123456789+08:00\x1b[0m "));
+        // Files remain in UTC
+        assert_eq!(
+            wrapper.get_string(),
+            "2022-04-15T05:20:00.123456789Z [ERROR] TRADER-001.RiskEngine: This is an error\n"
         );
+        assert!(wrapper
+            .get_json()
+            .starts_with("{\"timestamp\":\"2022-04-15T05:20:00.123456789Z\""));
+    }
+
+    #[rstest]
+    fn test_log_line_wrapper_console_timezone_ignored_for_unix_millis() {
+        let mut wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_123_456_789),
+        )
+        .with_timestamp_format(TimestampFormat::unix(TimestampPrecision::Millis))
+        .with_line_terminator(LineTerminator::Lf)
+        .with_console_timezone(LogTimezone::Offset(FixedOffset::east_opt(3600).unwrap()));
 
         assert_eq!(
-        log_contents,
-        "{\"timestamp\":\"1970-01-20T02:20:00.000000000Z\",\"trader_id\":\"TRADER-001\",\"level\":\"INFO\",\"color\":\"NORMAL\",\"component\":\"RiskEngine\",\"message\":\"This is a test.\"}\n"
-    );
+            wrapper.get_console_string(),
+            "1650000000123 [ERROR] TRADER-001.RiskEngine: This is an error\n"
+        );
+    }
+
+    #[rstest]
+    #[case("\n", LineTerminator::Lf)]
+    #[case("\r\n", LineTerminator::CrLf)]
+    #[case("", LineTerminator::Empty)]
+    fn test_line_terminator_from_str(#[case] value: &str, #[case] expected: LineTerminator) {
+        assert_eq!(LineTerminator::from_str(value).unwrap(), expected);
+        assert_eq!(expected.as_str(), value);
+    }
+
+    #[rstest]
+    #[case("\r")]
+    #[case("\n\r")]
+    #[case(" ")]
+    fn test_line_terminator_from_str_invalid(#[case] value: &str) {
+        assert!(LineTerminator::from_str(value).is_err());
+    }
+
+    #[rstest]
+    #[case(LineTerminator::Lf, "\n")]
+    #[case(LineTerminator::CrLf, "\r\n")]
+    #[case(LineTerminator::Empty, "")]
+    fn test_log_line_wrapper_uses_line_terminator(
+        #[case] line_terminator: LineTerminator,
+        #[case] expected: &str,
+    ) {
+        let mut wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_000_000),
+        )
+        .with_line_terminator(line_terminator);
+
+        assert_eq!(
+            wrapper.get_string(),
+            format!(
+                "1970-01-20T02:20:00.000000000Z [ERROR] TRADER-001.RiskEngine: This is an error{expected}"
+            )
+        );
+        assert!(wrapper
+            .get_colored()
+            .ends_with(&format!("\x1b[0m{expected}")));
+        assert!(wrapper.get_json().ends_with(&format!("}}{expected}")));
+    }
+
+    #[rstest]
+    fn test_line_terminator_default_is_native() {
+        let expected = if cfg!(windows) {
+            LineTerminator::CrLf
+        } else {
+            LineTerminator::Lf
+        };
+        assert_eq!(LineTerminator::default(), expected);
+        assert_eq!(LoggerConfig::default().line_terminator, expected);
+    }
+
+    #[rstest]
+    #[case(None, PathBuf::from("TRADER-001.log"))]
+    #[case(Some("logs"), Path::new("logs").join("TRADER-001.log"))]
+    #[case(Some("logs/nested"), Path::new("logs").join("nested").join("TRADER-001.log"))]
+    #[case(Some("C:\\logs"), Path::new("C:\\logs").join("TRADER-001.log"))]
+    fn test_log_file_path_joins_directory_and_file_name(
+        #[case] directory: Option<&str>,
+        #[case] expected: PathBuf,
+    ) {
+        let path = log_file_path(directory, "TRADER-001", "log");
+
+        assert_eq!(path, expected);
+        assert_eq!(path.file_name().unwrap(), "TRADER-001.log");
+    }
+
+    #[cfg(windows)]
+    #[rstest]
+    fn test_log_file_path_windows_absolute_directory() {
+        let path = log_file_path(Some("C:\\logs"), "TRADER-001", "json");
+
+        assert!(path.is_absolute());
+        assert_eq!(path.to_str().unwrap(), "C:\\logs\\TRADER-001.json");
+    }
+
+    fn error_line() -> LogLine {
+        LogLine {
+            level: log::Level::Error,
+            color: LogColor::Red,
+            component: Ustr::from("RiskEngine"),
+            message: "This is an error".to_string(),
+            file: None,
+            line: None,
+            seq: None,
+        }
+    }
+
+    fn logged_line(config: LoggerConfig) -> LogLine {
+        let (tx, rx) = std::sync::mpsc::channel::<LogEvent>();
+        let logger = Logger::new(config, LogSender::Unbounded(tx), Arc::default());
+
+        logger.log(
+            &log::Record::builder()
+                .args(format_args!("This is a test."))
+                .level(log::Level::Info)
+                .file(Some("src/risk/engine.rs"))
+                .line(Some(42))
+                .key_values(&[("component", "RiskEngine")])
+                .build(),
+        );
+
+        match rx.try_recv() {
+            Ok(LogEvent::Log(line)) => line,
+            _ => panic!("Expected a log line event"),
+        }
+    }
+
+    #[rstest]
+    fn test_source_location_when_enabled() {
+        let config = LoggerConfig::default().with_source_location(true);
+
+        let line = logged_line(config);
+        let mut wrapper = LogLineWrapper::new(
+            line,
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_000_000),
+        );
+
+        assert_eq!(
+            wrapper.get_string(),
+            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: This is a test. (src/risk/engine.rs:42)\n"
+        );
+        let json: Value = serde_json::from_str(wrapper.get_json().trim_end()).unwrap();
+        assert_eq!(json["file"], "src/risk/engine.rs");
+        assert_eq!(json["line"], "42");
+    }
+
+    #[rstest]
+    fn test_sequence_numbers_increment_by_one() {
+        let (tx, rx) = std::sync::mpsc::channel::<LogEvent>();
+        let logger = Logger::new(
+            LoggerConfig::default(),
+            LogSender::Unbounded(tx),
+            Arc::default(),
+        );
+
+        for level in [log::Level::Info, log::Level::Warn, log::Level::Error] {
+            logger.log(
+                &log::Record::builder()
+                    .args(format_args!("This is a test."))
+                    .level(level)
+                    .key_values(&[("component", "RiskEngine")])
+                    .build(),
+            );
+        }
+
+        let seqs: Vec<Option<u64>> = rx
+            .try_iter()
+            .map(|event| match event {
+                LogEvent::Log(line) => line.seq,
+                _ => panic!("Expected a log line event"),
+            })
+            .collect();
+        assert_eq!(seqs, vec![Some(0), Some(1), Some(2)]);
+    }
+
+    #[rstest]
+    #[case(LevelFilter::Info, LevelFilter::Off, None, LevelFilter::Info)]
+    #[case(LevelFilter::Warn, LevelFilter::Off, None, LevelFilter::Warn)]
+    #[case(LevelFilter::Off, LevelFilter::Off, None, LevelFilter::Error)]
+    #[case(LevelFilter::Warn, LevelFilter::Info, None, LevelFilter::Info)]
+    #[case(
+        LevelFilter::Warn,
+        LevelFilter::Warn,
+        Some(LevelFilter::Debug),
+        LevelFilter::Debug
+    )]
+    fn test_config_max_level(
+        #[case] stdout_level: LevelFilter,
+        #[case] fileout_level: LevelFilter,
+        #[case] sink_level: Option<LevelFilter>,
+        #[case] expected: LevelFilter,
+    ) {
+        let file_sinks = sink_level
+            .map(|level| FileSinkConfig::new(level, FileWriterConfig::default()))
+            .into_iter()
+            .collect();
+        let config = LoggerConfig::new(stdout_level, fileout_level, HashMap::new(), false, false)
+            .with_file_sinks(file_sinks);
+
+        assert_eq!(config.max_level(), expected);
+    }
+
+    #[rstest]
+    fn test_debug_events_skipped_when_no_output_accepts_them() {
+        let (tx, rx) = std::sync::mpsc::channel::<LogEvent>();
+        let config = LoggerConfig::new(
+            LevelFilter::Warn,
+            LevelFilter::Warn,
+            HashMap::new(),
+            false,
+            false,
+        );
+        let logger = Logger::new(config, LogSender::Unbounded(tx), Arc::default());
+
+        for level in [log::Level::Debug, log::Level::Debug, log::Level::Warn] {
+            logger.log(
+                &log::Record::builder()
+                    .args(format_args!("This is a test."))
+                    .level(level)
+                    .key_values(&[("component", "RiskEngine")])
+                    .build(),
+            );
+        }
+
+        // Skipped events are not sent, and are not assigned a sequence number
+        let lines: Vec<(Level, Option<u64>)> = rx
+            .try_iter()
+            .map(|event| match event {
+                LogEvent::Log(line) => (line.level, line.seq),
+                _ => panic!("Expected a log line event"),
+            })
+            .collect();
+        assert_eq!(lines, vec![(Level::Warn, Some(0))]);
+    }
+
+    #[rstest]
+    fn test_sequence_number_output() {
+        let line = logged_line(LoggerConfig::default());
+        assert_eq!(line.seq, Some(0));
+
+        let wrapper = || {
+            LogLineWrapper::new(
+                line.clone(),
+                Ustr::from("TRADER-001"),
+                UnixNanos::from(1_650_000_000_000_000),
+            )
+        };
+
+        assert_eq!(
+            wrapper().get_string(),
+            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: This is a test.\n"
+        );
+        assert_eq!(
+            wrapper().with_sequence_number(true).get_string(),
+            "1970-01-20T02:20:00.000000000Z [INFO] #0 TRADER-001.RiskEngine: This is a test.\n"
+        );
+        let json: Value = serde_json::from_str(wrapper().get_json().trim_end()).unwrap();
+        assert_eq!(json["seq"], 0);
+    }
+
+    #[rstest]
+    fn test_sequence_number_not_in_json_when_unassigned() {
+        let wrapper = LogLineWrapper::new(
+            error_line(),
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_000_000),
+        )
+        .with_sequence_number(true);
+
+        let json: Value = serde_json::from_str(wrapper.get_json().trim_end()).unwrap();
+        assert!(json.get("seq").is_none());
+    }
+
+    #[rstest]
+    fn test_source_location_when_disabled() {
+        let line = logged_line(LoggerConfig::default());
+        assert_eq!(line.file, None);
+        assert_eq!(line.line, None);
+
+        let mut wrapper = LogLineWrapper::new(
+            line,
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_000_000),
+        );
+
+        assert_eq!(
+            wrapper.get_string(),
+            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: This is a test.\n"
+        );
+        let json: Value = serde_json::from_str(wrapper.get_json().trim_end()).unwrap();
+        assert!(json.get("file").is_none());
+        assert!(json.get("line").is_none());
+    }
+
+    #[rstest]
+    fn log_config_parsing_with_source_location() {
+        assert!(
+            !LoggerConfig::from_spec("stdout=Info")
+                .unwrap()
+                .with_source_location
+        );
+        assert!(
+            LoggerConfig::from_spec("stdout=Info;with_source_location")
+                .unwrap()
+                .with_source_location
+        );
+    }
+
+    #[rstest]
+    fn log_config_parsing_with_sequence_number() {
+        assert!(
+            !LoggerConfig::from_spec("stdout=Info")
+                .unwrap()
+                .with_sequence_number
+        );
+        assert!(
+            LoggerConfig::from_spec("stdout=Info;with_sequence_number")
+                .unwrap()
+                .with_sequence_number
+        );
+    }
+
+    #[rstest]
+    fn log_config_parsing_queue_capacity() {
+        assert_eq!(
+            LoggerConfig::from_spec("stdout=Info")
+                .unwrap()
+                .queue_capacity,
+            None
+        );
+        assert_eq!(
+            LoggerConfig::from_spec("stdout=Info;queue_capacity=1000")
+                .unwrap()
+                .queue_capacity,
+            Some(1000)
+        );
+    }
+
+    #[rstest]
+    fn log_config_parsing_max_bytes_per_sec() {
+        let config = LoggerConfig::from_spec("stdout=Info").unwrap();
+        assert_eq!(config.max_bytes_per_sec, None);
+        assert_eq!(config.byte_limit_policy, ByteLimitPolicy::Drop);
+
+        let config = LoggerConfig::from_spec(
+            "stdout=Info;max_bytes_per_sec=1048576;byte_limit_policy=buffer",
+        )
+        .unwrap();
+        assert_eq!(config.max_bytes_per_sec, Some(1_048_576));
+        assert_eq!(config.byte_limit_policy, ByteLimitPolicy::Buffer);
+    }
+
+    #[rstest]
+    fn log_config_parsing_preallocate_bytes() {
+        let config = LoggerConfig::from_spec("stdout=Info").unwrap();
+        assert_eq!(config.preallocate_bytes, None);
+
+        let config = LoggerConfig::from_spec("stdout=Info;preallocate_bytes=1048576").unwrap();
+        assert_eq!(config.preallocate_bytes, Some(1_048_576));
+    }
+
+    #[rstest]
+    fn test_dropped_count_increments_when_queue_full() {
+        // Keep the receiver alive without draining it, so the queue fills up
+        let (tx, _rx) = std::sync::mpsc::sync_channel::<LogEvent>(2);
+        let dropped_count = Arc::new(AtomicU64::new(0));
+        let logger = Logger::new(
+            LoggerConfig::default().with_queue_capacity(Some(2)),
+            LogSender::Bounded(tx),
+            dropped_count.clone(),
+        );
+        let log_guard = LogGuard::new(None).with_dropped_count(dropped_count);
+
+        for _ in 0..5 {
+            logger.log(
+                &log::Record::builder()
+                    .args(format_args!("This is a test."))
+                    .level(log::Level::Info)
+                    .target("RiskEngine")
+                    .build(),
+            );
+        }
+
+        assert_eq!(log_guard.dropped_count(), 3);
+    }
+
+    #[rstest]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Debug, "Strategy", false)]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Info, "Strategy", true)]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Error, "Strategy", true)]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Off, "Strategy", false)]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Warning, "RiskEngine", false)]
+    #[case("stdout=Info;RiskEngine=Error", LogLevel::Error, "RiskEngine", true)]
+    #[case("stdout=Info;fileout=Debug", LogLevel::Debug, "Strategy", true)]
+    #[case("stdout=Info;fileout=Debug", LogLevel::Trace, "Strategy", false)]
+    #[case("stdout=Off;no_errors_to_stderr", LogLevel::Error, "Strategy", false)]
+    fn test_would_log(
+        #[case] spec: &str,
+        #[case] level: LogLevel,
+        #[case] component: &str,
+        #[case] expected: bool,
+    ) {
+        let log_guard = LogGuard::new(None).with_config(LoggerConfig::from_spec(spec).unwrap());
+        assert_eq!(log_guard.would_log(level, &Ustr::from(component)), expected);
+    }
+
+    #[rstest]
+    #[case("DataEngine", LogLevel::Info, false)]
+    #[case("DataEngine", LogLevel::Warning, true)]
+    #[case("DataEngine.Binance", LogLevel::Info, false)]
+    #[case("DataEngine.Binance", LogLevel::Warning, true)]
+    #[case("DataEngine.Binance.Rest", LogLevel::Info, false)]
+    #[case("DataEngine.Binance.Websocket", LogLevel::Debug, true)]
+    #[case("DataEngine.Binance.Websocket.Feed", LogLevel::Debug, true)]
+    #[case("DataEngine.Binance.Websocket", LogLevel::Trace, false)]
+    #[case("DataEngineX", LogLevel::Info, true)]
+    #[case("RiskEngine", LogLevel::Debug, true)]
+    fn test_would_log_component_hierarchy(
+        #[case] component: &str,
+        #[case] level: LogLevel,
+        #[case] expected: bool,
+    ) {
+        let config = LoggerConfig::from_spec(
+            "stdout=Debug;DataEngine=Warning;DataEngine.Binance.Websocket=Debug",
+        )
+        .unwrap();
+        let log_guard = LogGuard::new(None).with_config(config);
+
+        assert_eq!(log_guard.would_log(level, &Ustr::from(component)), expected);
+    }
+
+    #[rstest]
+    fn test_would_log_without_config() {
+        let log_guard = LogGuard::new(None);
+        assert!(!log_guard.would_log(LogLevel::Error, &Ustr::from("Strategy")));
+    }
+
+    #[rstest]
+    fn test_bypass_toggled_at_runtime() {
+        // Uses its own flag, as toggling the global flag would race with other tests
+        static BYPASSED: AtomicBool = AtomicBool::new(false);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let logger = Logger::new(
+            LoggerConfig::default(),
+            LogSender::Unbounded(tx),
+            Arc::default(),
+        )
+        .with_bypassed(&BYPASSED);
+        let log = |message| {
+            logger.log(
+                &log::Record::builder()
+                    .level(log::Level::Info)
+                    .args(format_args!("{message}"))
+                    .build(),
+            );
+        };
+
+        BYPASSED.store(true, Ordering::Relaxed);
+        log("Muted");
+        BYPASSED.store(false, Ordering::Relaxed);
+        log("Unmuted");
+
+        let messages: Vec<String> = rx
+            .try_iter()
+            .filter_map(|event| match event {
+                LogEvent::Log(line) => Some(line.message),
+                LogEvent::Flush | LogEvent::Reopen => None,
+            })
+            .collect();
+        assert_eq!(messages, vec!["Unmuted"]);
+    }
+
+    #[rstest]
+    fn test_error_line_written_to_stderr_only_by_default() {
+        let line = error_line();
+        let stdout_writer = StdoutWriter::new(LevelFilter::Info, false);
+        let stderr_writer = StderrWriter::new(false);
+
+        assert!(stderr_writer.enabled(&line));
+        assert!(!stdout_writer.enabled(&line));
+    }
+
+    #[rstest]
+    fn test_error_line_mirrored_to_stdout() {
+        let line = error_line();
+        let stdout_writer = StdoutWriter::new(LevelFilter::Info, false).with_errors(true);
+        let stderr_writer = StderrWriter::new(false);
+
+        assert!(stderr_writer.enabled(&line));
+        assert!(stdout_writer.enabled(&line));
+    }
+
+    #[rstest]
+    fn test_component_color_applied_to_normal_lines() {
+        let component_colors = HashMap::from([(Ustr::from("RiskEngine"), LogColor::Cyan)]);
+        let line = LogLine {
+            level: log::Level::Info,
+            color: LogColor::Normal,
+            component: Ustr::from("RiskEngine"),
+            message: "This is a test".to_string(),
+            file: None,
+            line: None,
+            seq: None,
+        };
+
+        let line = apply_component_color(&component_colors, line);
+        let mut wrapper = LogLineWrapper::new(
+            line,
+            Ustr::from("TRADER-001"),
+            UnixNanos::from(1_650_000_000_000_000),
+        );
+
+        assert_eq!(wrapper.line.color, LogColor::Cyan);
+        assert!(wrapper
+            .get_colored()
+            .contains("\x1b[36m[INFO] TRADER-001.RiskEngine: This is a test"));
+    }
+
+    #[rstest]
+    fn test_component_color_does_not_override_explicit_or_severity_color() {
+        let component_colors = HashMap::from([(Ustr::from("RiskEngine"), LogColor::Cyan)]);
+        let explicit = LogLine {
+            level: log::Level::Info,
+            color: LogColor::Green,
+            component: Ustr::from("RiskEngine"),
+            message: "This is a test".to_string(),
+            file: None,
+            line: None,
+            seq: None,
+        };
+        let warning = LogLine {
+            level: log::Level::Warn,
+            color: LogColor::Normal,
+            component: Ustr::from("RiskEngine"),
+            message: "This is a warning".to_string(),
+            file: None,
+            line: None,
+            seq: None,
+        };
+
+        assert_eq!(
+            apply_component_color(&component_colors, explicit).color,
+            LogColor::Green
+        );
+        assert_eq!(
+            apply_component_color(&component_colors, warning).color,
+            LogColor::Normal
+        );
+        assert_eq!(
+            apply_component_color(&component_colors, error_line()).color,
+            LogColor::Red
+        );
+    }
+
+    #[rstest]
+    fn test_stdout_with_errors_still_respects_level() {
+        let stdout_writer = StdoutWriter::new(LevelFilter::Off, false).with_errors(true);
+
+        assert!(!stdout_writer.enabled(&error_line()));
+    }
+
+    const STDERR_CHILD_ENV: &str = "NAUTILUS_TEST_STDERR_CHILD";
+
+    #[rstest]
+    fn test_error_event_written_to_stderr() {
+        if std::env::var_os(STDERR_CHILD_ENV).is_some() {
+            // Runs in the child process spawned below, which owns its stdio
+            let log_guard = Logger::init_with_config(
+                TraderId::from("TRADER-001"),
+                UUID4::new(),
+                LoggerConfig::default(),
+                FileWriterConfig::default(),
+            )
+            .unwrap();
+            log::info!(component = "RiskEngine"; "This is info.");
+            log::error!(component = "RiskEngine"; "This is an error.");
+            drop(log_guard); // Ensure log buffers are flushed
+            return;
+        }
+
+        let output = std::process::Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "logging::logger::tests::test_error_event_written_to_stderr",
+                "--nocapture",
+            ])
+            .env(STDERR_CHILD_ENV, "1")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stderr.contains("[ERROR] TRADER-001.RiskEngine: This is an error."));
+        assert!(!stderr.contains("This is info."));
+        assert!(!stdout.contains("This is an error."));
+    }
+
+    #[rstest]
+    fn test_init_with_env_invalid_variable() {
+        let init = || {
+            Logger::init_with_env(
+                TraderId::from("TRADER-001"),
+                UUID4::new(),
+                FileWriterConfig::default(),
+            )
+        };
+
+        env::remove_var("NAUTILUS_LOG");
+        let result = init();
+        assert!(matches!(
+            result,
+            Err(LogError::EnvVar(VarError::NotPresent))
+        ));
+
+        #[cfg(unix)]
+        {
+            use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+            env::set_var("NAUTILUS_LOG", OsStr::from_bytes(b"stdout=\xFF"));
+            let result = init();
+            env::remove_var("NAUTILUS_LOG");
+            assert!(matches!(
+                result,
+                Err(LogError::EnvVar(VarError::NotUnicode(_)))
+            ));
+        }
+    }
+
+    #[rstest]
+    fn test_init_when_already_initialized() {
+        let init = || {
+            Logger::init_with_config(
+                TraderId::from("TRADER-001"),
+                UUID4::new(),
+                LoggerConfig::default(),
+                FileWriterConfig::default(),
+            )
+        };
+
+        let _log_guard = init().unwrap();
+        let error = init().unwrap_err();
+
+        assert!(matches!(error, LogError::AlreadyInitialized(_)));
+        assert_eq!(error.status(), LogStatus::AlreadyInitialized);
+    }
+
+    #[rstest]
+    fn test_logging_to_file() {
+        let config = LoggerConfig {
+            fileout_level: LevelFilter::Debug,
+            ..Default::default()
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        log::info!(
+            component = "RiskEngine";
+            "This is a test."
+        );
+
+        let mut log_contents = String::new();
+
+        wait_until(
+            || {
+                std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .any(|entry| entry.path().is_file())
+            },
+            Duration::from_secs(2),
+        );
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        wait_until(
+            || {
+                let log_file_path = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                    .expect("No files found in directory")
+                    .path();
+                dbg!(&log_file_path);
+                log_contents =
+                    std::fs::read_to_string(log_file_path).expect("Error while reading log file");
+                !log_contents.is_empty()
+            },
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(
+            without_banner(&log_contents),
+            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: This is a test.\n"
+        );
+    }
+
+    #[rstest]
+    fn test_reopen_log_file_after_rename() {
+        let config = LoggerConfig {
+            stdout_level: LevelFilter::Off,
+            fileout_level: LevelFilter::Info,
+            ..Default::default()
+        };
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+        let log_path = temp_dir.path().join("trader.log");
+        let rotated_path = temp_dir.path().join("trader.log.1");
+
+        let file_writers = Logger::create_file_writers(
+            "TRADER-001",
+            &UUID4::new().to_string(),
+            &config,
+            file_config,
+        )
+        .unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            Logger::handle_messages("TRADER-001".to_string(), config, file_writers, rx);
+        });
+        let logger = Logger::new(
+            LoggerConfig::default(),
+            LogSender::Unbounded(tx.clone()),
+            Arc::default(),
+        );
+        let log = |message| {
+            logger.log(
+                &log::Record::builder()
+                    .level(log::Level::Info)
+                    .args(format_args!("{message}"))
+                    .key_values(&[("component", "RiskEngine")])
+                    .build(),
+            );
+        };
+
+        log("Before rotation");
+        wait_until(|| log_path.exists(), Duration::from_secs(2));
+        std::fs::rename(&log_path, &rotated_path).unwrap();
+        set_logger_tx(Some(LogSender::Unbounded(tx)));
+        logging_reopen();
+        set_logger_tx(None);
+        log("After rotation");
+        logger.flush();
+        handle.join().unwrap();
+
+        let rotated_contents = std::fs::read_to_string(&rotated_path).unwrap();
+        let log_contents = std::fs::read_to_string(&log_path).unwrap();
+        assert!(rotated_contents.ends_with("TRADER-001.RiskEngine: Before rotation\n"));
+        assert!(!rotated_contents.contains("After rotation"));
+        assert!(log_contents.ends_with("TRADER-001.RiskEngine: After rotation\n"));
+        assert!(!log_contents.contains("Before rotation"));
+    }
+
+    #[rstest]
+    fn test_log_file_preallocated_and_truncated_to_content() {
+        let config = LoggerConfig {
+            stdout_level: LevelFilter::Off,
+            fileout_level: LevelFilter::Info,
+            ..Default::default()
+        }
+        .with_preallocate_bytes(Some(4096));
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+        let log_path = temp_dir.path().join("trader.log");
+
+        let file_writers = Logger::create_file_writers(
+            "TRADER-001",
+            &UUID4::new().to_string(),
+            &config,
+            file_config,
+        )
+        .unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            Logger::handle_messages("TRADER-001".to_string(), config, file_writers, rx);
+        });
+        let logger = Logger::new(
+            LoggerConfig::default(),
+            LogSender::Unbounded(tx),
+            Arc::default(),
+        );
+
+        wait_until(
+            || {
+                log_path
+                    .metadata()
+                    .is_ok_and(|metadata| metadata.len() == 4096)
+            },
+            Duration::from_secs(2),
+        );
+
+        for message in ["First line", "Second line"] {
+            logger.log(
+                &log::Record::builder()
+                    .level(log::Level::Info)
+                    .args(format_args!("{message}"))
+                    .key_values(&[("component", "RiskEngine")])
+                    .build(),
+            );
+        }
+        logger.flush();
+        handle.join().unwrap();
+
+        let log_contents = std::fs::read_to_string(&log_path).unwrap();
+        assert_eq!(
+            log_path.metadata().unwrap().len(),
+            log_contents.len() as u64
+        );
+        assert!(!log_contents.contains('\0'));
+        assert!(log_contents.contains("TRADER-001.RiskEngine: First line\n"));
+        assert!(log_contents.ends_with("TRADER-001.RiskEngine: Second line\n"));
+    }
+
+    #[rstest]
+    fn test_file_writer_preallocation_tracks_written_offset() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+        let log_path = temp_dir.path().join("trader.log");
+        let allocated_len = || log_path.metadata().unwrap().len();
+        let new_writer = || {
+            FileWriter::new(
+                "TRADER-001".to_string(),
+                UUID4::new().to_string(),
+                file_config.clone(),
+                LevelFilter::Info,
+            )
+            .unwrap()
+            .with_preallocation(Some(16))
+        };
+
+        let mut writer = new_writer();
+        assert_eq!(writer.file_size(), 0);
+        assert_eq!(allocated_len(), 16);
+
+        writer.write("0123456789\n");
+        assert_eq!(writer.file_size(), 11);
+        assert_eq!(allocated_len(), 16);
+
+        // Grows by the pre-allocation size when the next line does not fit
+        writer.write("0123456789\n");
+        assert_eq!(writer.file_size(), 22);
+        assert_eq!(allocated_len(), 38);
+
+        drop(writer);
+        assert_eq!(allocated_len(), 22);
+
+        // Continues writing from the end of the existing content
+        let mut writer = new_writer();
+        assert_eq!(writer.file_size(), 22);
+        assert_eq!(allocated_len(), 38);
+        writer.write("abc\n");
+        drop(writer);
+
+        assert_eq!(
+            std::fs::read_to_string(&log_path).unwrap(),
+            "0123456789\n0123456789\nabc\n"
+        );
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(16))]
+    fn test_file_writer_ignores_preallocated_space_left_by_crash(
+        #[case] preallocate_bytes: Option<u64>,
+    ) {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+        let log_path = temp_dir.path().join("trader.log");
+        // A file left pre-allocated by a process which exited without truncating it
+        let mut contents = b"0123456789\n".to_vec();
+        contents.resize(10_000, 0);
+        std::fs::write(&log_path, contents).unwrap();
+
+        let mut writer = FileWriter::new(
+            "TRADER-001".to_string(),
+            UUID4::new().to_string(),
+            file_config,
+            LevelFilter::Info,
+        )
+        .unwrap()
+        .with_preallocation(preallocate_bytes);
+        assert_eq!(writer.file_size(), 11);
+
+        writer.write("abc\n");
+        drop(writer);
+
+        assert_eq!(
+            std::fs::read_to_string(&log_path).unwrap(),
+            "0123456789\nabc\n"
+        );
+    }
+
+    #[rstest]
+    fn test_file_writer_directory_under_file_errors() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_path = temp_dir.path().join("not_a_directory");
+        std::fs::write(&file_path, "").unwrap();
+        let file_config = FileWriterConfig {
+            directory: Some(file_path.join("logs").to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let result = FileWriter::new(
+            "TRADER-001".to_string(),
+            UUID4::new().to_string(),
+            file_config,
+            LevelFilter::Info,
+        );
+
+        assert!(matches!(result, Err(LogError::CreateDirectory { .. })));
+    }
+
+    #[rstest]
+    fn test_file_writer_path_is_directory_errors() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        std::fs::create_dir(temp_dir.path().join("trader.log")).unwrap();
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+
+        let result = FileWriter::new(
+            "TRADER-001".to_string(),
+            UUID4::new().to_string(),
+            file_config,
+            LevelFilter::Info,
+        );
+
+        assert!(matches!(result, Err(LogError::FileOpen { ref path, .. }) if path.is_dir()));
+    }
+
+    fn log_lines_with_byte_limit(policy: ByteLimitPolicy, count: usize) -> Vec<String> {
+        // Each line is 71 bytes, so two lines fit within the budget
+        let config = LoggerConfig {
+            stdout_level: LevelFilter::Off,
+            fileout_level: LevelFilter::Info,
+            ..Default::default()
+        }
+        .with_max_bytes_per_sec(Some(150), policy);
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_name: Some("trader".to_string()),
+            ..Default::default()
+        };
+
+        // All events share the static timestamp, so fall within a single window
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        let file_writers = Logger::create_file_writers(
+            "TRADER-001",
+            &UUID4::new().to_string(),
+            &config,
+            file_config,
+        )
+        .unwrap();
+        let (tx, rx) = std::sync::mpsc::channel();
+        let handle = std::thread::spawn(move || {
+            Logger::handle_messages("TRADER-001".to_string(), config, file_writers, rx);
+        });
+        let logger = Logger::new(
+            LoggerConfig::default(),
+            LogSender::Unbounded(tx),
+            Arc::default(),
+        );
+
+        for i in 0..count {
+            logger.log(
+                &log::Record::builder()
+                    .level(log::Level::Info)
+                    .args(format_args!("Message {i}"))
+                    .key_values(&[("component", "RiskEngine")])
+                    .build(),
+            );
+        }
+        logger.flush();
+        handle.join().unwrap();
+
+        std::fs::read_to_string(temp_dir.path().join("trader.log"))
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[rstest]
+    fn test_max_bytes_per_sec_drops_excess_lines() {
+        let lines = log_lines_with_byte_limit(ByteLimitPolicy::Drop, 10);
+
+        assert_eq!(
+            lines,
+            [
+                "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: Message 0",
+                "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: Message 1",
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_max_bytes_per_sec_buffers_excess_lines_until_flushed() {
+        // Two lines are written, two buffered (then written on flush) and the rest dropped
+        let lines = log_lines_with_byte_limit(ByteLimitPolicy::Buffer, 10);
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[3].ends_with("Message 3"));
+    }
+
+    #[cfg(feature = "ffi")]
+    #[rstest]
+    fn test_logging_invalid_utf8_message_to_file() {
+        let config = LoggerConfig {
+            fileout_level: LevelFilter::Debug,
+            ..Default::default()
+        };
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        let component = std::ffi::CString::new(b"Risk\xFFEngine".to_vec()).unwrap();
+        let message = std::ffi::CString::new(b"Bad bytes \xC3\x28 here".to_vec()).unwrap();
+        unsafe {
+            crate::ffi::logging::logger_log(
+                LogLevel::Info,
+                LogColor::Normal,
+                component.as_ptr(),
+                message.as_ptr(),
+            );
+        }
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        let mut log_contents = String::new();
+        wait_until(
+            || {
+                if let Some(log_file) = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                {
+                    log_contents = std::fs::read_to_string(log_file.path())
+                        .expect("Error while reading log file");
+                    !log_contents.is_empty()
+                } else {
+                    false
+                }
+            },
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(
+            without_banner(&log_contents),
+            "1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.Risk\u{FFFD}Engine: Bad bytes \u{FFFD}( here\n"
+        );
+    }
+
+    #[rstest]
+    fn test_log_component_level_filtering() {
+        let config = LoggerConfig::from_spec("stdout=Info;fileout=Debug;RiskEngine=Error").unwrap();
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        log::info!(
+            component = "RiskEngine";
+            "This is a test."
+        );
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        wait_until(
+            || {
+                if let Some(log_file) = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                {
+                    let log_file_path = log_file.path();
+                    let log_contents = std::fs::read_to_string(log_file_path)
+                        .expect("Error while reading log file");
+                    !without_banner(&log_contents).contains("RiskEngine")
+                } else {
+                    false
+                }
+            },
+            Duration::from_secs(3),
+        );
+
+        assert!(
+            std::fs::read_dir(&temp_dir)
+                .expect("Failed to read directory")
+                .filter_map(Result::ok)
+                .any(|entry| entry.path().is_file()),
+            "Log file exists"
+        );
+    }
+
+    #[rstest]
+    fn test_logging_to_file_in_json_format() {
+        let config =
+            LoggerConfig::from_spec("stdout=Info;is_colored;fileout=Debug;RiskEngine=Info")
+                .unwrap();
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_format: Some("json".to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        log::info!(
+            component = "RiskEngine";
+            "This is a test."
+        );
+
+        let mut log_contents = String::new();
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        wait_until(
+            || {
+                if let Some(log_file) = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                {
+                    let log_file_path = log_file.path();
+                    log_contents = std::fs::read_to_string(log_file_path)
+                        .expect("Error while reading log file");
+                    !log_contents.is_empty()
+                } else {
+                    false
+                }
+            },
+            Duration::from_secs(2),
+        );
+
+        assert_eq!(
+        without_banner(&log_contents),
+        "{\"timestamp\":\"1970-01-20T02:20:00.000000000Z\",\"seq\":1,\"trader_id\":\"TRADER-001\",\"level\":\"INFO\",\"color\":\"NORMAL\",\"component\":\"RiskEngine\",\"message\":\"This is a test.\"}\n"
+    );
+    }
+
+    #[rstest]
+    fn test_logging_to_file_in_csv_format() {
+        let config =
+            LoggerConfig::from_spec("stdout=Info;fileout=Debug;line_terminator=\\n").unwrap();
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_format: Some("csv".to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        log::warn!(
+            component = "RiskEngine";
+            "Order denied, \"price\" exceeds limit"
+        );
+
+        let mut log_contents = String::new();
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        wait_until(
+            || {
+                if let Some(log_file) = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                {
+                    assert_eq!(log_file.path().extension().unwrap(), "csv");
+                    log_contents = std::fs::read_to_string(log_file.path())
+                        .expect("Error while reading log file");
+                    log_contents.contains("RiskEngine")
+                } else {
+                    false
+                }
+            },
+            Duration::from_secs(2),
+        );
+
+        assert!(log_contents.starts_with(&format!("{CSV_HEADER}\n")));
+        assert_eq!(log_contents.matches(CSV_HEADER).count(), 1);
+        assert!(log_contents.ends_with(
+            "1970-01-20T02:20:00.000000000Z,WARN,RiskEngine,\"Order denied, \"\"price\"\" exceeds limit\"\n"
+        ));
+    }
+
+    #[rstest]
+    fn test_startup_banner_fields() {
+        let config = LoggerConfig::from_spec("stdout=Info;fileout=Debug;RiskEngine=Error").unwrap();
+        let instance_id = UUID4::new();
+
+        let banner =
+            Logger::startup_banner(TraderId::from("TRADER-001"), "host-1", instance_id, &config);
+        let json: Value = serde_json::from_str(&banner).unwrap();
+
+        assert_eq!(json["event"], "logger_started");
+        assert_eq!(json["trader_id"], "TRADER-001");
+        assert_eq!(json["machine_id"], "host-1");
+        assert_eq!(json["instance_id"], instance_id.to_string());
+        assert_eq!(json["stdout_level"], "INFO");
+        assert_eq!(json["fileout_level"], "DEBUG");
+        assert_eq!(json["component_levels"]["RiskEngine"], "ERROR");
+        assert_eq!(json["file_logging"], true);
+    }
+
+    #[rstest]
+    fn test_startup_banner_logged_on_init() {
+        let config = LoggerConfig::from_spec("stdout=Off;fileout=Info").unwrap();
+        let instance_id = UUID4::new();
+
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let file_config = FileWriterConfig {
+            directory: Some(temp_dir.path().to_str().unwrap().to_string()),
+            file_format: Some("json".to_string()),
+            ..Default::default()
+        };
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            instance_id,
+            config,
+            file_config,
+        )
+        .unwrap();
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        let mut log_contents = String::new();
+        wait_until(
+            || {
+                if let Some(log_file) = std::fs::read_dir(&temp_dir)
+                    .expect("Failed to read directory")
+                    .filter_map(Result::ok)
+                    .find(|entry| entry.path().is_file())
+                {
+                    log_contents = std::fs::read_to_string(log_file.path())
+                        .expect("Error while reading log file");
+                    !log_contents.is_empty()
+                } else {
+                    false
+                }
+            },
+            Duration::from_secs(2),
+        );
+
+        let line: Value = serde_json::from_str(log_contents.lines().next().unwrap()).unwrap();
+        assert_eq!(line["level"], "INFO");
+        assert_eq!(line["component"], "Logger");
+
+        let banner: Value = serde_json::from_str(line["message"].as_str().unwrap()).unwrap();
+        assert_eq!(banner["event"], "logger_started");
+        assert_eq!(banner["trader_id"], "TRADER-001");
+        assert_eq!(banner["instance_id"], instance_id.to_string());
+        assert_eq!(banner["stdout_level"], "OFF");
+        assert_eq!(banner["fileout_level"], "INFO");
+        assert_eq!(banner["file_logging"], true);
+        assert!(banner["machine_id"].is_string());
+    }
+
+    #[rstest]
+    fn test_parse_file_sinks() {
+        let values: Vec<Value> = serde_json::from_str(
+            r#"[
+                {"level": "DEBUG", "directory": "logs", "file_name": "forensics"},
+                {"level": "Warning", "file_name": "summary", "file_format": "json"},
+                {"file_name": "missing_level"}
+            ]"#,
+        )
+        .unwrap();
+
+        let sinks = crate::logging::parse_file_sinks(Some(values));
+
+        assert_eq!(
+            sinks,
+            vec![
+                FileSinkConfig::new(
+                    LevelFilter::Debug,
+                    FileWriterConfig::new(
+                        Some("logs".to_string()),
+                        Some("forensics".to_string()),
+                        None
+                    ),
+                ),
+                FileSinkConfig::new(
+                    LevelFilter::Warn,
+                    FileWriterConfig::new(
+                        None,
+                        Some("summary".to_string()),
+                        Some("json".to_string())
+                    ),
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_max_file_level_includes_sinks() {
+        let config = LoggerConfig {
+            fileout_level: LevelFilter::Info,
+            ..Default::default()
+        };
+        assert_eq!(config.max_file_level(), LevelFilter::Info);
+
+        let config = config.with_file_sinks(vec![
+            FileSinkConfig::new(LevelFilter::Debug, FileWriterConfig::default()),
+            FileSinkConfig::new(LevelFilter::Warn, FileWriterConfig::default()),
+        ]);
+        assert_eq!(config.max_file_level(), LevelFilter::Debug);
+    }
+
+    #[rstest]
+    fn test_logging_to_multiple_file_sinks() {
+        let temp_dir = tempdir().expect("Failed to create temporary directory");
+        let directory = Some(temp_dir.path().to_str().unwrap().to_string());
+        let config = LoggerConfig {
+            stdout_level: LevelFilter::Off,
+            ..Default::default()
+        }
+        .with_file_sinks(vec![
+            FileSinkConfig::new(
+                LevelFilter::Debug,
+                FileWriterConfig::new(directory.clone(), Some("forensics".to_string()), None),
+            ),
+            FileSinkConfig::new(
+                LevelFilter::Warn,
+                FileWriterConfig::new(directory, Some("summary".to_string()), None),
+            ),
+        ]);
+
+        let log_guard = Logger::init_with_config(
+            TraderId::from("TRADER-001"),
+            UUID4::new(),
+            config,
+            FileWriterConfig::default(),
+        )
+        .unwrap();
+
+        logging_clock_set_static_mode();
+        logging_clock_set_static_time(1_650_000_000_000_000);
+
+        log::debug!(component = "RiskEngine"; "Debug message.");
+        log::info!(component = "RiskEngine"; "Info message.");
+        log::warn!(component = "RiskEngine"; "Warning message.");
+        log::error!(component = "RiskEngine"; "Error message.");
+
+        drop(log_guard); // Ensure log buffers are flushed
+
+        let read_log = |file_name: &str| {
+            let path = temp_dir.path().join(file_name);
+            let mut log_contents = String::new();
+            wait_until(
+                || {
+                    log_contents = std::fs::read_to_string(&path).unwrap_or_default();
+                    log_contents.contains("Error message.")
+                },
+                Duration::from_secs(2),
+            );
+            without_banner(&log_contents)
+        };
+
+        assert_eq!(
+            read_log("forensics.log"),
+            "1970-01-20T02:20:00.000000000Z [DEBUG] TRADER-001.RiskEngine: Debug message.\n\
+             1970-01-20T02:20:00.000000000Z [INFO] TRADER-001.RiskEngine: Info message.\n\
+             1970-01-20T02:20:00.000000000Z [WARN] TRADER-001.RiskEngine: Warning message.\n\
+             1970-01-20T02:20:00.000000000Z [ERROR] TRADER-001.RiskEngine: Error message.\n"
+        );
+        assert_eq!(
+            read_log("summary.log"),
+            "1970-01-20T02:20:00.000000000Z [WARN] TRADER-001.RiskEngine: Warning message.\n\
+             1970-01-20T02:20:00.000000000Z [ERROR] TRADER-001.RiskEngine: Error message.\n"
+        );
     }
 }
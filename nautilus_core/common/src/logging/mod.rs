@@ -29,13 +29,16 @@ use tracing_subscriber::EnvFilter;
 use ustr::Ustr;
 
 use self::{
+    error::LogError,
     logger::{LogGuard, Logger, LoggerConfig},
-    writer::FileWriterConfig,
+    writer::{FileSinkConfig, FileWriterConfig},
 };
-use crate::enums::LogLevel;
+use crate::enums::{LogColor, LogLevel};
 
+pub mod error;
 pub mod headers;
 pub mod logger;
+pub mod ratelimit;
 pub mod writer;
 
 pub const RECV: &str = "<--";
@@ -64,6 +67,28 @@ pub extern "C" fn logging_set_bypass() {
     LOGGING_BYPASSED.store(true, Ordering::Relaxed);
 }
 
+/// Returns whether the logging system is in bypass mode.
+#[no_mangle]
+pub extern "C" fn logging_is_bypassed() -> u8 {
+    u8::from(LOGGING_BYPASSED.load(Ordering::Relaxed))
+}
+
+/// Sets whether the logging system is in bypass mode, so logging can be muted temporarily
+/// (e.g. during a noisy replay) without reinitializing the logger.
+#[no_mangle]
+pub extern "C" fn logging_set_bypassed(bypassed: u8) {
+    LOGGING_BYPASSED.store(bypassed != 0, Ordering::Relaxed);
+}
+
+/// Reopens the log file at its configured path, so that writes go to a new file after
+/// external log rotation (e.g. `logrotate` moving the file and signalling the process).
+///
+/// The reopen is posted to the logging thread, so is applied after any pending log events.
+#[no_mangle]
+pub extern "C" fn logging_reopen() {
+    logger::reopen_log_file();
+}
+
 /// Shuts down the logging system.
 #[no_mangle]
 pub extern "C" fn logging_shutdown() {
@@ -129,15 +154,23 @@ pub fn init_tracing() {
 ///
 /// Should only be called once during an applications run, ideally at the
 /// beginning of the run.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If a log file cannot be opened.
+/// - If a logger has already been initialized.
 pub fn init_logging(
     trader_id: TraderId,
     instance_id: UUID4,
     config: LoggerConfig,
     file_config: FileWriterConfig,
-) -> LogGuard {
+) -> Result<LogGuard, LogError> {
+    let is_colored = config.is_colored;
+    let log_guard = Logger::init_with_config(trader_id, instance_id, config, file_config)?;
     LOGGING_INITIALIZED.store(true, Ordering::Relaxed);
-    LOGGING_COLORED.store(config.is_colored, Ordering::Relaxed);
-    Logger::init_with_config(trader_id, instance_id, config, file_config)
+    LOGGING_COLORED.store(is_colored, Ordering::Relaxed);
+    Ok(log_guard)
 }
 
 #[must_use]
@@ -152,30 +185,190 @@ pub const fn map_log_level_to_filter(log_level: LogLevel) -> LevelFilter {
     }
 }
 
+/// Parses a log level filter from a level name such as `"INFO"` or `"WARNING"` (case-insensitive).
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `s` is not a valid log level name.
+pub fn parse_level_filter_str(s: &str) -> Result<LevelFilter, LogError> {
+    try_parse_level_filter_str(s).ok_or_else(|| LogError::InvalidLevel(s.to_string()))
+}
+
+fn try_parse_level_filter_str(s: &str) -> Option<LevelFilter> {
+    match s.to_uppercase().as_str() {
+        "WARNING" => Some(LevelFilter::Warn),
+        level => LevelFilter::from_str(level).ok(),
+    }
+}
+
+/// Parses a component levels JSON map, where values are log level names (e.g. `"DEBUG"`).
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If any value is not a valid log level name.
+pub fn parse_component_levels(
+    original_map: Option<HashMap<String, serde_json::Value>>,
+) -> Result<HashMap<Ustr, LevelFilter>, LogError> {
+    let mut new_map = HashMap::new();
+    for (key, value) in original_map.unwrap_or_default() {
+        let level = value
+            .as_str()
+            .ok_or_else(|| LogError::InvalidLevel(value.to_string()))
+            .and_then(parse_level_filter_str)?;
+        new_map.insert(Ustr::from(&key), level);
+    }
+    Ok(new_map)
+}
+
+/// Parses a JSON array of file sinks, where each sink is an object with a `level` and
+/// optional `directory`, `file_name` and `file_format` (e.g.
+/// `[{"level": "DEBUG", "file_name": "forensics"}]`).
+///
+/// Sinks without a valid level are ignored.
 #[must_use]
-pub fn parse_level_filter_str(s: &str) -> LevelFilter {
-    let mut log_level_str = s.to_string().to_uppercase();
-    if log_level_str == "WARNING" {
-        log_level_str = "WARN".to_string();
+pub fn parse_file_sinks(values: Option<Vec<serde_json::Value>>) -> Vec<FileSinkConfig> {
+    let mut sinks = Vec::new();
+    for value in values.unwrap_or_default() {
+        let Some(level) = value
+            .get("level")
+            .and_then(serde_json::Value::as_str)
+            .and_then(try_parse_level_filter_str)
+        else {
+            eprintln!("Invalid log file sink level, was {value}");
+            continue;
+        };
+        let get_string = |key: &str| {
+            value
+                .get(key)
+                .and_then(serde_json::Value::as_str)
+                .map(ToString::to_string)
+        };
+        let file_config = FileWriterConfig::new(
+            get_string("directory"),
+            get_string("file_name"),
+            get_string("file_format"),
+        );
+        sinks.push(FileSinkConfig::new(level, file_config));
     }
-    LevelFilter::from_str(&log_level_str)
-        .unwrap_or_else(|_| panic!("Invalid `LevelFilter` string, was {log_level_str}"))
+    sinks
 }
 
+/// Parses a component colors JSON map, where values are log color names (e.g. `"CYAN"`).
+///
+/// Values which are not valid log color names are ignored.
 #[must_use]
-pub fn parse_component_levels(
+pub fn parse_component_colors(
     original_map: Option<HashMap<String, serde_json::Value>>,
-) -> HashMap<Ustr, LevelFilter> {
-    match original_map {
-        Some(map) => {
-            let mut new_map = HashMap::new();
-            for (key, value) in map {
-                let ustr_key = Ustr::from(&key);
-                let value = parse_level_filter_str(value.as_str().unwrap());
-                new_map.insert(ustr_key, value);
+) -> HashMap<Ustr, LogColor> {
+    let mut new_map = HashMap::new();
+    for (key, value) in original_map.unwrap_or_default() {
+        let Some(color) = value.as_str().and_then(|v| LogColor::from_str(v).ok()) else {
+            eprintln!("Invalid log color for {key}, was {value}");
+            continue;
+        };
+        new_map.insert(Ustr::from(&key), color);
+    }
+    new_map
+}
+
+/// Removes all ANSI SGR escape sequences (`\x1b[...m`) from the given string.
+///
+/// This removes the [`LogColor`] and [`LogFormat`](crate::enums::LogFormat) codes along with any
+/// other SGR parameters, so the output is safe to write to sinks or pipe into tools which do not
+/// support ANSI codes. Escape characters not starting a complete SGR sequence are left unchanged.
+#[must_use]
+pub fn strip_ansi(input: &str) -> String {
+    let mut stripped = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find("\x1b[") {
+        stripped.push_str(&rest[..start]);
+        let params = &rest[start + 2..];
+        let params_len = params
+            .find(|c: char| !(c.is_ascii_digit() || c == ';' || c == ':'))
+            .unwrap_or(params.len());
+
+        if params[params_len..].starts_with('m') {
+            rest = &params[params_len + 1..];
+        } else {
+            stripped.push('\x1b');
+            rest = &rest[start + 1..];
+        }
+    }
+    stripped.push_str(rest);
+    stripped
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::LogFormat;
+
+    #[rstest]
+    fn test_strip_ansi_colored_and_formatted() {
+        let line = format!(
+            "{} {}[WARN] TRADER-001.RiskEngine: {}{}",
+            LogFormat::Bold.wrap("2024-01-01T00:00:00.000000000Z"),
+            LogColor::Yellow.as_ansi(),
+            LogFormat::Header.wrap("Throttled"),
+            LogFormat::Endc.as_ansi(),
+        );
+
+        assert_eq!(
+            strip_ansi(&line),
+            "2024-01-01T00:00:00.000000000Z [WARN] TRADER-001.RiskEngine: Throttled"
+        );
+    }
+
+    #[rstest]
+    #[case("\x1b[38;5;208morange\x1b[0m", "orange")]
+    #[case("\x1b[38:2:255:0:0mred\x1b[m", "red")]
+    #[case("\x1b[1;4;31mbold\x1b[22m text", "bold text")]
+    #[case("plain [text] 100m", "plain [text] 100m")]
+    #[case("", "")]
+    fn test_strip_ansi_unknown_codes(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(strip_ansi(input), expected);
+    }
+
+    #[rstest]
+    #[case("\x1b[2Kcleared", "\x1b[2Kcleared")]
+    #[case("trailing \x1b[31", "trailing \x1b[31")]
+    #[case("lone \x1b escape", "lone \x1b escape")]
+    #[case("\x1b\x1b[0mdouble", "\x1bdouble")]
+    fn test_strip_ansi_leaves_non_sgr_sequences(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(strip_ansi(input), expected);
+    }
+
+    #[rstest]
+    fn test_strip_ansi_roundtrip_all_codes() {
+        let colors = [
+            LogColor::Normal,
+            LogColor::Green,
+            LogColor::Blue,
+            LogColor::Magenta,
+            LogColor::Cyan,
+            LogColor::Yellow,
+            LogColor::Red,
+        ];
+        let formats = [
+            LogFormat::Header,
+            LogFormat::Endc,
+            LogFormat::Bold,
+            LogFormat::Underline,
+        ];
+
+        for color in colors {
+            for format in formats {
+                let text = format!("{}{}", color.as_ansi(), format.wrap("héllo ✓"));
+                assert_eq!(strip_ansi(&text), "héllo ✓");
             }
-            new_map
         }
-        None => HashMap::new(),
     }
 }
@@ -0,0 +1,454 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Rate limiting of repeated identical log messages, and of the bytes written to log files.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    hash::{Hash, Hasher},
+    str::FromStr,
+};
+
+use nautilus_core::nanos::UnixNanos;
+use ustr::Ustr;
+
+use crate::logging::{logger::LogLine, writer::LogWriter};
+
+/// The key in a rate limits JSON map for the default (fallback) limit.
+pub const RATE_LIMIT_DEFAULT_KEY: &str = "default";
+
+/// The window over which identical messages are counted (1 second).
+pub const RATE_LIMIT_WINDOW_NS: u64 = 1_000_000_000;
+
+/// The maximum number of distinct messages tracked per component within a window.
+///
+/// Once reached, further distinct messages for the component are not limited until the
+/// next window, which bounds the memory used by each component's counter.
+pub const RATE_LIMIT_MAX_TRACKED: usize = 1_000;
+
+/// Configuration for limiting the number of identical messages logged per component.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// The default max identical messages per window for components without an override
+    /// (`None` for no limit).
+    pub default_limit: Option<u32>,
+    /// The per-component max identical messages per window.
+    pub component_limits: HashMap<Ustr, u32>,
+}
+
+impl RateLimitConfig {
+    /// Creates a new [`RateLimitConfig`] instance.
+    #[must_use]
+    pub const fn new(default_limit: Option<u32>, component_limits: HashMap<Ustr, u32>) -> Self {
+        Self {
+            default_limit,
+            component_limits,
+        }
+    }
+
+    /// Returns the limit for the given `component` (falling back to the default limit).
+    #[must_use]
+    pub fn limit_for(&self, component: &Ustr) -> Option<u32> {
+        self.component_limits
+            .get(component)
+            .copied()
+            .or(self.default_limit)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ComponentCounter {
+    window_start: u64,
+    counts: HashMap<u64, u32>,
+}
+
+/// Limits repeated identical log messages, with an independent counter per component.
+#[derive(Debug, Default)]
+pub struct LogRateLimiter {
+    config: RateLimitConfig,
+    counters: HashMap<Ustr, ComponentCounter>,
+}
+
+impl LogRateLimiter {
+    /// Creates a new [`LogRateLimiter`] instance.
+    #[must_use]
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            counters: HashMap::new(),
+        }
+    }
+
+    /// Returns whether the given `line` is within its component's rate limit at `ts`,
+    /// counting it towards the limit if so.
+    pub fn allow(&mut self, line: &LogLine, ts: UnixNanos) -> bool {
+        let Some(limit) = self.config.limit_for(&line.component) else {
+            return true;
+        };
+
+        let ts = ts.as_u64();
+        let counter = self.counters.entry(line.component).or_default();
+        if ts.saturating_sub(counter.window_start) >= RATE_LIMIT_WINDOW_NS {
+            counter.window_start = ts;
+            counter.counts.clear();
+        }
+
+        let mut hasher = DefaultHasher::new();
+        line.level.hash(&mut hasher);
+        line.message.hash(&mut hasher);
+        let key = hasher.finish();
+
+        if !counter.counts.contains_key(&key) && counter.counts.len() >= RATE_LIMIT_MAX_TRACKED {
+            return true;
+        }
+
+        let count = counter.counts.entry(key).or_insert(0);
+        if *count >= limit {
+            return false;
+        }
+        *count += 1;
+        true
+    }
+}
+
+/// What happens to lines which would exceed a file's byte budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ByteLimitPolicy {
+    /// Excess lines are dropped.
+    #[default]
+    Drop,
+    /// Excess lines are buffered (up to one window's budget) and written in later windows.
+    Buffer,
+}
+
+impl FromStr for ByteLimitPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "drop" => Ok(Self::Drop),
+            "buffer" => Ok(Self::Buffer),
+            _ => anyhow::bail!("Invalid byte limit policy, must be 'drop' or 'buffer', was {s:?}"),
+        }
+    }
+}
+
+/// Limits the bytes written to a log file per window, measured by log event timestamps.
+///
+/// As the window is driven by event timestamps rather than the wall clock, throttling is
+/// deterministic when backtesting. Buffered lines are written once a later event starts a new
+/// window with budget available, and any remaining on [`LogByteLimiter::flush`].
+#[derive(Debug)]
+pub struct LogByteLimiter {
+    max_bytes_per_sec: Option<u64>,
+    policy: ByteLimitPolicy,
+    window_start: u64,
+    written_bytes: u64,
+    buffer: VecDeque<String>,
+    buffered_bytes: u64,
+    dropped_count: u64,
+}
+
+impl LogByteLimiter {
+    /// Creates a new [`LogByteLimiter`] instance (`max_bytes_per_sec` of `None` for no limit).
+    #[must_use]
+    pub const fn new(max_bytes_per_sec: Option<u64>, policy: ByteLimitPolicy) -> Self {
+        Self {
+            max_bytes_per_sec,
+            policy,
+            window_start: 0,
+            written_bytes: 0,
+            buffer: VecDeque::new(),
+            buffered_bytes: 0,
+            dropped_count: 0,
+        }
+    }
+
+    /// Returns the count of lines dropped for exceeding the byte budget.
+    #[must_use]
+    pub const fn dropped_count(&self) -> u64 {
+        self.dropped_count
+    }
+
+    /// Returns the count of lines currently buffered.
+    #[must_use]
+    pub fn buffered_count(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Writes the given `line` to `writer` if within the byte budget at `ts`, otherwise
+    /// buffering or dropping it according to the policy.
+    ///
+    /// Previously buffered lines are written first, so lines are never reordered.
+    pub fn write(&mut self, writer: &mut impl LogWriter, line: &str, ts: UnixNanos) {
+        let Some(max_bytes) = self.max_bytes_per_sec else {
+            writer.write(line);
+            return;
+        };
+
+        let ts = ts.as_u64();
+        if ts.saturating_sub(self.window_start) >= RATE_LIMIT_WINDOW_NS {
+            self.window_start = ts;
+            self.written_bytes = 0;
+        }
+
+        while let Some(len) = self.buffer.front().map(|line| line.len() as u64) {
+            if self.written_bytes + len > max_bytes {
+                break;
+            }
+            // SAFETY: Unwrap safe as just checked the buffer has a front line
+            writer.write(&self.buffer.pop_front().unwrap());
+            self.written_bytes += len;
+            self.buffered_bytes -= len;
+        }
+
+        let len = line.len() as u64;
+        if self.buffer.is_empty() && self.written_bytes + len <= max_bytes {
+            writer.write(line);
+            self.written_bytes += len;
+        } else if self.policy == ByteLimitPolicy::Buffer && self.buffered_bytes + len <= max_bytes {
+            self.buffer.push_back(line.to_string());
+            self.buffered_bytes += len;
+        } else {
+            self.dropped_count += 1;
+        }
+    }
+
+    /// Writes any buffered lines to `writer` regardless of the byte budget.
+    pub fn flush(&mut self, writer: &mut impl LogWriter) {
+        for line in self.buffer.drain(..) {
+            writer.write(&line);
+        }
+        self.buffered_bytes = 0;
+    }
+}
+
+/// Parses a rate limits JSON map into a [`RateLimitConfig`].
+///
+/// The `"default"` key sets the fallback limit, all other keys are component names.
+/// Values which are not non-negative integers are ignored.
+#[must_use]
+pub fn parse_rate_limits(map: Option<HashMap<String, serde_json::Value>>) -> RateLimitConfig {
+    let mut config = RateLimitConfig::default();
+    for (key, value) in map.unwrap_or_default() {
+        let Some(limit) = value.as_u64().and_then(|v| u32::try_from(v).ok()) else {
+            eprintln!("Invalid log rate limit for {key}, was {value}");
+            continue;
+        };
+        if key == RATE_LIMIT_DEFAULT_KEY {
+            config.default_limit = Some(limit);
+        } else {
+            config.component_limits.insert(Ustr::from(&key), limit);
+        }
+    }
+    config
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+    use crate::enums::LogColor;
+
+    fn line(component: &str, message: &str) -> LogLine {
+        LogLine {
+            level: log::Level::Warn,
+            color: LogColor::Yellow,
+            component: Ustr::from(component),
+            message: message.to_string(),
+            file: None,
+            line: None,
+            seq: None,
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingWriter {
+        lines: Vec<String>,
+    }
+
+    impl LogWriter for RecordingWriter {
+        fn write(&mut self, line: &str) {
+            self.lines.push(line.to_string());
+        }
+
+        fn flush(&mut self) {}
+
+        fn enabled(&self, _line: &LogLine) -> bool {
+            true
+        }
+    }
+
+    fn allowed_count(limiter: &mut LogRateLimiter, line: &LogLine, n: usize) -> usize {
+        (0..n)
+            .filter(|_| limiter.allow(line, UnixNanos::from(0)))
+            .count()
+    }
+
+    #[rstest]
+    fn test_no_limits_allows_all() {
+        let mut limiter = LogRateLimiter::new(RateLimitConfig::default());
+
+        assert_eq!(
+            allowed_count(&mut limiter, &line("Strategy", "msg"), 100),
+            100
+        );
+    }
+
+    #[rstest]
+    fn test_components_have_independent_thresholds() {
+        let config = parse_rate_limits(Some(HashMap::from([
+            ("default".to_string(), json!(2)),
+            ("DataEngine".to_string(), json!(5)),
+        ])));
+        let mut limiter = LogRateLimiter::new(config);
+
+        let data_engine_line = line("DataEngine", "Data gap detected");
+        let strategy_line = line("EMACross", "Data gap detected");
+
+        assert_eq!(allowed_count(&mut limiter, &data_engine_line, 10), 5);
+        assert_eq!(allowed_count(&mut limiter, &strategy_line, 10), 2);
+    }
+
+    #[rstest]
+    fn test_distinct_messages_counted_separately() {
+        let config = RateLimitConfig::new(Some(1), HashMap::new());
+        let mut limiter = LogRateLimiter::new(config);
+
+        assert!(limiter.allow(&line("Strategy", "first"), UnixNanos::from(0)));
+        assert!(limiter.allow(&line("Strategy", "second"), UnixNanos::from(0)));
+        assert!(!limiter.allow(&line("Strategy", "first"), UnixNanos::from(0)));
+    }
+
+    #[rstest]
+    fn test_counter_resets_after_window() {
+        let config = RateLimitConfig::new(Some(1), HashMap::new());
+        let mut limiter = LogRateLimiter::new(config);
+        let line = line("Strategy", "msg");
+
+        assert!(limiter.allow(&line, UnixNanos::from(0)));
+        assert!(!limiter.allow(&line, UnixNanos::from(RATE_LIMIT_WINDOW_NS - 1)));
+        assert!(limiter.allow(&line, UnixNanos::from(RATE_LIMIT_WINDOW_NS)));
+    }
+
+    #[rstest]
+    fn test_tracked_messages_bounded_per_component() {
+        let config = RateLimitConfig::new(Some(1), HashMap::new());
+        let mut limiter = LogRateLimiter::new(config);
+
+        for i in 0..RATE_LIMIT_MAX_TRACKED + 10 {
+            limiter.allow(&line("Strategy", &i.to_string()), UnixNanos::from(0));
+        }
+        limiter.allow(&line("Other", "msg"), UnixNanos::from(0));
+
+        assert_eq!(
+            limiter.counters[&Ustr::from("Strategy")].counts.len(),
+            RATE_LIMIT_MAX_TRACKED
+        );
+        assert_eq!(limiter.counters[&Ustr::from("Other")].counts.len(), 1);
+    }
+
+    #[rstest]
+    fn test_parse_rate_limits_ignores_invalid_values() {
+        let config = parse_rate_limits(Some(HashMap::from([
+            ("RiskEngine".to_string(), json!(10)),
+            ("Portfolio".to_string(), json!("ten")),
+        ])));
+
+        assert_eq!(config.default_limit, None);
+        assert_eq!(config.limit_for(&Ustr::from("RiskEngine")), Some(10));
+        assert_eq!(config.limit_for(&Ustr::from("Portfolio")), None);
+    }
+
+    #[rstest]
+    fn test_byte_limiter_without_limit_writes_all() {
+        let mut limiter = LogByteLimiter::new(None, ByteLimitPolicy::Drop);
+        let mut writer = RecordingWriter::default();
+
+        for _ in 0..100 {
+            limiter.write(&mut writer, "0123456789\n", UnixNanos::from(0));
+        }
+
+        assert_eq!(writer.lines.len(), 100);
+        assert_eq!(limiter.dropped_count(), 0);
+    }
+
+    #[rstest]
+    fn test_byte_limiter_drops_past_budget() {
+        let mut limiter = LogByteLimiter::new(Some(50), ByteLimitPolicy::Drop);
+        let mut writer = RecordingWriter::default();
+
+        // Nine byte lines every 100ms, so twenty lines span two windows
+        for i in 0..20 {
+            let ts = UnixNanos::from(i * RATE_LIMIT_WINDOW_NS / 10);
+            limiter.write(&mut writer, &format!("line {i:>3}\n"), ts);
+        }
+
+        assert_eq!(writer.lines.len(), 10);
+        assert_eq!(writer.lines[4], "line   4\n");
+        assert_eq!(writer.lines[5], "line  10\n");
+        assert_eq!(limiter.dropped_count(), 10);
+    }
+
+    #[rstest]
+    fn test_byte_limiter_buffers_past_budget() {
+        let mut limiter = LogByteLimiter::new(Some(20), ByteLimitPolicy::Buffer);
+        let mut writer = RecordingWriter::default();
+
+        for i in 0..5 {
+            limiter.write(&mut writer, &format!("line {i:>3}\n"), UnixNanos::from(0));
+        }
+
+        // Two lines fit the budget, two are buffered and the last exceeds the buffer
+        assert_eq!(writer.lines.len(), 2);
+        assert_eq!(limiter.buffered_count(), 2);
+        assert_eq!(limiter.dropped_count(), 1);
+
+        limiter.write(
+            &mut writer,
+            "line   5\n",
+            UnixNanos::from(RATE_LIMIT_WINDOW_NS),
+        );
+
+        // Buffered lines are written ahead of the new line, which is then buffered
+        assert_eq!(
+            writer.lines,
+            ["line   0\n", "line   1\n", "line   2\n", "line   3\n"]
+        );
+        assert_eq!(limiter.buffered_count(), 1);
+
+        limiter.flush(&mut writer);
+
+        assert_eq!(writer.lines.last().unwrap(), "line   5\n");
+        assert_eq!(limiter.buffered_count(), 0);
+    }
+
+    #[rstest]
+    #[case("drop", ByteLimitPolicy::Drop)]
+    #[case("Buffer", ByteLimitPolicy::Buffer)]
+    fn test_byte_limit_policy_from_str(#[case] input: &str, #[case] expected: ByteLimitPolicy) {
+        assert_eq!(ByteLimitPolicy::from_str(input).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_byte_limit_policy_from_str_invalid() {
+        assert!(ByteLimitPolicy::from_str("block").is_err());
+    }
+}
@@ -15,14 +15,20 @@
 
 use std::{
     fs::{create_dir_all, File},
-    io::{self, BufWriter, Stderr, Stdout, Write},
+    io::{self, BufWriter, Read, Seek, SeekFrom, Stderr, Stdout, Write},
     path::PathBuf,
 };
 
 use chrono::{DateTime, Utc};
 use log::LevelFilter;
 
-use crate::logging::logger::LogLine;
+use crate::logging::{
+    error::LogError,
+    logger::{LineTerminator, LogLine},
+};
+
+/// The header row written at the start of each CSV log file.
+pub const CSV_HEADER: &str = "timestamp,level,component,message";
 
 pub trait LogWriter {
     /// Writes a log line.
@@ -38,6 +44,7 @@ pub struct StdoutWriter {
     pub is_colored: bool,
     io: Stdout,
     level: LevelFilter,
+    include_errors: bool,
 }
 
 impl StdoutWriter {
@@ -48,8 +55,16 @@ impl StdoutWriter {
             io: io::stdout(),
             level,
             is_colored,
+            include_errors: false,
         }
     }
+
+    /// Sets whether error events are also written to stdout (in addition to stderr).
+    #[must_use]
+    pub const fn with_errors(mut self, include_errors: bool) -> Self {
+        self.include_errors = include_errors;
+        self
+    }
 }
 
 impl LogWriter for StdoutWriter {
@@ -68,8 +83,8 @@ impl LogWriter for StdoutWriter {
     }
 
     fn enabled(&self, line: &LogLine) -> bool {
-        // Prevent error logs also writing to stdout
-        line.level > LevelFilter::Error && line.level <= self.level
+        // Prevent error logs also writing to stdout unless configured to
+        (self.include_errors || line.level > LevelFilter::Error) && line.level <= self.level
     }
 }
 
@@ -114,7 +129,7 @@ impl LogWriter for StderrWriter {
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.common")
 )]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct FileWriterConfig {
     pub directory: Option<String>,
     pub file_name: Option<String>,
@@ -137,57 +152,208 @@ impl FileWriterConfig {
     }
 }
 
+/// Configuration for an additional log file sink, filtered independently at its own level.
+///
+/// Each sink should have a distinct `file_name` or `directory` so that it writes to its own file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileSinkConfig {
+    /// Maximum log level to write to the sink file.
+    pub level: LevelFilter,
+    /// The location and format of the sink file.
+    pub file_config: FileWriterConfig,
+}
+
+impl FileSinkConfig {
+    /// Creates a new [`FileSinkConfig`] instance.
+    #[must_use]
+    pub const fn new(level: LevelFilter, file_config: FileWriterConfig) -> Self {
+        Self { level, file_config }
+    }
+}
+
+/// The format of the lines written to a log file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFileFormat {
+    /// Plain text lines, as written to stdout.
+    #[default]
+    Text,
+    /// A JSON object per line.
+    Json,
+    /// A CSV row per line, following a [`CSV_HEADER`] row at the start of each file.
+    Csv,
+}
+
+impl LogFileFormat {
+    /// Returns the file extension for the format.
+    #[must_use]
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Self::Text => "log",
+            Self::Json => "json",
+            Self::Csv => "csv",
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct FileWriter {
-    pub json_format: bool,
+    pub file_format: LogFileFormat,
     buf: BufWriter<File>,
     path: PathBuf,
     file_config: FileWriterConfig,
     trader_id: String,
     instance_id: String,
     level: LevelFilter,
+    preallocate_bytes: Option<u64>,
+    line_terminator: LineTerminator,
+    len: u64,
+    allocated_len: u64,
 }
 
 impl FileWriter {
     /// Creates a new [`FileWriter`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the log file directory cannot be created.
+    /// - If the log file cannot be opened.
     pub fn new(
         trader_id: String,
         instance_id: String,
         file_config: FileWriterConfig,
         fileout_level: LevelFilter,
-    ) -> Option<Self> {
+    ) -> Result<Self, LogError> {
         // Set up log file
-        let json_format = match file_config.file_format.as_ref().map(|s| s.to_lowercase()) {
-            Some(ref format) if format == "json" => true,
-            None => false,
+        let file_format = match file_config.file_format.as_ref().map(|s| s.to_lowercase()) {
+            Some(ref format) if format == "json" => LogFileFormat::Json,
+            Some(ref format) if format == "csv" => LogFileFormat::Csv,
+            None => LogFileFormat::Text,
             Some(ref unrecognized) => {
                 tracing::error!(
                     "Unrecognized log file format: {unrecognized}. Using plain text format as default."
                 );
-                false
+                LogFileFormat::Text
             }
         };
 
         let file_path =
-            Self::create_log_file_path(&file_config, &trader_id, &instance_id, json_format);
-
-        match File::options()
-            .create(true)
-            .append(true)
-            .open(file_path.clone())
-        {
-            Ok(file) => Some(Self {
-                json_format,
-                buf: BufWriter::new(file),
-                path: file_path,
-                file_config,
-                trader_id,
-                instance_id,
-                level: fileout_level,
-            }),
-            Err(e) => {
-                tracing::error!("Error creating log file: {e}");
-                None
+            Self::create_log_file_path(&file_config, &trader_id, &instance_id, file_format)?;
+
+        let (file, len, allocated_len) =
+            Self::open_file(&file_path, None).map_err(|source| LogError::FileOpen {
+                path: file_path.clone(),
+                source,
+            })?;
+
+        Ok(Self {
+            file_format,
+            buf: BufWriter::new(file),
+            path: file_path,
+            file_config,
+            trader_id,
+            instance_id,
+            level: fileout_level,
+            preallocate_bytes: None,
+            line_terminator: LineTerminator::default(),
+            len,
+            allocated_len,
+        })
+    }
+
+    /// Sets the size in bytes to pre-allocate the log file to, and to grow it by when full.
+    ///
+    /// Lines are written at the logical end of the written content (rather than appended after
+    /// the allocated space), and the file is truncated to the written content when it is closed,
+    /// rotated or reopened. Any zero-filled space left at the end of an existing file (e.g. after
+    /// a crash) is not treated as content, so writing continues from the last written line.
+    #[must_use]
+    pub fn with_preallocation(mut self, preallocate_bytes: Option<u64>) -> Self {
+        let Some(preallocate_bytes) = preallocate_bytes.filter(|bytes| *bytes > 0) else {
+            return self;
+        };
+
+        self.preallocate_bytes = Some(preallocate_bytes);
+        if let Err(e) = self.open(self.path.clone()) {
+            tracing::error!("Error pre-allocating log file: {e}");
+            self.preallocate_bytes = None;
+        }
+        self
+    }
+
+    /// Sets the terminator of the CSV header row, which should match that of the written lines.
+    #[must_use]
+    pub const fn with_line_terminator(mut self, line_terminator: LineTerminator) -> Self {
+        self.line_terminator = line_terminator;
+        self
+    }
+
+    /// Returns the logical size of the log file in bytes (the written content, excluding any
+    /// pre-allocated space).
+    #[must_use]
+    pub const fn file_size(&self) -> u64 {
+        self.len
+    }
+
+    /// Opens the log file at `path` for writing, replacing the current file.
+    ///
+    /// The current file is released first, as `path` may be the same file.
+    fn open(&mut self, path: PathBuf) -> io::Result<()> {
+        self.release();
+
+        let (file, len, allocated_len) = Self::open_file(&path, self.preallocate_bytes)?;
+
+        self.buf = BufWriter::new(file);
+        self.path = path;
+        self.len = len;
+        self.allocated_len = allocated_len;
+        Ok(())
+    }
+
+    /// Opens the log file at `path`, returning it with its logical and allocated lengths.
+    ///
+    /// Trailing zero bytes are pre-allocated space (log lines never contain NUL), so are
+    /// excluded from the logical length. Without pre-allocation they are truncated away and the
+    /// file is opened for appending, otherwise `preallocate_bytes` are allocated after the
+    /// content and the file is positioned at the end of the content.
+    fn open_file(path: &PathBuf, preallocate_bytes: Option<u64>) -> io::Result<(File, u64, u64)> {
+        match preallocate_bytes {
+            Some(preallocate_bytes) => {
+                let mut file = File::options()
+                    .create(true)
+                    .read(true)
+                    .write(true)
+                    .truncate(false)
+                    .open(path)?;
+                let len = content_len(&mut file)?;
+                let allocated_len = file.metadata()?.len().max(len + preallocate_bytes);
+                allocate(&file, allocated_len)?;
+                file.seek(SeekFrom::Start(len))?;
+                Ok((file, len, allocated_len))
+            }
+            None => {
+                let mut file = File::options()
+                    .create(true)
+                    .read(true)
+                    .append(true)
+                    .open(path)?;
+                let len = content_len(&mut file)?;
+                if file.metadata()?.len() > len {
+                    file.set_len(len)?;
+                }
+                Ok((file, len, len))
+            }
+        }
+    }
+
+    /// Flushes buffered logs and truncates any pre-allocated space after the written content.
+    fn release(&mut self) {
+        self.flush();
+
+        if self.allocated_len > self.len {
+            match self.buf.get_ref().set_len(self.len) {
+                Ok(()) => self.allocated_len = self.len,
+                Err(e) => tracing::error!("Error truncating log file: {e}"),
             }
         }
     }
@@ -196,8 +362,8 @@ impl FileWriter {
         file_config: &FileWriterConfig,
         trader_id: &str,
         instance_id: &str,
-        is_json_format: bool,
-    ) -> PathBuf {
+        file_format: LogFileFormat,
+    ) -> Result<PathBuf, LogError> {
         let basename = if let Some(file_name) = file_config.file_name.as_ref() {
             file_name.clone()
         } else {
@@ -206,66 +372,139 @@ impl FileWriter {
             format!("{trader_id}_{current_date_utc}_{instance_id}")
         };
 
-        let suffix = if is_json_format { "json" } else { "log" };
-        let mut file_path = PathBuf::new();
-
         if let Some(directory) = file_config.directory.as_ref() {
-            file_path.push(directory);
-            create_dir_all(&file_path).expect("Failed to create directories for log file");
+            create_dir_all(directory).map_err(|source| LogError::CreateDirectory {
+                path: PathBuf::from(directory),
+                source,
+            })?;
         }
 
-        file_path.push(basename);
-        file_path.set_extension(suffix);
-        file_path
+        Ok(log_file_path(
+            file_config.directory.as_deref(),
+            &basename,
+            file_format.suffix(),
+        ))
     }
 
     #[must_use]
     pub fn should_rotate_file(&self) -> bool {
         let current_date_utc = Utc::now().date_naive();
-        let metadata = self
-            .path
-            .metadata()
-            .expect("Failed to read log file metadata");
-        let creation_time = metadata
-            .created()
-            .expect("Failed to get log file creation time");
+        let Ok(creation_time) = self.path.metadata().and_then(|metadata| metadata.created()) else {
+            return false; // Log file moved away externally and not yet reopened
+        };
 
         let creation_time_utc: DateTime<Utc> = creation_time.into();
         let creation_date_utc = creation_time_utc.date_naive();
 
         current_date_utc != creation_date_utc
     }
+
+    /// Writes the `bytes` at the logical end of the log file, growing any pre-allocated space.
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        let bytes_len = bytes.len() as u64;
+        if let Some(preallocate_bytes) = self.preallocate_bytes {
+            if self.len + bytes_len > self.allocated_len {
+                let allocated_len = self.len + bytes_len + preallocate_bytes;
+                match allocate(self.buf.get_ref(), allocated_len) {
+                    Ok(()) => self.allocated_len = allocated_len,
+                    Err(e) => tracing::error!("Error pre-allocating log file: {e}"),
+                }
+            }
+        }
+
+        match self.buf.write_all(bytes) {
+            Ok(()) => self.len += bytes_len,
+            Err(e) => tracing::error!("Error writing to file: {e:?}"),
+        }
+    }
+
+    /// Reopens the log file at its current path, creating it if it no longer exists.
+    ///
+    /// This allows external tools (e.g. `logrotate`) to move the log file away, with
+    /// subsequent writes going to a new file at the original path.
+    pub fn reopen(&mut self) {
+        if let Err(e) = self.open(self.path.clone()) {
+            tracing::error!("Error reopening log file: {e}");
+        }
+    }
+}
+
+/// Returns the length of the `file` excluding any trailing zero bytes.
+fn content_len(file: &mut File) -> io::Result<u64> {
+    let mut buf = [0u8; 8192];
+    let mut end = file.metadata()?.len();
+    while end > 0 {
+        let start = end.saturating_sub(buf.len() as u64);
+        let chunk = &mut buf[..(end - start) as usize];
+        file.seek(SeekFrom::Start(start))?;
+        file.read_exact(chunk)?;
+        if let Some(pos) = chunk.iter().rposition(|byte| *byte != 0) {
+            return Ok(start + pos as u64 + 1);
+        }
+        end = start;
+    }
+    Ok(0)
+}
+
+/// Allocates disk space for the `file` up to `len` bytes, extending it with zeros if shorter.
+///
+/// On Linux the blocks are reserved with `posix_fallocate`, so later writes into the space
+/// cannot fail for lack of disk space. Elsewhere the file is extended with `set_len`.
+#[cfg(target_os = "linux")]
+fn allocate(file: &File, len: u64) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let len =
+        libc::off_t::try_from(len).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    // SAFETY: The file descriptor is valid for the lifetime of `file`
+    match unsafe { libc::posix_fallocate(file.as_raw_fd(), 0, len) } {
+        0 => Ok(()),
+        errno => Err(io::Error::from_raw_os_error(errno)),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn allocate(file: &File, len: u64) -> io::Result<()> {
+    if file.metadata()?.len() < len {
+        file.set_len(len)?;
+    }
+    Ok(())
+}
+
+/// Returns the path of a log file named `basename` with the extension `suffix`, joined to the
+/// optional `directory` with the platform separator (so `C:\logs` is a valid directory on Windows).
+pub(crate) fn log_file_path(directory: Option<&str>, basename: &str, suffix: &str) -> PathBuf {
+    let mut file_path = directory.map(PathBuf::from).unwrap_or_default();
+    file_path.push(basename);
+    file_path.set_extension(suffix);
+    file_path
 }
 
 impl LogWriter for FileWriter {
     fn write(&mut self, line: &str) {
         if self.should_rotate_file() {
-            self.flush();
-
-            let file_path = Self::create_log_file_path(
+            match Self::create_log_file_path(
                 &self.file_config,
                 &self.trader_id,
                 &self.instance_id,
-                self.json_format,
-            );
-
-            match File::options()
-                .create(true)
-                .append(true)
-                .open(file_path.clone())
-            {
-                Ok(file) => {
-                    self.buf = BufWriter::new(file);
-                    self.path = file_path;
+                self.file_format,
+            ) {
+                Ok(file_path) => {
+                    if let Err(e) = self.open(file_path) {
+                        tracing::error!("Error creating log file: {e}");
+                    }
                 }
-                Err(e) => tracing::error!("Error creating log file: {e}"),
+                Err(e) => tracing::error!("{e}"),
             }
         }
 
-        match self.buf.write_all(line.as_bytes()) {
-            Ok(()) => {}
-            Err(e) => tracing::error!("Error writing to file: {e:?}"),
+        // A file with no content was just created (or moved away), so starts with the header
+        if self.file_format == LogFileFormat::Csv && self.len == 0 {
+            let header = format!("{CSV_HEADER}{}", self.line_terminator.as_str());
+            self.write_bytes(header.as_bytes());
         }
+
+        self.write_bytes(line.as_bytes());
     }
 
     fn flush(&mut self) {
@@ -279,3 +518,9 @@ impl LogWriter for FileWriter {
         line.level <= self.level
     }
 }
+
+impl Drop for FileWriter {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
@@ -0,0 +1,895 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Serializable data engine messages, exchanged between the Rust and Python engines.
+//!
+//! Each message is a JSON object tagged with its `type`, which describes the data it concerns
+//! with a [`DataDescriptor`]. For example a [`Subscribe`] command for quotes:
+//!
+//! ```json
+//! {
+//!   "type": "Subscribe",
+//!   "command_id": "2d89666b-1a1e-4a75-b193-4eb3b454c757",
+//!   "client_id": "BINANCE",
+//!   "venue": null,
+//!   "data_type": {"kind": "QUOTE", "instrument_id": "ETHUSDT.BINANCE", "params": {}},
+//!   "ts_init": 1
+//! }
+//! ```
+//!
+//! An [`Unsubscribe`] command has the same fields. A [`DataRequest`] replaces `command_id` with
+//! a `correlation_id`, and adds the optional `start`, `end` (UNIX nanoseconds) and `limit`. A
+//! [`DataResponse`] has the `correlation_id` of its request, and the `data` as an object of the
+//! data `kind` with its `items`:
+//!
+//! ```json
+//! {"kind": "BARS", "items": [{"type": "Bar", "bar_type": "...", ...}]}
+//! ```
+//!
+//! Descriptor `params` are the metadata of the equivalent [`DataType`], other than the
+//! `instrument_id` (e.g. `bar_type` for bars, `book_type` and `depth` for order books).
+
+use std::collections::HashMap;
+
+use indexmap::IndexMap;
+use nautilus_core::{correctness::FAILED, nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        delta::OrderBookDelta,
+        quote::QuoteTick,
+        trade::TradeTick,
+        DataType,
+    },
+    enums::BookType,
+    identifiers::{ClientId, InstrumentId, Venue},
+};
+use serde::{Deserialize, Serialize};
+
+const INSTRUMENT_ID: &str = "instrument_id";
+const BAR_TYPE: &str = "bar_type";
+
+/// The kind of market data described by a [`DataDescriptor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DataKind {
+    /// Quote ticks.
+    Quote,
+    /// Trade ticks.
+    Trade,
+    /// Bars of a bar type.
+    Bar,
+    /// Order book updates.
+    Book,
+}
+
+impl DataKind {
+    /// Returns the canonical data type name for the kind.
+    #[must_use]
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            Self::Quote => "QuoteTick",
+            Self::Trade => "TradeTick",
+            Self::Bar => "Bar",
+            Self::Book => "OrderBookDelta",
+        }
+    }
+}
+
+/// Describes the market data a message concerns.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DataDescriptor {
+    /// The kind of data.
+    pub kind: DataKind,
+    /// The instrument ID of the data.
+    pub instrument_id: InstrumentId,
+    /// Additional parameters for the data.
+    #[serde(default)]
+    pub params: IndexMap<String, String>,
+}
+
+impl DataDescriptor {
+    /// Creates a new [`DataDescriptor`] instance.
+    #[must_use]
+    pub const fn new(
+        kind: DataKind,
+        instrument_id: InstrumentId,
+        params: IndexMap<String, String>,
+    ) -> Self {
+        Self {
+            kind,
+            instrument_id,
+            params,
+        }
+    }
+
+    /// Creates a new [`DataDescriptor`] for quote ticks.
+    #[must_use]
+    pub fn quotes(instrument_id: InstrumentId) -> Self {
+        Self::new(DataKind::Quote, instrument_id, IndexMap::new())
+    }
+
+    /// Creates a new [`DataDescriptor`] for trade ticks.
+    #[must_use]
+    pub fn trades(instrument_id: InstrumentId) -> Self {
+        Self::new(DataKind::Trade, instrument_id, IndexMap::new())
+    }
+
+    /// Creates a new [`DataDescriptor`] for bars of the given `bar_type`.
+    #[must_use]
+    pub fn bars(bar_type: BarType) -> Self {
+        let params = IndexMap::from([(BAR_TYPE.to_string(), bar_type.to_string())]);
+        Self::new(DataKind::Bar, bar_type.instrument_id(), params)
+    }
+
+    /// Creates a new [`DataDescriptor`] for order book updates, to an optional `depth`.
+    #[must_use]
+    pub fn book(instrument_id: InstrumentId, book_type: BookType, depth: Option<usize>) -> Self {
+        let mut params = IndexMap::from([("book_type".to_string(), book_type.to_string())]);
+        if let Some(depth) = depth {
+            params.insert("depth".to_string(), depth.to_string());
+        }
+        Self::new(DataKind::Book, instrument_id, params)
+    }
+
+    /// Returns the equivalent [`DataType`], with the canonical type name for the kind.
+    #[must_use]
+    pub fn data_type(&self) -> DataType {
+        let mut metadata = IndexMap::with_capacity(self.params.len() + 1);
+        metadata.insert(INSTRUMENT_ID.to_string(), self.instrument_id.to_string());
+        metadata.extend(self.params.clone());
+        DataType::new(self.kind.type_name(), Some(metadata))
+    }
+}
+
+/// Maps data type names to the [`DataKind`] they describe.
+///
+/// The default registry includes the canonical name of each kind, and the names of the other
+/// built-in order book data types.
+#[derive(Clone, Debug)]
+pub struct DataTypeRegistry {
+    kinds: HashMap<String, DataKind>,
+}
+
+impl Default for DataTypeRegistry {
+    fn default() -> Self {
+        let kinds = [
+            ("QuoteTick", DataKind::Quote),
+            ("TradeTick", DataKind::Trade),
+            ("Bar", DataKind::Bar),
+            ("OrderBookDelta", DataKind::Book),
+            ("OrderBookDeltas", DataKind::Book),
+            ("OrderBookDepth10", DataKind::Book),
+        ]
+        .into_iter()
+        .map(|(name, kind)| (name.to_string(), kind))
+        .collect();
+
+        Self { kinds }
+    }
+}
+
+impl DataTypeRegistry {
+    /// Registers the data type `type_name` as describing data of the given `kind`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `type_name` is already registered for a different kind.
+    pub fn register(&mut self, type_name: &str, kind: DataKind) -> anyhow::Result<()> {
+        match self.kinds.get(type_name) {
+            Some(existing) if *existing != kind => anyhow::bail!(
+                "Data type '{type_name}' already registered for {existing:?}, cannot register for {kind:?}"
+            ),
+            _ => {
+                self.kinds.insert(type_name.to_string(), kind);
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the kind of data described by the data type `type_name`, if registered.
+    #[must_use]
+    pub fn kind(&self, type_name: &str) -> Option<DataKind> {
+        self.kinds.get(type_name).copied()
+    }
+
+    /// Returns the [`DataDescriptor`] for the data type `type_name` with the given `metadata`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `type_name` is not registered.
+    /// - If `metadata` has no valid `instrument_id` (or `bar_type` for bars).
+    pub fn descriptor_from_parts(
+        &self,
+        type_name: &str,
+        metadata: &IndexMap<String, String>,
+    ) -> anyhow::Result<DataDescriptor> {
+        let Some(kind) = self.kind(type_name) else {
+            anyhow::bail!("Unregistered data type '{type_name}'");
+        };
+
+        let instrument_id = match (metadata.get(INSTRUMENT_ID), metadata.get(BAR_TYPE)) {
+            (Some(instrument_id), _) => instrument_id.parse::<InstrumentId>()?,
+            (None, Some(bar_type)) if kind == DataKind::Bar => {
+                bar_type.parse::<BarType>()?.instrument_id()
+            }
+            _ => anyhow::bail!("No '{INSTRUMENT_ID}' in metadata for data type '{type_name}'"),
+        };
+
+        let params = metadata
+            .iter()
+            .filter(|(key, _)| key.as_str() != INSTRUMENT_ID)
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        Ok(DataDescriptor::new(kind, instrument_id, params))
+    }
+
+    /// Returns the [`DataDescriptor`] for the given `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the type name of `data_type` is not registered.
+    /// - If the metadata has no valid `instrument_id` (or `bar_type` for bars).
+    pub fn descriptor(&self, data_type: &DataType) -> anyhow::Result<DataDescriptor> {
+        let empty = IndexMap::new();
+        self.descriptor_from_parts(
+            data_type.type_name(),
+            data_type.metadata().unwrap_or(&empty),
+        )
+    }
+}
+
+fn check_client_id_or_venue(
+    client_id: Option<ClientId>,
+    venue: Option<Venue>,
+) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        client_id.is_some() || venue.is_some(),
+        "Both `client_id` and `venue` were `None`"
+    );
+    Ok(())
+}
+
+/// Implements `Serialize` and `Deserialize` for a message deriving them with
+/// `#[serde(tag = "type", remote = "Self")]`.
+///
+/// Serde writes the `type` tag of a struct but ignores it when reading, so the message is read
+/// through a single variant tagged enum which rejects any other `type`.
+macro_rules! impl_tagged_serde {
+    ($name:ident) => {
+        impl Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $name::serialize(self, serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Untagged($name);
+
+                impl<'de> Deserialize<'de> for Untagged {
+                    fn deserialize<D: serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Self, D::Error> {
+                        $name::deserialize(deserializer).map(Self)
+                    }
+                }
+
+                #[derive(Deserialize)]
+                #[serde(tag = "type")]
+                enum Tagged {
+                    $name(Untagged),
+                }
+
+                let Tagged::$name(Untagged(message)) = Tagged::deserialize(deserializer)?;
+                Ok(message)
+            }
+        }
+    };
+}
+
+impl_tagged_serde!(Subscribe);
+impl_tagged_serde!(Unsubscribe);
+impl_tagged_serde!(DataRequest);
+impl_tagged_serde!(DataResponse);
+
+/// A command to subscribe to market data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", remote = "Self")]
+pub struct Subscribe {
+    /// The unique ID of the command.
+    pub command_id: UUID4,
+    /// The data client ID for the command.
+    pub client_id: Option<ClientId>,
+    /// The venue for the command.
+    pub venue: Option<Venue>,
+    /// The data to subscribe to.
+    pub data_type: DataDescriptor,
+    /// UNIX timestamp (nanoseconds) when the command was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Subscribe {
+    /// Creates a new [`Subscribe`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If both `client_id` and `venue` are `None`.
+    pub fn new_checked(
+        command_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_client_id_or_venue(client_id, venue)?;
+        Ok(Self {
+            command_id,
+            client_id,
+            venue,
+            data_type,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`Subscribe`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If both `client_id` and `venue` are `None`.
+    #[must_use]
+    pub fn new(
+        command_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(command_id, client_id, venue, data_type, ts_init).expect(FAILED)
+    }
+}
+
+/// A command to unsubscribe from market data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", remote = "Self")]
+pub struct Unsubscribe {
+    /// The unique ID of the command.
+    pub command_id: UUID4,
+    /// The data client ID for the command.
+    pub client_id: Option<ClientId>,
+    /// The venue for the command.
+    pub venue: Option<Venue>,
+    /// The data to unsubscribe from.
+    pub data_type: DataDescriptor,
+    /// UNIX timestamp (nanoseconds) when the command was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl Unsubscribe {
+    /// Creates a new [`Unsubscribe`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If both `client_id` and `venue` are `None`.
+    pub fn new_checked(
+        command_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_client_id_or_venue(client_id, venue)?;
+        Ok(Self {
+            command_id,
+            client_id,
+            venue,
+            data_type,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`Unsubscribe`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If both `client_id` and `venue` are `None`.
+    #[must_use]
+    pub fn new(
+        command_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(command_id, client_id, venue, data_type, ts_init).expect(FAILED)
+    }
+}
+
+/// A request for historical market data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", remote = "Self")]
+pub struct DataRequest {
+    /// The ID correlating the request with its response.
+    pub correlation_id: UUID4,
+    /// The data client ID for the request.
+    pub client_id: Option<ClientId>,
+    /// The venue for the request.
+    pub venue: Option<Venue>,
+    /// The data requested.
+    pub data_type: DataDescriptor,
+    /// UNIX timestamp (nanoseconds) of the start of the requested range.
+    pub start: Option<UnixNanos>,
+    /// UNIX timestamp (nanoseconds) of the end of the requested range.
+    pub end: Option<UnixNanos>,
+    /// The maximum number of data items requested.
+    pub limit: Option<usize>,
+    /// UNIX timestamp (nanoseconds) when the request was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl DataRequest {
+    /// Creates a new [`DataRequest`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If both `client_id` and `venue` are `None`.
+    /// - If `start` is after `end`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_checked(
+        correlation_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_client_id_or_venue(client_id, venue)?;
+        if let (Some(start), Some(end)) = (start, end) {
+            anyhow::ensure!(start <= end, "`start` {start} was after `end` {end}");
+        }
+        Ok(Self {
+            correlation_id,
+            client_id,
+            venue,
+            data_type,
+            start,
+            end,
+            limit,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`DataRequest`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If both `client_id` and `venue` are `None`.
+    /// - If `start` is after `end`.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub fn new(
+        correlation_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+        limit: Option<usize>,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(
+            correlation_id,
+            client_id,
+            venue,
+            data_type,
+            start,
+            end,
+            limit,
+            ts_init,
+        )
+        .expect(FAILED)
+    }
+}
+
+/// The data items of a [`DataResponse`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "items", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ResponseData {
+    /// Quote ticks.
+    Quotes(Vec<QuoteTick>),
+    /// Trade ticks.
+    Trades(Vec<TradeTick>),
+    /// Bars.
+    Bars(Vec<Bar>),
+    /// Order book deltas.
+    Deltas(Vec<OrderBookDelta>),
+}
+
+impl ResponseData {
+    /// Returns the kind of the data items.
+    #[must_use]
+    pub const fn kind(&self) -> DataKind {
+        match self {
+            Self::Quotes(_) => DataKind::Quote,
+            Self::Trades(_) => DataKind::Trade,
+            Self::Bars(_) => DataKind::Bar,
+            Self::Deltas(_) => DataKind::Book,
+        }
+    }
+
+    /// Returns the number of data items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Quotes(items) => items.len(),
+            Self::Trades(items) => items.len(),
+            Self::Bars(items) => items.len(),
+            Self::Deltas(items) => items.len(),
+        }
+    }
+
+    /// Returns whether there are no data items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A response to a [`DataRequest`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", remote = "Self")]
+pub struct DataResponse {
+    /// The ID of the request the response is for.
+    pub correlation_id: UUID4,
+    /// The data client ID of the response.
+    pub client_id: Option<ClientId>,
+    /// The venue of the response.
+    pub venue: Option<Venue>,
+    /// The data requested.
+    pub data_type: DataDescriptor,
+    /// The data items.
+    pub data: ResponseData,
+    /// UNIX timestamp (nanoseconds) when the response was initialized.
+    pub ts_init: UnixNanos,
+}
+
+impl DataResponse {
+    /// Creates a new [`DataResponse`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If both `client_id` and `venue` are `None`.
+    /// - If the kind of `data` does not match the kind of `data_type`.
+    pub fn new_checked(
+        correlation_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        data: ResponseData,
+        ts_init: UnixNanos,
+    ) -> anyhow::Result<Self> {
+        check_client_id_or_venue(client_id, venue)?;
+        anyhow::ensure!(
+            data.kind() == data_type.kind,
+            "Response data of kind {:?} does not match the requested kind {:?}",
+            data.kind(),
+            data_type.kind
+        );
+        Ok(Self {
+            correlation_id,
+            client_id,
+            venue,
+            data_type,
+            data,
+            ts_init,
+        })
+    }
+
+    /// Creates a new [`DataResponse`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If both `client_id` and `venue` are `None`.
+    /// - If the kind of `data` does not match the kind of `data_type`.
+    #[must_use]
+    pub fn new(
+        correlation_id: UUID4,
+        client_id: Option<ClientId>,
+        venue: Option<Venue>,
+        data_type: DataDescriptor,
+        data: ResponseData,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self::new_checked(correlation_id, client_id, venue, data_type, data, ts_init).expect(FAILED)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::stubs::quote_ethusdt_binance;
+    use rstest::rstest;
+
+    use super::*;
+
+    const UUID: &str = "2d89666b-1a1e-4a75-b193-4eb3b454c757";
+
+    fn instrument_id() -> InstrumentId {
+        InstrumentId::from("ETHUSDT.BINANCE")
+    }
+
+    #[rstest]
+    #[case(DataDescriptor::quotes(instrument_id()))]
+    #[case(DataDescriptor::trades(instrument_id()))]
+    #[case(DataDescriptor::bars(BarType::from("ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL")))]
+    #[case(DataDescriptor::book(instrument_id(), BookType::L2_MBP, Some(10)))]
+    #[case(DataDescriptor::book(instrument_id(), BookType::L3_MBO, None))]
+    fn test_descriptor_data_type_round_trip(#[case] descriptor: DataDescriptor) {
+        let registry = DataTypeRegistry::default();
+
+        let data_type = descriptor.data_type();
+
+        assert_eq!(data_type.type_name(), descriptor.kind.type_name());
+        assert_eq!(data_type.instrument_id(), Some(instrument_id()));
+        assert_eq!(registry.descriptor(&data_type).unwrap(), descriptor);
+    }
+
+    #[rstest]
+    fn test_descriptor_bars_data_type_metadata() {
+        let bar_type = BarType::from("ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL");
+        let data_type = DataDescriptor::bars(bar_type).data_type();
+
+        assert_eq!(data_type.bar_type(), bar_type);
+        assert_eq!(
+            data_type.topic(),
+            "Bar.instrument_id=ETHUSDT.BINANCE.bar_type=ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL"
+        );
+    }
+
+    #[rstest]
+    fn test_registry_descriptor_for_bars_without_instrument_id() {
+        let registry = DataTypeRegistry::default();
+        let metadata = IndexMap::from([(
+            "bar_type".to_string(),
+            "ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL".to_string(),
+        )]);
+
+        let descriptor = registry.descriptor_from_parts("Bar", &metadata).unwrap();
+
+        assert_eq!(
+            descriptor,
+            DataDescriptor::bars(BarType::from("ETHUSDT.BINANCE-1-MINUTE-LAST-EXTERNAL"))
+        );
+    }
+
+    #[rstest]
+    #[case("OrderBookDeltas", DataKind::Book)]
+    #[case("OrderBookDepth10", DataKind::Book)]
+    #[case("QuoteTick", DataKind::Quote)]
+    fn test_registry_default_kinds(#[case] type_name: &str, #[case] expected: DataKind) {
+        assert_eq!(DataTypeRegistry::default().kind(type_name), Some(expected));
+    }
+
+    #[rstest]
+    fn test_registry_register() {
+        let mut registry = DataTypeRegistry::default();
+        let metadata =
+            IndexMap::from([("instrument_id".to_string(), "ETHUSDT.BINANCE".to_string())]);
+
+        assert!(registry
+            .descriptor_from_parts("BinanceBookTicker", &metadata)
+            .is_err());
+
+        registry
+            .register("BinanceBookTicker", DataKind::Quote)
+            .unwrap();
+        let descriptor = registry
+            .descriptor_from_parts("BinanceBookTicker", &metadata)
+            .unwrap();
+
+        assert_eq!(descriptor, DataDescriptor::quotes(instrument_id()));
+        assert!(registry
+            .register("BinanceBookTicker", DataKind::Quote)
+            .is_ok());
+        assert!(registry
+            .register("BinanceBookTicker", DataKind::Trade)
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_registry_descriptor_with_unregistered_type() {
+        let registry = DataTypeRegistry::default();
+        let data_type = DataType::new("Ticker", None);
+
+        assert_eq!(
+            registry.descriptor(&data_type).unwrap_err().to_string(),
+            "Unregistered data type 'Ticker'"
+        );
+    }
+
+    #[rstest]
+    fn test_registry_descriptor_without_instrument_id() {
+        let registry = DataTypeRegistry::default();
+        let data_type = DataType::new("QuoteTick", None);
+
+        assert_eq!(
+            registry.descriptor(&data_type).unwrap_err().to_string(),
+            "No 'instrument_id' in metadata for data type 'QuoteTick'"
+        );
+    }
+
+    #[rstest]
+    fn test_subscribe_json_schema() {
+        let command = Subscribe::new(
+            UUID4::from(UUID),
+            Some(ClientId::from("BINANCE")),
+            None,
+            DataDescriptor::quotes(instrument_id()),
+            UnixNanos::from(1),
+        );
+
+        let json = serde_json::to_string(&command).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                "{{\"type\":\"Subscribe\",\"command_id\":\"{UUID}\",\"client_id\":\"BINANCE\",\"venue\":null,\
+                \"data_type\":{{\"kind\":\"QUOTE\",\"instrument_id\":\"ETHUSDT.BINANCE\",\"params\":{{}}}},\
+                \"ts_init\":1}}"
+            )
+        );
+        assert_eq!(serde_json::from_str::<Subscribe>(&json).unwrap(), command);
+    }
+
+    #[rstest]
+    fn test_unsubscribe_json_round_trip() {
+        let command = Unsubscribe::new(
+            UUID4::from(UUID),
+            None,
+            Some(Venue::from("BINANCE")),
+            DataDescriptor::book(instrument_id(), BookType::L2_MBP, Some(10)),
+            UnixNanos::from(1),
+        );
+
+        let json = serde_json::to_string(&command).unwrap();
+
+        assert!(json.starts_with("{\"type\":\"Unsubscribe\""));
+        assert!(json.contains("\"params\":{\"book_type\":\"L2_MBP\",\"depth\":\"10\"}"));
+        assert_eq!(serde_json::from_str::<Unsubscribe>(&json).unwrap(), command);
+    }
+
+    #[rstest]
+    fn test_subscribe_json_with_wrong_type_tag() {
+        let json = format!(
+            "{{\"type\":\"Unsubscribe\",\"command_id\":\"{UUID}\",\"client_id\":\"BINANCE\",\"venue\":null,\
+            \"data_type\":{{\"kind\":\"QUOTE\",\"instrument_id\":\"ETHUSDT.BINANCE\"}},\"ts_init\":1}}"
+        );
+
+        assert!(serde_json::from_str::<Subscribe>(&json).is_err());
+    }
+
+    #[rstest]
+    fn test_data_request_json_schema() {
+        let request = DataRequest::new(
+            UUID4::from(UUID),
+            Some(ClientId::from("BINANCE")),
+            Some(Venue::from("BINANCE")),
+            DataDescriptor::trades(instrument_id()),
+            Some(UnixNanos::from(1_000)),
+            None,
+            Some(100),
+            UnixNanos::from(2_000),
+        );
+
+        let json = serde_json::to_string(&request).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                "{{\"type\":\"DataRequest\",\"correlation_id\":\"{UUID}\",\"client_id\":\"BINANCE\",\"venue\":\"BINANCE\",\
+                \"data_type\":{{\"kind\":\"TRADE\",\"instrument_id\":\"ETHUSDT.BINANCE\",\"params\":{{}}}},\
+                \"start\":1000,\"end\":null,\"limit\":100,\"ts_init\":2000}}"
+            )
+        );
+        assert_eq!(serde_json::from_str::<DataRequest>(&json).unwrap(), request);
+    }
+
+    #[rstest]
+    fn test_data_response_json_round_trip(quote_ethusdt_binance: QuoteTick) {
+        let quote = quote_ethusdt_binance;
+        let response = DataResponse::new(
+            UUID4::from(UUID),
+            Some(ClientId::from("BINANCE")),
+            None,
+            DataDescriptor::quotes(quote.instrument_id),
+            ResponseData::Quotes(vec![quote, quote]),
+            UnixNanos::from(1),
+        );
+
+        let json = serde_json::to_string(&response).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["type"], "DataResponse");
+        assert_eq!(value["data"]["kind"], "QUOTES");
+        assert_eq!(value["data"]["items"][0]["type"], "QuoteTick");
+        assert_eq!(value["data"]["items"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            serde_json::from_str::<DataResponse>(&json).unwrap(),
+            response
+        );
+    }
+
+    #[rstest]
+    fn test_data_response_with_mismatched_kind() {
+        let result = DataResponse::new_checked(
+            UUID4::from(UUID),
+            Some(ClientId::from("BINANCE")),
+            None,
+            DataDescriptor::trades(instrument_id()),
+            ResponseData::Quotes(vec![]),
+            UnixNanos::from(1),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_subscribe_without_client_id_or_venue() {
+        let result = Subscribe::new_checked(
+            UUID4::from(UUID),
+            None,
+            None,
+            DataDescriptor::quotes(instrument_id()),
+            UnixNanos::from(1),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Both `client_id` and `venue` were `None`"
+        );
+    }
+
+    #[rstest]
+    fn test_data_request_with_start_after_end() {
+        let result = DataRequest::new_checked(
+            UUID4::from(UUID),
+            Some(ClientId::from("BINANCE")),
+            None,
+            DataDescriptor::quotes(instrument_id()),
+            Some(UnixNanos::from(2)),
+            Some(UnixNanos::from(1)),
+            None,
+            UnixNanos::from(3),
+        );
+
+        assert!(result.is_err());
+    }
+}
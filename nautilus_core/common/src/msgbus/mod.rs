@@ -17,6 +17,7 @@
 
 pub mod database;
 pub mod handler;
+pub mod registry;
 pub mod stubs;
 pub mod switchboard;
 
@@ -430,31 +431,46 @@ impl MessageBus {
 /// '*' - match 0 or more characters after this
 /// '?' - match any character once
 /// 'a-z' - match the specific character
+///
+/// Runs in linear time for typical patterns by backtracking only to the most recent `*`.
 #[must_use]
 pub fn is_matching(topic: &Ustr, pattern: &Ustr) -> bool {
-    let mut table = [[false; 256]; 256];
-    table[0][0] = true;
-
-    let m = pattern.len();
-    let n = topic.len();
+    if topic.is_ascii() && pattern.is_ascii() {
+        match_wildcard(topic.as_bytes(), pattern.as_bytes())
+    } else {
+        let topic: Vec<char> = topic.chars().collect();
+        let pattern: Vec<char> = pattern.chars().collect();
+        match_wildcard(&topic, &pattern)
+    }
+}
 
-    pattern.chars().enumerate().for_each(|(j, c)| {
-        if c == '*' {
-            table[0][j + 1] = table[0][j];
+fn match_wildcard<T: Copy + PartialEq + From<u8>>(topic: &[T], pattern: &[T]) -> bool {
+    let star = T::from(b'*');
+    let any = T::from(b'?');
+
+    let mut t = 0;
+    let mut p = 0;
+    // The pattern index after the last `*` seen, and the topic index it was matched from
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < topic.len() {
+        if p < pattern.len() && pattern[p] == star {
+            backtrack = Some((p + 1, t));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == any || pattern[p] == topic[t]) {
+            t += 1;
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            // Let the last `*` consume one more character and retry
+            backtrack = Some((star_p, star_t + 1));
+            p = star_p;
+            t = star_t + 1;
+        } else {
+            return false;
         }
-    });
-
-    topic.chars().enumerate().for_each(|(i, tc)| {
-        pattern.chars().enumerate().for_each(|(j, pc)| {
-            if pc == '*' {
-                table[i + 1][j + 1] = table[i][j + 1] || table[i + 1][j];
-            } else if pc == '?' || tc == pc {
-                table[i + 1][j + 1] = table[i][j];
-            }
-        });
-    });
+    }
 
-    table[n][m]
+    pattern[p..].iter().all(|&c| c == star)
 }
 
 impl Default for MessageBus {
@@ -470,6 +486,8 @@ impl Default for MessageBus {
 mod tests {
 
     use nautilus_core::uuid::UUID4;
+    use proptest::prelude::*;
+    use regex::Regex;
     use rstest::*;
     use stubs::check_handler_was_called;
 
@@ -623,10 +641,67 @@ mod tests {
     #[case("data.quotes.BINANCE", "data.*.BINANCE", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.*", true)]
     #[case("data.trades.BINANCE.ETHUSDT", "data.*.BINANCE.ETH*", true)]
+    #[case("", "", true)]
+    #[case("", "*", true)]
+    #[case("", "?", false)]
+    #[case("comp", "comp*", true)]
+    #[case("coop", "c??p", true)]
+    #[case("cop", "c??p", false)]
+    #[case("data.quotes.BINANCE", "data.*.BINANCE.*", false)]
+    #[case("data.quotes.BINANCE", "*.*.*.*", false)]
+    #[case("aaab", "*a*b", true)]
+    #[case("abab", "*ab", true)]
+    #[case("data.quotes.Ω", "data.quotes.?", true)]
     fn test_is_matching(#[case] topic: &str, #[case] pattern: &str, #[case] expected: bool) {
         assert_eq!(
             is_matching(&Ustr::from(topic), &Ustr::from(pattern)),
             expected
         );
     }
+
+    #[rstest]
+    fn test_is_matching_long_topic() {
+        let topic = format!("data.{}.BINANCE", "x".repeat(500));
+
+        assert!(is_matching(
+            &Ustr::from(&topic),
+            &Ustr::from("data.*.BINANCE")
+        ));
+        assert!(!is_matching(
+            &Ustr::from(&topic),
+            &Ustr::from("data.*.BYBIT")
+        ));
+    }
+
+    fn reference_regex(pattern: &str) -> Regex {
+        let body: String = pattern
+            .chars()
+            .map(|c| match c {
+                '*' => ".*".to_string(),
+                '?' => ".".to_string(),
+                c => regex::escape(&c.to_string()),
+            })
+            .collect();
+        Regex::new(&format!("^(?s:{body})$")).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_is_matching_equals_reference_regex(
+            topic in "[ab.Ω]{0,12}",
+            pattern in "[ab.Ω*?]{0,8}",
+        ) {
+            let expected = reference_regex(&pattern).is_match(&topic);
+            prop_assert_eq!(is_matching(&Ustr::from(&topic), &Ustr::from(&pattern)), expected);
+        }
+
+        #[test]
+        fn test_is_matching_topic_equals_reference_regex(
+            topic in "data\\.(quotes|trades)\\.[A-Z]{1,3}\\.[A-Z]{1,4}",
+            pattern in "data\\.[a-z*?]{0,6}\\.[A-Z*?]{0,3}(\\.[A-Z*?]{0,4})?",
+        ) {
+            let expected = reference_regex(&pattern).is_match(&topic);
+            prop_assert_eq!(is_matching(&Ustr::from(&topic), &Ustr::from(&pattern)), expected);
+        }
+    }
 }
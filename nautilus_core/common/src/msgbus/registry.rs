@@ -0,0 +1,254 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A registry of topic pattern subscriptions for integer subscriber handles.
+
+use std::collections::{HashMap, HashSet};
+
+use ustr::Ustr;
+
+use super::is_matching;
+
+/// A subscription of a handle to a topic pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct HandleSubscription {
+    pattern: Ustr,
+    handle: u64,
+    priority: u8,
+}
+
+/// A registry of subscriptions of integer subscriber handles to topic patterns.
+///
+/// Patterns may contain the `*` and `?` wildcards (see [`is_matching`]). The handles matching
+/// each topic are cached when first queried, and the cache is invalidated whenever the
+/// subscriptions change, so repeated publishing to the same topics only matches patterns once.
+#[derive(Debug, Default)]
+pub struct SubscriptionRegistry {
+    /// The subscriptions in the order they were made.
+    subscriptions: Vec<HandleSubscription>,
+    /// The handles matching each topic queried since the subscriptions last changed.
+    cache: HashMap<Ustr, Vec<u64>>,
+}
+
+impl SubscriptionRegistry {
+    /// Creates a new empty [`SubscriptionRegistry`] instance.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of subscriptions.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.subscriptions.len()
+    }
+
+    /// Returns whether there are no subscriptions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.subscriptions.is_empty()
+    }
+
+    /// Returns whether `handle` is subscribed to `pattern`.
+    #[must_use]
+    pub fn is_subscribed(&self, pattern: Ustr, handle: u64) -> bool {
+        self.position(pattern, handle).is_some()
+    }
+
+    /// Subscribes `handle` to `pattern` with the given `priority`, where higher priority handles
+    /// are returned first by [`Self::matching_handlers`].
+    ///
+    /// Returns `false` (leaving the priority unchanged) if the handle is already subscribed to
+    /// the pattern.
+    pub fn subscribe(&mut self, pattern: Ustr, handle: u64, priority: u8) -> bool {
+        if self.is_subscribed(pattern, handle) {
+            return false;
+        }
+
+        self.subscriptions.push(HandleSubscription {
+            pattern,
+            handle,
+            priority,
+        });
+        self.cache.clear();
+        true
+    }
+
+    /// Unsubscribes `handle` from `pattern`.
+    ///
+    /// Returns `false` if the handle was not subscribed to the pattern.
+    pub fn unsubscribe(&mut self, pattern: Ustr, handle: u64) -> bool {
+        let Some(index) = self.position(pattern, handle) else {
+            return false;
+        };
+
+        self.subscriptions.remove(index);
+        self.cache.clear();
+        true
+    }
+
+    /// Removes all subscriptions.
+    pub fn clear(&mut self) {
+        self.subscriptions.clear();
+        self.cache.clear();
+    }
+
+    /// Returns the handles subscribed to patterns matching `topic`, in descending priority order
+    /// (then in the order they subscribed).
+    ///
+    /// A handle subscribed to several matching patterns is returned once, at its highest priority.
+    pub fn matching_handlers(&mut self, topic: Ustr) -> &[u64] {
+        let subscriptions = &self.subscriptions;
+        self.cache
+            .entry(topic)
+            .or_insert_with(|| resolve_handlers(subscriptions, topic))
+    }
+
+    /// Returns whether any handle is subscribed to a pattern matching `topic`.
+    pub fn has_subscribers(&mut self, topic: Ustr) -> bool {
+        !self.matching_handlers(topic).is_empty()
+    }
+
+    fn position(&self, pattern: Ustr, handle: u64) -> Option<usize> {
+        self.subscriptions
+            .iter()
+            .position(|sub| sub.pattern == pattern && sub.handle == handle)
+    }
+}
+
+fn resolve_handlers(subscriptions: &[HandleSubscription], topic: Ustr) -> Vec<u64> {
+    let mut matching: Vec<&HandleSubscription> = subscriptions
+        .iter()
+        .filter(|sub| is_matching(&topic, &sub.pattern))
+        .collect();
+    // Stable sort, so equal priorities remain in subscription order
+    matching.sort_by_key(|sub| std::cmp::Reverse(sub.priority));
+
+    let mut seen = HashSet::with_capacity(matching.len());
+    matching
+        .into_iter()
+        .map(|sub| sub.handle)
+        .filter(|handle| seen.insert(*handle))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_matching_handlers_in_priority_order() {
+        let mut registry = SubscriptionRegistry::new();
+        registry.subscribe(Ustr::from("data.quotes.*"), 1, 0);
+        registry.subscribe(Ustr::from("data.quotes.BINANCE.*"), 2, 10);
+        registry.subscribe(Ustr::from("data.trades.*"), 3, 20);
+        registry.subscribe(Ustr::from("data.*"), 4, 0);
+        registry.subscribe(Ustr::from("data.quotes.BINANCE.ETHUSDT"), 5, 5);
+
+        let handles = registry.matching_handlers(Ustr::from("data.quotes.BINANCE.ETHUSDT"));
+
+        assert_eq!(handles, [2, 5, 1, 4]);
+    }
+
+    #[rstest]
+    fn test_handle_subscribed_to_several_matching_patterns_returned_once() {
+        let mut registry = SubscriptionRegistry::new();
+        registry.subscribe(Ustr::from("data.*"), 1, 0);
+        registry.subscribe(Ustr::from("data.quotes.*"), 2, 1);
+        registry.subscribe(Ustr::from("data.quotes.BINANCE.?THUSDT"), 1, 2);
+
+        let handles = registry.matching_handlers(Ustr::from("data.quotes.BINANCE.ETHUSDT"));
+
+        assert_eq!(handles, [1, 2]);
+    }
+
+    #[rstest]
+    fn test_subscribe_twice_returns_false() {
+        let mut registry = SubscriptionRegistry::new();
+
+        assert!(registry.subscribe(Ustr::from("data.*"), 1, 0));
+        assert!(!registry.subscribe(Ustr::from("data.*"), 1, 5));
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[rstest]
+    fn test_cache_invalidated_on_subscribe_and_unsubscribe() {
+        let mut registry = SubscriptionRegistry::new();
+        let topic = Ustr::from("data.quotes.BINANCE.ETHUSDT");
+        assert!(!registry.has_subscribers(topic));
+
+        registry.subscribe(Ustr::from("data.quotes.*"), 1, 0);
+        assert_eq!(registry.matching_handlers(topic), [1]);
+
+        registry.subscribe(Ustr::from("data.*"), 2, 1);
+        assert_eq!(registry.matching_handlers(topic), [2, 1]);
+
+        assert!(registry.unsubscribe(Ustr::from("data.*"), 2));
+        assert_eq!(registry.matching_handlers(topic), [1]);
+
+        assert!(!registry.unsubscribe(Ustr::from("data.*"), 2));
+        registry.clear();
+        assert!(registry.is_empty());
+        assert!(!registry.has_subscribers(topic));
+    }
+
+    #[derive(Clone, Debug)]
+    enum RegistryInput {
+        Subscribe(String, u64, u8),
+        Unsubscribe(String, u64),
+        Query(String),
+    }
+
+    fn registry_input_strategy() -> impl Strategy<Value = RegistryInput> {
+        prop_oneof![
+            3 => ("[ab.*?]{0,6}", 0..4u64, 0..3u8)
+                .prop_map(|(pattern, handle, priority)| RegistryInput::Subscribe(pattern, handle, priority)),
+            1 => ("[ab.*?]{0,6}", 0..4u64)
+                .prop_map(|(pattern, handle)| RegistryInput::Unsubscribe(pattern, handle)),
+            4 => "[ab.]{0,8}".prop_map(RegistryInput::Query),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_cached_matching_handlers_equal_uncached(
+            inputs in prop::collection::vec(registry_input_strategy(), 1..60),
+        ) {
+            let mut registry = SubscriptionRegistry::new();
+
+            for input in inputs {
+                match input {
+                    RegistryInput::Subscribe(pattern, handle, priority) => {
+                        registry.subscribe(Ustr::from(&pattern), handle, priority);
+                    }
+                    RegistryInput::Unsubscribe(pattern, handle) => {
+                        registry.unsubscribe(Ustr::from(&pattern), handle);
+                    }
+                    RegistryInput::Query(topic) => {
+                        let topic = Ustr::from(&topic);
+                        let expected = resolve_handlers(&registry.subscriptions, topic);
+                        prop_assert_eq!(registry.matching_handlers(topic), expected.as_slice());
+                    }
+                }
+            }
+        }
+    }
+}
@@ -48,8 +48,8 @@ impl LogLevel {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -129,8 +129,8 @@ impl LogColor {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
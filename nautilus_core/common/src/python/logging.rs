@@ -16,7 +16,10 @@
 use std::collections::HashMap;
 
 use log::LevelFilter;
-use nautilus_core::uuid::UUID4;
+use nautilus_core::{
+    python::{to_pyruntime_err, to_pyvalue_err},
+    uuid::UUID4,
+};
 use nautilus_model::identifiers::TraderId;
 use pyo3::prelude::*;
 use ustr::Ustr;
@@ -26,7 +29,9 @@ use crate::{
     logging::{
         self, headers,
         logger::{self, LogGuard, LoggerConfig},
-        logging_set_bypass, map_log_level_to_filter, parse_level_filter_str,
+        logging_set_bypass, map_log_level_to_filter, parse_component_colors,
+        parse_component_levels, parse_file_sinks,
+        ratelimit::parse_rate_limits,
         writer::FileWriterConfig,
     },
 };
@@ -35,9 +40,8 @@ use crate::{
 impl LoggerConfig {
     #[staticmethod]
     #[pyo3(name = "from_spec")]
-    #[must_use]
-    pub fn py_from_spec(spec: String) -> Self {
-        Self::from_spec(&spec)
+    pub fn py_from_spec(spec: String) -> PyResult<Self> {
+        Self::from_spec(&spec).map_err(to_pyvalue_err)
     }
 }
 
@@ -85,7 +89,7 @@ pub fn py_init_tracing() {
 #[pyfunction]
 #[pyo3(name = "init_logging")]
 #[allow(clippy::too_many_arguments)]
-#[pyo3(signature = (trader_id, instance_id, level_stdout, level_file=None, component_levels=None, directory=None, file_name=None, file_format=None, is_colored=None, is_bypassed=None, print_config=None))]
+#[pyo3(signature = (trader_id, instance_id, level_stdout, level_file=None, component_levels=None, directory=None, file_name=None, file_format=None, is_colored=None, is_bypassed=None, print_config=None, rate_limits=None, component_colors=None, file_sinks=None))]
 pub fn py_init_logging(
     trader_id: TraderId,
     instance_id: UUID4,
@@ -98,16 +102,27 @@ pub fn py_init_logging(
     is_colored: Option<bool>,
     is_bypassed: Option<bool>,
     print_config: Option<bool>,
-) -> LogGuard {
+    rate_limits: Option<HashMap<String, u32>>,
+    component_colors: Option<HashMap<String, String>>,
+    file_sinks: Option<Vec<HashMap<String, String>>>,
+) -> PyResult<LogGuard> {
     let level_file = level_file.map_or(LevelFilter::Off, map_log_level_to_filter);
 
     let config = LoggerConfig::new(
         map_log_level_to_filter(level_stdout),
         level_file,
-        parse_component_levels(component_levels),
+        parse_component_levels(to_json_map(component_levels)).map_err(to_pyvalue_err)?,
         is_colored.unwrap_or(true),
         print_config.unwrap_or(false),
-    );
+    )
+    .with_rate_limits(parse_rate_limits(to_json_map(rate_limits)))
+    .with_component_colors(parse_component_colors(to_json_map(component_colors)))
+    .with_file_sinks(parse_file_sinks(file_sinks.map(|sinks| {
+        sinks
+            .into_iter()
+            .map(serde_json::Value::from_iter)
+            .collect()
+    })));
 
     let file_config = FileWriterConfig::new(directory, file_name, file_format);
 
@@ -115,24 +130,14 @@ pub fn py_init_logging(
         logging_set_bypass();
     }
 
-    logging::init_logging(trader_id, instance_id, config, file_config)
+    logging::init_logging(trader_id, instance_id, config, file_config).map_err(to_pyruntime_err)
 }
 
-fn parse_component_levels(
-    original_map: Option<HashMap<String, String>>,
-) -> HashMap<Ustr, LevelFilter> {
-    match original_map {
-        Some(map) => {
-            let mut new_map = HashMap::new();
-            for (key, value) in map {
-                let ustr_key = Ustr::from(&key);
-                let value = parse_level_filter_str(&value);
-                new_map.insert(ustr_key, value);
-            }
-            new_map
-        }
-        None => HashMap::new(),
-    }
+/// Converts the optional Python `map` into a JSON map for the shared logging config parsers.
+fn to_json_map<V: Into<serde_json::Value>>(
+    map: Option<HashMap<String, V>>,
+) -> Option<HashMap<String, serde_json::Value>> {
+    map.map(|map| map.into_iter().map(|(k, v)| (k, v.into())).collect())
 }
 
 /// Create a new log event.
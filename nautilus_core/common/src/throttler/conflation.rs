@@ -0,0 +1,488 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Conflation of market data updates, for consumers which cannot keep up with the full rate.
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    data::{
+        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, quote::QuoteTick,
+    },
+    enums::{BookAction, OrderSide},
+    identifiers::InstrumentId,
+};
+
+/// An update which can be coalesced with a later update for the same instrument.
+pub trait Conflate {
+    /// Returns the instrument ID the update is keyed by.
+    fn instrument_id(&self) -> InstrumentId;
+
+    /// Coalesces a newer update for the same instrument into this one.
+    ///
+    /// The default replaces this update with the newer one (latest wins), which is correct for
+    /// snapshots.
+    fn conflate(&mut self, newer: Self)
+    where
+        Self: Sized,
+    {
+        *self = newer;
+    }
+}
+
+impl Conflate for QuoteTick {
+    fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+}
+
+impl Conflate for OrderBookDepth10 {
+    fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+}
+
+impl Conflate for OrderBookDeltas {
+    fn instrument_id(&self) -> InstrumentId {
+        self.instrument_id
+    }
+
+    /// Appends the newer deltas, keeping only the latest delta for each book order (by side and
+    /// order ID, or by side and price for deltas without an order ID), so applying the result
+    /// leaves the book in the same state as applying both in turn.
+    ///
+    /// If the newer deltas clear the book, they replace the pending deltas entirely.
+    fn conflate(&mut self, newer: Self) {
+        if newer.deltas.iter().any(|d| d.action == BookAction::Clear) {
+            *self = newer;
+            return;
+        }
+
+        self.deltas.extend(newer.deltas);
+        let mut seen = HashSet::new();
+        let mut latest: Vec<OrderBookDelta> = self
+            .deltas
+            .drain(..)
+            .rev()
+            .filter(|delta| delta.action == BookAction::Clear || seen.insert(delta_key(delta)))
+            .collect();
+        latest.reverse();
+        self.deltas = latest;
+        self.flags = newer.flags;
+        self.sequence = newer.sequence;
+        self.ts_event = newer.ts_event;
+        self.ts_init = newer.ts_init;
+    }
+}
+
+/// Returns the key of the book order a delta applies to, which is the order ID for deltas with
+/// one (L3), otherwise the price level (L1 and L2).
+fn delta_key(delta: &OrderBookDelta) -> (OrderSide, u64, i64) {
+    match delta.order.order_id {
+        0 => (delta.order.side, 0, delta.order.price.raw),
+        order_id => (delta.order.side, order_id, 0),
+    }
+}
+
+/// Conflates updates keyed by instrument, emitting at most one coalesced update per instrument
+/// per interval.
+///
+/// Updates are pushed as they arrive and pending updates are taken by polling with the current
+/// time, so the emission schedule is deterministic when backtesting. The first update for an
+/// instrument is emitted on the next poll, after which each instrument's updates are held until
+/// the interval has elapsed since its previous emission.
+#[derive(Debug)]
+pub struct ConflatingThrottler<T> {
+    /// The minimum interval between emissions for each instrument.
+    interval_ns: u64,
+    /// The pending coalesced update for each instrument, in order of first arrival.
+    pending: IndexMap<InstrumentId, T>,
+    /// The time of the last emission for each instrument.
+    last_emitted: HashMap<InstrumentId, UnixNanos>,
+    /// The number of updates coalesced into a later update, for each instrument.
+    dropped: HashMap<InstrumentId, usize>,
+    /// The number of updates received.
+    recv_count: usize,
+    /// The number of coalesced updates emitted.
+    sent_count: usize,
+}
+
+impl<T: Conflate> ConflatingThrottler<T> {
+    /// Creates a new [`ConflatingThrottler`] instance.
+    #[must_use]
+    pub fn new(interval_ns: u64) -> Self {
+        Self {
+            interval_ns,
+            pending: IndexMap::new(),
+            last_emitted: HashMap::new(),
+            dropped: HashMap::new(),
+            recv_count: 0,
+            sent_count: 0,
+        }
+    }
+
+    /// Returns the minimum interval between emissions for each instrument.
+    #[must_use]
+    pub const fn interval_ns(&self) -> u64 {
+        self.interval_ns
+    }
+
+    /// Returns the number of instruments with a pending update.
+    #[must_use]
+    pub fn qsize(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the number of updates received.
+    #[must_use]
+    pub const fn recv_count(&self) -> usize {
+        self.recv_count
+    }
+
+    /// Returns the number of coalesced updates emitted.
+    #[must_use]
+    pub const fn sent_count(&self) -> usize {
+        self.sent_count
+    }
+
+    /// Returns the number of updates which were not emitted on their own, having been replaced
+    /// by (or merged with) a later update for the same instrument.
+    #[must_use]
+    pub fn dropped_count(&self) -> usize {
+        self.dropped.values().sum()
+    }
+
+    /// Returns the number of updates for `instrument_id` which were not emitted on their own.
+    #[must_use]
+    pub fn dropped_count_for(&self, instrument_id: &InstrumentId) -> usize {
+        self.dropped.get(instrument_id).copied().unwrap_or(0)
+    }
+
+    /// Pushes an update, coalescing it with any pending update for the same instrument.
+    pub fn push(&mut self, update: T) {
+        self.recv_count += 1;
+
+        let instrument_id = update.instrument_id();
+        if let Some(pending) = self.pending.get_mut(&instrument_id) {
+            pending.conflate(update);
+            *self.dropped.entry(instrument_id).or_default() += 1;
+        } else {
+            self.pending.insert(instrument_id, update);
+        }
+    }
+
+    /// Returns the pending updates for instruments whose interval has elapsed at `ts_now`.
+    pub fn poll(&mut self, ts_now: UnixNanos) -> Vec<T> {
+        let (ready, waiting): (IndexMap<_, _>, IndexMap<_, _>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|(instrument_id, _)| self.is_ready(instrument_id, ts_now));
+        self.pending = waiting;
+        self.emit(ready, ts_now)
+    }
+
+    /// Returns all pending updates immediately, regardless of the interval.
+    pub fn flush(&mut self, ts_now: UnixNanos) -> Vec<T> {
+        let ready = std::mem::take(&mut self.pending);
+        self.emit(ready, ts_now)
+    }
+
+    /// Discards all pending updates and resets the emission times and counters.
+    pub fn reset(&mut self) {
+        self.pending.clear();
+        self.last_emitted.clear();
+        self.dropped.clear();
+        self.recv_count = 0;
+        self.sent_count = 0;
+    }
+
+    fn is_ready(&self, instrument_id: &InstrumentId, ts_now: UnixNanos) -> bool {
+        match self.last_emitted.get(instrument_id) {
+            Some(last) => ts_now.as_u64() >= last.as_u64() + self.interval_ns,
+            None => true,
+        }
+    }
+
+    fn emit(&mut self, ready: IndexMap<InstrumentId, T>, ts_now: UnixNanos) -> Vec<T> {
+        self.sent_count += ready.len();
+        ready
+            .into_iter()
+            .map(|(instrument_id, update)| {
+                self.last_emitted.insert(instrument_id, ts_now);
+                update
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::{
+            delta::OrderBookDelta,
+            order::BookOrder,
+            stubs::{quote_ethusdt_binance, stub_deltas},
+        },
+        enums::OrderSide,
+        types::{price::Price, quantity::Quantity},
+    };
+    use rstest::rstest;
+
+    use super::*;
+
+    const INTERVAL_NS: u64 = 100;
+
+    fn quote(instrument_id: &str, bid: &str) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from(instrument_id),
+            bid_price: Price::from(bid),
+            ..quote_ethusdt_binance()
+        }
+    }
+
+    fn delta(
+        instrument_id: InstrumentId,
+        action: BookAction,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDeltas {
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from(price),
+            Quantity::from(size),
+            order_id,
+        );
+        let delta = OrderBookDelta::new(
+            instrument_id,
+            action,
+            order,
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        );
+        OrderBookDeltas::new(instrument_id, vec![delta])
+    }
+
+    fn add_delta(instrument_id: InstrumentId, price: &str, sequence: u64) -> OrderBookDeltas {
+        delta(
+            instrument_id,
+            BookAction::Add,
+            price,
+            "1",
+            sequence,
+            sequence,
+        )
+    }
+
+    #[rstest]
+    fn test_rapid_updates_within_interval_emit_final_state_once() {
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        for i in 0..10 {
+            throttler.push(quote("ETHUSDT-PERP.BINANCE", &format!("100{i}.0000")));
+        }
+
+        let emitted = throttler.poll(UnixNanos::from(INTERVAL_NS));
+
+        assert_eq!(emitted.len(), 1);
+        assert_eq!(emitted[0].bid_price, Price::from("1009.0000"));
+        assert_eq!(throttler.recv_count(), 10);
+        assert_eq!(throttler.sent_count(), 1);
+        assert_eq!(throttler.dropped_count(), 9);
+        assert_eq!(throttler.qsize(), 0);
+    }
+
+    #[rstest]
+    fn test_updates_held_until_interval_elapsed() {
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1000.0000"));
+        assert_eq!(throttler.poll(UnixNanos::from(0)).len(), 1);
+
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1001.0000"));
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1002.0000"));
+
+        assert!(throttler.poll(UnixNanos::from(INTERVAL_NS - 1)).is_empty());
+        let emitted = throttler.poll(UnixNanos::from(INTERVAL_NS));
+        assert_eq!(emitted.len(), 1);
+        assert_eq!(emitted[0].bid_price, Price::from("1002.0000"));
+    }
+
+    #[rstest]
+    fn test_instruments_are_throttled_independently() {
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1000.0000"));
+        throttler.poll(UnixNanos::from(0));
+
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1001.0000"));
+        throttler.push(quote("BTCUSDT-PERP.BINANCE", "2000.0000"));
+        throttler.push(quote("BTCUSDT-PERP.BINANCE", "2001.0000"));
+
+        let emitted = throttler.poll(UnixNanos::from(10));
+        assert_eq!(emitted.len(), 1);
+        assert_eq!(
+            emitted[0].instrument_id,
+            InstrumentId::from("BTCUSDT-PERP.BINANCE")
+        );
+        assert_eq!(emitted[0].bid_price, Price::from("2001.0000"));
+        assert_eq!(
+            throttler.dropped_count_for(&InstrumentId::from("BTCUSDT-PERP.BINANCE")),
+            1
+        );
+        assert_eq!(
+            throttler.dropped_count_for(&InstrumentId::from("ETHUSDT-PERP.BINANCE")),
+            0
+        );
+        assert_eq!(throttler.qsize(), 1);
+    }
+
+    #[rstest]
+    fn test_flush_emits_pending_immediately() {
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1000.0000"));
+        throttler.poll(UnixNanos::from(0));
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1001.0000"));
+        throttler.push(quote("BTCUSDT-PERP.BINANCE", "2000.0000"));
+
+        let emitted = throttler.flush(UnixNanos::from(10));
+
+        assert_eq!(emitted.len(), 2);
+        assert_eq!(throttler.qsize(), 0);
+        assert_eq!(throttler.sent_count(), 3);
+    }
+
+    #[rstest]
+    fn test_deltas_are_merged() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        for sequence in 1..=3 {
+            throttler.push(add_delta(
+                instrument_id,
+                &format!("10{sequence}.00"),
+                sequence,
+            ));
+        }
+
+        let emitted = throttler.poll(UnixNanos::from(0));
+
+        assert_eq!(emitted.len(), 1);
+        let deltas = &emitted[0];
+        assert_eq!(deltas.deltas.len(), 3);
+        assert_eq!(deltas.deltas[2].order.price, Price::from("103.00"));
+        assert_eq!(deltas.sequence, 3);
+        assert_eq!(deltas.ts_event, UnixNanos::from(3));
+        assert_eq!(throttler.dropped_count(), 2);
+    }
+
+    #[rstest]
+    fn test_deltas_keep_latest_per_order() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(delta(instrument_id, BookAction::Add, "100.00", "1", 1, 1));
+        throttler.push(delta(instrument_id, BookAction::Add, "101.00", "1", 2, 2));
+        throttler.push(delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "5",
+            1,
+            3,
+        ));
+        throttler.push(delta(
+            instrument_id,
+            BookAction::Delete,
+            "101.00",
+            "1",
+            2,
+            4,
+        ));
+        throttler.push(delta(
+            instrument_id,
+            BookAction::Update,
+            "100.00",
+            "7",
+            1,
+            5,
+        ));
+
+        let emitted = throttler.poll(UnixNanos::from(0));
+
+        let deltas = &emitted[0].deltas;
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].action, BookAction::Delete);
+        assert_eq!(deltas[0].order.order_id, 2);
+        assert_eq!(deltas[1].action, BookAction::Update);
+        assert_eq!(deltas[1].order.size, Quantity::from("7"));
+        assert_eq!(emitted[0].sequence, 5);
+    }
+
+    #[rstest]
+    fn test_deltas_without_order_id_keep_latest_per_level() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        for (size, sequence) in [("1", 1), ("2", 2), ("3", 3)] {
+            throttler.push(delta(
+                instrument_id,
+                BookAction::Update,
+                "100.00",
+                size,
+                0,
+                sequence,
+            ));
+        }
+        throttler.push(delta(instrument_id, BookAction::Update, "99.00", "4", 0, 4));
+
+        let emitted = throttler.poll(UnixNanos::from(0));
+
+        let deltas = &emitted[0].deltas;
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].order.price, Price::from("100.00"));
+        assert_eq!(deltas[0].order.size, Quantity::from("3"));
+        assert_eq!(deltas[1].order.price, Price::from("99.00"));
+    }
+
+    #[rstest]
+    fn test_deltas_with_clear_replace_pending() {
+        let snapshot = stub_deltas();
+        let instrument_id = snapshot.instrument_id;
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(add_delta(instrument_id, "100.00", 1));
+        throttler.push(snapshot.clone());
+
+        let emitted = throttler.poll(UnixNanos::from(0));
+
+        assert_eq!(emitted.len(), 1);
+        assert_eq!(emitted[0].deltas, snapshot.deltas);
+    }
+
+    #[rstest]
+    fn test_reset() {
+        let mut throttler = ConflatingThrottler::new(INTERVAL_NS);
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1000.0000"));
+        throttler.push(quote("ETHUSDT-PERP.BINANCE", "1001.0000"));
+
+        throttler.reset();
+
+        assert_eq!(throttler.qsize(), 0);
+        assert_eq!(throttler.recv_count(), 0);
+        assert_eq!(throttler.dropped_count(), 0);
+        assert!(throttler.poll(UnixNanos::from(0)).is_empty());
+    }
+}
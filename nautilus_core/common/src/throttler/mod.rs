@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 pub mod callbacks;
+pub mod conflation;
 pub mod inner;
 
 use std::{cell::RefCell, fmt::Debug, rc::Rc};
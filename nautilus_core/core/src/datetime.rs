@@ -27,6 +27,9 @@ use crate::nanos::UnixNanos;
 /// Number of milliseconds in one second.
 pub const MILLISECONDS_IN_SECOND: u64 = 1_000;
 
+/// Number of nanoseconds in one day.
+pub const NANOSECONDS_IN_DAY: u64 = 86_400 * NANOSECONDS_IN_SECOND;
+
 /// Number of nanoseconds in one second.
 pub const NANOSECONDS_IN_SECOND: u64 = 1_000_000_000;
 
@@ -18,5 +18,6 @@
 pub mod cvec;
 pub mod datetime;
 pub mod parsing;
+pub mod rolling;
 pub mod string;
 pub mod uuid;
@@ -83,6 +83,30 @@ pub unsafe fn optional_bytes_to_json(ptr: *const c_char) -> Option<HashMap<Strin
     }
 }
 
+/// Convert a C bytes pointer into an owned `Option<Vec<Value>>` from a JSON array.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[must_use]
+pub unsafe fn optional_bytes_to_json_vec(ptr: *const c_char) -> Option<Vec<Value>> {
+    if ptr.is_null() {
+        None
+    } else {
+        let c_str = CStr::from_ptr(ptr);
+        let bytes = c_str.to_bytes();
+        let json_string = std::str::from_utf8(bytes).unwrap();
+        let result: Result<Vec<Value>> = serde_json::from_str(json_string);
+        match result {
+            Ok(values) => Some(values),
+            Err(e) => {
+                eprintln!("Error parsing JSON: {e}");
+                None
+            }
+        }
+    }
+}
+
 /// Convert a C bytes pointer into an owned `Option<HashMap<Ustr, Ustr>>`.
 ///
 /// # Safety
@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use crate::rolling::RollingStats;
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`RollingStats`].
+///
+/// This struct wraps `RollingStats` in a way that makes it compatible with C function
+/// calls, enabling interaction with `RollingStats` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `RollingStats_API` to be
+/// dereferenced to `RollingStats`, providing access to `RollingStats`'s methods without
+/// having to manually access the underlying `RollingStats` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct RollingStats_API(Box<RollingStats>);
+
+impl Deref for RollingStats_API {
+    type Target = RollingStats;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RollingStats_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates new rolling statistics for a window of `capacity` values.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `capacity` is zero.
+#[no_mangle]
+pub extern "C" fn rolling_stats_new(capacity: usize) -> RollingStats_API {
+    RollingStats_API(Box::new(RollingStats::new(capacity)))
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_drop(stats: RollingStats_API) {
+    drop(stats); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_push(stats: &mut RollingStats_API, value: f64) {
+    stats.push(value);
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_capacity(stats: &RollingStats_API) -> usize {
+    stats.capacity()
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_len(stats: &RollingStats_API) -> usize {
+    stats.len()
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_is_full(stats: &RollingStats_API) -> u8 {
+    u8::from(stats.is_full())
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_sum(stats: &RollingStats_API) -> f64 {
+    stats.sum()
+}
+
+/// Returns the mean of the values in the window, or NaN if empty.
+#[no_mangle]
+pub extern "C" fn rolling_stats_mean(stats: &RollingStats_API) -> f64 {
+    stats.mean().unwrap_or(f64::NAN)
+}
+
+/// Returns the minimum value in the window, or NaN if empty.
+#[no_mangle]
+pub extern "C" fn rolling_stats_min(stats: &RollingStats_API) -> f64 {
+    stats.min().unwrap_or(f64::NAN)
+}
+
+/// Returns the maximum value in the window, or NaN if empty.
+#[no_mangle]
+pub extern "C" fn rolling_stats_max(stats: &RollingStats_API) -> f64 {
+    stats.max().unwrap_or(f64::NAN)
+}
+
+/// Returns the sample variance of the values in the window, or NaN if fewer than two values.
+#[no_mangle]
+pub extern "C" fn rolling_stats_variance(stats: &RollingStats_API) -> f64 {
+    stats.variance().unwrap_or(f64::NAN)
+}
+
+/// Returns the sample standard deviation of the values in the window, or NaN if fewer than two
+/// values.
+#[no_mangle]
+pub extern "C" fn rolling_stats_std(stats: &RollingStats_API) -> f64 {
+    stats.std().unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub extern "C" fn rolling_stats_clear(stats: &mut RollingStats_API) {
+    stats.clear();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_rolling_stats_api() {
+        let mut stats = rolling_stats_new(3);
+        assert_eq!(rolling_stats_capacity(&stats), 3);
+        assert!(rolling_stats_mean(&stats).is_nan());
+        assert!(rolling_stats_min(&stats).is_nan());
+
+        for value in [2.0, 1.0, 9.0, 5.0] {
+            rolling_stats_push(&mut stats, value);
+        }
+
+        assert_eq!(rolling_stats_len(&stats), 3);
+        assert_eq!(rolling_stats_is_full(&stats), 1);
+        assert_eq!(rolling_stats_sum(&stats), 15.0);
+        assert_eq!(rolling_stats_mean(&stats), 5.0);
+        assert_eq!(rolling_stats_min(&stats), 1.0);
+        assert_eq!(rolling_stats_max(&stats), 9.0);
+        assert_eq!(rolling_stats_variance(&stats), 16.0);
+        assert_eq!(rolling_stats_std(&stats), 4.0);
+
+        rolling_stats_clear(&mut stats);
+        assert_eq!(rolling_stats_len(&stats), 0);
+        assert!(rolling_stats_variance(&stats).is_nan());
+        rolling_stats_drop(stats);
+    }
+}
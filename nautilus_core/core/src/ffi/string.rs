@@ -14,6 +14,7 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
+    borrow::Cow,
     ffi::{c_char, CStr, CString},
     str,
 };
@@ -53,6 +54,22 @@ pub unsafe fn cstr_to_ustr(ptr: *const c_char) -> Ustr {
     Ustr::from(CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed"))
 }
 
+/// Convert a C string pointer into a [`Ustr`], replacing any invalid UTF-8 sequences
+/// with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `ptr` is null.
+#[must_use]
+pub unsafe fn cstr_to_ustr_lossy(ptr: *const c_char) -> Ustr {
+    Ustr::from(&cstr_to_str_lossy(ptr))
+}
+
 /// Convert a C string pointer into bytes.
 ///
 /// # Safety
@@ -104,6 +121,25 @@ pub unsafe fn cstr_to_str(ptr: *const c_char) -> &'static str {
     CStr::from_ptr(ptr).to_str().expect("CStr::from_ptr failed")
 }
 
+/// Convert a C string pointer into a string, replacing any invalid UTF-8 sequences
+/// with `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// The string is only copied when it contains invalid UTF-8.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `ptr` is null.
+#[must_use]
+pub unsafe fn cstr_to_str_lossy(ptr: *const c_char) -> Cow<'static, str> {
+    assert!(!ptr.is_null(), "`ptr` was NULL");
+    CStr::from_ptr(ptr).to_string_lossy()
+}
+
 /// Convert a C string pointer into an owned `Option<String>`.
 ///
 /// # Safety
@@ -179,6 +215,36 @@ mod tests {
         assert_eq!(result, "test string2");
     }
 
+    #[rstest]
+    fn test_cstr_to_str_lossy_with_valid_utf8() {
+        let c_string = CString::new("test string").expect("CString::new failed");
+        let result = unsafe { cstr_to_str_lossy(c_string.as_ptr()) };
+        assert!(matches!(result, Cow::Borrowed("test string")));
+    }
+
+    #[rstest]
+    fn test_cstr_to_str_lossy_with_invalid_utf8() {
+        let c_string = CString::new(b"bad \xFF\xFE bytes".to_vec()).expect("CString::new failed");
+        let result = unsafe { cstr_to_str_lossy(c_string.as_ptr()) };
+        assert_eq!(result, "bad \u{FFFD}\u{FFFD} bytes");
+    }
+
+    #[rstest]
+    fn test_cstr_to_ustr_lossy_with_invalid_utf8() {
+        let c_string = CString::new(b"Risk\xC3Engine".to_vec()).expect("CString::new failed");
+        let result = unsafe { cstr_to_ustr_lossy(c_string.as_ptr()) };
+        assert_eq!(result.as_str(), "Risk\u{FFFD}Engine");
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_cstr_to_str_lossy_with_null_ptr() {
+        let ptr: *const c_char = std::ptr::null();
+        unsafe {
+            let _ = cstr_to_str_lossy(ptr);
+        };
+    }
+
     #[rstest]
     fn test_cstr_to_vec() {
         // Create a valid C string pointer
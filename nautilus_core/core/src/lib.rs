@@ -33,6 +33,7 @@ pub mod message;
 pub mod nanos;
 pub mod parsing;
 pub mod paths;
+pub mod rolling;
 pub mod serialization;
 pub mod time;
 pub mod uuid;
@@ -0,0 +1,511 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Fixed-capacity rolling windows with constant time updates and running statistics.
+
+use std::collections::{vec_deque, VecDeque};
+
+use crate::correctness::{check_predicate_true, FAILED};
+
+/// A fixed-capacity ring buffer, where pushing a value when full evicts the oldest value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RingBuffer<T> {
+    values: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RingBuffer<T> {
+    /// Creates a new [`RingBuffer`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::new_checked(capacity).expect(FAILED)
+    }
+
+    /// Creates a new [`RingBuffer`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `capacity` is zero.
+    pub fn new_checked(capacity: usize) -> anyhow::Result<Self> {
+        check_predicate_true(capacity > 0, "`capacity` must be positive")?;
+
+        Ok(Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        })
+    }
+
+    /// Returns the maximum number of values held.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of values held.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns whether no values are held.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns whether the buffer is at capacity, so the next push evicts the oldest value.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.values.len() == self.capacity
+    }
+
+    /// Pushes `value` as the newest value, returning the oldest value if it was evicted.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.values.pop_front()
+        } else {
+            None
+        };
+        self.values.push_back(value);
+        evicted
+    }
+
+    /// Returns the oldest value (if any).
+    #[must_use]
+    pub fn oldest(&self) -> Option<&T> {
+        self.values.front()
+    }
+
+    /// Returns the newest value (if any).
+    #[must_use]
+    pub fn newest(&self) -> Option<&T> {
+        self.values.back()
+    }
+
+    /// Returns the value at `index`, counting from the oldest value at index 0.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    /// Returns an iterator over the values from oldest to newest.
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self.values.iter()
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingBuffer<T> {
+    type Item = &'a T;
+    type IntoIter = vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+/// A running sum of `f64` values using Kahan (compensated) summation, which bounds the
+/// rounding error accumulated over many additions and removals.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KahanSum {
+    sum: f64,
+    compensation: f64,
+}
+
+impl KahanSum {
+    /// Adds `value` to the sum.
+    pub fn accumulate(&mut self, value: f64) {
+        let y = value - self.compensation;
+        let t = self.sum + y;
+        self.compensation = (t - self.sum) - y;
+        self.sum = t;
+    }
+
+    /// Removes a previously added `value` from the sum.
+    pub fn remove(&mut self, value: f64) {
+        self.accumulate(-value);
+    }
+
+    /// Returns the sum.
+    #[must_use]
+    pub const fn value(&self) -> f64 {
+        self.sum
+    }
+
+    /// Resets the sum to zero.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Tracks the minimum and maximum of a rolling window of values in amortized constant time.
+///
+/// Each value is held in a monotonic deque only while it can still become the minimum (or
+/// maximum) of the window, so the front of each deque is the current minimum (or maximum).
+#[derive(Clone, Debug)]
+pub struct RollingMinMax<T> {
+    capacity: usize,
+    count: u64,
+    min_candidates: VecDeque<(u64, T)>,
+    max_candidates: VecDeque<(u64, T)>,
+}
+
+impl<T: PartialOrd + Copy> RollingMinMax<T> {
+    /// Creates a new [`RollingMinMax`] instance for a window of `capacity` values.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        check_predicate_true(capacity > 0, "`capacity` must be positive").expect(FAILED);
+
+        Self {
+            capacity,
+            count: 0,
+            min_candidates: VecDeque::with_capacity(capacity),
+            max_candidates: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Pushes `value` into the window, evicting the oldest value when full.
+    pub fn push(&mut self, value: T) {
+        let seq = self.count;
+        self.count += 1;
+
+        while self.min_candidates.back().is_some_and(|(_, v)| *v >= value) {
+            self.min_candidates.pop_back();
+        }
+        self.min_candidates.push_back((seq, value));
+
+        while self.max_candidates.back().is_some_and(|(_, v)| *v <= value) {
+            self.max_candidates.pop_back();
+        }
+        self.max_candidates.push_back((seq, value));
+
+        let window_start = self.count.saturating_sub(self.capacity as u64);
+        while self
+            .min_candidates
+            .front()
+            .is_some_and(|(seq, _)| *seq < window_start)
+        {
+            self.min_candidates.pop_front();
+        }
+        while self
+            .max_candidates
+            .front()
+            .is_some_and(|(seq, _)| *seq < window_start)
+        {
+            self.max_candidates.pop_front();
+        }
+    }
+
+    /// Returns the minimum value in the window (if any).
+    #[must_use]
+    pub fn min(&self) -> Option<T> {
+        self.min_candidates.front().map(|(_, value)| *value)
+    }
+
+    /// Returns the maximum value in the window (if any).
+    #[must_use]
+    pub fn max(&self) -> Option<T> {
+        self.max_candidates.front().map(|(_, value)| *value)
+    }
+
+    /// Removes all values from the window.
+    pub fn clear(&mut self) {
+        self.count = 0;
+        self.min_candidates.clear();
+        self.max_candidates.clear();
+    }
+}
+
+/// Running statistics over a rolling window of `f64` values, each updated in (amortized)
+/// constant time per push.
+///
+/// The sum is maintained with Kahan summation. The variance is maintained from sums of the values
+/// shifted by the first value pushed, avoiding the cancellation of large squared terms when
+/// the values are far from zero (e.g. prices).
+#[derive(Clone, Debug)]
+pub struct RollingStats {
+    window: RingBuffer<f64>,
+    min_max: RollingMinMax<f64>,
+    sum: KahanSum,
+    shift: f64,
+    shifted_sum: KahanSum,
+    shifted_sum_squares: KahanSum,
+}
+
+impl RollingStats {
+    /// Creates a new [`RollingStats`] instance for a window of `capacity` values.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            window: RingBuffer::new(capacity),
+            min_max: RollingMinMax::new(capacity),
+            sum: KahanSum::default(),
+            shift: 0.0,
+            shifted_sum: KahanSum::default(),
+            shifted_sum_squares: KahanSum::default(),
+        }
+    }
+
+    /// Pushes `value` into the window, returning the oldest value if it was evicted.
+    pub fn push(&mut self, value: f64) -> Option<f64> {
+        if self.window.is_empty() {
+            self.shift = value;
+        }
+
+        let evicted = self.window.push(value);
+        if let Some(evicted) = evicted {
+            let shifted = evicted - self.shift;
+            self.sum.remove(evicted);
+            self.shifted_sum.remove(shifted);
+            self.shifted_sum_squares.remove(shifted * shifted);
+        }
+
+        let shifted = value - self.shift;
+        self.sum.accumulate(value);
+        self.shifted_sum.accumulate(shifted);
+        self.shifted_sum_squares.accumulate(shifted * shifted);
+        self.min_max.push(value);
+
+        evicted
+    }
+
+    /// Returns the values in the window.
+    #[must_use]
+    pub const fn window(&self) -> &RingBuffer<f64> {
+        &self.window
+    }
+
+    /// Returns the maximum number of values in the window.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.window.capacity()
+    }
+
+    /// Returns the number of values in the window.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Returns whether the window holds no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Returns whether the window is at capacity.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.window.is_full()
+    }
+
+    /// Returns the sum of the values in the window.
+    #[must_use]
+    pub const fn sum(&self) -> f64 {
+        self.sum.value()
+    }
+
+    /// Returns the mean of the values in the window (if any).
+    #[must_use]
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.sum() / self.len() as f64)
+    }
+
+    /// Returns the minimum value in the window (if any).
+    #[must_use]
+    pub fn min(&self) -> Option<f64> {
+        self.min_max.min()
+    }
+
+    /// Returns the maximum value in the window (if any).
+    #[must_use]
+    pub fn max(&self) -> Option<f64> {
+        self.min_max.max()
+    }
+
+    /// Returns the sample variance of the values in the window (requires at least two values).
+    #[must_use]
+    pub fn variance(&self) -> Option<f64> {
+        let n = self.len() as f64;
+        (self.len() >= 2).then(|| {
+            let shifted_sum = self.shifted_sum.value();
+            let sum_squared_deviations =
+                (shifted_sum / n).mul_add(-shifted_sum, self.shifted_sum_squares.value());
+            (sum_squared_deviations / (n - 1.0)).max(0.0)
+        })
+    }
+
+    /// Returns the sample standard deviation of the values in the window (requires at least two
+    /// values).
+    #[must_use]
+    pub fn std(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Removes all values from the window.
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.min_max.clear();
+        self.sum.reset();
+        self.shift = 0.0;
+        self.shifted_sum.reset();
+        self.shifted_sum_squares.reset();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_ring_buffer_push_evicts_oldest_when_full() {
+        let mut buffer = RingBuffer::new(3);
+        assert!(buffer.is_empty());
+
+        assert_eq!(buffer.push(1), None);
+        assert_eq!(buffer.push(2), None);
+        assert_eq!(buffer.push(3), None);
+        assert!(buffer.is_full());
+        assert_eq!(buffer.push(4), Some(1));
+
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.oldest(), Some(&2));
+        assert_eq!(buffer.newest(), Some(&4));
+        assert_eq!(buffer.get(1), Some(&3));
+        assert_eq!(buffer.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        buffer.clear();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.oldest(), None);
+    }
+
+    #[rstest]
+    fn test_ring_buffer_zero_capacity_is_error() {
+        assert!(RingBuffer::<f64>::new_checked(0).is_err());
+    }
+
+    #[rstest]
+    fn test_kahan_sum_compensates_rounding_error() {
+        let mut sum = KahanSum::default();
+        let mut naive = 0.0;
+        for _ in 0..10 {
+            sum.accumulate(0.1);
+            naive += 0.1;
+        }
+        assert_eq!(sum.value(), 1.0);
+        assert_ne!(naive, 1.0);
+
+        sum.remove(0.1);
+        assert_eq!(sum.value(), 0.9);
+    }
+
+    #[rstest]
+    fn test_rolling_stats_empty() {
+        let stats = RollingStats::new(10);
+        assert_eq!(stats.sum(), 0.0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.min(), None);
+        assert_eq!(stats.max(), None);
+        assert_eq!(stats.variance(), None);
+    }
+
+    #[rstest]
+    fn test_rolling_stats_with_values() {
+        let mut stats = RollingStats::new(3);
+        stats.push(2.0);
+        assert_eq!(stats.mean(), Some(2.0));
+        assert_eq!(stats.variance(), None);
+
+        stats.push(1.0);
+        stats.push(9.0);
+        assert_eq!(stats.push(5.0), Some(2.0));
+
+        assert!(stats.is_full());
+        assert_eq!(stats.sum(), 15.0);
+        assert_eq!(stats.mean(), Some(5.0));
+        assert_eq!(stats.min(), Some(1.0));
+        assert_eq!(stats.max(), Some(9.0));
+        assert_eq!(stats.variance(), Some(16.0));
+        assert_eq!(stats.std(), Some(4.0));
+
+        stats.clear();
+        assert!(stats.is_empty());
+        assert_eq!(stats.max(), None);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(7)]
+    #[case(50)]
+    fn test_rolling_stats_matches_naive_recomputation(#[case] capacity: usize) {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut stats = RollingStats::new(capacity);
+        let mut values: Vec<f64> = Vec::new();
+
+        for _ in 0..1_000 {
+            // Repeated values exercise ties in the monotonic deques
+            let value = if rng.gen_bool(0.1) {
+                values.last().copied().unwrap_or(1_000.0)
+            } else {
+                rng.gen_range(900.0..1_100.0)
+            };
+            stats.push(value);
+            values.push(value);
+
+            let window = &values[values.len().saturating_sub(capacity)..];
+            let n = window.len() as f64;
+            let mean = window.iter().sum::<f64>() / n;
+            let min = window.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = window.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+            assert_eq!(stats.len(), window.len());
+            assert_eq!(stats.min(), Some(min));
+            assert_eq!(stats.max(), Some(max));
+            assert!((stats.mean().unwrap() - mean).abs() < 1e-9);
+
+            if window.len() >= 2 {
+                let variance = window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+                assert!((stats.variance().unwrap() - variance).abs() < 1e-6);
+            }
+        }
+    }
+}
@@ -18,7 +18,7 @@
 use bytes::Bytes;
 use serde::{
     de::{Unexpected, Visitor},
-    Deserializer,
+    Deserializer, Serializer,
 };
 
 struct BoolVisitor;
@@ -80,19 +80,31 @@ where
     deserializer.deserialize_any(BoolVisitor)
 }
 
+/// Serializes a `u8` flag as a boolean, the inverse of [`from_bool_as_u8`].
+#[allow(clippy::trivially_copy_pass_by_ref)] // Signature required by `serialize_with`
+pub fn to_bool_from_u8<S>(value: &u8, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_bool(*value != 0)
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Tests
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
     use rstest::*;
-    use serde::Deserialize;
+    use serde::{Deserialize, Serialize};
 
-    use super::from_bool_as_u8;
+    use super::{from_bool_as_u8, to_bool_from_u8};
 
-    #[derive(Deserialize)]
+    #[derive(Deserialize, Serialize)]
     pub struct TestStruct {
-        #[serde(deserialize_with = "from_bool_as_u8")]
+        #[serde(
+            deserialize_with = "from_bool_as_u8",
+            serialize_with = "to_bool_from_u8"
+        )]
         pub value: u8,
     }
 
@@ -111,4 +123,12 @@ mod tests {
         let test_struct: TestStruct = serde_json::from_str(json_str).unwrap();
         assert_eq!(test_struct.value, expected);
     }
+
+    #[rstest]
+    #[case(1, r#"{"value":true}"#)]
+    #[case(0, r#"{"value":false}"#)]
+    fn test_serialize_u8_as_bool(#[case] value: u8, #[case] expected: &str) {
+        let json_str = serde_json::to_string(&TestStruct { value }).unwrap();
+        assert_eq!(json_str, expected);
+    }
 }
@@ -123,7 +123,7 @@ impl Serialize for UUID4 {
     where
         S: Serializer,
     {
-        self.value.serialize(serializer)
+        self.to_string().serialize(serializer)
     }
 }
 
@@ -132,9 +132,8 @@ impl<'de> Deserialize<'de> for UUID4 {
     where
         D: Deserializer<'de>,
     {
-        let uuid4_str: &str = Deserialize::deserialize(_deserializer)?;
-        let uuid4: Self = uuid4_str.into();
-        Ok(uuid4)
+        let uuid4_str: String = Deserialize::deserialize(_deserializer)?;
+        Self::from_str(&uuid4_str).map_err(serde::de::Error::custom)
     }
 }
 
@@ -202,4 +201,22 @@ mod tests {
         let uuid = UUID4::from(uuid_string);
         assert_eq!(format!("{uuid}"), uuid_string);
     }
+
+    #[rstest]
+    fn test_serialization_json_roundtrip() {
+        let uuid_string = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+        let uuid = UUID4::from(uuid_string);
+
+        let json = serde_json::to_string(&uuid).unwrap();
+        let deserialized: UUID4 = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, format!("\"{uuid_string}\""));
+        assert_eq!(deserialized, uuid);
+    }
+
+    #[rstest]
+    fn test_deserialize_invalid() {
+        let result: Result<UUID4, _> = serde_json::from_str("\"not-a-uuid\"");
+        assert!(result.is_err());
+    }
 }
@@ -59,6 +59,15 @@ pub trait BarAggregator {
     fn handle_trade(&mut self, trade: TradeTick) {
         self.update(trade.price, trade.size, trade.ts_event);
     }
+    /// Updates the aggregator with the given bar and volume.
+    fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos);
+    /// Updates the aggregator with the given bar.
+    ///
+    /// Used to aggregate a composite bar type from bars of its composite component (e.g.
+    /// 5-MINUTE bars from 1-MINUTE EXTERNAL bars).
+    fn handle_bar(&mut self, bar: Bar) {
+        self.update_bar(bar, bar.volume, bar.ts_init);
+    }
 }
 
 /// Provides a generic bar builder for aggregation.
@@ -174,6 +183,32 @@ impl BarBuilder {
         self.ts_last = ts_event;
     }
 
+    /// Update the bar builder with the given bar.
+    pub fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        if ts_init < self.ts_last {
+            return; // Not applicable
+        }
+
+        if self.open.is_none() {
+            self.open = Some(bar.open);
+            self.high = Some(bar.high);
+            self.low = Some(bar.low);
+            self.initialized = true;
+        } else {
+            if bar.high > self.high.unwrap() {
+                self.high = Some(bar.high);
+            }
+            if bar.low < self.low.unwrap() {
+                self.low = Some(bar.low);
+            }
+        }
+
+        self.close = Some(bar.close);
+        self.volume = self.volume.add(volume);
+        self.count += 1;
+        self.ts_last = ts_init;
+    }
+
     /// Reset the bar builder.
     ///
     /// All stateful fields are reset to their initial value.
@@ -266,6 +301,10 @@ where
         self.builder.update(price, size, ts_event);
     }
 
+    fn apply_update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        self.builder.update_bar(bar, volume, ts_init);
+    }
+
     fn build_now_and_send(&mut self) {
         let bar = self.builder.build_now();
         (self.handler)(bar);
@@ -328,6 +367,16 @@ where
             self.core.build_now_and_send();
         }
     }
+
+    /// Apply the given bar update to the aggregator.
+    fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        self.core.apply_update_bar(bar, volume, ts_init);
+        let spec = self.core.bar_type.spec();
+
+        if self.core.builder.count >= spec.step {
+            self.core.build_now_and_send();
+        }
+    }
 }
 
 /// Provides a means of building volume bars aggregated from quote and trade ticks.
@@ -398,6 +447,34 @@ where
             raw_size_update -= raw_size_diff;
         }
     }
+
+    /// Apply the given bar update to the aggregator.
+    fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        let mut raw_volume_update = volume.raw;
+        let spec = self.core.bar_type.spec();
+        let raw_step = (spec.step as f64 * FIXED_SCALAR) as u64;
+
+        while raw_volume_update > 0 {
+            if self.core.builder.volume.raw + raw_volume_update < raw_step {
+                self.core.apply_update_bar(
+                    bar,
+                    Quantity::from_raw(raw_volume_update, volume.precision),
+                    ts_init,
+                );
+                break;
+            }
+
+            let raw_volume_diff = raw_step - self.core.builder.volume.raw;
+            self.core.apply_update_bar(
+                bar,
+                Quantity::from_raw(raw_volume_diff, volume.precision),
+                ts_init,
+            );
+
+            self.core.build_now_and_send();
+            raw_volume_update -= raw_volume_diff;
+        }
+    }
 }
 
 /// Provides a means of building value bars aggregated from quote and trade ticks.
@@ -474,6 +551,35 @@ where
             size_update -= size_diff;
         }
     }
+
+    /// Apply the given bar update to the aggregator, valuing its volume at the close price.
+    fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        let mut volume_update = volume.as_f64();
+        let price = bar.close.as_f64();
+        let spec = self.core.bar_type.spec();
+
+        while volume_update > 0.0 {
+            let value_update = price * volume_update;
+            if self.cum_value + value_update < spec.step as f64 {
+                self.cum_value += value_update;
+                self.core.apply_update_bar(
+                    bar,
+                    Quantity::new(volume_update, volume.precision),
+                    ts_init,
+                );
+                break;
+            }
+
+            let value_diff = spec.step as f64 - self.cum_value;
+            let volume_diff = volume_update * (value_diff / value_update);
+            self.core
+                .apply_update_bar(bar, Quantity::new(volume_diff, volume.precision), ts_init);
+
+            self.core.build_now_and_send();
+            self.cum_value = 0.0;
+            volume_update -= volume_diff;
+        }
+    }
 }
 
 /// Provides a means of building time bars aggregated from quote and trade ticks.
@@ -620,9 +726,23 @@ where
 
     fn update(&mut self, price: Price, size: Quantity, ts_event: UnixNanos) {
         self.core.apply_update(price, size, ts_event);
-        if self.build_on_next_tick {
-            let ts_init = ts_event;
+        self.build_if_pending(ts_event);
+    }
 
+    fn update_bar(&mut self, bar: Bar, volume: Quantity, ts_init: UnixNanos) {
+        self.core.apply_update_bar(bar, volume, ts_init);
+        self.build_if_pending(ts_init);
+    }
+}
+
+impl<C, H> TimeBarAggregator<C, H>
+where
+    C: Clock,
+    H: FnMut(Bar),
+{
+    /// Builds and sends the bar deferred by a timer firing before any update, if pending.
+    fn build_if_pending(&mut self, ts_init: UnixNanos) {
+        if self.build_on_next_tick {
             let ts_event = if self.is_left_open {
                 if self.timestamp_on_close {
                     self.stored_close_ns
@@ -647,16 +767,49 @@ where
 mod tests {
     use std::sync::{Arc, Mutex};
 
+    use nautilus_common::clock::TestClock;
+    use nautilus_core::uuid::UUID4;
     use nautilus_model::{
         data::bar::{BarSpecification, BarType},
         enums::{AggregationSource, BarAggregation, PriceType},
         instruments::{any::InstrumentAny, equity::Equity, stubs::*},
         types::{price::Price, quantity::Quantity},
     };
-    use rstest::rstest;
+    use rstest::{fixture, rstest};
+    use ustr::Ustr;
 
     use super::*;
 
+    const ONE_MINUTE_NS: u64 = 60_000_000_000;
+
+    fn minute_bar(bar_type: BarType, minute: u64, ohlc: [&str; 4], volume: &str) -> Bar {
+        let ts = UnixNanos::from(minute * ONE_MINUTE_NS);
+        Bar::new(
+            bar_type,
+            Price::from(ohlc[0]),
+            Price::from(ohlc[1]),
+            Price::from(ohlc[2]),
+            Price::from(ohlc[3]),
+            Quantity::from(volume),
+            ts,
+            ts,
+        )
+    }
+
+    /// Six consecutive 1-MINUTE EXTERNAL bars for AAPL closing at minutes 1 to 6.
+    #[fixture]
+    fn one_minute_bars() -> Vec<Bar> {
+        let bar_type = BarType::from("AAPL.XNAS-1-MINUTE-LAST-EXTERNAL");
+        vec![
+            minute_bar(bar_type, 1, ["100.00", "101.00", "99.50", "100.50"], "10"),
+            minute_bar(bar_type, 2, ["100.50", "102.00", "100.25", "101.75"], "20"),
+            minute_bar(bar_type, 3, ["101.75", "101.80", "98.75", "99.00"], "30"),
+            minute_bar(bar_type, 4, ["99.00", "100.00", "98.90", "99.90"], "40"),
+            minute_bar(bar_type, 5, ["99.90", "100.40", "99.60", "100.20"], "50"),
+            minute_bar(bar_type, 6, ["100.20", "100.30", "100.10", "100.15"], "60"),
+        ]
+    }
+
     #[rstest]
     fn test_bar_builder_instantiate(equity_aapl: Equity) {
         let instrument = InstrumentAny::Equity(equity_aapl);
@@ -915,6 +1068,158 @@ mod tests {
         assert_eq!(bar.volume, Quantity::new(3.0, 0));
     }
 
+    #[rstest]
+    fn test_bar_builder_update_bar(equity_aapl: Equity, one_minute_bars: Vec<Bar>) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-2-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let mut builder = BarBuilder::new(&instrument, bar_type);
+
+        for bar in &one_minute_bars[..2] {
+            builder.update_bar(*bar, bar.volume, bar.ts_init);
+        }
+        let bar = builder.build_now();
+
+        assert_eq!(bar.bar_type, bar_type);
+        assert_eq!(bar.open, Price::from("100.00"));
+        assert_eq!(bar.high, Price::from("102.00"));
+        assert_eq!(bar.low, Price::from("99.50"));
+        assert_eq!(bar.close, Price::from("101.75"));
+        assert_eq!(bar.volume, Quantity::from("30"));
+        assert_eq!(bar.ts_init, 2 * ONE_MINUTE_NS);
+    }
+
+    #[rstest]
+    fn test_bar_builder_update_bar_when_timestamp_less_than_last_update_ignores(
+        equity_aapl: Equity,
+        one_minute_bars: Vec<Bar>,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-2-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let mut builder = BarBuilder::new(&instrument, bar_type);
+
+        let (earlier, later) = (one_minute_bars[0], one_minute_bars[1]);
+        builder.update_bar(later, later.volume, later.ts_init);
+        builder.update_bar(earlier, earlier.volume, earlier.ts_init);
+
+        assert_eq!(builder.count, 1);
+        assert_eq!(builder.ts_last, later.ts_init);
+    }
+
+    #[rstest]
+    fn test_tick_bar_aggregator_handle_bar_when_step_count_reached(
+        equity_aapl: Equity,
+        one_minute_bars: Vec<Bar>,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-3-TICK-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+
+        let mut aggregator = TickBarAggregator::new(
+            &instrument,
+            bar_type,
+            move |bar: Bar| handler_clone.lock().unwrap().push(bar),
+            false,
+        );
+
+        for bar in one_minute_bars {
+            aggregator.handle_bar(bar);
+        }
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 2);
+        assert_eq!(handler_guard[0].open, Price::from("100.00"));
+        assert_eq!(handler_guard[0].close, Price::from("99.00"));
+        assert_eq!(handler_guard[0].volume, Quantity::from("60"));
+        assert_eq!(handler_guard[1].open, Price::from("99.00"));
+        assert_eq!(handler_guard[1].close, Price::from("100.15"));
+        assert_eq!(handler_guard[1].volume, Quantity::from("150"));
+    }
+
+    #[rstest]
+    fn test_volume_bar_aggregator_handle_bar_splits_volume(
+        equity_aapl: Equity,
+        one_minute_bars: Vec<Bar>,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-25-VOLUME-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+
+        let mut aggregator = VolumeBarAggregator::new(
+            &instrument,
+            bar_type,
+            move |bar: Bar| handler_clone.lock().unwrap().push(bar),
+            false,
+        );
+
+        // 10 + 20 volume fills the first bar, with the remaining 5 carried into the second
+        aggregator.handle_bar(one_minute_bars[0]);
+        aggregator.handle_bar(one_minute_bars[1]);
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 1);
+        assert_eq!(handler_guard[0].open, Price::from("100.00"));
+        assert_eq!(handler_guard[0].high, Price::from("102.00"));
+        assert_eq!(handler_guard[0].close, Price::from("101.75"));
+        assert_eq!(handler_guard[0].volume, Quantity::from("25"));
+    }
+
+    #[rstest]
+    fn test_time_bar_aggregator_composes_five_minute_bars_from_one_minute_bars(
+        equity_aapl: Equity,
+        one_minute_bars: Vec<Bar>,
+    ) {
+        let instrument = InstrumentAny::Equity(equity_aapl);
+        let bar_type = BarType::from("AAPL.XNAS-5-MINUTE-LAST-INTERNAL@1-MINUTE-EXTERNAL");
+        let handler = Arc::new(Mutex::new(Vec::new()));
+        let handler_clone = Arc::clone(&handler);
+
+        let mut aggregator = TimeBarAggregator::new(
+            &instrument,
+            bar_type,
+            move |bar: Bar| handler_clone.lock().unwrap().push(bar),
+            false,
+            TestClock::new(),
+            false,
+            true,
+            "left-open",
+        );
+        let timer_name = Ustr::from(&bar_type.to_string());
+        let close_event = |minute: u64| {
+            let ts = UnixNanos::from(minute * ONE_MINUTE_NS);
+            TimeEvent::new(timer_name, UUID4::new(), ts, ts)
+        };
+
+        for bar in &one_minute_bars[..5] {
+            assert_eq!(bar.bar_type, bar_type.composite());
+            aggregator.handle_bar(*bar);
+        }
+        aggregator.build_bar(close_event(5));
+        aggregator.handle_bar(one_minute_bars[5]);
+        aggregator.build_bar(close_event(10));
+
+        let handler_guard = handler.lock().unwrap();
+        assert_eq!(handler_guard.len(), 2);
+
+        let bar = handler_guard[0];
+        assert_eq!(bar.bar_type, bar_type);
+        assert_eq!(bar.open, Price::from("100.00"));
+        assert_eq!(bar.high, Price::from("102.00"));
+        assert_eq!(bar.low, Price::from("98.75"));
+        assert_eq!(bar.close, Price::from("100.20"));
+        assert_eq!(bar.volume, Quantity::from("150"));
+        assert_eq!(bar.ts_init, 5 * ONE_MINUTE_NS);
+
+        let bar = handler_guard[1];
+        assert_eq!(bar.open, Price::from("100.20"));
+        assert_eq!(bar.high, Price::from("100.30"));
+        assert_eq!(bar.low, Price::from("100.10"));
+        assert_eq!(bar.close, Price::from("100.15"));
+        assert_eq!(bar.volume, Quantity::from("60"));
+        assert_eq!(bar.ts_init, 10 * ONE_MINUTE_NS);
+    }
+
     #[rstest]
     fn test_tick_bar_aggregator_handle_trade_when_step_count_below_threshold(equity_aapl: Equity) {
         let instrument = InstrumentAny::Equity(equity_aapl);
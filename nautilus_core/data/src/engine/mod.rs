@@ -727,11 +727,8 @@ impl DataEngine {
             .borrow_mut()
             .order_book(&data.instrument_id())
         {
-            match data {
-                Data::Delta(delta) => book.apply_delta(delta),
-                Data::Deltas(deltas) => book.apply_deltas(deltas),
-                Data::Depth10(depth) => book.apply_depth(depth),
-                _ => log::error!("Invalid data type for book update, was {data:?}"),
+            if let Err(e) = book.apply(data) {
+                log::error!("Error updating order book: {e}");
             }
         }
     }
@@ -818,7 +815,7 @@ mod tests {
     fn init_logger(stdout_level: LevelFilter) {
         let mut config = LoggerConfig::default();
         config.stdout_level = stdout_level;
-        init_logging(
+        let _ = init_logging(
             TraderId::default(),
             UUID4::new(),
             config,
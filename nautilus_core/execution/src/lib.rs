@@ -29,5 +29,10 @@
 
 pub mod client;
 pub mod engine;
+pub mod matching_book;
 pub mod matching_core;
 pub mod messages;
+pub mod reconciliation;
+pub mod reports;
+pub mod throttled_queue;
+pub mod trailing;
@@ -0,0 +1,1010 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! An `OrderMatchingBook` of resting orders for simulated matching against market data.
+//!
+//! Where the `OrderMatchingCore` works with full order objects and callbacks, the matching
+//! book holds compact C compatible order references and returns the resulting triggers and
+//! fills, so that it can be driven directly from the Cython matching engine.
+//!
+//! The fill model is as follows:
+//! - An order which is marketable when it enters the book (on add, on update, or when a stop
+//!   order triggers) takes liquidity at the opposing best price, as a `TAKER`.
+//! - A resting limit order fills at its limit price, as a `MAKER`, when the opposing best
+//!   price reaches it, or when a trade prints through it (or at it, if configured to fill on touch).
+//! - Fill quantities are limited by the displayed size at the opposing best price for the
+//!   current quote (which taker fills consume), or by the trade size for trade fills.
+//!   Any unfilled quantity remains in the book, so orders can partially fill.
+//!
+//! Resting orders are matched in price-time priority, and have priority over orders
+//! entering the book for the same liquidity.
+
+use std::cmp::Ordering;
+
+use nautilus_model::{
+    data::{quote::QuoteTick, trade::TradeTick},
+    enums::{LiquiditySide, OrderSide, OrderSideSpecified, OrderType, TriggerType},
+    identifiers::{ClientOrderId, InstrumentId},
+    orderbook::book::OrderBook,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Represents an order resting in an [`OrderMatchingBook`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MatchingOrder {
+    /// The client order ID.
+    pub client_order_id: ClientOrderId,
+    /// The order side.
+    pub order_side: OrderSide,
+    /// The order type (`LIMIT`, `STOP_MARKET` or `STOP_LIMIT`).
+    pub order_type: OrderType,
+    /// The limit price (the trigger price for `STOP_MARKET` orders).
+    pub price: Price,
+    /// The trigger price (the limit price for `LIMIT` orders).
+    pub trigger_price: Price,
+    /// The trigger type (`NO_TRIGGER` for `LIMIT` orders).
+    pub trigger_type: TriggerType,
+    /// The quantity remaining to be filled.
+    pub leaves_qty: Quantity,
+}
+
+impl MatchingOrder {
+    /// Creates a new `LIMIT` [`MatchingOrder`] instance.
+    #[must_use]
+    pub const fn limit(
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        price: Price,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            client_order_id,
+            order_side,
+            order_type: OrderType::Limit,
+            price,
+            trigger_price: price,
+            trigger_type: TriggerType::NoTrigger,
+            leaves_qty: quantity,
+        }
+    }
+
+    /// Creates a new `STOP_MARKET` [`MatchingOrder`] instance.
+    #[must_use]
+    pub const fn stop_market(
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            client_order_id,
+            order_side,
+            order_type: OrderType::StopMarket,
+            price: trigger_price,
+            trigger_price,
+            trigger_type,
+            leaves_qty: quantity,
+        }
+    }
+
+    /// Creates a new `STOP_LIMIT` [`MatchingOrder`] instance.
+    #[must_use]
+    pub const fn stop_limit(
+        client_order_id: ClientOrderId,
+        order_side: OrderSide,
+        price: Price,
+        trigger_price: Price,
+        trigger_type: TriggerType,
+        quantity: Quantity,
+    ) -> Self {
+        Self {
+            client_order_id,
+            order_side,
+            order_type: OrderType::StopLimit,
+            price,
+            trigger_price,
+            trigger_type,
+            leaves_qty: quantity,
+        }
+    }
+
+    const fn is_stop(&self) -> bool {
+        matches!(
+            self.order_type,
+            OrderType::StopMarket | OrderType::StopLimit
+        )
+    }
+
+    fn check_valid(&self) -> anyhow::Result<()> {
+        if !self.leaves_qty.is_positive() {
+            anyhow::bail!(
+                "Invalid `leaves_qty` for {}, must be positive, was {}",
+                self.client_order_id,
+                self.leaves_qty
+            );
+        }
+        if !matches!(
+            self.order_type,
+            OrderType::Limit | OrderType::StopMarket | OrderType::StopLimit
+        ) {
+            anyhow::bail!(
+                "Unsupported order type for {}, was {}",
+                self.client_order_id,
+                self.order_type
+            );
+        }
+        if self.is_stop()
+            && !matches!(
+                self.trigger_type,
+                TriggerType::Default
+                    | TriggerType::BidAsk
+                    | TriggerType::LastTrade
+                    | TriggerType::LastOrBidAsk
+                    | TriggerType::MidPoint
+            )
+        {
+            anyhow::bail!(
+                "Unsupported trigger type for {}, was {}",
+                self.client_order_id,
+                self.trigger_type
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The kind of an [`OrderMatch`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum OrderMatchKind {
+    /// A stop order was triggered.
+    Triggered = 1,
+    /// An order was filled (fully or partially).
+    Filled = 2,
+}
+
+/// Represents an order trigger or fill generated by an [`OrderMatchingBook`].
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct OrderMatch {
+    /// The client order ID.
+    pub client_order_id: ClientOrderId,
+    /// The kind of match.
+    pub kind: OrderMatchKind,
+    /// The fill price, or the market price which triggered the order.
+    pub last_px: Price,
+    /// The fill quantity (zero for triggers).
+    pub last_qty: Quantity,
+    /// The quantity remaining to be filled after the match.
+    pub leaves_qty: Quantity,
+    /// The liquidity side of the fill (`NO_LIQUIDITY_SIDE` for triggers).
+    pub liquidity_side: LiquiditySide,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct RestingOrder {
+    order: MatchingOrder,
+    sequence: u64,
+    is_triggered: bool,
+    is_aggressive: bool,
+}
+
+impl RestingOrder {
+    const fn is_pending_trigger(&self) -> bool {
+        self.order.is_stop() && !self.is_triggered
+    }
+
+    const fn is_market(&self) -> bool {
+        matches!(self.order.order_type, OrderType::StopMarket) && self.is_triggered
+    }
+
+    fn priority_cmp(&self, other: &Self) -> Ordering {
+        let by_price = match self.order.order_side.as_specified() {
+            OrderSideSpecified::Buy => other.order.price.cmp(&self.order.price),
+            OrderSideSpecified::Sell => self.order.price.cmp(&other.order.price),
+        };
+        by_price.then(self.sequence.cmp(&other.sequence))
+    }
+}
+
+/// The market data which caused the matching book to be iterated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum MarketUpdate {
+    Quote,
+    Trade(Price, Quantity),
+    Order,
+}
+
+/// Provides a price-time priority book of resting orders for an instrument, which generates
+/// triggers and fills from market data.
+#[derive(Clone, Debug)]
+pub struct OrderMatchingBook {
+    /// The instrument ID for the matching book.
+    pub instrument_id: InstrumentId,
+    /// If resting limit orders fill when a trade prints at (rather than through) their price.
+    pub fill_on_touch: bool,
+    /// The current best bid price.
+    pub bid: Option<Price>,
+    /// The current best ask price.
+    pub ask: Option<Price>,
+    /// The last traded price.
+    pub last: Option<Price>,
+    bid_liquidity_raw: u64,
+    ask_liquidity_raw: u64,
+    orders_bid: Vec<RestingOrder>,
+    orders_ask: Vec<RestingOrder>,
+    sequence: u64,
+}
+
+impl OrderMatchingBook {
+    /// Creates a new [`OrderMatchingBook`] instance.
+    #[must_use]
+    pub const fn new(instrument_id: InstrumentId, fill_on_touch: bool) -> Self {
+        Self {
+            instrument_id,
+            fill_on_touch,
+            bid: None,
+            ask: None,
+            last: None,
+            bid_liquidity_raw: 0,
+            ask_liquidity_raw: 0,
+            orders_bid: Vec::new(),
+            orders_ask: Vec::new(),
+            sequence: 0,
+        }
+    }
+
+    // -- QUERIES ---------------------------------------------------------------------------------
+
+    /// Returns the resting buy orders in price-time priority.
+    #[must_use]
+    pub fn orders_bid(&self) -> Vec<MatchingOrder> {
+        self.orders_bid.iter().map(|o| o.order).collect()
+    }
+
+    /// Returns the resting sell orders in price-time priority.
+    #[must_use]
+    pub fn orders_ask(&self) -> Vec<MatchingOrder> {
+        self.orders_ask.iter().map(|o| o.order).collect()
+    }
+
+    /// Returns the resting order with the given `client_order_id` (if found).
+    #[must_use]
+    pub fn order(&self, client_order_id: ClientOrderId) -> Option<MatchingOrder> {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .find(|o| o.order.client_order_id == client_order_id)
+            .map(|o| o.order)
+    }
+
+    /// Returns whether an order with the given `client_order_id` is resting in the book.
+    #[must_use]
+    pub fn order_exists(&self, client_order_id: ClientOrderId) -> bool {
+        self.order(client_order_id).is_some()
+    }
+
+    /// Returns whether the stop order with the given `client_order_id` has been triggered.
+    #[must_use]
+    pub fn is_triggered(&self, client_order_id: ClientOrderId) -> bool {
+        self.orders_bid
+            .iter()
+            .chain(self.orders_ask.iter())
+            .any(|o| o.order.client_order_id == client_order_id && o.is_triggered)
+    }
+
+    // -- COMMANDS --------------------------------------------------------------------------------
+
+    /// Resets the matching book to its initial state, removing all orders.
+    pub fn reset(&mut self) {
+        self.bid = None;
+        self.ask = None;
+        self.last = None;
+        self.bid_liquidity_raw = 0;
+        self.ask_liquidity_raw = 0;
+        self.orders_bid.clear();
+        self.orders_ask.clear();
+        self.sequence = 0;
+    }
+
+    /// Adds the given `order` to the book, returning any immediate triggers and fills.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If an order with the same client order ID is already in the book.
+    /// - If the order type or trigger type is not supported, or `leaves_qty` is not positive.
+    pub fn add_order(&mut self, order: MatchingOrder) -> anyhow::Result<Vec<OrderMatch>> {
+        order.check_valid()?;
+        if self.order_exists(order.client_order_id) {
+            anyhow::bail!("Order {} already exists", order.client_order_id);
+        }
+
+        self.insert(order);
+        Ok(self.iterate(MarketUpdate::Order))
+    }
+
+    /// Updates the price, trigger price and quantity of the resting order matching the
+    /// client order ID of `order`, returning any immediate triggers and fills.
+    ///
+    /// The order keeps its time priority only if the price is unchanged and the quantity
+    /// is not increased.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order is not in the book (it may have already been filled).
+    /// - If the order side or type differs from the resting order.
+    /// - If the trigger type is not supported, or `leaves_qty` is not positive.
+    pub fn update_order(&mut self, order: MatchingOrder) -> anyhow::Result<Vec<OrderMatch>> {
+        order.check_valid()?;
+        let Some(existing) = self.order(order.client_order_id) else {
+            anyhow::bail!("Order {} not found", order.client_order_id);
+        };
+        if existing.order_side != order.order_side || existing.order_type != order.order_type {
+            anyhow::bail!(
+                "Cannot change the side or type of order {}",
+                order.client_order_id
+            );
+        }
+
+        let keeps_priority = existing.price == order.price
+            && existing.trigger_price == order.trigger_price
+            && order.leaves_qty <= existing.leaves_qty;
+        if !keeps_priority {
+            self.sequence += 1;
+        }
+        let sequence = self.sequence;
+
+        let orders = self.side_orders_mut(order.order_side);
+        let resting = orders
+            .iter_mut()
+            .find(|o| o.order.client_order_id == order.client_order_id)
+            .expect("Order exists");
+        resting.order = order;
+        resting.is_aggressive = true;
+        if !keeps_priority {
+            resting.sequence = sequence;
+            orders.sort_by(RestingOrder::priority_cmp);
+        }
+
+        Ok(self.iterate(MarketUpdate::Order))
+    }
+
+    /// Cancels the resting order with the given `client_order_id`, returning its final state.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the order is not in the book (it may have already been filled).
+    pub fn cancel_order(
+        &mut self,
+        client_order_id: ClientOrderId,
+    ) -> anyhow::Result<MatchingOrder> {
+        for orders in [&mut self.orders_bid, &mut self.orders_ask] {
+            if let Some(index) = orders
+                .iter()
+                .position(|o| o.order.client_order_id == client_order_id)
+            {
+                return Ok(orders.remove(index).order);
+            }
+        }
+        anyhow::bail!("Order {client_order_id} not found")
+    }
+
+    /// Processes the given `quote`, returning the resulting triggers and fills.
+    pub fn process_quote(&mut self, quote: &QuoteTick) -> Vec<OrderMatch> {
+        self.set_bid(quote.bid_price, quote.bid_size);
+        self.set_ask(quote.ask_price, quote.ask_size);
+        self.iterate(MarketUpdate::Quote)
+    }
+
+    /// Processes the given `trade`, returning the resulting triggers and fills.
+    pub fn process_trade(&mut self, trade: &TradeTick) -> Vec<OrderMatch> {
+        self.last = Some(trade.price);
+        self.iterate(MarketUpdate::Trade(trade.price, trade.size))
+    }
+
+    /// Processes the top of the given `book`, returning the resulting triggers and fills.
+    pub fn process_book(&mut self, book: &OrderBook) -> Vec<OrderMatch> {
+        if let (Some(price), Some(size)) = (book.best_bid_price(), book.best_bid_size()) {
+            self.set_bid(price, size);
+        }
+        if let (Some(price), Some(size)) = (book.best_ask_price(), book.best_ask_size()) {
+            self.set_ask(price, size);
+        }
+        self.iterate(MarketUpdate::Quote)
+    }
+
+    // -- MATCHING --------------------------------------------------------------------------------
+
+    fn side_orders_mut(&mut self, side: OrderSide) -> &mut Vec<RestingOrder> {
+        match side.as_specified() {
+            OrderSideSpecified::Buy => &mut self.orders_bid,
+            OrderSideSpecified::Sell => &mut self.orders_ask,
+        }
+    }
+
+    fn set_bid(&mut self, price: Price, size: Quantity) {
+        self.bid = Some(price);
+        self.bid_liquidity_raw = size.raw;
+    }
+
+    fn set_ask(&mut self, price: Price, size: Quantity) {
+        self.ask = Some(price);
+        self.ask_liquidity_raw = size.raw;
+    }
+
+    fn insert(&mut self, order: MatchingOrder) {
+        self.sequence += 1;
+        let resting = RestingOrder {
+            order,
+            sequence: self.sequence,
+            is_triggered: false,
+            is_aggressive: true,
+        };
+        let orders = self.side_orders_mut(order.order_side);
+        let index = orders.partition_point(|o| o.priority_cmp(&resting) == Ordering::Less);
+        orders.insert(index, resting);
+    }
+
+    fn iterate(&mut self, update: MarketUpdate) -> Vec<OrderMatch> {
+        let mut matches = Vec::new();
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            self.trigger_stops(side, update, &mut matches);
+            self.fill_passive(side, update, &mut matches);
+            self.fill_aggressive(side, &mut matches);
+        }
+        matches
+    }
+
+    fn trigger_stops(
+        &mut self,
+        side: OrderSide,
+        update: MarketUpdate,
+        matches: &mut Vec<OrderMatch>,
+    ) {
+        let (bid, ask, last) = (self.bid, self.ask, self.last);
+
+        for resting in self
+            .side_orders_mut(side)
+            .iter_mut()
+            .filter(|o| o.is_pending_trigger())
+        {
+            let order = &resting.order;
+            let bid_ask = match side.as_specified() {
+                OrderSideSpecified::Buy => ask,
+                OrderSideSpecified::Sell => bid,
+            };
+            let mid = bid.zip(ask).map(|(bid, ask)| {
+                Price::from_raw((bid.raw + ask.raw) / 2, bid.precision.max(ask.precision))
+            });
+
+            // Trades only trigger from the last price (and quotes only from quoted prices),
+            // with orders entering the book checked against both.
+            let (last, bid_ask, mid) = match update {
+                MarketUpdate::Order => (last, bid_ask, mid),
+                MarketUpdate::Trade(..) => (last, None, None),
+                MarketUpdate::Quote => (None, bid_ask, mid),
+            };
+            let market_px = match order.trigger_type {
+                TriggerType::LastTrade => last,
+                TriggerType::Default | TriggerType::BidAsk => bid_ask,
+                TriggerType::LastOrBidAsk => last.or(bid_ask),
+                TriggerType::MidPoint => mid,
+                _ => None,
+            };
+
+            let Some(market_px) = market_px.filter(|px| match side.as_specified() {
+                OrderSideSpecified::Buy => *px >= order.trigger_price,
+                OrderSideSpecified::Sell => *px <= order.trigger_price,
+            }) else {
+                continue;
+            };
+
+            resting.is_triggered = true;
+            resting.is_aggressive = true;
+            matches.push(OrderMatch {
+                client_order_id: order.client_order_id,
+                kind: OrderMatchKind::Triggered,
+                last_px: market_px,
+                last_qty: Quantity::zero(order.leaves_qty.precision),
+                leaves_qty: order.leaves_qty,
+                liquidity_side: LiquiditySide::NoLiquiditySide,
+            });
+        }
+    }
+
+    fn fill_passive(
+        &mut self,
+        side: OrderSide,
+        update: MarketUpdate,
+        matches: &mut Vec<OrderMatch>,
+    ) {
+        let fill_on_touch = self.fill_on_touch;
+        let (opposite_px, mut liquidity_raw) = match (update, side.as_specified()) {
+            (MarketUpdate::Trade(price, size), _) => (Some(price), size.raw),
+            (MarketUpdate::Quote, OrderSideSpecified::Buy) => (self.ask, self.ask_liquidity_raw),
+            (MarketUpdate::Quote, OrderSideSpecified::Sell) => (self.bid, self.bid_liquidity_raw),
+            (MarketUpdate::Order, _) => return,
+        };
+        let Some(opposite_px) = opposite_px else {
+            return;
+        };
+        let is_trade = matches!(update, MarketUpdate::Trade(..));
+
+        for resting in self.side_orders_mut(side).iter_mut() {
+            if liquidity_raw == 0 {
+                break;
+            }
+            if resting.is_aggressive || resting.is_market() || resting.is_pending_trigger() {
+                continue;
+            }
+
+            let limit_px = resting.order.price;
+            let is_matched = match side.as_specified() {
+                OrderSideSpecified::Buy if is_trade && !fill_on_touch => opposite_px < limit_px,
+                OrderSideSpecified::Sell if is_trade && !fill_on_touch => opposite_px > limit_px,
+                OrderSideSpecified::Buy => opposite_px <= limit_px,
+                OrderSideSpecified::Sell => opposite_px >= limit_px,
+            };
+            if !is_matched {
+                // Orders are in priority order, so no further orders can match
+                break;
+            }
+
+            let fill = fill_order(resting, limit_px, &mut liquidity_raw, LiquiditySide::Maker);
+            matches.push(fill);
+        }
+
+        if !is_trade {
+            match side.as_specified() {
+                OrderSideSpecified::Buy => self.ask_liquidity_raw = liquidity_raw,
+                OrderSideSpecified::Sell => self.bid_liquidity_raw = liquidity_raw,
+            }
+        }
+        self.remove_filled(side);
+    }
+
+    fn fill_aggressive(&mut self, side: OrderSide, matches: &mut Vec<OrderMatch>) {
+        let (opposite_px, mut liquidity_raw) = match side.as_specified() {
+            OrderSideSpecified::Buy => (self.ask, self.ask_liquidity_raw),
+            OrderSideSpecified::Sell => (self.bid, self.bid_liquidity_raw),
+        };
+
+        for resting in self.side_orders_mut(side).iter_mut() {
+            if !(resting.is_aggressive || resting.is_market()) || resting.is_pending_trigger() {
+                continue;
+            }
+
+            let is_marketable = resting.is_market()
+                || opposite_px.is_some_and(|px| match side.as_specified() {
+                    OrderSideSpecified::Buy => px <= resting.order.price,
+                    OrderSideSpecified::Sell => px >= resting.order.price,
+                });
+            // A limit order only takes liquidity on entry, then rests passively
+            resting.is_aggressive = false;
+
+            let Some(opposite_px) = opposite_px.filter(|_| is_marketable && liquidity_raw > 0)
+            else {
+                continue;
+            };
+
+            let fill = fill_order(
+                resting,
+                opposite_px,
+                &mut liquidity_raw,
+                LiquiditySide::Taker,
+            );
+            matches.push(fill);
+        }
+
+        match side.as_specified() {
+            OrderSideSpecified::Buy => self.ask_liquidity_raw = liquidity_raw,
+            OrderSideSpecified::Sell => self.bid_liquidity_raw = liquidity_raw,
+        }
+        self.remove_filled(side);
+    }
+
+    fn remove_filled(&mut self, side: OrderSide) {
+        self.side_orders_mut(side)
+            .retain(|o| o.order.leaves_qty.is_positive());
+    }
+}
+
+fn fill_order(
+    resting: &mut RestingOrder,
+    last_px: Price,
+    liquidity_raw: &mut u64,
+    liquidity_side: LiquiditySide,
+) -> OrderMatch {
+    let leaves_qty = resting.order.leaves_qty;
+    let fill_raw = leaves_qty.raw.min(*liquidity_raw);
+    *liquidity_raw -= fill_raw;
+
+    let leaves_qty = Quantity::from_raw(leaves_qty.raw - fill_raw, leaves_qty.precision);
+    resting.order.leaves_qty = leaves_qty;
+
+    OrderMatch {
+        client_order_id: resting.order.client_order_id,
+        kind: OrderMatchKind::Filled,
+        last_px,
+        last_qty: Quantity::from_raw(fill_raw, leaves_qty.precision),
+        leaves_qty,
+        liquidity_side,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{enums::AggressorSide, identifiers::TradeId};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn book() -> OrderMatchingBook {
+        OrderMatchingBook::new(InstrumentId::from("AAPL.XNAS"), false)
+    }
+
+    fn quote(bid: &str, ask: &str, size: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(size),
+            Quantity::from(size),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn trade(price: &str, size: &str) -> TradeTick {
+        TradeTick::new(
+            InstrumentId::from("AAPL.XNAS"),
+            Price::from(price),
+            Quantity::from(size),
+            AggressorSide::NoAggressor,
+            TradeId::from("1"),
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn limit(id: &str, side: OrderSide, price: &str, qty: &str) -> MatchingOrder {
+        MatchingOrder::limit(
+            ClientOrderId::from(id),
+            side,
+            Price::from(price),
+            Quantity::from(qty),
+        )
+    }
+
+    fn fill(id: &str, px: &str, qty: &str, leaves: &str, side: LiquiditySide) -> OrderMatch {
+        OrderMatch {
+            client_order_id: ClientOrderId::from(id),
+            kind: OrderMatchKind::Filled,
+            last_px: Price::from(px),
+            last_qty: Quantity::from(qty),
+            leaves_qty: Quantity::from(leaves),
+            liquidity_side: side,
+        }
+    }
+
+    fn ids(orders: &[MatchingOrder]) -> Vec<&str> {
+        orders.iter().map(|o| o.client_order_id.as_str()).collect()
+    }
+
+    #[rstest]
+    fn test_orders_held_in_price_time_priority() {
+        let mut book = book();
+        book.add_order(limit("B1", OrderSide::Buy, "99.00", "1"))
+            .unwrap();
+        book.add_order(limit("B2", OrderSide::Buy, "100.00", "1"))
+            .unwrap();
+        book.add_order(limit("B3", OrderSide::Buy, "99.00", "1"))
+            .unwrap();
+        book.add_order(limit("A1", OrderSide::Sell, "102.00", "1"))
+            .unwrap();
+        book.add_order(limit("A2", OrderSide::Sell, "101.00", "1"))
+            .unwrap();
+
+        assert_eq!(ids(&book.orders_bid()), vec!["B2", "B1", "B3"]);
+        assert_eq!(ids(&book.orders_ask()), vec!["A2", "A1"]);
+    }
+
+    #[rstest]
+    fn test_add_order_when_duplicate_or_invalid() {
+        let mut book = book();
+        book.add_order(limit("O1", OrderSide::Buy, "99.00", "1"))
+            .unwrap();
+
+        assert!(book
+            .add_order(limit("O1", OrderSide::Buy, "98.00", "1"))
+            .is_err());
+        assert!(book
+            .add_order(limit("O2", OrderSide::Buy, "98.00", "0"))
+            .is_err());
+        let stop = MatchingOrder::stop_market(
+            ClientOrderId::from("O3"),
+            OrderSide::Buy,
+            Price::from("101.00"),
+            TriggerType::MarkPrice,
+            Quantity::from(1),
+        );
+        assert!(book.add_order(stop).is_err());
+    }
+
+    #[rstest]
+    fn test_marketable_limit_takes_at_opposing_best_then_rests() {
+        let mut book = book();
+        book.process_quote(&quote("99.00", "100.00", "30"));
+
+        let matches = book
+            .add_order(limit("O1", OrderSide::Buy, "101.00", "100"))
+            .unwrap();
+
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.00", "30", "70", LiquiditySide::Taker)]
+        );
+        assert_eq!(
+            book.order(ClientOrderId::from("O1")).unwrap().leaves_qty,
+            Quantity::from(70)
+        );
+
+        // Displayed liquidity was consumed, the remainder rests until the market moves
+        let matches = book
+            .add_order(limit("O2", OrderSide::Buy, "101.00", "10"))
+            .unwrap();
+        assert!(matches.is_empty());
+    }
+
+    #[rstest]
+    fn test_resting_limit_partial_fills_as_maker() {
+        let mut book = book();
+        book.process_quote(&quote("99.00", "101.00", "100"));
+        book.add_order(limit("O1", OrderSide::Buy, "100.00", "100"))
+            .unwrap();
+
+        let matches = book.process_quote(&quote("99.00", "100.00", "40"));
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.00", "40", "60", LiquiditySide::Maker)]
+        );
+
+        let matches = book.process_trade(&trade("100.00", "100"));
+        assert!(matches.is_empty()); // Trade at (not through) the limit price
+
+        let matches = book.process_trade(&trade("99.99", "100"));
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.00", "60", "0", LiquiditySide::Maker)]
+        );
+        assert!(!book.order_exists(ClientOrderId::from("O1")));
+    }
+
+    #[rstest]
+    fn test_trade_fills_in_price_time_priority() {
+        let mut book = book();
+        book.add_order(limit("O1", OrderSide::Sell, "101.00", "50"))
+            .unwrap();
+        book.add_order(limit("O2", OrderSide::Sell, "100.50", "50"))
+            .unwrap();
+        book.add_order(limit("O3", OrderSide::Sell, "100.50", "50"))
+            .unwrap();
+
+        let matches = book.process_trade(&trade("101.50", "80"));
+
+        assert_eq!(
+            matches,
+            vec![
+                fill("O2", "100.50", "50", "0", LiquiditySide::Maker),
+                fill("O3", "100.50", "30", "20", LiquiditySide::Maker),
+            ]
+        );
+        assert_eq!(ids(&book.orders_ask()), vec!["O3", "O1"]);
+    }
+
+    #[rstest]
+    fn test_fill_on_touch() {
+        let mut book = OrderMatchingBook::new(InstrumentId::from("AAPL.XNAS"), true);
+        book.add_order(limit("O1", OrderSide::Buy, "100.00", "10"))
+            .unwrap();
+
+        let matches = book.process_trade(&trade("100.00", "5"));
+
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.00", "5", "5", LiquiditySide::Maker)]
+        );
+    }
+
+    #[rstest]
+    #[case(TriggerType::LastTrade, false, true)]
+    #[case(TriggerType::BidAsk, true, false)]
+    #[case(TriggerType::Default, true, false)]
+    #[case(TriggerType::LastOrBidAsk, true, true)]
+    fn test_stop_triggering_by_trigger_type(
+        #[case] trigger_type: TriggerType,
+        #[case] triggers_on_quote: bool,
+        #[case] triggers_on_trade: bool,
+    ) {
+        let stop = MatchingOrder::stop_market(
+            ClientOrderId::from("O1"),
+            OrderSide::Buy,
+            Price::from("101.00"),
+            trigger_type,
+            Quantity::from(10),
+        );
+
+        let mut quote_book = book();
+        quote_book.process_quote(&quote("99.00", "100.00", "100"));
+        assert!(quote_book.add_order(stop).unwrap().is_empty());
+
+        let matches = quote_book.process_quote(&quote("100.50", "101.00", "100"));
+        assert_eq!(!matches.is_empty(), triggers_on_quote);
+
+        let mut trade_book = book();
+        trade_book.process_quote(&quote("99.00", "100.00", "100"));
+        trade_book.add_order(stop).unwrap();
+
+        let matches = trade_book.process_trade(&trade("101.00", "1"));
+        assert_eq!(!matches.is_empty(), triggers_on_trade);
+    }
+
+    #[rstest]
+    fn test_stop_market_triggered_by_last_trade_fills_at_ask() {
+        let mut book = book();
+        book.process_quote(&quote("100.00", "100.50", "4"));
+        let stop = MatchingOrder::stop_market(
+            ClientOrderId::from("O1"),
+            OrderSide::Buy,
+            Price::from("100.50"),
+            TriggerType::LastTrade,
+            Quantity::from(10),
+        );
+        book.add_order(stop).unwrap();
+
+        let matches = book.process_trade(&trade("100.50", "1"));
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].kind, OrderMatchKind::Triggered);
+        assert_eq!(matches[0].last_px, Price::from("100.50"));
+        assert_eq!(
+            matches[1],
+            fill("O1", "100.50", "4", "6", LiquiditySide::Taker)
+        );
+
+        // The remainder takes liquidity from the next quote
+        let matches = book.process_quote(&quote("100.50", "100.75", "100"));
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.75", "6", "0", LiquiditySide::Taker)]
+        );
+    }
+
+    #[rstest]
+    fn test_stop_limit_triggered_by_bid_ask_rests_when_not_marketable() {
+        let mut book = book();
+        book.process_quote(&quote("99.00", "99.50", "100"));
+        let stop = MatchingOrder::stop_limit(
+            ClientOrderId::from("O1"),
+            OrderSide::Sell,
+            Price::from("99.25"),
+            Price::from("99.00"),
+            TriggerType::BidAsk,
+            Quantity::from(10),
+        );
+
+        let matches = book.add_order(stop).unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].kind, OrderMatchKind::Triggered);
+        assert!(book.is_triggered(ClientOrderId::from("O1")));
+
+        let stop = MatchingOrder::stop_limit(
+            ClientOrderId::from("O2"),
+            OrderSide::Sell,
+            Price::from("97.00"),
+            Price::from("98.00"),
+            TriggerType::BidAsk,
+            Quantity::from(10),
+        );
+        book.add_order(stop).unwrap();
+        let matches = book.process_quote(&quote("97.50", "97.75", "100"));
+
+        // O1 rests as a limit above the market, O2 triggers and takes the bid
+        assert_eq!(
+            matches,
+            vec![
+                OrderMatch {
+                    client_order_id: ClientOrderId::from("O2"),
+                    kind: OrderMatchKind::Triggered,
+                    last_px: Price::from("97.50"),
+                    last_qty: Quantity::from(0),
+                    leaves_qty: Quantity::from(10),
+                    liquidity_side: LiquiditySide::NoLiquiditySide,
+                },
+                fill("O2", "97.50", "10", "0", LiquiditySide::Taker),
+            ]
+        );
+        assert!(book.order_exists(ClientOrderId::from("O1")));
+    }
+
+    #[rstest]
+    fn test_cancel_racing_fill() {
+        let mut book = book();
+        book.add_order(limit("O1", OrderSide::Buy, "100.00", "100"))
+            .unwrap();
+        book.add_order(limit("O2", OrderSide::Buy, "100.00", "100"))
+            .unwrap();
+
+        // The fill arrives before the cancel for O1, which then fails
+        book.process_trade(&trade("99.00", "150"));
+        assert!(book.cancel_order(ClientOrderId::from("O1")).is_err());
+
+        // The partially filled O2 cancels with its remaining quantity
+        let canceled = book.cancel_order(ClientOrderId::from("O2")).unwrap();
+        assert_eq!(canceled.leaves_qty, Quantity::from(50));
+        assert!(book.process_trade(&trade("99.00", "150")).is_empty());
+    }
+
+    #[rstest]
+    fn test_update_order_priority() {
+        let mut book = book();
+        book.add_order(limit("O1", OrderSide::Buy, "100.00", "100"))
+            .unwrap();
+        book.add_order(limit("O2", OrderSide::Buy, "100.00", "100"))
+            .unwrap();
+
+        // Reducing quantity keeps time priority
+        book.update_order(limit("O1", OrderSide::Buy, "100.00", "50"))
+            .unwrap();
+        assert_eq!(ids(&book.orders_bid()), vec!["O1", "O2"]);
+
+        // Increasing quantity loses time priority
+        book.update_order(limit("O1", OrderSide::Buy, "100.00", "200"))
+            .unwrap();
+        assert_eq!(ids(&book.orders_bid()), vec!["O2", "O1"]);
+
+        assert!(book
+            .update_order(limit("O1", OrderSide::Sell, "100.00", "200"))
+            .is_err());
+        assert!(book
+            .update_order(limit("O3", OrderSide::Buy, "100.00", "200"))
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_update_order_to_marketable_price_takes() {
+        let mut book = book();
+        book.process_quote(&quote("99.00", "100.00", "100"));
+        book.add_order(limit("O1", OrderSide::Buy, "99.00", "10"))
+            .unwrap();
+
+        let matches = book
+            .update_order(limit("O1", OrderSide::Buy, "100.00", "10"))
+            .unwrap();
+
+        assert_eq!(
+            matches,
+            vec![fill("O1", "100.00", "10", "0", LiquiditySide::Taker)]
+        );
+    }
+}
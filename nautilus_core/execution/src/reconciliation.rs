@@ -0,0 +1,869 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Reconciliation of venue execution reports against the cached execution state.
+//!
+//! On reconnect the venue reports of orders, fills and positions are compared with the cached
+//! orders and positions, producing a list of [`Discrepancy`]s each with a suggested repair.
+
+use std::collections::{HashMap, HashSet};
+
+use indexmap::IndexMap;
+use nautilus_model::{
+    enums::{OrderStatus, PositionSide},
+    identifiers::{ClientOrderId, InstrumentId, TradeId, VenueOrderId},
+    orders::any::OrderAny,
+    position::Position,
+    types::quantity::Quantity,
+};
+use strum::Display;
+
+use crate::reports::{FillReport, OrderStatusReport, PositionStatusReport};
+
+/// The kind of discrepancy between the venue reports and the cached state.
+#[derive(Clone, Copy, Debug, Display, Hash, PartialEq, Eq)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiscrepancyKind {
+    /// A fill at the venue is missing from the cached order.
+    MissedFill,
+    /// An order at the venue is not in the cache.
+    ExternalOrder,
+    /// The cached order is open, but was canceled, expired or rejected at the venue.
+    OrderClosed,
+    /// The cached order has a greater filled quantity than reported by the venue.
+    Overfill,
+    /// The venue position side or quantity differs from the cached positions.
+    PositionMismatch,
+    /// The cached positions are open, but the position is flat at the venue.
+    PhantomPosition,
+}
+
+/// A repair event suggested to resolve a [`Discrepancy`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RepairAction {
+    /// Create the order from the venue order status report.
+    CreateExternalOrder(OrderStatusReport),
+    /// Apply the venue fill to the order.
+    ApplyFill(FillReport),
+    /// Generate a fill inferred from the order status report, for quantity filled at the venue
+    /// with no fill report. The price is implied from the reported average price (if any).
+    InferFill {
+        last_qty: Quantity,
+        last_px: Option<f64>,
+    },
+    /// Mark the cached order canceled.
+    MarkCanceled,
+    /// Adjust the cached position to the venue side and quantity.
+    AdjustPositionQuantity {
+        position_side: PositionSide,
+        quantity: Quantity,
+    },
+}
+
+/// A discrepancy between the venue reports and the cached state, keyed by the order IDs (which
+/// are `None` for position discrepancies).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Discrepancy {
+    pub instrument_id: InstrumentId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub kind: DiscrepancyKind,
+    /// The suggested repair, or `None` if the discrepancy must be resolved manually.
+    pub repair: Option<RepairAction>,
+}
+
+impl Discrepancy {
+    #[must_use]
+    pub const fn new(
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: Option<VenueOrderId>,
+        kind: DiscrepancyKind,
+        repair: Option<RepairAction>,
+    ) -> Self {
+        Self {
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            kind,
+            repair,
+        }
+    }
+}
+
+/// Reconciles the venue reports against the cached `orders` and `positions`, returning the
+/// discrepancies found with suggested repairs.
+///
+/// Orders are matched to status reports by client order ID, falling back to venue order ID.
+/// Fill reports with a trade ID already seen (in another report or on the cached order) are
+/// ignored, as are fill reports for orders which are neither cached nor reported. Cached orders
+/// with no status report are only checked for missed fills.
+///
+/// Position reports are compared with the net quantity of the cached open positions for each
+/// instrument, where an instrument with no position report is flat at the venue.
+///
+/// Quantities are compared at the cached precision (the order or position size precision), so
+/// venue quantities reported with a different precision do not produce discrepancies.
+#[must_use]
+pub fn reconcile(
+    order_reports: &[OrderStatusReport],
+    fill_reports: &[FillReport],
+    position_reports: &[PositionStatusReport],
+    orders: &[OrderAny],
+    positions: &[Position],
+) -> Vec<Discrepancy> {
+    let mut discrepancies = reconcile_orders(order_reports, fill_reports, orders);
+    discrepancies.extend(reconcile_positions(position_reports, positions));
+    discrepancies
+}
+
+fn reconcile_orders(
+    order_reports: &[OrderStatusReport],
+    fill_reports: &[FillReport],
+    orders: &[OrderAny],
+) -> Vec<Discrepancy> {
+    let orders_by_client_id: HashMap<ClientOrderId, &OrderAny> = orders
+        .iter()
+        .map(|order| (order.client_order_id(), order))
+        .collect();
+    let orders_by_venue_id: HashMap<VenueOrderId, &OrderAny> = orders
+        .iter()
+        .filter_map(|order| order.venue_order_id().map(|id| (id, order)))
+        .collect();
+    let find_order = |client_order_id: Option<ClientOrderId>, venue_order_id: VenueOrderId| {
+        client_order_id
+            .and_then(|id| orders_by_client_id.get(&id))
+            .or_else(|| orders_by_venue_id.get(&venue_order_id))
+            .copied()
+    };
+
+    // Group fills by venue order ID, ignoring duplicate trade IDs
+    let mut seen_trade_ids: HashSet<(InstrumentId, TradeId)> = HashSet::new();
+    let mut fills_by_venue_id: IndexMap<VenueOrderId, Vec<&FillReport>> = IndexMap::new();
+    for fill in fill_reports {
+        if seen_trade_ids.insert((fill.instrument_id, fill.trade_id)) {
+            fills_by_venue_id
+                .entry(fill.venue_order_id)
+                .or_default()
+                .push(fill);
+        }
+    }
+
+    let mut discrepancies = Vec::new();
+    for report in order_reports {
+        let fills = fills_by_venue_id
+            .shift_remove(&report.venue_order_id)
+            .unwrap_or_default();
+        match find_order(report.client_order_id, report.venue_order_id) {
+            Some(order) => reconcile_order(order, report, &fills, &mut discrepancies),
+            None => reconcile_external_order(report, &fills, &mut discrepancies),
+        }
+    }
+
+    // Fills for cached orders with no status report
+    for (venue_order_id, fills) in fills_by_venue_id {
+        if let Some(order) = find_order(fills[0].client_order_id, venue_order_id) {
+            let trade_ids: HashSet<TradeId> = order.trade_ids().into_iter().copied().collect();
+            for fill in fills {
+                if !trade_ids.contains(&fill.trade_id) {
+                    discrepancies.push(missed_fill(Some(order.client_order_id()), fill));
+                }
+            }
+        }
+    }
+
+    discrepancies
+}
+
+fn reconcile_order(
+    order: &OrderAny,
+    report: &OrderStatusReport,
+    fills: &[&FillReport],
+    discrepancies: &mut Vec<Discrepancy>,
+) {
+    let client_order_id = Some(order.client_order_id());
+    let trade_ids: HashSet<TradeId> = order.trade_ids().into_iter().copied().collect();
+    let new_fills: Vec<&FillReport> = fills
+        .iter()
+        .filter(|fill| !trade_ids.contains(&fill.trade_id))
+        .copied()
+        .collect();
+
+    reconcile_filled_qty(
+        client_order_id,
+        report,
+        order.filled_qty(),
+        order.avg_px(),
+        &new_fills,
+        order.quantity().precision,
+        discrepancies,
+    );
+
+    if order.is_open()
+        && matches!(
+            report.order_status,
+            OrderStatus::Canceled | OrderStatus::Expired | OrderStatus::Rejected
+        )
+    {
+        discrepancies.push(Discrepancy::new(
+            report.instrument_id,
+            client_order_id,
+            Some(report.venue_order_id),
+            DiscrepancyKind::OrderClosed,
+            Some(RepairAction::MarkCanceled),
+        ));
+    }
+}
+
+fn reconcile_external_order(
+    report: &OrderStatusReport,
+    fills: &[&FillReport],
+    discrepancies: &mut Vec<Discrepancy>,
+) {
+    let precision = report.quantity.precision;
+    discrepancies.push(Discrepancy::new(
+        report.instrument_id,
+        report.client_order_id,
+        Some(report.venue_order_id),
+        DiscrepancyKind::ExternalOrder,
+        Some(RepairAction::CreateExternalOrder(report.clone())),
+    ));
+    reconcile_filled_qty(
+        report.client_order_id,
+        report,
+        Quantity::zero(precision),
+        None,
+        fills,
+        precision,
+        discrepancies,
+    );
+}
+
+/// Compares the reported filled quantity with the cached filled quantity plus the `new_fills`,
+/// inferring a fill for any quantity filled at the venue with no fill report.
+fn reconcile_filled_qty(
+    client_order_id: Option<ClientOrderId>,
+    report: &OrderStatusReport,
+    cached_filled_qty: Quantity,
+    cached_avg_px: Option<f64>,
+    new_fills: &[&FillReport],
+    precision: u8,
+    discrepancies: &mut Vec<Discrepancy>,
+) {
+    let mut filled_qty = round_qty(cached_filled_qty, precision);
+    let mut filled_notional = cached_avg_px.unwrap_or(0.0) * cached_filled_qty.as_f64();
+    for fill in new_fills {
+        filled_qty += round_qty(fill.last_qty, precision);
+        filled_notional += fill.last_px.as_f64() * fill.last_qty.as_f64();
+        discrepancies.push(missed_fill(client_order_id, fill));
+    }
+
+    let report_filled_qty = round_qty(report.filled_qty, precision);
+    if report_filled_qty > filled_qty {
+        let last_qty = report_filled_qty - filled_qty;
+        let last_px = report.avg_px.map(|avg_px| {
+            (avg_px * report.filled_qty.as_f64() - filled_notional) / last_qty.as_f64()
+        });
+        discrepancies.push(Discrepancy::new(
+            report.instrument_id,
+            client_order_id,
+            Some(report.venue_order_id),
+            DiscrepancyKind::MissedFill,
+            Some(RepairAction::InferFill { last_qty, last_px }),
+        ));
+    } else if report_filled_qty < filled_qty {
+        discrepancies.push(Discrepancy::new(
+            report.instrument_id,
+            client_order_id,
+            Some(report.venue_order_id),
+            DiscrepancyKind::Overfill,
+            None,
+        ));
+    }
+}
+
+fn missed_fill(client_order_id: Option<ClientOrderId>, fill: &FillReport) -> Discrepancy {
+    Discrepancy::new(
+        fill.instrument_id,
+        client_order_id,
+        Some(fill.venue_order_id),
+        DiscrepancyKind::MissedFill,
+        Some(RepairAction::ApplyFill(fill.clone())),
+    )
+}
+
+fn reconcile_positions(
+    position_reports: &[PositionStatusReport],
+    positions: &[Position],
+) -> Vec<Discrepancy> {
+    // Net signed quantities (and size precision) per instrument
+    let mut cached: IndexMap<InstrumentId, (f64, u8)> = IndexMap::new();
+    for position in positions.iter().filter(|position| position.is_open()) {
+        let entry = cached
+            .entry(position.instrument_id)
+            .or_insert((0.0, position.size_precision));
+        entry.0 += position.signed_qty;
+    }
+    let mut venue: IndexMap<InstrumentId, (f64, u8)> = IndexMap::new();
+    for report in position_reports {
+        let entry = venue
+            .entry(report.instrument_id)
+            .or_insert((0.0, report.quantity.precision));
+        entry.0 += report.signed_qty();
+    }
+
+    let mut discrepancies = Vec::new();
+    for (instrument_id, &(venue_signed_qty, venue_precision)) in &venue {
+        let (cached_signed_qty, precision) = cached
+            .get(instrument_id)
+            .copied()
+            .unwrap_or((0.0, venue_precision));
+        let (venue_side, venue_qty) = side_and_qty(venue_signed_qty, precision);
+        let (cached_side, cached_qty) = side_and_qty(cached_signed_qty, precision);
+        if venue_side == cached_side && venue_qty == cached_qty {
+            continue;
+        }
+        let kind = if venue_side == PositionSide::Flat {
+            DiscrepancyKind::PhantomPosition
+        } else {
+            DiscrepancyKind::PositionMismatch
+        };
+        discrepancies.push(adjust_position(*instrument_id, kind, venue_side, venue_qty));
+    }
+
+    for (instrument_id, &(cached_signed_qty, precision)) in &cached {
+        if venue.contains_key(instrument_id) {
+            continue;
+        }
+        let (cached_side, _) = side_and_qty(cached_signed_qty, precision);
+        if cached_side != PositionSide::Flat {
+            discrepancies.push(adjust_position(
+                *instrument_id,
+                DiscrepancyKind::PhantomPosition,
+                PositionSide::Flat,
+                Quantity::zero(precision),
+            ));
+        }
+    }
+
+    discrepancies
+}
+
+fn adjust_position(
+    instrument_id: InstrumentId,
+    kind: DiscrepancyKind,
+    position_side: PositionSide,
+    quantity: Quantity,
+) -> Discrepancy {
+    Discrepancy::new(
+        instrument_id,
+        None,
+        None,
+        kind,
+        Some(RepairAction::AdjustPositionQuantity {
+            position_side,
+            quantity,
+        }),
+    )
+}
+
+/// Returns the quantity rounded to `precision`.
+fn round_qty(qty: Quantity, precision: u8) -> Quantity {
+    Quantity::new(qty.as_f64(), precision)
+}
+
+/// Returns the position side and quantity of the signed quantity rounded to `precision`.
+fn side_and_qty(signed_qty: f64, precision: u8) -> (PositionSide, Quantity) {
+    let quantity = Quantity::new(signed_qty.abs(), precision);
+    let side = if quantity.is_zero() {
+        PositionSide::Flat
+    } else if signed_qty > 0.0 {
+        PositionSide::Long
+    } else {
+        PositionSide::Short
+    };
+    (side, quantity)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+    use nautilus_model::{
+        enums::{LiquiditySide, OrderSide, OrderType},
+        identifiers::AccountId,
+        instruments::{any::InstrumentAny, currency_pair::CurrencyPair, stubs::audusd_sim},
+        orders::{
+            builder::OrderTestBuilder,
+            stubs::{TestOrderEventStubs, TestOrderStubs},
+        },
+        types::{money::Money, price::Price},
+    };
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn instrument(audusd_sim: CurrencyPair) -> InstrumentAny {
+        InstrumentAny::CurrencyPair(audusd_sim)
+    }
+
+    #[fixture]
+    fn accepted_order(instrument: InstrumentAny) -> OrderAny {
+        let order = OrderTestBuilder::new(OrderType::Limit)
+            .instrument_id(instrument.id())
+            .client_order_id(ClientOrderId::from("O-1"))
+            .side(OrderSide::Buy)
+            .price(Price::from("1.00000"))
+            .quantity(Quantity::from(100_000))
+            .build();
+        TestOrderStubs::make_accepted_order(&order)
+    }
+
+    fn order_report(
+        order: Option<&OrderAny>,
+        venue_order_id: &str,
+        order_status: OrderStatus,
+        filled_qty: Quantity,
+        avg_px: Option<f64>,
+    ) -> OrderStatusReport {
+        OrderStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            order.map(OrderAny::client_order_id),
+            VenueOrderId::from(venue_order_id),
+            OrderSide::Buy,
+            OrderType::Limit,
+            order_status,
+            Quantity::from(100_000),
+            filled_qty,
+            avg_px,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn fill_report(venue_order_id: &str, trade_id: &str, last_qty: Quantity) -> FillReport {
+        FillReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            None,
+            VenueOrderId::from(venue_order_id),
+            None,
+            TradeId::from(trade_id),
+            OrderSide::Buy,
+            last_qty,
+            Price::from("1.00002"),
+            Money::from("2 USD"),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn position_report(position_side: PositionSide, quantity: Quantity) -> PositionStatusReport {
+        PositionStatusReport::new(
+            AccountId::from("SIM-001"),
+            InstrumentId::from("AUD/USD.SIM"),
+            position_side,
+            quantity,
+            None,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    fn long_position(instrument: &InstrumentAny, order: &OrderAny, quantity: Quantity) -> Position {
+        let fill = TestOrderEventStubs::order_filled(
+            order,
+            instrument,
+            None,
+            None,
+            None,
+            Some(quantity),
+            None,
+            None,
+            None,
+            None,
+        );
+        Position::new(instrument, fill.into())
+    }
+
+    #[rstest]
+    fn test_reconcile_in_sync(accepted_order: OrderAny) {
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::Accepted,
+            Quantity::from(0),
+            None,
+        );
+
+        let discrepancies = reconcile(&[report], &[], &[], &[accepted_order], &[]);
+
+        assert!(discrepancies.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_missed_fill_with_fill_report(accepted_order: OrderAny) {
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::PartiallyFilled,
+            Quantity::from(50_000),
+            Some(1.00002),
+        );
+        let fill = fill_report("V-001", "T-1", Quantity::from(50_000));
+
+        let discrepancies = reconcile(
+            &[report],
+            std::slice::from_ref(&fill),
+            &[],
+            std::slice::from_ref(&accepted_order),
+            &[],
+        );
+
+        assert_eq!(
+            discrepancies,
+            vec![Discrepancy::new(
+                accepted_order.instrument_id(),
+                Some(accepted_order.client_order_id()),
+                Some(VenueOrderId::from("V-001")),
+                DiscrepancyKind::MissedFill,
+                Some(RepairAction::ApplyFill(fill)),
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_missed_fill_inferred_from_order_report(accepted_order: OrderAny) {
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::Filled,
+            Quantity::from(100_000),
+            Some(1.00001),
+        );
+
+        let discrepancies = reconcile(&[report], &[], &[], &[accepted_order], &[]);
+
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].kind, DiscrepancyKind::MissedFill);
+        let Some(RepairAction::InferFill { last_qty, last_px }) = discrepancies[0].repair else {
+            panic!("expected inferred fill, was {:?}", discrepancies[0].repair);
+        };
+        assert_eq!(last_qty, Quantity::from(100_000));
+        assert!((last_px.unwrap() - 1.00001).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reconcile_infers_remainder_after_fill_reports(accepted_order: OrderAny) {
+        // 50,000 @ 1.00002 reported, so the other 50,000 must have filled at 1.00000
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::Filled,
+            Quantity::from(100_000),
+            Some(1.00001),
+        );
+        let fill = fill_report("V-001", "T-1", Quantity::from(50_000));
+
+        let discrepancies = reconcile(&[report], &[fill], &[], &[accepted_order], &[]);
+
+        assert_eq!(discrepancies.len(), 2);
+        let Some(RepairAction::InferFill { last_qty, last_px }) = discrepancies[1].repair else {
+            panic!("expected inferred fill, was {:?}", discrepancies[1].repair);
+        };
+        assert_eq!(last_qty, Quantity::from(50_000));
+        assert!((last_px.unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[rstest]
+    fn test_reconcile_ignores_duplicate_fills(instrument: InstrumentAny, accepted_order: OrderAny) {
+        // The order already has trade `E-1` applied
+        let mut order = accepted_order;
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            Some(TradeId::from("E-1")),
+            None,
+            Some(Price::from("1.00002")),
+            Some(Quantity::from(20_000)),
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(fill).unwrap();
+        let report = order_report(
+            Some(&order),
+            "V-001",
+            OrderStatus::PartiallyFilled,
+            Quantity::from(50_000),
+            Some(1.00002),
+        );
+        let fill_reports = [
+            fill_report("V-001", "E-1", Quantity::from(20_000)),
+            fill_report("V-001", "T-2", Quantity::from(30_000)),
+            fill_report("V-001", "T-2", Quantity::from(30_000)),
+        ];
+
+        let discrepancies = reconcile(&[report], &fill_reports, &[], &[order], &[]);
+
+        assert_eq!(
+            discrepancies
+                .iter()
+                .map(|discrepancy| &discrepancy.repair)
+                .collect::<Vec<_>>(),
+            vec![&Some(RepairAction::ApplyFill(fill_reports[1].clone()))]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_compares_quantities_at_cached_precision(accepted_order: OrderAny) {
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::Accepted,
+            Quantity::from("0.4"),
+            None,
+        );
+
+        let discrepancies = reconcile(&[report], &[], &[], &[accepted_order], &[]);
+
+        assert!(discrepancies.is_empty());
+    }
+
+    #[rstest]
+    fn test_reconcile_order_canceled_at_venue(accepted_order: OrderAny) {
+        let report = order_report(
+            Some(&accepted_order),
+            "V-001",
+            OrderStatus::Canceled,
+            Quantity::from(0),
+            None,
+        );
+
+        let discrepancies = reconcile(
+            &[report],
+            &[],
+            &[],
+            std::slice::from_ref(&accepted_order),
+            &[],
+        );
+
+        assert_eq!(
+            discrepancies,
+            vec![Discrepancy::new(
+                accepted_order.instrument_id(),
+                Some(accepted_order.client_order_id()),
+                Some(VenueOrderId::from("V-001")),
+                DiscrepancyKind::OrderClosed,
+                Some(RepairAction::MarkCanceled),
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_overfill_needs_manual_repair(
+        instrument: InstrumentAny,
+        accepted_order: OrderAny,
+    ) {
+        let mut order = accepted_order;
+        let fill = TestOrderEventStubs::order_filled(
+            &order,
+            &instrument,
+            None,
+            None,
+            None,
+            Some(Quantity::from(20_000)),
+            None,
+            None,
+            None,
+            None,
+        );
+        order.apply(fill).unwrap();
+        let report = order_report(
+            Some(&order),
+            "V-001",
+            OrderStatus::Accepted,
+            Quantity::from(0),
+            None,
+        );
+
+        let discrepancies = reconcile(&[report], &[], &[], &[order], &[]);
+
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].kind, DiscrepancyKind::Overfill);
+        assert_eq!(discrepancies[0].repair, None);
+    }
+
+    #[rstest]
+    fn test_reconcile_external_order(accepted_order: OrderAny) {
+        let report = order_report(
+            None,
+            "V-EXT",
+            OrderStatus::PartiallyFilled,
+            Quantity::from(10_000),
+            Some(1.00002),
+        );
+        let fill = fill_report("V-EXT", "T-1", Quantity::from(10_000));
+
+        let discrepancies = reconcile(
+            std::slice::from_ref(&report),
+            std::slice::from_ref(&fill),
+            &[],
+            &[accepted_order],
+            &[],
+        );
+
+        assert_eq!(
+            discrepancies,
+            vec![
+                Discrepancy::new(
+                    report.instrument_id,
+                    None,
+                    Some(VenueOrderId::from("V-EXT")),
+                    DiscrepancyKind::ExternalOrder,
+                    Some(RepairAction::CreateExternalOrder(report)),
+                ),
+                Discrepancy::new(
+                    fill.instrument_id,
+                    None,
+                    Some(VenueOrderId::from("V-EXT")),
+                    DiscrepancyKind::MissedFill,
+                    Some(RepairAction::ApplyFill(fill)),
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_missed_fill_without_order_report(accepted_order: OrderAny) {
+        let fill = fill_report("V-001", "T-1", Quantity::from(100_000));
+
+        let discrepancies = reconcile(
+            &[],
+            std::slice::from_ref(&fill),
+            &[],
+            &[accepted_order],
+            &[],
+        );
+
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].repair, Some(RepairAction::ApplyFill(fill)));
+    }
+
+    #[rstest]
+    fn test_reconcile_phantom_position(instrument: InstrumentAny, accepted_order: OrderAny) {
+        let position = long_position(&instrument, &accepted_order, Quantity::from(100_000));
+
+        let discrepancies = reconcile(&[], &[], &[], &[], &[position]);
+
+        assert_eq!(
+            discrepancies,
+            vec![Discrepancy::new(
+                instrument.id(),
+                None,
+                None,
+                DiscrepancyKind::PhantomPosition,
+                Some(RepairAction::AdjustPositionQuantity {
+                    position_side: PositionSide::Flat,
+                    quantity: Quantity::from(0),
+                }),
+            )]
+        );
+    }
+
+    #[rstest]
+    fn test_reconcile_phantom_position_with_flat_report(
+        instrument: InstrumentAny,
+        accepted_order: OrderAny,
+    ) {
+        let position = long_position(&instrument, &accepted_order, Quantity::from(100_000));
+        let report = position_report(PositionSide::Flat, Quantity::from(0));
+
+        let discrepancies = reconcile(&[], &[], &[report], &[], &[position]);
+
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].kind, DiscrepancyKind::PhantomPosition);
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, Quantity::from(100_000), None)]
+    #[case(PositionSide::Long, Quantity::from("100000.4"), None)]
+    #[case(
+        PositionSide::Long,
+        Quantity::from(150_000),
+        Some((PositionSide::Long, Quantity::from(150_000)))
+    )]
+    #[case(
+        PositionSide::Short,
+        Quantity::from(100_000),
+        Some((PositionSide::Short, Quantity::from(100_000)))
+    )]
+    fn test_reconcile_position_quantity(
+        instrument: InstrumentAny,
+        accepted_order: OrderAny,
+        #[case] venue_side: PositionSide,
+        #[case] venue_qty: Quantity,
+        #[case] expected: Option<(PositionSide, Quantity)>,
+    ) {
+        let position = long_position(&instrument, &accepted_order, Quantity::from(100_000));
+        let report = position_report(venue_side, venue_qty);
+
+        let discrepancies = reconcile(&[], &[], &[report], &[], &[position]);
+
+        let expected: Vec<Discrepancy> = expected
+            .into_iter()
+            .map(|(position_side, quantity)| {
+                Discrepancy::new(
+                    instrument.id(),
+                    None,
+                    None,
+                    DiscrepancyKind::PositionMismatch,
+                    Some(RepairAction::AdjustPositionQuantity {
+                        position_side,
+                        quantity,
+                    }),
+                )
+            })
+            .collect();
+        assert_eq!(discrepancies, expected);
+    }
+
+    #[rstest]
+    fn test_reconcile_external_position() {
+        let report = position_report(PositionSide::Short, Quantity::from(10_000));
+
+        let discrepancies = reconcile(&[], &[], &[report], &[], &[]);
+
+        assert_eq!(discrepancies.len(), 1);
+        assert_eq!(discrepancies[0].kind, DiscrepancyKind::PositionMismatch);
+        assert_eq!(
+            discrepancies[0].repair,
+            Some(RepairAction::AdjustPositionQuantity {
+                position_side: PositionSide::Short,
+                quantity: Quantity::from(10_000),
+            })
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus, OrderType},
+    identifiers::{AccountId, ClientOrderId, InstrumentId, VenueOrderId},
+    types::quantity::Quantity,
+};
+use serde::{Deserialize, Serialize};
+
+/// Represents the status of an order at the venue.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OrderStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    /// The client order ID, if the order was submitted by this trader (otherwise `None`).
+    pub client_order_id: Option<ClientOrderId>,
+    pub venue_order_id: VenueOrderId,
+    pub order_side: OrderSide,
+    pub order_type: OrderType,
+    pub order_status: OrderStatus,
+    pub quantity: Quantity,
+    pub filled_qty: Quantity,
+    /// The average fill price, if any quantity has been filled.
+    pub avg_px: Option<f64>,
+    pub report_id: UUID4,
+    pub ts_accepted: UnixNanos,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl OrderStatusReport {
+    /// Creates a new [`OrderStatusReport`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        client_order_id: Option<ClientOrderId>,
+        venue_order_id: VenueOrderId,
+        order_side: OrderSide,
+        order_type: OrderType,
+        order_status: OrderStatus,
+        quantity: Quantity,
+        filled_qty: Quantity,
+        avg_px: Option<f64>,
+        report_id: UUID4,
+        ts_accepted: UnixNanos,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            client_order_id,
+            venue_order_id,
+            order_side,
+            order_type,
+            order_status,
+            quantity,
+            filled_qty,
+            avg_px,
+            report_id,
+            ts_accepted,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Returns whether the order is open at the venue.
+    #[must_use]
+    pub const fn is_open(&self) -> bool {
+        matches!(
+            self.order_status,
+            OrderStatus::Accepted
+                | OrderStatus::Triggered
+                | OrderStatus::PendingUpdate
+                | OrderStatus::PendingCancel
+                | OrderStatus::PartiallyFilled
+        )
+    }
+}
+
+impl Display for OrderStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OrderStatusReport(instrument_id={}, client_order_id={}, venue_order_id={}, order_status={}, quantity={}, filled_qty={})",
+            self.instrument_id,
+            self.client_order_id
+                .map_or("None".to_string(), |client_order_id| format!("{client_order_id}")),
+            self.venue_order_id,
+            self.order_status,
+            self.quantity,
+            self.filled_qty,
+        )
+    }
+}
@@ -0,0 +1,84 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::Display;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    enums::PositionSide,
+    identifiers::{AccountId, InstrumentId, PositionId},
+    types::quantity::Quantity,
+};
+use serde::{Deserialize, Serialize};
+
+/// Represents the status of a position at the venue.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PositionStatusReport {
+    pub account_id: AccountId,
+    pub instrument_id: InstrumentId,
+    pub position_side: PositionSide,
+    pub quantity: Quantity,
+    pub venue_position_id: Option<PositionId>,
+    pub report_id: UUID4,
+    pub ts_last: UnixNanos,
+    pub ts_init: UnixNanos,
+}
+
+impl PositionStatusReport {
+    /// Creates a new [`PositionStatusReport`] instance.
+    #[allow(clippy::too_many_arguments)]
+    #[must_use]
+    pub const fn new(
+        account_id: AccountId,
+        instrument_id: InstrumentId,
+        position_side: PositionSide,
+        quantity: Quantity,
+        venue_position_id: Option<PositionId>,
+        report_id: UUID4,
+        ts_last: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> Self {
+        Self {
+            account_id,
+            instrument_id,
+            position_side,
+            quantity,
+            venue_position_id,
+            report_id,
+            ts_last,
+            ts_init,
+        }
+    }
+
+    /// Returns the signed quantity of the position (negative when short).
+    #[must_use]
+    pub fn signed_qty(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Short => -self.quantity.as_f64(),
+            PositionSide::Long => self.quantity.as_f64(),
+            _ => 0.0,
+        }
+    }
+}
+
+impl Display for PositionStatusReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PositionStatusReport(instrument_id={}, position_side={}, quantity={})",
+            self.instrument_id, self.position_side, self.quantity,
+        )
+    }
+}
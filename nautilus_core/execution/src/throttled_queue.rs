@@ -0,0 +1,406 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A rate limited queue for trading commands, enforcing both a burst and a sustained limit.
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug, rc::Rc};
+
+use nautilus_common::{clock::Clock, throttler::RateLimit};
+use nautilus_core::nanos::UnixNanos;
+
+use crate::messages::TradingCommand;
+
+/// A sliding window tracking the timestamps of released commands against a [`RateLimit`].
+#[derive(Clone, Debug)]
+struct RateWindow {
+    limit: usize,
+    interval_ns: u64,
+    timestamps: VecDeque<UnixNanos>,
+}
+
+impl RateWindow {
+    fn new(rate_limit: &RateLimit) -> Self {
+        Self {
+            limit: rate_limit.limit,
+            interval_ns: rate_limit.interval_ns,
+            timestamps: VecDeque::with_capacity(rate_limit.limit),
+        }
+    }
+
+    /// Removes the timestamps which have fallen out of the window as at `now`.
+    fn expire(&mut self, now: UnixNanos) {
+        while let Some(ts) = self.timestamps.front() {
+            if now.as_u64() - ts.as_u64() < self.interval_ns {
+                break;
+            }
+            self.timestamps.pop_front();
+        }
+    }
+
+    fn has_slot(&self) -> bool {
+        self.timestamps.len() < self.limit
+    }
+
+    fn record(&mut self, now: UnixNanos) {
+        self.timestamps.push_back(now);
+    }
+
+    /// Time delta in nanoseconds until a slot becomes available, as at `now`.
+    fn delta_next(&self, now: UnixNanos) -> u64 {
+        match self.timestamps.front() {
+            Some(oldest) if !self.has_slot() => self
+                .interval_ns
+                .saturating_sub(now.as_u64() - oldest.as_u64()),
+            _ => 0,
+        }
+    }
+}
+
+/// Metrics for a [`ThrottledCommandQueue`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ThrottledQueueMetrics {
+    /// The number of commands received.
+    pub recv_count: usize,
+    /// The number of commands released.
+    pub released_count: usize,
+    /// The maximum number of commands queued at any one time.
+    pub max_queue_depth: usize,
+    /// The total time released commands spent queued (nanoseconds).
+    pub total_wait_ns: u64,
+    /// The longest time a released command spent queued (nanoseconds).
+    pub max_wait_ns: u64,
+}
+
+impl ThrottledQueueMetrics {
+    /// Returns the average time released commands spent queued (nanoseconds).
+    #[must_use]
+    pub fn avg_wait_ns(&self) -> f64 {
+        if self.released_count == 0 {
+            return 0.0;
+        }
+        self.total_wait_ns as f64 / self.released_count as f64
+    }
+}
+
+/// Queues trading commands and releases them only while both a burst and a sustained
+/// [`RateLimit`] have slots available.
+///
+/// Commands are released in FIFO order, except that cancel commands bypass any queued
+/// non-cancel commands. Cancels still consume slots and are subject to the same limits.
+pub struct ThrottledCommandQueue {
+    clock: Rc<RefCell<dyn Clock>>,
+    windows: [RateWindow; 2],
+    priority: VecDeque<(TradingCommand, UnixNanos)>,
+    queue: VecDeque<(TradingCommand, UnixNanos)>,
+    metrics: ThrottledQueueMetrics,
+}
+
+impl Debug for ThrottledCommandQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct(stringify!(ThrottledCommandQueue))
+            .field("windows", &self.windows)
+            .field("priority", &self.priority)
+            .field("queue", &self.queue)
+            .field("metrics", &self.metrics)
+            .finish()
+    }
+}
+
+impl ThrottledCommandQueue {
+    /// Creates a new [`ThrottledCommandQueue`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If either `burst` or `sustained` has a zero `limit` or `interval_ns`.
+    #[must_use]
+    pub fn new(burst: RateLimit, sustained: RateLimit, clock: Rc<RefCell<dyn Clock>>) -> Self {
+        for rate_limit in [&burst, &sustained] {
+            assert!(rate_limit.limit > 0, "`limit` must be positive");
+            assert!(rate_limit.interval_ns > 0, "`interval_ns` must be positive");
+        }
+
+        Self {
+            clock,
+            windows: [RateWindow::new(&burst), RateWindow::new(&sustained)],
+            priority: VecDeque::new(),
+            queue: VecDeque::new(),
+            metrics: ThrottledQueueMetrics::default(),
+        }
+    }
+
+    /// Queues the given `command` for release.
+    pub fn push(&mut self, command: TradingCommand) {
+        let now = self.clock.borrow().timestamp_ns();
+
+        if is_cancel(&command) {
+            self.priority.push_back((command, now));
+        } else {
+            self.queue.push_back((command, now));
+        }
+
+        self.metrics.recv_count += 1;
+        self.metrics.max_queue_depth = self.metrics.max_queue_depth.max(self.qsize());
+    }
+
+    /// Returns the queued commands for which rate limit slots are available, in release order.
+    pub fn poll_ready(&mut self) -> Vec<TradingCommand> {
+        let now = self.clock.borrow().timestamp_ns();
+
+        for window in &mut self.windows {
+            window.expire(now);
+        }
+
+        let mut ready = Vec::new();
+        while self.windows.iter().all(RateWindow::has_slot) {
+            let Some((command, queued_ts)) =
+                self.priority.pop_front().or_else(|| self.queue.pop_front())
+            else {
+                break;
+            };
+
+            for window in &mut self.windows {
+                window.record(now);
+            }
+
+            let wait_ns = now.as_u64() - queued_ts.as_u64();
+            self.metrics.released_count += 1;
+            self.metrics.total_wait_ns += wait_ns;
+            self.metrics.max_wait_ns = self.metrics.max_wait_ns.max(wait_ns);
+
+            ready.push(command);
+        }
+
+        ready
+    }
+
+    /// Time delta in nanoseconds until the next queued command can be released.
+    ///
+    /// Returns zero if a command can be released now, or if the queue is empty.
+    #[must_use]
+    pub fn delta_next(&self) -> u64 {
+        if self.qsize() == 0 {
+            return 0;
+        }
+
+        let now = self.clock.borrow().timestamp_ns();
+        self.windows
+            .iter()
+            .map(|window| window.delta_next(now))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Number of commands currently queued.
+    #[must_use]
+    pub fn qsize(&self) -> usize {
+        self.priority.len() + self.queue.len()
+    }
+
+    /// Returns the metrics for the queue.
+    #[must_use]
+    pub const fn metrics(&self) -> ThrottledQueueMetrics {
+        self.metrics
+    }
+
+    /// Reset the queue which clears all queued commands, window state and metrics.
+    pub fn reset(&mut self) {
+        self.priority.clear();
+        self.queue.clear();
+        for window in &mut self.windows {
+            window.timestamps.clear();
+        }
+        self.metrics = ThrottledQueueMetrics::default();
+    }
+}
+
+const fn is_cancel(command: &TradingCommand) -> bool {
+    matches!(
+        command,
+        TradingCommand::CancelOrder(_)
+            | TradingCommand::CancelAllOrders(_)
+            | TradingCommand::BatchCancelOrders(_)
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_common::clock::TestClock;
+    use nautilus_model::identifiers::ClientOrderId;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+    use crate::messages::{CancelOrder, SubmitOrder};
+
+    /// Burst limit of 2 per 10ns, sustained limit of 4 per 100ns.
+    struct TestQueue {
+        queue: ThrottledCommandQueue,
+        clock: Rc<RefCell<TestClock>>,
+    }
+
+    impl TestQueue {
+        fn advance_to(&self, ts: u64) {
+            self.clock.borrow_mut().advance_time(ts.into(), true);
+        }
+    }
+
+    #[fixture]
+    fn test_queue() -> TestQueue {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let queue = ThrottledCommandQueue::new(
+            RateLimit::new(2, 10),
+            RateLimit::new(4, 100),
+            clock.clone(),
+        );
+        TestQueue { queue, clock }
+    }
+
+    fn submit(id: &str) -> TradingCommand {
+        TradingCommand::SubmitOrder(SubmitOrder {
+            client_order_id: ClientOrderId::new(id),
+            ..Default::default()
+        })
+    }
+
+    fn cancel(id: &str) -> TradingCommand {
+        TradingCommand::CancelOrder(CancelOrder {
+            client_order_id: ClientOrderId::new(id),
+            ..Default::default()
+        })
+    }
+
+    fn ids(commands: &[TradingCommand]) -> Vec<String> {
+        commands
+            .iter()
+            .map(|command| match command {
+                TradingCommand::SubmitOrder(submit) => format!("S:{}", submit.client_order_id),
+                TradingCommand::CancelOrder(cancel) => format!("C:{}", cancel.client_order_id),
+                _ => unreachable!(),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_poll_ready_when_empty(mut test_queue: TestQueue) {
+        assert!(test_queue.queue.poll_ready().is_empty());
+        assert_eq!(test_queue.queue.qsize(), 0);
+        assert_eq!(test_queue.queue.delta_next(), 0);
+    }
+
+    #[rstest]
+    fn test_burst_limit_releases_in_fifo_order(mut test_queue: TestQueue) {
+        for id in ["O-1", "O-2", "O-3"] {
+            test_queue.queue.push(submit(id));
+        }
+
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-1", "S:O-2"]);
+        assert_eq!(test_queue.queue.qsize(), 1);
+        assert_eq!(test_queue.queue.delta_next(), 10);
+
+        test_queue.advance_to(9);
+        assert!(test_queue.queue.poll_ready().is_empty());
+        assert_eq!(test_queue.queue.delta_next(), 1);
+
+        test_queue.advance_to(10);
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-3"]);
+        assert_eq!(test_queue.queue.qsize(), 0);
+    }
+
+    #[rstest]
+    fn test_sustained_limit_respected_after_burst_window(mut test_queue: TestQueue) {
+        for i in 1..=6 {
+            test_queue.queue.push(submit(&format!("O-{i}")));
+        }
+
+        assert_eq!(test_queue.queue.poll_ready().len(), 2);
+        test_queue.advance_to(10);
+        assert_eq!(test_queue.queue.poll_ready().len(), 2);
+
+        // Burst window has reset but the sustained window is full
+        test_queue.advance_to(20);
+        assert!(test_queue.queue.poll_ready().is_empty());
+        assert_eq!(test_queue.queue.delta_next(), 80);
+
+        test_queue.advance_to(100);
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-5", "S:O-6"]);
+    }
+
+    #[rstest]
+    fn test_cancels_jump_the_queue_within_sustained_limit(mut test_queue: TestQueue) {
+        for i in 1..=5 {
+            test_queue.queue.push(submit(&format!("O-{i}")));
+        }
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-1", "S:O-2"]);
+
+        test_queue.advance_to(10);
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-3", "S:O-4"]);
+
+        // Sustained limit reached, cancels must wait behind the window but ahead of O-5
+        test_queue.advance_to(20);
+        test_queue.queue.push(cancel("O-1"));
+        test_queue.queue.push(cancel("O-2"));
+        assert!(test_queue.queue.poll_ready().is_empty());
+        assert_eq!(test_queue.queue.qsize(), 3);
+
+        test_queue.advance_to(100);
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["C:O-1", "C:O-2"]);
+
+        test_queue.advance_to(110);
+        assert_eq!(ids(&test_queue.queue.poll_ready()), ["S:O-5"]);
+    }
+
+    #[rstest]
+    fn test_metrics(mut test_queue: TestQueue) {
+        for id in ["O-1", "O-2", "O-3"] {
+            test_queue.queue.push(submit(id));
+        }
+        test_queue.queue.poll_ready();
+        test_queue.advance_to(10);
+        test_queue.queue.poll_ready();
+
+        let metrics = test_queue.queue.metrics();
+        assert_eq!(metrics.recv_count, 3);
+        assert_eq!(metrics.released_count, 3);
+        assert_eq!(metrics.max_queue_depth, 3);
+        assert_eq!(metrics.total_wait_ns, 10);
+        assert_eq!(metrics.max_wait_ns, 10);
+        assert_eq!(metrics.avg_wait_ns(), 10.0 / 3.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut test_queue: TestQueue) {
+        for id in ["O-1", "O-2", "O-3"] {
+            test_queue.queue.push(submit(id));
+        }
+        test_queue.queue.poll_ready();
+
+        test_queue.queue.reset();
+
+        assert_eq!(test_queue.queue.qsize(), 0);
+        assert_eq!(test_queue.queue.metrics(), ThrottledQueueMetrics::default());
+        test_queue.queue.push(submit("O-4"));
+        assert_eq!(test_queue.queue.poll_ready().len(), 1);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "`limit` must be positive")]
+    fn test_new_with_zero_limit() {
+        let clock = Rc::new(RefCell::new(TestClock::new()));
+        let _ = ThrottledCommandQueue::new(RateLimit::new(0, 10), RateLimit::new(4, 100), clock);
+    }
+}
@@ -0,0 +1,561 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Trailing stop price calculations for the `OrderEmulator` and venue simulators.
+
+use anyhow::bail;
+use nautilus_model::{
+    enums::{OrderSide, TrailingOffsetType, TriggerType},
+    orders::any::OrderAny,
+    types::{
+        fixed::{f64_to_fixed_i64, FIXED_PRECISION},
+        price::Price,
+    },
+};
+
+/// Calculates the new trigger price (and limit price for trailing stop limit orders)
+/// from the latest market prices.
+///
+/// Returns `(new_trigger_price, new_price)`, where each is `None` if no update is needed.
+/// A price is only ever moved towards the market (never loosening the stop), and all
+/// calculated prices are rounded to the nearest multiple of `price_increment`.
+///
+/// The `limit_offset` should be `Some` for trailing stop limit orders only.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If a market price required by the `trigger_type` is `None`.
+/// - If the `trigger_type`, `trailing_offset_type` or `order_side` is not supported.
+#[allow(clippy::too_many_arguments)]
+pub fn trailing_stop_calculate(
+    price_increment: Price,
+    order_side: OrderSide,
+    trigger_type: TriggerType,
+    trailing_offset_type: TrailingOffsetType,
+    trailing_offset: f64,
+    limit_offset: Option<f64>,
+    trigger_price: Option<Price>,
+    price: Option<Price>,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    let market_prices = match trigger_type {
+        TriggerType::Default | TriggerType::LastTrade | TriggerType::MarkPrice => {
+            vec![require_last(last)?]
+        }
+        TriggerType::BidAsk => vec![bid_ask_price(order_side, bid, ask)?],
+        TriggerType::LastOrBidAsk => {
+            vec![require_last(last)?, bid_ask_price(order_side, bid, ask)?]
+        }
+        _ => bail!("Cannot process trailing stop, `TriggerType.{trigger_type}` not supported"),
+    };
+
+    let mut trigger_price = trigger_price;
+    let mut price = price;
+    let mut new_trigger_price = None;
+    let mut new_price = None;
+
+    for market in market_prices {
+        let candidate = calculate_with_market(
+            price_increment,
+            trailing_offset_type,
+            order_side,
+            trailing_offset,
+            market,
+        )?;
+        if is_tighter(order_side, trigger_price, candidate) {
+            trigger_price = Some(candidate);
+            new_trigger_price = Some(candidate);
+        }
+
+        if let Some(limit_offset) = limit_offset {
+            let candidate = calculate_with_market(
+                price_increment,
+                trailing_offset_type,
+                order_side,
+                limit_offset,
+                market,
+            )?;
+            if is_tighter(order_side, price, candidate) {
+                price = Some(candidate);
+                new_price = Some(candidate);
+            }
+        }
+    }
+
+    Ok((new_trigger_price, new_price))
+}
+
+/// Calculates the new trigger price (and limit price) for the given trailing stop `order`.
+///
+/// See [`trailing_stop_calculate`] for details.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `order` is not a trailing stop order.
+/// - If the calculation fails, see [`trailing_stop_calculate`].
+pub fn trailing_stop_calculate_for_order(
+    price_increment: Price,
+    order: &OrderAny,
+    bid: Option<Price>,
+    ask: Option<Price>,
+    last: Option<Price>,
+) -> anyhow::Result<(Option<Price>, Option<Price>)> {
+    match order {
+        OrderAny::TrailingStopMarket(order) => trailing_stop_calculate(
+            price_increment,
+            order.side,
+            order.trigger_type,
+            order.trailing_offset_type,
+            order.trailing_offset.as_f64(),
+            None,
+            Some(order.trigger_price),
+            None,
+            bid,
+            ask,
+            last,
+        ),
+        OrderAny::TrailingStopLimit(order) => trailing_stop_calculate(
+            price_increment,
+            order.side,
+            order.trigger_type,
+            order.trailing_offset_type,
+            order.trailing_offset.as_f64(),
+            Some(order.limit_offset.as_f64()),
+            Some(order.trigger_price),
+            Some(order.price),
+            bid,
+            ask,
+            last,
+        ),
+        _ => bail!(
+            "Invalid `OrderType` for trailing stop calculation, was {}",
+            order.order_type()
+        ),
+    }
+}
+
+fn require_last(last: Option<Price>) -> anyhow::Result<Price> {
+    match last {
+        Some(last) => Ok(last),
+        None => bail!("Cannot process trailing stop, no LAST price (add trade ticks or use bars)"),
+    }
+}
+
+fn bid_ask_price(
+    order_side: OrderSide,
+    bid: Option<Price>,
+    ask: Option<Price>,
+) -> anyhow::Result<Price> {
+    match (order_side, bid, ask) {
+        (_, None, _) => {
+            bail!("Cannot process trailing stop, no BID price (add quote ticks or use bars)")
+        }
+        (_, _, None) => {
+            bail!("Cannot process trailing stop, no ASK price (add quote ticks or use bars)")
+        }
+        (OrderSide::Buy, _, Some(ask)) => Ok(ask),
+        (OrderSide::Sell, Some(bid), _) => Ok(bid),
+        _ => bail!("Invalid `OrderSide` for trailing stop, was {order_side}"),
+    }
+}
+
+fn calculate_with_market(
+    price_increment: Price,
+    trailing_offset_type: TrailingOffsetType,
+    order_side: OrderSide,
+    offset: f64,
+    market: Price,
+) -> anyhow::Result<Price> {
+    let market_f64 = market.as_f64();
+    let offset = match trailing_offset_type {
+        TrailingOffsetType::Price => offset,
+        TrailingOffsetType::BasisPoints => market_f64 * offset / 10_000.0,
+        TrailingOffsetType::Ticks => offset * price_increment.as_f64(),
+        _ => bail!(
+            "Cannot process trailing stop, `TrailingOffsetType.{trailing_offset_type}` not supported"
+        ),
+    };
+
+    let value = match order_side {
+        OrderSide::Buy => market_f64 + offset,
+        OrderSide::Sell => market_f64 - offset,
+        _ => bail!("Invalid `OrderSide` for trailing stop, was {order_side}"),
+    };
+
+    Ok(round_to_increment(value, price_increment))
+}
+
+/// Returns whether the `candidate` price is closer to the market than the `current` price.
+fn is_tighter(order_side: OrderSide, current: Option<Price>, candidate: Price) -> bool {
+    match current {
+        None => true,
+        Some(current) => match order_side {
+            OrderSide::Buy => current > candidate,
+            _ => current < candidate,
+        },
+    }
+}
+
+fn round_to_increment(value: f64, price_increment: Price) -> Price {
+    let raw = f64_to_fixed_i64(value, FIXED_PRECISION);
+    let increment = price_increment.raw;
+    let ticks = (raw as f64 / increment as f64).round() as i64;
+    Price::from_raw(ticks * increment, price_increment.precision)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn calculate_trigger(
+        order_side: OrderSide,
+        trailing_offset_type: TrailingOffsetType,
+        trailing_offset: f64,
+        trigger_price: Option<&str>,
+        last: &str,
+    ) -> Option<Price> {
+        trailing_stop_calculate(
+            Price::from("0.01"),
+            order_side,
+            TriggerType::LastTrade,
+            trailing_offset_type,
+            trailing_offset,
+            None,
+            trigger_price.map(Price::from),
+            None,
+            None,
+            None,
+            Some(Price::from(last)),
+        )
+        .unwrap()
+        .0
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, TrailingOffsetType::Price, 1.0, "101.00")]
+    #[case(OrderSide::Sell, TrailingOffsetType::Price, 1.0, "99.00")]
+    #[case(OrderSide::Buy, TrailingOffsetType::BasisPoints, 50.0, "100.50")]
+    #[case(OrderSide::Sell, TrailingOffsetType::BasisPoints, 50.0, "99.50")]
+    #[case(OrderSide::Buy, TrailingOffsetType::Ticks, 5.0, "100.05")]
+    #[case(OrderSide::Sell, TrailingOffsetType::Ticks, 5.0, "99.95")]
+    fn test_initial_trigger_price(
+        #[case] order_side: OrderSide,
+        #[case] trailing_offset_type: TrailingOffsetType,
+        #[case] trailing_offset: f64,
+        #[case] expected: &str,
+    ) {
+        let result = calculate_trigger(
+            order_side,
+            trailing_offset_type,
+            trailing_offset,
+            None,
+            "100.00",
+        );
+
+        assert_eq!(result, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::Price,
+        1.0,
+        "100.00",
+        "98.00",
+        Some("99.00")
+    )]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::Price,
+        1.0,
+        "100.00",
+        "99.50",
+        None
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::Price,
+        1.0,
+        "100.00",
+        "102.00",
+        Some("101.00")
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::Price,
+        1.0,
+        "100.00",
+        "100.50",
+        None
+    )]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::BasisPoints,
+        100.0,
+        "100.00",
+        "98.00",
+        Some("98.98")
+    )]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::BasisPoints,
+        100.0,
+        "100.00",
+        "99.50",
+        None
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::BasisPoints,
+        100.0,
+        "100.00",
+        "102.00",
+        Some("100.98")
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::BasisPoints,
+        100.0,
+        "100.00",
+        "100.50",
+        None
+    )]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::Ticks,
+        10.0,
+        "100.00",
+        "98.00",
+        Some("98.10")
+    )]
+    #[case(
+        OrderSide::Buy,
+        TrailingOffsetType::Ticks,
+        10.0,
+        "100.00",
+        "99.95",
+        None
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::Ticks,
+        10.0,
+        "100.00",
+        "102.00",
+        Some("101.90")
+    )]
+    #[case(
+        OrderSide::Sell,
+        TrailingOffsetType::Ticks,
+        10.0,
+        "100.00",
+        "100.05",
+        None
+    )]
+    fn test_trigger_price_never_loosened(
+        #[case] order_side: OrderSide,
+        #[case] trailing_offset_type: TrailingOffsetType,
+        #[case] trailing_offset: f64,
+        #[case] trigger_price: &str,
+        #[case] last: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let result = calculate_trigger(
+            order_side,
+            trailing_offset_type,
+            trailing_offset,
+            Some(trigger_price),
+            last,
+        );
+
+        assert_eq!(result, expected.map(Price::from));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.37", "100.87")] // 100.37 + 0.50185 -> 100.87185
+    #[case(OrderSide::Sell, "100.37", "99.87")] // 100.37 - 0.50185 -> 99.86815
+    #[case(OrderSide::Buy, "12.34", "12.40")] // 12.34 + 0.0617 -> 12.4017
+    #[case(OrderSide::Sell, "12.34", "12.28")] // 12.34 - 0.0617 -> 12.2783
+    fn test_basis_points_rounded_to_tick_size(
+        #[case] order_side: OrderSide,
+        #[case] last: &str,
+        #[case] expected: &str,
+    ) {
+        let result = calculate_trigger(
+            order_side,
+            TrailingOffsetType::BasisPoints,
+            50.0,
+            None,
+            last,
+        );
+
+        assert_eq!(result, Some(Price::from(expected)));
+    }
+
+    #[rstest]
+    fn test_basis_points_rounded_to_non_decimal_tick_size() {
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.25"),
+            OrderSide::Sell,
+            TriggerType::LastTrade,
+            TrailingOffsetType::BasisPoints,
+            10.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Price::from("4000.00")),
+        )
+        .unwrap();
+
+        // 4000.00 - 4.00 = 3996.00 (already on a tick)
+        assert_eq!(trigger_price, Some(Price::from("3996.00")));
+
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.25"),
+            OrderSide::Sell,
+            TriggerType::LastTrade,
+            TrailingOffsetType::BasisPoints,
+            10.0,
+            None,
+            Some(Price::from("3996.00")),
+            None,
+            None,
+            None,
+            Some(Price::from("4001.10")),
+        )
+        .unwrap();
+
+        // 4001.10 - 4.0011 = 3997.0989, rounded to the nearest 0.25 tick
+        assert_eq!(trigger_price, Some(Price::from("3997.00")));
+    }
+
+    #[rstest]
+    fn test_trailing_stop_limit_updates_both_prices() {
+        let (trigger_price, price) = trailing_stop_calculate(
+            Price::from("0.01"),
+            OrderSide::Sell,
+            TriggerType::BidAsk,
+            TrailingOffsetType::Price,
+            1.0,
+            Some(1.5),
+            Some(Price::from("98.00")),
+            Some(Price::from("97.50")),
+            Some(Price::from("100.00")),
+            Some(Price::from("100.02")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from("99.00")));
+        assert_eq!(price, Some(Price::from("98.50")));
+    }
+
+    #[rstest]
+    fn test_bid_ask_uses_ask_for_buy_stops() {
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.01"),
+            OrderSide::Buy,
+            TriggerType::BidAsk,
+            TrailingOffsetType::Ticks,
+            2.0,
+            None,
+            None,
+            None,
+            Some(Price::from("100.00")),
+            Some(Price::from("100.02")),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from("100.04")));
+    }
+
+    #[rstest]
+    fn test_last_or_bid_ask_uses_tightest_price() {
+        let (trigger_price, _) = trailing_stop_calculate(
+            Price::from("0.01"),
+            OrderSide::Sell,
+            TriggerType::LastOrBidAsk,
+            TrailingOffsetType::Price,
+            1.0,
+            None,
+            None,
+            None,
+            Some(Price::from("100.10")),
+            Some(Price::from("100.12")),
+            Some(Price::from("100.00")),
+        )
+        .unwrap();
+
+        assert_eq!(trigger_price, Some(Price::from("99.10")));
+    }
+
+    #[rstest]
+    #[case(TriggerType::LastTrade, Some("100.00"), None)]
+    #[case(TriggerType::BidAsk, None, Some("100.00"))]
+    #[case(TriggerType::LastOrBidAsk, Some("100.00"), None)]
+    #[case(TriggerType::IndexPrice, Some("100.00"), Some("100.00"))]
+    fn test_missing_market_price_or_unsupported_trigger_type(
+        #[case] trigger_type: TriggerType,
+        #[case] bid_ask: Option<&str>,
+        #[case] last: Option<&str>,
+    ) {
+        let result = trailing_stop_calculate(
+            Price::from("0.01"),
+            OrderSide::Buy,
+            trigger_type,
+            TrailingOffsetType::Price,
+            1.0,
+            None,
+            None,
+            None,
+            bid_ask.map(Price::from),
+            bid_ask.map(Price::from),
+            last.map(Price::from),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_unsupported_offset_type() {
+        let result = trailing_stop_calculate(
+            Price::from("0.01"),
+            OrderSide::Buy,
+            TriggerType::LastTrade,
+            TrailingOffsetType::PriceTier,
+            1.0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Price::from("100.00")),
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -162,7 +162,7 @@ mod tests {
         indicator_dema_10.update_raw(1.0);
         indicator_dema_10.update_raw(2.0);
         indicator_dema_10.update_raw(3.0);
-        assert_eq!(indicator_dema_10.value, 1.904_583_020_285_499_4);
+        assert_eq!(indicator_dema_10.value, 1.904_583_020_285_499);
     }
 
     #[rstest]
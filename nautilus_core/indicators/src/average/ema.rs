@@ -22,7 +22,6 @@ use nautilus_model::{
 
 use crate::indicator::{Indicator, MovingAverage};
 
-#[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
     feature = "python",
@@ -101,13 +100,16 @@ impl MovingAverage for ExponentialMovingAverage {
     fn count(&self) -> usize {
         self.count
     }
+
+    // Unfused to match the values of the Cython `ExponentialMovingAverage` bit-for-bit
+    #[allow(clippy::suboptimal_flops)]
     fn update_raw(&mut self, value: f64) {
         if !self.has_inputs {
             self.has_inputs = true;
             self.value = value;
         }
 
-        self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        self.value = self.alpha * value + (1.0 - self.alpha) * self.value;
         self.count += 1;
 
         // Initialization logic
@@ -170,7 +172,7 @@ mod tests {
         assert!(ema.has_inputs());
         assert!(ema.initialized());
         assert_eq!(ema.count, 10);
-        assert_eq!(ema.value, 6.239_368_480_121_215_5);
+        assert_eq!(ema.value, 6.239_368_480_121_215);
     }
 
     #[rstest]
@@ -224,4 +226,34 @@ mod tests {
         assert!(!indicator_ema_10.initialized);
         assert_eq!(indicator_ema_10.value, 1522.0);
     }
+
+    #[rstest]
+    fn test_parity_with_cython(
+        parity_series: Vec<f64>,
+        mut indicator_ema_10: ExponentialMovingAverage,
+    ) {
+        let expected = [
+            (1.000_2, false),
+            (1.000_218_181_818_181_7, false),
+            (1.000_269_421_487_603_1, false),
+            (1.000_293_163_035_311_6, false),
+            (1.000_258_042_483_436_6, false),
+            (1.000_174_762_031_902_6, false),
+            (1.000_052_078_026_102_2, false),
+            (0.999_988_063_839_538_1, false),
+            (0.999_990_234_050_531_1, false),
+            (1.000_101_100_586_798_1, true),
+            (1.000_246_355_025_562, true),
+            (1.000_347_017_748_187, true),
+            (1.000_483_923_612_153, true),
+            (1.000_523_210_228_125_2, true),
+            (1.000_609_899_277_557, true),
+        ];
+
+        for (value, (expected_value, expected_initialized)) in parity_series.iter().zip(expected) {
+            indicator_ema_10.update_raw(*value);
+            assert_eq!(indicator_ema_10.value, expected_value);
+            assert_eq!(indicator_ema_10.initialized, expected_initialized);
+        }
+    }
 }
@@ -197,7 +197,7 @@ mod tests {
         indicator_hma_10.update_raw(1.0);
         indicator_hma_10.update_raw(2.0);
         indicator_hma_10.update_raw(3.0);
-        assert_eq!(indicator_hma_10.value, 1.824_561_403_508_772);
+        assert_eq!(indicator_hma_10.value, 1.824_561_403_508_771_8);
     }
 
     #[rstest]
@@ -213,7 +213,7 @@ mod tests {
         indicator_hma_10.update_raw(1.00020);
         indicator_hma_10.update_raw(1.00010);
         indicator_hma_10.update_raw(1.00000);
-        assert_eq!(indicator_hma_10.value, 1.000_140_392_817_059_8);
+        assert_eq!(indicator_hma_10.value, 1.000_140_392_817_059_4);
     }
 
     #[rstest]
@@ -105,13 +105,15 @@ impl MovingAverage for WilderMovingAverage {
         self.count
     }
 
+    // Unfused to match the values of the Cython `WilderMovingAverage` bit-for-bit
+    #[allow(clippy::suboptimal_flops)]
     fn update_raw(&mut self, value: f64) {
         if !self.has_inputs {
             self.has_inputs = true;
             self.value = value;
         }
 
-        self.value = self.alpha.mul_add(value, (1.0 - self.alpha) * self.value);
+        self.value = self.alpha * value + (1.0 - self.alpha) * self.value;
         self.count += 1;
 
         // Initialization logic
@@ -174,7 +176,7 @@ mod tests {
         assert!(rma.has_inputs());
         assert!(rma.initialized());
         assert_eq!(rma.count, 10);
-        assert_eq!(rma.value, 4.486_784_401);
+        assert_eq!(rma.value, 4.486_784_400_999_999_5);
     }
 
     #[rstest]
@@ -22,7 +22,6 @@ use nautilus_model::{
 
 use crate::indicator::{Indicator, MovingAverage};
 
-#[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
     feature = "python",
@@ -200,4 +199,31 @@ mod tests {
         assert_eq!(sma.count, 1);
         assert_eq!(sma.value, 1500.0);
     }
+
+    #[rstest]
+    fn test_parity_with_cython(parity_series: Vec<f64>, mut indicator_sma_10: SimpleMovingAverage) {
+        let expected = [
+            (1.000_2, false),
+            (1.000_249_999_999_999_9, false),
+            (1.000_333_333_333_333, false),
+            (1.000_349_999_999_999_9, false),
+            (1.000_299_999_999_999_7, false),
+            (1.000_216_666_666_666_5, false),
+            (1.000_114_285_714_285_5, false),
+            (1.000_062_499_999_999_9, false),
+            (1.000_055_555_555_555_5, false),
+            (1.000_109_999_999_999_8, true),
+            (1.000_179_999_999_999_8, true),
+            (1.000_23, true),
+            (1.000_290_000_000_000_1, true),
+            (1.000_32, true),
+            (1.000_41, true),
+        ];
+
+        for (value, (expected_value, expected_initialized)) in parity_series.iter().zip(expected) {
+            indicator_sma_10.update_raw(*value);
+            assert_eq!(indicator_sma_10.value, expected_value);
+            assert_eq!(indicator_sma_10.initialized, expected_initialized);
+        }
+    }
 }
@@ -24,7 +24,6 @@ use nautilus_model::{
 use crate::indicator::{Indicator, MovingAverage};
 
 /// An indicator which calculates a weighted moving average across a rolling window.
-#[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
     feature = "python",
@@ -81,15 +80,42 @@ impl WeightedMovingAverage {
     }
 
     fn weighted_average(&self) -> f64 {
-        let mut sum = 0.0;
-        let mut weight_sum = 0.0;
-        let reverse_weights: Vec<f64> = self.weights.iter().copied().rev().collect();
-        for (index, input) in self.inputs.iter().rev().enumerate() {
-            let weight = reverse_weights.get(index).unwrap();
-            sum += input * weight;
-            weight_sum += weight;
+        // Until initialized, only the most recent weights are applied to the inputs
+        let weights = &self.weights[self.weights.len() - self.inputs.len()..];
+        let products: Vec<f64> = self
+            .inputs
+            .iter()
+            .zip(weights)
+            .map(|(input, weight)| input * weight)
+            .collect();
+        pairwise_sum(&products) / pairwise_sum(weights)
+    }
+}
+
+/// Returns the sum of `values` using the pairwise summation of `numpy.sum`, so that the
+/// weighted average matches `numpy.average` (as used by the Cython indicator) bit-for-bit.
+fn pairwise_sum(values: &[f64]) -> f64 {
+    const BLOCK_SIZE: usize = 128;
+
+    let n = values.len();
+    if n < 8 {
+        values.iter().fold(0.0, |sum, value| sum + value)
+    } else if n <= BLOCK_SIZE {
+        let mut partials = [0.0; 8];
+        partials.copy_from_slice(&values[..8]);
+        let mut i = 8;
+        while i < n - n % 8 {
+            for (partial, value) in partials.iter_mut().zip(&values[i..i + 8]) {
+                *partial += value;
+            }
+            i += 8;
         }
-        sum / weight_sum
+        let sum = ((partials[0] + partials[1]) + (partials[2] + partials[3]))
+            + ((partials[4] + partials[5]) + (partials[6] + partials[7]));
+        values[i..].iter().fold(sum, |sum, value| sum + value)
+    } else {
+        let mid = n / 2 - (n / 2) % 8;
+        pairwise_sum(&values[..mid]) + pairwise_sum(&values[mid..])
     }
 }
 
@@ -133,17 +159,13 @@ impl MovingAverage for WeightedMovingAverage {
     fn count(&self) -> usize {
         self.inputs.len()
     }
+
     fn update_raw(&mut self, value: f64) {
-        if !self.has_inputs {
-            self.has_inputs = true;
-            self.inputs.push(value);
-            self.value = value;
-            return;
-        }
         if self.inputs.len() == self.period {
             self.inputs.remove(0);
         }
         self.inputs.push(value);
+        self.has_inputs = true;
         self.value = self.weighted_average();
         if !self.initialized && self.count() >= self.period {
             self.initialized = true;
@@ -188,6 +210,15 @@ mod tests {
         assert_eq!(indicator_wma_10.value, 1.0);
     }
 
+    #[rstest]
+    fn test_initialized_with_one_input_when_period_one() {
+        let mut wma = WeightedMovingAverage::new(1, vec![1.0], None);
+        wma.update_raw(1.0);
+        assert!(wma.has_inputs());
+        assert!(wma.initialized());
+        assert_eq!(wma.value, 1.0);
+    }
+
     #[rstest]
     fn test_value_with_two_inputs_equal_weights() {
         let mut wma = WeightedMovingAverage::new(2, vec![0.5, 0.5], None);
@@ -219,8 +250,7 @@ mod tests {
         indicator_wma_10.update_raw(1.0);
         indicator_wma_10.update_raw(2.0);
         indicator_wma_10.update_raw(3.0);
-        let result = 1.0f64.mul_add(0.8, 3.0f64.mul_add(1.0, 2.0 * 0.9)) / (1.0 + 0.9 + 0.8);
-        assert_eq!(indicator_wma_10.value, result);
+        assert_eq!(indicator_wma_10.value, 2.074_074_074_074_074);
     }
 
     #[rstest]
@@ -236,7 +266,7 @@ mod tests {
         for i in 1..=11 {
             indicator_wma_10.update_raw(f64::from(i));
         }
-        assert_eq!(indicator_wma_10.value(), 8.000_000_000_000_002);
+        assert_eq!(indicator_wma_10.value(), 8.0);
     }
 
     #[rstest]
@@ -249,4 +279,34 @@ mod tests {
         assert!(!indicator_wma_10.has_inputs);
         assert!(!indicator_wma_10.initialized);
     }
+
+    #[rstest]
+    fn test_parity_with_cython(
+        parity_series: Vec<f64>,
+        mut indicator_wma_10: WeightedMovingAverage,
+    ) {
+        let expected = [
+            (1.000_2, false),
+            (1.000_252_631_578_947_3, false),
+            (1.000_344_444_444_444_3, false),
+            (1.000_361_764_705_882_5, false),
+            (1.000_297_5, false),
+            (1.000_186_666_666_666_8, false),
+            (1.000_042_857_142_857, false),
+            (0.999_967_307_692_307_7, false),
+            (0.999_959_259_259_259_1, false),
+            (1.000_06, true),
+            (1.000_203_636_363_636_4, true),
+            (1.000_316_363_636_363_7, true),
+            (1.000_474_545_454_545_4, true),
+            (1.000_549_090_909_091, true),
+            (1.000_672_727_272_727_4, true),
+        ];
+
+        for (value, (expected_value, expected_initialized)) in parity_series.iter().zip(expected) {
+            indicator_wma_10.update_raw(*value);
+            assert_eq!(indicator_wma_10.value, expected_value);
+            assert_eq!(indicator_wma_10.initialized, expected_initialized);
+        }
+    }
 }
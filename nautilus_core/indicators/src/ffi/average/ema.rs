@@ -0,0 +1,149 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::ema::ExponentialMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`ExponentialMovingAverage`].
+///
+/// This struct wraps `ExponentialMovingAverage` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ExponentialMovingAverage` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `ExponentialMovingAverage_API` to be
+/// dereferenced to `ExponentialMovingAverage`, providing access to `ExponentialMovingAverage`'s
+/// methods without having to manually access the underlying `ExponentialMovingAverage` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ExponentialMovingAverage_API(Box<ExponentialMovingAverage>);
+
+impl Deref for ExponentialMovingAverage_API {
+    type Target = ExponentialMovingAverage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ExponentialMovingAverage_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn ema_new(period: usize, price_type: PriceType) -> ExponentialMovingAverage_API {
+    ExponentialMovingAverage_API(Box::new(ExponentialMovingAverage::new(
+        period,
+        Some(price_type),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn ema_drop(ema: ExponentialMovingAverage_API) {
+    drop(ema); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn ema_update_raw(ema: &mut ExponentialMovingAverage_API, value: f64) {
+    ema.update_raw(value);
+}
+
+#[no_mangle]
+pub extern "C" fn ema_handle_quote(ema: &mut ExponentialMovingAverage_API, quote: &QuoteTick) {
+    ema.handle_quote(quote);
+}
+
+#[no_mangle]
+pub extern "C" fn ema_handle_trade(ema: &mut ExponentialMovingAverage_API, trade: &TradeTick) {
+    ema.handle_trade(trade);
+}
+
+#[no_mangle]
+pub extern "C" fn ema_handle_bar(ema: &mut ExponentialMovingAverage_API, bar: &Bar) {
+    ema.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn ema_value(ema: &ExponentialMovingAverage_API) -> f64 {
+    ema.value()
+}
+
+#[no_mangle]
+pub extern "C" fn ema_count(ema: &ExponentialMovingAverage_API) -> usize {
+    ema.count()
+}
+
+#[no_mangle]
+pub extern "C" fn ema_has_inputs(ema: &ExponentialMovingAverage_API) -> u8 {
+    u8::from(ema.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn ema_initialized(ema: &ExponentialMovingAverage_API) -> u8 {
+    u8::from(ema.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn ema_reset(ema: &mut ExponentialMovingAverage_API) {
+    ema.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_ema_warm_up(parity_series: Vec<f64>) {
+        let mut ema = ema_new(10, PriceType::Last);
+
+        for (i, value) in parity_series.iter().enumerate() {
+            ema_update_raw(&mut ema, *value);
+            assert_eq!(ema_count(&ema), i + 1);
+            assert_eq!(ema_initialized(&ema), u8::from(i + 1 >= 10));
+        }
+
+        assert_eq!(ema_has_inputs(&ema), 1);
+        assert_eq!(ema_value(&ema), 1.000_609_899_277_557);
+
+        ema_reset(&mut ema);
+        assert_eq!(ema_count(&ema), 0);
+        assert_eq!(ema_value(&ema), 0.0);
+        assert_eq!(ema_initialized(&ema), 0);
+        ema_drop(ema);
+    }
+
+    #[rstest]
+    fn test_ema_handle_quote(stub_quote: QuoteTick) {
+        let mut ema = ema_new(10, PriceType::Mid);
+        ema_handle_quote(&mut ema, &stub_quote);
+        assert_eq!(ema_value(&ema), 1501.0);
+        ema_drop(ema);
+    }
+}
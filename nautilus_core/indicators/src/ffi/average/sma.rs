@@ -0,0 +1,146 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::sma::SimpleMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`SimpleMovingAverage`].
+///
+/// This struct wraps `SimpleMovingAverage` in a way that makes it compatible with C function
+/// calls, enabling interaction with `SimpleMovingAverage` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `SimpleMovingAverage_API` to be
+/// dereferenced to `SimpleMovingAverage`, providing access to `SimpleMovingAverage`'s
+/// methods without having to manually access the underlying `SimpleMovingAverage` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct SimpleMovingAverage_API(Box<SimpleMovingAverage>);
+
+impl Deref for SimpleMovingAverage_API {
+    type Target = SimpleMovingAverage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SimpleMovingAverage_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn sma_new(period: usize, price_type: PriceType) -> SimpleMovingAverage_API {
+    SimpleMovingAverage_API(Box::new(SimpleMovingAverage::new(period, Some(price_type))))
+}
+
+#[no_mangle]
+pub extern "C" fn sma_drop(sma: SimpleMovingAverage_API) {
+    drop(sma); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn sma_update_raw(sma: &mut SimpleMovingAverage_API, value: f64) {
+    sma.update_raw(value);
+}
+
+#[no_mangle]
+pub extern "C" fn sma_handle_quote(sma: &mut SimpleMovingAverage_API, quote: &QuoteTick) {
+    sma.handle_quote(quote);
+}
+
+#[no_mangle]
+pub extern "C" fn sma_handle_trade(sma: &mut SimpleMovingAverage_API, trade: &TradeTick) {
+    sma.handle_trade(trade);
+}
+
+#[no_mangle]
+pub extern "C" fn sma_handle_bar(sma: &mut SimpleMovingAverage_API, bar: &Bar) {
+    sma.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn sma_value(sma: &SimpleMovingAverage_API) -> f64 {
+    sma.value()
+}
+
+#[no_mangle]
+pub extern "C" fn sma_count(sma: &SimpleMovingAverage_API) -> usize {
+    sma.count()
+}
+
+#[no_mangle]
+pub extern "C" fn sma_has_inputs(sma: &SimpleMovingAverage_API) -> u8 {
+    u8::from(sma.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn sma_initialized(sma: &SimpleMovingAverage_API) -> u8 {
+    u8::from(sma.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn sma_reset(sma: &mut SimpleMovingAverage_API) {
+    sma.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_sma_warm_up(parity_series: Vec<f64>) {
+        let mut sma = sma_new(10, PriceType::Last);
+
+        for (i, value) in parity_series.iter().enumerate() {
+            sma_update_raw(&mut sma, *value);
+            assert_eq!(sma_count(&sma), (i + 1).min(10));
+            assert_eq!(sma_initialized(&sma), u8::from(i + 1 >= 10));
+        }
+
+        assert_eq!(sma_has_inputs(&sma), 1);
+        assert_eq!(sma_value(&sma), 1.000_41);
+
+        sma_reset(&mut sma);
+        assert_eq!(sma_count(&sma), 0);
+        assert_eq!(sma_value(&sma), 0.0);
+        assert_eq!(sma_initialized(&sma), 0);
+        sma_drop(sma);
+    }
+
+    #[rstest]
+    fn test_sma_handle_quote(stub_quote: QuoteTick) {
+        let mut sma = sma_new(10, PriceType::Mid);
+        sma_handle_quote(&mut sma, &stub_quote);
+        assert_eq!(sma_value(&sma), 1501.0);
+        sma_drop(sma);
+    }
+}
@@ -0,0 +1,185 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+
+use crate::{
+    average::wma::WeightedMovingAverage,
+    indicator::{Indicator, MovingAverage},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`WeightedMovingAverage`].
+///
+/// This struct wraps `WeightedMovingAverage` in a way that makes it compatible with C function
+/// calls, enabling interaction with `WeightedMovingAverage` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `WeightedMovingAverage_API` to be
+/// dereferenced to `WeightedMovingAverage`, providing access to `WeightedMovingAverage`'s
+/// methods without having to manually access the underlying `WeightedMovingAverage` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct WeightedMovingAverage_API(Box<WeightedMovingAverage>);
+
+impl Deref for WeightedMovingAverage_API {
+    type Target = WeightedMovingAverage;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for WeightedMovingAverage_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new weighted moving average.
+///
+/// Pass a null `weights_ptr` for equal weights (a simple average of the inputs).
+///
+/// # Safety
+///
+/// - Assumes `weights_ptr` is either null or points to `weights_len` valid `f64` values.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `weights_ptr` is not null and `weights_len` is not equal to `period`.
+#[no_mangle]
+pub unsafe extern "C" fn wma_new(
+    period: usize,
+    weights_ptr: *const f64,
+    weights_len: usize,
+    price_type: PriceType,
+) -> WeightedMovingAverage_API {
+    let weights = if weights_ptr.is_null() {
+        vec![1.0; period]
+    } else {
+        std::slice::from_raw_parts(weights_ptr, weights_len).to_vec()
+    };
+
+    WeightedMovingAverage_API(Box::new(WeightedMovingAverage::new(
+        period,
+        weights,
+        Some(price_type),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn wma_drop(wma: WeightedMovingAverage_API) {
+    drop(wma); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn wma_update_raw(wma: &mut WeightedMovingAverage_API, value: f64) {
+    wma.update_raw(value);
+}
+
+#[no_mangle]
+pub extern "C" fn wma_handle_quote(wma: &mut WeightedMovingAverage_API, quote: &QuoteTick) {
+    wma.handle_quote(quote);
+}
+
+#[no_mangle]
+pub extern "C" fn wma_handle_trade(wma: &mut WeightedMovingAverage_API, trade: &TradeTick) {
+    wma.handle_trade(trade);
+}
+
+#[no_mangle]
+pub extern "C" fn wma_handle_bar(wma: &mut WeightedMovingAverage_API, bar: &Bar) {
+    wma.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn wma_value(wma: &WeightedMovingAverage_API) -> f64 {
+    wma.value()
+}
+
+#[no_mangle]
+pub extern "C" fn wma_count(wma: &WeightedMovingAverage_API) -> usize {
+    wma.count()
+}
+
+#[no_mangle]
+pub extern "C" fn wma_has_inputs(wma: &WeightedMovingAverage_API) -> u8 {
+    u8::from(wma.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn wma_initialized(wma: &WeightedMovingAverage_API) -> u8 {
+    u8::from(wma.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn wma_reset(wma: &mut WeightedMovingAverage_API) {
+    wma.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_wma_warm_up(parity_series: Vec<f64>) {
+        let weights = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+        let mut wma = unsafe { wma_new(10, weights.as_ptr(), weights.len(), PriceType::Last) };
+
+        for (i, value) in parity_series.iter().enumerate() {
+            wma_update_raw(&mut wma, *value);
+            assert_eq!(wma_count(&wma), (i + 1).min(10));
+            assert_eq!(wma_initialized(&wma), u8::from(i + 1 >= 10));
+        }
+
+        assert_eq!(wma_has_inputs(&wma), 1);
+        assert_eq!(wma_value(&wma), 1.000_672_727_272_727_4);
+
+        wma_reset(&mut wma);
+        assert_eq!(wma_count(&wma), 0);
+        assert_eq!(wma_value(&wma), 0.0);
+        assert_eq!(wma_initialized(&wma), 0);
+        wma_drop(wma);
+    }
+
+    #[rstest]
+    fn test_wma_handle_quote(stub_quote: QuoteTick) {
+        let mut wma = unsafe { wma_new(10, std::ptr::null(), 0, PriceType::Mid) };
+        wma_handle_quote(&mut wma, &stub_quote);
+        assert_eq!(wma_value(&wma), 1501.0);
+        wma_drop(wma);
+    }
+
+    #[rstest]
+    fn test_wma_null_weights_is_simple_average() {
+        let mut wma = unsafe { wma_new(3, std::ptr::null(), 0, PriceType::Last) };
+        wma_update_raw(&mut wma, 1.0);
+        wma_update_raw(&mut wma, 2.0);
+        wma_update_raw(&mut wma, 6.0);
+        assert_eq!(wma_value(&wma), 3.0);
+        assert_eq!(wma_initialized(&wma), 1);
+        wma_drop(wma);
+    }
+}
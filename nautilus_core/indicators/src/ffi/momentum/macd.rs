@@ -0,0 +1,157 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::{data::bar::Bar, enums::PriceType};
+
+use crate::{
+    average::MovingAverageType,
+    indicator::{Indicator, MovingAverage},
+    momentum::macd::MovingAverageConvergenceDivergence,
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying
+/// [`MovingAverageConvergenceDivergence`].
+///
+/// This struct wraps `MovingAverageConvergenceDivergence` in a way that makes it compatible with
+/// C function calls, enabling interaction with `MovingAverageConvergenceDivergence` in a C
+/// environment.
+///
+/// It implements the `Deref` trait, allowing instances of
+/// `MovingAverageConvergenceDivergence_API` to be dereferenced to
+/// `MovingAverageConvergenceDivergence`, providing access to
+/// `MovingAverageConvergenceDivergence`'s methods without having to manually access the
+/// underlying `MovingAverageConvergenceDivergence` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct MovingAverageConvergenceDivergence_API(Box<MovingAverageConvergenceDivergence>);
+
+impl Deref for MovingAverageConvergenceDivergence_API {
+    type Target = MovingAverageConvergenceDivergence;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MovingAverageConvergenceDivergence_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn macd_new(
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    ma_type: MovingAverageType,
+    price_type: PriceType,
+) -> MovingAverageConvergenceDivergence_API {
+    MovingAverageConvergenceDivergence_API(Box::new(MovingAverageConvergenceDivergence::new(
+        fast_period,
+        slow_period,
+        Some(ma_type),
+        Some(price_type),
+        Some(signal_period),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn macd_drop(macd: MovingAverageConvergenceDivergence_API) {
+    drop(macd); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn macd_update_raw(macd: &mut MovingAverageConvergenceDivergence_API, close: f64) {
+    macd.update_raw(close);
+}
+
+#[no_mangle]
+pub extern "C" fn macd_handle_bar(macd: &mut MovingAverageConvergenceDivergence_API, bar: &Bar) {
+    macd.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn macd_value(macd: &MovingAverageConvergenceDivergence_API) -> f64 {
+    macd.value
+}
+
+#[no_mangle]
+pub extern "C" fn macd_signal(macd: &MovingAverageConvergenceDivergence_API) -> f64 {
+    macd.signal
+}
+
+#[no_mangle]
+pub extern "C" fn macd_histogram(macd: &MovingAverageConvergenceDivergence_API) -> f64 {
+    macd.histogram
+}
+
+#[no_mangle]
+pub extern "C" fn macd_has_inputs(macd: &MovingAverageConvergenceDivergence_API) -> u8 {
+    u8::from(macd.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn macd_initialized(macd: &MovingAverageConvergenceDivergence_API) -> u8 {
+    u8::from(macd.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn macd_reset(macd: &mut MovingAverageConvergenceDivergence_API) {
+    macd.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_macd_outputs() {
+        let mut macd = macd_new(3, 10, 9, MovingAverageType::Exponential, PriceType::Last);
+        for i in 1..=16 {
+            macd_update_raw(&mut macd, f64::from(i));
+        }
+
+        assert_eq!(macd_has_inputs(&macd), 1);
+        assert_eq!(macd_initialized(&macd), 1);
+        assert_eq!(macd_value(&macd), 3.278_231_367_312_290_7);
+        assert_eq!(macd_signal(&macd), 2.817_347_366_397_809_7);
+        assert_eq!(macd_histogram(&macd), 0.460_884_000_914_481);
+
+        macd_reset(&mut macd);
+        assert_eq!(macd_value(&macd), 0.0);
+        assert_eq!(macd_signal(&macd), 0.0);
+        assert_eq!(macd_histogram(&macd), 0.0);
+        assert_eq!(macd_initialized(&macd), 0);
+        macd_drop(macd);
+    }
+
+    #[rstest]
+    fn test_macd_handle_bar(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut macd = macd_new(3, 10, 9, MovingAverageType::Simple, PriceType::Last);
+        macd_handle_bar(&mut macd, &bar_ethusdt_binance_minute_bid);
+        assert_eq!(macd_has_inputs(&macd), 1);
+        assert_eq!(macd_value(&macd), 0.0);
+        macd_drop(macd);
+    }
+}
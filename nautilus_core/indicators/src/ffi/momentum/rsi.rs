@@ -0,0 +1,142 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_model::data::bar::Bar;
+
+use crate::{
+    average::MovingAverageType, indicator::Indicator, momentum::rsi::RelativeStrengthIndex,
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`RelativeStrengthIndex`].
+///
+/// This struct wraps `RelativeStrengthIndex` in a way that makes it compatible with C function
+/// calls, enabling interaction with `RelativeStrengthIndex` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `RelativeStrengthIndex_API` to be
+/// dereferenced to `RelativeStrengthIndex`, providing access to `RelativeStrengthIndex`'s
+/// methods without having to manually access the underlying `RelativeStrengthIndex` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct RelativeStrengthIndex_API(Box<RelativeStrengthIndex>);
+
+impl Deref for RelativeStrengthIndex_API {
+    type Target = RelativeStrengthIndex;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RelativeStrengthIndex_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_new(period: usize, ma_type: MovingAverageType) -> RelativeStrengthIndex_API {
+    RelativeStrengthIndex_API(Box::new(RelativeStrengthIndex::new(period, Some(ma_type))))
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_drop(rsi: RelativeStrengthIndex_API) {
+    drop(rsi); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_update_raw(rsi: &mut RelativeStrengthIndex_API, value: f64) {
+    rsi.update_raw(value);
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_handle_bar(rsi: &mut RelativeStrengthIndex_API, bar: &Bar) {
+    rsi.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_value(rsi: &RelativeStrengthIndex_API) -> f64 {
+    rsi.value
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_count(rsi: &RelativeStrengthIndex_API) -> usize {
+    rsi.count
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_has_inputs(rsi: &RelativeStrengthIndex_API) -> u8 {
+    u8::from(rsi.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_initialized(rsi: &RelativeStrengthIndex_API) -> u8 {
+    u8::from(rsi.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn rsi_reset(rsi: &mut RelativeStrengthIndex_API) {
+    rsi.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    fn test_rsi_update_raw() {
+        let mut rsi = rsi_new(10, MovingAverageType::Exponential);
+        for value in [3.0, 2.0, 5.0, 6.0, 7.0, 6.0] {
+            rsi_update_raw(&mut rsi, value);
+        }
+
+        assert_eq!(rsi_has_inputs(&rsi), 1);
+        assert_eq!(rsi_initialized(&rsi), 0);
+        assert_eq!(rsi_count(&rsi), 6);
+        assert_eq!(rsi_value(&rsi), 0.683_736_332_582_526_5);
+
+        rsi_reset(&mut rsi);
+        assert_eq!(rsi_count(&rsi), 0);
+        assert_eq!(rsi_value(&rsi), 0.0);
+        rsi_drop(rsi);
+    }
+
+    #[rstest]
+    fn test_rsi_flat_series_is_max() {
+        let mut rsi = rsi_new(3, MovingAverageType::Wilder);
+        for _ in 0..3 {
+            rsi_update_raw(&mut rsi, 1.0);
+        }
+        assert_eq!(rsi_initialized(&rsi), 1);
+        assert_eq!(rsi_value(&rsi), 1.0);
+        rsi_drop(rsi);
+    }
+
+    #[rstest]
+    fn test_rsi_handle_bar(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut rsi = rsi_new(10, MovingAverageType::Exponential);
+        rsi_handle_bar(&mut rsi, &bar_ethusdt_binance_minute_bid);
+        assert_eq!(rsi_count(&rsi), 1);
+        assert_eq!(rsi_value(&rsi), 1.0);
+        rsi_drop(rsi);
+    }
+}
@@ -0,0 +1,144 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi::parsing::u8_as_bool;
+use nautilus_model::data::bar::Bar;
+
+use crate::{average::MovingAverageType, indicator::Indicator, volatility::atr::AverageTrueRange};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`AverageTrueRange`].
+///
+/// This struct wraps `AverageTrueRange` in a way that makes it compatible with C function
+/// calls, enabling interaction with `AverageTrueRange` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `AverageTrueRange_API` to be
+/// dereferenced to `AverageTrueRange`, providing access to `AverageTrueRange`'s methods
+/// without having to manually access the underlying `AverageTrueRange` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct AverageTrueRange_API(Box<AverageTrueRange>);
+
+impl Deref for AverageTrueRange_API {
+    type Target = AverageTrueRange;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for AverageTrueRange_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn atr_new(
+    period: usize,
+    ma_type: MovingAverageType,
+    use_previous: u8,
+    value_floor: f64,
+) -> AverageTrueRange_API {
+    AverageTrueRange_API(Box::new(AverageTrueRange::new(
+        period,
+        Some(ma_type),
+        Some(u8_as_bool(use_previous)),
+        Some(value_floor),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn atr_drop(atr: AverageTrueRange_API) {
+    drop(atr); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn atr_update_raw(atr: &mut AverageTrueRange_API, high: f64, low: f64, close: f64) {
+    atr.update_raw(high, low, close);
+}
+
+#[no_mangle]
+pub extern "C" fn atr_handle_bar(atr: &mut AverageTrueRange_API, bar: &Bar) {
+    atr.handle_bar(bar);
+}
+
+#[no_mangle]
+pub extern "C" fn atr_value(atr: &AverageTrueRange_API) -> f64 {
+    atr.value
+}
+
+#[no_mangle]
+pub extern "C" fn atr_count(atr: &AverageTrueRange_API) -> usize {
+    atr.count
+}
+
+#[no_mangle]
+pub extern "C" fn atr_has_inputs(atr: &AverageTrueRange_API) -> u8 {
+    u8::from(atr.has_inputs())
+}
+
+#[no_mangle]
+pub extern "C" fn atr_initialized(atr: &AverageTrueRange_API) -> u8 {
+    u8::from(atr.initialized())
+}
+
+#[no_mangle]
+pub extern "C" fn atr_reset(atr: &mut AverageTrueRange_API) {
+    atr.reset();
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::stubs::*;
+
+    #[rstest]
+    #[case(MovingAverageType::Simple, 0.000_349_999_999_999_924_43)]
+    #[case(MovingAverageType::Wilder, 0.000_310_493_827_160_444_64)]
+    fn test_atr_warm_up(#[case] ma_type: MovingAverageType, #[case] expected: f64) {
+        let mut atr = atr_new(3, ma_type, 1, 0.0);
+        atr_update_raw(&mut atr, 1.00020, 1.00000, 1.00010);
+        atr_update_raw(&mut atr, 1.00030, 1.00005, 1.00025);
+        assert_eq!(atr_initialized(&atr), 0);
+        atr_update_raw(&mut atr, 1.00060, 1.00040, 1.00050);
+        atr_update_raw(&mut atr, 1.00045, 1.00015, 1.00020);
+        atr_update_raw(&mut atr, 1.00025, 0.99990, 1.00000);
+
+        assert_eq!(atr_has_inputs(&atr), 1);
+        assert_eq!(atr_initialized(&atr), 1);
+        assert_eq!(atr_count(&atr), 5);
+        assert_eq!(atr_value(&atr), expected);
+
+        atr_reset(&mut atr);
+        assert_eq!(atr_value(&atr), 0.0);
+        assert_eq!(atr_initialized(&atr), 0);
+        atr_drop(atr);
+    }
+
+    #[rstest]
+    fn test_atr_handle_bar(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut atr = atr_new(10, MovingAverageType::Simple, 1, 0.0);
+        atr_handle_bar(&mut atr, &bar_ethusdt_binance_minute_bid);
+        assert_eq!(atr_value(&atr), 55.0);
+        atr_drop(atr);
+    }
+}
@@ -24,6 +24,7 @@
 //! depending on the intended use case, i.e. whether to provide Python bindings
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
+//! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod average;
@@ -37,5 +38,7 @@ pub mod volatility;
 #[cfg(test)]
 mod stubs;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
 #[cfg(feature = "python")]
 pub mod python;
@@ -25,6 +25,11 @@ use crate::{
     indicator::{Indicator, MovingAverage},
 };
 
+/// The default period for the signal line of a [`MovingAverageConvergenceDivergence`].
+pub const MACD_SIGNAL_PERIOD_DEFAULT: usize = 9;
+
+/// An indicator which calculates the difference between a fast and slow moving average (MACD),
+/// along with a signal line (a moving average of the MACD) and histogram (MACD less signal).
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
@@ -34,14 +39,18 @@ use crate::{
 pub struct MovingAverageConvergenceDivergence {
     pub fast_period: usize,
     pub slow_period: usize,
+    pub signal_period: usize,
     pub ma_type: MovingAverageType,
     pub count: usize,
     pub price_type: PriceType,
     pub value: f64,
+    pub signal: f64,
+    pub histogram: f64,
     pub initialized: bool,
     has_inputs: bool,
     fast_ma: Box<dyn MovingAverage + Send + 'static>,
     slow_ma: Box<dyn MovingAverage + Send + 'static>,
+    signal_ma: Box<dyn MovingAverage + Send + 'static>,
 }
 
 impl Display for MovingAverageConvergenceDivergence {
@@ -85,8 +94,11 @@ impl Indicator for MovingAverageConvergenceDivergence {
 
     fn reset(&mut self) {
         self.value = 0.0;
+        self.signal = 0.0;
+        self.histogram = 0.0;
         self.fast_ma.reset();
         self.slow_ma.reset();
+        self.signal_ma.reset();
         self.has_inputs = false;
         self.initialized = false;
     }
@@ -94,30 +106,34 @@ impl Indicator for MovingAverageConvergenceDivergence {
 
 impl MovingAverageConvergenceDivergence {
     /// Creates a new [`MovingAverageConvergenceDivergence`] instance.
+    ///
+    /// The signal line uses the same `ma_type` as the fast and slow moving averages, with a
+    /// `signal_period` defaulting to [`MACD_SIGNAL_PERIOD_DEFAULT`].
     #[must_use]
     pub fn new(
         fast_period: usize,
         slow_period: usize,
         ma_type: Option<MovingAverageType>,
         price_type: Option<PriceType>,
+        signal_period: Option<usize>,
     ) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
+        let signal_period = signal_period.unwrap_or(MACD_SIGNAL_PERIOD_DEFAULT);
         Self {
             fast_period,
             slow_period,
-            ma_type: ma_type.unwrap_or(MovingAverageType::Simple),
+            signal_period,
+            ma_type,
             price_type: price_type.unwrap_or(PriceType::Last),
             value: 0.0,
+            signal: 0.0,
+            histogram: 0.0,
             count: 0,
             initialized: false,
             has_inputs: false,
-            fast_ma: MovingAverageFactory::create(
-                ma_type.unwrap_or(MovingAverageType::Simple),
-                fast_period,
-            ),
-            slow_ma: MovingAverageFactory::create(
-                ma_type.unwrap_or(MovingAverageType::Simple),
-                slow_period,
-            ),
+            fast_ma: MovingAverageFactory::create(ma_type, fast_period),
+            slow_ma: MovingAverageFactory::create(ma_type, slow_period),
+            signal_ma: MovingAverageFactory::create(ma_type, signal_period),
         }
     }
 }
@@ -135,6 +151,9 @@ impl MovingAverage for MovingAverageConvergenceDivergence {
         self.fast_ma.update_raw(close);
         self.slow_ma.update_raw(close);
         self.value = self.fast_ma.value() - self.slow_ma.value();
+        self.signal_ma.update_raw(self.value);
+        self.signal = self.signal_ma.value();
+        self.histogram = self.value - self.signal;
 
         // Initialization logic
         if !self.initialized {
@@ -155,6 +174,7 @@ mod tests {
     use rstest::rstest;
 
     use crate::{
+        average::MovingAverageType,
         indicator::{Indicator, MovingAverage},
         momentum::macd::MovingAverageConvergenceDivergence,
         stubs::*,
@@ -246,9 +266,47 @@ mod tests {
         macd_10.update_raw(1.0);
         macd_10.reset();
         assert_eq!(macd_10.value, 0.0);
+        assert_eq!(macd_10.signal, 0.0);
+        assert_eq!(macd_10.histogram, 0.0);
         assert_eq!(macd_10.fast_ma.value(), 0.0);
         assert_eq!(macd_10.slow_ma.value(), 0.0);
+        assert_eq!(macd_10.signal_ma.value(), 0.0);
         assert!(!macd_10.has_inputs);
         assert!(!macd_10.initialized);
     }
+
+    #[rstest]
+    fn test_value_with_three_inputs_exponential() {
+        let mut macd = MovingAverageConvergenceDivergence::new(
+            3,
+            10,
+            Some(MovingAverageType::Exponential),
+            None,
+            None,
+        );
+        macd.update_raw(1.0);
+        macd.update_raw(2.0);
+        macd.update_raw(3.0);
+        assert_eq!(macd.value, 0.737_603_305_785_124_3);
+        assert_eq!(macd.signal, 0.198_429_752_066_115_8);
+        assert_eq!(macd.histogram, 0.539_173_553_719_008_5);
+    }
+
+    #[rstest]
+    fn test_value_with_more_inputs_exponential() {
+        let mut macd = MovingAverageConvergenceDivergence::new(
+            3,
+            10,
+            Some(MovingAverageType::Exponential),
+            None,
+            None,
+        );
+        for i in 1..=16 {
+            macd.update_raw(f64::from(i));
+        }
+        assert!(macd.initialized());
+        assert_eq!(macd.value, 3.278_231_367_312_290_7);
+        assert_eq!(macd.signal, 2.817_347_366_397_809_7);
+        assert_eq!(macd.histogram, 0.460_884_000_914_481);
+    }
 }
@@ -185,8 +185,8 @@ mod tests {
         }
 
         assert!(pressure_10.initialized());
-        assert_eq!(pressure_10.value, 4.377_880_184_331_797);
-        assert_eq!(pressure_10.value_cumulative, 23.231_207_409_222_474);
+        assert_eq!(pressure_10.value, 3.186_118_088_624_229_3);
+        assert_eq!(pressure_10.value_cumulative, 21.033_294_390_332_63);
     }
 
     #[rstest]
@@ -26,6 +26,10 @@ use crate::{
 };
 
 /// An indicator which calculates a relative strength index (RSI) across a rolling window.
+///
+/// The value is scaled between 0 and 1 (rather than 0 and 100). While the average loss is
+/// zero the value is the maximum of 1, including for a flat series of equal inputs (matching
+/// the Cython `RelativeStrengthIndex`).
 #[repr(C)]
 #[derive(Debug)]
 #[cfg_attr(
@@ -77,6 +81,8 @@ impl Indicator for RelativeStrengthIndex {
     }
 
     fn reset(&mut self) {
+        self.average_gain.reset();
+        self.average_loss.reset();
         self.value = 0.0;
         self.last_value = 0.0;
         self.count = 0;
@@ -89,16 +95,16 @@ impl RelativeStrengthIndex {
     /// Creates a new [`RelativeStrengthIndex`] instance.
     #[must_use]
     pub fn new(period: usize, ma_type: Option<MovingAverageType>) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Exponential);
         Self {
             period,
-            ma_type: ma_type.unwrap_or(MovingAverageType::Exponential),
+            ma_type,
             value: 0.0,
             last_value: 0.0,
             count: 0,
-            // inputs: Vec::new(),
             has_inputs: false,
-            average_gain: MovingAverageFactory::create(MovingAverageType::Exponential, period),
-            average_loss: MovingAverageFactory::create(MovingAverageType::Exponential, period),
+            average_gain: MovingAverageFactory::create(ma_type, period),
+            average_loss: MovingAverageFactory::create(ma_type, period),
             rsi_max: 1.0,
             initialized: false,
         }
@@ -134,10 +140,6 @@ impl RelativeStrengthIndex {
         let rs = self.average_gain.value() / self.average_loss.value();
         self.value = self.rsi_max - (self.rsi_max / (1.0 + rs));
         self.last_value = value;
-
-        if !self.initialized && self.count >= self.period {
-            self.initialized = true;
-        }
     }
 }
 
@@ -149,7 +151,10 @@ mod tests {
     use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
     use rstest::rstest;
 
-    use crate::{indicator::Indicator, momentum::rsi::RelativeStrengthIndex, stubs::*};
+    use crate::{
+        average::MovingAverageType, indicator::Indicator, momentum::rsi::RelativeStrengthIndex,
+        stubs::*,
+    };
 
     #[rstest]
     fn test_rsi_initialized(rsi_10: RelativeStrengthIndex) {
@@ -244,4 +249,37 @@ mod tests {
         assert_eq!(rsi_10.count, 1);
         assert_eq!(rsi_10.value, 1.0);
     }
+
+    #[rstest]
+    fn test_value_with_flat_inputs_returns_max(mut rsi_10: RelativeStrengthIndex) {
+        for _ in 0..10 {
+            rsi_10.update_raw(1.0);
+        }
+        assert!(rsi_10.initialized);
+        assert_eq!(rsi_10.value, 1.0);
+    }
+
+    #[rstest]
+    fn test_value_with_wilder_smoothing() {
+        let mut rsi = RelativeStrengthIndex::new(10, Some(MovingAverageType::Wilder));
+        rsi.update_raw(3.0);
+        rsi.update_raw(2.0);
+        rsi.update_raw(5.0);
+        rsi.update_raw(6.0);
+        rsi.update_raw(7.0);
+        rsi.update_raw(6.0);
+
+        assert_eq!(rsi.ma_type, MovingAverageType::Wilder);
+        assert_eq!(rsi.value, 0.701_770_182_420_629_9);
+    }
+
+    #[rstest]
+    fn test_reset_resets_moving_averages(mut rsi_10: RelativeStrengthIndex) {
+        rsi_10.update_raw(1.0);
+        rsi_10.update_raw(2.0);
+        rsi_10.reset();
+        rsi_10.update_raw(3.0);
+        rsi_10.update_raw(2.0);
+        assert_eq!(rsi_10.value, 0.0);
+    }
 }
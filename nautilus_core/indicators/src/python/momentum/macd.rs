@@ -28,15 +28,16 @@ use crate::{
 #[pymethods]
 impl MovingAverageConvergenceDivergence {
     #[new]
-    #[pyo3(signature = (fast_period, slow_period, ma_type=None, price_type=None))]
+    #[pyo3(signature = (fast_period, slow_period, ma_type=None, price_type=None, signal_period=None))]
     #[must_use]
     pub fn py_new(
         fast_period: usize,
         slow_period: usize,
         ma_type: Option<MovingAverageType>,
         price_type: Option<PriceType>,
+        signal_period: Option<usize>,
     ) -> Self {
-        Self::new(fast_period, slow_period, ma_type, price_type)
+        Self::new(fast_period, slow_period, ma_type, price_type, signal_period)
     }
 
     fn __repr__(&self) -> String {
@@ -64,6 +65,12 @@ impl MovingAverageConvergenceDivergence {
         self.slow_period
     }
 
+    #[getter]
+    #[pyo3(name = "signal_period")]
+    const fn py_signal_period(&self) -> usize {
+        self.signal_period
+    }
+
     #[getter]
     #[pyo3(name = "count")]
     fn py_count(&self) -> usize {
@@ -88,6 +95,18 @@ impl MovingAverageConvergenceDivergence {
         self.value
     }
 
+    #[getter]
+    #[pyo3(name = "signal")]
+    const fn py_signal(&self) -> f64 {
+        self.signal
+    }
+
+    #[getter]
+    #[pyo3(name = "histogram")]
+    const fn py_histogram(&self) -> f64 {
+        self.histogram
+    }
+
     #[pyo3(name = "handle_quote_tick")]
     fn py_handle_quote_tick(&mut self, quote: &QuoteTick) {
         self.py_update_raw(quote.extract_price(self.price_type).into());
@@ -19,4 +19,5 @@ pub mod fuzzy;
 pub mod kc;
 pub mod kp;
 pub mod rvi;
+pub mod rvol;
 pub mod vr;
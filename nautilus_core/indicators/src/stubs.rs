@@ -45,7 +45,7 @@ use crate::{
     ratio::{efficiency_ratio::EfficiencyRatio, spread_analyzer::SpreadAnalyzer},
     volatility::{
         dc::DonchianChannel, fuzzy::FuzzyCandlesticks, kc::KeltnerChannel, kp::KeltnerPosition,
-        rvi::RelativeVolatilityIndex, vr::VolatilityRatio,
+        rvi::RelativeVolatilityIndex, rvol::RollingVolatility, vr::VolatilityRatio,
     },
 };
 
@@ -109,6 +109,16 @@ pub fn bar_ethusdt_binance_minute_bid(#[default("1522")] close_price: &str) -> B
     }
 }
 
+/// A price series shared with the Cython indicator tests (`test_average_parity.py`),
+/// for checking the Rust and Cython indicators produce identical values.
+#[fixture]
+pub fn parity_series() -> Vec<f64> {
+    vec![
+        1.00020, 1.00030, 1.00050, 1.00040, 1.00010, 0.99980, 0.99950, 0.99970, 1.00000, 1.00060,
+        1.00090, 1.00080, 1.00110, 1.00070, 1.00100,
+    ]
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Average
 ////////////////////////////////////////////////////////////////////////////////
@@ -251,6 +261,7 @@ pub fn macd_10() -> MovingAverageConvergenceDivergence {
         8,
         Some(MovingAverageType::Simple),
         Some(PriceType::Bid),
+        None,
     )
 }
 
@@ -283,6 +294,11 @@ pub fn rvi_10() -> RelativeVolatilityIndex {
     RelativeVolatilityIndex::new(10, Some(10.0), Some(MovingAverageType::Simple))
 }
 
+#[fixture]
+pub fn rvol_10() -> RollingVolatility {
+    RollingVolatility::new(10, Some(PriceType::Mid))
+}
+
 #[fixture]
 pub fn kc_10() -> KeltnerChannel {
     KeltnerChannel::new(
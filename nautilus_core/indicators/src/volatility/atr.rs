@@ -74,6 +74,7 @@ impl Indicator for AverageTrueRange {
     }
 
     fn reset(&mut self) {
+        self.ma.reset();
         self.previous_close = 0.0;
         self.value = 0.0;
         self.count = 0;
@@ -91,20 +92,25 @@ impl AverageTrueRange {
         use_previous: Option<bool>,
         value_floor: Option<f64>,
     ) -> Self {
+        let ma_type = ma_type.unwrap_or(MovingAverageType::Simple);
         Self {
             period,
-            ma_type: ma_type.unwrap_or(MovingAverageType::Simple),
+            ma_type,
             use_previous: use_previous.unwrap_or(true),
             value_floor: value_floor.unwrap_or(0.0),
             value: 0.0,
             count: 0,
             previous_close: 0.0,
-            ma: MovingAverageFactory::create(MovingAverageType::Simple, period),
+            ma: MovingAverageFactory::create(ma_type, period),
             has_inputs: false,
             initialized: false,
         }
     }
 
+    /// Updates the indicator with the given raw `high`, `low` and `close` prices.
+    ///
+    /// If `use_previous`, the true range extends the bar range to the previous close, so that
+    /// gaps between bars are included (the first bar uses its own close as the previous close).
     pub fn update_raw(&mut self, high: f64, low: f64, close: f64) {
         if self.use_previous {
             if !self.has_inputs {
@@ -136,7 +142,7 @@ impl AverageTrueRange {
 
         if !self.initialized {
             self.has_inputs = true;
-            if self.count >= self.period {
+            if self.ma.initialized() {
                 self.initialized = true;
             }
         }
@@ -151,7 +157,7 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
-    use crate::testing::approx_equal;
+    use crate::{stubs::*, testing::approx_equal};
 
     #[rstest]
     fn test_name_returns_expected_string() {
@@ -287,4 +293,64 @@ mod tests {
         assert!(!atr.initialized);
         assert_eq!(atr.value, 0.0);
     }
+
+    #[rstest]
+    fn test_value_with_five_decimal_bar_prices() {
+        let mut atr = AverageTrueRange::new(10, Some(MovingAverageType::Simple), None, None);
+        atr.update_raw(1.00004, 1.00001, 1.00003);
+        assert!(atr.has_inputs());
+        assert_eq!(atr.value, 2.999_999_999_997_449e-5);
+    }
+
+    #[rstest]
+    fn test_handle_bar(bar_ethusdt_binance_minute_bid: Bar) {
+        let mut atr = AverageTrueRange::new(10, Some(MovingAverageType::Simple), None, None);
+        atr.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert!(atr.has_inputs());
+        assert!(!atr.initialized());
+        assert_eq!(atr.value, 55.0);
+    }
+
+    #[rstest]
+    #[case(MovingAverageType::Simple, 0.000_349_999_999_999_924_43)]
+    #[case(MovingAverageType::Wilder, 0.000_310_493_827_160_444_64)]
+    fn test_value_with_smoothing(#[case] ma_type: MovingAverageType, #[case] expected: f64) {
+        let mut atr = AverageTrueRange::new(3, Some(ma_type), None, None);
+        atr.update_raw(1.00020, 1.00000, 1.00010);
+        atr.update_raw(1.00030, 1.00005, 1.00025);
+        atr.update_raw(1.00060, 1.00040, 1.00050);
+        atr.update_raw(1.00045, 1.00015, 1.00020);
+        atr.update_raw(1.00025, 0.99990, 1.00000);
+        assert!(atr.initialized());
+        assert_eq!(atr.value, expected);
+    }
+
+    #[rstest]
+    fn test_value_with_gap_includes_previous_close() {
+        let mut atr = AverageTrueRange::new(1, Some(MovingAverageType::Simple), None, None);
+        atr.update_raw(1.0, 0.5, 1.0);
+        atr.update_raw(2.0, 1.5, 2.0); // Gap up from the previous close
+        assert_eq!(atr.value, 1.0);
+        atr.update_raw(0.5, 0.25, 0.5); // Gap down from the previous close
+        assert_eq!(atr.value, 1.75);
+    }
+
+    #[rstest]
+    fn test_value_with_gap_without_use_previous() {
+        let mut atr = AverageTrueRange::new(1, Some(MovingAverageType::Simple), Some(false), None);
+        atr.update_raw(1.0, 0.5, 1.0);
+        atr.update_raw(2.0, 1.5, 2.0);
+        assert_eq!(atr.value, 0.5);
+    }
+
+    #[rstest]
+    fn test_reset_resets_moving_average() {
+        let mut atr = AverageTrueRange::new(3, Some(MovingAverageType::Wilder), None, None);
+        atr.update_raw(1.00060, 1.00040, 1.00050);
+        atr.update_raw(1.00025, 0.99990, 1.00000);
+        atr.reset();
+        atr.update_raw(1.00020, 1.00000, 1.00010);
+        assert!(!atr.initialized());
+        assert_eq!(atr.value, 0.000_199_999_999_999_978);
+    }
 }
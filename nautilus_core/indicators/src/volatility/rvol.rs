@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::fmt::{Debug, Display};
+
+use nautilus_core::{
+    correctness::{check_predicate_true, FAILED},
+    rolling::RollingStats,
+};
+use nautilus_model::{
+    data::{bar::Bar, quote::QuoteTick, trade::TradeTick},
+    enums::PriceType,
+};
+
+use crate::indicator::Indicator;
+
+/// An indicator which calculates the realized volatility across a rolling window, as the sample
+/// standard deviation of the log returns `ln(price / previous price)`.
+///
+/// The volatility is per input (it is not annualized), and inputs must be positive prices.
+#[repr(C)]
+#[derive(Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.indicators")
+)]
+pub struct RollingVolatility {
+    /// The rolling window period of log returns for the indicator (>= 2).
+    pub period: usize,
+    pub price_type: PriceType,
+    pub value: f64,
+    pub count: usize,
+    pub initialized: bool,
+    has_inputs: bool,
+    previous: f64,
+    returns: RollingStats,
+}
+
+impl Display for RollingVolatility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({})", self.name(), self.period)
+    }
+}
+
+impl Indicator for RollingVolatility {
+    fn name(&self) -> String {
+        stringify!(RollingVolatility).to_string()
+    }
+
+    fn has_inputs(&self) -> bool {
+        self.has_inputs
+    }
+
+    fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    fn handle_quote(&mut self, quote: &QuoteTick) {
+        self.update_raw(quote.extract_price(self.price_type).into());
+    }
+
+    fn handle_trade(&mut self, trade: &TradeTick) {
+        self.update_raw((&trade.price).into());
+    }
+
+    fn handle_bar(&mut self, bar: &Bar) {
+        self.update_raw((&bar.close).into());
+    }
+
+    fn reset(&mut self) {
+        self.returns.clear();
+        self.previous = 0.0;
+        self.value = 0.0;
+        self.count = 0;
+        self.has_inputs = false;
+        self.initialized = false;
+    }
+}
+
+impl RollingVolatility {
+    /// Creates a new [`RollingVolatility`] instance.
+    #[must_use]
+    pub fn new(period: usize, price_type: Option<PriceType>) -> Self {
+        Self::new_checked(period, price_type).expect(FAILED)
+    }
+
+    pub fn new_checked(period: usize, price_type: Option<PriceType>) -> anyhow::Result<Self> {
+        check_predicate_true(period >= 2, "`period` must be at least 2")?;
+
+        Ok(Self {
+            period,
+            price_type: price_type.unwrap_or(PriceType::Last),
+            value: 0.0,
+            count: 0,
+            initialized: false,
+            has_inputs: false,
+            previous: 0.0,
+            returns: RollingStats::new(period),
+        })
+    }
+
+    pub fn update_raw(&mut self, value: f64) {
+        self.count += 1;
+
+        // The first input only sets the price for the first return
+        if !self.has_inputs {
+            self.has_inputs = true;
+            self.previous = value;
+            return;
+        }
+
+        self.returns.push((value / self.previous).ln());
+        self.previous = value;
+        self.value = self.returns.std().unwrap_or(0.0);
+
+        if !self.initialized && self.returns.is_full() {
+            self.initialized = true;
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::data::bar::Bar;
+    use rstest::rstest;
+
+    use crate::{indicator::Indicator, stubs::*, volatility::rvol::RollingVolatility};
+
+    #[rstest]
+    fn test_rvol_initialized(rvol_10: RollingVolatility) {
+        let display_str = format!("{rvol_10}");
+        assert_eq!(display_str, "RollingVolatility(10)");
+        assert_eq!(rvol_10.name(), "RollingVolatility");
+        assert_eq!(rvol_10.period, 10);
+        assert!(!rvol_10.has_inputs());
+        assert!(!rvol_10.initialized());
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_period_less_than_two_error() {
+        let _ = RollingVolatility::new(1, None);
+    }
+
+    #[rstest]
+    fn test_value_with_one_input(mut rvol_10: RollingVolatility) {
+        rvol_10.update_raw(1.0);
+        assert!(rvol_10.has_inputs());
+        assert_eq!(rvol_10.count, 1);
+        assert_eq!(rvol_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_constant_returns_is_zero() {
+        let mut rvol = RollingVolatility::new(2, None);
+        rvol.update_raw(1.0);
+        rvol.update_raw(2.0);
+        rvol.update_raw(4.0);
+        assert!(rvol.initialized());
+        assert_eq!(rvol.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_value_with_parity_series(parity_series: Vec<f64>, mut rvol_10: RollingVolatility) {
+        for (i, value) in parity_series.iter().enumerate() {
+            rvol_10.update_raw(*value);
+            // Initialized once the window holds `period` returns (`period + 1` inputs)
+            assert_eq!(rvol_10.initialized(), i >= 10);
+        }
+        assert_eq!(rvol_10.count, 15);
+        assert_eq!(rvol_10.value, 0.000_338_007_430_223_278_9);
+    }
+
+    #[rstest]
+    fn test_handle_bar(mut rvol_10: RollingVolatility, bar_ethusdt_binance_minute_bid: Bar) {
+        rvol_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        rvol_10.handle_bar(&bar_ethusdt_binance_minute_bid);
+        assert_eq!(rvol_10.count, 2);
+        assert_eq!(rvol_10.value, 0.0);
+    }
+
+    #[rstest]
+    fn test_reset(mut rvol_10: RollingVolatility) {
+        rvol_10.update_raw(1.0);
+        rvol_10.update_raw(1.1);
+        rvol_10.update_raw(1.0);
+        rvol_10.reset();
+        assert_eq!(rvol_10.value, 0.0);
+        assert_eq!(rvol_10.count, 0);
+        assert!(!rvol_10.has_inputs());
+        assert!(!rvol_10.initialized());
+
+        rvol_10.update_raw(2.0);
+        rvol_10.update_raw(4.0);
+        rvol_10.update_raw(8.0);
+        assert_eq!(rvol_10.value, 0.0);
+    }
+}
@@ -80,6 +80,17 @@ impl MarginAccount {
             .unwrap_or(&self.default_leverage)
     }
 
+    /// Returns the leverage for margin calculations, where a zero (spot) leverage falls back
+    /// to the default leverage, which is then set for the instrument.
+    fn resolve_leverage(&mut self, instrument_id: InstrumentId) -> f64 {
+        let leverage = self.get_leverage(&instrument_id);
+        if leverage != 0.0 {
+            return leverage;
+        }
+        self.set_leverage(instrument_id, self.default_leverage);
+        self.default_leverage
+    }
+
     #[must_use]
     pub fn is_unleveraged(&self, instrument_id: InstrumentId) -> bool {
         self.get_leverage(&instrument_id) == 1.0
@@ -184,11 +195,7 @@ impl MarginAccount {
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
         let notional = instrument.calculate_notional_value(quantity, price, use_quote_for_inverse);
-        let leverage = self.get_leverage(&instrument.id());
-        if leverage == 0.0 {
-            self.leverages
-                .insert(instrument.id(), self.default_leverage);
-        }
+        let leverage = self.resolve_leverage(instrument.id());
         let adjusted_notional = notional / leverage;
         let initial_margin_f64 = instrument.margin_init().to_f64().unwrap();
         let mut margin = adjusted_notional * initial_margin_f64;
@@ -210,11 +217,7 @@ impl MarginAccount {
         use_quote_for_inverse: Option<bool>,
     ) -> Money {
         let notional = instrument.calculate_notional_value(quantity, price, use_quote_for_inverse);
-        let leverage = self.get_leverage(&instrument.id());
-        if leverage == 0.0 {
-            self.leverages
-                .insert(instrument.id(), self.default_leverage);
-        }
+        let leverage = self.resolve_leverage(instrument.id());
         let adjusted_notional = notional / leverage;
         let margin_maint_f64 = instrument.margin_maint().to_f64().unwrap();
         let mut margin = adjusted_notional * margin_maint_f64;
@@ -586,6 +589,23 @@ mod tests {
         assert_eq!(result, Money::from("240.32 USD"));
     }
 
+    #[rstest]
+    fn test_calculate_margin_init_with_zero_leverage_uses_default_leverage(
+        mut margin_account: MarginAccount,
+        audusd_sim: CurrencyPair,
+    ) {
+        margin_account.set_default_leverage(10.0);
+        margin_account.set_leverage(audusd_sim.id, 0.0);
+        let result = margin_account.calculate_initial_margin(
+            audusd_sim,
+            Quantity::from(100_000),
+            Price::from("0.8"),
+            None,
+        );
+        assert_eq!(result, Money::from("240.32 USD"));
+        assert_eq!(margin_account.get_leverage(&audusd_sim.id), 10.0);
+    }
+
     #[rstest]
     fn test_calculate_margin_init_with_no_leverage_for_inverse(
         mut margin_account: MarginAccount,
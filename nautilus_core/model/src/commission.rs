@@ -0,0 +1,539 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Commission models for calculating the fees charged on fills.
+//!
+//! Commissions are calculated in `Decimal` and rounded half up (away from zero) to the
+//! settlement currency precision, so that the same fill always produces the same `Money`
+//! wherever it is calculated.
+
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+use crate::{
+    enums::LiquiditySide,
+    instruments::any::InstrumentAny,
+    types::{
+        currency::Currency, fixed::FIXED_PRECISION, money::Money, price::Price, quantity::Quantity,
+    },
+};
+
+const BPS_DIVISOR: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Provides the commission charged on a fill.
+pub trait CommissionModel {
+    /// Calculates the commission for a fill with the given `notional` value (denominated in the
+    /// `currency`), returning it in the `currency`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the model cannot calculate the commission for the `liquidity_side`.
+    fn calculate_for_notional(
+        &self,
+        notional: Decimal,
+        quantity: Quantity,
+        liquidity_side: LiquiditySide,
+        currency: Currency,
+    ) -> anyhow::Result<Money>;
+
+    /// Calculates the commission for a fill of `quantity` at `price` on the `instrument`,
+    /// returning it in the instrument's settlement currency.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the notional value cannot be calculated (zero price for an inverse instrument).
+    /// - If the model cannot calculate the commission for the `liquidity_side`.
+    fn calculate(
+        &self,
+        instrument: &InstrumentAny,
+        quantity: Quantity,
+        price: Price,
+        liquidity_side: LiquiditySide,
+    ) -> anyhow::Result<Money> {
+        let notional = calculate_notional(
+            quantity,
+            price,
+            instrument.multiplier(),
+            instrument.is_inverse(),
+        )?;
+        self.calculate_for_notional(
+            notional,
+            quantity,
+            liquidity_side,
+            instrument.settlement_currency(),
+        )
+    }
+}
+
+/// Returns the notional value of `quantity` at `price`, in the quote currency for
+/// standard instruments, or the base currency for inverse instruments.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the instrument `is_inverse` and `price` is zero.
+pub fn calculate_notional(
+    quantity: Quantity,
+    price: Price,
+    multiplier: Quantity,
+    is_inverse: bool,
+) -> anyhow::Result<Decimal> {
+    let quantity = quantity.as_decimal() * multiplier.as_decimal();
+    if !is_inverse {
+        return Ok(quantity * price.as_decimal());
+    }
+    if price.is_zero() {
+        anyhow::bail!("Cannot calculate inverse notional value with zero price");
+    }
+    Ok(quantity / price.as_decimal())
+}
+
+/// Returns the `amount` as `Money`, rounded half up (away from zero) to the `currency` precision.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the rounded `amount` is outside the representable range for `Money`.
+#[must_use]
+pub fn round_commission(amount: Decimal, currency: Currency) -> Money {
+    let rounded = amount.round_dp_with_strategy(
+        u32::from(currency.precision),
+        RoundingStrategy::MidpointAwayFromZero,
+    );
+    let raw = (rounded * Decimal::from(10_i64.pow(u32::from(FIXED_PRECISION))))
+        .to_i64()
+        .expect("Commission amount out of range for `Money`");
+    Money::from_raw(raw, currency)
+}
+
+/// Returns the fee rate in basis points for the `liquidity_side`.
+fn side_bps(
+    maker_bps: Decimal,
+    taker_bps: Decimal,
+    liquidity_side: LiquiditySide,
+) -> anyhow::Result<Decimal> {
+    match liquidity_side {
+        LiquiditySide::Maker => Ok(maker_bps),
+        LiquiditySide::Taker => Ok(taker_bps),
+        LiquiditySide::NoLiquiditySide => {
+            anyhow::bail!("Cannot calculate commission with no liquidity side")
+        }
+    }
+}
+
+/// Provides a commission of a percentage (in basis points) of the notional value,
+/// depending on whether the fill provided (maker) or took (taker) liquidity.
+///
+/// Negative fees represent rebates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MakerTakerCommissionModel {
+    /// The maker fee in basis points.
+    pub maker_bps: Decimal,
+    /// The taker fee in basis points.
+    pub taker_bps: Decimal,
+}
+
+impl MakerTakerCommissionModel {
+    /// Creates a new [`MakerTakerCommissionModel`] instance.
+    #[must_use]
+    pub const fn new(maker_bps: Decimal, taker_bps: Decimal) -> Self {
+        Self {
+            maker_bps,
+            taker_bps,
+        }
+    }
+}
+
+impl CommissionModel for MakerTakerCommissionModel {
+    fn calculate_for_notional(
+        &self,
+        notional: Decimal,
+        _quantity: Quantity,
+        liquidity_side: LiquiditySide,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        let bps = side_bps(self.maker_bps, self.taker_bps, liquidity_side)?;
+        Ok(round_commission(notional * bps / BPS_DIVISOR, currency))
+    }
+}
+
+/// Provides a fixed commission per contract (unit of quantity), regardless of price
+/// and liquidity side.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PerContractCommissionModel {
+    /// The fee per contract in the settlement currency.
+    pub fee_per_contract: Decimal,
+}
+
+impl PerContractCommissionModel {
+    /// Creates a new [`PerContractCommissionModel`] instance.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `fee_per_contract` is negative.
+    pub fn new(fee_per_contract: Decimal) -> anyhow::Result<Self> {
+        if fee_per_contract.is_sign_negative() && !fee_per_contract.is_zero() {
+            anyhow::bail!(
+                "Invalid `fee_per_contract`, must be non-negative, was {fee_per_contract}"
+            );
+        }
+        Ok(Self { fee_per_contract })
+    }
+}
+
+impl CommissionModel for PerContractCommissionModel {
+    fn calculate_for_notional(
+        &self,
+        _notional: Decimal,
+        quantity: Quantity,
+        _liquidity_side: LiquiditySide,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        Ok(round_commission(
+            quantity.as_decimal() * self.fee_per_contract,
+            currency,
+        ))
+    }
+}
+
+/// Represents a tier of a [`TieredCommissionModel`] fee schedule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommissionTier {
+    /// The minimum traded volume (inclusive) for the tier to apply.
+    pub min_volume: Decimal,
+    /// The maker fee in basis points.
+    pub maker_bps: Decimal,
+    /// The taker fee in basis points.
+    pub taker_bps: Decimal,
+}
+
+impl CommissionTier {
+    /// Creates a new [`CommissionTier`] instance.
+    #[must_use]
+    pub const fn new(min_volume: Decimal, maker_bps: Decimal, taker_bps: Decimal) -> Self {
+        Self {
+            min_volume,
+            maker_bps,
+            taker_bps,
+        }
+    }
+}
+
+/// Provides maker/taker commissions in basis points from a schedule of tiers, where the
+/// applicable tier is the highest tier whose minimum volume has been reached.
+///
+/// The traded volume (typically over a trailing window, as defined by the venue) is set
+/// with [`TieredCommissionModel::set_volume`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TieredCommissionModel {
+    tiers: Vec<CommissionTier>,
+    volume: Decimal,
+}
+
+impl TieredCommissionModel {
+    /// Creates a new [`TieredCommissionModel`] instance with a traded volume of zero.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `tiers` is empty.
+    /// - If the first tier does not start at a volume of zero.
+    /// - If the tiers are not in strictly ascending order of minimum volume.
+    pub fn new(tiers: Vec<CommissionTier>) -> anyhow::Result<Self> {
+        let Some(first) = tiers.first() else {
+            anyhow::bail!("Invalid `tiers`, was empty");
+        };
+        if !first.min_volume.is_zero() {
+            anyhow::bail!(
+                "Invalid `tiers`, first tier must start at zero volume, was {}",
+                first.min_volume
+            );
+        }
+        if tiers.windows(2).any(|w| w[0].min_volume >= w[1].min_volume) {
+            anyhow::bail!("Invalid `tiers`, must be in strictly ascending order of `min_volume`");
+        }
+        Ok(Self {
+            tiers,
+            volume: Decimal::ZERO,
+        })
+    }
+
+    /// Returns the current traded volume.
+    #[must_use]
+    pub const fn volume(&self) -> Decimal {
+        self.volume
+    }
+
+    /// Sets the traded volume used to select the applicable tier.
+    pub fn set_volume(&mut self, volume: Decimal) {
+        self.volume = volume;
+    }
+
+    /// Returns the tier which applies for the current traded volume.
+    #[must_use]
+    pub fn tier(&self) -> &CommissionTier {
+        self.tiers
+            .iter()
+            .rev()
+            .find(|tier| tier.min_volume <= self.volume)
+            .unwrap_or(&self.tiers[0])
+    }
+}
+
+impl CommissionModel for TieredCommissionModel {
+    fn calculate_for_notional(
+        &self,
+        notional: Decimal,
+        _quantity: Quantity,
+        liquidity_side: LiquiditySide,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        let tier = self.tier();
+        let bps = side_bps(tier.maker_bps, tier.taker_bps, liquidity_side)?;
+        Ok(round_commission(notional * bps / BPS_DIVISOR, currency))
+    }
+}
+
+/// Wraps the available commission models.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CommissionModelAny {
+    MakerTaker(MakerTakerCommissionModel),
+    PerContract(PerContractCommissionModel),
+    Tiered(TieredCommissionModel),
+}
+
+impl CommissionModel for CommissionModelAny {
+    fn calculate_for_notional(
+        &self,
+        notional: Decimal,
+        quantity: Quantity,
+        liquidity_side: LiquiditySide,
+        currency: Currency,
+    ) -> anyhow::Result<Money> {
+        match self {
+            Self::MakerTaker(model) => {
+                model.calculate_for_notional(notional, quantity, liquidity_side, currency)
+            }
+            Self::PerContract(model) => {
+                model.calculate_for_notional(notional, quantity, liquidity_side, currency)
+            }
+            Self::Tiered(model) => {
+                model.calculate_for_notional(notional, quantity, liquidity_side, currency)
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::{currency_pair::CurrencyPair, stubs::audusd_sim};
+
+    #[fixture]
+    fn tiered() -> TieredCommissionModel {
+        TieredCommissionModel::new(vec![
+            CommissionTier::new(dec!(0), dec!(10), dec!(20)),
+            CommissionTier::new(dec!(1_000_000), dec!(8), dec!(15)),
+            CommissionTier::new(dec!(5_000_000), dec!(5), dec!(10)),
+        ])
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_maker_taker_with_instrument(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let model = MakerTakerCommissionModel::new(dec!(-0.5), dec!(2));
+        let quantity = Quantity::from(100_000);
+        let price = Price::from("0.80000");
+
+        let maker = model
+            .calculate(&instrument, quantity, price, LiquiditySide::Maker)
+            .unwrap();
+        let taker = model
+            .calculate(&instrument, quantity, price, LiquiditySide::Taker)
+            .unwrap();
+
+        assert_eq!(maker, Money::from("-4.00 USD"));
+        assert_eq!(taker, Money::from("16.00 USD"));
+    }
+
+    #[rstest]
+    fn test_maker_taker_with_no_liquidity_side(audusd_sim: CurrencyPair) {
+        let instrument = InstrumentAny::CurrencyPair(audusd_sim);
+        let model = MakerTakerCommissionModel::new(dec!(1), dec!(2));
+
+        let result = model.calculate(
+            &instrument,
+            Quantity::from(1),
+            Price::from("1.00000"),
+            LiquiditySide::NoLiquiditySide,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    // 201 * 10.00 * 5 bps = 1.005 exactly, which rounds down as an f64 (1.00499...)
+    #[case(201, "10.00", "1.01 USD")]
+    #[case(100, "10.00", "0.50 USD")]
+    #[case(1, "9.99", "0.00 USD")]
+    #[case(1, "10.00", "0.01 USD")]
+    #[case(3, "10.00", "0.02 USD")]
+    fn test_maker_taker_rounds_sub_cent_amounts_half_up(
+        #[case] quantity: i64,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let model = MakerTakerCommissionModel::new(dec!(0), dec!(5));
+        let notional = calculate_notional(
+            Quantity::from(quantity),
+            Price::from(price),
+            Quantity::from(1),
+            false,
+        )
+        .unwrap();
+
+        let commission = model
+            .calculate_for_notional(
+                notional,
+                Quantity::from(quantity),
+                LiquiditySide::Taker,
+                Currency::USD(),
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_maker_taker_inverse_in_base_currency() {
+        let model = MakerTakerCommissionModel::new(dec!(-2.5), dec!(7.5));
+        let notional = calculate_notional(
+            Quantity::from(100_000),
+            Price::from("10000.0"),
+            Quantity::from(1),
+            true,
+        )
+        .unwrap();
+
+        let commission = model
+            .calculate_for_notional(
+                notional,
+                Quantity::from(100_000),
+                LiquiditySide::Taker,
+                Currency::BTC(),
+            )
+            .unwrap();
+
+        assert_eq!(notional, dec!(10));
+        assert_eq!(commission, Money::from("0.00750000 BTC"));
+    }
+
+    #[rstest]
+    fn test_inverse_notional_with_zero_price() {
+        let result = calculate_notional(
+            Quantity::from(1),
+            Price::from("0.0"),
+            Quantity::from(1),
+            true,
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case("0.85", 3, "2.55 USD")]
+    #[case("0.0125", 3, "0.04 USD")] // 0.0375 rounds half up
+    #[case("0.0125", 2, "0.03 USD")] // 0.025 rounds half up
+    #[case("0", 10, "0.00 USD")]
+    fn test_per_contract(#[case] fee: &str, #[case] quantity: i64, #[case] expected: &str) {
+        let model = PerContractCommissionModel::new(fee.parse().unwrap()).unwrap();
+
+        let commission = model
+            .calculate_for_notional(
+                dec!(1_000_000),
+                Quantity::from(quantity),
+                LiquiditySide::Maker,
+                Currency::USD(),
+            )
+            .unwrap();
+
+        assert_eq!(commission, Money::from(expected));
+    }
+
+    #[rstest]
+    fn test_per_contract_with_negative_fee() {
+        assert!(PerContractCommissionModel::new(dec!(-0.01)).is_err());
+    }
+
+    #[rstest]
+    #[case(dec!(0), dec!(20))]
+    #[case(dec!(999_999.99), dec!(20))]
+    #[case(dec!(1_000_000), dec!(15))]
+    #[case(dec!(4_999_999.99), dec!(15))]
+    #[case(dec!(5_000_000), dec!(10))]
+    #[case(dec!(50_000_000), dec!(10))]
+    fn test_tiered_selects_tier_at_boundary_volumes(
+        mut tiered: TieredCommissionModel,
+        #[case] volume: Decimal,
+        #[case] expected_taker_bps: Decimal,
+    ) {
+        tiered.set_volume(volume);
+
+        assert_eq!(tiered.tier().taker_bps, expected_taker_bps);
+    }
+
+    #[rstest]
+    fn test_tiered_commission(mut tiered: TieredCommissionModel) {
+        let calculate = |model: &TieredCommissionModel, side| {
+            model
+                .calculate_for_notional(dec!(10_000), Quantity::from(1), side, Currency::USD())
+                .unwrap()
+        };
+
+        assert_eq!(
+            calculate(&tiered, LiquiditySide::Taker),
+            Money::from("20.00 USD")
+        );
+        tiered.set_volume(dec!(1_000_000));
+        assert_eq!(
+            calculate(&tiered, LiquiditySide::Taker),
+            Money::from("15.00 USD")
+        );
+        assert_eq!(
+            calculate(&tiered, LiquiditySide::Maker),
+            Money::from("8.00 USD")
+        );
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec![CommissionTier::new(dec!(100), dec!(1), dec!(2))])]
+    #[case(vec![
+        CommissionTier::new(dec!(0), dec!(1), dec!(2)),
+        CommissionTier::new(dec!(0), dec!(1), dec!(2)),
+    ])]
+    fn test_tiered_with_invalid_tiers(#[case] tiers: Vec<CommissionTier>) {
+        assert!(TieredCommissionModel::new(tiers).is_err());
+    }
+}
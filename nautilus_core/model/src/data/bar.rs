@@ -362,6 +362,15 @@ impl FromStr for BarType {
             })?;
 
         if let Some(composite_str) = composite_str {
+            // Composite bars are always aggregated internally from the composite component
+            if aggregation_source != AggregationSource::Internal {
+                return Err(BarTypeParseError {
+                    input: s.to_string(),
+                    token: rev_pieces[4].to_string(),
+                    position: 4,
+                });
+            }
+
             let composite_pieces: Vec<&str> = composite_str.rsplitn(3, '-').collect();
             let rev_composite_pieces: Vec<&str> = composite_pieces.into_iter().rev().collect();
             if rev_composite_pieces.len() != 3 {
@@ -590,6 +599,8 @@ impl GetTsInit for Bar {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+
     use chrono::TimeZone;
     use rstest::rstest;
 
@@ -984,6 +995,59 @@ mod tests {
         assert_ne!(bar_type1, bar_type3);
     }
 
+    #[rstest]
+    fn test_bar_type_parse_composite_with_external_source() {
+        let input = "BTCUSDT-PERP.BINANCE-5-MINUTE-LAST-EXTERNAL@1-MINUTE-EXTERNAL";
+        let result = BarType::from_str(input);
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!(
+                "Error parsing `BarType` from '{input}', invalid token: 'EXTERNAL' at position 4"
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_composite_string_round_trip() {
+        let input = "AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL";
+        let bar_type = BarType::from(input);
+
+        assert_eq!(bar_type.to_string(), input);
+        assert_eq!(
+            bar_type.composite().to_string(),
+            "AUD/USD.SIM-1-MINUTE-BID-EXTERNAL"
+        );
+        assert_eq!(
+            bar_type.standard().to_string(),
+            "AUD/USD.SIM-5-MINUTE-BID-INTERNAL"
+        );
+    }
+
+    #[rstest]
+    fn test_bar_type_composite_equality_and_hash() {
+        let composite = BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL");
+        let same = BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-EXTERNAL");
+        let other_source = BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@1-MINUTE-INTERNAL");
+        let other_step = BarType::from("AUD/USD.SIM-5-MINUTE-BID-INTERNAL@5-SECOND-EXTERNAL");
+
+        // The composite component is part of the identity, so a composite bar type differs
+        // from its standard component with the same specification
+        assert_eq!(composite, same);
+        assert_ne!(composite, other_source);
+        assert_ne!(composite, other_step);
+        assert_ne!(composite, composite.standard());
+
+        let bar_types: HashSet<BarType> = HashSet::from([
+            composite,
+            same,
+            other_source,
+            other_step,
+            composite.standard(),
+        ]);
+        assert_eq!(bar_types.len(), 4);
+    }
+
     #[rstest]
     fn test_bar_type_comparison() {
         let instrument_id1 = InstrumentId {
@@ -0,0 +1,599 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Stitching of individual futures contract bar series into a continuous (back-adjusted) series.
+
+use nautilus_core::{datetime::NANOSECONDS_IN_DAY, nanos::UnixNanos};
+
+use super::bar::{Bar, BarType};
+use crate::{identifiers::InstrumentId, types::price::Price};
+
+/// The rule determining when a continuous series rolls from the front contract to the next.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum RollRule {
+    /// Roll a fixed number of calendar days before the front contract expires.
+    DaysBeforeExpiry = 1,
+    /// Roll at the first bar where the next contract trades more volume than the front
+    /// contract (at the same timestamp), otherwise at expiration of the front contract.
+    VolumeCrossover = 2,
+}
+
+/// The method used to back-adjust prices prior to each roll.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum AdjustmentMethod {
+    /// Shift prior prices by the price difference between the contracts at the roll.
+    Difference = 1,
+    /// Scale prior prices by the price ratio between the contracts at the roll.
+    Ratio = 2,
+}
+
+/// Represents a roll from one contract to the next within a continuous series.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RollEvent {
+    /// UNIX timestamp (nanoseconds) of the first bar taken from the new contract.
+    pub ts_roll: UnixNanos,
+    /// The contract rolled out of.
+    pub from_instrument_id: InstrumentId,
+    /// The contract rolled into.
+    pub to_instrument_id: InstrumentId,
+    /// The (unadjusted) close of the last bar taken from the old contract.
+    pub from_price: Price,
+    /// The (unadjusted) close of the new contract used as the reference for the adjustment.
+    pub to_price: Price,
+    /// The adjustment applied to all prior prices (a difference or ratio, per the method).
+    pub adjustment: f64,
+}
+
+/// The output of a [`ContinuousContractBuilder`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ContinuousSeries {
+    /// The back-adjusted continuous bars.
+    pub bars: Vec<Bar>,
+    /// The roll schedule, in time order.
+    pub rolls: Vec<RollEvent>,
+}
+
+#[derive(Clone, Debug)]
+struct ContractSeries {
+    instrument_id: InstrumentId,
+    expiration_ns: UnixNanos,
+    bars: Vec<Bar>,
+}
+
+impl ContractSeries {
+    fn bar_at(&self, ts_event: UnixNanos) -> Option<&Bar> {
+        self.bars
+            .binary_search_by_key(&ts_event, |bar| bar.ts_event)
+            .ok()
+            .map(|index| &self.bars[index])
+    }
+}
+
+/// Builds a continuous bar series from the bars of individual futures contracts.
+///
+/// Each contract contributes its bars from the previous roll (inclusive) up to its own roll
+/// (exclusive), so any overlapping data between contracts is only taken from one of them.
+/// Prices prior to each roll are back-adjusted, so the most recent contract remains unadjusted.
+#[derive(Clone, Debug)]
+pub struct ContinuousContractBuilder {
+    bar_type: BarType,
+    roll_rule: RollRule,
+    roll_days: u32,
+    adjustment_method: AdjustmentMethod,
+    contracts: Vec<ContractSeries>,
+}
+
+impl ContinuousContractBuilder {
+    /// Creates a new [`ContinuousContractBuilder`] instance.
+    ///
+    /// The `bar_type` is assigned to the output bars, and `roll_days` only applies to the
+    /// [`RollRule::DaysBeforeExpiry`] rule.
+    #[must_use]
+    pub fn new(
+        bar_type: BarType,
+        roll_rule: RollRule,
+        roll_days: u32,
+        adjustment_method: AdjustmentMethod,
+    ) -> Self {
+        Self {
+            bar_type,
+            roll_rule,
+            roll_days,
+            adjustment_method,
+            contracts: Vec::new(),
+        }
+    }
+
+    /// Adds the bars for a single contract expiring at `expiration_ns`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a contract with the same `instrument_id` has already been added.
+    /// - If any bar is not for `instrument_id`.
+    /// - If the bars are not in strictly ascending `ts_event` order.
+    pub fn add_contract(
+        &mut self,
+        instrument_id: InstrumentId,
+        expiration_ns: UnixNanos,
+        bars: Vec<Bar>,
+    ) -> anyhow::Result<()> {
+        if self
+            .contracts
+            .iter()
+            .any(|contract| contract.instrument_id == instrument_id)
+        {
+            anyhow::bail!("Contract {instrument_id} already added");
+        }
+        if let Some(bar) = bars
+            .iter()
+            .find(|bar| bar.bar_type.instrument_id() != instrument_id)
+        {
+            anyhow::bail!(
+                "Bar for {} does not match contract {instrument_id}",
+                bar.bar_type.instrument_id()
+            );
+        }
+        if bars
+            .windows(2)
+            .any(|pair| pair[0].ts_event >= pair[1].ts_event)
+        {
+            anyhow::bail!("Bars for {instrument_id} not in ascending `ts_event` order");
+        }
+
+        self.contracts.push(ContractSeries {
+            instrument_id,
+            expiration_ns,
+            bars,
+        });
+        Ok(())
+    }
+
+    /// Builds the back-adjusted continuous series and roll schedule from the added contracts.
+    ///
+    /// Contracts are ordered by expiration, and a contract with no bars before its roll is
+    /// skipped entirely. The adjustment at each roll compares the close of the last bar taken
+    /// from the old contract against the new contract's bar at the same timestamp, falling
+    /// back to the first bar taken from the new contract if there is no overlapping bar.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If no contracts have been added.
+    /// - If using [`AdjustmentMethod::Ratio`] and a reference close is not positive.
+    pub fn build(&self) -> anyhow::Result<ContinuousSeries> {
+        if self.contracts.is_empty() {
+            anyhow::bail!("No contracts added to build continuous series");
+        }
+
+        let mut contracts: Vec<&ContractSeries> = self.contracts.iter().collect();
+        contracts.sort_by_key(|contract| contract.expiration_ns);
+
+        let mut segments: Vec<(&ContractSeries, &[Bar])> = Vec::with_capacity(contracts.len());
+        let mut start = UnixNanos::default();
+        for (i, contract) in contracts.iter().enumerate() {
+            let end = match contracts.get(i + 1) {
+                Some(next) => self.roll_time(contract, next, start).max(start),
+                None => UnixNanos::from(u64::MAX),
+            };
+            let first = contract.bars.partition_point(|bar| bar.ts_event < start);
+            let last = contract.bars.partition_point(|bar| bar.ts_event < end);
+            if first < last {
+                segments.push((contract, &contract.bars[first..last]));
+            }
+            start = end;
+        }
+
+        let mut rolls = Vec::with_capacity(segments.len().saturating_sub(1));
+        for pair in segments.windows(2) {
+            let (from, from_bars) = pair[0];
+            let (to, to_bars) = pair[1];
+            let from_bar = from_bars[from_bars.len() - 1];
+            let to_bar = to.bar_at(from_bar.ts_event).unwrap_or(&to_bars[0]);
+            let from_close = from_bar.close.as_f64();
+            let to_close = to_bar.close.as_f64();
+
+            let adjustment = match self.adjustment_method {
+                AdjustmentMethod::Difference => to_close - from_close,
+                AdjustmentMethod::Ratio => {
+                    if from_close <= 0.0 || to_close <= 0.0 {
+                        anyhow::bail!(
+                            "Cannot ratio adjust roll from {} to {} with non-positive close",
+                            from.instrument_id,
+                            to.instrument_id,
+                        );
+                    }
+                    to_close / from_close
+                }
+            };
+
+            rolls.push(RollEvent {
+                ts_roll: to_bars[0].ts_event,
+                from_instrument_id: from.instrument_id,
+                to_instrument_id: to.instrument_id,
+                from_price: from_bar.close,
+                to_price: to_bar.close,
+                adjustment,
+            });
+        }
+
+        let mut bars = Vec::with_capacity(segments.iter().map(|(_, bars)| bars.len()).sum());
+        for (i, (_, segment_bars)) in segments.iter().enumerate() {
+            let adjustment = self.cumulative_adjustment(&rolls[i..]);
+            bars.extend(
+                segment_bars
+                    .iter()
+                    .map(|bar| self.adjust_bar(bar, adjustment)),
+            );
+        }
+
+        Ok(ContinuousSeries { bars, rolls })
+    }
+
+    fn roll_time(
+        &self,
+        front: &ContractSeries,
+        next: &ContractSeries,
+        start: UnixNanos,
+    ) -> UnixNanos {
+        match self.roll_rule {
+            RollRule::DaysBeforeExpiry => UnixNanos::from(
+                front
+                    .expiration_ns
+                    .as_u64()
+                    .saturating_sub(u64::from(self.roll_days) * NANOSECONDS_IN_DAY),
+            ),
+            RollRule::VolumeCrossover => next
+                .bars
+                .iter()
+                .filter(|bar| bar.ts_event >= start && bar.ts_event < front.expiration_ns)
+                .find(|bar| {
+                    front
+                        .bar_at(bar.ts_event)
+                        .is_some_and(|front_bar| bar.volume > front_bar.volume)
+                })
+                .map_or(front.expiration_ns, |bar| bar.ts_event),
+        }
+    }
+
+    fn cumulative_adjustment(&self, rolls: &[RollEvent]) -> f64 {
+        match self.adjustment_method {
+            AdjustmentMethod::Difference => rolls.iter().map(|roll| roll.adjustment).sum(),
+            AdjustmentMethod::Ratio => rolls.iter().map(|roll| roll.adjustment).product(),
+        }
+    }
+
+    fn adjust_price(&self, price: Price, adjustment: f64) -> Price {
+        let value = match self.adjustment_method {
+            AdjustmentMethod::Difference => price.as_f64() + adjustment,
+            AdjustmentMethod::Ratio => price.as_f64() * adjustment,
+        };
+        Price::new(value, price.precision)
+    }
+
+    fn adjust_bar(&self, bar: &Bar, adjustment: f64) -> Bar {
+        Bar {
+            bar_type: self.bar_type,
+            open: self.adjust_price(bar.open, adjustment),
+            high: self.adjust_price(bar.high, adjustment),
+            low: self.adjust_price(bar.low, adjustment),
+            close: self.adjust_price(bar.close, adjustment),
+            ..*bar
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::quantity::Quantity;
+
+    const CONTINUOUS_BAR_TYPE: &str = "ES.GLBX-1-DAY-LAST-EXTERNAL";
+
+    fn day(n: u64) -> UnixNanos {
+        UnixNanos::from(n * NANOSECONDS_IN_DAY)
+    }
+
+    fn bar(instrument_id: &str, n: u64, close: f64, volume: u64) -> Bar {
+        Bar::new(
+            BarType::from(format!("{instrument_id}-1-DAY-LAST-EXTERNAL").as_str()),
+            Price::new(close, 2),
+            Price::new(close + 1.0, 2),
+            Price::new(close - 1.0, 2),
+            Price::new(close, 2),
+            Quantity::new(volume as f64, 0),
+            day(n),
+            day(n),
+        )
+    }
+
+    fn bars(
+        instrument_id: &str,
+        days: std::ops::RangeInclusive<u64>,
+        close: fn(u64) -> f64,
+    ) -> Vec<Bar> {
+        days.map(|n| bar(instrument_id, n, close(n), 100)).collect()
+    }
+
+    fn builder(
+        roll_rule: RollRule,
+        roll_days: u32,
+        method: AdjustmentMethod,
+    ) -> ContinuousContractBuilder {
+        ContinuousContractBuilder::new(
+            BarType::from(CONTINUOUS_BAR_TYPE),
+            roll_rule,
+            roll_days,
+            method,
+        )
+    }
+
+    fn closes(series: &ContinuousSeries) -> Vec<f64> {
+        series.bars.iter().map(|bar| bar.close.as_f64()).collect()
+    }
+
+    #[rstest]
+    fn test_difference_adjustment_with_overlapping_contracts() {
+        let mut builder = builder(RollRule::DaysBeforeExpiry, 3, AdjustmentMethod::Difference);
+        // Added out of expiry order, with each contract overlapping the next
+        builder
+            .add_contract(
+                InstrumentId::from("ESM5.GLBX"),
+                day(20),
+                bars("ESM5.GLBX", 5..=19, |n| 104.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(10),
+                bars("ESH5.GLBX", 1..=9, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESU5.GLBX"),
+                day(30),
+                bars("ESU5.GLBX", 15..=29, |n| 110.0 + n as f64),
+            )
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        // One bar per day with no duplicates from the overlaps, and continuous at each roll
+        let ts_events: Vec<UnixNanos> = series.bars.iter().map(|bar| bar.ts_event).collect();
+        assert_eq!(ts_events, (1..=29).map(day).collect::<Vec<_>>());
+        assert_eq!(
+            closes(&series),
+            (1..=29).map(|n| 110.0 + n as f64).collect::<Vec<_>>()
+        );
+        assert_eq!(series.bars[0].high, Price::from("112.00"));
+        assert_eq!(series.bars[0].low, Price::from("110.00"));
+        assert!(series
+            .bars
+            .iter()
+            .all(|bar| bar.bar_type == BarType::from(CONTINUOUS_BAR_TYPE)));
+
+        assert_eq!(
+            series.rolls,
+            vec![
+                RollEvent {
+                    ts_roll: day(7),
+                    from_instrument_id: InstrumentId::from("ESH5.GLBX"),
+                    to_instrument_id: InstrumentId::from("ESM5.GLBX"),
+                    from_price: Price::from("105.00"),
+                    to_price: Price::from("110.00"),
+                    adjustment: 5.0,
+                },
+                RollEvent {
+                    ts_roll: day(17),
+                    from_instrument_id: InstrumentId::from("ESM5.GLBX"),
+                    to_instrument_id: InstrumentId::from("ESU5.GLBX"),
+                    from_price: Price::from("120.00"),
+                    to_price: Price::from("126.00"),
+                    adjustment: 6.0,
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_ratio_adjustment() {
+        let mut builder = builder(RollRule::DaysBeforeExpiry, 3, AdjustmentMethod::Ratio);
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(10),
+                bars("ESH5.GLBX", 1..=9, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESM5.GLBX"),
+                day(20),
+                bars("ESM5.GLBX", 5..=19, |n| 2.0 * (99.0 + n as f64)),
+            )
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        assert_eq!(
+            closes(&series),
+            (1..=19)
+                .map(|n| 2.0 * (99.0 + n as f64))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(series.bars[0].high, Price::from("202.00"));
+        assert_eq!(series.rolls.len(), 1);
+        assert_eq!(series.rolls[0].ts_roll, day(7));
+        assert_eq!(series.rolls[0].adjustment, 2.0);
+    }
+
+    #[rstest]
+    fn test_adjustment_without_overlapping_bar_uses_first_new_contract_bar() {
+        let mut builder = builder(RollRule::DaysBeforeExpiry, 3, AdjustmentMethod::Difference);
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(10),
+                bars("ESH5.GLBX", 1..=6, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESM5.GLBX"),
+                day(20),
+                bars("ESM5.GLBX", 8..=12, |n| 200.0 + n as f64),
+            )
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        assert_eq!(series.rolls[0].ts_roll, day(8));
+        assert_eq!(series.rolls[0].from_price, Price::from("105.00"));
+        assert_eq!(series.rolls[0].to_price, Price::from("208.00"));
+        assert_eq!(series.rolls[0].adjustment, 103.0);
+        assert_eq!(series.bars[5].close, Price::from("208.00"));
+        assert_eq!(series.bars[6].close, Price::from("208.00"));
+    }
+
+    #[rstest]
+    fn test_volume_crossover_roll() {
+        let mut builder = builder(RollRule::VolumeCrossover, 0, AdjustmentMethod::Difference);
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(10),
+                bars("ESH5.GLBX", 1..=9, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        let next_bars = (3..=12)
+            .map(|n| {
+                let volume = if n < 6 { 50 } else { 150 };
+                bar("ESM5.GLBX", n, 101.0 + n as f64, volume)
+            })
+            .collect();
+        builder
+            .add_contract(InstrumentId::from("ESM5.GLBX"), day(20), next_bars)
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        assert_eq!(series.rolls.len(), 1);
+        assert_eq!(series.rolls[0].ts_roll, day(6));
+        assert_eq!(series.rolls[0].adjustment, 2.0);
+        assert_eq!(series.bars.len(), 12);
+        assert_eq!(
+            closes(&series),
+            (1..=12).map(|n| 101.0 + n as f64).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn test_volume_crossover_falls_back_to_expiry() {
+        let mut builder = builder(RollRule::VolumeCrossover, 0, AdjustmentMethod::Difference);
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(5),
+                bars("ESH5.GLBX", 1..=7, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESM5.GLBX"),
+                day(20),
+                bars("ESM5.GLBX", 3..=9, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        assert_eq!(series.rolls[0].ts_roll, day(5));
+        assert_eq!(series.rolls[0].adjustment, 0.0);
+        assert_eq!(series.bars.len(), 9);
+    }
+
+    #[rstest]
+    fn test_contract_with_no_bars_before_roll_is_skipped() {
+        let mut builder = builder(RollRule::DaysBeforeExpiry, 10, AdjustmentMethod::Difference);
+        builder
+            .add_contract(
+                InstrumentId::from("ESH5.GLBX"),
+                day(10),
+                bars("ESH5.GLBX", 1..=9, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        builder
+            .add_contract(
+                InstrumentId::from("ESM5.GLBX"),
+                day(20),
+                bars("ESM5.GLBX", 5..=9, |n| 104.0 + n as f64),
+            )
+            .unwrap();
+
+        let series = builder.build().unwrap();
+
+        assert!(series.rolls.is_empty());
+        assert_eq!(
+            closes(&series),
+            (5..=9).map(|n| 104.0 + n as f64).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn test_add_contract_validation() {
+        let mut builder = builder(RollRule::DaysBeforeExpiry, 3, AdjustmentMethod::Difference);
+        let instrument_id = InstrumentId::from("ESH5.GLBX");
+
+        let mut unordered = bars("ESH5.GLBX", 1..=3, |n| 99.0 + n as f64);
+        unordered.swap(0, 2);
+        assert!(builder
+            .add_contract(instrument_id, day(10), unordered)
+            .is_err());
+        assert!(builder
+            .add_contract(
+                instrument_id,
+                day(10),
+                bars("ESM5.GLBX", 1..=3, |n| n as f64)
+            )
+            .is_err());
+
+        builder
+            .add_contract(
+                instrument_id,
+                day(10),
+                bars("ESH5.GLBX", 1..=3, |n| 99.0 + n as f64),
+            )
+            .unwrap();
+        assert!(builder
+            .add_contract(instrument_id, day(10), Vec::new())
+            .is_err());
+    }
+
+    #[rstest]
+    fn test_build_with_no_contracts() {
+        let builder = builder(RollRule::DaysBeforeExpiry, 3, AdjustmentMethod::Difference);
+        assert!(builder.build().is_err());
+    }
+}
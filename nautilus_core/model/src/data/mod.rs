@@ -16,6 +16,7 @@
 //! Data types for the trading domain model.
 
 pub mod bar;
+pub mod continuous;
 pub mod delta;
 pub mod deltas;
 pub mod depth;
@@ -78,6 +79,18 @@ impl Data {
         }
     }
 
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    pub fn ts_event(&self) -> UnixNanos {
+        match self {
+            Self::Delta(delta) => delta.ts_event,
+            Self::Deltas(deltas) => deltas.ts_event,
+            Self::Depth10(depth) => depth.ts_event,
+            Self::Quote(quote) => quote.ts_event,
+            Self::Trade(trade) => trade.ts_event,
+            Self::Bar(bar) => bar.ts_event,
+        }
+    }
+
     /// Returns whether the data is a type of order book data.
     pub fn is_order_book_data(&self) -> bool {
         matches!(self, Self::Delta(_) | Self::Deltas(_) | Self::Depth10(_))
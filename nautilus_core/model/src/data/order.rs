@@ -31,6 +31,15 @@ use crate::{
 
 pub type OrderId = u64;
 
+/// Book order flag indicating implied liquidity (derived from other instruments, such as spread legs).
+pub const BOOK_ORDER_FLAG_IMPLIED: u8 = 1 << 0;
+
+/// Book order flag indicating the order is only partially displayed (e.g. an iceberg order).
+pub const BOOK_ORDER_FLAG_HIDDEN: u8 = 1 << 1;
+
+/// Book order flag indicating the order is synthetic (not resting on the venue).
+pub const BOOK_ORDER_FLAG_SYNTHETIC: u8 = 1 << 2;
+
 /// Represents a NULL book order (used with the `Clear` action or where an order is not specified).
 pub const NULL_ORDER: BookOrder = BookOrder {
     side: OrderSide::NoOrderSide,
@@ -43,6 +52,7 @@ pub const NULL_ORDER: BookOrder = BookOrder {
         precision: 0,
     },
     order_id: 0,
+    flags: 0,
 };
 
 /// Represents an order in a book.
@@ -62,6 +72,9 @@ pub struct BookOrder {
     pub size: Quantity,
     /// The order ID.
     pub order_id: OrderId,
+    /// The order flags bit field (see the `BOOK_ORDER_FLAG_*` constants).
+    #[serde(default)]
+    pub flags: u8,
 }
 
 impl BookOrder {
@@ -73,9 +86,23 @@ impl BookOrder {
             price,
             size,
             order_id,
+            flags: 0,
         }
     }
 
+    /// Returns a copy of this order with the given `flags` bit field.
+    #[must_use]
+    pub const fn with_flags(mut self, flags: u8) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Returns whether all bits of the given `flag` are set for this order.
+    #[must_use]
+    pub const fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag == flag
+    }
+
     /// Returns a [`BookPrice`] from this order.
     #[must_use]
     pub fn to_book_price(&self) -> BookPrice {
@@ -233,4 +260,34 @@ mod tests {
         let expected = "BUY,100.00,10,123456";
         assert_eq!(result, expected);
     }
+
+    #[rstest]
+    fn test_flags() {
+        let order = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 1);
+        assert_eq!(order.flags, 0);
+        assert!(!order.has_flag(BOOK_ORDER_FLAG_IMPLIED));
+
+        let order = order.with_flags(BOOK_ORDER_FLAG_IMPLIED | BOOK_ORDER_FLAG_HIDDEN);
+        assert!(order.has_flag(BOOK_ORDER_FLAG_IMPLIED));
+        assert!(order.has_flag(BOOK_ORDER_FLAG_HIDDEN));
+        assert!(!order.has_flag(BOOK_ORDER_FLAG_SYNTHETIC));
+        assert!(!order.has_flag(BOOK_ORDER_FLAG_IMPLIED | BOOK_ORDER_FLAG_SYNTHETIC));
+    }
+
+    #[rstest]
+    fn test_json_serialization_with_flags() {
+        let order = BookOrder::new(OrderSide::Buy, Price::from("100.00"), Quantity::from(10), 1)
+            .with_flags(BOOK_ORDER_FLAG_HIDDEN);
+        let serialized = order.as_json_bytes().unwrap();
+        let deserialized = BookOrder::from_json_bytes(serialized.as_ref()).unwrap();
+        assert_eq!(deserialized.flags, BOOK_ORDER_FLAG_HIDDEN);
+    }
+
+    #[rstest]
+    fn test_json_deserialization_without_flags() {
+        let json = r#"{"side":"BUY","price":"100.00","size":"10","order_id":1}"#;
+        let order = BookOrder::from_json_bytes(json.as_bytes()).unwrap();
+        assert_eq!(order.order_id, 1);
+        assert_eq!(order.flags, 0);
+    }
 }
@@ -35,7 +35,12 @@ use super::GetTsInit;
 use crate::{
     enums::PriceType,
     identifiers::InstrumentId,
-    types::{fixed::FIXED_PRECISION, price::Price, quantity::Quantity},
+    types::{
+        currency::Currency,
+        fixed::{f64_to_fixed_i64, FIXED_PRECISION},
+        price::Price,
+        quantity::Quantity,
+    },
 };
 
 /// Represents a single quote tick in a market.
@@ -186,6 +191,275 @@ impl QuoteTick {
     }
 }
 
+impl QuoteTick {
+    /// Returns the quote inverted for the pair with the base and quote currencies swapped
+    /// (e.g. USD/EUR from EUR/USD), for the given `instrument_id`.
+    ///
+    /// The inverted bid is the reciprocal of the ask (and vice versa), with sizes converted into
+    /// the new base currency. Prices keep the number of significant digits of the original bid
+    /// price, with the bid rounded down and the ask rounded up (e.g. the inverse of a `1.2345`
+    /// bid is a `0.81005` ask).
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If the bid or ask price is not positive.
+    #[must_use]
+    pub fn invert(&self, instrument_id: InstrumentId) -> Self {
+        assert!(
+            self.bid_price.raw > 0 && self.ask_price.raw > 0,
+            "Cannot invert quote with non-positive prices, was {self}"
+        );
+        let leg = QuoteLeg::from(self).inverted();
+        let precision = precision_for_significant_digits(
+            leg.mid(),
+            significant_digits(self.bid_price.as_f64(), self.bid_price.precision),
+        );
+        leg.into_quote(
+            instrument_id,
+            precision,
+            self.bid_size.precision.min(self.ask_size.precision),
+            self.ts_event,
+            self.ts_init,
+        )
+    }
+}
+
+/// Derives a quote for the `target` currency pair (base, quote) from quotes `a` and `b` for two
+/// currency pairs which share a currency, e.g. EUR/JPY from EUR/USD and USD/JPY.
+///
+/// Each quote is given with the (base, quote) currencies of its pair, and is inverted as
+/// required, so EUR/GBP can also be derived from EUR/USD and GBP/USD. The cross bid is the
+/// product of the bids of each leg (and likewise for the ask), with sizes limited by the
+/// liquidity of both legs.
+///
+/// As for [`QuoteTick::invert`], the cross prices keep the significant digits of the least
+/// precise of the quotes, with the bid rounded down and the ask rounded up.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the pairs of `a` and `b` do not share a currency.
+/// - If the `target` pair cannot be derived through the shared currency.
+/// - If any bid or ask price is not positive.
+pub fn cross(
+    a: &QuoteTick,
+    a_pair: (Currency, Currency),
+    b: &QuoteTick,
+    b_pair: (Currency, Currency),
+    instrument_id: InstrumentId,
+    target: (Currency, Currency),
+) -> anyhow::Result<QuoteTick> {
+    check_positive_prices(a)?;
+    check_positive_prices(b)?;
+
+    let Some(shared) = shared_currency(a_pair, b_pair) else {
+        anyhow::bail!(
+            "No shared currency between {}/{} and {}/{}",
+            a_pair.0,
+            a_pair.1,
+            b_pair.0,
+            b_pair.1
+        );
+    };
+
+    let (base, quote) = target;
+    let legs = if other_currency(a_pair, shared) == Some(base)
+        && other_currency(b_pair, shared) == Some(quote)
+    {
+        ((a, a_pair), (b, b_pair))
+    } else if other_currency(b_pair, shared) == Some(base)
+        && other_currency(a_pair, shared) == Some(quote)
+    {
+        ((b, b_pair), (a, a_pair))
+    } else {
+        anyhow::bail!(
+            "Cannot derive {base}/{quote} from {}/{} and {}/{} through {shared}",
+            a_pair.0,
+            a_pair.1,
+            b_pair.0,
+            b_pair.1
+        );
+    };
+
+    // Express the first leg as base/shared and the second as shared/quote
+    let ((q1, pair1), (q2, pair2)) = legs;
+    let leg1 = QuoteLeg::from(q1).with_base(pair1, base);
+    let leg2 = QuoteLeg::from(q2).with_base(pair2, shared);
+
+    let leg = QuoteLeg {
+        bid: leg1.bid * leg2.bid,
+        ask: leg1.ask * leg2.ask,
+        // The second leg sizes are in the shared currency, so convert into the base currency
+        bid_size: leg1.bid_size.min(leg2.bid_size / leg1.bid),
+        ask_size: leg1.ask_size.min(leg2.ask_size / leg1.ask),
+    };
+
+    let precision = precision_for_significant_digits(
+        leg.mid(),
+        significant_digits(a.bid_price.as_f64(), a.bid_price.precision).min(significant_digits(
+            b.bid_price.as_f64(),
+            b.bid_price.precision,
+        )),
+    );
+    Ok(leg.into_quote(
+        instrument_id,
+        precision,
+        a.bid_size.precision.min(b.bid_size.precision),
+        a.ts_event.max(b.ts_event),
+        a.ts_init.max(b.ts_init),
+    ))
+}
+
+/// The prices and sizes of a quote as floats, for deriving inverted and cross quotes.
+#[derive(Clone, Copy, Debug)]
+struct QuoteLeg {
+    bid: f64,
+    ask: f64,
+    bid_size: f64,
+    ask_size: f64,
+}
+
+impl QuoteLeg {
+    /// Returns the leg for the pair with the base and quote currencies swapped.
+    fn inverted(self) -> Self {
+        Self {
+            bid: 1.0 / self.ask,
+            ask: 1.0 / self.bid,
+            bid_size: self.ask_size * self.ask,
+            ask_size: self.bid_size * self.bid,
+        }
+    }
+
+    /// Returns the leg for the `pair` expressed with `base` as the base currency.
+    fn with_base(self, pair: (Currency, Currency), base: Currency) -> Self {
+        if pair.0 == base {
+            self
+        } else {
+            self.inverted()
+        }
+    }
+
+    fn mid(self) -> f64 {
+        PriceType::Mid.select(self.bid, self.ask)
+    }
+
+    fn into_quote(
+        self,
+        instrument_id: InstrumentId,
+        price_precision: u8,
+        size_precision: u8,
+        ts_event: UnixNanos,
+        ts_init: UnixNanos,
+    ) -> QuoteTick {
+        QuoteTick::new(
+            instrument_id,
+            Price::from_raw(
+                round_raw(
+                    f64_to_fixed_i64(self.bid, FIXED_PRECISION),
+                    price_precision,
+                    false,
+                ),
+                price_precision,
+            ),
+            Price::from_raw(
+                round_raw(
+                    f64_to_fixed_i64(self.ask, FIXED_PRECISION),
+                    price_precision,
+                    true,
+                ),
+                price_precision,
+            ),
+            Quantity::new(self.bid_size, size_precision),
+            Quantity::new(self.ask_size, size_precision),
+            ts_event,
+            ts_init,
+        )
+    }
+}
+
+impl From<&QuoteTick> for QuoteLeg {
+    fn from(quote: &QuoteTick) -> Self {
+        Self {
+            bid: quote.bid_price.as_f64(),
+            ask: quote.ask_price.as_f64(),
+            bid_size: quote.bid_size.as_f64(),
+            ask_size: quote.ask_size.as_f64(),
+        }
+    }
+}
+
+fn check_positive_prices(quote: &QuoteTick) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        quote.bid_price.raw > 0 && quote.ask_price.raw > 0,
+        "Quote prices must be positive, was {quote}"
+    );
+    Ok(())
+}
+
+fn shared_currency(a: (Currency, Currency), b: (Currency, Currency)) -> Option<Currency> {
+    [a.0, a.1].into_iter().find(|c| *c == b.0 || *c == b.1)
+}
+
+fn other_currency(pair: (Currency, Currency), currency: Currency) -> Option<Currency> {
+    if pair.0 == currency {
+        Some(pair.1)
+    } else if pair.1 == currency {
+        Some(pair.0)
+    } else {
+        None
+    }
+}
+
+/// Returns the number of significant digits of the positive `value` at the given `precision`.
+fn significant_digits(value: f64, precision: u8) -> i32 {
+    i32::from(precision) + value.log10().floor() as i32 + 1
+}
+
+/// Returns the precision for the positive `value` to have the given number of significant
+/// `digits`, clamped to the valid range of precisions.
+fn precision_for_significant_digits(value: f64, digits: i32) -> u8 {
+    let precision = digits - 1 - value.log10().floor() as i32;
+    precision.clamp(0, i32::from(FIXED_PRECISION)) as u8
+}
+
+/// Returns the `raw` fixed-point price rounded to the given `precision`, down or `up`.
+///
+/// The bid of a derived quote is rounded down and the ask up, so the quote is never tighter
+/// than the prices it was derived from.
+fn round_raw(raw: i64, precision: u8, up: bool) -> i64 {
+    let step = 10_i64.pow(u32::from(FIXED_PRECISION - precision));
+    let floor = raw.div_euclid(step) * step;
+    if up && floor < raw {
+        floor + step
+    } else {
+        floor
+    }
+}
+
+/// Returns the spread between the given `bid` and `ask` prices.
+///
+/// Returns `NaN` for a crossed book (`ask < bid`).
+#[must_use]
+pub fn spread(bid: f64, ask: f64) -> f64 {
+    if ask < bid {
+        return f64::NAN;
+    }
+    ask - bid
+}
+
+/// Returns the spread and mid between the given `bid` and `ask` prices.
+///
+/// Returns `NaN` for both values for a crossed book (`ask < bid`).
+#[must_use]
+pub fn spread_and_mid(bid: f64, ask: f64) -> (f64, f64) {
+    let spread = spread(bid, ask);
+    if spread.is_nan() {
+        return (f64::NAN, f64::NAN);
+    }
+    (spread, PriceType::Mid.select(bid, ask))
+}
+
 impl Display for QuoteTick {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -214,15 +488,39 @@ impl GetTsInit for QuoteTick {
 ////////////////////////////////////////////////////////////////////////////////
 #[cfg(test)]
 mod tests {
-    use nautilus_core::serialization::Serializable;
+    use nautilus_core::{nanos::UnixNanos, serialization::Serializable};
     use pyo3::{IntoPy, Python};
     use rstest::rstest;
 
     use crate::{
-        data::{quote::QuoteTick, stubs::quote_ethusdt_binance},
+        data::{
+            quote::{cross, spread, spread_and_mid, QuoteTick},
+            stubs::quote_ethusdt_binance,
+        },
         enums::PriceType,
+        identifiers::InstrumentId,
+        types::{currency::Currency, price::Price, quantity::Quantity},
     };
 
+    fn fx_quote(
+        instrument_id: &str,
+        bid: &str,
+        ask: &str,
+        bid_size: &str,
+        ask_size: &str,
+        ts: u64,
+    ) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            UnixNanos::from(ts),
+            UnixNanos::from(ts),
+        )
+    }
+
     #[rstest]
     fn test_to_string(quote_ethusdt_binance: QuoteTick) {
         let quote = quote_ethusdt_binance;
@@ -246,6 +544,157 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    fn test_spread_and_mid() {
+        assert_eq!(spread(100.0, 100.5), 0.5);
+        assert_eq!(spread(100.0, 100.0), 0.0);
+        assert_eq!(spread_and_mid(100.0, 100.5), (0.5, 100.25));
+    }
+
+    #[rstest]
+    fn test_spread_and_mid_crossed_book() {
+        assert!(spread(100.5, 100.0).is_nan());
+        let (spread, mid) = spread_and_mid(100.5, 100.0);
+        assert!(spread.is_nan());
+        assert!(mid.is_nan());
+    }
+
+    #[rstest]
+    fn test_invert() {
+        let quote = fx_quote("EUR/USD.SIM", "1.2345", "1.2347", "1000000", "2000000", 1);
+
+        let inverted = quote.invert(InstrumentId::from("USD/EUR.SIM"));
+
+        // 1 / 1.2347 = 0.809913... (rounded down), 1 / 1.2345 = 0.810044... (rounded up)
+        assert_eq!(inverted.instrument_id, InstrumentId::from("USD/EUR.SIM"));
+        assert_eq!(inverted.bid_price, Price::from("0.80991"));
+        assert_eq!(inverted.ask_price, Price::from("0.81005"));
+        assert_eq!(inverted.bid_size, Quantity::from("2469400"));
+        assert_eq!(inverted.ask_size, Quantity::from("1234500"));
+        assert_eq!(inverted.ts_event, quote.ts_event);
+        assert_eq!(inverted.ts_init, quote.ts_init);
+    }
+
+    #[rstest]
+    fn test_invert_preserves_significant_digits() {
+        let quote = fx_quote("USD/JPY.SIM", "150.123", "150.125", "1000", "1000", 1);
+
+        let inverted = quote.invert(InstrumentId::from("JPY/USD.SIM"));
+
+        // 1 / 150.125 = 0.006661115..., 1 / 150.123 = 0.006661204...
+        assert_eq!(inverted.bid_price.to_string(), "0.00666111");
+        assert_eq!(inverted.ask_price.to_string(), "0.00666121");
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Cannot invert quote with non-positive prices")]
+    fn test_invert_with_zero_price_panics() {
+        let quote = fx_quote("EUR/USD.SIM", "0.0000", "1.2347", "1", "1", 1);
+        let _ = quote.invert(InstrumentId::from("USD/EUR.SIM"));
+    }
+
+    #[rstest]
+    fn test_cross_multiplies_through_shared_currency() {
+        let eurusd = fx_quote("EUR/USD.SIM", "1.1000", "1.1002", "1000000", "2000000", 1);
+        let usdjpy = fx_quote("USD/JPY.SIM", "150.00", "150.02", "5000000", "500000", 2);
+
+        let eurjpy = cross(
+            &eurusd,
+            (Currency::EUR(), Currency::USD()),
+            &usdjpy,
+            (Currency::USD(), Currency::JPY()),
+            InstrumentId::from("EUR/JPY.SIM"),
+            (Currency::EUR(), Currency::JPY()),
+        )
+        .unwrap();
+
+        // 1.1000 * 150.00 = 165.000, 1.1002 * 150.02 = 165.052004, to 5 significant digits
+        assert_eq!(eurjpy.bid_price, Price::from("165.00"));
+        assert_eq!(eurjpy.ask_price, Price::from("165.06"));
+        // Ask size limited by the USD/JPY leg: 500000 / 1.1002 = 454462.8...
+        assert_eq!(eurjpy.bid_size, Quantity::from("1000000"));
+        assert_eq!(eurjpy.ask_size, Quantity::from("454463"));
+        assert_eq!(eurjpy.ts_event, UnixNanos::from(2));
+        assert_eq!(eurjpy.ts_init, UnixNanos::from(2));
+    }
+
+    #[rstest]
+    fn test_cross_divides_through_shared_quote_currency() {
+        let eurusd = fx_quote("EUR/USD.SIM", "1.1000", "1.1002", "1000000", "1000000", 1);
+        let gbpusd = fx_quote("GBP/USD.SIM", "1.2500", "1.2502", "1000000", "1000000", 1);
+
+        let eurgbp = cross(
+            &gbpusd,
+            (Currency::GBP(), Currency::USD()),
+            &eurusd,
+            (Currency::EUR(), Currency::USD()),
+            InstrumentId::from("EUR/GBP.SIM"),
+            (Currency::EUR(), Currency::GBP()),
+        )
+        .unwrap();
+
+        // 1.1000 / 1.2502 = 0.8798592..., 1.1002 / 1.2500 = 0.88016
+        assert_eq!(eurgbp.bid_price, Price::from("0.87985"));
+        assert_eq!(eurgbp.ask_price, Price::from("0.88016"));
+    }
+
+    #[rstest]
+    fn test_cross_keeps_significant_digits_of_least_precise_quote() {
+        let eurusd = fx_quote("EUR/USD.SIM", "1.10000", "1.10020", "1", "1", 1);
+        let usdjpy = fx_quote("USD/JPY.SIM", "150.0", "150.1", "1", "1", 1);
+
+        let eurjpy = cross(
+            &eurusd,
+            (Currency::EUR(), Currency::USD()),
+            &usdjpy,
+            (Currency::USD(), Currency::JPY()),
+            InstrumentId::from("EUR/JPY.SIM"),
+            (Currency::EUR(), Currency::JPY()),
+        )
+        .unwrap();
+
+        // 4 significant digits of USD/JPY: 165.00 rounded down, 165.140... rounded up
+        assert_eq!(eurjpy.bid_price.to_string(), "165.0");
+        assert_eq!(eurjpy.ask_price.to_string(), "165.2");
+    }
+
+    #[rstest]
+    fn test_cross_with_no_shared_currency() {
+        let eurusd = fx_quote("EUR/USD.SIM", "1.1000", "1.1002", "1", "1", 1);
+        let gbpjpy = fx_quote("GBP/JPY.SIM", "190.00", "190.02", "1", "1", 1);
+
+        let result = cross(
+            &eurusd,
+            (Currency::EUR(), Currency::USD()),
+            &gbpjpy,
+            (Currency::GBP(), Currency::JPY()),
+            InstrumentId::from("EUR/JPY.SIM"),
+            (Currency::EUR(), Currency::JPY()),
+        );
+
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "No shared currency between EUR/USD and GBP/JPY"
+        );
+    }
+
+    #[rstest]
+    fn test_cross_with_underivable_target() {
+        let eurusd = fx_quote("EUR/USD.SIM", "1.1000", "1.1002", "1", "1", 1);
+        let usdjpy = fx_quote("USD/JPY.SIM", "150.00", "150.02", "1", "1", 1);
+
+        let result = cross(
+            &eurusd,
+            (Currency::EUR(), Currency::USD()),
+            &usdjpy,
+            (Currency::USD(), Currency::JPY()),
+            InstrumentId::from("GBP/JPY.SIM"),
+            (Currency::GBP(), Currency::JPY()),
+        );
+
+        assert!(result.is_err());
+    }
+
     #[rstest]
     fn test_from_pyobject(quote_ethusdt_binance: QuoteTick) {
         pyo3::prepare_freethreaded_python();
@@ -201,4 +201,23 @@ mod tests {
         let deserialized = TradeTick::from_msgpack_bytes(serialized.as_ref()).unwrap();
         assert_eq!(deserialized, trade);
     }
+
+    #[rstest]
+    fn test_msgpack_unknown_enum_value(stub_trade_ethusdt_buyer: TradeTick) {
+        let serialized = stub_trade_ethusdt_buyer.as_msgpack_bytes().unwrap();
+        // Replace the aggressor side with an unknown name of the same length
+        let pos = serialized
+            .windows(5)
+            .position(|window| window == b"BUYER")
+            .unwrap();
+        let mut serialized = serialized.to_vec();
+        serialized[pos..pos + 5].copy_from_slice(b"OTHER");
+
+        let err = TradeTick::from_msgpack_bytes(&serialized).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unknown `AggressorSide` value, was \"OTHER\""
+        );
+    }
 }
@@ -18,20 +18,94 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
+use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr, IntoStaticStr};
 
-use crate::enum_strum_serde;
+use crate::{enum_as_str, enum_strum_serde};
+
+/// An error for an enum discriminant with no known variant, such as from data persisted by a
+/// newer version or a corrupted file.
+#[derive(thiserror::Error, Clone, Debug, PartialEq, Eq)]
+#[error("Unknown `{enum_name}` value, was {value}")]
+pub struct UnknownEnumValue {
+    /// The name of the enum type.
+    pub enum_name: &'static str,
+    /// The unknown discriminant value.
+    pub value: u64,
+}
+
+impl UnknownEnumValue {
+    /// Creates a new [`UnknownEnumValue`] error for an unknown `value` of the enum `T`.
+    #[must_use]
+    pub fn new<T>(value: u64) -> Self {
+        let type_name = std::any::type_name::<T>();
+        Self {
+            enum_name: type_name.rsplit("::").next().unwrap_or(type_name),
+            value,
+        }
+    }
+}
 
 pub trait FromU8 {
     fn from_u8(value: u8) -> Option<Self>
     where
         Self: Sized;
+
+    /// Returns the variant for `value`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not a known discriminant.
+    fn try_from_u8(value: u8) -> Result<Self, UnknownEnumValue>
+    where
+        Self: Sized,
+    {
+        Self::from_u8(value).ok_or_else(|| UnknownEnumValue::new::<Self>(u64::from(value)))
+    }
+
+    /// Returns the variant which an unknown discriminant may be leniently decoded as.
+    ///
+    /// Returns `None` (the default) where no variant is a safe substitute, in which case an
+    /// unknown discriminant is always an error.
+    #[must_use]
+    fn unknown_fallback() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 pub trait FromU16 {
     fn from_u16(value: u16) -> Option<Self>
     where
         Self: Sized;
+
+    /// Returns the variant for `value`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not a known discriminant.
+    fn try_from_u16(value: u16) -> Result<Self, UnknownEnumValue>
+    where
+        Self: Sized,
+    {
+        Self::from_u16(value).ok_or_else(|| UnknownEnumValue::new::<Self>(u64::from(value)))
+    }
+}
+
+/// A side with a long/short duality (buy/sell, buyer/seller or long/short), allowing generic
+/// code to treat [`OrderSide`], [`AggressorSide`] and [`PositionSide`] uniformly.
+///
+/// Sides which are neither long nor short (e.g. `NoOrderSide` or `Flat`) are their own opposite.
+pub trait Direction: Sized {
+    /// Returns whether the side is long (`Buy`, `Buyer` or `Long`).
+    fn is_long_side(&self) -> bool;
+    /// Returns whether the side is short (`Sell`, `Seller` or `Short`).
+    fn is_short_side(&self) -> bool;
+    /// Returns the opposite side, with sides which are neither long nor short unchanged.
+    fn opposite(&self) -> Self;
 }
 
 /// An account type provided by a trading venue or broker.
@@ -47,6 +121,7 @@ pub trait FromU16 {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -59,13 +134,40 @@ pub trait FromU16 {
 )]
 pub enum AccountType {
     /// An account with unleveraged cash assets only.
+    #[strum(to_string = "CASH", serialize = "SPOT", serialize = "EXCHANGE")]
     Cash = 1,
     /// An account which facilitates trading on margin, using account assets as collateral.
+    #[strum(
+        to_string = "MARGIN",
+        serialize = "CROSS_MARGIN",
+        serialize = "CROSS",
+        serialize = "ISOLATED_MARGIN",
+        serialize = "ISOLATED",
+        serialize = "UNIFIED"
+    )]
     Margin = 2,
     /// An account specific to betting markets.
     Betting = 3,
 }
 
+impl AccountType {
+    /// Parses an [`AccountType`] from its name or a common broker alias (case-insensitive).
+    ///
+    /// Accepted aliases:
+    ///  - `CASH`, `SPOT`, `EXCHANGE` -> `CASH`
+    ///  - `MARGIN`, `CROSS_MARGIN`, `CROSS`, `ISOLATED_MARGIN`, `ISOLATED`, `UNIFIED` -> `MARGIN`
+    ///  - `BETTING` -> `BETTING`
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not a supported alias.
+    pub fn from_broker_alias(value: &str) -> anyhow::Result<Self> {
+        Self::from_str(value)
+            .map_err(|_| anyhow::anyhow!("invalid `AccountType` alias, was '{value}'"))
+    }
+}
+
 /// An aggregation source for derived data.
 #[repr(C)]
 #[derive(
@@ -79,6 +181,7 @@ pub enum AccountType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -110,6 +213,7 @@ pub enum AggregationSource {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -130,6 +234,45 @@ pub enum AggressorSide {
     Seller = 2,
 }
 
+impl AggressorSide {
+    /// Returns the opposite aggressor side (`BUYER` <-> `SELLER`), or `NO_AGGRESSOR` unchanged.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::NoAggressor => Self::NoAggressor,
+            Self::Buyer => Self::Seller,
+            Self::Seller => Self::Buyer,
+        }
+    }
+
+    /// Returns the liquidity side of the passive counterparty to a trade with this aggressor.
+    ///
+    /// The passive side of a trade (the [`AggressorSide::opposite`] of the aggressor) provided
+    /// liquidity, so is `MAKER`, unless the trade had no aggressor (`NO_LIQUIDITY_SIDE`). Useful
+    /// when reconstructing maker/taker from public trade feeds.
+    #[must_use]
+    pub const fn maker_side(self) -> LiquiditySide {
+        match self {
+            Self::NoAggressor => LiquiditySide::NoLiquiditySide,
+            Self::Buyer | Self::Seller => LiquiditySide::Maker,
+        }
+    }
+}
+
+impl Direction for AggressorSide {
+    fn is_long_side(&self) -> bool {
+        matches!(self, Self::Buyer)
+    }
+
+    fn is_short_side(&self) -> bool {
+        matches!(self, Self::Seller)
+    }
+
+    fn opposite(&self) -> Self {
+        Self::opposite(*self)
+    }
+}
+
 impl FromU8 for AggressorSide {
     fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -139,6 +282,12 @@ impl FromU8 for AggressorSide {
             _ => None,
         }
     }
+
+    /// An unknown aggressor is decoded as `NO_AGGRESSOR`, as the side of a trade does not
+    /// affect its price or size.
+    fn unknown_fallback() -> Option<Self> {
+        Some(Self::NoAggressor)
+    }
 }
 
 /// A broad financial market asset class.
@@ -154,6 +303,7 @@ impl FromU8 for AggressorSide {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -182,6 +332,35 @@ pub enum AssetClass {
     Alternative = 7,
 }
 
+impl AssetClass {
+    /// Returns whether the asset class is cryptocurrency.
+    #[must_use]
+    pub const fn is_crypto(self) -> bool {
+        matches!(self, Self::Cryptocurrency)
+    }
+
+    /// Returns whether markets for the asset class trade continuously (24/7), so have
+    /// no overnight or weekend closes.
+    #[must_use]
+    pub const fn is_24_7(self) -> bool {
+        self.is_crypto()
+    }
+
+    /// Returns a human-readable description of the asset class (e.g. for UI tooltips).
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::FX => "Foreign Exchange",
+            Self::Equity => "Equity",
+            Self::Commodity => "Commodity",
+            Self::Debt => "Debt",
+            Self::Index => "Index",
+            Self::Cryptocurrency => "Cryptocurrency",
+            Self::Alternative => "Alternative",
+        }
+    }
+}
+
 impl FromU8 for AssetClass {
     fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -210,6 +389,7 @@ impl FromU8 for AssetClass {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -247,6 +427,53 @@ pub enum InstrumentClass {
     BinaryOption = 12,
 }
 
+impl InstrumentClass {
+    /// Returns whether markets for the instrument class trade continuously (24/7), so have
+    /// no overnight or weekend closes.
+    #[must_use]
+    pub const fn is_24_7(self) -> bool {
+        matches!(self, Self::SportsBetting)
+    }
+
+    /// Returns whether instruments of the class require an expiry.
+    ///
+    /// Futures, forwards, options, warrants and binary options (including spreads of these)
+    /// expire, so require an expiry. Spot, swap, CFD, bond and sports betting instruments
+    /// have no expiry.
+    #[must_use]
+    pub const fn requires_expiry(self) -> bool {
+        matches!(
+            self,
+            Self::Future
+                | Self::FutureSpread
+                | Self::Forward
+                | Self::Option
+                | Self::OptionSpread
+                | Self::Warrant
+                | Self::BinaryOption
+        )
+    }
+
+    /// Returns a human-readable description of the instrument class (e.g. for UI tooltips).
+    #[must_use]
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Spot => "Spot",
+            Self::Swap => "Swap",
+            Self::Future => "Future",
+            Self::FutureSpread => "Future Spread",
+            Self::Forward => "Forward",
+            Self::Cfd => "Contract for Difference",
+            Self::Bond => "Bond",
+            Self::Option => "Option",
+            Self::OptionSpread => "Option Spread",
+            Self::Warrant => "Warrant",
+            Self::SportsBetting => "Sports Betting",
+            Self::BinaryOption => "Binary Option",
+        }
+    }
+}
+
 /// The aggregation method through which a bar is generated and closed.
 #[repr(C)]
 #[derive(
@@ -260,6 +487,7 @@ pub enum InstrumentClass {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -305,6 +533,43 @@ pub enum BarAggregation {
     Month = 16,
 }
 
+impl BarAggregation {
+    /// Parses a [`BarAggregation`] from a common time unit abbreviation (case-insensitive),
+    /// falling back to the full variant name.
+    ///
+    /// Supported abbreviations:
+    ///  - `MS`, `MILLIS` -> `MILLISECOND`
+    ///  - `S`, `SEC`, `SECS` -> `SECOND`
+    ///  - `M`, `MIN`, `MINS` -> `MINUTE`
+    ///  - `H`, `HR`, `HRS` -> `HOUR`
+    ///  - `D` -> `DAY`
+    ///  - `W`, `WK` -> `WEEK`
+    ///  - `MO`, `MON`, `MTH` -> `MONTH`
+    ///
+    /// Since parsing is case-insensitive, `M` is always minutes (as in `1m`); months must be
+    /// written as `MO`, `MON`, `MTH` or `MONTH`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is neither a supported abbreviation nor a variant name.
+    pub fn from_abbrev(value: &str) -> anyhow::Result<Self> {
+        let aggregation = match value.to_ascii_uppercase().as_str() {
+            "MS" | "MILLIS" => Self::Millisecond,
+            "S" | "SEC" | "SECS" => Self::Second,
+            "M" | "MIN" | "MINS" => Self::Minute,
+            "H" | "HR" | "HRS" => Self::Hour,
+            "D" => Self::Day,
+            "W" | "WK" => Self::Week,
+            "MO" | "MON" | "MTH" => Self::Month,
+            _ => Self::from_str(value).map_err(|_| {
+                anyhow::anyhow!("invalid `BarAggregation` abbreviation, was '{value}'")
+            })?,
+        };
+        Ok(aggregation)
+    }
+}
+
 /// The type of order book action for an order book event.
 #[repr(C)]
 #[derive(
@@ -318,6 +583,7 @@ pub enum BarAggregation {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -339,6 +605,22 @@ pub enum BookAction {
     Clear = 4,
 }
 
+impl BookAction {
+    /// Returns the processing priority of the action when applying a batch of deltas.
+    ///
+    /// Higher values are applied first (`Clear`, then `Delete`, `Update` and `Add`), so
+    /// batched deltas can be stable sorted by descending priority for deterministic replay.
+    #[must_use]
+    pub const fn priority(self) -> u8 {
+        match self {
+            Self::Clear => 3,
+            Self::Delete => 2,
+            Self::Update => 1,
+            Self::Add => 0,
+        }
+    }
+}
+
 impl FromU8 for BookAction {
     fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -364,6 +646,7 @@ impl FromU8 for BookAction {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -384,6 +667,20 @@ pub enum BookType {
     L3_MBO = 3,
 }
 
+impl BookType {
+    /// Returns whether deltas with the given `action` are meaningful for a book of this type.
+    ///
+    /// An `L1_MBP` book holds a single level per side which is replaced with `Update`, so levels
+    /// cannot be added at arbitrary prices. All actions are meaningful for `L2_MBP` and `L3_MBO`.
+    #[must_use]
+    pub const fn allows_action(self, action: BookAction) -> bool {
+        match self {
+            Self::L1_MBP => !matches!(action, BookAction::Add),
+            Self::L2_MBP | Self::L3_MBO => true,
+        }
+    }
+}
+
 impl FromU8 for BookType {
     fn from_u8(value: u8) -> Option<Self> {
         match value {
@@ -411,6 +708,7 @@ impl FromU8 for BookType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -424,15 +722,53 @@ impl FromU8 for BookType {
 pub enum ContingencyType {
     /// Not a contingent order.
     #[default]
+    #[strum(to_string = "NO_CONTINGENCY", serialize = "NONE")]
     NoContingency = 0,
     /// One-Cancels-the-Other.
+    #[strum(
+        to_string = "OCO",
+        serialize = "O.C.O",
+        serialize = "ONE_CANCELS_OTHER",
+        serialize = "ONECANCELSOTHER"
+    )]
     Oco = 1,
     /// One-Triggers-the-Other.
+    #[strum(
+        to_string = "OTO",
+        serialize = "O.T.O",
+        serialize = "ONE_TRIGGERS_OTHER",
+        serialize = "ONETRIGGERSOTHER"
+    )]
     Oto = 2,
     /// One-Updates-the-Other (by proportional quantity).
+    #[strum(
+        to_string = "OUO",
+        serialize = "O.U.O",
+        serialize = "ONE_UPDATES_OTHER",
+        serialize = "ONEUPDATESOTHER"
+    )]
     Ouo = 3,
 }
 
+impl ContingencyType {
+    /// Parses a [`ContingencyType`] from its name or a common venue alias (case-insensitive).
+    ///
+    /// Accepted aliases:
+    ///  - `NO_CONTINGENCY`, `NONE` -> `NO_CONTINGENCY`
+    ///  - `OCO`, `O.C.O`, `ONE_CANCELS_OTHER`, `OneCancelsOther` -> `OCO`
+    ///  - `OTO`, `O.T.O`, `ONE_TRIGGERS_OTHER`, `OneTriggersOther` -> `OTO`
+    ///  - `OUO`, `O.U.O`, `ONE_UPDATES_OTHER`, `OneUpdatesOther` -> `OUO`
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `value` is not a supported alias.
+    pub fn from_alias(value: &str) -> anyhow::Result<Self> {
+        Self::from_str(value)
+            .map_err(|_| anyhow::anyhow!("invalid `ContingencyType` alias, was '{value}'"))
+    }
+}
+
 /// The broad currency type.
 #[repr(C)]
 #[derive(
@@ -446,6 +782,7 @@ pub enum ContingencyType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -465,6 +802,20 @@ pub enum CurrencyType {
     CommodityBacked = 3,
 }
 
+impl CurrencyType {
+    /// Returns the conventional precision for currencies of this type.
+    ///
+    /// Used as a fallback when an instrument doesn't specify a precision: 8 for crypto,
+    /// and 2 for fiat and commodity backed currencies (as for the ISO 4217 metals).
+    #[must_use]
+    pub const fn default_precision(self) -> u8 {
+        match self {
+            Self::Crypto => 8,
+            Self::Fiat | Self::CommodityBacked => 2,
+        }
+    }
+}
+
 /// The type of event for an instrument close.
 #[repr(C)]
 #[derive(
@@ -478,6 +829,7 @@ pub enum CurrencyType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -508,6 +860,7 @@ pub enum InstrumentCloseType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -528,6 +881,30 @@ pub enum LiquiditySide {
     Taker = 2,
 }
 
+impl LiquiditySide {
+    /// Returns whether the liquidity side is maker.
+    #[must_use]
+    pub const fn is_maker(self) -> bool {
+        matches!(self, Self::Maker)
+    }
+
+    /// Returns whether the liquidity side is taker.
+    #[must_use]
+    pub const fn is_taker(self) -> bool {
+        matches!(self, Self::Taker)
+    }
+
+    /// Returns the applicable fee rate for the liquidity side, or `None` if no liquidity side.
+    #[must_use]
+    pub const fn fee_rate(self, maker_rate: f64, taker_rate: f64) -> Option<f64> {
+        match self {
+            Self::Maker => Some(maker_rate),
+            Self::Taker => Some(taker_rate),
+            Self::NoLiquiditySide => None,
+        }
+    }
+}
+
 /// The status of an individual market on a trading venue.
 #[repr(C)]
 #[derive(
@@ -541,6 +918,7 @@ pub enum LiquiditySide {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -579,6 +957,7 @@ pub enum MarketStatus {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -624,6 +1003,100 @@ pub enum MarketStatusAction {
     NotAvailableForTrading = 15,
 }
 
+impl MarketStatusAction {
+    /// Returns whether the action carries no session phase, either being informational
+    /// (`None`, `NewPriceIndication`, `ShortSellRestrictionChange`) or leaving the phase
+    /// unknown (`NotAvailableForTrading`).
+    #[must_use]
+    const fn is_phase_unknown(self) -> bool {
+        matches!(
+            self,
+            Self::None
+                | Self::NewPriceIndication
+                | Self::ShortSellRestrictionChange
+                | Self::NotAvailableForTrading
+        )
+    }
+
+    /// Returns the session phase which typically follows this one.
+    ///
+    /// A session normally moves `PreOpen -> Trading -> PreClose -> Close`, with `Close` followed
+    /// by the `PreOpen` of the next session. Opening auction phases and interruptions
+    /// (`Halt`, `Pause`, `Suspend`) return to `Trading`.
+    ///
+    /// Returns `None` for actions which carry no session phase.
+    #[must_use]
+    pub const fn next_expected(self) -> Option<Self> {
+        match self {
+            Self::PreOpen
+            | Self::Quoting
+            | Self::Cross
+            | Self::Rotation
+            | Self::Halt
+            | Self::Pause
+            | Self::Suspend => Some(Self::Trading),
+            Self::PreCross => Some(Self::Cross),
+            Self::Trading => Some(Self::PreClose),
+            Self::PreClose => Some(Self::Close),
+            Self::Close | Self::PostClose => Some(Self::PreOpen),
+            Self::None
+            | Self::NewPriceIndication
+            | Self::ShortSellRestrictionChange
+            | Self::NotAvailableForTrading => None,
+        }
+    }
+
+    /// Returns whether a venue may move a market from the `from` phase to the `to` phase.
+    ///
+    /// The allowed edges are:
+    /// - Any repeated phase, and any transition to or from an action with no session phase.
+    /// - The next expected phase (see [`MarketStatusAction::next_expected`]).
+    /// - `PreOpen` to an opening auction phase (`PreCross`, `Cross`, `Rotation`, `Quoting`).
+    /// - `Trading` to `Close`, for venues without a pre-close period.
+    /// - `PreClose` to a closing auction (`Cross`), and `Cross` to `Close`.
+    /// - `Close` to `PostClose`.
+    /// - Any phase from `PreOpen` to `PreClose` to an interruption (`Halt`, `Pause`, `Suspend`),
+    ///   and between interruptions.
+    /// - An interruption to a reopening auction (`Quoting`, `Cross`) or to `Close`.
+    #[must_use]
+    pub const fn valid_transition(from: Self, to: Self) -> bool {
+        if from as u8 == to as u8 || from.is_phase_unknown() || to.is_phase_unknown() {
+            return true;
+        }
+        if let Some(next) = from.next_expected() {
+            if next as u8 == to as u8 {
+                return true;
+            }
+        }
+        matches!(
+            (from, to),
+            (
+                Self::PreOpen,
+                Self::PreCross | Self::Cross | Self::Rotation | Self::Quoting
+            ) | (Self::Trading | Self::Cross, Self::Close)
+                | (Self::PreClose, Self::Cross)
+                | (Self::Close, Self::PostClose)
+                | (
+                    Self::PreOpen
+                        | Self::PreCross
+                        | Self::Quoting
+                        | Self::Cross
+                        | Self::Rotation
+                        | Self::Trading
+                        | Self::PreClose
+                        | Self::Halt
+                        | Self::Pause
+                        | Self::Suspend,
+                    Self::Halt | Self::Pause | Self::Suspend
+                )
+                | (
+                    Self::Halt | Self::Pause | Self::Suspend,
+                    Self::Quoting | Self::Cross | Self::Close
+                )
+        )
+    }
+}
+
 /// Convert the given `value` to an [`OrderSide`].
 impl FromU16 for MarketStatusAction {
     fn from_u16(value: u16) -> Option<Self> {
@@ -663,6 +1136,7 @@ impl FromU16 for MarketStatusAction {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -685,6 +1159,23 @@ pub enum OmsType {
     Hedging = 2,
 }
 
+impl OmsType {
+    /// Returns the default [`OmsType`] for the given `account` type, for use when no OMS type
+    /// is specified.
+    ///
+    /// The mapping is:
+    ///  - `CASH` -> `NETTING`
+    ///  - `MARGIN` -> `NETTING`
+    ///  - `BETTING` -> `HEDGING`
+    #[must_use]
+    pub const fn default_for_account(account: AccountType) -> Self {
+        match account {
+            AccountType::Cash | AccountType::Margin => Self::Netting,
+            AccountType::Betting => Self::Hedging,
+        }
+    }
+}
+
 /// The kind of options contract.
 #[repr(C)]
 #[derive(
@@ -698,6 +1189,7 @@ pub enum OmsType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -729,6 +1221,7 @@ pub enum OptionKind {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -759,6 +1252,41 @@ impl OrderSide {
             _ => panic!("Order invariant failed: side must be `Buy` or `Sell`"),
         }
     }
+
+    /// Returns the sign of the side for signed quantity math: `1.0` for `Buy`, `-1.0` for
+    /// `Sell` and `0.0` for `NoOrderSide`.
+    #[must_use]
+    pub const fn as_signed_multiplier(self) -> f64 {
+        match self {
+            Self::NoOrderSide => 0.0,
+            Self::Buy => 1.0,
+            Self::Sell => -1.0,
+        }
+    }
+
+    /// Returns the opposite side (`Buy` <-> `Sell`), or `NoOrderSide` unchanged.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::NoOrderSide => Self::NoOrderSide,
+            Self::Buy => Self::Sell,
+            Self::Sell => Self::Buy,
+        }
+    }
+}
+
+impl Direction for OrderSide {
+    fn is_long_side(&self) -> bool {
+        matches!(self, Self::Buy)
+    }
+
+    fn is_short_side(&self) -> bool {
+        matches!(self, Self::Sell)
+    }
+
+    fn opposite(&self) -> Self {
+        Self::opposite(*self)
+    }
 }
 
 /// Convert the given `value` to an [`OrderSide`].
@@ -823,6 +1351,7 @@ impl OrderSideSpecified {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -864,6 +1393,24 @@ pub enum OrderStatus {
     Filled = 14,
 }
 
+impl OrderStatus {
+    /// Returns whether an order with this status is live on the trading venue and can still fill.
+    ///
+    /// This is the canonical predicate for open orders (`ACCEPTED`, `TRIGGERED`, `PENDING_UPDATE`,
+    /// `PENDING_CANCEL` and `PARTIALLY_FILLED`).
+    #[must_use]
+    pub const fn is_active(self) -> bool {
+        matches!(
+            self,
+            Self::Accepted
+                | Self::Triggered
+                | Self::PendingUpdate
+                | Self::PendingCancel
+                | Self::PartiallyFilled
+        )
+    }
+}
+
 /// The type of order.
 #[repr(C)]
 #[derive(
@@ -877,6 +1424,7 @@ pub enum OrderStatus {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -908,6 +1456,30 @@ pub enum OrderType {
     TrailingStopLimit = 9,
 }
 
+impl OrderType {
+    /// Returns whether the order type is conditional, and so requires a [`TriggerType`]
+    /// (other than [`TriggerType::NoTrigger`]).
+    #[must_use]
+    pub const fn requires_trigger_type(self) -> bool {
+        matches!(
+            self,
+            Self::StopMarket
+                | Self::StopLimit
+                | Self::MarketIfTouched
+                | Self::LimitIfTouched
+                | Self::TrailingStopMarket
+                | Self::TrailingStopLimit
+        )
+    }
+
+    /// Returns whether the order type is a trailing order type, and so requires a
+    /// [`TrailingOffsetType`] (other than [`TrailingOffsetType::NoTrailingOffset`]).
+    #[must_use]
+    pub const fn is_trailing(self) -> bool {
+        matches!(self, Self::TrailingStopMarket | Self::TrailingStopLimit)
+    }
+}
+
 /// The market side for a specific position, or action related to positions.
 #[repr(C)]
 #[derive(
@@ -922,6 +1494,7 @@ pub enum OrderType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -945,6 +1518,40 @@ pub enum PositionSide {
     Short = 3,
 }
 
+impl PositionSide {
+    /// Returns the opposite side (`Long` for `Short` and vice versa), with `Flat` and
+    /// `NoPositionSide` unchanged.
+    #[must_use]
+    pub const fn opposite(self) -> Self {
+        match self {
+            Self::Long => Self::Short,
+            Self::Short => Self::Long,
+            Self::Flat => Self::Flat,
+            Self::NoPositionSide => Self::NoPositionSide,
+        }
+    }
+
+    /// Returns whether the side is `Flat`, where no position is currently held in the market.
+    #[must_use]
+    pub const fn is_flat(self) -> bool {
+        matches!(self, Self::Flat)
+    }
+}
+
+impl Direction for PositionSide {
+    fn is_long_side(&self) -> bool {
+        matches!(self, Self::Long)
+    }
+
+    fn is_short_side(&self) -> bool {
+        matches!(self, Self::Short)
+    }
+
+    fn opposite(&self) -> Self {
+        Self::opposite(*self)
+    }
+}
+
 /// The type of price for an instrument in a market.
 #[repr(C)]
 #[derive(
@@ -958,6 +1565,7 @@ pub enum PositionSide {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -979,6 +1587,24 @@ pub enum PriceType {
     Last = 4,
 }
 
+impl PriceType {
+    /// Selects the price of this type from the given `bid` and `ask` prices.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `self` is `Last`, which cannot be derived from a quote.
+    #[must_use]
+    pub fn select(self, bid: f64, ask: f64) -> f64 {
+        match self {
+            Self::Bid => bid,
+            Self::Ask => ask,
+            Self::Mid => (bid + ask) / 2.0,
+            Self::Last => panic!("Cannot select with price type {self}"),
+        }
+    }
+}
+
 /// A record flag bit field, indicating event end and data information.
 #[repr(C)]
 #[derive(
@@ -992,6 +1618,7 @@ pub enum PriceType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -1026,6 +1653,39 @@ impl RecordFlag {
     }
 }
 
+/// The rounding mode used when aligning a value to an instrument increment.
+#[repr(C)]
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    AsRefStr,
+    IntoStaticStr,
+    FromRepr,
+    EnumIter,
+    EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
+)]
+pub enum RoundingMode {
+    /// Round to the nearest increment, with halfway values rounded away from zero.
+    Nearest = 1,
+    /// Round up to the next increment (towards positive infinity).
+    Up = 2,
+    /// Round down to the previous increment (towards negative infinity).
+    Down = 3,
+}
+
 /// The 'Time in Force' instruction for an order.
 #[repr(C)]
 #[derive(
@@ -1039,6 +1699,7 @@ impl RecordFlag {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -1066,6 +1727,22 @@ pub enum TimeInForce {
     AtTheClose = 7,
 }
 
+impl TimeInForce {
+    /// Returns whether the order must execute immediately on arrival and never rests in the
+    /// book (IOC and FOK).
+    #[must_use]
+    pub const fn is_immediate(self) -> bool {
+        matches!(self, Self::Ioc | Self::Fok)
+    }
+
+    /// Returns whether an immediate order may be partially filled, with the remainder canceled
+    /// (IOC), rather than filled in full or not at all (FOK).
+    #[must_use]
+    pub const fn allows_partial(self) -> bool {
+        matches!(self, Self::Ioc)
+    }
+}
+
 /// The trading state for a node.
 #[repr(C)]
 #[derive(
@@ -1079,6 +1756,7 @@ pub enum TimeInForce {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -1111,6 +1789,7 @@ pub enum TradingState {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -1122,7 +1801,8 @@ pub enum TradingState {
     pyo3::pyclass(eq, eq_int, module = "nautilus_trader.core.nautilus_pyo3.model.enums")
 )]
 pub enum TrailingOffsetType {
-    /// No trailing offset type is specified (invalid for trailing type orders).
+    /// No trailing offset type is specified (required for all non-trailing order types, and
+    /// invalid for trailing type orders).
     NoTrailingOffset = 0,
     /// The trailing offset is based on a market price.
     Price = 1,
@@ -1148,6 +1828,7 @@ pub enum TrailingOffsetType {
     PartialOrd,
     Ord,
     AsRefStr,
+    IntoStaticStr,
     FromRepr,
     EnumIter,
     EnumString,
@@ -1182,6 +1863,27 @@ pub enum TriggerType {
     IndexPrice = 9,
 }
 
+impl TriggerType {
+    /// Returns the default [`TriggerType`] for the given `order_type`, for use when a
+    /// conditional order is created without an explicit trigger type.
+    ///
+    /// The mapping is:
+    ///  - `STOP_MARKET`, `STOP_LIMIT`, `MARKET_IF_TOUCHED`, `LIMIT_IF_TOUCHED` -> `LAST_TRADE`
+    ///  - `TRAILING_STOP_MARKET`, `TRAILING_STOP_LIMIT` -> `MARK_PRICE`
+    ///  - All other (non-conditional) order types -> `NO_TRIGGER`
+    #[must_use]
+    pub const fn default_for_order_type(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::StopMarket
+            | OrderType::StopLimit
+            | OrderType::MarketIfTouched
+            | OrderType::LimitIfTouched => Self::LastTrade,
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit => Self::MarkPrice,
+            OrderType::Market | OrderType::Limit | OrderType::MarketToLimit => Self::NoTrigger,
+        }
+    }
+}
+
 enum_strum_serde!(AccountType);
 enum_strum_serde!(AggregationSource);
 enum_strum_serde!(AggressorSide);
@@ -1204,7 +1906,89 @@ enum_strum_serde!(OrderType);
 enum_strum_serde!(PositionSide);
 enum_strum_serde!(PriceType);
 enum_strum_serde!(RecordFlag);
+enum_strum_serde!(RoundingMode);
 enum_strum_serde!(TimeInForce);
 enum_strum_serde!(TradingState);
 enum_strum_serde!(TrailingOffsetType);
 enum_strum_serde!(TriggerType);
+
+enum_as_str!(AccountType);
+enum_as_str!(AggregationSource);
+enum_as_str!(AggressorSide);
+enum_as_str!(AssetClass);
+enum_as_str!(InstrumentClass);
+enum_as_str!(BarAggregation);
+enum_as_str!(BookAction);
+enum_as_str!(BookType);
+enum_as_str!(ContingencyType);
+enum_as_str!(CurrencyType);
+enum_as_str!(InstrumentCloseType);
+enum_as_str!(LiquiditySide);
+enum_as_str!(MarketStatus);
+enum_as_str!(MarketStatusAction);
+enum_as_str!(OmsType);
+enum_as_str!(OptionKind);
+enum_as_str!(OrderSide);
+enum_as_str!(OrderStatus);
+enum_as_str!(OrderType);
+enum_as_str!(PositionSide);
+enum_as_str!(PriceType);
+enum_as_str!(RecordFlag);
+enum_as_str!(RoundingMode);
+enum_as_str!(TimeInForce);
+enum_as_str!(TradingState);
+enum_as_str!(TrailingOffsetType);
+enum_as_str!(TriggerType);
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fmt::Display;
+
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    fn assert_as_str_matches_to_string<T>(as_str: fn(T) -> &'static str)
+    where
+        T: IntoEnumIterator + Display + Copy,
+    {
+        for variant in T::iter() {
+            assert_eq!(as_str(variant), variant.to_string());
+        }
+    }
+
+    #[rstest]
+    fn test_as_str_matches_to_string() {
+        assert_as_str_matches_to_string(AccountType::as_str);
+        assert_as_str_matches_to_string(AggregationSource::as_str);
+        assert_as_str_matches_to_string(AggressorSide::as_str);
+        assert_as_str_matches_to_string(AssetClass::as_str);
+        assert_as_str_matches_to_string(InstrumentClass::as_str);
+        assert_as_str_matches_to_string(BarAggregation::as_str);
+        assert_as_str_matches_to_string(BookAction::as_str);
+        assert_as_str_matches_to_string(BookType::as_str);
+        assert_as_str_matches_to_string(ContingencyType::as_str);
+        assert_as_str_matches_to_string(CurrencyType::as_str);
+        assert_as_str_matches_to_string(InstrumentCloseType::as_str);
+        assert_as_str_matches_to_string(LiquiditySide::as_str);
+        assert_as_str_matches_to_string(MarketStatus::as_str);
+        assert_as_str_matches_to_string(MarketStatusAction::as_str);
+        assert_as_str_matches_to_string(OmsType::as_str);
+        assert_as_str_matches_to_string(OptionKind::as_str);
+        assert_as_str_matches_to_string(OrderSide::as_str);
+        assert_as_str_matches_to_string(OrderStatus::as_str);
+        assert_as_str_matches_to_string(OrderType::as_str);
+        assert_as_str_matches_to_string(PositionSide::as_str);
+        assert_as_str_matches_to_string(PriceType::as_str);
+        assert_as_str_matches_to_string(RecordFlag::as_str);
+        assert_as_str_matches_to_string(RoundingMode::as_str);
+        assert_as_str_matches_to_string(TimeInForce::as_str);
+        assert_as_str_matches_to_string(TradingState::as_str);
+        assert_as_str_matches_to_string(TrailingOffsetType::as_str);
+        assert_as_str_matches_to_string(TriggerType::as_str);
+    }
+}
@@ -105,9 +105,12 @@ impl PartialEq for AccountState {
 mod tests {
     use rstest::rstest;
 
-    use crate::events::account::{
-        state::AccountState,
-        stubs::{cash_account_state, margin_account_state},
+    use crate::{
+        events::account::{
+            state::AccountState,
+            stubs::{cash_account_state, cash_account_state_multi, margin_account_state},
+        },
+        types::currency::Currency,
     };
 
     #[rstest]
@@ -139,4 +142,35 @@ mod tests {
             event_id=16578139-a945-4b65-b46c-bc131a15d8e7)"
         );
     }
+
+    #[rstest]
+    fn test_serde_round_trip_multi_currency(cash_account_state_multi: AccountState) {
+        let json = serde_json::to_string(&cash_account_state_multi).unwrap();
+        let deserialized: AccountState = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, cash_account_state_multi);
+        assert_eq!(deserialized.balances, cash_account_state_multi.balances);
+        let currencies: Vec<Currency> = deserialized.balances.iter().map(|b| b.currency).collect();
+        assert_eq!(currencies, vec![Currency::BTC(), Currency::ETH()]);
+    }
+
+    #[rstest]
+    fn test_deserialize_with_invalid_balance_in_collection(cash_account_state_multi: AccountState) {
+        // Break the invariant of the second (ETH) balance only
+        let json = serde_json::to_string(&cash_account_state_multi)
+            .unwrap()
+            .replacen(
+                "\"free\":\"20.00000000 ETH\"",
+                "\"free\":\"19.00000000 ETH\"",
+                1,
+            );
+        assert!(json.contains("19.00000000 ETH"));
+
+        let result = serde_json::from_str::<AccountState>(&json);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("total balance is not equal to the sum of locked and free balances"));
+    }
 }
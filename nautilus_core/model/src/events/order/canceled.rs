@@ -16,7 +16,11 @@
 use std::fmt::{Debug, Display};
 
 use derive_builder::Builder;
-use nautilus_core::{nanos::UnixNanos, serialization::from_bool_as_u8, uuid::UUID4};
+use nautilus_core::{
+    nanos::UnixNanos,
+    serialization::{from_bool_as_u8, to_bool_from_u8},
+    uuid::UUID4,
+};
 use serde::{Deserialize, Serialize};
 use ustr::Ustr;
 
@@ -49,7 +53,10 @@ pub struct OrderCanceled {
     pub event_id: UUID4,
     pub ts_event: UnixNanos,
     pub ts_init: UnixNanos,
-    #[serde(deserialize_with = "from_bool_as_u8")]
+    #[serde(
+        deserialize_with = "from_bool_as_u8",
+        serialize_with = "to_bool_from_u8"
+    )]
     pub reconciliation: u8, // TODO: Change to bool once Cython removed
     pub venue_order_id: Option<VenueOrderId>,
     pub account_id: Option<AccountId>,
@@ -188,7 +195,7 @@ impl OrderEvent for OrderCanceled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation != 0
     }
 
     fn price(&self) -> Option<Price> {
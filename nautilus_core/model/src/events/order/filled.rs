@@ -313,7 +313,7 @@ impl OrderEvent for OrderFilled {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -20,7 +20,7 @@ use std::{
 
 use derive_builder::Builder;
 use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
-use serde::{Deserialize, Serialize};
+use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
 use ustr::Ustr;
 
 use crate::{
@@ -40,7 +40,7 @@ use crate::{
 #[repr(C)]
 #[derive(Clone, PartialEq, Eq, Builder, Serialize, Deserialize)]
 #[builder(default)]
-#[serde(tag = "type")]
+#[serde(from = "OrderInitializedSchema", into = "OrderInitializedSchema")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -198,6 +198,212 @@ impl OrderInitialized {
     }
 }
 
+/// The JSON schema of an [`OrderInitialized`], as produced by the Python `OrderInitialized.to_dict`.
+///
+/// The order type specific fields are nested under `options`, and absent trigger and
+/// contingency types are written as `NO_TRIGGER` and `NO_CONTINGENCY`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename = "OrderInitialized")]
+struct OrderInitializedSchema {
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    time_in_force: TimeInForce,
+    post_only: bool,
+    reduce_only: bool,
+    quote_quantity: bool,
+    options: OrderInitializedOptions,
+    emulation_trigger: TriggerType,
+    trigger_instrument_id: Option<InstrumentId>,
+    contingency_type: ContingencyType,
+    order_list_id: Option<OrderListId>,
+    linked_order_ids: Option<Vec<ClientOrderId>>,
+    parent_order_id: Option<ClientOrderId>,
+    exec_algorithm_id: Option<ExecAlgorithmId>,
+    exec_algorithm_params: Option<HashMap<Ustr, Ustr>>,
+    exec_spawn_id: Option<ClientOrderId>,
+    tags: Option<Vec<Ustr>>,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    #[serde(default)]
+    reconciliation: bool,
+}
+
+/// The order type specific fields of an [`OrderInitialized`], where only the fields used by
+/// the `order_type` are written (with an absent expire time written as zero).
+#[derive(Deserialize)]
+struct OrderInitializedOptions {
+    #[serde(skip)]
+    order_type: Option<OrderType>,
+    price: Option<Price>,
+    trigger_price: Option<Price>,
+    trigger_type: Option<TriggerType>,
+    limit_offset: Option<Price>,
+    trailing_offset: Option<Price>,
+    trailing_offset_type: Option<TrailingOffsetType>,
+    expire_time_ns: Option<u64>,
+    display_qty: Option<Quantity>,
+}
+
+impl Serialize for OrderInitializedOptions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(order_type) = self.order_type else {
+            return serializer.serialize_map(Some(0))?.end();
+        };
+        let has_price = matches!(
+            order_type,
+            OrderType::Limit
+                | OrderType::StopLimit
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopLimit
+        );
+        let has_trigger = matches!(
+            order_type,
+            OrderType::StopMarket
+                | OrderType::StopLimit
+                | OrderType::MarketIfTouched
+                | OrderType::LimitIfTouched
+                | OrderType::TrailingStopMarket
+                | OrderType::TrailingStopLimit
+        );
+        let has_trailing_offset = matches!(
+            order_type,
+            OrderType::TrailingStopMarket | OrderType::TrailingStopLimit
+        );
+        let has_display_qty = has_price || order_type == OrderType::MarketToLimit;
+
+        let mut map = serializer.serialize_map(None)?;
+        if has_price {
+            map.serialize_entry("price", &self.price)?;
+        }
+        if has_trigger {
+            map.serialize_entry("trigger_price", &self.trigger_price)?;
+            map.serialize_entry(
+                "trigger_type",
+                &self.trigger_type.unwrap_or(TriggerType::NoTrigger),
+            )?;
+        }
+        if order_type == OrderType::TrailingStopLimit {
+            map.serialize_entry("limit_offset", &self.limit_offset)?;
+        }
+        if has_trailing_offset {
+            map.serialize_entry("trailing_offset", &self.trailing_offset)?;
+            map.serialize_entry(
+                "trailing_offset_type",
+                &self
+                    .trailing_offset_type
+                    .unwrap_or(TrailingOffsetType::NoTrailingOffset),
+            )?;
+        }
+        if order_type != OrderType::Market {
+            map.serialize_entry("expire_time_ns", &self.expire_time_ns.unwrap_or(0))?;
+        }
+        if has_display_qty {
+            map.serialize_entry("display_qty", &self.display_qty)?;
+        }
+        map.end()
+    }
+}
+
+impl From<OrderInitialized> for OrderInitializedSchema {
+    fn from(value: OrderInitialized) -> Self {
+        Self {
+            trader_id: value.trader_id,
+            strategy_id: value.strategy_id,
+            instrument_id: value.instrument_id,
+            client_order_id: value.client_order_id,
+            order_side: value.order_side,
+            order_type: value.order_type,
+            quantity: value.quantity,
+            time_in_force: value.time_in_force,
+            post_only: value.post_only,
+            reduce_only: value.reduce_only,
+            quote_quantity: value.quote_quantity,
+            options: OrderInitializedOptions {
+                order_type: Some(value.order_type),
+                price: value.price,
+                trigger_price: value.trigger_price,
+                trigger_type: value.trigger_type,
+                limit_offset: value.limit_offset,
+                trailing_offset: value.trailing_offset,
+                trailing_offset_type: value.trailing_offset_type,
+                expire_time_ns: value.expire_time.map(|ns| ns.as_u64()),
+                display_qty: value.display_qty,
+            },
+            emulation_trigger: value.emulation_trigger.unwrap_or(TriggerType::NoTrigger),
+            trigger_instrument_id: value.trigger_instrument_id,
+            contingency_type: value
+                .contingency_type
+                .unwrap_or(ContingencyType::NoContingency),
+            order_list_id: value.order_list_id,
+            linked_order_ids: value.linked_order_ids,
+            parent_order_id: value.parent_order_id,
+            exec_algorithm_id: value.exec_algorithm_id,
+            exec_algorithm_params: value.exec_algorithm_params,
+            exec_spawn_id: value.exec_spawn_id,
+            tags: value.tags,
+            event_id: value.event_id,
+            ts_event: value.ts_event,
+            ts_init: value.ts_init,
+            reconciliation: value.reconciliation,
+        }
+    }
+}
+
+impl From<OrderInitializedSchema> for OrderInitialized {
+    fn from(value: OrderInitializedSchema) -> Self {
+        let options = value.options;
+        Self {
+            trader_id: value.trader_id,
+            strategy_id: value.strategy_id,
+            instrument_id: value.instrument_id,
+            client_order_id: value.client_order_id,
+            order_side: value.order_side,
+            order_type: value.order_type,
+            quantity: value.quantity,
+            time_in_force: value.time_in_force,
+            post_only: value.post_only,
+            reduce_only: value.reduce_only,
+            quote_quantity: value.quote_quantity,
+            reconciliation: value.reconciliation,
+            event_id: value.event_id,
+            ts_event: value.ts_event,
+            ts_init: value.ts_init,
+            price: options.price,
+            trigger_price: options.trigger_price,
+            trigger_type: options.trigger_type,
+            limit_offset: options.limit_offset,
+            trailing_offset: options.trailing_offset,
+            trailing_offset_type: options.trailing_offset_type,
+            expire_time: options
+                .expire_time_ns
+                .filter(|&ns| ns > 0)
+                .map(UnixNanos::from),
+            display_qty: options.display_qty,
+            emulation_trigger: Some(value.emulation_trigger)
+                .filter(|&trigger| trigger != TriggerType::NoTrigger),
+            trigger_instrument_id: value.trigger_instrument_id,
+            contingency_type: Some(value.contingency_type)
+                .filter(|&contingency| contingency != ContingencyType::NoContingency),
+            order_list_id: value.order_list_id,
+            linked_order_ids: value.linked_order_ids,
+            parent_order_id: value.parent_order_id,
+            exec_algorithm_id: value.exec_algorithm_id,
+            exec_algorithm_params: value.exec_algorithm_params,
+            exec_spawn_id: value.exec_spawn_id,
+            tags: value.tags,
+        }
+    }
+}
+
 impl Debug for OrderInitialized {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -444,7 +650,7 @@ impl OrderEvent for OrderInitialized {
     }
 
     fn reconciliation(&self) -> bool {
-        false
+        self.reconciliation
     }
 
     fn price(&self) -> Option<Price> {
@@ -0,0 +1,302 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{
+    ffi::{cvec::CVec, parsing::u8_as_bool},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
+
+use super::pnls_fill;
+use crate::{
+    accounts::{base::Account, cash::CashAccount},
+    enums::{AccountType, OrderSide},
+    events::account::state::AccountState,
+    ffi::{instruments::any::InstrumentAny_API, position::Position_API},
+    identifiers::AccountId,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`CashAccount`].
+///
+/// This struct wraps `CashAccount` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CashAccount` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `CashAccount_API` to be
+/// dereferenced to `CashAccount`, providing access to `CashAccount`'s methods without
+/// having to manually access the underlying `CashAccount` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct CashAccount_API(Box<CashAccount>);
+
+impl CashAccount_API {
+    #[must_use]
+    pub fn new(account: CashAccount) -> Self {
+        Self(Box::new(account))
+    }
+}
+
+impl Deref for CashAccount_API {
+    type Target = CashAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CashAccount_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new cash account with no balances.
+///
+/// # Safety
+///
+/// - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn cash_account_new(
+    account_id: AccountId,
+    base_currency: *const Currency,
+    calculate_account_state: u8,
+    ts_init: UnixNanos,
+) -> CashAccount_API {
+    let event = AccountState::new(
+        account_id,
+        AccountType::Cash,
+        vec![],
+        vec![],
+        false,
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        base_currency.as_ref().copied(),
+    );
+    CashAccount_API(Box::new(CashAccount::new(
+        event,
+        u8_as_bool(calculate_account_state),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn cash_account_drop(account: CashAccount_API) {
+    drop(account); // Memory freed here
+}
+
+/// Updates the balance of the account for the currency of `total`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `total` is not the sum of `locked` and `free`.
+/// - If `total` is negative.
+#[no_mangle]
+pub extern "C" fn cash_account_update_balance(
+    account: &mut CashAccount_API,
+    total: Money,
+    locked: Money,
+    free: Money,
+) {
+    account.update_balances(vec![AccountBalance::new(total, locked, free)]);
+}
+
+/// Returns the total balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn cash_account_balance_total(
+    account: &CashAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_total(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+/// Returns the free balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn cash_account_balance_free(
+    account: &CashAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_free(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+/// Returns the locked balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn cash_account_balance_locked(
+    account: &CashAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_locked(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+/// Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `side` is `NO_ORDER_SIDE`.
+#[no_mangle]
+pub extern "C" fn cash_account_calculate_balance_locked(
+    account: &mut CashAccount_API,
+    instrument: &InstrumentAny_API,
+    side: OrderSide,
+    quantity: Quantity,
+    price: Price,
+    use_quote_for_inverse: u8,
+) -> Money {
+    account
+        .calculate_balance_locked(
+            (**instrument).clone(),
+            side,
+            quantity,
+            price,
+            Some(u8_as_bool(use_quote_for_inverse)),
+        )
+        .unwrap()
+}
+
+/// Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+/// freed with `vec_money_drop`.
+///
+/// # Safety
+///
+/// - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `order_side` is `NO_ORDER_SIDE`.
+#[no_mangle]
+pub unsafe extern "C" fn cash_account_calculate_pnls(
+    account: &CashAccount_API,
+    instrument: &InstrumentAny_API,
+    order_side: OrderSide,
+    last_qty: Quantity,
+    last_px: Price,
+    position: *const Position_API,
+) -> CVec {
+    let (fill, position) = pnls_fill(instrument, order_side, last_qty, last_px, position);
+    account
+        .calculate_pnls((**instrument).clone(), fill, position)
+        .unwrap()
+        .into()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        ffi::types::money::vec_money_drop,
+        instruments::{stubs::audusd_sim, Instrument},
+    };
+
+    #[rstest]
+    fn test_cash_account_update_balance() {
+        let usd = Currency::USD();
+        let mut account =
+            unsafe { cash_account_new(AccountId::from("SIM-001"), &usd, 1, 0.into()) };
+
+        assert_eq!(
+            cash_account_balance_total(&account, usd),
+            Money::from("0 USD")
+        );
+
+        cash_account_update_balance(
+            &mut account,
+            Money::from("1000000 USD"),
+            Money::from("25000 USD"),
+            Money::from("975000 USD"),
+        );
+
+        assert_eq!(
+            cash_account_balance_total(&account, usd),
+            Money::from("1000000 USD")
+        );
+        assert_eq!(
+            cash_account_balance_locked(&account, usd),
+            Money::from("25000 USD")
+        );
+        assert_eq!(
+            cash_account_balance_free(&account, usd),
+            Money::from("975000 USD")
+        );
+        cash_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_cash_account_calculate_balance_locked() {
+        let instrument = InstrumentAny_API::from(audusd_sim().into_any());
+        let mut account =
+            unsafe { cash_account_new(AccountId::from("SIM-001"), &Currency::USD(), 1, 0.into()) };
+
+        let buy = cash_account_calculate_balance_locked(
+            &mut account,
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("1000000"),
+            Price::from("0.8"),
+            0,
+        );
+        let sell = cash_account_calculate_balance_locked(
+            &mut account,
+            &instrument,
+            OrderSide::Sell,
+            Quantity::from("1000000"),
+            Price::from("0.8"),
+            0,
+        );
+
+        assert_eq!(buy, Money::from("800032 USD"));
+        assert_eq!(sell, Money::from("1000040 AUD"));
+        cash_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_cash_account_calculate_pnls() {
+        let instrument = InstrumentAny_API::from(audusd_sim().into_any());
+        let account =
+            unsafe { cash_account_new(AccountId::from("SIM-001"), &Currency::USD(), 1, 0.into()) };
+
+        let pnls = unsafe {
+            cash_account_calculate_pnls(
+                &account,
+                &instrument,
+                OrderSide::Buy,
+                Quantity::from("1000000"),
+                Price::from("0.8"),
+                std::ptr::null(),
+            )
+        };
+        let values: &[Money] = unsafe { std::slice::from_raw_parts(pnls.ptr.cast(), pnls.len) };
+
+        assert_eq!(values, [Money::from("-800000 USD")]);
+        vec_money_drop(pnls);
+        cash_account_drop(account);
+    }
+}
@@ -0,0 +1,566 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{
+    ffi::{cvec::CVec, parsing::u8_as_bool},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
+
+use super::pnls_fill;
+use crate::{
+    accounts::{base::Account, margin::MarginAccount},
+    enums::{AccountType, OrderSide},
+    events::account::state::AccountState,
+    ffi::{instruments::any::InstrumentAny_API, position::Position_API},
+    identifiers::{AccountId, InstrumentId},
+    instruments::any::InstrumentAny,
+    types::{
+        balance::AccountBalance, currency::Currency, money::Money, price::Price, quantity::Quantity,
+    },
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`MarginAccount`].
+///
+/// This struct wraps `MarginAccount` in a way that makes it compatible with C function
+/// calls, enabling interaction with `MarginAccount` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `MarginAccount_API` to be
+/// dereferenced to `MarginAccount`, providing access to `MarginAccount`'s methods without
+/// having to manually access the underlying `MarginAccount` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct MarginAccount_API(Box<MarginAccount>);
+
+impl MarginAccount_API {
+    #[must_use]
+    pub fn new(account: MarginAccount) -> Self {
+        Self(Box::new(account))
+    }
+}
+
+impl Deref for MarginAccount_API {
+    type Target = MarginAccount;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for MarginAccount_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new margin account with no balances (and a default leverage of 1).
+///
+/// # Safety
+///
+/// - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn margin_account_new(
+    account_id: AccountId,
+    base_currency: *const Currency,
+    calculate_account_state: u8,
+    ts_init: UnixNanos,
+) -> MarginAccount_API {
+    let event = AccountState::new(
+        account_id,
+        AccountType::Margin,
+        vec![],
+        vec![],
+        false,
+        UUID4::new(),
+        ts_init,
+        ts_init,
+        base_currency.as_ref().copied(),
+    );
+    MarginAccount_API(Box::new(MarginAccount::new(
+        event,
+        u8_as_bool(calculate_account_state),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn margin_account_drop(account: MarginAccount_API) {
+    drop(account); // Memory freed here
+}
+
+/// Updates the balance of the account for the currency of `total`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `total` is not the sum of `locked` and `free`.
+/// - If `total` is negative.
+#[no_mangle]
+pub extern "C" fn margin_account_update_balance(
+    account: &mut MarginAccount_API,
+    total: Money,
+    locked: Money,
+    free: Money,
+) {
+    account.update_balances(vec![AccountBalance::new(total, locked, free)]);
+}
+
+/// Returns the total balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn margin_account_balance_total(
+    account: &MarginAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_total(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+/// Returns the free balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn margin_account_balance_free(
+    account: &MarginAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_free(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+/// Returns the locked balance for the `currency` (zero if the account has no balance).
+#[no_mangle]
+pub extern "C" fn margin_account_balance_locked(
+    account: &MarginAccount_API,
+    currency: Currency,
+) -> Money {
+    account
+        .balance_locked(Some(currency))
+        .unwrap_or_else(|| Money::new(0.0, currency))
+}
+
+#[no_mangle]
+pub extern "C" fn margin_account_set_default_leverage(
+    account: &mut MarginAccount_API,
+    leverage: f64,
+) {
+    account.set_default_leverage(leverage);
+}
+
+#[no_mangle]
+pub extern "C" fn margin_account_set_leverage(
+    account: &mut MarginAccount_API,
+    instrument_id: InstrumentId,
+    leverage: f64,
+) {
+    account.set_leverage(instrument_id, leverage);
+}
+
+#[no_mangle]
+pub extern "C" fn margin_account_leverage(
+    account: &MarginAccount_API,
+    instrument_id: InstrumentId,
+) -> f64 {
+    account.get_leverage(&instrument_id)
+}
+
+/// Updates the initial margin for the instrument, and recalculates the balance.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the account has no balance in the margin currency.
+/// - If the total margin exceeds the total balance.
+#[no_mangle]
+pub extern "C" fn margin_account_update_initial_margin(
+    account: &mut MarginAccount_API,
+    instrument_id: InstrumentId,
+    margin_init: Money,
+) {
+    account.update_initial_margin(instrument_id, margin_init);
+}
+
+/// Updates the maintenance margin for the instrument, and recalculates the balance.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the account has no balance in the margin currency.
+/// - If the total margin exceeds the total balance.
+#[no_mangle]
+pub extern "C" fn margin_account_update_maintenance_margin(
+    account: &mut MarginAccount_API,
+    instrument_id: InstrumentId,
+    margin_maintenance: Money,
+) {
+    account.update_maintenance_margin(instrument_id, margin_maintenance);
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If there is no margin for the instrument.
+#[no_mangle]
+pub extern "C" fn margin_account_initial_margin(
+    account: &MarginAccount_API,
+    instrument_id: InstrumentId,
+) -> Money {
+    account.initial_margin(instrument_id)
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If there is no margin for the instrument.
+#[no_mangle]
+pub extern "C" fn margin_account_maintenance_margin(
+    account: &MarginAccount_API,
+    instrument_id: InstrumentId,
+) -> Money {
+    account.maintenance_margin(instrument_id)
+}
+
+/// Returns the initial margin for a position of `quantity` at `price`, using the leverage for
+/// the instrument (where a zero leverage falls back to the default leverage).
+///
+/// For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+#[no_mangle]
+pub extern "C" fn margin_account_calculate_initial_margin(
+    account: &mut MarginAccount_API,
+    instrument: &InstrumentAny_API,
+    quantity: Quantity,
+    price: Price,
+    use_quote_for_inverse: u8,
+) -> Money {
+    let use_quote_for_inverse = Some(u8_as_bool(use_quote_for_inverse));
+    match (**instrument).clone() {
+        InstrumentAny::Betting(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::BinaryOption(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CryptoFuture(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CryptoPerpetual(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CurrencyPair(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::Equity(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::FuturesContract(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::FuturesSpread(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::OptionsContract(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::OptionsSpread(inst) => {
+            account.calculate_initial_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+    }
+}
+
+/// Returns the maintenance margin for a position of `quantity` at `price`, using the leverage for
+/// the instrument (where a zero leverage falls back to the default leverage).
+///
+/// For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+#[no_mangle]
+pub extern "C" fn margin_account_calculate_maintenance_margin(
+    account: &mut MarginAccount_API,
+    instrument: &InstrumentAny_API,
+    quantity: Quantity,
+    price: Price,
+    use_quote_for_inverse: u8,
+) -> Money {
+    let use_quote_for_inverse = Some(u8_as_bool(use_quote_for_inverse));
+    match (**instrument).clone() {
+        InstrumentAny::Betting(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::BinaryOption(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CryptoFuture(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CryptoPerpetual(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::CurrencyPair(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::Equity(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::FuturesContract(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::FuturesSpread(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::OptionsContract(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+        InstrumentAny::OptionsSpread(inst) => {
+            account.calculate_maintenance_margin(inst, quantity, price, use_quote_for_inverse)
+        }
+    }
+}
+
+/// Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `side` is `NO_ORDER_SIDE`.
+#[no_mangle]
+pub extern "C" fn margin_account_calculate_balance_locked(
+    account: &mut MarginAccount_API,
+    instrument: &InstrumentAny_API,
+    side: OrderSide,
+    quantity: Quantity,
+    price: Price,
+    use_quote_for_inverse: u8,
+) -> Money {
+    account
+        .calculate_balance_locked(
+            (**instrument).clone(),
+            side,
+            quantity,
+            price,
+            Some(u8_as_bool(use_quote_for_inverse)),
+        )
+        .unwrap()
+}
+
+/// Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+/// freed with `vec_money_drop`.
+///
+/// # Safety
+///
+/// - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `order_side` is `NO_ORDER_SIDE`.
+#[no_mangle]
+pub unsafe extern "C" fn margin_account_calculate_pnls(
+    account: &MarginAccount_API,
+    instrument: &InstrumentAny_API,
+    order_side: OrderSide,
+    last_qty: Quantity,
+    last_px: Price,
+    position: *const Position_API,
+) -> CVec {
+    let (fill, position) = pnls_fill(instrument, order_side, last_qty, last_px, position);
+    account
+        .calculate_pnls((**instrument).clone(), fill, position)
+        .unwrap()
+        .into()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        ffi::types::money::vec_money_drop,
+        instruments::{
+            stubs::{audusd_sim, xbtusd_bitmex},
+            Instrument,
+        },
+    };
+
+    fn margin_account() -> MarginAccount_API {
+        unsafe { margin_account_new(AccountId::from("SIM-001"), std::ptr::null(), 1, 0.into()) }
+    }
+
+    #[rstest]
+    fn test_margin_account_margins_lock_balance() {
+        let usd = Currency::USD();
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let mut account =
+            unsafe { margin_account_new(AccountId::from("SIM-001"), &usd, 1, 0.into()) };
+        margin_account_update_balance(
+            &mut account,
+            Money::from("1000000 USD"),
+            Money::from("0 USD"),
+            Money::from("1000000 USD"),
+        );
+
+        margin_account_update_initial_margin(&mut account, instrument_id, Money::from("2000 USD"));
+        margin_account_update_maintenance_margin(
+            &mut account,
+            instrument_id,
+            Money::from("1000 USD"),
+        );
+
+        assert_eq!(
+            margin_account_initial_margin(&account, instrument_id),
+            Money::from("2000 USD")
+        );
+        assert_eq!(
+            margin_account_maintenance_margin(&account, instrument_id),
+            Money::from("1000 USD")
+        );
+        assert_eq!(
+            margin_account_balance_total(&account, usd),
+            Money::from("1000000 USD")
+        );
+        assert_eq!(
+            margin_account_balance_locked(&account, usd),
+            Money::from("3000 USD")
+        );
+        assert_eq!(
+            margin_account_balance_free(&account, usd),
+            Money::from("997000 USD")
+        );
+        margin_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_margin_account_leverage() {
+        let instrument_id = InstrumentId::from("AUD/USD.SIM");
+        let mut account = unsafe {
+            margin_account_new(AccountId::from("SIM-001"), std::ptr::null(), 1, 0.into())
+        };
+
+        assert_eq!(margin_account_leverage(&account, instrument_id), 1.0);
+        margin_account_set_default_leverage(&mut account, 10.0);
+        assert_eq!(margin_account_leverage(&account, instrument_id), 10.0);
+        margin_account_set_leverage(&mut account, instrument_id, 50.0);
+        assert_eq!(margin_account_leverage(&account, instrument_id), 50.0);
+        margin_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_margin_account_calculate_initial_margin_with_leverage() {
+        let instrument = InstrumentAny_API::from(audusd_sim().into_any());
+        let mut account = margin_account();
+        margin_account_set_leverage(&mut account, instrument.id(), 50.0);
+
+        let margin = margin_account_calculate_initial_margin(
+            &mut account,
+            &instrument,
+            Quantity::from(100_000),
+            Price::from("0.8000"),
+            0,
+        );
+
+        assert_eq!(margin, Money::from("48.06 USD"));
+        margin_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_margin_account_calculate_initial_margin_with_zero_leverage_uses_default() {
+        let instrument = InstrumentAny_API::from(audusd_sim().into_any());
+        let mut account = margin_account();
+        margin_account_set_default_leverage(&mut account, 10.0);
+        margin_account_set_leverage(&mut account, instrument.id(), 0.0);
+
+        let margin = margin_account_calculate_initial_margin(
+            &mut account,
+            &instrument,
+            Quantity::from(100_000),
+            Price::from("0.8"),
+            0,
+        );
+
+        assert_eq!(margin, Money::from("240.32 USD"));
+        assert_eq!(margin_account_leverage(&account, instrument.id()), 10.0);
+        margin_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_margin_account_calculate_margins_for_inverse() {
+        let instrument = InstrumentAny_API::from(xbtusd_bitmex().into_any());
+        let mut account = margin_account();
+
+        let initial_base = margin_account_calculate_initial_margin(
+            &mut account,
+            &instrument,
+            Quantity::from(100_000),
+            Price::from("11493.60"),
+            0,
+        );
+        let initial_quote = margin_account_calculate_initial_margin(
+            &mut account,
+            &instrument,
+            Quantity::from(100_000),
+            Price::from("11493.60"),
+            1,
+        );
+        let maintenance = margin_account_calculate_maintenance_margin(
+            &mut account,
+            &instrument,
+            Quantity::from(100_000),
+            Price::from("11493.60"),
+            0,
+        );
+
+        assert_eq!(initial_base, Money::from("0.10005568 BTC"));
+        assert_eq!(initial_quote, Money::from("1150 USD"));
+        assert_eq!(maintenance, Money::from("0.03697710 BTC"));
+        margin_account_drop(account);
+    }
+
+    #[rstest]
+    fn test_margin_account_calculate_balance_locked_and_pnls() {
+        let instrument = InstrumentAny_API::from(audusd_sim().into_any());
+        let mut account = margin_account();
+
+        let locked = margin_account_calculate_balance_locked(
+            &mut account,
+            &instrument,
+            OrderSide::Buy,
+            Quantity::from("1000000"),
+            Price::from("0.8"),
+            0,
+        );
+        let pnls = unsafe {
+            margin_account_calculate_pnls(
+                &account,
+                &instrument,
+                OrderSide::Sell,
+                Quantity::from("1000000"),
+                Price::from("0.8"),
+                std::ptr::null(),
+            )
+        };
+        let mut values: Vec<Money> =
+            unsafe { std::slice::from_raw_parts(pnls.ptr.cast::<Money>(), pnls.len) }.to_vec();
+        values.sort_by_key(|money| money.currency.code);
+
+        assert_eq!(locked, Money::from("800032 USD"));
+        assert_eq!(
+            values,
+            vec![Money::from("-1000000 AUD"), Money::from("800000 USD")]
+        );
+        vec_money_drop(pnls);
+        margin_account_drop(account);
+    }
+}
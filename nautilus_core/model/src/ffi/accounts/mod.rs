@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+pub mod cash;
+pub mod margin;
+
+use crate::{
+    enums::OrderSide,
+    events::order::filled::OrderFilled,
+    ffi::position::Position_API,
+    instruments::any::InstrumentAny,
+    position::Position,
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Returns the fill and optional position for an account PnL calculation from the given fill details.
+///
+/// # Safety
+///
+/// - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+pub(crate) unsafe fn pnls_fill(
+    instrument: &InstrumentAny,
+    order_side: OrderSide,
+    last_qty: Quantity,
+    last_px: Price,
+    position: *const Position_API,
+) -> (OrderFilled, Option<Position>) {
+    let fill = OrderFilled {
+        instrument_id: instrument.id(),
+        order_side,
+        last_qty,
+        last_px,
+        currency: instrument.quote_currency(),
+        ..Default::default()
+    };
+    let position = position.as_ref().map(|position| (**position).clone());
+    (fill, position)
+}
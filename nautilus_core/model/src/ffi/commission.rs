@@ -0,0 +1,247 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::ffi::parsing::u8_as_bool;
+use rust_decimal::{prelude::FromPrimitive, Decimal};
+
+use crate::{
+    commission::{
+        calculate_notional, CommissionModel, CommissionModelAny, CommissionTier,
+        MakerTakerCommissionModel, PerContractCommissionModel, TieredCommissionModel,
+    },
+    enums::LiquiditySide,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`CommissionModelAny`].
+///
+/// This struct wraps `CommissionModelAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `CommissionModelAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `CommissionModel_API` to be
+/// dereferenced to `CommissionModelAny`, providing access to `CommissionModelAny`'s methods
+/// without having to manually access the underlying `CommissionModelAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct CommissionModel_API(Box<CommissionModelAny>);
+
+impl Deref for CommissionModel_API {
+    type Target = CommissionModelAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CommissionModel_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+pub(crate) fn f64_to_decimal(value: f64, name: &str) -> Decimal {
+    Decimal::from_f64(value).unwrap_or_else(|| panic!("Invalid `{name}`, was {value}"))
+}
+
+/// Creates a new maker/taker commission model with fees in basis points.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `maker_bps` or `taker_bps` is not finite.
+#[no_mangle]
+pub extern "C" fn commission_model_maker_taker_new(
+    maker_bps: f64,
+    taker_bps: f64,
+) -> CommissionModel_API {
+    CommissionModel_API(Box::new(CommissionModelAny::MakerTaker(
+        MakerTakerCommissionModel::new(
+            f64_to_decimal(maker_bps, "maker_bps"),
+            f64_to_decimal(taker_bps, "taker_bps"),
+        ),
+    )))
+}
+
+/// Creates a new per-contract commission model.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `fee_per_contract` is not finite or is negative.
+#[no_mangle]
+pub extern "C" fn commission_model_per_contract_new(fee_per_contract: f64) -> CommissionModel_API {
+    let model =
+        PerContractCommissionModel::new(f64_to_decimal(fee_per_contract, "fee_per_contract"))
+            .unwrap();
+    CommissionModel_API(Box::new(CommissionModelAny::PerContract(model)))
+}
+
+/// Creates a new tiered commission model from `len` tiers.
+///
+/// # Safety
+///
+/// - Assumes `min_volumes`, `maker_bps` and `taker_bps` are valid pointers to arrays of
+///   at least `len` elements.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If any value is not finite.
+/// - If the tiers are empty, do not start at zero volume, or are not in ascending order.
+#[no_mangle]
+pub unsafe extern "C" fn commission_model_tiered_new(
+    min_volumes: *const f64,
+    maker_bps: *const f64,
+    taker_bps: *const f64,
+    len: usize,
+) -> CommissionModel_API {
+    assert!(len > 0, "Invalid `len`, was zero");
+    let min_volumes = std::slice::from_raw_parts(min_volumes, len);
+    let maker_bps = std::slice::from_raw_parts(maker_bps, len);
+    let taker_bps = std::slice::from_raw_parts(taker_bps, len);
+    let tiers = (0..len)
+        .map(|i| {
+            CommissionTier::new(
+                f64_to_decimal(min_volumes[i], "min_volume"),
+                f64_to_decimal(maker_bps[i], "maker_bps"),
+                f64_to_decimal(taker_bps[i], "taker_bps"),
+            )
+        })
+        .collect();
+    let model = TieredCommissionModel::new(tiers).unwrap();
+    CommissionModel_API(Box::new(CommissionModelAny::Tiered(model)))
+}
+
+#[no_mangle]
+pub extern "C" fn commission_model_drop(model: CommissionModel_API) {
+    drop(model); // Memory freed here
+}
+
+/// Sets the traded volume used to select the tier of a tiered commission model.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `model` is not a tiered commission model.
+/// - If `volume` is not finite.
+#[no_mangle]
+pub extern "C" fn commission_model_set_volume(model: &mut CommissionModel_API, volume: f64) {
+    match &mut **model {
+        CommissionModelAny::Tiered(tiered) => tiered.set_volume(f64_to_decimal(volume, "volume")),
+        other => panic!("Cannot set volume for {other:?}"),
+    }
+}
+
+/// Calculates the commission for a fill, returned in the `settlement_currency`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `is_inverse` and `price` is zero.
+/// - If `liquidity_side` is `NO_LIQUIDITY_SIDE` for a maker/taker or tiered model.
+#[no_mangle]
+pub extern "C" fn commission_model_calculate(
+    model: &CommissionModel_API,
+    quantity: Quantity,
+    price: Price,
+    multiplier: Quantity,
+    is_inverse: u8,
+    settlement_currency: Currency,
+    liquidity_side: LiquiditySide,
+) -> Money {
+    let notional = calculate_notional(quantity, price, multiplier, u8_as_bool(is_inverse)).unwrap();
+    model
+        .calculate_for_notional(notional, quantity, liquidity_side, settlement_currency)
+        .unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_maker_taker_calculate() {
+        let model = commission_model_maker_taker_new(-0.25, 5.0);
+
+        let commission = commission_model_calculate(
+            &model,
+            Quantity::from(201),
+            Price::from("10.00"),
+            Quantity::from(1),
+            0,
+            Currency::USD(),
+            LiquiditySide::Taker,
+        );
+
+        assert_eq!(commission, Money::from("1.01 USD"));
+        commission_model_drop(model);
+    }
+
+    #[rstest]
+    fn test_per_contract_calculate() {
+        let model = commission_model_per_contract_new(0.0125);
+
+        let commission = commission_model_calculate(
+            &model,
+            Quantity::from(3),
+            Price::from("4500.25"),
+            Quantity::from(50),
+            0,
+            Currency::USD(),
+            LiquiditySide::Maker,
+        );
+
+        assert_eq!(commission, Money::from("0.04 USD"));
+        commission_model_drop(model);
+    }
+
+    #[rstest]
+    fn test_tiered_calculate_with_volume() {
+        let min_volumes = [0.0, 1_000_000.0];
+        let maker_bps = [10.0, 8.0];
+        let taker_bps = [20.0, 15.0];
+        let mut model = unsafe {
+            commission_model_tiered_new(
+                min_volumes.as_ptr(),
+                maker_bps.as_ptr(),
+                taker_bps.as_ptr(),
+                min_volumes.len(),
+            )
+        };
+        let calculate = |model: &CommissionModel_API| {
+            commission_model_calculate(
+                model,
+                Quantity::from(1),
+                Price::from("10000.00"),
+                Quantity::from(1),
+                0,
+                Currency::USD(),
+                LiquiditySide::Taker,
+            )
+        };
+
+        assert_eq!(calculate(&model), Money::from("20.00 USD"));
+        commission_model_set_volume(&mut model, 1_000_000.0);
+        assert_eq!(calculate(&model), Money::from("15.00 USD"));
+        commission_model_drop(model);
+    }
+}
@@ -0,0 +1,234 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ops::{Deref, DerefMut};
+
+use nautilus_core::{ffi::cvec::CVec, nanos::UnixNanos};
+
+use crate::{
+    data::{
+        bar::{Bar, BarType},
+        continuous::{
+            AdjustmentMethod, ContinuousContractBuilder, ContinuousSeries, RollEvent, RollRule,
+        },
+    },
+    identifiers::InstrumentId,
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying `ContinuousContractBuilder`.
+///
+/// This struct wraps `ContinuousContractBuilder` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ContinuousContractBuilder` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `ContinuousContractBuilder_API` to be
+/// dereferenced to `ContinuousContractBuilder`, providing access to `ContinuousContractBuilder`'s
+/// methods without having to manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ContinuousContractBuilder_API(Box<ContinuousContractBuilder>);
+
+impl Deref for ContinuousContractBuilder_API {
+    type Target = ContinuousContractBuilder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ContinuousContractBuilder_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// C compatible Foreign Function Interface (FFI) for an underlying `ContinuousSeries`.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ContinuousSeries_API(Box<ContinuousSeries>);
+
+impl Deref for ContinuousSeries_API {
+    type Target = ContinuousSeries;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn continuous_contract_builder_new(
+    bar_type: BarType,
+    roll_rule: RollRule,
+    roll_days: u32,
+    adjustment_method: AdjustmentMethod,
+) -> ContinuousContractBuilder_API {
+    ContinuousContractBuilder_API(Box::new(ContinuousContractBuilder::new(
+        bar_type,
+        roll_rule,
+        roll_days,
+        adjustment_method,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn continuous_contract_builder_drop(builder: ContinuousContractBuilder_API) {
+    drop(builder); // Memory freed here
+}
+
+/// Adds the bars for a single contract expiring at `expiration_ns`.
+///
+/// The bars are copied, so the caller retains ownership of the `bars` memory.
+///
+/// # Safety
+///
+/// - Assumes `bars` is a valid `CVec` containing `Bar` objects.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the contract was already added, or the bars are invalid for it.
+#[no_mangle]
+pub unsafe extern "C" fn continuous_contract_builder_add_contract(
+    builder: &mut ContinuousContractBuilder_API,
+    instrument_id: InstrumentId,
+    expiration_ns: u64,
+    bars: &CVec,
+) {
+    let CVec { ptr, len, .. } = *bars;
+    let bars: Vec<Bar> = std::slice::from_raw_parts(ptr.cast::<Bar>(), len).to_vec();
+    builder
+        .add_contract(instrument_id, UnixNanos::from(expiration_ns), bars)
+        .unwrap();
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If the continuous series cannot be built from the added contracts.
+#[no_mangle]
+pub extern "C" fn continuous_contract_builder_build(
+    builder: &ContinuousContractBuilder_API,
+) -> ContinuousSeries_API {
+    ContinuousSeries_API(Box::new(builder.build().unwrap()))
+}
+
+#[no_mangle]
+pub extern "C" fn continuous_series_drop(series: ContinuousSeries_API) {
+    drop(series); // Memory freed here
+}
+
+/// Returns a `CVec` of the adjusted `Bar`s, which must be freed with `vec_bars_drop`.
+#[no_mangle]
+pub extern "C" fn continuous_series_bars(series: &ContinuousSeries_API) -> CVec {
+    series.bars.clone().into()
+}
+
+/// Returns a `CVec` of the `RollEvent`s, which must be freed with `vec_roll_events_drop`.
+#[no_mangle]
+pub extern "C" fn continuous_series_rolls(series: &ContinuousSeries_API) -> CVec {
+    series.rolls.clone().into()
+}
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn vec_bars_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let bars: Vec<Bar> = unsafe { Vec::from_raw_parts(ptr.cast::<Bar>(), len, cap) };
+    drop(bars); // Memory freed here
+}
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn vec_roll_events_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let rolls: Vec<RollEvent> = unsafe { Vec::from_raw_parts(ptr.cast::<RollEvent>(), len, cap) };
+    drop(rolls); // Memory freed here
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::datetime::NANOSECONDS_IN_DAY;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::types::{price::Price, quantity::Quantity};
+
+    fn bar(bar_type: &str, n: u64, close: f64) -> Bar {
+        let price = Price::new(close, 2);
+        let ts = UnixNanos::from(n * NANOSECONDS_IN_DAY);
+        Bar::new(
+            BarType::from(bar_type),
+            price,
+            price,
+            price,
+            price,
+            Quantity::from("100"),
+            ts,
+            ts,
+        )
+    }
+
+    #[rstest]
+    fn test_continuous_contract_builder_ffi() {
+        let mut builder = continuous_contract_builder_new(
+            BarType::from("ES.GLBX-1-DAY-LAST-EXTERNAL"),
+            RollRule::DaysBeforeExpiry,
+            2,
+            AdjustmentMethod::Difference,
+        );
+        let front: CVec = (1..=5)
+            .map(|n| bar("ESH5.GLBX-1-DAY-LAST-EXTERNAL", n, 100.0))
+            .collect::<Vec<Bar>>()
+            .into();
+        let next: CVec = (3..=8)
+            .map(|n| bar("ESM5.GLBX-1-DAY-LAST-EXTERNAL", n, 110.0))
+            .collect::<Vec<Bar>>()
+            .into();
+
+        unsafe {
+            continuous_contract_builder_add_contract(
+                &mut builder,
+                InstrumentId::from("ESH5.GLBX"),
+                6 * NANOSECONDS_IN_DAY,
+                &front,
+            );
+            continuous_contract_builder_add_contract(
+                &mut builder,
+                InstrumentId::from("ESM5.GLBX"),
+                12 * NANOSECONDS_IN_DAY,
+                &next,
+            );
+        }
+        vec_bars_drop(front);
+        vec_bars_drop(next);
+
+        let series = continuous_contract_builder_build(&builder);
+        let bars = continuous_series_bars(&series);
+        let rolls = continuous_series_rolls(&series);
+
+        assert_eq!(bars.len, 8);
+        assert_eq!(rolls.len, 1);
+        let roll = unsafe { &*rolls.ptr.cast::<RollEvent>() };
+        assert_eq!(roll.ts_roll, UnixNanos::from(4 * NANOSECONDS_IN_DAY));
+        assert_eq!(roll.adjustment, 10.0);
+
+        vec_bars_drop(bars);
+        vec_roll_events_drop(rolls);
+        continuous_series_drop(series);
+        continuous_contract_builder_drop(builder);
+    }
+}
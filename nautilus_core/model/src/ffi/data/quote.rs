@@ -22,9 +22,9 @@ use std::{
 use nautilus_core::{ffi::string::str_to_cstr, nanos::UnixNanos};
 
 use crate::{
-    data::quote::QuoteTick,
+    data::quote::{cross, spread, QuoteTick},
     identifiers::InstrumentId,
-    types::{price::Price, quantity::Quantity},
+    types::{currency::Currency, price::Price, quantity::Quantity},
 };
 
 #[no_mangle]
@@ -77,3 +77,129 @@ pub extern "C" fn quote_tick_hash(delta: &QuoteTick) -> u64 {
 pub extern "C" fn quote_tick_to_cstr(quote: &QuoteTick) -> *const c_char {
     str_to_cstr(&quote.to_string())
 }
+
+/// Returns the [`QuoteTick`] inverted for the pair with the base and quote currencies swapped.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the bid or ask price is not positive.
+#[no_mangle]
+pub extern "C" fn quote_tick_invert(quote: &QuoteTick, instrument_id: InstrumentId) -> QuoteTick {
+    quote.invert(instrument_id)
+}
+
+/// Derives the [`QuoteTick`] for the `base`/`quote` pair from two quotes for pairs which share
+/// a currency, writing it to `cross_quote`.
+///
+/// Returns 1 if the cross quote was derived, otherwise 0 (with `cross_quote` left unwritten).
+///
+/// # Safety
+///
+/// - Assumes `cross_quote` is a valid pointer to write the [`QuoteTick`] to.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn quote_tick_cross(
+    a: &QuoteTick,
+    a_base: Currency,
+    a_quote: Currency,
+    b: &QuoteTick,
+    b_base: Currency,
+    b_quote: Currency,
+    instrument_id: InstrumentId,
+    base: Currency,
+    quote: Currency,
+    cross_quote: *mut QuoteTick,
+) -> u8 {
+    let result = cross(
+        a,
+        (a_base, a_quote),
+        b,
+        (b_base, b_quote),
+        instrument_id,
+        (base, quote),
+    )
+    .map(|quote| cross_quote.write(quote));
+    u8::from(result.is_ok())
+}
+
+/// Returns the spread between the given `bid` and `ask` prices, or `NaN` for a crossed book.
+#[no_mangle]
+pub extern "C" fn price_spread(bid: f64, ask: f64) -> f64 {
+    spread(bid, ask)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    fn quote(instrument_id: &str, bid: &str, ask: &str) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from(instrument_id),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from("1000"),
+            Quantity::from("1000"),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_quote_tick_cross() {
+        let eurusd = quote("EUR/USD.SIM", "1.1000", "1.1002");
+        let usdjpy = quote("USD/JPY.SIM", "150.00", "150.02");
+        let mut eurjpy = MaybeUninit::<QuoteTick>::uninit();
+
+        let status = unsafe {
+            quote_tick_cross(
+                &eurusd,
+                Currency::EUR(),
+                Currency::USD(),
+                &usdjpy,
+                Currency::USD(),
+                Currency::JPY(),
+                InstrumentId::from("EUR/JPY.SIM"),
+                Currency::EUR(),
+                Currency::JPY(),
+                eurjpy.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(status, 1);
+        let eurjpy = unsafe { eurjpy.assume_init() };
+        assert_eq!(eurjpy.bid_price, Price::from("165.00"));
+        assert_eq!(eurjpy.ask_price, Price::from("165.06"));
+    }
+
+    #[rstest]
+    fn test_quote_tick_cross_without_shared_currency() {
+        let eurusd = quote("EUR/USD.SIM", "1.1000", "1.1002");
+        let gbpjpy = quote("GBP/JPY.SIM", "190.00", "190.02");
+        let mut eurjpy = MaybeUninit::<QuoteTick>::uninit();
+
+        let status = unsafe {
+            quote_tick_cross(
+                &eurusd,
+                Currency::EUR(),
+                Currency::USD(),
+                &gbpjpy,
+                Currency::GBP(),
+                Currency::JPY(),
+                InstrumentId::from("EUR/JPY.SIM"),
+                Currency::EUR(),
+                Currency::JPY(),
+                eurjpy.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(status, 0);
+    }
+}
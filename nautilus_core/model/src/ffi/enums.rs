@@ -17,17 +17,20 @@ use std::{ffi::c_char, str::FromStr};
 
 use nautilus_core::ffi::string::{cstr_to_str, str_to_cstr};
 
-use crate::enums::{
-    AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
-    BookType, ContingencyType, CurrencyType, InstrumentClass, InstrumentCloseType, LiquiditySide,
-    MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide, OrderStatus, OrderType,
-    PositionSide, PriceType, RecordFlag, TimeInForce, TradingState, TrailingOffsetType,
-    TriggerType,
+use crate::{
+    enums::{
+        AccountType, AggregationSource, AggressorSide, AssetClass, BarAggregation, BookAction,
+        BookType, ContingencyType, CurrencyType, Direction, InstrumentClass, InstrumentCloseType,
+        LiquiditySide, MarketStatus, MarketStatusAction, OmsType, OptionKind, OrderSide,
+        OrderStatus, OrderType, PositionSide, PriceType, RecordFlag, RoundingMode, TimeInForce,
+        TradingState, TrailingOffsetType, TriggerType,
+    },
+    orders,
 };
 
 #[no_mangle]
 pub extern "C" fn account_type_to_cstr(value: AccountType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -42,9 +45,24 @@ pub unsafe extern "C" fn account_type_from_cstr(ptr: *const c_char) -> AccountTy
         .unwrap_or_else(|_| panic!("invalid `AccountType` enum string value, was '{value}'"))
 }
 
+/// Returns an enum from its name or a common broker alias (e.g. `SPOT` or `CROSS_MARGIN`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the string is not a supported `AccountType` alias.
+#[no_mangle]
+pub unsafe extern "C" fn account_type_from_broker_alias(ptr: *const c_char) -> AccountType {
+    AccountType::from_broker_alias(cstr_to_str(ptr)).unwrap_or_else(|e| panic!("{e}"))
+}
+
 #[no_mangle]
 pub extern "C" fn aggregation_source_to_cstr(value: AggregationSource) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -61,7 +79,7 @@ pub unsafe extern "C" fn aggregation_source_from_cstr(ptr: *const c_char) -> Agg
 
 #[no_mangle]
 pub extern "C" fn aggressor_side_to_cstr(value: AggressorSide) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -76,9 +94,33 @@ pub unsafe extern "C" fn aggressor_side_from_cstr(ptr: *const c_char) -> Aggress
         .unwrap_or_else(|_| panic!("invalid `AggressorSide` enum string value, was '{value}'"))
 }
 
+/// Returns the opposite aggressor side (`NO_AGGRESSOR` is unchanged).
+#[no_mangle]
+pub extern "C" fn aggressor_side_opposite(value: AggressorSide) -> AggressorSide {
+    value.opposite()
+}
+
+/// Returns whether the aggressor side is `BUYER`.
+#[no_mangle]
+pub extern "C" fn aggressor_side_is_long_side(value: AggressorSide) -> u8 {
+    u8::from(value.is_long_side())
+}
+
+/// Returns whether the aggressor side is `SELLER`.
+#[no_mangle]
+pub extern "C" fn aggressor_side_is_short_side(value: AggressorSide) -> u8 {
+    u8::from(value.is_short_side())
+}
+
+/// Returns the liquidity side of the passive counterparty to a trade with the aggressor side.
+#[no_mangle]
+pub extern "C" fn aggressor_side_maker_side(value: AggressorSide) -> LiquiditySide {
+    value.maker_side()
+}
+
 #[no_mangle]
 pub extern "C" fn asset_class_to_cstr(value: AssetClass) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -93,9 +135,24 @@ pub unsafe extern "C" fn asset_class_from_cstr(ptr: *const c_char) -> AssetClass
         .unwrap_or_else(|_| panic!("invalid `AssetClass` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn asset_class_is_crypto(value: AssetClass) -> u8 {
+    u8::from(value.is_crypto())
+}
+
+#[no_mangle]
+pub extern "C" fn asset_class_is_24_7(value: AssetClass) -> u8 {
+    u8::from(value.is_24_7())
+}
+
+#[no_mangle]
+pub extern "C" fn asset_class_description(value: AssetClass) -> *const c_char {
+    str_to_cstr(value.description())
+}
+
 #[no_mangle]
 pub extern "C" fn instrument_class_to_cstr(value: InstrumentClass) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -110,9 +167,24 @@ pub unsafe extern "C" fn instrument_class_from_cstr(ptr: *const c_char) -> Instr
         .unwrap_or_else(|_| panic!("invalid `InstrumentClass` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn instrument_class_is_24_7(value: InstrumentClass) -> u8 {
+    u8::from(value.is_24_7())
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_class_requires_expiry(value: InstrumentClass) -> u8 {
+    u8::from(value.requires_expiry())
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_class_description(value: InstrumentClass) -> *const c_char {
+    str_to_cstr(value.description())
+}
+
 #[no_mangle]
 pub extern "C" fn bar_aggregation_to_cstr(value: BarAggregation) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -127,9 +199,24 @@ pub unsafe extern "C" fn bar_aggregation_from_cstr(ptr: *const c_char) -> BarAgg
         .unwrap_or_else(|_| panic!("invalid `BarAggregation` enum string value, was '{value}'"))
 }
 
+/// Returns an enum from a common time unit abbreviation (e.g. `M`, `H` or `D`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the string is neither a supported abbreviation nor a `BarAggregation` name.
+#[no_mangle]
+pub unsafe extern "C" fn bar_aggregation_from_abbrev(ptr: *const c_char) -> BarAggregation {
+    BarAggregation::from_abbrev(cstr_to_str(ptr)).unwrap_or_else(|e| panic!("{e}"))
+}
+
 #[no_mangle]
 pub extern "C" fn book_action_to_cstr(value: BookAction) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -144,9 +231,14 @@ pub unsafe extern "C" fn book_action_from_cstr(ptr: *const c_char) -> BookAction
         .unwrap_or_else(|_| panic!("invalid `BookAction` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn book_action_priority(value: BookAction) -> u8 {
+    value.priority()
+}
+
 #[no_mangle]
 pub extern "C" fn book_type_to_cstr(value: BookType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -161,9 +253,15 @@ pub unsafe extern "C" fn book_type_from_cstr(ptr: *const c_char) -> BookType {
         .unwrap_or_else(|_| panic!("invalid `BookType` enum string value, was '{value}'"))
 }
 
+/// Returns whether deltas with the given `action` are meaningful for a book of the book type.
+#[no_mangle]
+pub extern "C" fn book_type_allows_action(value: BookType, action: BookAction) -> u8 {
+    u8::from(value.allows_action(action))
+}
+
 #[no_mangle]
 pub extern "C" fn contingency_type_to_cstr(value: ContingencyType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -178,9 +276,24 @@ pub unsafe extern "C" fn contingency_type_from_cstr(ptr: *const c_char) -> Conti
         .unwrap_or_else(|_| panic!("invalid `ContingencyType` enum string value, was '{value}'"))
 }
 
+/// Returns an enum from its name or a common venue alias (e.g. `O.C.O` or `OneCancelsOther`).
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the string is not a supported `ContingencyType` alias.
+#[no_mangle]
+pub unsafe extern "C" fn contingency_type_from_alias(ptr: *const c_char) -> ContingencyType {
+    ContingencyType::from_alias(cstr_to_str(ptr)).unwrap_or_else(|e| panic!("{e}"))
+}
+
 #[no_mangle]
 pub extern "C" fn currency_type_to_cstr(value: CurrencyType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -195,6 +308,11 @@ pub unsafe extern "C" fn currency_type_from_cstr(ptr: *const c_char) -> Currency
         .unwrap_or_else(|_| panic!("invalid `CurrencyType` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn currency_type_default_precision(value: CurrencyType) -> u8 {
+    value.default_precision()
+}
+
 /// Returns an enum from a Python string.
 ///
 /// # Safety
@@ -212,12 +330,12 @@ pub unsafe extern "C" fn instrument_close_type_from_cstr(
 
 #[no_mangle]
 pub extern "C" fn instrument_close_type_to_cstr(value: InstrumentCloseType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 #[no_mangle]
 pub extern "C" fn liquidity_side_to_cstr(value: LiquiditySide) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -232,9 +350,29 @@ pub unsafe extern "C" fn liquidity_side_from_cstr(ptr: *const c_char) -> Liquidi
         .unwrap_or_else(|_| panic!("invalid `LiquiditySide` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn liquidity_side_is_maker(value: LiquiditySide) -> u8 {
+    u8::from(value.is_maker())
+}
+
+#[no_mangle]
+pub extern "C" fn liquidity_side_is_taker(value: LiquiditySide) -> u8 {
+    u8::from(value.is_taker())
+}
+
+/// Returns the applicable fee rate for the liquidity side, or NaN if `NO_LIQUIDITY_SIDE`.
+#[no_mangle]
+pub extern "C" fn liquidity_side_fee_rate(
+    value: LiquiditySide,
+    maker_rate: f64,
+    taker_rate: f64,
+) -> f64 {
+    value.fee_rate(maker_rate, taker_rate).unwrap_or(f64::NAN)
+}
+
 #[no_mangle]
 pub extern "C" fn market_status_to_cstr(value: MarketStatus) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -251,7 +389,24 @@ pub unsafe extern "C" fn market_status_from_cstr(ptr: *const c_char) -> MarketSt
 
 #[no_mangle]
 pub extern "C" fn market_status_action_to_cstr(value: MarketStatusAction) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
+}
+
+/// Returns the session phase which typically follows `value`, or `NONE` if the action
+/// carries no session phase.
+#[no_mangle]
+pub extern "C" fn market_status_action_next_expected(
+    value: MarketStatusAction,
+) -> MarketStatusAction {
+    value.next_expected().unwrap_or(MarketStatusAction::None)
+}
+
+#[no_mangle]
+pub extern "C" fn market_status_action_valid_transition(
+    from: MarketStatusAction,
+    to: MarketStatusAction,
+) -> u8 {
+    u8::from(MarketStatusAction::valid_transition(from, to))
 }
 
 /// Returns an enum from a Python string.
@@ -268,7 +423,7 @@ pub unsafe extern "C" fn market_status_action_from_cstr(ptr: *const c_char) -> M
 
 #[no_mangle]
 pub extern "C" fn oms_type_to_cstr(value: OmsType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -283,9 +438,15 @@ pub unsafe extern "C" fn oms_type_from_cstr(ptr: *const c_char) -> OmsType {
         .unwrap_or_else(|_| panic!("invalid `OmsType` enum string value, was '{value}'"))
 }
 
+/// Returns the default OMS type for the given `account` type.
+#[no_mangle]
+pub extern "C" fn oms_type_default_for_account(account: AccountType) -> OmsType {
+    OmsType::default_for_account(account)
+}
+
 #[no_mangle]
 pub extern "C" fn option_kind_to_cstr(value: OptionKind) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -302,7 +463,7 @@ pub unsafe extern "C" fn option_kind_from_cstr(ptr: *const c_char) -> OptionKind
 
 #[no_mangle]
 pub extern "C" fn order_side_to_cstr(value: OrderSide) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -317,9 +478,32 @@ pub unsafe extern "C" fn order_side_from_cstr(ptr: *const c_char) -> OrderSide {
         .unwrap_or_else(|_| panic!("invalid `OrderSide` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn order_side_as_signed_multiplier(value: OrderSide) -> f64 {
+    value.as_signed_multiplier()
+}
+
+/// Returns the opposite order side (`NO_ORDER_SIDE` is unchanged).
+#[no_mangle]
+pub extern "C" fn order_side_opposite(value: OrderSide) -> OrderSide {
+    value.opposite()
+}
+
+/// Returns whether the order side is `BUY`.
+#[no_mangle]
+pub extern "C" fn order_side_is_long_side(value: OrderSide) -> u8 {
+    u8::from(value.is_long_side())
+}
+
+/// Returns whether the order side is `SELL`.
+#[no_mangle]
+pub extern "C" fn order_side_is_short_side(value: OrderSide) -> u8 {
+    u8::from(value.is_short_side())
+}
+
 #[no_mangle]
 pub extern "C" fn order_status_to_cstr(value: OrderStatus) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -334,9 +518,49 @@ pub unsafe extern "C" fn order_status_from_cstr(ptr: *const c_char) -> OrderStat
         .unwrap_or_else(|_| panic!("invalid `OrderStatus` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn order_status_valid_transition(
+    from_status: OrderStatus,
+    to_status: OrderStatus,
+) -> u8 {
+    u8::from(orders::base::order_status_valid_transition(
+        from_status,
+        to_status,
+    ))
+}
+
+/// Returns whether an order with the status is live on the trading venue and can still fill.
+#[no_mangle]
+pub extern "C" fn order_status_is_active(value: OrderStatus) -> u8 {
+    u8::from(value.is_active())
+}
+
+#[no_mangle]
+pub extern "C" fn order_type_requires_trigger_type(value: OrderType) -> u8 {
+    u8::from(value.requires_trigger_type())
+}
+
+#[no_mangle]
+pub extern "C" fn order_type_trigger_compatible(order_type: OrderType, trigger: TriggerType) -> u8 {
+    u8::from(orders::base::trigger_compatible(order_type, trigger))
+}
+
+#[no_mangle]
+pub extern "C" fn order_type_is_trailing(value: OrderType) -> u8 {
+    u8::from(value.is_trailing())
+}
+
+#[no_mangle]
+pub extern "C" fn order_type_trailing_offset_compatible(
+    order_type: OrderType,
+    offset: TrailingOffsetType,
+) -> u8 {
+    u8::from(orders::base::trailing_offset_compatible(order_type, offset))
+}
+
 #[no_mangle]
 pub extern "C" fn order_type_to_cstr(value: OrderType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -353,7 +577,7 @@ pub unsafe extern "C" fn order_type_from_cstr(ptr: *const c_char) -> OrderType {
 
 #[no_mangle]
 pub extern "C" fn position_side_to_cstr(value: PositionSide) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -368,9 +592,34 @@ pub unsafe extern "C" fn position_side_from_cstr(ptr: *const c_char) -> Position
         .unwrap_or_else(|_| panic!("invalid `PositionSide` enum string value, was '{value}'"))
 }
 
+/// Returns the opposite side (`LONG` for `SHORT` and vice versa), with `FLAT` and
+/// `NO_POSITION_SIDE` unchanged.
+#[no_mangle]
+pub extern "C" fn position_side_opposite(value: PositionSide) -> PositionSide {
+    value.opposite()
+}
+
+/// Returns whether the side is `FLAT`.
+#[no_mangle]
+pub extern "C" fn position_side_is_flat(value: PositionSide) -> u8 {
+    u8::from(value.is_flat())
+}
+
+/// Returns whether the side is `LONG`.
+#[no_mangle]
+pub extern "C" fn position_side_is_long_side(value: PositionSide) -> u8 {
+    u8::from(value.is_long_side())
+}
+
+/// Returns whether the side is `SHORT`.
+#[no_mangle]
+pub extern "C" fn position_side_is_short_side(value: PositionSide) -> u8 {
+    u8::from(value.is_short_side())
+}
+
 #[no_mangle]
 pub extern "C" fn price_type_to_cstr(value: PriceType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -387,7 +636,7 @@ pub unsafe extern "C" fn price_type_from_cstr(ptr: *const c_char) -> PriceType {
 
 #[no_mangle]
 pub extern "C" fn record_flag_to_cstr(value: RecordFlag) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -402,9 +651,26 @@ pub unsafe extern "C" fn record_flag_from_cstr(ptr: *const c_char) -> RecordFlag
         .unwrap_or_else(|_| panic!("invalid `RecordFlag` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn rounding_mode_to_cstr(value: RoundingMode) -> *const c_char {
+    str_to_cstr(value.as_str())
+}
+
+/// Returns an enum from a Python string.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rounding_mode_from_cstr(ptr: *const c_char) -> RoundingMode {
+    let value = cstr_to_str(ptr);
+    RoundingMode::from_str(value)
+        .unwrap_or_else(|_| panic!("invalid `RoundingMode` enum string value, was '{value}'"))
+}
+
 #[no_mangle]
 pub extern "C" fn time_in_force_to_cstr(value: TimeInForce) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -419,9 +685,19 @@ pub unsafe extern "C" fn time_in_force_from_cstr(ptr: *const c_char) -> TimeInFo
         .unwrap_or_else(|_| panic!("invalid `TimeInForce` enum string value, was '{value}'"))
 }
 
+#[no_mangle]
+pub extern "C" fn time_in_force_is_immediate(value: TimeInForce) -> u8 {
+    u8::from(value.is_immediate())
+}
+
+#[no_mangle]
+pub extern "C" fn time_in_force_allows_partial(value: TimeInForce) -> u8 {
+    u8::from(value.allows_partial())
+}
+
 #[no_mangle]
 pub extern "C" fn trading_state_to_cstr(value: TradingState) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -438,7 +714,7 @@ pub unsafe extern "C" fn trading_state_from_cstr(ptr: *const c_char) -> TradingS
 
 #[no_mangle]
 pub extern "C" fn trailing_offset_type_to_cstr(value: TrailingOffsetType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -455,7 +731,7 @@ pub unsafe extern "C" fn trailing_offset_type_from_cstr(ptr: *const c_char) -> T
 
 #[no_mangle]
 pub extern "C" fn trigger_type_to_cstr(value: TriggerType) -> *const c_char {
-    str_to_cstr(value.as_ref())
+    str_to_cstr(value.as_str())
 }
 
 /// Returns an enum from a Python string.
@@ -470,6 +746,12 @@ pub unsafe extern "C" fn trigger_type_from_cstr(ptr: *const c_char) -> TriggerTy
         .unwrap_or_else(|_| panic!("invalid `TriggerType` enum string value, was '{value}'"))
 }
 
+/// Returns the default trigger type for the given `order_type`.
+#[no_mangle]
+pub extern "C" fn trigger_type_default_for_order_type(order_type: OrderType) -> TriggerType {
+    TriggerType::default_for_order_type(order_type)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -489,4 +771,576 @@ mod tests {
         assert_eq!(OrderSide::Buy.value(), 1);
         assert_eq!(OrderSide::Sell.value(), 2);
     }
+
+    #[rstest]
+    #[case(OrderStatus::Initialized, false)]
+    #[case(OrderStatus::Denied, false)]
+    #[case(OrderStatus::Emulated, false)]
+    #[case(OrderStatus::Released, false)]
+    #[case(OrderStatus::Submitted, false)]
+    #[case(OrderStatus::Accepted, true)]
+    #[case(OrderStatus::Rejected, false)]
+    #[case(OrderStatus::Canceled, false)]
+    #[case(OrderStatus::Expired, false)]
+    #[case(OrderStatus::Triggered, true)]
+    #[case(OrderStatus::PendingUpdate, true)]
+    #[case(OrderStatus::PendingCancel, true)]
+    #[case(OrderStatus::PartiallyFilled, true)]
+    #[case(OrderStatus::Filled, false)]
+    fn test_order_status_is_active(#[case] status: OrderStatus, #[case] expected: bool) {
+        assert_eq!(status.is_active(), expected);
+        assert_eq!(order_status_is_active(status), u8::from(expected));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, PositionSide::Short, false)]
+    #[case(PositionSide::Short, PositionSide::Long, false)]
+    #[case(PositionSide::Flat, PositionSide::Flat, true)]
+    #[case(PositionSide::NoPositionSide, PositionSide::NoPositionSide, false)]
+    fn test_position_side_opposite_and_is_flat(
+        #[case] side: PositionSide,
+        #[case] expected_opposite: PositionSide,
+        #[case] expected_is_flat: bool,
+    ) {
+        assert_eq!(side.opposite(), expected_opposite);
+        assert_eq!(side.opposite().opposite(), side);
+        assert_eq!(side.is_flat(), expected_is_flat);
+        assert_eq!(position_side_opposite(side), expected_opposite);
+        assert_eq!(position_side_is_flat(side), u8::from(expected_is_flat));
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Accepted, OrderStatus::PartiallyFilled, 1)]
+    #[case(OrderStatus::Accepted, OrderStatus::Canceled, 1)]
+    #[case(OrderStatus::Filled, OrderStatus::Accepted, 0)]
+    fn test_order_status_valid_transition(
+        #[case] from: OrderStatus,
+        #[case] to: OrderStatus,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(order_status_valid_transition(from, to), expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, 0)]
+    #[case(OrderType::Limit, 0)]
+    #[case(OrderType::MarketToLimit, 0)]
+    #[case(OrderType::StopMarket, 1)]
+    #[case(OrderType::TrailingStopLimit, 1)]
+    fn test_order_type_requires_trigger_type(#[case] value: OrderType, #[case] expected: u8) {
+        assert_eq!(order_type_requires_trigger_type(value), expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::StopMarket, TriggerType::LastTrade)]
+    #[case(OrderType::TrailingStopMarket, TriggerType::MarkPrice)]
+    #[case(OrderType::TrailingStopLimit, TriggerType::MarkPrice)]
+    #[case(OrderType::Limit, TriggerType::NoTrigger)]
+    fn test_trigger_type_default_for_order_type(
+        #[case] order_type: OrderType,
+        #[case] expected: TriggerType,
+    ) {
+        assert_eq!(TriggerType::default_for_order_type(order_type), expected);
+        assert_eq!(trigger_type_default_for_order_type(order_type), expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::StopMarket, TriggerType::LastTrade, 1)]
+    #[case(OrderType::StopMarket, TriggerType::NoTrigger, 0)]
+    #[case(OrderType::Market, TriggerType::MidPoint, 0)]
+    #[case(OrderType::Market, TriggerType::NoTrigger, 1)]
+    #[case(OrderType::Limit, TriggerType::Default, 0)]
+    fn test_order_type_trigger_compatible(
+        #[case] order_type: OrderType,
+        #[case] trigger: TriggerType,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(order_type_trigger_compatible(order_type, trigger), expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::Market, 0)]
+    #[case(OrderType::StopLimit, 0)]
+    #[case(OrderType::TrailingStopMarket, 1)]
+    #[case(OrderType::TrailingStopLimit, 1)]
+    fn test_order_type_is_trailing(#[case] value: OrderType, #[case] expected: u8) {
+        assert_eq!(order_type_is_trailing(value), expected);
+    }
+
+    #[rstest]
+    #[case(OrderType::TrailingStopMarket, TrailingOffsetType::Price, 1)]
+    #[case(OrderType::TrailingStopLimit, TrailingOffsetType::BasisPoints, 1)]
+    #[case(OrderType::TrailingStopMarket, TrailingOffsetType::NoTrailingOffset, 0)]
+    #[case(OrderType::Limit, TrailingOffsetType::Ticks, 0)]
+    #[case(OrderType::Limit, TrailingOffsetType::NoTrailingOffset, 1)]
+    fn test_order_type_trailing_offset_compatible(
+        #[case] order_type: OrderType,
+        #[case] offset: TrailingOffsetType,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(
+            order_type_trailing_offset_compatible(order_type, offset),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("ms", BarAggregation::Millisecond)]
+    #[case("S", BarAggregation::Second)]
+    #[case("sec", BarAggregation::Second)]
+    #[case("m", BarAggregation::Minute)]
+    #[case("M", BarAggregation::Minute)]
+    #[case("MIN", BarAggregation::Minute)]
+    #[case("H", BarAggregation::Hour)]
+    #[case("hr", BarAggregation::Hour)]
+    #[case("D", BarAggregation::Day)]
+    #[case("W", BarAggregation::Week)]
+    #[case("MO", BarAggregation::Month)]
+    #[case("mth", BarAggregation::Month)]
+    #[case("MINUTE", BarAggregation::Minute)]
+    #[case("month", BarAggregation::Month)]
+    fn test_bar_aggregation_from_abbrev(#[case] value: &str, #[case] expected: BarAggregation) {
+        let cstr = std::ffi::CString::new(value).unwrap();
+        assert_eq!(BarAggregation::from_abbrev(value).unwrap(), expected);
+        assert_eq!(
+            unsafe { bar_aggregation_from_abbrev(cstr.as_ptr()) },
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_bar_aggregation_from_abbrev_invalid() {
+        assert!(BarAggregation::from_abbrev("X").is_err());
+        assert!(BarAggregation::from_abbrev("").is_err());
+    }
+
+    #[rstest]
+    #[case("NO_CONTINGENCY", ContingencyType::NoContingency)]
+    #[case("none", ContingencyType::NoContingency)]
+    #[case("OCO", ContingencyType::Oco)]
+    #[case("O.C.O", ContingencyType::Oco)]
+    #[case("ONE_CANCELS_OTHER", ContingencyType::Oco)]
+    #[case("OneCancelsOther", ContingencyType::Oco)]
+    #[case("oto", ContingencyType::Oto)]
+    #[case("O.T.O", ContingencyType::Oto)]
+    #[case("ONE_TRIGGERS_OTHER", ContingencyType::Oto)]
+    #[case("OneTriggersOther", ContingencyType::Oto)]
+    #[case("OUO", ContingencyType::Ouo)]
+    #[case("o.u.o", ContingencyType::Ouo)]
+    #[case("ONE_UPDATES_OTHER", ContingencyType::Ouo)]
+    #[case("OneUpdatesOther", ContingencyType::Ouo)]
+    fn test_contingency_type_from_alias(#[case] value: &str, #[case] expected: ContingencyType) {
+        let cstr = std::ffi::CString::new(value).unwrap();
+        assert_eq!(ContingencyType::from_alias(value).unwrap(), expected);
+        assert_eq!(
+            unsafe { contingency_type_from_alias(cstr.as_ptr()) },
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_contingency_type_from_alias_invalid() {
+        assert!(ContingencyType::from_alias("OCA").is_err());
+        assert!(ContingencyType::from_alias("").is_err());
+    }
+
+    #[rstest]
+    #[case(ContingencyType::NoContingency, "NO_CONTINGENCY")]
+    #[case(ContingencyType::Oco, "OCO")]
+    #[case(ContingencyType::Oto, "OTO")]
+    #[case(ContingencyType::Ouo, "OUO")]
+    fn test_contingency_type_display_unchanged_by_aliases(
+        #[case] value: ContingencyType,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(value.as_ref(), expected);
+    }
+
+    #[rstest]
+    #[case("CASH", AccountType::Cash)]
+    #[case("spot", AccountType::Cash)]
+    #[case("Exchange", AccountType::Cash)]
+    #[case("MARGIN", AccountType::Margin)]
+    #[case("CROSS_MARGIN", AccountType::Margin)]
+    #[case("cross", AccountType::Margin)]
+    #[case("ISOLATED_MARGIN", AccountType::Margin)]
+    #[case("isolated", AccountType::Margin)]
+    #[case("UNIFIED", AccountType::Margin)]
+    #[case("betting", AccountType::Betting)]
+    fn test_account_type_from_broker_alias(#[case] value: &str, #[case] expected: AccountType) {
+        let cstr = std::ffi::CString::new(value).unwrap();
+        assert_eq!(AccountType::from_broker_alias(value).unwrap(), expected);
+        assert_eq!(
+            unsafe { account_type_from_broker_alias(cstr.as_ptr()) },
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_account_type_from_broker_alias_invalid() {
+        assert!(AccountType::from_broker_alias("FUTURES").is_err());
+        assert!(AccountType::from_broker_alias("").is_err());
+    }
+
+    #[rstest]
+    #[case(AccountType::Cash, "CASH")]
+    #[case(AccountType::Margin, "MARGIN")]
+    #[case(AccountType::Betting, "BETTING")]
+    fn test_account_type_display_unchanged_by_aliases(
+        #[case] value: AccountType,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(value.to_string(), expected);
+        assert_eq!(value.as_ref(), expected);
+    }
+
+    #[rstest]
+    #[case(AccountType::Cash, OmsType::Netting)]
+    #[case(AccountType::Margin, OmsType::Netting)]
+    #[case(AccountType::Betting, OmsType::Hedging)]
+    fn test_oms_type_default_for_account(#[case] account: AccountType, #[case] expected: OmsType) {
+        assert_eq!(OmsType::default_for_account(account), expected);
+        assert_eq!(oms_type_default_for_account(account), expected);
+    }
+
+    #[rstest]
+    #[case(CurrencyType::Crypto, 8)]
+    #[case(CurrencyType::Fiat, 2)]
+    #[case(CurrencyType::CommodityBacked, 2)]
+    fn test_currency_type_default_precision(#[case] value: CurrencyType, #[case] expected: u8) {
+        assert_eq!(currency_type_default_precision(value), expected);
+    }
+
+    #[rstest]
+    fn test_book_action_priority_ordering() {
+        let mut actions = vec![
+            BookAction::Add,
+            BookAction::Update,
+            BookAction::Delete,
+            BookAction::Clear,
+            BookAction::Add,
+        ];
+        actions.sort_by_key(|action| std::cmp::Reverse(book_action_priority(*action)));
+
+        assert_eq!(
+            actions,
+            vec![
+                BookAction::Clear,
+                BookAction::Delete,
+                BookAction::Update,
+                BookAction::Add,
+                BookAction::Add,
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(AssetClass::FX, 0, 0)]
+    #[case(AssetClass::Equity, 0, 0)]
+    #[case(AssetClass::Commodity, 0, 0)]
+    #[case(AssetClass::Index, 0, 0)]
+    #[case(AssetClass::Cryptocurrency, 1, 1)]
+    fn test_asset_class_is_crypto_and_24_7(
+        #[case] asset_class: AssetClass,
+        #[case] is_crypto: u8,
+        #[case] is_24_7: u8,
+    ) {
+        assert_eq!(asset_class_is_crypto(asset_class), is_crypto);
+        assert_eq!(asset_class_is_24_7(asset_class), is_24_7);
+    }
+
+    #[rstest]
+    #[case(InstrumentClass::Spot, 0)]
+    #[case(InstrumentClass::Future, 0)]
+    #[case(InstrumentClass::Option, 0)]
+    #[case(InstrumentClass::SportsBetting, 1)]
+    fn test_instrument_class_is_24_7(
+        #[case] instrument_class: InstrumentClass,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(instrument_class_is_24_7(instrument_class), expected);
+    }
+
+    #[rstest]
+    #[case(InstrumentClass::Spot, 0)]
+    #[case(InstrumentClass::Swap, 0)]
+    #[case(InstrumentClass::Cfd, 0)]
+    #[case(InstrumentClass::Bond, 0)]
+    #[case(InstrumentClass::SportsBetting, 0)]
+    #[case(InstrumentClass::Future, 1)]
+    #[case(InstrumentClass::FutureSpread, 1)]
+    #[case(InstrumentClass::Forward, 1)]
+    #[case(InstrumentClass::Option, 1)]
+    #[case(InstrumentClass::OptionSpread, 1)]
+    #[case(InstrumentClass::Warrant, 1)]
+    #[case(InstrumentClass::BinaryOption, 1)]
+    fn test_instrument_class_requires_expiry(
+        #[case] instrument_class: InstrumentClass,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(instrument_class_requires_expiry(instrument_class), expected);
+    }
+
+    #[rstest]
+    #[case(AssetClass::FX, "Foreign Exchange")]
+    #[case(AssetClass::Equity, "Equity")]
+    #[case(AssetClass::Cryptocurrency, "Cryptocurrency")]
+    fn test_asset_class_description(#[case] asset_class: AssetClass, #[case] expected: &str) {
+        let ptr = asset_class_description(asset_class);
+
+        assert_eq!(asset_class.description(), expected);
+        assert_eq!(unsafe { cstr_to_str(ptr) }, expected);
+        unsafe { nautilus_core::ffi::string::cstr_drop(ptr) };
+    }
+
+    #[rstest]
+    #[case(InstrumentClass::Cfd, "Contract for Difference")]
+    #[case(InstrumentClass::FutureSpread, "Future Spread")]
+    #[case(InstrumentClass::SportsBetting, "Sports Betting")]
+    #[case(InstrumentClass::BinaryOption, "Binary Option")]
+    fn test_instrument_class_description(
+        #[case] instrument_class: InstrumentClass,
+        #[case] expected: &str,
+    ) {
+        let ptr = instrument_class_description(instrument_class);
+
+        assert_eq!(instrument_class.description(), expected);
+        assert_eq!(unsafe { cstr_to_str(ptr) }, expected);
+        unsafe { nautilus_core::ffi::string::cstr_drop(ptr) };
+    }
+
+    #[rstest]
+    #[case(TimeInForce::Gtc, 0, 0)]
+    #[case(TimeInForce::Ioc, 1, 1)]
+    #[case(TimeInForce::Fok, 1, 0)]
+    #[case(TimeInForce::Gtd, 0, 0)]
+    #[case(TimeInForce::Day, 0, 0)]
+    #[case(TimeInForce::AtTheOpen, 0, 0)]
+    #[case(TimeInForce::AtTheClose, 0, 0)]
+    fn test_time_in_force_is_immediate_and_allows_partial(
+        #[case] time_in_force: TimeInForce,
+        #[case] is_immediate: u8,
+        #[case] allows_partial: u8,
+    ) {
+        assert_eq!(time_in_force_is_immediate(time_in_force), is_immediate);
+        assert_eq!(time_in_force_allows_partial(time_in_force), allows_partial);
+    }
+
+    #[rstest]
+    #[case(LiquiditySide::Maker, 1, 0, -0.0001)]
+    #[case(LiquiditySide::Taker, 0, 1, 0.0005)]
+    fn test_liquidity_side_fee_side(
+        #[case] liquidity_side: LiquiditySide,
+        #[case] is_maker: u8,
+        #[case] is_taker: u8,
+        #[case] expected_rate: f64,
+    ) {
+        assert_eq!(liquidity_side_is_maker(liquidity_side), is_maker);
+        assert_eq!(liquidity_side_is_taker(liquidity_side), is_taker);
+        assert_eq!(
+            liquidity_side_fee_rate(liquidity_side, -0.0001, 0.0005),
+            expected_rate
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::NoOrderSide, 0.0)]
+    #[case(OrderSide::Buy, 1.0)]
+    #[case(OrderSide::Sell, -1.0)]
+    fn test_order_side_as_signed_multiplier(#[case] side: OrderSide, #[case] expected: f64) {
+        assert_eq!(side.as_signed_multiplier(), expected);
+        assert_eq!(order_side_as_signed_multiplier(side), expected);
+    }
+
+    /// Returns the sides of a generic `Direction` as `(is_long_side, is_short_side, opposite)`.
+    fn direction_of<D: Direction>(side: &D) -> (bool, bool, D) {
+        (side.is_long_side(), side.is_short_side(), side.opposite())
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, true, false, OrderSide::Sell)]
+    #[case(OrderSide::Sell, false, true, OrderSide::Buy)]
+    #[case(OrderSide::NoOrderSide, false, false, OrderSide::NoOrderSide)]
+    fn test_order_side_direction(
+        #[case] side: OrderSide,
+        #[case] is_long: bool,
+        #[case] is_short: bool,
+        #[case] opposite: OrderSide,
+    ) {
+        assert_eq!(direction_of(&side), (is_long, is_short, opposite));
+        assert_eq!(order_side_is_long_side(side), u8::from(is_long));
+        assert_eq!(order_side_is_short_side(side), u8::from(is_short));
+        assert_eq!(order_side_opposite(side), opposite);
+    }
+
+    #[rstest]
+    #[case(AggressorSide::Buyer, true, false, AggressorSide::Seller)]
+    #[case(AggressorSide::Seller, false, true, AggressorSide::Buyer)]
+    #[case(AggressorSide::NoAggressor, false, false, AggressorSide::NoAggressor)]
+    fn test_aggressor_side_direction(
+        #[case] side: AggressorSide,
+        #[case] is_long: bool,
+        #[case] is_short: bool,
+        #[case] opposite: AggressorSide,
+    ) {
+        assert_eq!(direction_of(&side), (is_long, is_short, opposite));
+        assert_eq!(aggressor_side_is_long_side(side), u8::from(is_long));
+        assert_eq!(aggressor_side_is_short_side(side), u8::from(is_short));
+    }
+
+    #[rstest]
+    #[case(PositionSide::Long, true, false, PositionSide::Short)]
+    #[case(PositionSide::Short, false, true, PositionSide::Long)]
+    #[case(PositionSide::Flat, false, false, PositionSide::Flat)]
+    #[case(
+        PositionSide::NoPositionSide,
+        false,
+        false,
+        PositionSide::NoPositionSide
+    )]
+    fn test_position_side_direction(
+        #[case] side: PositionSide,
+        #[case] is_long: bool,
+        #[case] is_short: bool,
+        #[case] opposite: PositionSide,
+    ) {
+        assert_eq!(direction_of(&side), (is_long, is_short, opposite));
+        assert_eq!(position_side_is_long_side(side), u8::from(is_long));
+        assert_eq!(position_side_is_short_side(side), u8::from(is_short));
+    }
+
+    #[rstest]
+    fn test_direction_generic_across_side_enums() {
+        // A generic helper written once for all side enums
+        fn count_long<D: Direction>(sides: &[D]) -> usize {
+            sides.iter().filter(|side| side.is_long_side()).count()
+        }
+
+        assert_eq!(
+            count_long(&[OrderSide::Buy, OrderSide::Sell, OrderSide::Buy]),
+            2
+        );
+        assert_eq!(
+            count_long(&[AggressorSide::Seller, AggressorSide::NoAggressor]),
+            0
+        );
+        assert_eq!(count_long(&[PositionSide::Long, PositionSide::Flat]), 1);
+    }
+
+    #[rstest]
+    #[case(AggressorSide::Buyer, AggressorSide::Seller, LiquiditySide::Maker)]
+    #[case(AggressorSide::Seller, AggressorSide::Buyer, LiquiditySide::Maker)]
+    #[case(
+        AggressorSide::NoAggressor,
+        AggressorSide::NoAggressor,
+        LiquiditySide::NoLiquiditySide
+    )]
+    fn test_aggressor_side_opposite_and_maker_side(
+        #[case] aggressor_side: AggressorSide,
+        #[case] opposite: AggressorSide,
+        #[case] maker_side: LiquiditySide,
+    ) {
+        assert_eq!(aggressor_side.opposite(), opposite);
+        assert_eq!(aggressor_side_opposite(aggressor_side), opposite);
+        assert_eq!(aggressor_side.maker_side(), maker_side);
+        assert_eq!(aggressor_side_maker_side(aggressor_side), maker_side);
+    }
+
+    #[rstest]
+    #[case(BookType::L1_MBP, BookAction::Add, false)]
+    #[case(BookType::L1_MBP, BookAction::Update, true)]
+    #[case(BookType::L1_MBP, BookAction::Delete, true)]
+    #[case(BookType::L1_MBP, BookAction::Clear, true)]
+    #[case(BookType::L2_MBP, BookAction::Add, true)]
+    #[case(BookType::L2_MBP, BookAction::Update, true)]
+    #[case(BookType::L2_MBP, BookAction::Delete, true)]
+    #[case(BookType::L2_MBP, BookAction::Clear, true)]
+    #[case(BookType::L3_MBO, BookAction::Add, true)]
+    #[case(BookType::L3_MBO, BookAction::Update, true)]
+    #[case(BookType::L3_MBO, BookAction::Delete, true)]
+    #[case(BookType::L3_MBO, BookAction::Clear, true)]
+    fn test_book_type_allows_action(
+        #[case] book_type: BookType,
+        #[case] action: BookAction,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(book_type.allows_action(action), expected);
+        assert_eq!(
+            book_type_allows_action(book_type, action),
+            u8::from(expected)
+        );
+    }
+
+    #[rstest]
+    fn test_liquidity_side_no_liquidity_side() {
+        let value = LiquiditySide::NoLiquiditySide;
+
+        assert_eq!(liquidity_side_is_maker(value), 0);
+        assert_eq!(liquidity_side_is_taker(value), 0);
+        assert_eq!(value.fee_rate(-0.0001, 0.0005), None);
+        assert!(liquidity_side_fee_rate(value, -0.0001, 0.0005).is_nan());
+    }
+
+    #[rstest]
+    fn test_market_status_action_normal_session_sequence() {
+        let sequence = [
+            MarketStatusAction::PreOpen,
+            MarketStatusAction::Trading,
+            MarketStatusAction::PreClose,
+            MarketStatusAction::Close,
+            MarketStatusAction::PreOpen,
+        ];
+
+        for pair in sequence.windows(2) {
+            assert_eq!(market_status_action_next_expected(pair[0]), pair[1]);
+            assert_eq!(market_status_action_valid_transition(pair[0], pair[1]), 1);
+        }
+    }
+
+    #[rstest]
+    fn test_market_status_action_session_sequence_with_pause() {
+        let sequence = [
+            MarketStatusAction::PreOpen,
+            MarketStatusAction::Trading,
+            MarketStatusAction::Pause,
+            MarketStatusAction::Trading,
+            MarketStatusAction::PreClose,
+            MarketStatusAction::Close,
+        ];
+
+        for pair in sequence.windows(2) {
+            assert_eq!(market_status_action_valid_transition(pair[0], pair[1]), 1);
+        }
+        assert_eq!(
+            market_status_action_next_expected(MarketStatusAction::Pause),
+            MarketStatusAction::Trading
+        );
+    }
+
+    #[rstest]
+    #[case(MarketStatusAction::PreOpen, MarketStatusAction::Cross, 1)]
+    #[case(MarketStatusAction::Trading, MarketStatusAction::Close, 1)]
+    #[case(MarketStatusAction::Trading, MarketStatusAction::Trading, 1)]
+    #[case(MarketStatusAction::Halt, MarketStatusAction::Quoting, 1)]
+    #[case(MarketStatusAction::Trading, MarketStatusAction::NewPriceIndication, 1)]
+    #[case(MarketStatusAction::Close, MarketStatusAction::Trading, 0)]
+    #[case(MarketStatusAction::PreOpen, MarketStatusAction::PreClose, 0)]
+    #[case(MarketStatusAction::PreClose, MarketStatusAction::Trading, 0)]
+    #[case(MarketStatusAction::Close, MarketStatusAction::Pause, 0)]
+    fn test_market_status_action_valid_transition(
+        #[case] from: MarketStatusAction,
+        #[case] to: MarketStatusAction,
+        #[case] expected: u8,
+    ) {
+        assert_eq!(market_status_action_valid_transition(from, to), expected);
+    }
+
+    #[rstest]
+    fn test_market_status_action_next_expected_with_no_phase() {
+        assert_eq!(
+            market_status_action_next_expected(MarketStatusAction::ShortSellRestrictionChange),
+            MarketStatusAction::None
+        );
+    }
 }
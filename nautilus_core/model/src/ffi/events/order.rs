@@ -13,18 +13,86 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::ffi::c_char;
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
 
-use nautilus_core::{ffi::string::cstr_to_ustr, nanos::UnixNanos, uuid::UUID4};
+use nautilus_core::{
+    ffi::{parsing::u8_as_bool, string::cstr_to_ustr},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
 
 use crate::{
+    enums::{LiquiditySide, OrderSide, OrderType},
     events::order::{
-        OrderAccepted, OrderDenied, OrderEmulated, OrderRejected, OrderReleased, OrderSubmitted,
+        OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated, OrderEvent,
+        OrderEventAny, OrderExpired, OrderFilled, OrderModifyRejected, OrderPendingCancel,
+        OrderPendingUpdate, OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered,
+        OrderUpdated,
+    },
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
     },
-    identifiers::{AccountId, ClientOrderId, InstrumentId, StrategyId, TraderId, VenueOrderId},
-    types::price::Price,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// C compatible Foreign Function Interface (FFI) for an underlying [`OrderEventAny`].
+///
+/// Order events with optional fields are not C compatible by value, so are constructed
+/// behind this pointer and read through the `order_event_*` accessors.
+///
+/// It implements the `Deref` trait, allowing instances of `OrderEventAny_API` to be
+/// dereferenced to `OrderEventAny`, providing access to `OrderEventAny`'s methods without
+/// having to manually access the underlying `OrderEventAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderEventAny_API(Box<OrderEventAny>);
+
+impl OrderEventAny_API {
+    #[must_use]
+    pub fn new(event: OrderEventAny) -> Self {
+        Self(Box::new(event))
+    }
+
+    fn event(&self) -> &dyn OrderEvent {
+        match &*self.0 {
+            OrderEventAny::Initialized(event) => event,
+            OrderEventAny::Denied(event) => event,
+            OrderEventAny::Emulated(event) => event,
+            OrderEventAny::Released(event) => event,
+            OrderEventAny::Submitted(event) => event,
+            OrderEventAny::Accepted(event) => event,
+            OrderEventAny::Rejected(event) => event,
+            OrderEventAny::Canceled(event) => event,
+            OrderEventAny::Expired(event) => event,
+            OrderEventAny::Triggered(event) => event,
+            OrderEventAny::PendingUpdate(event) => event,
+            OrderEventAny::PendingCancel(event) => event,
+            OrderEventAny::ModifyRejected(event) => event,
+            OrderEventAny::CancelRejected(event) => event,
+            OrderEventAny::Updated(event) => event,
+            OrderEventAny::PartiallyFilled(event) | OrderEventAny::Filled(event) => event,
+        }
+    }
+}
+
+impl Deref for OrderEventAny_API {
+    type Target = OrderEventAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderEventAny_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 /// # Safety
 ///
 /// - Assumes `reason_ptr` is a valid C string pointer.
@@ -174,3 +242,481 @@ pub unsafe extern "C" fn order_rejected_new(
         reconciliation,
     }
 }
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_canceled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::Canceled(OrderCanceled::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_expired_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::Expired(OrderExpired::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_triggered_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::Triggered(OrderTriggered::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_pending_update_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::PendingUpdate(OrderPendingUpdate::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        account_id,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_pending_cancel_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: AccountId,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::PendingCancel(OrderPendingCancel::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        account_id,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_modify_rejected_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    reason_ptr: *const c_char,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::ModifyRejected(OrderModifyRejected::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        cstr_to_ustr(reason_ptr),
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `reason_ptr` is a valid C string pointer.
+/// - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_cancel_rejected_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    reason_ptr: *const c_char,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::CancelRejected(OrderCancelRejected::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        cstr_to_ustr(reason_ptr),
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `venue_order_id`, `account_id`, `price` and `trigger_price` are each either null
+///   (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_updated_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: *const VenueOrderId,
+    account_id: *const AccountId,
+    quantity: Quantity,
+    price: *const Price,
+    trigger_price: *const Price,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::Updated(OrderUpdated::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        quantity,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        venue_order_id.as_ref().copied(),
+        account_id.as_ref().copied(),
+        price.as_ref().copied(),
+        trigger_price.as_ref().copied(),
+    )))
+}
+
+/// # Safety
+///
+/// - Assumes `position_id` and `commission` are each either null (no value) or a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_filled_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    trade_id: TradeId,
+    position_id: *const PositionId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    last_qty: Quantity,
+    last_px: Price,
+    currency: Currency,
+    commission: *const Money,
+    liquidity_side: LiquiditySide,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+    reconciliation: u8,
+) -> OrderEventAny_API {
+    OrderEventAny_API::new(OrderEventAny::Filled(OrderFilled::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        venue_order_id,
+        account_id,
+        trade_id,
+        order_side,
+        order_type,
+        last_qty,
+        last_px,
+        currency,
+        liquidity_side,
+        event_id,
+        ts_event,
+        ts_init,
+        u8_as_bool(reconciliation),
+        position_id.as_ref().copied(),
+        commission.as_ref().copied(),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_drop(event: OrderEventAny_API) {
+    drop(event); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_id(event: &OrderEventAny_API) -> UUID4 {
+    event.event().id()
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_trader_id(event: &OrderEventAny_API) -> TraderId {
+    event.event().trader_id()
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_strategy_id(event: &OrderEventAny_API) -> StrategyId {
+    event.event().strategy_id()
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_instrument_id(event: &OrderEventAny_API) -> InstrumentId {
+    event.event().instrument_id()
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_client_order_id(event: &OrderEventAny_API) -> ClientOrderId {
+    event.event().client_order_id()
+}
+
+/// Writes the venue order ID of the event to `output`, returning 1 if the event has one,
+/// otherwise 0 (leaving `output` unchanged).
+///
+/// # Safety
+///
+/// - Assumes `output` is a valid pointer to write the venue order ID to.
+#[no_mangle]
+pub unsafe extern "C" fn order_event_venue_order_id(
+    event: &OrderEventAny_API,
+    output: *mut VenueOrderId,
+) -> u8 {
+    match event.event().venue_order_id() {
+        Some(venue_order_id) => {
+            *output = venue_order_id;
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Writes the account ID of the event to `output`, returning 1 if the event has one,
+/// otherwise 0 (leaving `output` unchanged).
+///
+/// # Safety
+///
+/// - Assumes `output` is a valid pointer to write the account ID to.
+#[no_mangle]
+pub unsafe extern "C" fn order_event_account_id(
+    event: &OrderEventAny_API,
+    output: *mut AccountId,
+) -> u8 {
+    match event.event().account_id() {
+        Some(account_id) => {
+            *output = account_id;
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_reconciliation(event: &OrderEventAny_API) -> u8 {
+    u8::from(event.event().reconciliation())
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_ts_event(event: &OrderEventAny_API) -> UnixNanos {
+    event.event().ts_event()
+}
+
+#[no_mangle]
+pub extern "C" fn order_event_ts_init(event: &OrderEventAny_API) -> UnixNanos {
+    event.event().ts_init()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_order_canceled_new_with_optional_ids() {
+        let venue_order_id = VenueOrderId::from("V-001");
+        let event_id = UUID4::new();
+        let event = unsafe {
+            order_canceled_new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("AUD/USD.SIM"),
+                ClientOrderId::from("O-123456"),
+                &venue_order_id,
+                ptr::null(),
+                event_id,
+                1.into(),
+                2.into(),
+                0,
+            )
+        };
+
+        let mut output_venue_order_id = VenueOrderId::from("V-000");
+        let mut output_account_id = AccountId::from("SIM-000");
+        assert!(matches!(*event, OrderEventAny::Canceled(_)));
+        assert_eq!(order_event_id(&event), event_id);
+        assert_eq!(
+            order_event_client_order_id(&event),
+            ClientOrderId::from("O-123456")
+        );
+        assert_eq!(
+            unsafe { order_event_venue_order_id(&event, &mut output_venue_order_id) },
+            1
+        );
+        assert_eq!(output_venue_order_id, venue_order_id);
+        assert_eq!(
+            unsafe { order_event_account_id(&event, &mut output_account_id) },
+            0
+        );
+        assert_eq!(output_account_id, AccountId::from("SIM-000"));
+        assert_eq!(order_event_reconciliation(&event), 0);
+        assert_eq!(order_event_ts_event(&event), UnixNanos::from(1));
+        assert_eq!(order_event_ts_init(&event), UnixNanos::from(2));
+        order_event_drop(event);
+    }
+
+    #[rstest]
+    fn test_order_filled_new() {
+        let commission = Money::from("2.00 USD");
+        let event = unsafe {
+            order_filled_new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("AUD/USD.SIM"),
+                ClientOrderId::from("O-123456"),
+                VenueOrderId::from("V-001"),
+                AccountId::from("SIM-001"),
+                TradeId::from("T-001"),
+                ptr::null(),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(100_000),
+                Price::from("1.00000"),
+                Currency::USD(),
+                &commission,
+                LiquiditySide::Maker,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                1,
+            )
+        };
+
+        let OrderEventAny::Filled(fill) = *event else {
+            panic!("Expected `OrderFilled`, was {}", *event);
+        };
+        assert_eq!(fill.last_qty, Quantity::from(100_000));
+        assert_eq!(fill.commission, Some(commission));
+        assert_eq!(fill.position_id, None);
+        assert_eq!(order_event_reconciliation(&event), 1);
+        order_event_drop(event);
+    }
+}
@@ -0,0 +1,834 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi::{
+        parsing::u8_as_bool,
+        string::{cstr_to_ustr, optional_cstr_to_ustr, str_to_cstr},
+    },
+    nanos::UnixNanos,
+};
+use rust_decimal::Decimal;
+
+use crate::{
+    enums::{AssetClass, InstrumentClass, OptionKind, RoundingMode},
+    ffi::commission::f64_to_decimal,
+    identifiers::{InstrumentId, Symbol},
+    instruments::{
+        any::InstrumentAny, crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+        futures_contract::FuturesContract, options_contract::OptionsContract, InstrumentViolation,
+    },
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`InstrumentAny`].
+///
+/// This struct wraps `InstrumentAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with instruments in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `InstrumentAny_API` to be
+/// dereferenced to `InstrumentAny`, providing access to `InstrumentAny`'s methods without
+/// having to manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct InstrumentAny_API(Box<InstrumentAny>);
+
+impl Deref for InstrumentAny_API {
+    type Target = InstrumentAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for InstrumentAny_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<InstrumentAny> for InstrumentAny_API {
+    fn from(value: InstrumentAny) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+/// Creates a new [`CurrencyPair`] instrument.
+///
+/// # Safety
+///
+/// - Assumes each of `lot_size`, `max_quantity`, `min_quantity`, `max_notional`, `min_notional`,
+///   `max_price` and `min_price` is either null (no value) or a valid pointer of its type.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If any fee or margin value is not finite.
+/// - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+#[no_mangle]
+pub unsafe extern "C" fn currency_pair_new(
+    instrument_id: InstrumentId,
+    raw_symbol: Symbol,
+    base_currency: Currency,
+    quote_currency: Currency,
+    price_precision: u8,
+    size_precision: u8,
+    price_increment: Price,
+    size_increment: Quantity,
+    taker_fee: f64,
+    maker_fee: f64,
+    margin_init: f64,
+    margin_maint: f64,
+    lot_size: *const Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_notional: *const Money,
+    min_notional: *const Money,
+    max_price: *const Price,
+    min_price: *const Price,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> InstrumentAny_API {
+    let instrument = CurrencyPair::new_checked(
+        instrument_id,
+        raw_symbol,
+        base_currency,
+        quote_currency,
+        price_precision,
+        size_precision,
+        price_increment,
+        size_increment,
+        f64_to_decimal(taker_fee, "taker_fee"),
+        f64_to_decimal(maker_fee, "maker_fee"),
+        f64_to_decimal(margin_init, "margin_init"),
+        f64_to_decimal(margin_maint, "margin_maint"),
+        lot_size.as_ref().copied(),
+        max_quantity.as_ref().copied(),
+        min_quantity.as_ref().copied(),
+        max_notional.as_ref().copied(),
+        min_notional.as_ref().copied(),
+        max_price.as_ref().copied(),
+        min_price.as_ref().copied(),
+        ts_event,
+        ts_init,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+    InstrumentAny_API(Box::new(InstrumentAny::CurrencyPair(instrument)))
+}
+
+/// Creates a new [`CryptoPerpetual`] instrument.
+///
+/// # Safety
+///
+/// - Assumes each of `multiplier`, `lot_size`, `max_quantity`, `min_quantity`, `max_notional`,
+///   `min_notional`, `max_price` and `min_price` is either null (no value) or a valid pointer
+///   of its type.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If any fee or margin value is not finite.
+/// - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+#[no_mangle]
+pub unsafe extern "C" fn crypto_perpetual_new(
+    instrument_id: InstrumentId,
+    raw_symbol: Symbol,
+    base_currency: Currency,
+    quote_currency: Currency,
+    settlement_currency: Currency,
+    is_inverse: u8,
+    price_precision: u8,
+    size_precision: u8,
+    price_increment: Price,
+    size_increment: Quantity,
+    maker_fee: f64,
+    taker_fee: f64,
+    margin_init: f64,
+    margin_maint: f64,
+    multiplier: *const Quantity,
+    lot_size: *const Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_notional: *const Money,
+    min_notional: *const Money,
+    max_price: *const Price,
+    min_price: *const Price,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> InstrumentAny_API {
+    let instrument = CryptoPerpetual::new_checked(
+        instrument_id,
+        raw_symbol,
+        base_currency,
+        quote_currency,
+        settlement_currency,
+        u8_as_bool(is_inverse),
+        price_precision,
+        size_precision,
+        price_increment,
+        size_increment,
+        f64_to_decimal(maker_fee, "maker_fee"),
+        f64_to_decimal(taker_fee, "taker_fee"),
+        f64_to_decimal(margin_init, "margin_init"),
+        f64_to_decimal(margin_maint, "margin_maint"),
+        multiplier.as_ref().copied(),
+        lot_size.as_ref().copied(),
+        max_quantity.as_ref().copied(),
+        min_quantity.as_ref().copied(),
+        max_notional.as_ref().copied(),
+        min_notional.as_ref().copied(),
+        max_price.as_ref().copied(),
+        min_price.as_ref().copied(),
+        ts_event,
+        ts_init,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+    InstrumentAny_API(Box::new(InstrumentAny::CryptoPerpetual(instrument)))
+}
+
+/// Creates a new [`FuturesContract`] instrument.
+///
+/// Margin values of NaN are treated as not set.
+///
+/// # Safety
+///
+/// - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+/// - Assumes `underlying_ptr` is a valid C string pointer.
+/// - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+///   null (no value) or a valid pointer of its type.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+#[no_mangle]
+pub unsafe extern "C" fn futures_contract_new(
+    instrument_id: InstrumentId,
+    raw_symbol: Symbol,
+    asset_class: AssetClass,
+    exchange_ptr: *const c_char,
+    underlying_ptr: *const c_char,
+    activation_ns: UnixNanos,
+    expiration_ns: UnixNanos,
+    currency: Currency,
+    price_precision: u8,
+    price_increment: Price,
+    multiplier: Quantity,
+    lot_size: Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_price: *const Price,
+    min_price: *const Price,
+    margin_init: f64,
+    margin_maint: f64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> InstrumentAny_API {
+    let instrument = FuturesContract::new_checked(
+        instrument_id,
+        raw_symbol,
+        asset_class,
+        optional_cstr_to_ustr(exchange_ptr),
+        cstr_to_ustr(underlying_ptr),
+        activation_ns,
+        expiration_ns,
+        currency,
+        price_precision,
+        price_increment,
+        multiplier,
+        lot_size,
+        max_quantity.as_ref().copied(),
+        min_quantity.as_ref().copied(),
+        max_price.as_ref().copied(),
+        min_price.as_ref().copied(),
+        optional_f64_to_decimal(margin_init, "margin_init"),
+        optional_f64_to_decimal(margin_maint, "margin_maint"),
+        ts_event,
+        ts_init,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+    InstrumentAny_API(Box::new(InstrumentAny::FuturesContract(instrument)))
+}
+
+/// Creates a new [`OptionsContract`] instrument.
+///
+/// Margin values of NaN are treated as not set.
+///
+/// # Safety
+///
+/// - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+/// - Assumes `underlying_ptr` is a valid C string pointer.
+/// - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+///   null (no value) or a valid pointer of its type.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+#[no_mangle]
+pub unsafe extern "C" fn options_contract_new(
+    instrument_id: InstrumentId,
+    raw_symbol: Symbol,
+    asset_class: AssetClass,
+    exchange_ptr: *const c_char,
+    underlying_ptr: *const c_char,
+    option_kind: OptionKind,
+    strike_price: Price,
+    currency: Currency,
+    activation_ns: UnixNanos,
+    expiration_ns: UnixNanos,
+    price_precision: u8,
+    price_increment: Price,
+    multiplier: Quantity,
+    lot_size: Quantity,
+    max_quantity: *const Quantity,
+    min_quantity: *const Quantity,
+    max_price: *const Price,
+    min_price: *const Price,
+    margin_init: f64,
+    margin_maint: f64,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> InstrumentAny_API {
+    let instrument = OptionsContract::new_checked(
+        instrument_id,
+        raw_symbol,
+        asset_class,
+        optional_cstr_to_ustr(exchange_ptr),
+        cstr_to_ustr(underlying_ptr),
+        option_kind,
+        strike_price,
+        currency,
+        activation_ns,
+        expiration_ns,
+        price_precision,
+        price_increment,
+        multiplier,
+        lot_size,
+        max_quantity.as_ref().copied(),
+        min_quantity.as_ref().copied(),
+        max_price.as_ref().copied(),
+        min_price.as_ref().copied(),
+        optional_f64_to_decimal(margin_init, "margin_init"),
+        optional_f64_to_decimal(margin_maint, "margin_maint"),
+        ts_event,
+        ts_init,
+    )
+    .unwrap_or_else(|e| panic!("{e}"));
+    InstrumentAny_API(Box::new(InstrumentAny::OptionsContract(instrument)))
+}
+
+fn optional_f64_to_decimal(value: f64, name: &str) -> Option<Decimal> {
+    (!value.is_nan()).then(|| f64_to_decimal(value, name))
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_drop(instrument: InstrumentAny_API) {
+    drop(instrument); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_id(instrument: &InstrumentAny_API) -> InstrumentId {
+    instrument.id()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_class(instrument: &InstrumentAny_API) -> InstrumentClass {
+    instrument.instrument_class()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_quote_currency(instrument: &InstrumentAny_API) -> Currency {
+    instrument.quote_currency()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_settlement_currency(instrument: &InstrumentAny_API) -> Currency {
+    instrument.settlement_currency()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_is_inverse(instrument: &InstrumentAny_API) -> u8 {
+    u8::from(instrument.is_inverse())
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_is_quanto(instrument: &InstrumentAny_API) -> u8 {
+    u8::from(instrument.is_quanto())
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_price_precision(instrument: &InstrumentAny_API) -> u8 {
+    instrument.price_precision()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_size_precision(instrument: &InstrumentAny_API) -> u8 {
+    instrument.size_precision()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_price_increment(instrument: &InstrumentAny_API) -> Price {
+    instrument.price_increment()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_size_increment(instrument: &InstrumentAny_API) -> Quantity {
+    instrument.size_increment()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_multiplier(instrument: &InstrumentAny_API) -> Quantity {
+    instrument.multiplier()
+}
+
+/// Returns the lot size, or NaN if the instrument has none.
+#[no_mangle]
+pub extern "C" fn instrument_lot_size(instrument: &InstrumentAny_API) -> f64 {
+    instrument.lot_size().map_or(f64::NAN, |qty| qty.as_f64())
+}
+
+/// Returns the maximum order quantity, or NaN if the instrument has none.
+#[no_mangle]
+pub extern "C" fn instrument_max_quantity(instrument: &InstrumentAny_API) -> f64 {
+    instrument
+        .max_quantity()
+        .map_or(f64::NAN, |qty| qty.as_f64())
+}
+
+/// Returns the minimum order quantity, or NaN if the instrument has none.
+#[no_mangle]
+pub extern "C" fn instrument_min_quantity(instrument: &InstrumentAny_API) -> f64 {
+    instrument
+        .min_quantity()
+        .map_or(f64::NAN, |qty| qty.as_f64())
+}
+
+/// Returns the expiration UNIX timestamp (nanoseconds), or zero if the instrument does not expire.
+#[no_mangle]
+pub extern "C" fn instrument_expiration_ns(instrument: &InstrumentAny_API) -> UnixNanos {
+    instrument.expiration_ns().unwrap_or_default()
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_make_price(instrument: &InstrumentAny_API, value: f64) -> Price {
+    instrument.make_price(value)
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_make_qty(instrument: &InstrumentAny_API, value: f64) -> Quantity {
+    instrument.make_qty(value)
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_make_price_rounded(
+    instrument: &InstrumentAny_API,
+    value: f64,
+    mode: RoundingMode,
+) -> Price {
+    instrument.make_price_rounded(value, mode)
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_make_qty_rounded(
+    instrument: &InstrumentAny_API,
+    value: f64,
+    mode: RoundingMode,
+) -> Quantity {
+    instrument.make_qty_rounded(value, mode)
+}
+
+/// Validates an order price and quantity against the instrument, returning 1 if valid,
+/// otherwise 0 with the violation written to `violation`.
+///
+/// # Safety
+///
+/// - Assumes `violation` is a valid pointer to write the violation to.
+#[no_mangle]
+pub unsafe extern "C" fn instrument_validate_order(
+    instrument: &InstrumentAny_API,
+    price: Price,
+    quantity: Quantity,
+    violation: *mut InstrumentViolation,
+) -> u8 {
+    match instrument.validate_order(price, quantity) {
+        Ok(()) => 1,
+        Err(e) => {
+            *violation = e;
+            0
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_violation_to_cstr(value: InstrumentViolation) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+#[no_mangle]
+pub extern "C" fn instrument_notional_value(
+    instrument: &InstrumentAny_API,
+    quantity: Quantity,
+    price: Price,
+    use_quote_for_inverse: u8,
+) -> Money {
+    instrument.calculate_notional_value(quantity, price, Some(u8_as_bool(use_quote_for_inverse)))
+}
+
+/// Returns the equivalent quantity of the base asset for the given quote `quantity` at `last_px`.
+#[no_mangle]
+pub extern "C" fn instrument_calculate_base_quantity(
+    instrument: &InstrumentAny_API,
+    quantity: Quantity,
+    last_px: Price,
+) -> Quantity {
+    instrument.calculate_base_quantity(quantity, last_px)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, ptr};
+
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::instruments::{
+        stubs::{
+            audusd_sim, crypto_perpetual_ethusdt, currency_pair_ethusdt, futures_contract_es,
+            options_contract_appl,
+        },
+        Instrument,
+    };
+
+    // Values below mirror the Python `TestInstrumentProvider` definitions
+
+    #[rstest]
+    fn test_currency_pair_new_matches_default_fx_ccy(audusd_sim: CurrencyPair) {
+        let lot_size = Quantity::from(1000);
+        let max_quantity = Quantity::from(1_000_000);
+        let min_quantity = Quantity::from(100);
+        let instrument = unsafe {
+            currency_pair_new(
+                InstrumentId::from("AUD/USD.SIM"),
+                Symbol::from("AUD/USD"),
+                Currency::AUD(),
+                Currency::USD(),
+                5,
+                0,
+                Price::from("0.00001"),
+                Quantity::from(1),
+                0.00002,
+                0.00002,
+                0.03,
+                0.03,
+                &lot_size,
+                &max_quantity,
+                &min_quantity,
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                0.into(),
+                0.into(),
+            )
+        };
+
+        let InstrumentAny::CurrencyPair(pair) = &*instrument else {
+            panic!("Expected `CurrencyPair`");
+        };
+        assert_eq!(pair.id, audusd_sim.id);
+        assert_eq!(pair.price_increment, audusd_sim.price_increment);
+        assert_eq!(pair.size_increment, audusd_sim.size_increment);
+        assert_eq!(pair.maker_fee, dec!(0.00002));
+        assert_eq!(pair.taker_fee, audusd_sim.taker_fee);
+        assert_eq!(pair.margin_init, audusd_sim.margin_init);
+        assert_eq!(pair.lot_size, audusd_sim.lot_size);
+        assert_eq!(pair.max_quantity, audusd_sim.max_quantity);
+        assert_eq!(pair.min_quantity, audusd_sim.min_quantity);
+        assert_eq!(instrument_price_precision(&instrument), 5);
+        assert_eq!(instrument_size_precision(&instrument), 0);
+        assert_eq!(instrument_lot_size(&instrument), 1000.0);
+        assert_eq!(instrument_min_quantity(&instrument), 100.0);
+        assert_eq!(instrument_is_inverse(&instrument), 0);
+        assert_eq!(instrument_class(&instrument), InstrumentClass::Spot);
+        assert_eq!(instrument_expiration_ns(&instrument), UnixNanos::default());
+        assert_eq!(
+            instrument_make_price(&instrument, 0.723_456_7),
+            Price::from("0.72346")
+        );
+        instrument_drop(instrument);
+    }
+
+    #[rstest]
+    fn test_currency_pair_new_matches_ethusdt_binance(currency_pair_ethusdt: CurrencyPair) {
+        let max_quantity = Quantity::from("9000");
+        let min_quantity = Quantity::from("0.00001");
+        let max_price = Price::from("1000000");
+        let min_price = Price::from("0.01");
+        let instrument = unsafe {
+            currency_pair_new(
+                InstrumentId::from("ETHUSDT.BINANCE"),
+                Symbol::from("ETHUSDT"),
+                Currency::ETH(),
+                Currency::USDT(),
+                2,
+                5,
+                Price::from("0.01"),
+                Quantity::from("0.00001"),
+                0.0001,
+                0.0001,
+                0.01,
+                0.0035,
+                ptr::null(),
+                &max_quantity,
+                &min_quantity,
+                ptr::null(),
+                ptr::null(),
+                &max_price,
+                &min_price,
+                0.into(),
+                0.into(),
+            )
+        };
+
+        let InstrumentAny::CurrencyPair(pair) = &*instrument else {
+            panic!("Expected `CurrencyPair`");
+        };
+        assert_eq!(pair.margin_maint, currency_pair_ethusdt.margin_maint);
+        assert_eq!(pair.max_price, currency_pair_ethusdt.max_price);
+        assert_eq!(pair.min_price, currency_pair_ethusdt.min_price);
+        assert!(instrument_lot_size(&instrument).is_nan());
+        assert_eq!(instrument_is_quanto(&instrument), 0);
+        assert_eq!(
+            instrument_calculate_base_quantity(
+                &instrument,
+                Quantity::from("1000.0"),
+                Price::from("2000.00")
+            ),
+            Quantity::from("0.50000")
+        );
+        assert_eq!(
+            serde_json::to_string(pair).unwrap(),
+            serde_json::to_string(&currency_pair_ethusdt).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_instrument_rounding_and_validation(currency_pair_ethusdt: CurrencyPair) {
+        let instrument =
+            InstrumentAny_API(Box::new(InstrumentAny::CurrencyPair(currency_pair_ethusdt)));
+
+        assert_eq!(
+            instrument_make_price_rounded(&instrument, 2000.016, RoundingMode::Down),
+            Price::from("2000.01")
+        );
+        assert_eq!(
+            instrument_make_qty_rounded(&instrument, 0.000_014, RoundingMode::Up),
+            Quantity::from("0.00002")
+        );
+
+        let mut violation = InstrumentViolation::QuantityPrecision;
+        let valid = unsafe {
+            instrument_validate_order(
+                &instrument,
+                Price::from("2000.01"),
+                Quantity::from("1.00000"),
+                &mut violation,
+            )
+        };
+        assert_eq!(valid, 1);
+
+        let valid = unsafe {
+            instrument_validate_order(
+                &instrument,
+                Price::from("2000.005"),
+                Quantity::from("1.00000"),
+                &mut violation,
+            )
+        };
+        assert_eq!(valid, 0);
+        assert_eq!(violation, InstrumentViolation::PricePrecision);
+
+        let cstr = instrument_violation_to_cstr(violation);
+        assert_eq!(
+            unsafe { std::ffi::CStr::from_ptr(cstr) }.to_str().unwrap(),
+            "PRICE_PRECISION"
+        );
+        unsafe { nautilus_core::ffi::string::cstr_drop(cstr) };
+    }
+
+    #[rstest]
+    fn test_crypto_perpetual_new_matches_ethusdt_perp_binance(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+    ) {
+        let max_quantity = Quantity::from("10000.0");
+        let min_quantity = Quantity::from("0.001");
+        let min_notional = Money::new(10.00, Currency::USDT());
+        let max_price = Price::from("15000.00");
+        let min_price = Price::from("1.0");
+        let instrument = unsafe {
+            crypto_perpetual_new(
+                InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                Symbol::from("ETHUSDT"),
+                Currency::ETH(),
+                Currency::USDT(),
+                Currency::USDT(),
+                0,
+                2,
+                3,
+                Price::from("0.01"),
+                Quantity::from("0.001"),
+                0.0002,
+                0.0004,
+                1.0,
+                0.35,
+                ptr::null(),
+                ptr::null(),
+                &max_quantity,
+                &min_quantity,
+                ptr::null(),
+                &min_notional,
+                &max_price,
+                &min_price,
+                0.into(),
+                0.into(),
+            )
+        };
+
+        let InstrumentAny::CryptoPerpetual(perp) = &*instrument else {
+            panic!("Expected `CryptoPerpetual`");
+        };
+        assert_eq!(perp.id, crypto_perpetual_ethusdt.id);
+        assert_eq!(perp.maker_fee, crypto_perpetual_ethusdt.maker_fee);
+        assert_eq!(perp.taker_fee, crypto_perpetual_ethusdt.taker_fee);
+        assert_eq!(perp.margin_init, crypto_perpetual_ethusdt.margin_init);
+        assert_eq!(perp.margin_maint, crypto_perpetual_ethusdt.margin_maint);
+        assert_eq!(perp.max_quantity, crypto_perpetual_ethusdt.max_quantity);
+        assert_eq!(perp.min_quantity, crypto_perpetual_ethusdt.min_quantity);
+        assert_eq!(perp.min_notional, crypto_perpetual_ethusdt.min_notional);
+        assert_eq!(perp.max_price, crypto_perpetual_ethusdt.max_price);
+        assert_eq!(perp.min_price, crypto_perpetual_ethusdt.min_price);
+        assert_eq!(instrument_class(&instrument), InstrumentClass::Swap);
+        assert_eq!(
+            instrument_settlement_currency(&instrument),
+            Currency::USDT()
+        );
+        assert_eq!(
+            instrument_notional_value(
+                &instrument,
+                Quantity::from("2.000"),
+                Price::from("1500.00"),
+                0
+            ),
+            Money::new(3000.0, Currency::USDT())
+        );
+    }
+
+    #[rstest]
+    fn test_futures_contract_new_matches_es() {
+        let expected = futures_contract_es(None, None);
+        let exchange = CString::new("XCME").unwrap();
+        let underlying = CString::new("ES").unwrap();
+        let instrument = unsafe {
+            futures_contract_new(
+                InstrumentId::from("ESZ1.GLBX"),
+                Symbol::from("ESZ1"),
+                AssetClass::Index,
+                exchange.as_ptr(),
+                underlying.as_ptr(),
+                expected.activation_ns,
+                expected.expiration_ns,
+                Currency::USD(),
+                2,
+                Price::from("0.01"),
+                Quantity::from(1),
+                Quantity::from(1),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                f64::NAN,
+                f64::NAN,
+                0.into(),
+                0.into(),
+            )
+        };
+
+        let InstrumentAny::FuturesContract(future) = &*instrument else {
+            panic!("Expected `FuturesContract`");
+        };
+        assert_eq!(
+            serde_json::to_string(future).unwrap(),
+            serde_json::to_string(&expected).unwrap()
+        );
+        assert_eq!(
+            instrument_expiration_ns(&instrument),
+            expected.expiration_ns
+        );
+        assert_eq!(instrument_multiplier(&instrument), Quantity::from(1));
+        assert!(instrument_max_quantity(&instrument).is_nan());
+    }
+
+    #[rstest]
+    fn test_options_contract_new_matches_aapl(options_contract_appl: OptionsContract) {
+        let exchange = CString::new("GMNI").unwrap();
+        let underlying = CString::new("AAPL").unwrap();
+        let instrument = unsafe {
+            options_contract_new(
+                InstrumentId::from("AAPL211217C00150000.OPRA"),
+                Symbol::from("AAPL211217C00150000"),
+                AssetClass::Equity,
+                exchange.as_ptr(),
+                underlying.as_ptr(),
+                OptionKind::Call,
+                Price::from("149.0"),
+                Currency::USD(),
+                options_contract_appl.activation_ns,
+                options_contract_appl.expiration_ns,
+                2,
+                Price::from("0.01"),
+                Quantity::from(1),
+                Quantity::from(1),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                f64::NAN,
+                f64::NAN,
+                0.into(),
+                0.into(),
+            )
+        };
+
+        let InstrumentAny::OptionsContract(option) = &*instrument else {
+            panic!("Expected `OptionsContract`");
+        };
+        assert_eq!(
+            serde_json::to_string(option).unwrap(),
+            serde_json::to_string(&options_contract_appl).unwrap()
+        );
+        assert_eq!(option.strike_price(), Some(Price::from("149.0")));
+        assert_eq!(instrument_id(&instrument), options_contract_appl.id);
+    }
+}
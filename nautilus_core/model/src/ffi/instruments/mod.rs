@@ -13,4 +13,6 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+pub mod any;
+pub mod options_contract;
 pub mod synthetic;
@@ -0,0 +1,185 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_char;
+
+use nautilus_core::{ffi::string::cstr_to_str, nanos::UnixNanos};
+
+use super::any::InstrumentAny_API;
+use crate::{
+    instruments::{
+        any::InstrumentAny,
+        options_contract::{parse_occ_symbol, OccSymbol, OptionsContract},
+    },
+    types::{money::Money, price::Price},
+};
+
+fn as_options_contract(instrument: &InstrumentAny_API) -> &OptionsContract {
+    match &**instrument {
+        InstrumentAny::OptionsContract(contract) => contract,
+        _ => panic!(
+            "Instrument is not an `OptionsContract`, was {}",
+            instrument.id()
+        ),
+    }
+}
+
+/// Returns the intrinsic value of one contract at the given `underlying` price.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_intrinsic_value(
+    instrument: &InstrumentAny_API,
+    underlying: Price,
+) -> Money {
+    as_options_contract(instrument).intrinsic_value(underlying)
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_is_itm(instrument: &InstrumentAny_API, underlying: Price) -> u8 {
+    u8::from(as_options_contract(instrument).is_itm(underlying))
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_is_atm(instrument: &InstrumentAny_API, underlying: Price) -> u8 {
+    u8::from(as_options_contract(instrument).is_atm(underlying))
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_is_otm(instrument: &InstrumentAny_API, underlying: Price) -> u8 {
+    u8::from(as_options_contract(instrument).is_otm(underlying))
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_is_active(instrument: &InstrumentAny_API, now_ns: u64) -> u8 {
+    u8::from(as_options_contract(instrument).is_active(UnixNanos::from(now_ns)))
+}
+
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_is_expired(instrument: &InstrumentAny_API, now_ns: u64) -> u8 {
+    u8::from(as_options_contract(instrument).is_expired(UnixNanos::from(now_ns)))
+}
+
+/// Returns the fractional number of days from `now_ns` until expiration (zero once expired).
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `instrument` is not an options contract.
+#[no_mangle]
+pub extern "C" fn options_contract_days_to_expiry(
+    instrument: &InstrumentAny_API,
+    now_ns: u64,
+) -> f64 {
+    as_options_contract(instrument).days_to_expiry(UnixNanos::from(now_ns))
+}
+
+/// Parses an OCC option symbol, returning 1 if successful with the components written to
+/// `output`, otherwise 0.
+///
+/// # Safety
+///
+/// - Assumes `ptr` is a valid C string pointer.
+/// - Assumes `output` is a valid pointer to write the parsed symbol to.
+#[no_mangle]
+pub unsafe extern "C" fn occ_symbol_parse(ptr: *const c_char, output: *mut OccSymbol) -> u8 {
+    match parse_occ_symbol(cstr_to_str(ptr)) {
+        Ok(occ) => {
+            *output = occ;
+            1
+        }
+        Err(_) => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{enums::OptionKind, instruments::stubs::options_contract_appl};
+
+    #[rstest]
+    fn test_options_contract_analytics(options_contract_appl: OptionsContract) {
+        let expiration_ns = options_contract_appl.expiration_ns.as_u64();
+        let instrument =
+            InstrumentAny_API::from(InstrumentAny::OptionsContract(options_contract_appl));
+        let underlying = Price::from("150.00");
+
+        assert_eq!(
+            options_contract_intrinsic_value(&instrument, underlying),
+            Money::from("1.00 USD")
+        );
+        assert_eq!(options_contract_is_itm(&instrument, underlying), 1);
+        assert_eq!(options_contract_is_atm(&instrument, underlying), 0);
+        assert_eq!(options_contract_is_otm(&instrument, underlying), 0);
+        assert_eq!(options_contract_is_active(&instrument, expiration_ns), 0);
+        assert_eq!(options_contract_is_expired(&instrument, expiration_ns), 1);
+        assert_eq!(
+            options_contract_days_to_expiry(&instrument, expiration_ns),
+            0.0
+        );
+    }
+
+    #[rstest]
+    fn test_occ_symbol_parse() {
+        let symbol = CString::new("SPY   240119P00470000").unwrap();
+        let mut output = OccSymbol {
+            underlying: "".into(),
+            expiration_ns: UnixNanos::default(),
+            option_kind: OptionKind::Call,
+            strike_price: Price::from("0"),
+        };
+
+        assert_eq!(unsafe { occ_symbol_parse(symbol.as_ptr(), &mut output) }, 1);
+        assert_eq!(output.underlying, "SPY");
+        assert_eq!(output.option_kind, OptionKind::Put);
+        assert_eq!(output.strike_price, Price::from("470.000"));
+
+        let invalid = CString::new("SPY").unwrap();
+        assert_eq!(
+            unsafe { occ_symbol_parse(invalid.as_ptr(), &mut output) },
+            0
+        );
+    }
+}
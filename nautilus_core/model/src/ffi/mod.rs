@@ -15,10 +15,14 @@
 
 //! C foreign function interface (FFI) from `cbindgen`.
 
+pub mod accounts;
+pub mod commission;
 pub mod data;
 pub mod enums;
 pub mod events;
 pub mod identifiers;
 pub mod instruments;
 pub mod orderbook;
+pub mod orders;
+pub mod position;
 pub mod types;
@@ -28,7 +28,11 @@ use crate::{
     },
     enums::{BookType, OrderSide},
     identifiers::InstrumentId,
-    orderbook::{analysis::book_check_integrity, book::OrderBook},
+    orderbook::{
+        analysis::{BookFill, BookLevel},
+        book::{BookIntegrityPolicy, OrderBook},
+        checksum::BookChecksumType,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
@@ -147,14 +151,30 @@ pub extern "C" fn orderbook_clear_asks(book: &mut OrderBook_API, sequence: u64,
 }
 
 #[no_mangle]
-pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: &OrderBookDelta) {
-    book.apply_delta(delta);
+pub extern "C" fn orderbook_set_integrity_policy(
+    book: &mut OrderBook_API,
+    policy: BookIntegrityPolicy,
+) {
+    book.integrity_policy = policy;
 }
 
+/// Applies the order book delta, returning 1 on success or 0 if the delta's action is not
+/// allowed for the book type, or the book's integrity policy is `RaiseError` and the delta
+/// crosses the book.
 #[no_mangle]
-pub extern "C" fn orderbook_apply_deltas(book: &mut OrderBook_API, deltas: &OrderBookDeltas_API) {
-    // Clone will actually copy the contents of the `deltas` vec
-    book.apply_deltas(deltas.deref());
+pub extern "C" fn orderbook_apply_delta(book: &mut OrderBook_API, delta: &OrderBookDelta) -> u8 {
+    u8::from(book.apply_delta(delta).is_ok())
+}
+
+/// Applies the order book deltas, returning 1 on success or 0 if any delta's action is not
+/// allowed for the book type, or the book's integrity policy is `RaiseError` and a delta
+/// crosses the book.
+#[no_mangle]
+pub extern "C" fn orderbook_apply_deltas(
+    book: &mut OrderBook_API,
+    deltas: &OrderBookDeltas_API,
+) -> u8 {
+    u8::from(book.apply_deltas(deltas.deref()).is_ok())
 }
 
 #[no_mangle]
@@ -235,6 +255,7 @@ pub extern "C" fn orderbook_get_avg_px_for_quantity(
     order_side: OrderSide,
 ) -> f64 {
     book.get_avg_px_for_quantity(qty, order_side)
+        .unwrap_or(f64::NAN)
 }
 
 #[no_mangle]
@@ -243,7 +264,28 @@ pub extern "C" fn orderbook_get_quantity_for_price(
     price: Price,
     order_side: OrderSide,
 ) -> f64 {
-    book.get_quantity_for_price(price, order_side)
+    book.get_quantity_for_price(price, order_side).as_f64()
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_cumulative_depth(
+    book: &OrderBook_API,
+    side: OrderSide,
+    price: Price,
+) -> Quantity {
+    book.cumulative_depth(side, price)
+}
+
+/// Returns the size imbalance over the top `depth` levels per side (`NaN` if the book is empty).
+#[no_mangle]
+pub extern "C" fn orderbook_imbalance(book: &OrderBook_API, depth: usize) -> f64 {
+    book.imbalance(depth)
+}
+
+/// Returns the microprice (`NaN` if either side of the book is empty).
+#[no_mangle]
+pub extern "C" fn orderbook_microprice(book: &OrderBook_API) -> f64 {
+    book.microprice().unwrap_or(f64::NAN)
 }
 
 /// Updates the order book with a quote tick.
@@ -273,9 +315,69 @@ pub extern "C" fn orderbook_simulate_fills(book: &OrderBook_API, order: BookOrde
     book.simulate_fills(&order).into()
 }
 
+/// C compatible result of simulating fills for a quantity against an order book.
+///
+/// `fills` is a `CVec` of `BookFill` (one per level filled) ordered from the top of book.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CSimulatedFills {
+    pub fills: CVec,
+    pub filled_qty: Quantity,
+    pub is_partial: u8,
+}
+
+/// Simulates filling `qty` against the opposite side of the book, up to the optional
+/// `price_limit` (pass null for no limit).
+///
+/// The result must be freed with `simulated_fills_drop`. If `is_partial` is set then the
+/// book did not have sufficient liquidity at or better than `price_limit`.
+///
+/// # Safety
+///
+/// - Assumes `price_limit` is either null or a valid pointer to a `Price`.
+#[no_mangle]
+pub unsafe extern "C" fn orderbook_simulate_fills_for_quantity(
+    book: &OrderBook_API,
+    order_side: OrderSide,
+    price_limit: *const Price,
+    qty: Quantity,
+) -> CSimulatedFills {
+    let result = book.simulate_fills_for_quantity(order_side, price_limit.as_ref().copied(), qty);
+    CSimulatedFills {
+        fills: result.fills.into(),
+        filled_qty: result.filled_qty,
+        is_partial: u8::from(result.is_partial),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn simulated_fills_drop(result: CSimulatedFills) {
+    vec_book_fills_drop(result.fills);
+}
+
 #[no_mangle]
 pub extern "C" fn orderbook_check_integrity(book: &OrderBook_API) -> u8 {
-    u8::from(book_check_integrity(book).is_ok())
+    u8::from(book.check_integrity().is_ok())
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_checksum_crc32_kraken(book: &OrderBook_API, depth: usize) -> u32 {
+    book.checksum_crc32_kraken(depth)
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_checksum_crc32_okx(book: &OrderBook_API, depth: usize) -> u32 {
+    book.checksum_crc32_okx(depth)
+}
+
+#[no_mangle]
+pub extern "C" fn orderbook_verify_checksum(
+    book: &OrderBook_API,
+    checksum_type: BookChecksumType,
+    depth: usize,
+    expected: u32,
+) -> u8 {
+    u8::from(book.verify_checksum(checksum_type, depth, expected))
 }
 
 // TODO: This struct implementation potentially leaks memory
@@ -289,6 +391,65 @@ pub extern "C" fn vec_fills_drop(v: CVec) {
     drop(data); // Memory freed here
 }
 
+#[no_mangle]
+pub extern "C" fn vec_book_fills_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let data: Vec<BookFill> = unsafe { Vec::from_raw_parts(ptr.cast::<BookFill>(), len, cap) };
+    drop(data); // Memory freed here
+}
+
+/// C compatible snapshot of the top levels of an order book.
+///
+/// Both `bids` and `asks` are `CVec`s of `BookLevel` ordered best first.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CBookSnapshot {
+    pub bids: CVec,
+    pub asks: CVec,
+}
+
+/// Returns a snapshot of the top `depth` levels per side, which must be freed with
+/// `book_snapshot_drop`.
+#[no_mangle]
+pub extern "C" fn orderbook_snapshot(book: &OrderBook_API, depth: usize) -> CBookSnapshot {
+    let snapshot = book.snapshot(depth);
+    CBookSnapshot {
+        bids: snapshot.bids.into(),
+        asks: snapshot.asks.into(),
+    }
+}
+
+/// Returns the top `depth` levels per side grouped into price buckets of `tick_size`, which
+/// must be freed with `book_snapshot_drop`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `tick_size` is not positive (> 0).
+#[no_mangle]
+pub extern "C" fn orderbook_group_levels(
+    book: &OrderBook_API,
+    tick_size: Price,
+    depth: usize,
+) -> CBookSnapshot {
+    let grouped = book.group_levels(tick_size, depth);
+    CBookSnapshot {
+        bids: grouped.bids.into(),
+        asks: grouped.asks.into(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn book_snapshot_drop(snapshot: CBookSnapshot) {
+    let CBookSnapshot { bids, asks } = snapshot;
+    for v in [bids, asks] {
+        let CVec { ptr, len, cap } = v;
+        let data: Vec<BookLevel> =
+            unsafe { Vec::from_raw_parts(ptr.cast::<BookLevel>(), len, cap) };
+        drop(data); // Memory freed here
+    }
+}
+
 /// Returns a pretty printed `OrderBook` number of levels per side, as a C string pointer.
 #[no_mangle]
 pub extern "C" fn orderbook_pprint_to_cstr(
@@ -297,3 +458,120 @@ pub extern "C" fn orderbook_pprint_to_cstr(
 ) -> *const c_char {
     str_to_cstr(&book.pprint(num_levels))
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::enums::BookAction;
+
+    fn book_with_asks() -> OrderBook_API {
+        let mut book = orderbook_new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+        for (i, (price, size)) in [("100.0", "1.0"), ("101.0", "2.0")].into_iter().enumerate() {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from(price),
+                Quantity::from(size),
+                i as u64,
+            );
+            book.add(order, 0, i as u64, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_apply_delta_with_raise_error_policy_returns_status() {
+        let mut book = book_with_asks();
+        orderbook_set_integrity_policy(&mut book, BookIntegrityPolicy::RaiseError);
+        let crossing = OrderBookDelta::new(
+            book.instrument_id,
+            BookAction::Add,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("100.5"),
+                Quantity::from("1.0"),
+                10,
+            ),
+            0,
+            2,
+            2.into(),
+            2.into(),
+        );
+        let valid = OrderBookDelta::new(
+            book.instrument_id,
+            BookAction::Add,
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("99.0"),
+                Quantity::from("1.0"),
+                11,
+            ),
+            0,
+            3,
+            3.into(),
+            3.into(),
+        );
+
+        assert_eq!(orderbook_apply_delta(&mut book, &valid), 1);
+        assert_eq!(orderbook_apply_delta(&mut book, &crossing), 0);
+        orderbook_drop(book);
+    }
+
+    #[rstest]
+    fn test_get_avg_px_for_quantity_with_no_liquidity_returns_nan() {
+        let mut book = orderbook_new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), BookType::L2_MBP);
+
+        let avg_px =
+            orderbook_get_avg_px_for_quantity(&mut book, Quantity::from("1.0"), OrderSide::Buy);
+
+        assert!(avg_px.is_nan());
+        orderbook_drop(book);
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_without_limit() {
+        let book = book_with_asks();
+
+        let result = unsafe {
+            orderbook_simulate_fills_for_quantity(
+                &book,
+                OrderSide::Buy,
+                ptr::null(),
+                Quantity::from("4.0"),
+            )
+        };
+
+        assert_eq!(result.fills.len, 2);
+        assert_eq!(result.filled_qty, Quantity::from("3.0"));
+        assert_eq!(result.is_partial, 1);
+        simulated_fills_drop(result);
+        orderbook_drop(book);
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_with_limit() {
+        let book = book_with_asks();
+        let price_limit = Price::from("100.0");
+
+        let result = unsafe {
+            orderbook_simulate_fills_for_quantity(
+                &book,
+                OrderSide::Buy,
+                &price_limit,
+                Quantity::from("1.0"),
+            )
+        };
+
+        assert_eq!(result.fills.len, 1);
+        assert_eq!(result.filled_qty, Quantity::from("1.0"));
+        assert_eq!(result.is_partial, 0);
+        simulated_fills_drop(result);
+        orderbook_drop(book);
+    }
+}
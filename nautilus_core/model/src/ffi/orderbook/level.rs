@@ -92,6 +92,11 @@ pub extern "C" fn level_orders(level: &Level_API) -> CVec {
     orders_vec.into()
 }
 
+#[no_mangle]
+pub extern "C" fn level_count(level: &Level_API) -> u32 {
+    level.count()
+}
+
 #[no_mangle]
 pub extern "C" fn level_size(level: &Level_API) -> f64 {
     level.size()
@@ -0,0 +1,517 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi::{parsing::u8_as_bool, string::cstr_to_ustr},
+    nanos::UnixNanos,
+    uuid::UUID4,
+};
+
+use crate::{
+    enums::{LiquiditySide, OrderSide, OrderStatus, OrderType, TimeInForce, TriggerType},
+    events::order::{
+        OrderAccepted, OrderCanceled, OrderDenied, OrderEventAny, OrderFilled, OrderInitialized,
+        OrderModifyRejected, OrderPendingUpdate, OrderRejected, OrderSubmitted,
+    },
+    ffi::events::order::OrderEventAny_API,
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, StrategyId, TradeId, TraderId, VenueOrderId,
+    },
+    orders::any::OrderAny,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`OrderAny`].
+///
+/// This struct wraps `OrderAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `OrderAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `OrderAny_API` to be
+/// dereferenced to `OrderAny`, providing access to `OrderAny`'s methods without
+/// having to manually access the underlying `OrderAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct OrderAny_API(Box<OrderAny>);
+
+impl OrderAny_API {
+    #[must_use]
+    pub fn new(order: OrderAny) -> Self {
+        Self(Box::new(order))
+    }
+}
+
+impl Deref for OrderAny_API {
+    type Target = OrderAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for OrderAny_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new order from the values of an `OrderInitialized` event.
+///
+/// # Safety
+///
+/// - Assumes `price` and `trigger_price` are each either null (no price) or a valid `Price` pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `order_type` is not `MARKET`, `LIMIT`, `STOP_MARKET` or `STOP_LIMIT`.
+/// - If a price or trigger price required by the `order_type` is null.
+#[no_mangle]
+pub unsafe extern "C" fn order_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    client_order_id: ClientOrderId,
+    order_side: OrderSide,
+    order_type: OrderType,
+    quantity: Quantity,
+    price: *const Price,
+    trigger_price: *const Price,
+    trigger_type: TriggerType,
+    time_in_force: TimeInForce,
+    post_only: u8,
+    reduce_only: u8,
+    init_id: UUID4,
+    ts_init: UnixNanos,
+) -> OrderAny_API {
+    assert!(
+        matches!(
+            order_type,
+            OrderType::Market | OrderType::Limit | OrderType::StopMarket | OrderType::StopLimit
+        ),
+        "Unsupported `order_type` for FFI order, was {order_type}"
+    );
+    let init = OrderInitialized::new(
+        trader_id,
+        strategy_id,
+        instrument_id,
+        client_order_id,
+        order_side,
+        order_type,
+        quantity,
+        time_in_force,
+        u8_as_bool(post_only),
+        u8_as_bool(reduce_only),
+        false,
+        false,
+        init_id,
+        ts_init,
+        ts_init,
+        price.as_ref().copied(),
+        trigger_price.as_ref().copied(),
+        order_type.requires_trigger_type().then_some(trigger_type),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    OrderAny_API(Box::new(OrderAny::from(init)))
+}
+
+#[no_mangle]
+pub extern "C" fn order_drop(order: OrderAny_API) {
+    drop(order); // Memory freed here
+}
+
+fn apply_event(order: &mut OrderAny_API, event: OrderEventAny) -> u8 {
+    u8::from(order.apply(event).is_ok())
+}
+
+/// Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_event(order: &mut OrderAny_API, event: &OrderEventAny_API) -> u8 {
+    apply_event(order, (**event).clone())
+}
+
+/// Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_denied(order: &mut OrderAny_API, event: OrderDenied) -> u8 {
+    apply_event(order, OrderEventAny::Denied(event))
+}
+
+/// Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_submitted(order: &mut OrderAny_API, event: OrderSubmitted) -> u8 {
+    apply_event(order, OrderEventAny::Submitted(event))
+}
+
+/// Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_accepted(order: &mut OrderAny_API, event: OrderAccepted) -> u8 {
+    apply_event(order, OrderEventAny::Accepted(event))
+}
+
+/// Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_rejected(order: &mut OrderAny_API, event: OrderRejected) -> u8 {
+    apply_event(order, OrderEventAny::Rejected(event))
+}
+
+/// Applies a pending update event to the order, returning 1 if applied, or 0 if the event
+/// was rejected (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_pending_update(
+    order: &mut OrderAny_API,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> u8 {
+    let Some(account_id) = order.account_id() else {
+        return 0; // Not yet submitted
+    };
+    let event = OrderPendingUpdate::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        account_id,
+        event_id,
+        ts_event,
+        ts_init,
+        false,
+        order.venue_order_id(),
+    );
+    apply_event(order, OrderEventAny::PendingUpdate(event))
+}
+
+/// Applies a modify rejected event to the order, returning 1 if applied, or 0 if the event
+/// was rejected (leaving the order unchanged).
+///
+/// # Safety
+///
+/// - Assumes `reason_ptr` is a valid C string pointer.
+#[no_mangle]
+pub unsafe extern "C" fn order_apply_modify_rejected(
+    order: &mut OrderAny_API,
+    reason_ptr: *const c_char,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> u8 {
+    let event = OrderModifyRejected::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        cstr_to_ustr(reason_ptr),
+        event_id,
+        ts_event,
+        ts_init,
+        false,
+        order.venue_order_id(),
+        order.account_id(),
+    );
+    apply_event(order, OrderEventAny::ModifyRejected(event))
+}
+
+/// Applies a canceled event to the order, returning 1 if applied, or 0 if the event was
+/// rejected (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_canceled(
+    order: &mut OrderAny_API,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> u8 {
+    let event = OrderCanceled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        event_id,
+        ts_event,
+        ts_init,
+        false,
+        order.venue_order_id(),
+        order.account_id(),
+    );
+    apply_event(order, OrderEventAny::Canceled(event))
+}
+
+/// Applies a fill to the order, returning 1 if applied, or 0 if the fill was rejected
+/// (leaving the order unchanged).
+#[no_mangle]
+pub extern "C" fn order_apply_filled(
+    order: &mut OrderAny_API,
+    venue_order_id: VenueOrderId,
+    account_id: AccountId,
+    trade_id: TradeId,
+    last_qty: Quantity,
+    last_px: Price,
+    currency: Currency,
+    commission: Money,
+    liquidity_side: LiquiditySide,
+    event_id: UUID4,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) -> u8 {
+    let event = OrderFilled::new(
+        order.trader_id(),
+        order.strategy_id(),
+        order.instrument_id(),
+        order.client_order_id(),
+        venue_order_id,
+        account_id,
+        trade_id,
+        order.order_side(),
+        order.order_type(),
+        last_qty,
+        last_px,
+        currency,
+        liquidity_side,
+        event_id,
+        ts_event,
+        ts_init,
+        false,
+        None,
+        Some(commission),
+    );
+    apply_event(order, OrderEventAny::Filled(event))
+}
+
+#[no_mangle]
+pub extern "C" fn order_status(order: &OrderAny_API) -> OrderStatus {
+    order.status()
+}
+
+#[no_mangle]
+pub extern "C" fn order_quantity(order: &OrderAny_API) -> Quantity {
+    order.quantity()
+}
+
+#[no_mangle]
+pub extern "C" fn order_filled_qty(order: &OrderAny_API) -> Quantity {
+    order.filled_qty()
+}
+
+#[no_mangle]
+pub extern "C" fn order_leaves_qty(order: &OrderAny_API) -> Quantity {
+    order.leaves_qty()
+}
+
+/// Returns the average fill price, or NaN if the order has no fills.
+#[no_mangle]
+pub extern "C" fn order_avg_px(order: &OrderAny_API) -> f64 {
+    order.avg_px().unwrap_or(f64::NAN)
+}
+
+#[no_mangle]
+pub extern "C" fn order_is_closed(order: &OrderAny_API) -> u8 {
+    u8::from(order.is_closed())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ffi::events::order::{order_event_drop, order_expired_new};
+
+    fn limit_order() -> OrderAny_API {
+        let price = Price::from("1.00000");
+        unsafe {
+            order_new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("AUD/USD.SIM"),
+                ClientOrderId::from("O-123456"),
+                OrderSide::Buy,
+                OrderType::Limit,
+                Quantity::from(100_000),
+                &price,
+                std::ptr::null(),
+                TriggerType::NoTrigger,
+                TimeInForce::Gtc,
+                0,
+                0,
+                UUID4::new(),
+                UnixNanos::default(),
+            )
+        }
+    }
+
+    fn submit_and_accept(order: &mut OrderAny_API) {
+        let submitted = OrderSubmitted {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            account_id: AccountId::from("SIM-001"),
+            event_id: UUID4::new(),
+            ts_event: UnixNanos::default(),
+            ts_init: UnixNanos::default(),
+        };
+        let accepted = OrderAccepted {
+            trader_id: order.trader_id(),
+            strategy_id: order.strategy_id(),
+            instrument_id: order.instrument_id(),
+            client_order_id: order.client_order_id(),
+            venue_order_id: VenueOrderId::from("V-001"),
+            account_id: AccountId::from("SIM-001"),
+            event_id: UUID4::new(),
+            ts_event: UnixNanos::default(),
+            ts_init: UnixNanos::default(),
+            reconciliation: 0,
+        };
+        assert_eq!(order_apply_submitted(order, submitted), 1);
+        assert_eq!(order_apply_accepted(order, accepted), 1);
+    }
+
+    fn apply_fill(order: &mut OrderAny_API, trade_id: &str, last_qty: i64, last_px: &str) -> u8 {
+        order_apply_filled(
+            order,
+            VenueOrderId::from("V-001"),
+            AccountId::from("SIM-001"),
+            TradeId::from(trade_id),
+            Quantity::from(last_qty),
+            Price::from(last_px),
+            Currency::USD(),
+            Money::from("2.00 USD"),
+            LiquiditySide::Maker,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        )
+    }
+
+    #[rstest]
+    fn test_order_lifecycle_with_partial_fills() {
+        let mut order = limit_order();
+        submit_and_accept(&mut order);
+
+        assert!(order_avg_px(&order).is_nan());
+        assert_eq!(apply_fill(&mut order, "1", 40_000, "1.00000"), 1);
+        assert_eq!(order_status(&order), OrderStatus::PartiallyFilled);
+        assert_eq!(order_leaves_qty(&order), Quantity::from(60_000));
+
+        assert_eq!(apply_fill(&mut order, "2", 60_000, "1.00100"), 1);
+        assert_eq!(order_status(&order), OrderStatus::Filled);
+        assert_eq!(order_filled_qty(&order), Quantity::from(100_000));
+        assert!((order_avg_px(&order) - 1.0006).abs() < 1e-9);
+        assert_eq!(order_is_closed(&order), 1);
+        order_drop(order);
+    }
+
+    #[rstest]
+    fn test_order_modify_rejected_then_canceled() {
+        let mut order = limit_order();
+        submit_and_accept(&mut order);
+        let reason = CString::new("ORDER_DOES_NOT_EXIST").unwrap();
+
+        assert_eq!(
+            order_apply_pending_update(
+                &mut order,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default()
+            ),
+            1
+        );
+        let applied = unsafe {
+            order_apply_modify_rejected(
+                &mut order,
+                reason.as_ptr(),
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+            )
+        };
+        assert_eq!(applied, 1);
+        assert_eq!(order_status(&order), OrderStatus::Accepted);
+
+        let applied = order_apply_canceled(
+            &mut order,
+            UUID4::new(),
+            UnixNanos::default(),
+            UnixNanos::default(),
+        );
+        assert_eq!(applied, 1);
+        assert_eq!(order_status(&order), OrderStatus::Canceled);
+        order_drop(order);
+    }
+
+    #[rstest]
+    fn test_order_fill_before_submission_rejected() {
+        let mut order = limit_order();
+
+        assert_eq!(apply_fill(&mut order, "1", 40_000, "1.00000"), 0);
+        assert_eq!(order_status(&order), OrderStatus::Initialized);
+        assert_eq!(order_filled_qty(&order), Quantity::from(0));
+        order_drop(order);
+    }
+
+    #[rstest]
+    fn test_order_apply_event_expired() {
+        let mut order = limit_order();
+        submit_and_accept(&mut order);
+        let venue_order_id = VenueOrderId::from("V-001");
+        let account_id = AccountId::from("SIM-001");
+        let event = unsafe {
+            order_expired_new(
+                order.trader_id(),
+                order.strategy_id(),
+                order.instrument_id(),
+                order.client_order_id(),
+                &venue_order_id,
+                &account_id,
+                UUID4::new(),
+                UnixNanos::default(),
+                UnixNanos::default(),
+                0,
+            )
+        };
+
+        assert_eq!(order_apply_event(&mut order, &event), 1);
+        assert_eq!(order_status(&order), OrderStatus::Expired);
+        assert_eq!(order_apply_event(&mut order, &event), 0);
+        order_event_drop(event);
+        order_drop(order);
+    }
+}
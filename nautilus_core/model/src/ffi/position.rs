@@ -0,0 +1,456 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{ffi::parsing::u8_as_bool, nanos::UnixNanos, uuid::UUID4};
+
+use crate::{
+    enums::{LiquiditySide, OrderSide, OrderType, PositionSide},
+    events::order::filled::OrderFilled,
+    identifiers::{
+        AccountId, ClientOrderId, InstrumentId, PositionId, StrategyId, TradeId, TraderId,
+        VenueOrderId,
+    },
+    position::Position,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+///
+/// This struct wraps `Position` in a way that makes it compatible with C function
+/// calls, enabling interaction with `Position` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `Position_API` to be
+/// dereferenced to `Position`, providing access to `Position`'s methods without
+/// having to manually access the underlying `Position` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct Position_API(Box<Position>);
+
+impl Position_API {
+    #[must_use]
+    pub fn new(position: Position) -> Self {
+        Self(Box::new(position))
+    }
+}
+
+impl Deref for Position_API {
+    type Target = Position;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Position_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new flat position, which is opened by the first applied fill.
+///
+/// # Safety
+///
+/// - Assumes `base_currency` is either null (no base currency) or a valid `Currency` pointer.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn position_new(
+    trader_id: TraderId,
+    strategy_id: StrategyId,
+    instrument_id: InstrumentId,
+    position_id: PositionId,
+    account_id: AccountId,
+    price_precision: u8,
+    size_precision: u8,
+    multiplier: Quantity,
+    is_inverse: u8,
+    base_currency: *const Currency,
+    quote_currency: Currency,
+    settlement_currency: Currency,
+    ts_init: UnixNanos,
+) -> Position_API {
+    Position_API(Box::new(Position {
+        events: Vec::new(),
+        trader_id,
+        strategy_id,
+        instrument_id,
+        id: position_id,
+        account_id,
+        opening_order_id: ClientOrderId::default(),
+        closing_order_id: None,
+        entry: OrderSide::NoOrderSide,
+        side: PositionSide::Flat,
+        signed_qty: 0.0,
+        quantity: Quantity::zero(size_precision),
+        peak_qty: Quantity::zero(size_precision),
+        price_precision,
+        size_precision,
+        multiplier,
+        is_inverse: u8_as_bool(is_inverse),
+        base_currency: base_currency.as_ref().copied(),
+        quote_currency,
+        settlement_currency,
+        ts_init,
+        ts_opened: ts_init,
+        ts_last: ts_init,
+        ts_closed: None,
+        duration_ns: 0,
+        avg_px_open: 0.0,
+        avg_px_close: None,
+        realized_return: 0.0,
+        realized_pnl: None,
+        trade_ids: Vec::new(),
+        buy_qty: Quantity::zero(size_precision),
+        sell_qty: Quantity::zero(size_precision),
+        commissions: HashMap::new(),
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn position_drop(position: Position_API) {
+    drop(position); // Memory freed here
+}
+
+/// Applies an `OrderFilled` event with the given fill details to the position.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `order_side` is `NO_ORDER_SIDE`.
+/// - If the `trade_id` has already been applied to the position.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn position_apply_fill(
+    position: &mut Position_API,
+    client_order_id: ClientOrderId,
+    venue_order_id: VenueOrderId,
+    trade_id: TradeId,
+    order_side: OrderSide,
+    last_qty: Quantity,
+    last_px: Price,
+    commission: Money,
+    ts_event: UnixNanos,
+    ts_init: UnixNanos,
+) {
+    let fill = OrderFilled::new(
+        position.trader_id,
+        position.strategy_id,
+        position.instrument_id,
+        client_order_id,
+        venue_order_id,
+        position.account_id,
+        trade_id,
+        order_side,
+        OrderType::Market,
+        last_qty,
+        last_px,
+        position.quote_currency,
+        LiquiditySide::NoLiquiditySide,
+        UUID4::new(),
+        ts_event,
+        ts_init,
+        false,
+        Some(position.id),
+        Some(commission),
+    );
+    position.apply(&fill);
+}
+
+#[no_mangle]
+pub extern "C" fn position_side(position: &Position_API) -> PositionSide {
+    position.side
+}
+
+#[no_mangle]
+pub extern "C" fn position_signed_qty(position: &Position_API) -> f64 {
+    position.signed_qty
+}
+
+#[no_mangle]
+pub extern "C" fn position_quantity(position: &Position_API) -> Quantity {
+    position.quantity
+}
+
+#[no_mangle]
+pub extern "C" fn position_avg_px_open(position: &Position_API) -> f64 {
+    position.avg_px_open
+}
+
+/// Returns the average close price, or `NaN` if the position has not been reduced.
+#[no_mangle]
+pub extern "C" fn position_avg_px_close(position: &Position_API) -> f64 {
+    position.avg_px_close.unwrap_or(f64::NAN)
+}
+
+/// Returns the realized PnL in the settlement currency (zero if no fills have been applied).
+#[no_mangle]
+pub extern "C" fn position_realized_pnl(position: &Position_API) -> Money {
+    position
+        .realized_pnl
+        .unwrap_or_else(|| Money::new(0.0, position.settlement_currency))
+}
+
+#[no_mangle]
+pub extern "C" fn position_unrealized_pnl(position: &Position_API, last: Price) -> Money {
+    position.unrealized_pnl(last)
+}
+
+#[no_mangle]
+pub extern "C" fn position_total_pnl(position: &Position_API, last: Price) -> Money {
+    position.total_pnl(last)
+}
+
+/// Returns the notional value of the position at the `last` price.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the position is inverse and was created without a base currency.
+#[no_mangle]
+pub extern "C" fn position_notional_value(position: &Position_API, last: Price) -> Money {
+    position.notional_value(last)
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_open(position: &Position_API) -> u8 {
+    u8::from(position.is_open())
+}
+
+#[no_mangle]
+pub extern "C" fn position_is_closed(position: &Position_API) -> u8 {
+    u8::from(position.is_closed())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    struct TestFill {
+        side: OrderSide,
+        qty: i64,
+        px: &'static str,
+        commission: &'static str,
+        side_after: PositionSide,
+        signed_qty_after: f64,
+        avg_px_open_after: f64,
+        realized_pnl_after: &'static str,
+    }
+
+    #[fixture]
+    fn fills() -> Vec<TestFill> {
+        vec![
+            TestFill {
+                side: OrderSide::Buy,
+                qty: 100_000,
+                px: "1.00000",
+                commission: "2.00 USD",
+                side_after: PositionSide::Long,
+                signed_qty_after: 100_000.0,
+                avg_px_open_after: 1.0,
+                realized_pnl_after: "-2.00 USD",
+            },
+            TestFill {
+                side: OrderSide::Buy,
+                qty: 100_000,
+                px: "1.00020",
+                commission: "2.00 USD",
+                side_after: PositionSide::Long,
+                signed_qty_after: 200_000.0,
+                avg_px_open_after: 1.0001,
+                realized_pnl_after: "-4.00 USD",
+            },
+            TestFill {
+                side: OrderSide::Sell,
+                qty: 50_000,
+                px: "1.00030",
+                commission: "1.00 USD",
+                side_after: PositionSide::Long,
+                signed_qty_after: 150_000.0,
+                avg_px_open_after: 1.0001,
+                realized_pnl_after: "5.00 USD",
+            },
+            // Flips from long 150,000 to short 100,000 in one fill
+            TestFill {
+                side: OrderSide::Sell,
+                qty: 250_000,
+                px: "1.00050",
+                commission: "5.00 USD",
+                side_after: PositionSide::Short,
+                signed_qty_after: -100_000.0,
+                avg_px_open_after: 1.0005,
+                realized_pnl_after: "60.00 USD",
+            },
+            TestFill {
+                side: OrderSide::Buy,
+                qty: 100_000,
+                px: "1.00045",
+                commission: "2.00 USD",
+                side_after: PositionSide::Flat,
+                signed_qty_after: 0.0,
+                avg_px_open_after: 1.0005,
+                realized_pnl_after: "63.00 USD",
+            },
+        ]
+    }
+
+    fn position(
+        is_inverse: bool,
+        base_currency: *const Currency,
+        currency: Currency,
+    ) -> Position_API {
+        unsafe {
+            position_new(
+                TraderId::from("TRADER-001"),
+                StrategyId::from("S-001"),
+                InstrumentId::from("AUD/USD.SIM"),
+                PositionId::from("P-001"),
+                AccountId::from("SIM-001"),
+                5,
+                0,
+                Quantity::from(1),
+                u8::from(is_inverse),
+                base_currency,
+                currency,
+                currency,
+                UnixNanos::default(),
+            )
+        }
+    }
+
+    fn apply(
+        position: &mut Position_API,
+        i: usize,
+        side: OrderSide,
+        qty: i64,
+        px: &str,
+        commission: &str,
+    ) {
+        position_apply_fill(
+            position,
+            ClientOrderId::from(format!("O-{i}").as_str()),
+            VenueOrderId::from(format!("V-{i}").as_str()),
+            TradeId::from(format!("T-{i}").as_str()),
+            side,
+            Quantity::from(qty),
+            Price::from(px),
+            Money::from(commission),
+            UnixNanos::from(i as u64),
+            UnixNanos::from(i as u64),
+        );
+    }
+
+    #[rstest]
+    fn test_position_new_is_flat() {
+        let position = position(false, ptr::null(), Currency::USD());
+
+        assert_eq!(position_side(&position), PositionSide::Flat);
+        assert_eq!(position_is_open(&position), 0);
+        assert_eq!(position_realized_pnl(&position), Money::from("0 USD"));
+        assert!(position_avg_px_close(&position).is_nan());
+        position_drop(position);
+    }
+
+    #[rstest]
+    fn test_position_fills_with_known_pnl(fills: Vec<TestFill>) {
+        let mut position = position(false, ptr::null(), Currency::USD());
+
+        for (i, fill) in fills.iter().enumerate() {
+            apply(
+                &mut position,
+                i,
+                fill.side,
+                fill.qty,
+                fill.px,
+                fill.commission,
+            );
+
+            assert_eq!(position_side(&position), fill.side_after, "fill {i}");
+            assert_eq!(
+                position_signed_qty(&position),
+                fill.signed_qty_after,
+                "fill {i}"
+            );
+            assert!(
+                (position_avg_px_open(&position) - fill.avg_px_open_after).abs() < 1e-9,
+                "fill {i}"
+            );
+            assert_eq!(
+                position_realized_pnl(&position),
+                Money::from(fill.realized_pnl_after),
+                "fill {i}"
+            );
+
+            if i == 3 {
+                // After the flip the new short has not been reduced yet
+                let last = Price::from("1.00040");
+                assert!(position_avg_px_close(&position).is_nan());
+                assert_eq!(position_quantity(&position), Quantity::from(100_000));
+                assert_eq!(
+                    position_unrealized_pnl(&position, last),
+                    Money::from("10.00 USD")
+                );
+                assert_eq!(
+                    position_total_pnl(&position, last),
+                    Money::from("70.00 USD")
+                );
+                assert_eq!(
+                    position_notional_value(&position, last),
+                    Money::from("100040.00 USD")
+                );
+            }
+        }
+
+        assert_eq!(position_is_closed(&position), 1);
+        assert_eq!(position_avg_px_close(&position), 1.00045);
+        position_drop(position);
+    }
+
+    #[rstest]
+    fn test_position_inverse_pnl_and_notional_value() {
+        let btc = Currency::BTC();
+        let mut position = position(true, &btc, btc);
+        apply(
+            &mut position,
+            0,
+            OrderSide::Buy,
+            100_000,
+            "10000.0",
+            "0 BTC",
+        );
+        let last = Price::from("11000.0");
+
+        assert_eq!(
+            position_unrealized_pnl(&position, last),
+            Money::from("0.90909091 BTC")
+        );
+        assert_eq!(
+            position_notional_value(&position, last),
+            Money::from("9.09090909 BTC")
+        );
+        position_drop(position);
+    }
+}
@@ -0,0 +1,140 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::ffi::c_char;
+
+use nautilus_core::ffi::string::str_to_cstr;
+
+use crate::{
+    identifiers::InstrumentId,
+    types::{
+        balance::{AccountBalance, MarginBalance},
+        money::Money,
+    },
+};
+
+/// Returns a new [`AccountBalance`] from the `total`, `locked` and `free` balances.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the balances are not all in the same currency.
+/// - If `total` is not the result of `locked` + `free`.
+#[no_mangle]
+pub extern "C" fn account_balance_new(total: Money, locked: Money, free: Money) -> AccountBalance {
+    AccountBalance::new(total, locked, free)
+}
+
+/// Returns whether the `balance` is valid, with all balances in its currency and the `free`
+/// balance equal to the `total` less the `locked` balance.
+#[no_mangle]
+pub extern "C" fn account_balance_is_valid(balance: &AccountBalance) -> u8 {
+    u8::from(
+        balance.total.currency == balance.currency
+            && AccountBalance::new_checked(balance.total, balance.locked, balance.free).is_ok(),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_eq(lhs: &AccountBalance, rhs: &AccountBalance) -> u8 {
+    u8::from(lhs == rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn account_balance_to_cstr(balance: &AccountBalance) -> *const c_char {
+    str_to_cstr(&balance.to_string())
+}
+
+/// Returns a new [`MarginBalance`] from the `initial` and `maintenance` margins.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `maintenance` is not in the currency of `initial`.
+#[no_mangle]
+pub extern "C" fn margin_balance_new(
+    initial: Money,
+    maintenance: Money,
+    instrument_id: InstrumentId,
+) -> MarginBalance {
+    MarginBalance::new(initial, maintenance, instrument_id)
+}
+
+/// Returns whether the `balance` is valid, with both margins in its currency.
+#[no_mangle]
+pub extern "C" fn margin_balance_is_valid(balance: &MarginBalance) -> u8 {
+    u8::from(
+        balance.initial.currency == balance.currency
+            && balance.maintenance.currency == balance.currency,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_eq(lhs: &MarginBalance, rhs: &MarginBalance) -> u8 {
+    u8::from(lhs == rhs)
+}
+
+#[no_mangle]
+pub extern "C" fn margin_balance_to_cstr(balance: &MarginBalance) -> *const c_char {
+    str_to_cstr(&balance.to_string())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{identifiers::stubs::instrument_id_btc_usdt, types::currency::Currency};
+
+    #[rstest]
+    fn test_account_balance_new() {
+        let balance = account_balance_new(
+            Money::from("100 USD"),
+            Money::from("25 USD"),
+            Money::from("75 USD"),
+        );
+
+        assert_eq!(balance.currency, Currency::USD());
+        assert_eq!(account_balance_is_valid(&balance), 1);
+    }
+
+    #[rstest]
+    fn test_account_balance_is_valid_after_mutation() {
+        let mut balance = account_balance_new(
+            Money::from("100 USD"),
+            Money::from("25 USD"),
+            Money::from("75 USD"),
+        );
+        balance.free = Money::from("70 USD");
+
+        assert_eq!(account_balance_is_valid(&balance), 0);
+    }
+
+    #[rstest]
+    fn test_margin_balance_new() {
+        let balance = margin_balance_new(
+            Money::from("5000 USD"),
+            Money::from("20000 USD"),
+            instrument_id_btc_usdt(),
+        );
+
+        assert_eq!(balance.currency, Currency::USD());
+        assert_eq!(margin_balance_is_valid(&balance), 1);
+        assert_eq!(margin_balance_eq(&balance, &balance), 1);
+    }
+}
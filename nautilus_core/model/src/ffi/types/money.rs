@@ -15,6 +15,8 @@
 
 use std::ops::{AddAssign, SubAssign};
 
+use nautilus_core::ffi::cvec::CVec;
+
 use crate::types::{currency::Currency, money::Money};
 
 // TODO: Document panic
@@ -43,3 +45,11 @@ pub extern "C" fn money_add_assign(mut a: Money, b: Money) {
 pub extern "C" fn money_sub_assign(mut a: Money, b: Money) {
     a.sub_assign(b);
 }
+
+#[allow(clippy::drop_non_drop)]
+#[no_mangle]
+pub extern "C" fn vec_money_drop(v: CVec) {
+    let CVec { ptr, len, cap } = v;
+    let money: Vec<Money> = unsafe { Vec::from_raw_parts(ptr.cast::<Money>(), len, cap) };
+    drop(money); // Memory freed here
+}
@@ -21,9 +21,10 @@ use super::{
     crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair, equity::Equity,
     futures_contract::FuturesContract, futures_spread::FuturesSpread,
     options_contract::OptionsContract, options_spread::OptionsSpread, Instrument,
+    InstrumentViolation,
 };
 use crate::{
-    enums::InstrumentClass,
+    enums::{InstrumentClass, RoundingMode},
     identifiers::InstrumentId,
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
@@ -140,6 +141,22 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn is_quanto(&self) -> bool {
+        match self {
+            Self::Betting(inst) => inst.is_quanto(),
+            Self::BinaryOption(inst) => inst.is_quanto(),
+            Self::CryptoFuture(inst) => inst.is_quanto(),
+            Self::CryptoPerpetual(inst) => inst.is_quanto(),
+            Self::CurrencyPair(inst) => inst.is_quanto(),
+            Self::Equity(inst) => inst.is_quanto(),
+            Self::FuturesContract(inst) => inst.is_quanto(),
+            Self::FuturesSpread(inst) => inst.is_quanto(),
+            Self::OptionsContract(inst) => inst.is_quanto(),
+            Self::OptionsSpread(inst) => inst.is_quanto(),
+        }
+    }
+
     #[must_use]
     pub fn price_precision(&self) -> u8 {
         match self {
@@ -298,6 +315,62 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn make_price_rounded(&self, value: f64, mode: RoundingMode) -> Price {
+        match self {
+            Self::Betting(inst) => inst.make_price_rounded(value, mode),
+            Self::BinaryOption(inst) => inst.make_price_rounded(value, mode),
+            Self::CryptoFuture(inst) => inst.make_price_rounded(value, mode),
+            Self::CryptoPerpetual(inst) => inst.make_price_rounded(value, mode),
+            Self::CurrencyPair(inst) => inst.make_price_rounded(value, mode),
+            Self::Equity(inst) => inst.make_price_rounded(value, mode),
+            Self::FuturesContract(inst) => inst.make_price_rounded(value, mode),
+            Self::FuturesSpread(inst) => inst.make_price_rounded(value, mode),
+            Self::OptionsContract(inst) => inst.make_price_rounded(value, mode),
+            Self::OptionsSpread(inst) => inst.make_price_rounded(value, mode),
+        }
+    }
+
+    #[must_use]
+    pub fn make_qty_rounded(&self, value: f64, mode: RoundingMode) -> Quantity {
+        match self {
+            Self::Betting(inst) => inst.make_qty_rounded(value, mode),
+            Self::BinaryOption(inst) => inst.make_qty_rounded(value, mode),
+            Self::CryptoFuture(inst) => inst.make_qty_rounded(value, mode),
+            Self::CryptoPerpetual(inst) => inst.make_qty_rounded(value, mode),
+            Self::CurrencyPair(inst) => inst.make_qty_rounded(value, mode),
+            Self::Equity(inst) => inst.make_qty_rounded(value, mode),
+            Self::FuturesContract(inst) => inst.make_qty_rounded(value, mode),
+            Self::FuturesSpread(inst) => inst.make_qty_rounded(value, mode),
+            Self::OptionsContract(inst) => inst.make_qty_rounded(value, mode),
+            Self::OptionsSpread(inst) => inst.make_qty_rounded(value, mode),
+        }
+    }
+
+    /// Validates an order `price` and `quantity` against the instrument.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the first [`InstrumentViolation`] found.
+    pub fn validate_order(
+        &self,
+        price: Price,
+        quantity: Quantity,
+    ) -> Result<(), InstrumentViolation> {
+        match self {
+            Self::Betting(inst) => inst.validate_order(price, quantity),
+            Self::BinaryOption(inst) => inst.validate_order(price, quantity),
+            Self::CryptoFuture(inst) => inst.validate_order(price, quantity),
+            Self::CryptoPerpetual(inst) => inst.validate_order(price, quantity),
+            Self::CurrencyPair(inst) => inst.validate_order(price, quantity),
+            Self::Equity(inst) => inst.validate_order(price, quantity),
+            Self::FuturesContract(inst) => inst.validate_order(price, quantity),
+            Self::FuturesSpread(inst) => inst.validate_order(price, quantity),
+            Self::OptionsContract(inst) => inst.validate_order(price, quantity),
+            Self::OptionsSpread(inst) => inst.validate_order(price, quantity),
+        }
+    }
+
     #[must_use]
     pub fn calculate_notional_value(
         &self,
@@ -339,6 +412,22 @@ impl InstrumentAny {
         }
     }
 
+    #[must_use]
+    pub fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
+        match self {
+            Self::Betting(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::BinaryOption(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CryptoFuture(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CryptoPerpetual(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::CurrencyPair(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::Equity(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FuturesContract(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::FuturesSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::OptionsContract(inst) => inst.calculate_base_quantity(quantity, last_px),
+            Self::OptionsSpread(inst) => inst.calculate_base_quantity(quantity, last_px),
+        }
+    }
+
     // #[deprecated(since = "0.21.0", note = "Will be removed in a future version")]
     #[must_use]
     pub fn maker_fee(&self) -> Decimal {
@@ -372,6 +461,54 @@ impl InstrumentAny {
             Self::OptionsSpread(inst) => inst.taker_fee(),
         }
     }
+
+    #[must_use]
+    pub fn lot_size(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.lot_size(),
+            Self::BinaryOption(inst) => inst.lot_size(),
+            Self::CryptoFuture(inst) => inst.lot_size(),
+            Self::CryptoPerpetual(inst) => inst.lot_size(),
+            Self::CurrencyPair(inst) => inst.lot_size(),
+            Self::Equity(inst) => inst.lot_size(),
+            Self::FuturesContract(inst) => inst.lot_size(),
+            Self::FuturesSpread(inst) => inst.lot_size(),
+            Self::OptionsContract(inst) => inst.lot_size(),
+            Self::OptionsSpread(inst) => inst.lot_size(),
+        }
+    }
+
+    #[must_use]
+    pub fn max_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.max_quantity(),
+            Self::BinaryOption(inst) => inst.max_quantity(),
+            Self::CryptoFuture(inst) => inst.max_quantity(),
+            Self::CryptoPerpetual(inst) => inst.max_quantity(),
+            Self::CurrencyPair(inst) => inst.max_quantity(),
+            Self::Equity(inst) => inst.max_quantity(),
+            Self::FuturesContract(inst) => inst.max_quantity(),
+            Self::FuturesSpread(inst) => inst.max_quantity(),
+            Self::OptionsContract(inst) => inst.max_quantity(),
+            Self::OptionsSpread(inst) => inst.max_quantity(),
+        }
+    }
+
+    #[must_use]
+    pub fn min_quantity(&self) -> Option<Quantity> {
+        match self {
+            Self::Betting(inst) => inst.min_quantity(),
+            Self::BinaryOption(inst) => inst.min_quantity(),
+            Self::CryptoFuture(inst) => inst.min_quantity(),
+            Self::CryptoPerpetual(inst) => inst.min_quantity(),
+            Self::CurrencyPair(inst) => inst.min_quantity(),
+            Self::Equity(inst) => inst.min_quantity(),
+            Self::FuturesContract(inst) => inst.min_quantity(),
+            Self::FuturesSpread(inst) => inst.min_quantity(),
+            Self::OptionsContract(inst) => inst.min_quantity(),
+            Self::OptionsSpread(inst) => inst.min_quantity(),
+        }
+    }
 }
 
 impl PartialEq for InstrumentAny {
@@ -34,15 +34,136 @@ pub mod stubs;
 use nautilus_core::nanos::UnixNanos;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
 use ustr::Ustr;
 
 use self::any::InstrumentAny;
 use crate::{
-    enums::{AssetClass, InstrumentClass, OptionKind},
+    enums::{AssetClass, InstrumentClass, OptionKind, RoundingMode},
     identifiers::{InstrumentId, Symbol, Venue},
     types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
 };
 
+/// The reason an order is invalid for an instrument.
+#[repr(C)]
+#[derive(
+    Copy, Clone, Debug, Display, Hash, PartialEq, Eq, AsRefStr, FromRepr, EnumIter, EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum InstrumentViolation {
+    /// The quantity precision exceeds the instrument size precision.
+    QuantityPrecision = 1,
+    /// The quantity is not a multiple of the instrument size increment.
+    QuantityIncrement = 2,
+    /// The quantity is less than the instrument minimum quantity.
+    QuantityBelowMin = 3,
+    /// The quantity is greater than the instrument maximum quantity.
+    QuantityAboveMax = 4,
+    /// The price precision exceeds the instrument price precision.
+    PricePrecision = 5,
+    /// The price is not a multiple of the instrument price increment.
+    PriceIncrement = 6,
+    /// The notional value is less than the instrument minimum notional.
+    NotionalBelowMin = 7,
+    /// The notional value is greater than the instrument maximum notional.
+    NotionalAboveMax = 8,
+    /// A notional limit is in a different currency to the notional value.
+    NotionalCurrencyMismatch = 9,
+}
+
+/// Validates an order `quantity` against a size increment and optional quantity limits.
+///
+/// # Errors
+///
+/// This function returns the first [`InstrumentViolation`] found.
+pub fn validate_quantity(
+    quantity: Quantity,
+    size_increment: Quantity,
+    min_quantity: Option<Quantity>,
+    max_quantity: Option<Quantity>,
+) -> Result<(), InstrumentViolation> {
+    if quantity.precision > size_increment.precision {
+        return Err(InstrumentViolation::QuantityPrecision);
+    }
+    if quantity
+        .raw
+        .checked_rem(size_increment.raw)
+        .is_some_and(|rem| rem != 0)
+    {
+        return Err(InstrumentViolation::QuantityIncrement);
+    }
+    if min_quantity.is_some_and(|min| quantity < min) {
+        return Err(InstrumentViolation::QuantityBelowMin);
+    }
+    if max_quantity.is_some_and(|max| quantity > max) {
+        return Err(InstrumentViolation::QuantityAboveMax);
+    }
+    Ok(())
+}
+
+/// Validates an order `price` against a price increment.
+///
+/// # Errors
+///
+/// This function returns the first [`InstrumentViolation`] found.
+pub fn validate_price(price: Price, price_increment: Price) -> Result<(), InstrumentViolation> {
+    if price.precision > price_increment.precision {
+        return Err(InstrumentViolation::PricePrecision);
+    }
+    if price
+        .raw
+        .checked_rem(price_increment.raw)
+        .is_some_and(|rem| rem != 0)
+    {
+        return Err(InstrumentViolation::PriceIncrement);
+    }
+    Ok(())
+}
+
+/// Validates a `notional` value in the given `currency` against optional notional limits.
+///
+/// Limits are not converted, so a limit in a currency other than `currency` is a violation.
+///
+/// # Errors
+///
+/// This function returns the first [`InstrumentViolation`] found.
+pub fn validate_notional(
+    notional: Decimal,
+    currency: Currency,
+    min_notional: Option<Money>,
+    max_notional: Option<Money>,
+) -> Result<(), InstrumentViolation> {
+    if [min_notional, max_notional]
+        .iter()
+        .flatten()
+        .any(|limit| limit.currency != currency)
+    {
+        return Err(InstrumentViolation::NotionalCurrencyMismatch);
+    }
+    if min_notional.is_some_and(|min| notional < min.as_decimal()) {
+        return Err(InstrumentViolation::NotionalBelowMin);
+    }
+    if max_notional.is_some_and(|max| notional > max.as_decimal()) {
+        return Err(InstrumentViolation::NotionalAboveMax);
+    }
+    Ok(())
+}
+
+/// Tolerance (in increments) for floating point error when rounding up or down.
+const ROUNDING_EPSILON: f64 = 1e-9;
+
+/// Returns `value` rounded to a multiple of `increment` using the given rounding `mode`.
+fn round_to_increment(value: f64, increment: f64, mode: RoundingMode) -> f64 {
+    let steps = value / increment;
+    let steps = match mode {
+        RoundingMode::Nearest => steps.round(),
+        RoundingMode::Up => (steps - ROUNDING_EPSILON).ceil(),
+        RoundingMode::Down => (steps + ROUNDING_EPSILON).floor(),
+    };
+    steps * increment
+}
+
 pub trait Instrument: 'static + Send {
     fn into_any(self) -> InstrumentAny;
     fn id(&self) -> InstrumentId;
@@ -106,7 +227,70 @@ pub trait Instrument: 'static + Send {
         Quantity::new(value, self.size_precision())
     }
 
+    /// Creates a new `Price` from the given `value`, rounded to a multiple of the instrument
+    /// price increment using the given rounding `mode`.
+    fn make_price_rounded(&self, value: f64, mode: RoundingMode) -> Price {
+        let increment = self.price_increment().as_f64();
+        Price::new(
+            round_to_increment(value, increment, mode),
+            self.price_precision(),
+        )
+    }
+
+    /// Creates a new `Quantity` from the given `value`, rounded to a multiple of the instrument
+    /// size increment using the given rounding `mode`.
+    fn make_qty_rounded(&self, value: f64, mode: RoundingMode) -> Quantity {
+        let increment = self.size_increment().as_f64();
+        Quantity::new(
+            round_to_increment(value, increment, mode),
+            self.size_precision(),
+        )
+    }
+
+    /// Validates an order `price` and `quantity` against the instrument precisions, increments,
+    /// quantity limits and notional limits.
+    ///
+    /// Inverse instruments are valued in the quote currency for the notional check.
+    ///
+    /// # Errors
+    ///
+    /// This function returns the first [`InstrumentViolation`] found.
+    fn validate_order(&self, price: Price, quantity: Quantity) -> Result<(), InstrumentViolation> {
+        validate_quantity(
+            quantity,
+            self.size_increment(),
+            self.min_quantity(),
+            self.max_quantity(),
+        )?;
+        validate_price(price, self.price_increment())?;
+
+        if self.min_notional().is_some() || self.max_notional().is_some() {
+            let notional = self.calculate_notional_value(quantity, price, Some(true));
+            validate_notional(
+                notional.as_decimal(),
+                notional.currency,
+                self.min_notional(),
+                self.max_notional(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the instrument is a quanto, settling in a currency which is neither
+    /// its base nor quote currency.
+    fn is_quanto(&self) -> bool {
+        let settlement_currency = self.settlement_currency();
+        self.base_currency().is_some_and(|base_currency| {
+            settlement_currency != base_currency && settlement_currency != self.quote_currency()
+        })
+    }
+
     /// Calculates the notional value from the given parameters.
+    ///
+    /// Inverse instruments are valued in the base currency (`quantity * multiplier / price`),
+    /// quanto instruments in the settlement currency, and all others in the quote currency
+    /// (`quantity * multiplier * price`).
     /// The `use_quote_for_inverse` flag is only applicable for inverse instruments.
     ///
     /// # Panics
@@ -133,14 +317,19 @@ pub trait Instrument: 'static + Send {
             }
         } else {
             let amount = quantity.as_f64() * self.multiplier().as_f64() * price.as_f64();
-            let currency = self.quote_currency();
+            let currency = if self.is_quanto() {
+                self.settlement_currency()
+            } else {
+                self.quote_currency()
+            };
             (amount, currency)
         };
 
         Money::new(amount, currency)
     }
 
-    /// Returns the equivalent quantity of the base asset.
+    /// Returns the equivalent quantity of the base asset for the given quote `quantity`
+    /// at `last_px`, with the size precision of the instrument.
     fn calculate_base_quantity(&self, quantity: Quantity, last_px: Price) -> Quantity {
         let value = quantity.as_f64() * (1.0 / last_px.as_f64());
         Quantity::new(value, self.size_precision())
@@ -153,3 +342,268 @@ pub const EXPIRING_INSTRUMENT_TYPES: [InstrumentClass; 4] = [
     InstrumentClass::Option,
     InstrumentClass::OptionSpread,
 ];
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::instruments::{
+        crypto_perpetual::CryptoPerpetual, currency_pair::CurrencyPair,
+        futures_contract::FuturesContract, stubs::*,
+    };
+
+    fn ethusd_quanto_bitmex() -> CryptoPerpetual {
+        CryptoPerpetual::new(
+            InstrumentId::from("ETHUSD.BITMEX"),
+            Symbol::from("ETHUSD"),
+            Currency::ETH(),
+            Currency::USD(),
+            Currency::BTC(),
+            false,
+            2,
+            0,
+            Price::from("0.05"),
+            Quantity::from("1"),
+            dec!(-0.00025),
+            dec!(0.00075),
+            dec!(0.01),
+            dec!(0.0035),
+            Some(Quantity::from("0.000001")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0.into(),
+            0.into(),
+        )
+    }
+
+    fn futures_contract_es_multiplier_50() -> FuturesContract {
+        let es = futures_contract_es(None, None);
+        FuturesContract::new(
+            es.id,
+            es.raw_symbol,
+            es.asset_class,
+            es.exchange,
+            es.underlying,
+            es.activation_ns,
+            es.expiration_ns,
+            es.currency,
+            es.price_precision,
+            es.price_increment,
+            Quantity::from(50),
+            es.lot_size,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            0.into(),
+            0.into(),
+        )
+    }
+
+    #[rstest]
+    #[case(1.2346, RoundingMode::Nearest, "1.235")]
+    #[case(1.2344, RoundingMode::Nearest, "1.234")]
+    #[case(1.2341, RoundingMode::Up, "1.235")]
+    #[case(1.2349, RoundingMode::Down, "1.234")]
+    #[case(1.234, RoundingMode::Up, "1.234")]
+    #[case(1.234, RoundingMode::Down, "1.234")]
+    #[case(0.0004, RoundingMode::Down, "0.000")]
+    fn test_make_qty_rounded_to_step_size(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let quantity = crypto_perpetual_ethusdt.make_qty_rounded(value, mode);
+        assert_eq!(quantity, Quantity::from(expected));
+        assert_eq!(quantity.precision, 3);
+    }
+
+    #[rstest]
+    #[case(100.2, RoundingMode::Nearest, "100.0")]
+    #[case(100.25, RoundingMode::Nearest, "100.5")]
+    #[case(100.1, RoundingMode::Up, "100.5")]
+    #[case(100.9, RoundingMode::Down, "100.5")]
+    fn test_make_price_rounded_to_tick_size(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let price = xbtusd_bitmex.make_price_rounded(value, mode);
+        assert_eq!(price, Price::from(expected));
+    }
+
+    #[rstest]
+    #[case(10.6, RoundingMode::Nearest, "11")]
+    #[case(10.6, RoundingMode::Down, "10")]
+    #[case(10.2, RoundingMode::Up, "11")]
+    fn test_make_qty_rounded_precision_zero(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] value: f64,
+        #[case] mode: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let quantity = xbtusd_bitmex.make_qty_rounded(value, mode);
+        assert_eq!(quantity, Quantity::from(expected));
+        assert_eq!(quantity.precision, 0);
+    }
+
+    #[rstest]
+    #[case("2000.00", "0.005")] // Notional exactly at minimum
+    #[case("2000.00", "1.000")]
+    #[case("15000.00", "10000.000")]
+    fn test_validate_order_valid(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] quantity: &str,
+    ) {
+        let result =
+            crypto_perpetual_ethusdt.validate_order(Price::from(price), Quantity::from(quantity));
+        assert_eq!(result, Ok(()));
+    }
+
+    #[rstest]
+    #[case("2000.00", "1.0001", InstrumentViolation::QuantityPrecision)]
+    #[case("2000.00", "0.000", InstrumentViolation::QuantityBelowMin)]
+    #[case("2000.00", "10000.001", InstrumentViolation::QuantityAboveMax)]
+    #[case("2000.001", "1.000", InstrumentViolation::PricePrecision)]
+    #[case("1999.99", "0.005", InstrumentViolation::NotionalBelowMin)] // Just under minimum
+    #[case("2000.00", "0.004", InstrumentViolation::NotionalBelowMin)]
+    fn test_validate_order_violations(
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] expected: InstrumentViolation,
+    ) {
+        let result =
+            crypto_perpetual_ethusdt.validate_order(Price::from(price), Quantity::from(quantity));
+        assert_eq!(result, Err(expected));
+    }
+
+    #[rstest]
+    #[case("1.005", Ok(()))]
+    #[case("1.010", Ok(()))]
+    #[case("1.001", Err(InstrumentViolation::QuantityIncrement))]
+    fn test_validate_order_quantity_step_size(
+        mut crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] quantity: &str,
+        #[case] expected: Result<(), InstrumentViolation>,
+    ) {
+        crypto_perpetual_ethusdt.size_increment = Quantity::from("0.005");
+        let result = crypto_perpetual_ethusdt
+            .validate_order(Price::from("2000.00"), Quantity::from(quantity));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("50000.5", "1", Ok(()))]
+    #[case("50000.2", "1", Err(InstrumentViolation::PriceIncrement))]
+    #[case("50000.0", "1.5", Err(InstrumentViolation::QuantityPrecision))]
+    fn test_validate_order_precision_zero_inverse(
+        xbtusd_bitmex: CryptoPerpetual,
+        #[case] price: &str,
+        #[case] quantity: &str,
+        #[case] expected: Result<(), InstrumentViolation>,
+    ) {
+        let result = xbtusd_bitmex.validate_order(Price::from(price), Quantity::from(quantity));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(Some("100000 USDT"), Ok(()))]
+    #[case(Some("1000 USDT"), Err(InstrumentViolation::NotionalAboveMax))]
+    #[case(Some("100000 USD"), Err(InstrumentViolation::NotionalCurrencyMismatch))]
+    fn test_validate_order_max_notional(
+        mut crypto_perpetual_ethusdt: CryptoPerpetual,
+        #[case] max_notional: Option<&str>,
+        #[case] expected: Result<(), InstrumentViolation>,
+    ) {
+        crypto_perpetual_ethusdt.max_notional = max_notional.map(Money::from);
+        let result = crypto_perpetual_ethusdt
+            .validate_order(Price::from("2000.00"), Quantity::from("1.000"));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_is_quanto(
+        xbtusd_bitmex: CryptoPerpetual,
+        crypto_perpetual_ethusdt: CryptoPerpetual,
+        audusd_sim: CurrencyPair,
+    ) {
+        assert!(ethusd_quanto_bitmex().is_quanto());
+        assert!(!xbtusd_bitmex.is_quanto());
+        assert!(!crypto_perpetual_ethusdt.is_quanto());
+        assert!(!audusd_sim.is_quanto());
+        assert!(!futures_contract_es(None, None).is_quanto());
+    }
+
+    #[rstest]
+    fn test_notional_value_inverse_perpetual(xbtusd_bitmex: CryptoPerpetual) {
+        let notional = xbtusd_bitmex.calculate_notional_value(
+            Quantity::from(100_000),
+            Price::from("50000.0"),
+            None,
+        );
+        assert_eq!(notional, Money::from("2.00000000 BTC"));
+    }
+
+    #[rstest]
+    fn test_notional_value_inverse_perpetual_using_quote(xbtusd_bitmex: CryptoPerpetual) {
+        let notional = xbtusd_bitmex.calculate_notional_value(
+            Quantity::from(100_000),
+            Price::from("50000.0"),
+            Some(true),
+        );
+        assert_eq!(notional, Money::from("100000.00 USD"));
+    }
+
+    #[rstest]
+    fn test_notional_value_quanto_perpetual() {
+        let notional = ethusd_quanto_bitmex().calculate_notional_value(
+            Quantity::from(10),
+            Price::from("2000.00"),
+            None,
+        );
+        assert_eq!(notional, Money::from("0.02000000 BTC"));
+    }
+
+    #[rstest]
+    fn test_notional_value_linear_future_with_multiplier() {
+        let notional = futures_contract_es_multiplier_50().calculate_notional_value(
+            Quantity::from(2),
+            Price::from("4500.25"),
+            None,
+        );
+        assert_eq!(notional, Money::from("450025.00 USD"));
+    }
+
+    #[rstest]
+    fn test_notional_value_spot_pair(audusd_sim: CurrencyPair) {
+        let notional = audusd_sim.calculate_notional_value(
+            Quantity::from(100_000),
+            Price::from("0.80000"),
+            None,
+        );
+        assert_eq!(notional, Money::from("80000.00 USD"));
+    }
+
+    #[rstest]
+    fn test_calculate_base_quantity_spot_pair(currency_pair_btcusdt: CurrencyPair) {
+        let quantity = currency_pair_btcusdt
+            .calculate_base_quantity(Quantity::from("1000.0"), Price::from("50000.00"));
+        assert_eq!(quantity, Quantity::from("0.020000"));
+        assert_eq!(quantity.precision, currency_pair_btcusdt.size_precision);
+    }
+}
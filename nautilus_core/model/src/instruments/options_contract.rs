@@ -15,10 +15,12 @@
 
 use std::hash::{Hash, Hasher};
 
+use chrono::NaiveDate;
 use nautilus_core::{
     correctness::{
         check_equal_u8, check_positive_i64, check_valid_string, check_valid_string_optional, FAILED,
     },
+    datetime::NANOSECONDS_IN_DAY,
     nanos::UnixNanos,
 };
 use rust_decimal::Decimal;
@@ -189,6 +191,129 @@ impl OptionsContract {
         )
         .expect(FAILED)
     }
+
+    /// Returns the intrinsic value of one contract at the given `underlying` price, scaled by
+    /// the contract multiplier (zero when out of the money).
+    #[must_use]
+    pub fn intrinsic_value(&self, underlying: Price) -> Money {
+        let value = match self.option_kind {
+            OptionKind::Call => underlying.as_f64() - self.strike_price.as_f64(),
+            OptionKind::Put => self.strike_price.as_f64() - underlying.as_f64(),
+        };
+        Money::new(value.max(0.0) * self.multiplier.as_f64(), self.currency)
+    }
+
+    /// Returns whether the contract is in the money at the given `underlying` price.
+    #[must_use]
+    pub fn is_itm(&self, underlying: Price) -> bool {
+        match self.option_kind {
+            OptionKind::Call => underlying > self.strike_price,
+            OptionKind::Put => underlying < self.strike_price,
+        }
+    }
+
+    /// Returns whether the contract is at the money (strike equal to the `underlying` price).
+    #[must_use]
+    pub fn is_atm(&self, underlying: Price) -> bool {
+        underlying == self.strike_price
+    }
+
+    /// Returns whether the contract is out of the money at the given `underlying` price.
+    #[must_use]
+    pub fn is_otm(&self, underlying: Price) -> bool {
+        !self.is_itm(underlying) && !self.is_atm(underlying)
+    }
+
+    /// Returns whether the contract is active at `now_ns`, from activation (inclusive) until
+    /// expiration (exclusive).
+    #[must_use]
+    pub fn is_active(&self, now_ns: UnixNanos) -> bool {
+        self.activation_ns <= now_ns && now_ns < self.expiration_ns
+    }
+
+    /// Returns whether the contract has expired at `now_ns`.
+    #[must_use]
+    pub fn is_expired(&self, now_ns: UnixNanos) -> bool {
+        now_ns >= self.expiration_ns
+    }
+
+    /// Returns the fractional number of days from `now_ns` until expiration (zero once expired).
+    #[must_use]
+    pub fn days_to_expiry(&self, now_ns: UnixNanos) -> f64 {
+        if self.is_expired(now_ns) {
+            return 0.0;
+        }
+        (self.expiration_ns.as_u64() - now_ns.as_u64()) as f64 / NANOSECONDS_IN_DAY as f64
+    }
+}
+
+/// The components of an OCC (Options Clearing Corporation) option symbol.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OccSymbol {
+    /// The root symbol of the underlying.
+    pub underlying: Ustr,
+    /// UNIX timestamp (nanoseconds) for the expiration date at midnight UTC.
+    pub expiration_ns: UnixNanos,
+    /// The option kind.
+    pub option_kind: OptionKind,
+    /// The strike price (with 3 decimal places, as encoded in the symbol).
+    pub strike_price: Price,
+}
+
+/// Length of the OCC symbol suffix `YYMMDD` + `C`/`P` + 8 digit strike.
+const OCC_SUFFIX_LEN: usize = 15;
+
+/// Maximum length of the OCC root symbol.
+const OCC_ROOT_MAX_LEN: usize = 6;
+
+/// Parses an OCC option symbol such as `AAPL211217C00150000` or the space padded
+/// `SPY   240119P00470000` into its components.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the root symbol is empty or longer than six characters.
+/// - If the expiration date is not a valid `YYMMDD` date.
+/// - If the option kind is not `C` or `P`.
+/// - If the strike is not eight digits (thousandths of the strike price).
+pub fn parse_occ_symbol(symbol: &str) -> anyhow::Result<OccSymbol> {
+    if !symbol.is_ascii() || symbol.len() <= OCC_SUFFIX_LEN {
+        anyhow::bail!("Invalid OCC symbol, was {symbol:?}");
+    }
+    let (root, suffix) = symbol.split_at(symbol.len() - OCC_SUFFIX_LEN);
+
+    let root = root.trim_end();
+    if root.is_empty() || root.len() > OCC_ROOT_MAX_LEN || root.contains(char::is_whitespace) {
+        anyhow::bail!("Invalid OCC root symbol, was {root:?}");
+    }
+
+    let date = NaiveDate::parse_from_str(&suffix[..6], "%y%m%d")
+        .map_err(|e| anyhow::anyhow!("Invalid OCC expiration date {:?}: {e}", &suffix[..6]))?;
+    let expiration_ns = date
+        .and_hms_opt(0, 0, 0)
+        .and_then(|dt| dt.and_utc().timestamp_nanos_opt())
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .ok_or_else(|| anyhow::anyhow!("OCC expiration date out of range, was {date}"))?;
+
+    let option_kind = match &suffix[6..7] {
+        "C" => OptionKind::Call,
+        "P" => OptionKind::Put,
+        kind => anyhow::bail!("Invalid OCC option kind, must be 'C' or 'P', was {kind:?}"),
+    };
+
+    let strike = &suffix[7..];
+    if !strike.bytes().all(|b| b.is_ascii_digit()) {
+        anyhow::bail!("Invalid OCC strike, was {strike:?}");
+    }
+    let strike_thousandths: u64 = strike.parse()?;
+
+    Ok(OccSymbol {
+        underlying: Ustr::from(root),
+        expiration_ns: UnixNanos::from(expiration_ns),
+        option_kind,
+        strike_price: Price::new(strike_thousandths as f64 / 1000.0, 3),
+    })
 }
 
 impl PartialEq<Self> for OptionsContract {
@@ -333,11 +458,140 @@ impl Instrument for OptionsContract {
 mod tests {
     use rstest::rstest;
 
-    use crate::instruments::{options_contract::OptionsContract, stubs::*};
+    use super::*;
+    use crate::instruments::stubs::*;
 
     #[rstest]
     fn test_equality(options_contract_appl: OptionsContract) {
         let options_contract_appl2 = options_contract_appl;
         assert_eq!(options_contract_appl, options_contract_appl2);
     }
+
+    #[rstest]
+    #[case(OptionKind::Call, "160.00", "1100.00 USD", true, false)]
+    #[case(OptionKind::Call, "149.00", "0.00 USD", false, true)]
+    #[case(OptionKind::Call, "140.00", "0.00 USD", false, false)]
+    #[case(OptionKind::Put, "140.00", "900.00 USD", true, false)]
+    #[case(OptionKind::Put, "149.00", "0.00 USD", false, true)]
+    #[case(OptionKind::Put, "160.00", "0.00 USD", false, false)]
+    fn test_intrinsic_value_and_moneyness(
+        mut options_contract_appl: OptionsContract,
+        #[case] option_kind: OptionKind,
+        #[case] underlying: &str,
+        #[case] expected_value: &str,
+        #[case] is_itm: bool,
+        #[case] is_atm: bool,
+    ) {
+        options_contract_appl.option_kind = option_kind;
+        options_contract_appl.multiplier = Quantity::from(100);
+        let underlying = Price::from(underlying);
+
+        assert_eq!(
+            options_contract_appl.intrinsic_value(underlying),
+            Money::from(expected_value)
+        );
+        assert_eq!(options_contract_appl.is_itm(underlying), is_itm);
+        assert_eq!(options_contract_appl.is_atm(underlying), is_atm);
+        assert_eq!(options_contract_appl.is_otm(underlying), !is_itm && !is_atm);
+    }
+
+    #[rstest]
+    fn test_expiry_handling(options_contract_appl: OptionsContract) {
+        let activation_ns = options_contract_appl.activation_ns;
+        let expiration_ns = options_contract_appl.expiration_ns;
+        let before_activation = UnixNanos::from(activation_ns.as_u64() - 1);
+        let one_and_half_days_before_expiry =
+            UnixNanos::from(expiration_ns.as_u64() - NANOSECONDS_IN_DAY * 3 / 2);
+
+        assert!(!options_contract_appl.is_active(before_activation));
+        assert!(options_contract_appl.is_active(activation_ns));
+        assert!(!options_contract_appl.is_active(expiration_ns));
+        assert!(!options_contract_appl.is_expired(before_activation));
+        assert!(options_contract_appl.is_expired(expiration_ns));
+        assert_eq!(options_contract_appl.days_to_expiry(activation_ns), 91.0);
+        assert_eq!(
+            options_contract_appl.days_to_expiry(one_and_half_days_before_expiry),
+            1.5
+        );
+        assert_eq!(options_contract_appl.days_to_expiry(expiration_ns), 0.0);
+    }
+
+    #[rstest]
+    #[case(
+        "AAPL211217C00150000",
+        "AAPL",
+        "2021-12-17",
+        OptionKind::Call,
+        "150.000"
+    )]
+    #[case(
+        "SPY   240119P00470000",
+        "SPY",
+        "2024-01-19",
+        OptionKind::Put,
+        "470.000"
+    )]
+    #[case(
+        "SPXW240315C05100000",
+        "SPXW",
+        "2024-03-15",
+        OptionKind::Call,
+        "5100.000"
+    )]
+    #[case(
+        "TSLA240621P00182500",
+        "TSLA",
+        "2024-06-21",
+        OptionKind::Put,
+        "182.500"
+    )]
+    #[case(
+        "BRKB250117C00400000",
+        "BRKB",
+        "2025-01-17",
+        OptionKind::Call,
+        "400.000"
+    )]
+    fn test_parse_occ_symbol(
+        #[case] symbol: &str,
+        #[case] underlying: &str,
+        #[case] expiration: &str,
+        #[case] option_kind: OptionKind,
+        #[case] strike_price: &str,
+    ) {
+        let occ = parse_occ_symbol(symbol).unwrap();
+        let expiration = NaiveDate::parse_from_str(expiration, "%Y-%m-%d")
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap() as u64;
+
+        assert_eq!(occ.underlying, underlying);
+        assert_eq!(occ.expiration_ns, UnixNanos::from(expiration));
+        assert_eq!(occ.option_kind, option_kind);
+        assert_eq!(occ.strike_price, Price::from(strike_price));
+    }
+
+    #[rstest]
+    fn test_parse_occ_symbol_matches_contract(options_contract_appl: OptionsContract) {
+        let occ = parse_occ_symbol(options_contract_appl.raw_symbol.as_str()).unwrap();
+        assert_eq!(occ.underlying, options_contract_appl.underlying);
+        assert_eq!(occ.expiration_ns, options_contract_appl.expiration_ns);
+        assert_eq!(occ.option_kind, options_contract_appl.option_kind);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("211217C00150000")]
+    #[case("TOOLONG211217C00150000")]
+    #[case("AA PL211217C00150000")]
+    #[case("AAPL211317C00150000")]
+    #[case("AAPL211217X00150000")]
+    #[case("AAPL211217C0015000A")]
+    #[case("AAPL211217C+0150000")]
+    fn test_parse_occ_symbol_invalid(#[case] symbol: &str) {
+        assert!(parse_occ_symbol(symbol).is_err());
+    }
 }
@@ -29,6 +29,7 @@
 //! - `stubs`: Enables type stubs for use in testing scenarios.
 
 pub mod accounts;
+pub mod commission;
 pub mod currencies;
 pub mod data;
 pub mod enums;
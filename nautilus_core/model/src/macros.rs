@@ -15,6 +15,21 @@
 
 //! Model specific macros.
 
+/// Implements `as_str` for an enum deriving `IntoStaticStr`, returning the same name as
+/// `to_string` without allocating.
+#[macro_export]
+macro_rules! enum_as_str {
+    ($type:ty) => {
+        impl $type {
+            /// Returns the name of the variant as a static string, without allocating.
+            #[must_use]
+            pub fn as_str(self) -> &'static str {
+                self.into()
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! enum_strum_serde {
     ($type:ty) => {
@@ -28,12 +43,19 @@ macro_rules! enum_strum_serde {
         }
 
         impl<'de> Deserialize<'de> for $type {
+            /// Deserializes the variant from its name, with an error naming the enum for an
+            /// unknown name (e.g. from a file written by a newer version).
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
                 let s = String::deserialize(deserializer)?;
-                <$type>::from_str(&s).map_err(serde::de::Error::custom)
+                <$type>::from_str(&s).map_err(|_| {
+                    serde::de::Error::custom(format!(
+                        "Unknown `{}` value, was {s:?}",
+                        stringify!($type)
+                    ))
+                })
             }
         }
     };
@@ -17,22 +17,28 @@
 
 use std::collections::BTreeMap;
 
+use nautilus_core::correctness::{check_positive_i64, FAILED};
+
 use super::{book::OrderBook, ladder::BookPrice, level::Level};
 use crate::{
-    enums::{BookType, OrderSide},
+    enums::{BookType, OrderSide, OrderSideSpecified},
     orderbook::error::BookIntegrityError,
     types::{price::Price, quantity::Quantity},
 };
 
 /// Calculates the estimated fill quantity for a specified price from a set of
 /// order book levels and order side.
+///
+/// The returned quantity takes the precision of the orders in the book, or zero
+/// precision if there are no levels at or better than the given `price`.
 #[must_use]
 pub fn get_quantity_for_price(
     price: Price,
     order_side: OrderSide,
     levels: &BTreeMap<BookPrice, Level>,
-) -> f64 {
-    let mut matched_size: f64 = 0.0;
+) -> Quantity {
+    let mut matched_size_raw = 0u64;
+    let mut precision = 0u8;
 
     for (book_price, level) in levels {
         match order_side {
@@ -48,16 +54,22 @@ pub fn get_quantity_for_price(
             }
             _ => panic!("Invalid `OrderSide` {order_side}"),
         }
-        matched_size += level.size();
+        if let Some(order) = level.first() {
+            precision = order.size.precision;
+        }
+        matched_size_raw += level.size_raw();
     }
 
-    matched_size
+    Quantity::from_raw(matched_size_raw, precision)
 }
 
 /// Calculates the estimated average price for a specified quantity from a set of
 /// order book levels.
+///
+/// Returns `None` if there is no liquidity in the given `levels`. When the levels
+/// are thinner than `qty`, the average price of the available quantity is returned.
 #[must_use]
-pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> f64 {
+pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level>) -> Option<f64> {
     let mut cumulative_size_raw = 0u64;
     let mut cumulative_value = 0.0;
 
@@ -72,12 +84,32 @@ pub fn get_avg_px_for_quantity(qty: Quantity, levels: &BTreeMap<BookPrice, Level
     }
 
     if cumulative_size_raw == 0 {
-        0.0
+        None
     } else {
-        cumulative_value / cumulative_size_raw as f64
+        Some(cumulative_value / cumulative_size_raw as f64)
     }
 }
 
+/// Calculates the total size of the given `levels` for `side` at or better than `price`.
+///
+/// The returned quantity takes the precision of the orders in the book, or zero
+/// precision if there are no levels at or better than the given `price`.
+#[must_use]
+pub fn get_cumulative_depth(
+    price: Price,
+    side: OrderSide,
+    levels: &BTreeMap<BookPrice, Level>,
+) -> Quantity {
+    let opposite_side = match side.as_specified() {
+        OrderSideSpecified::Buy => OrderSide::Sell,
+        OrderSideSpecified::Sell => OrderSide::Buy,
+    };
+
+    // A resting level is at or better than `price` exactly when an opposite side
+    // order at `price` would be able to fill against it.
+    get_quantity_for_price(price, opposite_side, levels)
+}
+
 /// Calculates the estimated average price for a specified exposure from a set of
 /// order book levels.
 #[must_use]
@@ -117,6 +149,147 @@ pub fn get_avg_px_qty_for_exposure(
     }
 }
 
+/// Groups the given `levels` for `side` into buckets of `tick_raw` width, returning at most
+/// `depth` aggregated levels (in the same order as `levels`, so best first).
+///
+/// Each level is placed in the bucket at its most aggressive boundary (a multiple of `tick_raw`),
+/// with bids rounded down and asks rounded up as exchange UIs group, so a grouped book is never
+/// shown tighter than the original.
+/// Sizes and order counts are summed within each bucket.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `tick_raw` is not positive (> 0).
+#[must_use]
+pub fn group_levels<'a>(
+    levels: impl Iterator<Item = &'a Level>,
+    side: OrderSide,
+    tick_raw: i64,
+    depth: usize,
+) -> Vec<BookLevel> {
+    check_positive_i64(tick_raw, stringify!(tick_raw)).expect(FAILED);
+
+    let mut grouped: Vec<BookLevel> = Vec::with_capacity(depth);
+    for level in levels {
+        let raw = level.price.value.raw;
+        let price_raw = match side.as_specified() {
+            OrderSideSpecified::Buy => raw.div_euclid(tick_raw) * tick_raw,
+            OrderSideSpecified::Sell => -(-raw).div_euclid(tick_raw) * tick_raw,
+        };
+
+        match grouped.last_mut() {
+            Some(last) if last.price_raw == price_raw => {
+                last.size_raw += level.size_raw();
+                last.count += u64::from(level.count());
+            }
+            _ => {
+                if grouped.len() == depth {
+                    break;
+                }
+                grouped.push(BookLevel {
+                    price_raw,
+                    size_raw: level.size_raw(),
+                    count: u64::from(level.count()),
+                });
+            }
+        }
+    }
+
+    grouped
+}
+
+/// Represents a simulated fill against a single order book price level.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BookFill {
+    /// The price of the level filled against.
+    pub price: Price,
+    /// The quantity filled at the level.
+    pub size: Quantity,
+}
+
+/// Represents the result of simulating fills for a quantity against the order book.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedFills {
+    /// The fills per level, ordered from the top of book.
+    pub fills: Vec<BookFill>,
+    /// The total quantity filled across all levels.
+    pub filled_qty: Quantity,
+    /// If the book was too thin (or the price limit too tight) to fill the requested quantity.
+    pub is_partial: bool,
+}
+
+impl SimulatedFills {
+    /// Creates a new [`SimulatedFills`] instance from the raw `fills` (potentially more than one
+    /// per price level) and the requested `qty`.
+    #[must_use]
+    pub fn new(fills: Vec<(Price, Quantity)>, qty: Quantity) -> Self {
+        let mut level_fills: Vec<BookFill> = Vec::with_capacity(fills.len());
+        let mut filled_raw = 0u64;
+
+        for (price, size) in fills {
+            filled_raw += size.raw;
+            match level_fills.last_mut() {
+                Some(last) if last.price == price => last.size += size,
+                _ => level_fills.push(BookFill { price, size }),
+            }
+        }
+
+        Self {
+            fills: level_fills,
+            filled_qty: Quantity::from_raw(filled_raw, qty.precision),
+            is_partial: filled_raw < qty.raw,
+        }
+    }
+
+    /// Returns the volume weighted average price of the fills (if any quantity was filled).
+    #[must_use]
+    pub fn avg_px(&self) -> Option<f64> {
+        if self.filled_qty.raw == 0 {
+            return None;
+        }
+
+        let value: f64 = self
+            .fills
+            .iter()
+            .map(|fill| fill.price.as_f64() * fill.size.raw as f64)
+            .sum();
+        Some(value / self.filled_qty.raw as f64)
+    }
+}
+
+/// Represents an aggregated order book price level in a flat C compatible layout.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BookLevel {
+    /// The raw fixed-point price of the level.
+    pub price_raw: i64,
+    /// The raw fixed-point total size of the level.
+    pub size_raw: u64,
+    /// The number of orders at the level.
+    pub count: u64,
+}
+
+impl From<&Level> for BookLevel {
+    fn from(level: &Level) -> Self {
+        Self {
+            price_raw: level.price.value.raw,
+            size_raw: level.size_raw(),
+            count: u64::from(level.count()),
+        }
+    }
+}
+
+/// Represents a snapshot of the top levels of an order book, ordered best first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookSnapshot {
+    /// The bid levels, from the best (highest) price.
+    pub bids: Vec<BookLevel>,
+    /// The ask levels, from the best (lowest) price.
+    pub asks: Vec<BookLevel>,
+}
+
 pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError> {
     match book.book_type {
         BookType::L1_MBP => {
@@ -157,13 +330,62 @@ pub fn book_check_integrity(book: &OrderBook) -> Result<(), BookIntegrityError>
         BookType::L3_MBO => {}
     };
 
+    check_ladder_monotonic(OrderSide::Buy, book.bids.levels.keys())?;
+    check_ladder_monotonic(OrderSide::Sell, book.asks.levels.keys())?;
+
     if let (Some(top_bid_level), Some(top_ask_level)) = (book.bids.top(), book.asks.top()) {
-        let best_bid = top_bid_level.price;
-        let best_ask = top_ask_level.price;
+        check_not_crossed(top_bid_level.price, top_ask_level.price)?;
+    }
 
-        if best_bid.value >= best_ask.value {
-            return Err(BookIntegrityError::OrdersCrossed(best_bid, best_ask));
+    Ok(())
+}
+
+/// Checks that both sides of the `book` have at least one level.
+///
+/// This is not part of [`book_check_integrity`] as one-sided books are valid for some
+/// venues and instruments (e.g. illiquid markets and betting exchanges).
+pub fn book_check_two_sided(book: &OrderBook) -> Result<(), BookIntegrityError> {
+    if book.bids.is_empty() {
+        return Err(BookIntegrityError::EmptySide(OrderSide::Buy));
+    }
+    if book.asks.is_empty() {
+        return Err(BookIntegrityError::EmptySide(OrderSide::Sell));
+    }
+
+    Ok(())
+}
+
+/// Checks the given best bid and ask prices are neither crossed nor locked.
+pub fn check_not_crossed(
+    best_bid: BookPrice,
+    best_ask: BookPrice,
+) -> Result<(), BookIntegrityError> {
+    if best_bid.value > best_ask.value {
+        return Err(BookIntegrityError::OrdersCrossed(best_bid, best_ask));
+    }
+    if best_bid.value == best_ask.value {
+        return Err(BookIntegrityError::OrdersLocked(best_bid, best_ask));
+    }
+
+    Ok(())
+}
+
+fn check_ladder_monotonic<'a>(
+    side: OrderSide,
+    prices: impl Iterator<Item = &'a BookPrice>,
+) -> Result<(), BookIntegrityError> {
+    let mut prev: Option<&BookPrice> = None;
+    for price in prices {
+        if let Some(prev) = prev {
+            let is_monotonic = match side {
+                OrderSide::Buy => price.value < prev.value,
+                _ => price.value > prev.value,
+            };
+            if !is_monotonic {
+                return Err(BookIntegrityError::NonMonotonicLevels(side, *prev, *price));
+            }
         }
+        prev = Some(price);
     }
 
     Ok(())
@@ -19,18 +19,47 @@ use std::fmt::Display;
 
 use nautilus_core::nanos::UnixNanos;
 
-use super::{aggregation::pre_process_order, analysis, display::pprint_book, level::Level};
+use super::{
+    aggregation::pre_process_order,
+    analysis::{self, BookLevel, BookSnapshot, SimulatedFills},
+    checksum::{self, BookChecksumType},
+    display::pprint_book,
+    level::Level,
+    recorder::{self, BookEvent, BookRecorder},
+    top::{BookTop, BookTopChange},
+};
 use crate::{
     data::{
-        delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10, order::BookOrder,
-        quote::QuoteTick, trade::TradeTick,
+        delta::OrderBookDelta,
+        deltas::OrderBookDeltas,
+        depth::OrderBookDepth10,
+        order::{BookOrder, NULL_ORDER},
+        quote::QuoteTick,
+        trade::TradeTick,
+        Data,
     },
     enums::{BookAction, BookType, OrderSide, OrderSideSpecified},
     identifiers::InstrumentId,
-    orderbook::{error::InvalidBookOperation, ladder::Ladder},
+    orderbook::{
+        error::{BookIntegrityError, InvalidBookOperation},
+        ladder::Ladder,
+    },
     types::{price::Price, quantity::Quantity},
 };
 
+/// The policy applied when an order book delta leaves the book crossed or locked.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+pub enum BookIntegrityPolicy {
+    /// Apply the delta and leave the book as is.
+    #[default]
+    Ignore = 1,
+    /// Apply the delta and return a [`BookIntegrityError`].
+    RaiseError = 2,
+    /// Apply the delta then remove the opposite side levels crossed (or locked) by it.
+    DropCrossedLevels = 3,
+}
+
 /// Provides a high-performance, versatile order book.
 ///
 /// Capable of handling various levels of data granularity:
@@ -53,8 +82,11 @@ pub struct OrderBook {
     pub ts_last: UnixNanos,
     /// The current count of events applied to the order book.
     pub count: u64,
+    /// The policy applied when a delta leaves the order book crossed or locked.
+    pub integrity_policy: BookIntegrityPolicy,
     pub(crate) bids: Ladder,
     pub(crate) asks: Ladder,
+    recorder: Option<Box<BookRecorder>>,
 }
 
 impl PartialEq for OrderBook {
@@ -87,8 +119,10 @@ impl OrderBook {
             sequence: 0,
             ts_last: UnixNanos::default(),
             count: 0,
+            integrity_policy: BookIntegrityPolicy::default(),
             bids: Ladder::new(OrderSide::Buy),
             asks: Ladder::new(OrderSide::Sell),
+            recorder: None,
         }
     }
 
@@ -98,6 +132,7 @@ impl OrderBook {
         self.sequence = 0;
         self.ts_last = UnixNanos::default();
         self.count = 0;
+        self.reset_recording_base();
     }
 
     pub fn add(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
@@ -108,6 +143,7 @@ impl OrderBook {
         }
 
         self.increment(sequence, ts_event);
+        self.record_delta(BookAction::Add, order, flags, sequence, ts_event);
     }
 
     pub fn update(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
@@ -118,6 +154,7 @@ impl OrderBook {
         }
 
         self.increment(sequence, ts_event);
+        self.record_delta(BookAction::Update, order, flags, sequence, ts_event);
     }
 
     pub fn delete(&mut self, order: BookOrder, flags: u8, sequence: u64, ts_event: UnixNanos) {
@@ -128,54 +165,209 @@ impl OrderBook {
         }
 
         self.increment(sequence, ts_event);
+        self.record_delta(BookAction::Delete, order, flags, sequence, ts_event);
     }
 
     pub fn clear(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
         self.asks.clear();
         self.increment(sequence, ts_event);
+        self.record_delta(BookAction::Clear, NULL_ORDER, 0, sequence, ts_event);
     }
 
     pub fn clear_bids(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.bids.clear();
         self.increment(sequence, ts_event);
+        self.record(|| BookEvent::ClearSide {
+            side: OrderSide::Buy,
+            sequence,
+            ts_event,
+        });
     }
 
     pub fn clear_asks(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.asks.clear();
         self.increment(sequence, ts_event);
+        self.record(|| BookEvent::ClearSide {
+            side: OrderSide::Sell,
+            sequence,
+            ts_event,
+        });
     }
 
-    pub fn apply_delta(&mut self, delta: &OrderBookDelta) {
-        let order = delta.order;
-        let flags = delta.flags;
-        let sequence = delta.sequence;
-        let ts_event = delta.ts_event;
-        match delta.action {
-            BookAction::Add => self.add(order, flags, sequence, ts_event),
-            BookAction::Update => self.update(order, flags, sequence, ts_event),
-            BookAction::Delete => self.delete(order, flags, sequence, ts_event),
-            BookAction::Clear => self.clear(sequence, ts_event),
-        }
+    /// Applies the given `delta` to the order book, then applies the book's
+    /// [`BookIntegrityPolicy`] if the book is left crossed or locked.
+    ///
+    /// Returns the resulting change to the best bid and ask.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the delta's action is not allowed for the book type (the book is left unchanged).
+    /// - If the policy is `RaiseError` and the book is crossed or locked after applying the delta.
+    pub fn apply_delta(
+        &mut self,
+        delta: &OrderBookDelta,
+    ) -> Result<BookTopChange, BookIntegrityError> {
+        let old = self.top();
+        self.apply_delta_inner(delta)?;
+        Ok(BookTopChange::new(old, self.top()))
     }
 
-    pub fn apply_deltas(&mut self, deltas: &OrderBookDeltas) {
+    /// Applies the given `deltas` to the order book, stopping at the first integrity error.
+    ///
+    /// Returns the net change to the best bid and ask across all deltas.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any delta's action is not allowed for the book type (no deltas are applied).
+    /// - If the policy is `RaiseError` and a delta leaves the book crossed or locked.
+    pub fn apply_deltas(
+        &mut self,
+        deltas: &OrderBookDeltas,
+    ) -> Result<BookTopChange, BookIntegrityError> {
+        for delta in &deltas.deltas {
+            self.check_action(delta.action)?;
+        }
+
+        let old = self.top();
         for delta in &deltas.deltas {
-            self.apply_delta(delta);
+            self.apply_delta_inner(delta)?;
         }
+        Ok(BookTopChange::new(old, self.top()))
     }
 
-    pub fn apply_depth(&mut self, depth: &OrderBookDepth10) {
+    /// Applies the given `depth` snapshot to the order book, replacing all levels.
+    ///
+    /// For MBP books the level order counts are set from the non-zero depth counts.
+    ///
+    /// Returns the resulting change to the best bid and ask.
+    pub fn apply_depth(&mut self, depth: &OrderBookDepth10) -> BookTopChange {
+        let old = self.top();
+        // Recorded as a single depth event rather than its individual adds
+        let recorder = self.recorder.take();
         self.bids.clear();
         self.asks.clear();
 
-        for order in depth.bids {
+        for (order, count) in depth.bids.into_iter().zip(depth.bid_counts) {
             self.add(order, depth.flags, depth.sequence, depth.ts_event);
+            self.set_level_count(order, count);
         }
 
-        for order in depth.asks {
+        for (order, count) in depth.asks.into_iter().zip(depth.ask_counts) {
             self.add(order, depth.flags, depth.sequence, depth.ts_event);
+            self.set_level_count(order, count);
         }
+
+        self.recorder = recorder;
+        self.record(|| BookEvent::Depth(Box::new(*depth)));
+        BookTopChange::new(old, self.top())
+    }
+
+    /// Applies the given `data` to the order book, dispatching on the data type.
+    ///
+    /// Quotes and trades can only be applied to `L1_MBP` books, which are maintained
+    /// as a top-of-book representation. Returns the resulting change to the best bid and ask.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the data is a quote or trade and the book type is not `L1_MBP`.
+    /// - If the data is a bar (which cannot update a book).
+    /// - If applying a delta fails the book's integrity policy.
+    pub fn apply(&mut self, data: &Data) -> anyhow::Result<BookTopChange> {
+        let change = match data {
+            Data::Delta(delta) => self.apply_delta(delta)?,
+            Data::Deltas(deltas) => self.apply_deltas(deltas)?,
+            Data::Depth10(depth) => self.apply_depth(depth),
+            Data::Quote(quote) => {
+                let old = self.top();
+                self.update_quote_tick(quote)?;
+                BookTopChange::new(old, self.top())
+            }
+            Data::Trade(trade) => {
+                let old = self.top();
+                self.update_trade_tick(trade)?;
+                BookTopChange::new(old, self.top())
+            }
+            Data::Bar(bar) => anyhow::bail!("Invalid data type for book update, was {bar}"),
+        };
+        Ok(change)
+    }
+
+    /// Starts recording mutations, retaining up to `capacity` events in a ring buffer.
+    ///
+    /// The current state of the book is taken as the base snapshot for replay. All mutations
+    /// are recorded (deltas, adds, updates, deletes, clears, depth snapshots, quotes and trades,
+    /// and any levels dropped by the integrity policy). Resetting the book discards the recorded
+    /// events, with the empty book as the new base snapshot. Recording is off by default.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` is not positive (> 0).
+    pub fn start_recording(&mut self, capacity: usize) {
+        self.recorder = None;
+        self.recorder = Some(Box::new(BookRecorder::new(self.clone(), capacity)));
+    }
+
+    /// Stops recording mutations, discarding any recorded events.
+    pub fn stop_recording(&mut self) {
+        self.recorder = None;
+    }
+
+    /// Returns whether mutations are being recorded.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Returns the event recorder for the order book (if recording).
+    #[must_use]
+    pub fn recorder(&self) -> Option<&BookRecorder> {
+        self.recorder.as_deref()
+    }
+
+    /// Reconstructs the order book as at the given `sequence` from the recorded events.
+    ///
+    /// Returns `None` if not recording, or if `sequence` is before the earliest
+    /// recoverable state (the events have been evicted from the ring buffer).
+    #[must_use]
+    pub fn snapshot_at(&self, sequence: u64) -> Option<Self> {
+        self.recorder.as_ref()?.replay_to(sequence)
+    }
+
+    /// Returns the minimal set of deltas which transforms this order book into `other`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `other` has a different instrument ID or book type.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Vec<OrderBookDelta> {
+        recorder::book_diff(self, other)
+    }
+
+    /// Checks the integrity of the order book, including whether it is crossed or locked
+    /// and whether each ladder is strictly ordered by price.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If any integrity check fails, including the offending prices.
+    pub fn check_integrity(&self) -> Result<(), BookIntegrityError> {
+        analysis::book_check_integrity(self)
+    }
+
+    /// Checks that both sides of the order book have at least one level.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If either side of the book is empty.
+    pub fn check_two_sided(&self) -> Result<(), BookIntegrityError> {
+        analysis::book_check_two_sided(self)
     }
 
     pub fn bids(&self) -> impl Iterator<Item = &Level> {
@@ -186,6 +378,12 @@ impl OrderBook {
         self.asks.levels.values()
     }
 
+    /// Returns the current best bid and ask prices and sizes.
+    #[must_use]
+    pub fn top(&self) -> BookTop {
+        BookTop::from_ladders(&self.bids, &self.asks)
+    }
+
     #[must_use]
     pub fn has_bid(&self) -> bool {
         self.bids.top().map_or(false, |top| !top.orders.is_empty())
@@ -237,7 +435,7 @@ impl OrderBook {
     }
 
     #[must_use]
-    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
+    pub fn get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> Option<f64> {
         let levels = match order_side.as_specified() {
             OrderSideSpecified::Buy => &self.asks.levels,
             OrderSideSpecified::Sell => &self.bids.levels,
@@ -261,7 +459,7 @@ impl OrderBook {
     }
 
     #[must_use]
-    pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
+    pub fn get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> Quantity {
         let levels = match order_side.as_specified() {
             OrderSideSpecified::Buy => &self.asks.levels,
             OrderSideSpecified::Sell => &self.bids.levels,
@@ -278,12 +476,254 @@ impl OrderBook {
         }
     }
 
+    /// Returns the total size resting on the given `side` at or better than `price`.
+    #[must_use]
+    pub fn cumulative_depth(&self, side: OrderSide, price: Price) -> Quantity {
+        let levels = match side.as_specified() {
+            OrderSideSpecified::Buy => &self.bids.levels,
+            OrderSideSpecified::Sell => &self.asks.levels,
+        };
+
+        analysis::get_cumulative_depth(price, side, levels)
+    }
+
+    /// Returns the size imbalance over the top `depth` levels per side, in the range [-1, 1].
+    ///
+    /// Calculated as `(bid_size - ask_size) / (bid_size + ask_size)`, so a `depth` of 1 gives
+    /// the top of book imbalance. An empty side counts as zero size, and `NaN` is returned
+    /// if both sides are empty (or `depth` is zero).
+    #[must_use]
+    pub fn imbalance(&self, depth: usize) -> f64 {
+        let bid_size: u64 = self.bids().take(depth).map(Level::size_raw).sum();
+        let ask_size: u64 = self.asks().take(depth).map(Level::size_raw).sum();
+        let total = bid_size + ask_size;
+        if total == 0 {
+            return f64::NAN;
+        }
+
+        (bid_size as f64 - ask_size as f64) / total as f64
+    }
+
+    /// Returns the microprice, being the top of book prices weighted by the opposite side size.
+    ///
+    /// Calculated as `(bid_price * ask_size + ask_price * bid_size) / (bid_size + ask_size)`.
+    /// Returns `None` if either side of the book is empty.
+    #[must_use]
+    pub fn microprice(&self) -> Option<f64> {
+        let bid = self.bids.top()?;
+        let ask = self.asks.top()?;
+        let bid_size = bid.size_raw() as f64;
+        let ask_size = ask.size_raw() as f64;
+        if bid_size + ask_size == 0.0 {
+            return None;
+        }
+
+        Some(
+            (bid.price.value.as_f64() * ask_size + ask.price.value.as_f64() * bid_size)
+                / (bid_size + ask_size),
+        )
+    }
+
+    /// Returns a snapshot of the top `depth` levels per side, ordered best first.
+    ///
+    /// If a side has fewer than `depth` levels then all of its levels are returned.
+    #[must_use]
+    pub fn snapshot(&self, depth: usize) -> BookSnapshot {
+        BookSnapshot {
+            bids: self.bids().take(depth).map(BookLevel::from).collect(),
+            asks: self.asks().take(depth).map(BookLevel::from).collect(),
+        }
+    }
+
+    /// Returns the top `depth` levels per side re-binned into price buckets of `tick_size`.
+    ///
+    /// Each level is placed in the bucket at its most aggressive boundary for display, so bid
+    /// prices are rounded down and ask prices rounded up to a multiple of `tick_size`, with
+    /// sizes and order counts summed within each bucket.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `tick_size` is not positive (> 0).
+    #[must_use]
+    pub fn group_levels(&self, tick_size: Price, depth: usize) -> BookSnapshot {
+        BookSnapshot {
+            bids: analysis::group_levels(self.bids(), OrderSide::Buy, tick_size.raw, depth),
+            asks: analysis::group_levels(self.asks(), OrderSide::Sell, tick_size.raw, depth),
+        }
+    }
+
+    /// Simulates filling `qty` against the opposite side of the book without mutating it.
+    ///
+    /// Levels are walked from the top of book until `qty` is filled or the optional
+    /// `price_limit` is reached. If there is insufficient liquidity the available fills
+    /// are returned, with [`SimulatedFills::is_partial`] set.
+    #[must_use]
+    pub fn simulate_fills_for_quantity(
+        &self,
+        order_side: OrderSide,
+        price_limit: Option<Price>,
+        qty: Quantity,
+    ) -> SimulatedFills {
+        let (ladder, no_limit) = match order_side.as_specified() {
+            OrderSideSpecified::Buy => (&self.asks, Price::max(0)),
+            OrderSideSpecified::Sell => (&self.bids, Price::min(0)),
+        };
+        let order = BookOrder::new(order_side, price_limit.unwrap_or(no_limit), qty, 0);
+
+        SimulatedFills::new(ladder.simulate_fills(&order), qty)
+    }
+
+    /// Returns a Kraken style CRC32 checksum of the top `depth` levels per side.
+    #[must_use]
+    pub fn checksum_crc32_kraken(&self, depth: usize) -> u32 {
+        checksum::checksum_kraken(self, depth)
+    }
+
+    /// Returns an OKX style CRC32 checksum of the top `depth` levels per side.
+    #[must_use]
+    pub fn checksum_crc32_okx(&self, depth: usize) -> u32 {
+        checksum::checksum_okx(self, depth)
+    }
+
+    /// Returns whether the checksum of the top `depth` levels per side matches `expected`.
+    #[must_use]
+    pub fn verify_checksum(
+        &self,
+        checksum_type: BookChecksumType,
+        depth: usize,
+        expected: u32,
+    ) -> bool {
+        checksum::book_checksum(self, checksum_type, depth) == expected
+    }
+
+    /// Checks the checksum of the top `depth` levels per side against `expected`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the computed checksum does not match `expected`.
+    pub fn check_checksum(
+        &self,
+        checksum_type: BookChecksumType,
+        depth: usize,
+        expected: u32,
+    ) -> Result<(), BookIntegrityError> {
+        let computed = checksum::book_checksum(self, checksum_type, depth);
+        if computed == expected {
+            Ok(())
+        } else {
+            Err(BookIntegrityError::ChecksumMismatch(expected, computed))
+        }
+    }
+
     /// Return a [`String`] representation of the order book in a human-readable table format.
     #[must_use]
     pub fn pprint(&self, num_levels: usize) -> String {
         pprint_book(&self.bids, &self.asks, num_levels)
     }
 
+    fn apply_integrity_policy(&mut self, side: OrderSide) -> Result<(), BookIntegrityError> {
+        if self.integrity_policy == BookIntegrityPolicy::Ignore {
+            return Ok(());
+        }
+
+        let (Some(best_bid), Some(best_ask)) = (self.bids.top(), self.asks.top()) else {
+            return Ok(());
+        };
+        let (best_bid, best_ask) = (best_bid.price, best_ask.price);
+
+        match self.integrity_policy {
+            BookIntegrityPolicy::Ignore => unreachable!(),
+            BookIntegrityPolicy::RaiseError => analysis::check_not_crossed(best_bid, best_ask),
+            BookIntegrityPolicy::DropCrossedLevels => {
+                let (ladder, price) = match side {
+                    OrderSide::Buy => (&mut self.asks, best_bid.value),
+                    OrderSide::Sell => (&mut self.bids, best_ask.value),
+                    OrderSide::NoOrderSide => return Ok(()),
+                };
+                if ladder.remove_crossed_levels(price) > 0 {
+                    self.record(|| BookEvent::DropCrossedLevels { side, price });
+                }
+                Ok(())
+            }
+        }
+    }
+
+    fn reset_recording_base(&mut self) {
+        if let Some(mut recorder) = self.recorder.take() {
+            recorder.reset(self.clone());
+            self.recorder = Some(recorder);
+        }
+    }
+
+    fn record(&mut self, event: impl FnOnce() -> BookEvent) {
+        if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(self.sequence, event());
+        }
+    }
+
+    fn record_delta(
+        &mut self,
+        action: BookAction,
+        order: BookOrder,
+        flags: u8,
+        sequence: u64,
+        ts_event: UnixNanos,
+    ) {
+        let instrument_id = self.instrument_id;
+        self.record(|| {
+            BookEvent::Delta(OrderBookDelta::new(
+                instrument_id,
+                action,
+                order,
+                flags,
+                sequence,
+                ts_event,
+                ts_event,
+            ))
+        });
+    }
+
+    fn set_level_count(&mut self, order: BookOrder, count: u32) {
+        if self.book_type == BookType::L3_MBO || count == 0 {
+            return;
+        }
+
+        let ladder = match order.side.as_specified() {
+            OrderSideSpecified::Buy => &mut self.bids,
+            OrderSideSpecified::Sell => &mut self.asks,
+        };
+        if let Some(level) = ladder.levels.get_mut(&order.to_book_price()) {
+            level.set_count(count);
+        }
+    }
+
+    fn check_action(&self, action: BookAction) -> Result<(), BookIntegrityError> {
+        if self.book_type.allows_action(action) {
+            Ok(())
+        } else {
+            Err(BookIntegrityError::InvalidAction(action, self.book_type))
+        }
+    }
+
+    fn apply_delta_inner(&mut self, delta: &OrderBookDelta) -> Result<(), BookIntegrityError> {
+        self.check_action(delta.action)?;
+
+        let order = delta.order;
+        let flags = delta.flags;
+        let sequence = delta.sequence;
+        let ts_event = delta.ts_event;
+        match delta.action {
+            BookAction::Add => self.add(order, flags, sequence, ts_event),
+            BookAction::Update => self.update(order, flags, sequence, ts_event),
+            BookAction::Delete => self.delete(order, flags, sequence, ts_event),
+            BookAction::Clear => self.clear(sequence, ts_event),
+        }
+
+        self.apply_integrity_policy(order.side)
+    }
+
     fn increment(&mut self, sequence: u64, ts_event: UnixNanos) {
         self.sequence = sequence;
         self.ts_last = ts_event;
@@ -311,6 +751,8 @@ impl OrderBook {
 
         self.update_book_bid(bid, quote.ts_event);
         self.update_book_ask(ask, quote.ts_event);
+        self.increment(self.sequence, quote.ts_event);
+        self.record(|| BookEvent::Quote(*quote));
 
         Ok(())
     }
@@ -336,6 +778,8 @@ impl OrderBook {
 
         self.update_book_bid(bid, trade.ts_event);
         self.update_book_ask(ask, trade.ts_event);
+        self.increment(self.sequence, trade.ts_event);
+        self.record(|| BookEvent::Trade(*trade));
 
         Ok(())
     }
@@ -368,11 +812,17 @@ mod tests {
 
     use crate::{
         data::{
-            depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick,
+            delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+            order::BookOrder, quote::QuoteTick, stubs::*, trade::TradeTick, Data,
         },
-        enums::{AggressorSide, BookType, OrderSide},
+        enums::{AggressorSide, BookAction, BookType, OrderSide},
         identifiers::{InstrumentId, TradeId},
-        orderbook::{analysis::book_check_integrity, book::OrderBook},
+        orderbook::{
+            analysis::{book_check_integrity, BookFill, BookLevel, BookSnapshot},
+            book::{BookIntegrityPolicy, OrderBook},
+            error::BookIntegrityError,
+            level::Level,
+        },
         types::{price::Price, quantity::Quantity},
     };
 
@@ -499,8 +949,8 @@ mod tests {
 
         let qty = Quantity::from(1);
 
-        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Buy), 0.0);
-        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Sell), 0.0);
+        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Buy), None);
+        assert_eq!(book.get_avg_px_for_quantity(qty, OrderSide::Sell), None);
     }
 
     #[rstest]
@@ -510,8 +960,14 @@ mod tests {
 
         let price = Price::from("1.0");
 
-        assert_eq!(book.get_quantity_for_price(price, OrderSide::Buy), 0.0);
-        assert_eq!(book.get_quantity_for_price(price, OrderSide::Sell), 0.0);
+        assert_eq!(
+            book.get_quantity_for_price(price, OrderSide::Buy),
+            Quantity::zero(0)
+        );
+        assert_eq!(
+            book.get_quantity_for_price(price, OrderSide::Sell),
+            Quantity::zero(0)
+        );
     }
 
     #[rstest]
@@ -552,11 +1008,11 @@ mod tests {
 
         assert_eq!(
             book.get_avg_px_for_quantity(qty, OrderSide::Buy),
-            2.003_333_333_333_333_4
+            Some(2.003_333_333_333_333_4)
         );
         assert_eq!(
             book.get_avg_px_for_quantity(qty, OrderSide::Sell),
-            0.996_666_666_666_666_7
+            Some(0.996_666_666_666_666_7)
         );
     }
 
@@ -610,14 +1066,157 @@ mod tests {
 
         assert_eq!(
             book.get_quantity_for_price(Price::from("2.010"), OrderSide::Buy),
-            3.0
+            Quantity::from("3.0")
         );
         assert_eq!(
             book.get_quantity_for_price(Price::from("0.990"), OrderSide::Sell),
-            3.0
+            Quantity::from("3.0")
         );
     }
 
+    fn book_with_ladders() -> OrderBook {
+        let instrument_id = InstrumentId::from("BTCUSDT.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let levels = [
+            (OrderSide::Sell, "100.0", "1.0"),
+            (OrderSide::Sell, "101.0", "2.0"),
+            (OrderSide::Sell, "102.0", "3.0"),
+            (OrderSide::Buy, "99.0", "1.0"),
+            (OrderSide::Buy, "98.0", "2.0"),
+            (OrderSide::Buy, "97.0", "3.0"),
+        ];
+        for (side, price, size) in levels {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 0, 1.into());
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_get_avg_px_for_quantity_walks_levels() {
+        let book = book_with_ladders();
+
+        // Buy 3.0: 1.0 @ 100.0 + 2.0 @ 101.0 = 302.0 / 3.0
+        let avg_px = book
+            .get_avg_px_for_quantity(Quantity::from("3.0"), OrderSide::Buy)
+            .unwrap();
+        assert!((avg_px - 100.666_666_666_666_67).abs() < 1e-9);
+
+        // Sell 2.0: 1.0 @ 99.0 + 1.0 @ 98.0 = 197.0 / 2.0
+        assert_eq!(
+            book.get_avg_px_for_quantity(Quantity::from("2.0"), OrderSide::Sell),
+            Some(98.5)
+        );
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_no_market() {
+        let instrument_id = InstrumentId::from("BTCUSDT.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        let result = book.simulate_fills_for_quantity(OrderSide::Buy, None, Quantity::from("1.0"));
+
+        assert!(result.fills.is_empty());
+        assert_eq!(result.filled_qty, Quantity::from("0.0"));
+        assert!(result.is_partial);
+        assert_eq!(result.avg_px(), None);
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_buy() {
+        let book = book_with_ladders();
+
+        let result = book.simulate_fills_for_quantity(OrderSide::Buy, None, Quantity::from("4.0"));
+
+        assert_eq!(
+            result.fills,
+            vec![
+                BookFill {
+                    price: Price::from("100.0"),
+                    size: Quantity::from("1.0")
+                },
+                BookFill {
+                    price: Price::from("101.0"),
+                    size: Quantity::from("2.0")
+                },
+                BookFill {
+                    price: Price::from("102.0"),
+                    size: Quantity::from("1.0")
+                },
+            ]
+        );
+        assert_eq!(result.filled_qty, Quantity::from("4.0"));
+        assert!(!result.is_partial);
+        // (100.0 + 202.0 + 102.0) / 4.0
+        assert_eq!(result.avg_px(), Some(101.0));
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_sell_with_price_limit() {
+        let book = book_with_ladders();
+
+        let result = book.simulate_fills_for_quantity(
+            OrderSide::Sell,
+            Some(Price::from("98.0")),
+            Quantity::from("5.0"),
+        );
+
+        assert_eq!(
+            result.fills,
+            vec![
+                BookFill {
+                    price: Price::from("99.0"),
+                    size: Quantity::from("1.0")
+                },
+                BookFill {
+                    price: Price::from("98.0"),
+                    size: Quantity::from("2.0")
+                },
+            ]
+        );
+        assert_eq!(result.filled_qty, Quantity::from("3.0"));
+        assert!(result.is_partial);
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_book_thinner_than_requested() {
+        let book = book_with_ladders();
+
+        let result = book.simulate_fills_for_quantity(OrderSide::Buy, None, Quantity::from("10.0"));
+
+        assert_eq!(result.fills.len(), 3);
+        assert_eq!(result.filled_qty, Quantity::from("6.0"));
+        assert!(result.is_partial);
+        // The book itself is not mutated
+        assert_eq!(book.best_ask_size(), Some(Quantity::from("1.0")));
+    }
+
+    #[rstest]
+    fn test_simulate_fills_for_quantity_aggregates_orders_per_level() {
+        let instrument_id = InstrumentId::from("BTCUSDT.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        for (order_id, size) in [(1, "1.0"), (2, "2.0")] {
+            let order = BookOrder::new(
+                OrderSide::Sell,
+                Price::from("100.0"),
+                Quantity::from(size),
+                order_id,
+            );
+            book.add(order, 0, 0, 1.into());
+        }
+
+        let result = book.simulate_fills_for_quantity(OrderSide::Buy, None, Quantity::from("2.5"));
+
+        assert_eq!(
+            result.fills,
+            vec![BookFill {
+                price: Price::from("100.0"),
+                size: Quantity::from("2.5")
+            }]
+        );
+        assert!(!result.is_partial);
+    }
+
     #[rstest]
     fn test_get_price_for_exposure_no_market() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -667,6 +1266,97 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap().as_f64(), 100.0);
     }
 
+    #[rstest]
+    fn test_apply_depth_sets_level_counts(stub_depth10: OrderBookDepth10) {
+        let mut depth = stub_depth10;
+        depth.bid_counts[0] = 5;
+        depth.ask_counts[1] = 3;
+        depth.ask_counts[2] = 0; // Count not provided
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+
+        book.apply_depth(&depth);
+
+        let bid_counts: Vec<u32> = book.bids().map(Level::count).take(2).collect();
+        let ask_counts: Vec<u32> = book.asks().map(Level::count).take(3).collect();
+        assert_eq!(bid_counts, vec![5, 1]);
+        assert_eq!(ask_counts, vec![1, 3, 1]);
+        assert_eq!(book.snapshot(1).bids[0].count, 5);
+
+        // An MBP size update retains the venue count for the level
+        let order = BookOrder::new(OrderSide::Buy, Price::from("99.00"), Quantity::from(50), 0);
+        book.update(order, 0, 1, 2.into());
+        assert_eq!(book.bids().next().unwrap().count(), 5);
+    }
+
+    #[rstest]
+    fn test_level_counts_for_l3_book_track_orders() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L3_MBO);
+        let order = |size: &str, order_id: u64| {
+            BookOrder::new(
+                OrderSide::Sell,
+                Price::from("10.0"),
+                Quantity::from(size),
+                order_id,
+            )
+        };
+
+        book.add(order("1", 1), 0, 1, 1.into());
+        book.add(order("2", 2), 0, 2, 2.into());
+        book.add(order("3", 3), 0, 3, 3.into());
+        book.update(order("4", 2), 0, 4, 4.into());
+        book.delete(order("0", 1), 0, 5, 5.into());
+
+        let level = book.asks().next().unwrap();
+        assert_eq!(level.count(), 2);
+        assert_eq!(book.snapshot(1).asks[0].count, 2);
+    }
+
+    #[rstest]
+    fn test_level_counts_updated_by_applied_deltas() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let mut book = OrderBook::new(instrument_id, BookType::L3_MBO);
+        let delta = |action: BookAction, size: &str, order_id: u64, sequence: u64| {
+            let order = BookOrder::new(
+                OrderSide::Buy,
+                Price::from("10.0"),
+                Quantity::from(size),
+                order_id,
+            );
+            OrderBookDelta::new(
+                instrument_id,
+                action,
+                order,
+                0,
+                sequence,
+                sequence.into(),
+                sequence.into(),
+            )
+        };
+        let count = |book: &OrderBook| book.bids().next().map_or(0, Level::count);
+
+        book.apply_delta(&delta(BookAction::Add, "1", 1, 1))
+            .unwrap();
+        book.apply_delta(&delta(BookAction::Add, "2", 2, 2))
+            .unwrap();
+        assert_eq!(count(&book), 2);
+
+        book.apply_delta(&delta(BookAction::Update, "3", 2, 3))
+            .unwrap();
+        assert_eq!(count(&book), 2);
+
+        // A zero size update removes the order
+        book.apply_delta(&delta(BookAction::Update, "0", 1, 4))
+            .unwrap();
+        assert_eq!(count(&book), 1);
+
+        book.apply_delta(&delta(BookAction::Add, "4", 3, 5))
+            .unwrap();
+        book.apply_delta(&delta(BookAction::Delete, "0", 2, 6))
+            .unwrap();
+        assert_eq!(count(&book), 1);
+        assert_eq!(book.snapshot(1).bids[0].count, 1);
+    }
+
     #[rstest]
     fn test_orderbook_creation() {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
@@ -742,6 +1432,127 @@ mod tests {
         assert_eq!(book.best_ask_size().unwrap(), size);
     }
 
+    fn quote(bid: &str, ask: &str, bid_size: &str, ask_size: &str, ts: u64) -> QuoteTick {
+        QuoteTick::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            Price::from(bid),
+            Price::from(ask),
+            Quantity::from(bid_size),
+            Quantity::from(ask_size),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    #[rstest]
+    fn test_apply_quotes_l1() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L1_MBP);
+        let quotes = [
+            quote("5000.00", "5001.00", "1.0", "2.0", 1),
+            quote("5000.50", "5001.50", "3.0", "4.0", 2),
+            quote("4999.00", "5000.00", "5.0", "6.0", 3),
+        ];
+
+        for quote in quotes {
+            book.apply(&Data::Quote(quote)).unwrap();
+        }
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("4999.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("5000.00")));
+        assert_eq!(book.best_bid_size(), Some(Quantity::from("5.0")));
+        assert_eq!(book.best_ask_size(), Some(Quantity::from("6.0")));
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.asks.len(), 1);
+        assert_eq!(book.ts_last, 3);
+        assert_eq!(book.count, 3);
+    }
+
+    #[rstest]
+    fn test_apply_trade_l1() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L1_MBP);
+        book.apply(&Data::Quote(quote("5000.00", "5001.00", "1.0", "2.0", 1)))
+            .unwrap();
+        let trade = TradeTick::new(
+            instrument_id,
+            Price::from("5000.50"),
+            Quantity::from("0.5"),
+            AggressorSide::Buyer,
+            TradeId::new("1"),
+            2.into(),
+            2.into(),
+        );
+
+        book.apply(&Data::Trade(trade)).unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("5000.50")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("5000.50")));
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.asks.len(), 1);
+    }
+
+    #[rstest]
+    #[case(BookType::L2_MBP)]
+    #[case(BookType::L3_MBO)]
+    fn test_apply_quote_when_not_l1_returns_error(#[case] book_type: BookType) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, book_type);
+
+        let result = book.apply(&Data::Quote(quote("5000.00", "5001.00", "1.0", "2.0", 1)));
+
+        assert!(result.is_err());
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+    }
+
+    #[rstest]
+    fn test_apply_delta_with_disallowed_action_returns_error() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L1_MBP);
+
+        let result = book.apply_delta(&l2_delta(OrderSide::Buy, "100.00", "1.0", 1));
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::InvalidAction(
+                BookAction::Add,
+                BookType::L1_MBP
+            ))
+        ));
+        assert!(!book.has_bid());
+        assert_eq!(book.count, 0);
+    }
+
+    #[rstest]
+    fn test_apply_deltas_with_disallowed_action_applies_none() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L1_MBP);
+        let mut update = l2_delta(OrderSide::Buy, "99.00", "1.0", 1);
+        update.action = BookAction::Update;
+        let deltas = OrderBookDeltas::new(
+            instrument_id,
+            vec![update, l2_delta(OrderSide::Sell, "100.00", "1.0", 2)],
+        );
+
+        let result = book.apply_deltas(&deltas);
+
+        assert!(matches!(result, Err(BookIntegrityError::InvalidAction(..))));
+        assert!(!book.has_bid());
+        assert!(!book.has_ask());
+    }
+
+    #[rstest]
+    fn test_apply_depth10_data(stub_depth10: OrderBookDepth10) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        book.apply(&Data::Depth10(stub_depth10)).unwrap();
+
+        assert_eq!(book.best_bid_price(), Some(Price::from("99.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("100.00")));
+    }
+
     #[rstest]
     fn test_check_integrity_when_crossed() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -765,6 +1576,384 @@ mod tests {
         assert!(book_check_integrity(&book).is_err());
     }
 
+    #[rstest]
+    fn test_imbalance_and_microprice_empty_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        assert!(book.imbalance(1).is_nan());
+        assert_eq!(book.microprice(), None);
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Buy, Price::from("100.0")),
+            Quantity::zero(0)
+        );
+    }
+
+    #[rstest]
+    fn test_imbalance() {
+        let book = book_with_ladders();
+
+        // Top of book: bid 1.0, ask 1.0
+        assert_eq!(book.imbalance(1), 0.0);
+        // Two levels: bids 1.0 + 2.0, asks 1.0 + 2.0
+        assert_eq!(book.imbalance(2), 0.0);
+        assert!(book.imbalance(0).is_nan());
+    }
+
+    #[rstest]
+    fn test_imbalance_one_sided() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("99.0"),
+            Quantity::from("3.0"),
+            0,
+        );
+        book.add(order, 0, 0, 1.into());
+
+        assert_eq!(book.imbalance(5), 1.0);
+        assert_eq!(book.microprice(), None);
+    }
+
+    #[rstest]
+    fn test_imbalance_and_microprice_skewed_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        let orders = [
+            (OrderSide::Buy, "99.0", "3.0"),
+            (OrderSide::Buy, "98.0", "5.0"),
+            (OrderSide::Sell, "101.0", "1.0"),
+            (OrderSide::Sell, "102.0", "1.0"),
+        ];
+        for (side, price, size) in orders {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 0, 1.into());
+        }
+
+        // (3 - 1) / (3 + 1)
+        assert_eq!(book.imbalance(1), 0.5);
+        // (8 - 2) / (8 + 2)
+        assert_eq!(book.imbalance(10), 0.6);
+        // (99 * 1 + 101 * 3) / (1 + 3)
+        assert_eq!(book.microprice(), Some(100.5));
+    }
+
+    #[rstest]
+    fn test_cumulative_depth() {
+        let book = book_with_ladders();
+
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Buy, Price::from("98.0")),
+            Quantity::from("3.0")
+        );
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Buy, Price::from("99.5")),
+            Quantity::zero(0)
+        );
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Sell, Price::from("101.5")),
+            Quantity::from("3.0")
+        );
+        assert_eq!(
+            book.cumulative_depth(OrderSide::Sell, Price::from("200.0")),
+            Quantity::from("6.0")
+        );
+    }
+
+    #[rstest]
+    fn test_snapshot_matches_levels(stub_depth10: OrderBookDepth10) {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.apply_depth(&stub_depth10);
+
+        let snapshot = book.snapshot(5);
+
+        assert_eq!(snapshot.bids.len(), 5);
+        assert_eq!(snapshot.asks.len(), 5);
+        assert_eq!(
+            snapshot.bids[0].price_raw,
+            book.best_bid_price().unwrap().raw
+        );
+        assert_eq!(snapshot.bids[0].size_raw, book.best_bid_size().unwrap().raw);
+        assert_eq!(
+            snapshot.asks[0].price_raw,
+            book.best_ask_price().unwrap().raw
+        );
+        assert_eq!(snapshot.asks[0].size_raw, book.best_ask_size().unwrap().raw);
+        for (level, snapshot_level) in book.bids().zip(&snapshot.bids) {
+            assert_eq!(snapshot_level.price_raw, level.price.value.raw);
+            assert_eq!(snapshot_level.size_raw, level.size_raw());
+            assert_eq!(snapshot_level.count, 1);
+        }
+        for (level, snapshot_level) in book.asks().zip(&snapshot.asks) {
+            assert_eq!(snapshot_level.price_raw, level.price.value.raw);
+            assert_eq!(snapshot_level.size_raw, level.size_raw());
+            assert_eq!(snapshot_level.count, 1);
+        }
+        assert!(snapshot
+            .bids
+            .windows(2)
+            .all(|w| w[0].price_raw > w[1].price_raw));
+        assert!(snapshot
+            .asks
+            .windows(2)
+            .all(|w| w[0].price_raw < w[1].price_raw));
+    }
+
+    #[rstest]
+    fn test_snapshot_when_book_shallower_than_depth() {
+        let book = book_with_ladders();
+
+        let snapshot = book.snapshot(25);
+
+        assert_eq!(snapshot.bids.len(), 3);
+        assert_eq!(snapshot.asks.len(), 3);
+        assert_eq!(
+            snapshot.asks[2],
+            BookLevel {
+                price_raw: Price::from("102.0").raw,
+                size_raw: Quantity::from("3.0").raw,
+                count: 1,
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_snapshot_empty_book() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let book = OrderBook::new(instrument_id, BookType::L2_MBP);
+
+        assert_eq!(book.snapshot(10), BookSnapshot::default());
+    }
+
+    fn grouped(levels: &[(&str, &str, u64)]) -> Vec<BookLevel> {
+        levels
+            .iter()
+            .map(|(price, size, count)| BookLevel {
+                price_raw: Price::from(*price).raw,
+                size_raw: Quantity::from(*size).raw,
+                count: *count,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    fn test_group_levels() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        for (side, price, size) in [
+            (OrderSide::Buy, "100.49", "1"),
+            (OrderSide::Buy, "100.01", "2"),
+            (OrderSide::Buy, "100.00", "3"), // On a bucket boundary
+            (OrderSide::Buy, "99.99", "4"),
+            (OrderSide::Buy, "98.70", "5"),
+            (OrderSide::Sell, "100.50", "6"), // On a bucket boundary
+            (OrderSide::Sell, "100.51", "7"),
+            (OrderSide::Sell, "100.99", "8"),
+            (OrderSide::Sell, "101.01", "9"),
+        ] {
+            let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+            book.add(order, 0, 1, 1.into());
+        }
+
+        let snapshot = book.group_levels(Price::from("0.50"), 10);
+
+        assert_eq!(
+            snapshot.bids,
+            grouped(&[("100.00", "6", 3), ("99.50", "4", 1), ("98.50", "5", 1)])
+        );
+        assert_eq!(
+            snapshot.asks,
+            grouped(&[("100.50", "6", 1), ("101.00", "15", 2), ("101.50", "9", 1)])
+        );
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, "100.01", "100.00")]
+    #[case(OrderSide::Buy, "100.49", "100.00")]
+    #[case(OrderSide::Buy, "100.50", "100.50")]
+    #[case(OrderSide::Buy, "-0.26", "-0.50")]
+    #[case(OrderSide::Sell, "100.01", "100.50")]
+    #[case(OrderSide::Sell, "100.49", "100.50")]
+    #[case(OrderSide::Sell, "100.50", "100.50")]
+    #[case(OrderSide::Sell, "-0.26", "0.00")]
+    fn test_group_levels_rounding_direction(
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] expected: &str,
+    ) {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(1), 0);
+        book.add(order, 0, 1, 1.into());
+
+        let snapshot = book.group_levels(Price::from("0.50"), 1);
+
+        let levels = match side {
+            OrderSide::Buy => snapshot.bids,
+            _ => snapshot.asks,
+        };
+        assert_eq!(levels, grouped(&[(expected, "1", 1)]));
+    }
+
+    #[rstest]
+    fn test_group_levels_limited_to_depth() {
+        let book = book_with_ladders();
+
+        let snapshot = book.group_levels(Price::from("2.0"), 2);
+
+        assert_eq!(
+            snapshot.bids,
+            grouped(&[("98.0", "3.0", 2), ("96.0", "3.0", 1)])
+        );
+        assert_eq!(
+            snapshot.asks,
+            grouped(&[("100.0", "1.0", 1), ("102.0", "5.0", 2)])
+        );
+        assert_eq!(book.group_levels(Price::from("2.0"), 1).bids.len(), 1);
+    }
+
+    #[rstest]
+    fn test_group_levels_single_level() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let order = BookOrder::new(OrderSide::Buy, Price::from("10.07"), Quantity::from(5), 0);
+        book.add(order, 0, 1, 1.into());
+
+        let snapshot = book.group_levels(Price::from("0.05"), 5);
+
+        assert_eq!(snapshot.bids, grouped(&[("10.05", "5", 1)]));
+        assert!(snapshot.asks.is_empty());
+    }
+
+    #[rstest]
+    #[should_panic(expected = "tick_raw")]
+    fn test_group_levels_with_zero_tick_size() {
+        let book = book_with_ladders();
+        let _ = book.group_levels(Price::from("0.00"), 5);
+    }
+
+    fn l2_delta(side: OrderSide, price: &str, size: &str, sequence: u64) -> OrderBookDelta {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            BookAction::Add,
+            order,
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    fn book_with_policy(policy: BookIntegrityPolicy) -> OrderBook {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.integrity_policy = policy;
+
+        let deltas = [
+            l2_delta(OrderSide::Buy, "99.00", "1.0", 1),
+            l2_delta(OrderSide::Buy, "98.00", "1.0", 2),
+            l2_delta(OrderSide::Sell, "100.00", "1.0", 3),
+            l2_delta(OrderSide::Sell, "101.00", "1.0", 4),
+            l2_delta(OrderSide::Sell, "102.00", "1.0", 5),
+        ];
+        for delta in &deltas {
+            book.apply_delta(delta).unwrap();
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_check_integrity_when_locked() {
+        let mut book = book_with_policy(BookIntegrityPolicy::Ignore);
+        book.apply_delta(&l2_delta(OrderSide::Buy, "100.00", "1.0", 6))
+            .unwrap();
+
+        let result = book.check_integrity();
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::OrdersLocked(bid, ask))
+                if bid.value == Price::from("100.00") && ask.value == Price::from("100.00")
+        ));
+    }
+
+    #[rstest]
+    fn test_check_integrity_when_valid() {
+        let book = book_with_policy(BookIntegrityPolicy::Ignore);
+
+        assert!(book.check_integrity().is_ok());
+        assert!(book.check_two_sided().is_ok());
+    }
+
+    #[rstest]
+    fn test_check_two_sided_when_side_empty() {
+        let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
+        let mut book = OrderBook::new(instrument_id, BookType::L2_MBP);
+        book.apply_delta(&l2_delta(OrderSide::Buy, "99.00", "1.0", 1))
+            .unwrap();
+
+        // A one-sided book is still valid
+        assert!(book.check_integrity().is_ok());
+        assert!(matches!(
+            book.check_two_sided(),
+            Err(BookIntegrityError::EmptySide(OrderSide::Sell))
+        ));
+    }
+
+    #[rstest]
+    fn test_integrity_policy_ignore_when_crossed() {
+        let mut book = book_with_policy(BookIntegrityPolicy::Ignore);
+
+        let result = book.apply_delta(&l2_delta(OrderSide::Buy, "101.00", "1.0", 6));
+
+        assert!(result.is_ok());
+        assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("100.00")));
+        assert!(matches!(
+            book.check_integrity(),
+            Err(BookIntegrityError::OrdersCrossed(bid, ask))
+                if bid.value == Price::from("101.00") && ask.value == Price::from("100.00")
+        ));
+    }
+
+    #[rstest]
+    fn test_integrity_policy_raise_error_when_crossed() {
+        let mut book = book_with_policy(BookIntegrityPolicy::RaiseError);
+
+        let result = book.apply_delta(&l2_delta(OrderSide::Buy, "101.00", "1.0", 6));
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::OrdersCrossed(bid, ask))
+                if bid.value == Price::from("101.00") && ask.value == Price::from("100.00")
+        ));
+    }
+
+    #[rstest]
+    fn test_integrity_policy_drop_crossed_levels_for_bid() {
+        let mut book = book_with_policy(BookIntegrityPolicy::DropCrossedLevels);
+
+        let result = book.apply_delta(&l2_delta(OrderSide::Buy, "101.00", "1.0", 6));
+
+        assert!(result.is_ok());
+        assert_eq!(book.best_bid_price(), Some(Price::from("101.00")));
+        assert_eq!(book.best_ask_price(), Some(Price::from("102.00")));
+        assert_eq!(book.asks.len(), 1);
+        assert!(book.check_integrity().is_ok());
+    }
+
+    #[rstest]
+    fn test_integrity_policy_drop_crossed_levels_for_ask() {
+        let mut book = book_with_policy(BookIntegrityPolicy::DropCrossedLevels);
+
+        let result = book.apply_delta(&l2_delta(OrderSide::Sell, "98.00", "1.0", 6));
+
+        assert!(result.is_ok());
+        assert_eq!(book.best_ask_price(), Some(Price::from("98.00")));
+        assert_eq!(book.best_bid_price(), None);
+        assert!(book.check_integrity().is_ok());
+    }
+
     #[rstest]
     fn test_pprint() {
         let instrument_id = InstrumentId::from("ETHUSDT-PERP.BINANCE");
@@ -0,0 +1,241 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Functions related to venue order book checksums.
+//!
+//! Several venues publish a CRC32 checksum alongside L2 book updates so that clients
+//! can detect when their locally maintained book has become desynchronized.
+//!
+//! Price and size strings are rendered with the precision of each value (keeping trailing
+//! zeros, as in the venues' published examples), so the book must be maintained with the same
+//! precisions the venue uses in its own messages.
+
+use super::{book::OrderBook, level::Level};
+use crate::types::quantity::Quantity;
+
+/// The number of levels per side included in a Kraken checksum.
+pub const KRAKEN_CHECKSUM_DEPTH: usize = 10;
+
+/// The number of levels per side included in an OKX checksum.
+pub const OKX_CHECKSUM_DEPTH: usize = 25;
+
+/// The venue-specific scheme used to compute an order book checksum.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum BookChecksumType {
+    /// Kraken style: asks then bids, with decimal points and leading zeros removed.
+    Kraken = 1,
+    /// OKX style: interleaved bid/ask price and size strings joined by `:`.
+    Okx = 2,
+}
+
+/// Calculates a Kraken style CRC32 checksum for the top `depth` levels of the given `book`.
+///
+/// For each of the top ask levels (best first) then each of the top bid levels (best first),
+/// the price and size strings have the decimal point and leading zeros removed and are
+/// concatenated into a single string, which is then hashed with CRC32.
+#[must_use]
+pub fn checksum_kraken(book: &OrderBook, depth: usize) -> u32 {
+    let mut hasher = crc32fast::Hasher::new();
+
+    for level in book.asks.levels.values().take(depth) {
+        hasher.update(kraken_level_str(level).as_bytes());
+    }
+
+    for level in book.bids.levels.values().take(depth) {
+        hasher.update(kraken_level_str(level).as_bytes());
+    }
+
+    hasher.finalize()
+}
+
+/// Calculates an OKX style CRC32 checksum for the top `depth` levels of the given `book`.
+///
+/// Levels are interleaved as `bid_px:bid_sz:ask_px:ask_sz:...` (best first). When one side
+/// has fewer levels than the other, the remaining levels of the deeper side are appended.
+///
+/// OKX publishes the checksum as a signed 32-bit integer, the returned value should be
+/// compared after casting with `as i32` (or by casting the venue value to `u32`).
+#[must_use]
+pub fn checksum_okx(book: &OrderBook, depth: usize) -> u32 {
+    let bids: Vec<&Level> = book.bids.levels.values().take(depth).collect();
+    let asks: Vec<&Level> = book.asks.levels.values().take(depth).collect();
+
+    let mut fields: Vec<String> = Vec::with_capacity((bids.len() + asks.len()) * 2);
+    for i in 0..bids.len().max(asks.len()) {
+        if let Some(bid) = bids.get(i) {
+            fields.push(bid.price.value.to_string());
+            fields.push(level_quantity(bid).to_string());
+        }
+        if let Some(ask) = asks.get(i) {
+            fields.push(ask.price.value.to_string());
+            fields.push(level_quantity(ask).to_string());
+        }
+    }
+
+    crc32fast::hash(fields.join(":").as_bytes())
+}
+
+/// Calculates a checksum for the top `depth` levels of the given `book` using the given scheme.
+#[must_use]
+pub fn book_checksum(book: &OrderBook, checksum_type: BookChecksumType, depth: usize) -> u32 {
+    match checksum_type {
+        BookChecksumType::Kraken => checksum_kraken(book, depth),
+        BookChecksumType::Okx => checksum_okx(book, depth),
+    }
+}
+
+fn level_quantity(level: &Level) -> Quantity {
+    let precision = level.first().map_or(0, |order| order.size.precision);
+    Quantity::from_raw(level.size_raw(), precision)
+}
+
+fn kraken_level_str(level: &Level) -> String {
+    let price = level.price.value.to_string();
+    let size = level_quantity(level).to_string();
+    format!("{}{}", kraken_format(&price), kraken_format(&size))
+}
+
+fn kraken_format(value: &str) -> String {
+    value.replace('.', "").trim_start_matches('0').to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::order::BookOrder,
+        enums::{BookType, OrderSide},
+        identifiers::InstrumentId,
+        orderbook::error::BookIntegrityError,
+        types::{price::Price, quantity::Quantity},
+    };
+
+    fn add_level(book: &mut OrderBook, side: OrderSide, price: &str, size: &str) {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        book.add(order, 0, 0, 0.into());
+    }
+
+    /// The book snapshot of the checksum example in the Kraken WebSocket API documentation.
+    fn kraken_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("XBT/USD.KRAKEN"), BookType::L2_MBP);
+        let asks = [
+            "0.05005", "0.05010", "0.05015", "0.05020", "0.05025", "0.05030", "0.05035", "0.05040",
+            "0.05045", "0.05050",
+        ];
+        let bids = [
+            "0.05000", "0.04995", "0.04990", "0.04980", "0.04975", "0.04970", "0.04965", "0.04960",
+            "0.04955", "0.04950",
+        ];
+        for price in asks {
+            add_level(&mut book, OrderSide::Sell, price, "0.00000500");
+        }
+        for price in bids {
+            add_level(&mut book, OrderSide::Buy, price, "0.00000500");
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_kraken_format() {
+        assert_eq!(kraken_format("0.05005"), "5005");
+        assert_eq!(kraken_format("0.00000500"), "500");
+        assert_eq!(kraken_format("45285.2"), "452852");
+    }
+
+    #[rstest]
+    fn test_checksum_kraken_documented_example() {
+        let book = kraken_book();
+
+        // Example from the Kraken WebSocket API documentation:
+        // "50055005010500501550050205005025500503050050355005040500504550050505005000500499550049905004980500497550049705004965500496050049555004950500" -> 974947235
+        assert_eq!(
+            book.checksum_crc32_kraken(KRAKEN_CHECKSUM_DEPTH),
+            974_947_235
+        );
+    }
+
+    #[rstest]
+    fn test_checksum_kraken_only_includes_depth() {
+        let mut book = kraken_book();
+        let checksum = book.checksum_crc32_kraken(KRAKEN_CHECKSUM_DEPTH);
+
+        // Levels beyond the checksum depth do not change the checksum
+        add_level(&mut book, OrderSide::Sell, "0.05100", "1.00000000");
+        add_level(&mut book, OrderSide::Buy, "0.04900", "1.00000000");
+
+        assert_eq!(book.checksum_crc32_kraken(KRAKEN_CHECKSUM_DEPTH), checksum);
+    }
+
+    #[rstest]
+    fn test_checksum_okx_documented_example() {
+        // Example from the OKX API documentation:
+        // "3366.1:7:3366.8:9:3366:6:3368:8" -> -1881014294
+        let mut book = OrderBook::new(InstrumentId::from("BTC-USDT.OKX"), BookType::L2_MBP);
+        add_level(&mut book, OrderSide::Buy, "3366.1", "7");
+        add_level(&mut book, OrderSide::Buy, "3366", "6");
+        add_level(&mut book, OrderSide::Sell, "3366.8", "9");
+        add_level(&mut book, OrderSide::Sell, "3368", "8");
+
+        let checksum = book.checksum_crc32_okx(OKX_CHECKSUM_DEPTH);
+
+        assert_eq!(checksum as i32, -1_881_014_294);
+    }
+
+    #[rstest]
+    fn test_checksum_okx_uneven_sides() {
+        let mut book = OrderBook::new(InstrumentId::from("BTC-USDT.OKX"), BookType::L2_MBP);
+        add_level(&mut book, OrderSide::Buy, "3366.1", "7");
+        add_level(&mut book, OrderSide::Buy, "3366", "6");
+        add_level(&mut book, OrderSide::Sell, "3366.8", "9");
+
+        let expected = crc32fast::hash(b"3366.1:7:3366.8:9:3366:6");
+
+        assert_eq!(book.checksum_crc32_okx(OKX_CHECKSUM_DEPTH), expected);
+    }
+
+    #[rstest]
+    fn test_checksum_empty_book() {
+        let book = OrderBook::new(InstrumentId::from("XBT/USD.KRAKEN"), BookType::L2_MBP);
+
+        assert_eq!(book.checksum_crc32_kraken(KRAKEN_CHECKSUM_DEPTH), 0);
+        assert_eq!(book.checksum_crc32_okx(OKX_CHECKSUM_DEPTH), 0);
+    }
+
+    #[rstest]
+    fn test_verify_checksum() {
+        let book = kraken_book();
+
+        assert!(book.verify_checksum(BookChecksumType::Kraken, KRAKEN_CHECKSUM_DEPTH, 974_947_235));
+        assert!(!book.verify_checksum(BookChecksumType::Kraken, KRAKEN_CHECKSUM_DEPTH, 1));
+    }
+
+    #[rstest]
+    fn test_check_checksum_mismatch_returns_integrity_error() {
+        let book = kraken_book();
+
+        let result = book.check_checksum(BookChecksumType::Kraken, KRAKEN_CHECKSUM_DEPTH, 1);
+
+        assert!(matches!(
+            result,
+            Err(BookIntegrityError::ChecksumMismatch(1, 974_947_235))
+        ));
+    }
+}
@@ -18,7 +18,7 @@
 use nautilus_core::nanos::UnixNanos;
 
 use super::ladder::BookPrice;
-use crate::enums::{BookType, OrderSide};
+use crate::enums::{BookAction, BookType, OrderSide};
 
 #[derive(thiserror::Error, Debug)]
 pub enum InvalidBookOperation {
@@ -38,8 +38,18 @@ pub enum BookIntegrityError {
     NoOrderSide,
     #[error("Integrity error: orders in cross [{0} {1}]")]
     OrdersCrossed(BookPrice, BookPrice),
+    #[error("Integrity error: orders locked [{0} {1}]")]
+    OrdersLocked(BookPrice, BookPrice),
+    #[error("Integrity error: {0} levels not monotonic [{1} {2}]")]
+    NonMonotonicLevels(OrderSide, BookPrice, BookPrice),
+    #[error("Integrity error: {0} side of book is empty")]
+    EmptySide(OrderSide),
     #[error("Integrity error: number of {0} orders at level > 1 for L2_MBP book, was {1}")]
     TooManyOrders(OrderSide, usize),
     #[error("Integrity error: number of {0} levels > 1 for L1_MBP book, was {1}")]
     TooManyLevels(OrderSide, usize),
+    #[error("Integrity error: checksum mismatch, expected={0}, computed={1}")]
+    ChecksumMismatch(u32, u32),
+    #[error("Integrity error: {0} action not allowed for {1} book")]
+    InvalidAction(BookAction, BookType),
 }
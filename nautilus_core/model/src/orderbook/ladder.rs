@@ -166,6 +166,32 @@ impl Ladder {
         }
     }
 
+    /// Removes all levels from the top of the ladder which are at or through the given
+    /// `price` (i.e. would cross or lock against an opposite side order at `price`).
+    ///
+    /// Returns the number of levels removed.
+    pub fn remove_crossed_levels(&mut self, price: Price) -> usize {
+        let crossed: Vec<BookPrice> = self
+            .levels
+            .keys()
+            .take_while(|book_price| match self.side {
+                OrderSide::Buy => book_price.value >= price,
+                _ => book_price.value <= price,
+            })
+            .copied()
+            .collect();
+
+        for book_price in &crossed {
+            if let Some(level) = self.levels.remove(book_price) {
+                for order_id in level.orders.keys() {
+                    self.cache.remove(order_id);
+                }
+            }
+        }
+
+        crossed.len()
+    }
+
     #[must_use]
     pub fn sizes(&self) -> f64 {
         self.levels.values().map(super::level::Level::size).sum()
@@ -475,6 +501,7 @@ mod tests {
             size: Quantity::from(500),
             side,
             order_id: 2,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -497,6 +524,7 @@ mod tests {
             size: Quantity::from(100),
             side: ladder_side,
             order_id: 1,
+            flags: 0,
         });
 
         let order = BookOrder {
@@ -504,6 +532,7 @@ mod tests {
             size: Quantity::from(500),
             side: order_side,
             order_id: 2,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -520,6 +549,7 @@ mod tests {
             size: Quantity::from(100),
             side: OrderSide::Buy,
             order_id: 1,
+            flags: 0,
         });
 
         let order = BookOrder {
@@ -527,6 +557,7 @@ mod tests {
             size: Quantity::from(500),
             side: OrderSide::Buy,
             order_id: 2,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -544,18 +575,21 @@ mod tests {
                 size: Quantity::from(100),
                 side: OrderSide::Sell,
                 order_id: 1,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("101.00"),
                 size: Quantity::from(200),
                 side: OrderSide::Sell,
                 order_id: 2,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("102.00"),
                 size: Quantity::from(400),
                 side: OrderSide::Sell,
                 order_id: 3,
+                flags: 0,
             },
         ]);
 
@@ -564,6 +598,7 @@ mod tests {
             size: Quantity::from(500),
             side: OrderSide::Buy,
             order_id: 4,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -593,18 +628,21 @@ mod tests {
                 size: Quantity::from(100),
                 side: OrderSide::Buy,
                 order_id: 1,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("101.00"),
                 size: Quantity::from(200),
                 side: OrderSide::Buy,
                 order_id: 2,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("100.00"),
                 size: Quantity::from(400),
                 side: OrderSide::Buy,
                 order_id: 3,
+                flags: 0,
             },
         ]);
 
@@ -613,6 +651,7 @@ mod tests {
             size: Quantity::from(500),
             side: OrderSide::Sell,
             order_id: 4,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -642,18 +681,21 @@ mod tests {
                 size: Quantity::from("100.000000000"),
                 side: OrderSide::Buy,
                 order_id: 1,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("101.00"),
                 size: Quantity::from("200.000000000"),
                 side: OrderSide::Buy,
                 order_id: 2,
+                flags: 0,
             },
             BookOrder {
                 price: Price::from("100.00"),
                 size: Quantity::from("400.000000000"),
                 side: OrderSide::Buy,
                 order_id: 3,
+                flags: 0,
             },
         ]);
 
@@ -662,6 +704,7 @@ mod tests {
             size: Quantity::from("699.999999999"), // <-- Size slightly less than total size in ladder
             side: OrderSide::Sell,
             order_id: 4,
+            flags: 0,
         };
 
         let fills = ladder.simulate_fills(&order);
@@ -29,6 +29,10 @@ use crate::{
 ///
 /// The level maintains a collection of orders as well as tracking insertion order
 /// to preserve FIFO queue dynamics.
+///
+/// The level also tracks an order count, which is updated as orders are added and
+/// removed. For MBP books (where each level holds a single aggregated order) the count
+/// can instead be set from venue data with [`Level::set_count`].
 #[derive(Clone, Debug, Eq)]
 #[cfg_attr(
     feature = "python",
@@ -38,6 +42,7 @@ pub struct Level {
     pub price: BookPrice,
     pub orders: BTreeMap<OrderId, BookOrder>,
     insertion_order: Vec<OrderId>,
+    count: u32,
 }
 
 impl Level {
@@ -47,6 +52,7 @@ impl Level {
             price,
             orders: BTreeMap::new(),
             insertion_order: Vec::new(),
+            count: 0,
         }
     }
 
@@ -56,6 +62,7 @@ impl Level {
             price: order.to_book_price(),
             orders: BTreeMap::new(),
             insertion_order: Vec::new(),
+            count: 0,
         };
         level.add(order);
         level
@@ -71,6 +78,17 @@ impl Level {
         self.orders.is_empty()
     }
 
+    /// Returns the number of orders at the level.
+    #[must_use]
+    pub const fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Sets the number of orders at the level (e.g. from a venue reported count for MBP data).
+    pub fn set_count(&mut self, count: u32) {
+        self.count = count;
+    }
+
     #[must_use]
     pub fn first(&self) -> Option<&BookOrder> {
         self.insertion_order
@@ -120,14 +138,14 @@ impl Level {
 
         for order in orders {
             self.check_order_for_this_level(&order);
-            self.orders.insert(order.order_id, order);
+            self.insert(order);
         }
     }
 
     pub fn add(&mut self, order: BookOrder) {
         self.check_order_for_this_level(&order);
 
-        self.orders.insert(order.order_id, order);
+        self.insert(order);
         self.insertion_order.push(order.order_id);
     }
 
@@ -135,27 +153,41 @@ impl Level {
         self.check_order_for_this_level(&order);
 
         if order.size.raw == 0 {
-            self.orders.remove(&order.order_id);
+            self.remove(order.order_id);
             self.update_insertion_order();
         } else {
-            self.orders.insert(order.order_id, order);
+            self.insert(order);
         }
     }
 
     pub fn delete(&mut self, order: &BookOrder) {
-        self.orders.remove(&order.order_id);
+        self.remove(order.order_id);
         self.update_insertion_order();
     }
 
     pub fn remove_by_id(&mut self, order_id: OrderId, sequence: u64, ts_event: UnixNanos) {
         assert!(
-            self.orders.remove(&order_id).is_some(),
+            self.remove(order_id),
             "{}",
             &BookIntegrityError::OrderNotFound(order_id, sequence, ts_event)
         );
         self.update_insertion_order();
     }
 
+    fn insert(&mut self, order: BookOrder) {
+        if self.orders.insert(order.order_id, order).is_none() {
+            self.count = self.count.saturating_add(1);
+        }
+    }
+
+    fn remove(&mut self, order_id: OrderId) -> bool {
+        let removed = self.orders.remove(&order_id).is_some();
+        if removed {
+            self.count = self.count.saturating_sub(1);
+        }
+        removed
+    }
+
     fn check_order_for_this_level(&self, order: &BookOrder) {
         assert_eq!(order.price, self.price.value);
     }
@@ -419,4 +451,63 @@ mod tests {
         level.add(order2);
         assert_eq!(level.exposure_raw(), 60_000_000_000);
     }
+
+    #[rstest]
+    fn test_count_after_add_update_delete_cycles() {
+        let mut level = Level::new(BookPrice::new(Price::from("1.00"), OrderSide::Buy));
+        let order = |size: i64, order_id: u64| {
+            BookOrder::new(
+                OrderSide::Buy,
+                Price::from("1.00"),
+                Quantity::from(size),
+                order_id,
+            )
+        };
+        assert_eq!(level.count(), 0);
+
+        level.add(order(10, 0));
+        level.add(order(20, 1));
+        level.add_bulk(vec![order(30, 2), order(40, 3)]);
+        assert_eq!(level.count(), 4);
+
+        level.update(order(15, 0)); // Existing order
+        assert_eq!(level.count(), 4);
+
+        level.update(order(5, 4)); // New order
+        assert_eq!(level.count(), 5);
+
+        level.update(order(0, 1)); // Zero size removes the order
+        level.delete(&order(30, 2));
+        level.remove_by_id(3, 0, 0.into());
+        assert_eq!(level.count(), 2);
+        assert_eq!(level.count() as usize, level.len());
+
+        level.delete(&order(30, 2)); // Already deleted
+        assert_eq!(level.count(), 2);
+
+        level.delete(&order(15, 0));
+        level.delete(&order(5, 4));
+        assert_eq!(level.count(), 0);
+        assert!(level.is_empty());
+    }
+
+    #[rstest]
+    fn test_set_count_retained_on_update() {
+        let mut level = Level::from_order(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(10),
+            0,
+        ));
+        level.set_count(7);
+
+        level.update(BookOrder::new(
+            OrderSide::Buy,
+            Price::from("1.00"),
+            Quantity::from(20),
+            0,
+        ));
+        assert_eq!(level.count(), 7);
+        assert_eq!(level.size(), 20.0);
+    }
 }
@@ -18,7 +18,10 @@
 pub mod aggregation;
 pub mod analysis;
 pub mod book;
+pub mod checksum;
 pub mod display;
 pub mod error;
 pub mod ladder;
 pub mod level;
+pub mod recorder;
+pub mod top;
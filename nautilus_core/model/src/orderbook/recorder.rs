@@ -0,0 +1,618 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Order book event recording, replay and diffing (useful for debugging desyncs).
+
+use std::collections::VecDeque;
+
+use nautilus_core::{
+    correctness::{check_equal, check_positive_u64, FAILED},
+    nanos::UnixNanos,
+};
+
+use super::{book::OrderBook, ladder::Ladder};
+use crate::{
+    data::{
+        delta::OrderBookDelta, depth::OrderBookDepth10, order::BookOrder, quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::{BookAction, OrderSide, RecordFlag},
+    types::price::Price,
+};
+
+/// Represents a mutation of an order book retained by a [`BookRecorder`] for replay.
+#[derive(Clone, Debug)]
+pub enum BookEvent {
+    /// An add, update or delete of an order, or a clear of both sides.
+    Delta(OrderBookDelta),
+    /// A clear of all levels on one side of the book.
+    ClearSide {
+        side: OrderSide,
+        sequence: u64,
+        ts_event: UnixNanos,
+    },
+    /// The levels on the opposite side to `side` crossed (or locked) at `price`, dropped by the
+    /// book's integrity policy.
+    DropCrossedLevels { side: OrderSide, price: Price },
+    /// A depth snapshot replacing all levels.
+    Depth(Box<OrderBookDepth10>),
+    /// A quote tick applied to an `L1_MBP` book.
+    Quote(QuoteTick),
+    /// A trade tick applied to an `L1_MBP` book.
+    Trade(TradeTick),
+}
+
+/// Records the mutations applied to an order book in a bounded ring buffer.
+///
+/// The recorder holds a base snapshot of the book along with up to `capacity` events applied
+/// since. When the buffer is full the oldest event is applied to the base snapshot before being
+/// evicted, so memory is bounded to one book plus `capacity` events.
+#[derive(Clone, Debug)]
+pub struct BookRecorder {
+    capacity: usize,
+    base: OrderBook,
+    events: VecDeque<(u64, BookEvent)>,
+}
+
+impl BookRecorder {
+    /// Creates a new [`BookRecorder`] instance starting from the given `base` snapshot.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If `capacity` is not positive (> 0).
+    #[must_use]
+    pub fn new(base: OrderBook, capacity: usize) -> Self {
+        check_positive_u64(capacity as u64, stringify!(capacity)).expect(FAILED);
+        Self {
+            capacity,
+            base,
+            events: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of events retained.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of events currently retained.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns whether no events are currently retained.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Returns the sequence number of the base snapshot (the earliest sequence which can be replayed).
+    #[must_use]
+    pub const fn base_sequence(&self) -> u64 {
+        self.base.sequence
+    }
+
+    /// Returns an iterator over the retained events, oldest first.
+    pub fn events(&self) -> impl Iterator<Item = &BookEvent> {
+        self.events.iter().map(|(_, event)| event)
+    }
+
+    /// Records the given `event` which left the book at `sequence`, evicting the oldest event
+    /// into the base snapshot when full.
+    pub fn record(&mut self, sequence: u64, event: BookEvent) {
+        if self.events.len() == self.capacity {
+            if let Some((_, oldest)) = self.events.pop_front() {
+                apply_event(&mut self.base, &oldest);
+            }
+        }
+        self.events.push_back((sequence, event));
+    }
+
+    /// Replaces the base snapshot with the given `base` and discards all retained events.
+    pub fn reset(&mut self, base: OrderBook) {
+        self.base = base;
+        self.events.clear();
+    }
+
+    /// Reconstructs the book as at the given `sequence`, by replaying the retained events
+    /// which left the book at or before it onto the base snapshot.
+    ///
+    /// Returns `None` if `sequence` is before the base snapshot (the events have been evicted).
+    #[must_use]
+    pub fn replay_to(&self, sequence: u64) -> Option<OrderBook> {
+        if sequence < self.base.sequence {
+            return None;
+        }
+
+        let mut book = self.base.clone();
+        for (_, event) in self.events.iter().take_while(|(seq, _)| *seq <= sequence) {
+            apply_event(&mut book, event);
+        }
+        Some(book)
+    }
+}
+
+/// Applies the recorded `event` to the `book`, bypassing the action checks and integrity
+/// policy (any levels dropped by the policy were recorded as their own event).
+fn apply_event(book: &mut OrderBook, event: &BookEvent) {
+    match event {
+        BookEvent::Delta(delta) => {
+            let (order, flags, sequence, ts_event) =
+                (delta.order, delta.flags, delta.sequence, delta.ts_event);
+            match delta.action {
+                BookAction::Add => book.add(order, flags, sequence, ts_event),
+                BookAction::Update => book.update(order, flags, sequence, ts_event),
+                BookAction::Delete => book.delete(order, flags, sequence, ts_event),
+                BookAction::Clear => book.clear(sequence, ts_event),
+            }
+        }
+        BookEvent::ClearSide {
+            side,
+            sequence,
+            ts_event,
+        } => match side {
+            OrderSide::Buy => book.clear_bids(*sequence, *ts_event),
+            OrderSide::Sell => book.clear_asks(*sequence, *ts_event),
+            OrderSide::NoOrderSide => {}
+        },
+        BookEvent::DropCrossedLevels { side, price } => {
+            match side {
+                OrderSide::Buy => book.asks.remove_crossed_levels(*price),
+                OrderSide::Sell => book.bids.remove_crossed_levels(*price),
+                OrderSide::NoOrderSide => 0,
+            };
+        }
+        BookEvent::Depth(depth) => {
+            book.apply_depth(depth);
+        }
+        // The recorded book was `L1_MBP`, so the ticks apply as they did live
+        BookEvent::Quote(quote) => {
+            let _ = book.update_quote_tick(quote);
+        }
+        BookEvent::Trade(trade) => {
+            let _ = book.update_trade_tick(trade);
+        }
+    }
+}
+
+/// Returns the minimal set of deltas which transforms the `from` book into the `to` book.
+///
+/// Deletes are ordered first, then updates, then adds, with the last delta flagged `F_LAST`.
+/// If the `to` book is empty then a single `Clear` delta is returned. The deltas carry the
+/// sequence and timestamp of the `to` book.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the books have different instrument IDs or book types.
+#[must_use]
+pub fn book_diff(from: &OrderBook, to: &OrderBook) -> Vec<OrderBookDelta> {
+    check_equal(
+        from.instrument_id,
+        to.instrument_id,
+        "from.instrument_id",
+        "to.instrument_id",
+    )
+    .expect(FAILED);
+    check_equal(
+        from.book_type,
+        to.book_type,
+        "from.book_type",
+        "to.book_type",
+    )
+    .expect(FAILED);
+
+    let is_from_empty = from.bids.levels.is_empty() && from.asks.levels.is_empty();
+    let is_to_empty = to.bids.levels.is_empty() && to.asks.levels.is_empty();
+    if is_to_empty {
+        if is_from_empty {
+            return Vec::new();
+        }
+        return vec![OrderBookDelta::clear(
+            to.instrument_id,
+            to.sequence,
+            to.ts_last,
+            to.ts_last,
+        )];
+    }
+
+    let mut changes: Vec<(BookAction, BookOrder)> = Vec::new();
+    for action in [BookAction::Delete, BookAction::Update, BookAction::Add] {
+        diff_ladder(&from.bids, &to.bids, action, &mut changes);
+        diff_ladder(&from.asks, &to.asks, action, &mut changes);
+    }
+
+    let last_index = changes.len().saturating_sub(1);
+    changes
+        .into_iter()
+        .enumerate()
+        .map(|(i, (action, order))| {
+            let flags = if i == last_index {
+                RecordFlag::F_LAST as u8
+            } else {
+                0
+            };
+            OrderBookDelta::new(
+                to.instrument_id,
+                action,
+                order,
+                flags,
+                to.sequence,
+                to.ts_last,
+                to.ts_last,
+            )
+        })
+        .collect()
+}
+
+fn diff_ladder(
+    from: &Ladder,
+    to: &Ladder,
+    action: BookAction,
+    changes: &mut Vec<(BookAction, BookOrder)>,
+) {
+    match action {
+        BookAction::Delete => {
+            for order in ladder_orders(from) {
+                if !to.cache.contains_key(&order.order_id) {
+                    changes.push((BookAction::Delete, *order));
+                }
+            }
+        }
+        BookAction::Update | BookAction::Add => {
+            for order in ladder_orders(to) {
+                let change = match find_order(from, order.order_id) {
+                    None => BookAction::Add,
+                    Some(existing)
+                        if existing.price != order.price || existing.size != order.size =>
+                    {
+                        BookAction::Update
+                    }
+                    Some(_) => continue,
+                };
+                if change == action {
+                    changes.push((action, *order));
+                }
+            }
+        }
+        BookAction::Clear => {}
+    }
+}
+
+fn ladder_orders(ladder: &Ladder) -> impl Iterator<Item = &BookOrder> {
+    ladder
+        .levels
+        .values()
+        .flat_map(|level| level.orders.values())
+}
+
+fn find_order(ladder: &Ladder, order_id: u64) -> Option<&BookOrder> {
+    let price = ladder.cache.get(&order_id)?;
+    ladder.levels.get(price)?.orders.get(&order_id)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::{
+            depth::OrderBookDepth10,
+            stubs::{quote_ethusdt_binance, stub_depth10},
+        },
+        enums::BookType,
+        identifiers::InstrumentId,
+        orderbook::{analysis::BookSnapshot, book::BookIntegrityPolicy},
+        types::quantity::Quantity,
+    };
+
+    fn delta(
+        action: BookAction,
+        side: OrderSide,
+        price: &str,
+        size: &str,
+        order_id: u64,
+        sequence: u64,
+    ) -> OrderBookDelta {
+        OrderBookDelta::new(
+            InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            action,
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id),
+            0,
+            sequence,
+            sequence.into(),
+            sequence.into(),
+        )
+    }
+
+    fn deltas() -> Vec<OrderBookDelta> {
+        vec![
+            delta(BookAction::Add, OrderSide::Buy, "100.0", "1.0", 1, 1),
+            delta(BookAction::Add, OrderSide::Sell, "101.0", "2.0", 2, 2),
+            delta(BookAction::Add, OrderSide::Buy, "99.0", "3.0", 3, 3),
+            delta(BookAction::Update, OrderSide::Buy, "100.0", "4.0", 1, 4),
+            delta(BookAction::Add, OrderSide::Sell, "102.0", "5.0", 4, 5),
+            delta(BookAction::Delete, OrderSide::Buy, "99.0", "3.0", 3, 6),
+            delta(BookAction::Update, OrderSide::Sell, "100.5", "2.0", 2, 7),
+            delta(BookAction::Add, OrderSide::Buy, "100.0", "1.5", 5, 8),
+        ]
+    }
+
+    fn full_snapshot(book: &OrderBook) -> BookSnapshot {
+        book.snapshot(usize::MAX)
+    }
+
+    fn book(book_type: BookType) -> OrderBook {
+        OrderBook::new(InstrumentId::from("ETHUSDT-PERP.BINANCE"), book_type)
+    }
+
+    #[rstest]
+    fn test_recording_off_by_default() {
+        let mut book = book(BookType::L3_MBO);
+        book.apply_delta(&deltas()[0]).unwrap();
+
+        assert!(!book.is_recording());
+        assert!(book.recorder().is_none());
+        assert!(book.snapshot_at(1).is_none());
+    }
+
+    #[rstest]
+    #[case(BookType::L2_MBP)]
+    #[case(BookType::L3_MBO)]
+    fn test_replay_equivalence(#[case] book_type: BookType) {
+        let mut book = book(book_type);
+        book.start_recording(100);
+
+        let mut expected = vec![full_snapshot(&book)];
+        for delta in deltas() {
+            book.apply_delta(&delta).unwrap();
+            expected.push(full_snapshot(&book));
+        }
+
+        for (sequence, snapshot) in expected.iter().enumerate() {
+            let replayed = book.snapshot_at(sequence as u64).unwrap();
+            assert_eq!(&full_snapshot(&replayed), snapshot, "sequence {sequence}");
+        }
+    }
+
+    #[rstest]
+    fn test_recording_is_bounded() {
+        let mut book = book(BookType::L3_MBO);
+        book.start_recording(3);
+
+        let mut expected = vec![full_snapshot(&book)];
+        for delta in deltas() {
+            book.apply_delta(&delta).unwrap();
+            expected.push(full_snapshot(&book));
+        }
+
+        let recorder = book.recorder().unwrap();
+        assert_eq!(recorder.len(), 3);
+        assert_eq!(recorder.base_sequence(), 5);
+        assert!(book.snapshot_at(4).is_none());
+        for sequence in 5..=8 {
+            let replayed = book.snapshot_at(sequence).unwrap();
+            assert_eq!(full_snapshot(&replayed), expected[sequence as usize]);
+        }
+    }
+
+    #[rstest]
+    fn test_apply_depth_is_recorded(stub_depth10: OrderBookDepth10) {
+        let mut book = book(BookType::L2_MBP);
+        book.start_recording(10);
+        book.apply_delta(&deltas()[0]).unwrap();
+        let expected = full_snapshot(&book);
+        let depth = OrderBookDepth10 {
+            sequence: 2,
+            ..stub_depth10
+        };
+
+        book.apply_depth(&depth);
+
+        let recorder = book.recorder().unwrap();
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(recorder.base_sequence(), 0);
+        assert_eq!(full_snapshot(&book.snapshot_at(1).unwrap()), expected);
+        assert_eq!(
+            full_snapshot(&book.snapshot_at(2).unwrap()),
+            full_snapshot(&book)
+        );
+    }
+
+    #[rstest]
+    fn test_direct_mutations_are_recorded() {
+        let mut book = book(BookType::L3_MBO);
+        book.start_recording(100);
+
+        let order = |side, price, size, order_id| {
+            BookOrder::new(side, Price::from(price), Quantity::from(size), order_id)
+        };
+        let mut expected = vec![full_snapshot(&book)];
+        book.add(order(OrderSide::Buy, "100.0", "1.0", 1), 0, 1, 1.into());
+        expected.push(full_snapshot(&book));
+        book.add(order(OrderSide::Sell, "101.0", "2.0", 2), 0, 2, 2.into());
+        expected.push(full_snapshot(&book));
+        book.update(order(OrderSide::Buy, "99.0", "3.0", 1), 0, 3, 3.into());
+        expected.push(full_snapshot(&book));
+        book.add(order(OrderSide::Buy, "98.0", "1.0", 3), 0, 4, 4.into());
+        expected.push(full_snapshot(&book));
+        book.delete(order(OrderSide::Buy, "98.0", "1.0", 3), 0, 5, 5.into());
+        expected.push(full_snapshot(&book));
+        book.clear_asks(6, 6.into());
+        expected.push(full_snapshot(&book));
+        book.clear(7, 7.into());
+        expected.push(full_snapshot(&book));
+
+        assert_eq!(book.recorder().unwrap().len(), 7);
+        for (sequence, snapshot) in expected.iter().enumerate() {
+            let replayed = book.snapshot_at(sequence as u64).unwrap();
+            assert_eq!(&full_snapshot(&replayed), snapshot, "sequence {sequence}");
+        }
+    }
+
+    #[rstest]
+    fn test_dropped_crossed_levels_are_recorded() {
+        let mut book = book(BookType::L3_MBO);
+        book.integrity_policy = BookIntegrityPolicy::DropCrossedLevels;
+        book.start_recording(100);
+
+        let deltas = [
+            delta(BookAction::Add, OrderSide::Sell, "101.0", "1.0", 1, 1),
+            delta(BookAction::Add, OrderSide::Sell, "102.0", "1.0", 2, 2),
+            delta(BookAction::Add, OrderSide::Buy, "101.5", "1.0", 3, 3),
+        ];
+        for delta in &deltas {
+            book.apply_delta(delta).unwrap();
+        }
+
+        assert_eq!(book.recorder().unwrap().len(), 4);
+        // Replay bypasses the integrity policy, so the drop must come from the recording
+        book.integrity_policy = BookIntegrityPolicy::Ignore;
+        let replayed = book.snapshot_at(3).unwrap();
+        assert_eq!(full_snapshot(&replayed), full_snapshot(&book));
+        assert_eq!(replayed.best_ask_price(), Some(Price::from("102.0")));
+    }
+
+    #[rstest]
+    fn test_quotes_are_recorded(quote_ethusdt_binance: QuoteTick) {
+        let mut book = book(BookType::L1_MBP);
+        book.start_recording(10);
+        book.update_quote_tick(&quote_ethusdt_binance).unwrap();
+        let expected = full_snapshot(&book);
+
+        book.apply_delta(&OrderBookDelta::clear(
+            book.instrument_id,
+            1,
+            1.into(),
+            1.into(),
+        ))
+        .unwrap();
+
+        let recorder = book.recorder().unwrap();
+        assert_eq!(recorder.len(), 2);
+        assert_eq!(full_snapshot(&book.snapshot_at(0).unwrap()), expected);
+        assert!(!book.snapshot_at(1).unwrap().has_bid());
+    }
+
+    #[rstest]
+    fn test_stop_recording() {
+        let mut book = book(BookType::L3_MBO);
+        book.start_recording(10);
+        book.stop_recording();
+
+        assert!(!book.is_recording());
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_start_recording_with_zero_capacity() {
+        let mut book = book(BookType::L3_MBO);
+        book.start_recording(0);
+    }
+
+    #[rstest]
+    #[case(BookType::L2_MBP)]
+    #[case(BookType::L3_MBO)]
+    fn test_diff_then_apply_yields_equality(#[case] book_type: BookType) {
+        let mut from = book(book_type);
+        let mut to = book(book_type);
+        let deltas = deltas();
+        for delta in &deltas[..4] {
+            from.apply_delta(delta).unwrap();
+        }
+        for delta in &deltas {
+            to.apply_delta(delta).unwrap();
+        }
+
+        let diff = from.diff(&to);
+        for delta in &diff {
+            from.apply_delta(delta).unwrap();
+        }
+
+        assert!(!diff.is_empty());
+        assert_eq!(full_snapshot(&from), full_snapshot(&to));
+        assert_eq!(diff.last().unwrap().flags, RecordFlag::F_LAST as u8);
+    }
+
+    #[rstest]
+    fn test_diff_is_minimal() {
+        let mut from = book(BookType::L2_MBP);
+        for delta in &deltas()[..3] {
+            from.apply_delta(delta).unwrap();
+        }
+        let mut to = from.clone();
+        to.apply_delta(&delta(
+            BookAction::Update,
+            OrderSide::Buy,
+            "99.0",
+            "7.0",
+            0,
+            4,
+        ))
+        .unwrap();
+
+        let diff = from.diff(&to);
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].action, BookAction::Update);
+        assert_eq!(diff[0].order.size, Quantity::from("7.0"));
+        assert_eq!(diff[0].sequence, 4);
+    }
+
+    #[rstest]
+    fn test_diff_of_equal_books_is_empty() {
+        let mut book = book(BookType::L3_MBO);
+        for delta in deltas() {
+            book.apply_delta(&delta).unwrap();
+        }
+
+        assert!(book.diff(&book.clone()).is_empty());
+    }
+
+    #[rstest]
+    fn test_diff_to_empty_book_is_clear() {
+        let mut from = book(BookType::L3_MBO);
+        for delta in deltas() {
+            from.apply_delta(&delta).unwrap();
+        }
+        let to = book(BookType::L3_MBO);
+
+        let diff = from.diff(&to);
+        for delta in &diff {
+            from.apply_delta(delta).unwrap();
+        }
+
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].action, BookAction::Clear);
+        assert!(!from.has_bid());
+        assert!(!from.has_ask());
+    }
+
+    #[rstest]
+    #[should_panic]
+    fn test_diff_with_different_instruments() {
+        let from = book(BookType::L2_MBP);
+        let to = OrderBook::new(InstrumentId::from("BTCUSDT-PERP.BINANCE"), BookType::L2_MBP);
+
+        let _ = from.diff(&to);
+    }
+}
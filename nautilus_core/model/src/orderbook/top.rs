@@ -0,0 +1,290 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Top-of-book (best bid and ask) state and change tracking.
+
+use super::{ladder::Ladder, level::Level};
+use crate::types::{price::Price, quantity::Quantity};
+
+/// Represents the best bid and ask prices and sizes of an order book.
+///
+/// The sizes are the total size of the top level on each side.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookTop {
+    /// The best bid price (if the bid side is not empty).
+    pub bid_price: Option<Price>,
+    /// The total size at the best bid price (if the bid side is not empty).
+    pub bid_size: Option<Quantity>,
+    /// The best ask price (if the ask side is not empty).
+    pub ask_price: Option<Price>,
+    /// The total size at the best ask price (if the ask side is not empty).
+    pub ask_size: Option<Quantity>,
+}
+
+impl BookTop {
+    pub(crate) fn from_ladders(bids: &Ladder, asks: &Ladder) -> Self {
+        let (bid_price, bid_size) = level_price_size(bids.top());
+        let (ask_price, ask_size) = level_price_size(asks.top());
+        Self {
+            bid_price,
+            bid_size,
+            ask_price,
+            ask_size,
+        }
+    }
+}
+
+fn level_price_size(level: Option<&Level>) -> (Option<Price>, Option<Quantity>) {
+    match level.and_then(|level| level.first().map(|order| (level, order))) {
+        Some((level, order)) => (
+            Some(level.price.value),
+            Some(Quantity::from_raw(level.size_raw(), order.size.precision)),
+        ),
+        None => (None, None),
+    }
+}
+
+/// Represents the change to the top of an order book from applying data to it.
+///
+/// Callers interested only in best bid and ask updates can check [`BookTopChange::is_changed`]
+/// and skip any further processing when deeper levels were the only ones affected.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BookTopChange {
+    /// The top of book before the data was applied.
+    pub old: BookTop,
+    /// The top of book after the data was applied.
+    pub new: BookTop,
+}
+
+impl BookTopChange {
+    /// Creates a new [`BookTopChange`] instance.
+    #[must_use]
+    pub const fn new(old: BookTop, new: BookTop) -> Self {
+        Self { old, new }
+    }
+
+    /// Returns whether the best bid price changed.
+    #[must_use]
+    pub fn bid_price_changed(&self) -> bool {
+        self.old.bid_price != self.new.bid_price
+    }
+
+    /// Returns whether the total size at the best bid changed.
+    #[must_use]
+    pub fn bid_size_changed(&self) -> bool {
+        self.old.bid_size != self.new.bid_size
+    }
+
+    /// Returns whether the best ask price changed.
+    #[must_use]
+    pub fn ask_price_changed(&self) -> bool {
+        self.old.ask_price != self.new.ask_price
+    }
+
+    /// Returns whether the total size at the best ask changed.
+    #[must_use]
+    pub fn ask_size_changed(&self) -> bool {
+        self.old.ask_size != self.new.ask_size
+    }
+
+    /// Returns whether the best bid price or size changed.
+    #[must_use]
+    pub fn bid_changed(&self) -> bool {
+        self.bid_price_changed() || self.bid_size_changed()
+    }
+
+    /// Returns whether the best ask price or size changed.
+    #[must_use]
+    pub fn ask_changed(&self) -> bool {
+        self.ask_price_changed() || self.ask_size_changed()
+    }
+
+    /// Returns whether any of the best bid or ask prices or sizes changed.
+    #[must_use]
+    pub fn is_changed(&self) -> bool {
+        self.old != self.new
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        data::{
+            delta::OrderBookDelta, deltas::OrderBookDeltas, depth::OrderBookDepth10,
+            order::BookOrder, stubs::stub_depth10,
+        },
+        enums::{BookAction, BookType, OrderSide},
+        identifiers::InstrumentId,
+        orderbook::book::OrderBook,
+    };
+
+    fn l2_delta(action: BookAction, side: OrderSide, price: &str, size: &str) -> OrderBookDelta {
+        let order = BookOrder::new(side, Price::from(price), Quantity::from(size), 0);
+        OrderBookDelta::new(
+            InstrumentId::from("AAPL.XNAS"),
+            action,
+            order,
+            0,
+            1,
+            1.into(),
+            2.into(),
+        )
+    }
+
+    fn l2_book() -> OrderBook {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        for (side, price) in [
+            (OrderSide::Buy, "100.00"),
+            (OrderSide::Buy, "99.00"),
+            (OrderSide::Sell, "101.00"),
+            (OrderSide::Sell, "102.00"),
+        ] {
+            book.apply_delta(&l2_delta(BookAction::Add, side, price, "1.0"))
+                .unwrap();
+        }
+        book
+    }
+
+    #[rstest]
+    fn test_book_top_empty_book() {
+        let book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        assert_eq!(book.top(), BookTop::default());
+    }
+
+    #[rstest]
+    fn test_apply_delta_to_empty_book_reports_change() {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let change = book
+            .apply_delta(&l2_delta(BookAction::Add, OrderSide::Buy, "100.00", "1.0"))
+            .unwrap();
+
+        assert!(change.is_changed());
+        assert!(change.bid_price_changed());
+        assert!(change.bid_size_changed());
+        assert!(!change.ask_changed());
+        assert_eq!(change.old, BookTop::default());
+        assert_eq!(change.new.bid_price, Some(Price::from("100.00")));
+        assert_eq!(change.new.bid_size, Some(Quantity::from("1.0")));
+    }
+
+    #[rstest]
+    #[case(BookAction::Add, OrderSide::Buy, "98.00", "1.0")]
+    #[case(BookAction::Update, OrderSide::Buy, "99.00", "3.0")]
+    #[case(BookAction::Delete, OrderSide::Buy, "99.00", "0.0")]
+    #[case(BookAction::Add, OrderSide::Sell, "103.00", "1.0")]
+    #[case(BookAction::Update, OrderSide::Sell, "102.00", "3.0")]
+    #[case(BookAction::Delete, OrderSide::Sell, "102.00", "0.0")]
+    fn test_apply_delta_deep_level_reports_no_change(
+        #[case] action: BookAction,
+        #[case] side: OrderSide,
+        #[case] price: &str,
+        #[case] size: &str,
+    ) {
+        let mut book = l2_book();
+        let change = book
+            .apply_delta(&l2_delta(action, side, price, size))
+            .unwrap();
+
+        assert!(!change.is_changed());
+        assert_eq!(change.old, change.new);
+    }
+
+    #[rstest]
+    fn test_apply_delta_top_level_size_update_reports_size_change() {
+        let mut book = l2_book();
+        let change = book
+            .apply_delta(&l2_delta(
+                BookAction::Update,
+                OrderSide::Buy,
+                "100.00",
+                "2.0",
+            ))
+            .unwrap();
+
+        assert!(change.bid_changed());
+        assert!(!change.bid_price_changed());
+        assert!(change.bid_size_changed());
+        assert!(!change.ask_changed());
+        assert_eq!(change.old.bid_size, Some(Quantity::from("1.0")));
+        assert_eq!(change.new.bid_size, Some(Quantity::from("2.0")));
+    }
+
+    #[rstest]
+    fn test_apply_delta_new_best_price_reports_price_change() {
+        let mut book = l2_book();
+        let change = book
+            .apply_delta(&l2_delta(BookAction::Add, OrderSide::Sell, "100.50", "1.0"))
+            .unwrap();
+
+        assert!(change.ask_price_changed());
+        assert!(!change.ask_size_changed());
+        assert!(!change.bid_changed());
+        assert_eq!(change.old.ask_price, Some(Price::from("101.00")));
+        assert_eq!(change.new.ask_price, Some(Price::from("100.50")));
+    }
+
+    #[rstest]
+    fn test_apply_delta_top_level_delete_reports_price_change() {
+        let mut book = l2_book();
+        let change = book
+            .apply_delta(&l2_delta(
+                BookAction::Delete,
+                OrderSide::Buy,
+                "100.00",
+                "0.0",
+            ))
+            .unwrap();
+
+        assert!(change.bid_price_changed());
+        assert_eq!(change.new.bid_price, Some(Price::from("99.00")));
+    }
+
+    #[rstest]
+    fn test_apply_deltas_reports_net_change() {
+        let mut book = l2_book();
+        let deltas = OrderBookDeltas::new(
+            InstrumentId::from("AAPL.XNAS"),
+            vec![
+                l2_delta(BookAction::Update, OrderSide::Buy, "100.00", "5.0"),
+                l2_delta(BookAction::Update, OrderSide::Buy, "100.00", "1.0"),
+                l2_delta(BookAction::Add, OrderSide::Buy, "97.00", "1.0"),
+            ],
+        );
+        let change = book.apply_deltas(&deltas).unwrap();
+
+        assert!(!change.is_changed());
+    }
+
+    #[rstest]
+    fn test_apply_depth_reports_change(stub_depth10: OrderBookDepth10) {
+        let mut book = OrderBook::new(InstrumentId::from("AAPL.XNAS"), BookType::L2_MBP);
+        let change = book.apply_depth(&stub_depth10);
+
+        assert!(change.bid_changed());
+        assert!(change.ask_changed());
+        assert_eq!(change.new.bid_price, Some(Price::from("99.00")));
+        assert_eq!(change.new.ask_price, Some(Price::from("100.00")));
+
+        let change = book.apply_depth(&stub_depth10);
+
+        assert!(!change.is_changed());
+    }
+}
@@ -37,8 +37,8 @@ use crate::{
     },
     events::order::OrderEventAny,
     identifiers::{
-        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TraderId,
-        VenueOrderId,
+        AccountId, ClientOrderId, ExecAlgorithmId, InstrumentId, PositionId, StrategyId, TradeId,
+        TraderId, VenueOrderId,
     },
     types::{price::Price, quantity::Quantity},
 };
@@ -381,6 +381,36 @@ impl OrderAny {
         }
     }
 
+    #[must_use]
+    pub fn avg_px(&self) -> Option<f64> {
+        match self {
+            Self::Limit(order) => order.avg_px(),
+            Self::LimitIfTouched(order) => order.avg_px(),
+            Self::Market(order) => order.avg_px(),
+            Self::MarketIfTouched(order) => order.avg_px(),
+            Self::MarketToLimit(order) => order.avg_px(),
+            Self::StopLimit(order) => order.avg_px(),
+            Self::StopMarket(order) => order.avg_px(),
+            Self::TrailingStopLimit(order) => order.avg_px(),
+            Self::TrailingStopMarket(order) => order.avg_px(),
+        }
+    }
+
+    #[must_use]
+    pub fn trade_ids(&self) -> Vec<&TradeId> {
+        match self {
+            Self::Limit(order) => order.trade_ids(),
+            Self::LimitIfTouched(order) => order.trade_ids(),
+            Self::Market(order) => order.trade_ids(),
+            Self::MarketIfTouched(order) => order.trade_ids(),
+            Self::MarketToLimit(order) => order.trade_ids(),
+            Self::StopLimit(order) => order.trade_ids(),
+            Self::StopMarket(order) => order.trade_ids(),
+            Self::TrailingStopLimit(order) => order.trade_ids(),
+            Self::TrailingStopMarket(order) => order.trade_ids(),
+        }
+    }
+
     #[must_use]
     pub fn order_side_specified(&self) -> OrderSideSpecified {
         match self {
@@ -59,7 +59,7 @@ const LOCAL_ACTIVE_ORDER_STATUS: &[OrderStatus] = &[
     OrderStatus::Released,
 ];
 
-#[derive(thiserror::Error, Debug)]
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
 pub enum OrderError {
     #[error("Order not found: {0}")]
     NotFound(ClientOrderId),
@@ -73,6 +73,10 @@ pub enum OrderError {
     AlreadyInitialized,
     #[error("Order had no previous state")]
     NoPreviousState,
+    #[error("Order event was for a different order, expected {0} was {1}")]
+    MismatchedOrder(ClientOrderId, ClientOrderId),
+    #[error("Order fill was a duplicate, trade ID {0} already applied")]
+    DuplicateFill(TradeId),
 }
 
 #[must_use]
@@ -90,76 +94,115 @@ pub fn str_hashmap_to_ustr(h: HashMap<String, String>) -> HashMap<Ustr, Ustr> {
 }
 
 impl OrderStatus {
-    #[rustfmt::skip]
+    /// Transitions the order status in response to the given `event`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the event does not change the order status, or the transition is not a legal
+    ///   order lifecycle edge (see [`order_status_valid_transition`]).
     pub fn transition(&mut self, event: &OrderEventAny) -> Result<Self, OrderError> {
-        let new_state = match (self, event) {
-            (Self::Initialized, OrderEventAny::Denied(_)) => Self::Denied,
-            (Self::Initialized, OrderEventAny::Emulated(_)) => Self::Emulated,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Initialized, OrderEventAny::Submitted(_)) => Self::Submitted,
-            (Self::Initialized, OrderEventAny::Rejected(_)) => Self::Rejected,  // External orders
-            (Self::Initialized, OrderEventAny::Accepted(_)) => Self::Accepted,  // External orders
-            (Self::Initialized, OrderEventAny::Canceled(_)) => Self::Canceled,  // External orders
-            (Self::Initialized, OrderEventAny::Expired(_)) => Self::Expired,  // External orders
-            (Self::Initialized, OrderEventAny::Triggered(_)) => Self::Triggered, // External orders
-            (Self::Emulated, OrderEventAny::Canceled(_)) => Self::Canceled,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Expired(_)) => Self::Expired,  // Emulated orders
-            (Self::Emulated, OrderEventAny::Released(_)) => Self::Released,  // Emulated orders
-            (Self::Released, OrderEventAny::Submitted(_)) => Self::Submitted,  // Emulated orders
-            (Self::Released, OrderEventAny::Denied(_)) => Self::Denied,  // Emulated orders
-            (Self::Released, OrderEventAny::Canceled(_)) => Self::Canceled,  // Execution algo
-            (Self::Submitted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Submitted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Submitted, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Submitted, OrderEventAny::Canceled(_)) => Self::Canceled,  // FOK and IOC cases
-            (Self::Submitted, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::Submitted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Submitted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Accepted, OrderEventAny::Rejected(_)) => Self::Rejected,  // StopLimit order
-            (Self::Accepted, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Accepted, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Accepted, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Accepted, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::Accepted, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Accepted, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Accepted, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Canceled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,  // Real world possibility
-            (Self::Canceled, OrderEventAny::Filled(_)) => Self::Filled,  // Real world possibility
-            (Self::PendingUpdate, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingUpdate, OrderEventAny::Accepted(_)) => Self::Accepted,
-            (Self::PendingUpdate, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingUpdate, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingUpdate, OrderEventAny::Triggered(_)) => Self::Triggered,
-            (Self::PendingUpdate, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,  // Allow multiple requests
-            (Self::PendingUpdate, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PendingUpdate, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingUpdate, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PendingCancel, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::PendingCancel, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,  // Allow multiple requests
-            (Self::PendingCancel, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PendingCancel, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PendingCancel, OrderEventAny::Accepted(_)) => Self::Accepted,  // Allow failed cancel requests
-            (Self::PendingCancel, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PendingCancel, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::Triggered, OrderEventAny::Rejected(_)) => Self::Rejected,
-            (Self::Triggered, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::Triggered, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::Triggered, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::Triggered, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::Triggered, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::Triggered, OrderEventAny::Filled(_)) => Self::Filled,
-            (Self::PartiallyFilled, OrderEventAny::PendingUpdate(_)) => Self::PendingUpdate,
-            (Self::PartiallyFilled, OrderEventAny::PendingCancel(_)) => Self::PendingCancel,
-            (Self::PartiallyFilled, OrderEventAny::Canceled(_)) => Self::Canceled,
-            (Self::PartiallyFilled, OrderEventAny::Expired(_)) => Self::Expired,
-            (Self::PartiallyFilled, OrderEventAny::PartiallyFilled(_)) => Self::PartiallyFilled,
-            (Self::PartiallyFilled, OrderEventAny::Filled(_)) => Self::Filled,
+        let new_state = match event {
+            OrderEventAny::Denied(_) => Self::Denied,
+            OrderEventAny::Emulated(_) => Self::Emulated,
+            OrderEventAny::Released(_) => Self::Released,
+            OrderEventAny::Submitted(_) => Self::Submitted,
+            OrderEventAny::Accepted(_) => Self::Accepted,
+            OrderEventAny::Rejected(_) => Self::Rejected,
+            OrderEventAny::Canceled(_) => Self::Canceled,
+            OrderEventAny::Expired(_) => Self::Expired,
+            OrderEventAny::Triggered(_) => Self::Triggered,
+            OrderEventAny::PendingUpdate(_) => Self::PendingUpdate,
+            OrderEventAny::PendingCancel(_) => Self::PendingCancel,
+            OrderEventAny::PartiallyFilled(_) => Self::PartiallyFilled,
+            OrderEventAny::Filled(_) => Self::Filled,
             _ => return Err(OrderError::InvalidStateTransition),
         };
-        Ok(new_state)
+
+        if order_status_valid_transition(*self, new_state) {
+            Ok(new_state)
+        } else {
+            Err(OrderError::InvalidStateTransition)
+        }
     }
 }
 
+/// Returns whether `from` -> `to` is a legal edge of the order lifecycle.
+///
+/// The legal edges are:
+/// - `Initialized` -> `Denied`, `Emulated`, `Released`, `Submitted`, and (for external orders)
+///   `Rejected`, `Accepted`, `Canceled`, `Expired`, `Triggered`.
+/// - `Emulated` -> `Canceled`, `Expired`, `Released`.
+/// - `Released` -> `Submitted`, `Denied`, `Canceled` (execution algorithms).
+/// - `Submitted` -> `PendingUpdate`, `PendingCancel`, `Rejected`, `Canceled` (FOK and IOC),
+///   `Accepted`, `PartiallyFilled`, `Filled`.
+/// - `Accepted` -> `Rejected` (stop-limit), `PendingUpdate`, `PendingCancel`, `Canceled`,
+///   `Triggered`, `Expired`, `PartiallyFilled`, `Filled`.
+/// - `Canceled` -> `PartiallyFilled`, `Filled` (fills racing a cancel at the venue).
+/// - `PendingUpdate` -> `Rejected`, `Accepted`, `Canceled`, `Expired`, `Triggered`,
+///   `PendingUpdate`, `PendingCancel`, `PartiallyFilled`, `Filled`.
+/// - `PendingCancel` -> `Rejected`, `PendingCancel`, `Canceled`, `Expired`, `Accepted`
+///   (failed cancel requests), `PartiallyFilled`, `Filled`.
+/// - `Triggered` -> `Rejected`, `PendingUpdate`, `PendingCancel`, `Canceled`, `Expired`,
+///   `PartiallyFilled`, `Filled`.
+/// - `PartiallyFilled` -> `PendingUpdate`, `PendingCancel`, `Canceled`, `Expired`,
+///   `PartiallyFilled`, `Filled`.
+///
+/// `Denied`, `Rejected`, `Expired` and `Filled` are terminal, so no edges leave them.
+#[must_use]
+#[rustfmt::skip]
+pub const fn order_status_valid_transition(from: OrderStatus, to: OrderStatus) -> bool {
+    use OrderStatus::{
+        Accepted, Canceled, Denied, Emulated, Expired, Filled, Initialized, PartiallyFilled,
+        PendingCancel, PendingUpdate, Rejected, Released, Submitted, Triggered,
+    };
+
+    matches!(
+        (from, to),
+        (Initialized, Denied | Emulated | Released | Submitted)
+            | (Initialized, Rejected | Accepted | Canceled | Expired | Triggered)  // External orders
+            | (Emulated, Canceled | Expired | Released)  // Emulated orders
+            | (Released, Submitted | Denied)  // Emulated orders
+            | (Released, Canceled)  // Execution algo
+            | (Submitted, PendingUpdate | PendingCancel | Rejected | Accepted)
+            | (Submitted, Canceled)  // FOK and IOC cases
+            | (Submitted, PartiallyFilled | Filled)
+            | (Accepted, Rejected)  // StopLimit order
+            | (Accepted, PendingUpdate | PendingCancel | Canceled | Triggered | Expired)
+            | (Accepted, PartiallyFilled | Filled)
+            | (Canceled, PartiallyFilled | Filled)  // Real world possibility
+            | (PendingUpdate, Rejected | Accepted | Canceled | Expired | Triggered)
+            | (PendingUpdate, PendingUpdate)  // Allow multiple requests
+            | (PendingUpdate, PendingCancel | PartiallyFilled | Filled)
+            | (PendingCancel, Rejected | Canceled | Expired)
+            | (PendingCancel, PendingCancel)  // Allow multiple requests
+            | (PendingCancel, Accepted)  // Allow failed cancel requests
+            | (PendingCancel, PartiallyFilled | Filled)
+            | (Triggered, Rejected | PendingUpdate | PendingCancel | Canceled | Expired)
+            | (Triggered, PartiallyFilled | Filled)
+            | (PartiallyFilled, PendingUpdate | PendingCancel | Canceled | Expired)
+            | (PartiallyFilled, PartiallyFilled | Filled)
+    )
+}
+
+/// Returns whether the `trigger` type is compatible with the `order_type`.
+///
+/// Conditional orders must have a trigger type other than `NoTrigger`, and all other
+/// orders must have `NoTrigger`.
+#[must_use]
+pub const fn trigger_compatible(order_type: OrderType, trigger: TriggerType) -> bool {
+    order_type.requires_trigger_type() != matches!(trigger, TriggerType::NoTrigger)
+}
+
+/// Returns whether the trailing `offset` type is compatible with the `order_type`.
+///
+/// Trailing orders (`TrailingStopMarket` and `TrailingStopLimit`) must have a trailing offset
+/// type other than `NoTrailingOffset`, and all other orders must have `NoTrailingOffset`.
+#[must_use]
+pub const fn trailing_offset_compatible(order_type: OrderType, offset: TrailingOffsetType) -> bool {
+    order_type.is_trailing() != matches!(offset, TrailingOffsetType::NoTrailingOffset)
+}
+
 pub trait Order: 'static + Send {
     fn into_any(self) -> OrderAny;
     fn status(&self) -> OrderStatus;
@@ -285,14 +328,7 @@ pub trait Order: 'static + Send {
             }
         }
 
-        matches!(
-            self.status(),
-            OrderStatus::Accepted
-                | OrderStatus::Triggered
-                | OrderStatus::PendingCancel
-                | OrderStatus::PendingUpdate
-                | OrderStatus::PartiallyFilled
-        )
+        self.status().is_active()
     }
 
     fn is_canceled(&self) -> bool {
@@ -485,16 +521,38 @@ impl OrderCore {
         }
     }
 
+    /// Applies the given `event` to the order, updating its status and state.
+    ///
+    /// The event is validated before any state is changed, so a rejected event leaves the
+    /// order as it was.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `event` is for a different order.
+    /// - If the `event` is an `OrderInitialized` (the order is already initialized).
+    /// - If the `event` is a fill with a trade ID which has already been applied.
+    /// - If the resulting status change is not a legal order lifecycle edge.
     pub fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        assert_eq!(self.client_order_id, event.client_order_id());
-        assert_eq!(self.strategy_id, event.strategy_id());
+        if event.client_order_id() != self.client_order_id {
+            return Err(OrderError::MismatchedOrder(
+                self.client_order_id,
+                event.client_order_id(),
+            ));
+        }
 
-        let new_status = self.status.transition(&event)?;
-        self.previous_status = Some(self.status);
+        let new_status = self.next_status(&event)?;
+        // Keep the status from before any pending request, so it can be restored
+        if !matches!(
+            self.status,
+            OrderStatus::PendingUpdate | OrderStatus::PendingCancel
+        ) {
+            self.previous_status = Some(self.status);
+        }
         self.status = new_status;
 
         match &event {
-            OrderEventAny::Initialized(_) => return Err(OrderError::AlreadyInitialized),
+            OrderEventAny::Initialized(_) => unreachable!("rejected by `next_status`"),
             OrderEventAny::Denied(event) => self.denied(event),
             OrderEventAny::Emulated(event) => self.emulated(event),
             OrderEventAny::Released(event) => self.released(event),
@@ -518,6 +576,47 @@ impl OrderCore {
         Ok(())
     }
 
+    /// Returns the status the order would have after applying the `event`, without changing it.
+    ///
+    /// Rejected or completed modify and cancel requests restore the status from before the
+    /// request, and fills resolve to `PartiallyFilled` or `Filled` from the leaves quantity.
+    fn next_status(&self, event: &OrderEventAny) -> Result<OrderStatus, OrderError> {
+        let restore_from = |pending: OrderStatus| {
+            if self.status == pending {
+                self.previous_status.ok_or(OrderError::NoPreviousState)
+            } else {
+                Ok(self.status)
+            }
+        };
+
+        match event {
+            OrderEventAny::Initialized(_) => Err(OrderError::AlreadyInitialized),
+            OrderEventAny::ModifyRejected(_) | OrderEventAny::Updated(_) => {
+                restore_from(OrderStatus::PendingUpdate)
+            }
+            OrderEventAny::CancelRejected(_) => restore_from(OrderStatus::PendingCancel),
+            OrderEventAny::PartiallyFilled(fill) | OrderEventAny::Filled(fill) => {
+                if self.trade_ids.contains(&fill.trade_id) {
+                    return Err(OrderError::DuplicateFill(fill.trade_id));
+                }
+                let new_status = if fill.last_qty < self.leaves_qty {
+                    OrderStatus::PartiallyFilled
+                } else {
+                    OrderStatus::Filled
+                };
+                if order_status_valid_transition(self.status, new_status) {
+                    Ok(new_status)
+                } else {
+                    Err(OrderError::InvalidStateTransition)
+                }
+            }
+            _ => {
+                let mut status = self.status;
+                status.transition(event)
+            }
+        }
+    }
+
     fn denied(&self, _event: &OrderDenied) {
         // Do nothing else
     }
@@ -550,16 +649,12 @@ impl OrderCore {
         // Do nothing else
     }
 
-    fn modify_rejected(&mut self, _event: &OrderModifyRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn modify_rejected(&self, _event: &OrderModifyRejected) {
+        // Do nothing else
     }
 
-    fn cancel_rejected(&mut self, _event: &OrderCancelRejected) {
-        self.status = self
-            .previous_status
-            .unwrap_or_else(|| panic!("{}", OrderError::NoPreviousState));
+    fn cancel_rejected(&self, _event: &OrderCancelRejected) {
+        // Do nothing else
     }
 
     fn triggered(&mut self, _event: &OrderTriggered) {}
@@ -585,10 +680,10 @@ impl OrderCore {
         self.trade_ids.push(event.trade_id);
         self.last_trade_id = Some(event.trade_id);
         self.liquidity_side = Some(event.liquidity_side);
+        self.set_avg_px(event.last_qty, event.last_px); // Before the filled quantity is updated
         self.filled_qty += event.last_qty;
         self.leaves_qty -= event.last_qty;
         self.ts_last = event.ts_event;
-        self.set_avg_px(event.last_qty, event.last_px);
     }
 
     fn set_avg_px(&mut self, last_qty: Quantity, last_px: Price) {
@@ -620,11 +715,7 @@ impl OrderCore {
 
     #[must_use]
     pub fn opposite_side(side: OrderSide) -> OrderSide {
-        match side {
-            OrderSide::Buy => OrderSide::Sell,
-            OrderSide::Sell => OrderSide::Buy,
-            OrderSide::NoOrderSide => OrderSide::NoOrderSide,
-        }
+        side.opposite()
     }
 
     #[must_use]
@@ -648,7 +739,7 @@ impl OrderCore {
 
     #[must_use]
     pub fn would_reduce_only(&self, side: PositionSide, position_qty: Quantity) -> bool {
-        if side == PositionSide::Flat {
+        if side.is_flat() {
             return false;
         }
 
@@ -684,17 +775,51 @@ impl OrderCore {
 mod tests {
     use rstest::rstest;
     use rust_decimal_macros::dec;
+    use strum::IntoEnumIterator;
 
     use super::*;
     use crate::{
         enums::{OrderSide, OrderStatus, PositionSide},
         events::order::{
-            accepted::OrderAcceptedBuilder, denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
-            initialized::OrderInitializedBuilder, submitted::OrderSubmittedBuilder,
+            accepted::OrderAcceptedBuilder, canceled::OrderCanceledBuilder,
+            denied::OrderDeniedBuilder, filled::OrderFilledBuilder,
+            initialized::OrderInitializedBuilder, modify_rejected::OrderModifyRejectedBuilder,
+            pending_update::OrderPendingUpdateBuilder, submitted::OrderSubmittedBuilder,
         },
-        orders::market::MarketOrder,
+        orders::{limit::LimitOrder, market::MarketOrder},
     };
 
+    fn accepted_limit_order() -> LimitOrder {
+        let init = OrderInitializedBuilder::default()
+            .order_type(OrderType::Limit)
+            .price(Some(Price::from("1.00000")))
+            .build()
+            .unwrap();
+        let mut order: LimitOrder = init.into();
+        order
+            .apply(OrderEventAny::Submitted(
+                OrderSubmittedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        order
+            .apply(OrderEventAny::Accepted(
+                OrderAcceptedBuilder::default().build().unwrap(),
+            ))
+            .unwrap();
+        order
+    }
+
+    fn fill(trade_id: &str, last_qty: i64, last_px: &str) -> OrderEventAny {
+        OrderEventAny::Filled(
+            OrderFilledBuilder::default()
+                .trade_id(TradeId::from(trade_id))
+                .last_qty(Quantity::from(last_qty))
+                .last_px(Price::from(last_px))
+                .build()
+                .unwrap(),
+        )
+    }
+
     fn test_initialize_market_order() {
         let order = MarketOrder::default();
         assert_eq!(order.events().len(), 1);
@@ -704,6 +829,55 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(OrderStatus::Initialized, OrderStatus::Submitted, true)]
+    #[case(OrderStatus::Initialized, OrderStatus::Accepted, true)]
+    #[case(OrderStatus::Initialized, OrderStatus::Filled, false)]
+    #[case(OrderStatus::Emulated, OrderStatus::Released, true)]
+    #[case(OrderStatus::Emulated, OrderStatus::Submitted, false)]
+    #[case(OrderStatus::Released, OrderStatus::Submitted, true)]
+    #[case(OrderStatus::Submitted, OrderStatus::Accepted, true)]
+    #[case(OrderStatus::Submitted, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Submitted, OrderStatus::Initialized, false)]
+    #[case(OrderStatus::Accepted, OrderStatus::PartiallyFilled, true)]
+    #[case(OrderStatus::Accepted, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Accepted, OrderStatus::Submitted, false)]
+    #[case(OrderStatus::Accepted, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::Filled, true)]
+    #[case(OrderStatus::Canceled, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::PendingUpdate, OrderStatus::PendingUpdate, true)]
+    #[case(OrderStatus::PendingCancel, OrderStatus::Accepted, true)]
+    #[case(OrderStatus::PendingCancel, OrderStatus::PendingUpdate, false)]
+    #[case(OrderStatus::Triggered, OrderStatus::Filled, true)]
+    #[case(OrderStatus::Triggered, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::PartiallyFilled, OrderStatus::PartiallyFilled, true)]
+    #[case(OrderStatus::PartiallyFilled, OrderStatus::Filled, true)]
+    #[case(OrderStatus::PartiallyFilled, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::Filled, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::Filled, OrderStatus::Canceled, false)]
+    #[case(OrderStatus::Denied, OrderStatus::Submitted, false)]
+    #[case(OrderStatus::Rejected, OrderStatus::Accepted, false)]
+    #[case(OrderStatus::Expired, OrderStatus::Canceled, false)]
+    fn test_order_status_valid_transition(
+        #[case] from: OrderStatus,
+        #[case] to: OrderStatus,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(order_status_valid_transition(from, to), expected);
+    }
+
+    #[rstest]
+    fn test_terminal_order_statuses_have_no_transitions() {
+        for from in [
+            OrderStatus::Denied,
+            OrderStatus::Rejected,
+            OrderStatus::Expired,
+            OrderStatus::Filled,
+        ] {
+            assert!(OrderStatus::iter().all(|to| !order_status_valid_transition(from, to)));
+        }
+    }
+
     #[rstest]
     #[case(OrderSide::Buy, OrderSide::Sell)]
     #[case(OrderSide::Sell, OrderSide::Buy)]
@@ -804,4 +978,106 @@ mod tests {
         assert_eq!(order.commission(&Currency::USD()), None);
         assert_eq!(order.commissions(), HashMap::new());
     }
+
+    #[rstest]
+    fn test_order_partial_fills_accumulate() {
+        let mut order = accepted_limit_order();
+
+        order.apply(fill("1", 20_000, "1.00000")).unwrap();
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.filled_qty(), Quantity::from(20_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(80_000));
+
+        order.apply(fill("2", 30_000, "1.00100")).unwrap();
+        order.apply(fill("3", 50_000, "1.00200")).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Filled);
+        assert_eq!(order.filled_qty(), Quantity::from(100_000));
+        assert_eq!(order.leaves_qty(), Quantity::from(0));
+        assert!((order.avg_px().unwrap() - 1.0013).abs() < 1e-9);
+        assert_eq!(order.trade_ids().len(), 3);
+        assert!(order.is_closed());
+    }
+
+    #[rstest]
+    fn test_order_cancel_after_partial_fill() {
+        let mut order = accepted_limit_order();
+        order.apply(fill("1", 40_000, "1.00000")).unwrap();
+
+        let canceled = OrderCanceledBuilder::default().build().unwrap();
+        order.apply(OrderEventAny::Canceled(canceled)).unwrap();
+
+        assert_eq!(order.status(), OrderStatus::Canceled);
+        assert_eq!(order.filled_qty(), Quantity::from(40_000));
+        assert_eq!(order.avg_px(), Some(1.0));
+        assert!(order.is_closed());
+        assert_eq!(order.last_event(), &OrderEventAny::Canceled(canceled));
+    }
+
+    #[rstest]
+    fn test_order_modify_rejected_restores_prior_status() {
+        let mut order = accepted_limit_order();
+        order.apply(fill("1", 40_000, "1.00000")).unwrap();
+
+        let pending_update = OrderPendingUpdateBuilder::default().build().unwrap();
+        order
+            .apply(OrderEventAny::PendingUpdate(pending_update))
+            .unwrap();
+        assert_eq!(order.status(), OrderStatus::PendingUpdate);
+
+        // Repeated requests still restore the status from before the first request
+        order
+            .apply(OrderEventAny::PendingUpdate(pending_update))
+            .unwrap();
+        let modify_rejected = OrderModifyRejectedBuilder::default().build().unwrap();
+        order
+            .apply(OrderEventAny::ModifyRejected(modify_rejected))
+            .unwrap();
+
+        assert_eq!(order.status(), OrderStatus::PartiallyFilled);
+        assert_eq!(order.leaves_qty(), Quantity::from(60_000));
+    }
+
+    #[rstest]
+    fn test_order_out_of_order_event_leaves_state_unchanged() {
+        let mut order: MarketOrder = OrderInitializedBuilder::default().build().unwrap().into();
+
+        let result = order.apply(fill("1", 100_000, "1.00000"));
+
+        assert_eq!(result, Err(OrderError::InvalidStateTransition));
+        assert_eq!(order.status(), OrderStatus::Initialized);
+        assert_eq!(order.filled_qty(), Quantity::from(0));
+        assert_eq!(order.event_count(), 1);
+    }
+
+    #[rstest]
+    fn test_order_duplicate_fill_rejected() {
+        let mut order = accepted_limit_order();
+        order.apply(fill("1", 40_000, "1.00000")).unwrap();
+
+        let result = order.apply(fill("1", 40_000, "1.00000"));
+
+        assert_eq!(result, Err(OrderError::DuplicateFill(TradeId::from("1"))));
+        assert_eq!(order.filled_qty(), Quantity::from(40_000));
+    }
+
+    #[rstest]
+    fn test_order_event_for_different_order_rejected() {
+        let mut order = accepted_limit_order();
+        let canceled = OrderCanceledBuilder::default()
+            .client_order_id(ClientOrderId::from("O-OTHER"))
+            .build()
+            .unwrap();
+
+        let result = order.apply(OrderEventAny::Canceled(canceled));
+
+        assert_eq!(
+            result,
+            Err(OrderError::MismatchedOrder(
+                order.client_order_id,
+                ClientOrderId::from("O-OTHER")
+            ))
+        );
+        assert_eq!(order.status(), OrderStatus::Accepted);
+    }
 }
@@ -360,13 +360,18 @@ impl Order for LimitOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match event {
+            OrderEventAny::Updated(ref event) => Some(*event),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(ref event) = updated {
+            self.update(event);
+        }
+
         if is_order_filled {
             self.core.set_slippage(self.price);
         };
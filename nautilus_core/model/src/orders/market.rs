@@ -377,12 +377,17 @@ impl Order for MarketOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match event {
+            OrderEventAny::Updated(ref event) => Some(*event),
+            _ => None,
         };
 
         self.core.apply(event)?;
 
+        if let Some(ref event) = updated {
+            self.update(event);
+        }
+
         Ok(())
     }
 
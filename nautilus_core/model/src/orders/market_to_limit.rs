@@ -333,13 +333,18 @@ impl Order for MarketToLimitOrder {
     }
 
     fn apply(&mut self, event: OrderEventAny) -> Result<(), OrderError> {
-        if let OrderEventAny::Updated(ref event) = event {
-            self.update(event);
+        let updated = match event {
+            OrderEventAny::Updated(ref event) => Some(*event),
+            _ => None,
         };
         let is_order_filled = matches!(event, OrderEventAny::Filled(_));
 
         self.core.apply(event)?;
 
+        if let Some(ref event) = updated {
+            self.update(event);
+        }
+
         if is_order_filled && self.price.is_some() {
             self.core.set_slippage(self.price.unwrap());
         };
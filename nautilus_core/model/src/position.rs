@@ -39,7 +39,6 @@ use crate::{
 ///
 /// The position ID may be assigned at the trading venue, or can be system
 /// generated depending on a strategies OMS (Order Management System) settings.
-#[repr(C)]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(
     feature = "python",
@@ -134,7 +133,7 @@ impl Position {
             "`fill.trade_id` already contained in `trade_ids"
         );
 
-        if self.side == PositionSide::Flat {
+        if self.side.is_flat() {
             // Reset position
             self.events.clear();
             self.trade_ids.clear();
@@ -240,8 +239,17 @@ impl Position {
             ));
         }
 
+        // A fill larger than the open quantity closes the position and flips it
+        let is_flip = self.signed_qty < 0.0 && last_qty > self.signed_qty.abs();
+
         self.signed_qty += last_qty;
         self.buy_qty += last_qty_object;
+
+        if is_flip {
+            // The remaining quantity opens a long position at the fill price
+            self.avg_px_open = last_px;
+            self.avg_px_close = None;
+        }
     }
 
     pub fn handle_sell_order_fill(&mut self, fill: &OrderFilled) {
@@ -278,8 +286,17 @@ impl Position {
             ));
         }
 
+        // A fill larger than the open quantity closes the position and flips it
+        let is_flip = self.signed_qty > 0.0 && last_qty > self.signed_qty;
+
         self.signed_qty -= last_qty;
         self.sell_qty += last_qty_object;
+
+        if is_flip {
+            // The remaining quantity opens a short position at the fill price
+            self.avg_px_open = last_px;
+            self.avg_px_close = None;
+        }
     }
 
     #[must_use]
@@ -347,7 +364,7 @@ impl Position {
 
     #[must_use]
     pub fn unrealized_pnl(&self, last: Price) -> Money {
-        if self.side == PositionSide::Flat {
+        if self.side.is_flat() {
             Money::new(0.0, self.settlement_currency)
         } else {
             let avg_px_open = self.avg_px_open;
@@ -475,12 +492,12 @@ impl Position {
 
     #[must_use]
     pub fn is_open(&self) -> bool {
-        self.side != PositionSide::Flat && self.ts_closed.is_none()
+        !self.side.is_flat() && self.ts_closed.is_none()
     }
 
     #[must_use]
     pub fn is_closed(&self) -> bool {
-        self.side == PositionSide::Flat && self.ts_closed.is_some()
+        self.side.is_flat() && self.ts_closed.is_some()
     }
 
     #[must_use]
@@ -49,7 +49,7 @@ impl OrderBookDelta {
 
         let action_obj: Bound<'_, PyAny> = obj.getattr("action")?.extract()?;
         let action_u8 = action_obj.getattr("value")?.extract()?;
-        let action = BookAction::from_u8(action_u8).unwrap();
+        let action = BookAction::try_from_u8(action_u8).map_err(to_pyvalue_err)?;
 
         let flags: u8 = obj.getattr("flags")?.extract()?;
         let sequence: u64 = obj.getattr("sequence")?.extract()?;
@@ -62,7 +62,7 @@ impl OrderBookDelta {
         } else {
             let side_obj: Bound<'_, PyAny> = order_pyobject.getattr("side")?.extract()?;
             let side_u8 = side_obj.getattr("value")?.extract()?;
-            let side = OrderSide::from_u8(side_u8).unwrap();
+            let side = OrderSide::try_from_u8(side_u8).map_err(to_pyvalue_err)?;
 
             let price_py: Bound<'_, PyAny> = order_pyobject.getattr("price")?;
             let price_raw: i64 = price_py.getattr("raw")?.extract()?;
@@ -75,12 +75,7 @@ impl OrderBookDelta {
             let size = Quantity::from_raw(size_raw, size_prec);
 
             let order_id: OrderId = order_pyobject.getattr("order_id")?.extract()?;
-            BookOrder {
-                side,
-                price,
-                size,
-                order_id,
-            }
+            BookOrder::new(side, price, size, order_id)
         };
 
         Ok(Self::new(
@@ -296,7 +291,7 @@ mod tests {
 
         Python::with_gil(|py| {
             let dict_string = delta.py_as_dict(py).unwrap().to_string();
-            let expected_string = r"{'type': 'OrderBookDelta', 'instrument_id': 'AAPL.XNAS', 'action': 'ADD', 'order': {'side': 'BUY', 'price': '100.00', 'size': '10', 'order_id': 123456}, 'flags': 0, 'sequence': 1, 'ts_event': 1, 'ts_init': 2}";
+            let expected_string = r"{'type': 'OrderBookDelta', 'instrument_id': 'AAPL.XNAS', 'action': 'ADD', 'order': {'side': 'BUY', 'price': '100.00', 'size': '10', 'order_id': 123456, 'flags': 0}, 'flags': 0, 'sequence': 1, 'ts_event': 1, 'ts_init': 2}";
             assert_eq!(dict_string, expected_string);
         });
     }
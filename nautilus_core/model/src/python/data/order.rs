@@ -34,8 +34,9 @@ use crate::{
 #[pymethods]
 impl BookOrder {
     #[new]
-    fn py_new(side: OrderSide, price: Price, size: Quantity, order_id: OrderId) -> Self {
-        Self::new(side, price, size, order_id)
+    #[pyo3(signature = (side, price, size, order_id, flags=0))]
+    fn py_new(side: OrderSide, price: Price, size: Quantity, order_id: OrderId, flags: u8) -> Self {
+        Self::new(side, price, size, order_id).with_flags(flags)
     }
 
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
@@ -84,6 +85,12 @@ impl BookOrder {
         self.order_id
     }
 
+    #[getter]
+    #[pyo3(name = "flags")]
+    fn py_flags(&self) -> u8 {
+        self.flags
+    }
+
     #[staticmethod]
     #[pyo3(name = "fully_qualified_name")]
     fn py_fully_qualified_name() -> String {
@@ -164,7 +171,7 @@ mod tests {
         Python::with_gil(|py| {
             let dict_string = book_order.py_as_dict(py).unwrap().to_string();
             let expected_string =
-                r"{'side': 'BUY', 'price': '100.00', 'size': '10', 'order_id': 123456}";
+                r"{'side': 'BUY', 'price': '100.00', 'size': '10', 'order_id': 123456, 'flags': 0}";
             assert_eq!(dict_string, expected_string);
         });
     }
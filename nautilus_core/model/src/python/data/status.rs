@@ -43,7 +43,7 @@ impl InstrumentStatus {
 
         let action_obj: Bound<'_, PyAny> = obj.getattr("action")?.extract()?;
         let action_u16: u16 = action_obj.getattr("value")?.extract()?;
-        let action = MarketStatusAction::from_u16(action_u16).unwrap();
+        let action = MarketStatusAction::try_from_u16(action_u16).map_err(to_pyvalue_err)?;
 
         let ts_event: u64 = obj.getattr("ts_event")?.extract()?;
         let ts_init: u64 = obj.getattr("ts_init")?.extract()?;
@@ -59,7 +59,8 @@ impl TradeTick {
 
         let aggressor_side_obj: Bound<'_, PyAny> = obj.getattr("aggressor_side")?.extract()?;
         let aggressor_side_u8 = aggressor_side_obj.getattr("value")?.extract()?;
-        let aggressor_side = AggressorSide::from_u8(aggressor_side_u8).unwrap();
+        let aggressor_side =
+            AggressorSide::try_from_u8(aggressor_side_u8).map_err(to_pyvalue_err)?;
 
         let trade_id_obj: Bound<'_, PyAny> = obj.getattr("trade_id")?.extract()?;
         let trade_id_str: String = trade_id_obj.getattr("value")?.extract()?;
@@ -142,7 +143,8 @@ impl TradeTick {
         self.instrument_id = InstrumentId::from_str(instrument_id_str).map_err(to_pyvalue_err)?;
         self.price = Price::from_raw(price_raw, price_prec);
         self.size = Quantity::from_raw(size_raw, size_prec);
-        self.aggressor_side = AggressorSide::from_u8(aggressor_side_u8).unwrap();
+        self.aggressor_side =
+            AggressorSide::try_from_u8(aggressor_side_u8).map_err(to_pyvalue_err)?;
         self.trade_id = TradeId::from(trade_id_str);
         self.ts_event = ts_event.into();
         self.ts_init = ts_init.into();
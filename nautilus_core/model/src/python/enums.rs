@@ -58,8 +58,8 @@ impl AccountType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -81,6 +81,12 @@ impl AccountType {
         Self::from_str(&tokenized).map_err(to_pyvalue_err)
     }
 
+    #[classmethod]
+    #[pyo3(name = "from_broker_alias")]
+    fn py_from_broker_alias(_: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Self::from_broker_alias(value).map_err(to_pyvalue_err)
+    }
+
     #[classattr]
     #[pyo3(name = "CASH")]
     fn py_cash() -> Self {
@@ -127,8 +133,8 @@ impl AggregationSource {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -190,8 +196,8 @@ impl AggressorSide {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -259,8 +265,8 @@ impl AssetClass {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -352,8 +358,8 @@ impl InstrumentClass {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -457,8 +463,8 @@ impl BarAggregation {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -479,6 +485,13 @@ impl BarAggregation {
         let tokenized = data_str.to_uppercase();
         Self::from_str(&tokenized).map_err(to_pyvalue_err)
     }
+
+    #[classmethod]
+    #[pyo3(name = "from_abbrev")]
+    fn py_from_abbrev(_: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Self::from_abbrev(value).map_err(to_pyvalue_err)
+    }
+
     #[classattr]
     #[pyo3(name = "TICK")]
     fn py_tick() -> Self {
@@ -603,8 +616,8 @@ impl BookAction {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -678,8 +691,8 @@ impl ContingencyType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -701,6 +714,12 @@ impl ContingencyType {
         Self::from_str(&tokenized).map_err(to_pyvalue_err)
     }
 
+    #[classmethod]
+    #[pyo3(name = "from_alias")]
+    fn py_from_alias(_: &Bound<'_, PyType>, value: &str) -> PyResult<Self> {
+        Self::from_alias(value).map_err(to_pyvalue_err)
+    }
+
     #[classattr]
     #[pyo3(name = "NO_CONTINGENCY")]
     fn py_no_contingency() -> Self {
@@ -753,8 +772,8 @@ impl CurrencyType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -822,8 +841,8 @@ impl InstrumentCloseType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -885,8 +904,8 @@ impl LiquiditySide {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -954,8 +973,8 @@ impl MarketStatus {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1042,8 +1061,8 @@ impl MarketStatusAction {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1189,8 +1208,8 @@ impl OmsType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1212,6 +1231,12 @@ impl OmsType {
         Self::from_str(&tokenized).map_err(to_pyvalue_err)
     }
 
+    #[classmethod]
+    #[pyo3(name = "default_for_account")]
+    fn py_default_for_account(_: &Bound<'_, PyType>, account: AccountType) -> Self {
+        Self::default_for_account(account)
+    }
+
     #[classattr]
     #[pyo3(name = "UNSPECIFIED")]
     fn py_unspecified() -> Self {
@@ -1258,8 +1283,8 @@ impl OptionKind {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1321,8 +1346,8 @@ impl OrderSide {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1390,8 +1415,8 @@ impl OrderStatus {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1525,8 +1550,8 @@ impl OrderType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1630,8 +1655,8 @@ impl PositionSide {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1732,8 +1757,8 @@ impl RecordFlag {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1812,8 +1837,8 @@ impl TimeInForce {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1905,8 +1930,8 @@ impl TrailingOffsetType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -1986,8 +2011,8 @@ impl TriggerType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -2009,6 +2034,12 @@ impl TriggerType {
         Self::from_str(&tokenized).map_err(to_pyvalue_err)
     }
 
+    #[classmethod]
+    #[pyo3(name = "default_for_order_type")]
+    fn py_default_for_order_type(_: &Bound<'_, PyType>, order_type: OrderType) -> Self {
+        Self::default_for_order_type(order_type)
+    }
+
     #[classattr]
     #[pyo3(name = "NO_TRIGGER")]
     fn py_no_trigger() -> Self {
@@ -2097,8 +2128,8 @@ impl BookType {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
@@ -2166,8 +2197,8 @@ impl TradingState {
 
     #[getter]
     #[must_use]
-    pub fn name(&self) -> String {
-        self.to_string()
+    pub fn name(&self) -> &'static str {
+        self.as_str()
     }
 
     #[getter]
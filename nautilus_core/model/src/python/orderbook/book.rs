@@ -23,7 +23,7 @@ use crate::{
     },
     enums::{BookType, OrderSide},
     identifiers::InstrumentId,
-    orderbook::{analysis::book_check_integrity, book::OrderBook, level::Level},
+    orderbook::{book::OrderBook, level::Level},
     types::{price::Price, quantity::Quantity},
 };
 
@@ -127,13 +127,17 @@ impl OrderBook {
     }
 
     #[pyo3(name = "apply_delta")]
-    fn py_apply_delta(&mut self, delta: &OrderBookDelta) {
-        self.apply_delta(delta);
+    fn py_apply_delta(&mut self, delta: &OrderBookDelta) -> PyResult<()> {
+        self.apply_delta(delta)
+            .map(|_| ())
+            .map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "apply_deltas")]
-    fn py_apply_deltas(&mut self, deltas: &OrderBookDeltas) {
-        self.apply_deltas(deltas);
+    fn py_apply_deltas(&mut self, deltas: &OrderBookDeltas) -> PyResult<()> {
+        self.apply_deltas(deltas)
+            .map(|_| ())
+            .map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "apply_depth")]
@@ -143,7 +147,7 @@ impl OrderBook {
 
     #[pyo3(name = "check_integrity")]
     fn py_check_integrity(&mut self) -> PyResult<()> {
-        book_check_integrity(self).map_err(to_pyruntime_err)
+        self.check_integrity().map_err(to_pyruntime_err)
     }
 
     #[pyo3(name = "bids")]
@@ -190,7 +194,7 @@ impl OrderBook {
 
     #[pyo3(name = "get_avg_px_for_quantity")]
     fn py_get_avg_px_for_quantity(&self, qty: Quantity, order_side: OrderSide) -> f64 {
-        self.get_avg_px_for_quantity(qty, order_side)
+        self.get_avg_px_for_quantity(qty, order_side).unwrap_or(0.0)
     }
 
     #[pyo3(name = "get_avg_px_qty_for_exposure")]
@@ -204,7 +208,22 @@ impl OrderBook {
 
     #[pyo3(name = "get_quantity_for_price")]
     fn py_get_quantity_for_price(&self, price: Price, order_side: OrderSide) -> f64 {
-        self.get_quantity_for_price(price, order_side)
+        self.get_quantity_for_price(price, order_side).as_f64()
+    }
+
+    #[pyo3(name = "cumulative_depth")]
+    fn py_cumulative_depth(&self, side: OrderSide, price: Price) -> Quantity {
+        self.cumulative_depth(side, price)
+    }
+
+    #[pyo3(name = "imbalance")]
+    fn py_imbalance(&self, depth: usize) -> f64 {
+        self.imbalance(depth)
+    }
+
+    #[pyo3(name = "microprice")]
+    fn py_microprice(&self) -> Option<f64> {
+        self.microprice()
     }
 
     #[pyo3(name = "simulate_fills")]
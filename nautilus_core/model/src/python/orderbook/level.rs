@@ -44,6 +44,11 @@ impl Level {
         self.is_empty()
     }
 
+    #[pyo3(name = "count")]
+    fn py_count(&self) -> u32 {
+        self.count()
+    }
+
     #[pyo3(name = "size")]
     fn py_size(&self) -> f64 {
         self.size()
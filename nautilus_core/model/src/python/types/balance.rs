@@ -106,8 +106,8 @@ impl AccountBalance {
 #[pymethods]
 impl MarginBalance {
     #[new]
-    fn py_new(initial: Money, maintenance: Money, instrument: InstrumentId) -> Self {
-        Self::new(initial, maintenance, instrument)
+    fn py_new(initial: Money, maintenance: Money, instrument: InstrumentId) -> PyResult<Self> {
+        Self::new_checked(initial, maintenance, instrument).map_err(to_pyvalue_err)
     }
     fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python<'_>) -> Py<PyAny> {
         match op {
@@ -26,7 +26,12 @@ use crate::{
 };
 
 /// Represents an account balance denominated in a particular currency.
+///
+/// The `free` balance is always the `total` less the `locked` balance, which is checked on
+/// construction and deserialization.
+#[repr(C)]
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "AccountBalanceFields")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
@@ -48,6 +53,7 @@ impl AccountBalance {
     /// # Errors
     ///
     /// This function returns an error:
+    /// - If `locked` or `free` is not in the currency of `total`.
     /// - If `total` is not the result of `locked` + `free`.
     ///
     /// # Notes
@@ -55,10 +61,16 @@ impl AccountBalance {
     /// PyO3 requires a `Result` type that stacktrace can be printed for errors.
     pub fn new_checked(total: Money, locked: Money, free: Money) -> anyhow::Result<Self> {
         check_predicate_true(
-            total == locked + free,
+            locked.currency == total.currency && free.currency == total.currency,
             &format!(
-                "total balance is not equal to the sum of locked and free balances: {} != {} + {}",
-                total, locked, free
+                "balances are not all in the same currency: total={total}, locked={locked}, free={free}"
+            ),
+        )?;
+        // Balances in the same currency have the same precision, so the raw values are comparable
+        check_predicate_true(
+            locked.raw.checked_add(free.raw) == Some(total.raw),
+            &format!(
+                "total balance is not equal to the sum of locked and free balances: {total} != {locked} + {free}"
             ),
         )?;
         Ok(Self {
@@ -80,6 +92,30 @@ impl AccountBalance {
     }
 }
 
+/// The serialized fields of an [`AccountBalance`], which are checked on deserialization.
+#[derive(Deserialize)]
+struct AccountBalanceFields {
+    currency: Currency,
+    total: Money,
+    locked: Money,
+    free: Money,
+}
+
+impl TryFrom<AccountBalanceFields> for AccountBalance {
+    type Error = anyhow::Error;
+
+    fn try_from(fields: AccountBalanceFields) -> Result<Self, Self::Error> {
+        check_predicate_true(
+            fields.currency == fields.total.currency,
+            &format!(
+                "currency {} is not the currency of the total balance {}",
+                fields.currency, fields.total
+            ),
+        )?;
+        Self::new_checked(fields.total, fields.locked, fields.free)
+    }
+}
+
 impl PartialEq for AccountBalance {
     fn eq(&self, other: &Self) -> bool {
         self.total == other.total && self.locked == other.locked && self.free == other.free
@@ -105,26 +141,87 @@ impl Display for AccountBalance {
     }
 }
 
+/// Represents a margin balance for a particular instrument, denominated in a particular currency.
+#[repr(C)]
 #[derive(Copy, Clone, Serialize, Deserialize)]
+#[serde(try_from = "MarginBalanceFields")]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.model")
 )]
 pub struct MarginBalance {
+    /// The initial margin requirement.
     pub initial: Money,
+    /// The maintenance margin requirement.
     pub maintenance: Money,
+    /// The margin balance currency.
     pub currency: Currency,
+    /// The instrument ID for the margin.
     pub instrument_id: InstrumentId,
 }
 
 impl MarginBalance {
-    pub fn new(initial: Money, maintenance: Money, instrument_id: InstrumentId) -> Self {
-        Self {
+    /// Creates a new [`MarginBalance`] instance with correctness checking.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `maintenance` is not in the currency of `initial`.
+    ///
+    /// # Notes
+    ///
+    /// PyO3 requires a `Result` type that stacktrace can be printed for errors.
+    pub fn new_checked(
+        initial: Money,
+        maintenance: Money,
+        instrument_id: InstrumentId,
+    ) -> anyhow::Result<Self> {
+        check_predicate_true(
+            maintenance.currency == initial.currency,
+            &format!(
+                "margins are not in the same currency: initial={initial}, maintenance={maintenance}"
+            ),
+        )?;
+        Ok(Self {
             initial,
             maintenance,
             currency: initial.currency,
             instrument_id,
-        }
+        })
+    }
+
+    /// Creates a new [`MarginBalance`] instance.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a correctness check fails. See [`MarginBalance::new_checked`] for more details.
+    pub fn new(initial: Money, maintenance: Money, instrument_id: InstrumentId) -> Self {
+        Self::new_checked(initial, maintenance, instrument_id).expect(FAILED)
+    }
+}
+
+/// The serialized fields of a [`MarginBalance`], which are checked on deserialization.
+#[derive(Deserialize)]
+struct MarginBalanceFields {
+    initial: Money,
+    maintenance: Money,
+    currency: Currency,
+    instrument_id: InstrumentId,
+}
+
+impl TryFrom<MarginBalanceFields> for MarginBalance {
+    type Error = anyhow::Error;
+
+    fn try_from(fields: MarginBalanceFields) -> Result<Self, Self::Error> {
+        check_predicate_true(
+            fields.currency == fields.initial.currency,
+            &format!(
+                "currency {} is not the currency of the initial margin {}",
+                fields.currency, fields.initial
+            ),
+        )?;
+        Self::new_checked(fields.initial, fields.maintenance, fields.instrument_id)
     }
 }
 
@@ -162,9 +259,13 @@ impl Display for MarginBalance {
 mod tests {
     use rstest::rstest;
 
-    use crate::types::{
-        balance::{AccountBalance, MarginBalance},
-        stubs::{stub_account_balance, stub_margin_balance},
+    use crate::{
+        identifiers::stubs::instrument_id_btc_usdt,
+        types::{
+            balance::{AccountBalance, MarginBalance},
+            money::Money,
+            stubs::{stub_account_balance, stub_margin_balance},
+        },
     };
 
     #[rstest]
@@ -190,6 +291,102 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[rstest]
+    #[case("100 USD", "25 USD", "80 USD")]
+    #[case("100 USD", "25 USD", "70 USD")]
+    #[case("100.00 USD", "0.01 USD", "100.00 USD")]
+    fn test_account_balance_new_checked_with_invalid_free(
+        #[case] total: &str,
+        #[case] locked: &str,
+        #[case] free: &str,
+    ) {
+        let result =
+            AccountBalance::new_checked(Money::from(total), Money::from(locked), Money::from(free));
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("total balance is not equal to the sum of locked and free balances"));
+    }
+
+    #[rstest]
+    fn test_account_balance_new_checked_with_mixed_currencies() {
+        let result = AccountBalance::new_checked(
+            Money::from("100 USD"),
+            Money::from("0 EUR"),
+            Money::from("100 USD"),
+        );
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("balances are not all in the same currency"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "total balance is not equal to the sum of locked and free balances")]
+    fn test_account_balance_new_with_invalid_free() {
+        let _ = AccountBalance::new(
+            Money::from("100 USD"),
+            Money::from("25 USD"),
+            Money::from("80 USD"),
+        );
+    }
+
+    #[rstest]
+    fn test_account_balance_serde_round_trip(stub_account_balance: AccountBalance) {
+        let json = serde_json::to_string(&stub_account_balance).unwrap();
+        let deserialized: AccountBalance = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, stub_account_balance);
+        assert_eq!(deserialized.currency, stub_account_balance.currency);
+    }
+
+    #[rstest]
+    fn test_account_balance_deserialize_with_invalid_free() {
+        let json =
+            r#"{"currency":"USD","total":"100.00 USD","locked":"25.00 USD","free":"80.00 USD"}"#;
+
+        let result = serde_json::from_str::<AccountBalance>(json);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("total balance is not equal to the sum of locked and free balances"));
+    }
+
+    #[rstest]
+    fn test_account_balance_deserialize_with_mismatched_currency() {
+        let json =
+            r#"{"currency":"EUR","total":"100.00 USD","locked":"25.00 USD","free":"75.00 USD"}"#;
+
+        let result = serde_json::from_str::<AccountBalance>(json);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    fn test_margin_balance_new_checked_with_mixed_currencies() {
+        let result = MarginBalance::new_checked(
+            Money::from("5000 USD"),
+            Money::from("1 BTC"),
+            instrument_id_btc_usdt(),
+        );
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("margins are not in the same currency"));
+    }
+
+    #[rstest]
+    fn test_margin_balance_serde_round_trip(stub_margin_balance: MarginBalance) {
+        let json = serde_json::to_string(&stub_margin_balance).unwrap();
+        let deserialized: MarginBalance = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(deserialized, stub_margin_balance);
+    }
+
     #[rstest]
     fn test_margin_balance_equality() {
         let margin_balance_1 = stub_margin_balance();
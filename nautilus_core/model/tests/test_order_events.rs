@@ -0,0 +1,97 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Tests that order events serialize to the same JSON schema as the Python `to_dict`
+//! serialization, using a fixture shared with the Python test suite.
+
+use nautilus_core::paths::get_test_data_path;
+use nautilus_model::events::order::{
+    OrderAccepted, OrderCancelRejected, OrderCanceled, OrderDenied, OrderEmulated, OrderExpired,
+    OrderFilled, OrderInitialized, OrderModifyRejected, OrderPendingCancel, OrderPendingUpdate,
+    OrderRejected, OrderReleased, OrderSubmitted, OrderTriggered, OrderUpdated,
+};
+use rstest::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+/// Fields serialized by Python which have no Rust equivalent.
+const PYTHON_ONLY_FIELDS: [&str; 1] = ["info"];
+
+#[fixture]
+fn python_events() -> Vec<Value> {
+    let path = get_test_data_path()
+        .join("nautilus")
+        .join("order_events.json");
+    let data = std::fs::read_to_string(path).unwrap();
+    serde_json::from_str(&data).unwrap()
+}
+
+fn assert_roundtrip<T>(expected: &Value)
+where
+    T: Serialize + DeserializeOwned,
+{
+    // Deserialize from a JSON string (as received), since identifiers borrow from the input
+    let event: T = serde_json::from_str(&expected.to_string()).unwrap();
+    let value = serde_json::to_value(&event).unwrap();
+
+    let mut expected = expected.as_object().unwrap().clone();
+    expected.retain(|key, _| !PYTHON_ONLY_FIELDS.contains(&key.as_str()));
+    assert_eq!(value.as_object().unwrap(), &expected);
+}
+
+#[rstest]
+fn test_order_events_python_json_roundtrip(python_events: Vec<Value>) {
+    assert_eq!(python_events.len(), 16);
+
+    for event in &python_events {
+        match event["type"].as_str().unwrap() {
+            "OrderInitialized" => assert_roundtrip::<OrderInitialized>(event),
+            "OrderDenied" => assert_roundtrip::<OrderDenied>(event),
+            "OrderEmulated" => assert_roundtrip::<OrderEmulated>(event),
+            "OrderReleased" => assert_roundtrip::<OrderReleased>(event),
+            "OrderSubmitted" => assert_roundtrip::<OrderSubmitted>(event),
+            "OrderAccepted" => assert_roundtrip::<OrderAccepted>(event),
+            "OrderRejected" => assert_roundtrip::<OrderRejected>(event),
+            "OrderPendingUpdate" => assert_roundtrip::<OrderPendingUpdate>(event),
+            "OrderUpdated" => assert_roundtrip::<OrderUpdated>(event),
+            "OrderModifyRejected" => assert_roundtrip::<OrderModifyRejected>(event),
+            "OrderTriggered" => assert_roundtrip::<OrderTriggered>(event),
+            "OrderFilled" => assert_roundtrip::<OrderFilled>(event),
+            "OrderPendingCancel" => assert_roundtrip::<OrderPendingCancel>(event),
+            "OrderCancelRejected" => assert_roundtrip::<OrderCancelRejected>(event),
+            "OrderCanceled" => assert_roundtrip::<OrderCanceled>(event),
+            "OrderExpired" => assert_roundtrip::<OrderExpired>(event),
+            other => panic!("Unexpected event type {other}"),
+        }
+    }
+}
+
+#[rstest]
+fn test_order_filled_from_python_json(python_events: Vec<Value>) {
+    let event = python_events
+        .into_iter()
+        .find(|event| event["type"] == "OrderFilled")
+        .unwrap();
+
+    let fill: OrderFilled = serde_json::from_str(&event.to_string()).unwrap();
+
+    assert_eq!(
+        fill.event_id.to_string(),
+        "7f8d4a2e-3c1b-4e5f-9a6d-0b1c2d3e4f11"
+    );
+    assert_eq!(fill.last_px.to_string(), "42000.50");
+    assert_eq!(fill.commission.unwrap().to_string(), "2.10002500 USDT");
+    assert!(!fill.reconciliation);
+}
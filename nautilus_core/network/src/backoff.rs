@@ -0,0 +1,132 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Exponential backoff with jitter, for spacing out reconnection attempts.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+/// Produces exponentially increasing delays between retries, capped at a maximum and randomized
+/// by a jitter fraction so that many clients do not retry in lockstep.
+#[derive(Clone, Debug)]
+pub struct ExponentialBackoff {
+    initial: Duration,
+    max: Duration,
+    factor: f64,
+    jitter: f64,
+    current: Duration,
+}
+
+impl ExponentialBackoff {
+    /// Creates a new [`ExponentialBackoff`] instance.
+    ///
+    /// Each delay is the previous (un-jittered) delay multiplied by `factor` (at least 1.0), up to
+    /// `max`, and then randomized by up to `jitter` (clamped to 0.0..=1.0) of itself either way.
+    #[must_use]
+    pub fn new(initial: Duration, max: Duration, factor: f64, jitter: f64) -> Self {
+        Self {
+            initial,
+            max: max.max(initial),
+            factor: factor.max(1.0),
+            jitter: jitter.clamp(0.0, 1.0),
+            current: initial,
+        }
+    }
+
+    /// Returns the next delay, advancing the backoff.
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self.current;
+        self.current = self.current.mul_f64(self.factor).min(self.max);
+
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        let jitter = rand::thread_rng().gen_range(-self.jitter..=self.jitter);
+        delay.mul_f64(1.0 + jitter)
+    }
+
+    /// Resets the backoff to the initial delay (e.g. after a successful reconnection).
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_delays_grow_exponentially_up_to_max() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_millis(1_000),
+            2.0,
+            0.0,
+        );
+
+        let delays: Vec<u64> = (0..6)
+            .map(|_| backoff.next_delay().as_millis() as u64)
+            .collect();
+
+        assert_eq!(delays, [100, 200, 400, 800, 1_000, 1_000]);
+    }
+
+    #[rstest]
+    fn test_reset_returns_to_initial_delay() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(100),
+            Duration::from_secs(10),
+            2.0,
+            0.0,
+        );
+        backoff.next_delay();
+        backoff.next_delay();
+
+        backoff.reset();
+
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+    }
+
+    #[rstest]
+    fn test_jitter_stays_within_bounds() {
+        let mut backoff = ExponentialBackoff::new(
+            Duration::from_millis(1_000),
+            Duration::from_secs(1),
+            2.0,
+            0.25,
+        );
+
+        for _ in 0..100 {
+            let delay = backoff.next_delay();
+            assert!(delay >= Duration::from_millis(750));
+            assert!(delay <= Duration::from_millis(1_250));
+        }
+    }
+
+    #[rstest]
+    fn test_invalid_parameters_are_clamped() {
+        let mut backoff =
+            ExponentialBackoff::new(Duration::from_millis(100), Duration::ZERO, 0.5, -1.0);
+
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+        assert_eq!(backoff.next_delay(), Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,316 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::{c_char, c_void},
+    ops::Deref,
+    time::Duration,
+};
+
+use nautilus_core::ffi::string::cstr_to_str;
+use tokio::runtime::{Builder, Runtime};
+use tokio_tungstenite::tungstenite::stream::Mode;
+
+use crate::{
+    framed::{FramedSocketClient, FramedSocketConfig, FramedSocketEvent, Framing},
+    reconnecting::ConnectionState,
+};
+
+/// The kind of framing of a framed socket started with [`framed_socket_start`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FramingKind {
+    /// Frames are preceded by a big-endian length header.
+    LengthPrefixed = 1,
+    /// Frames are terminated by a delimiter byte sequence.
+    Delimited = 2,
+    /// Frames are terminated by a newline.
+    Newline = 3,
+}
+
+/// The callback receiving each complete frame as a byte slice, valid only during the call.
+pub type FrameCallback = extern "C" fn(context: *mut c_void, data: *const u8, len: usize);
+
+/// The opaque context passed back to a [`FrameCallback`].
+struct CallbackContext(*mut c_void);
+
+// SAFETY: The caller of `framed_socket_start` guarantees the context can be used from the
+// socket thread until the socket is stopped
+unsafe impl Send for CallbackContext {}
+
+impl CallbackContext {
+    const fn get(&self) -> *mut c_void {
+        self.0
+    }
+}
+
+/// A framed socket client with the runtime driving its connection.
+#[derive(Debug)]
+pub struct FramedSocketHandle {
+    runtime: Runtime,
+    client: FramedSocketClient,
+}
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`FramedSocketHandle`].
+///
+/// This struct wraps `FramedSocketHandle` in a way that makes it compatible with C function
+/// calls, enabling interaction with `FramedSocketClient` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `FramedSocketClient_API` to be
+/// dereferenced to `FramedSocketHandle`, providing access to its methods without having to
+/// manually access the underlying instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct FramedSocketClient_API(Box<FramedSocketHandle>);
+
+impl Deref for FramedSocketClient_API {
+    type Target = FramedSocketHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+unsafe fn bytes_from_raw(ptr: *const u8, len: usize) -> Vec<u8> {
+    if len == 0 {
+        return Vec::new();
+    }
+    std::slice::from_raw_parts(ptr, len).to_vec()
+}
+
+/// Connects a framed socket to the server at `url_ptr` (`host:port`), passing each complete
+/// frame received to `callback` (on the socket thread) along with `context`.
+///
+/// Returns 1 with the connected socket written to `socket`, otherwise 0 (if the runtime cannot
+/// be created or the initial connection fails).
+///
+/// - `header_len`: The length header size for `LENGTH_PREFIXED` framing (1, 2, 4 or 8 bytes).
+/// - `delimiter_ptr`/`delimiter_len`: The delimiter for `DELIMITED` framing (e.g. SOH).
+/// - `heartbeat_interval_ms`: The heartbeat period, zero disables heartbeats.
+/// - `heartbeat_ptr`/`heartbeat_len`: The heartbeat message (framed before sending).
+///
+/// The socket reconnects with exponential backoff if the connection drops.
+///
+/// # Safety
+///
+/// - Assumes `url_ptr` is a valid C string pointer.
+/// - Assumes `delimiter_ptr` and `heartbeat_ptr` point to at least `delimiter_len` and
+///   `heartbeat_len` bytes respectively (unless the length is zero).
+/// - Assumes `context` can be used from another thread until [`framed_socket_stop`] returns.
+/// - Assumes `socket` is a valid pointer to write the connected socket to.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn framed_socket_start(
+    url_ptr: *const c_char,
+    use_tls: u8,
+    framing: FramingKind,
+    header_len: u8,
+    delimiter_ptr: *const u8,
+    delimiter_len: usize,
+    heartbeat_interval_ms: u64,
+    heartbeat_ptr: *const u8,
+    heartbeat_len: usize,
+    callback: FrameCallback,
+    context: *mut c_void,
+    socket: *mut FramedSocketClient_API,
+) -> u8 {
+    let framing = match framing {
+        FramingKind::LengthPrefixed => Framing::LengthPrefixed {
+            header_len: usize::from(header_len),
+        },
+        FramingKind::Delimited => Framing::Delimited(bytes_from_raw(delimiter_ptr, delimiter_len)),
+        FramingKind::Newline => Framing::Newline,
+    };
+    let mut config = FramedSocketConfig::new(cstr_to_str(url_ptr), framing);
+    if use_tls != 0 {
+        config.mode = Mode::Tls;
+    }
+    if heartbeat_interval_ms > 0 {
+        config.heartbeat = Some((
+            Duration::from_millis(heartbeat_interval_ms),
+            bytes_from_raw(heartbeat_ptr, heartbeat_len),
+        ));
+    }
+
+    let runtime = match Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("framed-socket")
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            tracing::error!("Error creating framed socket runtime: {e}");
+            return 0;
+        }
+    };
+    let (client, mut events) = match runtime.block_on(FramedSocketClient::connect(config)) {
+        Ok(connected) => connected,
+        Err(e) => {
+            tracing::error!("Error connecting framed socket: {e}");
+            return 0;
+        }
+    };
+
+    let context = CallbackContext(context);
+    runtime.spawn(async move {
+        while let Some(event) = events.recv().await {
+            if let FramedSocketEvent::Frame(frame) = event {
+                callback(context.get(), frame.as_ptr(), frame.len());
+            }
+        }
+    });
+
+    socket.write(FramedSocketClient_API(Box::new(FramedSocketHandle {
+        runtime,
+        client,
+    })));
+    1
+}
+
+/// Sends `data_len` bytes at `data_ptr` as a frame, buffering it while reconnecting.
+///
+/// Returns 1 if the frame was sent (or buffered), otherwise 0.
+///
+/// # Safety
+///
+/// - Assumes `data_ptr` points to at least `data_len` bytes (unless `data_len` is zero).
+#[no_mangle]
+pub unsafe extern "C" fn framed_socket_send(
+    socket: &FramedSocketClient_API,
+    data_ptr: *const u8,
+    data_len: usize,
+) -> u8 {
+    match socket.client.send(&bytes_from_raw(data_ptr, data_len)) {
+        Ok(()) => 1,
+        Err(e) => {
+            tracing::error!("Error sending frame: {e}");
+            0
+        }
+    }
+}
+
+/// Returns the connection state of the framed socket.
+#[no_mangle]
+pub extern "C" fn framed_socket_state(socket: &FramedSocketClient_API) -> ConnectionState {
+    socket.client.state()
+}
+
+/// Shuts down the connection of the framed socket and drops it.
+#[no_mangle]
+pub extern "C" fn framed_socket_stop(socket: FramedSocketClient_API) {
+    let FramedSocketHandle { runtime, client } = *socket.0;
+    runtime.block_on(client.disconnect());
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit, net::TcpListener, sync::Mutex, time::Instant};
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::framed::tests::FragmentingServer;
+
+    type Frames = Mutex<Vec<Vec<u8>>>;
+
+    extern "C" fn collect_frame(context: *mut c_void, data: *const u8, len: usize) {
+        let frames = unsafe { &*(context as *const Frames) };
+        let frame = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+        frames.lock().unwrap().push(frame);
+    }
+
+    #[rstest]
+    fn test_framed_socket_ffi() {
+        let server_runtime = Runtime::new().unwrap();
+        let server =
+            server_runtime.block_on(FragmentingServer::start(b"35=A\x0135=0\x01".to_vec(), 3));
+        let url = CString::new(server.url()).unwrap();
+        let delimiter = b"\x01";
+        let frames = Frames::default();
+
+        let mut socket = MaybeUninit::<FramedSocketClient_API>::uninit();
+        let started = unsafe {
+            framed_socket_start(
+                url.as_ptr(),
+                0,
+                FramingKind::Delimited,
+                0,
+                delimiter.as_ptr(),
+                delimiter.len(),
+                0,
+                std::ptr::null(),
+                0,
+                collect_frame,
+                std::ptr::from_ref(&frames).cast_mut().cast(),
+                socket.as_mut_ptr(),
+            )
+        };
+        assert_eq!(started, 1);
+        let socket = unsafe { socket.assume_init() };
+        assert_eq!(framed_socket_state(&socket), ConnectionState::Connected);
+
+        let order = b"35=D";
+        assert_eq!(
+            unsafe { framed_socket_send(&socket, order.as_ptr(), order.len()) },
+            1
+        );
+
+        let start = Instant::now();
+        while frames.lock().unwrap().len() < 3 && start.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        framed_socket_stop(socket);
+
+        assert_eq!(
+            *frames.lock().unwrap(),
+            [b"35=A".to_vec(), b"35=0".to_vec(), b"35=D".to_vec()]
+        );
+    }
+
+    #[rstest]
+    fn test_framed_socket_start_connection_refused() {
+        // Bind then drop a listener to find a local port with nothing listening
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let url = CString::new(format!("127.0.0.1:{port}")).unwrap();
+        let mut socket = MaybeUninit::<FramedSocketClient_API>::uninit();
+
+        let started = unsafe {
+            framed_socket_start(
+                url.as_ptr(),
+                0,
+                FramingKind::Newline,
+                0,
+                std::ptr::null(),
+                0,
+                0,
+                std::ptr::null(),
+                0,
+                collect_frame,
+                std::ptr::null_mut(),
+                socket.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(started, 0);
+    }
+}
@@ -0,0 +1,163 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Dispatch of decoded messages by their message type.
+
+use super::{
+    error::FixError,
+    message::{FixMessage, FixMessageBody},
+    order::{NewOrderSingle, OrderCancelReplaceRequest, OrderCancelRequest},
+    report::{ExecutionReport, OrderCancelReject},
+    tags::MSG_TYPE,
+};
+
+/// Any of the supported FIX application messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FixMessageAny {
+    NewOrderSingle(NewOrderSingle),
+    OrderCancelRequest(OrderCancelRequest),
+    OrderCancelReplaceRequest(OrderCancelReplaceRequest),
+    ExecutionReport(ExecutionReport),
+    OrderCancelReject(OrderCancelReject),
+}
+
+impl FixMessageAny {
+    /// Parses the message as the type given by its `MsgType` (35).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message type is not supported.
+    /// - If the message is not valid for its message type.
+    pub fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        match message.msg_type() {
+            NewOrderSingle::MSG_TYPE => NewOrderSingle::from_fix(message).map(Self::NewOrderSingle),
+            OrderCancelRequest::MSG_TYPE => {
+                OrderCancelRequest::from_fix(message).map(Self::OrderCancelRequest)
+            }
+            OrderCancelReplaceRequest::MSG_TYPE => {
+                OrderCancelReplaceRequest::from_fix(message).map(Self::OrderCancelReplaceRequest)
+            }
+            ExecutionReport::MSG_TYPE => {
+                ExecutionReport::from_fix(message).map(Self::ExecutionReport)
+            }
+            OrderCancelReject::MSG_TYPE => {
+                OrderCancelReject::from_fix(message).map(Self::OrderCancelReject)
+            }
+            msg_type => Err(FixError::Unsupported {
+                tag: MSG_TYPE,
+                value: msg_type.to_string(),
+            }),
+        }
+    }
+
+    /// Converts the message to a [`FixMessage`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a field value has no FIX equivalent.
+    pub fn to_fix(&self) -> Result<FixMessage, FixError> {
+        match self {
+            Self::NewOrderSingle(message) => message.to_fix(),
+            Self::OrderCancelRequest(message) => message.to_fix(),
+            Self::OrderCancelReplaceRequest(message) => message.to_fix(),
+            Self::ExecutionReport(message) => message.to_fix(),
+            Self::OrderCancelReject(message) => message.to_fix(),
+        }
+    }
+
+    /// Decodes any supported message from bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the bytes are not a valid FIX message, or not a valid supported message.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FixError> {
+        Self::from_fix(&FixMessage::decode(bytes)?)
+    }
+
+    /// Encodes the message to bytes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a field value has no FIX equivalent.
+    pub fn encode(&self) -> Result<Vec<u8>, FixError> {
+        Ok(self.to_fix()?.encode())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::stubs::{
+        execution_report_fill_bytes, heartbeat_bytes, new_order_single_bytes,
+        order_cancel_reject_bytes, order_cancel_replace_request_bytes, order_cancel_request_bytes,
+    };
+
+    #[rstest]
+    fn test_decode_dispatches_on_msg_type(
+        new_order_single_bytes: Vec<u8>,
+        order_cancel_request_bytes: Vec<u8>,
+        order_cancel_replace_request_bytes: Vec<u8>,
+        execution_report_fill_bytes: Vec<u8>,
+        order_cancel_reject_bytes: Vec<u8>,
+    ) {
+        assert!(matches!(
+            FixMessageAny::decode(&new_order_single_bytes),
+            Ok(FixMessageAny::NewOrderSingle(_))
+        ));
+        assert!(matches!(
+            FixMessageAny::decode(&order_cancel_request_bytes),
+            Ok(FixMessageAny::OrderCancelRequest(_))
+        ));
+        assert!(matches!(
+            FixMessageAny::decode(&order_cancel_replace_request_bytes),
+            Ok(FixMessageAny::OrderCancelReplaceRequest(_))
+        ));
+        assert!(matches!(
+            FixMessageAny::decode(&execution_report_fill_bytes),
+            Ok(FixMessageAny::ExecutionReport(_))
+        ));
+        assert!(matches!(
+            FixMessageAny::decode(&order_cancel_reject_bytes),
+            Ok(FixMessageAny::OrderCancelReject(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_round_trip(execution_report_fill_bytes: Vec<u8>) {
+        let message = FixMessageAny::decode(&execution_report_fill_bytes).unwrap();
+
+        assert_eq!(message.encode().unwrap(), execution_report_fill_bytes);
+    }
+
+    #[rstest]
+    fn test_decode_unsupported_msg_type(heartbeat_bytes: Vec<u8>) {
+        assert_eq!(
+            FixMessageAny::decode(&heartbeat_bytes),
+            Err(FixError::Unsupported {
+                tag: MSG_TYPE,
+                value: "0".to_string(),
+            })
+        );
+    }
+}
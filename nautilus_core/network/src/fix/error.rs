@@ -0,0 +1,40 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Errors associated with encoding and decoding FIX messages.
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum FixError {
+    #[error("Malformed FIX message: {0}")]
+    Malformed(String),
+    #[error("Expected tag {expected} at field {index}, was {found}")]
+    UnexpectedTag {
+        expected: u32,
+        found: u32,
+        index: usize,
+    },
+    #[error("Invalid BodyLength, declared {declared:?} but was {actual}")]
+    BodyLength { declared: String, actual: usize },
+    #[error("Invalid CheckSum, declared {declared:?} but calculated {calculated:03}")]
+    CheckSum { declared: String, calculated: u8 },
+    #[error("Invalid MsgType, expected {expected:?} but was {found:?}")]
+    MsgType { expected: String, found: String },
+    #[error("Missing required tag {0}")]
+    MissingTag(u32),
+    #[error("Invalid value {value:?} for tag {tag}")]
+    InvalidValue { tag: u32, value: String },
+    #[error("Unsupported value {value:?} for tag {tag}")]
+    Unsupported { tag: u32, value: String },
+}
@@ -0,0 +1,278 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Mappings between FIX 4.4 field values and the domain model types.
+
+use std::fmt::Display;
+
+use chrono::{DateTime, NaiveDateTime};
+use nautilus_core::{nanos::UnixNanos, parsing::precision_from_str};
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::error::FixError;
+
+/// The format of a FIX `UTCTimestamp` with milliseconds (e.g. `20241015-14:30:00.123`).
+const UTC_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H:%M:%S%.3f";
+
+/// Returns the FIX value of `value` for `tag` using `to_fix`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `value` has no FIX equivalent.
+pub fn to_fix_value<T: Copy + Display>(
+    tag: u32,
+    value: T,
+    to_fix: impl FnOnce(T) -> Option<&'static str>,
+) -> Result<&'static str, FixError> {
+    to_fix(value).ok_or_else(|| FixError::Unsupported {
+        tag,
+        value: value.to_string(),
+    })
+}
+
+/// Parses a FIX `Side` (54) value, where only buy (`1`) and sell (`2`) are supported.
+#[must_use]
+pub fn side_from_fix(value: &str) -> Option<OrderSide> {
+    match value {
+        "1" => Some(OrderSide::Buy),
+        "2" => Some(OrderSide::Sell),
+        _ => None,
+    }
+}
+
+/// Returns the FIX `Side` (54) value for the order side, or `None` if it has no FIX equivalent.
+#[must_use]
+pub const fn side_to_fix(value: OrderSide) -> Option<&'static str> {
+    match value {
+        OrderSide::Buy => Some("1"),
+        OrderSide::Sell => Some("2"),
+        OrderSide::NoOrderSide => None,
+    }
+}
+
+/// Parses a FIX `OrdType` (40) value.
+#[must_use]
+pub fn order_type_from_fix(value: &str) -> Option<OrderType> {
+    match value {
+        "1" => Some(OrderType::Market),
+        "2" => Some(OrderType::Limit),
+        "3" => Some(OrderType::StopMarket),
+        "4" => Some(OrderType::StopLimit),
+        "J" => Some(OrderType::MarketIfTouched),
+        "K" => Some(OrderType::MarketToLimit),
+        _ => None,
+    }
+}
+
+/// Returns the FIX `OrdType` (40) value for the order type, or `None` if it has no FIX 4.4
+/// equivalent (e.g. trailing stops).
+#[must_use]
+pub const fn order_type_to_fix(value: OrderType) -> Option<&'static str> {
+    match value {
+        OrderType::Market => Some("1"),
+        OrderType::Limit => Some("2"),
+        OrderType::StopMarket => Some("3"),
+        OrderType::StopLimit => Some("4"),
+        OrderType::MarketIfTouched => Some("J"),
+        OrderType::MarketToLimit => Some("K"),
+        _ => None,
+    }
+}
+
+/// Parses a FIX `TimeInForce` (59) value.
+#[must_use]
+pub fn time_in_force_from_fix(value: &str) -> Option<TimeInForce> {
+    match value {
+        "0" => Some(TimeInForce::Day),
+        "1" => Some(TimeInForce::Gtc),
+        "2" => Some(TimeInForce::AtTheOpen),
+        "3" => Some(TimeInForce::Ioc),
+        "4" => Some(TimeInForce::Fok),
+        "6" => Some(TimeInForce::Gtd),
+        "7" => Some(TimeInForce::AtTheClose),
+        _ => None,
+    }
+}
+
+/// Returns the FIX `TimeInForce` (59) value for the time in force.
+#[must_use]
+pub const fn time_in_force_to_fix(value: TimeInForce) -> &'static str {
+    match value {
+        TimeInForce::Day => "0",
+        TimeInForce::Gtc => "1",
+        TimeInForce::AtTheOpen => "2",
+        TimeInForce::Ioc => "3",
+        TimeInForce::Fok => "4",
+        TimeInForce::Gtd => "6",
+        TimeInForce::AtTheClose => "7",
+    }
+}
+
+/// Parses a FIX `OrdStatus` (39) value, where `New` (`0`) maps to [`OrderStatus::Accepted`],
+/// `PendingNew` (`A`) to [`OrderStatus::Submitted`] and `PendingReplace` (`E`) to
+/// [`OrderStatus::PendingUpdate`].
+#[must_use]
+pub fn order_status_from_fix(value: &str) -> Option<OrderStatus> {
+    match value {
+        "0" => Some(OrderStatus::Accepted),
+        "1" => Some(OrderStatus::PartiallyFilled),
+        "2" => Some(OrderStatus::Filled),
+        "4" => Some(OrderStatus::Canceled),
+        "6" => Some(OrderStatus::PendingCancel),
+        "8" => Some(OrderStatus::Rejected),
+        "A" => Some(OrderStatus::Submitted),
+        "C" => Some(OrderStatus::Expired),
+        "E" => Some(OrderStatus::PendingUpdate),
+        _ => None,
+    }
+}
+
+/// Returns the FIX `OrdStatus` (39) value for the order status, or `None` if it has no FIX
+/// equivalent (e.g. statuses local to the trading node such as `EMULATED`).
+#[must_use]
+pub const fn order_status_to_fix(value: OrderStatus) -> Option<&'static str> {
+    match value {
+        OrderStatus::Accepted => Some("0"),
+        OrderStatus::PartiallyFilled => Some("1"),
+        OrderStatus::Filled => Some("2"),
+        OrderStatus::Canceled => Some("4"),
+        OrderStatus::PendingCancel => Some("6"),
+        OrderStatus::Rejected => Some("8"),
+        OrderStatus::Submitted => Some("A"),
+        OrderStatus::Expired => Some("C"),
+        OrderStatus::PendingUpdate => Some("E"),
+        _ => None,
+    }
+}
+
+/// Parses a FIX `Price` value, with the precision of the value as written.
+#[must_use]
+pub fn parse_price(value: &str) -> Option<Price> {
+    let float = value.parse::<f64>().ok()?;
+    Price::new_checked(float, precision_from_str(value)).ok()
+}
+
+/// Parses a FIX `Qty` value, with the precision of the value as written.
+#[must_use]
+pub fn parse_quantity(value: &str) -> Option<Quantity> {
+    let float = value.parse::<f64>().ok()?;
+    Quantity::new_checked(float, precision_from_str(value)).ok()
+}
+
+/// Parses a FIX `UTCTimestamp` value (e.g. `20241015-14:30:00.123`), where the fractional
+/// seconds are optional.
+#[must_use]
+pub fn parse_utc_timestamp(value: &str) -> Option<UnixNanos> {
+    let datetime = NaiveDateTime::parse_from_str(value, "%Y%m%d-%H:%M:%S%.f").ok()?;
+    let nanos = datetime.and_utc().timestamp_nanos_opt()?;
+    u64::try_from(nanos).ok().map(UnixNanos::from)
+}
+
+/// Formats the UNIX timestamp as a FIX `UTCTimestamp` with milliseconds.
+#[must_use]
+pub fn format_utc_timestamp(value: UnixNanos) -> String {
+    let nanos = i64::try_from(value.as_u64()).unwrap_or(i64::MAX);
+    DateTime::from_timestamp_nanos(nanos)
+        .format(UTC_TIMESTAMP_FORMAT)
+        .to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[rstest]
+    fn test_order_type_round_trip() {
+        for order_type in OrderType::iter() {
+            if let Some(value) = order_type_to_fix(order_type) {
+                assert_eq!(order_type_from_fix(value), Some(order_type));
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_time_in_force_round_trip() {
+        for time_in_force in TimeInForce::iter() {
+            assert_eq!(
+                time_in_force_from_fix(time_in_force_to_fix(time_in_force)),
+                Some(time_in_force)
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_order_status_round_trip() {
+        for order_status in OrderStatus::iter() {
+            if let Some(value) = order_status_to_fix(order_status) {
+                assert_eq!(order_status_from_fix(value), Some(order_status));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case("0", None)]
+    #[case("1", Some(OrderSide::Buy))]
+    #[case("2", Some(OrderSide::Sell))]
+    #[case("5", None)]
+    fn test_side_from_fix(#[case] value: &str, #[case] expected: Option<OrderSide>) {
+        assert_eq!(side_from_fix(value), expected);
+    }
+
+    #[rstest]
+    #[case("1.10250", Some(Price::from("1.10250")))]
+    #[case("100", Some(Price::from("100")))]
+    #[case("", None)]
+    #[case("1.1O", None)]
+    fn test_parse_price(#[case] value: &str, #[case] expected: Option<Price>) {
+        let price = parse_price(value);
+
+        assert_eq!(price, expected);
+        assert_eq!(price.map(|p| p.precision), expected.map(|p| p.precision));
+    }
+
+    #[rstest]
+    #[case("-1", None)]
+    #[case("1000.5", Some(Quantity::from("1000.5")))]
+    fn test_parse_quantity(#[case] value: &str, #[case] expected: Option<Quantity>) {
+        assert_eq!(parse_quantity(value), expected);
+    }
+
+    #[rstest]
+    #[case("20241015-14:30:00.123", Some(1_729_002_600_123_000_000))]
+    #[case("20241015-14:30:00", Some(1_729_002_600_000_000_000))]
+    #[case("20241015-14:30:00.123456", Some(1_729_002_600_123_456_000))]
+    #[case("2024-10-15T14:30:00Z", None)]
+    fn test_parse_utc_timestamp(#[case] value: &str, #[case] expected: Option<u64>) {
+        assert_eq!(parse_utc_timestamp(value), expected.map(UnixNanos::from));
+    }
+
+    #[rstest]
+    fn test_format_utc_timestamp() {
+        assert_eq!(
+            format_utc_timestamp(UnixNanos::from(1_729_002_600_123_456_789)),
+            "20241015-14:30:00.123"
+        );
+    }
+}
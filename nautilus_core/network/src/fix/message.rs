@@ -0,0 +1,527 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The raw tag-value representation of a FIX message.
+
+use std::fmt::{Display, Write};
+
+use nautilus_core::nanos::UnixNanos;
+
+use super::{
+    error::FixError,
+    fields::{format_utc_timestamp, parse_utc_timestamp},
+    tags::{
+        BEGIN_STRING, BODY_LENGTH, CHECK_SUM, MSG_SEQ_NUM, MSG_TYPE, SENDER_COMP_ID, SENDING_TIME,
+        TARGET_COMP_ID,
+    },
+};
+
+/// The Start of Heading character which terminates each field.
+pub const SOH: char = '\x01';
+
+/// The `BeginString` (8) of FIX 4.4 messages.
+pub const FIX_4_4: &str = "FIX.4.4";
+
+/// Returns the FIX checksum of `bytes`, which is the sum of the bytes modulo 256.
+#[must_use]
+pub fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte))
+}
+
+/// A FIX message as an ordered list of tag-value fields.
+///
+/// The `BeginString` (8) is held separately, while the `BodyLength` (9) and `CheckSum` (10)
+/// are not held at all, being computed on encoding and validated on decoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixMessage {
+    pub begin_string: String,
+    /// The fields in order, starting with the `MsgType` (35).
+    pub fields: Vec<(u32, String)>,
+}
+
+impl FixMessage {
+    /// Creates a new FIX 4.4 [`FixMessage`] instance of the given `msg_type`.
+    #[must_use]
+    pub fn new(msg_type: &str) -> Self {
+        Self {
+            begin_string: FIX_4_4.to_string(),
+            fields: vec![(MSG_TYPE, msg_type.to_string())],
+        }
+    }
+
+    /// Returns the `MsgType` (35) of the message.
+    #[must_use]
+    pub fn msg_type(&self) -> &str {
+        self.get(MSG_TYPE).unwrap_or_default()
+    }
+
+    /// Returns the value of the first field with the given `tag`, if any.
+    #[must_use]
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(field_tag, _)| *field_tag == tag)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Appends a field to the message.
+    pub fn push(&mut self, tag: u32, value: impl Display) {
+        self.fields.push((tag, value.to_string()));
+    }
+
+    /// Appends a field to the message if `value` is `Some`.
+    pub fn push_opt(&mut self, tag: u32, value: Option<impl Display>) {
+        if let Some(value) = value {
+            self.push(tag, value);
+        }
+    }
+
+    /// Returns the value of the required `tag`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message has no field with the given `tag`.
+    pub fn required(&self, tag: u32) -> Result<&str, FixError> {
+        self.get(tag).ok_or(FixError::MissingTag(tag))
+    }
+
+    /// Returns the value of the required `tag` converted with `parse`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message has no field with the given `tag`.
+    /// - If `parse` returns `None` for the value.
+    pub fn required_with<T>(
+        &self,
+        tag: u32,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<T, FixError> {
+        let value = self.required(tag)?;
+        parse(value).ok_or_else(|| FixError::InvalidValue {
+            tag,
+            value: value.to_string(),
+        })
+    }
+
+    /// Returns the value of the optional `tag` converted with `parse`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `parse` returns `None` for the value.
+    pub fn optional_with<T>(
+        &self,
+        tag: u32,
+        parse: impl FnOnce(&str) -> Option<T>,
+    ) -> Result<Option<T>, FixError> {
+        self.get(tag)
+            .map(|value| {
+                parse(value).ok_or_else(|| FixError::InvalidValue {
+                    tag,
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns an error if the message is not of the `expected` message type.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `MsgType` (35) is not `expected`.
+    pub fn expect_msg_type(&self, expected: &str) -> Result<(), FixError> {
+        if self.msg_type() == expected {
+            Ok(())
+        } else {
+            Err(FixError::MsgType {
+                expected: expected.to_string(),
+                found: self.msg_type().to_string(),
+            })
+        }
+    }
+
+    /// Encodes the message, computing the `BodyLength` (9) and `CheckSum` (10).
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = String::new();
+        for (tag, value) in &self.fields {
+            let _ = write!(body, "{tag}={value}{SOH}");
+        }
+
+        let mut message = format!(
+            "{BEGIN_STRING}={}{SOH}{BODY_LENGTH}={}{SOH}{body}",
+            self.begin_string,
+            body.len()
+        );
+        let checksum = checksum(message.as_bytes());
+        let _ = write!(message, "{CHECK_SUM}={checksum:03}{SOH}");
+        message.into_bytes()
+    }
+
+    /// Decodes a complete message, validating the standard header and trailer fields are in
+    /// place and the `BodyLength` (9) and `CheckSum` (10) are correct.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the message is not valid UTF-8, or is not terminated by SOH.
+    /// - If a field is not of the form `tag=value`, with a numeric tag and non-empty value.
+    /// - If the message does not start with `BeginString` (8), `BodyLength` (9) and
+    ///   `MsgType` (35), or does not end with `CheckSum` (10).
+    /// - If the `BodyLength` or `CheckSum` is incorrect.
+    pub fn decode(bytes: &[u8]) -> Result<Self, FixError> {
+        let text = std::str::from_utf8(bytes)
+            .map_err(|e| FixError::Malformed(format!("invalid UTF-8, {e}")))?;
+        let text = text
+            .strip_suffix(SOH)
+            .ok_or_else(|| FixError::Malformed("not terminated by SOH".to_string()))?;
+
+        // Fields with the byte offset at which each starts
+        let mut fields = Vec::new();
+        let mut offset = 0;
+        for field in text.split(SOH) {
+            let (tag, value) = field
+                .split_once('=')
+                .filter(|(_, value)| !value.is_empty())
+                .ok_or_else(|| FixError::Malformed(format!("invalid field {field:?}")))?;
+            let tag: u32 = tag
+                .parse()
+                .map_err(|_| FixError::Malformed(format!("invalid tag in field {field:?}")))?;
+            fields.push((tag, value, offset));
+            offset += field.len() + 1;
+        }
+
+        if fields.len() < 4 {
+            return Err(FixError::Malformed(format!(
+                "expected at least 4 fields, was {}",
+                fields.len()
+            )));
+        }
+        let last_index = fields.len() - 1;
+        for (index, expected) in [
+            (0, BEGIN_STRING),
+            (1, BODY_LENGTH),
+            (2, MSG_TYPE),
+            (last_index, CHECK_SUM),
+        ] {
+            let (found, _, _) = fields[index];
+            if found != expected {
+                return Err(FixError::UnexpectedTag {
+                    expected,
+                    found,
+                    index,
+                });
+            }
+        }
+
+        let (_, declared_length, _) = fields[1];
+        let (_, _, body_start) = fields[2];
+        let (_, declared_checksum, checksum_start) = fields[last_index];
+        let body_length = checksum_start - body_start;
+        if declared_length.parse::<usize>().ok() != Some(body_length) {
+            return Err(FixError::BodyLength {
+                declared: declared_length.to_string(),
+                actual: body_length,
+            });
+        }
+        let calculated = checksum(&bytes[..checksum_start]);
+        if declared_checksum.len() != 3 || declared_checksum.parse::<u8>().ok() != Some(calculated)
+        {
+            return Err(FixError::CheckSum {
+                declared: declared_checksum.to_string(),
+                calculated,
+            });
+        }
+
+        Ok(Self {
+            begin_string: fields[0].1.to_string(),
+            fields: fields[2..last_index]
+                .iter()
+                .map(|(tag, value, _)| (*tag, (*value).to_string()))
+                .collect(),
+        })
+    }
+}
+
+/// The session fields of the standard header of a FIX message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixHeader {
+    pub sender_comp_id: String,
+    pub target_comp_id: String,
+    pub msg_seq_num: u64,
+    pub sending_time: UnixNanos,
+}
+
+impl FixHeader {
+    /// Creates a new [`FixHeader`] instance.
+    #[must_use]
+    pub const fn new(
+        sender_comp_id: String,
+        target_comp_id: String,
+        msg_seq_num: u64,
+        sending_time: UnixNanos,
+    ) -> Self {
+        Self {
+            sender_comp_id,
+            target_comp_id,
+            msg_seq_num,
+            sending_time,
+        }
+    }
+
+    /// Parses the header from the fields of the `message`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a header field is missing or invalid.
+    pub fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        Ok(Self {
+            sender_comp_id: message.required(SENDER_COMP_ID)?.to_string(),
+            target_comp_id: message.required(TARGET_COMP_ID)?.to_string(),
+            msg_seq_num: message.required_with(MSG_SEQ_NUM, |v| v.parse().ok())?,
+            sending_time: message.required_with(SENDING_TIME, parse_utc_timestamp)?,
+        })
+    }
+
+    /// Creates a new message of the given `msg_type` with the header fields, in tag order.
+    #[must_use]
+    pub fn to_fix(&self, msg_type: &str) -> FixMessage {
+        let mut message = FixMessage::new(msg_type);
+        message.push(MSG_SEQ_NUM, self.msg_seq_num);
+        message.push(SENDER_COMP_ID, &self.sender_comp_id);
+        message.push(SENDING_TIME, format_utc_timestamp(self.sending_time));
+        message.push(TARGET_COMP_ID, &self.target_comp_id);
+        message
+    }
+}
+
+/// An application message which converts to and from a [`FixMessage`].
+pub trait FixMessageBody: Sized {
+    /// The `MsgType` (35) of the message.
+    const MSG_TYPE: &'static str;
+
+    /// Parses the message from the fields of the `message`, ignoring unknown tags.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the `message` is of another message type.
+    /// - If a required field is missing, or any field is invalid.
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError>;
+
+    /// Converts the message to a [`FixMessage`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a field value has no FIX equivalent (e.g. a trailing stop order type).
+    fn to_fix(&self) -> Result<FixMessage, FixError>;
+
+    /// Decodes the message from bytes, see [`FixMessage::decode`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the bytes are not a valid FIX message, or not a valid message of this type.
+    fn decode(bytes: &[u8]) -> Result<Self, FixError> {
+        Self::from_fix(&FixMessage::decode(bytes)?)
+    }
+
+    /// Encodes the message to bytes, see [`FixMessage::encode`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a field value has no FIX equivalent.
+    fn encode(&self) -> Result<Vec<u8>, FixError> {
+        Ok(self.to_fix()?.encode())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::stubs::{fix_bytes, heartbeat_bytes, new_order_single_with_parties_bytes};
+
+    #[rstest]
+    fn test_checksum() {
+        assert_eq!(checksum(b""), 0);
+        assert_eq!(checksum(b"8=FIX.4.4\x01"), 33);
+        assert_eq!(checksum(&[255, 2]), 1);
+    }
+
+    #[rstest]
+    fn test_encode_computes_body_length_and_checksum(heartbeat_bytes: Vec<u8>) {
+        let header = FixHeader::new(
+            "VENUE".to_string(),
+            "CLIENT".to_string(),
+            7,
+            UnixNanos::from(1_729_002_630_000_000_000),
+        );
+
+        assert_eq!(header.to_fix("0").encode(), heartbeat_bytes);
+    }
+
+    #[rstest]
+    fn test_decode_heartbeat(heartbeat_bytes: Vec<u8>) {
+        let message = FixMessage::decode(&heartbeat_bytes).unwrap();
+
+        assert_eq!(message.begin_string, FIX_4_4);
+        assert_eq!(message.msg_type(), "0");
+        assert_eq!(message.get(SENDER_COMP_ID), Some("VENUE"));
+        assert_eq!(message.get(CHECK_SUM), None);
+        assert_eq!(
+            FixHeader::from_fix(&message).unwrap(),
+            FixHeader::new(
+                "VENUE".to_string(),
+                "CLIENT".to_string(),
+                7,
+                UnixNanos::from(1_729_002_630_000_000_000),
+            )
+        );
+    }
+
+    #[rstest]
+    fn test_decode_encode_preserves_unknown_tags(new_order_single_with_parties_bytes: Vec<u8>) {
+        let message = FixMessage::decode(&new_order_single_with_parties_bytes).unwrap();
+
+        assert_eq!(message.get(453), Some("1"));
+        assert_eq!(message.get(448), Some("TRADER1"));
+        assert_eq!(message.encode(), new_order_single_with_parties_bytes);
+    }
+
+    #[rstest]
+    fn test_decode_invalid_checksum() {
+        let bytes = fix_bytes(
+            "8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=008|",
+        );
+
+        assert_eq!(
+            FixMessage::decode(&bytes),
+            Err(FixError::CheckSum {
+                declared: "008".to_string(),
+                calculated: 7,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("7")]
+    #[case("0007")]
+    fn test_decode_checksum_must_be_three_digits(#[case] declared: &str) {
+        let bytes = fix_bytes(&format!(
+            "8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10={declared}|"
+        ));
+
+        assert!(matches!(
+            FixMessage::decode(&bytes),
+            Err(FixError::CheckSum { .. })
+        ));
+    }
+
+    #[rstest]
+    fn test_decode_invalid_body_length() {
+        let bytes = fix_bytes(
+            "8=FIX.4.4|9=55|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=008|",
+        );
+
+        assert_eq!(
+            FixMessage::decode(&bytes),
+            Err(FixError::BodyLength {
+                declared: "55".to_string(),
+                actual: 54,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_header_out_of_order() {
+        let bytes = fix_bytes(
+            "9=54|8=FIX.4.4|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=007|",
+        );
+
+        assert_eq!(
+            FixMessage::decode(&bytes),
+            Err(FixError::UnexpectedTag {
+                expected: BEGIN_STRING,
+                found: BODY_LENGTH,
+                index: 0,
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_decode_missing_checksum() {
+        let bytes =
+            fix_bytes("8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|");
+
+        assert_eq!(
+            FixMessage::decode(&bytes),
+            Err(FixError::UnexpectedTag {
+                expected: CHECK_SUM,
+                found: TARGET_COMP_ID,
+                index: 6,
+            })
+        );
+    }
+
+    #[rstest]
+    #[case("8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=007")]
+    #[case("8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56|10=007|")]
+    #[case("8=FIX.4.4|9=54|35=0|34=7|49=|52=20241015-14:30:30.000|56=CLIENT|10=007|")]
+    #[case("8=FIX.4.4|9=54|35=0|34=7|X=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=007|")]
+    #[case("8=FIX.4.4|9=0|10=000|")]
+    fn test_decode_malformed(#[case] message: &str) {
+        assert!(matches!(
+            FixMessage::decode(&fix_bytes(message)),
+            Err(FixError::Malformed(_))
+        ));
+    }
+
+    #[rstest]
+    fn test_required_and_optional_fields(heartbeat_bytes: Vec<u8>) {
+        let message = FixMessage::decode(&heartbeat_bytes).unwrap();
+
+        assert_eq!(message.required(MSG_SEQ_NUM), Ok("7"));
+        assert_eq!(message.required(58), Err(FixError::MissingTag(58)));
+        assert_eq!(
+            message.required_with(SENDER_COMP_ID, |v| v.parse::<u64>().ok()),
+            Err(FixError::InvalidValue {
+                tag: SENDER_COMP_ID,
+                value: "VENUE".to_string(),
+            })
+        );
+        assert_eq!(
+            message.optional_with(58, |v| v.parse::<u64>().ok()),
+            Ok(None)
+        );
+        assert_eq!(
+            message.expect_msg_type("D"),
+            Err(FixError::MsgType {
+                expected: "D".to_string(),
+                found: "0".to_string(),
+            })
+        );
+    }
+}
@@ -0,0 +1,43 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! FIX 4.4 encoding and decoding of the common order entry messages.
+//!
+//! A [`FixMessage`] holds the raw tag-value fields of a message, where the `BodyLength` and
+//! `CheckSum` are computed on encoding and validated on decoding. The order entry messages are
+//! converted to and from a [`FixMessage`], with fields mapped to the domain model types.
+//!
+//! Repeating groups (e.g. party IDs) are not yet supported, so their fields are skipped along
+//! with any other unknown tags.
+
+pub mod any;
+pub mod error;
+pub mod fields;
+pub mod message;
+pub mod order;
+pub mod report;
+pub mod tags;
+
+#[cfg(test)]
+pub mod stubs;
+
+// Re-exports
+pub use self::{
+    any::FixMessageAny,
+    error::FixError,
+    message::{FixHeader, FixMessage, FixMessageBody},
+    order::{NewOrderSingle, OrderCancelReplaceRequest, OrderCancelRequest},
+    report::{CxlRejResponseTo, ExecType, ExecutionReport, OrderCancelReject},
+};
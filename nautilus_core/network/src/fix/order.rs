@@ -0,0 +1,380 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The order entry messages sent to a venue.
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::{OrderSide, OrderType, TimeInForce},
+    identifiers::{ClientOrderId, Symbol, VenueOrderId},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::{
+    error::FixError,
+    fields::{
+        format_utc_timestamp, order_type_from_fix, order_type_to_fix, parse_price, parse_quantity,
+        parse_utc_timestamp, side_from_fix, side_to_fix, time_in_force_from_fix,
+        time_in_force_to_fix, to_fix_value,
+    },
+    message::{FixHeader, FixMessage, FixMessageBody},
+    tags::{
+        ACCOUNT, CL_ORD_ID, EXPIRE_TIME, ORDER_ID, ORDER_QTY, ORD_TYPE, ORIG_CL_ORD_ID, PRICE,
+        SIDE, STOP_PX, SYMBOL, TIME_IN_FORCE, TRANSACT_TIME,
+    },
+};
+
+pub(crate) fn parse_client_order_id(value: &str) -> Option<ClientOrderId> {
+    ClientOrderId::new_checked(value).ok()
+}
+
+pub(crate) fn parse_venue_order_id(value: &str) -> Option<VenueOrderId> {
+    VenueOrderId::new_checked(value).ok()
+}
+
+pub(crate) fn parse_symbol(value: &str) -> Option<Symbol> {
+    Symbol::new_checked(value).ok()
+}
+
+/// A `NewOrderSingle` (D) message, submitting a new order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NewOrderSingle {
+    pub header: FixHeader,
+    pub client_order_id: ClientOrderId,
+    pub account: Option<String>,
+    pub symbol: Symbol,
+    pub order_side: OrderSide,
+    pub transact_time: UnixNanos,
+    pub quantity: Quantity,
+    pub order_type: OrderType,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub time_in_force: Option<TimeInForce>,
+    pub expire_time: Option<UnixNanos>,
+}
+
+impl FixMessageBody for NewOrderSingle {
+    const MSG_TYPE: &'static str = "D";
+
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        message.expect_msg_type(Self::MSG_TYPE)?;
+        Ok(Self {
+            header: FixHeader::from_fix(message)?,
+            client_order_id: message.required_with(CL_ORD_ID, parse_client_order_id)?,
+            account: message.get(ACCOUNT).map(ToString::to_string),
+            symbol: message.required_with(SYMBOL, parse_symbol)?,
+            order_side: message.required_with(SIDE, side_from_fix)?,
+            transact_time: message.required_with(TRANSACT_TIME, parse_utc_timestamp)?,
+            quantity: message.required_with(ORDER_QTY, parse_quantity)?,
+            order_type: message.required_with(ORD_TYPE, order_type_from_fix)?,
+            price: message.optional_with(PRICE, parse_price)?,
+            trigger_price: message.optional_with(STOP_PX, parse_price)?,
+            time_in_force: message.optional_with(TIME_IN_FORCE, time_in_force_from_fix)?,
+            expire_time: message.optional_with(EXPIRE_TIME, parse_utc_timestamp)?,
+        })
+    }
+
+    fn to_fix(&self) -> Result<FixMessage, FixError> {
+        let mut message = self.header.to_fix(Self::MSG_TYPE);
+        message.push(CL_ORD_ID, self.client_order_id);
+        message.push_opt(ACCOUNT, self.account.as_ref());
+        message.push(SYMBOL, self.symbol);
+        message.push(SIDE, to_fix_value(SIDE, self.order_side, side_to_fix)?);
+        message.push(TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        message.push(ORDER_QTY, self.quantity);
+        message.push(
+            ORD_TYPE,
+            to_fix_value(ORD_TYPE, self.order_type, order_type_to_fix)?,
+        );
+        message.push_opt(PRICE, self.price);
+        message.push_opt(STOP_PX, self.trigger_price);
+        message.push_opt(TIME_IN_FORCE, self.time_in_force.map(time_in_force_to_fix));
+        message.push_opt(EXPIRE_TIME, self.expire_time.map(format_utc_timestamp));
+        Ok(message)
+    }
+}
+
+/// An `OrderCancelRequest` (F) message, requesting cancellation of an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelRequest {
+    pub header: FixHeader,
+    pub orig_client_order_id: ClientOrderId,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub client_order_id: ClientOrderId,
+    pub symbol: Symbol,
+    pub order_side: OrderSide,
+    pub transact_time: UnixNanos,
+    pub quantity: Option<Quantity>,
+}
+
+impl FixMessageBody for OrderCancelRequest {
+    const MSG_TYPE: &'static str = "F";
+
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        message.expect_msg_type(Self::MSG_TYPE)?;
+        Ok(Self {
+            header: FixHeader::from_fix(message)?,
+            orig_client_order_id: message.required_with(ORIG_CL_ORD_ID, parse_client_order_id)?,
+            venue_order_id: message.optional_with(ORDER_ID, parse_venue_order_id)?,
+            client_order_id: message.required_with(CL_ORD_ID, parse_client_order_id)?,
+            symbol: message.required_with(SYMBOL, parse_symbol)?,
+            order_side: message.required_with(SIDE, side_from_fix)?,
+            transact_time: message.required_with(TRANSACT_TIME, parse_utc_timestamp)?,
+            quantity: message.optional_with(ORDER_QTY, parse_quantity)?,
+        })
+    }
+
+    fn to_fix(&self) -> Result<FixMessage, FixError> {
+        let mut message = self.header.to_fix(Self::MSG_TYPE);
+        message.push(ORIG_CL_ORD_ID, self.orig_client_order_id);
+        message.push_opt(ORDER_ID, self.venue_order_id);
+        message.push(CL_ORD_ID, self.client_order_id);
+        message.push(SYMBOL, self.symbol);
+        message.push(SIDE, to_fix_value(SIDE, self.order_side, side_to_fix)?);
+        message.push(TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        message.push_opt(ORDER_QTY, self.quantity);
+        Ok(message)
+    }
+}
+
+/// An `OrderCancelReplaceRequest` (G) message, requesting modification of an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReplaceRequest {
+    pub header: FixHeader,
+    pub venue_order_id: Option<VenueOrderId>,
+    pub orig_client_order_id: ClientOrderId,
+    pub client_order_id: ClientOrderId,
+    pub symbol: Symbol,
+    pub order_side: OrderSide,
+    pub transact_time: UnixNanos,
+    pub quantity: Quantity,
+    pub order_type: OrderType,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub time_in_force: Option<TimeInForce>,
+    pub expire_time: Option<UnixNanos>,
+}
+
+impl FixMessageBody for OrderCancelReplaceRequest {
+    const MSG_TYPE: &'static str = "G";
+
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        message.expect_msg_type(Self::MSG_TYPE)?;
+        Ok(Self {
+            header: FixHeader::from_fix(message)?,
+            venue_order_id: message.optional_with(ORDER_ID, parse_venue_order_id)?,
+            orig_client_order_id: message.required_with(ORIG_CL_ORD_ID, parse_client_order_id)?,
+            client_order_id: message.required_with(CL_ORD_ID, parse_client_order_id)?,
+            symbol: message.required_with(SYMBOL, parse_symbol)?,
+            order_side: message.required_with(SIDE, side_from_fix)?,
+            transact_time: message.required_with(TRANSACT_TIME, parse_utc_timestamp)?,
+            quantity: message.required_with(ORDER_QTY, parse_quantity)?,
+            order_type: message.required_with(ORD_TYPE, order_type_from_fix)?,
+            price: message.optional_with(PRICE, parse_price)?,
+            trigger_price: message.optional_with(STOP_PX, parse_price)?,
+            time_in_force: message.optional_with(TIME_IN_FORCE, time_in_force_from_fix)?,
+            expire_time: message.optional_with(EXPIRE_TIME, parse_utc_timestamp)?,
+        })
+    }
+
+    fn to_fix(&self) -> Result<FixMessage, FixError> {
+        let mut message = self.header.to_fix(Self::MSG_TYPE);
+        message.push_opt(ORDER_ID, self.venue_order_id);
+        message.push(ORIG_CL_ORD_ID, self.orig_client_order_id);
+        message.push(CL_ORD_ID, self.client_order_id);
+        message.push(SYMBOL, self.symbol);
+        message.push(SIDE, to_fix_value(SIDE, self.order_side, side_to_fix)?);
+        message.push(TRANSACT_TIME, format_utc_timestamp(self.transact_time));
+        message.push(ORDER_QTY, self.quantity);
+        message.push(
+            ORD_TYPE,
+            to_fix_value(ORD_TYPE, self.order_type, order_type_to_fix)?,
+        );
+        message.push_opt(PRICE, self.price);
+        message.push_opt(STOP_PX, self.trigger_price);
+        message.push_opt(TIME_IN_FORCE, self.time_in_force.map(time_in_force_to_fix));
+        message.push_opt(EXPIRE_TIME, self.expire_time.map(format_utc_timestamp));
+        Ok(message)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::stubs::{
+        new_order_single_bytes, new_order_single_with_parties_bytes,
+        order_cancel_replace_request_bytes, order_cancel_request_bytes,
+    };
+
+    fn client_header(msg_seq_num: u64, sending_time: u64) -> FixHeader {
+        FixHeader::new(
+            "CLIENT".to_string(),
+            "VENUE".to_string(),
+            msg_seq_num,
+            UnixNanos::from(sending_time),
+        )
+    }
+
+    #[rstest]
+    fn test_new_order_single_decode(new_order_single_bytes: Vec<u8>) {
+        let order = NewOrderSingle::decode(&new_order_single_bytes).unwrap();
+
+        assert_eq!(
+            order,
+            NewOrderSingle {
+                header: client_header(2, 1_729_002_600_000_000_000),
+                client_order_id: ClientOrderId::from("O-20241015-001"),
+                account: Some("ACC-001".to_string()),
+                symbol: Symbol::from("EUR/USD"),
+                order_side: OrderSide::Buy,
+                transact_time: UnixNanos::from(1_729_002_600_000_000_000),
+                quantity: Quantity::from("100000"),
+                order_type: OrderType::Limit,
+                price: Some(Price::from("1.08250")),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::Gtc),
+                expire_time: None,
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_round_trip(new_order_single_bytes: Vec<u8>) {
+        let order = NewOrderSingle::decode(&new_order_single_bytes).unwrap();
+
+        assert_eq!(order.encode().unwrap(), new_order_single_bytes);
+    }
+
+    #[rstest]
+    fn test_new_order_single_skips_party_ids(
+        new_order_single_bytes: Vec<u8>,
+        new_order_single_with_parties_bytes: Vec<u8>,
+    ) {
+        let order = NewOrderSingle::decode(&new_order_single_with_parties_bytes).unwrap();
+
+        assert_eq!(
+            order,
+            NewOrderSingle::decode(&new_order_single_bytes).unwrap()
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_missing_tag(new_order_single_bytes: Vec<u8>) {
+        let mut message = FixMessage::decode(&new_order_single_bytes).unwrap();
+        message.fields.retain(|(tag, _)| *tag != ORDER_QTY);
+
+        assert_eq!(
+            NewOrderSingle::from_fix(&message),
+            Err(FixError::MissingTag(ORDER_QTY))
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_invalid_value(new_order_single_bytes: Vec<u8>) {
+        let mut message = FixMessage::decode(&new_order_single_bytes).unwrap();
+        for (tag, value) in &mut message.fields {
+            if *tag == SIDE {
+                *value = "9".to_string();
+            }
+        }
+
+        assert_eq!(
+            NewOrderSingle::from_fix(&message),
+            Err(FixError::InvalidValue {
+                tag: SIDE,
+                value: "9".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_unsupported_order_type(new_order_single_bytes: Vec<u8>) {
+        let mut order = NewOrderSingle::decode(&new_order_single_bytes).unwrap();
+        order.order_type = OrderType::TrailingStopMarket;
+
+        assert_eq!(
+            order.encode(),
+            Err(FixError::Unsupported {
+                tag: ORD_TYPE,
+                value: OrderType::TrailingStopMarket.to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_new_order_single_wrong_msg_type(order_cancel_request_bytes: Vec<u8>) {
+        assert_eq!(
+            NewOrderSingle::decode(&order_cancel_request_bytes),
+            Err(FixError::MsgType {
+                expected: "D".to_string(),
+                found: "F".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_order_cancel_request_round_trip(order_cancel_request_bytes: Vec<u8>) {
+        let request = OrderCancelRequest::decode(&order_cancel_request_bytes).unwrap();
+
+        assert_eq!(
+            request,
+            OrderCancelRequest {
+                header: client_header(3, 1_729_002_601_000_000_000),
+                orig_client_order_id: ClientOrderId::from("O-20241015-001"),
+                venue_order_id: Some(VenueOrderId::from("V-1001")),
+                client_order_id: ClientOrderId::from("O-20241015-002"),
+                symbol: Symbol::from("EUR/USD"),
+                order_side: OrderSide::Buy,
+                transact_time: UnixNanos::from(1_729_002_601_000_000_000),
+                quantity: Some(Quantity::from("100000")),
+            }
+        );
+        assert_eq!(request.encode().unwrap(), order_cancel_request_bytes);
+    }
+
+    #[rstest]
+    fn test_order_cancel_replace_request_round_trip(order_cancel_replace_request_bytes: Vec<u8>) {
+        let request =
+            OrderCancelReplaceRequest::decode(&order_cancel_replace_request_bytes).unwrap();
+
+        assert_eq!(
+            request,
+            OrderCancelReplaceRequest {
+                header: client_header(4, 1_729_002_602_000_000_000),
+                venue_order_id: Some(VenueOrderId::from("V-1001")),
+                orig_client_order_id: ClientOrderId::from("O-20241015-001"),
+                client_order_id: ClientOrderId::from("O-20241015-003"),
+                symbol: Symbol::from("EUR/USD"),
+                order_side: OrderSide::Buy,
+                transact_time: UnixNanos::from(1_729_002_602_000_000_000),
+                quantity: Quantity::from("50000"),
+                order_type: OrderType::StopLimit,
+                price: Some(Price::from("1.08200")),
+                trigger_price: Some(Price::from("1.08300")),
+                time_in_force: Some(TimeInForce::Gtd),
+                expire_time: Some(UnixNanos::from(1_729_112_400_000_000_000)),
+            }
+        );
+        assert_eq!(
+            request.encode().unwrap(),
+            order_cancel_replace_request_bytes
+        );
+    }
+}
@@ -0,0 +1,394 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! The execution messages received from a venue.
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::{
+    enums::{OrderSide, OrderStatus, OrderType, TimeInForce},
+    identifiers::{ClientOrderId, Symbol, TradeId, VenueOrderId},
+    types::{price::Price, quantity::Quantity},
+};
+
+use super::{
+    error::FixError,
+    fields::{
+        format_utc_timestamp, order_status_from_fix, order_status_to_fix, order_type_from_fix,
+        order_type_to_fix, parse_price, parse_quantity, parse_utc_timestamp, side_from_fix,
+        side_to_fix, time_in_force_from_fix, time_in_force_to_fix, to_fix_value,
+    },
+    message::{FixHeader, FixMessage, FixMessageBody},
+    order::{parse_client_order_id, parse_symbol, parse_venue_order_id},
+    tags::{
+        ACCOUNT, AVG_PX, CL_ORD_ID, CUM_QTY, CXL_REJ_REASON, CXL_REJ_RESPONSE_TO, EXEC_ID,
+        EXEC_TYPE, LAST_PX, LAST_QTY, LEAVES_QTY, ORDER_ID, ORDER_QTY, ORD_STATUS, ORD_TYPE,
+        ORIG_CL_ORD_ID, PRICE, SIDE, STOP_PX, SYMBOL, TEXT, TIME_IN_FORCE, TRANSACT_TIME,
+    },
+};
+
+/// The `ExecType` (150) of an execution report, describing the event which it reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExecType {
+    New,
+    DoneForDay,
+    Canceled,
+    Replaced,
+    PendingCancel,
+    Rejected,
+    PendingNew,
+    Expired,
+    PendingReplace,
+    Trade,
+    OrderStatus,
+}
+
+impl ExecType {
+    /// Returns the [`ExecType`] for the given FIX `value`, if supported.
+    #[must_use]
+    pub fn from_fix(value: &str) -> Option<Self> {
+        match value {
+            "0" => Some(Self::New),
+            "3" => Some(Self::DoneForDay),
+            "4" => Some(Self::Canceled),
+            "5" => Some(Self::Replaced),
+            "6" => Some(Self::PendingCancel),
+            "8" => Some(Self::Rejected),
+            "A" => Some(Self::PendingNew),
+            "C" => Some(Self::Expired),
+            "E" => Some(Self::PendingReplace),
+            "F" => Some(Self::Trade),
+            "I" => Some(Self::OrderStatus),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX value of the exec type.
+    #[must_use]
+    pub const fn as_fix(self) -> &'static str {
+        match self {
+            Self::New => "0",
+            Self::DoneForDay => "3",
+            Self::Canceled => "4",
+            Self::Replaced => "5",
+            Self::PendingCancel => "6",
+            Self::Rejected => "8",
+            Self::PendingNew => "A",
+            Self::Expired => "C",
+            Self::PendingReplace => "E",
+            Self::Trade => "F",
+            Self::OrderStatus => "I",
+        }
+    }
+}
+
+/// The `CxlRejResponseTo` (434) of an order cancel reject, identifying the rejected request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CxlRejResponseTo {
+    OrderCancelRequest,
+    OrderCancelReplaceRequest,
+}
+
+impl CxlRejResponseTo {
+    /// Returns the [`CxlRejResponseTo`] for the given FIX `value`, if supported.
+    #[must_use]
+    pub fn from_fix(value: &str) -> Option<Self> {
+        match value {
+            "1" => Some(Self::OrderCancelRequest),
+            "2" => Some(Self::OrderCancelReplaceRequest),
+            _ => None,
+        }
+    }
+
+    /// Returns the FIX value of the response.
+    #[must_use]
+    pub const fn as_fix(self) -> &'static str {
+        match self {
+            Self::OrderCancelRequest => "1",
+            Self::OrderCancelReplaceRequest => "2",
+        }
+    }
+}
+
+fn parse_trade_id(value: &str) -> Option<TradeId> {
+    TradeId::new_checked(value).ok()
+}
+
+/// An `ExecutionReport` (8) message, reporting a change in the state of an order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecutionReport {
+    pub header: FixHeader,
+    pub venue_order_id: VenueOrderId,
+    pub client_order_id: Option<ClientOrderId>,
+    pub orig_client_order_id: Option<ClientOrderId>,
+    pub exec_id: TradeId,
+    pub exec_type: ExecType,
+    pub order_status: OrderStatus,
+    pub account: Option<String>,
+    pub symbol: Symbol,
+    pub order_side: OrderSide,
+    pub quantity: Option<Quantity>,
+    pub order_type: Option<OrderType>,
+    pub price: Option<Price>,
+    pub trigger_price: Option<Price>,
+    pub time_in_force: Option<TimeInForce>,
+    pub last_qty: Option<Quantity>,
+    pub last_px: Option<Price>,
+    pub leaves_qty: Quantity,
+    pub cum_qty: Quantity,
+    pub avg_px: Price,
+    pub transact_time: Option<UnixNanos>,
+    pub text: Option<String>,
+}
+
+impl FixMessageBody for ExecutionReport {
+    const MSG_TYPE: &'static str = "8";
+
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        message.expect_msg_type(Self::MSG_TYPE)?;
+        Ok(Self {
+            header: FixHeader::from_fix(message)?,
+            venue_order_id: message.required_with(ORDER_ID, parse_venue_order_id)?,
+            client_order_id: message.optional_with(CL_ORD_ID, parse_client_order_id)?,
+            orig_client_order_id: message.optional_with(ORIG_CL_ORD_ID, parse_client_order_id)?,
+            exec_id: message.required_with(EXEC_ID, parse_trade_id)?,
+            exec_type: message.required_with(EXEC_TYPE, ExecType::from_fix)?,
+            order_status: message.required_with(ORD_STATUS, order_status_from_fix)?,
+            account: message.get(ACCOUNT).map(ToString::to_string),
+            symbol: message.required_with(SYMBOL, parse_symbol)?,
+            order_side: message.required_with(SIDE, side_from_fix)?,
+            quantity: message.optional_with(ORDER_QTY, parse_quantity)?,
+            order_type: message.optional_with(ORD_TYPE, order_type_from_fix)?,
+            price: message.optional_with(PRICE, parse_price)?,
+            trigger_price: message.optional_with(STOP_PX, parse_price)?,
+            time_in_force: message.optional_with(TIME_IN_FORCE, time_in_force_from_fix)?,
+            last_qty: message.optional_with(LAST_QTY, parse_quantity)?,
+            last_px: message.optional_with(LAST_PX, parse_price)?,
+            leaves_qty: message.required_with(LEAVES_QTY, parse_quantity)?,
+            cum_qty: message.required_with(CUM_QTY, parse_quantity)?,
+            avg_px: message.required_with(AVG_PX, parse_price)?,
+            transact_time: message.optional_with(TRANSACT_TIME, parse_utc_timestamp)?,
+            text: message.get(TEXT).map(ToString::to_string),
+        })
+    }
+
+    fn to_fix(&self) -> Result<FixMessage, FixError> {
+        let mut message = self.header.to_fix(Self::MSG_TYPE);
+        message.push(ORDER_ID, self.venue_order_id);
+        message.push_opt(CL_ORD_ID, self.client_order_id);
+        message.push_opt(ORIG_CL_ORD_ID, self.orig_client_order_id);
+        message.push(EXEC_ID, self.exec_id);
+        message.push(EXEC_TYPE, self.exec_type.as_fix());
+        message.push(
+            ORD_STATUS,
+            to_fix_value(ORD_STATUS, self.order_status, order_status_to_fix)?,
+        );
+        message.push_opt(ACCOUNT, self.account.as_ref());
+        message.push(SYMBOL, self.symbol);
+        message.push(SIDE, to_fix_value(SIDE, self.order_side, side_to_fix)?);
+        message.push_opt(ORDER_QTY, self.quantity);
+        message.push_opt(
+            ORD_TYPE,
+            self.order_type
+                .map(|order_type| to_fix_value(ORD_TYPE, order_type, order_type_to_fix))
+                .transpose()?,
+        );
+        message.push_opt(PRICE, self.price);
+        message.push_opt(STOP_PX, self.trigger_price);
+        message.push_opt(TIME_IN_FORCE, self.time_in_force.map(time_in_force_to_fix));
+        message.push_opt(LAST_QTY, self.last_qty);
+        message.push_opt(LAST_PX, self.last_px);
+        message.push(LEAVES_QTY, self.leaves_qty);
+        message.push(CUM_QTY, self.cum_qty);
+        message.push(AVG_PX, self.avg_px);
+        message.push_opt(TRANSACT_TIME, self.transact_time.map(format_utc_timestamp));
+        message.push_opt(TEXT, self.text.as_ref());
+        Ok(message)
+    }
+}
+
+/// An `OrderCancelReject` (9) message, rejecting a cancel or cancel/replace request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OrderCancelReject {
+    pub header: FixHeader,
+    pub venue_order_id: VenueOrderId,
+    pub client_order_id: ClientOrderId,
+    pub orig_client_order_id: ClientOrderId,
+    pub order_status: OrderStatus,
+    pub account: Option<String>,
+    pub response_to: CxlRejResponseTo,
+    pub reason: Option<u32>,
+    pub text: Option<String>,
+}
+
+impl FixMessageBody for OrderCancelReject {
+    const MSG_TYPE: &'static str = "9";
+
+    fn from_fix(message: &FixMessage) -> Result<Self, FixError> {
+        message.expect_msg_type(Self::MSG_TYPE)?;
+        Ok(Self {
+            header: FixHeader::from_fix(message)?,
+            venue_order_id: message.required_with(ORDER_ID, parse_venue_order_id)?,
+            client_order_id: message.required_with(CL_ORD_ID, parse_client_order_id)?,
+            orig_client_order_id: message.required_with(ORIG_CL_ORD_ID, parse_client_order_id)?,
+            order_status: message.required_with(ORD_STATUS, order_status_from_fix)?,
+            account: message.get(ACCOUNT).map(ToString::to_string),
+            response_to: message.required_with(CXL_REJ_RESPONSE_TO, CxlRejResponseTo::from_fix)?,
+            reason: message.optional_with(CXL_REJ_REASON, |v| v.parse().ok())?,
+            text: message.get(TEXT).map(ToString::to_string),
+        })
+    }
+
+    fn to_fix(&self) -> Result<FixMessage, FixError> {
+        let mut message = self.header.to_fix(Self::MSG_TYPE);
+        message.push(ORDER_ID, self.venue_order_id);
+        message.push(CL_ORD_ID, self.client_order_id);
+        message.push(ORIG_CL_ORD_ID, self.orig_client_order_id);
+        message.push(
+            ORD_STATUS,
+            to_fix_value(ORD_STATUS, self.order_status, order_status_to_fix)?,
+        );
+        message.push_opt(ACCOUNT, self.account.as_ref());
+        message.push(CXL_REJ_RESPONSE_TO, self.response_to.as_fix());
+        message.push_opt(CXL_REJ_REASON, self.reason);
+        message.push_opt(TEXT, self.text.as_ref());
+        Ok(message)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::fix::stubs::{execution_report_fill_bytes, order_cancel_reject_bytes};
+
+    fn venue_header(msg_seq_num: u64, sending_time: u64) -> FixHeader {
+        FixHeader::new(
+            "VENUE".to_string(),
+            "CLIENT".to_string(),
+            msg_seq_num,
+            UnixNanos::from(sending_time),
+        )
+    }
+
+    #[rstest]
+    #[case(ExecType::New)]
+    #[case(ExecType::DoneForDay)]
+    #[case(ExecType::Canceled)]
+    #[case(ExecType::Replaced)]
+    #[case(ExecType::PendingCancel)]
+    #[case(ExecType::Rejected)]
+    #[case(ExecType::PendingNew)]
+    #[case(ExecType::Expired)]
+    #[case(ExecType::PendingReplace)]
+    #[case(ExecType::Trade)]
+    #[case(ExecType::OrderStatus)]
+    fn test_exec_type_round_trip(#[case] exec_type: ExecType) {
+        assert_eq!(ExecType::from_fix(exec_type.as_fix()), Some(exec_type));
+    }
+
+    #[rstest]
+    fn test_execution_report_decode(execution_report_fill_bytes: Vec<u8>) {
+        let report = ExecutionReport::decode(&execution_report_fill_bytes).unwrap();
+
+        assert_eq!(
+            report,
+            ExecutionReport {
+                header: venue_header(5, 1_729_002_600_250_000_000),
+                venue_order_id: VenueOrderId::from("V-1001"),
+                client_order_id: Some(ClientOrderId::from("O-20241015-001")),
+                orig_client_order_id: None,
+                exec_id: TradeId::from("E-2001"),
+                exec_type: ExecType::Trade,
+                order_status: OrderStatus::PartiallyFilled,
+                account: Some("ACC-001".to_string()),
+                symbol: Symbol::from("EUR/USD"),
+                order_side: OrderSide::Buy,
+                quantity: Some(Quantity::from("100000")),
+                order_type: Some(OrderType::Limit),
+                price: Some(Price::from("1.08250")),
+                trigger_price: None,
+                time_in_force: Some(TimeInForce::Gtc),
+                last_qty: Some(Quantity::from("40000")),
+                last_px: Some(Price::from("1.08245")),
+                leaves_qty: Quantity::from("60000"),
+                cum_qty: Quantity::from("40000"),
+                avg_px: Price::from("1.08245"),
+                transact_time: Some(UnixNanos::from(1_729_002_600_125_000_000)),
+                text: None,
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_execution_report_round_trip(execution_report_fill_bytes: Vec<u8>) {
+        let report = ExecutionReport::decode(&execution_report_fill_bytes).unwrap();
+
+        assert_eq!(report.encode().unwrap(), execution_report_fill_bytes);
+    }
+
+    #[rstest]
+    fn test_execution_report_missing_tag(execution_report_fill_bytes: Vec<u8>) {
+        let mut message = FixMessage::decode(&execution_report_fill_bytes).unwrap();
+        message.fields.retain(|(tag, _)| *tag != EXEC_ID);
+
+        assert_eq!(
+            ExecutionReport::from_fix(&message),
+            Err(FixError::MissingTag(EXEC_ID))
+        );
+    }
+
+    #[rstest]
+    fn test_execution_report_unknown_exec_type(execution_report_fill_bytes: Vec<u8>) {
+        let mut message = FixMessage::decode(&execution_report_fill_bytes).unwrap();
+        for (tag, value) in &mut message.fields {
+            if *tag == EXEC_TYPE {
+                *value = "Z".to_string();
+            }
+        }
+
+        assert_eq!(
+            ExecutionReport::from_fix(&message),
+            Err(FixError::InvalidValue {
+                tag: EXEC_TYPE,
+                value: "Z".to_string(),
+            })
+        );
+    }
+
+    #[rstest]
+    fn test_order_cancel_reject_round_trip(order_cancel_reject_bytes: Vec<u8>) {
+        let reject = OrderCancelReject::decode(&order_cancel_reject_bytes).unwrap();
+
+        assert_eq!(
+            reject,
+            OrderCancelReject {
+                header: venue_header(6, 1_729_002_603_000_000_000),
+                venue_order_id: VenueOrderId::from("V-1001"),
+                client_order_id: ClientOrderId::from("O-20241015-002"),
+                orig_client_order_id: ClientOrderId::from("O-20241015-001"),
+                order_status: OrderStatus::Filled,
+                account: None,
+                response_to: CxlRejResponseTo::OrderCancelRequest,
+                reason: Some(0),
+                text: Some("TOO LATE TO CANCEL".to_string()),
+            }
+        );
+        assert_eq!(reject.encode().unwrap(), order_cancel_reject_bytes);
+    }
+}
@@ -0,0 +1,84 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Captured FIX messages to facilitate testing.
+
+use rstest::fixture;
+
+/// Converts a FIX message written with `|` delimiters to bytes delimited by SOH.
+#[must_use]
+pub fn fix_bytes(message: &str) -> Vec<u8> {
+    message.replace('|', "\x01").into_bytes()
+}
+
+#[fixture]
+pub fn new_order_single_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=154|35=D|34=2|49=CLIENT|52=20241015-14:30:00.000|56=VENUE|\
+         11=O-20241015-001|1=ACC-001|55=EUR/USD|54=1|60=20241015-14:30:00.000|38=100000|40=2|\
+         44=1.08250|59=1|10=097|",
+    )
+}
+
+/// The same order as [`new_order_single_bytes`], with a party IDs repeating group.
+#[fixture]
+pub fn new_order_single_with_parties_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=185|35=D|34=2|49=CLIENT|52=20241015-14:30:00.000|56=VENUE|\
+         11=O-20241015-001|1=ACC-001|453=1|448=TRADER1|447=D|452=11|55=EUR/USD|54=1|\
+         60=20241015-14:30:00.000|38=100000|40=2|44=1.08250|59=1|10=157|",
+    )
+}
+
+#[fixture]
+pub fn order_cancel_request_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=151|35=F|34=3|49=CLIENT|52=20241015-14:30:01.000|56=VENUE|\
+         41=O-20241015-001|37=V-1001|11=O-20241015-002|55=EUR/USD|54=1|\
+         60=20241015-14:30:01.000|38=100000|10=020|",
+    )
+}
+
+#[fixture]
+pub fn order_cancel_replace_request_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=208|35=G|34=4|49=CLIENT|52=20241015-14:30:02.000|56=VENUE|37=V-1001|\
+         41=O-20241015-001|11=O-20241015-003|55=EUR/USD|54=1|60=20241015-14:30:02.000|\
+         38=50000|40=4|44=1.08200|99=1.08300|59=6|126=20241016-21:00:00.000|10=154|",
+    )
+}
+
+#[fixture]
+pub fn execution_report_fill_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=234|35=8|34=5|49=VENUE|52=20241015-14:30:00.250|56=CLIENT|37=V-1001|\
+         11=O-20241015-001|17=E-2001|150=F|39=1|1=ACC-001|55=EUR/USD|54=1|38=100000|40=2|\
+         44=1.08250|59=1|32=40000|31=1.08245|151=60000|14=40000|6=1.08245|\
+         60=20241015-14:30:00.125|10=246|",
+    )
+}
+
+#[fixture]
+pub fn order_cancel_reject_bytes() -> Vec<u8> {
+    fix_bytes(
+        "8=FIX.4.4|9=139|35=9|34=6|49=VENUE|52=20241015-14:30:03.000|56=CLIENT|37=V-1001|\
+         11=O-20241015-002|41=O-20241015-001|39=2|434=1|102=0|58=TOO LATE TO CANCEL|10=089|",
+    )
+}
+
+#[fixture]
+pub fn heartbeat_bytes() -> Vec<u8> {
+    fix_bytes("8=FIX.4.4|9=54|35=0|34=7|49=VENUE|52=20241015-14:30:30.000|56=CLIENT|10=007|")
+}
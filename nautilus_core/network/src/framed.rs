@@ -0,0 +1,716 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A raw TCP client which splits the byte stream into frames (e.g. for FIX-style feeds), and
+//! reconnects like the [`ReconnectingWebSocketClient`](crate::reconnecting::ReconnectingWebSocketClient).
+
+use std::time::Duration;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use nautilus_cryptography::providers::install_cryptographic_provider;
+use tokio::{
+    io::{split, AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    sync::{mpsc, watch},
+    task,
+    time::{interval_at, sleep, Instant},
+};
+use tokio_tungstenite::{
+    tungstenite::{self, client::IntoClientRequest, stream::Mode},
+    MaybeTlsStream,
+};
+
+use crate::{
+    backoff::ExponentialBackoff,
+    reconnecting::{tick, ConnectionState},
+    tls::tcp_tls,
+};
+
+type Stream = MaybeTlsStream<TcpStream>;
+
+/// How a byte stream is split into frames.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Framing {
+    /// Each frame is preceded by a big-endian length header of `header_len` bytes (1, 2, 4 or 8),
+    /// which excludes the header itself.
+    LengthPrefixed { header_len: usize },
+    /// Each frame is terminated by the given byte sequence (e.g. SOH).
+    Delimited(Vec<u8>),
+    /// Each frame is terminated by `\n`, optionally preceded by `\r`.
+    Newline,
+}
+
+impl Framing {
+    /// Removes and returns the next complete frame (without its header or terminator) from the
+    /// front of `buf`, or `None` if `buf` does not yet hold a complete frame.
+    ///
+    /// # Panics
+    ///
+    /// This function panics:
+    /// - If a length header is not 1, 2, 4 or 8 bytes.
+    /// - If a delimiter is empty.
+    pub fn decode(&self, buf: &mut BytesMut) -> Option<Bytes> {
+        match self {
+            Self::LengthPrefixed { header_len } => {
+                let header_len = *header_len;
+                assert!(
+                    matches!(header_len, 1 | 2 | 4 | 8),
+                    "Invalid length header size {header_len}, must be 1, 2, 4 or 8 bytes"
+                );
+                if buf.len() < header_len {
+                    return None;
+                }
+                let frame_len = usize::try_from((&buf[..header_len]).get_uint(header_len))
+                    .unwrap_or(usize::MAX);
+                if buf.len() - header_len < frame_len {
+                    return None;
+                }
+                buf.advance(header_len);
+                Some(buf.split_to(frame_len).freeze())
+            }
+            Self::Delimited(delimiter) => {
+                assert!(!delimiter.is_empty(), "Frame delimiter was empty");
+                let end = buf
+                    .windows(delimiter.len())
+                    .position(|window| window == delimiter.as_slice())?;
+                let frame = buf.split_to(end).freeze();
+                buf.advance(delimiter.len());
+                Some(frame)
+            }
+            Self::Newline => {
+                let end = buf.iter().position(|byte| *byte == b'\n')?;
+                let mut frame = buf.split_to(end);
+                buf.advance(1);
+                if frame.last() == Some(&b'\r') {
+                    frame.truncate(end - 1);
+                }
+                Some(frame.freeze())
+            }
+        }
+    }
+
+    /// Appends `payload` framed for sending to `buf`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the payload is too long for the length header.
+    pub fn encode(&self, payload: &[u8], buf: &mut BytesMut) -> Result<(), FramedSocketError> {
+        match self {
+            Self::LengthPrefixed { header_len } => {
+                let max = if *header_len >= 8 {
+                    u64::MAX
+                } else {
+                    (1 << (header_len * 8)) - 1
+                };
+                let len = payload.len() as u64;
+                if len > max {
+                    return Err(FramedSocketError::FrameTooLarge { len, max });
+                }
+                buf.put_uint(len, *header_len);
+                buf.put_slice(payload);
+            }
+            Self::Delimited(delimiter) => {
+                buf.put_slice(payload);
+                buf.put_slice(delimiter);
+            }
+            Self::Newline => {
+                buf.put_slice(payload);
+                buf.put_u8(b'\n');
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Configuration for a [`FramedSocketClient`].
+#[derive(Clone, Debug)]
+pub struct FramedSocketConfig {
+    /// The address of the server (`host:port`).
+    pub url: String,
+    /// The connection mode {Plain, TLS}.
+    pub mode: Mode,
+    /// How the byte stream is split into frames.
+    pub framing: Framing,
+    /// The optional heartbeat with period and message (framed before sending).
+    pub heartbeat: Option<(Duration, Vec<u8>)>,
+    /// The delay before the first reconnection attempt.
+    pub reconnect_delay_initial: Duration,
+    /// The maximum delay between reconnection attempts.
+    pub reconnect_delay_max: Duration,
+    /// The factor by which the delay grows after each failed reconnection attempt.
+    pub reconnect_backoff_factor: f64,
+    /// The fraction by which each reconnection delay is randomized.
+    pub reconnect_jitter: f64,
+    /// The number of failed reconnection attempts after which the client faults, if limited.
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl FramedSocketConfig {
+    /// Creates a new [`FramedSocketConfig`] for a plain connection to `url` with default settings.
+    #[must_use]
+    pub fn new(url: impl Into<String>, framing: Framing) -> Self {
+        Self {
+            url: url.into(),
+            mode: Mode::Plain,
+            framing,
+            heartbeat: None,
+            reconnect_delay_initial: Duration::from_millis(500),
+            reconnect_delay_max: Duration::from_secs(30),
+            reconnect_backoff_factor: 2.0,
+            reconnect_jitter: 0.2,
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+/// An event surfaced by a [`FramedSocketClient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FramedSocketEvent {
+    /// A complete frame received from the server (without its header or terminator).
+    Frame(Bytes),
+    /// The connection state changed.
+    StateChanged {
+        previous: ConnectionState,
+        current: ConnectionState,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum FramedSocketError {
+    #[error("Socket connection failed: {0}")]
+    Connect(#[from] tungstenite::Error),
+
+    #[error("Frame of {len} bytes exceeds the maximum of {max} bytes")]
+    FrameTooLarge { len: u64, max: u64 },
+
+    #[error("Socket client is closed")]
+    Closed,
+}
+
+#[derive(Debug)]
+enum Command {
+    Send(Bytes),
+    Disconnect,
+}
+
+/// A TCP client (optionally over TLS) delivering complete frames of the byte stream.
+///
+/// The connection is owned by a background task. Frames received from the server and
+/// connection state changes are surfaced as [`FramedSocketEvent`]s on the channel returned by
+/// [`FramedSocketClient::connect`], however the frames were fragmented across TCP segments.
+///
+/// When the connection drops the client reconnects with exponential backoff, buffering frames
+/// sent meanwhile, with the same states as the reconnecting WebSocket client.
+///
+/// Dropping the client shuts down the connection.
+#[derive(Debug)]
+pub struct FramedSocketClient {
+    framing: Framing,
+    commands: mpsc::UnboundedSender<Command>,
+    state: watch::Receiver<ConnectionState>,
+}
+
+impl FramedSocketClient {
+    /// Connects to the server, returning the client and the receiver of its events.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the initial connection fails.
+    pub async fn connect(
+        config: FramedSocketConfig,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<FramedSocketEvent>), FramedSocketError> {
+        install_cryptographic_provider();
+
+        tracing::debug!("Connecting to {}", config.url);
+        let stream = connect_stream(&config.url, config.mode).await?;
+
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (state_tx, state_rx) = watch::channel(ConnectionState::Connecting);
+
+        let framing = config.framing.clone();
+        let mut connection = Connection {
+            backoff: ExponentialBackoff::new(
+                config.reconnect_delay_initial,
+                config.reconnect_delay_max,
+                config.reconnect_backoff_factor,
+                config.reconnect_jitter,
+            ),
+            config,
+            commands: command_rx,
+            events: event_tx,
+            state: state_tx,
+            pending: Vec::new(),
+        };
+        connection.set_state(ConnectionState::Connected);
+        task::spawn(connection.run(stream));
+
+        let client = Self {
+            framing,
+            commands: command_tx,
+            state: state_rx,
+        };
+        Ok((client, event_rx))
+    }
+
+    /// Returns the current connection state.
+    #[must_use]
+    pub fn state(&self) -> ConnectionState {
+        *self.state.borrow()
+    }
+
+    /// Sends `payload` as a frame to the server, buffering it while reconnecting.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the payload is too long for the framing, or the client
+    /// is disconnected or faulted.
+    pub fn send(&self, payload: &[u8]) -> Result<(), FramedSocketError> {
+        let mut buf = BytesMut::with_capacity(payload.len() + 8);
+        self.framing.encode(payload, &mut buf)?;
+        self.commands
+            .send(Command::Send(buf.freeze()))
+            .map_err(|_| FramedSocketError::Closed)
+    }
+
+    /// Shuts down the connection, waiting until the client is disconnected.
+    pub async fn disconnect(&self) {
+        tracing::debug!("Disconnecting");
+        // The connection task may already have stopped if the client faulted
+        let _ = self.commands.send(Command::Disconnect);
+        let mut state = self.state.clone();
+        let _ = state.wait_for(|state| state.is_terminal()).await;
+    }
+}
+
+/// How a connected session ended.
+enum SessionEnd {
+    Disconnect,
+    ConnectionLost,
+}
+
+/// The connection task state.
+struct Connection {
+    config: FramedSocketConfig,
+    backoff: ExponentialBackoff,
+    commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::UnboundedSender<FramedSocketEvent>,
+    state: watch::Sender<ConnectionState>,
+    pending: Vec<Bytes>,
+}
+
+impl Connection {
+    async fn run(mut self, mut stream: Stream) {
+        loop {
+            match self.run_session(stream).await {
+                SessionEnd::Disconnect => {
+                    self.set_state(ConnectionState::Disconnected);
+                    return;
+                }
+                SessionEnd::ConnectionLost => {
+                    self.set_state(ConnectionState::Reconnecting);
+                }
+            }
+
+            stream = match self.reconnect().await {
+                Some(stream) => stream,
+                None => return,
+            };
+        }
+    }
+
+    /// Runs a connected session until the connection is lost or a disconnect is requested.
+    async fn run_session(&mut self, stream: Stream) -> SessionEnd {
+        let (mut reader, mut writer) = split(stream);
+
+        // Flush frames sent while reconnecting
+        while let Some(data) = self.pending.first() {
+            if let Err(e) = writer.write_all(data).await {
+                tracing::error!("Error sending buffered frame: {e}");
+                return SessionEnd::ConnectionLost;
+            }
+            self.pending.remove(0);
+        }
+        self.set_state(ConnectionState::Connected);
+
+        let mut heartbeat = self
+            .config
+            .heartbeat
+            .as_ref()
+            .map(|(period, _)| interval_at(Instant::now() + *period, *period));
+        let mut heartbeat_message = BytesMut::new();
+        if let Some((_, message)) = &self.config.heartbeat {
+            if let Err(e) = self.config.framing.encode(message, &mut heartbeat_message) {
+                tracing::error!("Invalid heartbeat message: {e}");
+            }
+        }
+        let mut buf = BytesMut::with_capacity(8 * 1024);
+
+        loop {
+            tokio::select! {
+                read = reader.read_buf(&mut buf) => match read {
+                    Ok(0) => {
+                        tracing::error!("Connection lost: stream ended");
+                        return SessionEnd::ConnectionLost;
+                    }
+                    Ok(bytes) => {
+                        tracing::trace!("Received <binary> {bytes} bytes");
+                        while let Some(frame) = self.config.framing.decode(&mut buf) {
+                            let _ = self.events.send(FramedSocketEvent::Frame(frame));
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Connection lost: {e}");
+                        return SessionEnd::ConnectionLost;
+                    }
+                },
+                command = self.commands.recv() => match command {
+                    Some(Command::Send(data)) => {
+                        if let Err(e) = writer.write_all(&data).await {
+                            tracing::error!("Error sending frame: {e}");
+                            self.pending.push(data);
+                            return SessionEnd::ConnectionLost;
+                        }
+                    }
+                    // All client handles dropped, so shut down the connection
+                    Some(Command::Disconnect) | None => {
+                        self.set_state(ConnectionState::Disconnecting);
+                        if let Err(e) = writer.shutdown().await {
+                            tracing::debug!("Error shutting down writer: {e}");
+                        }
+                        return SessionEnd::Disconnect;
+                    }
+                },
+                () = tick(heartbeat.as_mut()) => {
+                    tracing::trace!("Sending heartbeat");
+                    if let Err(e) = writer.write_all(&heartbeat_message).await {
+                        tracing::error!("Error sending heartbeat: {e}");
+                        return SessionEnd::ConnectionLost;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reconnects with backoff, returning `None` if disconnected or out of attempts meanwhile.
+    async fn reconnect(&mut self) -> Option<Stream> {
+        let mut attempts = 0;
+        loop {
+            if self
+                .config
+                .max_reconnect_attempts
+                .is_some_and(|max| attempts >= max)
+            {
+                tracing::error!("Failed to reconnect after {attempts} attempts");
+                self.set_state(ConnectionState::Faulted);
+                return None;
+            }
+
+            let delay = sleep(self.backoff.next_delay());
+            tokio::pin!(delay);
+            loop {
+                tokio::select! {
+                    () = &mut delay => break,
+                    command = self.commands.recv() => match command {
+                        Some(Command::Send(data)) => self.pending.push(data),
+                        Some(Command::Disconnect) | None => {
+                            self.set_state(ConnectionState::Disconnecting);
+                            self.set_state(ConnectionState::Disconnected);
+                            return None;
+                        }
+                    },
+                }
+            }
+
+            attempts += 1;
+            tracing::debug!("Reconnecting to {} (attempt {attempts})", self.config.url);
+            match connect_stream(&self.config.url, self.config.mode).await {
+                Ok(stream) => {
+                    tracing::info!("Reconnected to {}", self.config.url);
+                    self.backoff.reset();
+                    return Some(stream);
+                }
+                Err(e) => tracing::warn!("Reconnection attempt {attempts} failed: {e}"),
+            }
+        }
+    }
+
+    fn set_state(&mut self, current: ConnectionState) {
+        if current.is_terminal() {
+            // Reject further commands before the state change is observed
+            self.commands.close();
+        }
+        let previous = self.state.send_replace(current);
+        if previous != current {
+            tracing::debug!("Connection state changed: {previous:?} -> {current:?}");
+            let _ = self
+                .events
+                .send(FramedSocketEvent::StateChanged { previous, current });
+        }
+    }
+}
+
+async fn connect_stream(url: &str, mode: Mode) -> Result<Stream, tungstenite::Error> {
+    let stream = TcpStream::connect(url).await?;
+    stream.set_nodelay(true)?;
+    let request = url.into_client_request()?;
+    tcp_tls(&request, mode, stream, None).await
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rstest::rstest;
+    use tokio::{net::TcpListener, sync::broadcast, task::JoinHandle, time::timeout};
+
+    use super::*;
+
+    /// A server echoing back the bytes received on each connection, written in fragments of
+    /// `fragment_len` bytes (each in its own TCP segment) to exercise frame reassembly.
+    pub(crate) struct FragmentingServer {
+        pub(crate) port: u16,
+        received: Arc<Mutex<Vec<Vec<u8>>>>,
+        drop_signal: broadcast::Sender<()>,
+        task: JoinHandle<()>,
+    }
+
+    impl FragmentingServer {
+        /// Starts the server, writing `greeting` (in fragments) to each new connection.
+        pub(crate) async fn start(greeting: Vec<u8>, fragment_len: usize) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let received = Arc::new(Mutex::new(Vec::<Vec<u8>>::new()));
+            let (drop_signal, _) = broadcast::channel(1);
+
+            let task = task::spawn({
+                let received = received.clone();
+                let drop_signal = drop_signal.clone();
+                async move {
+                    loop {
+                        let (mut stream, _) = listener.accept().await.unwrap();
+                        stream.set_nodelay(true).unwrap();
+                        let index = {
+                            let mut received = received.lock().unwrap();
+                            received.push(Vec::new());
+                            received.len() - 1
+                        };
+                        let received = received.clone();
+                        let mut drop_rx = drop_signal.subscribe();
+                        let greeting = greeting.clone();
+
+                        task::spawn(async move {
+                            write_fragmented(&mut stream, &greeting, fragment_len).await;
+                            let mut buf = vec![0; 1024];
+                            loop {
+                                tokio::select! {
+                                    read = stream.read(&mut buf) => match read {
+                                        Ok(0) | Err(_) => break,
+                                        Ok(n) => {
+                                            received.lock().unwrap()[index]
+                                                .extend_from_slice(&buf[..n]);
+                                            let data = buf[..n].to_vec();
+                                            write_fragmented(&mut stream, &data, fragment_len)
+                                                .await;
+                                        }
+                                    },
+                                    _ = drop_rx.recv() => break,
+                                }
+                            }
+                        });
+                    }
+                }
+            });
+
+            Self {
+                port,
+                received,
+                drop_signal,
+                task,
+            }
+        }
+
+        pub(crate) fn url(&self) -> String {
+            format!("127.0.0.1:{}", self.port)
+        }
+
+        fn received(&self) -> Vec<Vec<u8>> {
+            self.received.lock().unwrap().clone()
+        }
+
+        fn drop_connections(&self) {
+            let _ = self.drop_signal.send(());
+        }
+    }
+
+    impl Drop for FragmentingServer {
+        fn drop(&mut self) {
+            self.drop_connections();
+            self.task.abort();
+        }
+    }
+
+    async fn write_fragmented(stream: &mut TcpStream, data: &[u8], fragment_len: usize) {
+        for chunk in data.chunks(fragment_len) {
+            if stream.write_all(chunk).await.is_err() {
+                return;
+            }
+            let _ = stream.flush().await;
+            sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    async fn wait_until(condition: impl Fn() -> bool) {
+        timeout(Duration::from_secs(5), async {
+            while !condition() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for condition");
+    }
+
+    async fn next_frame(events: &mut mpsc::UnboundedReceiver<FramedSocketEvent>) -> Bytes {
+        timeout(Duration::from_secs(5), async {
+            loop {
+                if let FramedSocketEvent::Frame(frame) = events.recv().await.unwrap() {
+                    return frame;
+                }
+            }
+        })
+        .await
+        .expect("timed out waiting for frame")
+    }
+
+    fn encoded(framing: &Framing, payloads: &[&[u8]]) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        for payload in payloads {
+            framing.encode(payload, &mut buf).unwrap();
+        }
+        buf.to_vec()
+    }
+
+    #[rstest]
+    #[case(Framing::LengthPrefixed { header_len: 2 }, b"\x00\x03abc\x00\x00".as_slice())]
+    #[case(Framing::Delimited(b"\x01".to_vec()), b"abc\x01\x01".as_slice())]
+    #[case(Framing::Newline, b"abc\n\n".as_slice())]
+    fn test_framing_encode(#[case] framing: Framing, #[case] expected: &[u8]) {
+        assert_eq!(encoded(&framing, &[b"abc", b""]), expected);
+    }
+
+    #[rstest]
+    #[case(Framing::LengthPrefixed { header_len: 4 })]
+    #[case(Framing::Delimited(b"\x0110=".to_vec()))]
+    #[case(Framing::Newline)]
+    fn test_framing_decode_byte_by_byte(#[case] framing: Framing) {
+        let payloads: [&[u8]; 3] = [b"8=FIX.4.4\x0135=0", b"", b"35=A\x0198=0"];
+        let mut buf = BytesMut::new();
+        let mut frames = Vec::new();
+
+        for byte in encoded(&framing, &payloads) {
+            buf.put_u8(byte);
+            while let Some(frame) = framing.decode(&mut buf) {
+                frames.push(frame);
+            }
+        }
+
+        assert_eq!(frames, payloads);
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    fn test_newline_framing_strips_carriage_return() {
+        let mut buf = BytesMut::from(&b"abc\r\ndef\n"[..]);
+
+        assert_eq!(Framing::Newline.decode(&mut buf).unwrap(), "abc");
+        assert_eq!(Framing::Newline.decode(&mut buf).unwrap(), "def");
+        assert_eq!(Framing::Newline.decode(&mut buf), None);
+    }
+
+    #[rstest]
+    fn test_length_prefixed_framing_rejects_oversized_payload() {
+        let framing = Framing::LengthPrefixed { header_len: 1 };
+        let mut buf = BytesMut::new();
+
+        let result = framing.encode(&[0; 256], &mut buf);
+
+        assert!(matches!(
+            result,
+            Err(FramedSocketError::FrameTooLarge { len: 256, max: 255 })
+        ));
+        assert!(buf.is_empty());
+    }
+
+    #[rstest]
+    #[case(Framing::LengthPrefixed { header_len: 2 })]
+    #[case(Framing::Delimited(b"\x01".to_vec()))]
+    #[case(Framing::Newline)]
+    #[tokio::test]
+    async fn test_frames_reassembled_across_segments(#[case] framing: Framing) {
+        let greeting = encoded(&framing, &[b"35=A", b"35=0"]);
+        let server = FragmentingServer::start(greeting, 3).await;
+        let config = FramedSocketConfig::new(server.url(), framing);
+        let (client, mut events) = FramedSocketClient::connect(config).await.unwrap();
+
+        assert_eq!(next_frame(&mut events).await, "35=A");
+        assert_eq!(next_frame(&mut events).await, "35=0");
+
+        client.send(b"35=D|55=EURUSD").unwrap();
+        assert_eq!(next_frame(&mut events).await, "35=D|55=EURUSD");
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_heartbeats_are_framed_and_sent() {
+        let server = FragmentingServer::start(Vec::new(), 16).await;
+        let mut config = FramedSocketConfig::new(server.url(), Framing::Newline);
+        config.heartbeat = Some((Duration::from_millis(20), b"35=0".to_vec()));
+        let (_client, mut events) = FramedSocketClient::connect(config).await.unwrap();
+
+        // The server echoes heartbeats back
+        assert_eq!(next_frame(&mut events).await, "35=0");
+        assert!(server.received()[0].starts_with(b"35=0\n"));
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_reconnects_and_flushes_buffered_frames() {
+        let server = FragmentingServer::start(Vec::new(), 4).await;
+        let mut config = FramedSocketConfig::new(server.url(), Framing::Newline);
+        config.reconnect_delay_initial = Duration::from_millis(50);
+        config.reconnect_delay_max = Duration::from_millis(50);
+        let (client, mut events) = FramedSocketClient::connect(config).await.unwrap();
+
+        // Wait for the server to accept the connection before dropping it
+        wait_until(|| server.received().len() == 1).await;
+        server.drop_connections();
+        wait_until(|| client.state() == ConnectionState::Reconnecting).await;
+        client.send(b"35=D").unwrap();
+
+        assert_eq!(next_frame(&mut events).await, "35=D");
+        assert_eq!(client.state(), ConnectionState::Connected);
+        assert_eq!(server.received().len(), 2);
+
+        client.disconnect().await;
+        assert_eq!(client.state(), ConnectionState::Disconnected);
+        assert!(matches!(
+            client.send(b"35=5"),
+            Err(FramedSocketError::Closed)
+        ));
+    }
+}
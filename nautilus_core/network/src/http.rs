@@ -15,7 +15,12 @@
 
 //! A high-performance HTTP client implementation.
 
-use std::{collections::HashMap, hash::Hash, sync::Arc, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 
 use bytes::Bytes;
 use reqwest::{
@@ -23,7 +28,10 @@ use reqwest::{
     Method, Response, Url,
 };
 
-use crate::ratelimiter::{clock::MonotonicClock, RateLimiter};
+use crate::{
+    backoff::ExponentialBackoff,
+    ratelimiter::{clock::MonotonicClock, quota::Quota, RateLimiter},
+};
 
 /// Represents the HTTP methods supported by the `HttpClient`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -39,6 +47,14 @@ pub enum HttpMethod {
     PATCH,
 }
 
+impl HttpMethod {
+    /// Returns whether requests with this method are idempotent, and so safe to retry.
+    #[must_use]
+    pub const fn is_idempotent(self) -> bool {
+        matches!(self, Self::GET | Self::PUT | Self::DELETE)
+    }
+}
+
 #[allow(clippy::from_over_into)]
 impl Into<Method> for HttpMethod {
     fn into(self) -> Method {
@@ -70,12 +86,13 @@ pub struct HttpResponse {
     pub(crate) body: Bytes,
 }
 
-/// A high-performance HTTP client with rate limiting and timeout capabilities.
+/// A high-performance HTTP client with rate limiting, retry and timeout capabilities.
 ///
 /// This struct is designed to handle HTTP requests efficiently, providing
-/// support for rate limiting, timeouts, and custom headers. The client is
+/// support for rate limiting, retries, timeouts, and custom headers. The client is
 /// built on top of `reqwest` and can be used for both synchronous and
 /// asynchronous HTTP requests.
+#[derive(Clone)]
 #[cfg_attr(
     feature = "python",
     pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
@@ -85,6 +102,237 @@ pub struct HttpClient {
     pub(crate) rate_limiter: Arc<RateLimiter<String, MonotonicClock>>,
     /// The underlying HTTP client used to make requests.
     pub(crate) client: InnerHttpClient,
+    /// The policy for retrying failed idempotent requests.
+    pub(crate) retry_policy: RetryPolicy,
+    /// The request statistics, shared between clones of the client.
+    pub(crate) stats: Arc<Mutex<HttpClientStats>>,
+}
+
+/// The policy for retrying failed idempotent requests with exponential backoff.
+///
+/// A request is retried if it fails with a transport error (e.g. a timeout or a refused
+/// connection) or with one of the `retryable_statuses`. Non-idempotent requests (e.g. order
+/// submissions with POST) are never retried, as the venue may have processed them.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct RetryPolicy {
+    /// The maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// The delay before the first retry.
+    pub delay_initial: Duration,
+    /// The maximum delay between retries.
+    pub delay_max: Duration,
+    /// The factor by which the delay grows after each retry.
+    pub backoff_factor: f64,
+    /// The fraction by which each delay is randomized.
+    pub jitter: f64,
+    /// The response status codes which are retried.
+    pub retryable_statuses: HashSet<u16>,
+}
+
+impl RetryPolicy {
+    /// Creates a new [`RetryPolicy`] which never retries.
+    #[must_use]
+    pub fn none() -> Self {
+        Self {
+            max_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Returns whether a response with the given `status` should be retried.
+    #[must_use]
+    pub fn is_retryable_status(&self, status: u16) -> bool {
+        self.retryable_statuses.contains(&status)
+    }
+
+    fn backoff(&self) -> ExponentialBackoff {
+        ExponentialBackoff::new(
+            self.delay_initial,
+            self.delay_max,
+            self.backoff_factor,
+            self.jitter,
+        )
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Creates a new default [`RetryPolicy`] instance.
+    ///
+    /// Retries up to 3 times on 429 (too many requests) and 5xx (server error) statuses.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            delay_initial: Duration::from_millis(100),
+            delay_max: Duration::from_secs(5),
+            backoff_factor: 2.0,
+            jitter: 0.2,
+            retryable_statuses: std::iter::once(429).chain(500..600).collect(),
+        }
+    }
+}
+
+/// Statistics of the requests sent by an `HttpClient`.
+///
+/// Latencies are measured per attempt, from sending the request to receiving the full response,
+/// and exclude time spent waiting for the rate limiter.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "nautilus_trader.core.nautilus_pyo3.network")
+)]
+pub struct HttpClientStats {
+    /// The number of requests made (excluding retries).
+    pub requests: u64,
+    /// The number of retries made.
+    pub retries: u64,
+    /// The number of requests delayed by the rate limiter.
+    pub rate_limited: u64,
+    /// The number of requests which ultimately failed (an error or a non-success status).
+    pub failures: u64,
+    /// The latency of the most recent attempt.
+    pub last_latency: Duration,
+    /// The maximum latency of any attempt.
+    pub max_latency: Duration,
+    /// The total latency of all attempts.
+    pub total_latency: Duration,
+}
+
+impl HttpClientStats {
+    /// Returns the mean latency of all attempts.
+    #[must_use]
+    pub fn mean_latency(&self) -> Duration {
+        let attempts = self.requests + self.retries;
+        if attempts == 0 {
+            return Duration::ZERO;
+        }
+        self.total_latency / u32::try_from(attempts).unwrap_or(u32::MAX)
+    }
+
+    fn record_latency(&mut self, latency: Duration) {
+        self.last_latency = latency;
+        self.max_latency = self.max_latency.max(latency);
+        self.total_latency += latency;
+    }
+}
+
+impl HttpClient {
+    /// Creates a new [`HttpClient`] instance.
+    ///
+    /// - `header_keys`: The header values retained from responses.
+    /// - `keyed_quotas`: The rate limiting quotas for specific keys (e.g. routes).
+    /// - `default_quota`: The rate limiting quota for any other key (none is passthrough).
+    /// - `retry_policy`: The policy for retrying failed idempotent requests.
+    #[must_use]
+    pub fn new(
+        header_keys: Vec<String>,
+        keyed_quotas: Vec<(String, Quota)>,
+        default_quota: Option<Quota>,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        Self {
+            rate_limiter: Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas)),
+            client: InnerHttpClient {
+                client: reqwest::Client::new(),
+                header_keys,
+            },
+            retry_policy,
+            stats: Arc::new(Mutex::new(HttpClientStats::default())),
+        }
+    }
+
+    /// Returns a snapshot of the request statistics.
+    #[must_use]
+    pub fn stats(&self) -> HttpClientStats {
+        self.lock_stats().clone()
+    }
+
+    /// Sends an HTTP request, waiting for the rate limiter and retrying per the retry policy.
+    ///
+    /// Each attempt (including retries) consumes `weight` cells from the quota of every key in
+    /// `keys`. For request /foo/bar, pass keys ["foo/bar", "foo"] for hierarchical limits.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the final attempt fails with a transport error. A final
+    /// response with a non-success status is returned as `Ok`.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn request(
+        &self,
+        method: HttpMethod,
+        url: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+        keys: Vec<String>,
+        weight: u32,
+        timeout_secs: Option<u64>,
+    ) -> Result<HttpResponse, HttpClientError> {
+        let mut backoff = self.retry_policy.backoff();
+        let mut retries = 0;
+        self.lock_stats().requests += 1;
+
+        loop {
+            let mut rate_limited = false;
+            for key in &keys {
+                rate_limited |= self.rate_limiter.until_key_ready_n(key, weight).await;
+            }
+            if rate_limited {
+                self.lock_stats().rate_limited += 1;
+            }
+
+            let start = Instant::now();
+            let result = self
+                .client
+                .send_request(
+                    method.into(),
+                    url.clone(),
+                    headers.clone(),
+                    body.clone(),
+                    timeout_secs,
+                )
+                .await;
+            let latency = start.elapsed();
+            tracing::debug!("{method:?} {url} completed in {latency:?}");
+            self.lock_stats().record_latency(latency);
+
+            let retryable = match &result {
+                Ok(response) => self.retry_policy.is_retryable_status(response.status),
+                Err(_) => true,
+            };
+            if !retryable || !method.is_idempotent() || retries >= self.retry_policy.max_retries {
+                if !matches!(&result, Ok(response) if response.status < 400) {
+                    self.lock_stats().failures += 1;
+                }
+                return result;
+            }
+
+            retries += 1;
+            self.lock_stats().retries += 1;
+            let delay = backoff.next_delay();
+            match &result {
+                Ok(response) => tracing::warn!(
+                    "{method:?} {url} returned status {}, retrying in {delay:?} ({retries}/{})",
+                    response.status,
+                    self.retry_policy.max_retries,
+                ),
+                Err(e) => tracing::warn!(
+                    "{method:?} {url} failed: {e}, retrying in {delay:?} ({retries}/{})",
+                    self.retry_policy.max_retries,
+                ),
+            }
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    fn lock_stats(&self) -> std::sync::MutexGuard<'_, HttpClientStats> {
+        // Statistics stay consistent enough to keep recording after a panic elsewhere
+        self.stats
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
 }
 
 /// Represents errors that can occur when using the `HttpClient`.
@@ -228,8 +476,13 @@ impl Default for InnerHttpClient {
 mod tests {
     use std::net::{SocketAddr, TcpListener};
 
+    use std::{
+        num::NonZeroU32,
+        sync::atomic::{AtomicU32, Ordering},
+    };
+
     use axum::{
-        routing::{delete, get, patch, post},
+        routing::{any, delete, get, patch, post},
         serve, Router,
     };
     use http::status::StatusCode;
@@ -270,6 +523,53 @@ mod tests {
         Ok(addr)
     }
 
+    /// Starts a server whose `/flaky` route fails with 503 for the first `failures` requests,
+    /// and whose `/status/400` route always fails, returning the count of requests received.
+    async fn start_flaky_server(failures: u32) -> (SocketAddr, Arc<AtomicU32>) {
+        let hits = Arc::new(AtomicU32::new(0));
+        let router = Router::new()
+            .route(
+                "/flaky",
+                any({
+                    let hits = hits.clone();
+                    move || async move {
+                        if hits.fetch_add(1, Ordering::SeqCst) < failures {
+                            StatusCode::SERVICE_UNAVAILABLE
+                        } else {
+                            StatusCode::OK
+                        }
+                    }
+                }),
+            )
+            .route(
+                "/status/400",
+                get({
+                    let hits = hits.clone();
+                    move || async move {
+                        hits.fetch_add(1, Ordering::SeqCst);
+                        StatusCode::BAD_REQUEST
+                    }
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(listener, router).await.unwrap();
+        });
+
+        (addr, hits)
+    }
+
+    fn retry_policy(max_retries: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            delay_initial: Duration::from_millis(1),
+            delay_max: Duration::from_millis(10),
+            ..Default::default()
+        }
+    }
+
     #[tokio::test]
     async fn test_get() {
         let addr = start_test_server().await.unwrap();
@@ -384,4 +684,136 @@ mod tests {
 
         assert_eq!(response.status, StatusCode::OK);
     }
+
+    #[tokio::test]
+    async fn test_idempotent_request_retried_until_success() {
+        let (addr, hits) = start_flaky_server(2).await;
+        let client = HttpClient::new(Vec::new(), Vec::new(), None, retry_policy(3));
+
+        let response = client
+            .request(
+                HttpMethod::GET,
+                format!("http://{addr}/flaky"),
+                HashMap::new(),
+                None,
+                Vec::new(),
+                1,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        let stats = client.stats();
+        assert_eq!(stats.requests, 1);
+        assert_eq!(stats.retries, 2);
+        assert_eq!(stats.failures, 0);
+        assert!(stats.max_latency >= stats.last_latency);
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_returns_last_response() {
+        let (addr, hits) = start_flaky_server(10).await;
+        let client = HttpClient::new(Vec::new(), Vec::new(), None, retry_policy(2));
+
+        let response = client
+            .request(
+                HttpMethod::DELETE,
+                format!("http://{addr}/flaky"),
+                HashMap::new(),
+                None,
+                Vec::new(),
+                1,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(client.stats().retries, 2);
+        assert_eq!(client.stats().failures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_request_not_retried() {
+        let (addr, hits) = start_flaky_server(1).await;
+        let client = HttpClient::new(Vec::new(), Vec::new(), None, retry_policy(3));
+
+        let response = client
+            .request(
+                HttpMethod::POST,
+                format!("http://{addr}/flaky"),
+                HashMap::new(),
+                None,
+                Vec::new(),
+                1,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(client.stats().retries, 0);
+    }
+
+    #[tokio::test]
+    async fn test_client_error_status_not_retried() {
+        let (addr, hits) = start_flaky_server(0).await;
+        let client = HttpClient::new(Vec::new(), Vec::new(), None, retry_policy(3));
+
+        let response = client
+            .request(
+                HttpMethod::GET,
+                format!("http://{addr}/status/400"),
+                HashMap::new(),
+                None,
+                Vec::new(),
+                1,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(client.stats().failures, 1);
+    }
+
+    #[tokio::test]
+    async fn test_weighted_requests_delayed_by_rate_limiter() {
+        let (addr, hits) = start_flaky_server(0).await;
+        // A burst of 10 weight per second, so two requests of weight 5 go straight through
+        let quota = Quota::per_second(NonZeroU32::new(10).unwrap());
+        let client = HttpClient::new(
+            Vec::new(),
+            vec![("orders".to_string(), quota)],
+            None,
+            RetryPolicy::none(),
+        );
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            client
+                .request(
+                    HttpMethod::POST,
+                    format!("http://{addr}/flaky"),
+                    HashMap::new(),
+                    None,
+                    vec!["orders".to_string()],
+                    5,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        // The third request waits for 5 cells to replenish at 10 per second
+        assert!(start.elapsed() >= Duration::from_millis(400));
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(client.stats().requests, 3);
+        assert_eq!(client.stats().rate_limited, 1);
+    }
 }
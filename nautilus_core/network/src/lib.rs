@@ -24,14 +24,22 @@
 //! depending on the intended use case, i.e. whether to provide Python bindings
 //! for the main `nautilus_trader` Python package, or as part of a Rust only build.
 //!
+//! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod backoff;
+pub mod fix;
+pub mod framed;
 pub mod http;
 #[allow(dead_code)]
 mod ratelimiter;
+pub mod reconnecting;
 pub mod socket;
 mod tls;
 pub mod websocket;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 #[cfg(feature = "python")]
 pub mod python;
@@ -14,18 +14,17 @@
 // -------------------------------------------------------------------------------------------------
 
 use std::{
-    collections::{hash_map::DefaultHasher, HashMap},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
     hash::{Hash, Hasher},
-    sync::Arc,
+    time::Duration,
 };
 
 use bytes::Bytes;
-use futures_util::{stream, StreamExt};
 use pyo3::{create_exception, exceptions::PyException, prelude::*, types::PyBytes};
 
 use crate::{
-    http::{HttpClient, HttpClientError, HttpMethod, HttpResponse, InnerHttpClient},
-    ratelimiter::{quota::Quota, RateLimiter},
+    http::{HttpClient, HttpClientError, HttpClientStats, HttpMethod, HttpResponse, RetryPolicy},
+    ratelimiter::quota::Quota,
 };
 
 // Python exception class for generic HTTP errors.
@@ -107,26 +106,27 @@ impl HttpClient {
     /// When a request is made the URL should be split into all the keys within it.
     ///
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
+    ///
+    /// Quotas are in units of request weight, e.g. "1200 weight per minute" is
+    /// `Quota.rate_per_minute(1200)` with each request passing its `weight`.
+    ///
+    /// `retry_policy`: The policy for retrying failed idempotent requests (defaults to
+    /// `RetryPolicy()`).
     #[new]
-    #[pyo3(signature = (header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None))]
+    #[pyo3(signature = (header_keys = Vec::new(), keyed_quotas = Vec::new(), default_quota = None, retry_policy = None))]
     #[must_use]
     pub fn py_new(
         header_keys: Vec<String>,
         keyed_quotas: Vec<(String, Quota)>,
         default_quota: Option<Quota>,
+        retry_policy: Option<RetryPolicy>,
     ) -> Self {
-        let client = reqwest::Client::new();
-        let rate_limiter = Arc::new(RateLimiter::new_with_quota(default_quota, keyed_quotas));
-
-        let client = InnerHttpClient {
-            client,
+        Self::new(
             header_keys,
-        };
-
-        Self {
-            rate_limiter,
-            client,
-        }
+            keyed_quotas,
+            default_quota,
+            retry_policy.unwrap_or_default(),
+        )
     }
 
     /// Send an HTTP request.
@@ -136,6 +136,7 @@ impl HttpClient {
     /// `headers`: The header key value pairs in the request.
     /// `body`: The bytes sent in the body of request.
     /// `keys`: The keys used for rate limiting the request.
+    /// `weight`: The weight of the request against the quota of each key.
     ///
     /// # Example
     ///
@@ -144,7 +145,7 @@ impl HttpClient {
     /// For request /foo/bar, should pass keys ["foo/bar", "foo"] for rate limiting.
     #[pyo3(name = "request")]
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, timeout_secs=None))]
+    #[pyo3(signature = (method, url, headers=None, body=None, keys=None, weight=1, timeout_secs=None))]
     fn py_request<'py>(
         &self,
         method: HttpMethod,
@@ -152,27 +153,104 @@ impl HttpClient {
         headers: Option<HashMap<String, String>>,
         body: Option<Bound<'py, PyBytes>>,
         keys: Option<Vec<String>>,
+        weight: u32,
         timeout_secs: Option<u64>,
         py: Python<'py>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let headers = headers.unwrap_or_default();
         let body_vec = body.map(|py_bytes| py_bytes.as_bytes().to_vec());
         let keys = keys.unwrap_or_default();
-        let client = self.client.clone();
-        let rate_limiter = self.rate_limiter.clone();
-        let method = method.into();
+        let client = self.clone();
         pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            // Check keys for rate limiting quota
-            let tasks = keys.iter().map(|key| rate_limiter.until_key_ready(key));
-            stream::iter(tasks)
-                .for_each(|key| async move {
-                    key.await;
-                })
-                .await;
             client
-                .send_request(method, url, headers, body_vec, timeout_secs)
+                .request(method, url, headers, body_vec, keys, weight, timeout_secs)
                 .await
                 .map_err(super::super::http::HttpClientError::into_py_err)
         })
     }
+
+    /// Returns a snapshot of the request statistics.
+    #[pyo3(name = "stats")]
+    fn py_stats(&self) -> HttpClientStats {
+        self.stats()
+    }
+}
+
+#[pymethods]
+impl RetryPolicy {
+    #[new]
+    #[pyo3(signature = (max_retries = 3, delay_initial_ms = 100, delay_max_ms = 5_000, backoff_factor = 2.0, jitter = 0.2, retryable_statuses = None))]
+    fn py_new(
+        max_retries: u32,
+        delay_initial_ms: u64,
+        delay_max_ms: u64,
+        backoff_factor: f64,
+        jitter: f64,
+        retryable_statuses: Option<HashSet<u16>>,
+    ) -> Self {
+        let default = Self::default();
+        Self {
+            max_retries,
+            delay_initial: Duration::from_millis(delay_initial_ms),
+            delay_max: Duration::from_millis(delay_max_ms),
+            backoff_factor,
+            jitter,
+            retryable_statuses: retryable_statuses.unwrap_or(default.retryable_statuses),
+        }
+    }
+
+    #[getter]
+    #[pyo3(name = "max_retries")]
+    const fn py_max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+#[pymethods]
+impl HttpClientStats {
+    #[getter]
+    #[pyo3(name = "requests")]
+    const fn py_requests(&self) -> u64 {
+        self.requests
+    }
+
+    #[getter]
+    #[pyo3(name = "retries")]
+    const fn py_retries(&self) -> u64 {
+        self.retries
+    }
+
+    #[getter]
+    #[pyo3(name = "rate_limited")]
+    const fn py_rate_limited(&self) -> u64 {
+        self.rate_limited
+    }
+
+    #[getter]
+    #[pyo3(name = "failures")]
+    const fn py_failures(&self) -> u64 {
+        self.failures
+    }
+
+    #[getter]
+    #[pyo3(name = "last_latency_ns")]
+    fn py_last_latency_ns(&self) -> u64 {
+        self.last_latency.as_nanos() as u64
+    }
+
+    #[getter]
+    #[pyo3(name = "max_latency_ns")]
+    fn py_max_latency_ns(&self) -> u64 {
+        self.max_latency.as_nanos() as u64
+    }
+
+    #[getter]
+    #[pyo3(name = "mean_latency_ns")]
+    fn py_mean_latency_ns(&self) -> u64 {
+        self.mean_latency().as_nanos() as u64
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{self:?}")
+    }
 }
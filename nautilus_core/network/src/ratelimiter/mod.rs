@@ -173,19 +173,36 @@ where
     }
 
     pub fn check_key(&self, key: &K) -> Result<(), NotUntil<C::Instant>> {
+        self.check_key_n(key, 1)
+    }
+
+    /// Checks whether a request of weight `n` for `key` conforms to its quota, consuming `n`
+    /// cells if it does.
+    pub fn check_key_n(&self, key: &K, n: u32) -> Result<(), NotUntil<C::Instant>> {
         match self.gcra.get(key) {
-            Some(quota) => quota.test_and_update(self.start, key, &self.state, self.clock.now()),
+            Some(quota) => {
+                quota.test_n_and_update(self.start, key, &self.state, self.clock.now(), n)
+            }
             None => self.default_gcra.as_ref().map_or(Ok(()), |gcra| {
-                gcra.test_and_update(self.start, key, &self.state, self.clock.now())
+                gcra.test_n_and_update(self.start, key, &self.state, self.clock.now(), n)
             }),
         }
     }
 
     pub async fn until_key_ready(&self, key: &K) {
+        self.until_key_ready_n(key, 1).await;
+    }
+
+    /// Waits until a request of weight `n` for `key` conforms to its quota, consuming `n` cells.
+    ///
+    /// Returns whether the request had to wait.
+    pub async fn until_key_ready_n(&self, key: &K, n: u32) -> bool {
+        let mut waited = false;
         loop {
-            match self.check_key(key) {
-                Ok(x) => x,
+            match self.check_key_n(key, n) {
+                Ok(()) => return waited,
                 Err(neg) => {
+                    waited = true;
                     sleep(neg.wait_time_from(self.clock.now())).await;
                 }
             }
@@ -330,4 +347,37 @@ mod tests {
         assert!(mock_limiter.check_key(&"per_second".to_string()).is_ok());
         assert!(mock_limiter.check_key(&"per_minute".to_string()).is_err());
     }
+
+    #[test]
+    fn test_weighted_requests() {
+        let mock_limiter = initialize_mock_rate_limiter();
+
+        mock_limiter.add_quota_for_key(
+            "weight".to_string(),
+            Quota::per_minute(NonZeroU32::new(10).unwrap()),
+        );
+
+        // Consume 8 of the 10 cells, leaving too few for a weight of 5
+        assert!(mock_limiter.check_key_n(&"weight".to_string(), 8).is_ok());
+        assert!(mock_limiter.check_key_n(&"weight".to_string(), 5).is_err());
+        assert!(mock_limiter.check_key_n(&"weight".to_string(), 2).is_ok());
+        assert!(mock_limiter.check_key(&"weight".to_string()).is_err());
+
+        // 30 seconds replenishes 5 cells of the 10 per minute quota
+        mock_limiter.advance_clock(Duration::from_secs(30));
+        assert!(mock_limiter.check_key_n(&"weight".to_string(), 5).is_ok());
+        assert!(mock_limiter.check_key(&"weight".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_weight_above_burst_capacity_is_capped() {
+        let mock_limiter = initialize_mock_rate_limiter();
+
+        // The default quota allows a burst of 2
+        assert!(mock_limiter.check_key_n(&"heavy".to_string(), 5).is_ok());
+        assert!(mock_limiter.check_key(&"heavy".to_string()).is_err());
+
+        mock_limiter.advance_clock(Duration::from_secs(1));
+        assert!(mock_limiter.check_key_n(&"heavy".to_string(), 5).is_ok());
+    }
 }
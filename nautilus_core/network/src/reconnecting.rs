@@ -0,0 +1,736 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! A WebSocket client which automatically reconnects and replays its subscriptions.
+
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use nautilus_cryptography::providers::install_cryptographic_provider;
+use tokio::{
+    net::TcpStream,
+    sync::{mpsc, watch},
+    task,
+    time::{interval_at, sleep, Instant, Interval},
+};
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{
+        self,
+        client::IntoClientRequest,
+        http::{HeaderName, HeaderValue},
+        Message,
+    },
+    MaybeTlsStream, WebSocketStream,
+};
+
+use crate::backoff::ExponentialBackoff;
+
+type WebSocket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Configuration for a [`ReconnectingWebSocketClient`].
+#[derive(Clone, Debug)]
+pub struct ReconnectingWebSocketConfig {
+    /// The URL of the WebSocket server.
+    pub url: String,
+    /// The headers sent with each connection request.
+    pub headers: Vec<(String, String)>,
+    /// The interval between heartbeat pings, if heartbeats are enabled.
+    pub heartbeat_interval: Option<Duration>,
+    /// The number of consecutive unanswered pings after which the connection is considered dead.
+    pub heartbeat_miss_threshold: u32,
+    /// The delay before the first reconnection attempt.
+    pub reconnect_delay_initial: Duration,
+    /// The maximum delay between reconnection attempts.
+    pub reconnect_delay_max: Duration,
+    /// The factor by which the delay grows after each failed reconnection attempt.
+    pub reconnect_backoff_factor: f64,
+    /// The fraction by which each reconnection delay is randomized.
+    pub reconnect_jitter: f64,
+    /// The number of failed reconnection attempts after which the client faults, if limited.
+    pub max_reconnect_attempts: Option<u32>,
+}
+
+impl ReconnectingWebSocketConfig {
+    /// Creates a new [`ReconnectingWebSocketConfig`] for the given `url` with default settings.
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            heartbeat_interval: None,
+            heartbeat_miss_threshold: 3,
+            reconnect_delay_initial: Duration::from_millis(500),
+            reconnect_delay_max: Duration::from_secs(30),
+            reconnect_backoff_factor: 2.0,
+            reconnect_jitter: 0.2,
+            max_reconnect_attempts: None,
+        }
+    }
+}
+
+/// The state of the connection of a reconnecting client.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    /// The initial connection is being established.
+    Connecting = 1,
+    /// The client is connected to the server.
+    Connected = 2,
+    /// The connection was lost and the client is attempting to reconnect.
+    Reconnecting = 3,
+    /// The client is closing the connection on request.
+    Disconnecting = 4,
+    /// The client has closed the connection on request.
+    Disconnected = 5,
+    /// The client gave up reconnecting after exhausting its reconnection attempts.
+    Faulted = 6,
+}
+
+impl ConnectionState {
+    /// Returns whether the state is terminal, i.e. the client will no longer connect.
+    #[must_use]
+    pub const fn is_terminal(self) -> bool {
+        matches!(self, Self::Disconnected | Self::Faulted)
+    }
+}
+
+/// An event surfaced by a [`ReconnectingWebSocketClient`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum WebSocketEvent {
+    /// A data message (text or binary) received from the server.
+    Message(Message),
+    /// The connection state changed.
+    StateChanged {
+        previous: ConnectionState,
+        current: ConnectionState,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ReconnectingWebSocketError {
+    #[error("WebSocket connection failed: {0}")]
+    Connect(#[from] tungstenite::Error),
+
+    #[error("WebSocket client is closed")]
+    Closed,
+}
+
+#[derive(Debug)]
+enum Command {
+    Send(Message),
+    Subscribe(String, Message),
+    Unsubscribe(String, Option<Message>),
+    Disconnect,
+}
+
+/// A WebSocket client which reconnects with exponential backoff when the connection drops.
+///
+/// The connection is owned by a background task. Messages received from the server and
+/// connection state changes are surfaced as [`WebSocketEvent`]s on the channel returned by
+/// [`ReconnectingWebSocketClient::connect`].
+///
+/// Subscription messages registered with [`ReconnectingWebSocketClient::subscribe`] are replayed
+/// in registration order after every reconnection, and messages sent while reconnecting are
+/// buffered until the connection is re-established.
+///
+/// If a heartbeat interval is configured the client pings the server, and forces a reconnection
+/// once `heartbeat_miss_threshold` consecutive pings go unanswered.
+///
+/// Dropping the client closes the connection gracefully.
+#[derive(Debug)]
+pub struct ReconnectingWebSocketClient {
+    commands: mpsc::UnboundedSender<Command>,
+    state: watch::Receiver<ConnectionState>,
+}
+
+impl ReconnectingWebSocketClient {
+    /// Connects to the server, returning the client and the receiver of its events.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the initial connection fails (the client only
+    /// reconnects connections which were once established).
+    pub async fn connect(
+        config: ReconnectingWebSocketConfig,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<WebSocketEvent>), ReconnectingWebSocketError> {
+        install_cryptographic_provider();
+
+        tracing::debug!("Connecting to {}", config.url);
+        let stream = connect_stream(&config).await?;
+
+        let (command_tx, command_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (state_tx, state_rx) = watch::channel(ConnectionState::Connecting);
+
+        let mut connection = Connection {
+            backoff: ExponentialBackoff::new(
+                config.reconnect_delay_initial,
+                config.reconnect_delay_max,
+                config.reconnect_backoff_factor,
+                config.reconnect_jitter,
+            ),
+            config,
+            commands: command_rx,
+            events: event_tx,
+            state: state_tx,
+            subscriptions: Vec::new(),
+            pending: Vec::new(),
+        };
+        connection.set_state(ConnectionState::Connected);
+        task::spawn(connection.run(stream));
+
+        let client = Self {
+            commands: command_tx,
+            state: state_rx,
+        };
+        Ok((client, event_rx))
+    }
+
+    /// Returns the current connection state.
+    #[must_use]
+    pub fn state(&self) -> ConnectionState {
+        *self.state.borrow()
+    }
+
+    /// Sends a message to the server, buffering it while reconnecting.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is disconnected or faulted.
+    pub fn send(&self, message: Message) -> Result<(), ReconnectingWebSocketError> {
+        self.command(Command::Send(message))
+    }
+
+    /// Registers the subscription `message` under `key` and sends it to the server.
+    ///
+    /// The message is replayed after every reconnection, until unsubscribed. Subscribing again
+    /// with an existing key replaces its message (keeping its position in the replay order).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is disconnected or faulted.
+    pub fn subscribe(
+        &self,
+        key: impl Into<String>,
+        message: Message,
+    ) -> Result<(), ReconnectingWebSocketError> {
+        self.command(Command::Subscribe(key.into(), message))
+    }
+
+    /// Removes the subscription registered under `key`, sending the optional unsubscribe
+    /// `message` to the server if currently connected.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the client is disconnected or faulted.
+    pub fn unsubscribe(
+        &self,
+        key: impl Into<String>,
+        message: Option<Message>,
+    ) -> Result<(), ReconnectingWebSocketError> {
+        self.command(Command::Unsubscribe(key.into(), message))
+    }
+
+    /// Closes the connection, waiting until the client is disconnected.
+    pub async fn disconnect(&self) {
+        tracing::debug!("Disconnecting");
+        // The connection task may already have stopped if the client faulted
+        let _ = self.commands.send(Command::Disconnect);
+        let mut state = self.state.clone();
+        let _ = state.wait_for(|state| state.is_terminal()).await;
+    }
+
+    fn command(&self, command: Command) -> Result<(), ReconnectingWebSocketError> {
+        self.commands
+            .send(command)
+            .map_err(|_| ReconnectingWebSocketError::Closed)
+    }
+}
+
+/// How a connected session ended.
+enum SessionEnd {
+    Disconnect,
+    ConnectionLost,
+}
+
+/// The connection task state.
+struct Connection {
+    config: ReconnectingWebSocketConfig,
+    backoff: ExponentialBackoff,
+    commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::UnboundedSender<WebSocketEvent>,
+    state: watch::Sender<ConnectionState>,
+    subscriptions: Vec<(String, Message)>,
+    pending: Vec<Message>,
+}
+
+impl Connection {
+    async fn run(mut self, mut stream: WebSocket) {
+        loop {
+            match self.run_session(stream).await {
+                SessionEnd::Disconnect => {
+                    self.set_state(ConnectionState::Disconnected);
+                    return;
+                }
+                SessionEnd::ConnectionLost => {
+                    self.set_state(ConnectionState::Reconnecting);
+                }
+            }
+
+            stream = match self.reconnect().await {
+                Some(stream) => stream,
+                None => return,
+            };
+        }
+    }
+
+    /// Runs a connected session until the connection is lost or a disconnect is requested.
+    async fn run_session(&mut self, stream: WebSocket) -> SessionEnd {
+        let (mut writer, mut reader) = stream.split();
+
+        // Replay subscriptions (on reconnection) then flush messages sent while reconnecting
+        let replay = self
+            .subscriptions
+            .iter()
+            .map(|(_, message)| message.clone())
+            .chain(self.pending.drain(..))
+            .collect::<Vec<_>>();
+        for message in replay {
+            if let Err(e) = writer.send(message).await {
+                tracing::error!("Error replaying message: {e}");
+                return SessionEnd::ConnectionLost;
+            }
+        }
+        self.set_state(ConnectionState::Connected);
+
+        let mut heartbeat = self
+            .config
+            .heartbeat_interval
+            .map(|period| interval_at(Instant::now() + period, period));
+        let mut missed_heartbeats = 0;
+
+        loop {
+            tokio::select! {
+                message = reader.next() => match message {
+                    Some(Ok(Message::Pong(_))) => {
+                        tracing::trace!("Received pong");
+                        missed_heartbeats = 0;
+                    }
+                    Some(Ok(Message::Ping(_))) => {
+                        tracing::trace!("Received ping");
+                    }
+                    Some(Ok(Message::Close(frame))) => {
+                        tracing::warn!("Received close message: {frame:?}");
+                        return SessionEnd::ConnectionLost;
+                    }
+                    Some(Ok(Message::Frame(_))) => (),
+                    Some(Ok(message)) => {
+                        let _ = self.events.send(WebSocketEvent::Message(message));
+                    }
+                    Some(Err(e)) => {
+                        tracing::error!("Connection lost: {e}");
+                        return SessionEnd::ConnectionLost;
+                    }
+                    None => {
+                        tracing::error!("Connection lost: stream ended");
+                        return SessionEnd::ConnectionLost;
+                    }
+                },
+                command = self.commands.recv() => {
+                    let message = match command {
+                        Some(Command::Send(message)) => message,
+                        Some(Command::Subscribe(key, message)) => {
+                            self.register(key, message.clone());
+                            message
+                        }
+                        Some(Command::Unsubscribe(key, message)) => {
+                            self.subscriptions.retain(|(k, _)| *k != key);
+                            match message {
+                                Some(message) => message,
+                                None => continue,
+                            }
+                        }
+                        // All client handles dropped, so close the connection gracefully
+                        Some(Command::Disconnect) | None => {
+                            self.set_state(ConnectionState::Disconnecting);
+                            if let Err(e) = writer.send(Message::Close(None)).await {
+                                tracing::error!("Error sending close message: {e}");
+                            }
+                            if let Err(e) = writer.close().await {
+                                tracing::debug!("Error closing writer: {e}");
+                            }
+                            return SessionEnd::Disconnect;
+                        }
+                    };
+                    if let Err(e) = writer.send(message.clone()).await {
+                        tracing::error!("Error sending message: {e}");
+                        self.pending.push(message);
+                        return SessionEnd::ConnectionLost;
+                    }
+                }
+                () = tick(heartbeat.as_mut()) => {
+                    if missed_heartbeats >= self.config.heartbeat_miss_threshold {
+                        tracing::warn!("Missed {missed_heartbeats} heartbeats, reconnecting");
+                        return SessionEnd::ConnectionLost;
+                    }
+                    missed_heartbeats += 1;
+                    if let Err(e) = writer.send(Message::Ping(vec![])).await {
+                        tracing::error!("Error sending ping: {e}");
+                        return SessionEnd::ConnectionLost;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Reconnects with backoff, returning `None` if disconnected or out of attempts meanwhile.
+    async fn reconnect(&mut self) -> Option<WebSocket> {
+        let mut attempts = 0;
+        loop {
+            if self
+                .config
+                .max_reconnect_attempts
+                .is_some_and(|max| attempts >= max)
+            {
+                tracing::error!("Failed to reconnect after {attempts} attempts");
+                self.set_state(ConnectionState::Faulted);
+                return None;
+            }
+
+            let delay = sleep(self.backoff.next_delay());
+            tokio::pin!(delay);
+            loop {
+                tokio::select! {
+                    () = &mut delay => break,
+                    command = self.commands.recv() => match command {
+                        Some(Command::Send(message)) => self.pending.push(message),
+                        Some(Command::Subscribe(key, message)) => self.register(key, message),
+                        Some(Command::Unsubscribe(key, _)) => {
+                            self.subscriptions.retain(|(k, _)| *k != key);
+                        }
+                        Some(Command::Disconnect) | None => {
+                            self.set_state(ConnectionState::Disconnecting);
+                            self.set_state(ConnectionState::Disconnected);
+                            return None;
+                        }
+                    },
+                }
+            }
+
+            attempts += 1;
+            tracing::debug!("Reconnecting to {} (attempt {attempts})", self.config.url);
+            match connect_stream(&self.config).await {
+                Ok(stream) => {
+                    tracing::info!("Reconnected to {}", self.config.url);
+                    self.backoff.reset();
+                    return Some(stream);
+                }
+                Err(e) => tracing::warn!("Reconnection attempt {attempts} failed: {e}"),
+            }
+        }
+    }
+
+    fn register(&mut self, key: String, message: Message) {
+        match self.subscriptions.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, existing)) => *existing = message,
+            None => self.subscriptions.push((key, message)),
+        }
+    }
+
+    fn set_state(&mut self, current: ConnectionState) {
+        if current.is_terminal() {
+            // Reject further commands before the state change is observed
+            self.commands.close();
+        }
+        let previous = self.state.send_replace(current);
+        if previous != current {
+            tracing::debug!("Connection state changed: {previous:?} -> {current:?}");
+            let _ = self
+                .events
+                .send(WebSocketEvent::StateChanged { previous, current });
+        }
+    }
+}
+
+/// Waits for the next heartbeat tick, or forever if heartbeats are disabled.
+pub(crate) async fn tick(heartbeat: Option<&mut Interval>) {
+    match heartbeat {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+async fn connect_stream(
+    config: &ReconnectingWebSocketConfig,
+) -> Result<WebSocket, tungstenite::Error> {
+    let mut request = config.url.as_str().into_client_request()?;
+    let headers = request.headers_mut();
+    for (key, value) in &config.headers {
+        headers.insert(
+            HeaderName::from_bytes(key.as_bytes())?,
+            HeaderValue::from_str(value)?,
+        );
+    }
+    connect_async(request).await.map(|(stream, _)| stream)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use rstest::rstest;
+    use tokio::{net::TcpListener, sync::broadcast, task::JoinHandle, time::timeout};
+    use tokio_tungstenite::accept_async;
+
+    use super::*;
+
+    /// A server recording the messages received on each connection and echoing them back,
+    /// which can drop all its connections on demand.
+    struct MockServer {
+        port: u16,
+        connections: Arc<Mutex<Vec<Vec<String>>>>,
+        drop_signal: broadcast::Sender<()>,
+        task: JoinHandle<()>,
+    }
+
+    impl MockServer {
+        /// Starts the server; if `silent_first` the first connection never answers pings.
+        async fn start(silent_first: bool) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let connections = Arc::new(Mutex::new(Vec::<Vec<String>>::new()));
+            let (drop_signal, _) = broadcast::channel(1);
+
+            let task = task::spawn({
+                let connections = connections.clone();
+                let drop_signal = drop_signal.clone();
+                async move {
+                    loop {
+                        let (stream, _) = listener.accept().await.unwrap();
+                        let mut websocket = accept_async(stream).await.unwrap();
+                        let index = {
+                            let mut connections = connections.lock().unwrap();
+                            connections.push(Vec::new());
+                            connections.len() - 1
+                        };
+                        let connections = connections.clone();
+                        let mut drop_rx = drop_signal.subscribe();
+
+                        task::spawn(async move {
+                            if silent_first && index == 0 {
+                                // Never read, so pings are never answered
+                                let _ = drop_rx.recv().await;
+                                return;
+                            }
+                            loop {
+                                tokio::select! {
+                                    message = websocket.next() => match message {
+                                        Some(Ok(Message::Text(text))) => {
+                                            connections.lock().unwrap()[index].push(text.clone());
+                                            websocket.send(Message::Text(text)).await.unwrap();
+                                        }
+                                        Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                                        Some(Ok(_)) => (),
+                                    },
+                                    // Drop the socket without a closing handshake
+                                    _ = drop_rx.recv() => break,
+                                }
+                            }
+                        });
+                    }
+                }
+            });
+
+            Self {
+                port,
+                connections,
+                drop_signal,
+                task,
+            }
+        }
+
+        fn config(&self) -> ReconnectingWebSocketConfig {
+            let mut config =
+                ReconnectingWebSocketConfig::new(format!("ws://127.0.0.1:{}", self.port));
+            config.reconnect_delay_initial = Duration::from_millis(10);
+            config.reconnect_delay_max = Duration::from_millis(50);
+            config
+        }
+
+        fn connections(&self) -> Vec<Vec<String>> {
+            self.connections.lock().unwrap().clone()
+        }
+
+        fn drop_connections(&self) {
+            let _ = self.drop_signal.send(());
+        }
+    }
+
+    impl Drop for MockServer {
+        fn drop(&mut self) {
+            self.drop_connections();
+            self.task.abort();
+        }
+    }
+
+    async fn wait_until(condition: impl Fn() -> bool) {
+        timeout(Duration::from_secs(5), async {
+            while !condition() {
+                sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for condition");
+    }
+
+    fn state_changes(events: &mut mpsc::UnboundedReceiver<WebSocketEvent>) -> Vec<ConnectionState> {
+        let mut states = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            if let WebSocketEvent::StateChanged { current, .. } = event {
+                states.push(current);
+            }
+        }
+        states
+    }
+
+    fn text(text: &str) -> Message {
+        Message::Text(text.to_string())
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_messages_are_surfaced_on_event_channel() {
+        let server = MockServer::start(false).await;
+        let (client, mut events) = ReconnectingWebSocketClient::connect(server.config())
+            .await
+            .unwrap();
+
+        client.send(text("hello")).unwrap();
+
+        let event = timeout(Duration::from_secs(5), async {
+            loop {
+                match events.recv().await.unwrap() {
+                    WebSocketEvent::Message(message) => return message,
+                    WebSocketEvent::StateChanged { .. } => continue,
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(event, text("hello"));
+        assert_eq!(client.state(), ConnectionState::Connected);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_subscriptions_replayed_after_connection_drop() {
+        let server = MockServer::start(false).await;
+        let (client, mut events) = ReconnectingWebSocketClient::connect(server.config())
+            .await
+            .unwrap();
+
+        client.subscribe("trades", text("sub:trades")).unwrap();
+        client.subscribe("quotes", text("sub:quotes")).unwrap();
+        client
+            .unsubscribe("quotes", Some(text("unsub:quotes")))
+            .unwrap();
+        client.subscribe("bars", text("sub:bars")).unwrap();
+        wait_until(|| server.connections()[0].len() == 4).await;
+
+        server.drop_connections();
+        wait_until(|| server.connections().get(1).is_some_and(|c| c.len() == 2)).await;
+
+        assert_eq!(server.connections()[1], ["sub:trades", "sub:bars"]);
+        wait_until(|| client.state() == ConnectionState::Connected).await;
+        let states = state_changes(&mut events);
+        assert_eq!(
+            states,
+            [
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting,
+                ConnectionState::Connected,
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_missed_heartbeats_force_reconnect() {
+        let server = MockServer::start(true).await;
+        let mut config = server.config();
+        config.heartbeat_interval = Some(Duration::from_millis(20));
+        config.heartbeat_miss_threshold = 2;
+        let (client, _events) = ReconnectingWebSocketClient::connect(config).await.unwrap();
+        client.subscribe("trades", text("sub:trades")).unwrap();
+
+        wait_until(|| server.connections().get(1).is_some_and(|c| c.len() == 1)).await;
+
+        assert!(server.connections()[0].is_empty());
+        assert_eq!(server.connections()[1], ["sub:trades"]);
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_disconnect() {
+        let server = MockServer::start(false).await;
+        let (client, mut events) = ReconnectingWebSocketClient::connect(server.config())
+            .await
+            .unwrap();
+
+        client.disconnect().await;
+
+        assert_eq!(client.state(), ConnectionState::Disconnected);
+        assert!(matches!(
+            client.send(text("hello")),
+            Err(ReconnectingWebSocketError::Closed)
+        ));
+        assert_eq!(
+            state_changes(&mut events),
+            [
+                ConnectionState::Connected,
+                ConnectionState::Disconnecting,
+                ConnectionState::Disconnected,
+            ]
+        );
+    }
+
+    #[rstest]
+    #[tokio::test]
+    async fn test_faulted_after_max_reconnect_attempts() {
+        let server = MockServer::start(false).await;
+        let mut config = server.config();
+        config.max_reconnect_attempts = Some(2);
+        let (client, mut events) = ReconnectingWebSocketClient::connect(config).await.unwrap();
+
+        drop(server); // Closes the listener so reconnection attempts are refused
+        wait_until(|| client.state() == ConnectionState::Faulted).await;
+
+        assert_eq!(
+            state_changes(&mut events),
+            [
+                ConnectionState::Connected,
+                ConnectionState::Reconnecting,
+                ConnectionState::Faulted,
+            ]
+        );
+        assert!(client.subscribe("trades", text("sub:trades")).is_err());
+    }
+}
@@ -13,8 +13,9 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
-use std::{collections::HashMap, sync::Arc, vec::IntoIter};
+use std::{cmp::Ordering, collections::HashMap, sync::Arc, vec::IntoIter};
 
+use anyhow::Context;
 use compare::Compare;
 use datafusion::{
     error::Result, logical_expr::expr::Sort, physical_plan::SendableRecordBatchStream, prelude::*,
@@ -23,29 +24,40 @@ use futures::StreamExt;
 use nautilus_core::ffi::cvec::CVec;
 use nautilus_model::data::{Data, GetTsInit};
 use nautilus_serialization::arrow::{
-    DataStreamingError, DecodeDataFromRecordBatch, EncodeToRecordBatch, WriteStream,
+    DataStreamingError, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy, WriteStream,
 };
 
 use super::kmerge_batch::{EagerStream, ElementBatchIter, KMerge};
 
+/// An item of a query, which is an error if reading or decoding a batch of a file failed.
+pub type QueryItem = anyhow::Result<Data>;
+
+/// Orders query items by `ts_init`, with errors first so they are returned as soon as read.
 #[derive(Debug, Default)]
 pub struct TsInitComparator;
 
-impl<I> Compare<ElementBatchIter<I, Data>> for TsInitComparator
+impl<I> Compare<ElementBatchIter<I, QueryItem>> for TsInitComparator
 where
-    I: Iterator<Item = IntoIter<Data>>,
+    I: Iterator<Item = IntoIter<QueryItem>>,
 {
     fn compare(
         &self,
-        l: &ElementBatchIter<I, Data>,
-        r: &ElementBatchIter<I, Data>,
-    ) -> std::cmp::Ordering {
+        l: &ElementBatchIter<I, QueryItem>,
+        r: &ElementBatchIter<I, QueryItem>,
+    ) -> Ordering {
+        let ordering = match (&l.item, &r.item) {
+            (Ok(l), Ok(r)) => l.ts_init().cmp(&r.ts_init()),
+            (Err(_), Ok(_)) => Ordering::Less,
+            (Ok(_), Err(_)) => Ordering::Greater,
+            (Err(_), Err(_)) => Ordering::Equal,
+        };
         // Max heap ordering must be reversed
-        l.item.ts_init().cmp(&r.item.ts_init()).reverse()
+        ordering.reverse()
     }
 }
 
-pub type QueryResult = KMerge<EagerStream<std::vec::IntoIter<Data>>, Data, TsInitComparator>;
+pub type QueryResult =
+    KMerge<EagerStream<std::vec::IntoIter<QueryItem>>, QueryItem, TsInitComparator>;
 
 /// Provides a DataFusion session and registers DataFusion queries.
 ///
@@ -60,7 +72,8 @@ pub struct DataBackendSession {
     pub chunk_size: usize,
     pub runtime: Arc<tokio::runtime::Runtime>,
     session_ctx: SessionContext,
-    batch_streams: Vec<EagerStream<IntoIter<Data>>>,
+    batch_streams: Vec<EagerStream<IntoIter<QueryItem>>>,
+    enum_policy: UnknownEnumPolicy,
 }
 
 impl DataBackendSession {
@@ -80,9 +93,21 @@ impl DataBackendSession {
             batch_streams: Vec::default(),
             chunk_size,
             runtime: Arc::new(runtime),
+            enum_policy: UnknownEnumPolicy::default(),
         }
     }
 
+    /// Sets how enum discriminants with no known variant are decoded (an error by default),
+    /// for files added after this is set.
+    ///
+    /// With [`UnknownEnumPolicy::Lenient`], unknown values of enums with a safe fallback (such
+    /// as `AggressorSide`) are decoded as that fallback, while all others remain an error.
+    #[must_use]
+    pub fn with_unknown_enum_policy(mut self, enum_policy: UnknownEnumPolicy) -> Self {
+        self.enum_policy = enum_policy;
+        self
+    }
+
     pub fn write_data<T: EncodeToRecordBatch>(
         data: &[T],
         metadata: &HashMap<String, String>,
@@ -102,6 +127,9 @@ impl DataBackendSession {
     /// `sql_query`: A custom sql query to retrieve records from file. If no query is provided a default
     /// query "SELECT * FROM <`table_name`>" is run.
     ///
+    /// A batch which cannot be read or decoded (e.g. with an unknown enum discriminant) is
+    /// returned as an error item of the query result, rather than ending the query.
+    ///
     /// # Safety
     ///
     /// The file data must be ordered by the `ts_init` in ascending order for this
@@ -113,7 +141,7 @@ impl DataBackendSession {
         sql_query: Option<&str>,
     ) -> Result<()>
     where
-        T: DecodeDataFromRecordBatch + Into<Data>,
+        T: DecodeFromRecordBatch + Into<Data>,
     {
         let parquet_options = ParquetReadOptions::<'_> {
             skip_metadata: Some(false),
@@ -136,19 +164,31 @@ impl DataBackendSession {
 
         let batch_stream = self.runtime.block_on(query.execute_stream())?;
 
-        self.add_batch_stream::<T>(batch_stream);
+        self.add_batch_stream::<T>(file_path, batch_stream);
         Ok(())
     }
 
-    fn add_batch_stream<T>(&mut self, stream: SendableRecordBatchStream)
+    fn add_batch_stream<T>(&mut self, file_path: &str, stream: SendableRecordBatchStream)
     where
-        T: DecodeDataFromRecordBatch + Into<Data>,
+        T: DecodeFromRecordBatch + Into<Data>,
     {
-        let transform = stream.map(|result| match result {
-            Ok(batch) => T::decode_data_batch(batch.schema().metadata(), batch)
-                .unwrap()
-                .into_iter(),
-            Err(e) => panic!("Error getting next batch from RecordBatchStream: {e}"),
+        let file_path = file_path.to_string();
+        let enum_policy = self.enum_policy;
+        let transform = stream.map(move |result| {
+            let data = result
+                .with_context(|| format!("Error getting next batch from '{file_path}'"))
+                .and_then(|batch| {
+                    T::decode_batch_with_policy(batch.schema().metadata(), batch, enum_policy)
+                        .with_context(|| format!("Error decoding batch from '{file_path}'"))
+                });
+            match data {
+                Ok(data) => data
+                    .into_iter()
+                    .map(|item| Ok(item.into()))
+                    .collect::<Vec<QueryItem>>()
+                    .into_iter(),
+                Err(e) => vec![Err(e)].into_iter(),
+            }
         });
 
         self.batch_streams
@@ -161,7 +201,7 @@ impl DataBackendSession {
     // Consumes the registered queries and returns a [`QueryResult].
     // Passes the output of the query though the a KMerge which sorts the
     // queries in ascending order of `ts_init`.
-    // QueryResult is an iterator that return Vec<Data>.
+    // QueryResult is an iterator that return `QueryItem`s, with any errors first.
     pub fn get_query_result(&mut self) -> QueryResult {
         let mut kmerge: KMerge<_, _, _> = KMerge::new(TsInitComparator);
 
@@ -223,12 +263,16 @@ impl DataQueryResult {
 }
 
 impl Iterator for DataQueryResult {
-    type Item = Vec<Data>;
+    type Item = anyhow::Result<Vec<Data>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         for _ in 0..self.size {
             match self.result.next() {
-                Some(item) => self.acc.push(item),
+                Some(Ok(item)) => self.acc.push(item),
+                Some(Err(e)) => {
+                    self.acc.clear();
+                    return Some(Err(e));
+                }
                 None => break,
             }
         }
@@ -237,7 +281,7 @@ impl Iterator for DataQueryResult {
         // Some(self.acc.drain(0..).collect())
         let mut acc: Vec<Data> = Vec::new();
         std::mem::swap(&mut acc, &mut self.acc);
-        Some(acc)
+        Some(Ok(acc))
     }
 }
 
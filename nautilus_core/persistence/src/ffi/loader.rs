@@ -0,0 +1,213 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi::{
+    cvec::CVec,
+    parsing::optional_bytes_to_str_map,
+    string::{cstr_to_str, optional_cstr_to_str},
+};
+use nautilus_model::{data::bar::BarType, identifiers::InstrumentId};
+
+use super::{chunk_to_cvec, drop_chunk};
+use crate::{
+    loader::{
+        reader::TextReaderAny, MalformedRowPolicy, TextFormat, TextLoaderConfig, TimestampFormat,
+    },
+    parquet::ParquetDataType,
+};
+
+/// The precision value which indicates the precision should be inferred from the file.
+pub const INFER_PRECISION: u8 = u8::MAX;
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`TextReaderAny`].
+///
+/// This struct wraps `TextReaderAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `TextReaderAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `TextReader_API` to be
+/// dereferenced to `TextReaderAny`, providing access to `TextReaderAny`'s methods without
+/// having to manually access the underlying `TextReaderAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct TextReader_API(Box<TextReaderAny>);
+
+impl Deref for TextReader_API {
+    type Target = TextReaderAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for TextReader_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Opens a new CSV or JSONL reader for `data_type` at the file path `path_ptr`, returning
+/// chunks of `chunk_size` items.
+///
+/// The `columns_ptr` is a JSON object mapping field names to source column names, and the
+/// instrument ID and bar type apply to every row when given. Pass `INFER_PRECISION` for a
+/// precision to be inferred from the file.
+///
+/// # Safety
+///
+/// - Assumes `path_ptr` is a valid C string pointer.
+/// - Assumes `columns_ptr` is either NULL or a valid C string pointer.
+/// - Assumes `instrument_id_ptr` is either NULL or a valid C string pointer.
+/// - Assumes `bar_type_ptr` is either NULL or a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the instrument ID or bar type is invalid.
+/// - If the reader cannot be created (e.g. the file cannot be opened).
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub unsafe extern "C" fn text_reader_new(
+    data_type: ParquetDataType,
+    format: TextFormat,
+    path_ptr: *const c_char,
+    columns_ptr: *const c_char,
+    instrument_id_ptr: *const c_char,
+    bar_type_ptr: *const c_char,
+    price_precision: u8,
+    size_precision: u8,
+    timestamp_format: TimestampFormat,
+    malformed_rows: MalformedRowPolicy,
+    chunk_size: usize,
+) -> TextReader_API {
+    let precision = |value: u8| (value != INFER_PRECISION).then_some(value);
+    let config = TextLoaderConfig {
+        format,
+        columns: optional_bytes_to_str_map(columns_ptr)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(field, column)| (field.to_string(), column.to_string()))
+            .collect(),
+        instrument_id: optional_cstr_to_str(instrument_id_ptr).map(InstrumentId::from),
+        bar_type: optional_cstr_to_str(bar_type_ptr).map(BarType::from),
+        price_precision: precision(price_precision),
+        size_precision: precision(size_precision),
+        timestamp_format,
+        malformed_rows,
+    };
+
+    let reader = TextReaderAny::new(data_type, cstr_to_str(path_ptr), config, chunk_size).unwrap();
+    TextReader_API(Box::new(reader))
+}
+
+#[no_mangle]
+pub extern "C" fn text_reader_drop(reader: TextReader_API) {
+    drop(reader); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn text_reader_data_type(reader: &TextReader_API) -> ParquetDataType {
+    reader.data_type()
+}
+
+/// Returns the number of malformed rows skipped so far.
+#[no_mangle]
+pub extern "C" fn text_reader_skipped_count(reader: &TextReader_API) -> usize {
+    reader.skipped_lines().len()
+}
+
+/// Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+/// once the file is exhausted.
+///
+/// The chunk must be freed with `text_reader_drop_chunk`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If reading the file fails, or a row is malformed with the `FAIL` policy.
+#[no_mangle]
+pub extern "C" fn text_reader_next_chunk(reader: &mut TextReader_API) -> CVec {
+    match &mut **reader {
+        TextReaderAny::QuoteTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        TextReaderAny::TradeTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        TextReaderAny::Bar(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+    }
+}
+
+/// Drops a chunk previously returned by `text_reader_next_chunk`.
+///
+/// # Safety
+///
+/// - Assumes `chunk` was returned by `text_reader_next_chunk` for this `reader`.
+#[no_mangle]
+pub unsafe extern "C" fn text_reader_drop_chunk(reader: &TextReader_API, chunk: CVec) {
+    drop_chunk(reader.data_type(), chunk);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_text_reader_ffi() {
+        let path = CString::new(get_test_data_file_path("nautilus/messy_quotes.csv")).unwrap();
+        let columns = CString::new(
+            r#"{"instrument_id":"symbol","bid_price":"bid","ask_price":"ask","ts_event":"timestamp","ts_init":"received"}"#,
+        )
+        .unwrap();
+        let mut reader = unsafe {
+            text_reader_new(
+                ParquetDataType::QuoteTick,
+                TextFormat::Csv,
+                path.as_ptr(),
+                columns.as_ptr(),
+                std::ptr::null(),
+                std::ptr::null(),
+                INFER_PRECISION,
+                INFER_PRECISION,
+                TimestampFormat::Auto,
+                MalformedRowPolicy::Skip,
+                2,
+            )
+        };
+        assert_eq!(text_reader_data_type(&reader), ParquetDataType::QuoteTick);
+
+        let mut lens = Vec::new();
+        loop {
+            let chunk = text_reader_next_chunk(&mut reader);
+            if chunk.len == 0 {
+                break;
+            }
+            lens.push(chunk.len);
+            unsafe { text_reader_drop_chunk(&reader, chunk) };
+        }
+
+        assert_eq!(lens, vec![2, 2, 1]);
+        assert_eq!(text_reader_skipped_count(&reader), 4);
+        text_reader_drop(reader);
+    }
+}
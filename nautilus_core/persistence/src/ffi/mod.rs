@@ -0,0 +1,50 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! C foreign function interface (FFI) from `cbindgen`.
+
+pub mod loader;
+pub mod reader;
+pub mod streaming;
+pub mod writer;
+
+use nautilus_core::ffi::cvec::CVec;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+
+use crate::parquet::ParquetDataType;
+
+/// Returns a chunk of data as a `CVec`, or an empty `CVec` if there is no chunk.
+fn chunk_to_cvec<T>(chunk: Option<Vec<T>>) -> CVec {
+    chunk.map_or_else(CVec::empty, CVec::from)
+}
+
+/// Drops a chunk of `data_type` items previously returned as a `CVec`.
+///
+/// # Safety
+///
+/// - Assumes `chunk` was created by `chunk_to_cvec` from a `Vec` of `data_type` items.
+#[allow(clippy::drop_non_drop)]
+unsafe fn drop_chunk(data_type: ParquetDataType, chunk: CVec) {
+    let CVec { ptr, len, cap } = chunk;
+    if ptr.is_null() {
+        return;
+    }
+
+    match data_type {
+        ParquetDataType::QuoteTick => drop(Vec::from_raw_parts(ptr.cast::<QuoteTick>(), len, cap)),
+        ParquetDataType::TradeTick => drop(Vec::from_raw_parts(ptr.cast::<TradeTick>(), len, cap)),
+        ParquetDataType::Bar => drop(Vec::from_raw_parts(ptr.cast::<Bar>(), len, cap)),
+    }
+}
@@ -0,0 +1,168 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::{
+    ffi::{cvec::CVec, string::cstr_to_str},
+    nanos::UnixNanos,
+};
+
+use super::{chunk_to_cvec, drop_chunk};
+use crate::parquet::{reader::ParquetReaderAny, ParquetDataType};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`ParquetReaderAny`].
+///
+/// This struct wraps `ParquetReaderAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ParquetReaderAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `ParquetReader_API` to be
+/// dereferenced to `ParquetReaderAny`, providing access to `ParquetReaderAny`'s methods without
+/// having to manually access the underlying `ParquetReaderAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ParquetReader_API(Box<ParquetReaderAny>);
+
+impl Deref for ParquetReader_API {
+    type Target = ParquetReaderAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParquetReader_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Opens a new Parquet reader for `data_type` at the file path `path_ptr`, returning chunks
+/// of `chunk_size` items with `ts_init` within the inclusive range `start_ns` to `end_ns`.
+///
+/// Pass `0` and `u64::MAX` to leave the start and end of the range unbounded.
+///
+/// # Safety
+///
+/// - Assumes `path_ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `chunk_size` is zero.
+/// - If the file cannot be opened or is not a valid market data Parquet file.
+#[no_mangle]
+pub unsafe extern "C" fn parquet_reader_new(
+    data_type: ParquetDataType,
+    path_ptr: *const c_char,
+    chunk_size: usize,
+    start_ns: u64,
+    end_ns: u64,
+) -> ParquetReader_API {
+    let reader = ParquetReaderAny::new(
+        data_type,
+        cstr_to_str(path_ptr),
+        chunk_size,
+        Some(UnixNanos::from(start_ns)),
+        Some(UnixNanos::from(end_ns)),
+    )
+    .unwrap();
+    ParquetReader_API(Box::new(reader))
+}
+
+#[no_mangle]
+pub extern "C" fn parquet_reader_drop(reader: ParquetReader_API) {
+    drop(reader); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn parquet_reader_data_type(reader: &ParquetReader_API) -> ParquetDataType {
+    reader.data_type()
+}
+
+/// Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+/// once the file is exhausted.
+///
+/// The chunk must be freed with `parquet_reader_drop_chunk`.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If reading or decoding the file fails.
+#[no_mangle]
+pub extern "C" fn parquet_reader_next_chunk(reader: &mut ParquetReader_API) -> CVec {
+    match &mut **reader {
+        ParquetReaderAny::QuoteTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        ParquetReaderAny::TradeTick(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+        ParquetReaderAny::Bar(reader) => chunk_to_cvec(reader.next_chunk().unwrap()),
+    }
+}
+
+/// Drops a chunk previously returned by `parquet_reader_next_chunk`.
+///
+/// # Safety
+///
+/// - Assumes `chunk` was returned by `parquet_reader_next_chunk` for this `reader`.
+#[no_mangle]
+pub unsafe extern "C" fn parquet_reader_drop_chunk(reader: &ParquetReader_API, chunk: CVec) {
+    drop_chunk(reader.data_type(), chunk);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::ffi::CString;
+
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parquet_reader_ffi() {
+        let path = CString::new(get_test_data_file_path("nautilus/quotes.parquet")).unwrap();
+        let mut reader = unsafe {
+            parquet_reader_new(
+                ParquetDataType::QuoteTick,
+                path.as_ptr(),
+                4_000,
+                0,
+                u64::MAX,
+            )
+        };
+        assert_eq!(
+            parquet_reader_data_type(&reader),
+            ParquetDataType::QuoteTick
+        );
+
+        let mut lens = Vec::new();
+        loop {
+            let chunk = parquet_reader_next_chunk(&mut reader);
+            if chunk.len == 0 {
+                break;
+            }
+            lens.push(chunk.len);
+            unsafe { parquet_reader_drop_chunk(&reader, chunk) };
+        }
+
+        assert_eq!(lens, vec![4_000, 4_000, 1_500]);
+        parquet_reader_drop(reader);
+    }
+}
@@ -0,0 +1,204 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use nautilus_core::ffi::string::cstr_to_str;
+
+use crate::{
+    parquet::ParquetCompression,
+    streaming::writer::{StreamingFormat, StreamingWriter, StreamingWriterConfig},
+};
+
+/// The streaming writer fed by [`writer_write_event`], installed by [`streaming_writer_init`].
+static STREAMING_WRITER: Mutex<Option<StreamingWriter>> = Mutex::new(None);
+
+fn installed_writer() -> MutexGuard<'static, Option<StreamingWriter>> {
+    // A panic while holding the lock leaves the writer usable, so recover from poisoning
+    STREAMING_WRITER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Flushes and closes the installed streaming writer when dropped.
+#[derive(Debug)]
+pub struct StreamingWriterGuard;
+
+impl Drop for StreamingWriterGuard {
+    fn drop(&mut self) {
+        if let Some(writer) = installed_writer().take() {
+            if let Err(e) = writer.close() {
+                eprintln!("Error closing streaming writer: {e}");
+            }
+        }
+    }
+}
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`StreamingWriterGuard`].
+///
+/// The guard signals that the streaming writer is installed, and flushes and closes its files
+/// when dropped (mirroring the `LogGuard` for the logger).
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct StreamingWriterGuard_API(Box<StreamingWriterGuard>);
+
+/// Installs a streaming writer for the run `run_id_ptr` under the directory `directory_ptr`,
+/// closing any previously installed writer.
+///
+/// A `flush_interval_ms` of zero disables time based flushing.
+///
+/// # Safety
+///
+/// - Assumes `directory_ptr` is a valid C string pointer.
+/// - Assumes `run_id_ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `flush_size` is zero or the run ID is empty.
+#[no_mangle]
+pub unsafe extern "C" fn streaming_writer_init(
+    directory_ptr: *const c_char,
+    run_id_ptr: *const c_char,
+    format: StreamingFormat,
+    flush_size: usize,
+    flush_interval_ms: u64,
+    compression: ParquetCompression,
+) -> StreamingWriterGuard_API {
+    let config = StreamingWriterConfig {
+        format,
+        flush_size,
+        flush_interval: (flush_interval_ms > 0).then(|| Duration::from_millis(flush_interval_ms)),
+        compression,
+    };
+    let writer =
+        StreamingWriter::new(cstr_to_str(directory_ptr), cstr_to_str(run_id_ptr), config).unwrap();
+
+    if let Some(previous) = installed_writer().replace(writer) {
+        if let Err(e) = previous.close() {
+            eprintln!("Error closing streaming writer: {e}");
+        }
+    }
+    StreamingWriterGuard_API(Box::new(StreamingWriterGuard))
+}
+
+/// Writes an event serialized as a JSON object (with a string `type` field) to the installed
+/// streaming writer.
+///
+/// Has no effect if no streaming writer is installed.
+///
+/// # Safety
+///
+/// - Assumes `json_ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the event cannot be written (see [`StreamingWriter::write_json`]).
+#[no_mangle]
+pub unsafe extern "C" fn writer_write_event(json_ptr: *const c_char) {
+    if let Some(writer) = installed_writer().as_mut() {
+        writer.write_json(cstr_to_str(json_ptr)).unwrap();
+    }
+}
+
+/// Closes the files of the installed streaming writer and starts writing the run `run_id_ptr`.
+///
+/// # Safety
+///
+/// - Assumes `run_id_ptr` is a valid C string pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If the run ID is empty or closing the current files fails.
+#[no_mangle]
+pub unsafe extern "C" fn streaming_writer_rotate(run_id_ptr: *const c_char) {
+    if let Some(writer) = installed_writer().as_mut() {
+        writer.rotate(cstr_to_str(run_id_ptr)).unwrap();
+    }
+}
+
+/// Flushes the buffered events of the installed streaming writer to its files.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If flushing fails (see [`StreamingWriter::flush`]).
+#[no_mangle]
+pub extern "C" fn streaming_writer_flush() {
+    if let Some(writer) = installed_writer().as_mut() {
+        writer.flush().unwrap();
+    }
+}
+
+/// Flushes and closes the installed streaming writer.
+#[no_mangle]
+pub extern "C" fn streaming_writer_drop(guard: StreamingWriterGuard_API) {
+    drop(guard);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, fs::File};
+
+    use arrow::ipc::reader::StreamReader;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_streaming_writer_ffi() {
+        let directory = std::env::temp_dir().join(format!(
+            "nautilus_streaming_writer_ffi_{}",
+            std::process::id()
+        ));
+        let directory_cstr = CString::new(directory.to_str().unwrap()).unwrap();
+        let run_id_cstr = CString::new("run-001").unwrap();
+
+        let guard = unsafe {
+            streaming_writer_init(
+                directory_cstr.as_ptr(),
+                run_id_cstr.as_ptr(),
+                StreamingFormat::Feather,
+                100,
+                0,
+                ParquetCompression::default(),
+            )
+        };
+        for i in 0..250 {
+            let json = format!(r#"{{"type": "OrderFilled", "trade_id": "{i}", "ts_event": {i}}}"#);
+            let json_cstr = CString::new(json).unwrap();
+            unsafe { writer_write_event(json_cstr.as_ptr()) };
+        }
+        streaming_writer_drop(guard); // Flushes the final partial batch
+
+        assert!(installed_writer().is_none());
+        let file = File::open(directory.join("run-001/order_filled.feather")).unwrap();
+        let rows: usize = StreamReader::try_new(file, None)
+            .unwrap()
+            .map(|batch| batch.unwrap().num_rows())
+            .sum();
+        assert_eq!(rows, 250);
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi::{cvec::CVec, string::cstr_to_str};
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+
+use crate::parquet::{
+    writer::{ParquetWriterAny, ParquetWriterConfig},
+    ParquetCompression, ParquetDataType,
+};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`ParquetWriterAny`].
+///
+/// This struct wraps `ParquetWriterAny` in a way that makes it compatible with C function
+/// calls, enabling interaction with `ParquetWriterAny` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `ParquetWriter_API` to be
+/// dereferenced to `ParquetWriterAny`, providing access to `ParquetWriterAny`'s methods without
+/// having to manually access the underlying `ParquetWriterAny` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct ParquetWriter_API(Box<ParquetWriterAny>);
+
+impl Deref for ParquetWriter_API {
+    type Target = ParquetWriterAny;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ParquetWriter_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Opens a new Parquet writer for `data_type` at the file path `path_ptr`, returning 1 on
+/// success with the writer written to `writer`, otherwise 0 (if `row_group_size` is zero).
+///
+/// # Safety
+///
+/// - Assumes `path_ptr` is a valid C string pointer.
+/// - Assumes `writer` is a valid pointer to write the new writer to.
+#[no_mangle]
+pub unsafe extern "C" fn parquet_writer_new(
+    data_type: ParquetDataType,
+    path_ptr: *const c_char,
+    row_group_size: usize,
+    compression: ParquetCompression,
+    writer: *mut ParquetWriter_API,
+) -> u8 {
+    let config = ParquetWriterConfig {
+        row_group_size,
+        compression,
+    };
+    match ParquetWriterAny::new(data_type, cstr_to_str(path_ptr), config) {
+        Ok(new_writer) => {
+            writer.write(ParquetWriter_API(Box::new(new_writer)));
+            1
+        }
+        Err(_) => 0,
+    }
+}
+
+/// Writes a batch of data to the Parquet writer, returning 1 on success, otherwise 0 (see
+/// [`crate::parquet::writer::ParquetWriter::write_batch`] for the failure cases).
+///
+/// The data is only borrowed, so the caller retains ownership of the `data` memory.
+///
+/// # Safety
+///
+/// - Assumes `data` is a valid `CVec` containing objects of the writer's data type.
+#[no_mangle]
+pub unsafe extern "C" fn parquet_writer_write_batch(
+    writer: &mut ParquetWriter_API,
+    data: &CVec,
+) -> u8 {
+    let CVec { ptr, len, .. } = *data;
+    if len == 0 {
+        return 1;
+    }
+
+    let result = match &mut **writer {
+        ParquetWriterAny::QuoteTick(writer) => {
+            writer.write_batch(std::slice::from_raw_parts(ptr.cast::<QuoteTick>(), len))
+        }
+        ParquetWriterAny::TradeTick(writer) => {
+            writer.write_batch(std::slice::from_raw_parts(ptr.cast::<TradeTick>(), len))
+        }
+        ParquetWriterAny::Bar(writer) => {
+            writer.write_batch(std::slice::from_raw_parts(ptr.cast::<Bar>(), len))
+        }
+    };
+    u8::from(result.is_ok())
+}
+
+#[no_mangle]
+pub extern "C" fn parquet_writer_data_type(writer: &ParquetWriter_API) -> ParquetDataType {
+    writer.data_type()
+}
+
+#[no_mangle]
+pub extern "C" fn parquet_writer_row_count(writer: &ParquetWriter_API) -> usize {
+    writer.row_count()
+}
+
+/// Closes the Parquet writer (freeing its memory), returning 1 on success with the total
+/// number of rows written to `row_count`, otherwise 0 (if flushing or closing the file fails).
+///
+/// # Safety
+///
+/// - Assumes `row_count` is a valid pointer to write the row count to.
+#[no_mangle]
+pub unsafe extern "C" fn parquet_writer_close(
+    writer: ParquetWriter_API,
+    row_count: *mut usize,
+) -> u8 {
+    match writer.0.close() {
+        // Memory freed here
+        Ok(rows) => {
+            row_count.write(rows);
+            1
+        }
+        Err(_) => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CString, mem::MaybeUninit};
+
+    use nautilus_model::{data::stubs::quote_ethusdt_binance, identifiers::InstrumentId};
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_parquet_writer_ffi(quote_ethusdt_binance: QuoteTick) {
+        let path = std::env::temp_dir()
+            .join(format!(
+                "nautilus_parquet_writer_ffi_{}",
+                std::process::id()
+            ))
+            .join("quotes.parquet");
+        let path_cstr = CString::new(path.to_str().unwrap()).unwrap();
+        let data: CVec = vec![quote_ethusdt_binance; 3].into();
+
+        let mut writer = MaybeUninit::<ParquetWriter_API>::uninit();
+        let opened = unsafe {
+            parquet_writer_new(
+                ParquetDataType::QuoteTick,
+                path_cstr.as_ptr(),
+                2,
+                ParquetCompression::Snappy,
+                writer.as_mut_ptr(),
+            )
+        };
+        assert_eq!(opened, 1);
+        let mut writer = unsafe { writer.assume_init() };
+        assert_eq!(unsafe { parquet_writer_write_batch(&mut writer, &data) }, 1);
+        let mut other = quote_ethusdt_binance;
+        other.instrument_id = InstrumentId::from("BTCUSDT.BINANCE");
+        let mismatched: CVec = vec![other].into();
+        assert_eq!(
+            unsafe { parquet_writer_write_batch(&mut writer, &mismatched) },
+            0
+        );
+
+        assert_eq!(
+            parquet_writer_data_type(&writer),
+            ParquetDataType::QuoteTick
+        );
+        assert_eq!(parquet_writer_row_count(&writer), 3);
+        let mut row_count = 0;
+        assert_eq!(unsafe { parquet_writer_close(writer, &mut row_count) }, 1);
+        assert_eq!(row_count, 3);
+        assert!(path.exists());
+
+        for data in [data, mismatched] {
+            let CVec { ptr, len, cap } = data;
+            drop(unsafe { Vec::from_raw_parts(ptr.cast::<QuoteTick>(), len, cap) });
+        }
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_parquet_writer_new_with_zero_row_group_size() {
+        let path_cstr = CString::new("quotes.parquet").unwrap();
+        let mut writer = MaybeUninit::<ParquetWriter_API>::uninit();
+
+        let opened = unsafe {
+            parquet_writer_new(
+                ParquetDataType::QuoteTick,
+                path_cstr.as_ptr(),
+                0,
+                ParquetCompression::Snappy,
+                writer.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(opened, 0);
+    }
+}
@@ -28,6 +28,12 @@
 //! - `python`: Enables Python bindings from `pyo3`.
 
 pub mod backend;
+pub mod loader;
+pub mod parquet;
+pub mod streaming;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
 
 #[cfg(feature = "python")]
 pub mod python;
@@ -0,0 +1,602 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Loading market data from vendor CSV and newline-delimited JSON (JSONL) files.
+//!
+//! Each data field is read from a source column of the same name unless mapped to another
+//! column with [`TextLoaderConfig::with_column`]. Columns which are not mapped to a field are
+//! ignored. The fields for each data type are:
+//!
+//! - `QuoteTick`: `instrument_id`, `bid_price`, `ask_price`, `bid_size`, `ask_size`, `ts_event`
+//!   and `ts_init`.
+//! - `TradeTick`: `instrument_id`, `price`, `size`, `aggressor_side`, `trade_id`, `ts_event`
+//!   and `ts_init`.
+//! - `Bar`: `open`, `high`, `low`, `close`, `volume`, `ts_event` and `ts_init`, with the bar
+//!   type taken from the config.
+//!
+//! The `instrument_id` field is only required if no instrument ID is set in the config, and
+//! `ts_init` defaults to `ts_event`. For trades, a missing `aggressor_side` defaults to
+//! `NO_AGGRESSOR` and a missing `trade_id` defaults to the line number.
+
+pub mod reader;
+
+use std::{collections::HashMap, str::FromStr};
+
+use chrono::{DateTime, NaiveDateTime};
+use nautilus_core::{nanos::UnixNanos, parsing::precision_from_str};
+use nautilus_model::{
+    data::{
+        bar::{Bar, BarType},
+        quote::QuoteTick,
+        trade::TradeTick,
+    },
+    enums::AggressorSide,
+    identifiers::{InstrumentId, TradeId},
+    types::{price::Price, quantity::Quantity},
+};
+
+/// Integer timestamps below this value are treated as milliseconds when the timestamp format
+/// is [`TimestampFormat::Auto`] (as nanoseconds it would be within two weeks of the epoch).
+pub const AUTO_MILLIS_THRESHOLD: u64 = 1_000_000_000_000_000;
+
+/// The text format of a file to load.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TextFormat {
+    /// Comma-separated values with a header row.
+    #[default]
+    Csv = 1,
+    /// Newline-delimited JSON, with one object per line.
+    JsonLines = 2,
+}
+
+/// The format of the timestamp columns in a file to load.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimestampFormat {
+    /// Detected per value: integers as nanoseconds or milliseconds since the UNIX epoch (see
+    /// [`AUTO_MILLIS_THRESHOLD`]), otherwise ISO 8601.
+    #[default]
+    Auto = 0,
+    /// Integer nanoseconds since the UNIX epoch.
+    UnixNanos = 1,
+    /// Integer milliseconds since the UNIX epoch.
+    UnixMillis = 2,
+    /// ISO 8601 (RFC 3339), or a date and time without an offset which is taken as UTC.
+    Iso8601 = 3,
+}
+
+/// The handling of rows which cannot be parsed.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MalformedRowPolicy {
+    /// Return an error naming the line of the first malformed row.
+    #[default]
+    Fail = 0,
+    /// Skip malformed rows, logging a warning with the line number of each.
+    Skip = 1,
+}
+
+/// Configuration for loading market data from a CSV or JSONL file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TextLoaderConfig {
+    /// The text format of the file.
+    pub format: TextFormat,
+    /// The source column for each field, where not the same as the field name.
+    pub columns: HashMap<String, String>,
+    /// The instrument ID for every row, otherwise read from the `instrument_id` field.
+    pub instrument_id: Option<InstrumentId>,
+    /// The bar type for every row (required to load bars).
+    pub bar_type: Option<BarType>,
+    /// The price precision, otherwise inferred from the first chunk of rows.
+    pub price_precision: Option<u8>,
+    /// The size precision, otherwise inferred from the first chunk of rows.
+    pub size_precision: Option<u8>,
+    /// The format of the `ts_event` and `ts_init` fields.
+    pub timestamp_format: TimestampFormat,
+    /// The handling of rows which cannot be parsed.
+    pub malformed_rows: MalformedRowPolicy,
+}
+
+impl TextLoaderConfig {
+    /// Creates a new [`TextLoaderConfig`] instance for the given `format`.
+    #[must_use]
+    pub fn new(format: TextFormat) -> Self {
+        Self {
+            format,
+            ..Default::default()
+        }
+    }
+
+    /// Sets the source `column` for the given `field`.
+    #[must_use]
+    pub fn with_column(mut self, field: &str, column: &str) -> Self {
+        self.columns.insert(field.to_string(), column.to_string());
+        self
+    }
+
+    /// Sets the instrument ID for every row.
+    #[must_use]
+    pub const fn with_instrument_id(mut self, instrument_id: InstrumentId) -> Self {
+        self.instrument_id = Some(instrument_id);
+        self
+    }
+
+    /// Sets the bar type for every row.
+    #[must_use]
+    pub const fn with_bar_type(mut self, bar_type: BarType) -> Self {
+        self.bar_type = Some(bar_type);
+        self
+    }
+
+    /// Sets explicit price and size precisions rather than inferring them.
+    #[must_use]
+    pub const fn with_precisions(mut self, price_precision: u8, size_precision: u8) -> Self {
+        self.price_precision = Some(price_precision);
+        self.size_precision = Some(size_precision);
+        self
+    }
+
+    /// Sets the format of the `ts_event` and `ts_init` fields.
+    #[must_use]
+    pub const fn with_timestamp_format(mut self, timestamp_format: TimestampFormat) -> Self {
+        self.timestamp_format = timestamp_format;
+        self
+    }
+
+    /// Sets the handling of rows which cannot be parsed.
+    #[must_use]
+    pub const fn with_malformed_rows(mut self, malformed_rows: MalformedRowPolicy) -> Self {
+        self.malformed_rows = malformed_rows;
+        self
+    }
+
+    /// Returns the source column for the given `field`.
+    #[must_use]
+    pub fn column<'a>(&'a self, field: &'a str) -> &'a str {
+        self.columns.get(field).map_or(field, String::as_str)
+    }
+}
+
+/// A single row of a file, keyed by source column name.
+pub type TextRecord = HashMap<String, String>;
+
+/// A row being parsed as market data, resolving fields through the config.
+pub struct TextRow<'a> {
+    record: &'a TextRecord,
+    config: &'a TextLoaderConfig,
+    line: usize,
+    price_precision: u8,
+    size_precision: u8,
+}
+
+impl<'a> TextRow<'a> {
+    /// Creates a new [`TextRow`] instance.
+    #[must_use]
+    pub const fn new(
+        record: &'a TextRecord,
+        config: &'a TextLoaderConfig,
+        line: usize,
+        price_precision: u8,
+        size_precision: u8,
+    ) -> Self {
+        Self {
+            record,
+            config,
+            line,
+            price_precision,
+            size_precision,
+        }
+    }
+
+    /// Returns the value of `field`, or `None` if missing or empty.
+    #[must_use]
+    pub fn get(&self, field: &str) -> Option<&'a str> {
+        self.record
+            .get(self.config.column(field))
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn required(&self, field: &str) -> anyhow::Result<&'a str> {
+        self.get(field).ok_or_else(|| {
+            anyhow::anyhow!(
+                "missing field `{field}` (column '{}')",
+                self.config.column(field)
+            )
+        })
+    }
+
+    fn f64(&self, field: &str) -> anyhow::Result<f64> {
+        let value = self.required(field)?;
+        value
+            .parse::<f64>()
+            .map_err(|e| anyhow::anyhow!("invalid `{field}` value '{value}', {e}"))
+    }
+
+    fn price(&self, field: &str) -> anyhow::Result<Price> {
+        Price::new_checked(self.f64(field)?, self.price_precision)
+            .map_err(|e| anyhow::anyhow!("invalid `{field}`, {e}"))
+    }
+
+    fn quantity(&self, field: &str) -> anyhow::Result<Quantity> {
+        Quantity::new_checked(self.f64(field)?, self.size_precision)
+            .map_err(|e| anyhow::anyhow!("invalid `{field}`, {e}"))
+    }
+
+    fn instrument_id(&self) -> anyhow::Result<InstrumentId> {
+        match self.config.instrument_id {
+            Some(instrument_id) => Ok(instrument_id),
+            None => InstrumentId::from_str(self.required(FIELD_INSTRUMENT_ID)?)
+                .map_err(|e| anyhow::anyhow!("invalid `{FIELD_INSTRUMENT_ID}`, {e}")),
+        }
+    }
+
+    /// Returns the `ts_event` and `ts_init` timestamps, with `ts_init` defaulting to `ts_event`.
+    fn timestamps(&self) -> anyhow::Result<(UnixNanos, UnixNanos)> {
+        let format = self.config.timestamp_format;
+        let parse = |field: &str, value: &str| {
+            parse_timestamp(value, format).map_err(|e| anyhow::anyhow!("invalid `{field}`, {e}"))
+        };
+
+        let ts_event = parse(FIELD_TS_EVENT, self.required(FIELD_TS_EVENT)?)?;
+        let ts_init = match self.get(FIELD_TS_INIT) {
+            Some(value) => parse(FIELD_TS_INIT, value)?,
+            None => ts_event,
+        };
+        Ok((ts_event, ts_init))
+    }
+}
+
+pub const FIELD_INSTRUMENT_ID: &str = "instrument_id";
+pub const FIELD_TS_EVENT: &str = "ts_event";
+pub const FIELD_TS_INIT: &str = "ts_init";
+
+/// Market data which can be loaded from a CSV or JSONL file.
+pub trait TextData: Sized {
+    /// The fields which may be mapped to a source column.
+    const FIELDS: &'static [&'static str];
+    /// The fields from which the price precision is inferred.
+    const PRICE_FIELDS: &'static [&'static str];
+    /// The fields from which the size precision is inferred.
+    const SIZE_FIELDS: &'static [&'static str];
+
+    /// Returns the fields which must be present in every row for the given `config`.
+    fn required_fields(config: &TextLoaderConfig) -> Vec<&'static str>;
+
+    /// Checks the `config` has all settings required to load the data type.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a required setting is missing.
+    fn check_config(_config: &TextLoaderConfig) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    /// Parses an item from the given `row`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If a required field is missing or any field value is invalid.
+    fn from_row(row: &TextRow) -> anyhow::Result<Self>;
+}
+
+fn with_instrument_id(config: &TextLoaderConfig, fields: &[&'static str]) -> Vec<&'static str> {
+    let mut fields = fields.to_vec();
+    if config.instrument_id.is_none() {
+        fields.push(FIELD_INSTRUMENT_ID);
+    }
+    fields
+}
+
+impl TextData for QuoteTick {
+    const FIELDS: &'static [&'static str] = &[
+        FIELD_INSTRUMENT_ID,
+        "bid_price",
+        "ask_price",
+        "bid_size",
+        "ask_size",
+        FIELD_TS_EVENT,
+        FIELD_TS_INIT,
+    ];
+    const PRICE_FIELDS: &'static [&'static str] = &["bid_price", "ask_price"];
+    const SIZE_FIELDS: &'static [&'static str] = &["bid_size", "ask_size"];
+
+    fn required_fields(config: &TextLoaderConfig) -> Vec<&'static str> {
+        with_instrument_id(
+            config,
+            &[
+                "bid_price",
+                "ask_price",
+                "bid_size",
+                "ask_size",
+                FIELD_TS_EVENT,
+            ],
+        )
+    }
+
+    fn from_row(row: &TextRow) -> anyhow::Result<Self> {
+        let (ts_event, ts_init) = row.timestamps()?;
+        Self::new_checked(
+            row.instrument_id()?,
+            row.price("bid_price")?,
+            row.price("ask_price")?,
+            row.quantity("bid_size")?,
+            row.quantity("ask_size")?,
+            ts_event,
+            ts_init,
+        )
+    }
+}
+
+impl TextData for TradeTick {
+    const FIELDS: &'static [&'static str] = &[
+        FIELD_INSTRUMENT_ID,
+        "price",
+        "size",
+        "aggressor_side",
+        "trade_id",
+        FIELD_TS_EVENT,
+        FIELD_TS_INIT,
+    ];
+    const PRICE_FIELDS: &'static [&'static str] = &["price"];
+    const SIZE_FIELDS: &'static [&'static str] = &["size"];
+
+    fn required_fields(config: &TextLoaderConfig) -> Vec<&'static str> {
+        with_instrument_id(config, &["price", "size", FIELD_TS_EVENT])
+    }
+
+    fn from_row(row: &TextRow) -> anyhow::Result<Self> {
+        let aggressor_side = match row.get("aggressor_side") {
+            Some(value) => parse_aggressor_side(value)?,
+            None => AggressorSide::NoAggressor,
+        };
+        let trade_id = match row.get("trade_id") {
+            Some(value) => TradeId::new_checked(value)?,
+            None => TradeId::new(&row.line.to_string()),
+        };
+        let (ts_event, ts_init) = row.timestamps()?;
+        Ok(Self::new(
+            row.instrument_id()?,
+            row.price("price")?,
+            row.quantity("size")?,
+            aggressor_side,
+            trade_id,
+            ts_event,
+            ts_init,
+        ))
+    }
+}
+
+impl TextData for Bar {
+    const FIELDS: &'static [&'static str] = &[
+        "open",
+        "high",
+        "low",
+        "close",
+        "volume",
+        FIELD_TS_EVENT,
+        FIELD_TS_INIT,
+    ];
+    const PRICE_FIELDS: &'static [&'static str] = &["open", "high", "low", "close"];
+    const SIZE_FIELDS: &'static [&'static str] = &["volume"];
+
+    fn required_fields(_config: &TextLoaderConfig) -> Vec<&'static str> {
+        vec!["open", "high", "low", "close", "volume", FIELD_TS_EVENT]
+    }
+
+    fn check_config(config: &TextLoaderConfig) -> anyhow::Result<()> {
+        if config.bar_type.is_none() {
+            anyhow::bail!("No `bar_type` configured for loading bars");
+        }
+        Ok(())
+    }
+
+    fn from_row(row: &TextRow) -> anyhow::Result<Self> {
+        let bar_type = row
+            .config
+            .bar_type
+            .ok_or_else(|| anyhow::anyhow!("no `bar_type` configured"))?;
+        let (ts_event, ts_init) = row.timestamps()?;
+        Ok(Self::new(
+            bar_type,
+            row.price("open")?,
+            row.price("high")?,
+            row.price("low")?,
+            row.price("close")?,
+            row.quantity("volume")?,
+            ts_event,
+            ts_init,
+        ))
+    }
+}
+
+/// Returns the largest decimal precision of the given `fields` over the `records`, ignoring
+/// values which are missing or not numbers.
+#[must_use]
+pub fn infer_precision<'a>(
+    records: impl Iterator<Item = &'a TextRecord>,
+    config: &TextLoaderConfig,
+    fields: &[&str],
+) -> u8 {
+    records
+        .flat_map(|record| {
+            fields
+                .iter()
+                .filter_map(|field| record.get(config.column(field)))
+        })
+        .map(|value| value.trim())
+        .filter(|value| value.parse::<f64>().is_ok_and(f64::is_finite))
+        .map(precision_from_str)
+        .max()
+        .unwrap_or(0)
+}
+
+/// Parses a UNIX timestamp from the given `value` in the given `format`.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If `value` is not a valid timestamp in the `format`, or is before the UNIX epoch.
+pub fn parse_timestamp(value: &str, format: TimestampFormat) -> anyhow::Result<UnixNanos> {
+    let parse_u64 = |value: &str| {
+        value
+            .parse::<u64>()
+            .map_err(|e| anyhow::anyhow!("invalid integer timestamp '{value}', {e}"))
+    };
+    let millis_to_nanos = |millis: u64| {
+        millis
+            .checked_mul(1_000_000)
+            .map(UnixNanos::from)
+            .ok_or_else(|| anyhow::anyhow!("millisecond timestamp '{value}' out of range"))
+    };
+
+    match format {
+        TimestampFormat::UnixNanos => parse_u64(value).map(UnixNanos::from),
+        TimestampFormat::UnixMillis => millis_to_nanos(parse_u64(value)?),
+        TimestampFormat::Iso8601 => parse_iso8601(value),
+        TimestampFormat::Auto => {
+            if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+                let value = parse_u64(value)?;
+                if value < AUTO_MILLIS_THRESHOLD {
+                    millis_to_nanos(value)
+                } else {
+                    Ok(UnixNanos::from(value))
+                }
+            } else {
+                parse_iso8601(value)
+            }
+        }
+    }
+}
+
+fn parse_iso8601(value: &str) -> anyhow::Result<UnixNanos> {
+    let datetime = DateTime::parse_from_rfc3339(value)
+        .map(|datetime| datetime.to_utc())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+                .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%.f"))
+                .map(|datetime| datetime.and_utc())
+        })
+        .map_err(|_| anyhow::anyhow!("invalid ISO 8601 timestamp '{value}'"))?;
+
+    datetime
+        .timestamp_nanos_opt()
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .map(UnixNanos::from)
+        .ok_or_else(|| anyhow::anyhow!("timestamp '{value}' out of range"))
+}
+
+/// Parses an aggressor side, also accepting the `BUY`/`B` and `SELL`/`S` spellings.
+fn parse_aggressor_side(value: &str) -> anyhow::Result<AggressorSide> {
+    match value.to_ascii_uppercase().as_str() {
+        "BUY" | "B" => Ok(AggressorSide::Buyer),
+        "SELL" | "S" => Ok(AggressorSide::Seller),
+        _ => AggressorSide::from_str(value)
+            .map_err(|_| anyhow::anyhow!("invalid `aggressor_side` value '{value}'")),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        "1577836800000000000",
+        TimestampFormat::Auto,
+        1_577_836_800_000_000_000
+    )]
+    #[case("1577836800123", TimestampFormat::Auto, 1_577_836_800_123_000_000)]
+    #[case(
+        "2020-01-01T00:00:00.5Z",
+        TimestampFormat::Auto,
+        1_577_836_800_500_000_000
+    )]
+    #[case(
+        "2020-01-01T10:00:00+10:00",
+        TimestampFormat::Auto,
+        1_577_836_800_000_000_000
+    )]
+    #[case(
+        "2020-01-01 00:00:01",
+        TimestampFormat::Auto,
+        1_577_836_801_000_000_000
+    )]
+    #[case(
+        "2020-01-01T00:00:00.000000001",
+        TimestampFormat::Iso8601,
+        1_577_836_800_000_000_001
+    )]
+    #[case("1577836800", TimestampFormat::UnixMillis, 1_577_836_800_000_000)]
+    #[case("1577836800", TimestampFormat::UnixNanos, 1_577_836_800)]
+    fn test_parse_timestamp(
+        #[case] value: &str,
+        #[case] format: TimestampFormat,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(
+            parse_timestamp(value, format).unwrap(),
+            UnixNanos::from(expected)
+        );
+    }
+
+    #[rstest]
+    #[case("", TimestampFormat::Auto)]
+    #[case("yesterday", TimestampFormat::Auto)]
+    #[case("-1", TimestampFormat::Auto)]
+    #[case("1969-12-31T23:59:59Z", TimestampFormat::Iso8601)]
+    #[case("2020-01-01T00:00:00Z", TimestampFormat::UnixNanos)]
+    #[case("18446744073709551615", TimestampFormat::UnixMillis)]
+    fn test_parse_timestamp_invalid(#[case] value: &str, #[case] format: TimestampFormat) {
+        assert!(parse_timestamp(value, format).is_err());
+    }
+
+    #[rstest]
+    #[case("buy", AggressorSide::Buyer)]
+    #[case("S", AggressorSide::Seller)]
+    #[case("SELLER", AggressorSide::Seller)]
+    #[case("no_aggressor", AggressorSide::NoAggressor)]
+    fn test_parse_aggressor_side(#[case] value: &str, #[case] expected: AggressorSide) {
+        assert_eq!(parse_aggressor_side(value).unwrap(), expected);
+    }
+
+    #[rstest]
+    fn test_infer_precision() {
+        let config = TextLoaderConfig::default().with_column("bid_price", "bid");
+        let records: Vec<TextRecord> = vec![
+            TextRecord::from([("bid".to_string(), "1.1".to_string())]),
+            TextRecord::from([("bid".to_string(), "1.10005".to_string())]),
+            TextRecord::from([("bid".to_string(), "n/a".to_string())]),
+            TextRecord::from([("ask_price".to_string(), "1.1000001".to_string())]),
+        ];
+
+        assert_eq!(
+            infer_precision(records.iter(), &config, QuoteTick::PRICE_FIELDS),
+            7
+        );
+        assert_eq!(
+            infer_precision(records.iter(), &config, QuoteTick::SIZE_FIELDS),
+            0
+        );
+    }
+}
@@ -0,0 +1,519 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, Lines},
+    path::{Path, PathBuf},
+};
+
+use csv::{ReaderBuilder, StringRecord};
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick};
+use serde_json::Value;
+
+use super::{
+    infer_precision, MalformedRowPolicy, TextData, TextFormat, TextLoaderConfig, TextRecord,
+    TextRow,
+};
+use crate::parquet::ParquetDataType;
+
+/// Reads market data from a CSV or JSONL file in fixed-size chunks.
+///
+/// Unless set in the config, the price and size precisions are inferred as the largest number
+/// of decimal places within the first chunk of rows, and then applied to the whole file.
+pub struct TextReader<T: TextData> {
+    path: PathBuf,
+    config: TextLoaderConfig,
+    source: RecordSource,
+    pending: VecDeque<(usize, anyhow::Result<TextRecord>)>,
+    chunk_size: usize,
+    precisions: Option<(u8, u8)>,
+    skipped_lines: Vec<usize>,
+    _phantom: std::marker::PhantomData<T>,
+}
+
+impl<T: TextData> TextReader<T> {
+    /// Creates a new [`TextReader`] instance for the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `chunk_size` is zero.
+    /// - If the config maps an unknown field, or has no bar type when loading bars.
+    /// - If the file cannot be opened.
+    /// - If a CSV header has no column for a required field.
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        config: TextLoaderConfig,
+        chunk_size: usize,
+    ) -> anyhow::Result<Self> {
+        if chunk_size == 0 {
+            anyhow::bail!("Invalid `chunk_size`, was zero");
+        }
+        if let Some(field) = config
+            .columns
+            .keys()
+            .find(|f| !T::FIELDS.contains(&f.as_str()))
+        {
+            anyhow::bail!(
+                "Invalid column mapping for unknown field `{field}`, expected one of {:?}",
+                T::FIELDS
+            );
+        }
+        T::check_config(&config)?;
+
+        let path = path.as_ref().to_path_buf();
+        let source = RecordSource::new(&path, config.format)?;
+        if let RecordSource::Csv { headers, .. } = &source {
+            for field in T::required_fields(&config) {
+                let column = config.column(field);
+                if !headers.iter().any(|header| header.trim() == column) {
+                    anyhow::bail!(
+                        "Missing column '{column}' for field `{field}` in '{}'",
+                        path.display()
+                    );
+                }
+            }
+        }
+
+        let precisions = config.price_precision.zip(config.size_precision);
+        Ok(Self {
+            path,
+            config,
+            source,
+            pending: VecDeque::new(),
+            chunk_size,
+            precisions,
+            skipped_lines: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        })
+    }
+
+    /// Returns the line numbers of the malformed rows skipped so far.
+    #[must_use]
+    pub fn skipped_lines(&self) -> &[usize] {
+        &self.skipped_lines
+    }
+
+    /// Returns the price and size precisions, once known.
+    #[must_use]
+    pub const fn precisions(&self) -> Option<(u8, u8)> {
+        self.precisions
+    }
+
+    /// Returns the next chunk of up to `chunk_size` items, or `None` once the file is exhausted.
+    ///
+    /// Every chunk other than the last contains exactly `chunk_size` items.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If reading the file fails.
+    /// - If a row is malformed and the policy is [`MalformedRowPolicy::Fail`], naming its line.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<T>>> {
+        let (price_precision, size_precision) = self.resolve_precisions();
+
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        while chunk.len() < self.chunk_size {
+            let Some((line, record)) = self.pending.pop_front().or_else(|| self.source.next())
+            else {
+                break;
+            };
+
+            let result = record.and_then(|record| {
+                T::from_row(&TextRow::new(
+                    &record,
+                    &self.config,
+                    line,
+                    price_precision,
+                    size_precision,
+                ))
+            });
+            match result {
+                Ok(item) => chunk.push(item),
+                Err(e) => match self.config.malformed_rows {
+                    MalformedRowPolicy::Fail => anyhow::bail!(
+                        "Malformed row at line {line} of '{}': {e}",
+                        self.path.display()
+                    ),
+                    MalformedRowPolicy::Skip => {
+                        log::warn!(
+                            "Skipping malformed row at line {line} of '{}': {e}",
+                            self.path.display()
+                        );
+                        self.skipped_lines.push(line);
+                    }
+                },
+            }
+        }
+
+        Ok((!chunk.is_empty()).then_some(chunk))
+    }
+
+    /// Returns the configured precisions, otherwise infers any unset precision from a buffered
+    /// first chunk of rows.
+    fn resolve_precisions(&mut self) -> (u8, u8) {
+        if let Some(precisions) = self.precisions {
+            return precisions;
+        }
+
+        while self.pending.len() < self.chunk_size {
+            match self.source.next() {
+                Some(record) => self.pending.push_back(record),
+                None => break,
+            }
+        }
+
+        let records = || self.pending.iter().filter_map(|(_, r)| r.as_ref().ok());
+        let precisions = (
+            self.config
+                .price_precision
+                .unwrap_or_else(|| infer_precision(records(), &self.config, T::PRICE_FIELDS)),
+            self.config
+                .size_precision
+                .unwrap_or_else(|| infer_precision(records(), &self.config, T::SIZE_FIELDS)),
+        );
+        self.precisions = Some(precisions);
+        precisions
+    }
+}
+
+impl<T: TextData> Iterator for TextReader<T> {
+    type Item = anyhow::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+/// The rows of a file, each with its line number.
+enum RecordSource {
+    Csv {
+        reader: csv::Reader<BufReader<File>>,
+        headers: StringRecord,
+        record: StringRecord,
+    },
+    JsonLines {
+        lines: Lines<BufReader<File>>,
+        line: usize,
+    },
+}
+
+impl RecordSource {
+    fn new(path: &Path, format: TextFormat) -> anyhow::Result<Self> {
+        let file = File::open(path)
+            .map_err(|e| anyhow::anyhow!("Error opening '{}': {e}", path.display()))?;
+        let buf_reader = BufReader::new(file);
+
+        Ok(match format {
+            TextFormat::Csv => {
+                // Flexible so rows with missing or extra fields are reported per row
+                let mut reader = ReaderBuilder::new()
+                    .has_headers(true)
+                    .flexible(true)
+                    .from_reader(buf_reader);
+                let headers = reader.headers()?.clone();
+                Self::Csv {
+                    reader,
+                    headers,
+                    record: StringRecord::new(),
+                }
+            }
+            TextFormat::JsonLines => Self::JsonLines {
+                lines: buf_reader.lines(),
+                line: 0,
+            },
+        })
+    }
+}
+
+impl Iterator for RecordSource {
+    type Item = (usize, anyhow::Result<TextRecord>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Csv {
+                reader,
+                headers,
+                record,
+            } => match reader.read_record(record) {
+                Ok(true) => {
+                    let line = record.position().map_or(0, |p| p.line() as usize);
+                    let values = headers
+                        .iter()
+                        .zip(record.iter())
+                        .map(|(header, value)| (header.trim().to_string(), value.to_string()))
+                        .collect();
+                    Some((line, Ok(values)))
+                }
+                Ok(false) => None,
+                Err(e) => {
+                    let line = e.position().map_or(0, |p| p.line() as usize);
+                    Some((line, Err(e.into())))
+                }
+            },
+            Self::JsonLines { lines, line } => loop {
+                *line += 1;
+                match lines.next()? {
+                    Ok(text) if text.trim().is_empty() => continue,
+                    Ok(text) => break Some((*line, parse_json_record(&text))),
+                    Err(e) => break Some((*line, Err(e.into()))),
+                }
+            },
+        }
+    }
+}
+
+/// Parses a JSON object into a record, with numbers kept in their textual form.
+///
+/// Note that JSON numbers lose any trailing zeros (e.g. `1.10` is read as `1.1`), so prices
+/// should be quoted strings where the precision is to be inferred.
+fn parse_json_record(text: &str) -> anyhow::Result<TextRecord> {
+    let object: serde_json::Map<String, Value> = serde_json::from_str(text)?;
+    Ok(object
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            Value::Null => None,
+            Value::String(value) => Some((key, value)),
+            value => Some((key, value.to_string())),
+        })
+        .collect())
+}
+
+/// Wraps a [`TextReader`] for each of the supported data types.
+pub enum TextReaderAny {
+    QuoteTick(TextReader<QuoteTick>),
+    TradeTick(TextReader<TradeTick>),
+    Bar(TextReader<Bar>),
+}
+
+impl TextReaderAny {
+    /// Creates a new [`TextReaderAny`] instance for the given `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the reader cannot be created (see [`TextReader::new`]).
+    pub fn new<P: AsRef<Path>>(
+        data_type: ParquetDataType,
+        path: P,
+        config: TextLoaderConfig,
+        chunk_size: usize,
+    ) -> anyhow::Result<Self> {
+        Ok(match data_type {
+            ParquetDataType::QuoteTick => {
+                Self::QuoteTick(TextReader::new(path, config, chunk_size)?)
+            }
+            ParquetDataType::TradeTick => {
+                Self::TradeTick(TextReader::new(path, config, chunk_size)?)
+            }
+            ParquetDataType::Bar => Self::Bar(TextReader::new(path, config, chunk_size)?),
+        })
+    }
+
+    /// Returns the data type being read.
+    #[must_use]
+    pub const fn data_type(&self) -> ParquetDataType {
+        match self {
+            Self::QuoteTick(_) => ParquetDataType::QuoteTick,
+            Self::TradeTick(_) => ParquetDataType::TradeTick,
+            Self::Bar(_) => ParquetDataType::Bar,
+        }
+    }
+
+    /// Returns the line numbers of the malformed rows skipped so far.
+    #[must_use]
+    pub fn skipped_lines(&self) -> &[usize] {
+        match self {
+            Self::QuoteTick(reader) => reader.skipped_lines(),
+            Self::TradeTick(reader) => reader.skipped_lines(),
+            Self::Bar(reader) => reader.skipped_lines(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_core::nanos::UnixNanos;
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::loader::TimestampFormat;
+
+    fn messy_quotes_config() -> TextLoaderConfig {
+        TextLoaderConfig::new(TextFormat::Csv)
+            .with_column("instrument_id", "symbol")
+            .with_column("bid_price", "bid")
+            .with_column("ask_price", "ask")
+            .with_column("ts_event", "timestamp")
+            .with_column("ts_init", "received")
+    }
+
+    fn write_temp(name: &str, contents: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nautilus_text_reader_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[rstest]
+    fn test_read_messy_csv_skipping_malformed_rows() {
+        let path = get_test_data_file_path("nautilus/messy_quotes.csv");
+        let config = messy_quotes_config().with_malformed_rows(MalformedRowPolicy::Skip);
+
+        let mut reader = TextReader::<QuoteTick>::new(path, config, 3).unwrap();
+        let chunks: Vec<Vec<QuoteTick>> = reader.by_ref().map(Result::unwrap).collect();
+
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(lens, vec![3, 2]);
+        assert_eq!(reader.precisions(), Some((5, 0)));
+        assert_eq!(reader.skipped_lines(), &[4, 6, 8, 9]);
+
+        let quotes = chunks.concat();
+        assert_eq!(quotes[0].instrument_id, InstrumentId::from("EUR/USD.SIM"));
+        assert_eq!(quotes[0].bid_price, Price::from("1.10000"));
+        assert_eq!(quotes[0].ask_price, Price::from("1.10005"));
+        assert_eq!(quotes[0].bid_size, Quantity::from(1_000_000));
+        assert_eq!(
+            quotes[0].ts_event,
+            UnixNanos::from(1_577_836_800_000_000_000)
+        );
+        assert_eq!(
+            quotes[0].ts_init,
+            UnixNanos::from(1_577_836_800_000_000_100)
+        );
+
+        // Epoch milliseconds, ISO 8601 without an offset and with `ts_init` defaulted
+        assert_eq!(
+            quotes[1].ts_event,
+            UnixNanos::from(1_577_836_801_000_000_000)
+        );
+        assert_eq!(
+            quotes[2].ts_event,
+            UnixNanos::from(1_577_836_802_500_000_000)
+        );
+        assert_eq!(quotes[2].ts_init, quotes[2].ts_event);
+        assert_eq!(quotes[3].instrument_id, InstrumentId::from("GBP/USD.SIM"));
+        assert_eq!(
+            quotes[4].ts_event,
+            UnixNanos::from(1_577_836_805_000_000_000)
+        );
+    }
+
+    #[rstest]
+    fn test_read_messy_csv_fails_on_malformed_row() {
+        let path = get_test_data_file_path("nautilus/messy_quotes.csv");
+
+        let reader = TextReader::<QuoteTick>::new(path, messy_quotes_config(), 100).unwrap();
+        let result: anyhow::Result<Vec<Vec<QuoteTick>>> = reader.collect();
+
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("line 4"), "{message}");
+        assert!(message.contains("messy_quotes.csv"), "{message}");
+        assert!(message.contains("ask_size"), "{message}");
+    }
+
+    #[rstest]
+    fn test_read_messy_jsonl_trades() {
+        let path = get_test_data_file_path("nautilus/messy_trades.jsonl");
+        let config = TextLoaderConfig::new(TextFormat::JsonLines)
+            .with_instrument_id(InstrumentId::from("ETHUSDT-PERP.BINANCE"))
+            .with_column("size", "qty")
+            .with_column("aggressor_side", "side")
+            .with_column("ts_event", "time")
+            .with_malformed_rows(MalformedRowPolicy::Skip);
+
+        let mut reader = TextReader::<TradeTick>::new(path, config, 100).unwrap();
+        let trades = reader.next_chunk().unwrap().unwrap();
+        assert!(reader.next_chunk().unwrap().is_none());
+
+        assert_eq!(reader.skipped_lines(), &[3, 6]);
+        assert_eq!(trades.len(), 3);
+        assert_eq!(trades[0].price, Price::from("2000.50"));
+        assert_eq!(trades[0].size, Quantity::from("1.250"));
+        assert_eq!(trades[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(trades[0].trade_id, TradeId::from("T1"));
+        assert_eq!(trades[1].aggressor_side, AggressorSide::Seller);
+        assert_eq!(
+            trades[1].ts_event,
+            UnixNanos::from(1_577_836_801_000_000_000)
+        );
+        assert_eq!(trades[2].aggressor_side, AggressorSide::NoAggressor);
+        assert_eq!(trades[2].trade_id, TradeId::from("5"));
+    }
+
+    #[rstest]
+    fn test_read_bars_with_explicit_precisions() {
+        let path = write_temp(
+            "bars.csv",
+            "time,o,h,l,c,v\n1577836800,1.1,1.2,1.0,1.15,100\n1577836860,1.15,1.3,1.1,1.2,250\n",
+        );
+        let bar_type = BarType::from("EUR/USD.SIM-1-MINUTE-BID-EXTERNAL");
+        let config = TextLoaderConfig::new(TextFormat::Csv)
+            .with_bar_type(bar_type)
+            .with_precisions(5, 1)
+            .with_timestamp_format(TimestampFormat::UnixMillis)
+            .with_column("ts_event", "time")
+            .with_column("open", "o")
+            .with_column("high", "h")
+            .with_column("low", "l")
+            .with_column("close", "c")
+            .with_column("volume", "v");
+
+        let bars: Vec<Bar> = TextReader::<Bar>::new(&path, config, 10)
+            .unwrap()
+            .flat_map(Result::unwrap)
+            .collect();
+
+        assert_eq!(bars.len(), 2);
+        assert_eq!(bars[0].bar_type, bar_type);
+        assert_eq!(bars[0].open, Price::from("1.10000"));
+        assert_eq!(bars[1].volume, Quantity::from("250.0"));
+        assert_eq!(bars[1].ts_event, UnixNanos::from(1_577_836_860_000_000));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_new_with_missing_csv_column() {
+        let path = write_temp("missing_column.csv", "symbol,bid,ask,timestamp\n");
+        let result = TextReader::<QuoteTick>::new(&path, messy_quotes_config(), 10);
+
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("bid_size"), "{message}");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_new_with_invalid_config() {
+        let path = get_test_data_file_path("nautilus/messy_quotes.csv");
+        let unknown_field = messy_quotes_config().with_column("bid", "bid");
+
+        assert!(TextReader::<QuoteTick>::new(&path, messy_quotes_config(), 0).is_err());
+        assert!(TextReader::<QuoteTick>::new(&path, unknown_field, 10).is_err());
+        assert!(TextReader::<Bar>::new(&path, TextLoaderConfig::default(), 10).is_err());
+    }
+}
@@ -0,0 +1,456 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Appending market data to an existing catalog partition, with deduplication.
+//!
+//! A partition is a single Parquet file holding the data for one instrument (or bar type) over
+//! one date. Appending merges the new data with the rows already in the partition, so
+//! re-ingesting an overlapping window does not create duplicate rows. The merged partition is
+//! written to a temporary file alongside the original and then renamed over it, so a crash
+//! part way through leaves the original partition intact.
+
+use std::{
+    collections::HashSet,
+    ffi::OsString,
+    fs::File,
+    hash::Hash,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::GetTsInit;
+
+use super::{
+    reader::ParquetReader,
+    writer::{ParquetWriter, ParquetWriterConfig},
+    ParquetData,
+};
+
+/// The file name suffix of the temporary file a partition is rewritten to.
+pub const TEMP_FILE_SUFFIX: &str = ".tmp";
+
+/// How new rows sharing a `ts_event` with existing rows are resolved when appending.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ConflictResolution {
+    /// Keep both existing and new rows, only dropping exact duplicates.
+    #[default]
+    KeepAll,
+    /// Replace all existing rows at a `ts_event` with the new rows at that `ts_event`.
+    KeepLatest,
+}
+
+/// Configuration for [`append_partition`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AppendConfig {
+    /// The configuration for writing the merged partition.
+    pub writer: ParquetWriterConfig,
+    /// How same-timestamp conflicts between existing and new rows are resolved.
+    pub conflicts: ConflictResolution,
+    /// If the partition should be left unchanged, only reporting what would be written.
+    pub dry_run: bool,
+}
+
+/// The outcome of appending to a partition.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AppendReport {
+    /// The number of rows in the partition before appending.
+    pub existing_rows: usize,
+    /// The number of rows given to append.
+    pub new_rows: usize,
+    /// The number of exact duplicate rows dropped (or which would be dropped on a dry run).
+    pub duplicates_dropped: usize,
+    /// The number of existing rows replaced by a newer row at the same `ts_event`.
+    pub conflicts_replaced: usize,
+    /// The number of rows in the partition after appending.
+    pub row_count: usize,
+}
+
+/// Appends `data` to the partition at `path`, creating it if it does not exist.
+///
+/// The existing and new rows are merged in ascending `ts_init` order (existing rows first for
+/// equal `ts_init`) and exact duplicates (equal in every field) are dropped. With
+/// [`ConflictResolution::KeepLatest`], existing rows at any `ts_event` present in `data` are
+/// also dropped in favour of the new rows.
+///
+/// Unless `config.dry_run` is set, the merged rows are written to a temporary file next to
+/// `path` which is synced to disk and then renamed over it, so a crash leaves either the old or
+/// the new partition. Any temporary file left by an earlier failed append is overwritten.
+///
+/// # Errors
+///
+/// This function returns an error:
+/// - If the existing partition cannot be read.
+/// - If the metadata of `data` does not match the existing partition.
+/// - If writing the temporary file or renaming it fails, in which case the partition is left
+///   unchanged.
+pub fn append_partition<T, P>(
+    path: P,
+    data: &[T],
+    config: AppendConfig,
+) -> anyhow::Result<AppendReport>
+where
+    T: ParquetData + Eq + Hash,
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let existing = read_partition::<T>(path, config.writer.row_group_size)?;
+
+    if let (Some(current), Some(first)) = (existing.first(), data.first()) {
+        let (current, metadata) = (current.data_metadata(), first.data_metadata());
+        if metadata != current {
+            anyhow::bail!(
+                "Data metadata {metadata:?} does not match partition '{}' metadata {current:?}",
+                path.display()
+            );
+        }
+    }
+
+    let (merged, report) = merge(existing, data, config.conflicts);
+    if config.dry_run || data.is_empty() {
+        return Ok(report);
+    }
+
+    let temp_path = temp_path(path);
+    if let Err(e) = write_partition(&temp_path, &merged, config.writer)
+        .and_then(|()| Ok(std::fs::rename(&temp_path, path)?))
+    {
+        let _ = std::fs::remove_file(&temp_path);
+        return Err(e);
+    }
+    sync_parent_dir(path)?;
+    Ok(report)
+}
+
+/// Returns the path of the temporary file the partition at `path` is rewritten to.
+#[must_use]
+pub fn temp_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(TEMP_FILE_SUFFIX);
+    PathBuf::from(name)
+}
+
+fn read_partition<T: ParquetData>(path: &Path, chunk_size: usize) -> anyhow::Result<Vec<T>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut rows = Vec::new();
+    for chunk in ParquetReader::<T>::new(path, chunk_size, None, None)
+        .map_err(|e| anyhow::anyhow!("Error reading '{}': {e}", path.display()))?
+    {
+        rows.extend(chunk?);
+    }
+    Ok(rows)
+}
+
+fn write_partition<T: ParquetData>(
+    path: &Path,
+    data: &[T],
+    config: ParquetWriterConfig,
+) -> anyhow::Result<()> {
+    let mut writer = ParquetWriter::new(path, config)?;
+    for chunk in data.chunks(config.row_group_size) {
+        writer.write_batch(chunk)?;
+    }
+    writer.close()?;
+
+    // Ensure the contents are durable before the file is renamed over the partition
+    File::open(path)?.sync_all()?;
+    Ok(())
+}
+
+/// Syncs the directory containing `path`, so a rename into it is durable.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> anyhow::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+/// Directories cannot be opened for syncing on this platform, so renames are not synced.
+#[cfg(not(unix))]
+fn sync_parent_dir(_path: &Path) -> anyhow::Result<()> {
+    Ok(())
+}
+
+fn merge<T>(existing: Vec<T>, data: &[T], conflicts: ConflictResolution) -> (Vec<T>, AppendReport)
+where
+    T: ParquetData + Eq + Hash,
+{
+    let mut report = AppendReport {
+        existing_rows: existing.len(),
+        new_rows: data.len(),
+        ..Default::default()
+    };
+
+    let mut merged = existing;
+    if conflicts == ConflictResolution::KeepLatest {
+        let new_ts_events: HashSet<UnixNanos> = data.iter().map(ParquetData::ts_event).collect();
+        let new_rows: HashSet<&T> = data.iter().collect();
+        merged.retain(|row| {
+            if !new_ts_events.contains(&row.ts_event()) {
+                return true;
+            }
+            if new_rows.contains(row) {
+                report.duplicates_dropped += 1;
+            } else {
+                report.conflicts_replaced += 1;
+            }
+            false
+        });
+    }
+
+    // Stable sort so existing rows stay ahead of new rows with an equal `ts_init`
+    merged.extend_from_slice(data);
+    merged.sort_by_key(GetTsInit::ts_init);
+
+    // Duplicates always share a `ts_init`, so only rows seen at the current one are tracked
+    let mut seen: HashSet<T> = HashSet::new();
+    let mut current_ts_init = None;
+    merged.retain(|row| {
+        if current_ts_init != Some(row.ts_init()) {
+            current_ts_init = Some(row.ts_init());
+            seen.clear();
+        }
+        if seen.insert(row.clone()) {
+            true
+        } else {
+            report.duplicates_dropped += 1;
+            false
+        }
+    });
+
+    report.row_count = merged.len();
+    (merged, report)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        data::quote::QuoteTick,
+        identifiers::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_serialization::arrow::DecodeFromRecordBatch;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("nautilus_parquet_append_{}", std::process::id()))
+            .join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn read_all(path: &Path) -> Vec<QuoteTick> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+        let metadata = builder.schema().metadata().clone();
+        builder
+            .build()
+            .unwrap()
+            .flat_map(|batch| QuoteTick::decode_batch(&metadata, batch.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn quote(ts: u64, bid: f64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from("EUR/USD.SIM"),
+            bid_price: Price::new(bid, 5),
+            ask_price: Price::new(bid + 0.000_02, 5),
+            bid_size: Quantity::new(100_000.0, 0),
+            ask_size: Quantity::new(75_000.0, 0),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+        }
+    }
+
+    fn quotes(range: std::ops::Range<u64>) -> Vec<QuoteTick> {
+        range.map(|i| quote(i, 1.1 + i as f64 * 0.000_01)).collect()
+    }
+
+    #[rstest]
+    fn test_append_creates_partition() {
+        let path = temp_dir("create").join("2024-01-01.parquet");
+        let data = quotes(0..10);
+
+        let report = append_partition(&path, &data, AppendConfig::default()).unwrap();
+
+        assert_eq!(report.existing_rows, 0);
+        assert_eq!(report.row_count, 10);
+        assert_eq!(read_all(&path), data);
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[rstest]
+    fn test_append_overlap_at_partition_end() {
+        let path = temp_dir("overlap_end").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..10), AppendConfig::default()).unwrap();
+
+        // Re-downloaded window shares the last five rows (including the boundary row)
+        let report = append_partition(&path, &quotes(5..15), AppendConfig::default()).unwrap();
+
+        assert_eq!(report.existing_rows, 10);
+        assert_eq!(report.new_rows, 10);
+        assert_eq!(report.duplicates_dropped, 5);
+        assert_eq!(report.row_count, 15);
+        assert_eq!(read_all(&path), quotes(0..15));
+    }
+
+    #[rstest]
+    fn test_append_overlap_at_partition_start() {
+        let path = temp_dir("overlap_start").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(5..10), AppendConfig::default()).unwrap();
+
+        let report = append_partition(&path, &quotes(0..6), AppendConfig::default()).unwrap();
+
+        assert_eq!(report.duplicates_dropped, 1);
+        assert_eq!(read_all(&path), quotes(0..10));
+    }
+
+    #[rstest]
+    fn test_append_keep_all_retains_same_timestamp_rows() {
+        let path = temp_dir("keep_all").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..3), AppendConfig::default()).unwrap();
+
+        let revised = quote(2, 1.2);
+        let report = append_partition(&path, &[revised], AppendConfig::default()).unwrap();
+
+        assert_eq!(report.duplicates_dropped, 0);
+        assert_eq!(report.conflicts_replaced, 0);
+        let mut expected = quotes(0..3);
+        expected.push(revised);
+        assert_eq!(read_all(&path), expected);
+    }
+
+    #[rstest]
+    fn test_append_keep_latest_replaces_same_timestamp_rows() {
+        let path = temp_dir("keep_latest").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..3), AppendConfig::default()).unwrap();
+
+        let config = AppendConfig {
+            conflicts: ConflictResolution::KeepLatest,
+            ..Default::default()
+        };
+        let data = vec![quote(1, 1.1 + 0.000_01), quote(2, 1.2), quote(3, 1.3)];
+        let report = append_partition(&path, &data, config).unwrap();
+
+        assert_eq!(report.duplicates_dropped, 1);
+        assert_eq!(report.conflicts_replaced, 1);
+        assert_eq!(report.row_count, 4);
+        assert_eq!(
+            read_all(&path),
+            vec![quote(0, 1.1), data[0], data[1], data[2]]
+        );
+    }
+
+    #[rstest]
+    fn test_append_drops_duplicates_within_new_data() {
+        let path = temp_dir("within").join("2024-01-01.parquet");
+        let mut data = quotes(0..3);
+        data.push(data[1]);
+
+        let report = append_partition(&path, &data, AppendConfig::default()).unwrap();
+
+        assert_eq!(report.duplicates_dropped, 1);
+        assert_eq!(read_all(&path), quotes(0..3));
+    }
+
+    #[rstest]
+    fn test_append_dry_run_leaves_partition_unchanged() {
+        let path = temp_dir("dry_run").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..10), AppendConfig::default()).unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        let config = AppendConfig {
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = append_partition(&path, &quotes(8..12), config).unwrap();
+
+        assert_eq!(report.duplicates_dropped, 2);
+        assert_eq!(report.row_count, 12);
+        assert_eq!(read_all(&path), quotes(0..10));
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified
+        );
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[rstest]
+    fn test_append_dry_run_without_partition_writes_no_file() {
+        let path = temp_dir("dry_run_new").join("2024-01-01.parquet");
+        let config = AppendConfig {
+            dry_run: true,
+            ..Default::default()
+        };
+
+        let report = append_partition(&path, &quotes(0..5), config).unwrap();
+
+        assert_eq!(report.row_count, 5);
+        assert!(!path.exists());
+    }
+
+    #[rstest]
+    fn test_append_overwrites_stale_temp_file() {
+        // Simulates a crash after writing the temporary file but before the rename
+        let path = temp_dir("stale_temp").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..5), AppendConfig::default()).unwrap();
+        std::fs::write(temp_path(&path), b"partial").unwrap();
+
+        assert_eq!(read_all(&path), quotes(0..5));
+
+        append_partition(&path, &quotes(5..8), AppendConfig::default()).unwrap();
+
+        assert_eq!(read_all(&path), quotes(0..8));
+        assert!(!temp_path(&path).exists());
+    }
+
+    #[rstest]
+    fn test_append_failed_write_leaves_partition_intact() {
+        let path = temp_dir("failed_rename").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..5), AppendConfig::default()).unwrap();
+
+        // A directory at the temporary path makes writing the temporary file fail
+        std::fs::create_dir_all(temp_path(&path)).unwrap();
+        assert!(append_partition(&path, &quotes(5..8), AppendConfig::default()).is_err());
+        assert_eq!(read_all(&path), quotes(0..5));
+
+        std::fs::remove_dir(temp_path(&path)).unwrap();
+        append_partition(&path, &quotes(5..8), AppendConfig::default()).unwrap();
+        assert_eq!(read_all(&path), quotes(0..8));
+    }
+
+    #[rstest]
+    fn test_append_with_mismatched_metadata() {
+        let path = temp_dir("mismatched").join("2024-01-01.parquet");
+        append_partition(&path, &quotes(0..5), AppendConfig::default()).unwrap();
+
+        let mut data = quotes(5..6);
+        data[0].instrument_id = InstrumentId::from("GBP/USD.SIM");
+
+        assert!(append_partition(&path, &data, AppendConfig::default()).is_err());
+        assert_eq!(read_all(&path), quotes(0..5));
+    }
+}
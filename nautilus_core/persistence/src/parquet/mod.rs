@@ -0,0 +1,96 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Native Parquet persistence for market data, without a round trip through Python.
+
+pub mod append;
+pub mod query;
+pub mod reader;
+pub mod writer;
+
+use std::collections::HashMap;
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick, GetTsInit};
+use nautilus_serialization::arrow::{DecodeFromRecordBatch, EncodeToRecordBatch};
+
+/// The market data types which can be persisted to Parquet.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParquetDataType {
+    QuoteTick = 1,
+    TradeTick = 2,
+    Bar = 3,
+}
+
+/// The compression codec applied to each column chunk of a Parquet file.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ParquetCompression {
+    Uncompressed = 0,
+    Snappy = 1,
+    #[default]
+    Zstd = 2,
+}
+
+/// Market data which can be written to (and read back from) a Parquet file.
+pub trait ParquetData:
+    EncodeToRecordBatch + DecodeFromRecordBatch + GetTsInit + Clone + Send + 'static
+{
+    /// Returns the Arrow schema metadata for the data (instrument ID and precisions), which
+    /// is embedded in the file metadata so `Price` and `Quantity` values can be reconstructed.
+    fn data_metadata(&self) -> HashMap<String, String>;
+
+    /// Returns the UNIX timestamp (nanoseconds) when the data event occurred.
+    fn ts_event(&self) -> UnixNanos;
+}
+
+impl ParquetData for QuoteTick {
+    fn data_metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.bid_price.precision,
+            self.bid_size.precision,
+        )
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+}
+
+impl ParquetData for TradeTick {
+    fn data_metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(
+            &self.instrument_id,
+            self.price.precision,
+            self.size.precision,
+        )
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+}
+
+impl ParquetData for Bar {
+    fn data_metadata(&self) -> HashMap<String, String> {
+        Self::get_metadata(&self.bar_type, self.open.precision, self.volume.precision)
+    }
+
+    fn ts_event(&self) -> UnixNanos {
+        self.ts_event
+    }
+}
@@ -0,0 +1,373 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Querying market data across multiple catalog files as a single chronological stream.
+
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::{Data, GetTsInit};
+
+use super::{reader::ParquetReaderAny, ParquetDataType};
+
+/// Reads market data from multiple Parquet files (possibly of different data types) filtered
+/// to an inclusive `ts_init` range, merged into a single stream of [`Data`] chunks in ascending
+/// `ts_init` order.
+///
+/// Each file must itself be in ascending `ts_init` order, in which case the merged output is
+/// globally ordered. Items with equal `ts_init` are yielded in the order the files were given.
+pub struct CatalogQuery {
+    sources: Vec<QuerySource>,
+    heads: Vec<Option<Data>>,
+    heap: BinaryHeap<Reverse<(UnixNanos, usize)>>,
+    chunk_size: usize,
+}
+
+impl CatalogQuery {
+    /// Creates a new [`CatalogQuery`] instance over the given `(data_type, path)` files.
+    ///
+    /// The `start` and `end` bounds are inclusive, with `None` leaving that side unbounded.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `chunk_size` is zero.
+    /// - If a reader cannot be created for any file (see [`ParquetReaderAny::new`]).
+    /// - If the first items of any file are out of order (see [`CatalogQuery::next_chunk`]).
+    pub fn new<P: AsRef<Path>>(
+        files: impl IntoIterator<Item = (ParquetDataType, P)>,
+        chunk_size: usize,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Self> {
+        if chunk_size == 0 {
+            anyhow::bail!("Invalid `chunk_size`, was zero");
+        }
+
+        let mut sources = Vec::new();
+        for (data_type, path) in files {
+            let path = path.as_ref();
+            let reader = ParquetReaderAny::new(data_type, path, chunk_size, start, end)
+                .map_err(|e| anyhow::anyhow!("Error opening '{}': {e}", path.display()))?;
+            sources.push(QuerySource::new(path.to_path_buf(), reader));
+        }
+
+        let mut query = Self {
+            heads: vec![None; sources.len()],
+            heap: BinaryHeap::with_capacity(sources.len()),
+            sources,
+            chunk_size,
+        };
+        for index in 0..query.sources.len() {
+            query.advance(index)?;
+        }
+        Ok(query)
+    }
+
+    /// Returns the next chunk of up to `chunk_size` items in ascending `ts_init` order, or
+    /// `None` once every file is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If reading or decoding any file fails.
+    /// - If a file is not in ascending `ts_init` order, naming the file and the offending row
+    ///   (counted from zero over the rows within the queried range).
+    ///
+    /// No further data is returned after an error.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Data>>> {
+        let mut chunk = Vec::with_capacity(self.chunk_size);
+        while chunk.len() < self.chunk_size {
+            let Some(Reverse((_, index))) = self.heap.pop() else {
+                break;
+            };
+            // SAFETY: Unwrap safe as a source is only on the heap while it has a head item
+            chunk.push(self.heads[index].take().unwrap());
+            if let Err(e) = self.advance(index) {
+                self.heap.clear();
+                return Err(e);
+            }
+        }
+
+        Ok((!chunk.is_empty()).then_some(chunk))
+    }
+
+    /// Reads the next item of the source at `index` onto the heap.
+    fn advance(&mut self, index: usize) -> anyhow::Result<()> {
+        if let Some(data) = self.sources[index].next()? {
+            self.heap.push(Reverse((data.ts_init(), index)));
+            self.heads[index] = Some(data);
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for CatalogQuery {
+    type Item = anyhow::Result<Vec<Data>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+/// A single file of the query, which checks its items are in ascending `ts_init` order.
+struct QuerySource {
+    path: PathBuf,
+    reader: ParquetReaderAny,
+    chunk: std::vec::IntoIter<Data>,
+    row: usize,
+    last_ts_init: Option<UnixNanos>,
+}
+
+impl QuerySource {
+    fn new(path: PathBuf, reader: ParquetReaderAny) -> Self {
+        Self {
+            path,
+            reader,
+            chunk: Vec::new().into_iter(),
+            row: 0,
+            last_ts_init: None,
+        }
+    }
+
+    fn next(&mut self) -> anyhow::Result<Option<Data>> {
+        loop {
+            if let Some(data) = self.chunk.next() {
+                let ts_init = data.ts_init();
+                if let Some(last_ts_init) = self.last_ts_init {
+                    if ts_init < last_ts_init {
+                        anyhow::bail!(
+                            "Out of order data in '{}' at row {}: `ts_init` {ts_init} is before the previous row's {last_ts_init}",
+                            self.path.display(),
+                            self.row,
+                        );
+                    }
+                }
+                self.last_ts_init = Some(ts_init);
+                self.row += 1;
+                return Ok(Some(data));
+            }
+
+            match self
+                .reader
+                .next_chunk()
+                .map_err(|e| anyhow::anyhow!("Error reading '{}': {e}", self.path.display()))?
+            {
+                Some(chunk) => self.chunk = chunk.into_iter(),
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use nautilus_model::{
+        data::{
+            bar::{Bar, BarType},
+            quote::QuoteTick,
+            trade::TradeTick,
+        },
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_serialization::arrow::EncodeToRecordBatch;
+    use parquet::arrow::ArrowWriter;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parquet::{
+        writer::{ParquetWriter, ParquetWriterConfig},
+        ParquetData,
+    };
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("nautilus_parquet_query_{}", std::process::id()))
+            .join(name)
+    }
+
+    fn write<T: ParquetData>(name: &str, data: &[T]) -> PathBuf {
+        let path = temp_path(name);
+        let config = ParquetWriterConfig {
+            row_group_size: 4,
+            ..Default::default()
+        };
+        let mut writer = ParquetWriter::new(&path, config).unwrap();
+        writer.write_batch(data).unwrap();
+        writer.close().unwrap();
+        path
+    }
+
+    fn quote(ts: u64) -> QuoteTick {
+        QuoteTick {
+            instrument_id: InstrumentId::from("EUR/USD.SIM"),
+            bid_price: Price::new(1.1, 5),
+            ask_price: Price::new(1.100_02, 5),
+            bid_size: Quantity::new(100_000.0, 0),
+            ask_size: Quantity::new(75_000.0, 0),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+        }
+    }
+
+    fn trade(ts: u64) -> TradeTick {
+        TradeTick {
+            instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+            price: Price::new(10_000.0, 4),
+            size: Quantity::new(1.5, 8),
+            aggressor_side: AggressorSide::Buyer,
+            trade_id: TradeId::new(&ts.to_string()),
+            ts_event: ts.into(),
+            ts_init: ts.into(),
+        }
+    }
+
+    fn bar(ts: u64) -> Bar {
+        Bar::new(
+            BarType::from("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL"),
+            Price::from("1.00001"),
+            Price::from("1.00004"),
+            Price::from("1.00000"),
+            Price::from("1.00002"),
+            Quantity::from(100_000),
+            ts.into(),
+            ts.into(),
+        )
+    }
+
+    /// Writes three files with interleaved timestamps, where quotes and bars share `ts_init`
+    /// values which are multiples of six.
+    fn write_interleaved(prefix: &str) -> Vec<(ParquetDataType, PathBuf)> {
+        let quotes: Vec<QuoteTick> = (0..10).map(|i| quote(i * 3)).collect();
+        let trades: Vec<TradeTick> = (0..10).map(|i| trade(i * 3 + 1)).collect();
+        let bars: Vec<Bar> = (0..5).map(|i| bar(i * 6)).collect();
+        vec![
+            (
+                ParquetDataType::QuoteTick,
+                write(&format!("{prefix}_quotes.parquet"), &quotes),
+            ),
+            (
+                ParquetDataType::TradeTick,
+                write(&format!("{prefix}_trades.parquet"), &trades),
+            ),
+            (
+                ParquetDataType::Bar,
+                write(&format!("{prefix}_bars.parquet"), &bars),
+            ),
+        ]
+    }
+
+    fn remove_files(files: &[(ParquetDataType, PathBuf)]) {
+        for (_, path) in files {
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[rstest]
+    fn test_query_merges_files_chronologically() {
+        let files = write_interleaved("merge");
+
+        let query = CatalogQuery::new(files.clone(), 7, None, None).unwrap();
+        let chunks: Vec<Vec<Data>> = query.map(Result::unwrap).collect();
+
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(lens, vec![7, 7, 7, 4]);
+
+        let data = chunks.concat();
+        let ts_inits: Vec<u64> = data.iter().map(|d| d.ts_init().as_u64()).collect();
+        let mut expected: Vec<u64> = (0..10)
+            .flat_map(|i| [i * 3, i * 3 + 1])
+            .chain((0..5).map(|i| i * 6))
+            .collect();
+        expected.sort_unstable();
+        assert_eq!(ts_inits, expected);
+
+        // Ties are broken by file order, so each quote precedes the bar at the same time
+        assert!(matches!(data[0], Data::Quote(_)));
+        assert!(matches!(data[1], Data::Bar(_)));
+        assert!(matches!(data[2], Data::Trade(_)));
+        assert_eq!(data.iter().filter(|d| matches!(d, Data::Bar(_))).count(), 5);
+
+        remove_files(&files);
+    }
+
+    #[rstest]
+    fn test_query_with_time_range() {
+        let files = write_interleaved("range");
+
+        let query =
+            CatalogQuery::new(files.clone(), 100, Some(10.into()), Some(19.into())).unwrap();
+        let data: Vec<Data> = query.flat_map(Result::unwrap).collect();
+
+        let ts_inits: Vec<u64> = data.iter().map(|d| d.ts_init().as_u64()).collect();
+        assert_eq!(ts_inits, vec![10, 12, 12, 13, 15, 16, 18, 18, 19]);
+
+        remove_files(&files);
+    }
+
+    #[rstest]
+    fn test_query_out_of_order_file() {
+        let files = write_interleaved("out_of_order");
+
+        // Written directly, as the `ParquetWriter` rejects out of order data
+        let path = temp_path("out_of_order_unsorted.parquet");
+        let data = vec![quote(1), quote(5), quote(2)];
+        let batch = QuoteTick::encode_batch(&data[0].data_metadata(), &data).unwrap();
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let mut all_files = files.clone();
+        all_files.push((ParquetDataType::QuoteTick, path.clone()));
+
+        let result: anyhow::Result<Vec<Vec<Data>>> = CatalogQuery::new(all_files, 100, None, None)
+            .unwrap()
+            .collect();
+        let message = result.unwrap_err().to_string();
+        assert!(
+            message.contains("out_of_order_unsorted.parquet"),
+            "{message}"
+        );
+        assert!(message.contains("at row 2"), "{message}");
+
+        remove_files(&files);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_query_without_files() {
+        let files: Vec<(ParquetDataType, PathBuf)> = Vec::new();
+        let mut query = CatalogQuery::new(files, 10, None, None).unwrap();
+        assert!(query.next_chunk().unwrap().is_none());
+    }
+
+    #[rstest]
+    fn test_new_with_zero_chunk_size() {
+        let files: Vec<(ParquetDataType, PathBuf)> = Vec::new();
+        assert!(CatalogQuery::new(files, 0, None, None).is_err());
+    }
+}
@@ -0,0 +1,498 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Chunked reading of market data from Parquet files, filtered by a `ts_init` time range.
+//!
+//! Files written by both the Rust [`ParquetWriter`](super::writer::ParquetWriter) and the
+//! legacy pyarrow catalog writer are supported. The schemas differ as follows:
+//!
+//! - Legacy quote files name the price columns `bid` and `ask` rather than `bid_price` and
+//!   `ask_price`. Columns are decoded by position, so only the `ts_init` column is looked up
+//!   by name (which is the same for both writers).
+//! - Legacy files only carry the instrument metadata (instrument ID, bar type and precisions)
+//!   within the embedded Arrow schema, whereas the Rust writer also stores it as plain file
+//!   key-values. The metadata is always taken from the Arrow schema.
+//! - Both writers store the unsigned `ts_init` column with a physical `INT64` type, so row
+//!   group statistics are reinterpreted as `u64` when pruning.
+//!
+//! Enum columns with an unknown discriminant (e.g. written by a newer version) are an error by
+//! default, see [`ParquetReader::with_unknown_enum_policy`].
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use arrow::array::{BooleanArray, UInt64Array};
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::{bar::Bar, quote::QuoteTick, trade::TradeTick, Data};
+use nautilus_serialization::arrow::UnknownEnumPolicy;
+use parquet::{
+    arrow::{
+        arrow_reader::{
+            ArrowPredicateFn, ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder, RowFilter,
+        },
+        ProjectionMask,
+    },
+    file::{metadata::ParquetMetaData, statistics::Statistics},
+};
+
+use super::{ParquetData, ParquetDataType};
+
+const TS_INIT_COLUMN: &str = "ts_init";
+
+/// Reads market data from a Parquet file in fixed-size chunks, optionally filtered to an
+/// inclusive `ts_init` range.
+///
+/// Row groups with `ts_init` statistics entirely outside the range are skipped without being
+/// read, and the range is applied to the remaining rows as a predicate on the `ts_init` column
+/// before any other columns are decoded.
+pub struct ParquetReader<T: ParquetData> {
+    reader: ParquetRecordBatchReader,
+    path: PathBuf,
+    metadata: HashMap<String, String>,
+    chunk_size: usize,
+    row_group_count: usize,
+    enum_policy: UnknownEnumPolicy,
+    rows_read: usize,
+    buffer: Vec<T>,
+}
+
+impl<T: ParquetData> ParquetReader<T> {
+    /// Creates a new [`ParquetReader`] instance for the file at `path`.
+    ///
+    /// The `start` and `end` bounds are inclusive, with `None` leaving that side unbounded.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `chunk_size` is zero.
+    /// - If the file cannot be opened or is not a valid Parquet file.
+    /// - If the file has no `ts_init` column.
+    pub fn new<P: AsRef<Path>>(
+        path: P,
+        chunk_size: usize,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Self> {
+        if chunk_size == 0 {
+            anyhow::bail!("Invalid `chunk_size`, was zero");
+        }
+
+        let path = path.as_ref().to_path_buf();
+        let file = File::open(&path)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file)?;
+        let metadata = builder.schema().metadata().clone();
+
+        let ts_init_index = builder
+            .parquet_schema()
+            .columns()
+            .iter()
+            .position(|column| column.name() == TS_INIT_COLUMN)
+            .ok_or_else(|| anyhow::anyhow!("Missing `{TS_INIT_COLUMN}` column"))?;
+
+        let start = start.map_or(u64::MIN, |start| start.as_u64());
+        let end = end.map_or(u64::MAX, |end| end.as_u64());
+        let row_groups = prune_row_groups(builder.metadata(), ts_init_index, start, end);
+        let row_group_count = row_groups.len();
+
+        let projection = ProjectionMask::leaves(builder.parquet_schema(), [ts_init_index]);
+        let predicate = ArrowPredicateFn::new(projection, move |batch| {
+            let ts_init = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<UInt64Array>()
+                .ok_or_else(|| {
+                    arrow::error::ArrowError::CastError(format!(
+                        "Invalid `{TS_INIT_COLUMN}` column type, expected UInt64"
+                    ))
+                })?;
+            Ok(ts_init
+                .iter()
+                .map(|ts| ts.map(|ts| start <= ts && ts <= end))
+                .collect::<BooleanArray>())
+        });
+
+        let reader = builder
+            .with_batch_size(chunk_size)
+            .with_row_groups(row_groups)
+            .with_row_filter(RowFilter::new(vec![Box::new(predicate)]))
+            .build()?;
+
+        Ok(Self {
+            reader,
+            path,
+            metadata,
+            chunk_size,
+            row_group_count,
+            enum_policy: UnknownEnumPolicy::default(),
+            rows_read: 0,
+            buffer: Vec::with_capacity(chunk_size),
+        })
+    }
+
+    /// Sets how enum discriminants with no known variant are decoded (an error by default).
+    ///
+    /// With [`UnknownEnumPolicy::Lenient`], unknown values of enums with a safe fallback (such
+    /// as `AggressorSide`) are decoded as that fallback, while all others remain an error.
+    #[must_use]
+    pub fn with_unknown_enum_policy(mut self, enum_policy: UnknownEnumPolicy) -> Self {
+        self.enum_policy = enum_policy;
+        self
+    }
+
+    /// Returns the instrument metadata embedded in the file.
+    #[must_use]
+    pub const fn metadata(&self) -> &HashMap<String, String> {
+        &self.metadata
+    }
+
+    /// Returns the number of row groups which will be read after pruning by statistics.
+    #[must_use]
+    pub const fn row_group_count(&self) -> usize {
+        self.row_group_count
+    }
+
+    /// Returns the next chunk of up to `chunk_size` items, or `None` once the file is exhausted.
+    ///
+    /// Every chunk other than the last contains exactly `chunk_size` items.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If reading or decoding a record batch fails, with the file and the row the batch starts
+    ///   at (counting only rows within the `ts_init` range).
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<T>>> {
+        while self.buffer.len() < self.chunk_size {
+            match self.reader.next() {
+                Some(batch) => {
+                    let batch = batch?;
+                    let num_rows = batch.num_rows();
+                    let data = T::decode_batch_with_policy(&self.metadata, batch, self.enum_policy)
+                        .with_context(|| {
+                            format!(
+                                "Error decoding {:?} in batch starting at row {}",
+                                self.path, self.rows_read
+                            )
+                        })?;
+                    self.rows_read += num_rows;
+                    self.buffer.extend(data);
+                }
+                None => break,
+            }
+        }
+
+        if self.buffer.is_empty() {
+            return Ok(None);
+        }
+
+        let rest = self
+            .buffer
+            .split_off(self.chunk_size.min(self.buffer.len()));
+        Ok(Some(std::mem::replace(&mut self.buffer, rest)))
+    }
+}
+
+impl<T: ParquetData> Iterator for ParquetReader<T> {
+    type Item = anyhow::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
+
+fn prune_row_groups(
+    metadata: &ParquetMetaData,
+    ts_init_index: usize,
+    start: u64,
+    end: u64,
+) -> Vec<usize> {
+    metadata
+        .row_groups()
+        .iter()
+        .enumerate()
+        .filter(
+            |(_, row_group)| match row_group.column(ts_init_index).statistics() {
+                // Unsigned values are stored with a physical `INT64` type
+                Some(Statistics::Int64(stats)) => {
+                    let min = stats.min_opt().map_or(u64::MIN, |min| *min as u64);
+                    let max = stats.max_opt().map_or(u64::MAX, |max| *max as u64);
+                    min <= end && start <= max
+                }
+                _ => true, // Cannot prune without statistics
+            },
+        )
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Wraps a [`ParquetReader`] for each of the supported data types.
+pub enum ParquetReaderAny {
+    QuoteTick(ParquetReader<QuoteTick>),
+    TradeTick(ParquetReader<TradeTick>),
+    Bar(ParquetReader<Bar>),
+}
+
+impl ParquetReaderAny {
+    /// Creates a new [`ParquetReaderAny`] instance for the given `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the reader cannot be created (see [`ParquetReader::new`]).
+    pub fn new<P: AsRef<Path>>(
+        data_type: ParquetDataType,
+        path: P,
+        chunk_size: usize,
+        start: Option<UnixNanos>,
+        end: Option<UnixNanos>,
+    ) -> anyhow::Result<Self> {
+        Ok(match data_type {
+            ParquetDataType::QuoteTick => {
+                Self::QuoteTick(ParquetReader::new(path, chunk_size, start, end)?)
+            }
+            ParquetDataType::TradeTick => {
+                Self::TradeTick(ParquetReader::new(path, chunk_size, start, end)?)
+            }
+            ParquetDataType::Bar => Self::Bar(ParquetReader::new(path, chunk_size, start, end)?),
+        })
+    }
+
+    /// Returns the data type being read.
+    #[must_use]
+    pub const fn data_type(&self) -> ParquetDataType {
+        match self {
+            Self::QuoteTick(_) => ParquetDataType::QuoteTick,
+            Self::TradeTick(_) => ParquetDataType::TradeTick,
+            Self::Bar(_) => ParquetDataType::Bar,
+        }
+    }
+
+    /// Returns the next chunk of items as [`Data`], or `None` once the file is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If reading or decoding a record batch fails.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Data>>> {
+        Ok(match self {
+            Self::QuoteTick(reader) => reader.next_chunk()?.map(into_data),
+            Self::TradeTick(reader) => reader.next_chunk()?.map(into_data),
+            Self::Bar(reader) => reader.next_chunk()?.map(into_data),
+        })
+    }
+}
+
+fn into_data<T: Into<Data>>(chunk: Vec<T>) -> Vec<Data> {
+    chunk.into_iter().map(Into::into).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::{
+        array::{Int64Array, StringArray, UInt8Array},
+        record_batch::RecordBatch,
+    };
+    use nautilus_model::{
+        enums::AggressorSide,
+        identifiers::InstrumentId,
+        types::{price::Price, quantity::Quantity},
+    };
+    use nautilus_serialization::arrow::ArrowSchemaProvider;
+    use nautilus_test_kit::common::get_test_data_file_path;
+    use parquet::arrow::ArrowWriter;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parquet::writer::{ParquetWriter, ParquetWriterConfig};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("nautilus_parquet_reader_{}", std::process::id()))
+            .join(name)
+    }
+
+    fn write_quotes(name: &str, count: u64, row_group_size: usize) -> PathBuf {
+        let path = temp_path(name);
+        let data: Vec<QuoteTick> = (0..count)
+            .map(|i| QuoteTick {
+                instrument_id: InstrumentId::from("EUR/USD.SIM"),
+                bid_price: Price::new(1.1, 5),
+                ask_price: Price::new(1.100_02, 5),
+                bid_size: Quantity::new(100_000.0, 0),
+                ask_size: Quantity::new(75_000.0, 0),
+                ts_event: i.into(),
+                ts_init: i.into(),
+            })
+            .collect();
+
+        let config = ParquetWriterConfig {
+            row_group_size,
+            ..Default::default()
+        };
+        let mut writer = ParquetWriter::new(&path, config).unwrap();
+        writer.write_batch(&data).unwrap();
+        writer.close().unwrap();
+        path
+    }
+
+    /// Writes trades with the raw `aggressor_sides`, which may be unknown discriminants.
+    fn write_trades_with_aggressor_sides(name: &str, aggressor_sides: Vec<u8>) -> PathBuf {
+        let path = temp_path(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        let len = aggressor_sides.len();
+        let metadata = TradeTick::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let batch = RecordBatch::try_new(
+            TradeTick::get_schema(Some(metadata)).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![1_000_000_000_000; len])),
+                Arc::new(UInt64Array::from(vec![1_000_000_000_000; len])),
+                Arc::new(UInt8Array::from(aggressor_sides)),
+                Arc::new(StringArray::from(vec!["1"; len])),
+                Arc::new(UInt64Array::from_iter_values(0..len as u64)),
+                Arc::new(UInt64Array::from_iter_values(0..len as u64)),
+            ],
+        )
+        .unwrap();
+
+        let mut writer =
+            ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        path
+    }
+
+    fn ts_inits(chunk: &[QuoteTick]) -> Vec<u64> {
+        chunk.iter().map(|quote| quote.ts_init.as_u64()).collect()
+    }
+
+    #[rstest]
+    fn test_read_filter_mid_row_group() {
+        let path = write_quotes("mid_row_group.parquet", 100, 20);
+
+        let mut reader =
+            ParquetReader::<QuoteTick>::new(&path, 8, Some(25.into()), Some(54.into())).unwrap();
+        assert_eq!(reader.row_group_count(), 2);
+
+        let chunks: Vec<Vec<QuoteTick>> = reader.by_ref().map(Result::unwrap).collect();
+        let lens: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(lens, vec![8, 8, 8, 6]);
+        assert_eq!(ts_inits(&chunks.concat()), (25..=54).collect::<Vec<_>>());
+        assert_eq!(chunks[0][0].bid_price, Price::from("1.10000"));
+        assert!(reader.next_chunk().unwrap().is_none());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_read_without_filter() {
+        let path = write_quotes("unfiltered.parquet", 50, 20);
+
+        let reader = ParquetReader::<QuoteTick>::new(&path, 1_000, None, None).unwrap();
+        assert_eq!(reader.row_group_count(), 3);
+
+        let chunks: Vec<Vec<QuoteTick>> = reader.map(Result::unwrap).collect();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(ts_inits(&chunks[0]), (0..50).collect::<Vec<_>>());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_read_empty_range() {
+        let path = write_quotes("empty_range.parquet", 100, 20);
+
+        let mut reader =
+            ParquetReader::<QuoteTick>::new(&path, 8, Some(200.into()), Some(300.into())).unwrap();
+        assert_eq!(reader.row_group_count(), 0);
+        assert!(reader.next_chunk().unwrap().is_none());
+
+        // A range between two rows within a single row group
+        let path_gap = write_quotes("empty_gap.parquet", 10, 20);
+        let mut reader =
+            ParquetReader::<QuoteTick>::new(&path_gap, 8, Some(100.into()), Some(5.into()))
+                .unwrap();
+        assert!(reader.next_chunk().unwrap().is_none());
+
+        std::fs::remove_file(path).unwrap();
+        std::fs::remove_file(path_gap).unwrap();
+    }
+
+    #[rstest]
+    fn test_read_legacy_pyarrow_quotes() {
+        let path = get_test_data_file_path("nautilus/quotes.parquet");
+
+        let reader = ParquetReader::<QuoteTick>::new(&path, 1_000, None, None).unwrap();
+        assert_eq!(reader.row_group_count(), 10);
+        let quotes: Vec<QuoteTick> = reader.flat_map(Result::unwrap).collect();
+        assert_eq!(quotes.len(), 9_500);
+        assert_eq!(quotes[0].instrument_id, InstrumentId::from("EUR/USD.SIM"));
+
+        // Starting after the first row group ends, so it is pruned
+        let start = UnixNanos::from(1_577_900_944_000_000_828);
+        let reader = ParquetReader::<QuoteTick>::new(&path, 1_000, Some(start), None).unwrap();
+        assert_eq!(reader.row_group_count(), 9);
+        let filtered: Vec<QuoteTick> = reader.flat_map(Result::unwrap).collect();
+        assert_eq!(filtered.len(), 8_500);
+        assert!(filtered.iter().all(|quote| quote.ts_init >= start));
+    }
+
+    #[rstest]
+    fn test_read_unknown_enum_value() {
+        let path = write_trades_with_aggressor_sides("unknown_enum.parquet", vec![1, 2, 1, 9]);
+
+        let mut reader = ParquetReader::<TradeTick>::new(&path, 2, None, None).unwrap();
+        assert_eq!(reader.next_chunk().unwrap().unwrap().len(), 2);
+        let err = reader.next_chunk().unwrap_err();
+
+        assert_eq!(
+            format!("{err:#}"),
+            format!(
+                "Error decoding {path:?} in batch starting at row 2: \
+                 Unknown `AggressorSide` value, was 9 in column `aggressor_side` at row 1"
+            )
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_read_unknown_enum_value_when_lenient() {
+        let path = write_trades_with_aggressor_sides("unknown_enum_lenient.parquet", vec![1, 9]);
+
+        let reader = ParquetReader::<TradeTick>::new(&path, 10, None, None)
+            .unwrap()
+            .with_unknown_enum_policy(UnknownEnumPolicy::Lenient);
+        let trades: Vec<TradeTick> = reader.flat_map(Result::unwrap).collect();
+
+        let sides: Vec<AggressorSide> = trades.iter().map(|trade| trade.aggressor_side).collect();
+        assert_eq!(
+            sides,
+            vec![AggressorSide::Buyer, AggressorSide::NoAggressor]
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_new_with_zero_chunk_size() {
+        let path = get_test_data_file_path("nautilus/quotes.parquet");
+        assert!(ParquetReader::<QuoteTick>::new(path, 0, None, None).is_err());
+    }
+}
@@ -0,0 +1,462 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+use nautilus_core::nanos::UnixNanos;
+use nautilus_model::data::{
+    bar::Bar, is_monotonically_increasing_by_init, quote::QuoteTick, trade::TradeTick,
+};
+use parquet::{
+    arrow::ArrowWriter,
+    basic::{Compression, ZstdLevel},
+    file::properties::WriterProperties,
+    format::KeyValue,
+};
+
+use super::{ParquetCompression, ParquetData, ParquetDataType};
+
+/// The default maximum number of rows per row group.
+pub const DEFAULT_ROW_GROUP_SIZE: usize = 100_000;
+
+/// Configuration for a [`ParquetWriter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParquetWriterConfig {
+    /// The maximum number of rows per row group.
+    pub row_group_size: usize,
+    /// The compression codec for column chunks.
+    pub compression: ParquetCompression,
+}
+
+impl Default for ParquetWriterConfig {
+    fn default() -> Self {
+        Self {
+            row_group_size: DEFAULT_ROW_GROUP_SIZE,
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+impl From<ParquetCompression> for Compression {
+    fn from(value: ParquetCompression) -> Self {
+        match value {
+            ParquetCompression::Uncompressed => Self::UNCOMPRESSED,
+            ParquetCompression::Snappy => Self::SNAPPY,
+            ParquetCompression::Zstd => Self::ZSTD(ZstdLevel::default()),
+        }
+    }
+}
+
+/// Writes batches of market data for a single instrument (or bar type) to a Parquet file.
+///
+/// The instrument metadata (instrument ID, bar type and precisions) is taken from the first
+/// batch and embedded in the file metadata, and all following batches must match it.
+/// Data must be written in ascending `ts_init` order, so readers can rely on row group
+/// statistics for time range filtering.
+///
+/// The file is created on the first non-empty batch, so no file is written without data.
+pub struct ParquetWriter<T: ParquetData> {
+    path: PathBuf,
+    config: ParquetWriterConfig,
+    metadata: HashMap<String, String>,
+    writer: Option<ArrowWriter<File>>,
+    last_ts_init: UnixNanos,
+    row_count: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ParquetData> ParquetWriter<T> {
+    /// Creates a new [`ParquetWriter`] instance for the file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `config.row_group_size` is zero.
+    pub fn new<P: AsRef<Path>>(path: P, config: ParquetWriterConfig) -> anyhow::Result<Self> {
+        if config.row_group_size == 0 {
+            anyhow::bail!("Invalid `row_group_size`, was zero");
+        }
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            config,
+            metadata: HashMap::new(),
+            writer: None,
+            last_ts_init: UnixNanos::default(),
+            row_count: 0,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the path of the file being written.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the number of rows written so far.
+    #[must_use]
+    pub const fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Writes a batch of `data` to the file, flushing row groups as they fill.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the rows of `data` do not all have the same metadata.
+    /// - If the metadata of `data` does not match the data already written.
+    /// - If `data` is not in ascending `ts_init` order, including relative to prior batches.
+    /// - If encoding or writing the batch fails.
+    pub fn write_batch(&mut self, data: &[T]) -> anyhow::Result<()> {
+        let Some(first) = data.first() else {
+            return Ok(());
+        };
+
+        let metadata = first.data_metadata();
+        if let Some(row) = data.iter().find(|row| row.data_metadata() != metadata) {
+            anyhow::bail!(
+                "Batch contains mixed metadata {:?} and {metadata:?}",
+                row.data_metadata()
+            );
+        }
+        if self.writer.is_some() && metadata != self.metadata {
+            anyhow::bail!(
+                "Batch metadata {metadata:?} does not match file metadata {:?}",
+                self.metadata
+            );
+        }
+        if first.ts_init() < self.last_ts_init || !is_monotonically_increasing_by_init(data) {
+            anyhow::bail!("Batch not in ascending `ts_init` order");
+        }
+
+        let record_batch = T::encode_batch(&metadata, data)?;
+        let writer = match self.writer.as_mut() {
+            Some(writer) => writer,
+            None => {
+                let writer = self.open_writer(&metadata, &record_batch)?;
+                self.metadata = metadata;
+                self.writer.insert(writer)
+            }
+        };
+        writer.write(&record_batch)?;
+
+        // SAFETY: Unwrap safe as already checked that `data` not empty
+        self.last_ts_init = data.last().unwrap().ts_init();
+        self.row_count += data.len();
+        Ok(())
+    }
+
+    /// Closes the writer, flushing any buffered rows and the file footer.
+    ///
+    /// Returns the total number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If flushing or closing the file fails.
+    pub fn close(self) -> anyhow::Result<usize> {
+        if let Some(writer) = self.writer {
+            writer.close()?;
+        }
+        Ok(self.row_count)
+    }
+
+    fn open_writer(
+        &self,
+        metadata: &HashMap<String, String>,
+        record_batch: &arrow::record_batch::RecordBatch,
+    ) -> anyhow::Result<ArrowWriter<File>> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Also embed the metadata as plain key-values (matching the pyarrow writer)
+        let mut key_values: Vec<KeyValue> = metadata
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.clone()))
+            .collect();
+        key_values.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let props = WriterProperties::builder()
+            .set_compression(self.config.compression.into())
+            .set_max_row_group_size(self.config.row_group_size)
+            .set_key_value_metadata(Some(key_values))
+            .build();
+
+        let file = File::create(&self.path)?;
+        Ok(ArrowWriter::try_new(
+            file,
+            record_batch.schema(),
+            Some(props),
+        )?)
+    }
+}
+
+/// Wraps a [`ParquetWriter`] for each of the supported data types.
+pub enum ParquetWriterAny {
+    QuoteTick(ParquetWriter<QuoteTick>),
+    TradeTick(ParquetWriter<TradeTick>),
+    Bar(ParquetWriter<Bar>),
+}
+
+impl ParquetWriterAny {
+    /// Creates a new [`ParquetWriterAny`] instance for the given `data_type`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `config` is invalid.
+    pub fn new<P: AsRef<Path>>(
+        data_type: ParquetDataType,
+        path: P,
+        config: ParquetWriterConfig,
+    ) -> anyhow::Result<Self> {
+        Ok(match data_type {
+            ParquetDataType::QuoteTick => Self::QuoteTick(ParquetWriter::new(path, config)?),
+            ParquetDataType::TradeTick => Self::TradeTick(ParquetWriter::new(path, config)?),
+            ParquetDataType::Bar => Self::Bar(ParquetWriter::new(path, config)?),
+        })
+    }
+
+    /// Returns the data type being written.
+    #[must_use]
+    pub const fn data_type(&self) -> ParquetDataType {
+        match self {
+            Self::QuoteTick(_) => ParquetDataType::QuoteTick,
+            Self::TradeTick(_) => ParquetDataType::TradeTick,
+            Self::Bar(_) => ParquetDataType::Bar,
+        }
+    }
+
+    /// Returns the number of rows written so far.
+    #[must_use]
+    pub const fn row_count(&self) -> usize {
+        match self {
+            Self::QuoteTick(writer) => writer.row_count(),
+            Self::TradeTick(writer) => writer.row_count(),
+            Self::Bar(writer) => writer.row_count(),
+        }
+    }
+
+    /// Closes the writer, returning the total number of rows written.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If flushing or closing the file fails.
+    pub fn close(self) -> anyhow::Result<usize> {
+        match self {
+            Self::QuoteTick(writer) => writer.close(),
+            Self::TradeTick(writer) => writer.close(),
+            Self::Bar(writer) => writer.close(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use nautilus_model::{
+        data::bar::BarType,
+        enums::AggressorSide,
+        identifiers::{InstrumentId, TradeId},
+        types::{price::Price, quantity::Quantity},
+    };
+    use parquet::{arrow::arrow_reader::ParquetRecordBatchReaderBuilder, file::reader::FileReader};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("nautilus_parquet_writer_{}", std::process::id()))
+            .join(name)
+    }
+
+    fn read_all<T: ParquetData>(path: &Path) -> Vec<T> {
+        let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+        let metadata = builder.schema().metadata().clone();
+        builder
+            .build()
+            .unwrap()
+            .flat_map(|batch| T::decode_batch(&metadata, batch.unwrap()).unwrap())
+            .collect()
+    }
+
+    fn quotes(count: u64) -> Vec<QuoteTick> {
+        (0..count)
+            .map(|i| QuoteTick {
+                instrument_id: InstrumentId::from("EUR/USD.SIM"),
+                bid_price: Price::new(1.1 + i as f64 * 0.000_01, 5),
+                ask_price: Price::new(1.100_02 + i as f64 * 0.000_01, 5),
+                bid_size: Quantity::new(100_000.0, 0),
+                ask_size: Quantity::new(75_000.0, 0),
+                ts_event: i.into(),
+                ts_init: i.into(),
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(ParquetCompression::Uncompressed)]
+    #[case(ParquetCompression::Snappy)]
+    #[case(ParquetCompression::Zstd)]
+    fn test_quote_ticks_round_trip(#[case] compression: ParquetCompression) {
+        let path = temp_path(&format!("quotes_{compression:?}.parquet"));
+        let config = ParquetWriterConfig {
+            row_group_size: 40,
+            compression,
+        };
+        let data = quotes(250);
+
+        let mut writer = ParquetWriter::new(&path, config).unwrap();
+        for chunk in data.chunks(60) {
+            writer.write_batch(chunk).unwrap();
+        }
+        assert_eq!(writer.close().unwrap(), 250);
+
+        let reader =
+            parquet::file::reader::SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+        let file_metadata = reader.metadata();
+        assert_eq!(file_metadata.num_row_groups(), 7);
+        assert_eq!(file_metadata.row_group(0).num_rows(), 40);
+        let key_values = file_metadata.file_metadata().key_value_metadata().unwrap();
+        assert!(key_values
+            .iter()
+            .any(|kv| kv.key == "price_precision" && kv.value.as_deref() == Some("5")));
+
+        assert_eq!(read_all::<QuoteTick>(&path), data);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_trade_ticks_round_trip() {
+        let path = temp_path("trades.parquet");
+        let data: Vec<TradeTick> = (0..10)
+            .map(|i| TradeTick {
+                instrument_id: InstrumentId::from("ETHUSDT-PERP.BINANCE"),
+                price: Price::new(10_000.0 + i as f64, 4),
+                size: Quantity::new(1.5, 8),
+                aggressor_side: AggressorSide::Buyer,
+                trade_id: TradeId::new(&i.to_string()),
+                ts_event: i.into(),
+                ts_init: i.into(),
+            })
+            .collect();
+
+        let mut writer = ParquetWriter::new(&path, ParquetWriterConfig::default()).unwrap();
+        writer.write_batch(&data).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(read_all::<TradeTick>(&path), data);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_bars_round_trip() {
+        let path = temp_path("bars.parquet");
+        let bar_type = BarType::from("AUD/USD.SIM-1-MINUTE-BID-EXTERNAL");
+        let data: Vec<Bar> = (0..10)
+            .map(|i| {
+                Bar::new(
+                    bar_type,
+                    Price::from("1.00001"),
+                    Price::from("1.00004"),
+                    Price::from("1.00000"),
+                    Price::from("1.00002"),
+                    Quantity::from(100_000),
+                    i.into(),
+                    i.into(),
+                )
+            })
+            .collect();
+
+        let mut writer = ParquetWriter::new(&path, ParquetWriterConfig::default()).unwrap();
+        writer.write_batch(&data).unwrap();
+        writer.close().unwrap();
+
+        assert_eq!(read_all::<Bar>(&path), data);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_write_batch_with_mismatched_metadata() {
+        let path = temp_path("mismatched.parquet");
+        let mut writer = ParquetWriter::new(&path, ParquetWriterConfig::default()).unwrap();
+        writer.write_batch(&quotes(2)).unwrap();
+
+        let mut other = quotes(4);
+        other[3].instrument_id = InstrumentId::from("GBP/USD.SIM");
+        assert!(writer.write_batch(&other[3..]).is_err());
+        writer.close().unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_write_batch_with_mixed_metadata() {
+        let path = temp_path("mixed.parquet");
+        let mut writer = ParquetWriter::new(&path, ParquetWriterConfig::default()).unwrap();
+
+        let mut data = quotes(4);
+        data[2].bid_price = Price::new(1.1, 4);
+        assert!(writer.write_batch(&data).is_err());
+        assert_eq!(writer.close().unwrap(), 0);
+        assert!(!path.exists());
+    }
+
+    #[rstest]
+    fn test_write_batch_out_of_order() {
+        let path = temp_path("unordered.parquet");
+        let mut writer = ParquetWriter::new(&path, ParquetWriterConfig::default()).unwrap();
+        let data = quotes(4);
+        writer.write_batch(&data[2..]).unwrap();
+
+        assert!(writer.write_batch(&data[..2]).is_err());
+        assert_eq!(writer.row_count(), 2);
+        writer.close().unwrap();
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[rstest]
+    fn test_close_without_data_writes_no_file() {
+        let path = temp_path("empty.parquet");
+        let mut writer =
+            ParquetWriter::<QuoteTick>::new(&path, ParquetWriterConfig::default()).unwrap();
+        writer.write_batch(&[]).unwrap();
+
+        assert_eq!(writer.close().unwrap(), 0);
+        assert!(!path.exists());
+    }
+
+    #[rstest]
+    fn test_new_with_zero_row_group_size() {
+        let config = ParquetWriterConfig {
+            row_group_size: 0,
+            ..Default::default()
+        };
+        assert!(ParquetWriter::<QuoteTick>::new(temp_path("invalid.parquet"), config).is_err());
+    }
+}
@@ -100,7 +100,8 @@ impl DataQueryResult {
     /// Each iteration returns a chunk of values read from the parquet file.
     fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<PyObject>> {
         match slf.next() {
-            Some(acc) if !acc.is_empty() => {
+            Some(Err(e)) => Err(to_pyruntime_err(format!("{e:#}"))),
+            Some(Ok(acc)) if !acc.is_empty() => {
                 let cvec = slf.set_chunk(acc);
                 Python::with_gil(|py| match PyCapsule::new_bound::<CVec>(py, cvec, None) {
                     Ok(capsule) => Ok(Some(capsule.into_py(py))),
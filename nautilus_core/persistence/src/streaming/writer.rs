@@ -0,0 +1,717 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use arrow::{
+    datatypes::{DataType, Field, Schema, SchemaRef},
+    ipc::writer::StreamWriter,
+    json::{reader::infer_json_schema_from_iterator, ReaderBuilder},
+    record_batch::RecordBatch,
+};
+use parquet::{arrow::ArrowWriter, file::properties::WriterProperties};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::parquet::ParquetCompression;
+
+/// The default number of buffered events per type which triggers a flush.
+pub const DEFAULT_FLUSH_SIZE: usize = 10_000;
+
+/// The JSON field naming the type of a serialized event.
+const TYPE_FIELD: &str = "type";
+
+/// The file format events are streamed to.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum StreamingFormat {
+    /// Arrow IPC stream files, as written by the Python `StreamingFeatherWriter`.
+    #[default]
+    Feather = 1,
+    /// Parquet files, with a row group per flush.
+    Parquet = 2,
+}
+
+impl StreamingFormat {
+    /// Returns the file extension for the format.
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Feather => "feather",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Configuration for a [`StreamingWriter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StreamingWriterConfig {
+    /// The file format to write.
+    pub format: StreamingFormat,
+    /// The number of buffered events of a type which triggers a flush of that type.
+    pub flush_size: usize,
+    /// The maximum time events of a type are buffered before being flushed (checked on write).
+    pub flush_interval: Option<Duration>,
+    /// The compression codec for Parquet column chunks.
+    pub compression: ParquetCompression,
+}
+
+impl Default for StreamingWriterConfig {
+    fn default() -> Self {
+        Self {
+            format: StreamingFormat::default(),
+            flush_size: DEFAULT_FLUSH_SIZE,
+            flush_interval: Some(Duration::from_secs(1)),
+            compression: ParquetCompression::default(),
+        }
+    }
+}
+
+enum TableSink {
+    Feather(StreamWriter<BufWriter<File>>),
+    Parquet(ArrowWriter<File>),
+}
+
+impl TableSink {
+    fn write(&mut self, batch: &RecordBatch) -> anyhow::Result<()> {
+        match self {
+            Self::Feather(writer) => {
+                writer.write(batch)?;
+                writer.get_mut().flush()?;
+            }
+            Self::Parquet(writer) => {
+                writer.write(batch)?;
+                writer.flush()?; // Closes the row group
+            }
+        }
+        Ok(())
+    }
+
+    fn close(self) -> anyhow::Result<()> {
+        match self {
+            Self::Feather(mut writer) => {
+                writer.finish()?;
+                writer.get_mut().flush()?;
+            }
+            Self::Parquet(writer) => {
+                writer.close()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The buffered events and open file for a single event type.
+struct EventTable {
+    path: PathBuf,
+    rows: Vec<Value>,
+    sink: Option<(TableSink, SchemaRef)>,
+    last_flush: Instant,
+    row_count: usize,
+}
+
+impl EventTable {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            rows: Vec::new(),
+            sink: None,
+            last_flush: Instant::now(),
+            row_count: 0,
+        }
+    }
+
+    fn flush(&mut self, config: &StreamingWriterConfig) -> anyhow::Result<()> {
+        self.last_flush = Instant::now();
+        if self.rows.is_empty() {
+            return Ok(());
+        }
+
+        match &self.sink {
+            Some((_, schema)) => check_schema(schema, &self.rows).map_err(|e| {
+                anyhow::anyhow!("Error encoding events for '{}': {e}", self.path.display())
+            })?,
+            None => {
+                let schema = infer_schema(&self.rows)?;
+                let sink = open_sink(&self.path, &schema, config)?;
+                self.sink = Some((sink, schema));
+            }
+        }
+
+        // SAFETY: Unwrap safe as the sink was opened above if not already open
+        let (sink, schema) = self.sink.as_mut().unwrap();
+        let batch = decode_rows(schema.clone(), &self.rows).map_err(|e| {
+            anyhow::anyhow!("Error encoding events for '{}': {e}", self.path.display())
+        })?;
+        sink.write(&batch)?;
+        self.rows.clear();
+        Ok(())
+    }
+
+    fn close(mut self, config: &StreamingWriterConfig) -> anyhow::Result<()> {
+        self.flush(config)?;
+        if let Some((sink, _)) = self.sink.take() {
+            sink.close()?;
+        }
+        Ok(())
+    }
+}
+
+/// Streams serializable events to a file per event type, under a directory per run ID.
+///
+/// Events are buffered per type and flushed once `flush_size` events are buffered, or once
+/// `flush_interval` has elapsed since the last flush of that type, with each flush written as a
+/// record batch (or Parquet row group). Files are written to `<directory>/<run_id>/<table>.<ext>`,
+/// where the table is the snake case name of the event type, and [`StreamingWriter::rotate`]
+/// starts a new set of files for the next run.
+///
+/// The file schema for each type is inferred from its first flushed events, with columns which
+/// are null throughout those events written as strings. Later flushes must fit that schema, so a
+/// flush errors on a field not in the schema or a value of a different type.
+///
+/// Any buffered events are flushed and the files closed when the writer is dropped.
+pub struct StreamingWriter {
+    directory: PathBuf,
+    run_id: String,
+    config: StreamingWriterConfig,
+    tables: HashMap<String, EventTable>,
+    row_count: usize,
+}
+
+impl StreamingWriter {
+    /// Creates a new [`StreamingWriter`] instance writing the run `run_id` under `directory`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `config.flush_size` is zero.
+    /// - If `run_id` is empty.
+    pub fn new<P: AsRef<Path>>(
+        directory: P,
+        run_id: &str,
+        config: StreamingWriterConfig,
+    ) -> anyhow::Result<Self> {
+        if config.flush_size == 0 {
+            anyhow::bail!("Invalid `flush_size`, was zero");
+        }
+        if run_id.is_empty() {
+            anyhow::bail!("Invalid `run_id`, was empty");
+        }
+
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+            run_id: run_id.to_string(),
+            config,
+            tables: HashMap::new(),
+            row_count: 0,
+        })
+    }
+
+    /// Returns the ID of the run being written.
+    #[must_use]
+    pub fn run_id(&self) -> &str {
+        &self.run_id
+    }
+
+    /// Returns the directory the files for the current run are written to.
+    #[must_use]
+    pub fn run_directory(&self) -> PathBuf {
+        self.directory.join(&self.run_id)
+    }
+
+    /// Returns the number of events written for the current run (including those buffered).
+    #[must_use]
+    pub const fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns the number of events of the given `table` written for the current run.
+    #[must_use]
+    pub fn table_row_count(&self, table: &str) -> usize {
+        self.tables.get(table).map_or(0, |table| table.row_count)
+    }
+
+    /// Writes the given `event`, under the table named by its serialized `type` field (or the
+    /// name of `T` if it has none).
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `event` does not serialize to a JSON object.
+    /// - If flushing the event's table fails (see [`StreamingWriter::flush`]).
+    pub fn write<T: Serialize>(&mut self, event: &T) -> anyhow::Result<()> {
+        let value = serde_json::to_value(event)?;
+        let type_name = match value.get(TYPE_FIELD).and_then(Value::as_str) {
+            Some(type_name) => type_name.to_string(),
+            None => short_type_name::<T>().to_string(),
+        };
+        self.write_value(&type_name, value)
+    }
+
+    /// Writes the event serialized as the JSON object `json`, which must have a string `type`
+    /// field naming the event type.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `json` is not a JSON object with a string `type` field.
+    /// - If flushing the event's table fails (see [`StreamingWriter::flush`]).
+    pub fn write_json(&mut self, json: &str) -> anyhow::Result<()> {
+        let value: Value = serde_json::from_str(json)?;
+        let Some(type_name) = value.get(TYPE_FIELD).and_then(Value::as_str) else {
+            anyhow::bail!("Event JSON missing string `{TYPE_FIELD}` field");
+        };
+        let type_name = type_name.to_string();
+        self.write_value(&type_name, value)
+    }
+
+    /// Flushes the buffered events of every type to their files.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If the buffered events have fields not in the table schema, or values of another type.
+    /// - If creating or writing a file fails.
+    pub fn flush(&mut self) -> anyhow::Result<()> {
+        for table in self.tables.values_mut() {
+            table.flush(&self.config)?;
+        }
+        Ok(())
+    }
+
+    /// Closes the files for the current run and starts writing the run `run_id`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If `run_id` is empty.
+    /// - If closing the current files fails (see [`StreamingWriter::flush`]).
+    pub fn rotate(&mut self, run_id: &str) -> anyhow::Result<()> {
+        if run_id.is_empty() {
+            anyhow::bail!("Invalid `run_id`, was empty");
+        }
+
+        self.close_tables()?;
+        self.run_id = run_id.to_string();
+        self.row_count = 0;
+        Ok(())
+    }
+
+    /// Flushes any buffered events and closes all files, returning the number of events written
+    /// for the current run.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error:
+    /// - If flushing or closing any file fails.
+    pub fn close(mut self) -> anyhow::Result<usize> {
+        self.close_tables()?;
+        Ok(self.row_count)
+    }
+
+    fn write_value(&mut self, type_name: &str, value: Value) -> anyhow::Result<()> {
+        if !value.is_object() {
+            anyhow::bail!("Invalid event for `{type_name}`, expected a JSON object");
+        }
+
+        let table_name = to_snake_case(type_name);
+        if !self.tables.contains_key(&table_name) {
+            let path = self
+                .run_directory()
+                .join(format!("{table_name}.{}", self.config.format.extension()));
+            self.tables
+                .insert(table_name.clone(), EventTable::new(path));
+        }
+
+        // SAFETY: Unwrap safe as the table was inserted above if not already present
+        let table = self.tables.get_mut(&table_name).unwrap();
+
+        table.rows.push(value);
+        table.row_count += 1;
+        self.row_count += 1;
+
+        let interval_elapsed = self
+            .config
+            .flush_interval
+            .is_some_and(|interval| table.last_flush.elapsed() >= interval);
+        if table.rows.len() >= self.config.flush_size || interval_elapsed {
+            table.flush(&self.config)?;
+        }
+        Ok(())
+    }
+
+    fn close_tables(&mut self) -> anyhow::Result<()> {
+        for (_, table) in self.tables.drain() {
+            table.close(&self.config)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for StreamingWriter {
+    fn drop(&mut self) {
+        if let Err(e) = self.close_tables() {
+            eprintln!("Error closing streaming writer files: {e}");
+        }
+    }
+}
+
+/// Infers the schema of the given JSON `rows`, typing columns which are only null as strings.
+fn infer_schema(rows: &[Value]) -> anyhow::Result<SchemaRef> {
+    let schema = infer_json_schema_from_iterator(rows.iter().map(Ok))?;
+    let fields: Vec<Field> = schema
+        .fields()
+        .iter()
+        .map(|field| match field.data_type() {
+            DataType::Null => Field::new(field.name(), DataType::Utf8, true),
+            _ => field.as_ref().clone(),
+        })
+        .collect();
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+/// Checks the given JSON `rows` fit the file `schema`, where fields which are only null fit any
+/// column and integers fit floating point columns.
+fn check_schema(schema: &Schema, rows: &[Value]) -> anyhow::Result<()> {
+    let inferred = infer_json_schema_from_iterator(rows.iter().map(Ok))?;
+    for field in inferred.fields() {
+        let Ok(column) = schema.field_with_name(field.name()) else {
+            anyhow::bail!("Field `{}` not in the schema", field.name());
+        };
+        let fits = match (column.data_type(), field.data_type()) {
+            (_, DataType::Null) | (DataType::Float64, DataType::Int64) => true,
+            (column_type, field_type) => column_type == field_type,
+        };
+        if !fits {
+            anyhow::bail!(
+                "Field `{}` has type {}, expected {}",
+                field.name(),
+                field.data_type(),
+                column.data_type()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn decode_rows(schema: SchemaRef, rows: &[Value]) -> anyhow::Result<RecordBatch> {
+    let mut decoder = ReaderBuilder::new(schema)
+        .with_batch_size(rows.len())
+        .with_coerce_primitive(true)
+        .build_decoder()?;
+    decoder.serialize(rows)?;
+    decoder
+        .flush()?
+        .ok_or_else(|| anyhow::anyhow!("No record batch decoded"))
+}
+
+fn open_sink(
+    path: &Path,
+    schema: &SchemaRef,
+    config: &StreamingWriterConfig,
+) -> anyhow::Result<TableSink> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file = File::create(path)?;
+    Ok(match config.format {
+        StreamingFormat::Feather => {
+            TableSink::Feather(StreamWriter::try_new(BufWriter::new(file), schema)?)
+        }
+        StreamingFormat::Parquet => {
+            let props = WriterProperties::builder()
+                .set_compression(config.compression.into())
+                .build();
+            TableSink::Parquet(ArrowWriter::try_new(file, schema.clone(), Some(props))?)
+        }
+    })
+}
+
+/// Returns the name of `T` without its module path.
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let name = name.split('<').next().unwrap_or(name);
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Converts an event type name (e.g. `OrderFilled`) to its table name (e.g. `order_filled`).
+fn to_snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i > 0 {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use arrow::ipc::reader::StreamReader;
+    use nautilus_model::events::order::{filled::OrderFilled, stubs::order_filled};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use rstest::rstest;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct PositionChanged {
+        position_id: String,
+        quantity: f64,
+        realized_pnl: Option<String>,
+        ts_event: u64,
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("nautilus_streaming_writer_{}", std::process::id()))
+            .join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn position_changed(i: u64) -> PositionChanged {
+        PositionChanged {
+            position_id: format!("P-{}", i % 10),
+            quantity: i as f64,
+            // Null throughout the first flushes, so the column is typed as a string
+            realized_pnl: (i >= 50_000).then(|| format!("{i}.00 USD")),
+            ts_event: i,
+        }
+    }
+
+    fn count_rows(path: &Path, format: StreamingFormat) -> usize {
+        let file = File::open(path).unwrap();
+        match format {
+            StreamingFormat::Feather => StreamReader::try_new(file, None)
+                .unwrap()
+                .map(|batch| batch.unwrap().num_rows())
+                .sum(),
+            StreamingFormat::Parquet => ParquetRecordBatchReaderBuilder::try_new(file)
+                .unwrap()
+                .build()
+                .unwrap()
+                .map(|batch| batch.unwrap().num_rows())
+                .sum(),
+        }
+    }
+
+    #[rstest]
+    #[case(StreamingFormat::Feather)]
+    #[case(StreamingFormat::Parquet)]
+    fn test_write_100k_events(#[case] format: StreamingFormat, order_filled: OrderFilled) {
+        let directory = temp_dir(&format!("100k_{format:?}"));
+        let config = StreamingWriterConfig {
+            format,
+            flush_size: 1_000,
+            flush_interval: None,
+            ..Default::default()
+        };
+        let mut writer = StreamingWriter::new(&directory, "run-001", config).unwrap();
+
+        for i in 0..100_000 {
+            if i % 4 == 0 {
+                writer.write(&order_filled).unwrap();
+            } else {
+                writer.write(&position_changed(i)).unwrap();
+            }
+        }
+
+        assert_eq!(writer.table_row_count("order_filled"), 25_000);
+        assert_eq!(writer.table_row_count("position_changed"), 75_000);
+        assert_eq!(writer.close().unwrap(), 100_000);
+
+        let run_directory = directory.join("run-001");
+        let extension = format.extension();
+        assert_eq!(
+            count_rows(
+                &run_directory.join(format!("order_filled.{extension}")),
+                format
+            ),
+            25_000
+        );
+        assert_eq!(
+            count_rows(
+                &run_directory.join(format!("position_changed.{extension}")),
+                format
+            ),
+            75_000
+        );
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[rstest]
+    fn test_parquet_row_group_per_flush() {
+        let directory = temp_dir("row_groups");
+        let config = StreamingWriterConfig {
+            format: StreamingFormat::Parquet,
+            flush_size: 100,
+            flush_interval: None,
+            ..Default::default()
+        };
+        let mut writer = StreamingWriter::new(&directory, "run-001", config).unwrap();
+        for i in 0..250 {
+            writer.write(&position_changed(i)).unwrap();
+        }
+        writer.close().unwrap();
+
+        let file = File::open(directory.join("run-001/position_changed.parquet")).unwrap();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 3);
+    }
+
+    #[rstest]
+    fn test_flush_interval_flushes_buffered_events() {
+        let directory = temp_dir("interval");
+        let config = StreamingWriterConfig {
+            flush_interval: Some(Duration::ZERO),
+            ..Default::default()
+        };
+        let mut writer = StreamingWriter::new(&directory, "run-001", config).unwrap();
+
+        writer.write(&position_changed(0)).unwrap();
+
+        let path = directory.join("run-001/position_changed.feather");
+        assert_eq!(count_rows(&path, StreamingFormat::Feather), 1);
+    }
+
+    #[rstest]
+    fn test_write_json(order_filled: OrderFilled) {
+        let directory = temp_dir("json");
+        let mut writer =
+            StreamingWriter::new(&directory, "run-001", StreamingWriterConfig::default()).unwrap();
+
+        let json = serde_json::to_string(&order_filled).unwrap();
+        writer.write_json(&json).unwrap();
+        writer.write(&order_filled).unwrap();
+
+        assert_eq!(writer.table_row_count("order_filled"), 2);
+        assert!(writer
+            .write_json("{\"trader_id\": \"TRADER-001\"}")
+            .is_err());
+        assert!(writer.write_json("[1, 2, 3]").is_err());
+    }
+
+    #[rstest]
+    fn test_rotate_starts_new_run_directory() {
+        let directory = temp_dir("rotate");
+        let mut writer =
+            StreamingWriter::new(&directory, "run-001", StreamingWriterConfig::default()).unwrap();
+        for i in 0..10 {
+            writer.write(&position_changed(i)).unwrap();
+        }
+
+        writer.rotate("run-002").unwrap();
+        for i in 0..5 {
+            writer.write(&position_changed(i)).unwrap();
+        }
+        assert_eq!(writer.run_id(), "run-002");
+        assert_eq!(writer.close().unwrap(), 5);
+
+        let format = StreamingFormat::Feather;
+        let first = directory.join("run-001/position_changed.feather");
+        let second = directory.join("run-002/position_changed.feather");
+        assert_eq!(count_rows(&first, format), 10);
+        assert_eq!(count_rows(&second, format), 5);
+    }
+
+    #[rstest]
+    fn test_drop_flushes_buffered_events() {
+        let directory = temp_dir("drop");
+        let mut writer =
+            StreamingWriter::new(&directory, "run-001", StreamingWriterConfig::default()).unwrap();
+        for i in 0..10 {
+            writer.write(&position_changed(i)).unwrap();
+        }
+        drop(writer);
+
+        let path = directory.join("run-001/position_changed.feather");
+        assert_eq!(count_rows(&path, StreamingFormat::Feather), 10);
+    }
+
+    #[rstest]
+    fn test_mismatched_field_type_errors_on_flush() {
+        let directory = temp_dir("mismatched");
+        let config = StreamingWriterConfig {
+            flush_size: 1,
+            ..Default::default()
+        };
+        let mut writer = StreamingWriter::new(&directory, "run-001", config).unwrap();
+
+        writer
+            .write_json(&json!({"type": "AccountState", "balance": 1.5}).to_string())
+            .unwrap();
+        assert!(writer
+            .write_json(&json!({"type": "AccountState", "balance": "n/a"}).to_string())
+            .is_err());
+    }
+
+    #[rstest]
+    #[case(json!({"type": "AccountState", "balance": 2}), true)]
+    #[case(json!({"type": "AccountState", "balance": null}), true)]
+    #[case(json!({"type": "AccountState", "balance": true}), false)]
+    #[case(json!({"type": "AccountState", "balance": 1.5, "margin": 1.0}), false)]
+    fn test_later_flush_checks_schema(#[case] event: Value, #[case] expected_ok: bool) {
+        let directory = temp_dir("schema");
+        let config = StreamingWriterConfig {
+            flush_size: 1,
+            ..Default::default()
+        };
+        let mut writer = StreamingWriter::new(&directory, "run-001", config).unwrap();
+
+        writer
+            .write_json(&json!({"type": "AccountState", "balance": 1.5}).to_string())
+            .unwrap();
+        assert_eq!(writer.write_json(&event.to_string()).is_ok(), expected_ok);
+    }
+
+    #[rstest]
+    #[case(StreamingWriterConfig { flush_size: 0, ..Default::default() }, "run-001")]
+    #[case(StreamingWriterConfig::default(), "")]
+    fn test_new_with_invalid_args(#[case] config: StreamingWriterConfig, #[case] run_id: &str) {
+        assert!(StreamingWriter::new(temp_dir("invalid"), run_id, config).is_err());
+    }
+
+    #[rstest]
+    #[case("OrderFilled", "order_filled")]
+    #[case("PositionChanged", "position_changed")]
+    #[case("position_opened", "position_opened")]
+    fn test_to_snake_case(#[case] name: &str, #[case] expected: &str) {
+        assert_eq!(to_snake_case(name), expected);
+    }
+
+    #[rstest]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<PositionChanged>(), "PositionChanged");
+        assert_eq!(short_type_name::<Vec<u8>>(), "Vec");
+    }
+}
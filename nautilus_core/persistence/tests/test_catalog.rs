@@ -13,21 +13,62 @@
 //  limitations under the License.
 // -------------------------------------------------------------------------------------------------
 
+use std::{fs::File, path::PathBuf, sync::Arc};
+
+use arrow::{
+    array::{Int64Array, StringArray, UInt64Array, UInt8Array},
+    record_batch::RecordBatch,
+};
 use nautilus_core::ffi::cvec::CVec;
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
-    trade::TradeTick, Data,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, is_monotonically_increasing_by_init, quote::QuoteTick,
+        trade::TradeTick, Data,
+    },
+    enums::AggressorSide,
+    identifiers::InstrumentId,
 };
 use nautilus_persistence::{
     backend::session::{DataBackendSession, DataQueryResult, QueryResult},
     python::backend::session::NautilusDataType,
 };
+use nautilus_serialization::arrow::{ArrowSchemaProvider, EncodingError, UnknownEnumPolicy};
 use nautilus_test_kit::common::get_test_data_file_path;
+use parquet::arrow::ArrowWriter;
 #[cfg(target_os = "linux")]
 use procfs::{self, process::Process};
 use pyo3::{prelude::*, types::PyCapsule};
 use rstest::rstest;
 
+/// Writes trades with the raw `aggressor_sides`, which may be unknown discriminants.
+fn write_trades_with_aggressor_sides(name: &str, aggressor_sides: Vec<u8>) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join(format!("nautilus_test_catalog_{}", std::process::id()))
+        .join(name);
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+    let len = aggressor_sides.len();
+    let metadata = TradeTick::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+    let batch = RecordBatch::try_new(
+        TradeTick::get_schema(Some(metadata)).into(),
+        vec![
+            Arc::new(Int64Array::from(vec![1_000_000_000_000; len])),
+            Arc::new(UInt64Array::from(vec![1_000_000_000_000; len])),
+            Arc::new(UInt8Array::from(aggressor_sides)),
+            Arc::new(StringArray::from(vec!["1"; len])),
+            Arc::new(UInt64Array::from_iter_values(0..len as u64)),
+            Arc::new(UInt64Array::from_iter_values(0..len as u64)),
+        ],
+    )
+    .unwrap();
+
+    let mut writer =
+        ArrowWriter::try_new(File::create(&path).unwrap(), batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    path
+}
+
 /// Memory leak test
 ///
 /// Uses arguments from setup to run function for given number of iterations.
@@ -119,6 +160,7 @@ fn test_quote_tick_cvec_interface() {
     let query_result = DataQueryResult::new(query_result, catalog.chunk_size);
     let mut count = 0;
     for chunk in query_result {
+        let chunk = chunk.unwrap();
         if chunk.is_empty() {
             break;
         }
@@ -190,7 +232,7 @@ fn test_order_book_delta_query() {
         )
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
 
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
@@ -232,7 +274,7 @@ fn test_quote_tick_query() {
         .add_file::<QuoteTick>("quote_005", file_path.as_str(), None)
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
 
     if let Data::Quote(q) = ticks[0] {
         assert_eq!("EUR/USD.SIM", q.instrument_id.to_string());
@@ -256,7 +298,7 @@ fn test_quote_tick_query_with_filter() {
         )
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
 
@@ -273,7 +315,7 @@ fn test_quote_tick_multiple_query() {
         .add_file::<TradeTick>("quote_tick_2", file_path_trades.as_str(), None)
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
 
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
@@ -288,7 +330,7 @@ fn test_trade_tick_query() {
         .add_file::<TradeTick>("trade_001", file_path.as_str(), None)
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
 
     if let Data::Trade(t) = ticks[0] {
         assert_eq!("EUR/USD.SIM", t.instrument_id.to_string());
@@ -309,7 +351,7 @@ fn test_bar_query() {
         .add_file::<Bar>("bar_001", file_path.as_str(), None)
         .unwrap();
     let query_result: QueryResult = catalog.get_query_result();
-    let ticks: Vec<Data> = query_result.collect();
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
 
     if let Data::Bar(b) = &ticks[0] {
         assert_eq!("ADABTC.BINANCE", b.bar_type.instrument_id().to_string());
@@ -320,3 +362,72 @@ fn test_bar_query() {
     assert_eq!(ticks.len(), expected_length);
     assert!(is_monotonically_increasing_by_init(&ticks));
 }
+
+#[rstest]
+fn test_trade_tick_query_unknown_enum_value() {
+    let bad_path = write_trades_with_aggressor_sides("unknown_enum.parquet", vec![1, 9]);
+    let file_path = get_test_data_file_path("nautilus/trades.parquet");
+    let mut catalog = DataBackendSession::new(10_000);
+    catalog
+        .add_file::<TradeTick>("trade_001", file_path.as_str(), None)
+        .unwrap();
+    catalog
+        .add_file::<TradeTick>("trade_002", bad_path.to_str().unwrap(), None)
+        .unwrap();
+    let mut query_result: QueryResult = catalog.get_query_result();
+
+    // The error is returned first, then the data of the readable file
+    let err = query_result.next().unwrap().unwrap_err();
+    assert_eq!(
+        format!("{err:#}"),
+        format!(
+            "Error decoding batch from '{}': \
+             Unknown `AggressorSide` value, was 9 in column `aggressor_side` at row 1",
+            bad_path.display()
+        )
+    );
+    assert!(matches!(
+        err.downcast_ref::<EncodingError>(),
+        Some(EncodingError::UnknownEnumValue { .. })
+    ));
+    let ticks: Vec<Data> = query_result.map(Result::unwrap).collect();
+    assert_eq!(ticks.len(), 100);
+    std::fs::remove_file(bad_path).unwrap();
+}
+
+#[rstest]
+fn test_trade_tick_query_unknown_enum_value_when_lenient() {
+    let path = write_trades_with_aggressor_sides("unknown_enum_lenient.parquet", vec![1, 9]);
+    let mut catalog =
+        DataBackendSession::new(10_000).with_unknown_enum_policy(UnknownEnumPolicy::Lenient);
+    catalog
+        .add_file::<TradeTick>("trade_001", path.to_str().unwrap(), None)
+        .unwrap();
+    let query_result: QueryResult = catalog.get_query_result();
+
+    let sides: Vec<AggressorSide> = query_result
+        .map(|item| match item.unwrap() {
+            Data::Trade(trade) => trade.aggressor_side,
+            data => panic!("Unexpected data {data:?}"),
+        })
+        .collect();
+
+    assert_eq!(
+        sides,
+        vec![AggressorSide::Buyer, AggressorSide::NoAggressor]
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[rstest]
+fn test_query_result_chunk_unknown_enum_value() {
+    let path = write_trades_with_aggressor_sides("unknown_enum_chunk.parquet", vec![9]);
+    let mut catalog = DataBackendSession::new(10);
+    catalog
+        .add_file::<TradeTick>("trade_001", path.to_str().unwrap(), None)
+        .unwrap();
+    let mut query_result = DataQueryResult::new(catalog.get_query_result(), catalog.chunk_size);
+
+    assert!(query_result.next().unwrap().is_err());
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,634 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+//! Pre-trade risk checks for order submissions.
+
+use std::collections::HashMap;
+
+use nautilus_core::{nanos::UnixNanos, uuid::UUID4};
+use nautilus_model::{
+    commission::calculate_notional,
+    enums::{OrderSide, PositionSide, TradingState},
+    events::order::OrderDenied,
+    identifiers::InstrumentId,
+    instruments::{
+        validate_notional, validate_price, validate_quantity, Instrument, InstrumentViolation,
+    },
+    orders::any::OrderAny,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::Decimal;
+use strum::{AsRefStr, Display, EnumIter, EnumString, FromRepr};
+use ustr::Ustr;
+
+/// The reason an order was denied by pre-trade risk checks.
+#[repr(C)]
+#[derive(
+    Copy, Clone, Debug, Display, Hash, PartialEq, Eq, AsRefStr, FromRepr, EnumIter, EnumString,
+)]
+#[strum(ascii_case_insensitive)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum RiskDenialReason {
+    /// Trading is halted, so no orders are permitted.
+    TradingHalted = 1,
+    /// Trading is reducing, and the order would not reduce the current position.
+    TradingReducing = 2,
+    /// No risk limits are configured for the order's instrument.
+    UnknownInstrument = 3,
+    /// The quantity precision exceeds the instrument size precision.
+    QuantityPrecision = 4,
+    /// The quantity is not a multiple of the instrument size increment.
+    QuantityIncrement = 5,
+    /// The quantity is less than the instrument minimum quantity.
+    QuantityBelowMin = 6,
+    /// The quantity is greater than the instrument maximum quantity.
+    QuantityAboveMax = 7,
+    /// The quantity is greater than the configured maximum order quantity.
+    QuantityExceedsLimit = 8,
+    /// The price precision exceeds the instrument price precision.
+    PricePrecision = 9,
+    /// The price is not a multiple of the instrument price increment.
+    PriceIncrement = 10,
+    /// The price is not positive.
+    PriceNotPositive = 11,
+    /// The price deviates from the last price by more than the configured collar.
+    PriceOutsideCollar = 12,
+    /// The notional value is less than the instrument minimum notional.
+    NotionalBelowMin = 13,
+    /// The notional value is greater than the instrument maximum notional.
+    NotionalAboveMax = 14,
+    /// The notional value is greater than the configured maximum notional per order.
+    NotionalExceedsLimit = 15,
+    /// The order would open a new position beyond the configured maximum open positions.
+    MaxOpenPositions = 16,
+    /// A notional limit is in a different currency to the order notional value.
+    NotionalCurrencyMismatch = 17,
+}
+
+impl From<InstrumentViolation> for RiskDenialReason {
+    fn from(value: InstrumentViolation) -> Self {
+        match value {
+            InstrumentViolation::QuantityPrecision => Self::QuantityPrecision,
+            InstrumentViolation::QuantityIncrement => Self::QuantityIncrement,
+            InstrumentViolation::QuantityBelowMin => Self::QuantityBelowMin,
+            InstrumentViolation::QuantityAboveMax => Self::QuantityAboveMax,
+            InstrumentViolation::PricePrecision => Self::PricePrecision,
+            InstrumentViolation::PriceIncrement => Self::PriceIncrement,
+            InstrumentViolation::NotionalBelowMin => Self::NotionalBelowMin,
+            InstrumentViolation::NotionalAboveMax => Self::NotionalAboveMax,
+            InstrumentViolation::NotionalCurrencyMismatch => Self::NotionalCurrencyMismatch,
+        }
+    }
+}
+
+impl RiskDenialReason {
+    /// Returns an [`OrderDenied`] event for the given `order`, denied for this reason.
+    #[must_use]
+    pub fn to_order_denied(
+        self,
+        order: &OrderAny,
+        event_id: UUID4,
+        ts_init: UnixNanos,
+    ) -> OrderDenied {
+        OrderDenied::new(
+            order.trader_id(),
+            order.strategy_id(),
+            order.instrument_id(),
+            order.client_order_id(),
+            Ustr::from(self.as_ref()),
+            event_id,
+            ts_init,
+            ts_init,
+        )
+    }
+}
+
+/// The instrument specification and configured limits used for pre-trade checks of an instrument.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstrumentRiskLimits {
+    /// The instrument price increment (minimum price variation).
+    pub price_increment: Price,
+    /// The instrument size increment (minimum size variation).
+    pub size_increment: Quantity,
+    /// The instrument contract multiplier.
+    pub multiplier: Quantity,
+    /// If the instrument is inverse (notional is then in the base currency).
+    pub is_inverse: bool,
+    /// The currency of the order notional value (the quote currency, or the base currency
+    /// for inverse instruments), which the notional limits must be denominated in.
+    pub notional_currency: Currency,
+    /// The instrument minimum order quantity.
+    pub min_quantity: Option<Quantity>,
+    /// The instrument maximum order quantity.
+    pub max_quantity: Option<Quantity>,
+    /// The instrument minimum order notional value.
+    pub min_notional: Option<Money>,
+    /// The instrument maximum order notional value.
+    pub max_notional: Option<Money>,
+    /// The configured maximum quantity per order.
+    pub max_order_quantity: Option<Quantity>,
+    /// The configured maximum notional value per order.
+    pub max_notional_per_order: Option<Money>,
+    /// The configured maximum deviation of an order price from the last price, as a
+    /// fraction of the last price (e.g. 0.05 for 5%).
+    pub price_collar: Option<Decimal>,
+}
+
+impl InstrumentRiskLimits {
+    /// Creates a new [`InstrumentRiskLimits`] instance from the given `instrument`
+    /// specification, with no configured limits.
+    #[must_use]
+    pub fn from_instrument<T: Instrument + ?Sized>(instrument: &T) -> Self {
+        Self {
+            price_increment: instrument.price_increment(),
+            size_increment: instrument.size_increment(),
+            multiplier: instrument.multiplier(),
+            is_inverse: instrument.is_inverse(),
+            notional_currency: if instrument.is_inverse() {
+                instrument
+                    .base_currency()
+                    .unwrap_or_else(|| instrument.quote_currency())
+            } else {
+                instrument.quote_currency()
+            },
+            min_quantity: instrument.min_quantity(),
+            max_quantity: instrument.max_quantity(),
+            min_notional: instrument.min_notional(),
+            max_notional: instrument.max_notional(),
+            max_order_quantity: None,
+            max_notional_per_order: None,
+            price_collar: None,
+        }
+    }
+
+    /// Sets the maximum quantity per order.
+    #[must_use]
+    pub const fn with_max_order_quantity(mut self, max_order_quantity: Quantity) -> Self {
+        self.max_order_quantity = Some(max_order_quantity);
+        self
+    }
+
+    /// Sets the maximum notional value per order.
+    #[must_use]
+    pub const fn with_max_notional_per_order(mut self, max_notional_per_order: Money) -> Self {
+        self.max_notional_per_order = Some(max_notional_per_order);
+        self
+    }
+
+    /// Sets the maximum deviation of an order price from the last price, as a fraction of
+    /// the last price.
+    #[must_use]
+    pub const fn with_price_collar(mut self, price_collar: Decimal) -> Self {
+        self.price_collar = Some(price_collar);
+        self
+    }
+}
+
+/// Provides pre-trade risk checks of orders against per-instrument limits and the
+/// current [`TradingState`].
+#[derive(Clone, Debug)]
+pub struct RiskChecker {
+    /// The current trading state.
+    pub trading_state: TradingState,
+    /// The maximum number of open positions (`None` for no limit).
+    pub max_open_positions: Option<usize>,
+    limits: HashMap<InstrumentId, InstrumentRiskLimits>,
+}
+
+impl Default for RiskChecker {
+    /// Creates a new default [`RiskChecker`] instance.
+    fn default() -> Self {
+        Self::new(TradingState::Active, None)
+    }
+}
+
+impl RiskChecker {
+    /// Creates a new [`RiskChecker`] instance.
+    #[must_use]
+    pub fn new(trading_state: TradingState, max_open_positions: Option<usize>) -> Self {
+        Self {
+            trading_state,
+            max_open_positions,
+            limits: HashMap::new(),
+        }
+    }
+
+    /// Sets the risk limits for the given `instrument_id`, replacing any existing limits.
+    pub fn set_limits(&mut self, instrument_id: InstrumentId, limits: InstrumentRiskLimits) {
+        self.limits.insert(instrument_id, limits);
+    }
+
+    /// Returns the risk limits for the given `instrument_id` (if found).
+    #[must_use]
+    pub fn limits(&self, instrument_id: &InstrumentId) -> Option<&InstrumentRiskLimits> {
+        self.limits.get(instrument_id)
+    }
+
+    /// Checks an order for the given instrument against the pre-trade risk limits.
+    ///
+    /// The `price` is the order (limit) price if any, and the notional value is calculated
+    /// from the `price` or otherwise the `last_price` (with notional checks skipped if neither
+    /// is available). The price collar is only checked when both prices are available.
+    ///
+    /// When the trading state is [`TradingState::Reducing`], only orders which reduce the
+    /// current position without flipping it are permitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`RiskDenialReason`] for the first check which fails. Notional limits are
+    /// not converted, so a limit in a currency other than the notional currency denies the
+    /// order with [`RiskDenialReason::NotionalCurrencyMismatch`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn check_order(
+        &self,
+        instrument_id: &InstrumentId,
+        order_side: OrderSide,
+        quantity: Quantity,
+        price: Option<Price>,
+        last_price: Option<Price>,
+        position_side: PositionSide,
+        position_quantity: Quantity,
+        open_positions: usize,
+    ) -> Result<(), RiskDenialReason> {
+        match self.trading_state {
+            TradingState::Halted => return Err(RiskDenialReason::TradingHalted),
+            TradingState::Reducing => {
+                let reduces = match (order_side, position_side) {
+                    (OrderSide::Buy, PositionSide::Short)
+                    | (OrderSide::Sell, PositionSide::Long) => quantity <= position_quantity,
+                    _ => false,
+                };
+                if !reduces {
+                    return Err(RiskDenialReason::TradingReducing);
+                }
+            }
+            TradingState::Active => {}
+        }
+
+        let Some(limits) = self.limits.get(instrument_id) else {
+            return Err(RiskDenialReason::UnknownInstrument);
+        };
+
+        check_quantity(limits, quantity)?;
+
+        if let Some(price) = price {
+            check_price(limits, price)?;
+            if let (Some(collar), Some(last_price)) = (limits.price_collar, last_price) {
+                let last = last_price.as_decimal();
+                if (price.as_decimal() - last).abs() > last.abs() * collar {
+                    return Err(RiskDenialReason::PriceOutsideCollar);
+                }
+            }
+        }
+
+        if let Some(notional_price) = price.or(last_price) {
+            check_notional(limits, quantity, notional_price)?;
+        }
+
+        if let Some(max_open_positions) = self.max_open_positions {
+            let is_flat = matches!(
+                position_side,
+                PositionSide::Flat | PositionSide::NoPositionSide
+            );
+            if is_flat && open_positions >= max_open_positions {
+                return Err(RiskDenialReason::MaxOpenPositions);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_quantity(
+    limits: &InstrumentRiskLimits,
+    quantity: Quantity,
+) -> Result<(), RiskDenialReason> {
+    validate_quantity(
+        quantity,
+        limits.size_increment,
+        limits.min_quantity,
+        limits.max_quantity,
+    )?;
+    if limits.max_order_quantity.is_some_and(|max| quantity > max) {
+        return Err(RiskDenialReason::QuantityExceedsLimit);
+    }
+    Ok(())
+}
+
+fn check_price(limits: &InstrumentRiskLimits, price: Price) -> Result<(), RiskDenialReason> {
+    validate_price(price, limits.price_increment)?;
+    if price.raw <= 0 {
+        return Err(RiskDenialReason::PriceNotPositive);
+    }
+    Ok(())
+}
+
+fn check_notional(
+    limits: &InstrumentRiskLimits,
+    quantity: Quantity,
+    price: Price,
+) -> Result<(), RiskDenialReason> {
+    let Ok(notional) = calculate_notional(quantity, price, limits.multiplier, limits.is_inverse)
+    else {
+        return Ok(()); // Inverse notional undefined for a zero price (denied by price checks)
+    };
+    validate_notional(
+        notional,
+        limits.notional_currency,
+        limits.min_notional,
+        limits.max_notional,
+    )?;
+    validate_notional(
+        notional,
+        limits.notional_currency,
+        None,
+        limits.max_notional_per_order,
+    )
+    .map_err(|violation| match violation {
+        InstrumentViolation::NotionalAboveMax => RiskDenialReason::NotionalExceedsLimit,
+        violation => violation.into(),
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use nautilus_model::{
+        events::order::OrderInitialized,
+        instruments::{currency_pair::CurrencyPair, stubs::currency_pair_ethusdt},
+    };
+    use rstest::{fixture, rstest};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[fixture]
+    fn checker(currency_pair_ethusdt: CurrencyPair) -> RiskChecker {
+        let mut limits = InstrumentRiskLimits::from_instrument(&currency_pair_ethusdt)
+            .with_max_order_quantity(Quantity::from("100"))
+            .with_max_notional_per_order(Money::from("100000 USDT"))
+            .with_price_collar(dec!(0.05));
+        limits.price_increment = Price::from("0.05");
+        limits.size_increment = Quantity::from("0.00100");
+        limits.min_quantity = Some(Quantity::from("0.001"));
+        limits.min_notional = Some(Money::from("10 USDT"));
+        limits.max_notional = Some(Money::from("150000 USDT"));
+
+        let mut checker = RiskChecker::new(TradingState::Active, Some(3));
+        checker.set_limits(currency_pair_ethusdt.id, limits);
+        checker
+    }
+
+    fn check(
+        checker: &RiskChecker,
+        order_side: OrderSide,
+        quantity: &str,
+        price: Option<&str>,
+        position_side: PositionSide,
+        position_quantity: &str,
+        open_positions: usize,
+    ) -> Result<(), RiskDenialReason> {
+        checker.check_order(
+            &InstrumentId::from("ETHUSDT.BINANCE"),
+            order_side,
+            Quantity::from(quantity),
+            price.map(Price::from),
+            Some(Price::from("2000.00")),
+            position_side,
+            Quantity::from(position_quantity),
+            open_positions,
+        )
+    }
+
+    #[rstest]
+    fn test_from_instrument(currency_pair_ethusdt: CurrencyPair) {
+        let limits = InstrumentRiskLimits::from_instrument(&currency_pair_ethusdt);
+
+        assert_eq!(limits.price_increment, Price::from("0.01"));
+        assert_eq!(limits.size_increment, Quantity::from("0.00001"));
+        assert_eq!(limits.min_quantity, Some(Quantity::from("0.00001")));
+        assert_eq!(limits.max_quantity, Some(Quantity::from("9000")));
+        assert_eq!(limits.notional_currency, Currency::USDT());
+        assert_eq!(limits.max_order_quantity, None);
+        assert_eq!(limits.max_notional_per_order, None);
+        assert_eq!(limits.price_collar, None);
+    }
+
+    #[rstest]
+    #[case("1.000", Some("2000.00"))]
+    #[case("50", Some("1999.95"))]
+    #[case("1.000", None)]
+    fn test_check_order_passes(
+        checker: RiskChecker,
+        #[case] quantity: &str,
+        #[case] price: Option<&str>,
+    ) {
+        let result = check(
+            &checker,
+            OrderSide::Buy,
+            quantity,
+            price,
+            PositionSide::Flat,
+            "0",
+            0,
+        );
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[rstest]
+    #[case("1.000001", Some("2000.00"), RiskDenialReason::QuantityPrecision)]
+    #[case("1.0005", Some("2000.00"), RiskDenialReason::QuantityIncrement)]
+    #[case("0", Some("2000.00"), RiskDenialReason::QuantityBelowMin)]
+    #[case("9001", Some("2000.00"), RiskDenialReason::QuantityAboveMax)]
+    #[case("101", Some("2000.00"), RiskDenialReason::QuantityExceedsLimit)]
+    #[case("1.000", Some("2000.001"), RiskDenialReason::PricePrecision)]
+    #[case("1.000", Some("2000.01"), RiskDenialReason::PriceIncrement)]
+    #[case("1.000", Some("0.00"), RiskDenialReason::PriceNotPositive)]
+    #[case("1.000", Some("-2000.00"), RiskDenialReason::PriceNotPositive)]
+    #[case("1.000", Some("2100.05"), RiskDenialReason::PriceOutsideCollar)]
+    #[case("1.000", Some("1899.95"), RiskDenialReason::PriceOutsideCollar)]
+    #[case("0.001", Some("2000.00"), RiskDenialReason::NotionalBelowMin)]
+    #[case("90", Some("2000.00"), RiskDenialReason::NotionalAboveMax)]
+    #[case("60", Some("2000.00"), RiskDenialReason::NotionalExceedsLimit)]
+    #[case("60", None, RiskDenialReason::NotionalExceedsLimit)]
+    fn test_check_order_denied(
+        checker: RiskChecker,
+        #[case] quantity: &str,
+        #[case] price: Option<&str>,
+        #[case] expected: RiskDenialReason,
+    ) {
+        let result = check(
+            &checker,
+            OrderSide::Buy,
+            quantity,
+            price,
+            PositionSide::Flat,
+            "0",
+            0,
+        );
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[rstest]
+    fn test_check_order_notional_currency_mismatch(mut checker: RiskChecker) {
+        let instrument_id = InstrumentId::from("ETHUSDT.BINANCE");
+        let mut limits = checker.limits(&instrument_id).unwrap().clone();
+        limits.max_notional_per_order = Some(Money::from("100000 USD"));
+        checker.set_limits(instrument_id, limits);
+
+        let result = check(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            Some("2000.00"),
+            PositionSide::Flat,
+            "0",
+            0,
+        );
+
+        assert_eq!(result, Err(RiskDenialReason::NotionalCurrencyMismatch));
+    }
+
+    #[rstest]
+    fn test_check_order_unknown_instrument(checker: RiskChecker) {
+        let result = checker.check_order(
+            &InstrumentId::from("AUD/USD.SIM"),
+            OrderSide::Buy,
+            Quantity::from("1"),
+            None,
+            None,
+            PositionSide::Flat,
+            Quantity::from("0"),
+            0,
+        );
+
+        assert_eq!(result, Err(RiskDenialReason::UnknownInstrument));
+    }
+
+    #[rstest]
+    fn test_check_order_trading_halted(mut checker: RiskChecker) {
+        checker.trading_state = TradingState::Halted;
+
+        let result = check(
+            &checker,
+            OrderSide::Sell,
+            "1.000",
+            Some("2000.00"),
+            PositionSide::Long,
+            "10",
+            1,
+        );
+
+        assert_eq!(result, Err(RiskDenialReason::TradingHalted));
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, PositionSide::Long, "10", "10", Ok(()))]
+    #[case(OrderSide::Buy, PositionSide::Short, "10", "5", Ok(()))]
+    #[case(
+        OrderSide::Buy,
+        PositionSide::Long,
+        "10",
+        "1",
+        Err(RiskDenialReason::TradingReducing)
+    )]
+    #[case(
+        OrderSide::Sell,
+        PositionSide::Short,
+        "10",
+        "1",
+        Err(RiskDenialReason::TradingReducing)
+    )]
+    #[case(
+        OrderSide::Buy,
+        PositionSide::Flat,
+        "0",
+        "1",
+        Err(RiskDenialReason::TradingReducing)
+    )]
+    #[case(
+        OrderSide::Sell,
+        PositionSide::Long,
+        "10",
+        "11",
+        Err(RiskDenialReason::TradingReducing)
+    )]
+    fn test_check_order_trading_reducing(
+        mut checker: RiskChecker,
+        #[case] order_side: OrderSide,
+        #[case] position_side: PositionSide,
+        #[case] position_quantity: &str,
+        #[case] quantity: &str,
+        #[case] expected: Result<(), RiskDenialReason>,
+    ) {
+        checker.trading_state = TradingState::Reducing;
+
+        let result = check(
+            &checker,
+            order_side,
+            quantity,
+            Some("2000.00"),
+            position_side,
+            position_quantity,
+            1,
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(PositionSide::Flat, 3, Err(RiskDenialReason::MaxOpenPositions))]
+    #[case(PositionSide::Flat, 2, Ok(()))]
+    #[case(PositionSide::Long, 3, Ok(()))]
+    fn test_check_order_max_open_positions(
+        checker: RiskChecker,
+        #[case] position_side: PositionSide,
+        #[case] open_positions: usize,
+        #[case] expected: Result<(), RiskDenialReason>,
+    ) {
+        let result = check(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            Some("2000.00"),
+            position_side,
+            "1",
+            open_positions,
+        );
+
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    fn test_to_order_denied() {
+        let order = OrderAny::from(OrderInitialized::default());
+        let event_id = UUID4::new();
+
+        let denied = RiskDenialReason::NotionalExceedsLimit.to_order_denied(
+            &order,
+            event_id,
+            UnixNanos::from(1),
+        );
+
+        assert_eq!(denied.client_order_id, order.client_order_id());
+        assert_eq!(denied.reason, Ustr::from("NOTIONAL_EXCEEDS_LIMIT"));
+        assert_eq!(denied.event_id, event_id);
+        assert_eq!(denied.ts_init, UnixNanos::from(1));
+    }
+}
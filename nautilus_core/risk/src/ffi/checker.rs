@@ -0,0 +1,336 @@
+// -------------------------------------------------------------------------------------------------
+//  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+//  https://nautechsystems.io
+//
+//  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+//  You may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+// -------------------------------------------------------------------------------------------------
+
+use std::{
+    ffi::c_char,
+    ops::{Deref, DerefMut},
+};
+
+use nautilus_core::ffi::{parsing::u8_as_bool, string::str_to_cstr};
+use nautilus_model::{
+    enums::{OrderSide, PositionSide, TradingState},
+    identifiers::InstrumentId,
+    types::{currency::Currency, money::Money, price::Price, quantity::Quantity},
+};
+use rust_decimal::Decimal;
+
+use crate::checker::{InstrumentRiskLimits, RiskChecker, RiskDenialReason};
+
+/// C compatible Foreign Function Interface (FFI) for an underlying [`RiskChecker`].
+///
+/// This struct wraps `RiskChecker` in a way that makes it compatible with C function
+/// calls, enabling interaction with `RiskChecker` in a C environment.
+///
+/// It implements the `Deref` trait, allowing instances of `RiskChecker_API` to be
+/// dereferenced to `RiskChecker`, providing access to `RiskChecker`'s methods without
+/// having to manually access the underlying `RiskChecker` instance.
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct RiskChecker_API(Box<RiskChecker>);
+
+impl Deref for RiskChecker_API {
+    type Target = RiskChecker;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for RiskChecker_API {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Creates a new risk checker.
+///
+/// # Safety
+///
+/// - Assumes `max_open_positions` is either null (no limit) or a valid `usize` pointer.
+#[no_mangle]
+pub unsafe extern "C" fn risk_checker_new(
+    trading_state: TradingState,
+    max_open_positions: *const usize,
+) -> RiskChecker_API {
+    RiskChecker_API(Box::new(RiskChecker::new(
+        trading_state,
+        max_open_positions.as_ref().copied(),
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn risk_checker_drop(checker: RiskChecker_API) {
+    drop(checker); // Memory freed here
+}
+
+#[no_mangle]
+pub extern "C" fn risk_checker_trading_state(checker: &RiskChecker_API) -> TradingState {
+    checker.trading_state
+}
+
+#[no_mangle]
+pub extern "C" fn risk_checker_set_trading_state(
+    checker: &mut RiskChecker_API,
+    trading_state: TradingState,
+) {
+    checker.trading_state = trading_state;
+}
+
+/// Sets the risk limits for the given `instrument_id`, replacing any existing limits.
+///
+/// Pass a `price_collar` of NaN for no price collar.
+///
+/// # Safety
+///
+/// - Assumes each of the optional limit pointers is either null (no limit) or a valid pointer.
+///
+/// # Panics
+///
+/// This function panics:
+/// - If `price_collar` is not NaN and cannot be represented as a `Decimal`.
+#[no_mangle]
+pub unsafe extern "C" fn risk_checker_set_limits(
+    checker: &mut RiskChecker_API,
+    instrument_id: InstrumentId,
+    price_increment: Price,
+    size_increment: Quantity,
+    multiplier: Quantity,
+    is_inverse: u8,
+    notional_currency: Currency,
+    min_quantity: *const Quantity,
+    max_quantity: *const Quantity,
+    min_notional: *const Money,
+    max_notional: *const Money,
+    max_order_quantity: *const Quantity,
+    max_notional_per_order: *const Money,
+    price_collar: f64,
+) {
+    let price_collar = (!price_collar.is_nan()).then(|| {
+        Decimal::try_from(price_collar)
+            .unwrap_or_else(|e| panic!("Invalid `price_collar`, was {price_collar}: {e}"))
+    });
+    let limits = InstrumentRiskLimits {
+        price_increment,
+        size_increment,
+        multiplier,
+        is_inverse: u8_as_bool(is_inverse),
+        notional_currency,
+        min_quantity: min_quantity.as_ref().copied(),
+        max_quantity: max_quantity.as_ref().copied(),
+        min_notional: min_notional.as_ref().copied(),
+        max_notional: max_notional.as_ref().copied(),
+        max_order_quantity: max_order_quantity.as_ref().copied(),
+        max_notional_per_order: max_notional_per_order.as_ref().copied(),
+        price_collar,
+    };
+    checker.set_limits(instrument_id, limits);
+}
+
+/// Checks an order against the pre-trade risk limits, returning 1 if the order passes,
+/// otherwise 0 with the denial reason written to `reason`.
+///
+/// # Safety
+///
+/// - Assumes `price` and `last_price` are each either null (no price) or a valid `Price` pointer.
+/// - Assumes `reason` is a valid pointer to write the denial reason to.
+#[no_mangle]
+pub unsafe extern "C" fn risk_checker_check_order(
+    checker: &RiskChecker_API,
+    instrument_id: InstrumentId,
+    order_side: OrderSide,
+    quantity: Quantity,
+    price: *const Price,
+    last_price: *const Price,
+    position_side: PositionSide,
+    position_quantity: Quantity,
+    open_positions: usize,
+    reason: *mut RiskDenialReason,
+) -> u8 {
+    match checker.check_order(
+        &instrument_id,
+        order_side,
+        quantity,
+        price.as_ref().copied(),
+        last_price.as_ref().copied(),
+        position_side,
+        position_quantity,
+        open_positions,
+    ) {
+        Ok(()) => 1,
+        Err(denial) => {
+            *reason = denial;
+            0
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn risk_denial_reason_to_cstr(value: RiskDenialReason) -> *const c_char {
+    str_to_cstr(value.as_ref())
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Tests
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(test)]
+mod tests {
+    use std::{ffi::CStr, ptr};
+
+    use nautilus_core::ffi::string::cstr_drop;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn checker() -> RiskChecker_API {
+        let max_open_positions = 1;
+        let max_order_quantity = Quantity::from("100");
+        let mut checker = unsafe { risk_checker_new(TradingState::Active, &max_open_positions) };
+        unsafe {
+            risk_checker_set_limits(
+                &mut checker,
+                InstrumentId::from("ETHUSDT.BINANCE"),
+                Price::from("0.01"),
+                Quantity::from("0.001"),
+                Quantity::from("1"),
+                0,
+                Currency::USDT(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+                &max_order_quantity,
+                ptr::null(),
+                0.05,
+            );
+        }
+        checker
+    }
+
+    fn check_order(
+        checker: &RiskChecker_API,
+        order_side: OrderSide,
+        quantity: &str,
+        price: &str,
+        position_side: PositionSide,
+        open_positions: usize,
+    ) -> (u8, RiskDenialReason) {
+        let price = Price::from(price);
+        let last_price = Price::from("2000.00");
+        let mut reason = RiskDenialReason::TradingHalted;
+        let passed = unsafe {
+            risk_checker_check_order(
+                checker,
+                InstrumentId::from("ETHUSDT.BINANCE"),
+                order_side,
+                Quantity::from(quantity),
+                &price,
+                &last_price,
+                position_side,
+                Quantity::from("1"),
+                open_positions,
+                &mut reason,
+            )
+        };
+        (passed, reason)
+    }
+
+    #[rstest]
+    fn test_risk_checker_check_order() {
+        let checker = checker();
+
+        let (passed, _) = check_order(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            "2000.00",
+            PositionSide::Flat,
+            0,
+        );
+        assert_eq!(passed, 1);
+
+        let (passed, reason) = check_order(
+            &checker,
+            OrderSide::Buy,
+            "101",
+            "2000.00",
+            PositionSide::Flat,
+            0,
+        );
+        assert_eq!(passed, 0);
+        assert_eq!(reason, RiskDenialReason::QuantityExceedsLimit);
+
+        let (passed, reason) = check_order(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            "2200.00",
+            PositionSide::Flat,
+            0,
+        );
+        assert_eq!(passed, 0);
+        assert_eq!(reason, RiskDenialReason::PriceOutsideCollar);
+
+        let (passed, reason) = check_order(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            "2000.00",
+            PositionSide::Flat,
+            1,
+        );
+        assert_eq!(passed, 0);
+        assert_eq!(reason, RiskDenialReason::MaxOpenPositions);
+        risk_checker_drop(checker);
+    }
+
+    #[rstest]
+    fn test_risk_checker_set_trading_state() {
+        let mut checker = checker();
+
+        risk_checker_set_trading_state(&mut checker, TradingState::Reducing);
+        assert_eq!(risk_checker_trading_state(&checker), TradingState::Reducing);
+
+        let (passed, reason) = check_order(
+            &checker,
+            OrderSide::Buy,
+            "1.000",
+            "2000.00",
+            PositionSide::Long,
+            1,
+        );
+        assert_eq!(passed, 0);
+        assert_eq!(reason, RiskDenialReason::TradingReducing);
+
+        let (passed, _) = check_order(
+            &checker,
+            OrderSide::Sell,
+            "1.000",
+            "2000.00",
+            PositionSide::Long,
+            1,
+        );
+        assert_eq!(passed, 1);
+        risk_checker_drop(checker);
+    }
+
+    #[rstest]
+    fn test_risk_denial_reason_to_cstr() {
+        let ptr = risk_denial_reason_to_cstr(RiskDenialReason::PriceOutsideCollar);
+        let value = unsafe { CStr::from_ptr(ptr) }.to_str().unwrap().to_string();
+        unsafe { cstr_drop(ptr) };
+
+        assert_eq!(value, "PRICE_OUTSIDE_COLLAR");
+    }
+}
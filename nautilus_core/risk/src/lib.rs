@@ -27,5 +27,9 @@
 //! - `ffi`: Enables the C foreign function interface (FFI) from `cbindgen`.
 //! - `python`: Enables Python bindings from `pyo3`.
 
+pub mod checker;
 pub mod engine;
 pub mod sizing;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
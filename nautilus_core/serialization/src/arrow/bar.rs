@@ -30,7 +30,9 @@ use super::{
     extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_BAR_TYPE, KEY_PRICE_PRECISION,
     KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy,
+};
 
 impl ArrowSchemaProvider for Bar {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -120,9 +122,10 @@ impl EncodeToRecordBatch for Bar {
 }
 
 impl DecodeFromRecordBatch for Bar {
-    fn decode_batch(
+    fn decode_batch_with_policy(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+        _enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError> {
         let (bar_type, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
@@ -23,16 +23,18 @@ use arrow::{
 };
 use nautilus_model::{
     data::{delta::OrderBookDelta, order::BookOrder},
-    enums::{BookAction, FromU8, OrderSide},
+    enums::{BookAction, OrderSide},
     identifiers::InstrumentId,
     types::{price::Price, quantity::Quantity},
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    decode_enum, extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy,
+};
 
 impl ArrowSchemaProvider for OrderBookDelta {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -46,6 +48,7 @@ impl ArrowSchemaProvider for OrderBookDelta {
             Field::new("sequence", DataType::UInt64, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
+            Field::new("order_flags", DataType::UInt8, false),
         ];
 
         match metadata {
@@ -93,6 +96,7 @@ impl EncodeToRecordBatch for OrderBookDelta {
         let mut sequence_builder = UInt64Array::builder(data.len());
         let mut ts_event_builder = UInt64Array::builder(data.len());
         let mut ts_init_builder = UInt64Array::builder(data.len());
+        let mut order_flags_builder = UInt8Array::builder(data.len());
 
         for delta in data {
             action_builder.append_value(delta.action as u8);
@@ -104,6 +108,7 @@ impl EncodeToRecordBatch for OrderBookDelta {
             sequence_builder.append_value(delta.sequence);
             ts_event_builder.append_value(delta.ts_event.as_u64());
             ts_init_builder.append_value(delta.ts_init.as_u64());
+            order_flags_builder.append_value(delta.order.flags);
         }
 
         let action_array = action_builder.finish();
@@ -115,6 +120,7 @@ impl EncodeToRecordBatch for OrderBookDelta {
         let sequence_array = sequence_builder.finish();
         let ts_event_array = ts_event_builder.finish();
         let ts_init_array = ts_init_builder.finish();
+        let order_flags_array = order_flags_builder.finish();
 
         RecordBatch::try_new(
             Self::get_schema(Some(metadata.clone())).into(),
@@ -128,15 +134,17 @@ impl EncodeToRecordBatch for OrderBookDelta {
                 Arc::new(sequence_array),
                 Arc::new(ts_event_array),
                 Arc::new(ts_init_array),
+                Arc::new(order_flags_array),
             ],
         )
     }
 }
 
 impl DecodeFromRecordBatch for OrderBookDelta {
-    fn decode_batch(
+    fn decode_batch_with_policy(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+        enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError> {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
@@ -150,23 +158,23 @@ impl DecodeFromRecordBatch for OrderBookDelta {
         let sequence_values = extract_column::<UInt64Array>(cols, "sequence", 6, DataType::UInt64)?;
         let ts_event_values = extract_column::<UInt64Array>(cols, "ts_event", 7, DataType::UInt64)?;
         let ts_init_values = extract_column::<UInt64Array>(cols, "ts_init", 8, DataType::UInt64)?;
+        // Batches encoded before book order flags were added do not have the column
+        let order_flags_values = if cols.len() > 9 {
+            Some(extract_column::<UInt8Array>(
+                cols,
+                "order_flags",
+                9,
+                DataType::UInt8,
+            )?)
+        } else {
+            None
+        };
 
         let result: Result<Vec<Self>, EncodingError> = (0..record_batch.num_rows())
             .map(|i| {
-                let action_value = action_values.value(i);
-                let action = BookAction::from_u8(action_value).ok_or_else(|| {
-                    EncodingError::ParseError(
-                        stringify!(BookAction),
-                        format!("Invalid enum value, was {action_value}"),
-                    )
-                })?;
-                let side_value = side_values.value(i);
-                let side = OrderSide::from_u8(side_value).ok_or_else(|| {
-                    EncodingError::ParseError(
-                        stringify!(OrderSide),
-                        format!("Invalid enum value, was {side_value}"),
-                    )
-                })?;
+                let action =
+                    decode_enum::<BookAction>(action_values.value(i), "action", i, enum_policy)?;
+                let side = decode_enum::<OrderSide>(side_values.value(i), "side", i, enum_policy)?;
                 let price = Price::from_raw(price_values.value(i), price_precision);
                 let size = Quantity::from_raw(size_values.value(i), size_precision);
                 let order_id = order_id_values.value(i);
@@ -174,6 +182,7 @@ impl DecodeFromRecordBatch for OrderBookDelta {
                 let sequence = sequence_values.value(i);
                 let ts_event = ts_event_values.value(i).into();
                 let ts_init = ts_init_values.value(i).into();
+                let order_flags = order_flags_values.map_or(0, |values| values.value(i));
 
                 Ok(Self {
                     instrument_id,
@@ -183,6 +192,7 @@ impl DecodeFromRecordBatch for OrderBookDelta {
                         price,
                         size,
                         order_id,
+                        flags: order_flags,
                     },
                     flags,
                     sequence,
@@ -216,6 +226,11 @@ mod tests {
     use arrow::record_batch::RecordBatch;
     use rstest::rstest;
 
+    use nautilus_model::{
+        data::order::{BOOK_ORDER_FLAG_HIDDEN, BOOK_ORDER_FLAG_IMPLIED, BOOK_ORDER_FLAG_SYNTHETIC},
+        enums::RecordFlag,
+    };
+
     use super::*;
 
     #[rstest]
@@ -233,6 +248,7 @@ mod tests {
             Field::new("sequence", DataType::UInt64, false),
             Field::new("ts_event", DataType::UInt64, false),
             Field::new("ts_init", DataType::UInt64, false),
+            Field::new("order_flags", DataType::UInt8, false),
         ];
         let expected_schema = Schema::new_with_metadata(expected_fields, metadata);
         assert_eq!(schema, expected_schema);
@@ -251,6 +267,7 @@ mod tests {
         expected_map.insert("sequence".to_string(), "UInt64".to_string());
         expected_map.insert("ts_event".to_string(), "UInt64".to_string());
         expected_map.insert("ts_init".to_string(), "UInt64".to_string());
+        expected_map.insert("order_flags".to_string(), "UInt8".to_string());
         assert_eq!(schema_map, expected_map);
     }
 
@@ -267,6 +284,7 @@ mod tests {
                 price: Price::from("100.10"),
                 size: Quantity::from(100),
                 order_id: 1,
+                flags: 0,
             },
             flags: 0,
             sequence: 1,
@@ -282,6 +300,7 @@ mod tests {
                 price: Price::from("101.20"),
                 size: Quantity::from(200),
                 order_id: 2,
+                flags: 0,
             },
             flags: 1,
             sequence: 2,
@@ -302,8 +321,9 @@ mod tests {
         let sequence_values = columns[6].as_any().downcast_ref::<UInt64Array>().unwrap();
         let ts_event_values = columns[7].as_any().downcast_ref::<UInt64Array>().unwrap();
         let ts_init_values = columns[8].as_any().downcast_ref::<UInt64Array>().unwrap();
+        let order_flags_values = columns[9].as_any().downcast_ref::<UInt8Array>().unwrap();
 
-        assert_eq!(columns.len(), 9);
+        assert_eq!(columns.len(), 10);
         assert_eq!(action_values.len(), 2);
         assert_eq!(action_values.value(0), 1);
         assert_eq!(action_values.value(1), 2);
@@ -331,15 +351,72 @@ mod tests {
         assert_eq!(ts_init_values.len(), 2);
         assert_eq!(ts_init_values.value(0), 3);
         assert_eq!(ts_init_values.value(1), 4);
+        assert_eq!(order_flags_values.len(), 2);
+        assert_eq!(order_flags_values.value(0), 0);
+        assert_eq!(order_flags_values.value(1), 0);
     }
 
     #[rstest]
-    fn test_decode_batch() {
+    fn test_encode_decode_round_trip_with_flags() {
         let instrument_id = InstrumentId::from("AAPL.XNAS");
         let metadata = OrderBookDelta::get_metadata(&instrument_id, 2, 0);
+        let order = BookOrder::new(
+            OrderSide::Buy,
+            Price::from("100.10"),
+            Quantity::from(100),
+            1,
+        );
+        let data = vec![
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Add,
+                order.with_flags(BOOK_ORDER_FLAG_IMPLIED),
+                RecordFlag::F_SNAPSHOT as u8,
+                1,
+                1.into(),
+                3.into(),
+            ),
+            OrderBookDelta::new(
+                instrument_id,
+                BookAction::Update,
+                order.with_flags(BOOK_ORDER_FLAG_HIDDEN | BOOK_ORDER_FLAG_SYNTHETIC),
+                RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8,
+                2,
+                2.into(),
+                4.into(),
+            ),
+        ];
+
+        let record_batch = OrderBookDelta::encode_batch(&metadata, &data).unwrap();
+        let decoded = OrderBookDelta::decode_batch(&metadata, record_batch).unwrap();
+
+        assert_eq!(decoded, data);
+        assert_eq!(
+            decoded[1].flags,
+            RecordFlag::F_SNAPSHOT as u8 | RecordFlag::F_LAST as u8
+        );
+        assert!(decoded[1].order.has_flag(BOOK_ORDER_FLAG_HIDDEN));
+    }
+
+    #[rstest]
+    fn test_decode_batch_without_order_flags_column() {
+        let metadata = OrderBookDelta::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let record_batch = batch_with_actions_and_sides(&metadata, vec![1, 2], vec![1, 1]);
+        let legacy_batch = record_batch.project(&(0..9).collect::<Vec<_>>()).unwrap();
+
+        let decoded = OrderBookDelta::decode_batch(&metadata, legacy_batch).unwrap();
 
-        let action = UInt8Array::from(vec![1, 2]);
-        let side = UInt8Array::from(vec![1, 1]);
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(decoded[0].order.flags, 0);
+    }
+
+    fn batch_with_actions_and_sides(
+        metadata: &HashMap<String, String>,
+        action: Vec<u8>,
+        side: Vec<u8>,
+    ) -> RecordBatch {
+        let action = UInt8Array::from(action);
+        let side = UInt8Array::from(side);
         let price = Int64Array::from(vec![100_100_000_000, 100_100_000_000]);
         let size = UInt64Array::from(vec![10000, 9000]);
         let order_id = UInt64Array::from(vec![1, 2]);
@@ -347,8 +424,9 @@ mod tests {
         let sequence = UInt64Array::from(vec![1, 2]);
         let ts_event = UInt64Array::from(vec![1, 2]);
         let ts_init = UInt64Array::from(vec![3, 4]);
+        let order_flags = UInt8Array::from(vec![0, 0]);
 
-        let record_batch = RecordBatch::try_new(
+        RecordBatch::try_new(
             OrderBookDelta::get_schema(Some(metadata.clone())).into(),
             vec![
                 Arc::new(action),
@@ -360,11 +438,51 @@ mod tests {
                 Arc::new(sequence),
                 Arc::new(ts_event),
                 Arc::new(ts_init),
+                Arc::new(order_flags),
             ],
         )
-        .unwrap();
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_decode_batch() {
+        let instrument_id = InstrumentId::from("AAPL.XNAS");
+        let metadata = OrderBookDelta::get_metadata(&instrument_id, 2, 0);
+        let record_batch = batch_with_actions_and_sides(&metadata, vec![1, 2], vec![1, 1]);
 
         let decoded_data = OrderBookDelta::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    #[rstest]
+    #[case(UnknownEnumPolicy::Strict)]
+    #[case(UnknownEnumPolicy::Lenient)]
+    fn test_decode_batch_with_unknown_action(#[case] enum_policy: UnknownEnumPolicy) {
+        let metadata = OrderBookDelta::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let record_batch = batch_with_actions_and_sides(&metadata, vec![9, 2], vec![1, 1]);
+
+        let err = OrderBookDelta::decode_batch_with_policy(&metadata, record_batch, enum_policy)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unknown `BookAction` value, was 9 in column `action` at row 0"
+        );
+    }
+
+    #[rstest]
+    #[case(UnknownEnumPolicy::Strict)]
+    #[case(UnknownEnumPolicy::Lenient)]
+    fn test_decode_batch_with_unknown_side(#[case] enum_policy: UnknownEnumPolicy) {
+        let metadata = OrderBookDelta::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let record_batch = batch_with_actions_and_sides(&metadata, vec![1, 2], vec![1, 7]);
+
+        let err = OrderBookDelta::decode_batch_with_policy(&metadata, record_batch, enum_policy)
+            .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unknown `OrderSide` value, was 7 in column `side` at row 1"
+        );
+    }
 }
@@ -35,7 +35,9 @@ use super::{
     extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy,
+};
 
 impl ArrowSchemaProvider for OrderBookDepth10 {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -226,9 +228,10 @@ impl EncodeToRecordBatch for OrderBookDepth10 {
 }
 
 impl DecodeFromRecordBatch for OrderBookDepth10 {
-    fn decode_batch(
+    fn decode_batch_with_policy(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+        _enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError> {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
@@ -33,9 +33,12 @@ use arrow::{
     ipc::writer::StreamWriter,
     record_batch::RecordBatch,
 };
-use nautilus_model::data::{
-    bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick, trade::TradeTick,
-    Data,
+use nautilus_model::{
+    data::{
+        bar::Bar, delta::OrderBookDelta, depth::OrderBookDepth10, quote::QuoteTick,
+        trade::TradeTick, Data,
+    },
+    enums::{FromU8, UnknownEnumValue},
 };
 use pyo3::prelude::*;
 
@@ -67,6 +70,12 @@ pub enum EncodingError {
     ParseError(&'static str, String),
     #[error("Invalid column type `{0}` at index {1}: expected {2}, found {3}")]
     InvalidColumnType(&'static str, usize, DataType, DataType),
+    #[error("{error} in column `{column}` at row {row}")]
+    UnknownEnumValue {
+        column: &'static str,
+        row: usize,
+        error: UnknownEnumValue,
+    },
     #[error("Arrow error: {0}")]
     ArrowError(#[from] arrow::error::ArrowError),
 }
@@ -97,6 +106,17 @@ where
     ) -> Result<RecordBatch, ArrowError>;
 }
 
+/// How enum discriminants with no known variant are handled when decoding.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum UnknownEnumPolicy {
+    /// Unknown discriminants are an [`EncodingError::UnknownEnumValue`] error.
+    #[default]
+    Strict,
+    /// Unknown discriminants are decoded as the enum's [`FromU8::unknown_fallback`] variant, or
+    /// are an error if the enum has no safe fallback.
+    Lenient,
+}
+
 pub trait DecodeFromRecordBatch
 where
     Self: Sized + Into<Data> + ArrowSchemaProvider,
@@ -104,6 +124,14 @@ where
     fn decode_batch(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+    ) -> Result<Vec<Self>, EncodingError> {
+        Self::decode_batch_with_policy(metadata, record_batch, UnknownEnumPolicy::Strict)
+    }
+
+    fn decode_batch_with_policy(
+        metadata: &HashMap<String, String>,
+        record_batch: RecordBatch,
+        enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError>;
 }
 
@@ -152,6 +180,22 @@ pub fn extract_column<'a, T: Array + 'static>(
     Ok(downcasted_values)
 }
 
+/// Decodes the enum discriminant `value` from row `row` of `column`, applying the `policy` for
+/// unknown discriminants.
+pub fn decode_enum<T: FromU8>(
+    value: u8,
+    column: &'static str,
+    row: usize,
+    policy: UnknownEnumPolicy,
+) -> Result<T, EncodingError> {
+    T::try_from_u8(value)
+        .or_else(|e| match policy {
+            UnknownEnumPolicy::Strict => Err(e),
+            UnknownEnumPolicy::Lenient => T::unknown_fallback().ok_or(e),
+        })
+        .map_err(|error| EncodingError::UnknownEnumValue { column, row, error })
+}
+
 pub fn order_book_deltas_to_arrow_record_batch_bytes(
     data: Vec<OrderBookDelta>,
 ) -> Result<RecordBatch, EncodingError> {
@@ -31,7 +31,9 @@ use super::{
     extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy,
+};
 
 impl ArrowSchemaProvider for QuoteTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -118,9 +120,10 @@ impl EncodeToRecordBatch for QuoteTick {
 }
 
 impl DecodeFromRecordBatch for QuoteTick {
-    fn decode_batch(
+    fn decode_batch_with_policy(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+        _enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError> {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
@@ -29,10 +29,12 @@ use nautilus_model::{
 };
 
 use super::{
-    extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
+    decode_enum, extract_column, DecodeDataFromRecordBatch, EncodingError, KEY_INSTRUMENT_ID,
     KEY_PRICE_PRECISION, KEY_SIZE_PRECISION,
 };
-use crate::arrow::{ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch};
+use crate::arrow::{
+    ArrowSchemaProvider, Data, DecodeFromRecordBatch, EncodeToRecordBatch, UnknownEnumPolicy,
+};
 
 impl ArrowSchemaProvider for TradeTick {
     fn get_schema(metadata: Option<HashMap<String, String>>) -> Schema {
@@ -119,9 +121,10 @@ impl EncodeToRecordBatch for TradeTick {
 }
 
 impl DecodeFromRecordBatch for TradeTick {
-    fn decode_batch(
+    fn decode_batch_with_policy(
         metadata: &HashMap<String, String>,
         record_batch: RecordBatch,
+        enum_policy: UnknownEnumPolicy,
     ) -> Result<Vec<Self>, EncodingError> {
         let (instrument_id, price_precision, size_precision) = parse_metadata(metadata)?;
         let cols = record_batch.columns();
@@ -138,14 +141,12 @@ impl DecodeFromRecordBatch for TradeTick {
             .map(|i| {
                 let price = Price::from_raw(price_values.value(i), price_precision);
                 let size = Quantity::from_raw(size_values.value(i), size_precision);
-                let aggressor_side_value = aggressor_side_values.value(i);
-                let aggressor_side = AggressorSide::from_repr(aggressor_side_value as usize)
-                    .ok_or_else(|| {
-                        EncodingError::ParseError(
-                            stringify!(AggressorSide),
-                            format!("Invalid enum value, was {aggressor_side_value}"),
-                        )
-                    })?;
+                let aggressor_side = decode_enum::<AggressorSide>(
+                    aggressor_side_values.value(i),
+                    "aggressor_side",
+                    i,
+                    enum_policy,
+                )?;
                 let trade_id = TradeId::from(trade_id_values.value(i));
                 let ts_event = ts_event_values.value(i).into();
                 let ts_init = ts_init_values.value(i).into();
@@ -308,4 +309,57 @@ mod tests {
         let decoded_data = TradeTick::decode_batch(&metadata, record_batch).unwrap();
         assert_eq!(decoded_data.len(), 2);
     }
+
+    fn batch_with_aggressor_sides(
+        metadata: &HashMap<String, String>,
+        aggressor_sides: Vec<u8>,
+    ) -> RecordBatch {
+        let len = aggressor_sides.len();
+        RecordBatch::try_new(
+            TradeTick::get_schema(Some(metadata.clone())).into(),
+            vec![
+                Arc::new(Int64Array::from(vec![1_000_000_000_000; len])),
+                Arc::new(UInt64Array::from(vec![1000; len])),
+                Arc::new(UInt8Array::from(aggressor_sides)),
+                Arc::new(StringArray::from(vec!["1"; len])),
+                Arc::new(UInt64Array::from(vec![1; len])),
+                Arc::new(UInt64Array::from(vec![2; len])),
+            ],
+        )
+        .unwrap()
+    }
+
+    #[rstest]
+    fn test_decode_batch_with_unknown_aggressor_side() {
+        let metadata = TradeTick::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let record_batch = batch_with_aggressor_sides(&metadata, vec![1, 9]);
+
+        let err = TradeTick::decode_batch(&metadata, record_batch).unwrap_err();
+
+        match err {
+            EncodingError::UnknownEnumValue { column, row, error } => {
+                assert_eq!(column, "aggressor_side");
+                assert_eq!(row, 1);
+                assert_eq!(error.enum_name, "AggressorSide");
+                assert_eq!(error.value, 9);
+            }
+            _ => panic!("Expected an unknown enum value error, was {err}"),
+        }
+    }
+
+    #[rstest]
+    fn test_decode_batch_with_unknown_aggressor_side_when_lenient() {
+        let metadata = TradeTick::get_metadata(&InstrumentId::from("AAPL.XNAS"), 2, 0);
+        let record_batch = batch_with_aggressor_sides(&metadata, vec![1, 9]);
+
+        let decoded_data = TradeTick::decode_batch_with_policy(
+            &metadata,
+            record_batch,
+            UnknownEnumPolicy::Lenient,
+        )
+        .unwrap();
+
+        assert_eq!(decoded_data[0].aggressor_side, AggressorSide::Buyer);
+        assert_eq!(decoded_data[1].aggressor_side, AggressorSide::NoAggressor);
+    }
 }
@@ -156,6 +156,9 @@ cpdef LogGuard init_logging(
     bint colors=*,
     bint bypass=*,
     bint print_config=*,
+    dict rate_limits=*,
+    dict component_colors=*,
+    list file_sinks=*,
 )
 
 # Global static to flag if pyo3 based logging is initialized
@@ -57,6 +57,7 @@ from nautilus_trader.core.rust.common cimport ComponentTrigger
 from nautilus_trader.core.rust.common cimport LogColor
 from nautilus_trader.core.rust.common cimport LogGuard_API
 from nautilus_trader.core.rust.common cimport LogLevel
+from nautilus_trader.core.rust.common cimport LogStatus
 from nautilus_trader.core.rust.common cimport TimeEventHandler_t
 from nautilus_trader.core.rust.common cimport component_state_from_cstr
 from nautilus_trader.core.rust.common cimport component_state_to_cstr
@@ -79,6 +80,7 @@ from nautilus_trader.core.rust.common cimport log_color_from_cstr
 from nautilus_trader.core.rust.common cimport log_color_to_cstr
 from nautilus_trader.core.rust.common cimport log_level_from_cstr
 from nautilus_trader.core.rust.common cimport log_level_to_cstr
+from nautilus_trader.core.rust.common cimport log_status_to_cstr
 from nautilus_trader.core.rust.common cimport logger_drop
 from nautilus_trader.core.rust.common cimport logger_log
 from nautilus_trader.core.rust.common cimport logging_clock_set_realtime_mode
@@ -1061,6 +1063,9 @@ cpdef LogGuard init_logging(
     bint colors = True,
     bint bypass = False,
     bint print_config = False,
+    dict rate_limits: dict[str, int] = None,
+    dict component_colors: dict[str, str] = None,
+    list file_sinks: list[dict[str, str]] = None,
 ):
     """
     Initialize the logging system.
@@ -1089,9 +1094,9 @@ cpdef LogGuard init_logging(
     file_name : str, optional
         The custom log file name (will use a '.log' suffix for plain text or '.json' for JSON).
         If ``None`` will not log to a file (unless `file_auto` is True).
-    file_format : str { 'JSON' }, optional
+    file_format : str { 'JSON', 'CSV' }, optional
         The log file format. If ``None`` (default) then will log in plain text.
-        If set to 'JSON' then logs will be in JSON format.
+        If set to 'JSON' then logs will be in JSON format, or if 'CSV' then CSV format.
     component_levels : dict[ComponentId, LogLevel]
         The additional per component log level filters, where keys are component
         IDs (e.g. actor/strategy IDs) and values are log levels.
@@ -1101,6 +1106,17 @@ cpdef LogGuard init_logging(
         If the output for the core logging system is bypassed (useful for logging tests).
     print_config : bool, default False
         If the core logging configuration should be printed to stdout on initialization.
+    rate_limits : dict[str, int], optional
+        The maximum number of identical messages per second for each component, where keys
+        are component IDs and values are limits. The 'default' key sets the limit for all
+        other components. If ``None`` then messages are not rate limited.
+    component_colors : dict[str, str], optional
+        The fixed color for each component's log messages, where keys are component IDs and
+        values are log color names (e.g. 'CYAN'). Only applies to messages logged with the
+        default color below warning level.
+    file_sinks : list[dict[str, str]], optional
+        The additional log file sinks, each with its own 'level' and optional 'directory',
+        'file_name' and 'file_format' keys. Every sink filters messages independently.
 
     Returns
     -------
@@ -1110,6 +1126,8 @@ cpdef LogGuard init_logging(
     ------
     RuntimeError
         If the logging system has already been initialized.
+    RuntimeError
+        If the logging configuration is invalid, or a log file cannot be opened.
 
     """
     if trader_id is None:
@@ -1122,6 +1140,7 @@ cpdef LogGuard init_logging(
     if logging_is_initialized():
         raise RuntimeError("Logging system already initialized")
 
+    cdef LogStatus status = LogStatus.OK
     cdef LogGuard_API log_guard_api = logging_init(
         trader_id._mem,
         instance_id._mem,
@@ -1131,11 +1150,21 @@ cpdef LogGuard init_logging(
         pystr_to_cstr(file_name) if file_name else NULL,
         pystr_to_cstr(file_format) if file_format else NULL,
         pybytes_to_cstr(msgspec.json.encode(component_levels)) if component_levels else NULL,
+        pybytes_to_cstr(msgspec.json.encode(rate_limits)) if rate_limits else NULL,
+        pybytes_to_cstr(msgspec.json.encode(component_colors)) if component_colors else NULL,
+        pybytes_to_cstr(msgspec.json.encode(file_sinks)) if file_sinks else NULL,
         colors,
         bypass,
         print_config,
+        &status,
     )
 
+    if status != LogStatus.OK:
+        logger_drop(log_guard_api)
+        raise RuntimeError(
+            f"Error initializing logging: {cstr_to_pystr(log_status_to_cstr(status))}",
+        )
+
     cdef LogGuard log_guard = LogGuard.__new__(LogGuard)
     log_guard._mem = log_guard_api
     return log_guard
@@ -489,15 +489,30 @@ class LoggingConfig(NautilusConfig, frozen=True):
         The path to the log file directory.
         If ``None`` then will write to the current working directory.
     log_file_name : str, optional
-        The custom log file name (will use a '.log' suffix for plain text or '.json' for JSON).
+        The custom log file name (will use a '.log' suffix for plain text, '.json' for JSON
+        or '.csv' for CSV).
         This will override automatic naming, and no daily file rotation will occur.
-    log_file_format : str { 'JSON' }, optional
+    log_file_format : str { 'JSON', 'CSV' }, optional
         The log file format. If ``None`` (default) then will log in plain text.
+        CSV files start with a ``timestamp,level,component,message`` header row.
     log_colors : bool, default True
         If ANSI codes should be used to produce colored log lines.
     log_component_levels : dict[str, LogLevel]
         The additional per component log level filters, where keys are component
-        IDs (e.g. actor/strategy IDs) and values are log levels.
+        IDs (e.g. actor/strategy IDs) and values are log levels. A level also applies
+        to dotted child components (e.g. `DataEngine` to `DataEngine.Binance`) without
+        a level of their own.
+    log_rate_limits : dict[str, int], optional
+        The maximum number of identical messages per second for each component, where keys
+        are component IDs and values are limits. The 'default' key sets the limit for all
+        other components. If ``None`` then messages are not rate limited.
+    log_component_colors : dict[str, str], optional
+        The fixed color for each component's log messages, where keys are component IDs and
+        values are log color names (e.g. 'CYAN'). Only applies to messages logged with the
+        default color below warning level.
+    log_file_sinks : list[dict[str, str]], optional
+        The additional log file sinks, each with its own 'level' and optional 'directory',
+        'file_name' and 'file_format' keys. Every sink filters messages independently.
     bypass_logging : bool, default False
         If all logging should be bypassed.
     print_config : bool, default False
@@ -519,6 +534,9 @@ class LoggingConfig(NautilusConfig, frozen=True):
     log_file_format: str | None = None
     log_colors: bool = True
     log_component_levels: dict[str, str] | None = None
+    log_rate_limits: dict[str, int] | None = None
+    log_component_colors: dict[str, str] | None = None
+    log_file_sinks: list[dict[str, str]] | None = None
     bypass_logging: bool = False
     print_config: bool = False
     use_pyo3: bool = False
@@ -5,6 +5,29 @@
 #include <stdint.h>
 #include <Python.h>
 
+/**
+ * The current schema version of encoded cache blobs.
+ */
+#define CACHE_SCHEMA_VERSION 1
+
+/**
+ * The delimiter between the parts of a cache key.
+ */
+#define KEY_DELIMITER ':'
+
+/**
+ * The window over which identical messages are counted (1 second).
+ */
+#define RATE_LIMIT_WINDOW_NS 1000000000
+
+/**
+ * The maximum number of distinct messages tracked per component within a window.
+ *
+ * Once reached, further distinct messages for the component are not limited until the
+ * next window, which bounds the memory used by each component's counter.
+ */
+#define RATE_LIMIT_MAX_TRACKED 1000
+
 /**
  * The state of a component within the system.
  */
@@ -197,6 +220,73 @@ typedef enum LogLevel {
     ERROR = 5,
 } LogLevel;
 
+/**
+ * The status of a logging operation across the FFI boundary, where each error status
+ * corresponds to a [`LogError`](crate::logging::error::LogError) variant.
+ */
+typedef enum LogStatus {
+    /**
+     * The operation succeeded.
+     */
+    OK = 0,
+    /**
+     * A log level string was invalid.
+     */
+    INVALID_LEVEL = 1,
+    /**
+     * A logger configuration option was invalid.
+     */
+    CONFIG_PARSE = 2,
+    /**
+     * A logger configuration JSON value could not be parsed.
+     */
+    JSON_PARSE = 3,
+    /**
+     * A log file directory could not be created.
+     */
+    CREATE_DIRECTORY = 4,
+    /**
+     * A log file could not be opened.
+     */
+    FILE_OPEN = 5,
+    /**
+     * The logger configuration environment variable could not be read.
+     */
+    ENV_VAR = 6,
+    /**
+     * A logger has already been initialized for the process.
+     */
+    ALREADY_INITIALIZED = 7,
+} LogStatus;
+
+/**
+ * Maps cache objects to the keys they are persisted under, so any key-value backend can be
+ * used for a cache database.
+ *
+ * Keys are of the form `{trader_key}:{collection}:{id}` (e.g. `trader-TRADER-001:orders:O-1`),
+ * matching the keys written by the Python cache database.
+ */
+typedef struct CacheMap CacheMap;
+
+/**
+ * A finite-state machine for the lifecycle of a component.
+ *
+ * Enforces the same transitions as the Python `Component` base class, starting from
+ * `PRE_INITIALIZED`, with `DISPOSED` and `FAULTED` as terminal states.
+ */
+typedef struct ComponentFSM ComponentFSM;
+
+/**
+ * Conflates updates keyed by instrument, emitting at most one coalesced update per instrument
+ * per interval.
+ *
+ * Updates are pushed as they arrive and pending updates are taken by polling with the current
+ * time, so the emission schedule is deterministic when backtesting. The first update for an
+ * instrument is emitted on the next poll, after which each instrument's updates are held until
+ * the interval has elapsed since its previous emission.
+ */
+typedef struct ConflatingThrottler_OrderBookDeltas ConflatingThrottler_OrderBookDeltas;
+
 /**
  * A real-time clock which uses system time.
  *
@@ -206,6 +296,15 @@ typedef struct LiveClock LiveClock;
 
 typedef struct LogGuard LogGuard;
 
+/**
+ * A registry of subscriptions of integer subscriber handles to topic patterns.
+ *
+ * Patterns may contain the `*` and `?` wildcards (see [`is_matching`]). The handles matching
+ * each topic are cached when first queried, and the cache is invalidated whenever the
+ * subscriptions change, so repeated publishing to the same topics only matches patterns once.
+ */
+typedef struct SubscriptionRegistry SubscriptionRegistry;
+
 /**
  * A static test clock.
  *
@@ -213,6 +312,20 @@ typedef struct LogGuard LogGuard;
  */
 typedef struct TestClock TestClock;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`CacheMap`].
+ *
+ * This struct wraps `CacheMap` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CacheMap` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `CacheMap_API` to be
+ * dereferenced to `CacheMap`, providing access to `CacheMap`'s methods without
+ * having to manually access the underlying `CacheMap` instance.
+ */
+typedef struct CacheMap_API {
+    struct CacheMap *_0;
+} CacheMap_API;
+
 /**
  * C compatible Foreign Function Interface (FFI) for an underlying [`TestClock`].
  *
@@ -242,6 +355,20 @@ typedef struct LiveClock_API {
     struct LiveClock *_0;
 } LiveClock_API;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFSM`].
+ *
+ * This struct wraps `ComponentFSM` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ComponentFSM` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `ComponentFSM_API` to be
+ * dereferenced to `ComponentFSM`, providing access to `ComponentFSM`'s methods without
+ * having to manually access the underlying `ComponentFSM` instance.
+ */
+typedef struct ComponentFSM_API {
+    struct ComponentFSM *_0;
+} ComponentFSM_API;
+
 /**
  * C compatible Foreign Function Interface (FFI) for an underlying [`LogGuard`].
  *
@@ -256,6 +383,35 @@ typedef struct LogGuard_API {
     struct LogGuard *_0;
 } LogGuard_API;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`SubscriptionRegistry`].
+ *
+ * This struct wraps `SubscriptionRegistry` in a way that makes it compatible with C function
+ * calls, enabling interaction with `SubscriptionRegistry` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `SubscriptionRegistry_API` to be
+ * dereferenced to `SubscriptionRegistry`, providing access to `SubscriptionRegistry`'s methods
+ * without having to manually access the underlying `SubscriptionRegistry` instance.
+ */
+typedef struct SubscriptionRegistry_API {
+    struct SubscriptionRegistry *_0;
+} SubscriptionRegistry_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`ConflatingThrottler`] of
+ * [`OrderBookDeltas`].
+ *
+ * This struct wraps `ConflatingThrottler` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ConflatingThrottler` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `DeltasThrottler_API` to be
+ * dereferenced to `ConflatingThrottler`, providing access to `ConflatingThrottler`'s methods
+ * without having to manually access the underlying `ConflatingThrottler` instance.
+ */
+typedef struct DeltasThrottler_API {
+    struct ConflatingThrottler_OrderBookDeltas *_0;
+} DeltasThrottler_API;
+
 /**
  * Represents a time event occurring at the event timestamp.
  *
@@ -310,6 +466,25 @@ uint8_t logging_is_initialized(void);
  */
 void logging_set_bypass(void);
 
+/**
+ * Returns whether the logging system is in bypass mode.
+ */
+uint8_t logging_is_bypassed(void);
+
+/**
+ * Sets whether the logging system is in bypass mode, so logging can be muted temporarily
+ * (e.g. during a noisy replay) without reinitializing the logger.
+ */
+void logging_set_bypassed(uint8_t bypassed);
+
+/**
+ * Reopens the log file at its configured path, so that writes go to a new file after
+ * external log rotation (e.g. `logrotate` moving the file and signalling the process).
+ *
+ * The reopen is posted to the logging thread, so is applied after any pending log events.
+ */
+void logging_reopen(void);
+
 /**
  * Shuts down the logging system.
  */
@@ -335,6 +510,118 @@ void logging_clock_set_static_mode(void);
  */
 void logging_clock_set_static_time(uint64_t time_ns);
 
+struct CacheMap_API cache_map_new(TraderId_t trader_id,
+                                  UUID4_t instance_id,
+                                  uint8_t use_trader_prefix,
+                                  uint8_t use_instance_id);
+
+void cache_map_drop(struct CacheMap_API cache_map);
+
+const char *cache_map_order_key(const struct CacheMap_API *cache_map,
+                                ClientOrderId_t client_order_id);
+
+const char *cache_map_position_key(const struct CacheMap_API *cache_map, PositionId_t position_id);
+
+const char *cache_map_account_key(const struct CacheMap_API *cache_map, AccountId_t account_id);
+
+/**
+ * Returns a `CVec` of the order encoded as a cache blob, which must be freed with `cvec_drop`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the order cannot be encoded.
+ */
+CVec cache_order_encode(const OrderAny_API *order);
+
+/**
+ * Decodes an order from a cache blob.
+ *
+ * # Safety
+ *
+ * - Assumes `blob_ptr` points to at least `blob_len` bytes.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the blob is not a valid order blob with a supported schema version.
+ */
+OrderAny_API cache_order_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+/**
+ * Returns a `CVec` of the position encoded as a cache blob, which must be freed with `cvec_drop`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the position cannot be encoded.
+ */
+CVec cache_position_encode(const Position_API *position);
+
+/**
+ * Decodes a position from a cache blob.
+ *
+ * # Safety
+ *
+ * - Assumes `blob_ptr` points to at least `blob_len` bytes.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the blob is not a valid position blob with a supported schema version.
+ */
+Position_API cache_position_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+/**
+ * Returns a `CVec` of the cash account encoded as a cache blob, which must be freed with
+ * `cvec_drop`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the account cannot be encoded.
+ */
+CVec cache_cash_account_encode(const CashAccount_API *account);
+
+/**
+ * Decodes a cash account from a cache blob.
+ *
+ * # Safety
+ *
+ * - Assumes `blob_ptr` points to at least `blob_len` bytes.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the blob is not a valid cash account blob with a supported schema version.
+ */
+CashAccount_API cache_cash_account_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+/**
+ * Returns a `CVec` of the margin account encoded as a cache blob, which must be freed with
+ * `cvec_drop`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the account cannot be encoded.
+ */
+CVec cache_margin_account_encode(const MarginAccount_API *account);
+
+/**
+ * Decodes a margin account from a cache blob.
+ *
+ * # Safety
+ *
+ * - Assumes `blob_ptr` points to at least `blob_len` bytes.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the blob is not a valid margin account blob with a supported schema version.
+ */
+MarginAccount_API cache_margin_account_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
 struct TestClock_API test_clock_new(void);
 
 void test_clock_drop(struct TestClock_API clock);
@@ -484,6 +771,23 @@ void live_clock_cancel_timer(struct LiveClock_API *clock, const char *name_ptr);
 
 void live_clock_cancel_timers(struct LiveClock_API *clock);
 
+struct ComponentFSM_API component_fsm_new(void);
+
+void component_fsm_drop(struct ComponentFSM_API fsm);
+
+enum ComponentState component_fsm_state(const struct ComponentFSM_API *fsm);
+
+uint8_t component_fsm_is_valid_trigger(const struct ComponentFSM_API *fsm,
+                                       enum ComponentTrigger trigger);
+
+/**
+ * Applies the `trigger` to the state machine.
+ *
+ * Returns 1 if the state transitioned, or 0 if the trigger is invalid for the current state
+ * (which is then left unchanged), so the caller can raise `InvalidStateTrigger`.
+ */
+uint8_t component_fsm_trigger(struct ComponentFSM_API *fsm, enum ComponentTrigger trigger);
+
 const char *component_state_to_cstr(enum ComponentState value);
 
 /**
@@ -506,6 +810,11 @@ const char *component_trigger_to_cstr(enum ComponentTrigger value);
  */
 enum ComponentTrigger component_trigger_from_cstr(const char *ptr);
 
+/**
+ * Returns the state the trigger drives a component toward.
+ */
+enum ComponentState component_trigger_target_state(enum ComponentTrigger value);
+
 const char *log_level_to_cstr(enum LogLevel value);
 
 /**
@@ -528,6 +837,8 @@ const char *log_color_to_cstr(enum LogColor value);
  */
 enum LogColor log_color_from_cstr(const char *ptr);
 
+const char *log_status_to_cstr(enum LogStatus value);
+
 /**
  * Initializes logging.
  *
@@ -536,6 +847,9 @@ enum LogColor log_color_from_cstr(const char *ptr);
  * Logging can be configured to filter components and write up to a specific level only
  * by passing a configuration using the `NAUTILUS_LOG` environment variable.
  *
+ * The outcome is written to `status`, which is `LogStatus::Ok` on success. On failure the
+ * error is printed to stderr, and the returned guard has no logger to flush.
+ *
  * # Safety
  *
  * Should only be called once during an applications run, ideally at the
@@ -545,6 +859,10 @@ enum LogColor log_color_from_cstr(const char *ptr);
  * - Assume `file_name_ptr` is either NULL or a valid C string pointer.
  * - Assume `file_format_ptr` is either NULL or a valid C string pointer.
  * - Assume `component_level_ptr` is either NULL or a valid C string pointer.
+ * - Assume `rate_limits_ptr` is either NULL or a valid C string pointer.
+ * - Assume `component_colors_ptr` is either NULL or a valid C string pointer.
+ * - Assume `file_sinks_ptr` is either NULL or a valid C string pointer (a JSON array).
+ * - Assume `status` is a valid pointer to write the status to.
  */
 struct LogGuard_API logging_init(TraderId_t trader_id,
                                  UUID4_t instance_id,
@@ -554,13 +872,20 @@ struct LogGuard_API logging_init(TraderId_t trader_id,
                                  const char *file_name_ptr,
                                  const char *file_format_ptr,
                                  const char *component_levels_ptr,
+                                 const char *rate_limits_ptr,
+                                 const char *component_colors_ptr,
+                                 const char *file_sinks_ptr,
                                  uint8_t is_colored,
                                  uint8_t is_bypassed,
-                                 uint8_t print_config);
+                                 uint8_t print_config,
+                                 enum LogStatus *status);
 
 /**
  * Creates a new log event.
  *
+ * Invalid UTF-8 sequences in the component or message are replaced with
+ * `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+ *
  * # Safety
  *
  * - Assumes `component_ptr` is a valid C string pointer.
@@ -571,6 +896,26 @@ void logger_log(enum LogLevel level,
                 const char *component_ptr,
                 const char *message_ptr);
 
+/**
+ * Creates a new log event with the source `file` and `line` it originated from.
+ *
+ * The location is only included if the logger is configured `with_source_location`.
+ * Invalid UTF-8 sequences in the component, message or file are replaced with
+ * `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+ *
+ * # Safety
+ *
+ * - Assumes `component_ptr` is a valid C string pointer.
+ * - Assumes `message_ptr` is a valid C string pointer.
+ * - Assumes `file_ptr` is either NULL or a valid C string pointer.
+ */
+void logger_log_with_location(enum LogLevel level,
+                              enum LogColor color,
+                              const char *component_ptr,
+                              const char *message_ptr,
+                              const char *file_ptr,
+                              uint32_t line);
+
 /**
  * Logs the Nautilus system header.
  *
@@ -593,11 +938,201 @@ void logging_log_header(TraderId_t trader_id,
  */
 void logging_log_sysinfo(const char *component_ptr);
 
+/**
+ * Returns whether an event at `level` from the component would be written by the logger.
+ *
+ * Allows callers to skip formatting messages which would be filtered out.
+ *
+ * # Safety
+ *
+ * - Assumes `component_ptr` is a valid C string pointer.
+ */
+uint8_t logger_would_log(const struct LogGuard_API *log_guard,
+                         enum LogLevel level,
+                         const char *component_ptr);
+
+/**
+ * Returns the count of log events dropped because the logger queue was full.
+ */
+uint64_t logger_get_dropped_count(const struct LogGuard_API *log_guard);
+
 /**
  * Flushes global logger buffers of any records.
  */
 void logger_drop(struct LogGuard_API log_guard);
 
+/**
+ * Returns the string with all ANSI SGR escape sequences (`\x1b[...m`) removed.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+const char *ansi_strip(const char *ptr);
+
+/**
+ * Returns a new [`Subscribe`] command serialized as a JSON C string.
+ *
+ * # Safety
+ *
+ * - Assumes `type_name_ptr` is a valid C string pointer.
+ * - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+ * - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the data type is not registered, or its metadata has no valid instrument ID.
+ * - If both `client_id_ptr` and `venue_ptr` are NULL.
+ */
+const char *data_subscribe_new(const char *type_name_ptr,
+                               const char *metadata_ptr,
+                               const char *client_id_ptr,
+                               const char *venue_ptr,
+                               UUID4_t command_id,
+                               uint64_t ts_init);
+
+/**
+ * Returns a new [`Unsubscribe`] command serialized as a JSON C string.
+ *
+ * # Safety
+ *
+ * - Assumes `type_name_ptr` is a valid C string pointer.
+ * - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+ * - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the data type is not registered, or its metadata has no valid instrument ID.
+ * - If both `client_id_ptr` and `venue_ptr` are NULL.
+ */
+const char *data_unsubscribe_new(const char *type_name_ptr,
+                                 const char *metadata_ptr,
+                                 const char *client_id_ptr,
+                                 const char *venue_ptr,
+                                 UUID4_t command_id,
+                                 uint64_t ts_init);
+
+/**
+ * Returns a new [`DataRequest`] serialized as a JSON C string.
+ *
+ * A `start`, `end` or `limit` of zero is treated as unbounded.
+ *
+ * # Safety
+ *
+ * - Assumes `type_name_ptr` is a valid C string pointer.
+ * - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+ * - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the data type is not registered, or its metadata has no valid instrument ID.
+ * - If both `client_id_ptr` and `venue_ptr` are NULL.
+ * - If `start` is after `end`.
+ */
+const char *data_request_new(const char *type_name_ptr,
+                             const char *metadata_ptr,
+                             const char *client_id_ptr,
+                             const char *venue_ptr,
+                             UUID4_t correlation_id,
+                             uint64_t start,
+                             uint64_t end,
+                             uintptr_t limit,
+                             uint64_t ts_init);
+
+/**
+ * Returns whether the `topic` matches the `pattern` (which may contain `*` and `?` wildcards).
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` and `pattern_ptr` are valid C string pointers.
+ */
+uint8_t msgbus_is_matching(const char *topic_ptr, const char *pattern_ptr);
+
+struct SubscriptionRegistry_API subscription_registry_new(void);
+
+void subscription_registry_drop(struct SubscriptionRegistry_API registry);
+
+/**
+ * Subscribes the `handle` to the `pattern`, returning 0 if it was already subscribed.
+ *
+ * # Safety
+ *
+ * - Assumes `pattern_ptr` is a valid C string pointer.
+ */
+uint8_t subscription_registry_subscribe(struct SubscriptionRegistry_API *registry,
+                                        const char *pattern_ptr,
+                                        uint64_t handle,
+                                        uint8_t priority);
+
+/**
+ * Unsubscribes the `handle` from the `pattern`, returning 0 if it was not subscribed.
+ *
+ * # Safety
+ *
+ * - Assumes `pattern_ptr` is a valid C string pointer.
+ */
+uint8_t subscription_registry_unsubscribe(struct SubscriptionRegistry_API *registry,
+                                          const char *pattern_ptr,
+                                          uint64_t handle);
+
+/**
+ * Returns a `CVec` of the `uint64_t` handles subscribed to patterns matching the `topic`, in
+ * descending priority order, which must be freed with [`vec_handles_drop`].
+ *
+ * # Safety
+ *
+ * - Assumes `topic_ptr` is a valid C string pointer.
+ */
+CVec subscription_registry_matching_handlers(struct SubscriptionRegistry_API *registry,
+                                             const char *topic_ptr);
+
+/**
+ * Returns a `CVec` with a `CVec` of matching handles (as for
+ * [`subscription_registry_matching_handlers`]) for each of the `topics_len` topics, which must
+ * be freed with [`vec_handles_batch_drop`].
+ *
+ * # Safety
+ *
+ * - Assumes `topics_ptr` points to `topics_len` valid C string pointers.
+ */
+CVec subscription_registry_matching_handlers_batch(struct SubscriptionRegistry_API *registry,
+                                                   const char *const *topics_ptr,
+                                                   uintptr_t topics_len);
+
+void vec_handles_drop(CVec v);
+
+void vec_handles_batch_drop(CVec v);
+
+struct DeltasThrottler_API throttle_new(uint64_t interval_ns);
+
+void throttle_drop(struct DeltasThrottler_API throttler);
+
+/**
+ * Pushes a copy of `deltas`, merging it with any pending deltas for the same instrument.
+ */
+void throttle_push(struct DeltasThrottler_API *throttler, const OrderBookDeltas_API *deltas);
+
+/**
+ * Returns a `CVec` of `OrderBookDeltas_API` for the instruments whose interval has elapsed at
+ * `ts_now`, which must be freed with [`vec_throttled_deltas_drop`].
+ */
+CVec throttle_poll(struct DeltasThrottler_API *throttler, uint64_t ts_now);
+
+/**
+ * Returns a `CVec` of `OrderBookDeltas_API` for all pending deltas, regardless of the interval,
+ * which must be freed with [`vec_throttled_deltas_drop`].
+ */
+CVec throttle_flush(struct DeltasThrottler_API *throttler, uint64_t ts_now);
+
+uintptr_t throttle_qsize(const struct DeltasThrottler_API *throttler);
+
+uintptr_t throttle_dropped_count(const struct DeltasThrottler_API *throttler);
+
+void vec_throttled_deltas_drop(CVec v);
+
 /**
  * # Safety
  *
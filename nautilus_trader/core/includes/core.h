@@ -10,6 +10,11 @@
  */
 #define MILLISECONDS_IN_SECOND 1000
 
+/**
+ * Number of nanoseconds in one day.
+ */
+#define NANOSECONDS_IN_DAY (86400 * NANOSECONDS_IN_SECOND)
+
 /**
  * Number of nanoseconds in one second.
  */
@@ -25,6 +30,16 @@
  */
 #define NANOSECONDS_IN_MICROSECOND 1000
 
+/**
+ * Running statistics over a rolling window of `f64` values, each updated in (amortized)
+ * constant time per push.
+ *
+ * The sum is maintained with Kahan summation. The variance is maintained from sums of the values
+ * shifted by the first value pushed, avoiding the cancellation of large squared terms when
+ * the values are far from zero (e.g. prices).
+ */
+typedef struct RollingStats RollingStats;
+
 /**
  * `CVec` is a C compatible struct that stores an opaque pointer to a block of
  * memory, it's length and the capacity of the vector it was allocated from.
@@ -49,6 +64,20 @@ typedef struct CVec {
     uintptr_t cap;
 } CVec;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`RollingStats`].
+ *
+ * This struct wraps `RollingStats` in a way that makes it compatible with C function
+ * calls, enabling interaction with `RollingStats` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `RollingStats_API` to be
+ * dereferenced to `RollingStats`, providing access to `RollingStats`'s methods without
+ * having to manually access the underlying `RollingStats` instance.
+ */
+typedef struct RollingStats_API {
+    struct RollingStats *_0;
+} RollingStats_API;
+
 /**
  * Represents a pseudo-random UUID (universally unique identifier)
  * version 4 based on a 128-bit label as specified in RFC 4122.
@@ -132,6 +161,56 @@ uint8_t precision_from_cstr(const char *ptr);
  */
 uint8_t min_increment_precision_from_cstr(const char *ptr);
 
+/**
+ * Creates new rolling statistics for a window of `capacity` values.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `capacity` is zero.
+ */
+struct RollingStats_API rolling_stats_new(uintptr_t capacity);
+
+void rolling_stats_drop(struct RollingStats_API stats);
+
+void rolling_stats_push(struct RollingStats_API *stats, double value);
+
+uintptr_t rolling_stats_capacity(const struct RollingStats_API *stats);
+
+uintptr_t rolling_stats_len(const struct RollingStats_API *stats);
+
+uint8_t rolling_stats_is_full(const struct RollingStats_API *stats);
+
+double rolling_stats_sum(const struct RollingStats_API *stats);
+
+/**
+ * Returns the mean of the values in the window, or NaN if empty.
+ */
+double rolling_stats_mean(const struct RollingStats_API *stats);
+
+/**
+ * Returns the minimum value in the window, or NaN if empty.
+ */
+double rolling_stats_min(const struct RollingStats_API *stats);
+
+/**
+ * Returns the maximum value in the window, or NaN if empty.
+ */
+double rolling_stats_max(const struct RollingStats_API *stats);
+
+/**
+ * Returns the sample variance of the values in the window, or NaN if fewer than two values.
+ */
+double rolling_stats_variance(const struct RollingStats_API *stats);
+
+/**
+ * Returns the sample standard deviation of the values in the window, or NaN if fewer than two
+ * values.
+ */
+double rolling_stats_std(const struct RollingStats_API *stats);
+
+void rolling_stats_clear(struct RollingStats_API *stats);
+
 /**
  * Drops the C string memory at the pointer.
  *
@@ -0,0 +1,282 @@
+/* Generated with cbindgen:0.27.0 */
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdint.h>
+#include <Python.h>
+
+/**
+ * The default period for the signal line of a [`MovingAverageConvergenceDivergence`].
+ */
+#define MACD_SIGNAL_PERIOD_DEFAULT 9
+
+typedef enum MovingAverageType {
+    SIMPLE,
+    EXPONENTIAL,
+    DOUBLE_EXPONENTIAL,
+    WILDER,
+    HULL,
+} MovingAverageType;
+
+/**
+ * An indicator which calculates a Average True Range (ATR) across a rolling window.
+ */
+typedef struct AverageTrueRange AverageTrueRange;
+
+typedef struct ExponentialMovingAverage ExponentialMovingAverage;
+
+/**
+ * An indicator which calculates the difference between a fast and slow moving average (MACD),
+ * along with a signal line (a moving average of the MACD) and histogram (MACD less signal).
+ */
+typedef struct MovingAverageConvergenceDivergence MovingAverageConvergenceDivergence;
+
+/**
+ * An indicator which calculates a relative strength index (RSI) across a rolling window.
+ *
+ * The value is scaled between 0 and 1 (rather than 0 and 100). While the average loss is
+ * zero the value is the maximum of 1, including for a flat series of equal inputs (matching
+ * the Cython `RelativeStrengthIndex`).
+ */
+typedef struct RelativeStrengthIndex RelativeStrengthIndex;
+
+typedef struct SimpleMovingAverage SimpleMovingAverage;
+
+/**
+ * An indicator which calculates a weighted moving average across a rolling window.
+ */
+typedef struct WeightedMovingAverage WeightedMovingAverage;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`ExponentialMovingAverage`].
+ *
+ * This struct wraps `ExponentialMovingAverage` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ExponentialMovingAverage` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `ExponentialMovingAverage_API` to be
+ * dereferenced to `ExponentialMovingAverage`, providing access to `ExponentialMovingAverage`'s
+ * methods without having to manually access the underlying `ExponentialMovingAverage` instance.
+ */
+typedef struct ExponentialMovingAverage_API {
+    struct ExponentialMovingAverage *_0;
+} ExponentialMovingAverage_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`SimpleMovingAverage`].
+ *
+ * This struct wraps `SimpleMovingAverage` in a way that makes it compatible with C function
+ * calls, enabling interaction with `SimpleMovingAverage` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `SimpleMovingAverage_API` to be
+ * dereferenced to `SimpleMovingAverage`, providing access to `SimpleMovingAverage`'s
+ * methods without having to manually access the underlying `SimpleMovingAverage` instance.
+ */
+typedef struct SimpleMovingAverage_API {
+    struct SimpleMovingAverage *_0;
+} SimpleMovingAverage_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`WeightedMovingAverage`].
+ *
+ * This struct wraps `WeightedMovingAverage` in a way that makes it compatible with C function
+ * calls, enabling interaction with `WeightedMovingAverage` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `WeightedMovingAverage_API` to be
+ * dereferenced to `WeightedMovingAverage`, providing access to `WeightedMovingAverage`'s
+ * methods without having to manually access the underlying `WeightedMovingAverage` instance.
+ */
+typedef struct WeightedMovingAverage_API {
+    struct WeightedMovingAverage *_0;
+} WeightedMovingAverage_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying
+ * [`MovingAverageConvergenceDivergence`].
+ *
+ * This struct wraps `MovingAverageConvergenceDivergence` in a way that makes it compatible with
+ * C function calls, enabling interaction with `MovingAverageConvergenceDivergence` in a C
+ * environment.
+ *
+ * It implements the `Deref` trait, allowing instances of
+ * `MovingAverageConvergenceDivergence_API` to be dereferenced to
+ * `MovingAverageConvergenceDivergence`, providing access to
+ * `MovingAverageConvergenceDivergence`'s methods without having to manually access the
+ * underlying `MovingAverageConvergenceDivergence` instance.
+ */
+typedef struct MovingAverageConvergenceDivergence_API {
+    struct MovingAverageConvergenceDivergence *_0;
+} MovingAverageConvergenceDivergence_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`RelativeStrengthIndex`].
+ *
+ * This struct wraps `RelativeStrengthIndex` in a way that makes it compatible with C function
+ * calls, enabling interaction with `RelativeStrengthIndex` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `RelativeStrengthIndex_API` to be
+ * dereferenced to `RelativeStrengthIndex`, providing access to `RelativeStrengthIndex`'s
+ * methods without having to manually access the underlying `RelativeStrengthIndex` instance.
+ */
+typedef struct RelativeStrengthIndex_API {
+    struct RelativeStrengthIndex *_0;
+} RelativeStrengthIndex_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`AverageTrueRange`].
+ *
+ * This struct wraps `AverageTrueRange` in a way that makes it compatible with C function
+ * calls, enabling interaction with `AverageTrueRange` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `AverageTrueRange_API` to be
+ * dereferenced to `AverageTrueRange`, providing access to `AverageTrueRange`'s methods
+ * without having to manually access the underlying `AverageTrueRange` instance.
+ */
+typedef struct AverageTrueRange_API {
+    struct AverageTrueRange *_0;
+} AverageTrueRange_API;
+
+struct ExponentialMovingAverage_API ema_new(uintptr_t period, PriceType price_type);
+
+void ema_drop(struct ExponentialMovingAverage_API ema);
+
+void ema_update_raw(struct ExponentialMovingAverage_API *ema, double value);
+
+void ema_handle_quote(struct ExponentialMovingAverage_API *ema, const QuoteTick_t *quote);
+
+void ema_handle_trade(struct ExponentialMovingAverage_API *ema, const TradeTick_t *trade);
+
+void ema_handle_bar(struct ExponentialMovingAverage_API *ema, const Bar_t *bar);
+
+double ema_value(const struct ExponentialMovingAverage_API *ema);
+
+uintptr_t ema_count(const struct ExponentialMovingAverage_API *ema);
+
+uint8_t ema_has_inputs(const struct ExponentialMovingAverage_API *ema);
+
+uint8_t ema_initialized(const struct ExponentialMovingAverage_API *ema);
+
+void ema_reset(struct ExponentialMovingAverage_API *ema);
+
+struct SimpleMovingAverage_API sma_new(uintptr_t period, PriceType price_type);
+
+void sma_drop(struct SimpleMovingAverage_API sma);
+
+void sma_update_raw(struct SimpleMovingAverage_API *sma, double value);
+
+void sma_handle_quote(struct SimpleMovingAverage_API *sma, const QuoteTick_t *quote);
+
+void sma_handle_trade(struct SimpleMovingAverage_API *sma, const TradeTick_t *trade);
+
+void sma_handle_bar(struct SimpleMovingAverage_API *sma, const Bar_t *bar);
+
+double sma_value(const struct SimpleMovingAverage_API *sma);
+
+uintptr_t sma_count(const struct SimpleMovingAverage_API *sma);
+
+uint8_t sma_has_inputs(const struct SimpleMovingAverage_API *sma);
+
+uint8_t sma_initialized(const struct SimpleMovingAverage_API *sma);
+
+void sma_reset(struct SimpleMovingAverage_API *sma);
+
+/**
+ * Creates a new weighted moving average.
+ *
+ * Pass a null `weights_ptr` for equal weights (a simple average of the inputs).
+ *
+ * # Safety
+ *
+ * - Assumes `weights_ptr` is either null or points to `weights_len` valid `f64` values.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `weights_ptr` is not null and `weights_len` is not equal to `period`.
+ */
+struct WeightedMovingAverage_API wma_new(uintptr_t period,
+                                         const double *weights_ptr,
+                                         uintptr_t weights_len,
+                                         PriceType price_type);
+
+void wma_drop(struct WeightedMovingAverage_API wma);
+
+void wma_update_raw(struct WeightedMovingAverage_API *wma, double value);
+
+void wma_handle_quote(struct WeightedMovingAverage_API *wma, const QuoteTick_t *quote);
+
+void wma_handle_trade(struct WeightedMovingAverage_API *wma, const TradeTick_t *trade);
+
+void wma_handle_bar(struct WeightedMovingAverage_API *wma, const Bar_t *bar);
+
+double wma_value(const struct WeightedMovingAverage_API *wma);
+
+uintptr_t wma_count(const struct WeightedMovingAverage_API *wma);
+
+uint8_t wma_has_inputs(const struct WeightedMovingAverage_API *wma);
+
+uint8_t wma_initialized(const struct WeightedMovingAverage_API *wma);
+
+void wma_reset(struct WeightedMovingAverage_API *wma);
+
+struct MovingAverageConvergenceDivergence_API macd_new(uintptr_t fast_period,
+                                                       uintptr_t slow_period,
+                                                       uintptr_t signal_period,
+                                                       enum MovingAverageType ma_type,
+                                                       PriceType price_type);
+
+void macd_drop(struct MovingAverageConvergenceDivergence_API macd);
+
+void macd_update_raw(struct MovingAverageConvergenceDivergence_API *macd, double close);
+
+void macd_handle_bar(struct MovingAverageConvergenceDivergence_API *macd, const Bar_t *bar);
+
+double macd_value(const struct MovingAverageConvergenceDivergence_API *macd);
+
+double macd_signal(const struct MovingAverageConvergenceDivergence_API *macd);
+
+double macd_histogram(const struct MovingAverageConvergenceDivergence_API *macd);
+
+uint8_t macd_has_inputs(const struct MovingAverageConvergenceDivergence_API *macd);
+
+uint8_t macd_initialized(const struct MovingAverageConvergenceDivergence_API *macd);
+
+void macd_reset(struct MovingAverageConvergenceDivergence_API *macd);
+
+struct RelativeStrengthIndex_API rsi_new(uintptr_t period, enum MovingAverageType ma_type);
+
+void rsi_drop(struct RelativeStrengthIndex_API rsi);
+
+void rsi_update_raw(struct RelativeStrengthIndex_API *rsi, double value);
+
+void rsi_handle_bar(struct RelativeStrengthIndex_API *rsi, const Bar_t *bar);
+
+double rsi_value(const struct RelativeStrengthIndex_API *rsi);
+
+uintptr_t rsi_count(const struct RelativeStrengthIndex_API *rsi);
+
+uint8_t rsi_has_inputs(const struct RelativeStrengthIndex_API *rsi);
+
+uint8_t rsi_initialized(const struct RelativeStrengthIndex_API *rsi);
+
+void rsi_reset(struct RelativeStrengthIndex_API *rsi);
+
+struct AverageTrueRange_API atr_new(uintptr_t period,
+                                    enum MovingAverageType ma_type,
+                                    uint8_t use_previous,
+                                    double value_floor);
+
+void atr_drop(struct AverageTrueRange_API atr);
+
+void atr_update_raw(struct AverageTrueRange_API *atr, double high, double low, double close);
+
+void atr_handle_bar(struct AverageTrueRange_API *atr, const Bar_t *bar);
+
+double atr_value(const struct AverageTrueRange_API *atr);
+
+uintptr_t atr_count(const struct AverageTrueRange_API *atr);
+
+uint8_t atr_has_inputs(const struct AverageTrueRange_API *atr);
+
+uint8_t atr_initialized(const struct AverageTrueRange_API *atr);
+
+void atr_reset(struct AverageTrueRange_API *atr);
@@ -7,11 +7,36 @@
 
 #define DEPTH10_LEN 10
 
+/**
+ * Book order flag indicating implied liquidity (derived from other instruments, such as spread legs).
+ */
+#define BOOK_ORDER_FLAG_IMPLIED (1 << 0)
+
+/**
+ * Book order flag indicating the order is only partially displayed (e.g. an iceberg order).
+ */
+#define BOOK_ORDER_FLAG_HIDDEN (1 << 1)
+
+/**
+ * Book order flag indicating the order is synthetic (not resting on the venue).
+ */
+#define BOOK_ORDER_FLAG_SYNTHETIC (1 << 2)
+
 /**
  * The maximum length of ASCII characters for a `TradeId` string value (including null terminator).
  */
 #define TRADE_ID_LEN 37
 
+/**
+ * The number of levels per side included in a Kraken checksum.
+ */
+#define KRAKEN_CHECKSUM_DEPTH 10
+
+/**
+ * The number of levels per side included in an OKX checksum.
+ */
+#define OKX_CHECKSUM_DEPTH 25
+
 /**
  * The maximum fixed-point precision.
  */
@@ -85,6 +110,20 @@ typedef enum AccountType {
     BETTING = 3,
 } AccountType;
 
+/**
+ * The method used to back-adjust prices prior to each roll.
+ */
+typedef enum AdjustmentMethod {
+    /**
+     * Shift prior prices by the price difference between the contracts at the roll.
+     */
+    DIFFERENCE = 1,
+    /**
+     * Scale prior prices by the price ratio between the contracts at the roll.
+     */
+    RATIO = 2,
+} AdjustmentMethod;
+
 /**
  * An aggregation source for derived data.
  */
@@ -173,6 +212,38 @@ typedef enum BookAction {
     CLEAR = 4,
 } BookAction;
 
+/**
+ * The venue-specific scheme used to compute an order book checksum.
+ */
+typedef enum BookChecksumType {
+    /**
+     * Kraken style: asks then bids, with decimal points and leading zeros removed.
+     */
+    KRAKEN = 1,
+    /**
+     * OKX style: interleaved bid/ask price and size strings joined by `:`.
+     */
+    OKX = 2,
+} BookChecksumType;
+
+/**
+ * The policy applied when an order book delta leaves the book crossed or locked.
+ */
+typedef enum BookIntegrityPolicy {
+    /**
+     * Apply the delta and leave the book as is.
+     */
+    IGNORE = 1,
+    /**
+     * Apply the delta and return a [`BookIntegrityError`].
+     */
+    RAISE_ERROR = 2,
+    /**
+     * Apply the delta then remove the opposite side levels crossed (or locked) by it.
+     */
+    DROP_CROSSED_LEVELS = 3,
+} BookIntegrityPolicy;
+
 /**
  * The order book type, representing the type of levels granularity and delta updating heuristics.
  */
@@ -301,6 +372,48 @@ typedef enum InstrumentCloseType {
     CONTRACT_EXPIRED = 2,
 } InstrumentCloseType;
 
+/**
+ * The reason an order is invalid for an instrument.
+ */
+typedef enum InstrumentViolation {
+    /**
+     * The quantity precision exceeds the instrument size precision.
+     */
+    QUANTITY_PRECISION = 1,
+    /**
+     * The quantity is not a multiple of the instrument size increment.
+     */
+    QUANTITY_INCREMENT = 2,
+    /**
+     * The quantity is less than the instrument minimum quantity.
+     */
+    QUANTITY_BELOW_MIN = 3,
+    /**
+     * The quantity is greater than the instrument maximum quantity.
+     */
+    QUANTITY_ABOVE_MAX = 4,
+    /**
+     * The price precision exceeds the instrument price precision.
+     */
+    PRICE_PRECISION = 5,
+    /**
+     * The price is not a multiple of the instrument price increment.
+     */
+    PRICE_INCREMENT = 6,
+    /**
+     * The notional value is less than the instrument minimum notional.
+     */
+    NOTIONAL_BELOW_MIN = 7,
+    /**
+     * The notional value is greater than the instrument maximum notional.
+     */
+    NOTIONAL_ABOVE_MAX = 8,
+    /**
+     * A notional limit is in a different currency to the notional value.
+     */
+    NOTIONAL_CURRENCY_MISMATCH = 9,
+} InstrumentViolation;
+
 /**
  * The liqudity side for a trade.
  */
@@ -665,6 +778,39 @@ typedef enum RecordFlag {
     RESERVED_1 = (1 << 2),
 } RecordFlag;
 
+/**
+ * The rule determining when a continuous series rolls from the front contract to the next.
+ */
+typedef enum RollRule {
+    /**
+     * Roll a fixed number of calendar days before the front contract expires.
+     */
+    DAYS_BEFORE_EXPIRY = 1,
+    /**
+     * Roll at the first bar where the next contract trades more volume than the front
+     * contract (at the same timestamp), otherwise at expiration of the front contract.
+     */
+    VOLUME_CROSSOVER = 2,
+} RollRule;
+
+/**
+ * The rounding mode used when aligning a value to an instrument increment.
+ */
+typedef enum RoundingMode {
+    /**
+     * Round to the nearest increment, with halfway values rounded away from zero.
+     */
+    NEAREST = 1,
+    /**
+     * Round up to the next increment (towards positive infinity).
+     */
+    UP = 2,
+    /**
+     * Round down to the previous increment (towards negative infinity).
+     */
+    DOWN = 3,
+} RoundingMode;
+
 /**
  * The 'Time in Force' instruction for an order.
  */
@@ -722,7 +868,8 @@ typedef enum TradingState {
  */
 typedef enum TrailingOffsetType {
     /**
-     * No trailing offset type is specified (invalid for trailing type orders).
+     * No trailing offset type is specified (required for all non-trailing order types, and
+     * invalid for trailing type orders).
      */
     NO_TRAILING_OFFSET = 0,
     /**
@@ -789,14 +936,45 @@ typedef enum TriggerType {
     INDEX_PRICE = 9,
 } TriggerType;
 
+typedef struct CashAccount CashAccount;
+
+/**
+ * Wraps the available commission models.
+ */
+typedef struct CommissionModelAny CommissionModelAny;
+
+/**
+ * Builds a continuous bar series from the bars of individual futures contracts.
+ *
+ * Each contract contributes its bars from the previous roll (inclusive) up to its own roll
+ * (exclusive), so any overlapping data between contracts is only taken from one of them.
+ * Prices prior to each roll are back-adjusted, so the most recent contract remains unadjusted.
+ */
+typedef struct ContinuousContractBuilder ContinuousContractBuilder;
+
+/**
+ * The output of a [`ContinuousContractBuilder`].
+ */
+typedef struct ContinuousSeries ContinuousSeries;
+
+typedef struct InstrumentAny InstrumentAny;
+
 /**
  * Represents a discrete price level in an order book.
  *
  * The level maintains a collection of orders as well as tracking insertion order
  * to preserve FIFO queue dynamics.
+ *
+ * The level also tracks an order count, which is updated as orders are added and
+ * removed. For MBP books (where each level holds a single aggregated order) the count
+ * can instead be set from venue data with [`Level::set_count`].
  */
 typedef struct Level Level;
 
+typedef struct MarginAccount MarginAccount;
+
+typedef struct OrderAny OrderAny;
+
 /**
  * Provides a high-performance, versatile order book.
  *
@@ -814,6 +992,19 @@ typedef struct OrderBook OrderBook;
  */
 typedef struct OrderBookDeltas_t OrderBookDeltas_t;
 
+/**
+ * Wraps an `OrderEvent` allowing polymorphism.
+ */
+typedef struct OrderEventAny OrderEventAny;
+
+/**
+ * Represents a position in a market.
+ *
+ * The position ID may be assigned at the trading venue, or can be system
+ * generated depending on a strategies OMS (Order Management System) settings.
+ */
+typedef struct Position Position;
+
 /**
  * Represents a synthetic instrument with prices derived from component instruments using a
  * formula.
@@ -918,6 +1109,10 @@ typedef struct BookOrder_t {
      * The order ID.
      */
     uint64_t order_id;
+    /**
+     * The order flags bit field (see the `BOOK_ORDER_FLAG_*` constants).
+     */
+    uint8_t flags;
 } BookOrder_t;
 
 /**
@@ -1253,6 +1448,150 @@ typedef struct Data_t {
     };
 } Data_t;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`CashAccount`].
+ *
+ * This struct wraps `CashAccount` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CashAccount` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `CashAccount_API` to be
+ * dereferenced to `CashAccount`, providing access to `CashAccount`'s methods without
+ * having to manually access the underlying `CashAccount` instance.
+ */
+typedef struct CashAccount_API {
+    struct CashAccount *_0;
+} CashAccount_API;
+
+/**
+ * Represents a valid account ID.
+ */
+typedef struct AccountId_t {
+    char* _0;
+} AccountId_t;
+
+/**
+ * Represents a medium of exchange in a specified denomination with a fixed decimal precision.
+ *
+ * Handles up to 9 decimals of precision.
+ */
+typedef struct Currency_t {
+    /**
+     * The currency code as an alpha-3 string (e.g., "USD", "EUR").
+     */
+    char* code;
+    /**
+     * The currency decimal precision.
+     */
+    uint8_t precision;
+    /**
+     * The currency code (ISO 4217).
+     */
+    uint16_t iso4217;
+    /**
+     * The full name of the currency.
+     */
+    char* name;
+    /**
+     * The currency type, indicating its category (e.g. Fiat, Crypto).
+     */
+    enum CurrencyType currency_type;
+} Currency_t;
+
+/**
+ * Represents an amount of money in a specified currency denomination.
+ *
+ * - `MONEY_MAX` = 9_223_372_036
+ * - `MONEY_MIN` = -9_223_372_036
+ */
+typedef struct Money_t {
+    /**
+     * The raw monetary amount as a signed 64-bit integer.
+     * Represents the unscaled amount, with `currency.precision` defining the number of decimal places.
+     */
+    int64_t raw;
+    /**
+     * The currency denomination associated with the monetary amount.
+     */
+    struct Currency_t currency;
+} Money_t;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`InstrumentAny`].
+ *
+ * This struct wraps `InstrumentAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with instruments in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `InstrumentAny_API` to be
+ * dereferenced to `InstrumentAny`, providing access to `InstrumentAny`'s methods without
+ * having to manually access the underlying instance.
+ */
+typedef struct InstrumentAny_API {
+    struct InstrumentAny *_0;
+} InstrumentAny_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+ *
+ * This struct wraps `Position` in a way that makes it compatible with C function
+ * calls, enabling interaction with `Position` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `Position_API` to be
+ * dereferenced to `Position`, providing access to `Position`'s methods without
+ * having to manually access the underlying `Position` instance.
+ */
+typedef struct Position_API {
+    struct Position *_0;
+} Position_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`MarginAccount`].
+ *
+ * This struct wraps `MarginAccount` in a way that makes it compatible with C function
+ * calls, enabling interaction with `MarginAccount` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `MarginAccount_API` to be
+ * dereferenced to `MarginAccount`, providing access to `MarginAccount`'s methods without
+ * having to manually access the underlying `MarginAccount` instance.
+ */
+typedef struct MarginAccount_API {
+    struct MarginAccount *_0;
+} MarginAccount_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`CommissionModelAny`].
+ *
+ * This struct wraps `CommissionModelAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with `CommissionModelAny` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `CommissionModel_API` to be
+ * dereferenced to `CommissionModelAny`, providing access to `CommissionModelAny`'s methods
+ * without having to manually access the underlying `CommissionModelAny` instance.
+ */
+typedef struct CommissionModel_API {
+    struct CommissionModelAny *_0;
+} CommissionModel_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying `ContinuousContractBuilder`.
+ *
+ * This struct wraps `ContinuousContractBuilder` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ContinuousContractBuilder` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `ContinuousContractBuilder_API` to be
+ * dereferenced to `ContinuousContractBuilder`, providing access to `ContinuousContractBuilder`'s
+ * methods without having to manually access the underlying instance.
+ */
+typedef struct ContinuousContractBuilder_API {
+    struct ContinuousContractBuilder *_0;
+} ContinuousContractBuilder_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying `ContinuousSeries`.
+ */
+typedef struct ContinuousSeries_API {
+    struct ContinuousSeries *_0;
+} ContinuousSeries_API;
+
 /**
  * Represents a valid trader ID.
  */
@@ -1306,13 +1645,6 @@ typedef struct OrderReleased_t {
     uint64_t ts_init;
 } OrderReleased_t;
 
-/**
- * Represents a valid account ID.
- */
-typedef struct AccountId_t {
-    char* _0;
-} AccountId_t;
-
 typedef struct OrderSubmitted_t {
     struct TraderId_t trader_id;
     struct StrategyId_t strategy_id;
@@ -1357,6 +1689,27 @@ typedef struct OrderRejected_t {
     uint8_t reconciliation;
 } OrderRejected_t;
 
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`OrderEventAny`].
+ *
+ * Order events with optional fields are not C compatible by value, so are constructed
+ * behind this pointer and read through the `order_event_*` accessors.
+ *
+ * It implements the `Deref` trait, allowing instances of `OrderEventAny_API` to be
+ * dereferenced to `OrderEventAny`, providing access to `OrderEventAny`'s methods without
+ * having to manually access the underlying `OrderEventAny` instance.
+ */
+typedef struct OrderEventAny_API {
+    struct OrderEventAny *_0;
+} OrderEventAny_API;
+
+/**
+ * Represents a valid position ID.
+ */
+typedef struct PositionId_t {
+    char* _0;
+} PositionId_t;
+
 /**
  * Represents a system client ID.
  */
@@ -1386,11 +1739,26 @@ typedef struct OrderListId_t {
 } OrderListId_t;
 
 /**
- * Represents a valid position ID.
+ * The components of an OCC (Options Clearing Corporation) option symbol.
  */
-typedef struct PositionId_t {
-    char* _0;
-} PositionId_t;
+typedef struct OccSymbol {
+    /**
+     * The root symbol of the underlying.
+     */
+    char* underlying;
+    /**
+     * UNIX timestamp (nanoseconds) for the expiration date at midnight UTC.
+     */
+    uint64_t expiration_ns;
+    /**
+     * The option kind.
+     */
+    enum OptionKind option_kind;
+    /**
+     * The strike price (with 3 decimal places, as encoded in the symbol).
+     */
+    struct Price_t strike_price;
+} OccSymbol;
 
 /**
  * C compatible Foreign Function Interface (FFI) for an underlying
@@ -1421,6 +1789,27 @@ typedef struct OrderBook_API {
     struct OrderBook *_0;
 } OrderBook_API;
 
+/**
+ * C compatible result of simulating fills for a quantity against an order book.
+ *
+ * `fills` is a `CVec` of `BookFill` (one per level filled) ordered from the top of book.
+ */
+typedef struct CSimulatedFills {
+    CVec fills;
+    struct Quantity_t filled_qty;
+    uint8_t is_partial;
+} CSimulatedFills;
+
+/**
+ * C compatible snapshot of the top levels of an order book.
+ *
+ * Both `bids` and `asks` are `CVec`s of `BookLevel` ordered best first.
+ */
+typedef struct CBookSnapshot {
+    CVec bids;
+    CVec asks;
+} CBookSnapshot;
+
 /**
  * C compatible Foreign Function Interface (FFI) for an underlying order book[`Level`].
  *
@@ -1436,55 +1825,102 @@ typedef struct Level_API {
 } Level_API;
 
 /**
- * Represents a medium of exchange in a specified denomination with a fixed decimal precision.
+ * C compatible Foreign Function Interface (FFI) for an underlying [`OrderAny`].
  *
- * Handles up to 9 decimals of precision.
+ * This struct wraps `OrderAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with `OrderAny` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `OrderAny_API` to be
+ * dereferenced to `OrderAny`, providing access to `OrderAny`'s methods without
+ * having to manually access the underlying `OrderAny` instance.
  */
-typedef struct Currency_t {
-    /**
-     * The currency code as an alpha-3 string (e.g., "USD", "EUR").
-     */
-    char* code;
+typedef struct OrderAny_API {
+    struct OrderAny *_0;
+} OrderAny_API;
+
+/**
+ * Represents an account balance denominated in a particular currency.
+ *
+ * The `free` balance is always the `total` less the `locked` balance, which is checked on
+ * construction and deserialization.
+ */
+typedef struct AccountBalance_t {
     /**
-     * The currency decimal precision.
+     * The account balance currency.
      */
-    uint8_t precision;
+    struct Currency_t currency;
     /**
-     * The currency code (ISO 4217).
+     * The total account balance.
      */
-    uint16_t iso4217;
+    struct Money_t total;
     /**
-     * The full name of the currency.
+     * The account balance locked (assigned to pending orders).
      */
-    char* name;
+    struct Money_t locked;
     /**
-     * The currency type, indicating its category (e.g. Fiat, Crypto).
+     * The account balance free for trading.
      */
-    enum CurrencyType currency_type;
-} Currency_t;
+    struct Money_t free;
+} AccountBalance_t;
 
 /**
- * Represents an amount of money in a specified currency denomination.
- *
- * - `MONEY_MAX` = 9_223_372_036
- * - `MONEY_MIN` = -9_223_372_036
+ * Represents a margin balance for a particular instrument, denominated in a particular currency.
  */
-typedef struct Money_t {
+typedef struct MarginBalance_t {
     /**
-     * The raw monetary amount as a signed 64-bit integer.
-     * Represents the unscaled amount, with `currency.precision` defining the number of decimal places.
+     * The initial margin requirement.
      */
-    int64_t raw;
+    struct Money_t initial;
     /**
-     * The currency denomination associated with the monetary amount.
+     * The maintenance margin requirement.
+     */
+    struct Money_t maintenance;
+    /**
+     * The margin balance currency.
      */
     struct Currency_t currency;
-} Money_t;
+    /**
+     * The instrument ID for the margin.
+     */
+    struct InstrumentId_t instrument_id;
+} MarginBalance_t;
+
+/**
+ * Represents a simulated fill against a single order book price level.
+ */
+typedef struct BookFill {
+    /**
+     * The price of the level filled against.
+     */
+    struct Price_t price;
+    /**
+     * The quantity filled at the level.
+     */
+    struct Quantity_t size;
+} BookFill;
+
+/**
+ * Represents an aggregated order book price level in a flat C compatible layout.
+ */
+typedef struct BookLevel {
+    /**
+     * The raw fixed-point price of the level.
+     */
+    int64_t price_raw;
+    /**
+     * The raw fixed-point total size of the level.
+     */
+    uint64_t size_raw;
+    /**
+     * The number of orders at the level.
+     */
+    uint64_t count;
+} BookLevel;
 
 /**
  * Represents a NULL book order (used with the `Clear` action or where an order is not specified).
  */
-#define NULL_ORDER (BookOrder_t){ .side = OrderSide_NoOrderSide, .price = (Price_t){ .raw = 0, .precision = 0 }, .size = (Quantity_t){ .raw = 0, .precision = 0 }, .order_id = 0 }
+#define NULL_ORDER (BookOrder_t){ .side = OrderSide_NoOrderSide, .price = (Price_t){ .raw = 0, .precision = 0 }, .size = (Quantity_t){ .raw = 0, .precision = 0 }, .order_id = 0, .flags = 0 }
 
 /**
  * The sentinel `Price` representing errors (this will be removed when Cython is gone).
@@ -1495,6 +1931,314 @@ struct Data_t data_clone(const struct Data_t *data);
 
 void interned_string_stats(void);
 
+/**
+ * Creates a new cash account with no balances.
+ *
+ * # Safety
+ *
+ * - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+ */
+struct CashAccount_API cash_account_new(struct AccountId_t account_id,
+                                        const struct Currency_t *base_currency,
+                                        uint8_t calculate_account_state,
+                                        uint64_t ts_init);
+
+void cash_account_drop(struct CashAccount_API account);
+
+/**
+ * Updates the balance of the account for the currency of `total`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `total` is not the sum of `locked` and `free`.
+ * - If `total` is negative.
+ */
+void cash_account_update_balance(struct CashAccount_API *account,
+                                 struct Money_t total,
+                                 struct Money_t locked,
+                                 struct Money_t free);
+
+/**
+ * Returns the total balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t cash_account_balance_total(const struct CashAccount_API *account,
+                                          struct Currency_t currency);
+
+/**
+ * Returns the free balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t cash_account_balance_free(const struct CashAccount_API *account,
+                                         struct Currency_t currency);
+
+/**
+ * Returns the locked balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t cash_account_balance_locked(const struct CashAccount_API *account,
+                                           struct Currency_t currency);
+
+/**
+ * Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `side` is `NO_ORDER_SIDE`.
+ */
+struct Money_t cash_account_calculate_balance_locked(struct CashAccount_API *account,
+                                                     const struct InstrumentAny_API *instrument,
+                                                     enum OrderSide side,
+                                                     struct Quantity_t quantity,
+                                                     struct Price_t price,
+                                                     uint8_t use_quote_for_inverse);
+
+/**
+ * Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+ * freed with `vec_money_drop`.
+ *
+ * # Safety
+ *
+ * - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `order_side` is `NO_ORDER_SIDE`.
+ */
+CVec cash_account_calculate_pnls(const struct CashAccount_API *account,
+                                 const struct InstrumentAny_API *instrument,
+                                 enum OrderSide order_side,
+                                 struct Quantity_t last_qty,
+                                 struct Price_t last_px,
+                                 const struct Position_API *position);
+
+/**
+ * Creates a new margin account with no balances (and a default leverage of 1).
+ *
+ * # Safety
+ *
+ * - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+ */
+struct MarginAccount_API margin_account_new(struct AccountId_t account_id,
+                                            const struct Currency_t *base_currency,
+                                            uint8_t calculate_account_state,
+                                            uint64_t ts_init);
+
+void margin_account_drop(struct MarginAccount_API account);
+
+/**
+ * Updates the balance of the account for the currency of `total`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `total` is not the sum of `locked` and `free`.
+ * - If `total` is negative.
+ */
+void margin_account_update_balance(struct MarginAccount_API *account,
+                                   struct Money_t total,
+                                   struct Money_t locked,
+                                   struct Money_t free);
+
+/**
+ * Returns the total balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t margin_account_balance_total(const struct MarginAccount_API *account,
+                                            struct Currency_t currency);
+
+/**
+ * Returns the free balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t margin_account_balance_free(const struct MarginAccount_API *account,
+                                           struct Currency_t currency);
+
+/**
+ * Returns the locked balance for the `currency` (zero if the account has no balance).
+ */
+struct Money_t margin_account_balance_locked(const struct MarginAccount_API *account,
+                                             struct Currency_t currency);
+
+void margin_account_set_default_leverage(struct MarginAccount_API *account, double leverage);
+
+void margin_account_set_leverage(struct MarginAccount_API *account,
+                                 struct InstrumentId_t instrument_id,
+                                 double leverage);
+
+double margin_account_leverage(const struct MarginAccount_API *account,
+                               struct InstrumentId_t instrument_id);
+
+/**
+ * Updates the initial margin for the instrument, and recalculates the balance.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the account has no balance in the margin currency.
+ * - If the total margin exceeds the total balance.
+ */
+void margin_account_update_initial_margin(struct MarginAccount_API *account,
+                                          struct InstrumentId_t instrument_id,
+                                          struct Money_t margin_init);
+
+/**
+ * Updates the maintenance margin for the instrument, and recalculates the balance.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the account has no balance in the margin currency.
+ * - If the total margin exceeds the total balance.
+ */
+void margin_account_update_maintenance_margin(struct MarginAccount_API *account,
+                                              struct InstrumentId_t instrument_id,
+                                              struct Money_t margin_maintenance);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If there is no margin for the instrument.
+ */
+struct Money_t margin_account_initial_margin(const struct MarginAccount_API *account,
+                                             struct InstrumentId_t instrument_id);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If there is no margin for the instrument.
+ */
+struct Money_t margin_account_maintenance_margin(const struct MarginAccount_API *account,
+                                                 struct InstrumentId_t instrument_id);
+
+/**
+ * Returns the initial margin for a position of `quantity` at `price`, using the leverage for
+ * the instrument (where a zero leverage falls back to the default leverage).
+ *
+ * For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+ */
+struct Money_t margin_account_calculate_initial_margin(struct MarginAccount_API *account,
+                                                       const struct InstrumentAny_API *instrument,
+                                                       struct Quantity_t quantity,
+                                                       struct Price_t price,
+                                                       uint8_t use_quote_for_inverse);
+
+/**
+ * Returns the maintenance margin for a position of `quantity` at `price`, using the leverage for
+ * the instrument (where a zero leverage falls back to the default leverage).
+ *
+ * For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+ */
+struct Money_t margin_account_calculate_maintenance_margin(struct MarginAccount_API *account,
+                                                           const struct InstrumentAny_API *instrument,
+                                                           struct Quantity_t quantity,
+                                                           struct Price_t price,
+                                                           uint8_t use_quote_for_inverse);
+
+/**
+ * Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `side` is `NO_ORDER_SIDE`.
+ */
+struct Money_t margin_account_calculate_balance_locked(struct MarginAccount_API *account,
+                                                       const struct InstrumentAny_API *instrument,
+                                                       enum OrderSide side,
+                                                       struct Quantity_t quantity,
+                                                       struct Price_t price,
+                                                       uint8_t use_quote_for_inverse);
+
+/**
+ * Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+ * freed with `vec_money_drop`.
+ *
+ * # Safety
+ *
+ * - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `order_side` is `NO_ORDER_SIDE`.
+ */
+CVec margin_account_calculate_pnls(const struct MarginAccount_API *account,
+                                   const struct InstrumentAny_API *instrument,
+                                   enum OrderSide order_side,
+                                   struct Quantity_t last_qty,
+                                   struct Price_t last_px,
+                                   const struct Position_API *position);
+
+/**
+ * Creates a new maker/taker commission model with fees in basis points.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `maker_bps` or `taker_bps` is not finite.
+ */
+struct CommissionModel_API commission_model_maker_taker_new(double maker_bps, double taker_bps);
+
+/**
+ * Creates a new per-contract commission model.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `fee_per_contract` is not finite or is negative.
+ */
+struct CommissionModel_API commission_model_per_contract_new(double fee_per_contract);
+
+/**
+ * Creates a new tiered commission model from `len` tiers.
+ *
+ * # Safety
+ *
+ * - Assumes `min_volumes`, `maker_bps` and `taker_bps` are valid pointers to arrays of
+ *   at least `len` elements.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If any value is not finite.
+ * - If the tiers are empty, do not start at zero volume, or are not in ascending order.
+ */
+struct CommissionModel_API commission_model_tiered_new(const double *min_volumes,
+                                                       const double *maker_bps,
+                                                       const double *taker_bps,
+                                                       uintptr_t len);
+
+void commission_model_drop(struct CommissionModel_API model);
+
+/**
+ * Sets the traded volume used to select the tier of a tiered commission model.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `model` is not a tiered commission model.
+ * - If `volume` is not finite.
+ */
+void commission_model_set_volume(struct CommissionModel_API *model, double volume);
+
+/**
+ * Calculates the commission for a fill, returned in the `settlement_currency`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `is_inverse` and `price` is zero.
+ * - If `liquidity_side` is `NO_LIQUIDITY_SIDE` for a maker/taker or tiered model.
+ */
+struct Money_t commission_model_calculate(const struct CommissionModel_API *model,
+                                          struct Quantity_t quantity,
+                                          struct Price_t price,
+                                          struct Quantity_t multiplier,
+                                          uint8_t is_inverse,
+                                          struct Currency_t settlement_currency,
+                                          enum LiquiditySide liquidity_side);
+
 struct BarSpecification_t bar_specification_new(uintptr_t step,
                                                 uint8_t aggregation,
                                                 uint8_t price_type);
@@ -1610,6 +2354,56 @@ uint64_t bar_hash(const struct Bar_t *bar);
  */
 const char *bar_to_cstr(const struct Bar_t *bar);
 
+struct ContinuousContractBuilder_API continuous_contract_builder_new(struct BarType_t bar_type,
+                                                                     enum RollRule roll_rule,
+                                                                     uint32_t roll_days,
+                                                                     enum AdjustmentMethod adjustment_method);
+
+void continuous_contract_builder_drop(struct ContinuousContractBuilder_API builder);
+
+/**
+ * Adds the bars for a single contract expiring at `expiration_ns`.
+ *
+ * The bars are copied, so the caller retains ownership of the `bars` memory.
+ *
+ * # Safety
+ *
+ * - Assumes `bars` is a valid `CVec` containing `Bar` objects.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the contract was already added, or the bars are invalid for it.
+ */
+void continuous_contract_builder_add_contract(struct ContinuousContractBuilder_API *builder,
+                                              struct InstrumentId_t instrument_id,
+                                              uint64_t expiration_ns,
+                                              const CVec *bars);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If the continuous series cannot be built from the added contracts.
+ */
+struct ContinuousSeries_API continuous_contract_builder_build(const struct ContinuousContractBuilder_API *builder);
+
+void continuous_series_drop(struct ContinuousSeries_API series);
+
+/**
+ * Returns a `CVec` of the adjusted `Bar`s, which must be freed with `vec_bars_drop`.
+ */
+CVec continuous_series_bars(const struct ContinuousSeries_API *series);
+
+/**
+ * Returns a `CVec` of the `RollEvent`s, which must be freed with `vec_roll_events_drop`.
+ */
+CVec continuous_series_rolls(const struct ContinuousSeries_API *series);
+
+void vec_bars_drop(CVec v);
+
+void vec_roll_events_drop(CVec v);
+
 struct OrderBookDelta_t orderbook_delta_new(struct InstrumentId_t instrument_id,
                                             enum BookAction action,
                                             struct BookOrder_t order,
@@ -1728,6 +2522,43 @@ uint64_t quote_tick_hash(const struct QuoteTick_t *delta);
  */
 const char *quote_tick_to_cstr(const struct QuoteTick_t *quote);
 
+/**
+ * Returns the [`QuoteTick`] inverted for the pair with the base and quote currencies swapped.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the bid or ask price is not positive.
+ */
+struct QuoteTick_t quote_tick_invert(const struct QuoteTick_t *quote,
+                                     struct InstrumentId_t instrument_id);
+
+/**
+ * Derives the [`QuoteTick`] for the `base`/`quote` pair from two quotes for pairs which share
+ * a currency, writing it to `cross_quote`.
+ *
+ * Returns 1 if the cross quote was derived, otherwise 0 (with `cross_quote` left unwritten).
+ *
+ * # Safety
+ *
+ * - Assumes `cross_quote` is a valid pointer to write the [`QuoteTick`] to.
+ */
+uint8_t quote_tick_cross(const struct QuoteTick_t *a,
+                         struct Currency_t a_base,
+                         struct Currency_t a_quote,
+                         const struct QuoteTick_t *b,
+                         struct Currency_t b_base,
+                         struct Currency_t b_quote,
+                         struct InstrumentId_t instrument_id,
+                         struct Currency_t base,
+                         struct Currency_t quote,
+                         struct QuoteTick_t *cross_quote);
+
+/**
+ * Returns the spread between the given `bid` and `ask` prices, or `NaN` for a crossed book.
+ */
+double price_spread(double bid, double ask);
+
 struct TradeTick_t trade_tick_new(struct InstrumentId_t instrument_id,
                                   int64_t price_raw,
                                   uint8_t price_prec,
@@ -1758,6 +2589,20 @@ const char *account_type_to_cstr(enum AccountType value);
  */
 enum AccountType account_type_from_cstr(const char *ptr);
 
+/**
+ * Returns an enum from its name or a common broker alias (e.g. `SPOT` or `CROSS_MARGIN`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the string is not a supported `AccountType` alias.
+ */
+enum AccountType account_type_from_broker_alias(const char *ptr);
+
 const char *aggregation_source_to_cstr(enum AggregationSource value);
 
 /**
@@ -1780,6 +2625,26 @@ const char *aggressor_side_to_cstr(enum AggressorSide value);
  */
 enum AggressorSide aggressor_side_from_cstr(const char *ptr);
 
+/**
+ * Returns the opposite aggressor side (`NO_AGGRESSOR` is unchanged).
+ */
+enum AggressorSide aggressor_side_opposite(enum AggressorSide value);
+
+/**
+ * Returns whether the aggressor side is `BUYER`.
+ */
+uint8_t aggressor_side_is_long_side(enum AggressorSide value);
+
+/**
+ * Returns whether the aggressor side is `SELLER`.
+ */
+uint8_t aggressor_side_is_short_side(enum AggressorSide value);
+
+/**
+ * Returns the liquidity side of the passive counterparty to a trade with the aggressor side.
+ */
+enum LiquiditySide aggressor_side_maker_side(enum AggressorSide value);
+
 const char *asset_class_to_cstr(enum AssetClass value);
 
 /**
@@ -1791,6 +2656,12 @@ const char *asset_class_to_cstr(enum AssetClass value);
  */
 enum AssetClass asset_class_from_cstr(const char *ptr);
 
+uint8_t asset_class_is_crypto(enum AssetClass value);
+
+uint8_t asset_class_is_24_7(enum AssetClass value);
+
+const char *asset_class_description(enum AssetClass value);
+
 const char *instrument_class_to_cstr(enum InstrumentClass value);
 
 /**
@@ -1802,9 +2673,15 @@ const char *instrument_class_to_cstr(enum InstrumentClass value);
  */
 enum InstrumentClass instrument_class_from_cstr(const char *ptr);
 
-const char *bar_aggregation_to_cstr(uint8_t value);
+uint8_t instrument_class_is_24_7(enum InstrumentClass value);
 
-/**
+uint8_t instrument_class_requires_expiry(enum InstrumentClass value);
+
+const char *instrument_class_description(enum InstrumentClass value);
+
+const char *bar_aggregation_to_cstr(uint8_t value);
+
+/**
  * Returns an enum from a Python string.
  *
  * # Safety
@@ -1813,6 +2690,20 @@ const char *bar_aggregation_to_cstr(uint8_t value);
  */
 uint8_t bar_aggregation_from_cstr(const char *ptr);
 
+/**
+ * Returns an enum from a common time unit abbreviation (e.g. `M`, `H` or `D`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the string is neither a supported abbreviation nor a `BarAggregation` name.
+ */
+uint8_t bar_aggregation_from_abbrev(const char *ptr);
+
 const char *book_action_to_cstr(enum BookAction value);
 
 /**
@@ -1824,6 +2715,8 @@ const char *book_action_to_cstr(enum BookAction value);
  */
 enum BookAction book_action_from_cstr(const char *ptr);
 
+uint8_t book_action_priority(enum BookAction value);
+
 const char *book_type_to_cstr(enum BookType value);
 
 /**
@@ -1835,6 +2728,11 @@ const char *book_type_to_cstr(enum BookType value);
  */
 enum BookType book_type_from_cstr(const char *ptr);
 
+/**
+ * Returns whether deltas with the given `action` are meaningful for a book of the book type.
+ */
+uint8_t book_type_allows_action(enum BookType value, enum BookAction action);
+
 const char *contingency_type_to_cstr(enum ContingencyType value);
 
 /**
@@ -1846,6 +2744,20 @@ const char *contingency_type_to_cstr(enum ContingencyType value);
  */
 enum ContingencyType contingency_type_from_cstr(const char *ptr);
 
+/**
+ * Returns an enum from its name or a common venue alias (e.g. `O.C.O` or `OneCancelsOther`).
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the string is not a supported `ContingencyType` alias.
+ */
+enum ContingencyType contingency_type_from_alias(const char *ptr);
+
 const char *currency_type_to_cstr(enum CurrencyType value);
 
 /**
@@ -1857,6 +2769,8 @@ const char *currency_type_to_cstr(enum CurrencyType value);
  */
 enum CurrencyType currency_type_from_cstr(const char *ptr);
 
+uint8_t currency_type_default_precision(enum CurrencyType value);
+
 /**
  * Returns an enum from a Python string.
  *
@@ -1879,6 +2793,15 @@ const char *liquidity_side_to_cstr(enum LiquiditySide value);
  */
 enum LiquiditySide liquidity_side_from_cstr(const char *ptr);
 
+uint8_t liquidity_side_is_maker(enum LiquiditySide value);
+
+uint8_t liquidity_side_is_taker(enum LiquiditySide value);
+
+/**
+ * Returns the applicable fee rate for the liquidity side, or NaN if `NO_LIQUIDITY_SIDE`.
+ */
+double liquidity_side_fee_rate(enum LiquiditySide value, double maker_rate, double taker_rate);
+
 const char *market_status_to_cstr(enum MarketStatus value);
 
 /**
@@ -1892,6 +2815,15 @@ enum MarketStatus market_status_from_cstr(const char *ptr);
 
 const char *market_status_action_to_cstr(enum MarketStatusAction value);
 
+/**
+ * Returns the session phase which typically follows `value`, or `NONE` if the action
+ * carries no session phase.
+ */
+enum MarketStatusAction market_status_action_next_expected(enum MarketStatusAction value);
+
+uint8_t market_status_action_valid_transition(enum MarketStatusAction from,
+                                              enum MarketStatusAction to);
+
 /**
  * Returns an enum from a Python string.
  *
@@ -1912,6 +2844,11 @@ const char *oms_type_to_cstr(enum OmsType value);
  */
 enum OmsType oms_type_from_cstr(const char *ptr);
 
+/**
+ * Returns the default OMS type for the given `account` type.
+ */
+enum OmsType oms_type_default_for_account(enum AccountType account);
+
 const char *option_kind_to_cstr(enum OptionKind value);
 
 /**
@@ -1934,6 +2871,23 @@ const char *order_side_to_cstr(enum OrderSide value);
  */
 enum OrderSide order_side_from_cstr(const char *ptr);
 
+double order_side_as_signed_multiplier(enum OrderSide value);
+
+/**
+ * Returns the opposite order side (`NO_ORDER_SIDE` is unchanged).
+ */
+enum OrderSide order_side_opposite(enum OrderSide value);
+
+/**
+ * Returns whether the order side is `BUY`.
+ */
+uint8_t order_side_is_long_side(enum OrderSide value);
+
+/**
+ * Returns whether the order side is `SELL`.
+ */
+uint8_t order_side_is_short_side(enum OrderSide value);
+
 const char *order_status_to_cstr(enum OrderStatus value);
 
 /**
@@ -1945,6 +2899,22 @@ const char *order_status_to_cstr(enum OrderStatus value);
  */
 enum OrderStatus order_status_from_cstr(const char *ptr);
 
+uint8_t order_status_valid_transition(enum OrderStatus from_status, enum OrderStatus to_status);
+
+/**
+ * Returns whether an order with the status is live on the trading venue and can still fill.
+ */
+uint8_t order_status_is_active(enum OrderStatus value);
+
+uint8_t order_type_requires_trigger_type(enum OrderType value);
+
+uint8_t order_type_trigger_compatible(enum OrderType order_type, enum TriggerType trigger);
+
+uint8_t order_type_is_trailing(enum OrderType value);
+
+uint8_t order_type_trailing_offset_compatible(enum OrderType order_type,
+                                              enum TrailingOffsetType offset);
+
 const char *order_type_to_cstr(enum OrderType value);
 
 /**
@@ -1967,6 +2937,27 @@ const char *position_side_to_cstr(enum PositionSide value);
  */
 enum PositionSide position_side_from_cstr(const char *ptr);
 
+/**
+ * Returns the opposite side (`LONG` for `SHORT` and vice versa), with `FLAT` and
+ * `NO_POSITION_SIDE` unchanged.
+ */
+enum PositionSide position_side_opposite(enum PositionSide value);
+
+/**
+ * Returns whether the side is `FLAT`.
+ */
+uint8_t position_side_is_flat(enum PositionSide value);
+
+/**
+ * Returns whether the side is `LONG`.
+ */
+uint8_t position_side_is_long_side(enum PositionSide value);
+
+/**
+ * Returns whether the side is `SHORT`.
+ */
+uint8_t position_side_is_short_side(enum PositionSide value);
+
 const char *price_type_to_cstr(enum PriceType value);
 
 /**
@@ -1989,6 +2980,17 @@ const char *record_flag_to_cstr(enum RecordFlag value);
  */
 enum RecordFlag record_flag_from_cstr(const char *ptr);
 
+const char *rounding_mode_to_cstr(enum RoundingMode value);
+
+/**
+ * Returns an enum from a Python string.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ */
+enum RoundingMode rounding_mode_from_cstr(const char *ptr);
+
 const char *time_in_force_to_cstr(enum TimeInForce value);
 
 /**
@@ -2000,6 +3002,10 @@ const char *time_in_force_to_cstr(enum TimeInForce value);
  */
 enum TimeInForce time_in_force_from_cstr(const char *ptr);
 
+uint8_t time_in_force_is_immediate(enum TimeInForce value);
+
+uint8_t time_in_force_allows_partial(enum TimeInForce value);
+
 const char *trading_state_to_cstr(enum TradingState value);
 
 /**
@@ -2033,6 +3039,11 @@ const char *trigger_type_to_cstr(enum TriggerType value);
  */
 enum TriggerType trigger_type_from_cstr(const char *ptr);
 
+/**
+ * Returns the default trigger type for the given `order_type`.
+ */
+enum TriggerType trigger_type_default_for_order_type(enum OrderType order_type);
+
 /**
  * # Safety
  *
@@ -2100,6 +3111,206 @@ struct OrderRejected_t order_rejected_new(struct TraderId_t trader_id,
                                           uint64_t ts_init,
                                           uint8_t reconciliation);
 
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_canceled_new(struct TraderId_t trader_id,
+                                            struct StrategyId_t strategy_id,
+                                            struct InstrumentId_t instrument_id,
+                                            struct ClientOrderId_t client_order_id,
+                                            const struct VenueOrderId_t *venue_order_id,
+                                            const struct AccountId_t *account_id,
+                                            UUID4_t event_id,
+                                            uint64_t ts_event,
+                                            uint64_t ts_init,
+                                            uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_expired_new(struct TraderId_t trader_id,
+                                           struct StrategyId_t strategy_id,
+                                           struct InstrumentId_t instrument_id,
+                                           struct ClientOrderId_t client_order_id,
+                                           const struct VenueOrderId_t *venue_order_id,
+                                           const struct AccountId_t *account_id,
+                                           UUID4_t event_id,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init,
+                                           uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_triggered_new(struct TraderId_t trader_id,
+                                             struct StrategyId_t strategy_id,
+                                             struct InstrumentId_t instrument_id,
+                                             struct ClientOrderId_t client_order_id,
+                                             const struct VenueOrderId_t *venue_order_id,
+                                             const struct AccountId_t *account_id,
+                                             UUID4_t event_id,
+                                             uint64_t ts_event,
+                                             uint64_t ts_init,
+                                             uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_pending_update_new(struct TraderId_t trader_id,
+                                                  struct StrategyId_t strategy_id,
+                                                  struct InstrumentId_t instrument_id,
+                                                  struct ClientOrderId_t client_order_id,
+                                                  const struct VenueOrderId_t *venue_order_id,
+                                                  struct AccountId_t account_id,
+                                                  UUID4_t event_id,
+                                                  uint64_t ts_event,
+                                                  uint64_t ts_init,
+                                                  uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_pending_cancel_new(struct TraderId_t trader_id,
+                                                  struct StrategyId_t strategy_id,
+                                                  struct InstrumentId_t instrument_id,
+                                                  struct ClientOrderId_t client_order_id,
+                                                  const struct VenueOrderId_t *venue_order_id,
+                                                  struct AccountId_t account_id,
+                                                  UUID4_t event_id,
+                                                  uint64_t ts_event,
+                                                  uint64_t ts_init,
+                                                  uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_modify_rejected_new(struct TraderId_t trader_id,
+                                                   struct StrategyId_t strategy_id,
+                                                   struct InstrumentId_t instrument_id,
+                                                   struct ClientOrderId_t client_order_id,
+                                                   const struct VenueOrderId_t *venue_order_id,
+                                                   const struct AccountId_t *account_id,
+                                                   const char *reason_ptr,
+                                                   UUID4_t event_id,
+                                                   uint64_t ts_event,
+                                                   uint64_t ts_init,
+                                                   uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `reason_ptr` is a valid C string pointer.
+ * - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_cancel_rejected_new(struct TraderId_t trader_id,
+                                                   struct StrategyId_t strategy_id,
+                                                   struct InstrumentId_t instrument_id,
+                                                   struct ClientOrderId_t client_order_id,
+                                                   const struct VenueOrderId_t *venue_order_id,
+                                                   const struct AccountId_t *account_id,
+                                                   const char *reason_ptr,
+                                                   UUID4_t event_id,
+                                                   uint64_t ts_event,
+                                                   uint64_t ts_init,
+                                                   uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `venue_order_id`, `account_id`, `price` and `trigger_price` are each either null
+ *   (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_updated_new(struct TraderId_t trader_id,
+                                           struct StrategyId_t strategy_id,
+                                           struct InstrumentId_t instrument_id,
+                                           struct ClientOrderId_t client_order_id,
+                                           const struct VenueOrderId_t *venue_order_id,
+                                           const struct AccountId_t *account_id,
+                                           struct Quantity_t quantity,
+                                           const struct Price_t *price,
+                                           const struct Price_t *trigger_price,
+                                           UUID4_t event_id,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init,
+                                           uint8_t reconciliation);
+
+/**
+ * # Safety
+ *
+ * - Assumes `position_id` and `commission` are each either null (no value) or a valid pointer.
+ */
+struct OrderEventAny_API order_filled_new(struct TraderId_t trader_id,
+                                          struct StrategyId_t strategy_id,
+                                          struct InstrumentId_t instrument_id,
+                                          struct ClientOrderId_t client_order_id,
+                                          struct VenueOrderId_t venue_order_id,
+                                          struct AccountId_t account_id,
+                                          struct TradeId_t trade_id,
+                                          const struct PositionId_t *position_id,
+                                          enum OrderSide order_side,
+                                          enum OrderType order_type,
+                                          struct Quantity_t last_qty,
+                                          struct Price_t last_px,
+                                          struct Currency_t currency,
+                                          const struct Money_t *commission,
+                                          enum LiquiditySide liquidity_side,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init,
+                                          uint8_t reconciliation);
+
+void order_event_drop(struct OrderEventAny_API event);
+
+UUID4_t order_event_id(const struct OrderEventAny_API *event);
+
+struct TraderId_t order_event_trader_id(const struct OrderEventAny_API *event);
+
+struct StrategyId_t order_event_strategy_id(const struct OrderEventAny_API *event);
+
+struct InstrumentId_t order_event_instrument_id(const struct OrderEventAny_API *event);
+
+struct ClientOrderId_t order_event_client_order_id(const struct OrderEventAny_API *event);
+
+/**
+ * Writes the venue order ID of the event to `output`, returning 1 if the event has one,
+ * otherwise 0 (leaving `output` unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `output` is a valid pointer to write the venue order ID to.
+ */
+uint8_t order_event_venue_order_id(const struct OrderEventAny_API *event,
+                                   struct VenueOrderId_t *output);
+
+/**
+ * Writes the account ID of the event to `output`, returning 1 if the event has one,
+ * otherwise 0 (leaving `output` unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `output` is a valid pointer to write the account ID to.
+ */
+uint8_t order_event_account_id(const struct OrderEventAny_API *event, struct AccountId_t *output);
+
+uint8_t order_event_reconciliation(const struct OrderEventAny_API *event);
+
+uint64_t order_event_ts_event(const struct OrderEventAny_API *event);
+
+uint64_t order_event_ts_init(const struct OrderEventAny_API *event);
+
 /**
  * Returns a Nautilus identifier from a C string pointer.
  *
@@ -2296,6 +3507,318 @@ struct VenueOrderId_t venue_order_id_new(const char *ptr);
 
 uint64_t venue_order_id_hash(const struct VenueOrderId_t *id);
 
+/**
+ * Creates a new [`CurrencyPair`] instrument.
+ *
+ * # Safety
+ *
+ * - Assumes each of `lot_size`, `max_quantity`, `min_quantity`, `max_notional`, `min_notional`,
+ *   `max_price` and `min_price` is either null (no value) or a valid pointer of its type.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If any fee or margin value is not finite.
+ * - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+ */
+struct InstrumentAny_API currency_pair_new(struct InstrumentId_t instrument_id,
+                                           struct Symbol_t raw_symbol,
+                                           struct Currency_t base_currency,
+                                           struct Currency_t quote_currency,
+                                           uint8_t price_precision,
+                                           uint8_t size_precision,
+                                           struct Price_t price_increment,
+                                           struct Quantity_t size_increment,
+                                           double taker_fee,
+                                           double maker_fee,
+                                           double margin_init,
+                                           double margin_maint,
+                                           const struct Quantity_t *lot_size,
+                                           const struct Quantity_t *max_quantity,
+                                           const struct Quantity_t *min_quantity,
+                                           const struct Money_t *max_notional,
+                                           const struct Money_t *min_notional,
+                                           const struct Price_t *max_price,
+                                           const struct Price_t *min_price,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init);
+
+/**
+ * Creates a new [`CryptoPerpetual`] instrument.
+ *
+ * # Safety
+ *
+ * - Assumes each of `multiplier`, `lot_size`, `max_quantity`, `min_quantity`, `max_notional`,
+ *   `min_notional`, `max_price` and `min_price` is either null (no value) or a valid pointer
+ *   of its type.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If any fee or margin value is not finite.
+ * - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+ */
+struct InstrumentAny_API crypto_perpetual_new(struct InstrumentId_t instrument_id,
+                                              struct Symbol_t raw_symbol,
+                                              struct Currency_t base_currency,
+                                              struct Currency_t quote_currency,
+                                              struct Currency_t settlement_currency,
+                                              uint8_t is_inverse,
+                                              uint8_t price_precision,
+                                              uint8_t size_precision,
+                                              struct Price_t price_increment,
+                                              struct Quantity_t size_increment,
+                                              double maker_fee,
+                                              double taker_fee,
+                                              double margin_init,
+                                              double margin_maint,
+                                              const struct Quantity_t *multiplier,
+                                              const struct Quantity_t *lot_size,
+                                              const struct Quantity_t *max_quantity,
+                                              const struct Quantity_t *min_quantity,
+                                              const struct Money_t *max_notional,
+                                              const struct Money_t *min_notional,
+                                              const struct Price_t *max_price,
+                                              const struct Price_t *min_price,
+                                              uint64_t ts_event,
+                                              uint64_t ts_init);
+
+/**
+ * Creates a new [`FuturesContract`] instrument.
+ *
+ * Margin values of NaN are treated as not set.
+ *
+ * # Safety
+ *
+ * - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+ * - Assumes `underlying_ptr` is a valid C string pointer.
+ * - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+ *   null (no value) or a valid pointer of its type.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+ */
+struct InstrumentAny_API futures_contract_new(struct InstrumentId_t instrument_id,
+                                              struct Symbol_t raw_symbol,
+                                              enum AssetClass asset_class,
+                                              const char *exchange_ptr,
+                                              const char *underlying_ptr,
+                                              uint64_t activation_ns,
+                                              uint64_t expiration_ns,
+                                              struct Currency_t currency,
+                                              uint8_t price_precision,
+                                              struct Price_t price_increment,
+                                              struct Quantity_t multiplier,
+                                              struct Quantity_t lot_size,
+                                              const struct Quantity_t *max_quantity,
+                                              const struct Quantity_t *min_quantity,
+                                              const struct Price_t *max_price,
+                                              const struct Price_t *min_price,
+                                              double margin_init,
+                                              double margin_maint,
+                                              uint64_t ts_event,
+                                              uint64_t ts_init);
+
+/**
+ * Creates a new [`OptionsContract`] instrument.
+ *
+ * Margin values of NaN are treated as not set.
+ *
+ * # Safety
+ *
+ * - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+ * - Assumes `underlying_ptr` is a valid C string pointer.
+ * - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+ *   null (no value) or a valid pointer of its type.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+ */
+struct InstrumentAny_API options_contract_new(struct InstrumentId_t instrument_id,
+                                              struct Symbol_t raw_symbol,
+                                              enum AssetClass asset_class,
+                                              const char *exchange_ptr,
+                                              const char *underlying_ptr,
+                                              enum OptionKind option_kind,
+                                              struct Price_t strike_price,
+                                              struct Currency_t currency,
+                                              uint64_t activation_ns,
+                                              uint64_t expiration_ns,
+                                              uint8_t price_precision,
+                                              struct Price_t price_increment,
+                                              struct Quantity_t multiplier,
+                                              struct Quantity_t lot_size,
+                                              const struct Quantity_t *max_quantity,
+                                              const struct Quantity_t *min_quantity,
+                                              const struct Price_t *max_price,
+                                              const struct Price_t *min_price,
+                                              double margin_init,
+                                              double margin_maint,
+                                              uint64_t ts_event,
+                                              uint64_t ts_init);
+
+void instrument_drop(struct InstrumentAny_API instrument);
+
+struct InstrumentId_t instrument_id(const struct InstrumentAny_API *instrument);
+
+enum InstrumentClass instrument_class(const struct InstrumentAny_API *instrument);
+
+struct Currency_t instrument_quote_currency(const struct InstrumentAny_API *instrument);
+
+struct Currency_t instrument_settlement_currency(const struct InstrumentAny_API *instrument);
+
+uint8_t instrument_is_inverse(const struct InstrumentAny_API *instrument);
+
+uint8_t instrument_is_quanto(const struct InstrumentAny_API *instrument);
+
+uint8_t instrument_price_precision(const struct InstrumentAny_API *instrument);
+
+uint8_t instrument_size_precision(const struct InstrumentAny_API *instrument);
+
+struct Price_t instrument_price_increment(const struct InstrumentAny_API *instrument);
+
+struct Quantity_t instrument_size_increment(const struct InstrumentAny_API *instrument);
+
+struct Quantity_t instrument_multiplier(const struct InstrumentAny_API *instrument);
+
+/**
+ * Returns the lot size, or NaN if the instrument has none.
+ */
+double instrument_lot_size(const struct InstrumentAny_API *instrument);
+
+/**
+ * Returns the maximum order quantity, or NaN if the instrument has none.
+ */
+double instrument_max_quantity(const struct InstrumentAny_API *instrument);
+
+/**
+ * Returns the minimum order quantity, or NaN if the instrument has none.
+ */
+double instrument_min_quantity(const struct InstrumentAny_API *instrument);
+
+/**
+ * Returns the expiration UNIX timestamp (nanoseconds), or zero if the instrument does not expire.
+ */
+uint64_t instrument_expiration_ns(const struct InstrumentAny_API *instrument);
+
+struct Price_t instrument_make_price(const struct InstrumentAny_API *instrument, double value);
+
+struct Quantity_t instrument_make_qty(const struct InstrumentAny_API *instrument, double value);
+
+struct Price_t instrument_make_price_rounded(const struct InstrumentAny_API *instrument,
+                                             double value,
+                                             enum RoundingMode mode);
+
+struct Quantity_t instrument_make_qty_rounded(const struct InstrumentAny_API *instrument,
+                                              double value,
+                                              enum RoundingMode mode);
+
+/**
+ * Validates an order price and quantity against the instrument, returning 1 if valid,
+ * otherwise 0 with the violation written to `violation`.
+ *
+ * # Safety
+ *
+ * - Assumes `violation` is a valid pointer to write the violation to.
+ */
+uint8_t instrument_validate_order(const struct InstrumentAny_API *instrument,
+                                  struct Price_t price,
+                                  struct Quantity_t quantity,
+                                  enum InstrumentViolation *violation);
+
+const char *instrument_violation_to_cstr(enum InstrumentViolation value);
+
+struct Money_t instrument_notional_value(const struct InstrumentAny_API *instrument,
+                                         struct Quantity_t quantity,
+                                         struct Price_t price,
+                                         uint8_t use_quote_for_inverse);
+
+/**
+ * Returns the equivalent quantity of the base asset for the given quote `quantity` at `last_px`.
+ */
+struct Quantity_t instrument_calculate_base_quantity(const struct InstrumentAny_API *instrument,
+                                                     struct Quantity_t quantity,
+                                                     struct Price_t last_px);
+
+/**
+ * Returns the intrinsic value of one contract at the given `underlying` price.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+struct Money_t options_contract_intrinsic_value(const struct InstrumentAny_API *instrument,
+                                                struct Price_t underlying);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+uint8_t options_contract_is_itm(const struct InstrumentAny_API *instrument,
+                                struct Price_t underlying);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+uint8_t options_contract_is_atm(const struct InstrumentAny_API *instrument,
+                                struct Price_t underlying);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+uint8_t options_contract_is_otm(const struct InstrumentAny_API *instrument,
+                                struct Price_t underlying);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+uint8_t options_contract_is_active(const struct InstrumentAny_API *instrument, uint64_t now_ns);
+
+/**
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+uint8_t options_contract_is_expired(const struct InstrumentAny_API *instrument, uint64_t now_ns);
+
+/**
+ * Returns the fractional number of days from `now_ns` until expiration (zero once expired).
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `instrument` is not an options contract.
+ */
+double options_contract_days_to_expiry(const struct InstrumentAny_API *instrument, uint64_t now_ns);
+
+/**
+ * Parses an OCC option symbol, returning 1 if successful with the components written to
+ * `output`, otherwise 0.
+ *
+ * # Safety
+ *
+ * - Assumes `ptr` is a valid C string pointer.
+ * - Assumes `output` is a valid pointer to write the parsed symbol to.
+ */
+uint8_t occ_symbol_parse(const char *ptr, struct OccSymbol *output);
+
 /**
  * # Safety
  *
@@ -2386,9 +3909,22 @@ void orderbook_clear_bids(struct OrderBook_API *book, uint64_t sequence, uint64_
 
 void orderbook_clear_asks(struct OrderBook_API *book, uint64_t sequence, uint64_t ts_event);
 
-void orderbook_apply_delta(struct OrderBook_API *book, const struct OrderBookDelta_t *delta);
+void orderbook_set_integrity_policy(struct OrderBook_API *book, enum BookIntegrityPolicy policy);
 
-void orderbook_apply_deltas(struct OrderBook_API *book, const struct OrderBookDeltas_API *deltas);
+/**
+ * Applies the order book delta, returning 1 on success or 0 if the delta's action is not
+ * allowed for the book type, or the book's integrity policy is `RaiseError` and the delta
+ * crosses the book.
+ */
+uint8_t orderbook_apply_delta(struct OrderBook_API *book, const struct OrderBookDelta_t *delta);
+
+/**
+ * Applies the order book deltas, returning 1 on success or 0 if any delta's action is not
+ * allowed for the book type, or the book's integrity policy is `RaiseError` and a delta
+ * crosses the book.
+ */
+uint8_t orderbook_apply_deltas(struct OrderBook_API *book,
+                               const struct OrderBookDeltas_API *deltas);
 
 void orderbook_apply_depth(struct OrderBook_API *book, const struct OrderBookDepth10_t *depth);
 
@@ -2420,6 +3956,20 @@ double orderbook_get_quantity_for_price(struct OrderBook_API *book,
                                         struct Price_t price,
                                         enum OrderSide order_side);
 
+struct Quantity_t orderbook_cumulative_depth(const struct OrderBook_API *book,
+                                             enum OrderSide side,
+                                             struct Price_t price);
+
+/**
+ * Returns the size imbalance over the top `depth` levels per side (`NaN` if the book is empty).
+ */
+double orderbook_imbalance(const struct OrderBook_API *book, uintptr_t depth);
+
+/**
+ * Returns the microprice (`NaN` if either side of the book is empty).
+ */
+double orderbook_microprice(const struct OrderBook_API *book);
+
 /**
  * Updates the order book with a quote tick.
  *
@@ -2442,10 +3992,60 @@ void orderbook_update_trade_tick(struct OrderBook_API *book, const struct TradeT
 
 CVec orderbook_simulate_fills(const struct OrderBook_API *book, struct BookOrder_t order);
 
+/**
+ * Simulates filling `qty` against the opposite side of the book, up to the optional
+ * `price_limit` (pass null for no limit).
+ *
+ * The result must be freed with `simulated_fills_drop`. If `is_partial` is set then the
+ * book did not have sufficient liquidity at or better than `price_limit`.
+ *
+ * # Safety
+ *
+ * - Assumes `price_limit` is either null or a valid pointer to a `Price`.
+ */
+struct CSimulatedFills orderbook_simulate_fills_for_quantity(const struct OrderBook_API *book,
+                                                             enum OrderSide order_side,
+                                                             const struct Price_t *price_limit,
+                                                             struct Quantity_t qty);
+
+void simulated_fills_drop(struct CSimulatedFills result);
+
 uint8_t orderbook_check_integrity(const struct OrderBook_API *book);
 
+uint32_t orderbook_checksum_crc32_kraken(const struct OrderBook_API *book, uintptr_t depth);
+
+uint32_t orderbook_checksum_crc32_okx(const struct OrderBook_API *book, uintptr_t depth);
+
+uint8_t orderbook_verify_checksum(const struct OrderBook_API *book,
+                                  enum BookChecksumType checksum_type,
+                                  uintptr_t depth,
+                                  uint32_t expected);
+
 void vec_fills_drop(CVec v);
 
+void vec_book_fills_drop(CVec v);
+
+/**
+ * Returns a snapshot of the top `depth` levels per side, which must be freed with
+ * `book_snapshot_drop`.
+ */
+struct CBookSnapshot orderbook_snapshot(const struct OrderBook_API *book, uintptr_t depth);
+
+/**
+ * Returns the top `depth` levels per side grouped into price buckets of `tick_size`, which
+ * must be freed with `book_snapshot_drop`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `tick_size` is not positive (> 0).
+ */
+struct CBookSnapshot orderbook_group_levels(const struct OrderBook_API *book,
+                                            struct Price_t tick_size,
+                                            uintptr_t depth);
+
+void book_snapshot_drop(struct CBookSnapshot snapshot);
+
 /**
  * Returns a pretty printed `OrderBook` number of levels per side, as a C string pointer.
  */
@@ -2461,6 +4061,8 @@ struct Price_t level_price(const struct Level_API *level);
 
 CVec level_orders(const struct Level_API *level);
 
+uint32_t level_count(const struct Level_API *level);
+
 double level_size(const struct Level_API *level);
 
 double level_exposure(const struct Level_API *level);
@@ -2469,6 +4071,254 @@ void vec_levels_drop(CVec v);
 
 void vec_orders_drop(CVec v);
 
+/**
+ * Creates a new order from the values of an `OrderInitialized` event.
+ *
+ * # Safety
+ *
+ * - Assumes `price` and `trigger_price` are each either null (no price) or a valid `Price` pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `order_type` is not `MARKET`, `LIMIT`, `STOP_MARKET` or `STOP_LIMIT`.
+ * - If a price or trigger price required by the `order_type` is null.
+ */
+struct OrderAny_API order_new(struct TraderId_t trader_id,
+                              struct StrategyId_t strategy_id,
+                              struct InstrumentId_t instrument_id,
+                              struct ClientOrderId_t client_order_id,
+                              enum OrderSide order_side,
+                              enum OrderType order_type,
+                              struct Quantity_t quantity,
+                              const struct Price_t *price,
+                              const struct Price_t *trigger_price,
+                              enum TriggerType trigger_type,
+                              enum TimeInForce time_in_force,
+                              uint8_t post_only,
+                              uint8_t reduce_only,
+                              UUID4_t init_id,
+                              uint64_t ts_init);
+
+void order_drop(struct OrderAny_API order);
+
+/**
+ * Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_event(struct OrderAny_API *order, const struct OrderEventAny_API *event);
+
+/**
+ * Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_denied(struct OrderAny_API *order, struct OrderDenied_t event);
+
+/**
+ * Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_submitted(struct OrderAny_API *order, struct OrderSubmitted_t event);
+
+/**
+ * Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_accepted(struct OrderAny_API *order, struct OrderAccepted_t event);
+
+/**
+ * Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_rejected(struct OrderAny_API *order, struct OrderRejected_t event);
+
+/**
+ * Applies a pending update event to the order, returning 1 if applied, or 0 if the event
+ * was rejected (leaving the order unchanged).
+ */
+uint8_t order_apply_pending_update(struct OrderAny_API *order,
+                                   UUID4_t event_id,
+                                   uint64_t ts_event,
+                                   uint64_t ts_init);
+
+/**
+ * Applies a modify rejected event to the order, returning 1 if applied, or 0 if the event
+ * was rejected (leaving the order unchanged).
+ *
+ * # Safety
+ *
+ * - Assumes `reason_ptr` is a valid C string pointer.
+ */
+uint8_t order_apply_modify_rejected(struct OrderAny_API *order,
+                                    const char *reason_ptr,
+                                    UUID4_t event_id,
+                                    uint64_t ts_event,
+                                    uint64_t ts_init);
+
+/**
+ * Applies a canceled event to the order, returning 1 if applied, or 0 if the event was
+ * rejected (leaving the order unchanged).
+ */
+uint8_t order_apply_canceled(struct OrderAny_API *order,
+                             UUID4_t event_id,
+                             uint64_t ts_event,
+                             uint64_t ts_init);
+
+/**
+ * Applies a fill to the order, returning 1 if applied, or 0 if the fill was rejected
+ * (leaving the order unchanged).
+ */
+uint8_t order_apply_filled(struct OrderAny_API *order,
+                           struct VenueOrderId_t venue_order_id,
+                           struct AccountId_t account_id,
+                           struct TradeId_t trade_id,
+                           struct Quantity_t last_qty,
+                           struct Price_t last_px,
+                           struct Currency_t currency,
+                           struct Money_t commission,
+                           enum LiquiditySide liquidity_side,
+                           UUID4_t event_id,
+                           uint64_t ts_event,
+                           uint64_t ts_init);
+
+enum OrderStatus order_status(const struct OrderAny_API *order);
+
+struct Quantity_t order_quantity(const struct OrderAny_API *order);
+
+struct Quantity_t order_filled_qty(const struct OrderAny_API *order);
+
+struct Quantity_t order_leaves_qty(const struct OrderAny_API *order);
+
+/**
+ * Returns the average fill price, or NaN if the order has no fills.
+ */
+double order_avg_px(const struct OrderAny_API *order);
+
+uint8_t order_is_closed(const struct OrderAny_API *order);
+
+/**
+ * Creates a new flat position, which is opened by the first applied fill.
+ *
+ * # Safety
+ *
+ * - Assumes `base_currency` is either null (no base currency) or a valid `Currency` pointer.
+ */
+struct Position_API position_new(struct TraderId_t trader_id,
+                                 struct StrategyId_t strategy_id,
+                                 struct InstrumentId_t instrument_id,
+                                 struct PositionId_t position_id,
+                                 struct AccountId_t account_id,
+                                 uint8_t price_precision,
+                                 uint8_t size_precision,
+                                 struct Quantity_t multiplier,
+                                 uint8_t is_inverse,
+                                 const struct Currency_t *base_currency,
+                                 struct Currency_t quote_currency,
+                                 struct Currency_t settlement_currency,
+                                 uint64_t ts_init);
+
+void position_drop(struct Position_API position);
+
+/**
+ * Applies an `OrderFilled` event with the given fill details to the position.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `order_side` is `NO_ORDER_SIDE`.
+ * - If the `trade_id` has already been applied to the position.
+ */
+void position_apply_fill(struct Position_API *position,
+                         struct ClientOrderId_t client_order_id,
+                         struct VenueOrderId_t venue_order_id,
+                         struct TradeId_t trade_id,
+                         enum OrderSide order_side,
+                         struct Quantity_t last_qty,
+                         struct Price_t last_px,
+                         struct Money_t commission,
+                         uint64_t ts_event,
+                         uint64_t ts_init);
+
+enum PositionSide position_side(const struct Position_API *position);
+
+double position_signed_qty(const struct Position_API *position);
+
+struct Quantity_t position_quantity(const struct Position_API *position);
+
+double position_avg_px_open(const struct Position_API *position);
+
+/**
+ * Returns the average close price, or `NaN` if the position has not been reduced.
+ */
+double position_avg_px_close(const struct Position_API *position);
+
+/**
+ * Returns the realized PnL in the settlement currency (zero if no fills have been applied).
+ */
+struct Money_t position_realized_pnl(const struct Position_API *position);
+
+struct Money_t position_unrealized_pnl(const struct Position_API *position, struct Price_t last);
+
+struct Money_t position_total_pnl(const struct Position_API *position, struct Price_t last);
+
+/**
+ * Returns the notional value of the position at the `last` price.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the position is inverse and was created without a base currency.
+ */
+struct Money_t position_notional_value(const struct Position_API *position, struct Price_t last);
+
+uint8_t position_is_open(const struct Position_API *position);
+
+uint8_t position_is_closed(const struct Position_API *position);
+
+/**
+ * Returns a new [`AccountBalance`] from the `total`, `locked` and `free` balances.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the balances are not all in the same currency.
+ * - If `total` is not the result of `locked` + `free`.
+ */
+struct AccountBalance_t account_balance_new(struct Money_t total,
+                                            struct Money_t locked,
+                                            struct Money_t free);
+
+/**
+ * Returns whether the `balance` is valid, with all balances in its currency and the `free`
+ * balance equal to the `total` less the `locked` balance.
+ */
+uint8_t account_balance_is_valid(const struct AccountBalance_t *balance);
+
+uint8_t account_balance_eq(const struct AccountBalance_t *lhs, const struct AccountBalance_t *rhs);
+
+const char *account_balance_to_cstr(const struct AccountBalance_t *balance);
+
+/**
+ * Returns a new [`MarginBalance`] from the `initial` and `maintenance` margins.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `maintenance` is not in the currency of `initial`.
+ */
+struct MarginBalance_t margin_balance_new(struct Money_t initial,
+                                          struct Money_t maintenance,
+                                          struct InstrumentId_t instrument_id);
+
+/**
+ * Returns whether the `balance` is valid, with both margins in its currency.
+ */
+uint8_t margin_balance_is_valid(const struct MarginBalance_t *balance);
+
+uint8_t margin_balance_eq(const struct MarginBalance_t *lhs, const struct MarginBalance_t *rhs);
+
+const char *margin_balance_to_cstr(const struct MarginBalance_t *balance);
+
 /**
  * Returns a [`Currency`] from pointers and primitives.
  *
@@ -2517,6 +4367,8 @@ void money_add_assign(struct Money_t a, struct Money_t b);
 
 void money_sub_assign(struct Money_t a, struct Money_t b);
 
+void vec_money_drop(CVec v);
+
 struct Price_t price_new(double value, uint8_t precision);
 
 struct Price_t price_from_raw(int64_t raw, uint8_t precision);
@@ -0,0 +1,404 @@
+/* Generated with cbindgen:0.27.0 */
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdint.h>
+#include <Python.h>
+
+/**
+ * Integer timestamps below this value are treated as milliseconds when the timestamp format
+ * is [`TimestampFormat::Auto`] (as nanoseconds it would be within two weeks of the epoch).
+ */
+#define AUTO_MILLIS_THRESHOLD 1000000000000000
+
+/**
+ * The default maximum number of rows per row group.
+ */
+#define DEFAULT_ROW_GROUP_SIZE 100000
+
+/**
+ * The default number of buffered events per type which triggers a flush.
+ */
+#define DEFAULT_FLUSH_SIZE 10000
+
+/**
+ * The precision value which indicates the precision should be inferred from the file.
+ */
+#define INFER_PRECISION UINT8_MAX
+
+/**
+ * The handling of rows which cannot be parsed.
+ */
+typedef enum MalformedRowPolicy {
+    /**
+     * Return an error naming the line of the first malformed row.
+     */
+    MALFORMED_ROW_POLICY_FAIL = 0,
+    /**
+     * Skip malformed rows, logging a warning with the line number of each.
+     */
+    MALFORMED_ROW_POLICY_SKIP = 1,
+} MalformedRowPolicy;
+
+/**
+ * The compression codec applied to each column chunk of a Parquet file.
+ */
+typedef enum ParquetCompression {
+    PARQUET_COMPRESSION_UNCOMPRESSED = 0,
+    PARQUET_COMPRESSION_SNAPPY = 1,
+    PARQUET_COMPRESSION_ZSTD = 2,
+} ParquetCompression;
+
+/**
+ * The market data types which can be persisted to Parquet.
+ */
+typedef enum ParquetDataType {
+    PARQUET_DATA_TYPE_QUOTE_TICK = 1,
+    PARQUET_DATA_TYPE_TRADE_TICK = 2,
+    PARQUET_DATA_TYPE_BAR = 3,
+} ParquetDataType;
+
+/**
+ * The file format events are streamed to.
+ */
+typedef enum StreamingFormat {
+    /**
+     * Arrow IPC stream files, as written by the Python `StreamingFeatherWriter`.
+     */
+    STREAMING_FORMAT_FEATHER = 1,
+    /**
+     * Parquet files, with a row group per flush.
+     */
+    STREAMING_FORMAT_PARQUET = 2,
+} StreamingFormat;
+
+/**
+ * The text format of a file to load.
+ */
+typedef enum TextFormat {
+    /**
+     * Comma-separated values with a header row.
+     */
+    TEXT_FORMAT_CSV = 1,
+    /**
+     * Newline-delimited JSON, with one object per line.
+     */
+    TEXT_FORMAT_JSON_LINES = 2,
+} TextFormat;
+
+/**
+ * The format of the timestamp columns in a file to load.
+ */
+typedef enum TimestampFormat {
+    /**
+     * Detected per value: integers as nanoseconds or milliseconds since the UNIX epoch (see
+     * [`AUTO_MILLIS_THRESHOLD`]), otherwise ISO 8601.
+     */
+    TIMESTAMP_FORMAT_AUTO = 0,
+    /**
+     * Integer nanoseconds since the UNIX epoch.
+     */
+    TIMESTAMP_FORMAT_UNIX_NANOS = 1,
+    /**
+     * Integer milliseconds since the UNIX epoch.
+     */
+    TIMESTAMP_FORMAT_UNIX_MILLIS = 2,
+    /**
+     * ISO 8601 (RFC 3339), or a date and time without an offset which is taken as UTC.
+     */
+    TIMESTAMP_FORMAT_ISO8601 = 3,
+} TimestampFormat;
+
+/**
+ * Wraps a [`ParquetReader`] for each of the supported data types.
+ */
+typedef struct ParquetReaderAny ParquetReaderAny;
+
+/**
+ * Wraps a [`ParquetWriter`] for each of the supported data types.
+ */
+typedef struct ParquetWriterAny ParquetWriterAny;
+
+/**
+ * Flushes and closes the installed streaming writer when dropped.
+ */
+typedef struct StreamingWriterGuard StreamingWriterGuard;
+
+/**
+ * Wraps a [`TextReader`] for each of the supported data types.
+ */
+typedef struct TextReaderAny TextReaderAny;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`TextReaderAny`].
+ *
+ * This struct wraps `TextReaderAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with `TextReaderAny` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `TextReader_API` to be
+ * dereferenced to `TextReaderAny`, providing access to `TextReaderAny`'s methods without
+ * having to manually access the underlying `TextReaderAny` instance.
+ */
+typedef struct TextReader_API {
+    struct TextReaderAny *_0;
+} TextReader_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`ParquetReaderAny`].
+ *
+ * This struct wraps `ParquetReaderAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ParquetReaderAny` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `ParquetReader_API` to be
+ * dereferenced to `ParquetReaderAny`, providing access to `ParquetReaderAny`'s methods without
+ * having to manually access the underlying `ParquetReaderAny` instance.
+ */
+typedef struct ParquetReader_API {
+    struct ParquetReaderAny *_0;
+} ParquetReader_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`StreamingWriterGuard`].
+ *
+ * The guard signals that the streaming writer is installed, and flushes and closes its files
+ * when dropped (mirroring the `LogGuard` for the logger).
+ */
+typedef struct StreamingWriterGuard_API {
+    struct StreamingWriterGuard *_0;
+} StreamingWriterGuard_API;
+
+/**
+ * C compatible Foreign Function Interface (FFI) for an underlying [`ParquetWriterAny`].
+ *
+ * This struct wraps `ParquetWriterAny` in a way that makes it compatible with C function
+ * calls, enabling interaction with `ParquetWriterAny` in a C environment.
+ *
+ * It implements the `Deref` trait, allowing instances of `ParquetWriter_API` to be
+ * dereferenced to `ParquetWriterAny`, providing access to `ParquetWriterAny`'s methods without
+ * having to manually access the underlying `ParquetWriterAny` instance.
+ */
+typedef struct ParquetWriter_API {
+    struct ParquetWriterAny *_0;
+} ParquetWriter_API;
+
+/**
+ * Opens a new CSV or JSONL reader for `data_type` at the file path `path_ptr`, returning
+ * chunks of `chunk_size` items.
+ *
+ * The `columns_ptr` is a JSON object mapping field names to source column names, and the
+ * instrument ID and bar type apply to every row when given. Pass `INFER_PRECISION` for a
+ * precision to be inferred from the file.
+ *
+ * # Safety
+ *
+ * - Assumes `path_ptr` is a valid C string pointer.
+ * - Assumes `columns_ptr` is either NULL or a valid C string pointer.
+ * - Assumes `instrument_id_ptr` is either NULL or a valid C string pointer.
+ * - Assumes `bar_type_ptr` is either NULL or a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the instrument ID or bar type is invalid.
+ * - If the reader cannot be created (e.g. the file cannot be opened).
+ */
+struct TextReader_API text_reader_new(enum ParquetDataType data_type,
+                                      enum TextFormat format,
+                                      const char *path_ptr,
+                                      const char *columns_ptr,
+                                      const char *instrument_id_ptr,
+                                      const char *bar_type_ptr,
+                                      uint8_t price_precision,
+                                      uint8_t size_precision,
+                                      enum TimestampFormat timestamp_format,
+                                      enum MalformedRowPolicy malformed_rows,
+                                      uintptr_t chunk_size);
+
+void text_reader_drop(struct TextReader_API reader);
+
+enum ParquetDataType text_reader_data_type(const struct TextReader_API *reader);
+
+/**
+ * Returns the number of malformed rows skipped so far.
+ */
+uintptr_t text_reader_skipped_count(const struct TextReader_API *reader);
+
+/**
+ * Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+ * once the file is exhausted.
+ *
+ * The chunk must be freed with `text_reader_drop_chunk`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If reading the file fails, or a row is malformed with the `FAIL` policy.
+ */
+CVec text_reader_next_chunk(struct TextReader_API *reader);
+
+/**
+ * Drops a chunk previously returned by `text_reader_next_chunk`.
+ *
+ * # Safety
+ *
+ * - Assumes `chunk` was returned by `text_reader_next_chunk` for this `reader`.
+ */
+void text_reader_drop_chunk(const struct TextReader_API *reader, CVec chunk);
+
+/**
+ * Opens a new Parquet reader for `data_type` at the file path `path_ptr`, returning chunks
+ * of `chunk_size` items with `ts_init` within the inclusive range `start_ns` to `end_ns`.
+ *
+ * Pass `0` and `u64::MAX` to leave the start and end of the range unbounded.
+ *
+ * # Safety
+ *
+ * - Assumes `path_ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `chunk_size` is zero.
+ * - If the file cannot be opened or is not a valid market data Parquet file.
+ */
+struct ParquetReader_API parquet_reader_new(enum ParquetDataType data_type,
+                                            const char *path_ptr,
+                                            uintptr_t chunk_size,
+                                            uint64_t start_ns,
+                                            uint64_t end_ns);
+
+void parquet_reader_drop(struct ParquetReader_API reader);
+
+enum ParquetDataType parquet_reader_data_type(const struct ParquetReader_API *reader);
+
+/**
+ * Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+ * once the file is exhausted.
+ *
+ * The chunk must be freed with `parquet_reader_drop_chunk`.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If reading or decoding the file fails.
+ */
+CVec parquet_reader_next_chunk(struct ParquetReader_API *reader);
+
+/**
+ * Drops a chunk previously returned by `parquet_reader_next_chunk`.
+ *
+ * # Safety
+ *
+ * - Assumes `chunk` was returned by `parquet_reader_next_chunk` for this `reader`.
+ */
+void parquet_reader_drop_chunk(const struct ParquetReader_API *reader, CVec chunk);
+
+/**
+ * Installs a streaming writer for the run `run_id_ptr` under the directory `directory_ptr`,
+ * closing any previously installed writer.
+ *
+ * A `flush_interval_ms` of zero disables time based flushing.
+ *
+ * # Safety
+ *
+ * - Assumes `directory_ptr` is a valid C string pointer.
+ * - Assumes `run_id_ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If `flush_size` is zero or the run ID is empty.
+ */
+struct StreamingWriterGuard_API streaming_writer_init(const char *directory_ptr,
+                                                      const char *run_id_ptr,
+                                                      enum StreamingFormat format,
+                                                      uintptr_t flush_size,
+                                                      uint64_t flush_interval_ms,
+                                                      enum ParquetCompression compression);
+
+/**
+ * Writes an event serialized as a JSON object (with a string `type` field) to the installed
+ * streaming writer.
+ *
+ * Has no effect if no streaming writer is installed.
+ *
+ * # Safety
+ *
+ * - Assumes `json_ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the event cannot be written (see [`StreamingWriter::write_json`]).
+ */
+void writer_write_event(const char *json_ptr);
+
+/**
+ * Closes the files of the installed streaming writer and starts writing the run `run_id_ptr`.
+ *
+ * # Safety
+ *
+ * - Assumes `run_id_ptr` is a valid C string pointer.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If the run ID is empty or closing the current files fails.
+ */
+void streaming_writer_rotate(const char *run_id_ptr);
+
+/**
+ * Flushes the buffered events of the installed streaming writer to its files.
+ *
+ * # Panics
+ *
+ * This function panics:
+ * - If flushing fails (see [`StreamingWriter::flush`]).
+ */
+void streaming_writer_flush(void);
+
+/**
+ * Flushes and closes the installed streaming writer.
+ */
+void streaming_writer_drop(struct StreamingWriterGuard_API guard);
+
+/**
+ * Opens a new Parquet writer for `data_type` at the file path `path_ptr`, returning 1 on
+ * success with the writer written to `writer`, otherwise 0 (if `row_group_size` is zero).
+ *
+ * # Safety
+ *
+ * - Assumes `path_ptr` is a valid C string pointer.
+ * - Assumes `writer` is a valid pointer to write the new writer to.
+ */
+uint8_t parquet_writer_new(enum ParquetDataType data_type,
+                           const char *path_ptr,
+                           uintptr_t row_group_size,
+                           enum ParquetCompression compression,
+                           struct ParquetWriter_API *writer);
+
+/**
+ * Writes a batch of data to the Parquet writer, returning 1 on success, otherwise 0 (see
+ * [`crate::parquet::writer::ParquetWriter::write_batch`] for the failure cases).
+ *
+ * The data is only borrowed, so the caller retains ownership of the `data` memory.
+ *
+ * # Safety
+ *
+ * - Assumes `data` is a valid `CVec` containing objects of the writer's data type.
+ */
+uint8_t parquet_writer_write_batch(struct ParquetWriter_API *writer, const CVec *data);
+
+enum ParquetDataType parquet_writer_data_type(const struct ParquetWriter_API *writer);
+
+uintptr_t parquet_writer_row_count(const struct ParquetWriter_API *writer);
+
+/**
+ * Closes the Parquet writer (freeing its memory), returning 1 on success with the total
+ * number of rows written to `row_count`, otherwise 0 (if flushing or closing the file fails).
+ *
+ * # Safety
+ *
+ * - Assumes `row_count` is a valid pointer to write the row count to.
+ */
+uint8_t parquet_writer_close(struct ParquetWriter_API writer, uintptr_t *row_count);
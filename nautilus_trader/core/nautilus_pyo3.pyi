@@ -228,6 +228,9 @@ def init_logging(
     is_colored: bool | None = None,
     is_bypassed: bool | None = None,
     print_config: bool | None = None,
+    rate_limits: dict[str, int] | None = None,
+    component_colors: dict[str, str] | None = None,
+    file_sinks: list[dict[str, str]] | None = None,
 ) -> LogGuard: ...
 
 def log_header(
@@ -853,6 +856,8 @@ class AccountType(Enum):
     CASH = "CASH"
     MARGIN = "MARGIN"
     BETTING = "BETTING"
+    @classmethod
+    def from_broker_alias(cls, value: str) -> AccountType: ...
 
 class AggregationSource(Enum):
     EXTERNAL = "EXTERNAL"
@@ -902,6 +907,8 @@ class BarAggregation(Enum):
     DAY = "DAY"
     WEEK = "WEEK"
     MONTH = "MONTH"
+    @classmethod
+    def from_abbrev(cls, value: str) -> BarAggregation: ...
 
 class BookAction(Enum):
     ADD = "ADD"
@@ -918,6 +925,8 @@ class ContingencyType(Enum):
     OCO = "OCO"
     OTO = "OTO"
     OUO = "OUO"
+    @classmethod
+    def from_alias(cls, value: str) -> ContingencyType: ...
 
 class CurrencyType(Enum):
     CRYPTO = "CRYPTO"
@@ -964,6 +973,8 @@ class OmsType(Enum):
     UNSPECIFIED = "UNSPECIFIED"
     NETTING = "NETTING"
     HEDGING = "HEDGING"
+    @classmethod
+    def default_for_account(cls, account: AccountType) -> OmsType: ...
 
 class OptionKind(Enum):
     CALL = "CALL"
@@ -1048,6 +1059,8 @@ class TriggerType(Enum):
     MID_POINT = "MID_POINT"
     MARK_PRICE = "MARK_PRICE"
     INDEX_PRICE = "INDEX_PRICE"
+    @classmethod
+    def default_for_order_type(cls, order_type: OrderType) -> TriggerType: ...
 
 class MovingAverageType(Enum):
     SIMPLE = "SIMPLE"
@@ -2724,6 +2737,7 @@ class HttpClient:
         header_keys: list[str] = [],
         keyed_quotas: list[tuple[str, Quota]] = [],
         default_quota: Quota | None = None,
+        retry_policy: RetryPolicy | None = None,
     ) -> None: ...
     async def request(
         self,
@@ -2732,8 +2746,26 @@ class HttpClient:
         headers: dict[str, str] | None = None,
         body: bytes | None = None,
         keys: list[str] | None = None,
+        weight: int = 1,
         timeout_secs: int | None = None,
     ) -> HttpResponse: ...
+    def stats(self) -> HttpClientStats: ...
+
+class HttpClientStats:
+    @property
+    def requests(self) -> int: ...
+    @property
+    def retries(self) -> int: ...
+    @property
+    def rate_limited(self) -> int: ...
+    @property
+    def failures(self) -> int: ...
+    @property
+    def last_latency_ns(self) -> int: ...
+    @property
+    def max_latency_ns(self) -> int: ...
+    @property
+    def mean_latency_ns(self) -> int: ...
 
 class HttpMethod(Enum):
     GET = "GET"
@@ -2758,6 +2790,19 @@ class Quota:
     @classmethod
     def rate_per_hour(cls, max_burst: int) -> Quota: ...
 
+class RetryPolicy:
+    def __init__(
+        self,
+        max_retries: int = 3,
+        delay_initial_ms: int = 100,
+        delay_max_ms: int = 5_000,
+        backoff_factor: float = 2.0,
+        jitter: float = 0.2,
+        retryable_statuses: set[int] | None = None,
+    ) -> None: ...
+    @property
+    def max_retries(self) -> int: ...
+
 class WebSocketClientError(Exception):
     ...
 
@@ -3563,6 +3608,7 @@ class MovingAverageConvergenceDivergence:
         slow_period: int,
         ma_type: MovingAverageType = ...,
         price_type: PriceType | None = None,
+        signal_period: int | None = None,
     ) -> None: ...
     @property
     def name(self) -> str: ...
@@ -3573,11 +3619,17 @@ class MovingAverageConvergenceDivergence:
     @property
     def slow_period(self) -> int: ...
     @property
+    def signal_period(self) -> int: ...
+    @property
     def initialized(self) -> bool: ...
     @property
     def has_inputs(self) -> bool: ...
     @property
     def value(self) -> float: ...
+    @property
+    def signal(self) -> float: ...
+    @property
+    def histogram(self) -> float: ...
     def update_raw(self, close: float) -> None: ...
     def handle_quote_tick(self, quote: QuoteTick) -> None: ...
     def handle_trade_tick(self, trade: TradeTick) -> None: ...
@@ -3673,6 +3725,30 @@ class DonchianChannel:
     def handle_bar(self, bar: Bar) -> None: ...
     def reset(self) -> None: ...
 
+class RollingVolatility:
+    def __init__(
+        self,
+        period: int,
+        price_type: PriceType | None = None,
+    ) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def period(self) -> int: ...
+    @property
+    def count(self) -> int: ...
+    @property
+    def initialized(self) -> bool: ...
+    @property
+    def has_inputs(self) -> bool: ...
+    @property
+    def value(self) -> float: ...
+    def update_raw(self, value: float) -> None: ...
+    def reset(self) -> None: ...
+    def handle_quote_tick(self, quote: QuoteTick) -> None: ...
+    def handle_trade_tick(self, trade: TradeTick) -> None: ...
+    def handle_bar(self, bar: Bar) -> None: ...
+
 class KeltnerChannel:
     def __init__(
         self,
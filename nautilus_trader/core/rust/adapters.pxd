@@ -0,0 +1,80 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uintptr_t
+from nautilus_trader.core.rust.core cimport CVec
+
+cdef extern from "../includes/adapters.h":
+
+    # The Nautilus data types which can be read from DBN files in chunks.
+    cdef enum DatabentoDataType:
+        # Order book deltas from the MBO schema.
+        DATABENTO_DATA_TYPE_ORDER_BOOK_DELTA # = 1,
+        # Quotes from the MBP-1 and BBO schemas.
+        DATABENTO_DATA_TYPE_QUOTE_TICK # = 2,
+        # Trades from the trades schema, or the trades within the MBO and MBP-1 schemas.
+        DATABENTO_DATA_TYPE_TRADE_TICK # = 3,
+        # Bars from the OHLCV schemas.
+        DATABENTO_DATA_TYPE_BAR # = 4,
+
+    # A [`DatabentoReader`] for any of the supported data types.
+    cdef struct DatabentoReaderAny:
+        pass
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`DatabentoReaderAny`].
+    #
+    # This struct wraps `DatabentoReaderAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `DatabentoReaderAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `DatabentoReader_API` to be
+    # dereferenced to `DatabentoReaderAny`, providing access to `DatabentoReaderAny`'s methods
+    # without having to manually access the underlying `DatabentoReaderAny` instance.
+    cdef struct DatabentoReader_API:
+        DatabentoReaderAny *_0;
+
+    # Opens a new DBN reader for `data_type` at the file path `path_ptr`, returning chunks of
+    # `chunk_size` items with prices at `price_precision`.
+    #
+    # The venue of each record is given by its publisher in the publishers JSON file at
+    # `publishers_path_ptr`. The `symbology_ptr` is a JSON object mapping Databento instrument
+    # IDs to Nautilus instrument IDs, which take precedence over the symbology in the file.
+    #
+    # # Safety
+    #
+    # - Assumes `path_ptr` is a valid C string pointer.
+    # - Assumes `publishers_path_ptr` is a valid C string pointer.
+    # - Assumes `symbology_ptr` is either NULL or a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the publishers file cannot be loaded.
+    # - If a Databento instrument ID in the symbology map is not an integer.
+    # - If the reader cannot be created (e.g. the file cannot be opened, or has another schema).
+    DatabentoReader_API databento_reader_new(DatabentoDataType data_type,
+                                             const char *path_ptr,
+                                             const char *publishers_path_ptr,
+                                             const char *symbology_ptr,
+                                             uint8_t price_precision,
+                                             uintptr_t chunk_size);
+
+    void databento_reader_drop(DatabentoReader_API reader);
+
+    DatabentoDataType databento_reader_data_type(const DatabentoReader_API *reader);
+
+    # Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+    # once the file is exhausted.
+    #
+    # The chunk must be freed with `databento_reader_drop_chunk`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If reading or decoding the file fails.
+    CVec databento_reader_next_chunk(DatabentoReader_API *reader);
+
+    # Drops a chunk previously returned by `databento_reader_next_chunk`.
+    #
+    # # Safety
+    #
+    # - Assumes `chunk` was returned by `databento_reader_next_chunk` for this `reader`.
+    void databento_reader_drop_chunk(const DatabentoReader_API *reader, CVec chunk);
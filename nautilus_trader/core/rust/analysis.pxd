@@ -0,0 +1,65 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint64_t, uintptr_t
+from nautilus_trader.core.rust.core cimport CVec
+from nautilus_trader.core.rust.model cimport Money_t
+
+cdef extern from "../includes/analysis.h":
+
+    # The default number of trading days per year used to annualize statistics.
+    const uintptr_t TRADING_DAYS_PER_YEAR # = 252
+
+    # A summary of portfolio performance over an equity curve.
+    #
+    # Ratios and returns are fractions (e.g. 0.1 is 10%). Statistics which cannot be calculated
+    # from the given equity curve (e.g. volatility of fewer than two daily returns) are NaN.
+    cdef struct PerformanceSummary:
+        # The return from the first to the last point of the equity curve.
+        double total_return;
+        # The total return compounded to an annual rate.
+        double annualized_return;
+        # The annualized standard deviation of daily returns.
+        double volatility;
+        # The annualized Sharpe ratio of daily returns.
+        double sharpe_ratio;
+        # The annualized Sortino ratio of daily returns.
+        double sortino_ratio;
+        # The largest decline from a peak of the equity curve (zero or negative).
+        double max_drawdown;
+        # UNIX timestamp (nanoseconds) of the peak the max drawdown is measured from (zero if none).
+        uint64_t max_drawdown_start;
+        # UNIX timestamp (nanoseconds) of the trough of the max drawdown (zero if none).
+        uint64_t max_drawdown_end;
+        # The fraction of trades with a positive realized PnL.
+        double win_rate;
+
+    # The result of a performance analysis over an equity curve.
+    cdef struct PerformanceResult:
+        PerformanceSummary summary;
+        # The drawdown at each point of the equity curve, as a `CVec` of `double`, which must be
+        # freed with `vec_drawdowns_drop`.
+        CVec drawdowns;
+
+    # Analyzes the performance of an equity curve of `len` account balances at the given UNIX
+    # timestamps (nanoseconds), with the win rate calculated from the realized PnL of each trade.
+    #
+    # Statistics are annualized with `period` trading days per year.
+    #
+    # # Safety
+    #
+    # - Assumes `ts_ptr` and `balances_ptr` each point to at least `len` elements.
+    # - Assumes `realized_pnls_ptr` points to at least `realized_pnls_len` elements.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the equity curve is empty, not ordered by timestamp, or has balances which are not
+    #   positive or not all in the same currency.
+    PerformanceResult analysis_performance(const uint64_t *ts_ptr,
+                                           const Money_t *balances_ptr,
+                                           uintptr_t len,
+                                           const double *realized_pnls_ptr,
+                                           uintptr_t realized_pnls_len,
+                                           uintptr_t period);
+
+    void vec_drawdowns_drop(CVec v);
@@ -4,9 +4,22 @@ from cpython.object cimport PyObject
 from libc.stdint cimport uint8_t, uint64_t, uintptr_t
 from nautilus_trader.core.rust.common cimport TestClock_API, LiveClock_API
 from nautilus_trader.core.rust.core cimport CVec, UUID4_t
+from nautilus_trader.core.rust.model cimport ClientOrderId_t, InstrumentId_t, LiquiditySide, OrderBook_API, OrderSide, OrderType, Price_t, Quantity_t, QuoteTick_t, TradeTick_t, TrailingOffsetType, TriggerType
 
 cdef extern from "../includes/backtest.h":
 
+    # The kind of an [`OrderMatch`].
+    cdef enum OrderMatchKind:
+        # A stop order was triggered.
+        TRIGGERED # = 1,
+        # An order was filled (fully or partially).
+        FILLED # = 2,
+
+    # Provides a price-time priority book of resting orders for an instrument, which generates
+    # triggers and fills from market data.
+    cdef struct OrderMatchingBook:
+        pass
+
     # Provides a means of accumulating and draining time event handlers.
     cdef struct TimeEventAccumulator:
         pass
@@ -14,6 +27,60 @@ cdef extern from "../includes/backtest.h":
     cdef struct TimeEventAccumulatorAPI:
         TimeEventAccumulator *_0;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying `OrderMatchingBook`.
+    #
+    # This struct wraps `OrderMatchingBook` in a way that makes it compatible with C function
+    # calls, enabling interaction with `OrderMatchingBook` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `OrderMatchingBook_API` to be
+    # dereferenced to `OrderMatchingBook`, providing access to `OrderMatchingBook`'s methods without
+    # having to manually access the underlying `OrderMatchingBook` instance.
+    cdef struct OrderMatchingBook_API:
+        OrderMatchingBook *_0;
+
+    # Represents an order resting in an [`OrderMatchingBook`].
+    cdef struct MatchingOrder:
+        # The client order ID.
+        ClientOrderId_t client_order_id;
+        # The order side.
+        OrderSide order_side;
+        # The order type (`LIMIT`, `STOP_MARKET` or `STOP_LIMIT`).
+        OrderType order_type;
+        # The limit price (the trigger price for `STOP_MARKET` orders).
+        Price_t price;
+        # The trigger price (the limit price for `LIMIT` orders).
+        Price_t trigger_price;
+        # The trigger type (`NO_TRIGGER` for `LIMIT` orders).
+        TriggerType trigger_type;
+        # The quantity remaining to be filled.
+        Quantity_t leaves_qty;
+
+    # The output of a trailing stop calculation, where a price is only valid if its flag is set.
+    cdef struct TrailingStopOutput:
+        # If the trigger price should be updated (1) or not (0).
+        uint8_t has_trigger_price;
+        # The new trigger price.
+        Price_t trigger_price;
+        # If the limit price should be updated (1) or not (0).
+        uint8_t has_price;
+        # The new limit price.
+        Price_t price;
+
+    # Represents an order trigger or fill generated by an [`OrderMatchingBook`].
+    cdef struct OrderMatch:
+        # The client order ID.
+        ClientOrderId_t client_order_id;
+        # The kind of match.
+        OrderMatchKind kind;
+        # The fill price, or the market price which triggered the order.
+        Price_t last_px;
+        # The fill quantity (zero for triggers).
+        Quantity_t last_qty;
+        # The quantity remaining to be filled after the match.
+        Quantity_t leaves_qty;
+        # The liquidity side of the fill (`NO_LIQUIDITY_SIDE` for triggers).
+        LiquiditySide liquidity_side;
+
     TimeEventAccumulatorAPI time_event_accumulator_new();
 
     void time_event_accumulator_drop(TimeEventAccumulatorAPI accumulator);
@@ -24,3 +91,90 @@ cdef extern from "../includes/backtest.h":
                                               uint8_t set_time);
 
     CVec time_event_accumulator_drain(TimeEventAccumulatorAPI *accumulator);
+
+    OrderMatchingBook_API order_matching_book_new(InstrumentId_t instrument_id,
+                                                  uint8_t fill_on_touch);
+
+    void order_matching_book_drop(OrderMatchingBook_API book);
+
+    void order_matching_book_reset(OrderMatchingBook_API *book);
+
+    uint8_t order_matching_book_order_exists(const OrderMatchingBook_API *book,
+                                             ClientOrderId_t client_order_id);
+
+    uint8_t order_matching_book_is_triggered(const OrderMatchingBook_API *book,
+                                             ClientOrderId_t client_order_id);
+
+    CVec order_matching_book_orders_bid(const OrderMatchingBook_API *book);
+
+    CVec order_matching_book_orders_ask(const OrderMatchingBook_API *book);
+
+    # Adds the given `order` to the book, writing the resulting `CVec` of `OrderMatch` to `matches`.
+    #
+    # Returns 1 if the order was added, or 0 if it is invalid or already exists in the book
+    # (in which case `matches` is written as an empty `CVec`).
+    #
+    # # Safety
+    #
+    # - Assumes `matches` is a valid pointer to write the matches to.
+    uint8_t order_matching_book_add_order(OrderMatchingBook_API *book,
+                                          MatchingOrder order,
+                                          CVec *matches);
+
+    # Updates the resting order for the given `order`, writing the resulting `CVec` of
+    # `OrderMatch` to `matches`.
+    #
+    # Returns 1 if the order was updated, or 0 if it is invalid or no longer in the book (for
+    # instance because it was filled before the update was processed), in which case `matches`
+    # is written as an empty `CVec`.
+    #
+    # # Safety
+    #
+    # - Assumes `matches` is a valid pointer to write the matches to.
+    uint8_t order_matching_book_update_order(OrderMatchingBook_API *book,
+                                             MatchingOrder order,
+                                             CVec *matches);
+
+    # Cancels the resting order with the given `client_order_id`.
+    #
+    # Returns 1 if the order was canceled, or 0 if it was not in the book (for instance
+    # because it was filled before the cancel was processed).
+    uint8_t order_matching_book_cancel_order(OrderMatchingBook_API *book,
+                                             ClientOrderId_t client_order_id);
+
+    CVec order_matching_book_process_quote(OrderMatchingBook_API *book, const QuoteTick_t *quote);
+
+    CVec order_matching_book_process_trade(OrderMatchingBook_API *book, const TradeTick_t *trade);
+
+    CVec order_matching_book_process_book(OrderMatchingBook_API *book,
+                                          const OrderBook_API *order_book);
+
+    void vec_matching_orders_drop(CVec v);
+
+    void vec_order_matches_drop(CVec v);
+
+    # Calculates the new trigger price (and limit price) for a trailing stop order.
+    #
+    # Pass a `limit_offset` of NaN for trailing stop market orders.
+    #
+    # # Safety
+    #
+    # - Assumes `trigger_price`, `price`, `bid`, `ask` and `last` are each either null (no price)
+    #   or a valid `Price` pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If a market price required by the `trigger_type` is null.
+    # - If the `trigger_type`, `trailing_offset_type` or `order_side` is not supported.
+    TrailingStopOutput trailing_stop_calculate(Price_t price_increment,
+                                               OrderSide order_side,
+                                               TriggerType trigger_type,
+                                               TrailingOffsetType trailing_offset_type,
+                                               double trailing_offset,
+                                               double limit_offset,
+                                               const Price_t *trigger_price,
+                                               const Price_t *price,
+                                               const Price_t *bid,
+                                               const Price_t *ask,
+                                               const Price_t *last);
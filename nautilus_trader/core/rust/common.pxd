@@ -3,10 +3,25 @@
 from cpython.object cimport PyObject
 from libc.stdint cimport uint8_t, uint64_t, uintptr_t
 from nautilus_trader.core.rust.core cimport CVec, UUID4_t
-from nautilus_trader.core.rust.model cimport TraderId_t
+from nautilus_trader.core.rust.model cimport AccountId_t, CashAccount_API, ClientOrderId_t, MarginAccount_API, OrderAny_API, OrderBookDeltas_API, PositionId_t, Position_API, TraderId_t
 
 cdef extern from "../includes/common.h":
 
+    # The current schema version of encoded cache blobs.
+    const uint16_t CACHE_SCHEMA_VERSION # = 1
+
+    # The delimiter between the parts of a cache key.
+    const uint32_t KEY_DELIMITER # = ':'
+
+    # The window over which identical messages are counted (1 second).
+    const uint64_t RATE_LIMIT_WINDOW_NS # = 1000000000
+
+    # The maximum number of distinct messages tracked per component within a window.
+    #
+    # Once reached, further distinct messages for the component are not limited until the
+    # next window, which bounds the memory used by each component's counter.
+    const uintptr_t RATE_LIMIT_MAX_TRACKED # = 1000
+
     # The state of a component within the system.
     cpdef enum ComponentState:
         # When a component is instantiated, but not yet ready to fulfill its specification.
@@ -103,6 +118,51 @@ cdef extern from "../includes/common.h":
         # The **ERROR** log level.
         ERROR # = 5,
 
+    # The status of a logging operation across the FFI boundary, where each error status
+    # corresponds to a [`LogError`](crate::logging::error::LogError) variant.
+    cpdef enum LogStatus:
+        # The operation succeeded.
+        OK # = 0,
+        # A log level string was invalid.
+        INVALID_LEVEL # = 1,
+        # A logger configuration option was invalid.
+        CONFIG_PARSE # = 2,
+        # A logger configuration JSON value could not be parsed.
+        JSON_PARSE # = 3,
+        # A log file directory could not be created.
+        CREATE_DIRECTORY # = 4,
+        # A log file could not be opened.
+        FILE_OPEN # = 5,
+        # The logger configuration environment variable could not be read.
+        ENV_VAR # = 6,
+        # A logger has already been initialized for the process.
+        ALREADY_INITIALIZED # = 7,
+
+    # Maps cache objects to the keys they are persisted under, so any key-value backend can be
+    # used for a cache database.
+    #
+    # Keys are of the form `{trader_key}:{collection}:{id}` (e.g. `trader-TRADER-001:orders:O-1`),
+    # matching the keys written by the Python cache database.
+    cdef struct CacheMap:
+        pass
+
+    # A finite-state machine for the lifecycle of a component.
+    #
+    # Enforces the same transitions as the Python `Component` base class, starting from
+    # `PRE_INITIALIZED`, with `DISPOSED` and `FAULTED` as terminal states.
+    cdef struct ComponentFSM:
+        pass
+
+    # Conflates updates keyed by instrument, emitting at most one coalesced update per instrument
+    # per interval.
+    #
+    # Updates are pushed as they arrive and pending updates are taken by polling with the current
+    # time, so the emission schedule is deterministic when backtesting. The first update for an
+    # instrument is emitted on the next poll, after which each instrument's updates are held until
+    # the interval has elapsed since its previous emission.
+    cdef struct ConflatingThrottler_OrderBookDeltas:
+        pass
+
     # A real-time clock which uses system time.
     #
     # Timestamps are guaranteed to be unique and monotonically increasing.
@@ -112,12 +172,31 @@ cdef extern from "../includes/common.h":
     cdef struct LogGuard:
         pass
 
+    # A registry of subscriptions of integer subscriber handles to topic patterns.
+    #
+    # Patterns may contain the `*` and `?` wildcards (see [`is_matching`]). The handles matching
+    # each topic are cached when first queried, and the cache is invalidated whenever the
+    # subscriptions change, so repeated publishing to the same topics only matches patterns once.
+    cdef struct SubscriptionRegistry:
+        pass
+
     # A static test clock.
     #
     # Stores the current timestamp internally which can be advanced.
     cdef struct TestClock:
         pass
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`CacheMap`].
+    #
+    # This struct wraps `CacheMap` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CacheMap` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `CacheMap_API` to be
+    # dereferenced to `CacheMap`, providing access to `CacheMap`'s methods without
+    # having to manually access the underlying `CacheMap` instance.
+    cdef struct CacheMap_API:
+        CacheMap *_0;
+
     # C compatible Foreign Function Interface (FFI) for an underlying [`TestClock`].
     #
     # This struct wraps `TestClock` in a way that makes it compatible with C function
@@ -141,6 +220,17 @@ cdef extern from "../includes/common.h":
     cdef struct LiveClock_API:
         LiveClock *_0;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`ComponentFSM`].
+    #
+    # This struct wraps `ComponentFSM` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ComponentFSM` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `ComponentFSM_API` to be
+    # dereferenced to `ComponentFSM`, providing access to `ComponentFSM`'s methods without
+    # having to manually access the underlying `ComponentFSM` instance.
+    cdef struct ComponentFSM_API:
+        ComponentFSM *_0;
+
     # C compatible Foreign Function Interface (FFI) for an underlying [`LogGuard`].
     #
     # This struct wraps `LogGuard` in a way that makes it compatible with C function
@@ -152,6 +242,29 @@ cdef extern from "../includes/common.h":
     cdef struct LogGuard_API:
         LogGuard *_0;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`SubscriptionRegistry`].
+    #
+    # This struct wraps `SubscriptionRegistry` in a way that makes it compatible with C function
+    # calls, enabling interaction with `SubscriptionRegistry` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `SubscriptionRegistry_API` to be
+    # dereferenced to `SubscriptionRegistry`, providing access to `SubscriptionRegistry`'s methods
+    # without having to manually access the underlying `SubscriptionRegistry` instance.
+    cdef struct SubscriptionRegistry_API:
+        SubscriptionRegistry *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`ConflatingThrottler`] of
+    # [`OrderBookDeltas`].
+    #
+    # This struct wraps `ConflatingThrottler` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ConflatingThrottler` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `DeltasThrottler_API` to be
+    # dereferenced to `ConflatingThrottler`, providing access to `ConflatingThrottler`'s methods
+    # without having to manually access the underlying `ConflatingThrottler` instance.
+    cdef struct DeltasThrottler_API:
+        ConflatingThrottler_OrderBookDeltas *_0;
+
     # Represents a time event occurring at the event timestamp.
     #
     # A `TimeEvent` carries metadata such as the event's name, a unique event ID,
@@ -184,6 +297,19 @@ cdef extern from "../includes/common.h":
     # Sets the logging system to bypass mode.
     void logging_set_bypass();
 
+    # Returns whether the logging system is in bypass mode.
+    uint8_t logging_is_bypassed();
+
+    # Sets whether the logging system is in bypass mode, so logging can be muted temporarily
+    # (e.g. during a noisy replay) without reinitializing the logger.
+    void logging_set_bypassed(uint8_t bypassed);
+
+    # Reopens the log file at its configured path, so that writes go to a new file after
+    # external log rotation (e.g. `logrotate` moving the file and signalling the process).
+    #
+    # The reopen is posted to the logging thread, so is applied after any pending log events.
+    void logging_reopen();
+
     # Shuts down the logging system.
     void logging_shutdown();
 
@@ -199,6 +325,101 @@ cdef extern from "../includes/common.h":
     # Sets the global logging clock static time with the given UNIX timestamp (nanoseconds).
     void logging_clock_set_static_time(uint64_t time_ns);
 
+    CacheMap_API cache_map_new(TraderId_t trader_id,
+                               UUID4_t instance_id,
+                               uint8_t use_trader_prefix,
+                               uint8_t use_instance_id);
+
+    void cache_map_drop(CacheMap_API cache_map);
+
+    const char *cache_map_order_key(const CacheMap_API *cache_map, ClientOrderId_t client_order_id);
+
+    const char *cache_map_position_key(const CacheMap_API *cache_map, PositionId_t position_id);
+
+    const char *cache_map_account_key(const CacheMap_API *cache_map, AccountId_t account_id);
+
+    # Returns a `CVec` of the order encoded as a cache blob, which must be freed with `cvec_drop`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the order cannot be encoded.
+    CVec cache_order_encode(const OrderAny_API *order);
+
+    # Decodes an order from a cache blob.
+    #
+    # # Safety
+    #
+    # - Assumes `blob_ptr` points to at least `blob_len` bytes.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the blob is not a valid order blob with a supported schema version.
+    OrderAny_API cache_order_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+    # Returns a `CVec` of the position encoded as a cache blob, which must be freed with `cvec_drop`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the position cannot be encoded.
+    CVec cache_position_encode(const Position_API *position);
+
+    # Decodes a position from a cache blob.
+    #
+    # # Safety
+    #
+    # - Assumes `blob_ptr` points to at least `blob_len` bytes.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the blob is not a valid position blob with a supported schema version.
+    Position_API cache_position_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+    # Returns a `CVec` of the cash account encoded as a cache blob, which must be freed with
+    # `cvec_drop`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the account cannot be encoded.
+    CVec cache_cash_account_encode(const CashAccount_API *account);
+
+    # Decodes a cash account from a cache blob.
+    #
+    # # Safety
+    #
+    # - Assumes `blob_ptr` points to at least `blob_len` bytes.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the blob is not a valid cash account blob with a supported schema version.
+    CashAccount_API cache_cash_account_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
+    # Returns a `CVec` of the margin account encoded as a cache blob, which must be freed with
+    # `cvec_drop`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the account cannot be encoded.
+    CVec cache_margin_account_encode(const MarginAccount_API *account);
+
+    # Decodes a margin account from a cache blob.
+    #
+    # # Safety
+    #
+    # - Assumes `blob_ptr` points to at least `blob_len` bytes.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the blob is not a valid margin account blob with a supported schema version.
+    MarginAccount_API cache_margin_account_decode(const uint8_t *blob_ptr, uintptr_t blob_len);
+
     TestClock_API test_clock_new();
 
     void test_clock_drop(TestClock_API clock);
@@ -326,6 +547,20 @@ cdef extern from "../includes/common.h":
 
     void live_clock_cancel_timers(LiveClock_API *clock);
 
+    ComponentFSM_API component_fsm_new();
+
+    void component_fsm_drop(ComponentFSM_API fsm);
+
+    ComponentState component_fsm_state(const ComponentFSM_API *fsm);
+
+    uint8_t component_fsm_is_valid_trigger(const ComponentFSM_API *fsm, ComponentTrigger trigger);
+
+    # Applies the `trigger` to the state machine.
+    #
+    # Returns 1 if the state transitioned, or 0 if the trigger is invalid for the current state
+    # (which is then left unchanged), so the caller can raise `InvalidStateTrigger`.
+    uint8_t component_fsm_trigger(ComponentFSM_API *fsm, ComponentTrigger trigger);
+
     const char *component_state_to_cstr(ComponentState value);
 
     # Returns an enum from a Python string.
@@ -344,6 +579,9 @@ cdef extern from "../includes/common.h":
     # - Assumes `ptr` is a valid C string pointer.
     ComponentTrigger component_trigger_from_cstr(const char *ptr);
 
+    # Returns the state the trigger drives a component toward.
+    ComponentState component_trigger_target_state(ComponentTrigger value);
+
     const char *log_level_to_cstr(LogLevel value);
 
     # Returns an enum from a Python string.
@@ -362,6 +600,8 @@ cdef extern from "../includes/common.h":
     # - Assumes `ptr` is a valid C string pointer.
     LogColor log_color_from_cstr(const char *ptr);
 
+    const char *log_status_to_cstr(LogStatus value);
+
     # Initializes logging.
     #
     # Logging should be used for Python and sync Rust logic which is most of
@@ -369,6 +609,9 @@ cdef extern from "../includes/common.h":
     # Logging can be configured to filter components and write up to a specific level only
     # by passing a configuration using the `NAUTILUS_LOG` environment variable.
     #
+    # The outcome is written to `status`, which is `LogStatus::Ok` on success. On failure the
+    # error is printed to stderr, and the returned guard has no logger to flush.
+    #
     # # Safety
     #
     # Should only be called once during an applications run, ideally at the
@@ -378,6 +621,10 @@ cdef extern from "../includes/common.h":
     # - Assume `file_name_ptr` is either NULL or a valid C string pointer.
     # - Assume `file_format_ptr` is either NULL or a valid C string pointer.
     # - Assume `component_level_ptr` is either NULL or a valid C string pointer.
+    # - Assume `rate_limits_ptr` is either NULL or a valid C string pointer.
+    # - Assume `component_colors_ptr` is either NULL or a valid C string pointer.
+    # - Assume `file_sinks_ptr` is either NULL or a valid C string pointer (a JSON array).
+    # - Assume `status` is a valid pointer to write the status to.
     LogGuard_API logging_init(TraderId_t trader_id,
                               UUID4_t instance_id,
                               LogLevel level_stdout,
@@ -386,12 +633,19 @@ cdef extern from "../includes/common.h":
                               const char *file_name_ptr,
                               const char *file_format_ptr,
                               const char *component_levels_ptr,
+                              const char *rate_limits_ptr,
+                              const char *component_colors_ptr,
+                              const char *file_sinks_ptr,
                               uint8_t is_colored,
                               uint8_t is_bypassed,
-                              uint8_t print_config);
+                              uint8_t print_config,
+                              LogStatus *status);
 
     # Creates a new log event.
     #
+    # Invalid UTF-8 sequences in the component or message are replaced with
+    # `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+    #
     # # Safety
     #
     # - Assumes `component_ptr` is a valid C string pointer.
@@ -401,6 +655,24 @@ cdef extern from "../includes/common.h":
                     const char *component_ptr,
                     const char *message_ptr);
 
+    # Creates a new log event with the source `file` and `line` it originated from.
+    #
+    # The location is only included if the logger is configured `with_source_location`.
+    # Invalid UTF-8 sequences in the component, message or file are replaced with
+    # `U+FFFD REPLACEMENT CHARACTER` rather than causing a panic.
+    #
+    # # Safety
+    #
+    # - Assumes `component_ptr` is a valid C string pointer.
+    # - Assumes `message_ptr` is a valid C string pointer.
+    # - Assumes `file_ptr` is either NULL or a valid C string pointer.
+    void logger_log_with_location(LogLevel level,
+                                  LogColor color,
+                                  const char *component_ptr,
+                                  const char *message_ptr,
+                                  const char *file_ptr,
+                                  uint32_t line);
+
     # Logs the Nautilus system header.
     #
     # # Safety
@@ -419,9 +691,171 @@ cdef extern from "../includes/common.h":
     # - Assumes `component_ptr` is a valid C string pointer.
     void logging_log_sysinfo(const char *component_ptr);
 
+    # Returns whether an event at `level` from the component would be written by the logger.
+    #
+    # Allows callers to skip formatting messages which would be filtered out.
+    #
+    # # Safety
+    #
+    # - Assumes `component_ptr` is a valid C string pointer.
+    uint8_t logger_would_log(const LogGuard_API *log_guard,
+                             LogLevel level,
+                             const char *component_ptr);
+
+    # Returns the count of log events dropped because the logger queue was full.
+    uint64_t logger_get_dropped_count(const LogGuard_API *log_guard);
+
     # Flushes global logger buffers of any records.
     void logger_drop(LogGuard_API log_guard);
 
+    # Returns the string with all ANSI SGR escape sequences (`\x1b[...m`) removed.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    const char *ansi_strip(const char *ptr);
+
+    # Returns a new [`Subscribe`] command serialized as a JSON C string.
+    #
+    # # Safety
+    #
+    # - Assumes `type_name_ptr` is a valid C string pointer.
+    # - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+    # - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the data type is not registered, or its metadata has no valid instrument ID.
+    # - If both `client_id_ptr` and `venue_ptr` are NULL.
+    const char *data_subscribe_new(const char *type_name_ptr,
+                                   const char *metadata_ptr,
+                                   const char *client_id_ptr,
+                                   const char *venue_ptr,
+                                   UUID4_t command_id,
+                                   uint64_t ts_init);
+
+    # Returns a new [`Unsubscribe`] command serialized as a JSON C string.
+    #
+    # # Safety
+    #
+    # - Assumes `type_name_ptr` is a valid C string pointer.
+    # - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+    # - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the data type is not registered, or its metadata has no valid instrument ID.
+    # - If both `client_id_ptr` and `venue_ptr` are NULL.
+    const char *data_unsubscribe_new(const char *type_name_ptr,
+                                     const char *metadata_ptr,
+                                     const char *client_id_ptr,
+                                     const char *venue_ptr,
+                                     UUID4_t command_id,
+                                     uint64_t ts_init);
+
+    # Returns a new [`DataRequest`] serialized as a JSON C string.
+    #
+    # A `start`, `end` or `limit` of zero is treated as unbounded.
+    #
+    # # Safety
+    #
+    # - Assumes `type_name_ptr` is a valid C string pointer.
+    # - Assumes `metadata_ptr` is a valid C string pointer to a JSON object of strings, or NULL.
+    # - Assumes `client_id_ptr` and `venue_ptr` are valid C string pointers or NULL.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the data type is not registered, or its metadata has no valid instrument ID.
+    # - If both `client_id_ptr` and `venue_ptr` are NULL.
+    # - If `start` is after `end`.
+    const char *data_request_new(const char *type_name_ptr,
+                                 const char *metadata_ptr,
+                                 const char *client_id_ptr,
+                                 const char *venue_ptr,
+                                 UUID4_t correlation_id,
+                                 uint64_t start,
+                                 uint64_t end,
+                                 uintptr_t limit,
+                                 uint64_t ts_init);
+
+    # Returns whether the `topic` matches the `pattern` (which may contain `*` and `?` wildcards).
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` and `pattern_ptr` are valid C string pointers.
+    uint8_t msgbus_is_matching(const char *topic_ptr, const char *pattern_ptr);
+
+    SubscriptionRegistry_API subscription_registry_new();
+
+    void subscription_registry_drop(SubscriptionRegistry_API registry);
+
+    # Subscribes the `handle` to the `pattern`, returning 0 if it was already subscribed.
+    #
+    # # Safety
+    #
+    # - Assumes `pattern_ptr` is a valid C string pointer.
+    uint8_t subscription_registry_subscribe(SubscriptionRegistry_API *registry,
+                                            const char *pattern_ptr,
+                                            uint64_t handle,
+                                            uint8_t priority);
+
+    # Unsubscribes the `handle` from the `pattern`, returning 0 if it was not subscribed.
+    #
+    # # Safety
+    #
+    # - Assumes `pattern_ptr` is a valid C string pointer.
+    uint8_t subscription_registry_unsubscribe(SubscriptionRegistry_API *registry,
+                                              const char *pattern_ptr,
+                                              uint64_t handle);
+
+    # Returns a `CVec` of the `uint64_t` handles subscribed to patterns matching the `topic`, in
+    # descending priority order, which must be freed with [`vec_handles_drop`].
+    #
+    # # Safety
+    #
+    # - Assumes `topic_ptr` is a valid C string pointer.
+    CVec subscription_registry_matching_handlers(SubscriptionRegistry_API *registry,
+                                                 const char *topic_ptr);
+
+    # Returns a `CVec` with a `CVec` of matching handles (as for
+    # [`subscription_registry_matching_handlers`]) for each of the `topics_len` topics, which must
+    # be freed with [`vec_handles_batch_drop`].
+    #
+    # # Safety
+    #
+    # - Assumes `topics_ptr` points to `topics_len` valid C string pointers.
+    CVec subscription_registry_matching_handlers_batch(SubscriptionRegistry_API *registry,
+                                                       const char *const *topics_ptr,
+                                                       uintptr_t topics_len);
+
+    void vec_handles_drop(CVec v);
+
+    void vec_handles_batch_drop(CVec v);
+
+    DeltasThrottler_API throttle_new(uint64_t interval_ns);
+
+    void throttle_drop(DeltasThrottler_API throttler);
+
+    # Pushes a copy of `deltas`, merging it with any pending deltas for the same instrument.
+    void throttle_push(DeltasThrottler_API *throttler, const OrderBookDeltas_API *deltas);
+
+    # Returns a `CVec` of `OrderBookDeltas_API` for the instruments whose interval has elapsed at
+    # `ts_now`, which must be freed with [`vec_throttled_deltas_drop`].
+    CVec throttle_poll(DeltasThrottler_API *throttler, uint64_t ts_now);
+
+    # Returns a `CVec` of `OrderBookDeltas_API` for all pending deltas, regardless of the interval,
+    # which must be freed with [`vec_throttled_deltas_drop`].
+    CVec throttle_flush(DeltasThrottler_API *throttler, uint64_t ts_now);
+
+    uintptr_t throttle_qsize(const DeltasThrottler_API *throttler);
+
+    uintptr_t throttle_dropped_count(const DeltasThrottler_API *throttler);
+
+    void vec_throttled_deltas_drop(CVec v);
+
     # # Safety
     #
     # - Assumes `name_ptr` is borrowed from a valid Python UTF-8 `str`.
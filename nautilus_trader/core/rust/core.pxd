@@ -7,6 +7,9 @@ cdef extern from "../includes/core.h":
     # Number of milliseconds in one second.
     const uint64_t MILLISECONDS_IN_SECOND # = 1000
 
+    # Number of nanoseconds in one day.
+    const uint64_t NANOSECONDS_IN_DAY # = (86400 * NANOSECONDS_IN_SECOND)
+
     # Number of nanoseconds in one second.
     const uint64_t NANOSECONDS_IN_SECOND # = 1000000000
 
@@ -16,6 +19,15 @@ cdef extern from "../includes/core.h":
     # Number of nanoseconds in one microsecond.
     const uint64_t NANOSECONDS_IN_MICROSECOND # = 1000
 
+    # Running statistics over a rolling window of `f64` values, each updated in (amortized)
+    # constant time per push.
+    #
+    # The sum is maintained with Kahan summation. The variance is maintained from sums of the values
+    # shifted by the first value pushed, avoiding the cancellation of large squared terms when
+    # the values are far from zero (e.g. prices).
+    cdef struct RollingStats:
+        pass
+
     # `CVec` is a C compatible struct that stores an opaque pointer to a block of
     # memory, it's length and the capacity of the vector it was allocated from.
     #
@@ -31,6 +43,17 @@ cdef extern from "../includes/core.h":
         # Used when deallocating the memory
         uintptr_t cap;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`RollingStats`].
+    #
+    # This struct wraps `RollingStats` in a way that makes it compatible with C function
+    # calls, enabling interaction with `RollingStats` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `RollingStats_API` to be
+    # dereferenced to `RollingStats`, providing access to `RollingStats`'s methods without
+    # having to manually access the underlying `RollingStats` instance.
+    cdef struct RollingStats_API:
+        RollingStats *_0;
+
     # Represents a pseudo-random UUID (universally unique identifier)
     # version 4 based on a 128-bit label as specified in RFC 4122.
     cdef struct UUID4_t:
@@ -89,6 +112,44 @@ cdef extern from "../includes/core.h":
     # - If `ptr` is null.
     uint8_t min_increment_precision_from_cstr(const char *ptr);
 
+    # Creates new rolling statistics for a window of `capacity` values.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `capacity` is zero.
+    RollingStats_API rolling_stats_new(uintptr_t capacity);
+
+    void rolling_stats_drop(RollingStats_API stats);
+
+    void rolling_stats_push(RollingStats_API *stats, double value);
+
+    uintptr_t rolling_stats_capacity(const RollingStats_API *stats);
+
+    uintptr_t rolling_stats_len(const RollingStats_API *stats);
+
+    uint8_t rolling_stats_is_full(const RollingStats_API *stats);
+
+    double rolling_stats_sum(const RollingStats_API *stats);
+
+    # Returns the mean of the values in the window, or NaN if empty.
+    double rolling_stats_mean(const RollingStats_API *stats);
+
+    # Returns the minimum value in the window, or NaN if empty.
+    double rolling_stats_min(const RollingStats_API *stats);
+
+    # Returns the maximum value in the window, or NaN if empty.
+    double rolling_stats_max(const RollingStats_API *stats);
+
+    # Returns the sample variance of the values in the window, or NaN if fewer than two values.
+    double rolling_stats_variance(const RollingStats_API *stats);
+
+    # Returns the sample standard deviation of the values in the window, or NaN if fewer than two
+    # values.
+    double rolling_stats_std(const RollingStats_API *stats);
+
+    void rolling_stats_clear(RollingStats_API *stats);
+
     # Drops the C string memory at the pointer.
     #
     # # Safety
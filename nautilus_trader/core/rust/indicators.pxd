@@ -0,0 +1,257 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uintptr_t
+from nautilus_trader.core.rust.model cimport Bar_t, PriceType, QuoteTick_t, TradeTick_t
+
+cdef extern from "../includes/indicators.h":
+
+    # The default period for the signal line of a [`MovingAverageConvergenceDivergence`].
+    const uintptr_t MACD_SIGNAL_PERIOD_DEFAULT # = 9
+
+    cdef enum MovingAverageType:
+        SIMPLE,
+        EXPONENTIAL,
+        DOUBLE_EXPONENTIAL,
+        WILDER,
+        HULL,
+
+    # An indicator which calculates a Average True Range (ATR) across a rolling window.
+    cdef struct AverageTrueRange:
+        pass
+
+    cdef struct ExponentialMovingAverage:
+        pass
+
+    # An indicator which calculates the difference between a fast and slow moving average (MACD),
+    # along with a signal line (a moving average of the MACD) and histogram (MACD less signal).
+    cdef struct MovingAverageConvergenceDivergence:
+        pass
+
+    # An indicator which calculates a relative strength index (RSI) across a rolling window.
+    #
+    # The value is scaled between 0 and 1 (rather than 0 and 100). While the average loss is
+    # zero the value is the maximum of 1, including for a flat series of equal inputs (matching
+    # the Cython `RelativeStrengthIndex`).
+    cdef struct RelativeStrengthIndex:
+        pass
+
+    cdef struct SimpleMovingAverage:
+        pass
+
+    # An indicator which calculates a weighted moving average across a rolling window.
+    cdef struct WeightedMovingAverage:
+        pass
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`ExponentialMovingAverage`].
+    #
+    # This struct wraps `ExponentialMovingAverage` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ExponentialMovingAverage` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `ExponentialMovingAverage_API` to be
+    # dereferenced to `ExponentialMovingAverage`, providing access to `ExponentialMovingAverage`'s
+    # methods without having to manually access the underlying `ExponentialMovingAverage` instance.
+    cdef struct ExponentialMovingAverage_API:
+        ExponentialMovingAverage *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`SimpleMovingAverage`].
+    #
+    # This struct wraps `SimpleMovingAverage` in a way that makes it compatible with C function
+    # calls, enabling interaction with `SimpleMovingAverage` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `SimpleMovingAverage_API` to be
+    # dereferenced to `SimpleMovingAverage`, providing access to `SimpleMovingAverage`'s
+    # methods without having to manually access the underlying `SimpleMovingAverage` instance.
+    cdef struct SimpleMovingAverage_API:
+        SimpleMovingAverage *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`WeightedMovingAverage`].
+    #
+    # This struct wraps `WeightedMovingAverage` in a way that makes it compatible with C function
+    # calls, enabling interaction with `WeightedMovingAverage` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `WeightedMovingAverage_API` to be
+    # dereferenced to `WeightedMovingAverage`, providing access to `WeightedMovingAverage`'s
+    # methods without having to manually access the underlying `WeightedMovingAverage` instance.
+    cdef struct WeightedMovingAverage_API:
+        WeightedMovingAverage *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying
+    # [`MovingAverageConvergenceDivergence`].
+    #
+    # This struct wraps `MovingAverageConvergenceDivergence` in a way that makes it compatible with
+    # C function calls, enabling interaction with `MovingAverageConvergenceDivergence` in a C
+    # environment.
+    #
+    # It implements the `Deref` trait, allowing instances of
+    # `MovingAverageConvergenceDivergence_API` to be dereferenced to
+    # `MovingAverageConvergenceDivergence`, providing access to
+    # `MovingAverageConvergenceDivergence`'s methods without having to manually access the
+    # underlying `MovingAverageConvergenceDivergence` instance.
+    cdef struct MovingAverageConvergenceDivergence_API:
+        MovingAverageConvergenceDivergence *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`RelativeStrengthIndex`].
+    #
+    # This struct wraps `RelativeStrengthIndex` in a way that makes it compatible with C function
+    # calls, enabling interaction with `RelativeStrengthIndex` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `RelativeStrengthIndex_API` to be
+    # dereferenced to `RelativeStrengthIndex`, providing access to `RelativeStrengthIndex`'s
+    # methods without having to manually access the underlying `RelativeStrengthIndex` instance.
+    cdef struct RelativeStrengthIndex_API:
+        RelativeStrengthIndex *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`AverageTrueRange`].
+    #
+    # This struct wraps `AverageTrueRange` in a way that makes it compatible with C function
+    # calls, enabling interaction with `AverageTrueRange` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `AverageTrueRange_API` to be
+    # dereferenced to `AverageTrueRange`, providing access to `AverageTrueRange`'s methods
+    # without having to manually access the underlying `AverageTrueRange` instance.
+    cdef struct AverageTrueRange_API:
+        AverageTrueRange *_0;
+
+    ExponentialMovingAverage_API ema_new(uintptr_t period, PriceType price_type);
+
+    void ema_drop(ExponentialMovingAverage_API ema);
+
+    void ema_update_raw(ExponentialMovingAverage_API *ema, double value);
+
+    void ema_handle_quote(ExponentialMovingAverage_API *ema, const QuoteTick_t *quote);
+
+    void ema_handle_trade(ExponentialMovingAverage_API *ema, const TradeTick_t *trade);
+
+    void ema_handle_bar(ExponentialMovingAverage_API *ema, const Bar_t *bar);
+
+    double ema_value(const ExponentialMovingAverage_API *ema);
+
+    uintptr_t ema_count(const ExponentialMovingAverage_API *ema);
+
+    uint8_t ema_has_inputs(const ExponentialMovingAverage_API *ema);
+
+    uint8_t ema_initialized(const ExponentialMovingAverage_API *ema);
+
+    void ema_reset(ExponentialMovingAverage_API *ema);
+
+    SimpleMovingAverage_API sma_new(uintptr_t period, PriceType price_type);
+
+    void sma_drop(SimpleMovingAverage_API sma);
+
+    void sma_update_raw(SimpleMovingAverage_API *sma, double value);
+
+    void sma_handle_quote(SimpleMovingAverage_API *sma, const QuoteTick_t *quote);
+
+    void sma_handle_trade(SimpleMovingAverage_API *sma, const TradeTick_t *trade);
+
+    void sma_handle_bar(SimpleMovingAverage_API *sma, const Bar_t *bar);
+
+    double sma_value(const SimpleMovingAverage_API *sma);
+
+    uintptr_t sma_count(const SimpleMovingAverage_API *sma);
+
+    uint8_t sma_has_inputs(const SimpleMovingAverage_API *sma);
+
+    uint8_t sma_initialized(const SimpleMovingAverage_API *sma);
+
+    void sma_reset(SimpleMovingAverage_API *sma);
+
+    # Creates a new weighted moving average.
+    #
+    # Pass a null `weights_ptr` for equal weights (a simple average of the inputs).
+    #
+    # # Safety
+    #
+    # - Assumes `weights_ptr` is either null or points to `weights_len` valid `f64` values.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `weights_ptr` is not null and `weights_len` is not equal to `period`.
+    WeightedMovingAverage_API wma_new(uintptr_t period,
+                                      const double *weights_ptr,
+                                      uintptr_t weights_len,
+                                      PriceType price_type);
+
+    void wma_drop(WeightedMovingAverage_API wma);
+
+    void wma_update_raw(WeightedMovingAverage_API *wma, double value);
+
+    void wma_handle_quote(WeightedMovingAverage_API *wma, const QuoteTick_t *quote);
+
+    void wma_handle_trade(WeightedMovingAverage_API *wma, const TradeTick_t *trade);
+
+    void wma_handle_bar(WeightedMovingAverage_API *wma, const Bar_t *bar);
+
+    double wma_value(const WeightedMovingAverage_API *wma);
+
+    uintptr_t wma_count(const WeightedMovingAverage_API *wma);
+
+    uint8_t wma_has_inputs(const WeightedMovingAverage_API *wma);
+
+    uint8_t wma_initialized(const WeightedMovingAverage_API *wma);
+
+    void wma_reset(WeightedMovingAverage_API *wma);
+
+    MovingAverageConvergenceDivergence_API macd_new(uintptr_t fast_period,
+                                                    uintptr_t slow_period,
+                                                    uintptr_t signal_period,
+                                                    MovingAverageType ma_type,
+                                                    PriceType price_type);
+
+    void macd_drop(MovingAverageConvergenceDivergence_API macd);
+
+    void macd_update_raw(MovingAverageConvergenceDivergence_API *macd, double close);
+
+    void macd_handle_bar(MovingAverageConvergenceDivergence_API *macd, const Bar_t *bar);
+
+    double macd_value(const MovingAverageConvergenceDivergence_API *macd);
+
+    double macd_signal(const MovingAverageConvergenceDivergence_API *macd);
+
+    double macd_histogram(const MovingAverageConvergenceDivergence_API *macd);
+
+    uint8_t macd_has_inputs(const MovingAverageConvergenceDivergence_API *macd);
+
+    uint8_t macd_initialized(const MovingAverageConvergenceDivergence_API *macd);
+
+    void macd_reset(MovingAverageConvergenceDivergence_API *macd);
+
+    RelativeStrengthIndex_API rsi_new(uintptr_t period, MovingAverageType ma_type);
+
+    void rsi_drop(RelativeStrengthIndex_API rsi);
+
+    void rsi_update_raw(RelativeStrengthIndex_API *rsi, double value);
+
+    void rsi_handle_bar(RelativeStrengthIndex_API *rsi, const Bar_t *bar);
+
+    double rsi_value(const RelativeStrengthIndex_API *rsi);
+
+    uintptr_t rsi_count(const RelativeStrengthIndex_API *rsi);
+
+    uint8_t rsi_has_inputs(const RelativeStrengthIndex_API *rsi);
+
+    uint8_t rsi_initialized(const RelativeStrengthIndex_API *rsi);
+
+    void rsi_reset(RelativeStrengthIndex_API *rsi);
+
+    AverageTrueRange_API atr_new(uintptr_t period,
+                                 MovingAverageType ma_type,
+                                 uint8_t use_previous,
+                                 double value_floor);
+
+    void atr_drop(AverageTrueRange_API atr);
+
+    void atr_update_raw(AverageTrueRange_API *atr, double high, double low, double close);
+
+    void atr_handle_bar(AverageTrueRange_API *atr, const Bar_t *bar);
+
+    double atr_value(const AverageTrueRange_API *atr);
+
+    uintptr_t atr_count(const AverageTrueRange_API *atr);
+
+    uint8_t atr_has_inputs(const AverageTrueRange_API *atr);
+
+    uint8_t atr_initialized(const AverageTrueRange_API *atr);
+
+    void atr_reset(AverageTrueRange_API *atr);
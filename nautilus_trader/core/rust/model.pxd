@@ -7,9 +7,24 @@ cdef extern from "../includes/model.h":
 
     const uintptr_t DEPTH10_LEN # = 10
 
+    # Book order flag indicating implied liquidity (derived from other instruments, such as spread legs).
+    const uint8_t BOOK_ORDER_FLAG_IMPLIED # = (1 << 0)
+
+    # Book order flag indicating the order is only partially displayed (e.g. an iceberg order).
+    const uint8_t BOOK_ORDER_FLAG_HIDDEN # = (1 << 1)
+
+    # Book order flag indicating the order is synthetic (not resting on the venue).
+    const uint8_t BOOK_ORDER_FLAG_SYNTHETIC # = (1 << 2)
+
     # The maximum length of ASCII characters for a `TradeId` string value (including null terminator).
     const uintptr_t TRADE_ID_LEN # = 37
 
+    # The number of levels per side included in a Kraken checksum.
+    const uintptr_t KRAKEN_CHECKSUM_DEPTH # = 10
+
+    # The number of levels per side included in an OKX checksum.
+    const uintptr_t OKX_CHECKSUM_DEPTH # = 25
+
     # The maximum fixed-point precision.
     const uint8_t FIXED_PRECISION # = 9
 
@@ -52,6 +67,13 @@ cdef extern from "../includes/model.h":
         # An account specific to betting markets.
         BETTING # = 3,
 
+    # The method used to back-adjust prices prior to each roll.
+    cpdef enum AdjustmentMethod:
+        # Shift prior prices by the price difference between the contracts at the roll.
+        DIFFERENCE # = 1,
+        # Scale prior prices by the price ratio between the contracts at the roll.
+        RATIO # = 2,
+
     # An aggregation source for derived data.
     cpdef enum AggregationSource:
         # The data is externally aggregated (outside the Nautilus system boundary).
@@ -96,6 +118,22 @@ cdef extern from "../includes/model.h":
         # The state of the order book is cleared.
         CLEAR # = 4,
 
+    # The venue-specific scheme used to compute an order book checksum.
+    cpdef enum BookChecksumType:
+        # Kraken style: asks then bids, with decimal points and leading zeros removed.
+        KRAKEN # = 1,
+        # OKX style: interleaved bid/ask price and size strings joined by `:`.
+        OKX # = 2,
+
+    # The policy applied when an order book delta leaves the book crossed or locked.
+    cpdef enum BookIntegrityPolicy:
+        # Apply the delta and leave the book as is.
+        IGNORE # = 1,
+        # Apply the delta and return a [`BookIntegrityError`].
+        RAISE_ERROR # = 2,
+        # Apply the delta then remove the opposite side levels crossed (or locked) by it.
+        DROP_CROSSED_LEVELS # = 3,
+
     # The order book type, representing the type of levels granularity and delta updating heuristics.
     cpdef enum BookType:
         # Top-of-book best bid/ask, one level per side.
@@ -161,6 +199,27 @@ cdef extern from "../includes/model.h":
         # When the instrument expiration was reached.
         CONTRACT_EXPIRED # = 2,
 
+    # The reason an order is invalid for an instrument.
+    cpdef enum InstrumentViolation:
+        # The quantity precision exceeds the instrument size precision.
+        QUANTITY_PRECISION # = 1,
+        # The quantity is not a multiple of the instrument size increment.
+        QUANTITY_INCREMENT # = 2,
+        # The quantity is less than the instrument minimum quantity.
+        QUANTITY_BELOW_MIN # = 3,
+        # The quantity is greater than the instrument maximum quantity.
+        QUANTITY_ABOVE_MAX # = 4,
+        # The price precision exceeds the instrument price precision.
+        PRICE_PRECISION # = 5,
+        # The price is not a multiple of the instrument price increment.
+        PRICE_INCREMENT # = 6,
+        # The notional value is less than the instrument minimum notional.
+        NOTIONAL_BELOW_MIN # = 7,
+        # The notional value is greater than the instrument maximum notional.
+        NOTIONAL_ABOVE_MAX # = 8,
+        # A notional limit is in a different currency to the notional value.
+        NOTIONAL_CURRENCY_MISMATCH # = 9,
+
     # The liqudity side for a trade.
     cpdef enum LiquiditySide:
         # No liquidity side specified.
@@ -354,6 +413,23 @@ cdef extern from "../includes/model.h":
         # Reserved for future use.
         RESERVED_1 # = (1 << 2),
 
+    # The rule determining when a continuous series rolls from the front contract to the next.
+    cpdef enum RollRule:
+        # Roll a fixed number of calendar days before the front contract expires.
+        DAYS_BEFORE_EXPIRY # = 1,
+        # Roll at the first bar where the next contract trades more volume than the front
+        # contract (at the same timestamp), otherwise at expiration of the front contract.
+        VOLUME_CROSSOVER # = 2,
+
+    # The rounding mode used when aligning a value to an instrument increment.
+    cpdef enum RoundingMode:
+        # Round to the nearest increment, with halfway values rounded away from zero.
+        NEAREST # = 1,
+        # Round up to the next increment (towards positive infinity).
+        UP # = 2,
+        # Round down to the previous increment (towards negative infinity).
+        DOWN # = 3,
+
     # The 'Time in Force' instruction for an order.
     cpdef enum TimeInForce:
         # Good-Till-Canceled (GTC) - the order remains active until canceled.
@@ -382,7 +458,8 @@ cdef extern from "../includes/model.h":
 
     # The trailing offset type for an order type which specifies a trailing stop/trigger or limit price.
     cpdef enum TrailingOffsetType:
-        # No trailing offset type is specified (invalid for trailing type orders).
+        # No trailing offset type is specified (required for all non-trailing order types, and
+        # invalid for trailing type orders).
         NO_TRAILING_OFFSET # = 0,
         # The trailing offset is based on a market price.
         PRICE # = 1,
@@ -416,13 +493,45 @@ cdef extern from "../includes/model.h":
         # Based on the index price for the instrument.
         INDEX_PRICE # = 9,
 
+    cdef struct CashAccount:
+        pass
+
+    # Wraps the available commission models.
+    cdef struct CommissionModelAny:
+        pass
+
+    # Builds a continuous bar series from the bars of individual futures contracts.
+    #
+    # Each contract contributes its bars from the previous roll (inclusive) up to its own roll
+    # (exclusive), so any overlapping data between contracts is only taken from one of them.
+    # Prices prior to each roll are back-adjusted, so the most recent contract remains unadjusted.
+    cdef struct ContinuousContractBuilder:
+        pass
+
+    # The output of a [`ContinuousContractBuilder`].
+    cdef struct ContinuousSeries:
+        pass
+
+    cdef struct InstrumentAny:
+        pass
+
     # Represents a discrete price level in an order book.
     #
     # The level maintains a collection of orders as well as tracking insertion order
     # to preserve FIFO queue dynamics.
+    #
+    # The level also tracks an order count, which is updated as orders are added and
+    # removed. For MBP books (where each level holds a single aggregated order) the count
+    # can instead be set from venue data with [`Level::set_count`].
     cdef struct Level:
         pass
 
+    cdef struct MarginAccount:
+        pass
+
+    cdef struct OrderAny:
+        pass
+
     # Provides a high-performance, versatile order book.
     #
     # Capable of handling various levels of data granularity:
@@ -438,6 +547,17 @@ cdef extern from "../includes/model.h":
     cdef struct OrderBookDeltas_t:
         pass
 
+    # Wraps an `OrderEvent` allowing polymorphism.
+    cdef struct OrderEventAny:
+        pass
+
+    # Represents a position in a market.
+    #
+    # The position ID may be assigned at the trading venue, or can be system
+    # generated depending on a strategies OMS (Order Management System) settings.
+    cdef struct Position:
+        pass
+
     # Represents a synthetic instrument with prices derived from component instruments using a
     # formula.
     cdef struct SyntheticInstrument:
@@ -504,6 +624,8 @@ cdef extern from "../includes/model.h":
         Quantity_t size;
         # The order ID.
         uint64_t order_id;
+        # The order flags bit field (see the `BOOK_ORDER_FLAG_*` constants).
+        uint8_t flags;
 
     # Represents a single change/delta in an order book.
     cdef struct OrderBookDelta_t:
@@ -686,6 +808,106 @@ cdef extern from "../includes/model.h":
         TradeTick_t trade;
         Bar_t bar;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`CashAccount`].
+    #
+    # This struct wraps `CashAccount` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CashAccount` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `CashAccount_API` to be
+    # dereferenced to `CashAccount`, providing access to `CashAccount`'s methods without
+    # having to manually access the underlying `CashAccount` instance.
+    cdef struct CashAccount_API:
+        CashAccount *_0;
+
+    # Represents a valid account ID.
+    cdef struct AccountId_t:
+        char* _0;
+
+    # Represents a medium of exchange in a specified denomination with a fixed decimal precision.
+    #
+    # Handles up to 9 decimals of precision.
+    cdef struct Currency_t:
+        # The currency code as an alpha-3 string (e.g., "USD", "EUR").
+        char* code;
+        # The currency decimal precision.
+        uint8_t precision;
+        # The currency code (ISO 4217).
+        uint16_t iso4217;
+        # The full name of the currency.
+        char* name;
+        # The currency type, indicating its category (e.g. Fiat, Crypto).
+        CurrencyType currency_type;
+
+    # Represents an amount of money in a specified currency denomination.
+    #
+    # - `MONEY_MAX` = 9_223_372_036
+    # - `MONEY_MIN` = -9_223_372_036
+    cdef struct Money_t:
+        # The raw monetary amount as a signed 64-bit integer.
+        # Represents the unscaled amount, with `currency.precision` defining the number of decimal places.
+        int64_t raw;
+        # The currency denomination associated with the monetary amount.
+        Currency_t currency;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`InstrumentAny`].
+    #
+    # This struct wraps `InstrumentAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with instruments in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `InstrumentAny_API` to be
+    # dereferenced to `InstrumentAny`, providing access to `InstrumentAny`'s methods without
+    # having to manually access the underlying instance.
+    cdef struct InstrumentAny_API:
+        InstrumentAny *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`Position`].
+    #
+    # This struct wraps `Position` in a way that makes it compatible with C function
+    # calls, enabling interaction with `Position` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `Position_API` to be
+    # dereferenced to `Position`, providing access to `Position`'s methods without
+    # having to manually access the underlying `Position` instance.
+    cdef struct Position_API:
+        Position *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`MarginAccount`].
+    #
+    # This struct wraps `MarginAccount` in a way that makes it compatible with C function
+    # calls, enabling interaction with `MarginAccount` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `MarginAccount_API` to be
+    # dereferenced to `MarginAccount`, providing access to `MarginAccount`'s methods without
+    # having to manually access the underlying `MarginAccount` instance.
+    cdef struct MarginAccount_API:
+        MarginAccount *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`CommissionModelAny`].
+    #
+    # This struct wraps `CommissionModelAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `CommissionModelAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `CommissionModel_API` to be
+    # dereferenced to `CommissionModelAny`, providing access to `CommissionModelAny`'s methods
+    # without having to manually access the underlying `CommissionModelAny` instance.
+    cdef struct CommissionModel_API:
+        CommissionModelAny *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying `ContinuousContractBuilder`.
+    #
+    # This struct wraps `ContinuousContractBuilder` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ContinuousContractBuilder` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `ContinuousContractBuilder_API` to be
+    # dereferenced to `ContinuousContractBuilder`, providing access to `ContinuousContractBuilder`'s
+    # methods without having to manually access the underlying instance.
+    cdef struct ContinuousContractBuilder_API:
+        ContinuousContractBuilder *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying `ContinuousSeries`.
+    cdef struct ContinuousSeries_API:
+        ContinuousSeries *_0;
+
     # Represents a valid trader ID.
     cdef struct TraderId_t:
         char* _0;
@@ -727,10 +949,6 @@ cdef extern from "../includes/model.h":
         uint64_t ts_event;
         uint64_t ts_init;
 
-    # Represents a valid account ID.
-    cdef struct AccountId_t:
-        char* _0;
-
     cdef struct OrderSubmitted_t:
         TraderId_t trader_id;
         StrategyId_t strategy_id;
@@ -769,6 +987,21 @@ cdef extern from "../includes/model.h":
         uint64_t ts_init;
         uint8_t reconciliation;
 
+    # C compatible Foreign Function Interface (FFI) for an underlying [`OrderEventAny`].
+    #
+    # Order events with optional fields are not C compatible by value, so are constructed
+    # behind this pointer and read through the `order_event_*` accessors.
+    #
+    # It implements the `Deref` trait, allowing instances of `OrderEventAny_API` to be
+    # dereferenced to `OrderEventAny`, providing access to `OrderEventAny`'s methods without
+    # having to manually access the underlying `OrderEventAny` instance.
+    cdef struct OrderEventAny_API:
+        OrderEventAny *_0;
+
+    # Represents a valid position ID.
+    cdef struct PositionId_t:
+        char* _0;
+
     # Represents a system client ID.
     cdef struct ClientId_t:
         char* _0;
@@ -785,9 +1018,16 @@ cdef extern from "../includes/model.h":
     cdef struct OrderListId_t:
         char* _0;
 
-    # Represents a valid position ID.
-    cdef struct PositionId_t:
-        char* _0;
+    # The components of an OCC (Options Clearing Corporation) option symbol.
+    cdef struct OccSymbol:
+        # The root symbol of the underlying.
+        char* underlying;
+        # UNIX timestamp (nanoseconds) for the expiration date at midnight UTC.
+        uint64_t expiration_ns;
+        # The option kind.
+        OptionKind option_kind;
+        # The strike price (with 3 decimal places, as encoded in the symbol).
+        Price_t strike_price;
 
     # C compatible Foreign Function Interface (FFI) for an underlying
     # [`SyntheticInstrument`].
@@ -812,6 +1052,21 @@ cdef extern from "../includes/model.h":
     cdef struct OrderBook_API:
         OrderBook *_0;
 
+    # C compatible result of simulating fills for a quantity against an order book.
+    #
+    # `fills` is a `CVec` of `BookFill` (one per level filled) ordered from the top of book.
+    cdef struct CSimulatedFills:
+        CVec fills;
+        Quantity_t filled_qty;
+        uint8_t is_partial;
+
+    # C compatible snapshot of the top levels of an order book.
+    #
+    # Both `bids` and `asks` are `CVec`s of `BookLevel` ordered best first.
+    cdef struct CBookSnapshot:
+        CVec bids;
+        CVec asks;
+
     # C compatible Foreign Function Interface (FFI) for an underlying order book[`Level`].
     #
     # This struct wraps `Level` in a way that makes it compatible with C function
@@ -823,34 +1078,60 @@ cdef extern from "../includes/model.h":
     cdef struct Level_API:
         Level *_0;
 
-    # Represents a medium of exchange in a specified denomination with a fixed decimal precision.
+    # C compatible Foreign Function Interface (FFI) for an underlying [`OrderAny`].
     #
-    # Handles up to 9 decimals of precision.
-    cdef struct Currency_t:
-        # The currency code as an alpha-3 string (e.g., "USD", "EUR").
-        char* code;
-        # The currency decimal precision.
-        uint8_t precision;
-        # The currency code (ISO 4217).
-        uint16_t iso4217;
-        # The full name of the currency.
-        char* name;
-        # The currency type, indicating its category (e.g. Fiat, Crypto).
-        CurrencyType currency_type;
+    # This struct wraps `OrderAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `OrderAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `OrderAny_API` to be
+    # dereferenced to `OrderAny`, providing access to `OrderAny`'s methods without
+    # having to manually access the underlying `OrderAny` instance.
+    cdef struct OrderAny_API:
+        OrderAny *_0;
 
-    # Represents an amount of money in a specified currency denomination.
+    # Represents an account balance denominated in a particular currency.
     #
-    # - `MONEY_MAX` = 9_223_372_036
-    # - `MONEY_MIN` = -9_223_372_036
-    cdef struct Money_t:
-        # The raw monetary amount as a signed 64-bit integer.
-        # Represents the unscaled amount, with `currency.precision` defining the number of decimal places.
-        int64_t raw;
-        # The currency denomination associated with the monetary amount.
+    # The `free` balance is always the `total` less the `locked` balance, which is checked on
+    # construction and deserialization.
+    cdef struct AccountBalance_t:
+        # The account balance currency.
+        Currency_t currency;
+        # The total account balance.
+        Money_t total;
+        # The account balance locked (assigned to pending orders).
+        Money_t locked;
+        # The account balance free for trading.
+        Money_t free;
+
+    # Represents a margin balance for a particular instrument, denominated in a particular currency.
+    cdef struct MarginBalance_t:
+        # The initial margin requirement.
+        Money_t initial;
+        # The maintenance margin requirement.
+        Money_t maintenance;
+        # The margin balance currency.
         Currency_t currency;
+        # The instrument ID for the margin.
+        InstrumentId_t instrument_id;
+
+    # Represents a simulated fill against a single order book price level.
+    cdef struct BookFill:
+        # The price of the level filled against.
+        Price_t price;
+        # The quantity filled at the level.
+        Quantity_t size;
+
+    # Represents an aggregated order book price level in a flat C compatible layout.
+    cdef struct BookLevel:
+        # The raw fixed-point price of the level.
+        int64_t price_raw;
+        # The raw fixed-point total size of the level.
+        uint64_t size_raw;
+        # The number of orders at the level.
+        uint64_t count;
 
     # Represents a NULL book order (used with the `Clear` action or where an order is not specified).
-    const BookOrder_t NULL_ORDER # = <BookOrder_t>{ OrderSide_NoOrderSide, <Price_t>{ 0, 0 }, <Quantity_t>{ 0, 0 }, 0 }
+    const BookOrder_t NULL_ORDER # = <BookOrder_t>{ OrderSide_NoOrderSide, <Price_t>{ 0, 0 }, <Quantity_t>{ 0, 0 }, 0, 0 }
 
     # The sentinel `Price` representing errors (this will be removed when Cython is gone).
     const Price_t ERROR_PRICE # = <Price_t>{ PRICE_ERROR, 0 }
@@ -859,6 +1140,257 @@ cdef extern from "../includes/model.h":
 
     void interned_string_stats();
 
+    # Creates a new cash account with no balances.
+    #
+    # # Safety
+    #
+    # - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+    CashAccount_API cash_account_new(AccountId_t account_id,
+                                     const Currency_t *base_currency,
+                                     uint8_t calculate_account_state,
+                                     uint64_t ts_init);
+
+    void cash_account_drop(CashAccount_API account);
+
+    # Updates the balance of the account for the currency of `total`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `total` is not the sum of `locked` and `free`.
+    # - If `total` is negative.
+    void cash_account_update_balance(CashAccount_API *account,
+                                     Money_t total,
+                                     Money_t locked,
+                                     Money_t free);
+
+    # Returns the total balance for the `currency` (zero if the account has no balance).
+    Money_t cash_account_balance_total(const CashAccount_API *account, Currency_t currency);
+
+    # Returns the free balance for the `currency` (zero if the account has no balance).
+    Money_t cash_account_balance_free(const CashAccount_API *account, Currency_t currency);
+
+    # Returns the locked balance for the `currency` (zero if the account has no balance).
+    Money_t cash_account_balance_locked(const CashAccount_API *account, Currency_t currency);
+
+    # Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `side` is `NO_ORDER_SIDE`.
+    Money_t cash_account_calculate_balance_locked(CashAccount_API *account,
+                                                  const InstrumentAny_API *instrument,
+                                                  OrderSide side,
+                                                  Quantity_t quantity,
+                                                  Price_t price,
+                                                  uint8_t use_quote_for_inverse);
+
+    # Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+    # freed with `vec_money_drop`.
+    #
+    # # Safety
+    #
+    # - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `order_side` is `NO_ORDER_SIDE`.
+    CVec cash_account_calculate_pnls(const CashAccount_API *account,
+                                     const InstrumentAny_API *instrument,
+                                     OrderSide order_side,
+                                     Quantity_t last_qty,
+                                     Price_t last_px,
+                                     const Position_API *position);
+
+    # Creates a new margin account with no balances (and a default leverage of 1).
+    #
+    # # Safety
+    #
+    # - Assumes `base_currency` is either null (multi-currency account) or a valid `Currency` pointer.
+    MarginAccount_API margin_account_new(AccountId_t account_id,
+                                         const Currency_t *base_currency,
+                                         uint8_t calculate_account_state,
+                                         uint64_t ts_init);
+
+    void margin_account_drop(MarginAccount_API account);
+
+    # Updates the balance of the account for the currency of `total`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `total` is not the sum of `locked` and `free`.
+    # - If `total` is negative.
+    void margin_account_update_balance(MarginAccount_API *account,
+                                       Money_t total,
+                                       Money_t locked,
+                                       Money_t free);
+
+    # Returns the total balance for the `currency` (zero if the account has no balance).
+    Money_t margin_account_balance_total(const MarginAccount_API *account, Currency_t currency);
+
+    # Returns the free balance for the `currency` (zero if the account has no balance).
+    Money_t margin_account_balance_free(const MarginAccount_API *account, Currency_t currency);
+
+    # Returns the locked balance for the `currency` (zero if the account has no balance).
+    Money_t margin_account_balance_locked(const MarginAccount_API *account, Currency_t currency);
+
+    void margin_account_set_default_leverage(MarginAccount_API *account, double leverage);
+
+    void margin_account_set_leverage(MarginAccount_API *account,
+                                     InstrumentId_t instrument_id,
+                                     double leverage);
+
+    double margin_account_leverage(const MarginAccount_API *account, InstrumentId_t instrument_id);
+
+    # Updates the initial margin for the instrument, and recalculates the balance.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the account has no balance in the margin currency.
+    # - If the total margin exceeds the total balance.
+    void margin_account_update_initial_margin(MarginAccount_API *account,
+                                              InstrumentId_t instrument_id,
+                                              Money_t margin_init);
+
+    # Updates the maintenance margin for the instrument, and recalculates the balance.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the account has no balance in the margin currency.
+    # - If the total margin exceeds the total balance.
+    void margin_account_update_maintenance_margin(MarginAccount_API *account,
+                                                  InstrumentId_t instrument_id,
+                                                  Money_t margin_maintenance);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If there is no margin for the instrument.
+    Money_t margin_account_initial_margin(const MarginAccount_API *account,
+                                          InstrumentId_t instrument_id);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If there is no margin for the instrument.
+    Money_t margin_account_maintenance_margin(const MarginAccount_API *account,
+                                              InstrumentId_t instrument_id);
+
+    # Returns the initial margin for a position of `quantity` at `price`, using the leverage for
+    # the instrument (where a zero leverage falls back to the default leverage).
+    #
+    # For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+    Money_t margin_account_calculate_initial_margin(MarginAccount_API *account,
+                                                    const InstrumentAny_API *instrument,
+                                                    Quantity_t quantity,
+                                                    Price_t price,
+                                                    uint8_t use_quote_for_inverse);
+
+    # Returns the maintenance margin for a position of `quantity` at `price`, using the leverage for
+    # the instrument (where a zero leverage falls back to the default leverage).
+    #
+    # For inverse instruments the margin is in the base currency, unless `use_quote_for_inverse`.
+    Money_t margin_account_calculate_maintenance_margin(MarginAccount_API *account,
+                                                        const InstrumentAny_API *instrument,
+                                                        Quantity_t quantity,
+                                                        Price_t price,
+                                                        uint8_t use_quote_for_inverse);
+
+    # Returns the balance to lock for an order with the given `side`, `quantity` and `price`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `side` is `NO_ORDER_SIDE`.
+    Money_t margin_account_calculate_balance_locked(MarginAccount_API *account,
+                                                    const InstrumentAny_API *instrument,
+                                                    OrderSide side,
+                                                    Quantity_t quantity,
+                                                    Price_t price,
+                                                    uint8_t use_quote_for_inverse);
+
+    # Returns a `CVec` of the `Money` PnLs for a fill with the given details, which must be
+    # freed with `vec_money_drop`.
+    #
+    # # Safety
+    #
+    # - Assumes `position` is either null (no position) or a valid `Position_API` pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `order_side` is `NO_ORDER_SIDE`.
+    CVec margin_account_calculate_pnls(const MarginAccount_API *account,
+                                       const InstrumentAny_API *instrument,
+                                       OrderSide order_side,
+                                       Quantity_t last_qty,
+                                       Price_t last_px,
+                                       const Position_API *position);
+
+    # Creates a new maker/taker commission model with fees in basis points.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `maker_bps` or `taker_bps` is not finite.
+    CommissionModel_API commission_model_maker_taker_new(double maker_bps, double taker_bps);
+
+    # Creates a new per-contract commission model.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `fee_per_contract` is not finite or is negative.
+    CommissionModel_API commission_model_per_contract_new(double fee_per_contract);
+
+    # Creates a new tiered commission model from `len` tiers.
+    #
+    # # Safety
+    #
+    # - Assumes `min_volumes`, `maker_bps` and `taker_bps` are valid pointers to arrays of
+    #   at least `len` elements.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If any value is not finite.
+    # - If the tiers are empty, do not start at zero volume, or are not in ascending order.
+    CommissionModel_API commission_model_tiered_new(const double *min_volumes,
+                                                    const double *maker_bps,
+                                                    const double *taker_bps,
+                                                    uintptr_t len);
+
+    void commission_model_drop(CommissionModel_API model);
+
+    # Sets the traded volume used to select the tier of a tiered commission model.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `model` is not a tiered commission model.
+    # - If `volume` is not finite.
+    void commission_model_set_volume(CommissionModel_API *model, double volume);
+
+    # Calculates the commission for a fill, returned in the `settlement_currency`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `is_inverse` and `price` is zero.
+    # - If `liquidity_side` is `NO_LIQUIDITY_SIDE` for a maker/taker or tiered model.
+    Money_t commission_model_calculate(const CommissionModel_API *model,
+                                       Quantity_t quantity,
+                                       Price_t price,
+                                       Quantity_t multiplier,
+                                       uint8_t is_inverse,
+                                       Currency_t settlement_currency,
+                                       LiquiditySide liquidity_side);
+
     BarSpecification_t bar_specification_new(uintptr_t step,
                                              uint8_t aggregation,
                                              uint8_t price_type);
@@ -959,6 +1491,48 @@ cdef extern from "../includes/model.h":
     # Returns a [`Bar`] as a C string.
     const char *bar_to_cstr(const Bar_t *bar);
 
+    ContinuousContractBuilder_API continuous_contract_builder_new(BarType_t bar_type,
+                                                                  RollRule roll_rule,
+                                                                  uint32_t roll_days,
+                                                                  AdjustmentMethod adjustment_method);
+
+    void continuous_contract_builder_drop(ContinuousContractBuilder_API builder);
+
+    # Adds the bars for a single contract expiring at `expiration_ns`.
+    #
+    # The bars are copied, so the caller retains ownership of the `bars` memory.
+    #
+    # # Safety
+    #
+    # - Assumes `bars` is a valid `CVec` containing `Bar` objects.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the contract was already added, or the bars are invalid for it.
+    void continuous_contract_builder_add_contract(ContinuousContractBuilder_API *builder,
+                                                  InstrumentId_t instrument_id,
+                                                  uint64_t expiration_ns,
+                                                  const CVec *bars);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If the continuous series cannot be built from the added contracts.
+    ContinuousSeries_API continuous_contract_builder_build(const ContinuousContractBuilder_API *builder);
+
+    void continuous_series_drop(ContinuousSeries_API series);
+
+    # Returns a `CVec` of the adjusted `Bar`s, which must be freed with `vec_bars_drop`.
+    CVec continuous_series_bars(const ContinuousSeries_API *series);
+
+    # Returns a `CVec` of the `RollEvent`s, which must be freed with `vec_roll_events_drop`.
+    CVec continuous_series_rolls(const ContinuousSeries_API *series);
+
+    void vec_bars_drop(CVec v);
+
+    void vec_roll_events_drop(CVec v);
+
     OrderBookDelta_t orderbook_delta_new(InstrumentId_t instrument_id,
                                          BookAction action,
                                          BookOrder_t order,
@@ -1066,8 +1640,38 @@ cdef extern from "../includes/model.h":
     # Returns a [`QuoteTick`] as a C string pointer.
     const char *quote_tick_to_cstr(const QuoteTick_t *quote);
 
-    TradeTick_t trade_tick_new(InstrumentId_t instrument_id,
-                               int64_t price_raw,
+    # Returns the [`QuoteTick`] inverted for the pair with the base and quote currencies swapped.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the bid or ask price is not positive.
+    QuoteTick_t quote_tick_invert(const QuoteTick_t *quote, InstrumentId_t instrument_id);
+
+    # Derives the [`QuoteTick`] for the `base`/`quote` pair from two quotes for pairs which share
+    # a currency, writing it to `cross_quote`.
+    #
+    # Returns 1 if the cross quote was derived, otherwise 0 (with `cross_quote` left unwritten).
+    #
+    # # Safety
+    #
+    # - Assumes `cross_quote` is a valid pointer to write the [`QuoteTick`] to.
+    uint8_t quote_tick_cross(const QuoteTick_t *a,
+                             Currency_t a_base,
+                             Currency_t a_quote,
+                             const QuoteTick_t *b,
+                             Currency_t b_base,
+                             Currency_t b_quote,
+                             InstrumentId_t instrument_id,
+                             Currency_t base,
+                             Currency_t quote,
+                             QuoteTick_t *cross_quote);
+
+    # Returns the spread between the given `bid` and `ask` prices, or `NaN` for a crossed book.
+    double price_spread(double bid, double ask);
+
+    TradeTick_t trade_tick_new(InstrumentId_t instrument_id,
+                               int64_t price_raw,
                                uint8_t price_prec,
                                uint64_t size_raw,
                                uint8_t size_prec,
@@ -1092,6 +1696,18 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     AccountType account_type_from_cstr(const char *ptr);
 
+    # Returns an enum from its name or a common broker alias (e.g. `SPOT` or `CROSS_MARGIN`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the string is not a supported `AccountType` alias.
+    AccountType account_type_from_broker_alias(const char *ptr);
+
     const char *aggregation_source_to_cstr(AggregationSource value);
 
     # Returns an enum from a Python string.
@@ -1110,6 +1726,18 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     AggressorSide aggressor_side_from_cstr(const char *ptr);
 
+    # Returns the opposite aggressor side (`NO_AGGRESSOR` is unchanged).
+    AggressorSide aggressor_side_opposite(AggressorSide value);
+
+    # Returns whether the aggressor side is `BUYER`.
+    uint8_t aggressor_side_is_long_side(AggressorSide value);
+
+    # Returns whether the aggressor side is `SELLER`.
+    uint8_t aggressor_side_is_short_side(AggressorSide value);
+
+    # Returns the liquidity side of the passive counterparty to a trade with the aggressor side.
+    LiquiditySide aggressor_side_maker_side(AggressorSide value);
+
     const char *asset_class_to_cstr(AssetClass value);
 
     # Returns an enum from a Python string.
@@ -1119,6 +1747,12 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     AssetClass asset_class_from_cstr(const char *ptr);
 
+    uint8_t asset_class_is_crypto(AssetClass value);
+
+    uint8_t asset_class_is_24_7(AssetClass value);
+
+    const char *asset_class_description(AssetClass value);
+
     const char *instrument_class_to_cstr(InstrumentClass value);
 
     # Returns an enum from a Python string.
@@ -1128,6 +1762,12 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     InstrumentClass instrument_class_from_cstr(const char *ptr);
 
+    uint8_t instrument_class_is_24_7(InstrumentClass value);
+
+    uint8_t instrument_class_requires_expiry(InstrumentClass value);
+
+    const char *instrument_class_description(InstrumentClass value);
+
     const char *bar_aggregation_to_cstr(uint8_t value);
 
     # Returns an enum from a Python string.
@@ -1137,6 +1777,18 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     uint8_t bar_aggregation_from_cstr(const char *ptr);
 
+    # Returns an enum from a common time unit abbreviation (e.g. `M`, `H` or `D`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the string is neither a supported abbreviation nor a `BarAggregation` name.
+    uint8_t bar_aggregation_from_abbrev(const char *ptr);
+
     const char *book_action_to_cstr(BookAction value);
 
     # Returns an enum from a Python string.
@@ -1146,6 +1798,8 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     BookAction book_action_from_cstr(const char *ptr);
 
+    uint8_t book_action_priority(BookAction value);
+
     const char *book_type_to_cstr(BookType value);
 
     # Returns an enum from a Python string.
@@ -1155,6 +1809,9 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     BookType book_type_from_cstr(const char *ptr);
 
+    # Returns whether deltas with the given `action` are meaningful for a book of the book type.
+    uint8_t book_type_allows_action(BookType value, BookAction action);
+
     const char *contingency_type_to_cstr(ContingencyType value);
 
     # Returns an enum from a Python string.
@@ -1164,6 +1821,18 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     ContingencyType contingency_type_from_cstr(const char *ptr);
 
+    # Returns an enum from its name or a common venue alias (e.g. `O.C.O` or `OneCancelsOther`).
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the string is not a supported `ContingencyType` alias.
+    ContingencyType contingency_type_from_alias(const char *ptr);
+
     const char *currency_type_to_cstr(CurrencyType value);
 
     # Returns an enum from a Python string.
@@ -1173,6 +1842,8 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     CurrencyType currency_type_from_cstr(const char *ptr);
 
+    uint8_t currency_type_default_precision(CurrencyType value);
+
     # Returns an enum from a Python string.
     #
     # # Safety
@@ -1191,6 +1862,13 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     LiquiditySide liquidity_side_from_cstr(const char *ptr);
 
+    uint8_t liquidity_side_is_maker(LiquiditySide value);
+
+    uint8_t liquidity_side_is_taker(LiquiditySide value);
+
+    # Returns the applicable fee rate for the liquidity side, or NaN if `NO_LIQUIDITY_SIDE`.
+    double liquidity_side_fee_rate(LiquiditySide value, double maker_rate, double taker_rate);
+
     const char *market_status_to_cstr(MarketStatus value);
 
     # Returns an enum from a Python string.
@@ -1202,6 +1880,12 @@ cdef extern from "../includes/model.h":
 
     const char *market_status_action_to_cstr(MarketStatusAction value);
 
+    # Returns the session phase which typically follows `value`, or `NONE` if the action
+    # carries no session phase.
+    MarketStatusAction market_status_action_next_expected(MarketStatusAction value);
+
+    uint8_t market_status_action_valid_transition(MarketStatusAction from, MarketStatusAction to);
+
     # Returns an enum from a Python string.
     #
     # # Safety
@@ -1218,6 +1902,9 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     OmsType oms_type_from_cstr(const char *ptr);
 
+    # Returns the default OMS type for the given `account` type.
+    OmsType oms_type_default_for_account(AccountType account);
+
     const char *option_kind_to_cstr(OptionKind value);
 
     # Returns an enum from a Python string.
@@ -1236,6 +1923,17 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     OrderSide order_side_from_cstr(const char *ptr);
 
+    double order_side_as_signed_multiplier(OrderSide value);
+
+    # Returns the opposite order side (`NO_ORDER_SIDE` is unchanged).
+    OrderSide order_side_opposite(OrderSide value);
+
+    # Returns whether the order side is `BUY`.
+    uint8_t order_side_is_long_side(OrderSide value);
+
+    # Returns whether the order side is `SELL`.
+    uint8_t order_side_is_short_side(OrderSide value);
+
     const char *order_status_to_cstr(OrderStatus value);
 
     # Returns an enum from a Python string.
@@ -1245,6 +1943,19 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     OrderStatus order_status_from_cstr(const char *ptr);
 
+    uint8_t order_status_valid_transition(OrderStatus from_status, OrderStatus to_status);
+
+    # Returns whether an order with the status is live on the trading venue and can still fill.
+    uint8_t order_status_is_active(OrderStatus value);
+
+    uint8_t order_type_requires_trigger_type(OrderType value);
+
+    uint8_t order_type_trigger_compatible(OrderType order_type, TriggerType trigger);
+
+    uint8_t order_type_is_trailing(OrderType value);
+
+    uint8_t order_type_trailing_offset_compatible(OrderType order_type, TrailingOffsetType offset);
+
     const char *order_type_to_cstr(OrderType value);
 
     # Returns an enum from a Python string.
@@ -1263,6 +1974,19 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     PositionSide position_side_from_cstr(const char *ptr);
 
+    # Returns the opposite side (`LONG` for `SHORT` and vice versa), with `FLAT` and
+    # `NO_POSITION_SIDE` unchanged.
+    PositionSide position_side_opposite(PositionSide value);
+
+    # Returns whether the side is `FLAT`.
+    uint8_t position_side_is_flat(PositionSide value);
+
+    # Returns whether the side is `LONG`.
+    uint8_t position_side_is_long_side(PositionSide value);
+
+    # Returns whether the side is `SHORT`.
+    uint8_t position_side_is_short_side(PositionSide value);
+
     const char *price_type_to_cstr(PriceType value);
 
     # Returns an enum from a Python string.
@@ -1281,6 +2005,15 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     RecordFlag record_flag_from_cstr(const char *ptr);
 
+    const char *rounding_mode_to_cstr(RoundingMode value);
+
+    # Returns an enum from a Python string.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    RoundingMode rounding_mode_from_cstr(const char *ptr);
+
     const char *time_in_force_to_cstr(TimeInForce value);
 
     # Returns an enum from a Python string.
@@ -1290,6 +2023,10 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     TimeInForce time_in_force_from_cstr(const char *ptr);
 
+    uint8_t time_in_force_is_immediate(TimeInForce value);
+
+    uint8_t time_in_force_allows_partial(TimeInForce value);
+
     const char *trading_state_to_cstr(TradingState value);
 
     # Returns an enum from a Python string.
@@ -1317,6 +2054,9 @@ cdef extern from "../includes/model.h":
     # - Assumes `ptr` is a valid C string pointer.
     TriggerType trigger_type_from_cstr(const char *ptr);
 
+    # Returns the default trigger type for the given `order_type`.
+    TriggerType trigger_type_default_for_order_type(OrderType order_type);
+
     # # Safety
     #
     # - Assumes `reason_ptr` is a valid C string pointer.
@@ -1380,6 +2120,183 @@ cdef extern from "../includes/model.h":
                                        uint64_t ts_init,
                                        uint8_t reconciliation);
 
+    # # Safety
+    #
+    # - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_canceled_new(TraderId_t trader_id,
+                                         StrategyId_t strategy_id,
+                                         InstrumentId_t instrument_id,
+                                         ClientOrderId_t client_order_id,
+                                         const VenueOrderId_t *venue_order_id,
+                                         const AccountId_t *account_id,
+                                         UUID4_t event_id,
+                                         uint64_t ts_event,
+                                         uint64_t ts_init,
+                                         uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_expired_new(TraderId_t trader_id,
+                                        StrategyId_t strategy_id,
+                                        InstrumentId_t instrument_id,
+                                        ClientOrderId_t client_order_id,
+                                        const VenueOrderId_t *venue_order_id,
+                                        const AccountId_t *account_id,
+                                        UUID4_t event_id,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init,
+                                        uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_triggered_new(TraderId_t trader_id,
+                                          StrategyId_t strategy_id,
+                                          InstrumentId_t instrument_id,
+                                          ClientOrderId_t client_order_id,
+                                          const VenueOrderId_t *venue_order_id,
+                                          const AccountId_t *account_id,
+                                          UUID4_t event_id,
+                                          uint64_t ts_event,
+                                          uint64_t ts_init,
+                                          uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+    OrderEventAny_API order_pending_update_new(TraderId_t trader_id,
+                                               StrategyId_t strategy_id,
+                                               InstrumentId_t instrument_id,
+                                               ClientOrderId_t client_order_id,
+                                               const VenueOrderId_t *venue_order_id,
+                                               AccountId_t account_id,
+                                               UUID4_t event_id,
+                                               uint64_t ts_event,
+                                               uint64_t ts_init,
+                                               uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id` is either null (no value) or a valid pointer.
+    OrderEventAny_API order_pending_cancel_new(TraderId_t trader_id,
+                                               StrategyId_t strategy_id,
+                                               InstrumentId_t instrument_id,
+                                               ClientOrderId_t client_order_id,
+                                               const VenueOrderId_t *venue_order_id,
+                                               AccountId_t account_id,
+                                               UUID4_t event_id,
+                                               uint64_t ts_event,
+                                               uint64_t ts_init,
+                                               uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `reason_ptr` is a valid C string pointer.
+    # - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_modify_rejected_new(TraderId_t trader_id,
+                                                StrategyId_t strategy_id,
+                                                InstrumentId_t instrument_id,
+                                                ClientOrderId_t client_order_id,
+                                                const VenueOrderId_t *venue_order_id,
+                                                const AccountId_t *account_id,
+                                                const char *reason_ptr,
+                                                UUID4_t event_id,
+                                                uint64_t ts_event,
+                                                uint64_t ts_init,
+                                                uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `reason_ptr` is a valid C string pointer.
+    # - Assumes `venue_order_id` and `account_id` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_cancel_rejected_new(TraderId_t trader_id,
+                                                StrategyId_t strategy_id,
+                                                InstrumentId_t instrument_id,
+                                                ClientOrderId_t client_order_id,
+                                                const VenueOrderId_t *venue_order_id,
+                                                const AccountId_t *account_id,
+                                                const char *reason_ptr,
+                                                UUID4_t event_id,
+                                                uint64_t ts_event,
+                                                uint64_t ts_init,
+                                                uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `venue_order_id`, `account_id`, `price` and `trigger_price` are each either null
+    #   (no value) or a valid pointer.
+    OrderEventAny_API order_updated_new(TraderId_t trader_id,
+                                        StrategyId_t strategy_id,
+                                        InstrumentId_t instrument_id,
+                                        ClientOrderId_t client_order_id,
+                                        const VenueOrderId_t *venue_order_id,
+                                        const AccountId_t *account_id,
+                                        Quantity_t quantity,
+                                        const Price_t *price,
+                                        const Price_t *trigger_price,
+                                        UUID4_t event_id,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init,
+                                        uint8_t reconciliation);
+
+    # # Safety
+    #
+    # - Assumes `position_id` and `commission` are each either null (no value) or a valid pointer.
+    OrderEventAny_API order_filled_new(TraderId_t trader_id,
+                                       StrategyId_t strategy_id,
+                                       InstrumentId_t instrument_id,
+                                       ClientOrderId_t client_order_id,
+                                       VenueOrderId_t venue_order_id,
+                                       AccountId_t account_id,
+                                       TradeId_t trade_id,
+                                       const PositionId_t *position_id,
+                                       OrderSide order_side,
+                                       OrderType order_type,
+                                       Quantity_t last_qty,
+                                       Price_t last_px,
+                                       Currency_t currency,
+                                       const Money_t *commission,
+                                       LiquiditySide liquidity_side,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init,
+                                       uint8_t reconciliation);
+
+    void order_event_drop(OrderEventAny_API event);
+
+    UUID4_t order_event_id(const OrderEventAny_API *event);
+
+    TraderId_t order_event_trader_id(const OrderEventAny_API *event);
+
+    StrategyId_t order_event_strategy_id(const OrderEventAny_API *event);
+
+    InstrumentId_t order_event_instrument_id(const OrderEventAny_API *event);
+
+    ClientOrderId_t order_event_client_order_id(const OrderEventAny_API *event);
+
+    # Writes the venue order ID of the event to `output`, returning 1 if the event has one,
+    # otherwise 0 (leaving `output` unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `output` is a valid pointer to write the venue order ID to.
+    uint8_t order_event_venue_order_id(const OrderEventAny_API *event, VenueOrderId_t *output);
+
+    # Writes the account ID of the event to `output`, returning 1 if the event has one,
+    # otherwise 0 (leaving `output` unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `output` is a valid pointer to write the account ID to.
+    uint8_t order_event_account_id(const OrderEventAny_API *event, AccountId_t *output);
+
+    uint8_t order_event_reconciliation(const OrderEventAny_API *event);
+
+    uint64_t order_event_ts_event(const OrderEventAny_API *event);
+
+    uint64_t order_event_ts_init(const OrderEventAny_API *event);
+
     # Returns a Nautilus identifier from a C string pointer.
     #
     # # Safety
@@ -1540,6 +2457,279 @@ cdef extern from "../includes/model.h":
 
     uint64_t venue_order_id_hash(const VenueOrderId_t *id);
 
+    # Creates a new [`CurrencyPair`] instrument.
+    #
+    # # Safety
+    #
+    # - Assumes each of `lot_size`, `max_quantity`, `min_quantity`, `max_notional`, `min_notional`,
+    #   `max_price` and `min_price` is either null (no value) or a valid pointer of its type.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If any fee or margin value is not finite.
+    # - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+    InstrumentAny_API currency_pair_new(InstrumentId_t instrument_id,
+                                        Symbol_t raw_symbol,
+                                        Currency_t base_currency,
+                                        Currency_t quote_currency,
+                                        uint8_t price_precision,
+                                        uint8_t size_precision,
+                                        Price_t price_increment,
+                                        Quantity_t size_increment,
+                                        double taker_fee,
+                                        double maker_fee,
+                                        double margin_init,
+                                        double margin_maint,
+                                        const Quantity_t *lot_size,
+                                        const Quantity_t *max_quantity,
+                                        const Quantity_t *min_quantity,
+                                        const Money_t *max_notional,
+                                        const Money_t *min_notional,
+                                        const Price_t *max_price,
+                                        const Price_t *min_price,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init);
+
+    # Creates a new [`CryptoPerpetual`] instrument.
+    #
+    # # Safety
+    #
+    # - Assumes each of `multiplier`, `lot_size`, `max_quantity`, `min_quantity`, `max_notional`,
+    #   `min_notional`, `max_price` and `min_price` is either null (no value) or a valid pointer
+    #   of its type.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If any fee or margin value is not finite.
+    # - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+    InstrumentAny_API crypto_perpetual_new(InstrumentId_t instrument_id,
+                                           Symbol_t raw_symbol,
+                                           Currency_t base_currency,
+                                           Currency_t quote_currency,
+                                           Currency_t settlement_currency,
+                                           uint8_t is_inverse,
+                                           uint8_t price_precision,
+                                           uint8_t size_precision,
+                                           Price_t price_increment,
+                                           Quantity_t size_increment,
+                                           double maker_fee,
+                                           double taker_fee,
+                                           double margin_init,
+                                           double margin_maint,
+                                           const Quantity_t *multiplier,
+                                           const Quantity_t *lot_size,
+                                           const Quantity_t *max_quantity,
+                                           const Quantity_t *min_quantity,
+                                           const Money_t *max_notional,
+                                           const Money_t *min_notional,
+                                           const Price_t *max_price,
+                                           const Price_t *min_price,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init);
+
+    # Creates a new [`FuturesContract`] instrument.
+    #
+    # Margin values of NaN are treated as not set.
+    #
+    # # Safety
+    #
+    # - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+    # - Assumes `underlying_ptr` is a valid C string pointer.
+    # - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+    #   null (no value) or a valid pointer of its type.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+    InstrumentAny_API futures_contract_new(InstrumentId_t instrument_id,
+                                           Symbol_t raw_symbol,
+                                           AssetClass asset_class,
+                                           const char *exchange_ptr,
+                                           const char *underlying_ptr,
+                                           uint64_t activation_ns,
+                                           uint64_t expiration_ns,
+                                           Currency_t currency,
+                                           uint8_t price_precision,
+                                           Price_t price_increment,
+                                           Quantity_t multiplier,
+                                           Quantity_t lot_size,
+                                           const Quantity_t *max_quantity,
+                                           const Quantity_t *min_quantity,
+                                           const Price_t *max_price,
+                                           const Price_t *min_price,
+                                           double margin_init,
+                                           double margin_maint,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init);
+
+    # Creates a new [`OptionsContract`] instrument.
+    #
+    # Margin values of NaN are treated as not set.
+    #
+    # # Safety
+    #
+    # - Assumes `exchange_ptr` is either null (no exchange) or a valid C string pointer.
+    # - Assumes `underlying_ptr` is a valid C string pointer.
+    # - Assumes each of `max_quantity`, `min_quantity`, `max_price` and `min_price` is either
+    #   null (no value) or a valid pointer of its type.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the instrument fails validation (e.g. mismatched precisions or non-positive increments).
+    InstrumentAny_API options_contract_new(InstrumentId_t instrument_id,
+                                           Symbol_t raw_symbol,
+                                           AssetClass asset_class,
+                                           const char *exchange_ptr,
+                                           const char *underlying_ptr,
+                                           OptionKind option_kind,
+                                           Price_t strike_price,
+                                           Currency_t currency,
+                                           uint64_t activation_ns,
+                                           uint64_t expiration_ns,
+                                           uint8_t price_precision,
+                                           Price_t price_increment,
+                                           Quantity_t multiplier,
+                                           Quantity_t lot_size,
+                                           const Quantity_t *max_quantity,
+                                           const Quantity_t *min_quantity,
+                                           const Price_t *max_price,
+                                           const Price_t *min_price,
+                                           double margin_init,
+                                           double margin_maint,
+                                           uint64_t ts_event,
+                                           uint64_t ts_init);
+
+    void instrument_drop(InstrumentAny_API instrument);
+
+    InstrumentId_t instrument_id(const InstrumentAny_API *instrument);
+
+    InstrumentClass instrument_class(const InstrumentAny_API *instrument);
+
+    Currency_t instrument_quote_currency(const InstrumentAny_API *instrument);
+
+    Currency_t instrument_settlement_currency(const InstrumentAny_API *instrument);
+
+    uint8_t instrument_is_inverse(const InstrumentAny_API *instrument);
+
+    uint8_t instrument_is_quanto(const InstrumentAny_API *instrument);
+
+    uint8_t instrument_price_precision(const InstrumentAny_API *instrument);
+
+    uint8_t instrument_size_precision(const InstrumentAny_API *instrument);
+
+    Price_t instrument_price_increment(const InstrumentAny_API *instrument);
+
+    Quantity_t instrument_size_increment(const InstrumentAny_API *instrument);
+
+    Quantity_t instrument_multiplier(const InstrumentAny_API *instrument);
+
+    # Returns the lot size, or NaN if the instrument has none.
+    double instrument_lot_size(const InstrumentAny_API *instrument);
+
+    # Returns the maximum order quantity, or NaN if the instrument has none.
+    double instrument_max_quantity(const InstrumentAny_API *instrument);
+
+    # Returns the minimum order quantity, or NaN if the instrument has none.
+    double instrument_min_quantity(const InstrumentAny_API *instrument);
+
+    # Returns the expiration UNIX timestamp (nanoseconds), or zero if the instrument does not expire.
+    uint64_t instrument_expiration_ns(const InstrumentAny_API *instrument);
+
+    Price_t instrument_make_price(const InstrumentAny_API *instrument, double value);
+
+    Quantity_t instrument_make_qty(const InstrumentAny_API *instrument, double value);
+
+    Price_t instrument_make_price_rounded(const InstrumentAny_API *instrument,
+                                          double value,
+                                          RoundingMode mode);
+
+    Quantity_t instrument_make_qty_rounded(const InstrumentAny_API *instrument,
+                                           double value,
+                                           RoundingMode mode);
+
+    # Validates an order price and quantity against the instrument, returning 1 if valid,
+    # otherwise 0 with the violation written to `violation`.
+    #
+    # # Safety
+    #
+    # - Assumes `violation` is a valid pointer to write the violation to.
+    uint8_t instrument_validate_order(const InstrumentAny_API *instrument,
+                                      Price_t price,
+                                      Quantity_t quantity,
+                                      InstrumentViolation *violation);
+
+    const char *instrument_violation_to_cstr(InstrumentViolation value);
+
+    Money_t instrument_notional_value(const InstrumentAny_API *instrument,
+                                      Quantity_t quantity,
+                                      Price_t price,
+                                      uint8_t use_quote_for_inverse);
+
+    # Returns the equivalent quantity of the base asset for the given quote `quantity` at `last_px`.
+    Quantity_t instrument_calculate_base_quantity(const InstrumentAny_API *instrument,
+                                                  Quantity_t quantity,
+                                                  Price_t last_px);
+
+    # Returns the intrinsic value of one contract at the given `underlying` price.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    Money_t options_contract_intrinsic_value(const InstrumentAny_API *instrument,
+                                             Price_t underlying);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    uint8_t options_contract_is_itm(const InstrumentAny_API *instrument, Price_t underlying);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    uint8_t options_contract_is_atm(const InstrumentAny_API *instrument, Price_t underlying);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    uint8_t options_contract_is_otm(const InstrumentAny_API *instrument, Price_t underlying);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    uint8_t options_contract_is_active(const InstrumentAny_API *instrument, uint64_t now_ns);
+
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    uint8_t options_contract_is_expired(const InstrumentAny_API *instrument, uint64_t now_ns);
+
+    # Returns the fractional number of days from `now_ns` until expiration (zero once expired).
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `instrument` is not an options contract.
+    double options_contract_days_to_expiry(const InstrumentAny_API *instrument, uint64_t now_ns);
+
+    # Parses an OCC option symbol, returning 1 if successful with the components written to
+    # `output`, otherwise 0.
+    #
+    # # Safety
+    #
+    # - Assumes `ptr` is a valid C string pointer.
+    # - Assumes `output` is a valid pointer to write the parsed symbol to.
+    uint8_t occ_symbol_parse(const char *ptr, OccSymbol *output);
+
     # # Safety
     #
     # - Assumes `components_ptr` is a valid C string pointer of a JSON format list of strings.
@@ -1623,9 +2813,17 @@ cdef extern from "../includes/model.h":
 
     void orderbook_clear_asks(OrderBook_API *book, uint64_t sequence, uint64_t ts_event);
 
-    void orderbook_apply_delta(OrderBook_API *book, const OrderBookDelta_t *delta);
+    void orderbook_set_integrity_policy(OrderBook_API *book, BookIntegrityPolicy policy);
+
+    # Applies the order book delta, returning 1 on success or 0 if the delta's action is not
+    # allowed for the book type, or the book's integrity policy is `RaiseError` and the delta
+    # crosses the book.
+    uint8_t orderbook_apply_delta(OrderBook_API *book, const OrderBookDelta_t *delta);
 
-    void orderbook_apply_deltas(OrderBook_API *book, const OrderBookDeltas_API *deltas);
+    # Applies the order book deltas, returning 1 on success or 0 if any delta's action is not
+    # allowed for the book type, or the book's integrity policy is `RaiseError` and a delta
+    # crosses the book.
+    uint8_t orderbook_apply_deltas(OrderBook_API *book, const OrderBookDeltas_API *deltas);
 
     void orderbook_apply_depth(OrderBook_API *book, const OrderBookDepth10_t *depth);
 
@@ -1657,6 +2855,14 @@ cdef extern from "../includes/model.h":
                                             Price_t price,
                                             OrderSide order_side);
 
+    Quantity_t orderbook_cumulative_depth(const OrderBook_API *book, OrderSide side, Price_t price);
+
+    # Returns the size imbalance over the top `depth` levels per side (`NaN` if the book is empty).
+    double orderbook_imbalance(const OrderBook_API *book, uintptr_t depth);
+
+    # Returns the microprice (`NaN` if either side of the book is empty).
+    double orderbook_microprice(const OrderBook_API *book);
+
     # Updates the order book with a quote tick.
     #
     # # Panics
@@ -1675,10 +2881,54 @@ cdef extern from "../includes/model.h":
 
     CVec orderbook_simulate_fills(const OrderBook_API *book, BookOrder_t order);
 
+    # Simulates filling `qty` against the opposite side of the book, up to the optional
+    # `price_limit` (pass null for no limit).
+    #
+    # The result must be freed with `simulated_fills_drop`. If `is_partial` is set then the
+    # book did not have sufficient liquidity at or better than `price_limit`.
+    #
+    # # Safety
+    #
+    # - Assumes `price_limit` is either null or a valid pointer to a `Price`.
+    CSimulatedFills orderbook_simulate_fills_for_quantity(const OrderBook_API *book,
+                                                          OrderSide order_side,
+                                                          const Price_t *price_limit,
+                                                          Quantity_t qty);
+
+    void simulated_fills_drop(CSimulatedFills result);
+
     uint8_t orderbook_check_integrity(const OrderBook_API *book);
 
+    uint32_t orderbook_checksum_crc32_kraken(const OrderBook_API *book, uintptr_t depth);
+
+    uint32_t orderbook_checksum_crc32_okx(const OrderBook_API *book, uintptr_t depth);
+
+    uint8_t orderbook_verify_checksum(const OrderBook_API *book,
+                                      BookChecksumType checksum_type,
+                                      uintptr_t depth,
+                                      uint32_t expected);
+
     void vec_fills_drop(CVec v);
 
+    void vec_book_fills_drop(CVec v);
+
+    # Returns a snapshot of the top `depth` levels per side, which must be freed with
+    # `book_snapshot_drop`.
+    CBookSnapshot orderbook_snapshot(const OrderBook_API *book, uintptr_t depth);
+
+    # Returns the top `depth` levels per side grouped into price buckets of `tick_size`, which
+    # must be freed with `book_snapshot_drop`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `tick_size` is not positive (> 0).
+    CBookSnapshot orderbook_group_levels(const OrderBook_API *book,
+                                         Price_t tick_size,
+                                         uintptr_t depth);
+
+    void book_snapshot_drop(CBookSnapshot snapshot);
+
     # Returns a pretty printed `OrderBook` number of levels per side, as a C string pointer.
     const char *orderbook_pprint_to_cstr(const OrderBook_API *book, uintptr_t num_levels);
 
@@ -1692,6 +2942,8 @@ cdef extern from "../includes/model.h":
 
     CVec level_orders(const Level_API *level);
 
+    uint32_t level_count(const Level_API *level);
+
     double level_size(const Level_API *level);
 
     double level_exposure(const Level_API *level);
@@ -1700,6 +2952,212 @@ cdef extern from "../includes/model.h":
 
     void vec_orders_drop(CVec v);
 
+    # Creates a new order from the values of an `OrderInitialized` event.
+    #
+    # # Safety
+    #
+    # - Assumes `price` and `trigger_price` are each either null (no price) or a valid `Price` pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `order_type` is not `MARKET`, `LIMIT`, `STOP_MARKET` or `STOP_LIMIT`.
+    # - If a price or trigger price required by the `order_type` is null.
+    OrderAny_API order_new(TraderId_t trader_id,
+                           StrategyId_t strategy_id,
+                           InstrumentId_t instrument_id,
+                           ClientOrderId_t client_order_id,
+                           OrderSide order_side,
+                           OrderType order_type,
+                           Quantity_t quantity,
+                           const Price_t *price,
+                           const Price_t *trigger_price,
+                           TriggerType trigger_type,
+                           TimeInForce time_in_force,
+                           uint8_t post_only,
+                           uint8_t reduce_only,
+                           UUID4_t init_id,
+                           uint64_t ts_init);
+
+    void order_drop(OrderAny_API order);
+
+    # Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_event(OrderAny_API *order, const OrderEventAny_API *event);
+
+    # Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_denied(OrderAny_API *order, OrderDenied_t event);
+
+    # Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_submitted(OrderAny_API *order, OrderSubmitted_t event);
+
+    # Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_accepted(OrderAny_API *order, OrderAccepted_t event);
+
+    # Applies the `event` to the order, returning 1 if applied, or 0 if the event was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_rejected(OrderAny_API *order, OrderRejected_t event);
+
+    # Applies a pending update event to the order, returning 1 if applied, or 0 if the event
+    # was rejected (leaving the order unchanged).
+    uint8_t order_apply_pending_update(OrderAny_API *order,
+                                       UUID4_t event_id,
+                                       uint64_t ts_event,
+                                       uint64_t ts_init);
+
+    # Applies a modify rejected event to the order, returning 1 if applied, or 0 if the event
+    # was rejected (leaving the order unchanged).
+    #
+    # # Safety
+    #
+    # - Assumes `reason_ptr` is a valid C string pointer.
+    uint8_t order_apply_modify_rejected(OrderAny_API *order,
+                                        const char *reason_ptr,
+                                        UUID4_t event_id,
+                                        uint64_t ts_event,
+                                        uint64_t ts_init);
+
+    # Applies a canceled event to the order, returning 1 if applied, or 0 if the event was
+    # rejected (leaving the order unchanged).
+    uint8_t order_apply_canceled(OrderAny_API *order,
+                                 UUID4_t event_id,
+                                 uint64_t ts_event,
+                                 uint64_t ts_init);
+
+    # Applies a fill to the order, returning 1 if applied, or 0 if the fill was rejected
+    # (leaving the order unchanged).
+    uint8_t order_apply_filled(OrderAny_API *order,
+                               VenueOrderId_t venue_order_id,
+                               AccountId_t account_id,
+                               TradeId_t trade_id,
+                               Quantity_t last_qty,
+                               Price_t last_px,
+                               Currency_t currency,
+                               Money_t commission,
+                               LiquiditySide liquidity_side,
+                               UUID4_t event_id,
+                               uint64_t ts_event,
+                               uint64_t ts_init);
+
+    OrderStatus order_status(const OrderAny_API *order);
+
+    Quantity_t order_quantity(const OrderAny_API *order);
+
+    Quantity_t order_filled_qty(const OrderAny_API *order);
+
+    Quantity_t order_leaves_qty(const OrderAny_API *order);
+
+    # Returns the average fill price, or NaN if the order has no fills.
+    double order_avg_px(const OrderAny_API *order);
+
+    uint8_t order_is_closed(const OrderAny_API *order);
+
+    # Creates a new flat position, which is opened by the first applied fill.
+    #
+    # # Safety
+    #
+    # - Assumes `base_currency` is either null (no base currency) or a valid `Currency` pointer.
+    Position_API position_new(TraderId_t trader_id,
+                              StrategyId_t strategy_id,
+                              InstrumentId_t instrument_id,
+                              PositionId_t position_id,
+                              AccountId_t account_id,
+                              uint8_t price_precision,
+                              uint8_t size_precision,
+                              Quantity_t multiplier,
+                              uint8_t is_inverse,
+                              const Currency_t *base_currency,
+                              Currency_t quote_currency,
+                              Currency_t settlement_currency,
+                              uint64_t ts_init);
+
+    void position_drop(Position_API position);
+
+    # Applies an `OrderFilled` event with the given fill details to the position.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `order_side` is `NO_ORDER_SIDE`.
+    # - If the `trade_id` has already been applied to the position.
+    void position_apply_fill(Position_API *position,
+                             ClientOrderId_t client_order_id,
+                             VenueOrderId_t venue_order_id,
+                             TradeId_t trade_id,
+                             OrderSide order_side,
+                             Quantity_t last_qty,
+                             Price_t last_px,
+                             Money_t commission,
+                             uint64_t ts_event,
+                             uint64_t ts_init);
+
+    PositionSide position_side(const Position_API *position);
+
+    double position_signed_qty(const Position_API *position);
+
+    Quantity_t position_quantity(const Position_API *position);
+
+    double position_avg_px_open(const Position_API *position);
+
+    # Returns the average close price, or `NaN` if the position has not been reduced.
+    double position_avg_px_close(const Position_API *position);
+
+    # Returns the realized PnL in the settlement currency (zero if no fills have been applied).
+    Money_t position_realized_pnl(const Position_API *position);
+
+    Money_t position_unrealized_pnl(const Position_API *position, Price_t last);
+
+    Money_t position_total_pnl(const Position_API *position, Price_t last);
+
+    # Returns the notional value of the position at the `last` price.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the position is inverse and was created without a base currency.
+    Money_t position_notional_value(const Position_API *position, Price_t last);
+
+    uint8_t position_is_open(const Position_API *position);
+
+    uint8_t position_is_closed(const Position_API *position);
+
+    # Returns a new [`AccountBalance`] from the `total`, `locked` and `free` balances.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the balances are not all in the same currency.
+    # - If `total` is not the result of `locked` + `free`.
+    AccountBalance_t account_balance_new(Money_t total, Money_t locked, Money_t free);
+
+    # Returns whether the `balance` is valid, with all balances in its currency and the `free`
+    # balance equal to the `total` less the `locked` balance.
+    uint8_t account_balance_is_valid(const AccountBalance_t *balance);
+
+    uint8_t account_balance_eq(const AccountBalance_t *lhs, const AccountBalance_t *rhs);
+
+    const char *account_balance_to_cstr(const AccountBalance_t *balance);
+
+    # Returns a new [`MarginBalance`] from the `initial` and `maintenance` margins.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `maintenance` is not in the currency of `initial`.
+    MarginBalance_t margin_balance_new(Money_t initial,
+                                       Money_t maintenance,
+                                       InstrumentId_t instrument_id);
+
+    # Returns whether the `balance` is valid, with both margins in its currency.
+    uint8_t margin_balance_is_valid(const MarginBalance_t *balance);
+
+    uint8_t margin_balance_eq(const MarginBalance_t *lhs, const MarginBalance_t *rhs);
+
+    const char *margin_balance_to_cstr(const MarginBalance_t *balance);
+
     # Returns a [`Currency`] from pointers and primitives.
     #
     # # Safety
@@ -1742,6 +3200,8 @@ cdef extern from "../includes/model.h":
 
     void money_sub_assign(Money_t a, Money_t b);
 
+    void vec_money_drop(CVec v);
+
     Price_t price_new(double value, uint8_t precision);
 
     Price_t price_from_raw(int64_t raw, uint8_t precision);
@@ -0,0 +1,97 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uint64_t, uintptr_t
+
+cdef extern from "../includes/network.h":
+
+    # The state of the connection of a reconnecting client.
+    cpdef enum ConnectionState:
+        # The initial connection is being established.
+        CONNECTION_STATE_CONNECTING # = 1,
+        # The client is connected to the server.
+        CONNECTION_STATE_CONNECTED # = 2,
+        # The connection was lost and the client is attempting to reconnect.
+        CONNECTION_STATE_RECONNECTING # = 3,
+        # The client is closing the connection on request.
+        CONNECTION_STATE_DISCONNECTING # = 4,
+        # The client has closed the connection on request.
+        CONNECTION_STATE_DISCONNECTED # = 5,
+        # The client gave up reconnecting after exhausting its reconnection attempts.
+        CONNECTION_STATE_FAULTED # = 6,
+
+    # The kind of framing of a framed socket started with [`framed_socket_start`].
+    cpdef enum FramingKind:
+        # Frames are preceded by a big-endian length header.
+        FRAMING_KIND_LENGTH_PREFIXED # = 1,
+        # Frames are terminated by a delimiter byte sequence.
+        FRAMING_KIND_DELIMITED # = 2,
+        # Frames are terminated by a newline.
+        FRAMING_KIND_NEWLINE # = 3,
+
+    # A framed socket client with the runtime driving its connection.
+    cdef struct FramedSocketHandle:
+        pass
+
+    # The callback receiving each complete frame as a byte slice, valid only during the call.
+    ctypedef void (*FrameCallback)(void *context, const uint8_t *data, uintptr_t len);
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`FramedSocketHandle`].
+    #
+    # This struct wraps `FramedSocketHandle` in a way that makes it compatible with C function
+    # calls, enabling interaction with `FramedSocketClient` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `FramedSocketClient_API` to be
+    # dereferenced to `FramedSocketHandle`, providing access to its methods without having to
+    # manually access the underlying instance.
+    cdef struct FramedSocketClient_API:
+        FramedSocketHandle *_0;
+
+    # Connects a framed socket to the server at `url_ptr` (`host:port`), passing each complete
+    # frame received to `callback` (on the socket thread) along with `context`.
+    #
+    # Returns 1 with the connected socket written to `socket`, otherwise 0 (if the runtime cannot
+    # be created or the initial connection fails).
+    #
+    # - `header_len`: The length header size for `LENGTH_PREFIXED` framing (1, 2, 4 or 8 bytes).
+    # - `delimiter_ptr`/`delimiter_len`: The delimiter for `DELIMITED` framing (e.g. SOH).
+    # - `heartbeat_interval_ms`: The heartbeat period, zero disables heartbeats.
+    # - `heartbeat_ptr`/`heartbeat_len`: The heartbeat message (framed before sending).
+    #
+    # The socket reconnects with exponential backoff if the connection drops.
+    #
+    # # Safety
+    #
+    # - Assumes `url_ptr` is a valid C string pointer.
+    # - Assumes `delimiter_ptr` and `heartbeat_ptr` point to at least `delimiter_len` and
+    #   `heartbeat_len` bytes respectively (unless the length is zero).
+    # - Assumes `context` can be used from another thread until [`framed_socket_stop`] returns.
+    # - Assumes `socket` is a valid pointer to write the connected socket to.
+    uint8_t framed_socket_start(const char *url_ptr,
+                                uint8_t use_tls,
+                                FramingKind framing,
+                                uint8_t header_len,
+                                const uint8_t *delimiter_ptr,
+                                uintptr_t delimiter_len,
+                                uint64_t heartbeat_interval_ms,
+                                const uint8_t *heartbeat_ptr,
+                                uintptr_t heartbeat_len,
+                                FrameCallback callback,
+                                void *context,
+                                FramedSocketClient_API *socket);
+
+    # Sends `data_len` bytes at `data_ptr` as a frame, buffering it while reconnecting.
+    #
+    # Returns 1 if the frame was sent (or buffered), otherwise 0.
+    #
+    # # Safety
+    #
+    # - Assumes `data_ptr` points to at least `data_len` bytes (unless `data_len` is zero).
+    uint8_t framed_socket_send(const FramedSocketClient_API *socket,
+                               const uint8_t *data_ptr,
+                               uintptr_t data_len);
+
+    # Returns the connection state of the framed socket.
+    ConnectionState framed_socket_state(const FramedSocketClient_API *socket);
+
+    # Shuts down the connection of the framed socket and drops it.
+    void framed_socket_stop(FramedSocketClient_API socket);
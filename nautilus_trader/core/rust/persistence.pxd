@@ -0,0 +1,312 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uintptr_t
+from nautilus_trader.core.rust.core cimport CVec
+
+cdef extern from "../includes/persistence.h":
+
+    # Integer timestamps below this value are treated as milliseconds when the timestamp format
+    # is [`TimestampFormat::Auto`] (as nanoseconds it would be within two weeks of the epoch).
+    const uint64_t AUTO_MILLIS_THRESHOLD # = 1000000000000000
+
+    # The default maximum number of rows per row group.
+    const uintptr_t DEFAULT_ROW_GROUP_SIZE # = 100000
+
+    # The default number of buffered events per type which triggers a flush.
+    const uintptr_t DEFAULT_FLUSH_SIZE # = 10000
+
+    # The precision value which indicates the precision should be inferred from the file.
+    const uint8_t INFER_PRECISION # = UINT8_MAX
+
+    # The handling of rows which cannot be parsed.
+    cdef enum MalformedRowPolicy:
+        # Return an error naming the line of the first malformed row.
+        MALFORMED_ROW_POLICY_FAIL # = 0,
+        # Skip malformed rows, logging a warning with the line number of each.
+        MALFORMED_ROW_POLICY_SKIP # = 1,
+
+    # The compression codec applied to each column chunk of a Parquet file.
+    cdef enum ParquetCompression:
+        PARQUET_COMPRESSION_UNCOMPRESSED # = 0,
+        PARQUET_COMPRESSION_SNAPPY # = 1,
+        PARQUET_COMPRESSION_ZSTD # = 2,
+
+    # The market data types which can be persisted to Parquet.
+    cdef enum ParquetDataType:
+        PARQUET_DATA_TYPE_QUOTE_TICK # = 1,
+        PARQUET_DATA_TYPE_TRADE_TICK # = 2,
+        PARQUET_DATA_TYPE_BAR # = 3,
+
+    # The file format events are streamed to.
+    cdef enum StreamingFormat:
+        # Arrow IPC stream files, as written by the Python `StreamingFeatherWriter`.
+        STREAMING_FORMAT_FEATHER # = 1,
+        # Parquet files, with a row group per flush.
+        STREAMING_FORMAT_PARQUET # = 2,
+
+    # The text format of a file to load.
+    cdef enum TextFormat:
+        # Comma-separated values with a header row.
+        TEXT_FORMAT_CSV # = 1,
+        # Newline-delimited JSON, with one object per line.
+        TEXT_FORMAT_JSON_LINES # = 2,
+
+    # The format of the timestamp columns in a file to load.
+    cdef enum TimestampFormat:
+        # Detected per value: integers as nanoseconds or milliseconds since the UNIX epoch (see
+        # [`AUTO_MILLIS_THRESHOLD`]), otherwise ISO 8601.
+        TIMESTAMP_FORMAT_AUTO # = 0,
+        # Integer nanoseconds since the UNIX epoch.
+        TIMESTAMP_FORMAT_UNIX_NANOS # = 1,
+        # Integer milliseconds since the UNIX epoch.
+        TIMESTAMP_FORMAT_UNIX_MILLIS # = 2,
+        # ISO 8601 (RFC 3339), or a date and time without an offset which is taken as UTC.
+        TIMESTAMP_FORMAT_ISO8601 # = 3,
+
+    # Wraps a [`ParquetReader`] for each of the supported data types.
+    cdef struct ParquetReaderAny:
+        pass
+
+    # Wraps a [`ParquetWriter`] for each of the supported data types.
+    cdef struct ParquetWriterAny:
+        pass
+
+    # Flushes and closes the installed streaming writer when dropped.
+    cdef struct StreamingWriterGuard:
+        pass
+
+    # Wraps a [`TextReader`] for each of the supported data types.
+    cdef struct TextReaderAny:
+        pass
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`TextReaderAny`].
+    #
+    # This struct wraps `TextReaderAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `TextReaderAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `TextReader_API` to be
+    # dereferenced to `TextReaderAny`, providing access to `TextReaderAny`'s methods without
+    # having to manually access the underlying `TextReaderAny` instance.
+    cdef struct TextReader_API:
+        TextReaderAny *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`ParquetReaderAny`].
+    #
+    # This struct wraps `ParquetReaderAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ParquetReaderAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `ParquetReader_API` to be
+    # dereferenced to `ParquetReaderAny`, providing access to `ParquetReaderAny`'s methods without
+    # having to manually access the underlying `ParquetReaderAny` instance.
+    cdef struct ParquetReader_API:
+        ParquetReaderAny *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`StreamingWriterGuard`].
+    #
+    # The guard signals that the streaming writer is installed, and flushes and closes its files
+    # when dropped (mirroring the `LogGuard` for the logger).
+    cdef struct StreamingWriterGuard_API:
+        StreamingWriterGuard *_0;
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`ParquetWriterAny`].
+    #
+    # This struct wraps `ParquetWriterAny` in a way that makes it compatible with C function
+    # calls, enabling interaction with `ParquetWriterAny` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `ParquetWriter_API` to be
+    # dereferenced to `ParquetWriterAny`, providing access to `ParquetWriterAny`'s methods without
+    # having to manually access the underlying `ParquetWriterAny` instance.
+    cdef struct ParquetWriter_API:
+        ParquetWriterAny *_0;
+
+    # Opens a new CSV or JSONL reader for `data_type` at the file path `path_ptr`, returning
+    # chunks of `chunk_size` items.
+    #
+    # The `columns_ptr` is a JSON object mapping field names to source column names, and the
+    # instrument ID and bar type apply to every row when given. Pass `INFER_PRECISION` for a
+    # precision to be inferred from the file.
+    #
+    # # Safety
+    #
+    # - Assumes `path_ptr` is a valid C string pointer.
+    # - Assumes `columns_ptr` is either NULL or a valid C string pointer.
+    # - Assumes `instrument_id_ptr` is either NULL or a valid C string pointer.
+    # - Assumes `bar_type_ptr` is either NULL or a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the instrument ID or bar type is invalid.
+    # - If the reader cannot be created (e.g. the file cannot be opened).
+    TextReader_API text_reader_new(ParquetDataType data_type,
+                                   TextFormat format,
+                                   const char *path_ptr,
+                                   const char *columns_ptr,
+                                   const char *instrument_id_ptr,
+                                   const char *bar_type_ptr,
+                                   uint8_t price_precision,
+                                   uint8_t size_precision,
+                                   TimestampFormat timestamp_format,
+                                   MalformedRowPolicy malformed_rows,
+                                   uintptr_t chunk_size);
+
+    void text_reader_drop(TextReader_API reader);
+
+    ParquetDataType text_reader_data_type(const TextReader_API *reader);
+
+    # Returns the number of malformed rows skipped so far.
+    uintptr_t text_reader_skipped_count(const TextReader_API *reader);
+
+    # Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+    # once the file is exhausted.
+    #
+    # The chunk must be freed with `text_reader_drop_chunk`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If reading the file fails, or a row is malformed with the `FAIL` policy.
+    CVec text_reader_next_chunk(TextReader_API *reader);
+
+    # Drops a chunk previously returned by `text_reader_next_chunk`.
+    #
+    # # Safety
+    #
+    # - Assumes `chunk` was returned by `text_reader_next_chunk` for this `reader`.
+    void text_reader_drop_chunk(const TextReader_API *reader, CVec chunk);
+
+    # Opens a new Parquet reader for `data_type` at the file path `path_ptr`, returning chunks
+    # of `chunk_size` items with `ts_init` within the inclusive range `start_ns` to `end_ns`.
+    #
+    # Pass `0` and `u64::MAX` to leave the start and end of the range unbounded.
+    #
+    # # Safety
+    #
+    # - Assumes `path_ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `chunk_size` is zero.
+    # - If the file cannot be opened or is not a valid market data Parquet file.
+    ParquetReader_API parquet_reader_new(ParquetDataType data_type,
+                                         const char *path_ptr,
+                                         uintptr_t chunk_size,
+                                         uint64_t start_ns,
+                                         uint64_t end_ns);
+
+    void parquet_reader_drop(ParquetReader_API reader);
+
+    ParquetDataType parquet_reader_data_type(const ParquetReader_API *reader);
+
+    # Returns the next chunk of data as a `CVec` of the reader's data type, or an empty `CVec`
+    # once the file is exhausted.
+    #
+    # The chunk must be freed with `parquet_reader_drop_chunk`.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If reading or decoding the file fails.
+    CVec parquet_reader_next_chunk(ParquetReader_API *reader);
+
+    # Drops a chunk previously returned by `parquet_reader_next_chunk`.
+    #
+    # # Safety
+    #
+    # - Assumes `chunk` was returned by `parquet_reader_next_chunk` for this `reader`.
+    void parquet_reader_drop_chunk(const ParquetReader_API *reader, CVec chunk);
+
+    # Installs a streaming writer for the run `run_id_ptr` under the directory `directory_ptr`,
+    # closing any previously installed writer.
+    #
+    # A `flush_interval_ms` of zero disables time based flushing.
+    #
+    # # Safety
+    #
+    # - Assumes `directory_ptr` is a valid C string pointer.
+    # - Assumes `run_id_ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `flush_size` is zero or the run ID is empty.
+    StreamingWriterGuard_API streaming_writer_init(const char *directory_ptr,
+                                                   const char *run_id_ptr,
+                                                   StreamingFormat format,
+                                                   uintptr_t flush_size,
+                                                   uint64_t flush_interval_ms,
+                                                   ParquetCompression compression);
+
+    # Writes an event serialized as a JSON object (with a string `type` field) to the installed
+    # streaming writer.
+    #
+    # Has no effect if no streaming writer is installed.
+    #
+    # # Safety
+    #
+    # - Assumes `json_ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the event cannot be written (see [`StreamingWriter::write_json`]).
+    void writer_write_event(const char *json_ptr);
+
+    # Closes the files of the installed streaming writer and starts writing the run `run_id_ptr`.
+    #
+    # # Safety
+    #
+    # - Assumes `run_id_ptr` is a valid C string pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If the run ID is empty or closing the current files fails.
+    void streaming_writer_rotate(const char *run_id_ptr);
+
+    # Flushes the buffered events of the installed streaming writer to its files.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If flushing fails (see [`StreamingWriter::flush`]).
+    void streaming_writer_flush();
+
+    # Flushes and closes the installed streaming writer.
+    void streaming_writer_drop(StreamingWriterGuard_API guard);
+
+    # Opens a new Parquet writer for `data_type` at the file path `path_ptr`, returning 1 on
+    # success with the writer written to `writer`, otherwise 0 (if `row_group_size` is zero).
+    #
+    # # Safety
+    #
+    # - Assumes `path_ptr` is a valid C string pointer.
+    # - Assumes `writer` is a valid pointer to write the new writer to.
+    uint8_t parquet_writer_new(ParquetDataType data_type,
+                               const char *path_ptr,
+                               uintptr_t row_group_size,
+                               ParquetCompression compression,
+                               ParquetWriter_API *writer);
+
+    # Writes a batch of data to the Parquet writer, returning 1 on success, otherwise 0 (see
+    # [`crate::parquet::writer::ParquetWriter::write_batch`] for the failure cases).
+    #
+    # The data is only borrowed, so the caller retains ownership of the `data` memory.
+    #
+    # # Safety
+    #
+    # - Assumes `data` is a valid `CVec` containing objects of the writer's data type.
+    uint8_t parquet_writer_write_batch(ParquetWriter_API *writer, const CVec *data);
+
+    ParquetDataType parquet_writer_data_type(const ParquetWriter_API *writer);
+
+    uintptr_t parquet_writer_row_count(const ParquetWriter_API *writer);
+
+    # Closes the Parquet writer (freeing its memory), returning 1 on success with the total
+    # number of rows written to `row_count`, otherwise 0 (if flushing or closing the file fails).
+    #
+    # # Safety
+    #
+    # - Assumes `row_count` is a valid pointer to write the row count to.
+    uint8_t parquet_writer_close(ParquetWriter_API writer, uintptr_t *row_count);
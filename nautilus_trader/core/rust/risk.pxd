@@ -0,0 +1,120 @@
+# Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+from libc.stdint cimport uint8_t, uintptr_t
+from nautilus_trader.core.rust.model cimport Currency_t, InstrumentId_t, Money_t, OrderSide, PositionSide, Price_t, Quantity_t, TradingState
+
+cdef extern from "../includes/risk.h":
+
+    # The reason an order was denied by pre-trade risk checks.
+    cdef enum RiskDenialReason:
+        # Trading is halted, so no orders are permitted.
+        TRADING_HALTED # = 1,
+        # Trading is reducing, and the order would not reduce the current position.
+        TRADING_REDUCING # = 2,
+        # No risk limits are configured for the order's instrument.
+        UNKNOWN_INSTRUMENT # = 3,
+        # The quantity precision exceeds the instrument size precision.
+        QUANTITY_PRECISION # = 4,
+        # The quantity is not a multiple of the instrument size increment.
+        QUANTITY_INCREMENT # = 5,
+        # The quantity is less than the instrument minimum quantity.
+        QUANTITY_BELOW_MIN # = 6,
+        # The quantity is greater than the instrument maximum quantity.
+        QUANTITY_ABOVE_MAX # = 7,
+        # The quantity is greater than the configured maximum order quantity.
+        QUANTITY_EXCEEDS_LIMIT # = 8,
+        # The price precision exceeds the instrument price precision.
+        PRICE_PRECISION # = 9,
+        # The price is not a multiple of the instrument price increment.
+        PRICE_INCREMENT # = 10,
+        # The price is not positive.
+        PRICE_NOT_POSITIVE # = 11,
+        # The price deviates from the last price by more than the configured collar.
+        PRICE_OUTSIDE_COLLAR # = 12,
+        # The notional value is less than the instrument minimum notional.
+        NOTIONAL_BELOW_MIN # = 13,
+        # The notional value is greater than the instrument maximum notional.
+        NOTIONAL_ABOVE_MAX # = 14,
+        # The notional value is greater than the configured maximum notional per order.
+        NOTIONAL_EXCEEDS_LIMIT # = 15,
+        # The order would open a new position beyond the configured maximum open positions.
+        MAX_OPEN_POSITIONS # = 16,
+        # A notional limit is in a different currency to the order notional value.
+        NOTIONAL_CURRENCY_MISMATCH # = 17,
+
+    # Provides pre-trade risk checks of orders against per-instrument limits and the
+    # current [`TradingState`].
+    cdef struct RiskChecker:
+        pass
+
+    # C compatible Foreign Function Interface (FFI) for an underlying [`RiskChecker`].
+    #
+    # This struct wraps `RiskChecker` in a way that makes it compatible with C function
+    # calls, enabling interaction with `RiskChecker` in a C environment.
+    #
+    # It implements the `Deref` trait, allowing instances of `RiskChecker_API` to be
+    # dereferenced to `RiskChecker`, providing access to `RiskChecker`'s methods without
+    # having to manually access the underlying `RiskChecker` instance.
+    cdef struct RiskChecker_API:
+        RiskChecker *_0;
+
+    # Creates a new risk checker.
+    #
+    # # Safety
+    #
+    # - Assumes `max_open_positions` is either null (no limit) or a valid `usize` pointer.
+    RiskChecker_API risk_checker_new(TradingState trading_state,
+                                     const uintptr_t *max_open_positions);
+
+    void risk_checker_drop(RiskChecker_API checker);
+
+    TradingState risk_checker_trading_state(const RiskChecker_API *checker);
+
+    void risk_checker_set_trading_state(RiskChecker_API *checker, TradingState trading_state);
+
+    # Sets the risk limits for the given `instrument_id`, replacing any existing limits.
+    #
+    # Pass a `price_collar` of NaN for no price collar.
+    #
+    # # Safety
+    #
+    # - Assumes each of the optional limit pointers is either null (no limit) or a valid pointer.
+    #
+    # # Panics
+    #
+    # This function panics:
+    # - If `price_collar` is not NaN and cannot be represented as a `Decimal`.
+    void risk_checker_set_limits(RiskChecker_API *checker,
+                                 InstrumentId_t instrument_id,
+                                 Price_t price_increment,
+                                 Quantity_t size_increment,
+                                 Quantity_t multiplier,
+                                 uint8_t is_inverse,
+                                 Currency_t notional_currency,
+                                 const Quantity_t *min_quantity,
+                                 const Quantity_t *max_quantity,
+                                 const Money_t *min_notional,
+                                 const Money_t *max_notional,
+                                 const Quantity_t *max_order_quantity,
+                                 const Money_t *max_notional_per_order,
+                                 double price_collar);
+
+    # Checks an order against the pre-trade risk limits, returning 1 if the order passes,
+    # otherwise 0 with the denial reason written to `reason`.
+    #
+    # # Safety
+    #
+    # - Assumes `price` and `last_price` are each either null (no price) or a valid `Price` pointer.
+    # - Assumes `reason` is a valid pointer to write the denial reason to.
+    uint8_t risk_checker_check_order(const RiskChecker_API *checker,
+                                     InstrumentId_t instrument_id,
+                                     OrderSide order_side,
+                                     Quantity_t quantity,
+                                     const Price_t *price,
+                                     const Price_t *last_price,
+                                     PositionSide position_side,
+                                     Quantity_t position_quantity,
+                                     uintptr_t open_positions,
+                                     RiskDenialReason *reason);
+
+    const char *risk_denial_reason_to_cstr(RiskDenialReason value);
@@ -17,6 +17,7 @@ from libc.stdint cimport uint8_t
 from libc.stdint cimport uint64_t
 
 from nautilus_trader.core.data cimport Data
+from nautilus_trader.core.rust.model cimport BookIntegrityPolicy
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport Level_API
 from nautilus_trader.core.rust.model cimport OrderBook_API
@@ -37,6 +38,7 @@ cdef class OrderBook(Data):
     cdef BookType _book_type
 
     cpdef void reset(self)
+    cpdef void set_integrity_policy(self, BookIntegrityPolicy policy)
     cpdef void add(self, BookOrder order, uint64_t ts_event, uint8_t flags=*, uint64_t sequence=*)
     cpdef void update(self, BookOrder order, uint64_t ts_event, uint8_t flags=*, uint64_t sequence=*)
     cpdef void delete(self, BookOrder order, uint64_t ts_event, uint8_t flags=*, uint64_t sequence=*)
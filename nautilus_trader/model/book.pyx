@@ -28,6 +28,7 @@ from nautilus_trader.core.correctness cimport Condition
 from nautilus_trader.core.data cimport Data
 from nautilus_trader.core.rust.core cimport CVec
 from nautilus_trader.core.rust.model cimport BookAction
+from nautilus_trader.core.rust.model cimport BookIntegrityPolicy
 from nautilus_trader.core.rust.model cimport BookOrder_t
 from nautilus_trader.core.rust.model cimport BookType
 from nautilus_trader.core.rust.model cimport Level_API
@@ -71,6 +72,7 @@ from nautilus_trader.core.rust.model cimport orderbook_new
 from nautilus_trader.core.rust.model cimport orderbook_pprint_to_cstr
 from nautilus_trader.core.rust.model cimport orderbook_reset
 from nautilus_trader.core.rust.model cimport orderbook_sequence
+from nautilus_trader.core.rust.model cimport orderbook_set_integrity_policy
 from nautilus_trader.core.rust.model cimport orderbook_simulate_fills
 from nautilus_trader.core.rust.model cimport orderbook_spread
 from nautilus_trader.core.rust.model cimport orderbook_ts_last
@@ -239,6 +241,18 @@ cdef class OrderBook(Data):
         """
         orderbook_reset(&self._mem)
 
+    cpdef void set_integrity_policy(self, BookIntegrityPolicy policy):
+        """
+        Set the policy applied when a delta leaves the order book crossed or locked.
+
+        Parameters
+        ----------
+        policy : BookIntegrityPolicy
+            The integrity policy.
+
+        """
+        orderbook_set_integrity_policy(&self._mem, policy)
+
     cpdef void add(self, BookOrder order, uint64_t ts_event, uint8_t flags=0, uint64_t sequence=0):
         """
         Add the given order to the book.
@@ -336,13 +350,16 @@ cdef class OrderBook(Data):
 
         Raises
         ------
-        ValueError
-            If `delta.book_type` is not equal to `self.type`.
+        RuntimeError
+            If the delta's action is not allowed for the book type.
+        RuntimeError
+            If the integrity policy is ``RAISE_ERROR`` and the delta crosses the book.
 
         """
         Condition.not_none(delta, "delta")
 
-        orderbook_apply_delta(&self._mem, &delta._mem)
+        if not orderbook_apply_delta(&self._mem, &delta._mem):
+            raise RuntimeError(f"Failed to apply {delta!r}")
 
     cpdef void apply_deltas(self, OrderBookDeltas deltas):
         """
@@ -353,10 +370,18 @@ cdef class OrderBook(Data):
         deltas : OrderBookDeltas
             The deltas to apply.
 
+        Raises
+        ------
+        RuntimeError
+            If any delta's action is not allowed for the book type.
+        RuntimeError
+            If the integrity policy is ``RAISE_ERROR`` and a delta crosses the book.
+
         """
         Condition.not_none(deltas, "deltas")
 
-        orderbook_apply_deltas(&self._mem, &deltas._mem)
+        if not orderbook_apply_deltas(&self._mem, &deltas._mem):
+            raise RuntimeError(f"Failed to apply {deltas!r}")
 
     cpdef void apply_depth(self, OrderBookDepth10 depth):
         """
@@ -562,7 +587,7 @@ cdef class OrderBook(Data):
 
         Warnings
         --------
-        If no average price can be calculated then will return 0.0 (zero).
+        If no average price can be calculated then will return NaN.
 
         """
         Condition.not_none(quantity, "quantity")
@@ -4777,7 +4777,7 @@ cdef class OrderFilled(OrderEvent):
             event_id=UUID4(values["event_id"]),
             ts_event=values["ts_event"],
             ts_init=values["ts_init"],
-            info=values["info"],
+            info=values.get("info"),
             reconciliation=values.get("reconciliation", False),
         )
 
@@ -0,0 +1,37 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from libc.stdint cimport uint8_t
+from libc.stdint cimport uint64_t
+
+from nautilus_trader.core.rust.network cimport FramedSocketClient_API
+from nautilus_trader.core.rust.network cimport FramingKind
+
+
+cdef class FramedSocket:
+    cdef FramedSocketClient_API _mem
+    cdef str _url
+    cdef object _handler
+    cdef FramingKind _framing
+    cdef uint8_t _header_len
+    cdef bytes _delimiter
+    cdef bint _use_tls
+    cdef uint64_t _heartbeat_interval_ms
+    cdef bytes _heartbeat
+    cdef bint _is_connected
+
+    cpdef void connect(self)
+    cpdef void send(self, bytes data)
+    cpdef void disconnect(self)
@@ -0,0 +1,195 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from typing import Callable
+
+from cpython.bytes cimport PyBytes_FromStringAndSize
+from libc.stdint cimport uint8_t
+from libc.stdint cimport uint64_t
+from libc.stdint cimport uintptr_t
+
+from nautilus_trader.core.correctness cimport Condition
+from nautilus_trader.core.rust.network cimport ConnectionState
+from nautilus_trader.core.rust.network cimport FramedSocketClient_API
+from nautilus_trader.core.rust.network cimport FramingKind
+from nautilus_trader.core.rust.network cimport framed_socket_send
+from nautilus_trader.core.rust.network cimport framed_socket_start
+from nautilus_trader.core.rust.network cimport framed_socket_state
+from nautilus_trader.core.string cimport pystr_to_cstr
+
+
+# Declared `nogil` here so the GIL can be released while disconnecting, as the socket thread
+# acquires the GIL to pass frames to the handler until the connection has stopped
+cdef extern from "../includes/network.h" nogil:
+    void framed_socket_stop(FramedSocketClient_API socket)
+
+
+cdef void _on_frame(void *context, const uint8_t *data, uintptr_t length) noexcept with gil:
+    (<FramedSocket>context)._handler(PyBytes_FromStringAndSize(<const char *>data, length))
+
+
+cdef class FramedSocket:
+    """
+    Provides a framed TCP socket client, which reconnects with exponential backoff if the
+    connection drops.
+
+    Each complete frame received is passed to the `handler` (on the socket thread) as bytes.
+
+    Parameters
+    ----------
+    url : str
+        The server address as ``host:port``.
+    handler : Callable[[bytes], None]
+        The handler for each frame received.
+    framing : FramingKind
+        The kind of framing.
+    header_len : uint8_t, default 4
+        The length header size for ``FRAMING_KIND_LENGTH_PREFIXED`` framing (1, 2, 4 or 8 bytes).
+    delimiter : bytes, default b""
+        The frame delimiter for ``FRAMING_KIND_DELIMITED`` framing.
+    use_tls : bool, default False
+        If the connection uses TLS.
+    heartbeat_interval_ms : uint64_t, default 0
+        The heartbeat period (milliseconds), zero disables heartbeats.
+    heartbeat : bytes, default b""
+        The heartbeat message (framed before sending).
+
+    """
+
+    def __init__(
+        self,
+        str url not None,
+        handler: Callable[[bytes], None],
+        FramingKind framing,
+        uint8_t header_len = 4,
+        bytes delimiter not None = b"",
+        bint use_tls = False,
+        uint64_t heartbeat_interval_ms = 0,
+        bytes heartbeat not None = b"",
+    ) -> None:
+        Condition.valid_string(url, "url")
+        Condition.callable(handler, "handler")
+
+        self._url = url
+        self._handler = handler
+        self._framing = framing
+        self._header_len = header_len
+        self._delimiter = delimiter
+        self._use_tls = use_tls
+        self._heartbeat_interval_ms = heartbeat_interval_ms
+        self._heartbeat = heartbeat
+        self._is_connected = False
+
+    def __del__(self) -> None:
+        if self._is_connected:
+            self.disconnect()
+
+    @property
+    def is_connected(self) -> bool:
+        """
+        Return whether the socket has been connected (and not yet disconnected).
+
+        Returns
+        -------
+        bool
+
+        """
+        return self._is_connected
+
+    @property
+    def state(self) -> ConnectionState:
+        """
+        Return the connection state of the socket.
+
+        Returns
+        -------
+        ConnectionState
+
+        Raises
+        ------
+        RuntimeError
+            If the socket is not connected.
+
+        """
+        if not self._is_connected:
+            raise RuntimeError("Socket not connected")
+        return framed_socket_state(&self._mem)
+
+    cpdef void connect(self):
+        """
+        Connect the socket to the server.
+
+        Raises
+        ------
+        RuntimeError
+            If the socket is already connected.
+        ConnectionError
+            If the initial connection fails.
+
+        """
+        if self._is_connected:
+            raise RuntimeError("Socket already connected")
+
+        if not framed_socket_start(
+            pystr_to_cstr(self._url),
+            self._use_tls,
+            self._framing,
+            self._header_len,
+            <const uint8_t *>self._delimiter,
+            len(self._delimiter),
+            self._heartbeat_interval_ms,
+            <const uint8_t *>self._heartbeat,
+            len(self._heartbeat),
+            _on_frame,
+            <void *>self,
+            &self._mem,
+        ):
+            raise ConnectionError(f"Failed to connect framed socket to {self._url}")
+        self._is_connected = True
+
+    cpdef void send(self, bytes data):
+        """
+        Send the given data as a frame, buffering it while reconnecting.
+
+        Parameters
+        ----------
+        data : bytes
+            The data to send.
+
+        Raises
+        ------
+        RuntimeError
+            If the socket is not connected, or the frame cannot be sent.
+
+        """
+        Condition.not_none(data, "data")
+
+        if not self._is_connected:
+            raise RuntimeError("Socket not connected")
+        if not framed_socket_send(&self._mem, <const uint8_t *>data, len(data)):
+            raise RuntimeError("Failed to send frame")
+
+    cpdef void disconnect(self):
+        """
+        Disconnect the socket from the server (does nothing if not connected).
+
+        """
+        if not self._is_connected:
+            return
+
+        cdef FramedSocketClient_API mem = self._mem
+        self._is_connected = False
+        with nogil:
+            framed_socket_stop(mem)
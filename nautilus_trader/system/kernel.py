@@ -174,7 +174,7 @@ class NautilusKernel:
                 if logging.clear_log_file and logging.log_directory and logging.log_file_name:
                     file_path = Path(
                         logging.log_directory,
-                        f"{logging.log_file_name}.{'log' if logging.log_file_format is None else 'json'}",
+                        f"{logging.log_file_name}.{'log' if logging.log_file_format is None else logging.log_file_format.lower()}",
                     )
 
                     if file_path.exists():
@@ -199,6 +199,9 @@ class NautilusKernel:
                         is_colored=logging.log_colors,
                         is_bypassed=logging.bypass_logging,
                         print_config=logging.print_config,
+                        rate_limits=logging.log_rate_limits,
+                        component_colors=logging.log_component_colors,
+                        file_sinks=logging.log_file_sinks,
                     )
                     nautilus_pyo3.log_header(
                         trader_id=nautilus_pyo3.TraderId(self._trader_id.value),
@@ -225,6 +228,9 @@ class NautilusKernel:
                         colors=logging.log_colors,
                         bypass=logging.bypass_logging,
                         print_config=logging.print_config,
+                        rate_limits=logging.log_rate_limits,
+                        component_colors=logging.log_component_colors,
+                        file_sinks=logging.log_file_sinks,
                     )
                     log_header(
                         trader_id=self._trader_id,
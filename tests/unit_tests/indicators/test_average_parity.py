@@ -0,0 +1,134 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+from nautilus_trader.indicators.average.ema import ExponentialMovingAverage
+from nautilus_trader.indicators.average.sma import SimpleMovingAverage
+from nautilus_trader.indicators.average.wma import WeightedMovingAverage
+
+
+# Shared with the Rust indicator tests (`parity_series` stub), the expected values must match
+# bit-for-bit between the Cython and Rust indicators
+PARITY_SERIES = [
+    1.00020,
+    1.00030,
+    1.00050,
+    1.00040,
+    1.00010,
+    0.99980,
+    0.99950,
+    0.99970,
+    1.00000,
+    1.00060,
+    1.00090,
+    1.00080,
+    1.00110,
+    1.00070,
+    1.00100,
+]
+
+EMA_10_EXPECTED = [
+    (1.0002, False),
+    (1.0002181818181817, False),
+    (1.0002694214876031, False),
+    (1.0002931630353116, False),
+    (1.0002580424834366, False),
+    (1.0001747620319026, False),
+    (1.0000520780261022, False),
+    (0.9999880638395381, False),
+    (0.9999902340505311, False),
+    (1.0001011005867981, True),
+    (1.000246355025562, True),
+    (1.000347017748187, True),
+    (1.000483923612153, True),
+    (1.0005232102281252, True),
+    (1.000609899277557, True),
+]
+
+SMA_10_EXPECTED = [
+    (1.0002, False),
+    (1.0002499999999999, False),
+    (1.000333333333333, False),
+    (1.0003499999999999, False),
+    (1.0002999999999997, False),
+    (1.0002166666666665, False),
+    (1.0001142857142855, False),
+    (1.0000624999999999, False),
+    (1.0000555555555555, False),
+    (1.0001099999999998, True),
+    (1.0001799999999998, True),
+    (1.00023, True),
+    (1.0002900000000001, True),
+    (1.00032, True),
+    (1.00041, True),
+]
+
+WMA_10_EXPECTED = [
+    (1.0002, False),
+    (1.0002526315789473, False),
+    (1.0003444444444443, False),
+    (1.0003617647058825, False),
+    (1.0002975, False),
+    (1.0001866666666668, False),
+    (1.000042857142857, False),
+    (0.9999673076923077, False),
+    (0.9999592592592591, False),
+    (1.00006, True),
+    (1.0002036363636364, True),
+    (1.0003163636363637, True),
+    (1.0004745454545454, True),
+    (1.000549090909091, True),
+    (1.0006727272727274, True),
+]
+
+
+def _update_all(indicator) -> list[tuple[float, bool]]:
+    results = []
+    for value in PARITY_SERIES:
+        indicator.update_raw(value)
+        results.append((indicator.value, indicator.initialized))
+    return results
+
+
+def test_ema_parity_with_rust():
+    # Arrange
+    ema = ExponentialMovingAverage(10)
+
+    # Act
+    results = _update_all(ema)
+
+    # Assert
+    assert results == EMA_10_EXPECTED
+
+
+def test_sma_parity_with_rust():
+    # Arrange
+    sma = SimpleMovingAverage(10)
+
+    # Act
+    results = _update_all(sma)
+
+    # Assert
+    assert results == SMA_10_EXPECTED
+
+
+def test_wma_parity_with_rust():
+    # Arrange
+    wma = WeightedMovingAverage(10, weights=[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0])
+
+    # Act
+    results = _update_all(wma)
+
+    # Assert
+    assert results == WMA_10_EXPECTED
@@ -13,9 +13,14 @@
 #  limitations under the License.
 # -------------------------------------------------------------------------------------------------
 
+import msgspec
+import pytest
+
+from nautilus_trader import TEST_DATA_DIR
 from nautilus_trader.common.component import TestClock
 from nautilus_trader.common.factories import OrderFactory
 from nautilus_trader.core.uuid import UUID4
+from nautilus_trader.model import events
 from nautilus_trader.model.currencies import USD
 from nautilus_trader.model.currencies import USDT
 from nautilus_trader.model.enums import AccountType
@@ -822,3 +827,34 @@ class TestPositionEvents:
             repr(event)
             == f"PositionClosed(trader_id=TESTER-000, strategy_id=S-001, instrument_id=AUD/USD.SIM, position_id=P-123456, account_id=SIM-000, opening_order_id=O-19700101-000000-000-001-1, closing_order_id=O-19700101-000000-000-001-2, entry=BUY, side=FLAT, signed_qty=0.0, quantity=0, peak_qty=100_000, currency=USD, avg_px_open=1.00001, avg_px_close=1.00011, realized_return=0.00010, realized_pnl=6.00 USD, unrealized_pnl=0.00 USD, ts_opened=0, ts_last=0, ts_closed=0, duration_ns=0, event_id={uuid})"  # noqa
         )
+
+
+ORDER_EVENTS_FIXTURE = msgspec.json.decode((TEST_DATA_DIR / "nautilus" / "order_events.json").read_bytes())
+
+
+class TestOrderEventsJsonSchema:
+    # The fixture is shared with the Rust tests in `nautilus_model`, which assert that Rust
+    # order events serialize to (and deserialize from) the same JSON schema.
+
+    @pytest.mark.parametrize("values", ORDER_EVENTS_FIXTURE, ids=lambda v: v["type"])
+    def test_order_event_json_roundtrip(self, values: dict) -> None:
+        # Arrange
+        event_cls = getattr(events, values["type"])
+
+        # Act
+        event = event_cls.from_dict(values)
+
+        # Assert
+        assert event_cls.to_dict(event) == values
+
+    def test_order_filled_from_rust_json_without_info(self) -> None:
+        # Arrange
+        values = next(v for v in ORDER_EVENTS_FIXTURE if v["type"] == "OrderFilled")
+        values = {k: v for k, v in values.items() if k != "info"}
+
+        # Act
+        event = OrderFilled.from_dict(values)
+
+        # Assert
+        assert event.info is None
+        assert event.commission == Money.from_str("2.10002500 USDT")
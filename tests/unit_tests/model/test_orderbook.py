@@ -14,6 +14,7 @@
 # -------------------------------------------------------------------------------------------------
 
 import copy
+import math
 import pickle
 
 import pandas as pd
@@ -195,7 +196,7 @@ class TestOrderBook:
         quantity = Quantity.from_str("1.0")
 
         # Act, Assert
-        assert book.get_avg_px_for_quantity(quantity, order_side) == 0.0
+        assert math.isnan(book.get_avg_px_for_quantity(quantity, order_side))
 
     @pytest.mark.parametrize(
         ("order_side", "expected"),
@@ -0,0 +1,94 @@
+# -------------------------------------------------------------------------------------------------
+#  Copyright (C) 2015-2024 Nautech Systems Pty Ltd. All rights reserved.
+#  https://nautechsystems.io
+#
+#  Licensed under the GNU Lesser General Public License Version 3.0 (the "License");
+#  You may not use this file except in compliance with the License.
+#  You may obtain a copy of the License at https://www.gnu.org/licenses/lgpl-3.0.en.html
+#
+#  Unless required by applicable law or agreed to in writing, software
+#  distributed under the License is distributed on an "AS IS" BASIS,
+#  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+#  See the License for the specific language governing permissions and
+#  limitations under the License.
+# -------------------------------------------------------------------------------------------------
+
+import socket
+import socketserver
+import threading
+import time
+
+import pytest
+
+from nautilus_trader.core.rust.network import ConnectionState
+from nautilus_trader.core.rust.network import FramingKind
+from nautilus_trader.network.framed import FramedSocket
+
+
+class _EchoHandler(socketserver.StreamRequestHandler):
+    def handle(self) -> None:
+        for line in self.rfile:
+            self.wfile.write(line)
+
+
+def _unused_port() -> int:
+    with socket.socket() as sock:
+        sock.bind(("127.0.0.1", 0))
+        return sock.getsockname()[1]
+
+
+def test_connect_when_refused_raises_connection_error() -> None:
+    # Arrange
+    framed = FramedSocket(
+        url=f"127.0.0.1:{_unused_port()}",
+        handler=lambda frame: None,
+        framing=FramingKind.FRAMING_KIND_NEWLINE,
+    )
+
+    # Act, Assert
+    with pytest.raises(ConnectionError):
+        framed.connect()
+    assert not framed.is_connected
+
+
+def test_send_when_not_connected_raises_runtime_error() -> None:
+    # Arrange
+    framed = FramedSocket(
+        url="127.0.0.1:1",
+        handler=lambda frame: None,
+        framing=FramingKind.FRAMING_KIND_NEWLINE,
+    )
+
+    # Act, Assert
+    with pytest.raises(RuntimeError):
+        framed.send(b"ping")
+
+
+def test_send_receives_echoed_frames() -> None:
+    # Arrange
+    server = socketserver.ThreadingTCPServer(("127.0.0.1", 0), _EchoHandler)
+    server.daemon_threads = True
+    threading.Thread(target=server.serve_forever, daemon=True).start()
+    frames: list[bytes] = []
+    framed = FramedSocket(
+        url=f"127.0.0.1:{server.server_address[1]}",
+        handler=frames.append,
+        framing=FramingKind.FRAMING_KIND_NEWLINE,
+    )
+
+    # Act
+    framed.connect()
+    framed.send(b"ping")
+    framed.send(b"pong")
+    deadline = time.monotonic() + 5
+    while len(frames) < 2 and time.monotonic() < deadline:
+        time.sleep(0.01)
+    state = framed.state
+    framed.disconnect()
+    server.shutdown()
+    server.server_close()
+
+    # Assert
+    assert state == ConnectionState.CONNECTION_STATE_CONNECTED
+    assert frames == [b"ping", b"pong"]
+    assert not framed.is_connected